@@ -0,0 +1,896 @@
+//! A [Debug Adapter Protocol][dap] server, to debug scripts from an editor.
+//!
+//! The server talks to a single client, over stdio or over a TCP connection on localhost. It
+//! runs the script of the `launch` request once the client is done with its configuration, and
+//! drives the execution with the [`Debugger`] hooks of the context: the execution pauses on
+//! breakpoints, steps and exceptions, and the requests of the client are handled until it asks
+//! to resume.
+//!
+//! Messages are read on a separate thread, so that `pause` and `disconnect` requests can stop
+//! a script that is running.
+//!
+//! [dap]: https://microsoft.github.io/debug-adapter-protocol/specification
+
+use boa_engine::{
+    object::{FunctionBuilder, JsObject},
+    property::PropertyKey,
+    vm::{Debugger, InterruptHandle, PauseHandle, PauseReason, ResumeMode},
+    Context, JsValue,
+};
+use serde_json::{json, Value};
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+    fs::{canonicalize, read},
+    io::{self, BufRead, BufReader, Write},
+    net::TcpListener,
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        mpsc::{channel, Receiver},
+        Arc, Mutex,
+    },
+    thread,
+};
+
+/// The id of the only thread reported to the client.
+const THREAD_ID: i64 = 1;
+
+/// Runs a debug adapter over stdio, or over the first TCP connection made to `port` on
+/// localhost.
+///
+/// `program` is the script run when the `launch` request doesn't name one.
+pub(crate) fn run(port: Option<u16>, program: Option<PathBuf>) -> io::Result<()> {
+    match port {
+        Some(port) => {
+            let listener = TcpListener::bind(("127.0.0.1", port))?;
+            eprintln!("Debug adapter listening on {}", listener.local_addr()?);
+            let (stream, _) = listener.accept()?;
+            serve(
+                BufReader::new(stream.try_clone()?),
+                Box::new(stream),
+                program,
+            );
+        }
+        None => serve(BufReader::new(io::stdin()), Box::new(io::stdout()), program),
+    }
+    Ok(())
+}
+
+/// Serves the requests read from `reader` until the client disconnects.
+fn serve<R>(reader: R, writer: Box<dyn Write + Send>, program: Option<PathBuf>)
+where
+    R: BufRead + Send + 'static,
+{
+    let mut context = Context::default();
+    let connection = Arc::new(Connection::new(writer));
+    let requests = spawn_reader(
+        reader,
+        connection.clone(),
+        context.pause_handle(),
+        context.interrupt_handle(),
+    );
+
+    let session = Rc::new(Session {
+        connection,
+        requests,
+        program: RefCell::new(program),
+        stop_on_entry: Cell::new(false),
+        pause_on_exceptions: Cell::new(false),
+        disconnected: Cell::new(false),
+        references: RefCell::default(),
+    });
+
+    // Wait for the client to launch the script and finish its configuration.
+    let (mut launched, mut configured) = (false, false);
+    while !(launched && configured) {
+        let request = match session.requests.recv() {
+            Ok(request) => request,
+            Err(_) => return,
+        };
+        match request.command.as_str() {
+            "launch" => launched = true,
+            "configurationDone" => configured = true,
+            _ => {}
+        }
+        session.handle(&request, &mut context);
+        if session.disconnected.get() {
+            return;
+        }
+    }
+
+    session.redirect_console(&mut context);
+    context.set_debugger(Some(session.clone()));
+    let exit_code = session.run_program(&mut context);
+    context.set_debugger(None);
+
+    session
+        .connection
+        .event("exited", json!({ "exitCode": exit_code }));
+    session.connection.event("terminated", json!({}));
+
+    // Keep answering requests until the client disconnects.
+    while !session.disconnected.get() {
+        match session.requests.recv() {
+            Ok(request) => {
+                session.handle(&request, &mut context);
+            }
+            Err(_) => break,
+        }
+    }
+}
+
+/// Reads the messages of the client on a new thread, and sends its requests to the returned
+/// channel.
+///
+/// `pause` requests are answered directly, since the execution cannot handle them before it
+/// pauses. `disconnect` and `terminate` requests also interrupt the running script.
+fn spawn_reader<R>(
+    mut reader: R,
+    connection: Arc<Connection>,
+    pause_handle: PauseHandle,
+    interrupt_handle: InterruptHandle,
+) -> Receiver<Request>
+where
+    R: BufRead + Send + 'static,
+{
+    let (sender, receiver) = channel();
+    thread::spawn(move || {
+        while let Ok(Some(message)) = read_message(&mut reader) {
+            let request = match Request::from_message(message) {
+                Some(request) => request,
+                None => continue,
+            };
+            match request.command.as_str() {
+                "pause" => {
+                    pause_handle.request_pause();
+                    connection.respond(&request, json!({}));
+                    continue;
+                }
+                "disconnect" | "terminate" => interrupt_handle.interrupt(),
+                _ => {}
+            }
+            if sender.send(request).is_err() {
+                break;
+            }
+        }
+    });
+    receiver
+}
+
+/// Reads a message framed by a `Content-Length` header.
+///
+/// Returns `None` when the end of the stream is reached.
+fn read_message<R: BufRead>(reader: &mut R) -> io::Result<Option<Value>> {
+    let mut content_length = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("Content-Length") {
+                content_length = value.trim().parse::<usize>().ok();
+            }
+        }
+    }
+
+    let content_length = content_length.ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidData, "missing Content-Length header")
+    })?;
+    let mut content = vec![0; content_length];
+    reader.read_exact(&mut content)?;
+    serde_json::from_slice(&content)
+        .map(Some)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// A request sent by the client.
+#[derive(Debug)]
+struct Request {
+    seq: i64,
+    command: String,
+    arguments: Value,
+}
+
+impl Request {
+    /// Extracts a request from a message, ignoring the other kinds of messages.
+    fn from_message(mut message: Value) -> Option<Self> {
+        if message["type"] != "request" {
+            return None;
+        }
+        Some(Self {
+            seq: message["seq"].as_i64()?,
+            command: message["command"].as_str()?.to_owned(),
+            arguments: message["arguments"].take(),
+        })
+    }
+}
+
+/// The sending half of the connection with the client, shared with the reader thread.
+struct Connection {
+    writer: Mutex<Box<dyn Write + Send>>,
+    seq: AtomicI64,
+}
+
+impl fmt::Debug for Connection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Connection")
+            .field("seq", &self.seq)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Connection {
+    fn new(writer: Box<dyn Write + Send>) -> Self {
+        Self {
+            writer: Mutex::new(writer),
+            seq: AtomicI64::new(1),
+        }
+    }
+
+    /// Sends a message to the client.
+    ///
+    /// Write errors are ignored: a closed connection ends the reader thread, which stops the
+    /// session.
+    fn send(&self, mut message: Value) {
+        message["seq"] = self.seq.fetch_add(1, Ordering::Relaxed).into();
+        let content = message.to_string();
+        if let Ok(mut writer) = self.writer.lock() {
+            let _written = write!(writer, "Content-Length: {}\r\n\r\n{content}", content.len())
+                .and_then(|()| writer.flush());
+        }
+    }
+
+    /// Sends a successful response to `request`.
+    fn respond(&self, request: &Request, body: Value) {
+        let mut message = json!({
+            "type": "response",
+            "request_seq": request.seq,
+            "success": true,
+            "command": request.command,
+        });
+        message["body"] = body;
+        self.send(message);
+    }
+
+    /// Sends an error response to `request`.
+    fn respond_error(&self, request: &Request, message: &str) {
+        self.send(json!({
+            "type": "response",
+            "request_seq": request.seq,
+            "success": false,
+            "command": request.command,
+            "message": message,
+        }));
+    }
+
+    /// Sends an event to the client.
+    fn event(&self, event: &str, body: Value) {
+        let mut message = json!({ "type": "event", "event": event });
+        message["body"] = body;
+        self.send(message);
+    }
+}
+
+/// What a variables reference handed to the client points to.
+#[derive(Debug, Clone)]
+enum Reference {
+    /// The locals of the frame at the given depth.
+    Locals(usize),
+
+    /// The own properties of an object.
+    Object(JsObject),
+}
+
+/// The state of a debugging session, which is also the debugger of the context.
+#[derive(Debug)]
+struct Session {
+    connection: Arc<Connection>,
+    requests: Receiver<Request>,
+    program: RefCell<Option<PathBuf>>,
+    stop_on_entry: Cell<bool>,
+    pause_on_exceptions: Cell<bool>,
+    disconnected: Cell<bool>,
+
+    /// The variables references handed out since the execution last paused, numbered from 1.
+    references: RefCell<Vec<Reference>>,
+}
+
+impl Session {
+    /// Runs the launched program, and returns its exit code.
+    fn run_program(&self, context: &mut Context) -> i64 {
+        let program = if let Some(program) = self.program.borrow().clone() {
+            program
+        } else {
+            self.output("stderr", "no program to launch\n");
+            return 1;
+        };
+        let buffer = match read(&program) {
+            Ok(buffer) => buffer,
+            Err(e) => {
+                self.output(
+                    "stderr",
+                    &format!("could not read {}: {e}\n", program.display()),
+                );
+                return 1;
+            }
+        };
+
+        if self.stop_on_entry.get() {
+            context.pause_handle().request_pause();
+        }
+        match context.eval_with_source_name(&buffer, source_name(&program)) {
+            Ok(_) => 0,
+            Err(e) => {
+                let message = format!("Uncaught {}\n", crate::uncaught_error(&e, context));
+                self.output("stderr", &message);
+                1
+            }
+        }
+    }
+
+    /// Replaces the logging methods of `console` with methods that send their output to the
+    /// client, so they don't mix with the messages when the server talks over stdio.
+    fn redirect_console(&self, context: &mut Context) {
+        let console = match context.global_object().clone().get("console", context) {
+            Ok(console) => console,
+            Err(_) => return,
+        };
+        let console = match console.as_object() {
+            Some(console) => console.clone(),
+            None => return,
+        };
+
+        for (name, category) in [
+            ("log", "stdout"),
+            ("info", "stdout"),
+            ("debug", "stdout"),
+            ("warn", "stderr"),
+            ("error", "stderr"),
+        ] {
+            let function = FunctionBuilder::closure_with_state(
+                context,
+                |_, args, (connection, category), _| {
+                    let mut output = Vec::with_capacity(args.len());
+                    for arg in args {
+                        output.push(match arg.as_string() {
+                            Some(string) => string.to_std_string_escaped(),
+                            None => arg.display().to_string(),
+                        });
+                    }
+                    let output = output.join(" ") + "\n";
+                    connection.event("output", json!({ "category": category, "output": output }));
+                    Ok(JsValue::undefined())
+                },
+                (self.connection.clone(), category),
+            )
+            .name(name)
+            .length(0)
+            .build();
+            let _replaced = console.set(name, function, false, context);
+        }
+    }
+
+    /// Sends text to the output of the client.
+    fn output(&self, category: &str, output: &str) {
+        self.connection
+            .event("output", json!({ "category": category, "output": output }));
+    }
+
+    /// Handles a request, and returns how the execution resumes if it is a request to resume.
+    fn handle(&self, request: &Request, context: &mut Context) -> Option<ResumeMode> {
+        let arguments = &request.arguments;
+        let resume_mode = match request.command.as_str() {
+            "continue" => Some(ResumeMode::Continue),
+            "next" => Some(ResumeMode::StepOver),
+            "stepIn" => Some(ResumeMode::StepIn),
+            "stepOut" => Some(ResumeMode::StepOut),
+            _ => None,
+        };
+        if let Some(resume_mode) = resume_mode {
+            let body = if resume_mode == ResumeMode::Continue {
+                json!({ "allThreadsContinued": true })
+            } else {
+                json!({})
+            };
+            self.connection.respond(request, body);
+            return Some(resume_mode);
+        }
+
+        let result = match request.command.as_str() {
+            "initialize" => {
+                self.connection.respond(
+                    request,
+                    json!({
+                        "supportsConfigurationDoneRequest": true,
+                        "supportsEvaluateForHovers": true,
+                        "supportsTerminateRequest": true,
+                        "exceptionBreakpointFilters": [{
+                            "filter": "all",
+                            "label": "All Exceptions",
+                            "default": false,
+                        }],
+                    }),
+                );
+                self.connection.event("initialized", json!({}));
+                return None;
+            }
+            "launch" => {
+                if let Some(program) = arguments["program"].as_str() {
+                    *self.program.borrow_mut() = Some(PathBuf::from(program));
+                }
+                self.stop_on_entry
+                    .set(arguments["stopOnEntry"].as_bool().unwrap_or_default());
+                Ok(json!({}))
+            }
+            "configurationDone" => Ok(json!({})),
+            "setBreakpoints" => Ok(Self::set_breakpoints(arguments, context)),
+            "setExceptionBreakpoints" => {
+                let filters = arguments["filters"].as_array();
+                self.pause_on_exceptions
+                    .set(filters.map_or(false, |filters| filters.iter().any(|f| f == "all")));
+                Ok(json!({}))
+            }
+            "threads" => Ok(json!({ "threads": [{ "id": THREAD_ID, "name": "main" }] })),
+            "stackTrace" => Ok(Self::stack_trace(arguments, context)),
+            "scopes" => self.scopes(arguments, context),
+            "variables" => self.variables(arguments, context),
+            "evaluate" => self.evaluate(arguments, context),
+            "disconnect" | "terminate" => {
+                self.disconnected.set(true);
+                Ok(json!({}))
+            }
+            command => Err(format!("unsupported request `{command}`")),
+        };
+
+        match result {
+            Ok(body) => self.connection.respond(request, body),
+            Err(message) => self.connection.respond_error(request, &message),
+        }
+        None
+    }
+
+    /// Replaces the breakpoints of a source.
+    fn set_breakpoints(arguments: &Value, context: &mut Context) -> Value {
+        let path = arguments["source"]["path"].as_str().unwrap_or_default();
+        let name = source_name(Path::new(path));
+        context.clear_breakpoints(&name);
+
+        let mut breakpoints = Vec::new();
+        for breakpoint in arguments["breakpoints"].as_array().into_iter().flatten() {
+            if let Some(line) = breakpoint["line"].as_u64() {
+                context.set_breakpoint(&name, line as u32);
+                breakpoints.push(json!({ "verified": true, "line": line }));
+            }
+        }
+        json!({ "breakpoints": breakpoints })
+    }
+
+    /// Describes the call stack. Frame ids are the depth of the frames plus one.
+    fn stack_trace(arguments: &Value, context: &Context) -> Value {
+        let frames = context.stack_frames();
+        let start = arguments["startFrame"].as_u64().unwrap_or_default() as usize;
+        let levels = match arguments["levels"].as_u64() {
+            Some(levels) if levels > 0 => levels as usize,
+            _ => frames.len(),
+        };
+
+        let stack_frames: Vec<_> = frames
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(levels)
+            .map(|(depth, frame)| {
+                let name = if frame.function_name().is_empty() {
+                    "<anonymous>".to_owned()
+                } else {
+                    frame.function_name().to_std_string_escaped()
+                };
+                let source = frame.source_name().map(|path| {
+                    let path = path.to_std_string_escaped();
+                    let name = Path::new(&path)
+                        .file_name()
+                        .map(|name| name.to_string_lossy().into_owned());
+                    json!({ "name": name, "path": path })
+                });
+                json!({
+                    "id": depth + 1,
+                    "name": name,
+                    "source": source,
+                    "line": frame.line_number().unwrap_or_default(),
+                    "column": frame.column_number().unwrap_or_default(),
+                })
+            })
+            .collect();
+        json!({ "stackFrames": stack_frames, "totalFrames": frames.len() })
+    }
+
+    /// Lists the scopes of a frame.
+    fn scopes(&self, arguments: &Value, context: &Context) -> Result<Value, String> {
+        let depth = frame_depth(arguments)
+            .filter(|depth| *depth < context.stack_frames().len())
+            .ok_or("invalid frame id")?;
+        Ok(json!({
+            "scopes": [{
+                "name": "Locals",
+                "variablesReference": self.reference(Reference::Locals(depth)),
+                "expensive": false,
+            }],
+        }))
+    }
+
+    /// Lists the variables of a scope, or the properties of an object.
+    fn variables(&self, arguments: &Value, context: &mut Context) -> Result<Value, String> {
+        let reference = arguments["variablesReference"]
+            .as_u64()
+            .and_then(|reference| reference.checked_sub(1))
+            .and_then(|index| self.references.borrow().get(index as usize).cloned())
+            .ok_or("invalid variables reference")?;
+
+        let variables: Vec<(String, JsValue)> = match reference {
+            Reference::Locals(depth) => context
+                .frame_locals(depth)
+                .ok_or("the locals of the frame are not available")?
+                .into_iter()
+                .map(|(name, value)| (name.to_std_string_escaped(), value))
+                .collect(),
+            Reference::Object(object) => own_properties(&object),
+        };
+
+        let variables: Vec<_> = variables
+            .into_iter()
+            .map(|(name, value)| {
+                json!({
+                    "name": name,
+                    "value": describe(&value),
+                    "type": value.type_of().to_std_string_escaped(),
+                    "variablesReference": self.value_reference(&value),
+                })
+            })
+            .collect();
+        Ok(json!({ "variables": variables }))
+    }
+
+    /// Evaluates an expression in a frame, or in the global scope without a frame.
+    fn evaluate(&self, arguments: &Value, context: &mut Context) -> Result<Value, String> {
+        let expression = arguments["expression"]
+            .as_str()
+            .ok_or("missing expression")?;
+        let result = match frame_depth(arguments) {
+            Some(depth) => context.eval_in_frame(depth, expression),
+            None => context.eval(expression),
+        };
+        match result {
+            Ok(value) => Ok(json!({
+                "result": describe(&value),
+                "type": value.type_of().to_std_string_escaped(),
+                "variablesReference": self.value_reference(&value),
+            })),
+            Err(e) => Err(crate::uncaught_error(&e, context)),
+        }
+    }
+
+    /// Hands out a variables reference.
+    fn reference(&self, reference: Reference) -> usize {
+        let mut references = self.references.borrow_mut();
+        references.push(reference);
+        references.len()
+    }
+
+    /// Hands out a variables reference for the properties of a value, or `0` if the value is
+    /// not an object.
+    fn value_reference(&self, value: &JsValue) -> usize {
+        value.as_object().map_or(0, |object| {
+            self.reference(Reference::Object(object.clone()))
+        })
+    }
+
+    /// Handles the requests that were sent while the script was running.
+    fn handle_pending_requests(&self, context: &mut Context) {
+        while let Ok(request) = self.requests.try_recv() {
+            self.handle(&request, context);
+        }
+    }
+}
+
+impl Debugger for Session {
+    fn on_pause(&self, reason: &PauseReason, context: &mut Context) -> ResumeMode {
+        if self.disconnected.get() {
+            return ResumeMode::Continue;
+        }
+
+        let (reason, text) = match reason {
+            PauseReason::Breakpoint => ("breakpoint", None),
+            PauseReason::Step => ("step", None),
+            PauseReason::Exception(exception) => {
+                ("exception", Some(exception.display().to_string()))
+            }
+            PauseReason::Request if self.stop_on_entry.replace(false) => ("entry", None),
+            PauseReason::Request => ("pause", None),
+        };
+        self.connection.event(
+            "stopped",
+            json!({
+                "reason": reason,
+                "text": text,
+                "threadId": THREAD_ID,
+                "allThreadsStopped": true,
+            }),
+        );
+
+        let resume_mode = loop {
+            let request = if let Ok(request) = self.requests.recv() {
+                request
+            } else {
+                // The client is gone, so the script is stopped.
+                self.disconnected.set(true);
+                context.interrupt_handle().interrupt();
+                break ResumeMode::Continue;
+            };
+            if let Some(resume_mode) = self.handle(&request, context) {
+                break resume_mode;
+            }
+            if self.disconnected.get() {
+                break ResumeMode::Continue;
+            }
+        };
+
+        self.references.borrow_mut().clear();
+        resume_mode
+    }
+
+    fn on_enter_frame(&self, context: &mut Context) {
+        self.handle_pending_requests(context);
+    }
+
+    fn on_exception(&self, _exception: &JsValue, _context: &mut Context) -> bool {
+        self.pause_on_exceptions.get()
+    }
+}
+
+/// Gets the name used for a script in the context, so that the paths of the breakpoints set by
+/// the client match the scripts it launches.
+fn source_name(path: &Path) -> String {
+    canonicalize(path)
+        .unwrap_or_else(|_| path.to_owned())
+        .display()
+        .to_string()
+}
+
+/// Gets the depth of the frame named by the `frameId` argument of a request.
+fn frame_depth(arguments: &Value) -> Option<usize> {
+    let frame_id = arguments["frameId"].as_u64()?;
+    Some(frame_id.checked_sub(1)? as usize)
+}
+
+/// Reads the own data properties of an object, with the indexed properties first.
+fn own_properties(object: &JsObject) -> Vec<(String, JsValue)> {
+    let object = object.borrow();
+    let properties = object.properties();
+
+    let mut indexed: Vec<_> = properties.index_properties().collect();
+    indexed.sort_by_key(|(index, _)| *index);
+
+    let indexed = indexed
+        .into_iter()
+        .filter_map(|(index, property)| Some((index.to_string(), property.value()?.clone())));
+    let named = properties
+        .string_properties()
+        .filter_map(|(name, property)| {
+            Some((name.to_std_string_escaped(), property.value()?.clone()))
+        });
+    indexed.chain(named).collect()
+}
+
+/// Describes a value in a single line.
+fn describe(value: &JsValue) -> String {
+    let object = match value.as_object() {
+        Some(object) => object,
+        None => return value.display().to_string(),
+    };
+
+    let own_value = |key: &str| {
+        object
+            .borrow()
+            .properties()
+            .get(&PropertyKey::from(key))
+            .and_then(|property| property.value().cloned())
+    };
+    if object.is_callable() {
+        match own_value("name").and_then(|name| name.as_string().cloned()) {
+            Some(name) if !name.is_empty() => format!("function {}", name.to_std_string_escaped()),
+            _ => "function".to_owned(),
+        }
+    } else if object.is_array() {
+        let length = own_value("length")
+            .and_then(|length| length.as_number())
+            .unwrap_or_default();
+        format!("Array({length})")
+    } else {
+        "Object".to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_message, serve};
+    use serde_json::{json, Value};
+    use std::{
+        fs,
+        io::{BufReader, Write},
+        net::{TcpListener, TcpStream},
+        thread,
+    };
+
+    /// A scripted client, which records every message it receives.
+    struct Client {
+        reader: BufReader<TcpStream>,
+        writer: TcpStream,
+        seq: i64,
+        messages: Vec<Value>,
+    }
+
+    impl Client {
+        fn request(&mut self, command: &str, arguments: Value) -> Value {
+            self.seq += 1;
+            let mut message = json!({ "seq": self.seq, "type": "request", "command": command });
+            message["arguments"] = arguments;
+            let content = message.to_string();
+            write!(
+                self.writer,
+                "Content-Length: {}\r\n\r\n{content}",
+                content.len()
+            )
+            .unwrap();
+
+            let seq = self.seq;
+            let response = self
+                .wait_for(|message| message["type"] == "response" && message["request_seq"] == seq);
+            assert_eq!(response["success"], true, "{response}");
+            response["body"].clone()
+        }
+
+        fn event(&mut self, event: &str) -> Value {
+            let message = self.wait_for(|message| message["event"] == event);
+            message["body"].clone()
+        }
+
+        fn wait_for(&mut self, predicate: impl Fn(&Value) -> bool) -> Value {
+            if let Some(index) = self.messages.iter().position(&predicate) {
+                return self.messages.remove(index);
+            }
+            loop {
+                let message = read_message(&mut self.reader).unwrap().unwrap();
+                if predicate(&message) {
+                    return message;
+                }
+                self.messages.push(message);
+            }
+        }
+    }
+
+    #[test]
+    fn debug_session() {
+        let script = "\
+function add(a, b) {
+    const sum = a + b;
+    return sum;
+}
+let total = add(1, 2);
+console.log('total', total);
+total = add(total, { value: 10 }.value);
+";
+        let dir = std::env::temp_dir().join(format!("boa-dap-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let program = dir.join("program.js");
+        fs::write(&program, script).unwrap();
+        let path = program.display().to_string();
+
+        let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            serve(
+                BufReader::new(stream.try_clone().unwrap()),
+                Box::new(stream),
+                None,
+            );
+        });
+
+        let stream = TcpStream::connect(address).unwrap();
+        let mut client = Client {
+            reader: BufReader::new(stream.try_clone().unwrap()),
+            writer: stream,
+            seq: 0,
+            messages: Vec::new(),
+        };
+
+        let capabilities = client.request("initialize", json!({ "adapterID": "boa" }));
+        assert_eq!(capabilities["supportsConfigurationDoneRequest"], true);
+        client.event("initialized");
+        client.request("launch", json!({ "program": path }));
+        let breakpoints = client.request(
+            "setBreakpoints",
+            json!({ "source": { "path": path }, "breakpoints": [{ "line": 2 }] }),
+        );
+        assert_eq!(breakpoints["breakpoints"][0]["verified"], true);
+        client.request("configurationDone", json!({}));
+
+        // The first call hits the breakpoint.
+        let stopped = client.event("stopped");
+        assert_eq!(stopped["reason"], "breakpoint");
+        let threads = client.request("threads", json!({}));
+        assert_eq!(threads["threads"][0]["id"], 1);
+        let trace = client.request("stackTrace", json!({ "threadId": 1 }));
+        let frames = trace["stackFrames"].as_array().unwrap();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0]["name"], "add");
+        assert_eq!(frames[0]["line"], 2);
+        assert_eq!(frames[0]["source"]["name"], "program.js");
+        assert_eq!(frames[1]["name"], "<main>");
+        assert_eq!(frames[1]["line"], 5);
+
+        let scopes = client.request("scopes", json!({ "frameId": 1 }));
+        let reference = scopes["scopes"][0]["variablesReference"].clone();
+        let variables = client.request("variables", json!({ "variablesReference": reference }));
+        let variables: Vec<_> = variables["variables"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|variable| variable["name"] != "arguments")
+            .map(|variable| format!("{}={}", variable["name"], variable["value"]))
+            .collect();
+        assert_eq!(variables, [r#""a"="1""#, r#""b"="2""#]);
+
+        let result = client.request("evaluate", json!({ "expression": "a + b", "frameId": 1 }));
+        assert_eq!(result["result"], "3");
+        let result = client.request("evaluate", json!({ "expression": "[a, b]", "frameId": 1 }));
+        assert_eq!(result["result"], "Array(2)");
+        let elements = client.request(
+            "variables",
+            json!({ "variablesReference": result["variablesReference"] }),
+        );
+        assert_eq!(elements["variables"][1]["value"], "2");
+
+        // Step over the declaration of `sum`.
+        client.request("next", json!({ "threadId": 1 }));
+        let stopped = client.event("stopped");
+        assert_eq!(stopped["reason"], "step");
+        let trace = client.request("stackTrace", json!({ "threadId": 1 }));
+        assert_eq!(trace["stackFrames"][0]["line"], 3);
+        let result = client.request("evaluate", json!({ "expression": "sum", "frameId": 1 }));
+        assert_eq!(result["result"], "3");
+
+        // Step out to the caller, then remove the breakpoint and run to the end.
+        client.request("stepOut", json!({ "threadId": 1 }));
+        let stopped = client.event("stopped");
+        assert_eq!(stopped["reason"], "step");
+        let trace = client.request("stackTrace", json!({ "threadId": 1 }));
+        assert_eq!(trace["stackFrames"][0]["line"], 6);
+
+        client.request(
+            "setBreakpoints",
+            json!({ "source": { "path": path }, "breakpoints": [] }),
+        );
+        client.request("continue", json!({ "threadId": 1 }));
+
+        let output = client.event("output");
+        assert_eq!(output["category"], "stdout");
+        assert_eq!(output["output"], "total 3\n");
+        let exited = client.event("exited");
+        assert_eq!(exited["exitCode"], 0);
+        client.event("terminated");
+        let result = client.request("evaluate", json!({ "expression": "total" }));
+        assert_eq!(result["result"], "13");
+        client.request("disconnect", json!({}));
+
+        server.join().unwrap();
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -59,11 +59,20 @@
     rustdoc::missing_doc_code_examples
 )]
 
-use boa_engine::{syntax::ast::node::StatementList, Context};
+use boa_engine::{
+    syntax::ast::node::StatementList, Context, JsError, JsNativeError, JsResult, JsValue,
+};
 use clap::{ArgEnum, Parser};
 use colored::{Color, Colorize};
 use rustyline::{config::Config, error::ReadlineError, EditMode, Editor};
-use std::{fs::read, fs::OpenOptions, io, path::PathBuf};
+use std::{
+    collections::hash_map::DefaultHasher,
+    fs::{create_dir_all, read, write, OpenOptions},
+    hash::{Hash, Hasher},
+    io,
+    path::{Path, PathBuf},
+};
+mod debug_adapter;
 mod helper;
 
 #[cfg(all(target_arch = "x86_64", target_os = "linux", target_env = "gnu"))]
@@ -100,6 +109,16 @@ struct Opt {
     /// Use vi mode in the REPL
     #[clap(long = "vi")]
     vi_mode: bool,
+
+    /// Cache the compiled bytecode of the files in the given directory, and run the files
+    /// from the cache when their source didn't change.
+    #[clap(long, value_name = "DIR", parse(from_os_str))]
+    bytecode_cache: Option<PathBuf>,
+
+    /// Run a Debug Adapter Protocol server over stdio, or over TCP on the given port of
+    /// localhost, and debug the first file unless the client launches another one.
+    #[clap(long, value_name = "PORT")]
+    dap: Option<Option<u16>>,
 }
 
 impl Opt {
@@ -180,9 +199,67 @@ where
     Ok(())
 }
 
+/// Evaluates the source of a file, loading its compiled bytecode from the cache in
+/// `cache_dir` if it is there, and writing it to the cache otherwise.
+///
+/// Failures to read or write the cache are reported, and the file is compiled instead.
+fn eval_with_bytecode_cache(
+    src: &[u8],
+    file: &Path,
+    cache_dir: &Path,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    let mut hasher = DefaultHasher::new();
+    src.hash(&mut hasher);
+    let stem = file.file_stem().map_or_else(
+        || "script".into(),
+        |stem| stem.to_string_lossy().into_owned(),
+    );
+    let cache_file = cache_dir.join(format!("{stem}-{:016x}.boac", hasher.finish()));
+
+    if let Ok(cache) = read(&cache_file) {
+        match context.deserialize_code_block(&cache) {
+            Ok(code_block) => return context.execute(code_block),
+            Err(e) => eprintln!(
+                "could not load {}: {}",
+                cache_file.display(),
+                e.to_opaque(context).display()
+            ),
+        }
+    }
+
+    let statement_list =
+        parse_tokens(src, context).map_err(|e| JsNativeError::syntax().with_message(e))?;
+    let code_block =
+        context.compile_with_source_name(&statement_list, file.display().to_string())?;
+    let cache = context.serialize_code_block(&code_block)?;
+    if let Err(e) = create_dir_all(cache_dir).and_then(|()| write(&cache_file, cache)) {
+        eprintln!("could not write {}: {e}", cache_file.display());
+    }
+
+    context.execute(code_block)
+}
+
+/// Formats an uncaught error, using the stack trace of error objects if there is one.
+fn uncaught_error(error: &JsError, context: &mut Context) -> String {
+    let error = error.to_opaque(context);
+    let stack = error
+        .as_object()
+        .and_then(|object| object.get("stack", context).ok())
+        .and_then(|stack| stack.as_string().cloned());
+    match stack {
+        Some(stack) => stack.to_string(),
+        None => error.display().to_string(),
+    }
+}
+
 pub fn main() -> Result<(), io::Error> {
     let args = Opt::parse();
 
+    if let Some(port) = args.dap {
+        return debug_adapter::run(port, args.files.first().cloned());
+    }
+
     let mut context = Context::default();
 
     // Trace Output
@@ -196,9 +273,14 @@ pub fn main() -> Result<(), io::Error> {
                 eprintln!("{e}");
             }
         } else {
-            match context.eval(&buffer) {
+            let result = if let Some(cache_dir) = &args.bytecode_cache {
+                eval_with_bytecode_cache(&buffer, file, cache_dir, &mut context)
+            } else {
+                context.eval_with_source_name(&buffer, file.display().to_string())
+            };
+            match result {
                 Ok(v) => println!("{}", v.display()),
-                Err(v) => eprintln!("Uncaught {}", v.display()),
+                Err(v) => eprintln!("Uncaught {}", uncaught_error(&v, &mut context)),
             }
         }
     }
@@ -248,7 +330,7 @@ pub fn main() -> Result<(), io::Error> {
                                 eprintln!(
                                     "{}: {}",
                                     "Uncaught".red(),
-                                    v.display().to_string().red()
+                                    uncaught_error(&v, &mut context).red()
                                 );
                             }
                         }
@@ -0,0 +1,13 @@
+(function () {
+  let numbers = [];
+  for (let i = 0; i < 1000; i++) {
+    numbers.push(i % 2 === 0 ? i : i / 3);
+  }
+
+  let sum = 0;
+  for (let i = 0; i < numbers.length; i++) {
+    sum += numbers[i] * 2;
+  }
+
+  return sum;
+})();
@@ -0,0 +1,100 @@
+//! Benchmarks of the representation of `JsValue`.
+
+use boa_engine::{object::JsObject, JsBigInt, JsString, JsSymbol, JsValue};
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use std::mem::size_of;
+
+#[cfg(all(target_arch = "x86_64", target_os = "linux", target_env = "gnu"))]
+#[cfg_attr(
+    all(target_arch = "x86_64", target_os = "linux", target_env = "gnu"),
+    global_allocator
+)]
+static ALLOC: jemallocator::Jemalloc = jemallocator::Jemalloc;
+
+/// The number of values created by each iteration of the benchmarks.
+const VALUES: usize = 10_000;
+
+/// Returns a list of values of every type, with their names.
+fn values() -> Vec<(&'static str, JsValue)> {
+    vec![
+        ("Undefined", JsValue::undefined()),
+        ("Null", JsValue::null()),
+        ("Boolean", JsValue::new(true)),
+        ("Integer", JsValue::new(42)),
+        ("Rational", JsValue::new(3.5)),
+        (
+            "String",
+            JsValue::new(JsString::new("a heap allocated string")),
+        ),
+        ("Symbol", JsValue::new(JsSymbol::new(None))),
+        ("BigInt", JsValue::new(JsBigInt::new(1_i64 << 40))),
+        ("Object", JsValue::new(JsObject::empty())),
+    ]
+}
+
+/// Measures the creation of vectors of numbers.
+///
+/// The throughput is the size of the created values, so it changes with the size of `JsValue`.
+fn create(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Value creation");
+    group.throughput(Throughput::Bytes((VALUES * size_of::<JsValue>()) as u64));
+    group.bench_function("Integers", |b| {
+        b.iter(|| {
+            (0..VALUES as i32)
+                .map(|i| JsValue::new(black_box(i)))
+                .collect::<Vec<_>>()
+        });
+    });
+    group.bench_function("Rationals", |b| {
+        b.iter(|| {
+            (0..VALUES)
+                .map(|i| JsValue::new(black_box(i as f64 + 0.5)))
+                .collect::<Vec<_>>()
+        });
+    });
+    group.finish();
+}
+
+/// Measures cloning and dropping vectors of values of every type.
+fn clone_and_drop(c: &mut Criterion) {
+    let values = values();
+    let mut group = c.benchmark_group("Value cloning");
+    group.throughput(Throughput::Bytes((VALUES * size_of::<JsValue>()) as u64));
+    for (name, value) in &values {
+        group.bench_function(*name, |b| {
+            b.iter_batched(
+                || vec![value.clone(); VALUES],
+                |copy| copy.clone(),
+                BatchSize::SmallInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+/// Measures matching on values of every type.
+fn inspect(c: &mut Criterion) {
+    let values: Vec<_> = values()
+        .into_iter()
+        .map(|(_, value)| value)
+        .cycle()
+        .take(VALUES)
+        .collect();
+    let mut group = c.benchmark_group("Value inspection");
+    group.throughput(Throughput::Elements(VALUES as u64));
+    group.bench_function("to_boolean", |b| {
+        b.iter(|| values.iter().filter(|v| black_box(v).to_boolean()).count());
+    });
+    group.bench_function("as_number", |b| {
+        b.iter(|| {
+            values
+                .iter()
+                .filter_map(|v| black_box(v).as_number())
+                .sum::<f64>()
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, create, clone_and_drop, inspect);
+criterion_main!(benches);
@@ -1,12 +1,11 @@
 //! This module implements the JavaScript bigint primitive rust type.
 
-use crate::{builtins::Number, Context, JsValue};
+use crate::{builtins::Number, value::TaggedRc, JsNativeError, JsResult};
 use num_integer::Integer;
 use num_traits::{pow::Pow, FromPrimitive, One, ToPrimitive, Zero};
 use std::{
     fmt::{self, Display},
     ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Rem, Shl, Shr, Sub},
-    rc::Rc,
 };
 
 /// The raw bigint type.
@@ -18,8 +17,9 @@ use serde::{Deserialize, Serialize};
 /// JavaScript bigint primitive rust type.
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
 pub struct JsBigInt {
-    inner: Rc<RawBigInt>,
+    inner: TaggedRc<RawBigInt>,
 }
 
 impl JsBigInt {
@@ -33,7 +33,7 @@ impl JsBigInt {
     #[inline]
     pub fn zero() -> Self {
         Self {
-            inner: Rc::new(RawBigInt::zero()),
+            inner: TaggedRc::new(RawBigInt::zero()),
         }
     }
 
@@ -47,7 +47,7 @@ impl JsBigInt {
     #[inline]
     pub fn one() -> Self {
         Self {
-            inner: Rc::new(RawBigInt::one()),
+            inner: TaggedRc::new(RawBigInt::one()),
         }
     }
 
@@ -75,7 +75,7 @@ impl JsBigInt {
     #[inline]
     pub fn from_string_radix(buf: &str, radix: u32) -> Option<Self> {
         Some(Self {
-            inner: Rc::new(RawBigInt::parse_bytes(buf.as_bytes(), radix)?),
+            inner: TaggedRc::new(RawBigInt::parse_bytes(buf.as_bytes(), radix)?),
         })
     }
 
@@ -148,11 +148,13 @@ impl JsBigInt {
     }
 
     #[inline]
-    pub fn pow(x: &Self, y: &Self, context: &mut Context) -> Result<Self, JsValue> {
+    pub fn pow(x: &Self, y: &Self) -> JsResult<Self> {
         let y = if let Some(y) = y.inner.to_biguint() {
             y
         } else {
-            return context.throw_range_error("BigInt negative exponent");
+            return Err(JsNativeError::range()
+                .with_message("BigInt negative exponent")
+                .into());
         };
 
         let num_bits = (x.inner.bits() as f64
@@ -161,14 +163,16 @@ impl JsBigInt {
             + 1f64;
 
         if num_bits > 1_000_000_000f64 {
-            return context.throw_range_error("Maximum BigInt size exceeded");
+            return Err(JsNativeError::range()
+                .with_message("Maximum BigInt size exceeded")
+                .into());
         }
 
         Ok(Self::new(x.inner.as_ref().clone().pow(y)))
     }
 
     #[inline]
-    pub fn shift_right(x: &Self, y: &Self, context: &mut Context) -> Result<Self, JsValue> {
+    pub fn shift_right(x: &Self, y: &Self) -> JsResult<Self> {
         if let Some(n) = y.inner.to_i32() {
             let inner = if n > 0 {
                 x.inner.as_ref().clone().shr(n as usize)
@@ -178,12 +182,14 @@ impl JsBigInt {
 
             Ok(Self::new(inner))
         } else {
-            context.throw_range_error("Maximum BigInt size exceeded")
+            Err(JsNativeError::range()
+                .with_message("Maximum BigInt size exceeded")
+                .into())
         }
     }
 
     #[inline]
-    pub fn shift_left(x: &Self, y: &Self, context: &mut Context) -> Result<Self, JsValue> {
+    pub fn shift_left(x: &Self, y: &Self) -> JsResult<Self> {
         if let Some(n) = y.inner.to_i32() {
             let inner = if n > 0 {
                 x.inner.as_ref().clone().shl(n as usize)
@@ -193,7 +199,9 @@ impl JsBigInt {
 
             Ok(Self::new(inner))
         } else {
-            context.throw_range_error("Maximum BigInt size exceeded")
+            Err(JsNativeError::range()
+                .with_message("Maximum BigInt size exceeded")
+                .into())
         }
     }
 
@@ -269,7 +277,7 @@ impl JsBigInt {
 impl Display for JsBigInt {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        Display::fmt(&self.inner, f)
+        Display::fmt(&*self.inner, f)
     }
 }
 
@@ -277,7 +285,7 @@ impl From<RawBigInt> for JsBigInt {
     #[inline]
     fn from(value: RawBigInt) -> Self {
         Self {
-            inner: Rc::new(value),
+            inner: TaggedRc::new(value),
         }
     }
 }
@@ -286,7 +294,7 @@ impl From<Box<RawBigInt>> for JsBigInt {
     #[inline]
     fn from(value: Box<RawBigInt>) -> Self {
         Self {
-            inner: value.into(),
+            inner: TaggedRc::new(*value),
         }
     }
 }
@@ -295,7 +303,7 @@ impl From<i8> for JsBigInt {
     #[inline]
     fn from(value: i8) -> Self {
         Self {
-            inner: Rc::new(RawBigInt::from(value)),
+            inner: TaggedRc::new(RawBigInt::from(value)),
         }
     }
 }
@@ -304,7 +312,7 @@ impl From<u8> for JsBigInt {
     #[inline]
     fn from(value: u8) -> Self {
         Self {
-            inner: Rc::new(RawBigInt::from(value)),
+            inner: TaggedRc::new(RawBigInt::from(value)),
         }
     }
 }
@@ -313,7 +321,7 @@ impl From<i16> for JsBigInt {
     #[inline]
     fn from(value: i16) -> Self {
         Self {
-            inner: Rc::new(RawBigInt::from(value)),
+            inner: TaggedRc::new(RawBigInt::from(value)),
         }
     }
 }
@@ -322,7 +330,7 @@ impl From<u16> for JsBigInt {
     #[inline]
     fn from(value: u16) -> Self {
         Self {
-            inner: Rc::new(RawBigInt::from(value)),
+            inner: TaggedRc::new(RawBigInt::from(value)),
         }
     }
 }
@@ -331,7 +339,7 @@ impl From<i32> for JsBigInt {
     #[inline]
     fn from(value: i32) -> Self {
         Self {
-            inner: Rc::new(RawBigInt::from(value)),
+            inner: TaggedRc::new(RawBigInt::from(value)),
         }
     }
 }
@@ -340,7 +348,7 @@ impl From<u32> for JsBigInt {
     #[inline]
     fn from(value: u32) -> Self {
         Self {
-            inner: Rc::new(RawBigInt::from(value)),
+            inner: TaggedRc::new(RawBigInt::from(value)),
         }
     }
 }
@@ -349,7 +357,7 @@ impl From<i64> for JsBigInt {
     #[inline]
     fn from(value: i64) -> Self {
         Self {
-            inner: Rc::new(RawBigInt::from(value)),
+            inner: TaggedRc::new(RawBigInt::from(value)),
         }
     }
 }
@@ -358,7 +366,7 @@ impl From<u64> for JsBigInt {
     #[inline]
     fn from(value: u64) -> Self {
         Self {
-            inner: Rc::new(RawBigInt::from(value)),
+            inner: TaggedRc::new(RawBigInt::from(value)),
         }
     }
 }
@@ -367,7 +375,7 @@ impl From<isize> for JsBigInt {
     #[inline]
     fn from(value: isize) -> Self {
         Self {
-            inner: Rc::new(RawBigInt::from(value)),
+            inner: TaggedRc::new(RawBigInt::from(value)),
         }
     }
 }
@@ -376,7 +384,7 @@ impl From<usize> for JsBigInt {
     #[inline]
     fn from(value: usize) -> Self {
         Self {
-            inner: Rc::new(RawBigInt::from(value)),
+            inner: TaggedRc::new(RawBigInt::from(value)),
         }
     }
 }
@@ -2,7 +2,9 @@
 mod tests;
 
 use crate::{
-    builtins::{typed_array::TypedArrayKind, BuiltIn, JsArgs},
+    builtins::{
+        shared_array_buffer::SharedDataBlock, typed_array::TypedArrayKind, BuiltIn, JsArgs,
+    },
     context::intrinsics::StandardConstructors,
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
@@ -11,24 +13,140 @@ use crate::{
     property::Attribute,
     symbol::WellKnownSymbols,
     value::{IntegerOrInfinity, Numeric},
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue,
 };
-use boa_gc::{Finalize, Trace};
+use boa_gc::{unsafe_empty_trace, ExternalMemory, Finalize, Trace};
 use boa_profiler::Profiler;
 use num_traits::{Signed, ToPrimitive};
 use tap::{Conv, Pipe};
 
+/// The internal slots of an `ArrayBuffer` or a `SharedArrayBuffer` object.
 #[derive(Debug, Clone, Trace, Finalize)]
 pub struct ArrayBuffer {
-    pub array_buffer_data: Option<Vec<u8>>,
+    pub array_buffer_data: Option<DataBlock>,
     pub array_buffer_byte_length: u64,
+    pub array_buffer_max_byte_length: Option<u64>,
     pub array_buffer_detach_key: JsValue,
+    /// The memory owned by the data block, accounted as part of the heap.
+    pub(crate) data_block_memory: ExternalMemory,
 }
 
 impl ArrayBuffer {
     pub(crate) fn array_buffer_byte_length(&self) -> u64 {
         self.array_buffer_byte_length
     }
+
+    /// `25.1.3.9 IsFixedLengthArrayBuffer ( arrayBuffer )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isfixedlengtharraybuffer
+    pub fn is_fixed_length(&self) -> bool {
+        // 1. If arrayBuffer has an [[ArrayBufferMaxByteLength]] internal slot, return false.
+        // 2. Return true.
+        self.array_buffer_max_byte_length.is_none()
+    }
+
+    /// `25.1.2.3 IsSharedArrayBuffer ( obj )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-issharedarraybuffer
+    pub fn is_shared(&self) -> bool {
+        // 1. Let bufferData be obj.[[ArrayBufferData]].
+        // 2. If bufferData is null, return false.
+        // 3. If bufferData is a Data Block, return false.
+        // 4. Assert: bufferData is a Shared Data Block.
+        // 5. Return true.
+        matches!(self.array_buffer_data, Some(DataBlock::Shared(_)))
+    }
+
+    /// Accounts the memory owned by the data block after it changes.
+    pub(crate) fn update_data_block_memory(&mut self) {
+        let bytes = self
+            .array_buffer_data
+            .as_ref()
+            .map_or(0, DataBlock::owned_memory);
+        self.data_block_memory.set(bytes);
+    }
+}
+
+/// The `[[ArrayBufferData]]` of an `ArrayBuffer` or a `SharedArrayBuffer`.
+#[derive(Debug, Clone, Finalize)]
+pub enum DataBlock {
+    /// A Data Block, owned by a single `ArrayBuffer`.
+    Unshared(Vec<u8>),
+
+    /// A Shared Data Block, which may be shared with the `SharedArrayBuffer` objects of other
+    /// agents.
+    Shared(SharedDataBlock),
+}
+
+// SAFETY: A data block holds no garbage collected values.
+unsafe impl Trace for DataBlock {
+    unsafe_empty_trace!();
+}
+
+impl DataBlock {
+    /// Gets the number of bytes of the block.
+    #[inline]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Unshared(block) => block.len(),
+            Self::Shared(block) => block.len(),
+        }
+    }
+
+    /// Returns `true` if the block has no bytes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the number of bytes owned by the block in the heap of the current thread.
+    ///
+    /// Shared Data Blocks are not accounted, since they can outlive the agent that created them.
+    pub(crate) fn owned_memory(&self) -> usize {
+        match self {
+            Self::Unshared(block) => block.capacity(),
+            Self::Shared(_) => 0,
+        }
+    }
+
+    /// Gets the Shared Data Block, if this is one.
+    #[inline]
+    pub fn as_shared(&self) -> Option<&SharedDataBlock> {
+        match self {
+            Self::Unshared(_) => None,
+            Self::Shared(block) => Some(block),
+        }
+    }
+
+    /// Returns `true` if both blocks are the same Shared Data Block.
+    pub(crate) fn same_block(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Shared(block), Self::Shared(other)) => block.ptr_eq(other),
+            _ => false,
+        }
+    }
+
+    /// Reads the bytes starting at `index` into `buffer`.
+    pub(crate) fn read(&self, index: usize, buffer: &mut [u8], order: SharedMemoryOrder) {
+        match self {
+            Self::Unshared(block) => buffer.copy_from_slice(&block[index..index + buffer.len()]),
+            Self::Shared(block) => block.read(index, buffer, order),
+        }
+    }
+
+    /// Writes `buffer` into the bytes starting at `index`.
+    pub(crate) fn write(&mut self, index: usize, buffer: &[u8], order: SharedMemoryOrder) {
+        match self {
+            Self::Unshared(block) => block[index..index + buffer.len()].copy_from_slice(buffer),
+            Self::Shared(block) => block.write(index, buffer, order),
+        }
+    }
 }
 
 impl BuiltIn for ArrayBuffer {
@@ -48,6 +166,18 @@ impl BuiltIn for ArrayBuffer {
             .name("get byteLength")
             .build();
 
+        let get_max_byte_length = FunctionBuilder::native(context, Self::get_max_byte_length)
+            .name("get maxByteLength")
+            .build();
+
+        let get_resizable = FunctionBuilder::native(context, Self::get_resizable)
+            .name("get resizable")
+            .build();
+
+        let get_detached = FunctionBuilder::native(context, Self::get_detached)
+            .name("get detached")
+            .build();
+
         ConstructorBuilder::with_standard_constructor(
             context,
             Self::constructor,
@@ -56,6 +186,14 @@ impl BuiltIn for ArrayBuffer {
         .name(Self::NAME)
         .length(Self::LENGTH)
         .accessor("byteLength", Some(get_byte_length), None, flag_attributes)
+        .accessor(
+            "maxByteLength",
+            Some(get_max_byte_length),
+            None,
+            flag_attributes,
+        )
+        .accessor("resizable", Some(get_resizable), None, flag_attributes)
+        .accessor("detached", Some(get_detached), None, flag_attributes)
         .static_accessor(
             WellKnownSymbols::species(),
             Some(get_species),
@@ -63,7 +201,10 @@ impl BuiltIn for ArrayBuffer {
             Attribute::CONFIGURABLE,
         )
         .static_method(Self::is_view, "isView", 1)
+        .method(Self::resize, "resize", 1)
         .method(Self::slice, "slice", 2)
+        .method(Self::transfer, "transfer", 0)
+        .method(Self::transfer_to_fixed_length, "transferToFixedLength", 0)
         .property(
             WellKnownSymbols::to_string_tag(),
             Self::NAME,
@@ -78,7 +219,7 @@ impl BuiltIn for ArrayBuffer {
 impl ArrayBuffer {
     const LENGTH: usize = 1;
 
-    /// `25.1.3.1 ArrayBuffer ( length )`
+    /// `25.1.4.1 ArrayBuffer ( length [ , options ] )`
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -91,15 +232,48 @@ impl ArrayBuffer {
     ) -> JsResult<JsValue> {
         // 1. If NewTarget is undefined, throw a TypeError exception.
         if new_target.is_undefined() {
-            return context
-                .throw_type_error("ArrayBuffer.constructor called with undefined new target");
+            return Err(JsNativeError::typ()
+                .with_message("ArrayBuffer.constructor called with undefined new target")
+                .into());
         }
 
         // 2. Let byteLength be ? ToIndex(length).
         let byte_length = args.get_or_undefined(0).to_index(context)?;
 
-        // 3. Return ? AllocateArrayBuffer(NewTarget, byteLength).
-        Ok(Self::allocate(new_target, byte_length, context)?.into())
+        // 3. Let requestedMaxByteLength be ? GetArrayBufferMaxByteLengthOption(options).
+        let max_byte_length = Self::get_max_byte_length_option(args.get_or_undefined(1), context)?;
+
+        // 4. Return ? AllocateArrayBuffer(NewTarget, byteLength, requestedMaxByteLength).
+        Ok(Self::allocate(new_target, byte_length, max_byte_length, context)?.into())
+    }
+
+    /// `25.1.3.7 GetArrayBufferMaxByteLengthOption ( options )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getarraybuffermaxbytelengthoption
+    fn get_max_byte_length_option(
+        options: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Option<u64>> {
+        // 1. If options is not an Object, return empty.
+        let options = if let Some(options) = options.as_object() {
+            options
+        } else {
+            return Ok(None);
+        };
+
+        // 2. Let maxByteLength be ? Get(options, "maxByteLength").
+        let max_byte_length = options.get("maxByteLength", context)?;
+
+        // 3. If maxByteLength is undefined, return empty.
+        if max_byte_length.is_undefined() {
+            return Ok(None);
+        }
+
+        // 4. Return ? ToIndex(maxByteLength).
+        max_byte_length.to_index(context).map(Some)
     }
 
     /// `25.1.4.3 get ArrayBuffer [ @@species ]`
@@ -142,24 +316,32 @@ impl ArrayBuffer {
     pub(crate) fn get_byte_length(
         this: &JsValue,
         _args: &[JsValue],
-        context: &mut Context,
+        _: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
         let obj = if let Some(obj) = this.as_object() {
             obj
         } else {
-            return context.throw_type_error("ArrayBuffer.byteLength called with non-object value");
+            return Err(JsNativeError::typ()
+                .with_message("ArrayBuffer.byteLength called with non-object value")
+                .into());
         };
         let obj = obj.borrow();
         let o = if let Some(o) = obj.as_array_buffer() {
             o
         } else {
-            return context.throw_type_error("ArrayBuffer.byteLength called with invalid object");
+            return Err(JsNativeError::typ()
+                .with_message("ArrayBuffer.byteLength called with invalid object")
+                .into());
         };
 
-        // TODO: Shared Array Buffer
         // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        if o.is_shared() {
+            return Err(JsNativeError::typ()
+                .with_message("ArrayBuffer.byteLength called with a SharedArrayBuffer")
+                .into());
+        }
 
         // 4. If IsDetachedBuffer(O) is true, return +0𝔽.
         if Self::is_detached_buffer(o) {
@@ -171,6 +353,290 @@ impl ArrayBuffer {
         Ok(o.array_buffer_byte_length.into())
     }
 
+    /// `25.1.6.4 get ArrayBuffer.prototype.maxByteLength`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.maxbytelength
+    fn get_max_byte_length(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        let obj = Self::this_array_buffer(this, "ArrayBuffer.maxByteLength")?;
+        let obj = obj.borrow();
+        let o = obj.as_array_buffer().expect("checked by this_array_buffer");
+
+        // 4. If IsDetachedBuffer(O) is true, return +0𝔽.
+        if o.is_detached_buffer() {
+            return Ok(0.into());
+        }
+
+        // 5. If IsFixedLengthArrayBuffer(O) is true, then
+        //     a. Let length be O.[[ArrayBufferByteLength]].
+        // 6. Else,
+        //     a. Let length be O.[[ArrayBufferMaxByteLength]].
+        // 7. Return 𝔽(length).
+        Ok(o.array_buffer_max_byte_length
+            .unwrap_or(o.array_buffer_byte_length)
+            .into())
+    }
+
+    /// `25.1.6.5 get ArrayBuffer.prototype.resizable`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.resizable
+    fn get_resizable(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        let obj = Self::this_array_buffer(this, "ArrayBuffer.resizable")?;
+        let obj = obj.borrow();
+        let o = obj.as_array_buffer().expect("checked by this_array_buffer");
+
+        // 4. If IsFixedLengthArrayBuffer(O) is false, return true; otherwise return false.
+        Ok((!o.is_fixed_length()).into())
+    }
+
+    /// `25.1.6.3 get ArrayBuffer.prototype.detached`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.detached
+    fn get_detached(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        let obj = Self::this_array_buffer(this, "ArrayBuffer.detached")?;
+        let obj = obj.borrow();
+        let o = obj.as_array_buffer().expect("checked by this_array_buffer");
+
+        // 4. Return IsDetachedBuffer(O).
+        Ok(o.is_detached_buffer().into())
+    }
+
+    /// `25.1.6.6 ArrayBuffer.prototype.resize ( newLength )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.resize
+    fn resize(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferMaxByteLength]]).
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        let obj = Self::this_array_buffer(this, "ArrayBuffer.resize")?;
+        if obj
+            .borrow()
+            .as_array_buffer()
+            .map_or(true, Self::is_fixed_length)
+        {
+            return Err(JsNativeError::typ()
+                .with_message("ArrayBuffer.resize called with a fixed length ArrayBuffer")
+                .into());
+        }
+
+        // 4. Let newByteLength be ? ToIndex(newLength).
+        let new_byte_length = args.get_or_undefined(0).to_index(context)?;
+
+        let mut obj = obj.borrow_mut();
+        let o = obj
+            .as_array_buffer_mut()
+            .expect("checked by this_array_buffer");
+
+        // 5. If IsDetachedBuffer(O) is true, throw a TypeError exception.
+        let block = if let Some(DataBlock::Unshared(block)) = &mut o.array_buffer_data {
+            block
+        } else {
+            return Err(JsNativeError::typ()
+                .with_message("ArrayBuffer.resize called with detached buffer")
+                .into());
+        };
+
+        // 6. If newByteLength > O.[[ArrayBufferMaxByteLength]], throw a RangeError exception.
+        if matches!(o.array_buffer_max_byte_length, Some(max) if new_byte_length > max) {
+            return Err(JsNativeError::range()
+                .with_message("new byte length exceeds the maximum byte length of the ArrayBuffer")
+                .into());
+        }
+
+        // 7. Let hostHandled be ? HostResizeArrayBuffer(O, newByteLength).
+        // 8. If hostHandled is handled, return undefined.
+        // 9. Let oldBlock be O.[[ArrayBufferData]].
+        // 10. Let newBlock be ? CreateByteDataBlock(newByteLength).
+        // 11. Let copyLength be min(newByteLength, O.[[ArrayBufferByteLength]]).
+        // 12. Perform CopyDataBlockBytes(newBlock, 0, oldBlock, 0, copyLength).
+        // 13. NOTE: Neither creation of the new Data Block nor copying from the old Data Block are
+        //     observable. Implementations may implement this method as in-place growth or shrinkage.
+        // 14. Set O.[[ArrayBufferData]] to newBlock.
+        resize_byte_data_block(block, new_byte_length)?;
+        o.update_data_block_memory();
+
+        // 15. Set O.[[ArrayBufferByteLength]] to newByteLength.
+        o.array_buffer_byte_length = new_byte_length;
+
+        // 16. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `25.1.6.8 ArrayBuffer.prototype.transfer ( [ newLength ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.transfer
+    fn transfer(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Return ? ArrayBufferCopyAndDetach(O, newLength, preserve-resizability).
+        Self::copy_and_detach(this, args.get_or_undefined(0), true, context)
+    }
+
+    /// `25.1.6.9 ArrayBuffer.prototype.transferToFixedLength ( [ newLength ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.transfertofixedlength
+    fn transfer_to_fixed_length(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Return ? ArrayBufferCopyAndDetach(O, newLength, fixed-length).
+        Self::copy_and_detach(this, args.get_or_undefined(0), false, context)
+    }
+
+    /// `25.1.3.3 ArrayBufferCopyAndDetach ( arrayBuffer, newLength, preserveResizability )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffercopyanddetach
+    fn copy_and_detach(
+        array_buffer: &JsValue,
+        new_length: &JsValue,
+        preserve_resizability: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? RequireInternalSlot(arrayBuffer, [[ArrayBufferData]]).
+        // 2. If IsSharedArrayBuffer(arrayBuffer) is true, throw a TypeError exception.
+        let obj = Self::this_array_buffer(array_buffer, "ArrayBuffer.transfer")?;
+
+        // 3. If newLength is undefined, then
+        let new_byte_length = if new_length.is_undefined() {
+            // a. Let newByteLength be arrayBuffer.[[ArrayBufferByteLength]].
+            obj.borrow()
+                .as_array_buffer()
+                .expect("checked by this_array_buffer")
+                .array_buffer_byte_length
+        // 4. Else,
+        } else {
+            // a. Let newByteLength be ? ToIndex(newLength).
+            new_length.to_index(context)?
+        };
+
+        let (max_byte_length, block) = {
+            let mut obj = obj.borrow_mut();
+            let o = obj
+                .as_array_buffer_mut()
+                .expect("checked by this_array_buffer");
+
+            // 5. If IsDetachedBuffer(arrayBuffer) is true, throw a TypeError exception.
+            if o.is_detached_buffer() {
+                return Err(JsNativeError::typ()
+                    .with_message("ArrayBuffer.transfer called with detached buffer")
+                    .into());
+            }
+
+            // 6. If preserveResizability is preserve-resizability and IsFixedLengthArrayBuffer(arrayBuffer) is false, then
+            //     a. Let newMaxByteLength be arrayBuffer.[[ArrayBufferMaxByteLength]].
+            // 7. Else,
+            //     a. Let newMaxByteLength be empty.
+            let max_byte_length = if preserve_resizability {
+                o.array_buffer_max_byte_length
+            } else {
+                None
+            };
+
+            // 8. If arrayBuffer.[[ArrayBufferDetachKey]] is not undefined, throw a TypeError exception.
+            if !o.array_buffer_detach_key.is_undefined() {
+                return Err(JsNativeError::typ()
+                    .with_message("ArrayBuffer.transfer called with a non-detachable buffer")
+                    .into());
+            }
+
+            // 9. Let newBuffer be ? AllocateArrayBuffer(%ArrayBuffer%, newByteLength, newMaxByteLength).
+            if matches!(max_byte_length, Some(max) if new_byte_length > max) {
+                return Err(JsNativeError::range()
+                    .with_message(
+                        "new byte length exceeds the maximum byte length of the ArrayBuffer",
+                    )
+                    .into());
+            }
+
+            // 10. Let copyLength be min(newByteLength, arrayBuffer.[[ArrayBufferByteLength]]).
+            // 11. Let fromBlock be arrayBuffer.[[ArrayBufferData]].
+            // 12. Let toBlock be newBuffer.[[ArrayBufferData]].
+            // 13. Perform CopyDataBlockBytes(toBlock, 0, fromBlock, 0, copyLength).
+            // 14. NOTE: Neither creation of the new Data Block nor copying from the old Data Block
+            //     are observable. Implementations may implement this method as a zero-copy move or
+            //     a realloc.
+            let mut block = match o.array_buffer_data.take() {
+                Some(DataBlock::Unshared(block)) => block,
+                _ => unreachable!("buffer must be an unshared, not detached ArrayBuffer"),
+            };
+            if let Err(err) = resize_byte_data_block(&mut block, new_byte_length) {
+                o.array_buffer_data = Some(DataBlock::Unshared(block));
+                return Err(err);
+            }
+
+            // 15. Perform ! DetachArrayBuffer(arrayBuffer).
+            o.array_buffer_byte_length = 0;
+            o.update_data_block_memory();
+
+            (max_byte_length, block)
+        };
+
+        let prototype = context
+            .intrinsics()
+            .constructors()
+            .array_buffer()
+            .prototype();
+        let new_buffer = context.construct_object();
+        new_buffer.set_prototype(prototype.into());
+        let data_block_memory = ExternalMemory::new(block.capacity());
+        new_buffer.borrow_mut().data = ObjectData::array_buffer(Self {
+            array_buffer_data: Some(DataBlock::Unshared(block)),
+            array_buffer_byte_length: new_byte_length,
+            array_buffer_max_byte_length: max_byte_length,
+            array_buffer_detach_key: JsValue::undefined(),
+            data_block_memory,
+        });
+
+        // 16. Return newBuffer.
+        Ok(new_buffer.into())
+    }
+
+    /// Gets the `ArrayBuffer` object of a `this` value, throwing a `TypeError` if it is not an
+    /// `ArrayBuffer` or if it is a `SharedArrayBuffer`.
+    fn this_array_buffer<'a>(this: &'a JsValue, method: &str) -> JsResult<&'a JsObject> {
+        this.as_object()
+            .filter(|obj| {
+                obj.borrow()
+                    .as_array_buffer()
+                    .map_or(false, |buffer| !buffer.is_shared())
+            })
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!("{method} called with invalid object"))
+                    .into()
+            })
+    }
+
     /// `25.1.5.3 ArrayBuffer.prototype.slice ( start, end )`
     ///
     /// More information:
@@ -183,25 +649,37 @@ impl ArrayBuffer {
         let obj = if let Some(obj) = this.as_object() {
             obj
         } else {
-            return context.throw_type_error("ArrayBuffer.slice called with non-object value");
+            return Err(JsNativeError::typ()
+                .with_message("ArrayBuffer.slice called with non-object value")
+                .into());
         };
-        let obj_borrow = obj.borrow();
-        let o = if let Some(o) = obj_borrow.as_array_buffer() {
-            o
-        } else {
-            return context.throw_type_error("ArrayBuffer.slice called with invalid object");
-        };
-
-        // TODO: Shared Array Buffer
-        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        let len = {
+            let obj_borrow = obj.borrow();
+            let o = if let Some(o) = obj_borrow.as_array_buffer() {
+                o
+            } else {
+                return Err(JsNativeError::typ()
+                    .with_message("ArrayBuffer.slice called with invalid object")
+                    .into());
+            };
+
+            // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+            if o.is_shared() {
+                return Err(JsNativeError::typ()
+                    .with_message("ArrayBuffer.slice called with a SharedArrayBuffer")
+                    .into());
+            }
 
-        // 4. If IsDetachedBuffer(O) is true, throw a TypeError exception.
-        if Self::is_detached_buffer(o) {
-            return context.throw_type_error("ArrayBuffer.slice called with detached buffer");
-        }
+            // 4. If IsDetachedBuffer(O) is true, throw a TypeError exception.
+            if Self::is_detached_buffer(o) {
+                return Err(JsNativeError::typ()
+                    .with_message("ArrayBuffer.slice called with detached buffer")
+                    .into());
+            }
 
-        // 5. Let len be O.[[ArrayBufferByteLength]].
-        let len = o.array_buffer_byte_length as i64;
+            // 5. Let len be O.[[ArrayBufferByteLength]].
+            o.array_buffer_byte_length as i64
+        };
 
         // 6. Let relativeStart be ? ToIntegerOrInfinity(start).
         let relative_start = args.get_or_undefined(0).to_integer_or_infinity(context)?;
@@ -247,16 +725,21 @@ impl ArrayBuffer {
             let new_obj = new.borrow();
             // 17. Perform ? RequireInternalSlot(new, [[ArrayBufferData]]).
             let new_array_buffer = new_obj.as_array_buffer().ok_or_else(|| {
-                context.construct_type_error("ArrayBuffer constructor returned invalid object")
+                JsNativeError::typ().with_message("ArrayBuffer constructor returned invalid object")
             })?;
 
-            // TODO: Shared Array Buffer
             // 18. If IsSharedArrayBuffer(new) is true, throw a TypeError exception.
+            if new_array_buffer.is_shared() {
+                return Err(JsNativeError::typ()
+                    .with_message("ArrayBuffer constructor returned a SharedArrayBuffer")
+                    .into());
+            }
 
             // 19. If IsDetachedBuffer(new) is true, throw a TypeError exception.
             if new_array_buffer.is_detached_buffer() {
-                return context
-                    .throw_type_error("ArrayBuffer constructor returned detached ArrayBuffer");
+                return Err(JsNativeError::typ()
+                    .with_message("ArrayBuffer constructor returned detached ArrayBuffer")
+                    .into());
             }
         }
         // 20. If SameValue(new, O) is true, throw a TypeError exception.
@@ -265,10 +748,16 @@ impl ArrayBuffer {
             .map(|obj| JsObject::equals(obj, &new))
             .unwrap_or_default()
         {
-            return context.throw_type_error("New ArrayBuffer is the same as this ArrayBuffer");
+            return Err(JsNativeError::typ()
+                .with_message("New ArrayBuffer is the same as this ArrayBuffer")
+                .into());
         }
 
         {
+            let obj_borrow = obj.borrow();
+            let o = obj_borrow
+                .as_array_buffer()
+                .expect("Already checked that `this` was an `ArrayBuffer`");
             let mut new_obj_borrow = new.borrow_mut();
             let new_array_buffer = new_obj_borrow
                 .as_array_buffer_mut()
@@ -276,14 +765,17 @@ impl ArrayBuffer {
 
             // 21. If new.[[ArrayBufferByteLength]] < newLen, throw a TypeError exception.
             if new_array_buffer.array_buffer_byte_length < new_len {
-                return context.throw_type_error("New ArrayBuffer length too small");
+                return Err(JsNativeError::typ()
+                    .with_message("New ArrayBuffer length too small")
+                    .into());
             }
 
             // 22. NOTE: Side-effects of the above steps may have detached O.
             // 23. If IsDetachedBuffer(O) is true, throw a TypeError exception.
             if Self::is_detached_buffer(o) {
-                return context
-                    .throw_type_error("ArrayBuffer detached while ArrayBuffer.slice was running");
+                return Err(JsNativeError::typ()
+                    .with_message("ArrayBuffer detached while ArrayBuffer.slice was running")
+                    .into());
             }
 
             // 24. Let fromBuf be O.[[ArrayBufferData]].
@@ -298,15 +790,24 @@ impl ArrayBuffer {
                 .as_mut()
                 .expect("ArrayBuffer cannot be detached here");
 
-            // 26. Perform CopyDataBlockBytes(toBuf, 0, fromBuf, first, newLen).
-            copy_data_block_bytes(to_buf, 0, from_buf, first as usize, new_len as usize);
+            // 26. Let currentLen be O.[[ArrayBufferByteLength]].
+            let current_len = o.array_buffer_byte_length as i64;
+
+            // 27. If first < currentLen, then
+            if first < current_len {
+                // a. Let count be min(newLen, currentLen - first).
+                let count = std::cmp::min(new_len as i64, current_len - first);
+
+                // b. Perform CopyDataBlockBytes(toBuf, 0, fromBuf, first, count).
+                copy_data_block_bytes(to_buf, 0, from_buf, first as usize, count as usize);
+            }
         }
 
-        // 27. Return new.
+        // 28. Return new.
         Ok(new.into())
     }
 
-    /// `25.1.2.1 AllocateArrayBuffer ( constructor, byteLength )`
+    /// `25.1.3.1 AllocateArrayBuffer ( constructor, byteLength [ , maxByteLength ] )`
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -315,9 +816,24 @@ impl ArrayBuffer {
     pub(crate) fn allocate(
         constructor: &JsValue,
         byte_length: u64,
+        max_byte_length: Option<u64>,
         context: &mut Context,
     ) -> JsResult<JsObject> {
-        // 1. Let obj be ? OrdinaryCreateFromConstructor(constructor, "%ArrayBuffer.prototype%", « [[ArrayBufferData]], [[ArrayBufferByteLength]], [[ArrayBufferDetachKey]] »).
+        // 1. Let slots be « [[ArrayBufferData]], [[ArrayBufferByteLength]], [[ArrayBufferDetachKey]] ».
+        // 2. If maxByteLength is present and maxByteLength is not empty, let allocatingResizableBuffer be true; otherwise let allocatingResizableBuffer be false.
+        // 3. If allocatingResizableBuffer is true, then
+        if let Some(max_byte_length) = max_byte_length {
+            // a. If byteLength > maxByteLength, throw a RangeError exception.
+            if byte_length > max_byte_length {
+                return Err(JsNativeError::range()
+                    .with_message("byte length exceeds the maximum byte length of the ArrayBuffer")
+                    .into());
+            }
+
+            // b. Append [[ArrayBufferMaxByteLength]] to slots.
+        }
+
+        // 4. Let obj be ? OrdinaryCreateFromConstructor(constructor, "%ArrayBuffer.prototype%", slots).
         let prototype = get_prototype_from_constructor(
             constructor,
             StandardConstructors::array_buffer,
@@ -326,18 +842,28 @@ impl ArrayBuffer {
         let obj = context.construct_object();
         obj.set_prototype(prototype.into());
 
-        // 2. Let block be ? CreateByteDataBlock(byteLength).
-        let block = create_byte_data_block(byte_length, context)?;
-
-        // 3. Set obj.[[ArrayBufferData]] to block.
-        // 4. Set obj.[[ArrayBufferByteLength]] to byteLength.
+        // 5. Let block be ? CreateByteDataBlock(byteLength).
+        let block = create_byte_data_block(byte_length)?;
+
+        // 6. Set obj.[[ArrayBufferData]] to block.
+        // 7. Set obj.[[ArrayBufferByteLength]] to byteLength.
+        // 8. If allocatingResizableBuffer is true, then
+        //     a. If it is not possible to create a Data Block block consisting of maxByteLength
+        //        bytes, throw a RangeError exception.
+        //     b. NOTE: Resizable ArrayBuffers are designed to be implementable with in-place
+        //        growth. Implementations may throw if, for example, virtual memory cannot be
+        //        reserved up front.
+        //     c. Set obj.[[ArrayBufferMaxByteLength]] to maxByteLength.
+        let data_block_memory = ExternalMemory::new(block.capacity());
         obj.borrow_mut().data = ObjectData::array_buffer(Self {
-            array_buffer_data: Some(block),
+            array_buffer_data: Some(DataBlock::Unshared(block)),
             array_buffer_byte_length: byte_length,
-            array_buffer_detach_key: JsValue::Undefined,
+            array_buffer_max_byte_length: max_byte_length,
+            array_buffer_detach_key: JsValue::undefined(),
+            data_block_memory,
         });
 
-        // 5. Return obj.
+        // 9. Return obj.
         Ok(obj)
     }
 
@@ -367,14 +893,16 @@ impl ArrayBuffer {
         context: &mut Context,
     ) -> JsResult<JsObject> {
         // 1. Let targetBuffer be ? AllocateArrayBuffer(cloneConstructor, srcLength).
-        let target_buffer = Self::allocate(clone_constructor, src_length, context)?;
+        let target_buffer = Self::allocate(clone_constructor, src_length, None, context)?;
 
         // 2. If IsDetachedBuffer(srcBuffer) is true, throw a TypeError exception.
         // 3. Let srcBlock be srcBuffer.[[ArrayBufferData]].
         let src_block = if let Some(b) = &self.array_buffer_data {
             b
         } else {
-            return context.throw_syntax_error("Cannot clone detached array buffer");
+            return Err(JsNativeError::syntax()
+                .with_message("Cannot clone detached array buffer")
+                .into());
         };
 
         {
@@ -571,7 +1099,7 @@ impl ArrayBuffer {
         byte_index: u64,
         t: TypedArrayKind,
         _is_typed_array: bool,
-        _order: SharedMemoryOrder,
+        order: SharedMemoryOrder,
         is_little_endian: Option<bool>,
     ) -> JsValue {
         // 1. Assert: IsDetachedBuffer(arrayBuffer) is false.
@@ -585,13 +1113,20 @@ impl ArrayBuffer {
         // 4. Let elementSize be the Element Size value specified in Table 73 for Element Type type.
         let element_size = t.element_size() as usize;
 
-        // TODO: Shared Array Buffer
         // 5. If IsSharedArrayBuffer(arrayBuffer) is true, then
-
+        //     a. Let execution be the [[CandidateExecution]] field of the surrounding agent's Agent Record.
+        //     b. Let eventList be the [[EventList]] field of the element in execution.[[EventsRecords]] whose [[AgentSignifier]] is AgentSignifier().
+        //     c. If isTypedArray is true and IsNoTearConfiguration(type, order) is true, let noTear be true; otherwise let noTear be false.
+        //     d. Let rawValue be a List of length elementSize whose elements are nondeterministically chosen byte values.
+        //     e. NOTE: In implementations, rawValue is the result of a non-atomic or atomic read instruction on the underlying hardware. The nondeterminism is a semantic prescription of the memory model to describe observable behaviour of hardware with weak consistency.
+        //     f. Let readEvent be ReadSharedMemory { [[Order]]: order, [[NoTear]]: noTear, [[Block]]: block, [[ByteIndex]]: byteIndex, [[ElementSize]]: elementSize }.
+        //     g. Append readEvent to eventList.
+        //     h. Append Chosen Value Record { [[Event]]: readEvent, [[ChosenValue]]: rawValue } to execution.[[ChosenValues]].
         // 6. Else, let rawValue be a List whose elements are bytes from block at indices byteIndex (inclusive) through byteIndex + elementSize (exclusive).
         // 7. Assert: The number of elements in rawValue is elementSize.
-        let byte_index = byte_index as usize;
-        let raw_value = &block[byte_index..byte_index + element_size];
+        let mut raw_value = [0; 8];
+        let raw_value = &mut raw_value[..element_size];
+        block.read(byte_index as usize, raw_value, order);
 
         // TODO: Agent Record [[LittleEndian]] filed
         // 8. If isLittleEndian is not present, set isLittleEndian to the value of the [[LittleEndian]] field of the surrounding agent's Agent Record.
@@ -607,7 +1142,7 @@ impl ArrayBuffer {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-numerictorawbytes
-    fn numeric_to_raw_bytes(
+    pub(crate) fn numeric_to_raw_bytes(
         t: TypedArrayKind,
         value: &JsValue,
         is_little_endian: bool,
@@ -704,7 +1239,7 @@ impl ArrayBuffer {
         byte_index: u64,
         t: TypedArrayKind,
         value: &JsValue,
-        _order: SharedMemoryOrder,
+        order: SharedMemoryOrder,
         is_little_endian: Option<bool>,
         context: &mut Context,
     ) -> JsResult<JsValue> {
@@ -726,17 +1261,85 @@ impl ArrayBuffer {
         // 7. Let rawBytes be NumericToRawBytes(type, value, isLittleEndian).
         let raw_bytes = Self::numeric_to_raw_bytes(t, value, is_little_endian, context)?;
 
-        // TODO: Shared Array Buffer
         // 8. If IsSharedArrayBuffer(arrayBuffer) is true, then
-
+        //     a. Let execution be the [[CandidateExecution]] field of the surrounding agent's Agent Record.
+        //     b. Let eventList be the [[EventList]] field of the element in execution.[[EventsRecords]] whose [[AgentSignifier]] is AgentSignifier().
+        //     c. If isTypedArray is true and IsNoTearConfiguration(type, order) is true, let noTear be true; otherwise let noTear be false.
+        //     d. Append WriteSharedMemory { [[Order]]: order, [[NoTear]]: noTear, [[Block]]: block, [[ByteIndex]]: byteIndex, [[ElementSize]]: elementSize, [[Payload]]: rawBytes } to eventList.
         // 9. Else, store the individual bytes of rawBytes into block, starting at block[byteIndex].
-        for (i, raw_byte) in raw_bytes.iter().enumerate() {
-            block[byte_index as usize + i] = *raw_byte;
-        }
+        block.write(byte_index as usize, &raw_bytes, order);
 
         // 10. Return NormalCompletion(undefined).
         Ok(JsValue::undefined())
     }
+
+    /// `25.1.2.13 GetModifySetValueInBuffer ( arrayBuffer, byteIndex, type, value, op [ , isLittleEndian ] )`
+    ///
+    /// `op` receives the bytes read from the buffer and the bytes of `value`, and returns the
+    /// bytes to write.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getmodifysetvalueinbuffer
+    pub(crate) fn get_modify_set_value_in_buffer<F>(
+        &mut self,
+        byte_index: u64,
+        t: TypedArrayKind,
+        value: &JsValue,
+        op: F,
+        context: &mut Context,
+    ) -> JsResult<JsValue>
+    where
+        F: FnOnce(&[u8], &[u8]) -> Vec<u8>,
+    {
+        // 1. Assert: IsDetachedBuffer(arrayBuffer) is false.
+        // 2. Assert: There are sufficient bytes in arrayBuffer starting at byteIndex to represent a value of type.
+        // 3. Assert: Type(value) is BigInt if ! IsBigIntElementType(type) is true; otherwise, Type(value) is Number.
+        // 4. Let block be arrayBuffer.[[ArrayBufferData]].
+        let block = self
+            .array_buffer_data
+            .as_mut()
+            .expect("ArrayBuffer cannot be detached here");
+
+        // 5. Let elementSize be the Element Size value specified in Table 73 for Element Type type.
+        let element_size = t.element_size() as usize;
+        let byte_index = byte_index as usize;
+
+        // 6. If isLittleEndian is not present, set isLittleEndian to the value of the [[LittleEndian]] field of the surrounding agent's Agent Record.
+        // 7. Let rawBytes be NumericToRawBytes(type, value, isLittleEndian).
+        let raw_bytes = Self::numeric_to_raw_bytes(t, value, true, context)?;
+
+        let raw_bytes_read = match block {
+            // 8. If IsSharedArrayBuffer(arrayBuffer) is true, then
+            //     a. Let execution be the [[CandidateExecution]] field of the surrounding agent's Agent Record.
+            //     b. Let eventList be the [[EventList]] field of the element in execution.[[EventsRecords]] whose [[AgentSignifier]] is AgentSignifier().
+            //     c. Let rawBytesRead be a List of length elementSize whose elements are nondeterministically chosen byte values.
+            //     d. NOTE: In implementations, rawBytesRead is the result of a load-link, of a load-exclusive, or of an operand of a read-modify-write instruction on the underlying hardware. The nondeterminism is a semantic prescription of the memory model to describe observable behaviour of hardware with weak consistency.
+            //     e. Let rmwEvent be ReadModifyWriteSharedMemory { [[Order]]: SeqCst, [[NoTear]]: true, [[Block]]: block, [[ByteIndex]]: byteIndex, [[ElementSize]]: elementSize, [[Payload]]: rawBytes, [[ModifyOp]]: op }.
+            //     f. Append rmwEvent to eventList.
+            //     g. Append Chosen Value Record { [[Event]]: rmwEvent, [[ChosenValue]]: rawBytesRead } to execution.[[ChosenValues]].
+            DataBlock::Shared(block) => {
+                block.modify(byte_index, element_size, |read| op(read, &raw_bytes))
+            }
+            // 9. Else,
+            DataBlock::Unshared(block) => {
+                // a. Let rawBytesRead be a List of length elementSize whose elements are the sequence of elementSize bytes starting with block[byteIndex].
+                let bytes = &mut block[byte_index..byte_index + element_size];
+                let raw_bytes_read = bytes.to_vec();
+
+                // b. Let rawBytesModified be op(rawBytesRead, rawBytes).
+                let raw_bytes_modified = op(&raw_bytes_read, &raw_bytes);
+
+                // c. Store the individual bytes of rawBytesModified into block, starting at block[byteIndex].
+                bytes.copy_from_slice(&raw_bytes_modified);
+                raw_bytes_read
+            }
+        };
+
+        // 10. Return RawBytesToNumeric(type, rawBytesRead, isLittleEndian).
+        Ok(Self::raw_bytes_to_numeric(t, &raw_bytes_read, true))
+    }
 }
 
 /// `CreateByteDataBlock ( size )` abstract operation.
@@ -745,16 +1348,23 @@ impl ArrayBuffer {
 /// integer). For more information, check the [spec][spec].
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-createbytedatablock
-pub fn create_byte_data_block(size: u64, context: &mut Context) -> JsResult<Vec<u8>> {
+pub fn create_byte_data_block(size: u64) -> JsResult<Vec<u8>> {
     // 1. Let db be a new Data Block value consisting of size bytes. If it is impossible to
     //    create such a Data Block, throw a RangeError exception.
     let size = size.try_into().map_err(|e| {
-        context.construct_range_error(format!("couldn't allocate the data block: {e}"))
+        JsNativeError::range().with_message(format!("couldn't allocate the data block: {e}"))
     })?;
 
+    // Blocks that don't fit in the heap are refused before being allocated.
+    if !boa_gc::fits_heap_limit(size) {
+        return Err(JsNativeError::range()
+            .with_message("couldn't allocate the data block: out of memory")
+            .into());
+    }
+
     let mut data_block = Vec::new();
     data_block.try_reserve(size).map_err(|e| {
-        context.construct_range_error(format!("couldn't allocate the data block: {e}"))
+        JsNativeError::range().with_message(format!("couldn't allocate the data block: {e}"))
     })?;
 
     // 2. Set all of the bytes of db to 0.
@@ -764,16 +1374,40 @@ pub fn create_byte_data_block(size: u64, context: &mut Context) -> JsResult<Vec<
     Ok(data_block)
 }
 
+/// Resizes `block` to `size` bytes, setting the new bytes to 0.
+///
+/// This is the in-place equivalent of creating a new block with [`create_byte_data_block`] and
+/// copying the bytes of the old block to it.
+fn resize_byte_data_block(block: &mut Vec<u8>, size: u64) -> JsResult<()> {
+    let size: usize = size.try_into().map_err(|e| {
+        JsNativeError::range().with_message(format!("couldn't allocate the data block: {e}"))
+    })?;
+
+    if let Some(additional) = size.checked_sub(block.len()) {
+        if !boa_gc::fits_heap_limit(additional) {
+            return Err(JsNativeError::range()
+                .with_message("couldn't allocate the data block: out of memory")
+                .into());
+        }
+        block.try_reserve(additional).map_err(|e| {
+            JsNativeError::range().with_message(format!("couldn't allocate the data block: {e}"))
+        })?;
+    }
+    block.resize(size, 0);
+
+    Ok(())
+}
+
 /// `6.2.8.3 CopyDataBlockBytes ( toBlock, toIndex, fromBlock, fromIndex, count )`
 ///
 /// More information:
 ///  - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-copydatablockbytes
-fn copy_data_block_bytes(
-    to_block: &mut [u8],
+pub(crate) fn copy_data_block_bytes(
+    to_block: &mut DataBlock,
     mut to_index: usize,
-    from_block: &[u8],
+    from_block: &DataBlock,
     mut from_index: usize,
     mut count: usize,
 ) {
@@ -793,12 +1427,23 @@ fn copy_data_block_bytes(
     // 6. Repeat, while count > 0,
     while count > 0 {
         // a. If fromBlock is a Shared Data Block, then
-        // TODO: Shared Data Block
-
+        //     i. Let execution be the [[CandidateExecution]] field of the surrounding agent's Agent Record.
+        //     ii. Let eventList be the [[EventList]] field of the element in execution.[[EventsRecords]] whose [[AgentSignifier]] is AgentSignifier().
+        //     iii. Let bytes be a List whose sole element is a nondeterministically chosen byte value.
+        //     iv. NOTE: In implementations, bytes is the result of a non-atomic read instruction on the underlying hardware. The nondeterminism is a semantic prescription of the memory model to describe observable behaviour of hardware with weak consistency.
+        //     v. Let readEvent be ReadSharedMemory { [[Order]]: Unordered, [[NoTear]]: true, [[Block]]: fromBlock, [[ByteIndex]]: fromIndex, [[ElementSize]]: 1 }.
+        //     vi. Append readEvent to eventList.
+        //     vii. Append Chosen Value Record { [[Event]]: readEvent, [[ChosenValue]]: bytes } to execution.[[ChosenValues]].
+        //     viii. If toBlock is a Shared Data Block, then
+        //         1. Append WriteSharedMemory { [[Order]]: Unordered, [[NoTear]]: true, [[Block]]: toBlock, [[ByteIndex]]: toIndex, [[ElementSize]]: 1, [[Payload]]: bytes } to eventList.
+        //     ix. Else,
+        //         1. Set toBlock[toIndex] to bytes[0].
         // b. Else,
-        // i. Assert: toBlock is not a Shared Data Block.
-        // ii. Set toBlock[toIndex] to fromBlock[fromIndex].
-        to_block[to_index] = from_block[from_index];
+        //     i. Assert: toBlock is not a Shared Data Block.
+        //     ii. Set toBlock[toIndex] to fromBlock[fromIndex].
+        let mut byte = [0];
+        from_block.read(from_index, &mut byte, SharedMemoryOrder::Unordered);
+        to_block.write(to_index, &byte, SharedMemoryOrder::Unordered);
 
         // c. Set toIndex to toIndex + 1.
         to_index += 1;
@@ -813,10 +1458,10 @@ fn copy_data_block_bytes(
     // 7. Return NormalCompletion(empty).
 }
 
-// TODO: Allow unused variants until shared array buffers are implemented.
-#[allow(dead_code)]
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub(crate) enum SharedMemoryOrder {
+    // TODO: Allow the unused variant until `Init` events are modeled.
+    #[allow(dead_code)]
     Init,
     SeqCst,
     Unordered,
@@ -1,15 +1,140 @@
 use super::*;
+use crate::forward;
 
 #[test]
 fn ut_sunny_day_create_byte_data_block() {
+    assert!(create_byte_data_block(100).is_ok());
+}
+
+#[test]
+fn ut_rainy_day_create_byte_data_block() {
+    assert!(create_byte_data_block(u64::MAX).is_err());
+}
+
+#[test]
+fn resizable() {
     let mut context = Context::default();
 
-    assert!(create_byte_data_block(100, &mut context).is_ok());
+    let init = r#"
+        let fixed = new ArrayBuffer(8);
+        let rab = new ArrayBuffer(8, { maxByteLength: 16 });
+        new Uint8Array(rab).fill(1);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "fixed.resizable"), "false");
+    assert_eq!(forward(&mut context, "fixed.maxByteLength"), "8");
+    assert_eq!(forward(&mut context, "rab.resizable"), "true");
+    assert_eq!(forward(&mut context, "rab.maxByteLength"), "16");
+
+    forward(&mut context, "rab.resize(12)");
+    assert_eq!(forward(&mut context, "rab.byteLength"), "12");
+    assert_eq!(
+        forward(&mut context, "Array.from(new Uint8Array(rab)).join('')"),
+        "\"111111110000\""
+    );
+
+    forward(&mut context, "rab.resize(2)");
+    assert_eq!(forward(&mut context, "rab.byteLength"), "2");
+
+    assert_eq!(
+        forward(&mut context, "try { rab.resize(17) } catch (e) { e.name }"),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { fixed.resize(4) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new ArrayBuffer(8, { maxByteLength: 4 }) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
 }
 
 #[test]
-fn ut_rainy_day_create_byte_data_block() {
+fn transfer() {
     let mut context = Context::default();
 
-    assert!(create_byte_data_block(u64::MAX, &mut context).is_err());
+    let init = r#"
+        let buffer = new ArrayBuffer(4, { maxByteLength: 8 });
+        new Uint8Array(buffer).set([1, 2, 3, 4]);
+        let transferred = buffer.transfer(6);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "buffer.detached"), "true");
+    assert_eq!(forward(&mut context, "buffer.byteLength"), "0");
+    assert_eq!(forward(&mut context, "transferred.detached"), "false");
+    assert_eq!(forward(&mut context, "transferred.resizable"), "true");
+    assert_eq!(forward(&mut context, "transferred.maxByteLength"), "8");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.from(new Uint8Array(transferred)).join()"
+        ),
+        "\"1,2,3,4,0,0\""
+    );
+
+    forward(
+        &mut context,
+        "let fixed = transferred.transferToFixedLength(2)",
+    );
+    assert_eq!(forward(&mut context, "fixed.resizable"), "false");
+    assert_eq!(
+        forward(&mut context, "Array.from(new Uint8Array(fixed)).join()"),
+        "\"1,2\""
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { buffer.transfer() } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn length_tracking_views() {
+    let mut context = Context::default();
+
+    let init = r#"
+        let rab = new ArrayBuffer(8, { maxByteLength: 16 });
+        let tracking = new Uint16Array(rab, 2);
+        let fixed = new Uint16Array(rab, 2, 2);
+        let view = new DataView(rab, 4);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "tracking.length"), "3");
+    assert_eq!(forward(&mut context, "view.byteLength"), "4");
+
+    forward(&mut context, "rab.resize(16)");
+    assert_eq!(forward(&mut context, "tracking.length"), "7");
+    assert_eq!(forward(&mut context, "tracking.byteLength"), "14");
+    assert_eq!(forward(&mut context, "fixed.length"), "2");
+    assert_eq!(forward(&mut context, "view.byteLength"), "12");
+
+    forward(&mut context, "rab.resize(5)");
+    assert_eq!(forward(&mut context, "tracking.length"), "1");
+    assert_eq!(forward(&mut context, "fixed.length"), "0");
+    assert_eq!(forward(&mut context, "fixed.byteOffset"), "0");
+    assert_eq!(forward(&mut context, "fixed[0]"), "undefined");
+    assert_eq!(forward(&mut context, "view.byteLength"), "1");
+
+    forward(&mut context, "rab.resize(3)");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { view.getUint8(0) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { fixed.fill(0) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
 }
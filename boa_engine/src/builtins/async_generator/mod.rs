@@ -15,7 +15,7 @@ use crate::{
     symbol::WellKnownSymbols,
     value::JsValue,
     vm::GeneratorResumeKind,
-    Context, JsResult,
+    Context, JsError, JsNativeError, JsResult,
 };
 use boa_gc::{Cell, Finalize, Gc, Trace};
 use boa_profiler::Profiler;
@@ -173,12 +173,12 @@ impl AsyncGenerator {
         // 3. Let result be Completion(AsyncGeneratorValidate(generator, empty)).
         // 4. IfAbruptRejectPromise(result, promiseCapability).
         let generator_object = generator.as_object().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ().with_message("generator resumed on non generator object")
         });
         if_abrupt_reject_promise!(generator_object, promise_capability, context);
         let mut generator_obj_mut = generator_object.borrow_mut();
         let generator = generator_obj_mut.as_async_generator_mut().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ().with_message("generator resumed on non generator object")
         });
         if_abrupt_reject_promise!(generator, promise_capability, context);
 
@@ -262,12 +262,12 @@ impl AsyncGenerator {
         // 3. Let result be Completion(AsyncGeneratorValidate(generator, empty)).
         // 4. IfAbruptRejectPromise(result, promiseCapability).
         let generator_object = generator.as_object().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ().with_message("generator resumed on non generator object")
         });
         if_abrupt_reject_promise!(generator_object, promise_capability, context);
         let mut generator_obj_mut = generator_object.borrow_mut();
         let generator = generator_obj_mut.as_async_generator_mut().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ().with_message("generator resumed on non generator object")
         });
         if_abrupt_reject_promise!(generator, promise_capability, context);
 
@@ -346,12 +346,12 @@ impl AsyncGenerator {
         // 3. Let result be Completion(AsyncGeneratorValidate(generator, empty)).
         // 4. IfAbruptRejectPromise(result, promiseCapability).
         let generator_object = generator.as_object().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ().with_message("generator resumed on non generator object")
         });
         if_abrupt_reject_promise!(generator_object, promise_capability, context);
         let mut generator_obj_mut = generator_object.borrow_mut();
         let generator = generator_obj_mut.as_async_generator_mut().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ().with_message("generator resumed on non generator object")
         });
         if_abrupt_reject_promise!(generator, promise_capability, context);
 
@@ -386,7 +386,10 @@ impl AsyncGenerator {
         }
 
         // 8. Let completion be ThrowCompletion(exception).
-        let completion = (Err(args.get_or_undefined(0).clone()), false);
+        let completion = (
+            Err(JsError::from_opaque(args.get_or_undefined(0).clone())),
+            false,
+        );
 
         // 9. Perform AsyncGeneratorEnqueue(generator, completion, promiseCapability).
         generator.enqueue(completion.clone(), promise_capability.clone());
@@ -460,7 +463,7 @@ impl AsyncGenerator {
                 // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « value »).
                 promise_capability
                     .reject()
-                    .call(&JsValue::undefined(), &[value], context)
+                    .call(&JsValue::undefined(), &[value.to_opaque(context)], context)
                     .expect("cannot fail per spec");
             }
             // 8. Else,
@@ -519,8 +522,9 @@ impl AsyncGenerator {
             .state = AsyncGeneratorState::Executing;
 
         // 6. Push genContext onto the execution context stack; genContext is now the running execution context.
+        context.debugger_suspend_frame();
         std::mem::swap(
-            &mut context.realm.environments,
+            &mut context.vm.environments,
             &mut generator_context_mut.environments,
         );
         std::mem::swap(&mut context.vm.stack, &mut generator_context_mut.stack);
@@ -539,6 +543,7 @@ impl AsyncGenerator {
                 }
             }
             (Err(value), _) => {
+                let value = value.to_opaque(context);
                 context.vm.push(value);
                 context.vm.frame_mut().generator_resume_kind = GeneratorResumeKind::Throw;
             }
@@ -548,7 +553,7 @@ impl AsyncGenerator {
 
         let mut generator_context_mut = generator_context.borrow_mut();
         std::mem::swap(
-            &mut context.realm.environments,
+            &mut context.vm.environments,
             &mut generator_context_mut.environments,
         );
         std::mem::swap(&mut context.vm.stack, &mut generator_context_mut.stack);
@@ -655,7 +660,7 @@ impl AsyncGenerator {
                 gen.state = AsyncGeneratorState::Completed;
 
                 // b. Let result be ThrowCompletion(reason).
-                let result = Err(args.get_or_undefined(0).clone());
+                let result = Err(JsError::from_opaque(args.get_or_undefined(0).clone()));
 
                 // c. Perform AsyncGeneratorCompleteStep(generator, result, true).
                 let next = gen.queue.pop_front().expect("must have one entry");
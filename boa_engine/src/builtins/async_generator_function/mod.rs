@@ -69,6 +69,7 @@ impl BuiltIn for AsyncGeneratorFunction {
         constructor.borrow_mut().data = ObjectData::function(Function::Native {
             function: Self::constructor,
             constructor: Some(ConstructorKind::Base),
+            realm: context.realm().clone(),
         });
 
         prototype.set_prototype(Some(
@@ -0,0 +1,723 @@
+//! This module implements the global `Atomics` object.
+//!
+//! The `Atomics` object provides atomic operations as static methods. They are used with
+//! `SharedArrayBuffer` and `ArrayBuffer` objects.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-atomics-object
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics
+
+use crate::{
+    builtins::{
+        array_buffer::{ArrayBuffer, DataBlock, SharedMemoryOrder},
+        shared_array_buffer::WaitResult,
+        typed_array::{
+            integer_indexed_object::{ContentType, IntegerIndexed},
+            TypedArrayKind,
+        },
+        BuiltIn, JsArgs,
+    },
+    object::{JsObject, Object, ObjectInitializer},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::IntegerOrInfinity,
+    Context, JsNativeError, JsResult, JsValue,
+};
+use boa_profiler::Profiler;
+use std::time::Duration;
+use tap::{Conv, Pipe};
+
+#[cfg(test)]
+mod tests;
+
+/// Javascript `Atomics` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct Atomics;
+
+impl BuiltIn for Atomics {
+    const NAME: &'static str = "Atomics";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let to_string_tag = WellKnownSymbols::to_string_tag();
+
+        ObjectInitializer::new(context)
+            .function(Self::add, "add", 3)
+            .function(Self::and, "and", 3)
+            .function(Self::compare_exchange, "compareExchange", 4)
+            .function(Self::exchange, "exchange", 3)
+            .function(Self::is_lock_free, "isLockFree", 1)
+            .function(Self::load, "load", 2)
+            .function(Self::or, "or", 3)
+            .function(Self::store, "store", 3)
+            .function(Self::sub, "sub", 3)
+            .function(Self::wait, "wait", 4)
+            .function(Self::notify, "notify", 3)
+            .function(Self::xor, "xor", 3)
+            .property(
+                to_string_tag,
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build()
+            .conv::<JsValue>()
+            .pipe(Some)
+    }
+}
+
+impl Atomics {
+    /// `Atomics.add ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.add
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/add
+    pub(crate) fn add(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let type be the Element Type value in Table 71 for typedArray.[[TypedArrayName]].
+        // 2. Let isLittleEndian be the value of the [[LittleEndian]] field of the surrounding agent's Agent Record.
+        // 3. Let add be a new read-modify-write modification function with parameters (xBytes, yBytes) that captures type and isLittleEndian and performs the following steps atomically when called:
+        //     a. Let x be RawBytesToNumeric(type, xBytes, isLittleEndian).
+        //     b. Let y be RawBytesToNumeric(type, yBytes, isLittleEndian).
+        //     c. If x is a Number, then
+        //         i. Let sum be Number::add(x, y).
+        //     d. Else,
+        //         i. Assert: x is a BigInt.
+        //         ii. Let sum be BigInt::add(x, y).
+        //     e. Let sumBytes be NumericToRawBytes(type, sum, isLittleEndian).
+        //     f. Assert: sumBytes, xBytes, and yBytes have the same number of elements.
+        //     g. Return sumBytes.
+        // 4. Return ? AtomicReadModifyWrite(typedArray, index, value, add).
+        Self::atomic_read_modify_write(args, context, |x, y| {
+            from_integer(to_integer(x).wrapping_add(to_integer(y)), x.len())
+        })
+    }
+
+    /// `Atomics.and ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.and
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/and
+    pub(crate) fn and(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let and be a new read-modify-write modification function with parameters (xBytes, yBytes) that captures nothing and performs the following steps atomically when called:
+        //     a. Return ByteListBitwiseOp(&, xBytes, yBytes).
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, and).
+        Self::atomic_read_modify_write(args, context, |x, y| {
+            x.iter().zip(y).map(|(x, y)| x & y).collect()
+        })
+    }
+
+    /// `Atomics.compareExchange ( typedArray, index, expectedValue, replacementValue )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.compareexchange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/compareExchange
+    pub(crate) fn compare_exchange(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let typed_array = args.get_or_undefined(0);
+
+        // 1. Let buffer be ? ValidateIntegerTypedArray(typedArray).
+        // 2. Let block be buffer.[[ArrayBufferData]].
+        let (buffer, kind) = validate_integer_typed_array(typed_array, false)?;
+
+        // 3. Let indexedPosition be ? ValidateAtomicAccess(typedArray, index).
+        let indexed_position =
+            validate_atomic_access(typed_array, args.get_or_undefined(1), context)?;
+
+        // 4. If typedArray.[[ContentType]] is BigInt, then
+        //     a. Let expected be ? ToBigInt(expectedValue).
+        //     b. Let replacement be ? ToBigInt(replacementValue).
+        // 5. Else,
+        //     a. Let expected be 𝔽(? ToIntegerOrInfinity(expectedValue)).
+        //     b. Let replacement be 𝔽(? ToIntegerOrInfinity(replacementValue)).
+        let expected = to_element_value(kind, args.get_or_undefined(2), context)?;
+        let replacement = to_element_value(kind, args.get_or_undefined(3), context)?;
+
+        // 6. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
+        let mut buffer = buffer.borrow_mut();
+        let buffer = revalidate_atomic_access(&mut buffer, indexed_position, kind)?;
+
+        // 7. Let elementType be TypedArrayElementType(typedArray).
+        // 8. Let elementSize be TypedArrayElementSize(typedArray).
+        // 9. Let isLittleEndian be the value of the [[LittleEndian]] field of the surrounding agent's Agent Record.
+        // 10. Let expectedBytes be NumericToRawBytes(elementType, expected, isLittleEndian).
+        let expected_bytes = ArrayBuffer::numeric_to_raw_bytes(kind, &expected, true, context)?;
+
+        // 11. Let replacementBytes be NumericToRawBytes(elementType, replacement, isLittleEndian).
+        // 12. If IsSharedArrayBuffer(buffer) is true, then
+        //     a. Let compareExchange denote a semantic function of two List of byte values arguments that returns the second argument if the first argument is element-wise equal to expectedBytes.
+        //     b. Let rawBytesRead be AtomicCompareExchangeInSharedBlock(block, indexedPosition, elementSize, expectedBytes, replacementBytes).
+        // 13. Else,
+        //     a. Let rawBytesRead be a List of length elementSize whose elements are the sequence of elementSize bytes starting with block[indexedPosition].
+        //     b. If ByteListEqual(rawBytesRead, expectedBytes) is true, then
+        //         i. Store the individual bytes of replacementBytes into block, starting at block[indexedPosition].
+        // 14. Return RawBytesToNumeric(elementType, rawBytesRead, isLittleEndian).
+        buffer.get_modify_set_value_in_buffer(
+            indexed_position,
+            kind,
+            &replacement,
+            |read, replacement| {
+                if read == expected_bytes {
+                    replacement.to_vec()
+                } else {
+                    read.to_vec()
+                }
+            },
+            context,
+        )
+    }
+
+    /// `Atomics.exchange ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.exchange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/exchange
+    pub(crate) fn exchange(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let second be a new read-modify-write modification function with parameters (oldBytes, newBytes) that captures nothing and performs the following steps atomically when called:
+        //     a. Return newBytes.
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, second).
+        Self::atomic_read_modify_write(args, context, |_, new| new.to_vec())
+    }
+
+    /// `Atomics.isLockFree ( size )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.islockfree
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/isLockFree
+    pub(crate) fn is_lock_free(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let n be ? ToIntegerOrInfinity(size).
+        let n = args.get_or_undefined(0).to_integer_or_infinity(context)?;
+
+        // 2. Let AR be the Agent Record of the surrounding agent.
+        // 3. If n = 1, return AR.[[IsLockFree1]].
+        // 4. If n = 2, return AR.[[IsLockFree2]].
+        // 5. If n = 4, return true.
+        // 6. If n = 8, return AR.[[IsLockFree8]].
+        // 7. Return false.
+        // NOTE: Atomic operations on shared blocks are serialized by a lock, so only the
+        // mandatory size is reported as lock-free.
+        Ok((n == IntegerOrInfinity::Integer(4)).into())
+    }
+
+    /// `Atomics.load ( typedArray, index )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.load
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/load
+    pub(crate) fn load(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let typed_array = args.get_or_undefined(0);
+
+        // 1. Let buffer be ? ValidateIntegerTypedArray(typedArray).
+        let (buffer, kind) = validate_integer_typed_array(typed_array, false)?;
+
+        // 2. Let indexedPosition be ? ValidateAtomicAccess(typedArray, index).
+        let indexed_position =
+            validate_atomic_access(typed_array, args.get_or_undefined(1), context)?;
+
+        // 3. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
+        let mut buffer = buffer.borrow_mut();
+        let buffer = revalidate_atomic_access(&mut buffer, indexed_position, kind)?;
+
+        // 4. Let elementType be TypedArrayElementType(typedArray).
+        // 5. Return GetValueFromBuffer(buffer, indexedPosition, elementType, true, SeqCst).
+        Ok(buffer.get_value_from_buffer(
+            indexed_position,
+            kind,
+            true,
+            SharedMemoryOrder::SeqCst,
+            None,
+        ))
+    }
+
+    /// `Atomics.or ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.or
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/or
+    pub(crate) fn or(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let or be a new read-modify-write modification function with parameters (xBytes, yBytes) that captures nothing and performs the following steps atomically when called:
+        //     a. Return ByteListBitwiseOp(|, xBytes, yBytes).
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, or).
+        Self::atomic_read_modify_write(args, context, |x, y| {
+            x.iter().zip(y).map(|(x, y)| x | y).collect()
+        })
+    }
+
+    /// `Atomics.store ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.store
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/store
+    pub(crate) fn store(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let typed_array = args.get_or_undefined(0);
+
+        // 1. Let buffer be ? ValidateIntegerTypedArray(typedArray).
+        let (buffer, kind) = validate_integer_typed_array(typed_array, false)?;
+
+        // 2. Let indexedPosition be ? ValidateAtomicAccess(typedArray, index).
+        let indexed_position =
+            validate_atomic_access(typed_array, args.get_or_undefined(1), context)?;
+
+        // 3. If typedArray.[[ContentType]] is BigInt, let v be ? ToBigInt(value).
+        // 4. Otherwise, let v be 𝔽(? ToIntegerOrInfinity(value)).
+        let v = to_element_value(kind, args.get_or_undefined(2), context)?;
+
+        // 5. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
+        let mut buffer = buffer.borrow_mut();
+        let buffer = revalidate_atomic_access(&mut buffer, indexed_position, kind)?;
+
+        // 6. Let elementType be TypedArrayElementType(typedArray).
+        // 7. Perform SetValueInBuffer(buffer, indexedPosition, elementType, v, true, SeqCst).
+        buffer.set_value_in_buffer(
+            indexed_position,
+            kind,
+            &v,
+            SharedMemoryOrder::SeqCst,
+            None,
+            context,
+        )?;
+
+        // 8. Return v.
+        Ok(v)
+    }
+
+    /// `Atomics.sub ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.sub
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/sub
+    pub(crate) fn sub(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let type be TypedArrayElementType(typedArray).
+        // 2. Let isLittleEndian be the value of the [[LittleEndian]] field of the surrounding agent's Agent Record.
+        // 3. Let subtract be a new read-modify-write modification function with parameters (xBytes, yBytes) that captures type and isLittleEndian and performs the following steps atomically when called:
+        //     a. Let x be RawBytesToNumeric(type, xBytes, isLittleEndian).
+        //     b. Let y be RawBytesToNumeric(type, yBytes, isLittleEndian).
+        //     c. If x is a Number, then
+        //         i. Let difference be Number::subtract(x, y).
+        //     d. Else,
+        //         i. Assert: x is a BigInt.
+        //         ii. Let difference be BigInt::subtract(x, y).
+        //     e. Let differenceBytes be NumericToRawBytes(type, difference, isLittleEndian).
+        //     f. Assert: differenceBytes, xBytes, and yBytes have the same number of elements.
+        //     g. Return differenceBytes.
+        // 4. Return ? AtomicReadModifyWrite(typedArray, index, value, subtract).
+        Self::atomic_read_modify_write(args, context, |x, y| {
+            from_integer(to_integer(x).wrapping_sub(to_integer(y)), x.len())
+        })
+    }
+
+    /// `Atomics.wait ( typedArray, index, value, timeout )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.wait
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/wait
+    pub(crate) fn wait(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? DoWait(sync, typedArray, index, value, timeout).
+        let typed_array = args.get_or_undefined(0);
+
+        // DoWait ( mode, typedArray, index, value, timeout )
+        // 1. Let buffer be ? ValidateIntegerTypedArray(typedArray, true).
+        let (buffer, kind) = validate_integer_typed_array(typed_array, true)?;
+
+        // 2. If IsSharedArrayBuffer(buffer) is false, throw a TypeError exception.
+        let block = match buffer.borrow().as_array_buffer().and_then(|buffer| {
+            match &buffer.array_buffer_data {
+                Some(DataBlock::Shared(block)) => Some(block.clone()),
+                _ => None,
+            }
+        }) {
+            Some(block) => block,
+            None => {
+                return Err(JsNativeError::typ()
+                    .with_message("Atomics.wait can only be used with a SharedArrayBuffer")
+                    .into())
+            }
+        };
+
+        // 3. Let i be ? ValidateAtomicAccess(typedArray, index).
+        let i = validate_atomic_access(typed_array, args.get_or_undefined(1), context)?;
+
+        // 4. Let arrayTypeName be typedArray.[[TypedArrayName]].
+        // 5. If arrayTypeName is "BigInt64Array", let v be ? ToBigInt64(value).
+        // 6. Else, let v be ? ToInt32(value).
+        let v: JsValue = if kind == TypedArrayKind::BigInt64 {
+            args.get_or_undefined(2).to_bigint(context)?.into()
+        } else {
+            args.get_or_undefined(2).to_i32(context)?.into()
+        };
+
+        // 7. Let q be ? ToNumber(timeout).
+        let q = args.get_or_undefined(3).to_number(context)?;
+
+        // 8. If q is NaN or +∞𝔽, let t be +∞; else if q is -∞𝔽, let t be 0; else let t be max(ℝ(q), 0).
+        let timeout = if q.is_nan() || q == f64::INFINITY {
+            None
+        } else {
+            let seconds = q.max(0.0) / 1000.0;
+            // Timeouts too long to be represented are treated as infinite.
+            (seconds < u64::MAX as f64).then(|| Duration::from_secs_f64(seconds))
+        };
+
+        // 9. If mode is sync and AgentCanSuspend() is false, throw a TypeError exception.
+        if !context.can_block() {
+            return Err(JsNativeError::typ()
+                .with_message("Atomics.wait cannot block in this agent")
+                .into());
+        }
+
+        // 10. Let block be buffer.[[ArrayBufferData]].
+        // 11. Let WL be GetWaiterList(block, i).
+        // 12. Perform EnterCriticalSection(WL).
+        // 13. Let elementType be TypedArrayElementType(typedArray).
+        // 14. Let w be GetValueFromBuffer(buffer, i, elementType, true, SeqCst).
+        // 15. If v ≠ w, then
+        //     a. Perform LeaveCriticalSection(WL).
+        //     b. Return "not-equal".
+        // 16. Let W be AgentSignifier().
+        // 17. Perform AddWaiter(WL, W).
+        // 18. Let notified be SuspendAgent(WL, W, t).
+        // 19. If notified is true, then
+        //     a. Assert: W is not on the list of waiters in WL.
+        // 20. Else,
+        //     a. Perform RemoveWaiter(WL, W).
+        // 21. Perform LeaveCriticalSection(WL).
+        // 22. If notified is true, return "ok".
+        // 23. Return "timed-out".
+        let expected = ArrayBuffer::numeric_to_raw_bytes(kind, &v, true, context)?;
+        let result = match block.wait(i as usize, &expected, timeout) {
+            WaitResult::Ok => "ok",
+            WaitResult::NotEqual => "not-equal",
+            WaitResult::TimedOut => "timed-out",
+        };
+
+        Ok(result.into())
+    }
+
+    /// `Atomics.notify ( typedArray, index, count )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.notify
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/notify
+    pub(crate) fn notify(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let typed_array = args.get_or_undefined(0);
+
+        // 1. Let buffer be ? ValidateIntegerTypedArray(typedArray, true).
+        let (buffer, _) = validate_integer_typed_array(typed_array, true)?;
+
+        // 2. Let indexedPosition be ? ValidateAtomicAccess(typedArray, index).
+        let indexed_position =
+            validate_atomic_access(typed_array, args.get_or_undefined(1), context)?;
+
+        // 3. If count is undefined, let c be +∞.
+        // 4. Else,
+        //     a. Let intCount be ? ToIntegerOrInfinity(count).
+        //     b. Let c be max(intCount, 0).
+        let count = match args.get_or_undefined(2) {
+            count if count.is_undefined() => u64::MAX,
+            count => count
+                .to_integer_or_infinity(context)?
+                .clamp_finite(0, i64::MAX) as u64,
+        };
+
+        // 5. Let block be buffer.[[ArrayBufferData]].
+        // 6. Let arrayTypeName be typedArray.[[TypedArrayName]].
+        // 7. If IsSharedArrayBuffer(buffer) is false, return +0𝔽.
+        let block = match buffer.borrow().as_array_buffer().and_then(|buffer| {
+            match &buffer.array_buffer_data {
+                Some(DataBlock::Shared(block)) => Some(block.clone()),
+                _ => None,
+            }
+        }) {
+            Some(block) => block,
+            None => return Ok(0.into()),
+        };
+
+        // 8. Let WL be GetWaiterList(block, indexedPosition).
+        // 9. Let n be 0.
+        // 10. Perform EnterCriticalSection(WL).
+        // 11. Let S be RemoveWaiters(WL, c).
+        // 12. Repeat, while S is not empty,
+        //     a. Let W be the first agent in S.
+        //     b. Remove W from the front of S.
+        //     c. Perform NotifyWaiter(WL, W).
+        //     d. Set n to n + 1.
+        // 13. Perform LeaveCriticalSection(WL).
+        let n = block.notify(indexed_position as usize, count);
+
+        // 14. Return 𝔽(n).
+        Ok((n as f64).into())
+    }
+
+    /// `Atomics.xor ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.xor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/xor
+    pub(crate) fn xor(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let xor be a new read-modify-write modification function with parameters (xBytes, yBytes) that captures nothing and performs the following steps atomically when called:
+        //     a. Return ByteListBitwiseOp(^, xBytes, yBytes).
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, xor).
+        Self::atomic_read_modify_write(args, context, |x, y| {
+            x.iter().zip(y).map(|(x, y)| x ^ y).collect()
+        })
+    }
+
+    /// `25.4.2.10 AtomicReadModifyWrite ( typedArray, index, value, op )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomicreadmodifywrite
+    fn atomic_read_modify_write<F>(
+        args: &[JsValue],
+        context: &mut Context,
+        op: F,
+    ) -> JsResult<JsValue>
+    where
+        F: FnOnce(&[u8], &[u8]) -> Vec<u8>,
+    {
+        let typed_array = args.get_or_undefined(0);
+
+        // 1. Let buffer be ? ValidateIntegerTypedArray(typedArray).
+        let (buffer, kind) = validate_integer_typed_array(typed_array, false)?;
+
+        // 2. Let indexedPosition be ? ValidateAtomicAccess(typedArray, index).
+        let indexed_position =
+            validate_atomic_access(typed_array, args.get_or_undefined(1), context)?;
+
+        // 3. If typedArray.[[ContentType]] is BigInt, let v be ? ToBigInt(value).
+        // 4. Otherwise, let v be 𝔽(? ToIntegerOrInfinity(value)).
+        let v = to_element_value(kind, args.get_or_undefined(2), context)?;
+
+        // 5. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
+        let mut buffer = buffer.borrow_mut();
+        let buffer = revalidate_atomic_access(&mut buffer, indexed_position, kind)?;
+
+        // 6. Let elementType be TypedArrayElementType(typedArray).
+        // 7. Return GetModifySetValueInBuffer(buffer, indexedPosition, elementType, v, op).
+        buffer.get_modify_set_value_in_buffer(indexed_position, kind, &v, op, context)
+    }
+}
+
+/// `25.4.2.1 ValidateIntegerTypedArray ( typedArray [ , waitable ] )`
+///
+/// Returns the viewed buffer and the element type of `typed_array`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-validateintegertypedarray
+fn validate_integer_typed_array(
+    typed_array: &JsValue,
+    waitable: bool,
+) -> JsResult<(JsObject, TypedArrayKind)> {
+    // 1. If waitable is not present, set waitable to false.
+    // 2. Perform ? ValidateTypedArray(typedArray).
+    let typed_array = typed_array
+        .as_object()
+        .map(JsObject::borrow)
+        .filter(|obj| obj.is_typed_array())
+        .ok_or_else(|| JsNativeError::typ().with_message("value is not a typed array object"))?;
+    let typed_array = typed_array
+        .as_typed_array()
+        .expect("must be a typed array object");
+    if typed_array.is_out_of_bounds() {
+        return Err(JsNativeError::typ()
+            .with_message("Buffer of the typed array is detached")
+            .into());
+    }
+
+    // 3. Let buffer be typedArray.[[ViewedArrayBuffer]].
+    let buffer = typed_array
+        .viewed_array_buffer()
+        .expect("detached buffers were already checked")
+        .clone();
+
+    // 4. If waitable is true, then
+    //     a. If typedArray.[[TypedArrayName]] is not "Int32Array" or "BigInt64Array", throw a TypeError exception.
+    // 5. Else,
+    //     a. Let type be TypedArrayElementType(typedArray).
+    //     b. If IsUnclampedIntegerElementType(type) is false and IsBigIntElementType(type) is false, throw a TypeError exception.
+    let kind = typed_array.typed_array_name();
+    let valid = if waitable {
+        matches!(kind, TypedArrayKind::Int32 | TypedArrayKind::BigInt64)
+    } else {
+        !matches!(
+            kind,
+            TypedArrayKind::Uint8Clamped | TypedArrayKind::Float32 | TypedArrayKind::Float64
+        )
+    };
+    if !valid {
+        return Err(JsNativeError::typ()
+            .with_message(format!(
+                "{} is not a valid typed array type for this atomic operation",
+                kind.name()
+            ))
+            .into());
+    }
+
+    // 6. Return buffer.
+    Ok((buffer, kind))
+}
+
+/// `25.4.2.2 ValidateAtomicAccess ( typedArray, requestIndex )`
+///
+/// Returns the byte index of the element at `request_index` in the viewed buffer.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-validateatomicaccess
+fn validate_atomic_access(
+    typed_array: &JsValue,
+    request_index: &JsValue,
+    context: &mut Context,
+) -> JsResult<u64> {
+    // 1. Let length be typedArray.[[ArrayLength]].
+    let length = typed_array
+        .as_object()
+        .and_then(|obj| {
+            obj.borrow()
+                .as_typed_array()
+                .map(IntegerIndexed::array_length)
+        })
+        .expect("typed array was already validated");
+
+    // 2. Let accessIndex be ? ToIndex(requestIndex).
+    let access_index = request_index.to_index(context)?;
+
+    // 3. Assert: accessIndex ≥ 0.
+    // 4. If accessIndex ≥ length, throw a RangeError exception.
+    if access_index >= length {
+        return Err(JsNativeError::range()
+            .with_message("index out of bounds of the typed array")
+            .into());
+    }
+
+    let typed_array = typed_array.as_object().expect("already checked").borrow();
+    let typed_array = typed_array.as_typed_array().expect("already checked");
+
+    // 5. Let elementSize be TypedArrayElementSize(typedArray).
+    // 6. Let offset be typedArray.[[ByteOffset]].
+    // 7. Return (accessIndex × elementSize) + offset.
+    Ok(access_index * typed_array.typed_array_name().element_size() + typed_array.byte_offset())
+}
+
+/// Checks that the buffer of a typed array was not detached or shrunk while converting the
+/// arguments of an atomic operation.
+///
+/// This is the `If IsDetachedBuffer(buffer) is true, throw a TypeError exception.` step shared
+/// by the atomic operations that convert values. A resizable buffer that no longer contains the
+/// element at `indexed_position` throws a `RangeError`.
+fn revalidate_atomic_access(
+    buffer: &mut Object,
+    indexed_position: u64,
+    kind: TypedArrayKind,
+) -> JsResult<&mut ArrayBuffer> {
+    let buffer = buffer
+        .as_array_buffer_mut()
+        .expect("typed arrays always view an ArrayBuffer");
+
+    if ArrayBuffer::is_detached_buffer(buffer) {
+        return Err(JsNativeError::typ()
+            .with_message("Buffer of the typed array was detached")
+            .into());
+    }
+
+    if indexed_position + kind.element_size() > buffer.array_buffer_byte_length() {
+        return Err(JsNativeError::range()
+            .with_message("index out of bounds of the typed array")
+            .into());
+    }
+
+    Ok(buffer)
+}
+
+/// Converts `value` to the value stored by an atomic operation on a typed array of type `kind`.
+///
+/// This is `ToBigInt(value)` for `BigInt` typed arrays, and `𝔽(? ToIntegerOrInfinity(value))`
+/// otherwise.
+fn to_element_value(
+    kind: TypedArrayKind,
+    value: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    if kind.content_type() == ContentType::BigInt {
+        return Ok(value.to_bigint(context)?.into());
+    }
+
+    let number = value.to_number(context)?;
+    let integer = if number.is_nan() {
+        0.0
+    } else {
+        // Adding `+0` turns `-0` into `+0`.
+        number.trunc() + 0.0
+    };
+
+    Ok(integer.into())
+}
+
+/// Interprets up to eight little endian bytes as an integer.
+fn to_integer(bytes: &[u8]) -> u64 {
+    let mut buffer = [0; 8];
+    buffer[..bytes.len()].copy_from_slice(bytes);
+    u64::from_le_bytes(buffer)
+}
+
+/// Truncates an integer to its lowest `size` little endian bytes.
+fn from_integer(integer: u64, size: usize) -> Vec<u8> {
+    integer.to_le_bytes()[..size].to_vec()
+}
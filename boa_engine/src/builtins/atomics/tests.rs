@@ -0,0 +1,171 @@
+use crate::{forward, object::JsSharedArrayBuffer, Context, JsValue};
+use std::{thread, time::Duration};
+
+#[test]
+fn read_modify_write() {
+    let mut context = Context::default();
+
+    let init = r#"
+        var ta = new Int32Array(new SharedArrayBuffer(16));
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "Atomics.store(ta, 0, 12.7)"), "12");
+    assert_eq!(forward(&mut context, "Atomics.add(ta, 0, 3)"), "12");
+    assert_eq!(forward(&mut context, "Atomics.sub(ta, 0, 5)"), "15");
+    assert_eq!(forward(&mut context, "Atomics.and(ta, 0, 6)"), "10");
+    assert_eq!(forward(&mut context, "Atomics.or(ta, 0, 1)"), "2");
+    assert_eq!(forward(&mut context, "Atomics.xor(ta, 0, 7)"), "3");
+    assert_eq!(forward(&mut context, "Atomics.exchange(ta, 0, -1)"), "4");
+    assert_eq!(forward(&mut context, "Atomics.load(ta, 0)"), "-1");
+    assert_eq!(
+        forward(&mut context, "Atomics.compareExchange(ta, 0, 5, 6)"),
+        "-1"
+    );
+    assert_eq!(
+        forward(&mut context, "Atomics.compareExchange(ta, 0, -1, 6)"),
+        "-1"
+    );
+    assert_eq!(forward(&mut context, "ta[0]"), "6");
+}
+
+#[test]
+fn wrapping_and_bigint() {
+    let mut context = Context::default();
+
+    let init = r#"
+        var u8 = new Uint8Array(4);
+        var big = new BigInt64Array(new SharedArrayBuffer(16));
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "Atomics.add(u8, 1, 300)"), "0");
+    assert_eq!(forward(&mut context, "u8[1]"), "44");
+    assert_eq!(forward(&mut context, "Atomics.sub(u8, 2, 1)"), "0");
+    assert_eq!(forward(&mut context, "u8[2]"), "255");
+    assert_eq!(forward(&mut context, "Atomics.sub(big, 1, 1n)"), "0n");
+    assert_eq!(forward(&mut context, "Atomics.load(big, 1)"), "-1n");
+}
+
+#[test]
+fn validation() {
+    let mut context = Context::default();
+
+    let init = r#"
+        function error(f) {
+            try { f(); } catch (e) { return e.name; }
+        }
+        var ta = new Int32Array(new SharedArrayBuffer(8));
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "error(() => Atomics.load(new Float64Array(1), 0))"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "error(() => Atomics.load(new Uint8ClampedArray(1), 0))"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "error(() => Atomics.load(ta, 2))"),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "error(() => Atomics.wait(new Int32Array(2), 0, 0, 0))"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "error(() => Atomics.wait(new Int16Array(new SharedArrayBuffer(4)), 0, 0, 0))"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(forward(&mut context, "Atomics.isLockFree(4)"), "true");
+    assert_eq!(
+        forward(&mut context, "Atomics.notify(new Int32Array(2), 0)"),
+        "0"
+    );
+}
+
+#[test]
+fn wait_without_notify() {
+    let mut context = Context::default();
+
+    let init = r#"
+        var ta = new Int32Array(new SharedArrayBuffer(8));
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Atomics.wait(ta, 0, 1, 0)"),
+        "\"not-equal\""
+    );
+    assert_eq!(
+        forward(&mut context, "Atomics.wait(ta, 0, 0, 10)"),
+        "\"timed-out\""
+    );
+    assert_eq!(forward(&mut context, "Atomics.notify(ta, 0)"), "0");
+
+    context.set_can_block(false);
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Atomics.wait(ta, 0, 0, 0) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn wait_notify_across_agents() {
+    let mut context = Context::default();
+
+    let buffer = JsSharedArrayBuffer::new(8, &mut context).unwrap();
+    let block = buffer.data_block();
+    context.register_global_property("sab", buffer, crate::property::Attribute::all());
+
+    let waiter = thread::spawn(move || {
+        let mut context = Context::default();
+        let buffer = JsSharedArrayBuffer::from_data_block(block, &mut context);
+        context.register_global_property("sab", buffer, crate::property::Attribute::all());
+        forward(
+            &mut context,
+            r#"
+            var ta = new Int32Array(sab);
+            Atomics.store(ta, 1, 1);
+            Atomics.wait(ta, 0, 0)
+            "#,
+        )
+    });
+
+    forward(&mut context, "var ta = new Int32Array(sab);");
+
+    // Wait until the other agent is about to block.
+    while forward(&mut context, "Atomics.load(ta, 1)") != "1" {
+        thread::sleep(Duration::from_millis(1));
+    }
+
+    let mut notified = JsValue::from(0);
+    while notified == JsValue::from(0) {
+        thread::sleep(Duration::from_millis(1));
+        notified = context.eval("Atomics.notify(ta, 0, 1)").unwrap();
+    }
+
+    assert_eq!(notified, JsValue::from(1));
+    assert_eq!(waiter.join().unwrap(), "\"ok\"");
+}
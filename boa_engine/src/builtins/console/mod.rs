@@ -56,11 +56,12 @@ pub fn formatter(data: &[JsValue], context: &mut Context) -> JsResult<String> {
         .get(0)
         .cloned()
         .unwrap_or_default()
-        .to_string(context)?;
+        .to_string(context)?
+        .to_std_string_escaped();
 
     match data.len() {
         0 => Ok(String::new()),
-        1 => Ok(target.to_string()),
+        1 => Ok(target),
         _ => {
             let mut formatted = String::new();
             let mut arg_index = 1;
@@ -96,7 +97,8 @@ pub fn formatter(data: &[JsValue], context: &mut Context) -> JsResult<String> {
                                 .get(arg_index)
                                 .cloned()
                                 .unwrap_or_default()
-                                .to_string(context)?;
+                                .to_string(context)?
+                                .to_std_string_escaped();
                             formatted.push_str(&arg);
                             arg_index += 1;
                         }
@@ -293,18 +295,12 @@ impl Console {
     }
 
     fn get_stack_trace(context: &mut Context) -> Vec<String> {
-        let mut stack_trace: Vec<String> = vec![];
-
-        for frame in context.vm.frames.iter().rev() {
-            stack_trace.push(
-                context
-                    .interner()
-                    .resolve_expect(frame.code.name)
-                    .to_owned(),
-            );
-        }
-
-        stack_trace
+        context
+            .vm
+            .stack_trace(context.interner())
+            .into_iter()
+            .map(|frame| format!("    at {frame}"))
+            .collect()
     }
 
     /// `console.trace(...data)`
@@ -497,7 +493,7 @@ impl Console {
             None => "default".into(),
         };
 
-        if let Some(t) = context.console_mut().timer_map.remove(label.as_str()) {
+        if let Some(t) = context.console_mut().timer_map.remove(&label) {
             let time = Self::system_time_in_ms();
             logger(
                 LogMessage::Info(format!("{label}: {} ms - timer removed", time - t)),
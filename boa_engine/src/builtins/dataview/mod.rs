@@ -1,5 +1,7 @@
 use crate::{
-    builtins::{array_buffer::SharedMemoryOrder, typed_array::TypedArrayKind, BuiltIn, JsArgs},
+    builtins::{
+        array_buffer::SharedMemoryOrder, typed_array::TypedArrayKind, ArrayBuffer, BuiltIn, JsArgs,
+    },
     context::intrinsics::StandardConstructors,
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
@@ -8,7 +10,7 @@ use crate::{
     property::Attribute,
     symbol::WellKnownSymbols,
     value::JsValue,
-    Context, JsResult,
+    Context, JsNativeError, JsResult,
 };
 use boa_gc::{Finalize, Trace};
 use tap::{Conv, Pipe};
@@ -16,7 +18,9 @@ use tap::{Conv, Pipe};
 #[derive(Debug, Clone, Trace, Finalize)]
 pub struct DataView {
     pub(crate) viewed_array_buffer: JsObject,
-    pub(crate) byte_length: u64,
+    /// The `[[ByteLength]]` of the view, or `None` if it is `auto` and the view tracks the length
+    /// of a resizable buffer.
+    pub(crate) byte_length: Option<u64>,
     pub(crate) byte_offset: u64,
 }
 
@@ -100,82 +104,176 @@ impl DataView {
     ) -> JsResult<JsValue> {
         let byte_length = args.get_or_undefined(2);
 
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("new target is undefined")
+                .into());
+        }
+
+        // 2. Perform ? RequireInternalSlot(buffer, [[ArrayBufferData]]).
         let buffer_obj = args
             .get_or_undefined(0)
             .as_object()
-            .ok_or_else(|| context.construct_type_error("buffer must be an ArrayBuffer"))?;
+            .filter(|obj| obj.is_array_buffer())
+            .ok_or_else(|| JsNativeError::typ().with_message("buffer must be an ArrayBuffer"))?;
 
-        // 1. If NewTarget is undefined, throw a TypeError exception.
-        let (offset, view_byte_length) = {
-            if new_target.is_undefined() {
-                return context.throw_type_error("new target is undefined");
-            }
-            // 2. Perform ? RequireInternalSlot(buffer, [[ArrayBufferData]]).
+        // 3. Let offset be ? ToIndex(byteOffset).
+        let offset = args.get_or_undefined(1).to_index(context)?;
+
+        let (buffer_byte_length, buffer_is_fixed_length) = {
             let buffer_borrow = buffer_obj.borrow();
             let buffer = buffer_borrow
                 .as_array_buffer()
-                .ok_or_else(|| context.construct_type_error("buffer must be an ArrayBuffer"))?;
+                .expect("already checked that the buffer is an ArrayBuffer");
 
-            // 3. Let offset be ? ToIndex(byteOffset).
-            let offset = args.get_or_undefined(1).to_index(context)?;
             // 4. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
             if buffer.is_detached_buffer() {
-                return context.throw_type_error("ArrayBuffer is detached");
+                return Err(JsNativeError::typ()
+                    .with_message("ArrayBuffer is detached")
+                    .into());
             }
-            // 5. Let bufferByteLength be buffer.[[ArrayBufferByteLength]].
-            let buffer_byte_length = buffer.array_buffer_byte_length();
-            // 6. If offset > bufferByteLength, throw a RangeError exception.
-            if offset > buffer_byte_length {
-                return context
-                    .throw_range_error("Start offset is outside the bounds of the buffer");
+
+            // 5. Let bufferByteLength be ArrayBufferByteLength(buffer, seq-cst).
+            // 7. Let bufferIsFixedLength be IsFixedLengthArrayBuffer(buffer).
+            (buffer.array_buffer_byte_length(), buffer.is_fixed_length())
+        };
+
+        // 6. If offset > bufferByteLength, throw a RangeError exception.
+        if offset > buffer_byte_length {
+            return Err(JsNativeError::range()
+                .with_message("Start offset is outside the bounds of the buffer")
+                .into());
+        }
+
+        // 8. If byteLength is undefined, then
+        let view_byte_length = if byte_length.is_undefined() {
+            // a. If bufferIsFixedLength is true, then
+            //     i. Let viewByteLength be bufferByteLength - offset.
+            // b. Else,
+            //     i. Let viewByteLength be auto.
+            buffer_is_fixed_length.then(|| buffer_byte_length - offset)
+        // 9. Else,
+        } else {
+            // a. Let viewByteLength be ? ToIndex(byteLength).
+            let view_byte_length = byte_length.to_index(context)?;
+
+            // b. If offset + viewByteLength > bufferByteLength, throw a RangeError exception.
+            if offset + view_byte_length > buffer_byte_length {
+                return Err(JsNativeError::range()
+                    .with_message("Invalid data view length")
+                    .into());
             }
-            // 7. If byteLength is undefined, then
-            let view_byte_length = if byte_length.is_undefined() {
-                // a. Let viewByteLength be bufferByteLength - offset.
-                buffer_byte_length - offset
-            } else {
-                // 8.a. Let viewByteLength be ? ToIndex(byteLength).
-                let view_byte_length = byte_length.to_index(context)?;
-                // 8.b. If offset + viewByteLength > bufferByteLength, throw a RangeError exception.
-                if offset + view_byte_length > buffer_byte_length {
-                    return context.throw_range_error("Invalid data view length");
-                }
-
-                view_byte_length
-            };
-            (offset, view_byte_length)
+
+            Some(view_byte_length)
         };
 
-        // 9. Let O be ? OrdinaryCreateFromConstructor(NewTarget, "%DataView.prototype%", « [[DataView]], [[ViewedArrayBuffer]], [[ByteLength]], [[ByteOffset]] »).
+        // 10. Let O be ? OrdinaryCreateFromConstructor(NewTarget, "%DataView.prototype%", « [[DataView]], [[ViewedArrayBuffer]], [[ByteLength]], [[ByteOffset]] »).
         let prototype =
             get_prototype_from_constructor(new_target, StandardConstructors::data_view, context)?;
 
-        // 10. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
-        if buffer_obj
-            .borrow()
-            .as_array_buffer()
-            .ok_or_else(|| context.construct_type_error("buffer must be an ArrayBuffer"))?
-            .is_detached_buffer()
         {
-            return context.throw_type_error("ArrayBuffer can't be detached");
+            let buffer_borrow = buffer_obj.borrow();
+            let buffer = buffer_borrow
+                .as_array_buffer()
+                .expect("already checked that the buffer is an ArrayBuffer");
+
+            // 11. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
+            if buffer.is_detached_buffer() {
+                return Err(JsNativeError::typ()
+                    .with_message("ArrayBuffer can't be detached")
+                    .into());
+            }
+
+            // 12. Set bufferByteLength to ArrayBufferByteLength(buffer, seq-cst).
+            let buffer_byte_length = buffer.array_buffer_byte_length();
+
+            // 13. If offset > bufferByteLength, throw a RangeError exception.
+            if offset > buffer_byte_length {
+                return Err(JsNativeError::range()
+                    .with_message("Start offset is outside the bounds of the buffer")
+                    .into());
+            }
+
+            // 14. If byteLength is not undefined, then
+            //     a. If offset + viewByteLength > bufferByteLength, throw a RangeError exception.
+            if !byte_length.is_undefined()
+                && offset + view_byte_length.unwrap_or_default() > buffer_byte_length
+            {
+                return Err(JsNativeError::range()
+                    .with_message("Invalid data view length")
+                    .into());
+            }
         }
 
         let obj = JsObject::from_proto_and_data(
             prototype,
             ObjectData::data_view(Self {
-                // 11. Set O.[[ViewedArrayBuffer]] to buffer.
+                // 15. Set O.[[ViewedArrayBuffer]] to buffer.
                 viewed_array_buffer: buffer_obj.clone(),
-                // 12. Set O.[[ByteLength]] to viewByteLength.
+                // 16. Set O.[[ByteLength]] to viewByteLength.
                 byte_length: view_byte_length,
-                // 13. Set O.[[ByteOffset]] to offset.
+                // 17. Set O.[[ByteOffset]] to offset.
                 byte_offset: offset,
             }),
         );
 
-        // 14. Return O.
+        // 18. Return O.
         Ok(obj.into())
     }
 
+    /// `25.3.1.3 IsViewOutOfBounds ( viewRecord )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isviewoutofbounds
+    fn is_out_of_bounds(&self, buffer: &ArrayBuffer) -> bool {
+        // 1. Let view be viewRecord.[[Object]].
+        // 2. Let bufferByteLength be viewRecord.[[CachedBufferByteLength]].
+        // 3. Assert: IsDetachedBuffer(view.[[ViewedArrayBuffer]]) is true if and only if bufferByteLength is detached.
+        // 4. If bufferByteLength is detached, return true.
+        if buffer.is_detached_buffer() {
+            return true;
+        }
+        let buffer_byte_length = buffer.array_buffer_byte_length();
+
+        // 5. Let byteOffsetStart be view.[[ByteOffset]].
+        let byte_offset_start = self.byte_offset;
+
+        // 6. If view.[[ByteLength]] is auto, then
+        //     a. Let byteOffsetEnd be bufferByteLength.
+        // 7. Else,
+        //     a. Let byteOffsetEnd be byteOffsetStart + view.[[ByteLength]].
+        let byte_offset_end = self.byte_length.map_or(buffer_byte_length, |byte_length| {
+            byte_offset_start + byte_length
+        });
+
+        // 8. If byteOffsetStart > bufferByteLength or byteOffsetEnd > bufferByteLength, return true.
+        // 9. NOTE: 0-length DataViews are not considered out-of-bounds.
+        // 10. Return false.
+        byte_offset_start > buffer_byte_length || byte_offset_end > buffer_byte_length
+    }
+
+    /// `25.3.1.2 GetViewByteLength ( viewRecord )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getviewbytelength
+    fn view_byte_length(&self, buffer: &ArrayBuffer) -> u64 {
+        // 1. Assert: IsViewOutOfBounds(viewRecord) is false.
+        // 2. Let view be viewRecord.[[Object]].
+        // 3. If view.[[ByteLength]] is not auto, return view.[[ByteLength]].
+        // 4. Assert: IsFixedLengthArrayBuffer(view.[[ViewedArrayBuffer]]) is false.
+        // 5. Let byteOffset be view.[[ByteOffset]].
+        // 6. Let byteLength be viewRecord.[[CachedBufferByteLength]].
+        // 7. Assert: byteLength is not detached.
+        // 8. Return byteLength - byteOffset.
+        self.byte_length
+            .unwrap_or_else(|| buffer.array_buffer_byte_length() - self.byte_offset)
+    }
+
     /// `25.3.4.1 get DataView.prototype.buffer`
     ///
     /// The buffer accessor property represents the `ArrayBuffer` or `SharedArrayBuffer` referenced
@@ -190,7 +288,7 @@ impl DataView {
     pub(crate) fn get_buffer(
         this: &JsValue,
         _args: &[JsValue],
-        context: &mut Context,
+        _: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? RequireInternalSlot(O, [[DataView]]).
@@ -198,7 +296,7 @@ impl DataView {
         let dataview = dataview
             .as_ref()
             .and_then(|obj| obj.as_data_view())
-            .ok_or_else(|| context.construct_type_error("`this` is not a DataView"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a DataView"))?;
         // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
         // 4. Let buffer be O.[[ViewedArrayBuffer]].
         let buffer = dataview.viewed_array_buffer.clone();
@@ -219,7 +317,7 @@ impl DataView {
     pub(crate) fn get_byte_length(
         this: &JsValue,
         _args: &[JsValue],
-        context: &mut Context,
+        _: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? RequireInternalSlot(O, [[DataView]]).
@@ -227,20 +325,23 @@ impl DataView {
         let dataview = dataview
             .as_ref()
             .and_then(|obj| obj.as_data_view())
-            .ok_or_else(|| context.construct_type_error("`this` is not a DataView"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a DataView"))?;
         // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
         // 4. Let buffer be O.[[ViewedArrayBuffer]].
         let buffer_borrow = dataview.viewed_array_buffer.borrow();
         let borrow = buffer_borrow
             .as_array_buffer()
             .expect("DataView must be constructed with an ArrayBuffer");
-        // 5. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
-        if borrow.is_detached_buffer() {
-            return context.throw_type_error("ArrayBuffer is detached");
+        // 5. Let viewRecord be MakeDataViewWithBufferWitnessRecord(O, seq-cst).
+        // 6. If IsViewOutOfBounds(viewRecord) is true, throw a TypeError exception.
+        if dataview.is_out_of_bounds(borrow) {
+            return Err(JsNativeError::typ()
+                .with_message("DataView is out of bounds of its ArrayBuffer")
+                .into());
         }
-        // 6. Let size be O.[[ByteLength]].
-        let size = dataview.byte_length;
-        // 7. Return 𝔽(size).
+        // 7. Let size be GetViewByteLength(viewRecord).
+        let size = dataview.view_byte_length(borrow);
+        // 8. Return 𝔽(size).
         Ok(size.into())
     }
 
@@ -258,7 +359,7 @@ impl DataView {
     pub(crate) fn get_byte_offset(
         this: &JsValue,
         _args: &[JsValue],
-        context: &mut Context,
+        _: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? RequireInternalSlot(O, [[DataView]]).
@@ -266,20 +367,23 @@ impl DataView {
         let dataview = dataview
             .as_ref()
             .and_then(|obj| obj.as_data_view())
-            .ok_or_else(|| context.construct_type_error("`this` is not a DataView"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a DataView"))?;
         // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
         // 4. Let buffer be O.[[ViewedArrayBuffer]].
         let buffer_borrow = dataview.viewed_array_buffer.borrow();
         let borrow = buffer_borrow
             .as_array_buffer()
             .expect("DataView must be constructed with an ArrayBuffer");
-        // 5. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
-        if borrow.is_detached_buffer() {
-            return context.throw_type_error("ArrayBuffer is detached");
+        // 5. Let viewRecord be MakeDataViewWithBufferWitnessRecord(O, seq-cst).
+        // 6. If IsViewOutOfBounds(viewRecord) is true, throw a TypeError exception.
+        if dataview.is_out_of_bounds(borrow) {
+            return Err(JsNativeError::typ()
+                .with_message("DataView is out of bounds of its ArrayBuffer")
+                .into());
         }
-        // 6. Let offset be O.[[ByteOffset]].
+        // 7. Let offset be O.[[ByteOffset]].
         let offset = dataview.byte_offset;
-        // 7. Return 𝔽(offset).
+        // 8. Return 𝔽(offset).
         Ok(offset.into())
     }
 
@@ -306,7 +410,7 @@ impl DataView {
         let view = view
             .as_ref()
             .and_then(|obj| obj.as_data_view())
-            .ok_or_else(|| context.construct_type_error("`this` is not a DataView"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a DataView"))?;
         // 3. Let getIndex be ? ToIndex(requestIndex).
         let get_index = request_index.to_index(context)?;
 
@@ -322,20 +426,32 @@ impl DataView {
 
         // 6. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
         if buffer.is_detached_buffer() {
-            return context.throw_type_error("ArrayBuffer is detached");
+            return Err(JsNativeError::typ()
+                .with_message("ArrayBuffer is detached")
+                .into());
+        }
+
+        // If IsViewOutOfBounds(viewRecord) is true, throw a TypeError exception.
+        if view.is_out_of_bounds(buffer) {
+            return Err(JsNativeError::typ()
+                .with_message("DataView is out of bounds of its ArrayBuffer")
+                .into());
         }
+
         // 7. Let viewOffset be view.[[ByteOffset]].
         let view_offset = view.byte_offset;
 
-        // 8. Let viewSize be view.[[ByteLength]].
-        let view_size = view.byte_length;
+        // 8. Let viewSize be GetViewByteLength(viewRecord).
+        let view_size = view.view_byte_length(buffer);
 
         // 9. Let elementSize be the Element Size value specified in Table 72 for Element Type type.
         let element_size = t.element_size();
 
         // 10. If getIndex + elementSize > viewSize, throw a RangeError exception.
         if get_index + element_size > view_size {
-            return context.throw_range_error("Offset is outside the bounds of the DataView");
+            return Err(JsNativeError::range()
+                .with_message("Offset is outside the bounds of the DataView")
+                .into());
         }
 
         // 11. Let bufferIndex be getIndex + viewOffset.
@@ -665,7 +781,7 @@ impl DataView {
         let view = view
             .as_ref()
             .and_then(|obj| obj.as_data_view())
-            .ok_or_else(|| context.construct_type_error("`this` is not a DataView"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a DataView"))?;
         // 3. Let getIndex be ? ToIndex(requestIndex).
         let get_index = request_index.to_index(context)?;
 
@@ -688,21 +804,32 @@ impl DataView {
 
         // 8. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
         if buffer.is_detached_buffer() {
-            return context.throw_type_error("ArrayBuffer is detached");
+            return Err(JsNativeError::typ()
+                .with_message("ArrayBuffer is detached")
+                .into());
+        }
+
+        // If IsViewOutOfBounds(viewRecord) is true, throw a TypeError exception.
+        if view.is_out_of_bounds(buffer) {
+            return Err(JsNativeError::typ()
+                .with_message("DataView is out of bounds of its ArrayBuffer")
+                .into());
         }
 
         // 9. Let viewOffset be view.[[ByteOffset]].
         let view_offset = view.byte_offset;
 
-        // 10. Let viewSize be view.[[ByteLength]].
-        let view_size = view.byte_length;
+        // 10. Let viewSize be GetViewByteLength(viewRecord).
+        let view_size = view.view_byte_length(buffer);
 
         // 11. Let elementSize be the Element Size value specified in Table 72 for Element Type type.
         let element_size = t.element_size();
 
         // 12. If getIndex + elementSize > viewSize, throw a RangeError exception.
         if get_index + element_size > view_size {
-            return context.throw_range_error("Offset is outside the bounds of DataView");
+            return Err(JsNativeError::range()
+                .with_message("Offset is outside the bounds of DataView")
+                .into());
         }
 
         // 13. Let bufferIndex be getIndex + viewOffset.
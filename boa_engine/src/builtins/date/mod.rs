@@ -2,15 +2,18 @@
 mod tests;
 
 use super::JsArgs;
+#[cfg(feature = "intl")]
+use crate::builtins::intl::date_time_format::{format_date_time_to_locale_string, DateTimeReqs};
 use crate::{
     builtins::BuiltIn,
     context::intrinsics::StandardConstructors,
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
     },
+    string::utf16,
     symbol::WellKnownSymbols,
     value::{JsValue, PreferredType},
-    Context, JsResult, JsString,
+    Context, JsNativeError, JsResult, JsString,
 };
 use boa_profiler::Profiler;
 use chrono::{prelude::*, Duration, LocalResult};
@@ -47,8 +50,8 @@ fn ignore_ambiguity<T>(result: LocalResult<T>) -> Option<T> {
 
 macro_rules! getter_method {
     ($name:ident) => {{
-        fn get_value(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-            Ok(JsValue::new(this_time_value(this, context)?.$name()))
+        fn get_value(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+            Ok(JsValue::new(this_time_value(this)?.$name()))
         }
         get_value
     }};
@@ -61,7 +64,7 @@ macro_rules! getter_method {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Date(Option<NaiveDateTime>);
+pub struct Date(pub(crate) Option<NaiveDateTime>);
 
 impl Display for Date {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -135,6 +138,9 @@ impl BuiltIn for Date {
         .method(Self::to_iso_string, "toISOString", 0)
         .method(Self::to_json, "toJSON", 1)
         // Locale strings
+        .method(Self::to_locale_date_string, "toLocaleDateString", 0)
+        .method(Self::to_locale_string, "toLocaleString", 0)
+        .method(Self::to_locale_time_string, "toLocaleTimeString", 0)
         .method(Self::to_string, "toString", 0)
         .method(Self::to_time_string, "toTimeString", 0)
         .method(getter_method!(to_utc_string), "toUTCString", 0)
@@ -390,24 +396,25 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsObject> {
         let value = &args[0];
-        let tv = match this_time_value(value, context) {
-            Ok(dt) => dt.0,
-            _ => match value.to_primitive(context, PreferredType::Default)? {
-                JsValue::String(ref str) => match chrono::DateTime::parse_from_rfc3339(str) {
+        let tv = if let Ok(dt) = this_time_value(value) {
+            dt.0
+        } else {
+            let primitive = value.to_primitive(context, PreferredType::Default)?;
+            if let Some(str) = primitive.as_string() {
+                match chrono::DateTime::parse_from_rfc3339(&str.to_std_string_escaped()) {
                     Ok(dt) => Some(dt.naive_utc()),
                     _ => None,
-                },
-                tv => {
-                    let tv = tv.to_number(context)?;
-                    if tv.is_nan() {
-                        None
-                    } else {
-                        let secs = (tv / 1_000f64) as i64;
-                        let nano_secs = ((tv % 1_000f64) * 1_000_000f64) as u32;
-                        NaiveDateTime::from_timestamp_opt(secs, nano_secs)
-                    }
                 }
-            },
+            } else {
+                let tv = primitive.to_number(context)?;
+                if tv.is_nan() {
+                    None
+                } else {
+                    let secs = (tv / 1_000f64) as i64;
+                    let nano_secs = ((tv % 1_000f64) * 1_000_000f64) as u32;
+                    NaiveDateTime::from_timestamp_opt(secs, nano_secs)
+                }
+            }
         };
 
         let tv = tv.filter(|time| Self::time_clip(time.timestamp_millis() as f64).is_some());
@@ -507,22 +514,27 @@ impl Date {
         let o = if let Some(o) = this.as_object() {
             o
         } else {
-            return context.throw_type_error("Date.prototype[@@toPrimitive] called on non object");
+            return Err(JsNativeError::typ()
+                .with_message("Date.prototype[@@toPrimitive] called on non object")
+                .into());
         };
 
         let hint = args.get_or_undefined(0);
 
-        let try_first = match hint.as_string().map(JsString::as_str) {
+        let try_first = match hint.as_string() {
             // 3. If hint is "string" or "default", then
             // a. Let tryFirst be string.
-            Some("string" | "default") => PreferredType::String,
+            Some(string) if string == utf16!("string") || string == utf16!("default") => {
+                PreferredType::String
+            }
             // 4. Else if hint is "number", then
             // a. Let tryFirst be number.
-            Some("number") => PreferredType::Number,
+            Some(number) if number == utf16!("number") => PreferredType::Number,
             // 5. Else, throw a TypeError exception.
             _ => {
-                return context
-                    .throw_type_error("Date.prototype[@@toPrimitive] called with invalid hint")
+                return Err(JsNativeError::typ()
+                    .with_message("Date.prototype[@@toPrimitive] called with invalid hint")
+                    .into())
             }
         };
 
@@ -700,10 +712,10 @@ impl Date {
     pub fn get_timezone_offset(
         this: &JsValue,
         _: &[JsValue],
-        context: &mut Context,
+        _: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let t = this_time_value(this, context)?;
+        let t = this_time_value(this)?;
 
         // 2. If t is NaN, return NaN.
         if t.0.is_none() {
@@ -849,7 +861,7 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setDate
     pub fn set_date(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let t be LocalTime(? thisTimeValue(this value)).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let dt be ? ToNumber(date).
         let dt = args
@@ -888,7 +900,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. If t is NaN, set t to +0𝔽; otherwise, set t to LocalTime(t).
         if t.0.is_none() {
@@ -946,7 +958,7 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setHours
     pub fn set_hours(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let t be LocalTime(? thisTimeValue(this value)).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let h be ? ToNumber(hour).
         let h = args
@@ -1005,7 +1017,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be LocalTime(? thisTimeValue(this value)).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Set ms to ? ToNumber(ms).
         let ms = args
@@ -1043,7 +1055,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be LocalTime(? thisTimeValue(this value)).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let m be ? ToNumber(min).
         let m = args
@@ -1091,7 +1103,7 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setMonth
     pub fn set_month(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let t be LocalTime(? thisTimeValue(this value)).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let m be ? ToNumber(month).
         let m = args
@@ -1136,7 +1148,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be LocalTime(? thisTimeValue(this value)).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let s be ? ToNumber(sec).
         let s = args
@@ -1177,7 +1189,7 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setYear
     pub fn set_year(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. If t is NaN, set t to +0𝔽; otherwise, set t to LocalTime(t).
         if t.0.is_none() {
@@ -1234,7 +1246,7 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setTime
     pub fn set_time(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Perform ? thisTimeValue(this value).
-        this_time_value(this, context)?;
+        this_time_value(this)?;
 
         // 2. Let t be ? ToNumber(time).
         let t = if let Some(t) = args.get(0) {
@@ -1275,7 +1287,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let dt be ? ToNumber(date).
         let dt = args
@@ -1314,7 +1326,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. If t is NaN, set t to +0𝔽.
         if t.0.is_none() {
@@ -1376,7 +1388,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let h be ? ToNumber(hour).
         let h = args
@@ -1435,7 +1447,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let milli be ? ToNumber(ms).
         let ms = args
@@ -1473,7 +1485,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let m be ? ToNumber(min).
         let m = args
@@ -1529,7 +1541,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let m be ? ToNumber(month).
         let m = args
@@ -1576,7 +1588,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let s be ? ToNumber(sec).
         let s = args
@@ -1618,14 +1630,10 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.todatestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toDateString
     #[allow(clippy::wrong_self_convention)]
-    pub fn to_date_string(
-        this: &JsValue,
-        _: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
+    pub fn to_date_string(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         // 1. Let O be this Date object.
         // 2. Let tv be ? thisTimeValue(O).
-        let tv = this_time_value(this, context)?;
+        let tv = this_time_value(this)?;
 
         // 3. If tv is NaN, return "Invalid Date".
         // 4. Let t be LocalTime(tv).
@@ -1669,12 +1677,8 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.toisostring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toISOString
     #[allow(clippy::wrong_self_convention)]
-    pub fn to_iso_string(
-        this: &JsValue,
-        _: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
-        if let Some(t) = this_time_value(this, context)?.0 {
+    pub fn to_iso_string(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        if let Some(t) = this_time_value(this)?.0 {
             Ok(Utc::now()
                 .timezone()
                 .from_utc_datetime(&t)
@@ -1682,7 +1686,9 @@ impl Date {
                 .to_string()
                 .into())
         } else {
-            context.throw_range_error("Invalid time value")
+            Err(JsNativeError::range()
+                .with_message("Invalid time value")
+                .into())
         }
     }
 
@@ -1716,6 +1722,148 @@ impl Date {
         context.call(&func, &o.into(), &[])
     }
 
+    /// `Date.prototype.toLocaleDateString( [locales [, options]] )`
+    ///
+    /// The `toLocaleDateString()` method returns a string with a language-sensitive representation
+    /// of the date portion of this date.
+    ///
+    /// Without the `intl` feature, this returns the same string as `toDateString()`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sup-date.prototype.tolocaledatestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleDateString
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn to_locale_date_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        #[cfg(feature = "intl")]
+        {
+            // 3. Let options be ? ToDateTimeOptions(options, "date", "date").
+            Self::format_to_locale_string(
+                this,
+                args,
+                &DateTimeReqs::Date,
+                &DateTimeReqs::Date,
+                context,
+            )
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            Self::to_date_string(this, args, context)
+        }
+    }
+
+    /// `Date.prototype.toLocaleString( [locales [, options]] )`
+    ///
+    /// The `toLocaleString()` method returns a string with a language-sensitive representation of
+    /// this date.
+    ///
+    /// Without the `intl` feature, this returns the same string as `toString()`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sup-date.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleString
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn to_locale_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        #[cfg(feature = "intl")]
+        {
+            // 3. Let options be ? ToDateTimeOptions(options, "any", "all").
+            Self::format_to_locale_string(
+                this,
+                args,
+                &DateTimeReqs::AnyAll,
+                &DateTimeReqs::AnyAll,
+                context,
+            )
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            Self::to_string(this, args, context)
+        }
+    }
+
+    /// `Date.prototype.toLocaleTimeString( [locales [, options]] )`
+    ///
+    /// The `toLocaleTimeString()` method returns a string with a language-sensitive representation
+    /// of the time portion of this date.
+    ///
+    /// Without the `intl` feature, this returns the same string as `toTimeString()`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sup-date.prototype.tolocaletimestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleTimeString
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn to_locale_time_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        #[cfg(feature = "intl")]
+        {
+            // 3. Let options be ? ToDateTimeOptions(options, "time", "time").
+            Self::format_to_locale_string(
+                this,
+                args,
+                &DateTimeReqs::Time,
+                &DateTimeReqs::Time,
+                context,
+            )
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            Self::to_time_string(this, args, context)
+        }
+    }
+
+    /// The common steps of the `toLocaleString`, `toLocaleDateString` and `toLocaleTimeString`
+    /// methods, which format this date with a new `Intl.DateTimeFormat`.
+    #[cfg(feature = "intl")]
+    fn format_to_locale_string(
+        this: &JsValue,
+        args: &[JsValue],
+        required: &DateTimeReqs,
+        defaults: &DateTimeReqs,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let x be ? thisTimeValue(this value).
+        let x = this_time_value(this)?.get_time();
+
+        // 2. If x is NaN, return "Invalid Date".
+        if x.is_nan() {
+            return Ok(JsString::from("Invalid Date").into());
+        }
+
+        // 3. Let options be ? ToDateTimeOptions(options, required, defaults).
+        // 4. Let dateFormat be ? Construct(%DateTimeFormat%, « locales, options »).
+        // 5. Return ? FormatDateTime(dateFormat, x).
+        format_date_time_to_locale_string(
+            x,
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            required,
+            defaults,
+            context,
+        )
+    }
+
     /// `Date.prototype.toString()`
     ///
     /// The toString() method returns a string representing the specified Date object.
@@ -1727,9 +1875,9 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tostring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toString
     #[allow(clippy::wrong_self_convention)]
-    pub fn to_string(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    pub fn to_string(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         // 1. Let tv be ? thisTimeValue(this value).
-        let tv = this_time_value(this, context)?;
+        let tv = this_time_value(this)?;
 
         // 2. Return ToDateString(tv).
         if let Some(t) = tv.0 {
@@ -1756,14 +1904,10 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.totimestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toTimeString
     #[allow(clippy::wrong_self_convention)]
-    pub fn to_time_string(
-        this: &JsValue,
-        _: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
+    pub fn to_time_string(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         // 1. Let O be this Date object.
         // 2. Let tv be ? thisTimeValue(O).
-        let tv = this_time_value(this, context)?;
+        let tv = this_time_value(this)?;
 
         // 3. If tv is NaN, return "Invalid Date".
         // 4. Let t be LocalTime(tv).
@@ -1846,7 +1990,7 @@ impl Date {
             return Ok(JsValue::nan());
         }
 
-        match DateTime::parse_from_rfc3339(&args[0].to_string(context)?) {
+        match DateTime::parse_from_rfc3339(&args[0].to_string(context)?.to_std_string_escaped()) {
             Ok(v) => Ok(JsValue::new(v.naive_utc().timestamp_millis() as f64)),
             _ => Ok(JsValue::new(f64::NAN)),
         }
@@ -1923,9 +2067,13 @@ impl Date {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-thistimevalue
 #[inline]
-pub fn this_time_value(value: &JsValue, context: &mut Context) -> JsResult<Date> {
+pub fn this_time_value(value: &JsValue) -> JsResult<Date> {
     value
         .as_object()
         .and_then(|obj| obj.borrow().as_date().copied())
-        .ok_or_else(|| context.construct_type_error("'this' is not a Date"))
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("'this' is not a Date")
+                .into()
+        })
 }
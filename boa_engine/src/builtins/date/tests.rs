@@ -1,6 +1,6 @@
 #![allow(clippy::zero_prefixed_literal)]
 
-use crate::{forward, forward_val, Context, JsValue};
+use crate::{forward, forward_val, Context, JsValue, JsVariant};
 use chrono::prelude::*;
 
 // NOTE: Javascript Uses 0-based months, where chrono uses 1-based months. Many of the assertions look wrong because of
@@ -13,7 +13,7 @@ fn forward_dt_utc(context: &mut Context, src: &str) -> Option<NaiveDateTime> {
         panic!("expected success")
     };
 
-    if let JsValue::Object(ref date_time) = date_time {
+    if let JsVariant::Object(date_time) = date_time.variant() {
         if let Some(date_time) = date_time.borrow().as_date() {
             date_time.0
         } else {
@@ -59,7 +59,8 @@ fn date_this_time_value() {
         &mut context,
         "({toString: Date.prototype.toString}).toString()",
     )
-    .expect_err("Expected error");
+    .expect_err("Expected error")
+    .to_opaque(&mut context);
     let message_property = &error
         .get_property("message")
         .expect("Expected 'message' property")
@@ -23,7 +23,7 @@ use crate::{
 use boa_profiler::Profiler;
 use tap::{Conv, Pipe};
 
-use super::Error;
+use super::{Error, ErrorKind};
 
 /// JavaScript `EvalError` implementation.
 #[derive(Debug, Clone, Copy)]
@@ -70,7 +70,7 @@ impl EvalError {
         // 2. Let O be ? OrdinaryCreateFromConstructor(newTarget, "%NativeError.prototype%", « [[ErrorData]] »).
         let prototype =
             get_prototype_from_constructor(new_target, StandardConstructors::eval_error, context)?;
-        let o = JsObject::from_proto_and_data(prototype, ObjectData::error());
+        let o = JsObject::from_proto_and_data(prototype, ObjectData::error(ErrorKind::Eval));
 
         // 3. If message is not undefined, then
         let message = args.get_or_undefined(0);
@@ -85,6 +85,9 @@ impl EvalError {
         // 4. Perform ? InstallErrorCause(O, options).
         Error::install_error_cause(&o, args.get_or_undefined(1), context)?;
 
+        // Capture the call stack, which is not part of the specification.
+        Error::install_error_stack(&o, context)?;
+
         // 5. Return O.
         Ok(o.into())
     }
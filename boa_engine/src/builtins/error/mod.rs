@@ -17,7 +17,7 @@ use crate::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
     },
     property::Attribute,
-    Context, JsResult, JsString, JsValue,
+    Context, JsNativeError, JsResult, JsString, JsValue,
 };
 use boa_profiler::Profiler;
 use tap::{Conv, Pipe};
@@ -43,6 +43,43 @@ pub(crate) use self::uri::UriError;
 
 use super::JsArgs;
 
+/// The kind of a native error object.
+///
+/// This is stored in the internal `[[ErrorData]]` slot of the error objects, so that the
+/// kind of error is known even if the prototype or the `name` property are changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The `AggregateError` object type.
+    Aggregate,
+
+    /// The `Error` object type.
+    Error,
+
+    /// The `EvalError` object type.
+    Eval,
+
+    /// The `TypeError` object type.
+    Type,
+
+    /// The `RangeError` object type.
+    Range,
+
+    /// The `ReferenceError` object type.
+    Reference,
+
+    /// The `SyntaxError` object type.
+    Syntax,
+
+    /// The `URIError` object type.
+    Uri,
+
+    /// The `RangeError` thrown when a script is terminated by a runtime limit of the context.
+    ///
+    /// Scripts see it as a `RangeError`, but the host can still tell it apart from the errors
+    /// thrown by the script with [`JsError::is_termination`][crate::JsError::is_termination].
+    Termination,
+}
+
 /// Built-in `Error` object.
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Error;
@@ -94,6 +131,27 @@ impl Error {
         Ok(())
     }
 
+    /// Defines the non-standard `stack` property of a newly created error object.
+    ///
+    /// The stack starts with the string representation of the error, followed by a line
+    /// for each frame of the call stack, e.g. `    at add (main.js:2:5)`.
+    pub(crate) fn install_error_stack(o: &JsObject, context: &mut Context) -> JsResult<()> {
+        let header = Self::to_string(&o.clone().into(), &[], context)?.to_string(context)?;
+        Self::define_error_stack(o, &header.to_std_string_escaped(), context);
+        Ok(())
+    }
+
+    /// Defines the `stack` property of an error object, starting with the given header.
+    pub(crate) fn define_error_stack(o: &JsObject, header: &str, context: &mut Context) {
+        let mut stack = String::from(header);
+        for frame in context.vm.stack_trace(context.interner()) {
+            stack.push_str("\n    at ");
+            stack.push_str(&frame);
+        }
+
+        o.create_non_enumerable_data_property_or_throw("stack", stack, context);
+    }
+
     /// `Error( message [ , options ] )`
     ///
     /// Create a new error object.
@@ -107,7 +165,7 @@ impl Error {
         // 2. Let O be ? OrdinaryCreateFromConstructor(newTarget, "%Error.prototype%", « [[ErrorData]] »).
         let prototype =
             get_prototype_from_constructor(new_target, StandardConstructors::error, context)?;
-        let o = JsObject::from_proto_and_data(prototype, ObjectData::error());
+        let o = JsObject::from_proto_and_data(prototype, ObjectData::error(ErrorKind::Error));
 
         // 3. If message is not undefined, then
         let message = args.get_or_undefined(0);
@@ -122,6 +180,9 @@ impl Error {
         // 4. Perform ? InstallErrorCause(O, options).
         Self::install_error_cause(&o, args.get_or_undefined(1), context)?;
 
+        // Capture the call stack, which is not part of the specification.
+        Self::install_error_stack(&o, context)?;
+
         // 5. Return O.
         Ok(o.into())
     }
@@ -147,7 +208,9 @@ impl Error {
             o
         // 2. If Type(O) is not Object, throw a TypeError exception.
         } else {
-            return context.throw_type_error("'this' is not an Object");
+            return Err(JsNativeError::typ()
+                .with_message("'this' is not an Object")
+                .into());
         };
 
         // 3. Let name be ? Get(O, "name").
@@ -21,7 +21,7 @@ use crate::{
 use boa_profiler::Profiler;
 use tap::{Conv, Pipe};
 
-use super::Error;
+use super::{Error, ErrorKind};
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct ReferenceError;
@@ -74,7 +74,7 @@ impl ReferenceError {
             StandardConstructors::reference_error,
             context,
         )?;
-        let o = JsObject::from_proto_and_data(prototype, ObjectData::error());
+        let o = JsObject::from_proto_and_data(prototype, ObjectData::error(ErrorKind::Reference));
 
         // 3. If message is not undefined, then
         let message = args.get_or_undefined(0);
@@ -89,6 +89,9 @@ impl ReferenceError {
         // 4. Perform ? InstallErrorCause(O, options).
         Error::install_error_cause(&o, args.get_or_undefined(1), context)?;
 
+        // Capture the call stack, which is not part of the specification.
+        Error::install_error_stack(&o, context)?;
+
         // 5. Return O.
         Ok(o.into())
     }
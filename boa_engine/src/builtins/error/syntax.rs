@@ -23,7 +23,7 @@ use crate::{
 use boa_profiler::Profiler;
 use tap::{Conv, Pipe};
 
-use super::Error;
+use super::{Error, ErrorKind};
 
 /// JavaScript `SyntaxError` implementation.
 #[derive(Debug, Clone, Copy)]
@@ -73,7 +73,7 @@ impl SyntaxError {
             StandardConstructors::syntax_error,
             context,
         )?;
-        let o = JsObject::from_proto_and_data(prototype, ObjectData::error());
+        let o = JsObject::from_proto_and_data(prototype, ObjectData::error(ErrorKind::Syntax));
 
         // 3. If message is not undefined, then
         let message = args.get_or_undefined(0);
@@ -88,6 +88,9 @@ impl SyntaxError {
         // 4. Perform ? InstallErrorCause(O, options).
         Error::install_error_cause(&o, args.get_or_undefined(1), context)?;
 
+        // Capture the call stack, which is not part of the specification.
+        Error::install_error_stack(&o, context)?;
+
         // 5. Return O.
         Ok(o.into())
     }
@@ -1,4 +1,4 @@
-use crate::{forward, Context};
+use crate::{forward, Context, JsError, JsNativeError, JsNativeErrorKind, JsValue};
 
 #[test]
 fn error_to_string() {
@@ -84,3 +84,93 @@ fn uri_error_to_string() {
         "\"URIError\""
     );
 }
+
+#[test]
+fn error_stack() {
+    let mut context = Context::default();
+    let init = r"
+        function inner() {
+            return null.prop;
+        }
+        function outer() {
+            return inner();
+        }
+        try {
+            outer();
+        } catch (e) {
+            globalThis.stack = e.stack;
+        }
+    ";
+    context
+        .eval_with_source_name(init, "test.js")
+        .expect("script should run");
+    assert_eq!(
+        forward(&mut context, "stack"),
+        "\"TypeError: cannot convert 'null' or 'undefined' to object\n    \
+         at inner (test.js:3:13)\n    \
+         at outer (test.js:6:13)\n    \
+         at <main> (test.js:9:13)\""
+    );
+    assert_eq!(
+        forward(&mut context, "new RangeError('x').stack"),
+        "\"RangeError: x\n    at <main> (<anonymous>:1:1)\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(new Error(), 'stack').enumerable"
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn native_error_to_opaque() {
+    let mut context = Context::default();
+    let error = JsNativeError::range()
+        .with_message("out of range")
+        .with_cause(JsError::from_opaque(JsValue::new(5)))
+        .to_opaque(&mut context);
+    context
+        .global_object()
+        .clone()
+        .set("e", error, false, &mut context)
+        .expect("setting a global should not fail");
+    assert_eq!(forward(&mut context, "e instanceof RangeError"), "true");
+    assert_eq!(forward(&mut context, "e.message"), "\"out of range\"");
+    assert_eq!(forward(&mut context, "e.cause"), "5");
+    assert_eq!(
+        forward(&mut context, "e.propertyIsEnumerable('message')"),
+        "false"
+    );
+}
+
+#[test]
+fn try_native_error() {
+    let mut context = Context::default();
+
+    let error = context.eval("undefined.x").unwrap_err();
+    let native = error.try_native(&mut context).unwrap();
+    assert!(matches!(native.kind, JsNativeErrorKind::Type));
+
+    let error = context
+        .eval("throw new SyntaxError('bad', { cause: 1 })")
+        .unwrap_err();
+    let native = error.try_native(&mut context).unwrap();
+    assert!(matches!(native.kind, JsNativeErrorKind::Syntax));
+    assert_eq!(native.message(), "bad");
+    assert_eq!(native.cause(), Some(&JsError::from_opaque(JsValue::new(1))));
+
+    let error = context
+        .eval("throw new AggregateError([1, 2], 'many')")
+        .unwrap_err();
+    let native = error.try_native(&mut context).unwrap();
+    assert!(matches!(
+        native.kind,
+        JsNativeErrorKind::Aggregate(ref errors) if errors.len() == 2
+    ));
+
+    let error = context.eval("throw 'a string'").unwrap_err();
+    assert_eq!(error.as_opaque(), Some(&JsValue::new("a string")));
+    assert!(error.try_native(&mut context).is_err());
+}
@@ -122,7 +122,7 @@ impl Arguments {
         )
         .expect("Defining new own properties for a new ordinary object cannot fail");
 
-        let throw_type_error = context.intrinsics().objects().throw_type_error();
+        let throw_type_error = context.intrinsics().objects().throw_type_error_function();
 
         // 8. Perform ! DefinePropertyOrThrow(obj, "callee", PropertyDescriptor {
         // [[Get]]: %ThrowTypeError%, [[Set]]: %ThrowTypeError%, [[Enumerable]]: false,
@@ -22,23 +22,25 @@ use crate::{
     },
     object::{ConstructorBuilder, FunctionBuilder, JsFunction, PrivateElement, Ref, RefMut},
     property::{Attribute, PropertyDescriptor, PropertyKey},
+    realm::Realm,
+    string::utf16,
     symbol::WellKnownSymbols,
     syntax::{
         ast::node::{FormalParameterList, StatementList},
         Parser,
     },
     value::IntegerOrInfinity,
-    Context, JsResult, JsString, JsValue,
+    Context, JsNativeError, JsResult, JsString, JsValue,
 };
 use boa_gc::{self, custom_trace, Finalize, Gc, Trace};
 use boa_interner::Sym;
 use boa_profiler::Profiler;
-use dyn_clone::DynClone;
 use std::{
     any::Any,
     borrow::Cow,
     fmt,
     ops::{Deref, DerefMut},
+    rc::Rc,
 };
 use tap::{Conv, Pipe};
 
@@ -62,34 +64,32 @@ mod tests;
 /// - The last argument is the [`Context`] of the engine.
 pub type NativeFunctionSignature = fn(&JsValue, &[JsValue], &mut Context) -> JsResult<JsValue>;
 
-// Allows restricting closures to only `Copy` ones.
-// Used the sealed pattern to disallow external implementations
-// of `DynCopy`.
-mod sealed {
-    pub trait Sealed {}
-    impl<T: Copy> Sealed for T {}
-}
-pub trait DynCopy: sealed::Sealed {}
-impl<T: Copy> DynCopy for T {}
-
 /// Trait representing a native built-in closure.
 ///
 /// Closures need to have this signature in order to
 /// be callable from Javascript, but most of the time the compiler
 /// is smart enough to correctly infer the types.
+///
+/// Values owned by the closure itself are not visited by the garbage collector, and dropping
+/// a garbage collected value while the function object is being collected is undefined
+/// behaviour. Because of this, the safe constructors of closure functions only accept `Copy`
+/// closures, which cannot own garbage collected values, and `JsValue`s needed by the closure
+/// are passed through the [`Captures`] of the function, which are traced like any other
+/// object. Closures that own non-`Copy` Rust state, like an `Rc<RefCell<T>>` or a channel
+/// sender, can be created with [`FunctionBuilder::closure_with_state`][with_state], which
+/// stores the state in the captures too.
+///
+/// [with_state]: crate::object::FunctionBuilder::closure_with_state
 pub trait ClosureFunctionSignature:
-    Fn(&JsValue, &[JsValue], Captures, &mut Context) -> JsResult<JsValue> + DynCopy + DynClone + 'static
+    Fn(&JsValue, &[JsValue], Captures, &mut Context) -> JsResult<JsValue> + 'static
 {
 }
 
 impl<T> ClosureFunctionSignature for T where
-    T: Fn(&JsValue, &[JsValue], Captures, &mut Context) -> JsResult<JsValue> + Copy + 'static
+    T: Fn(&JsValue, &[JsValue], Captures, &mut Context) -> JsResult<JsValue> + 'static
 {
 }
 
-// Allows cloning Box<dyn ClosureFunctionSignature>
-dyn_clone::clone_trait_object!(ClosureFunctionSignature);
-
 #[derive(Debug, Trace, Finalize, PartialEq, Eq, Clone)]
 pub enum ThisMode {
     Lexical,
@@ -164,7 +164,7 @@ unsafe impl Trace for ClassFieldDefinition {
 }
 
 /// Wrapper for `Gc<GcCell<dyn NativeObject>>` that allows passing additional
-/// captures through a `Copy` closure.
+/// garbage collected captures to a closure.
 ///
 /// Any type implementing `Trace + Any + Debug`
 /// can be used as a capture context, so you can pass e.g. a String,
@@ -215,16 +215,25 @@ pub enum Function {
     Native {
         function: NativeFunctionSignature,
         constructor: Option<ConstructorKind>,
+
+        /// The `[[Realm]]` internal slot.
+        realm: Realm,
     },
     Closure {
-        function: Box<dyn ClosureFunctionSignature>,
+        function: Rc<dyn ClosureFunctionSignature>,
         constructor: Option<ConstructorKind>,
         captures: Captures,
+
+        /// The `[[Realm]]` internal slot.
+        realm: Realm,
     },
     Ordinary {
         code: Gc<crate::vm::CodeBlock>,
         environments: DeclarativeEnvironmentStack,
 
+        /// The `[[Realm]]` internal slot.
+        realm: Realm,
+
         /// The `[[ConstructorKind]]` internal slot.
         constructor_kind: ConstructorKind,
 
@@ -241,23 +250,29 @@ pub enum Function {
         code: Gc<crate::vm::CodeBlock>,
         environments: DeclarativeEnvironmentStack,
         promise_capability: PromiseCapability,
+        realm: Realm,
     },
     Generator {
         code: Gc<crate::vm::CodeBlock>,
         environments: DeclarativeEnvironmentStack,
+        realm: Realm,
     },
     AsyncGenerator {
         code: Gc<crate::vm::CodeBlock>,
         environments: DeclarativeEnvironmentStack,
+        realm: Realm,
     },
 }
 
 unsafe impl Trace for Function {
     custom_trace! {this, {
         match this {
-            Self::Native { .. } => {}
-            Self::Closure { captures, .. } => mark(captures),
-            Self::Ordinary { code, environments, home_object, fields, private_methods, .. } => {
+            Self::Native { realm, .. } => mark(realm),
+            Self::Closure { captures, realm, .. } => {
+                mark(captures);
+                mark(realm);
+            }
+            Self::Ordinary { code, environments, home_object, fields, private_methods, realm, .. } => {
                 mark(code);
                 mark(environments);
                 mark(home_object);
@@ -265,16 +280,19 @@ unsafe impl Trace for Function {
                 for (_, elem) in private_methods {
                     mark(elem);
                 }
+                mark(realm);
             }
-            Self::Async { code, environments, promise_capability } => {
+            Self::Async { code, environments, promise_capability, realm } => {
                 mark(code);
                 mark(environments);
                 mark(promise_capability);
+                mark(realm);
             }
-            Self::Generator { code, environments }
-            | Self::AsyncGenerator { code, environments } => {
+            Self::Generator { code, environments, realm }
+            | Self::AsyncGenerator { code, environments, realm } => {
                 mark(code);
                 mark(environments);
+                mark(realm);
             }
         }
     }}
@@ -310,6 +328,18 @@ impl Function {
         }
     }
 
+    /// Returns the `[[Realm]]` internal slot of the function.
+    pub(crate) fn realm(&self) -> &Realm {
+        match self {
+            Self::Native { realm, .. }
+            | Self::Closure { realm, .. }
+            | Self::Ordinary { realm, .. }
+            | Self::Async { realm, .. }
+            | Self::Generator { realm, .. }
+            | Self::AsyncGenerator { realm, .. } => realm,
+        }
+    }
+
     /// Returns a reference to the function `[[HomeObject]]` slot if present.
     pub(crate) fn get_home_object(&self) -> Option<&JsObject> {
         if let Self::Ordinary { home_object, .. } = self {
@@ -424,6 +454,7 @@ pub(crate) fn make_builtin_fn<N>(
         ObjectData::function(Function::Native {
             function,
             constructor: None,
+            realm: interpreter.realm().clone(),
         }),
     );
     let attribute = PropertyDescriptor::builder()
@@ -498,7 +529,7 @@ impl BuiltInFunctionObject {
             } else {
                 let mut parameters = Vec::with_capacity(args.len());
                 for arg in args {
-                    parameters.push(arg.to_string(context)?);
+                    parameters.push(arg.to_string(context)?.to_std_string_escaped());
                 }
                 let mut parameters = parameters.join(",");
                 parameters.push(')');
@@ -510,16 +541,14 @@ impl BuiltInFunctionObject {
                 ) {
                     Ok(parameters) => parameters,
                     Err(e) => {
-                        return context.throw_syntax_error(format!(
-                            "failed to parse function parameters: {e}"
-                        ))
+                        return Err(JsNativeError::syntax()
+                            .with_message(format!("failed to parse function parameters: {e}"))
+                            .into())
                     }
                 };
 
                 if generator && parameters.contains_yield_expression() {
-                    return context.throw_syntax_error(
-                            "yield expression is not allowed in formal parameter list of generator function",
-                        );
+                    return Err(JsNativeError::syntax().with_message("yield expression is not allowed in formal parameter list of generator function").into());
                 }
 
                 parameters
@@ -527,19 +556,19 @@ impl BuiltInFunctionObject {
 
             // It is a Syntax Error if FormalParameters Contains YieldExpression is true.
             if generator && r#async && parameters.contains_yield_expression() {
-                return context.throw_syntax_error(
-                    "yield expression not allowed in async generator parameters",
-                );
+                return Err(JsNativeError::syntax()
+                    .with_message("yield expression not allowed in async generator parameters")
+                    .into());
             }
 
             // It is a Syntax Error if FormalParameters Contains AwaitExpression is true.
             if generator && r#async && parameters.contains_await_expression() {
-                return context.throw_syntax_error(
-                    "await expression not allowed in async generator parameters",
-                );
+                return Err(JsNativeError::syntax()
+                    .with_message("await expression not allowed in async generator parameters")
+                    .into());
             }
 
-            let body_arg = body_arg.to_string(context)?;
+            let body_arg = body_arg.to_string(context)?.to_std_string_escaped();
 
             let body = match Parser::new(body_arg.as_bytes()).parse_function_body(
                 context.interner_mut(),
@@ -548,8 +577,9 @@ impl BuiltInFunctionObject {
             ) {
                 Ok(statement_list) => statement_list,
                 Err(e) => {
-                    return context
-                        .throw_syntax_error(format!("failed to parse function body: {e}"))
+                    return Err(JsNativeError::syntax()
+                        .with_message(format!("failed to parse function body: {e}"))
+                        .into())
                 }
             };
 
@@ -559,9 +589,9 @@ impl BuiltInFunctionObject {
                 for parameter in parameters.parameters.iter() {
                     for name in parameter.names() {
                         if name == Sym::ARGUMENTS || name == Sym::EVAL {
-                            return context.throw_syntax_error(
-                                " Unexpected 'eval' or 'arguments' in strict mode",
-                            );
+                            return Err(JsNativeError::syntax()
+                                .with_message(" Unexpected 'eval' or 'arguments' in strict mode")
+                                .into());
                         }
                     }
                 }
@@ -570,16 +600,19 @@ impl BuiltInFunctionObject {
             // Early Error: If the source code matching FormalParameters is strict mode code,
             // the Early Error rules for UniqueFormalParameters : FormalParameters are applied.
             if (body.strict()) && parameters.has_duplicates() {
-                return context
-                    .throw_syntax_error("Duplicate parameter name not allowed in this context");
+                return Err(JsNativeError::syntax()
+                    .with_message("Duplicate parameter name not allowed in this context")
+                    .into());
             }
 
             // Early Error: It is a Syntax Error if FunctionBodyContainsUseStrict of GeneratorBody is true
             // and IsSimpleParameterList of FormalParameters is false.
             if body.strict() && !parameters.is_simple() {
-                return context.throw_syntax_error(
-                    "Illegal 'use strict' directive in function with non-simple parameter list",
-                );
+                return Err(JsNativeError::syntax()
+                    .with_message(
+                        "Illegal 'use strict' directive in function with non-simple parameter list",
+                    )
+                    .into());
             }
 
             // It is a Syntax Error if any element of the BoundNames of FormalParameters
@@ -593,10 +626,12 @@ impl BuiltInFunctionObject {
                             .iter()
                             .any(|(name, _)| *name == param_name)
                         {
-                            return context.throw_syntax_error(format!(
-                                "Redeclaration of formal parameter `{}`",
-                                context.interner().resolve_expect(param_name)
-                            ));
+                            return Err(JsNativeError::syntax()
+                                .with_message(format!(
+                                    "Redeclaration of formal parameter `{}`",
+                                    context.interner().resolve_expect(param_name)
+                                ))
+                                .into());
                         }
                     }
                 }
@@ -609,7 +644,7 @@ impl BuiltInFunctionObject {
                 .kind(FunctionKind::Expression)
                 .compile(&parameters, &body, context)?;
 
-            let environments = context.realm.environments.pop_to_global();
+            let environments = context.vm.environments.pop_to_global();
 
             let function_object = if generator {
                 crate::vm::create_generator_function_object(code, r#async, context)
@@ -617,7 +652,7 @@ impl BuiltInFunctionObject {
                 crate::vm::create_function_object(code, r#async, Some(prototype), context)
             };
 
-            context.realm.environments.extend(environments);
+            context.vm.environments.extend(environments);
 
             Ok(function_object)
         } else if generator {
@@ -631,10 +666,10 @@ impl BuiltInFunctionObject {
                     context,
                 )?;
 
-            let environments = context.realm.environments.pop_to_global();
+            let environments = context.vm.environments.pop_to_global();
             let function_object =
                 crate::vm::create_generator_function_object(code, r#async, context);
-            context.realm.environments.extend(environments);
+            context.vm.environments.extend(environments);
 
             Ok(function_object)
         } else {
@@ -647,10 +682,10 @@ impl BuiltInFunctionObject {
                     context,
                 )?;
 
-            let environments = context.realm.environments.pop_to_global();
+            let environments = context.vm.environments.pop_to_global();
             let function_object =
                 crate::vm::create_function_object(code, r#async, Some(prototype), context);
-            context.realm.environments.extend(environments);
+            context.vm.environments.extend(environments);
 
             Ok(function_object)
         }
@@ -671,7 +706,7 @@ impl BuiltInFunctionObject {
         // 1. Let func be the this value.
         // 2. If IsCallable(func) is false, throw a TypeError exception.
         let func = this.as_callable().ok_or_else(|| {
-            context.construct_type_error(format!("{} is not a function", this.display()))
+            JsNativeError::typ().with_message(format!("{} is not a function", this.display()))
         })?;
 
         let this_arg = args.get_or_undefined(0);
@@ -711,7 +746,8 @@ impl BuiltInFunctionObject {
         // 1. Let Target be the this value.
         // 2. If IsCallable(Target) is false, throw a TypeError exception.
         let target = this.as_callable().ok_or_else(|| {
-            context.construct_type_error("cannot bind `this` without a `[[Call]]` internal method")
+            JsNativeError::typ()
+                .with_message("cannot bind `this` without a `[[Call]]` internal method")
         })?;
 
         let this_arg = args.get_or_undefined(0).clone();
@@ -792,7 +828,7 @@ impl BuiltInFunctionObject {
         // 1. Let func be the this value.
         // 2. If IsCallable(func) is false, throw a TypeError exception.
         let func = this.as_callable().ok_or_else(|| {
-            context.construct_type_error(format!("{} is not a function", this.display()))
+            JsNativeError::typ().with_message(format!("{} is not a function", this.display()))
         })?;
         let this_arg = args.get_or_undefined(0);
 
@@ -809,7 +845,7 @@ impl BuiltInFunctionObject {
         let function = object
             .as_deref()
             .and_then(Object::as_function)
-            .ok_or_else(|| context.construct_type_error("Not a function"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("Not a function"))?;
 
         let name = {
             // Is there a case here where if there is no name field on a value
@@ -826,13 +862,9 @@ impl BuiltInFunctionObject {
         };
 
         match (function, name) {
-            (
-                Function::Native {
-                    function: _,
-                    constructor: _,
-                },
-                Some(name),
-            ) => Ok(format!("function {name}() {{\n  [native Code]\n}}").into()),
+            (Function::Native { .. }, Some(name)) => {
+                Ok(format!("function {name}() {{\n  [native Code]\n}}").into())
+            }
             (Function::Ordinary { .. }, Some(name)) if name.is_empty() => {
                 Ok("[Function (anonymous)]".into())
             }
@@ -885,7 +917,7 @@ impl BuiltIn for BuiltInFunctionObject {
             .constructor(false)
             .build();
 
-        let throw_type_error = context.intrinsics().objects().throw_type_error();
+        let throw_type_error = context.intrinsics().objects().throw_type_error_function();
 
         ConstructorBuilder::with_standard_constructor(
             context,
@@ -927,7 +959,7 @@ impl BuiltIn for BuiltInFunctionObject {
 ///  - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-setfunctionname
-fn set_function_name(
+pub(crate) fn set_function_name(
     function: &JsObject,
     name: &PropertyKey,
     prefix: Option<&str>,
@@ -940,7 +972,7 @@ fn set_function_name(
             // a. Let description be name's [[Description]] value.
             if let Some(desc) = sym.description() {
                 // c. Else, set name to the string-concatenation of "[", description, and "]".
-                Cow::Owned(JsString::concat_array(&["[", &desc, "]"]))
+                Cow::Owned(JsString::concat_array(&[utf16!("["), &desc, utf16!("]")]))
             } else {
                 // b. If description is undefined, set name to the empty String.
                 Cow::Owned(JsString::new(""))
@@ -960,7 +992,11 @@ fn set_function_name(
 
     // 5. If prefix is present, then
     if let Some(prefix) = prefix {
-        name = Cow::Owned(JsString::concat_array(&[prefix, " ", &name]));
+        name = Cow::Owned(JsString::concat_array(&[
+            &JsString::from(prefix),
+            utf16!(" "),
+            &name,
+        ]));
         // b. If F has an [[InitialName]] internal slot, then
         // i. Optionally, set F.[[InitialName]] to name.
         // todo: implement [[InitialName]] for builtins
@@ -1029,6 +1065,15 @@ impl BoundFunction {
         ))
     }
 
+    /// Creates the internal slots of a bound function, without creating the object.
+    pub(crate) fn new(target_function: JsObject, this: JsValue, args: Vec<JsValue>) -> Self {
+        Self {
+            target_function,
+            this,
+            args,
+        }
+    }
+
     /// Get a reference to the bound function's this.
     pub fn this(&self) -> &JsValue {
         &self.this
@@ -1,9 +1,13 @@
 use crate::{
+    builtins::JsArgs,
     forward, forward_val,
     object::FunctionBuilder,
     property::{Attribute, PropertyDescriptor},
-    Context, JsString,
+    string::utf16,
+    Context, JsNativeError, JsString, JsValue,
 };
+use boa_gc::WeakGc;
+use std::{cell::RefCell, rc::Rc, sync::mpsc};
 
 #[allow(clippy::float_cmp)]
 #[test]
@@ -139,10 +143,12 @@ fn function_prototype_call_throw() {
         let call = Function.prototype.call;
         call(call)
         "#;
-    let value = forward_val(&mut context, throw).unwrap_err();
+    let value = forward_val(&mut context, throw)
+        .unwrap_err()
+        .to_opaque(&mut context);
     assert!(value.is_object());
     let string = value.to_string(&mut context).unwrap();
-    assert!(string.starts_with("TypeError"));
+    assert!(string.starts_with(utf16!("TypeError")));
 }
 
 #[test]
@@ -243,11 +249,11 @@ fn closure_capture_clone() {
 
             let hw = JsString::concat(
                 string,
-                object
+                &object
                     .__get_own_property__(&"key".into(), context)?
                     .and_then(|prop| prop.value().cloned())
                     .and_then(|val| val.as_string().cloned())
-                    .ok_or_else(|| context.construct_type_error("invalid `key` property"))?,
+                    .ok_or_else(|| JsNativeError::typ().with_message("invalid `key` property"))?,
             );
             Ok(hw.into())
         },
@@ -260,3 +266,104 @@ fn closure_capture_clone() {
 
     assert_eq!(forward(&mut context, "closure()"), "\"Hello world!\"");
 }
+
+#[test]
+fn closure_owned_state() {
+    let mut context = Context::default();
+
+    let counter = Rc::new(RefCell::new(0));
+    let (sender, receiver) = mpsc::channel();
+
+    context
+        .register_global_closure_with_state(
+            "increment",
+            1,
+            |_, args, (counter, sender), context| {
+                let step = args.get_or_undefined(0).to_i32(context)?;
+                *counter.borrow_mut() += step;
+                sender
+                    .send(*counter.borrow())
+                    .expect("receiver should be alive");
+                Ok(JsValue::new(*counter.borrow()))
+            },
+            (counter.clone(), sender),
+        )
+        .unwrap();
+
+    assert_eq!(forward(&mut context, "increment(2); increment(3)"), "5");
+    assert_eq!(*counter.borrow(), 5);
+    assert_eq!(receiver.try_iter().collect::<Vec<_>>(), vec![2, 5]);
+}
+
+#[test]
+fn closure_captures_are_traced() {
+    let mut context = Context::default();
+
+    let object = context.construct_object();
+    object
+        .set("value", 42, false, &mut context)
+        .expect("setting a property should not fail");
+
+    let func = FunctionBuilder::closure_with_captures(
+        &mut context,
+        |_, _, object, context| {
+            let value = object.get("value", context)?;
+            Ok(JsString::concat_array(&[utf16!("captured "), &value.to_string(context)?]).into())
+        },
+        object,
+    )
+    .name("closure")
+    .build();
+    context.register_global_property("closure", func, Attribute::default());
+
+    boa_gc::force_collect();
+
+    assert_eq!(forward(&mut context, "closure()"), "\"captured 42\"");
+}
+
+#[test]
+fn closure_state_is_traced() {
+    let mut context = Context::default();
+
+    let object = context.construct_object();
+    object
+        .set("value", 42, false, &mut context)
+        .expect("setting a property should not fail");
+
+    context
+        .register_global_closure_with_state(
+            "closure",
+            0,
+            |_, _, objects, context| objects.borrow()[0].get("value", context),
+            Rc::new(boa_gc::Cell::new(vec![object])),
+        )
+        .unwrap();
+
+    boa_gc::force_collect();
+
+    assert_eq!(forward(&mut context, "closure()"), "42");
+}
+
+#[test]
+fn closure_capturing_object_is_collected() {
+    let mut context = Context::default();
+
+    let object = context.construct_object();
+    let func = FunctionBuilder::closure_with_captures(
+        &mut context,
+        |_, _, object, _| Ok(object.clone().into()),
+        object,
+    )
+    .build();
+    let weak = WeakGc::new(func.inner());
+
+    let captured = func
+        .call(&JsValue::undefined(), &[], &mut context)
+        .expect("calling the closure should not fail");
+    assert!(captured.is_object());
+
+    // Collecting the function drops its captures while the heap is being swept.
+    drop((func, captured));
+    boa_gc::force_collect();
+    assert!(!weak.is_alive());
+}
@@ -17,7 +17,7 @@ use crate::{
     symbol::WellKnownSymbols,
     value::JsValue,
     vm::{CallFrame, GeneratorResumeKind, ReturnType},
-    Context, JsResult,
+    Context, JsError, JsNativeError, JsResult,
 };
 use boa_gc::{Cell, Finalize, Gc, Trace};
 use boa_profiler::Profiler;
@@ -151,7 +151,9 @@ impl Generator {
             Some(obj) if obj.is_generator() => {
                 Self::generator_resume(obj, args.get_or_undefined(0), context)
             }
-            _ => context.throw_type_error("Generator.prototype.next called on non generator"),
+            _ => Err(JsNativeError::typ()
+                .with_message("Generator.prototype.next called on non generator")
+                .into()),
         }
     }
 
@@ -195,7 +197,11 @@ impl Generator {
         // 1. Let g be the this value.
         // 2. Let C be ThrowCompletion(exception).
         // 3. Return ? GeneratorResumeAbrupt(g, C, empty).
-        Self::generator_resume_abrupt(this, Err(args.get_or_undefined(0).clone()), context)
+        Self::generator_resume_abrupt(
+            this,
+            Err(JsError::from_opaque(args.get_or_undefined(0).clone())),
+            context,
+        )
     }
 
     /// `27.5.3.3 GeneratorResume ( generator, value, generatorBrand )`
@@ -212,12 +218,14 @@ impl Generator {
         // 1. Let state be ? GeneratorValidate(generator, generatorBrand).
         let mut generator_obj_mut = generator_obj.borrow_mut();
         let generator = generator_obj_mut.as_generator_mut().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ().with_message("generator resumed on non generator object")
         })?;
         let state = generator.state;
 
         if state == GeneratorState::Executing {
-            return Err(context.construct_type_error("Generator should not be executing"));
+            return Err(JsNativeError::typ()
+                .with_message("Generator should not be executing")
+                .into());
         }
 
         // 2. If state is completed, return CreateIterResultObject(undefined, true).
@@ -249,8 +257,9 @@ impl Generator {
         let mut generator_context = generator_context_cell.borrow_mut();
         drop(generator_obj_mut);
 
+        context.debugger_suspend_frame();
         std::mem::swap(
-            &mut context.realm.environments,
+            &mut context.vm.environments,
             &mut generator_context.environments,
         );
         std::mem::swap(&mut context.vm.stack, &mut generator_context.stack);
@@ -268,7 +277,7 @@ impl Generator {
             .pop_frame()
             .expect("generator call frame must exist");
         std::mem::swap(
-            &mut context.realm.environments,
+            &mut context.vm.environments,
             &mut generator_context.environments,
         );
         std::mem::swap(&mut context.vm.stack, &mut generator_context.stack);
@@ -314,16 +323,18 @@ impl Generator {
     ) -> JsResult<JsValue> {
         // 1. Let state be ? GeneratorValidate(generator, generatorBrand).
         let generator_obj = this.as_object().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ().with_message("generator resumed on non generator object")
         })?;
         let mut generator_obj_mut = generator_obj.borrow_mut();
         let generator = generator_obj_mut.as_generator_mut().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ().with_message("generator resumed on non generator object")
         })?;
         let mut state = generator.state;
 
         if state == GeneratorState::Executing {
-            return Err(context.construct_type_error("Generator should not be executing"));
+            return Err(JsNativeError::typ()
+                .with_message("Generator should not be executing")
+                .into());
         }
 
         // 2. If state is suspendedStart, then
@@ -365,8 +376,9 @@ impl Generator {
         generator.state = GeneratorState::Executing;
         drop(generator_obj_mut);
 
+        context.debugger_suspend_frame();
         std::mem::swap(
-            &mut context.realm.environments,
+            &mut context.vm.environments,
             &mut generator_context.environments,
         );
         std::mem::swap(&mut context.vm.stack, &mut generator_context.stack);
@@ -379,6 +391,7 @@ impl Generator {
                 context.run()
             }
             Err(value) => {
+                let value = value.to_opaque(context);
                 context.vm.push(value);
                 context.vm.frame_mut().generator_resume_kind = GeneratorResumeKind::Throw;
                 context.run()
@@ -389,7 +402,7 @@ impl Generator {
             .pop_frame()
             .expect("generator call frame must exist");
         std::mem::swap(
-            &mut context.realm.environments,
+            &mut context.vm.environments,
             &mut generator_context.environments,
         );
         std::mem::swap(&mut context.vm.stack, &mut generator_context.stack);
@@ -0,0 +1,888 @@
+//! The date and time formatting abstract operations of `Intl.DateTimeFormat`.
+//!
+//! Dates are formatted from the CLDR patterns and symbols of the ICU data provider. The pattern
+//! of a `DateTimeFormat` is the available format of its locale that best matches the requested
+//! components, with its field widths adjusted to the requested ones.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma402/#sec-datetime-format-functions
+
+use super::{DatePatterns, DateTimeFormat, HourCycle, Style};
+use crate::{JsError, JsNativeError, JsResult};
+use chrono::{Datelike, Duration, NaiveDateTime, Offset, TimeZone, Timelike};
+use chrono_tz::{OffsetName, Tz};
+
+/// The width of a date and time component, in the order used to compare widths in
+/// `BasicFormatMatcher`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) enum Width {
+    TwoDigit,
+    Numeric,
+    Narrow,
+    Short,
+    Long,
+}
+
+impl Width {
+    const ALL: [Self; 5] = [
+        Self::TwoDigit,
+        Self::Numeric,
+        Self::Narrow,
+        Self::Short,
+        Self::Long,
+    ];
+
+    /// Gets the width with the option value `name`.
+    pub(super) fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|width| width.name() == name)
+    }
+
+    /// Gets the option value of the width.
+    pub(super) fn name(self) -> &'static str {
+        match self {
+            Self::TwoDigit => "2-digit",
+            Self::Numeric => "numeric",
+            Self::Narrow => "narrow",
+            Self::Short => "short",
+            Self::Long => "long",
+        }
+    }
+
+    /// Checks if the width is one of a text component.
+    fn is_text(self) -> bool {
+        matches!(self, Self::Narrow | Self::Short | Self::Long)
+    }
+}
+
+/// A date and time component of Table 4.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#table-datetimeformat-components
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Component {
+    Weekday,
+    Era,
+    Year,
+    Month,
+    Day,
+    DayPeriod,
+    Hour,
+    Minute,
+    Second,
+    FractionalSecondDigits,
+    TimeZoneName,
+}
+
+impl Component {
+    /// The components, in table order.
+    pub(super) const ALL: [Self; 11] = [
+        Self::Weekday,
+        Self::Era,
+        Self::Year,
+        Self::Month,
+        Self::Day,
+        Self::DayPeriod,
+        Self::Hour,
+        Self::Minute,
+        Self::Second,
+        Self::FractionalSecondDigits,
+        Self::TimeZoneName,
+    ];
+
+    /// Gets the name of the option and of the resolved option of the component.
+    pub(super) fn property(self) -> &'static str {
+        match self {
+            Self::Weekday => "weekday",
+            Self::Era => "era",
+            Self::Year => "year",
+            Self::Month => "month",
+            Self::Day => "day",
+            Self::DayPeriod => "dayPeriod",
+            Self::Hour => "hour",
+            Self::Minute => "minute",
+            Self::Second => "second",
+            Self::FractionalSecondDigits => "fractionalSecondDigits",
+            Self::TimeZoneName => "timeZoneName",
+        }
+    }
+
+    /// Gets the allowed values of the option of the component, which is a number option for
+    /// `fractionalSecondDigits`.
+    pub(super) fn values(self) -> &'static [&'static str] {
+        match self {
+            Self::Weekday | Self::Era | Self::DayPeriod => &["narrow", "short", "long"],
+            Self::Year | Self::Day | Self::Hour | Self::Minute | Self::Second => {
+                &["2-digit", "numeric"]
+            }
+            Self::Month => &["2-digit", "numeric", "narrow", "short", "long"],
+            Self::FractionalSecondDigits => &[],
+            Self::TimeZoneName => &["short", "long"],
+        }
+    }
+
+    /// Checks if the component is a date component, as opposed to a time component.
+    fn is_date(self) -> bool {
+        matches!(
+            self,
+            Self::Weekday | Self::Era | Self::Year | Self::Month | Self::Day
+        )
+    }
+
+    /// Gets the pattern field that displays the component with the value `value`.
+    fn field(self, value: u8) -> PatternItem {
+        let symbol = match self {
+            Self::Weekday => 'E',
+            Self::Era => 'G',
+            Self::Year => 'y',
+            Self::Month => 'M',
+            Self::Day => 'd',
+            Self::DayPeriod => 'B',
+            Self::Hour => 'h',
+            Self::Minute => 'm',
+            Self::Second => 's',
+            Self::FractionalSecondDigits => return PatternItem::Field('S', usize::from(value)),
+            Self::TimeZoneName => 'z',
+        };
+        PatternItem::Field(symbol, field_length(symbol, Width::ALL[usize::from(value)]))
+    }
+}
+
+/// The values of the date and time components of a format or of the options of a
+/// `DateTimeFormat`.
+///
+/// The value of a component is the index of its width in [`Width::ALL`], or its number of digits
+/// for `fractionalSecondDigits`, so that the values of a component are ordered.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(super) struct Components([Option<u8>; 11]);
+
+impl Components {
+    /// Gets the value of `component`.
+    pub(super) fn get(&self, component: Component) -> Option<u8> {
+        self.0[component as usize]
+    }
+
+    /// Sets the value of `component`.
+    pub(super) fn set(&mut self, component: Component, value: Option<u8>) {
+        self.0[component as usize] = value;
+    }
+
+    /// Gets the width of `component`, which is `None` for `fractionalSecondDigits`.
+    pub(super) fn width(&self, component: Component) -> Option<Width> {
+        if component == Component::FractionalSecondDigits {
+            return None;
+        }
+        self.get(component)
+            .map(|value| Width::ALL[usize::from(value)])
+    }
+
+    /// Checks if there's a value for any of the components that satisfy `predicate`.
+    fn any(&self, predicate: impl Fn(Component) -> bool) -> bool {
+        Component::ALL
+            .into_iter()
+            .any(|component| predicate(component) && self.get(component).is_some())
+    }
+
+    /// Gets the components that satisfy `predicate`.
+    fn filter(&self, predicate: impl Fn(Component) -> bool) -> Self {
+        let mut components = Self::default();
+        for component in Component::ALL {
+            if predicate(component) {
+                components.set(component, self.get(component));
+            }
+        }
+        components
+    }
+}
+
+/// An item of a CLDR date and time pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum PatternItem {
+    /// A field, with its symbol and its length.
+    Field(char, usize),
+    /// A literal text.
+    Literal(String),
+}
+
+/// Parses a CLDR date and time pattern, where runs of the same letter are fields and quoted text
+/// is literal.
+pub(super) fn parse_pattern(pattern: &str) -> Vec<PatternItem> {
+    let mut items = Vec::new();
+    let mut literal = String::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            if chars.next_if_eq(&'\'').is_some() {
+                literal.push('\'');
+                continue;
+            }
+            while let Some(c) = chars.next() {
+                match c {
+                    '\'' if chars.next_if_eq(&'\'').is_some() => literal.push('\''),
+                    '\'' => break,
+                    // The patterns of the ICU data escape quotes with a backslash.
+                    '\\' if chars.next_if_eq(&'\'').is_some() => literal.push('\''),
+                    c => literal.push(c),
+                }
+            }
+        } else if c.is_ascii_alphabetic() {
+            let mut length = 1;
+            while chars.next_if_eq(&c).is_some() {
+                length += 1;
+            }
+            if !literal.is_empty() {
+                items.push(PatternItem::Literal(std::mem::take(&mut literal)));
+            }
+            items.push(PatternItem::Field(c, length));
+        } else {
+            literal.push(c);
+        }
+    }
+    if !literal.is_empty() {
+        items.push(PatternItem::Literal(literal));
+    }
+    items
+}
+
+/// Gets the component and its value displayed by the field `symbol` of length `length`, or
+/// `Err` if the field isn't supported.
+///
+/// The `a` field of the AM/PM markers doesn't display a component.
+fn field_component(symbol: char, length: usize) -> Result<Option<(Component, u8)>, ()> {
+    let text = |length: usize| match length {
+        4 => Width::Long,
+        5 => Width::Narrow,
+        _ => Width::Short,
+    };
+    let numeric = |length: usize| {
+        if length == 2 {
+            Width::TwoDigit
+        } else {
+            Width::Numeric
+        }
+    };
+    let (component, width) = match symbol {
+        'a' => return Ok(None),
+        'S' => {
+            return Ok(Some((
+                Component::FractionalSecondDigits,
+                length.min(3) as u8,
+            )))
+        }
+        'G' => (Component::Era, text(length)),
+        'y' => (Component::Year, numeric(length)),
+        'M' | 'L' if length <= 2 => (Component::Month, numeric(length)),
+        'M' | 'L' => (Component::Month, text(length)),
+        'd' => (Component::Day, numeric(length)),
+        'E' => (Component::Weekday, text(length)),
+        'c' if length >= 3 => (Component::Weekday, text(length)),
+        'B' => (Component::DayPeriod, text(length)),
+        'h' | 'H' | 'K' | 'k' => (Component::Hour, numeric(length)),
+        'm' => (Component::Minute, numeric(length)),
+        's' => (Component::Second, numeric(length)),
+        'z' | 'v' if length < 4 => (Component::TimeZoneName, Width::Short),
+        'z' | 'v' => (Component::TimeZoneName, Width::Long),
+        _ => return Err(()),
+    };
+    Ok(Some((component, width as u8)))
+}
+
+/// Gets the components displayed by `pattern`, or `None` if it has unsupported fields.
+pub(super) fn pattern_components(pattern: &[PatternItem]) -> Option<Components> {
+    let mut components = Components::default();
+    for item in pattern {
+        if let PatternItem::Field(symbol, length) = *item {
+            if let Some((component, value)) = field_component(symbol, length).ok()? {
+                components.set(component, Some(value));
+            }
+        }
+    }
+    Some(components)
+}
+
+/// Gets the length of the field `symbol` for the width `width`.
+fn field_length(symbol: char, width: Width) -> usize {
+    match width {
+        Width::Numeric => 1,
+        Width::TwoDigit => 2,
+        Width::Short if matches!(symbol, 'z' | 'v') => 1,
+        Width::Short => 3,
+        Width::Long => 4,
+        Width::Narrow => 5,
+    }
+}
+
+/// Gets the symbol of the hour field of `pattern`, if there's one.
+fn hour_symbol(pattern: &[PatternItem]) -> Option<char> {
+    pattern.iter().find_map(|item| match *item {
+        PatternItem::Field(symbol @ ('h' | 'H' | 'K' | 'k'), _) => Some(symbol),
+        _ => None,
+    })
+}
+
+/// Sets the symbol of the hour fields of `pattern` to the one of `hour_cycle`.
+fn set_hour_cycle(pattern: &mut [PatternItem], hour_cycle: HourCycle) {
+    for item in pattern {
+        if let PatternItem::Field(symbol @ ('h' | 'H' | 'K' | 'k'), _) = item {
+            *symbol = hour_cycle.symbol();
+        }
+    }
+}
+
+/// Combines a date pattern and a time pattern with the pattern `combination`, where `{1}` is
+/// the date and `{0}` is the time.
+fn combine(combination: &str, date: Vec<PatternItem>, time: Vec<PatternItem>) -> Vec<PatternItem> {
+    let mut pattern = Vec::new();
+    let mut date = Some(date);
+    let mut time = Some(time);
+    let mut rest = combination;
+    loop {
+        let (index, items) = match (rest.find("{0}"), rest.find("{1}")) {
+            (Some(t), Some(d)) if d < t => (d, date.take()),
+            (Some(t), _) => (t, time.take()),
+            (None, Some(d)) => (d, date.take()),
+            (None, None) => {
+                pattern.extend(parse_pattern(rest));
+                return merge_literals(pattern);
+            }
+        };
+        pattern.extend(parse_pattern(&rest[..index]));
+        pattern.extend(items.into_iter().flatten());
+        rest = &rest[index + 3..];
+    }
+}
+
+/// Merges the adjacent literals of `pattern`.
+fn merge_literals(pattern: Vec<PatternItem>) -> Vec<PatternItem> {
+    let mut merged: Vec<PatternItem> = Vec::with_capacity(pattern.len());
+    for item in pattern {
+        match (merged.last_mut(), item) {
+            (Some(PatternItem::Literal(last)), PatternItem::Literal(literal)) => {
+                last.push_str(&literal);
+            }
+            (_, item) => merged.push(item),
+        }
+    }
+    merged
+}
+
+/// The penalties of `BasicFormatMatcher`.
+const REMOVAL_PENALTY: i32 = 120;
+const ADDITION_PENALTY: i32 = 20;
+const LONG_LESS_PENALTY: i32 = 8;
+const LONG_MORE_PENALTY: i32 = 6;
+const SHORT_LESS_PENALTY: i32 = 6;
+const SHORT_MORE_PENALTY: i32 = 3;
+
+/// Abstract operation `BasicFormatMatcher ( options, formats )`
+///
+/// Returns the format with the best score, and whether it displays all the requested
+/// components.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-basicformatmatcher
+fn basic_format_matcher<'a>(
+    options: &Components,
+    formats: &'a [(Components, Vec<PatternItem>)],
+) -> Option<(&'a [PatternItem], bool)> {
+    // 7. Let bestScore be -Infinity.
+    // 8. Let bestFormat be undefined.
+    let mut best: Option<(i32, &'a (Components, Vec<PatternItem>))> = None;
+
+    // 9. Assert: formats is a List.
+    // 10. For each element format of formats, do
+    for format in formats {
+        // a. Let score be 0.
+        let mut score = 0;
+
+        // b. For each property name property shown in Table 6, do
+        for component in Component::ALL {
+            match (options.get(component), format.0.get(component)) {
+                // iii. If optionsProp is undefined and formatProp is not undefined, decrease score by additionPenalty.
+                (None, Some(_)) => score -= ADDITION_PENALTY,
+                // iv. Else if optionsProp is not undefined and formatProp is undefined, decrease score by removalPenalty.
+                (Some(_), None) => score -= REMOVAL_PENALTY,
+                // v. Else if optionsProp ≠ formatProp, then
+                (Some(requested), Some(value)) if requested != value => {
+                    // 3. Let delta be max(min(formatPropIndex - optionsPropIndex, 2), -2).
+                    // 4-7. Decrease score by the penalty of delta.
+                    score -= match (i32::from(value) - i32::from(requested)).clamp(-2, 2) {
+                        2 => LONG_MORE_PENALTY,
+                        1 => SHORT_MORE_PENALTY,
+                        -1 => SHORT_LESS_PENALTY,
+                        _ => LONG_LESS_PENALTY,
+                    };
+                }
+                _ => {}
+            }
+        }
+
+        // c. If score > bestScore, then
+        //     i. Let bestScore be score.
+        //     ii. Let bestFormat be format.
+        if best.map_or(true, |(best_score, _)| score > best_score) {
+            best = Some((score, format));
+        }
+    }
+
+    // 11. Return bestFormat.
+    best.map(|(_, (components, pattern))| {
+        let complete = !options.any(|component| components.get(component).is_none());
+        (pattern.as_slice(), complete)
+    })
+}
+
+impl DatePatterns {
+    /// Abstract operation `DateTimeStyleFormat ( dateStyle, timeStyle, styles )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-date-time-style-format
+    pub(super) fn style_pattern(
+        &self,
+        date_style: Option<Style>,
+        time_style: Option<Style>,
+        hour_cycle: HourCycle,
+    ) -> Vec<PatternItem> {
+        let time_patterns = if hour_cycle.is_12_hour() {
+            &self.time_12
+        } else {
+            &self.time_24
+        };
+        let date = date_style.map(|style| parse_pattern(&self.date[style as usize]));
+        let time = time_style.map(|style| parse_pattern(&time_patterns[style as usize]));
+
+        let mut pattern = match (date_style, date, time) {
+            (Some(style), Some(date), Some(time)) => {
+                combine(&self.combinations[style as usize], date, time)
+            }
+            (_, date, time) => date.or(time).unwrap_or_default(),
+        };
+        set_hour_cycle(&mut pattern, hour_cycle);
+        pattern
+    }
+
+    /// Gets the pattern of the available format that best matches `options`, with its fields
+    /// adjusted to the requested widths.
+    ///
+    /// This is the best fit format matcher, which combines a date format and a time format if
+    /// no single available format displays all the requested components.
+    pub(super) fn best_fit_pattern(
+        &self,
+        options: &Components,
+        hour_cycle: HourCycle,
+    ) -> Vec<PatternItem> {
+        let formats = self
+            .available
+            .iter()
+            .map(|pattern| parse_pattern(pattern))
+            .filter(|pattern| {
+                hour_symbol(pattern).map_or(true, |symbol| {
+                    matches!(symbol, 'h' | 'K') == hour_cycle.is_12_hour()
+                })
+            })
+            .filter_map(|pattern| Some((pattern_components(&pattern)?, pattern)))
+            .collect::<Vec<_>>();
+        let best = |options: &Components| {
+            basic_format_matcher(options, &formats)
+                .map_or((Vec::new(), false), |(pattern, complete)| {
+                    (pattern.to_vec(), complete)
+                })
+        };
+
+        let (mut pattern, complete) = best(options);
+        if !complete
+            && options.any(Component::is_date)
+            && options.any(|component| !component.is_date())
+        {
+            let (date, _) = best(&options.filter(Component::is_date));
+            let (time, _) = best(&options.filter(|component| !component.is_date()));
+            let style = match (
+                options.width(Component::Month),
+                options.get(Component::Weekday),
+            ) {
+                (Some(Width::Long), Some(_)) => Style::Full,
+                (Some(Width::Long), None) => Style::Long,
+                (Some(Width::Short | Width::Narrow), _) => Style::Medium,
+                _ => Style::Short,
+            };
+            pattern = combine(&self.combinations[style as usize], date, time);
+        }
+
+        adjust_field_widths(&mut pattern, options);
+        append_missing_fields(&mut pattern, options);
+        set_hour_cycle(&mut pattern, hour_cycle);
+        merge_literals(pattern)
+    }
+}
+
+/// Adjusts the widths of the fields of `pattern` to the widths requested by `options`, if they
+/// are both text widths or both numeric widths.
+fn adjust_field_widths(pattern: &mut [PatternItem], options: &Components) {
+    for item in pattern {
+        let (symbol, length) = match item {
+            PatternItem::Field(symbol, length) => (*symbol, length),
+            PatternItem::Literal(_) => continue,
+        };
+        let (component, value) = match field_component(symbol, *length) {
+            Ok(Some(field)) => field,
+            _ => continue,
+        };
+        let requested = match options.get(component) {
+            Some(requested) if requested != value => requested,
+            _ => continue,
+        };
+        if component == Component::FractionalSecondDigits {
+            *length = usize::from(requested);
+            continue;
+        }
+        let requested = Width::ALL[usize::from(requested)];
+        let width = Width::ALL[usize::from(value)];
+        // Minutes and seconds are always displayed with two digits after the hour.
+        let keep_two_digits =
+            matches!(component, Component::Minute | Component::Second) && width == Width::TwoDigit;
+        if requested.is_text() == width.is_text() && !keep_two_digits {
+            *length = field_length(symbol, requested);
+        }
+    }
+}
+
+/// Appends the fields of the components requested by `options` that aren't displayed by
+/// `pattern`.
+///
+/// Fractional seconds are inserted after the seconds, and the day period replaces the AM/PM
+/// marker.
+fn append_missing_fields(pattern: &mut Vec<PatternItem>, options: &Components) {
+    let components = pattern_components(pattern).unwrap_or_default();
+    for component in Component::ALL {
+        let value = match options.get(component) {
+            Some(value) if components.get(component).is_none() => value,
+            _ => continue,
+        };
+        let field = component.field(value);
+        let position = |symbol: char| {
+            pattern
+                .iter()
+                .position(|item| matches!(item, PatternItem::Field(s, _) if *s == symbol))
+        };
+        match (component, position('s'), position('a')) {
+            (Component::FractionalSecondDigits, Some(seconds), _) => {
+                let index = seconds + 1;
+                pattern.splice(index..index, [PatternItem::Literal(".".to_string()), field]);
+            }
+            (Component::DayPeriod, _, Some(marker)) => pattern[marker] = field,
+            _ => {
+                if !pattern.is_empty() {
+                    pattern.push(PatternItem::Literal(" ".to_string()));
+                }
+                pattern.push(field);
+            }
+        }
+    }
+}
+
+/// A part of a formatted date, with its type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Part {
+    pub(crate) kind: &'static str,
+    pub(crate) value: String,
+}
+
+impl Part {
+    fn new(kind: &'static str, value: impl Into<String>) -> Self {
+        Self {
+            kind,
+            value: value.into(),
+        }
+    }
+}
+
+/// The fields of a time value in a time zone.
+///
+/// This is the record returned by `ToLocalTime`.
+#[derive(Debug, Clone)]
+struct LocalTime {
+    date_time: NaiveDateTime,
+    offset: i32,
+    abbreviation: String,
+}
+
+/// Abstract operation `ToLocalTime ( t, calendar, timeZone )`, for the `gregory` calendar.
+///
+/// Throws a `RangeError` if `t` isn't a valid time value, which is `TimeClip`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-tolocaltime
+fn to_local_time(t: f64, time_zone: Tz) -> JsResult<LocalTime> {
+    let invalid = || -> JsError {
+        JsNativeError::range()
+            .with_message("invalid time value")
+            .into()
+    };
+    if !t.is_finite() || t.abs() > 8.64e15 {
+        return Err(invalid());
+    }
+    let t = t.trunc() as i64;
+    let utc = NaiveDateTime::from_timestamp_opt(
+        t.div_euclid(1000),
+        (t.rem_euclid(1000) * 1_000_000) as u32,
+    )
+    .ok_or_else(invalid)?;
+    let offset = time_zone.offset_from_utc_datetime(&utc);
+    let seconds = offset.fix().local_minus_utc();
+    Ok(LocalTime {
+        date_time: utc
+            .checked_add_signed(Duration::seconds(i64::from(seconds)))
+            .ok_or_else(invalid)?,
+        offset: seconds,
+        abbreviation: offset.abbreviation().to_string(),
+    })
+}
+
+impl DateTimeFormat {
+    /// Abstract operation `PartitionDateTimePattern ( dateTimeFormat, x )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitiondatetimepattern
+    pub(crate) fn partition_date_time_pattern(&self, x: f64) -> JsResult<Vec<Part>> {
+        // 1. Let x be TimeClip(x).
+        // 2. If x is NaN, throw a RangeError exception.
+        // 11. Let tm be ToLocalTime(x, dateTimeFormat.[[Calendar]], dateTimeFormat.[[TimeZone]]).
+        let tm = to_local_time(x, self.time_zone)?;
+
+        Ok(self.format_local_time(&tm))
+    }
+
+    /// Abstract operation `FormatDateTime ( dateTimeFormat, x )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatdatetime
+    pub(crate) fn format_date_time(&self, x: f64) -> JsResult<String> {
+        // 1. Let parts be ? PartitionDateTimePattern(dateTimeFormat, x).
+        // 2. Let result be the empty String.
+        // 3. For each Record { [[Type]], [[Value]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        // 4. Return result.
+        Ok(self
+            .partition_date_time_pattern(x)?
+            .into_iter()
+            .map(|part| part.value)
+            .collect())
+    }
+
+    /// Abstract operation `PartitionDateTimeRangePattern ( dateTimeFormat, x, y )`
+    ///
+    /// Returns the parts with their sources. There are no range patterns in the ICU data, so
+    /// dates that differ in a displayed field are formatted with the fallback range pattern.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitiondatetimerangepattern
+    pub(crate) fn partition_date_time_range_pattern(
+        &self,
+        x: f64,
+        y: f64,
+    ) -> JsResult<Vec<(Part, &'static str)>> {
+        // 1. Let x be TimeClip(x).
+        // 2. If x is NaN, throw a RangeError exception.
+        // 3. Let y be TimeClip(y).
+        // 4. If y is NaN, throw a RangeError exception.
+        // 5. Let tm1 be ToLocalTime(x, dateTimeFormat.[[Calendar]], dateTimeFormat.[[TimeZone]]).
+        // 6. Let tm2 be ToLocalTime(y, dateTimeFormat.[[Calendar]], dateTimeFormat.[[TimeZone]]).
+        let start = self.partition_date_time_pattern(x)?;
+        let end = self.partition_date_time_pattern(y)?;
+
+        // 13. If dateFieldsPracticallyEqual is true, then
+        if start == end {
+            // a. Let pattern be dateTimeFormat.[[Pattern]].
+            // b. Let patternParts be PartitionPattern(pattern).
+            // c. Let result be ! FormatDateTimePattern(dateTimeFormat, patternParts, x, undefined).
+            // d. For each Record { [[Type]], [[Value]] } r in result, do
+            //     i. Set r.[[Source]] to "shared".
+            // e. Return result.
+            return Ok(start.into_iter().map(|part| (part, "shared")).collect());
+        }
+
+        // 14-18. Format the dates with the range pattern, which is "{0} – {1}".
+        let mut result = start
+            .into_iter()
+            .map(|part| (part, "startRange"))
+            .collect::<Vec<_>>();
+        result.push((Part::new("literal", " – "), "shared"));
+        result.extend(end.into_iter().map(|part| (part, "endRange")));
+        Ok(result)
+    }
+
+    /// Formats the fields of `tm` with the pattern of the `DateTimeFormat`.
+    ///
+    /// This is the loop of `FormatDateTimePattern` over the parts of the pattern.
+    fn format_local_time(&self, tm: &LocalTime) -> Vec<Part> {
+        let date_time = &tm.date_time;
+        let mut result = Vec::new();
+        for item in &self.pattern {
+            let (symbol, length) = match item {
+                PatternItem::Literal(literal) => {
+                    result.push(Part::new("literal", literal.as_str()));
+                    continue;
+                }
+                PatternItem::Field(symbol, length) => (*symbol, *length),
+            };
+            let text = match length {
+                4 => 2,
+                5 => 1,
+                _ => 0,
+            };
+            let part = match symbol {
+                'G' => {
+                    let era = usize::from(date_time.year() > 0);
+                    Part::new("era", self.symbols.eras[text][era].as_str())
+                }
+                'y' => {
+                    let year = date_time.year();
+                    // If p is "year" and v ≤ 0, let v be 1 - v.
+                    let year = if year <= 0 { 1 - year } else { year };
+                    let value = if length == 2 {
+                        self.number(year % 100, 2)
+                    } else {
+                        self.number(year, length)
+                    };
+                    Part::new("year", value)
+                }
+                'M' | 'L' if length <= 2 => {
+                    Part::new("month", self.number(date_time.month(), length))
+                }
+                'M' | 'L' => {
+                    let months = if symbol == 'L' {
+                        &self.symbols.stand_alone_months
+                    } else {
+                        &self.symbols.months
+                    };
+                    Part::new("month", months[text][date_time.month0() as usize].as_str())
+                }
+                'd' => Part::new("day", self.number(date_time.day(), length)),
+                'E' | 'c' => {
+                    let weekdays = if symbol == 'c' {
+                        &self.symbols.stand_alone_weekdays
+                    } else {
+                        &self.symbols.weekdays
+                    };
+                    let weekday = date_time.weekday().num_days_from_sunday() as usize;
+                    Part::new("weekday", weekdays[text][weekday].as_str())
+                }
+                'a' => {
+                    let period = usize::from(date_time.hour() >= 12);
+                    Part::new("dayPeriod", self.symbols.day_periods[text][period].as_str())
+                }
+                'B' => Part::new("dayPeriod", self.flexible_day_period(date_time, text)),
+                'h' | 'H' | 'K' | 'k' => {
+                    let hour = date_time.hour();
+                    let hour = match symbol {
+                        'h' if hour % 12 == 0 => 12,
+                        'h' | 'K' => hour % 12,
+                        'k' if hour == 0 => 24,
+                        _ => hour,
+                    };
+                    Part::new("hour", self.number(hour, length))
+                }
+                'm' => Part::new("minute", self.number(date_time.minute(), length)),
+                's' => Part::new("second", self.number(date_time.second(), length)),
+                'S' => {
+                    let digits = length.min(3);
+                    let millisecond = date_time.nanosecond() / 1_000_000;
+                    let value = millisecond / 10u32.pow(3 - digits as u32);
+                    Part::new("fractionalSecond", self.number(value, digits))
+                }
+                'z' | 'v' => Part::new("timeZoneName", self.time_zone_name(tm, length >= 4)),
+                _ => continue,
+            };
+            result.push(part);
+        }
+        result
+    }
+
+    /// Formats `value` with at least `digits` digits, in the numbering system of the
+    /// `DateTimeFormat`.
+    fn number<T: std::fmt::Display>(&self, value: T, digits: usize) -> String {
+        format!("{value:0digits$}")
+            .chars()
+            .map(|c| {
+                c.to_digit(10)
+                    .map_or(c, |digit| self.digits[digit as usize])
+            })
+            .collect()
+    }
+
+    /// Gets the flexible day period of `date_time`.
+    ///
+    /// The ICU data has no flexible day periods, so they are the English ones for English
+    /// locales, and the AM/PM markers or noon otherwise.
+    fn flexible_day_period(&self, date_time: &NaiveDateTime, text: usize) -> String {
+        let hour = date_time.hour();
+        let is_noon = hour == 12 && date_time.minute() == 0 && date_time.second() == 0;
+        if self.locale.id.language.as_str() == "en" {
+            return match hour {
+                _ if is_noon => "noon",
+                6..=11 => "in the morning",
+                12..=17 => "in the afternoon",
+                18..=20 => "in the evening",
+                _ => "at night",
+            }
+            .to_string();
+        }
+        match &self.symbols.noon[text] {
+            Some(noon) if is_noon => noon.clone(),
+            _ => self.symbols.day_periods[text][usize::from(hour >= 12)].clone(),
+        }
+    }
+
+    /// Gets the name of the time zone of the `DateTimeFormat` at the local time `tm`.
+    ///
+    /// The ICU data has no time zone names, so the short name is the abbreviation of the time
+    /// zone database if it's alphabetic, and the names fall back to the localized GMT format.
+    fn time_zone_name(&self, tm: &LocalTime, long: bool) -> String {
+        if self.time_zone == Tz::UTC {
+            return if long {
+                "Coordinated Universal Time".to_string()
+            } else {
+                "UTC".to_string()
+            };
+        }
+        if !long && tm.abbreviation.bytes().all(|c| c.is_ascii_alphabetic()) {
+            return tm.abbreviation.clone();
+        }
+        if tm.offset == 0 {
+            return "GMT".to_string();
+        }
+        let sign = if tm.offset < 0 { '-' } else { '+' };
+        let hours = tm.offset.unsigned_abs() / 3600;
+        let minutes = tm.offset.unsigned_abs() % 3600 / 60;
+        if long {
+            format!(
+                "GMT{sign}{}:{}",
+                self.number(hours, 2),
+                self.number(minutes, 2)
+            )
+        } else if minutes == 0 {
+            format!("GMT{sign}{}", self.number(hours, 1))
+        } else {
+            format!(
+                "GMT{sign}{}:{}",
+                self.number(hours, 1),
+                self.number(minutes, 2)
+            )
+        }
+    }
+}
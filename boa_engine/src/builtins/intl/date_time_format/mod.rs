@@ -0,0 +1,1291 @@
+//! This module implements the global `Intl.DateTimeFormat` object.
+//!
+//! `Intl.DateTimeFormat` is a built-in object that has properties and methods for date and time i18n.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#datetimeformat-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat
+
+mod format;
+
+use self::format::{
+    parse_pattern, pattern_components, Component, Components, Part, PatternItem, Width,
+};
+use super::{
+    canonicalize_locale_list, default_locale, get_number_option, get_option,
+    number_format::{
+        coerce_options_to_object, get_string_option, is_unicode_type, numbering_system_digits,
+        numbering_system_name, DecimalSymbols,
+    },
+    GetOptionType,
+};
+use crate::{
+    builtins::{
+        time_zone::{host_time_zone, named_time_zone},
+        Array, JsArgs,
+    },
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsFunction, JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use chrono::Utc;
+use chrono_tz::Tz;
+use icu_datetime::{
+    pattern::CoarseHourCycle,
+    provider::calendar::{
+        patterns::LengthPatternsV1, DatePatternsV1Marker, DateSkeletonPatternsV1Marker,
+        DateSymbolsV1Marker,
+    },
+};
+use icu_locid::{
+    extensions::unicode::{Key, Value},
+    LanguageIdentifier, Locale,
+};
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+
+/// The `[[DateStyle]]` or `[[TimeStyle]]` of a date and time format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Full,
+    Long,
+    Medium,
+    Short,
+}
+
+impl Style {
+    /// Gets the style with the option value `name`.
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "full" => Some(Self::Full),
+            "long" => Some(Self::Long),
+            "medium" => Some(Self::Medium),
+            "short" => Some(Self::Short),
+            _ => None,
+        }
+    }
+
+    /// Gets the option value of the style.
+    fn name(self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::Long => "long",
+            Self::Medium => "medium",
+            Self::Short => "short",
+        }
+    }
+}
+
+/// The `[[HourCycle]]` of a date and time format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HourCycle {
+    H11,
+    H12,
+    H23,
+    H24,
+}
+
+impl HourCycle {
+    /// Gets the hour cycle with the option value `name`.
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "h11" => Some(Self::H11),
+            "h12" => Some(Self::H12),
+            "h23" => Some(Self::H23),
+            "h24" => Some(Self::H24),
+            _ => None,
+        }
+    }
+
+    /// Gets the option value of the hour cycle.
+    fn name(self) -> &'static str {
+        match self {
+            Self::H11 => "h11",
+            Self::H12 => "h12",
+            Self::H23 => "h23",
+            Self::H24 => "h24",
+        }
+    }
+
+    /// Gets the symbol of the hour field of the hour cycle.
+    fn symbol(self) -> char {
+        match self {
+            Self::H11 => 'K',
+            Self::H12 => 'h',
+            Self::H23 => 'H',
+            Self::H24 => 'k',
+        }
+    }
+
+    /// Checks if the hour cycle is a 12-hour cycle.
+    fn is_12_hour(self) -> bool {
+        matches!(self, Self::H11 | Self::H12)
+    }
+}
+
+/// Copies the symbols of an ICU data array.
+fn symbol_names<const N: usize>(symbols: &[Cow<'_, str>; N]) -> [String; N] {
+    std::array::from_fn(|i| symbols[i].to_string())
+}
+
+/// The date symbols of a locale, copied from its ICU data.
+///
+/// The symbols of each kind are in the abbreviated, narrow and wide widths.
+#[derive(Debug, Clone)]
+struct DateSymbols {
+    months: [[String; 12]; 3],
+    stand_alone_months: [[String; 12]; 3],
+    weekdays: [[String; 7]; 3],
+    stand_alone_weekdays: [[String; 7]; 3],
+    day_periods: [[String; 2]; 3],
+    noon: [Option<String>; 3],
+    eras: [[String; 2]; 3],
+}
+
+impl DateSymbols {
+    /// Loads the date symbols of `locale` from the data provider of the context.
+    fn load(locale: &Locale, context: &Context) -> Option<Self> {
+        let payload = ResourceProvider::<DateSymbolsV1Marker>::load_resource(
+            context.icu().provider(),
+            &DataRequest {
+                options: locale.into(),
+                metadata: DataRequestMetadata::default(),
+            },
+        )
+        .ok()?
+        .take_payload()
+        .ok()?;
+        let symbols = payload.get();
+
+        let months = &symbols.months.format;
+        let stand_alone = symbols.months.stand_alone.as_ref();
+        let stand_alone_months = [
+            stand_alone
+                .and_then(|widths| widths.abbreviated.as_ref())
+                .unwrap_or(&months.abbreviated),
+            stand_alone
+                .and_then(|widths| widths.narrow.as_ref())
+                .unwrap_or(&months.narrow),
+            stand_alone
+                .and_then(|widths| widths.wide.as_ref())
+                .unwrap_or(&months.wide),
+        ];
+        let weekdays = &symbols.weekdays.format;
+        let stand_alone = symbols.weekdays.stand_alone.as_ref();
+        let stand_alone_weekdays = [
+            stand_alone
+                .and_then(|widths| widths.abbreviated.as_ref())
+                .unwrap_or(&weekdays.abbreviated),
+            stand_alone
+                .and_then(|widths| widths.narrow.as_ref())
+                .unwrap_or(&weekdays.narrow),
+            stand_alone
+                .and_then(|widths| widths.wide.as_ref())
+                .unwrap_or(&weekdays.wide),
+        ];
+        let day_periods = &symbols.day_periods.format;
+        let day_periods = [
+            &day_periods.abbreviated,
+            &day_periods.narrow,
+            &day_periods.wide,
+        ];
+        let eras = &symbols.eras;
+
+        Some(Self {
+            months: [&months.abbreviated, &months.narrow, &months.wide]
+                .map(|names| symbol_names(&names.0)),
+            stand_alone_months: stand_alone_months.map(|names| symbol_names(&names.0)),
+            weekdays: [&weekdays.abbreviated, &weekdays.narrow, &weekdays.wide]
+                .map(|names| symbol_names(&names.0)),
+            stand_alone_weekdays: stand_alone_weekdays.map(|names| symbol_names(&names.0)),
+            day_periods: day_periods
+                .map(|periods| [periods.am.to_string(), periods.pm.to_string()]),
+            noon: day_periods.map(|periods| periods.noon.as_ref().map(ToString::to_string)),
+            eras: [&eras.abbr, &eras.narrow, &eras.names].map(|names| {
+                ["bc", "ad"].map(|era| names.get(era).map(ToString::to_string).unwrap_or_default())
+            }),
+        })
+    }
+}
+
+/// The date and time patterns of a locale, copied from its ICU data.
+///
+/// The length patterns are in the full, long, medium and short styles.
+#[derive(Debug, Clone)]
+struct DatePatterns {
+    date: [String; 4],
+    time_12: [String; 4],
+    time_24: [String; 4],
+    combinations: [String; 4],
+    hour_cycle: HourCycle,
+    hour_cycle_12: HourCycle,
+    hour_cycle_24: HourCycle,
+    /// The patterns of the available formats, for `BestFitFormatMatcher`.
+    available: Vec<String>,
+}
+
+impl DatePatterns {
+    /// Loads the date and time patterns of `locale` from the data provider of the context.
+    fn load(locale: &Locale, context: &Context) -> Option<Self> {
+        let request = DataRequest {
+            options: locale.into(),
+            metadata: DataRequestMetadata::default(),
+        };
+        let payload = ResourceProvider::<DatePatternsV1Marker>::load_resource(
+            context.icu().provider(),
+            &request,
+        )
+        .ok()?
+        .take_payload()
+        .ok()?;
+        let patterns = payload.get();
+        let skeletons = ResourceProvider::<DateSkeletonPatternsV1Marker>::load_resource(
+            context.icu().provider(),
+            &request,
+        )
+        .ok()?
+        .take_payload()
+        .ok()?;
+
+        let lengths = |patterns: &LengthPatternsV1<'_>| {
+            [
+                &patterns.full,
+                &patterns.long,
+                &patterns.medium,
+                &patterns.short,
+            ]
+            .map(ToString::to_string)
+        };
+        let time_12 = lengths(&patterns.time_h11_h12);
+        let time_24 = lengths(&patterns.time_h23_h24);
+        let has_field = |pattern: &str, symbol: char| {
+            parse_pattern(pattern)
+                .iter()
+                .any(|item| matches!(item, PatternItem::Field(s, _) if *s == symbol))
+        };
+        let hour_cycle_12 = if has_field(&time_12[3], 'K') {
+            HourCycle::H11
+        } else {
+            HourCycle::H12
+        };
+        let hour_cycle_24 = if has_field(&time_24[3], 'k') {
+            HourCycle::H24
+        } else {
+            HourCycle::H23
+        };
+        let combinations = &patterns.length_combinations;
+
+        Some(Self {
+            date: lengths(&patterns.date),
+            hour_cycle: match patterns.preferred_hour_cycle {
+                CoarseHourCycle::H11H12 => hour_cycle_12,
+                CoarseHourCycle::H23H24 => hour_cycle_24,
+            },
+            time_12,
+            time_24,
+            combinations: [
+                &combinations.full,
+                &combinations.long,
+                &combinations.medium,
+                &combinations.short,
+            ]
+            .map(ToString::to_string),
+            hour_cycle_12,
+            hour_cycle_24,
+            available: skeletons
+                .get()
+                .0
+                .iter()
+                .filter_map(|(_, patterns)| {
+                    // Skip the formats with plural variants, which have week fields.
+                    let mut patterns = patterns.patterns_iter();
+                    match (patterns.next(), patterns.next()) {
+                        (Some(pattern), None) => Some(pattern.to_string()),
+                        _ => None,
+                    }
+                })
+                .collect(),
+        })
+    }
+}
+
+/// The date and time data of a locale for the `gregory` calendar.
+#[derive(Debug, Clone)]
+struct DateData {
+    symbols: DateSymbols,
+    patterns: DatePatterns,
+}
+
+impl DateData {
+    /// Loads the date and time data of `langid` from the data provider of the context.
+    fn load(langid: &LanguageIdentifier, context: &Context) -> Option<Self> {
+        let mut locale = Locale::from(langid.clone());
+        set_keyword(&mut locale, "ca", "gregory");
+        Some(Self {
+            symbols: DateSymbols::load(&locale, context)?,
+            patterns: DatePatterns::load(&locale, context)?,
+        })
+    }
+
+    /// Looks up the available locale that best matches `langid`, by removing its subtags
+    /// until there is data for it.
+    fn lookup(
+        langid: &LanguageIdentifier,
+        context: &Context,
+    ) -> Option<(LanguageIdentifier, Self)> {
+        let mut candidate = langid.clone();
+        loop {
+            if let Some(data) = Self::load(&candidate, context) {
+                return Some((candidate, data));
+            }
+            if !candidate.variants.is_empty() {
+                candidate.variants.clear();
+            } else if candidate.region.is_some() {
+                candidate.region = None;
+            } else if candidate.script.is_some() {
+                candidate.script = None;
+            } else {
+                return None;
+            }
+        }
+    }
+}
+
+/// Sets the Unicode extension keyword `key` of `locale` to `value`.
+fn set_keyword(locale: &mut Locale, key: &str, value: &str) {
+    if let (Ok(key), Ok(value)) = (key.parse::<Key>(), value.parse::<Value>()) {
+        locale.extensions.unicode.keywords.set(key, value);
+    }
+}
+
+/// Resolves the value of the relevant extension key `key`, as done by `ResolveLocale`.
+///
+/// The keyword of the `requested` locale is kept in the resolved `locale` if it's supported and
+/// the `option` doesn't override it.
+fn resolve_keyword(
+    locale: &mut Locale,
+    requested: Option<&Locale>,
+    key: &str,
+    option: Option<String>,
+    is_supported: impl Fn(&str) -> bool,
+) -> Option<String> {
+    let extension = key.parse::<Key>().ok().and_then(|key| {
+        requested
+            .and_then(|requested| requested.extensions.unicode.keywords.get(&key))
+            .map(ToString::to_string)
+            .filter(|value| is_supported(value))
+    });
+    let option = option.filter(|value| is_supported(value));
+    match (extension, option) {
+        (Some(extension), Some(option)) if extension == option => {
+            set_keyword(locale, key, &extension);
+            Some(extension)
+        }
+        (_, Some(option)) => Some(option),
+        (Some(extension), None) => {
+            set_keyword(locale, key, &extension);
+            Some(extension)
+        }
+        (None, None) => None,
+    }
+}
+
+/// Abstract operation `DefaultTimeZone ( )`
+///
+/// Uses the IANA time zone of the host if it has one, and the `Etc/GMT` time zone of its current
+/// UTC offset otherwise.
+fn default_time_zone() -> Tz {
+    if let Some(tz) = host_time_zone() {
+        return tz;
+    }
+
+    let offset = chrono::Local::now().offset().local_minus_utc();
+    if offset == 0 || offset % 3600 != 0 {
+        return Tz::UTC;
+    }
+    // The signs of the `Etc/GMT` time zones are inverted.
+    named_time_zone(&format!("Etc/GMT{:+}", -offset / 3600)).unwrap_or(Tz::UTC)
+}
+
+/// Gets the time value of the `date` argument of the format methods, which is the current time
+/// if it's undefined.
+fn date_time_value(date: &JsValue, context: &mut Context) -> JsResult<f64> {
+    // 3. If date is not provided or is undefined, then
+    if date.is_undefined() {
+        // a. Let x be ! Call(%Date.now%, undefined).
+        Ok(Utc::now().timestamp_millis() as f64)
+    }
+    // 4. Else,
+    else {
+        // a. Let x be ? ToNumber(date).
+        date.to_number(context)
+    }
+}
+
+/// JavaScript `Intl.DateTimeFormat` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct DateTimeFormat {
+    #[unsafe_ignore_trace]
+    locale: Locale,
+    calendar: String,
+    numbering_system: String,
+    #[unsafe_ignore_trace]
+    digits: [char; 10],
+    #[unsafe_ignore_trace]
+    time_zone: Tz,
+    #[unsafe_ignore_trace]
+    hour_cycle: Option<HourCycle>,
+    #[unsafe_ignore_trace]
+    date_style: Option<Style>,
+    #[unsafe_ignore_trace]
+    time_style: Option<Style>,
+    #[unsafe_ignore_trace]
+    pattern: Vec<PatternItem>,
+    #[unsafe_ignore_trace]
+    symbols: DateSymbols,
+    bound_format: Option<JsObject>,
+}
+
+impl DateTimeFormat {
+    const NAME: &'static str = "DateTimeFormat";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let get_format = FunctionBuilder::native(context, Self::get_format)
+            .name("get format")
+            .build();
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context
+                .intrinsics()
+                .constructors()
+                .date_time_format()
+                .clone(),
+        )
+        .name(Self::NAME)
+        .length(0)
+        .static_method(Self::supported_locales_of, "supportedLocalesOf", 1)
+        .accessor("format", Some(get_format), None, Attribute::CONFIGURABLE)
+        .method(Self::format_to_parts, "formatToParts", 1)
+        .method(Self::format_range, "formatRange", 2)
+        .method(Self::format_range_to_parts, "formatRangeToParts", 2)
+        .method(Self::resolved_options, "resolvedOptions", 0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            "Intl.DateTimeFormat",
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build()
+    }
+
+    /// The `Intl.DateTimeFormat` constructor is the `%DateTimeFormat%` intrinsic object and a standard built-in property of the `Intl` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#datetimeformat-objects
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, let newTarget be the active function object, else let newTarget be NewTarget.
+        // 2. Let dateTimeFormat be ? OrdinaryCreateFromConstructor(newTarget, "%DateTimeFormat.prototype%",
+        // « [[InitializedDateTimeFormat]], [[Locale]], [[Calendar]], [[NumberingSystem]], [[TimeZone]], [[Weekday]],
+        // [[Era]], [[Year]], [[Month]], [[Day]], [[DayPeriod]], [[Hour]], [[Minute]], [[Second]],
+        // [[FractionalSecondDigits]], [[TimeZoneName]], [[HourCycle]], [[Pattern]], [[BoundFormat]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::date_time_format,
+            context,
+        )?;
+
+        // 3. Perform ? InitializeDateTimeFormat(dateTimeFormat, locales, options).
+        let date_time_format = Self::initialize(
+            args,
+            args.get_or_undefined(1),
+            &DateTimeReqs::AnyAll,
+            &DateTimeReqs::Date,
+            context,
+        )?;
+
+        // 4. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Let this be the this value.
+        //     b. Return ? ChainDateTimeFormat(dateTimeFormat, NewTarget, this).
+        // 5. Return dateTimeFormat.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::date_time_format(Box::new(date_time_format)),
+        )
+        .into())
+    }
+
+    /// Abstract operation `InitializeDateTimeFormat ( dateTimeFormat, locales, options )`
+    ///
+    /// The requested locales are read from the first element of `locales`, and the options are
+    /// converted with `ToDateTimeOptions ( options, required, defaults )`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-initializedatetimeformat
+    pub(crate) fn initialize(
+        locales: &[JsValue],
+        options: &JsValue,
+        required: &DateTimeReqs,
+        defaults: &DateTimeReqs,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 1. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 2. Let options be ? ToDateTimeOptions(options, "any", "date").
+        let options = to_date_time_options(options, required, defaults, context)?;
+
+        // 3. Let opt be a new Record.
+        // 4. Let matcher be ? GetOption(options, "localeMatcher", "string", « "lookup", "best fit" », "best fit").
+        // 5. Set opt.[[localeMatcher]] to matcher.
+        get_string_option(&options, "localeMatcher", &["lookup", "best fit"], context)?;
+
+        // 6. Let calendar be ? GetOption(options, "calendar", "string", undefined, undefined).
+        let calendar_option = get_string_option(&options, "calendar", &[], context)?;
+
+        // 7. If calendar is not undefined, then
+        if let Some(calendar) = &calendar_option {
+            // a. If calendar does not match the Unicode Locale Identifier type nonterminal, throw a RangeError exception.
+            if !is_unicode_type(calendar) {
+                return Err(JsNativeError::range()
+                    .with_message(format!("invalid calendar `{calendar}`"))
+                    .into());
+            }
+        }
+
+        // 8. Set opt.[[ca]] to calendar.
+        // 9. Let numberingSystem be ? GetOption(options, "numberingSystem", "string", undefined, undefined).
+        let numbering_system_option = get_string_option(&options, "numberingSystem", &[], context)?;
+
+        // 10. If numberingSystem is not undefined, then
+        if let Some(numbering_system) = &numbering_system_option {
+            // a. If numberingSystem does not match the Unicode Locale Identifier type nonterminal, throw a RangeError exception.
+            if !is_unicode_type(numbering_system) {
+                return Err(JsNativeError::range()
+                    .with_message(format!("invalid numbering system `{numbering_system}`"))
+                    .into());
+            }
+        }
+
+        // 11. Set opt.[[nu]] to numberingSystem.
+        // 12. Let hour12 be ? GetOption(options, "hour12", "boolean", undefined, undefined).
+        let hour12 = get_option(
+            &options,
+            "hour12",
+            &GetOptionType::Boolean,
+            &[],
+            &JsValue::undefined(),
+            context,
+        )?;
+        let hour12 = if hour12.is_undefined() {
+            None
+        } else {
+            Some(hour12.to_boolean())
+        };
+
+        // 13. Let hourCycle be ? GetOption(options, "hourCycle", "string", « "h11", "h12", "h23", "h24" », undefined).
+        // 14. If hour12 is not undefined, then
+        //     a. Let hourCycle be null.
+        // 15. Set opt.[[hc]] to hourCycle.
+        let hour_cycle_option = get_string_option(
+            &options,
+            "hourCycle",
+            &["h11", "h12", "h23", "h24"],
+            context,
+        )?;
+
+        // 16. Let localeData be %DateTimeFormat%.[[LocaleData]].
+        // 17. Let r be ResolveLocale(%DateTimeFormat%.[[AvailableLocales]], requestedLocales, opt, %DateTimeFormat%.[[RelevantExtensionKeys]], localeData).
+        let (requested, (langid, data)) = requested_locales
+            .iter()
+            .find_map(|locale| {
+                DateData::lookup(&locale.id, context).map(|found| (Some(locale), found))
+            })
+            .or_else(|| {
+                let default = default_locale(context.icu().locale_canonicalizer());
+                DateData::lookup(&default.id, context).map(|found| (None, found))
+            })
+            .or_else(|| {
+                DateData::load(&LanguageIdentifier::default(), context)
+                    .map(|data| (None, (LanguageIdentifier::default(), data)))
+            })
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("the data provider has no date and time data")
+            })?;
+        let default_digits = DecimalSymbols::lookup(&langid, context).map_or(
+            ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+            |(_, symbols)| symbols.digits,
+        );
+
+        // 18. Set dateTimeFormat.[[Locale]] to r.[[locale]].
+        let mut locale = Locale::from(langid);
+
+        // 19. Let calendar be r.[[ca]].
+        // 20. Set dateTimeFormat.[[Calendar]] to calendar.
+        let calendar = resolve_keyword(&mut locale, requested, "ca", calendar_option, |calendar| {
+            calendar == "gregory"
+        })
+        .unwrap_or_else(|| "gregory".to_string());
+
+        // 21. Set dateTimeFormat.[[HourCycle]] to r.[[hc]].
+        let hour_cycle = if hour12.is_none() {
+            resolve_keyword(&mut locale, requested, "hc", hour_cycle_option, |hc| {
+                HourCycle::from_name(hc).is_some()
+            })
+            .and_then(|hc| HourCycle::from_name(&hc))
+        } else {
+            None
+        };
+
+        // 22. Set dateTimeFormat.[[NumberingSystem]] to r.[[nu]].
+        let numbering_system = resolve_keyword(
+            &mut locale,
+            requested,
+            "nu",
+            numbering_system_option,
+            |system| numbering_system_digits(system).is_some(),
+        )
+        .unwrap_or_else(|| numbering_system_name(&default_digits).to_string());
+        let digits = numbering_system_digits(&numbering_system).unwrap_or(default_digits);
+
+        // 23. Let dataLocale be r.[[dataLocale]].
+        // 24. Let timeZone be ? Get(options, "timeZone").
+        let time_zone = options.get("timeZone", context)?;
+
+        // 25. If timeZone is undefined, then
+        //     a. Let timeZone be ! DefaultTimeZone().
+        // 26. Else,
+        //     a. Let timeZone be ? ToString(timeZone).
+        //     b. If the result of ! IsValidTimeZoneName(timeZone) is false, then
+        //         i. Throw a RangeError exception.
+        //     c. Let timeZone be ! CanonicalizeTimeZoneName(timeZone).
+        // 27. Set dateTimeFormat.[[TimeZone]] to timeZone.
+        let time_zone = if time_zone.is_undefined() {
+            default_time_zone()
+        } else {
+            let name = time_zone.to_string(context)?.to_std_string_escaped();
+            named_time_zone(&name).ok_or_else(|| {
+                JsNativeError::range().with_message(format!("invalid time zone `{name}`"))
+            })?
+        };
+
+        // 28. Let opt be a new Record.
+        // 29. For each row of Table 4, except the header row, in table order, do
+        let mut components = Components::default();
+        for component in Component::ALL {
+            // a. Let prop be the name given in the Property column of the row.
+            // b. If prop is "fractionalSecondDigits", then
+            //     i. Let value be ? GetNumberOption(options, "fractionalSecondDigits", 1, 3, undefined).
+            // c. Else,
+            //     i. Let value be ? GetOption(options, prop, "string", « the strings given in the Values column of the row », undefined).
+            // d. Set opt.[[<prop>]] to value.
+            let value = if component == Component::FractionalSecondDigits {
+                get_number_option(&options, component.property(), 1.0, 3.0, None, context)?
+                    .map(|digits| digits as u8)
+            } else {
+                get_string_option(&options, component.property(), component.values(), context)?
+                    .and_then(|value| Width::from_name(&value))
+                    .map(|width| width as u8)
+            };
+            components.set(component, value);
+        }
+
+        // 30. Let dataLocaleData be localeData.[[<dataLocale>]].
+        // 31. Let matcher be ? GetOption(options, "formatMatcher", "string", « "basic", "best fit" », "best fit").
+        get_string_option(&options, "formatMatcher", &["basic", "best fit"], context)?;
+
+        // 32. Let dateStyle be ? GetOption(options, "dateStyle", "string", « "full", "long", "medium", "short" », undefined).
+        // 33. Set dateTimeFormat.[[DateStyle]] to dateStyle.
+        let date_style = get_string_option(
+            &options,
+            "dateStyle",
+            &["full", "long", "medium", "short"],
+            context,
+        )?
+        .and_then(|style| Style::from_name(&style));
+
+        // 34. Let timeStyle be ? GetOption(options, "timeStyle", "string", « "full", "long", "medium", "short" », undefined).
+        // 35. Set dateTimeFormat.[[TimeStyle]] to timeStyle.
+        let time_style = get_string_option(
+            &options,
+            "timeStyle",
+            &["full", "long", "medium", "short"],
+            context,
+        )?
+        .and_then(|style| Style::from_name(&style));
+
+        // 36-37. Let hc be the hour cycle of the locale, the hc option or extension, or hour12.
+        let hour_cycle = match hour12 {
+            Some(true) => data.patterns.hour_cycle_12,
+            Some(false) => data.patterns.hour_cycle_24,
+            None => hour_cycle.unwrap_or(data.patterns.hour_cycle),
+        };
+
+        // 38. If dateStyle is not undefined or timeStyle is not undefined, then
+        let pattern = if date_style.is_some() || time_style.is_some() {
+            // a. For each row in Table 4, except the header row, do
+            //     i. Let prop be the name given in the Property column of the row.
+            //     ii. Let p be opt.[[<prop>]].
+            //     iii. If p is not undefined, then
+            //         1. Throw a TypeError exception.
+            if let Some(component) = Component::ALL
+                .into_iter()
+                .find(|component| components.get(*component).is_some())
+            {
+                return Err(JsNativeError::typ()
+                    .with_message(format!(
+                        "the {} option can't be used with dateStyle or timeStyle",
+                        component.property()
+                    ))
+                    .into());
+            }
+
+            // b. Let styles be dataLocaleData.[[styles]].[[<calendar>]].
+            // c. Let bestFormat be DateTimeStyleFormat(dateStyle, timeStyle, styles).
+            data.patterns
+                .style_pattern(date_style, time_style, hour_cycle)
+        }
+        // 39. Else,
+        else {
+            // a-d. Let bestFormat be BestFitFormatMatcher(opt, formats).
+            data.patterns.best_fit_pattern(&components, hour_cycle)
+        };
+
+        // 40. For each row in Table 4, except the header row, in table order, do
+        //     a. If bestFormat has a field [[<prop>]], set dateTimeFormat's internal slot whose name is the Internal Slot column of the row to the value of bestFormat.[[<prop>]].
+        // 41. If dateTimeFormat.[[Hour]] is undefined, then
+        //     a. Set dateTimeFormat.[[HourCycle]] to undefined.
+        let hour_cycle = pattern_components(&pattern)
+            .and_then(|components| components.get(Component::Hour))
+            .map(|_| hour_cycle);
+
+        // 42-43. Set dateTimeFormat.[[Pattern]] to pattern.
+        // 44. Return dateTimeFormat.
+        Ok(Self {
+            locale,
+            calendar,
+            numbering_system,
+            digits,
+            time_zone,
+            hour_cycle,
+            date_style,
+            time_style,
+            pattern,
+            symbols: data.symbols,
+            bound_format: None,
+        })
+    }
+
+    /// Gets the `DateTimeFormat` object of `this`, or throws a `TypeError` if it's not one.
+    fn this_date_time_format(this: &JsValue, method: &str) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|object| object.borrow().is_date_time_format())
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "Intl.DateTimeFormat.prototype.{method} called on a non-DateTimeFormat object"
+                    ))
+                    .into()
+            })
+    }
+
+    /// `Intl.DateTimeFormat.supportedLocalesOf ( locales [ , options ] )`
+    ///
+    /// Returns the requested locales that are supported without falling back to the default
+    /// locale.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.supportedlocalesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let availableLocales be %DateTimeFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(args, context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        // SupportedLocales ( availableLocales, requestedLocales, options )
+        // 1. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(args.get_or_undefined(1), context)?;
+
+        // 2. Let matcher be ? GetOption(options, "localeMatcher", "string", « "lookup", "best fit" », "best fit").
+        get_string_option(&options, "localeMatcher", &["lookup", "best fit"], context)?;
+
+        // 3-4. Let supportedLocales be the requested locales that have an available locale.
+        let supported = requested_locales
+            .into_iter()
+            .filter(|locale| {
+                DateData::lookup(&locale.id, context)
+                    .map_or(false, |(langid, _)| langid != LanguageIdentifier::default())
+            })
+            .map(|locale| JsValue::new(JsString::new(locale.to_string())))
+            .collect::<Vec<_>>();
+
+        // 5. Return CreateArrayFromList(supportedLocales).
+        Ok(Array::create_array_from_list(supported, context).into())
+    }
+
+    /// `get Intl.DateTimeFormat.prototype.format`
+    ///
+    /// Returns a function that formats a date according to the locale and options of the
+    /// `DateTimeFormat`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/format
+    fn get_format(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 2. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Set dtf to ? UnwrapDateTimeFormat(dtf).
+        // 3. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = Self::this_date_time_format(this, "format")?;
+
+        // 4. If dtf.[[BoundFormat]] is undefined, then
+        let bound_format = dtf
+            .borrow()
+            .as_date_time_format()
+            .and_then(|date_time_format| date_time_format.bound_format.clone());
+        if let Some(bound_format) = bound_format {
+            // 5. Return dtf.[[BoundFormat]].
+            return Ok(bound_format.into());
+        }
+
+        // a. Let F be a new built-in function object as defined in DateTime Format Functions (11.1.6).
+        // b. Set F.[[DateTimeFormat]] to dtf.
+        let function = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args, dtf: &mut JsObject, context| {
+                // 1. Let dtf be F.[[DateTimeFormat]].
+                // 2. Assert: Type(dtf) is Object and dtf has an [[InitializedDateTimeFormat]] internal slot.
+                // 3-4. Let x be the time value of date.
+                let x = date_time_value(args.get_or_undefined(0), context)?;
+
+                // 5. Return ? FormatDateTime(dtf, x).
+                let dtf = dtf.borrow();
+                let dtf = dtf
+                    .as_date_time_format()
+                    .expect("the captured object must be a DateTimeFormat");
+                Ok(JsString::new(dtf.format_date_time(x)?).into())
+            },
+            dtf.clone(),
+        )
+        .name("")
+        .length(1)
+        .build();
+
+        // c. Set dtf.[[BoundFormat]] to F.
+        if let Some(date_time_format) = dtf.borrow_mut().as_date_time_format_mut() {
+            date_time_format.bound_format = Some(function.clone().into());
+        }
+
+        // 5. Return dtf.[[BoundFormat]].
+        Ok(function.into())
+    }
+
+    /// Creates an array with the objects of `parts`, which have a `source` property if they have
+    /// a source.
+    fn parts_to_array(
+        parts: impl IntoIterator<Item = (Part, Option<&'static str>)>,
+        context: &mut Context,
+    ) -> JsValue {
+        let mut result = Vec::new();
+        for (part, source) in parts {
+            // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+            let o = JsObject::from_proto_and_data(
+                context.intrinsics().constructors().object().prototype(),
+                ObjectData::ordinary(),
+            );
+
+            // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+            o.create_data_property_or_throw("type", part.kind, context)
+                .expect("CreateDataPropertyOrThrow must not fail on a new object");
+
+            // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+            o.create_data_property_or_throw("value", JsString::new(part.value), context)
+                .expect("CreateDataPropertyOrThrow must not fail on a new object");
+
+            // d. Perform ! CreateDataPropertyOrThrow(O, "source", part.[[Source]]).
+            if let Some(source) = source {
+                o.create_data_property_or_throw("source", source, context)
+                    .expect("CreateDataPropertyOrThrow must not fail on a new object");
+            }
+
+            result.push(o.into());
+        }
+
+        Array::create_array_from_list(result, context).into()
+    }
+
+    /// `Intl.DateTimeFormat.prototype.formatToParts ( date )`
+    ///
+    /// Returns an array with the parts of the formatted date.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DateTimeFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = Self::this_date_time_format(this, "formatToParts")?;
+
+        // 3-4. Let x be the time value of date.
+        let x = date_time_value(args.get_or_undefined(0), context)?;
+
+        // 5. Return ? FormatDateTimeToParts(dtf, x).
+        let parts = dtf
+            .borrow()
+            .as_date_time_format()
+            .expect("the object must be a DateTimeFormat")
+            .partition_date_time_pattern(x)?;
+        Ok(Self::parts_to_array(
+            parts.into_iter().map(|part| (part, None)),
+            context,
+        ))
+    }
+
+    /// Gets the time values of the `startDate` and `endDate` arguments of the range format
+    /// methods.
+    fn range_values(args: &[JsValue], context: &mut Context) -> JsResult<(f64, f64)> {
+        let start_date = args.get_or_undefined(0);
+        let end_date = args.get_or_undefined(1);
+
+        // 3. If startDate is undefined or endDate is undefined, throw a TypeError exception.
+        if start_date.is_undefined() || end_date.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("startDate and endDate must not be undefined")
+                .into());
+        }
+
+        // 4. Let x be ? ToNumber(startDate).
+        // 5. Let y be ? ToNumber(endDate).
+        Ok((start_date.to_number(context)?, end_date.to_number(context)?))
+    }
+
+    /// `Intl.DateTimeFormat.prototype.formatRange ( startDate, endDate )`
+    ///
+    /// Returns a string with the range between two dates.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.formatRange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatRange
+    fn format_range(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dtf be this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = Self::this_date_time_format(this, "formatRange")?;
+
+        // 3-5. Let x and y be the time values of startDate and endDate.
+        let (x, y) = Self::range_values(args, context)?;
+
+        // 6. Return ? FormatDateTimeRange(dtf, x, y).
+        let result = dtf
+            .borrow()
+            .as_date_time_format()
+            .expect("the object must be a DateTimeFormat")
+            .partition_date_time_range_pattern(x, y)?
+            .into_iter()
+            .map(|(part, _)| part.value)
+            .collect::<String>();
+        Ok(JsString::new(result).into())
+    }
+
+    /// `Intl.DateTimeFormat.prototype.formatRangeToParts ( startDate, endDate )`
+    ///
+    /// Returns an array with the parts of the range between two dates.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DateTimeFormat.prototype.formatRangeToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatRangeToParts
+    fn format_range_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let dtf be this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = Self::this_date_time_format(this, "formatRangeToParts")?;
+
+        // 3-5. Let x and y be the time values of startDate and endDate.
+        let (x, y) = Self::range_values(args, context)?;
+
+        // 6. Return ? FormatDateTimeRangeToParts(dtf, x, y).
+        let parts = dtf
+            .borrow()
+            .as_date_time_format()
+            .expect("the object must be a DateTimeFormat")
+            .partition_date_time_range_pattern(x, y)?;
+        Ok(Self::parts_to_array(
+            parts.into_iter().map(|(part, source)| (part, Some(source))),
+            context,
+        ))
+    }
+
+    /// `Intl.DateTimeFormat.prototype.resolvedOptions ( )`
+    ///
+    /// Returns an object with the locale and options computed during the initialization of the
+    /// `DateTimeFormat`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 2. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Set dtf to ? UnwrapDateTimeFormat(dtf).
+        // 3. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = Self::this_date_time_format(this, "resolvedOptions")?;
+        let dtf = dtf.borrow();
+        let dtf = dtf
+            .as_date_time_format()
+            .expect("the object must be a DateTimeFormat");
+
+        // 5. For each row of Table 6, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. If p is "hour12", then
+        //         i. Let hc be dtf.[[HourCycle]].
+        //         ii. If hc is "h11" or "h12", let v be true.
+        //         iii. Else if, hc is "h23" or "h24", let v be false.
+        //         iv. Else, let v be undefined.
+        //     c. Else,
+        //         i. Let v be the value of dtf's internal slot whose name is the Internal Slot value of the current row.
+        //     d. If the Internal Slot value of the current row is an Internal Slot value in Table 7, then
+        //         i. If dtf.[[DateStyle]] is not undefined or dtf.[[TimeStyle]] is not undefined, then
+        //             1. Let v be undefined.
+        //     e. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut properties: Vec<(&str, JsValue)> = vec![
+            ("locale", JsString::new(dtf.locale.to_string()).into()),
+            ("calendar", JsString::new(&dtf.calendar).into()),
+            (
+                "numberingSystem",
+                JsString::new(&dtf.numbering_system).into(),
+            ),
+            ("timeZone", JsString::new(dtf.time_zone.name()).into()),
+        ];
+        if let Some(hour_cycle) = dtf.hour_cycle {
+            properties.push(("hourCycle", hour_cycle.name().into()));
+            properties.push(("hour12", hour_cycle.is_12_hour().into()));
+        }
+        if dtf.date_style.is_none() && dtf.time_style.is_none() {
+            let components = pattern_components(&dtf.pattern).unwrap_or_default();
+            for component in Component::ALL {
+                let value = match (components.get(component), components.width(component)) {
+                    (_, Some(width)) => width.name().into(),
+                    (Some(digits), None) => digits.into(),
+                    (None, None) => continue,
+                };
+                properties.push((component.property(), value));
+            }
+        }
+        if let Some(date_style) = dtf.date_style {
+            properties.push(("dateStyle", date_style.name().into()));
+        }
+        if let Some(time_style) = dtf.time_style {
+            properties.push(("timeStyle", time_style.name().into()));
+        }
+
+        // 4. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = JsObject::from_proto_and_data(
+            context.intrinsics().constructors().object().prototype(),
+            ObjectData::ordinary(),
+        );
+        for (property, value) in properties {
+            options
+                .create_data_property_or_throw(property, value, context)
+                .expect("CreateDataPropertyOrThrow must not fail on a new object");
+        }
+
+        // 6. Return options.
+        Ok(options.into())
+    }
+}
+
+/// Formats the time value `x` with a new `DateTimeFormat` created from `locales` and `options`,
+/// as done by the `toLocaleString`, `toLocaleDateString` and `toLocaleTimeString` methods of
+/// `Date`.
+pub(crate) fn format_date_time_to_locale_string(
+    x: f64,
+    locales: &JsValue,
+    options: &JsValue,
+    required: &DateTimeReqs,
+    defaults: &DateTimeReqs,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 3. Let options be ? ToDateTimeOptions(options, required, defaults).
+    // 4. Let dateFormat be ? Construct(%DateTimeFormat%, « locales, options »).
+    let date_format =
+        DateTimeFormat::initialize(&[locales.clone()], options, required, defaults, context)?;
+
+    // 5. Return ? FormatDateTime(dateFormat, x).
+    Ok(JsString::new(date_format.format_date_time(x)?).into())
+}
+
+/// Represents the `required` and `defaults` arguments in the abstract operation
+/// `toDateTimeOptions`.
+///
+/// Since `required` and `defaults` differ only in the `any` and `all` variants,
+/// we combine both in a single variant `AnyAll`.
+#[derive(Debug, PartialEq)]
+pub(crate) enum DateTimeReqs {
+    Date,
+    Time,
+    AnyAll,
+}
+
+/// The abstract operation `toDateTimeOptions` is called with arguments `options`, `required` and
+/// `defaults`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-todatetimeoptions
+pub(crate) fn to_date_time_options(
+    options: &JsValue,
+    required: &DateTimeReqs,
+    defaults: &DateTimeReqs,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    // 1. If options is undefined, let options be null;
+    // otherwise let options be ? ToObject(options).
+    // 2. Let options be ! OrdinaryObjectCreate(options).
+    let options = if options.is_undefined() {
+        None
+    } else {
+        Some(options.to_object(context)?)
+    };
+    let options = JsObject::from_proto_and_data(options, ObjectData::ordinary());
+
+    // 3. Let needDefaults be true.
+    let mut need_defaults = true;
+
+    // 4. If required is "date" or "any", then
+    if [DateTimeReqs::Date, DateTimeReqs::AnyAll].contains(required) {
+        // a. For each property name prop of « "weekday", "year", "month", "day" », do
+        for property in ["weekday", "year", "month", "day"] {
+            // i. Let value be ? Get(options, prop).
+            let value = options.get(property, context)?;
+
+            // ii. If value is not undefined, let needDefaults be false.
+            if !value.is_undefined() {
+                need_defaults = false;
+            }
+        }
+    }
+
+    // 5. If required is "time" or "any", then
+    if [DateTimeReqs::Time, DateTimeReqs::AnyAll].contains(required) {
+        // a. For each property name prop of « "dayPeriod", "hour", "minute", "second",
+        // "fractionalSecondDigits" », do
+        for property in [
+            "dayPeriod",
+            "hour",
+            "minute",
+            "second",
+            "fractionalSecondDigits",
+        ] {
+            // i. Let value be ? Get(options, prop).
+            let value = options.get(property, context)?;
+
+            // ii. If value is not undefined, let needDefaults be false.
+            if !value.is_undefined() {
+                need_defaults = false;
+            }
+        }
+    }
+
+    // 6. Let dateStyle be ? Get(options, "dateStyle").
+    let date_style = options.get("dateStyle", context)?;
+
+    // 7. Let timeStyle be ? Get(options, "timeStyle").
+    let time_style = options.get("timeStyle", context)?;
+
+    // 8. If dateStyle is not undefined or timeStyle is not undefined, let needDefaults be false.
+    if !date_style.is_undefined() || !time_style.is_undefined() {
+        need_defaults = false;
+    }
+
+    // 9. If required is "date" and timeStyle is not undefined, then
+    if required == &DateTimeReqs::Date && !time_style.is_undefined() {
+        // a. Throw a TypeError exception.
+        return Err(JsNativeError::typ()
+            .with_message("'date' is required, but timeStyle was defined")
+            .into());
+    }
+
+    // 10. If required is "time" and dateStyle is not undefined, then
+    if required == &DateTimeReqs::Time && !date_style.is_undefined() {
+        // a. Throw a TypeError exception.
+        return Err(JsNativeError::typ()
+            .with_message("'time' is required, but dateStyle was defined")
+            .into());
+    }
+
+    // 11. If needDefaults is true and defaults is either "date" or "all", then
+    if need_defaults && [DateTimeReqs::Date, DateTimeReqs::AnyAll].contains(defaults) {
+        // a. For each property name prop of « "year", "month", "day" », do
+        for property in ["year", "month", "day"] {
+            // i. Perform ? CreateDataPropertyOrThrow(options, prop, "numeric").
+            options.create_data_property_or_throw(property, "numeric", context)?;
+        }
+    }
+
+    // 12. If needDefaults is true and defaults is either "time" or "all", then
+    if need_defaults && [DateTimeReqs::Time, DateTimeReqs::AnyAll].contains(defaults) {
+        // a. For each property name prop of « "hour", "minute", "second" », do
+        for property in ["hour", "minute", "second"] {
+            // i. Perform ? CreateDataPropertyOrThrow(options, prop, "numeric").
+            options.create_data_property_or_throw(property, "numeric", context)?;
+        }
+    }
+
+    // 13. Return options.
+    Ok(options)
+}
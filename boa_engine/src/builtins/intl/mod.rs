@@ -8,7 +8,7 @@
 //! [spec]: https://tc39.es/ecma402/#intl-object
 
 use crate::{
-    builtins::intl::date_time_format::DateTimeFormat,
+    builtins::intl::{date_time_format::DateTimeFormat, number_format::NumberFormat},
     builtins::{Array, BuiltIn, JsArgs},
     object::{JsObject, ObjectInitializer},
     property::Attribute,
@@ -17,6 +17,7 @@ use crate::{
 };
 
 pub mod date_time_format;
+pub mod number_format;
 #[cfg(test)]
 mod tests;
 
@@ -39,6 +40,7 @@ impl BuiltIn for Intl {
 
         let string_tag = WellKnownSymbols::to_string_tag();
         let date_time_format = DateTimeFormat::init(context);
+        let number_format = NumberFormat::init(context);
         ObjectInitializer::new(context)
             .function(Self::get_canonical_locales, "getCanonicalLocales", 1)
             .property(
@@ -51,6 +53,11 @@ impl BuiltIn for Intl {
                 date_time_format,
                 Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
             )
+            .property(
+                "NumberFormat",
+                number_format,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
             .build()
             .conv::<JsValue>()
             .pipe(Some)
@@ -77,7 +84,7 @@ impl Intl {
         let ll = canonicalize_locale_list(args, context)?;
 
         // 2. Return CreateArrayFromList(ll).
-        Ok(JsValue::Object(Array::create_array_from_list(
+        Ok(JsValue::new(Array::create_array_from_list(
             ll.into_iter().map(|loc| loc.to_string().into()),
             context,
         )))
@@ -459,7 +466,9 @@ fn canonicalize_locale_list(args: &[JsValue], context: &mut Context) -> JsResult
             let k_value = o.get(k, context)?;
             // ii. If Type(kValue) is not String or Object, throw a TypeError exception.
             if !(k_value.is_object() || k_value.is_string()) {
-                return context.throw_type_error("locale should be a String or Object");
+                return Err(JsNativeError::typ()
+                    .with_message("locale should be a String or Object")
+                    .into());
             }
             // iii. If Type(kValue) is Object and kValue has an [[InitializedLocale]] internal slot, then
             // TODO: handle checks for InitializedLocale internal slot (there should be an if statement here)
@@ -469,7 +478,8 @@ fn canonicalize_locale_list(args: &[JsValue], context: &mut Context) -> JsResult
             let tag = k_value.to_string(context)?;
             // v. If IsStructurallyValidLanguageTag(tag) is false, throw a RangeError exception.
             let mut tag = tag.parse().map_err(|_| {
-                context.construct_range_error("locale is not a structurally valid language tag")
+                JsNativeError::range()
+                    .with_message("locale is not a structurally valid language tag")
             })?;
 
             // vi. Let canonicalizedTag be CanonicalizeUnicodeLocaleId(tag).
@@ -595,7 +605,7 @@ fn resolve_locale(
         // e. Let value be keyLocaleData[0].
         // TODO f. Assert: Type(value) is either String or Null.
         let mut value = match key_locale_data.get(0) {
-            Some(first_elt) => JsValue::String(first_elt.clone()),
+            Some(first_elt) => JsValue::new(first_elt.clone()),
             None => JsValue::null(),
         };
 
@@ -616,7 +626,7 @@ fn resolve_locale(
                     // a. If keyLocaleData contains requestedValue, then
                     if key_locale_data.contains(requested_value) {
                         // i. Let value be requestedValue.
-                        value = JsValue::String(JsString::new(requested_value));
+                        value = JsValue::new(JsString::new(requested_value));
                         // ii. Let supportedExtensionAddition be the string-concatenation
                         // of "-", key, "-", and value.
                         supported_extension_addition =
@@ -625,7 +635,7 @@ fn resolve_locale(
                 // 4. Else if keyLocaleData contains "true", then
                 } else if key_locale_data.contains(&JsString::new("true")) {
                     // a. Let value be "true".
-                    value = JsValue::String(JsString::new("true"));
+                    value = JsValue::new(JsString::new("true"));
                     // b. Let supportedExtensionAddition be the string-concatenation of "-" and key.
                     supported_extension_addition = JsString::concat_array(&["-", key]);
                 }
@@ -659,7 +669,7 @@ fn resolve_locale(
                 if let Some(options_val_str) = options_value.as_string() {
                     if options_val_str.is_empty() {
                         // a. Let optionsValue be "true".
-                        options_value = JsValue::String(JsString::new("true"));
+                        options_value = JsValue::new(JsString::new("true"));
                     }
                 }
             }
@@ -747,13 +757,15 @@ pub(crate) fn get_option(
     // 7. If values is not undefined and values does not contain an element equal to value,
     // throw a RangeError exception.
     value = match r#type {
-        GetOptionType::Boolean => JsValue::Boolean(value.to_boolean()),
+        GetOptionType::Boolean => JsValue::new(value.to_boolean()),
         GetOptionType::String => {
             let string_value = value.to_string(context)?;
             if !values.is_empty() && !values.contains(&string_value) {
-                return context.throw_range_error("GetOption: values array does not contain value");
+                return Err(JsNativeError::range()
+                    .with_message("GetOption: values array does not contain value")
+                    .into());
             }
-            JsValue::String(string_value)
+            JsValue::new(string_value)
         }
     };
 
@@ -815,7 +827,9 @@ pub(crate) fn default_number_option(
 
     // 3. If value is NaN or less than minimum or greater than maximum, throw a RangeError exception.
     if value.is_nan() || value < minimum || value > maximum {
-        return context.throw_range_error("DefaultNumberOption: value is out of range.");
+        return Err(JsNativeError::range()
+            .with_message("DefaultNumberOption: value is out of range.")
+            .into());
     }
 
     // 4. Return floor(value).
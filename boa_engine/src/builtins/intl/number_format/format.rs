@@ -0,0 +1,971 @@
+//! The number formatting abstract operations of `Intl.NumberFormat`.
+//!
+//! Numbers are formatted from their decimal digits, so the rounding is exact. The decimal
+//! symbols of the locale come from the ICU data provider, which has no data for the names of
+//! currencies and units, nor for the compact notation. Those are only available in English, and
+//! the other locales fall back to the data of the root locale, see
+//! [`NumberFormat::has_english_data`].
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma402/#sec-number-format-functions
+
+use super::{
+    CompactDisplay, CurrencyDisplay, CurrencySign, Notation, NumberFormat, RoundingType,
+    SignDisplay, Style, UnitDisplay,
+};
+use crate::{value::Numeric, JsBigInt};
+
+/// A finite non-negative decimal number.
+///
+/// The value is `0.d₁d₂d₃… × 10^point`, where `dᵢ` are the significant digits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Decimal {
+    /// The significant digits, without leading or trailing zeros. It's empty for zero.
+    digits: Vec<u8>,
+    /// The number of digits before the decimal point, which can be negative or larger than the
+    /// number of significant digits.
+    point: i32,
+}
+
+impl Decimal {
+    /// The decimal zero.
+    const ZERO: Self = Self {
+        digits: Vec::new(),
+        point: 0,
+    };
+
+    /// Creates a decimal from the absolute value of a finite number, using the shortest digits
+    /// that round-trip to the number.
+    pub(crate) fn from_f64(x: f64) -> Self {
+        debug_assert!(x.is_finite());
+        let mut buffer = ryu_js::Buffer::new();
+        let string = buffer.format_finite(x.abs());
+        let (mantissa, exponent) = match string.split_once('e') {
+            Some((mantissa, exponent)) => (mantissa, exponent.parse::<i32>().unwrap_or_default()),
+            None => (string, 0),
+        };
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        Self::new(
+            integer
+                .bytes()
+                .chain(fraction.bytes())
+                .map(|c| c - b'0')
+                .collect(),
+            integer.len() as i32 + exponent,
+        )
+    }
+
+    /// Creates a decimal from the absolute value of a `BigInt`.
+    pub(crate) fn from_bigint(x: &JsBigInt) -> Self {
+        let string = x.to_string_radix(10);
+        let digits = string.trim_start_matches('-');
+        Self::new(
+            digits.bytes().map(|c| c - b'0').collect(),
+            digits.len() as i32,
+        )
+    }
+
+    /// Creates a decimal from its digits, removing the leading and trailing zeros.
+    fn new(mut digits: Vec<u8>, mut point: i32) -> Self {
+        let leading = digits.iter().take_while(|d| **d == 0).count();
+        digits.drain(..leading);
+        point -= leading as i32;
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        if digits.is_empty() {
+            return Self::ZERO;
+        }
+        Self { digits, point }
+    }
+
+    /// Returns `true` if the decimal is zero.
+    pub(crate) fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    /// Returns the magnitude of the decimal, `floor(log10(x))`, which must not be zero.
+    fn magnitude(&self) -> i32 {
+        self.point - 1
+    }
+
+    /// Multiplies the decimal by `10^exponent`.
+    fn scale(&self, exponent: i32) -> Self {
+        if self.is_zero() {
+            return Self::ZERO;
+        }
+        Self {
+            digits: self.digits.clone(),
+            point: self.point + exponent,
+        }
+    }
+
+    /// Gets the digit at `index`, counted from the first significant digit.
+    fn digit(&self, index: i32) -> char {
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| self.digits.get(index))
+            .map_or('0', |d| char::from(b'0' + d))
+    }
+
+    /// Rounds the decimal to `fraction_digits` digits after the decimal point, rounding half
+    /// away from zero.
+    fn round(&self, fraction_digits: i32) -> Self {
+        let keep = self.point + fraction_digits;
+        if self.is_zero() || keep < 0 {
+            return Self::ZERO;
+        }
+        let keep = keep as usize;
+        if keep >= self.digits.len() {
+            return self.clone();
+        }
+        let mut digits = self.digits[..keep].to_vec();
+        let mut point = self.point;
+        if self.digits[keep] >= 5 {
+            loop {
+                match digits.last_mut() {
+                    Some(9) => {
+                        digits.pop();
+                    }
+                    Some(digit) => {
+                        *digit += 1;
+                        break;
+                    }
+                    None => {
+                        digits.push(1);
+                        point += 1;
+                        break;
+                    }
+                }
+            }
+        }
+        Self::new(digits, point)
+    }
+
+    /// Writes the decimal with `fraction_digits` digits after the decimal point.
+    fn to_raw(&self, fraction_digits: i32) -> RawNumber {
+        let integer = if self.point <= 0 {
+            "0".to_string()
+        } else {
+            (0..self.point).map(|i| self.digit(i)).collect()
+        };
+        let fraction = (self.point..self.point + fraction_digits)
+            .map(|i| self.digit(i))
+            .collect();
+        RawNumber {
+            rounded: self.clone(),
+            integer,
+            fraction,
+        }
+    }
+
+    /// Abstract operation `ToRawPrecision ( x, minPrecision, maxPrecision )`
+    fn to_raw_precision(&self, min_precision: u8, max_precision: u8) -> RawNumber {
+        if self.is_zero() {
+            return Self::ZERO.to_raw(i32::from(min_precision) - 1);
+        }
+        let rounded = self.round(i32::from(max_precision) - self.point);
+        let significant = (rounded.digits.len() as i32).max(i32::from(min_precision));
+        rounded.to_raw((significant - rounded.point).max(0))
+    }
+
+    /// Abstract operation `ToRawFixed ( x, minInteger, minFraction, maxFraction )`
+    fn to_raw_fixed(&self, min_fraction: u8, max_fraction: u8) -> RawNumber {
+        let rounded = self.round(i32::from(max_fraction));
+        let fraction = (rounded.digits.len() as i32 - rounded.point).max(i32::from(min_fraction));
+        rounded.to_raw(fraction.max(0))
+    }
+}
+
+/// The result of `FormatNumericToString`.
+#[derive(Debug)]
+struct RawNumber {
+    /// The rounded number.
+    rounded: Decimal,
+    /// The ASCII digits of the integer part.
+    integer: String,
+    /// The ASCII digits of the fraction part.
+    fraction: String,
+}
+
+/// A part of a formatted number, as returned by `formatToParts`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Part {
+    pub(crate) kind: &'static str,
+    pub(crate) value: String,
+}
+
+impl Part {
+    fn new<S: Into<String>>(kind: &'static str, value: S) -> Self {
+        Self {
+            kind,
+            value: value.into(),
+        }
+    }
+}
+
+impl NumberFormat {
+    /// Abstract operation `FormatNumericToString ( intlObject, x )`
+    fn format_numeric_to_string(&self, x: &Decimal) -> RawNumber {
+        let mut result = match self.rounding {
+            RoundingType::SignificantDigits { min, max } => x.to_raw_precision(min, max),
+            RoundingType::FractionDigits { min, max } => x.to_raw_fixed(min, max),
+            RoundingType::Compact => {
+                let result = x.to_raw_precision(1, 2);
+                if result.integer.len() > 1 {
+                    x.to_raw_fixed(0, 0)
+                } else {
+                    result
+                }
+            }
+        };
+
+        let minimum_integer_digits = usize::from(self.minimum_integer_digits);
+        if result.integer.len() < minimum_integer_digits {
+            let padding = "0".repeat(minimum_integer_digits - result.integer.len());
+            result.integer.insert_str(0, &padding);
+        }
+        result
+    }
+
+    /// Abstract operation `ComputeExponentForMagnitude ( numberFormat, magnitude )`
+    fn compute_exponent_for_magnitude(&self, magnitude: i32) -> i32 {
+        match self.notation {
+            Notation::Standard => 0,
+            Notation::Scientific => magnitude,
+            Notation::Engineering => magnitude.div_euclid(3) * 3,
+            Notation::Compact(_) if magnitude < 3 => 0,
+            Notation::Compact(_) => (magnitude.div_euclid(3) * 3).min(12),
+        }
+    }
+
+    /// Abstract operation `ComputeExponent ( numberFormat, x )`
+    fn compute_exponent(&self, x: &Decimal) -> i32 {
+        // 1. If x = 0, then
+        if x.is_zero() {
+            // a. Return 0.
+            return 0;
+        }
+
+        // 2. If x < 0, then
+        //     a. Let x = -x.
+        // 3. Let magnitude be the base 10 logarithm of x rounded down to the nearest integer.
+        let magnitude = x.magnitude();
+
+        // 4. Let exponent be ComputeExponentForMagnitude(numberFormat, magnitude).
+        let exponent = self.compute_exponent_for_magnitude(magnitude);
+
+        // 5. Let x be x × 10^(-exponent).
+        // 6. Let formatNumberResult be FormatNumericToString(numberFormat, x).
+        let result = self.format_numeric_to_string(&x.scale(-exponent));
+
+        // 7. If formatNumberResult.[[RoundedNumber]] = 0, then
+        if result.rounded.is_zero() {
+            // a. Return exponent.
+            return exponent;
+        }
+
+        // 8. Let newMagnitude be the base 10 logarithm of formatNumberResult.[[RoundedNumber]] rounded down to the nearest integer.
+        // 9. If newMagnitude is magnitude - exponent, then
+        if result.rounded.magnitude() == magnitude - exponent {
+            // a. Return exponent.
+            return exponent;
+        }
+
+        // 10. Return ComputeExponentForMagnitude(numberFormat, magnitude + 1).
+        self.compute_exponent_for_magnitude(magnitude + 1)
+    }
+
+    /// Transliterates the ASCII digits of `digits` to the numbering system of the number format.
+    fn transliterate(&self, digits: &str) -> String {
+        digits
+            .bytes()
+            .map(|d| self.digits[usize::from(d - b'0')])
+            .collect()
+    }
+
+    /// Pushes the parts of the integer digits `integer`, with the grouping separators.
+    fn push_integer_parts(&self, integer: &str, parts: &mut Vec<Part>) {
+        let symbols = &self.symbols;
+        let primary = usize::from(symbols.primary_group_size);
+        let secondary = usize::from(symbols.secondary_group_size);
+        let min_grouping = usize::from(symbols.min_grouping).max(1);
+
+        if !self.use_grouping || primary == 0 || integer.len() < primary + min_grouping {
+            parts.push(Part::new("integer", self.transliterate(integer)));
+            return;
+        }
+
+        // The groups, from the least significant one.
+        let mut groups = Vec::new();
+        let mut end = integer.len();
+        let mut size = primary;
+        while end > size {
+            groups.push(&integer[end - size..end]);
+            end -= size;
+            size = if secondary == 0 { primary } else { secondary };
+        }
+        groups.push(&integer[..end]);
+
+        for (i, group) in groups.iter().rev().enumerate() {
+            if i > 0 {
+                parts.push(Part::new("group", symbols.grouping_separator.clone()));
+            }
+            parts.push(Part::new("integer", self.transliterate(group)));
+        }
+    }
+
+    /// Abstract operation `PartitionNumberPattern ( numberFormat, x )`
+    ///
+    /// Returns the parts of the formatted number.
+    pub(crate) fn partition_number_pattern(&self, x: &Numeric) -> Vec<Part> {
+        // 1. Let exponent be 0.
+        let mut exponent = 0;
+
+        // The sign of x, and the number without its sign.
+        let (negative, value) = match x {
+            Numeric::Number(x) if x.is_nan() => (false, None),
+            Numeric::Number(x) if x.is_infinite() => (x.is_sign_negative(), None),
+            Numeric::Number(x) => (x.is_sign_negative(), Some(Decimal::from_f64(*x))),
+            Numeric::BigInt(x) => (*x < JsBigInt::zero(), Some(Decimal::from_bigint(x))),
+        };
+
+        let is_nan = matches!(x, Numeric::Number(x) if x.is_nan());
+        let mut number = Vec::new();
+        let mut rounded_zero = false;
+        let mut formatted_integer = None;
+        match (x, value) {
+            // 2. If x is NaN, then
+            //     a. Let n be an implementation- and locale-dependent (ILD) String value indicating the NaN value.
+            (_, None) if is_nan => number.push(Part::new("nan", "NaN")),
+            // 3. Else if x is +∞, then
+            //     a. Let n be an ILD String value indicating positive infinity.
+            // 4. Else if x is -∞, then
+            //     a. Let n be an ILD String value indicating negative infinity.
+            (_, None) => number.push(Part::new("infinity", "∞")),
+            // 5. Else,
+            (_, Some(mut value)) => {
+                // a. If x is not -0𝔽, then
+                //     i. Let x be ℝ(x).
+                //     ii. If numberFormat.[[Style]] is "percent", let x be 100 × x.
+                if self.style == Style::Percent {
+                    value = value.scale(2);
+                }
+
+                //     iii. Let exponent be ComputeExponent(numberFormat, x).
+                exponent = self.compute_exponent(&value);
+
+                //     iv. Let x be x × 10^-exponent.
+                value = value.scale(-exponent);
+
+                // b. Let formatNumberResult be FormatNumericToString(numberFormat, x).
+                // c. Let n be formatNumberResult.[[FormattedString]].
+                // d. Let x be formatNumberResult.[[RoundedNumber]].
+                let result = self.format_numeric_to_string(&value);
+                rounded_zero = result.rounded.is_zero();
+
+                self.push_integer_parts(&result.integer, &mut number);
+                if !result.fraction.is_empty() {
+                    number.push(Part::new("decimal", self.symbols.decimal_separator.clone()));
+                    number.push(Part::new("fraction", self.transliterate(&result.fraction)));
+                }
+                formatted_integer = Some((result.integer, result.fraction));
+            }
+        }
+
+        // The exponent of the scientific and engineering notations, or the compact suffix.
+        match self.notation {
+            Notation::Scientific | Notation::Engineering if formatted_integer.is_some() => {
+                number.push(Part::new("exponentSeparator", "E"));
+                if exponent < 0 {
+                    number.push(Part::new("exponentMinusSign", "-"));
+                }
+                number.push(Part::new(
+                    "exponentInteger",
+                    self.transliterate(&exponent.unsigned_abs().to_string()),
+                ));
+            }
+            Notation::Compact(display) if exponent > 0 => {
+                let (short, long, root) = match exponent {
+                    3 => ("K", "thousand", "K"),
+                    6 => ("M", "million", "M"),
+                    9 => ("B", "billion", "G"),
+                    _ => ("T", "trillion", "T"),
+                };
+                match display {
+                    _ if !self.has_english_data() => number.push(Part::new("compact", root)),
+                    CompactDisplay::Short => number.push(Part::new("compact", short)),
+                    CompactDisplay::Long => {
+                        number.push(Part::new("literal", " "));
+                        number.push(Part::new("compact", long));
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        // 6. Let pattern be GetNumberFormatPattern(numberFormat, x).
+        let sign = match self.sign_display {
+            SignDisplay::Never => None,
+            SignDisplay::Auto => negative.then_some(true),
+            SignDisplay::Always => Some(negative),
+            SignDisplay::ExceptZero if rounded_zero || is_nan => None,
+            SignDisplay::ExceptZero => Some(negative),
+        };
+        let accounting = self.style == Style::Currency
+            && self.currency_sign == CurrencySign::Accounting
+            && sign == Some(true);
+
+        // The plural form of the English patterns is "one" for the integer 1 only.
+        let one = matches!(&formatted_integer, Some((integer, fraction)) if integer.trim_start_matches('0') == "1" && fraction.is_empty());
+
+        // 7-8. Let result be a new empty List, and for each pattern part, append its parts.
+        let mut result = Vec::new();
+        let (sign_prefix, sign_suffix) = match sign {
+            Some(true) => (
+                self.symbols.minus_sign_prefix.as_str(),
+                self.symbols.minus_sign_suffix.as_str(),
+            ),
+            Some(false) => (
+                self.symbols.plus_sign_prefix.as_str(),
+                self.symbols.plus_sign_suffix.as_str(),
+            ),
+            None => ("", ""),
+        };
+        let sign_kind = if sign == Some(true) {
+            "minusSign"
+        } else {
+            "plusSign"
+        };
+
+        if accounting {
+            result.push(Part::new("literal", "("));
+        } else if !sign_prefix.is_empty() {
+            result.push(Part::new(sign_kind, sign_prefix));
+        }
+
+        match self.style {
+            Style::Decimal => result.extend(number),
+            Style::Percent => {
+                result.extend(number);
+                result.push(Part::new("percentSign", "%"));
+            }
+            Style::Currency => {
+                let code = self.currency.as_deref().unwrap_or_default();
+                let display = if self.has_english_data() {
+                    self.currency_display
+                } else {
+                    CurrencyDisplay::Code
+                };
+                match display {
+                    CurrencyDisplay::Code => {
+                        result.push(Part::new("currency", code));
+                        result.push(Part::new("literal", "\u{a0}"));
+                        result.extend(number);
+                    }
+                    CurrencyDisplay::Symbol | CurrencyDisplay::NarrowSymbol => {
+                        let symbol =
+                            currency_symbol(code, display == CurrencyDisplay::NarrowSymbol);
+                        let alphabetic = symbol.chars().last().map_or(false, char::is_alphabetic);
+                        result.push(Part::new("currency", symbol));
+                        if alphabetic {
+                            result.push(Part::new("literal", "\u{a0}"));
+                        }
+                        result.extend(number);
+                    }
+                    CurrencyDisplay::Name => {
+                        result.extend(number);
+                        result.push(Part::new("literal", " "));
+                        result.push(Part::new("currency", currency_name(code, one)));
+                    }
+                }
+            }
+            Style::Unit => {
+                let unit = self.unit.as_deref().unwrap_or_default();
+                let pattern = if self.has_english_data() {
+                    unit_pattern(unit, self.unit_display, one)
+                } else if self.unit_display == UnitDisplay::Long {
+                    unit_pattern(unit, UnitDisplay::Short, true)
+                } else {
+                    unit_pattern(unit, self.unit_display, true)
+                };
+                let (before, after) = pattern.split_once("{0}").unwrap_or(("", pattern.as_str()));
+                push_unit_affix(before, false, &mut result);
+                result.extend(number);
+                push_unit_affix(after, true, &mut result);
+            }
+        }
+
+        if accounting {
+            result.push(Part::new("literal", ")"));
+        } else if !sign_suffix.is_empty() {
+            result.push(Part::new(sign_kind, sign_suffix));
+        }
+
+        // 9. Return result.
+        result
+    }
+
+    /// Returns `true` if the English names and patterns of the tables below are used for the
+    /// locale of the number format.
+    ///
+    /// The ICU data provider has no localized counterpart of those tables, so the other locales
+    /// fall back to the data of the root locale: the ISO 4217 code of the currencies before the
+    /// number, the abbreviated singular patterns of the units, and the short suffixes of the
+    /// compact notation.
+    fn has_english_data(&self) -> bool {
+        let language = &self.locale.id.language;
+        language.is_empty() || language.as_str() == "en"
+    }
+
+    /// Abstract operation `FormatNumeric ( numberFormat, x )`
+    pub(crate) fn format_numeric(&self, x: &Numeric) -> String {
+        // 1. Let parts be ? PartitionNumberPattern(numberFormat, x).
+        // 2. Let result be the empty String.
+        // 3. For each Record { [[Type]], [[Value]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        // 4. Return result.
+        self.partition_number_pattern(x)
+            .into_iter()
+            .map(|part| part.value)
+            .collect()
+    }
+}
+
+/// Pushes the parts of the text before or after the number in a unit pattern, where the
+/// whitespace next to the number is a literal.
+fn push_unit_affix(affix: &str, after: bool, parts: &mut Vec<Part>) {
+    let unit = affix.trim();
+    if unit.is_empty() {
+        if !affix.is_empty() {
+            parts.push(Part::new("literal", affix));
+        }
+        return;
+    }
+    let (leading, trailing) = affix.split_at(affix.find(unit).unwrap_or_default());
+    let trailing = &trailing[unit.len()..];
+    if after && !leading.is_empty() {
+        parts.push(Part::new("literal", leading));
+    }
+    parts.push(Part::new("unit", unit));
+    if !after && !trailing.is_empty() {
+        parts.push(Part::new("literal", trailing));
+    }
+}
+
+/// The number of digits after the decimal separator of the amounts of the currencies whose minor
+/// unit isn't the hundredth, according to ISO 4217.
+///
+/// This isn't locale data, so it's used for all the locales.
+const CURRENCY_DIGITS: [(&str, u8); 28] = [
+    ("BHD", 3),
+    ("BIF", 0),
+    ("CLF", 4),
+    ("CLP", 0),
+    ("DJF", 0),
+    ("GNF", 0),
+    ("IQD", 3),
+    ("ISK", 0),
+    ("JOD", 3),
+    ("JPY", 0),
+    ("KMF", 0),
+    ("KRW", 0),
+    ("KWD", 3),
+    ("LYD", 3),
+    ("OMR", 3),
+    ("PYG", 0),
+    ("RWF", 0),
+    ("TND", 3),
+    ("UGX", 0),
+    ("UYI", 0),
+    ("UYW", 4),
+    ("VND", 0),
+    ("VUV", 0),
+    ("XAF", 0),
+    ("XOF", 0),
+    ("XPF", 0),
+    ("XXX", 0),
+    ("XTS", 0),
+];
+
+/// Abstract operation `CurrencyDigits ( currency )`
+pub(super) fn currency_digits(currency: &str) -> u8 {
+    CURRENCY_DIGITS
+        .iter()
+        .find(|(code, _)| *code == currency)
+        .map_or(2, |(_, digits)| *digits)
+}
+
+/// The English symbols and names of the most used currencies, as
+/// `(code, symbol, narrow symbol, singular name, plural name)`.
+const CURRENCIES: [(&str, &str, &str, &str, &str); 20] = [
+    ("AUD", "A$", "$", "Australian dollar", "Australian dollars"),
+    ("BRL", "R$", "R$", "Brazilian real", "Brazilian reals"),
+    ("CAD", "CA$", "$", "Canadian dollar", "Canadian dollars"),
+    ("CHF", "CHF", "CHF", "Swiss franc", "Swiss francs"),
+    ("CNY", "CN¥", "¥", "Chinese yuan", "Chinese yuan"),
+    ("EUR", "€", "€", "euro", "euros"),
+    ("GBP", "£", "£", "British pound", "British pounds"),
+    ("HKD", "HK$", "$", "Hong Kong dollar", "Hong Kong dollars"),
+    ("ILS", "₪", "₪", "Israeli new shekel", "Israeli new shekels"),
+    ("INR", "₹", "₹", "Indian rupee", "Indian rupees"),
+    ("JPY", "¥", "¥", "Japanese yen", "Japanese yen"),
+    ("KRW", "₩", "₩", "South Korean won", "South Korean won"),
+    ("MXN", "MX$", "$", "Mexican peso", "Mexican pesos"),
+    (
+        "NZD",
+        "NZ$",
+        "$",
+        "New Zealand dollar",
+        "New Zealand dollars",
+    ),
+    ("PHP", "₱", "₱", "Philippine peso", "Philippine pesos"),
+    ("RUB", "RUB", "₽", "Russian ruble", "Russian rubles"),
+    ("TWD", "NT$", "$", "New Taiwan dollar", "New Taiwan dollars"),
+    ("USD", "$", "$", "US dollar", "US dollars"),
+    ("VND", "₫", "₫", "Vietnamese dong", "Vietnamese dong"),
+    (
+        "XAF",
+        "FCFA",
+        "FCFA",
+        "Central African CFA franc",
+        "Central African CFA francs",
+    ),
+];
+
+/// Gets the symbol of `currency`, which is its code for the currencies without a symbol.
+fn currency_symbol(currency: &str, narrow: bool) -> String {
+    CURRENCIES
+        .iter()
+        .find(|entry| entry.0 == currency)
+        .map_or(currency, |entry| if narrow { entry.2 } else { entry.1 })
+        .to_string()
+}
+
+/// Gets the name of `currency`, which is its code for the currencies without a name.
+fn currency_name(currency: &str, one: bool) -> String {
+    CURRENCIES
+        .iter()
+        .find(|entry| entry.0 == currency)
+        .map_or(currency, |entry| if one { entry.3 } else { entry.4 })
+        .to_string()
+}
+
+/// The English patterns of the sanctioned units, as
+/// `(unit, short singular, short plural, narrow, long singular, long plural)`.
+const UNITS: [(&str, &str, &str, &str, &str, &str); 45] = [
+    ("acre", "{0} ac", "{0} ac", "{0}ac", "{0} acre", "{0} acres"),
+    ("bit", "{0} bit", "{0} bit", "{0}bit", "{0} bit", "{0} bits"),
+    (
+        "byte",
+        "{0} byte",
+        "{0} byte",
+        "{0}B",
+        "{0} byte",
+        "{0} bytes",
+    ),
+    (
+        "celsius",
+        "{0}°C",
+        "{0}°C",
+        "{0}°C",
+        "{0} degree Celsius",
+        "{0} degrees Celsius",
+    ),
+    (
+        "centimeter",
+        "{0} cm",
+        "{0} cm",
+        "{0}cm",
+        "{0} centimeter",
+        "{0} centimeters",
+    ),
+    ("day", "{0} day", "{0} days", "{0}d", "{0} day", "{0} days"),
+    (
+        "degree",
+        "{0} deg",
+        "{0} deg",
+        "{0}°",
+        "{0} degree",
+        "{0} degrees",
+    ),
+    (
+        "fahrenheit",
+        "{0}°F",
+        "{0}°F",
+        "{0}°",
+        "{0} degree Fahrenheit",
+        "{0} degrees Fahrenheit",
+    ),
+    (
+        "fluid-ounce",
+        "{0} fl oz",
+        "{0} fl oz",
+        "{0}fl oz",
+        "{0} fluid ounce",
+        "{0} fluid ounces",
+    ),
+    ("foot", "{0} ft", "{0} ft", "{0}′", "{0} foot", "{0} feet"),
+    (
+        "gallon",
+        "{0} gal",
+        "{0} gal",
+        "{0}gal",
+        "{0} gallon",
+        "{0} gallons",
+    ),
+    (
+        "gigabit",
+        "{0} Gb",
+        "{0} Gb",
+        "{0}Gb",
+        "{0} gigabit",
+        "{0} gigabits",
+    ),
+    (
+        "gigabyte",
+        "{0} GB",
+        "{0} GB",
+        "{0}GB",
+        "{0} gigabyte",
+        "{0} gigabytes",
+    ),
+    ("gram", "{0} g", "{0} g", "{0}g", "{0} gram", "{0} grams"),
+    (
+        "hectare",
+        "{0} ha",
+        "{0} ha",
+        "{0}ha",
+        "{0} hectare",
+        "{0} hectares",
+    ),
+    ("hour", "{0} hr", "{0} hr", "{0}h", "{0} hour", "{0} hours"),
+    ("inch", "{0} in", "{0} in", "{0}″", "{0} inch", "{0} inches"),
+    (
+        "kilobit",
+        "{0} kb",
+        "{0} kb",
+        "{0}kb",
+        "{0} kilobit",
+        "{0} kilobits",
+    ),
+    (
+        "kilobyte",
+        "{0} kB",
+        "{0} kB",
+        "{0}kB",
+        "{0} kilobyte",
+        "{0} kilobytes",
+    ),
+    (
+        "kilogram",
+        "{0} kg",
+        "{0} kg",
+        "{0}kg",
+        "{0} kilogram",
+        "{0} kilograms",
+    ),
+    (
+        "kilometer",
+        "{0} km",
+        "{0} km",
+        "{0}km",
+        "{0} kilometer",
+        "{0} kilometers",
+    ),
+    ("liter", "{0} L", "{0} L", "{0}L", "{0} liter", "{0} liters"),
+    (
+        "megabit",
+        "{0} Mb",
+        "{0} Mb",
+        "{0}Mb",
+        "{0} megabit",
+        "{0} megabits",
+    ),
+    (
+        "megabyte",
+        "{0} MB",
+        "{0} MB",
+        "{0}MB",
+        "{0} megabyte",
+        "{0} megabytes",
+    ),
+    ("meter", "{0} m", "{0} m", "{0}m", "{0} meter", "{0} meters"),
+    (
+        "microsecond",
+        "{0} μs",
+        "{0} μs",
+        "{0}μs",
+        "{0} microsecond",
+        "{0} microseconds",
+    ),
+    ("mile", "{0} mi", "{0} mi", "{0}mi", "{0} mile", "{0} miles"),
+    (
+        "mile-scandinavian",
+        "{0} smi",
+        "{0} smi",
+        "{0}smi",
+        "{0} mile-scandinavian",
+        "{0} miles-scandinavian",
+    ),
+    (
+        "milliliter",
+        "{0} mL",
+        "{0} mL",
+        "{0}mL",
+        "{0} milliliter",
+        "{0} milliliters",
+    ),
+    (
+        "millimeter",
+        "{0} mm",
+        "{0} mm",
+        "{0}mm",
+        "{0} millimeter",
+        "{0} millimeters",
+    ),
+    (
+        "millisecond",
+        "{0} ms",
+        "{0} ms",
+        "{0}ms",
+        "{0} millisecond",
+        "{0} milliseconds",
+    ),
+    (
+        "minute",
+        "{0} min",
+        "{0} min",
+        "{0}m",
+        "{0} minute",
+        "{0} minutes",
+    ),
+    (
+        "month",
+        "{0} mth",
+        "{0} mths",
+        "{0}m",
+        "{0} month",
+        "{0} months",
+    ),
+    (
+        "nanosecond",
+        "{0} ns",
+        "{0} ns",
+        "{0}ns",
+        "{0} nanosecond",
+        "{0} nanoseconds",
+    ),
+    (
+        "ounce",
+        "{0} oz",
+        "{0} oz",
+        "{0}oz",
+        "{0} ounce",
+        "{0} ounces",
+    ),
+    (
+        "percent",
+        "{0}%",
+        "{0}%",
+        "{0}%",
+        "{0} percent",
+        "{0} percent",
+    ),
+    (
+        "petabyte",
+        "{0} PB",
+        "{0} PB",
+        "{0}PB",
+        "{0} petabyte",
+        "{0} petabytes",
+    ),
+    (
+        "pound",
+        "{0} lb",
+        "{0} lb",
+        "{0}lb",
+        "{0} pound",
+        "{0} pounds",
+    ),
+    (
+        "second",
+        "{0} sec",
+        "{0} sec",
+        "{0}s",
+        "{0} second",
+        "{0} seconds",
+    ),
+    (
+        "stone",
+        "{0} st",
+        "{0} st",
+        "{0}st",
+        "{0} stone",
+        "{0} stones",
+    ),
+    (
+        "terabit",
+        "{0} Tb",
+        "{0} Tb",
+        "{0}Tb",
+        "{0} terabit",
+        "{0} terabits",
+    ),
+    (
+        "terabyte",
+        "{0} TB",
+        "{0} TB",
+        "{0}TB",
+        "{0} terabyte",
+        "{0} terabytes",
+    ),
+    ("week", "{0} wk", "{0} wks", "{0}w", "{0} week", "{0} weeks"),
+    ("yard", "{0} yd", "{0} yd", "{0}yd", "{0} yard", "{0} yards"),
+    ("year", "{0} yr", "{0} yrs", "{0}y", "{0} year", "{0} years"),
+];
+
+/// Abstract operation `IsSanctionedSingleUnitIdentifier ( unitIdentifier )`
+pub(super) fn is_sanctioned_single_unit_identifier(unit: &str) -> bool {
+    UNITS.iter().any(|entry| entry.0 == unit)
+}
+
+/// Gets the pattern of a single unit, where `{0}` is the number.
+fn single_unit_pattern(unit: &str, display: UnitDisplay, one: bool) -> &'static str {
+    let entry = UNITS
+        .iter()
+        .find(|entry| entry.0 == unit)
+        .expect("the unit must be sanctioned");
+    match (display, one) {
+        (UnitDisplay::Short, true) => entry.1,
+        (UnitDisplay::Short, false) => entry.2,
+        (UnitDisplay::Narrow, _) => entry.3,
+        (UnitDisplay::Long, true) => entry.4,
+        (UnitDisplay::Long, false) => entry.5,
+    }
+}
+
+/// Gets the pattern of a well-formed unit identifier, where `{0}` is the number.
+///
+/// The pattern of `X-per-Y` is the pattern of `X` followed by the name of `Y`, as in `km/h`.
+fn unit_pattern(unit: &str, display: UnitDisplay, one: bool) -> String {
+    match unit.split_once("-per-") {
+        None => single_unit_pattern(unit, display, one).to_string(),
+        Some((numerator, denominator)) => {
+            let numerator = single_unit_pattern(numerator, display, one);
+            let denominator = match display {
+                UnitDisplay::Long => {
+                    let name = single_unit_pattern(denominator, UnitDisplay::Long, true);
+                    format!(" per {}", name.trim_start_matches("{0}").trim())
+                }
+                UnitDisplay::Short | UnitDisplay::Narrow => {
+                    let symbol = single_unit_pattern(denominator, UnitDisplay::Narrow, true);
+                    format!("/{}", symbol.trim_start_matches("{0}").trim())
+                }
+            };
+            format!("{numerator}{denominator}")
+        }
+    }
+}
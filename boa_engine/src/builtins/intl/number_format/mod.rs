@@ -0,0 +1,1158 @@
+//! This module implements the global `Intl.NumberFormat` object.
+//!
+//! `Intl.NumberFormat` is a built-in object that has properties and methods for language-sensitive
+//! number formatting.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#numberformat-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat
+
+mod format;
+
+use self::format::{currency_digits, is_sanctioned_single_unit_identifier};
+use super::{
+    canonicalize_locale_list, default_locale, default_number_option, get_number_option, get_option,
+    GetOptionType,
+};
+use crate::{
+    builtins::{Array, JsArgs},
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsFunction, JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::Numeric,
+    Context, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_locid::{
+    extensions::unicode::{Key, Value},
+    LanguageIdentifier, Locale,
+};
+use icu_provider::prelude::*;
+
+/// The `[[Style]]` of a number format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Decimal,
+    Percent,
+    Currency,
+    Unit,
+}
+
+/// The `[[CurrencyDisplay]]` of a number format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CurrencyDisplay {
+    Code,
+    Symbol,
+    NarrowSymbol,
+    Name,
+}
+
+/// The `[[CurrencySign]]` of a number format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CurrencySign {
+    Standard,
+    Accounting,
+}
+
+/// The `[[UnitDisplay]]` of a number format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UnitDisplay {
+    Short,
+    Narrow,
+    Long,
+}
+
+/// The `[[CompactDisplay]]` of a number format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CompactDisplay {
+    Short,
+    Long,
+}
+
+/// The `[[Notation]]` of a number format, with the `[[CompactDisplay]]` of the compact notation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Notation {
+    Standard,
+    Scientific,
+    Engineering,
+    Compact(CompactDisplay),
+}
+
+/// The `[[SignDisplay]]` of a number format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SignDisplay {
+    Auto,
+    Never,
+    Always,
+    ExceptZero,
+}
+
+/// The `[[RoundingType]]` of a number format, with its digit options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RoundingType {
+    FractionDigits { min: u8, max: u8 },
+    SignificantDigits { min: u8, max: u8 },
+    Compact,
+}
+
+/// The decimal symbols of a locale, copied from its ICU data.
+#[derive(Debug, Clone)]
+pub(super) struct DecimalSymbols {
+    minus_sign_prefix: String,
+    minus_sign_suffix: String,
+    plus_sign_prefix: String,
+    plus_sign_suffix: String,
+    decimal_separator: String,
+    grouping_separator: String,
+    primary_group_size: u8,
+    secondary_group_size: u8,
+    min_grouping: u8,
+    pub(super) digits: [char; 10],
+}
+
+impl DecimalSymbols {
+    /// Loads the decimal symbols of `langid` from the data provider of the context.
+    fn load(langid: &LanguageIdentifier, context: &Context) -> Option<Self> {
+        let payload = ResourceProvider::<DecimalSymbolsV1Marker>::load_resource(
+            context.icu().provider(),
+            &DataRequest {
+                options: langid.clone().into(),
+                metadata: DataRequestMetadata::default(),
+            },
+        )
+        .ok()?
+        .take_payload()
+        .ok()?;
+        let symbols = payload.get();
+
+        Some(Self {
+            minus_sign_prefix: symbols.minus_sign_affixes.prefix.to_string(),
+            minus_sign_suffix: symbols.minus_sign_affixes.suffix.to_string(),
+            plus_sign_prefix: symbols.plus_sign_affixes.prefix.to_string(),
+            plus_sign_suffix: symbols.plus_sign_affixes.suffix.to_string(),
+            decimal_separator: symbols.decimal_separator.to_string(),
+            grouping_separator: symbols.grouping_separator.to_string(),
+            primary_group_size: symbols.grouping_sizes.primary,
+            secondary_group_size: symbols.grouping_sizes.secondary,
+            min_grouping: symbols.grouping_sizes.min_grouping,
+            digits: symbols.digits,
+        })
+    }
+
+    /// Looks up the available locale that best matches `langid`, by removing its subtags
+    /// until there is data for it.
+    pub(super) fn lookup(
+        langid: &LanguageIdentifier,
+        context: &Context,
+    ) -> Option<(LanguageIdentifier, Self)> {
+        let mut candidate = langid.clone();
+        loop {
+            if let Some(symbols) = Self::load(&candidate, context) {
+                return Some((candidate, symbols));
+            }
+            if !candidate.variants.is_empty() {
+                candidate.variants.clear();
+            } else if candidate.region.is_some() {
+                candidate.region = None;
+            } else if candidate.script.is_some() {
+                candidate.script = None;
+            } else {
+                return None;
+            }
+        }
+    }
+}
+
+/// The numbering systems with simple digit mappings, as `(name, zero digit)`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#table-numbering-system-digits
+const NUMBERING_SYSTEMS: [(&str, u32); 65] = [
+    ("adlm", 0x1E950),
+    ("ahom", 0x11730),
+    ("arab", 0x0660),
+    ("arabext", 0x06F0),
+    ("bali", 0x1B50),
+    ("beng", 0x09E6),
+    ("bhks", 0x11C50),
+    ("brah", 0x11066),
+    ("cakm", 0x11136),
+    ("cham", 0xAA50),
+    ("deva", 0x0966),
+    ("diak", 0x11950),
+    ("fullwide", 0xFF10),
+    ("gong", 0x11DA0),
+    ("gonm", 0x11D50),
+    ("gujr", 0x0AE6),
+    ("guru", 0x0A66),
+    ("hanidec", 0),
+    ("hmng", 0x16B50),
+    ("hmnp", 0x1E140),
+    ("java", 0xA9D0),
+    ("kali", 0xA900),
+    ("khmr", 0x17E0),
+    ("knda", 0x0CE6),
+    ("lana", 0x1A80),
+    ("lanatham", 0x1A90),
+    ("laoo", 0x0ED0),
+    ("latn", 0x0030),
+    ("lepc", 0x1C40),
+    ("limb", 0x1946),
+    ("mathbold", 0x1D7CE),
+    ("mathdbl", 0x1D7D8),
+    ("mathmono", 0x1D7F6),
+    ("mathsanb", 0x1D7EC),
+    ("mathsans", 0x1D7E2),
+    ("mlym", 0x0D66),
+    ("modi", 0x11650),
+    ("mong", 0x1810),
+    ("mroo", 0x16A60),
+    ("mtei", 0xABF0),
+    ("mymr", 0x1040),
+    ("mymrshan", 0x1090),
+    ("mymrtlng", 0xA9F0),
+    ("newa", 0x11450),
+    ("nkoo", 0x07C0),
+    ("olck", 0x1C50),
+    ("orya", 0x0B66),
+    ("osma", 0x104A0),
+    ("rohg", 0x10D30),
+    ("saur", 0xA8D0),
+    ("shrd", 0x111D0),
+    ("sind", 0x112F0),
+    ("sinh", 0x0DE6),
+    ("sora", 0x110F0),
+    ("sund", 0x1BB0),
+    ("takr", 0x116C0),
+    ("tamldec", 0x0BE6),
+    ("telu", 0x0C66),
+    ("thai", 0x0E50),
+    ("tibt", 0x0F20),
+    ("tirh", 0x114D0),
+    ("tnsa", 0x16AC0),
+    ("vaii", 0xA620),
+    ("wara", 0x118E0),
+    ("wcho", 0x1E2F0),
+];
+
+/// The digits of the `hanidec` numbering system, which aren't contiguous.
+const HANIDEC_DIGITS: [char; 10] = ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+
+/// Gets the digits of the numbering system `name`, if it's a numbering system with simple digit
+/// mappings.
+pub(super) fn numbering_system_digits(name: &str) -> Option<[char; 10]> {
+    if name == "hanidec" {
+        return Some(HANIDEC_DIGITS);
+    }
+    let (_, zero) = NUMBERING_SYSTEMS
+        .iter()
+        .find(|(system, _)| *system == name)?;
+    let mut digits = ['0'; 10];
+    for (digit, value) in digits.iter_mut().zip(0..) {
+        *digit = char::from_u32(zero + value)?;
+    }
+    Some(digits)
+}
+
+/// Gets the name of the numbering system that uses `digits`, which is `latn` if there's none.
+pub(super) fn numbering_system_name(digits: &[char; 10]) -> &'static str {
+    NUMBERING_SYSTEMS
+        .iter()
+        .map(|(system, _)| *system)
+        .find(|system| numbering_system_digits(system).as_ref() == Some(digits))
+        .unwrap_or("latn")
+}
+
+/// Checks if `name` matches the Unicode Locale Identifier `type` nonterminal,
+/// `(3*8alphanum) *("-" (3*8alphanum))`.
+pub(super) fn is_unicode_type(name: &str) -> bool {
+    name.split('-').all(|subtag| {
+        (3..=8).contains(&subtag.len()) && subtag.bytes().all(|c| c.is_ascii_alphanumeric())
+    })
+}
+
+/// Abstract operation `IsWellFormedCurrencyCode ( currency )`
+fn is_well_formed_currency_code(currency: &str) -> bool {
+    currency.len() == 3 && currency.bytes().all(|c| c.is_ascii_alphabetic())
+}
+
+/// Abstract operation `IsWellFormedUnitIdentifier ( unitIdentifier )`
+fn is_well_formed_unit_identifier(unit: &str) -> bool {
+    if is_sanctioned_single_unit_identifier(unit) {
+        return true;
+    }
+    unit.split_once("-per-")
+        .map_or(false, |(numerator, denominator)| {
+            is_sanctioned_single_unit_identifier(numerator)
+                && is_sanctioned_single_unit_identifier(denominator)
+        })
+}
+
+/// Abstract operation `CoerceOptionsToObject ( options )`
+pub(super) fn coerce_options_to_object(
+    options: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    // 1. If options is undefined, then
+    if options.is_undefined() {
+        // a. Return OrdinaryObjectCreate(null).
+        return Ok(JsObject::from_proto_and_data(None, ObjectData::ordinary()));
+    }
+
+    // 2. Return ? ToObject(options).
+    options.to_object(context)
+}
+
+/// Gets the string option `property` of `options`, which must be one of `values` if it's not
+/// empty.
+pub(super) fn get_string_option(
+    options: &JsObject,
+    property: &str,
+    values: &[&str],
+    context: &mut Context,
+) -> JsResult<Option<String>> {
+    let values = values.iter().map(JsString::new).collect::<Vec<_>>();
+    let value = get_option(
+        options,
+        property,
+        &GetOptionType::String,
+        &values,
+        &JsValue::undefined(),
+        context,
+    )?;
+    Ok(value.as_string().map(JsString::to_std_string_escaped))
+}
+
+/// JavaScript `Intl.NumberFormat` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct NumberFormat {
+    #[unsafe_ignore_trace]
+    locale: Locale,
+    numbering_system: String,
+    #[unsafe_ignore_trace]
+    digits: [char; 10],
+    #[unsafe_ignore_trace]
+    symbols: DecimalSymbols,
+    #[unsafe_ignore_trace]
+    style: Style,
+    currency: Option<String>,
+    #[unsafe_ignore_trace]
+    currency_display: CurrencyDisplay,
+    #[unsafe_ignore_trace]
+    currency_sign: CurrencySign,
+    unit: Option<String>,
+    #[unsafe_ignore_trace]
+    unit_display: UnitDisplay,
+    minimum_integer_digits: u8,
+    #[unsafe_ignore_trace]
+    rounding: RoundingType,
+    use_grouping: bool,
+    #[unsafe_ignore_trace]
+    notation: Notation,
+    #[unsafe_ignore_trace]
+    sign_display: SignDisplay,
+    bound_format: Option<JsObject>,
+}
+
+impl NumberFormat {
+    const NAME: &'static str = "NumberFormat";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let get_format = FunctionBuilder::native(context, Self::get_format)
+            .name("get format")
+            .build();
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().number_format().clone(),
+        )
+        .name(Self::NAME)
+        .length(0)
+        .static_method(Self::supported_locales_of, "supportedLocalesOf", 1)
+        .accessor("format", Some(get_format), None, Attribute::CONFIGURABLE)
+        .method(Self::format_to_parts, "formatToParts", 1)
+        .method(Self::resolved_options, "resolvedOptions", 0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            "Intl.NumberFormat",
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build()
+    }
+
+    /// The `Intl.NumberFormat` constructor is the `%NumberFormat%` intrinsic object and a standard built-in property of the `Intl` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/NumberFormat
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, let newTarget be the active function object, else let newTarget be NewTarget.
+        // 2. Let numberFormat be ? OrdinaryCreateFromConstructor(newTarget, "%NumberFormat.prototype%", « [[InitializedNumberFormat]], ... »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::number_format,
+            context,
+        )?;
+
+        // 3. Perform ? InitializeNumberFormat(numberFormat, locales, options).
+        let number_format = Self::initialize(args, args.get_or_undefined(1), context)?;
+
+        // 4. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Let this be the this value.
+        //     b. Return ? ChainNumberFormat(numberFormat, NewTarget, this).
+        // 5. Return numberFormat.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::number_format(Box::new(number_format)),
+        )
+        .into())
+    }
+
+    /// Abstract operation `InitializeNumberFormat ( numberFormat, locales, options )`
+    ///
+    /// The requested locales are read from the first element of `locales`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-initializenumberformat
+    pub(crate) fn initialize(
+        locales: &[JsValue],
+        options: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 1. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 2. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(options, context)?;
+
+        // 3. Let opt be a new Record.
+        // 4. Let matcher be ? GetOption(options, "localeMatcher", "string", « "lookup", "best fit" », "best fit").
+        // 5. Set opt.[[localeMatcher]] to matcher.
+        get_string_option(&options, "localeMatcher", &["lookup", "best fit"], context)?;
+
+        // 6. Let numberingSystem be ? GetOption(options, "numberingSystem", "string", undefined, undefined).
+        let numbering_system_option = get_string_option(&options, "numberingSystem", &[], context)?;
+
+        // 7. If numberingSystem is not undefined, then
+        if let Some(numbering_system) = &numbering_system_option {
+            // a. If numberingSystem does not match the Unicode Locale Identifier type nonterminal, throw a RangeError exception.
+            if !is_unicode_type(numbering_system) {
+                return Err(JsNativeError::range()
+                    .with_message(format!("invalid numbering system `{numbering_system}`"))
+                    .into());
+            }
+        }
+
+        // 8. Set opt.[[nu]] to numberingSystem.
+        // 9. Let localeData be %NumberFormat%.[[LocaleData]].
+        // 10. Let r be ResolveLocale(%NumberFormat%.[[AvailableLocales]], requestedLocales, opt, %NumberFormat%.[[RelevantExtensionKeys]], localeData).
+        let (requested, (langid, symbols)) = requested_locales
+            .iter()
+            .find_map(|locale| {
+                DecimalSymbols::lookup(&locale.id, context).map(|found| (Some(locale), found))
+            })
+            .or_else(|| {
+                let default = default_locale(context.icu().locale_canonicalizer());
+                DecimalSymbols::lookup(&default.id, context).map(|found| (None, found))
+            })
+            .or_else(|| {
+                DecimalSymbols::load(&LanguageIdentifier::default(), context)
+                    .map(|symbols| (None, (LanguageIdentifier::default(), symbols)))
+            })
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("the data provider has no decimal symbols")
+            })?;
+
+        let nu = "nu".parse::<Key>().expect("`nu` must be a valid key");
+        let extension = requested
+            .and_then(|locale| locale.extensions.unicode.keywords.get(&nu))
+            .map(ToString::to_string)
+            .filter(|system| numbering_system_digits(system).is_some());
+        let option =
+            numbering_system_option.filter(|system| numbering_system_digits(system).is_some());
+
+        let mut locale = Locale::from(langid);
+        let numbering_system = match (extension, option) {
+            (Some(extension), Some(option)) if extension == option => {
+                Self::set_keyword(&mut locale, nu, &extension);
+                extension
+            }
+            (_, Some(option)) => option,
+            (Some(extension), None) => {
+                Self::set_keyword(&mut locale, nu, &extension);
+                extension
+            }
+            (None, None) => numbering_system_name(&symbols.digits).to_string(),
+        };
+        let digits = numbering_system_digits(&numbering_system).unwrap_or(symbols.digits);
+
+        // 11-15. Set the locale, data locale and numbering system of numberFormat.
+        // 16. Perform ? SetNumberFormatUnitOptions(numberFormat, options).
+        // SetNumberFormatUnitOptions ( intlObj, options )
+        // 3. Let style be ? GetOption(options, "style", "string", « "decimal", "percent", "currency", "unit" », "decimal").
+        let style = match get_string_option(
+            &options,
+            "style",
+            &["decimal", "percent", "currency", "unit"],
+            context,
+        )?
+        .as_deref()
+        {
+            Some("percent") => Style::Percent,
+            Some("currency") => Style::Currency,
+            Some("unit") => Style::Unit,
+            _ => Style::Decimal,
+        };
+
+        // 5. Let currency be ? GetOption(options, "currency", "string", undefined, undefined).
+        let currency = get_string_option(&options, "currency", &[], context)?;
+        match &currency {
+            // 6. If currency is undefined, then
+            //     a. If style is "currency", throw a TypeError exception.
+            None if style == Style::Currency => {
+                return Err(JsNativeError::typ()
+                    .with_message("the currency option is required with the currency style")
+                    .into());
+            }
+            // 7. Else,
+            //     a. If ! IsWellFormedCurrencyCode(currency) is false, throw a RangeError exception.
+            Some(currency) if !is_well_formed_currency_code(currency) => {
+                return Err(JsNativeError::range()
+                    .with_message(format!("invalid currency code `{currency}`"))
+                    .into());
+            }
+            _ => {}
+        }
+
+        // 8. Let currencyDisplay be ? GetOption(options, "currencyDisplay", "string", « "code", "symbol", "narrowSymbol", "name" », "symbol").
+        let currency_display = match get_string_option(
+            &options,
+            "currencyDisplay",
+            &["code", "symbol", "narrowSymbol", "name"],
+            context,
+        )?
+        .as_deref()
+        {
+            Some("code") => CurrencyDisplay::Code,
+            Some("narrowSymbol") => CurrencyDisplay::NarrowSymbol,
+            Some("name") => CurrencyDisplay::Name,
+            _ => CurrencyDisplay::Symbol,
+        };
+
+        // 9. Let currencySign be ? GetOption(options, "currencySign", "string", « "standard", "accounting" », "standard").
+        let currency_sign = match get_string_option(
+            &options,
+            "currencySign",
+            &["standard", "accounting"],
+            context,
+        )?
+        .as_deref()
+        {
+            Some("accounting") => CurrencySign::Accounting,
+            _ => CurrencySign::Standard,
+        };
+
+        // 10. Let unit be ? GetOption(options, "unit", "string", undefined, undefined).
+        let unit = get_string_option(&options, "unit", &[], context)?;
+        match &unit {
+            // 11. If unit is undefined, then
+            //     a. If style is "unit", throw a TypeError exception.
+            None if style == Style::Unit => {
+                return Err(JsNativeError::typ()
+                    .with_message("the unit option is required with the unit style")
+                    .into());
+            }
+            // 12. Else,
+            //     a. If ! IsWellFormedUnitIdentifier(unit) is false, throw a RangeError exception.
+            Some(unit) if !is_well_formed_unit_identifier(unit) => {
+                return Err(JsNativeError::range()
+                    .with_message(format!("invalid unit identifier `{unit}`"))
+                    .into());
+            }
+            _ => {}
+        }
+
+        // 13. Let unitDisplay be ? GetOption(options, "unitDisplay", "string", « "short", "narrow", "long" », "short").
+        let unit_display = match get_string_option(
+            &options,
+            "unitDisplay",
+            &["short", "narrow", "long"],
+            context,
+        )?
+        .as_deref()
+        {
+            Some("narrow") => UnitDisplay::Narrow,
+            Some("long") => UnitDisplay::Long,
+            _ => UnitDisplay::Short,
+        };
+
+        // 14. If style is "currency", then
+        //     a. Let currency be the result of converting currency to upper case as specified in 6.1.
+        //     b. Set intlObj.[[Currency]] to currency.
+        let currency = currency
+            .filter(|_| style == Style::Currency)
+            .map(|currency| currency.to_ascii_uppercase());
+
+        // 15. If style is "unit", then
+        //     a. Set intlObj.[[Unit]] to unit.
+        let unit = unit.filter(|_| style == Style::Unit);
+
+        // 17. If style is "currency", then
+        //     a. Let currency be numberFormat.[[Currency]].
+        //     b. Let cDigits be CurrencyDigits(currency).
+        //     c. Let mnfdDefault be cDigits.
+        //     d. Let mxfdDefault be cDigits.
+        // 18. Else,
+        //     a. Let mnfdDefault be 0.
+        //     b. If style is "percent", then
+        //         i. Let mxfdDefault be 0.
+        //     c. Else,
+        //         i. Let mxfdDefault be 3.
+        let (mnfd_default, mxfd_default) = match (style, &currency) {
+            (Style::Currency, Some(currency)) => {
+                let digits = currency_digits(currency);
+                (digits, digits)
+            }
+            (Style::Percent, _) => (0, 0),
+            _ => (0, 3),
+        };
+
+        // 19. Let notation be ? GetOption(options, "notation", "string", « "standard", "scientific", "engineering", "compact" », "standard").
+        let notation = get_string_option(
+            &options,
+            "notation",
+            &["standard", "scientific", "engineering", "compact"],
+            context,
+        )?;
+
+        // 20. Set numberFormat.[[Notation]] to notation.
+        // 21. Perform ? SetNumberFormatDigitOptions(numberFormat, options, mnfdDefault, mxfdDefault, notation).
+        let (minimum_integer_digits, rounding) = Self::set_digit_options(
+            &options,
+            mnfd_default,
+            mxfd_default,
+            notation.as_deref() == Some("compact"),
+            context,
+        )?;
+
+        // 22. Let compactDisplay be ? GetOption(options, "compactDisplay", "string", « "short", "long" », "short").
+        let compact_display =
+            match get_string_option(&options, "compactDisplay", &["short", "long"], context)?
+                .as_deref()
+            {
+                Some("long") => CompactDisplay::Long,
+                _ => CompactDisplay::Short,
+            };
+
+        // 23. If notation is "compact", then
+        //     a. Set numberFormat.[[CompactDisplay]] to compactDisplay.
+        let notation = match notation.as_deref() {
+            Some("scientific") => Notation::Scientific,
+            Some("engineering") => Notation::Engineering,
+            Some("compact") => Notation::Compact(compact_display),
+            _ => Notation::Standard,
+        };
+
+        // 24. Let useGrouping be ? GetOption(options, "useGrouping", "boolean", undefined, true).
+        // 25. Set numberFormat.[[UseGrouping]] to useGrouping.
+        let use_grouping = get_option(
+            &options,
+            "useGrouping",
+            &GetOptionType::Boolean,
+            &[],
+            &JsValue::new(true),
+            context,
+        )?
+        .to_boolean();
+
+        // 26. Let signDisplay be ? GetOption(options, "signDisplay", "string", « "auto", "never", "always", "exceptZero" », "auto").
+        // 27. Set numberFormat.[[SignDisplay]] to signDisplay.
+        let sign_display = match get_string_option(
+            &options,
+            "signDisplay",
+            &["auto", "never", "always", "exceptZero"],
+            context,
+        )?
+        .as_deref()
+        {
+            Some("never") => SignDisplay::Never,
+            Some("always") => SignDisplay::Always,
+            Some("exceptZero") => SignDisplay::ExceptZero,
+            _ => SignDisplay::Auto,
+        };
+
+        // 28. Return numberFormat.
+        Ok(Self {
+            locale,
+            numbering_system,
+            digits,
+            symbols,
+            style,
+            currency,
+            currency_display,
+            currency_sign,
+            unit,
+            unit_display,
+            minimum_integer_digits,
+            rounding,
+            use_grouping,
+            notation,
+            sign_display,
+            bound_format: None,
+        })
+    }
+
+    /// Sets the Unicode extension keyword `key` of `locale` to `value`.
+    fn set_keyword(locale: &mut Locale, key: Key, value: &str) {
+        if let Ok(value) = value.parse::<Value>() {
+            locale.extensions.unicode.keywords.set(key, value);
+        }
+    }
+
+    /// Abstract operation `SetNumberFormatDigitOptions ( intlObj, options, mnfdDefault, mxfdDefault, notation )`
+    ///
+    /// Returns the minimum integer digits and the rounding type.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-setnfdigitoptions
+    fn set_digit_options(
+        options: &JsObject,
+        mnfd_default: u8,
+        mxfd_default: u8,
+        compact: bool,
+        context: &mut Context,
+    ) -> JsResult<(u8, RoundingType)> {
+        // 5. Let mnid be ? GetNumberOption(options, "minimumIntegerDigits,", 1, 21, 1).
+        let mnid = get_number_option(
+            options,
+            "minimumIntegerDigits",
+            1.0,
+            21.0,
+            Some(1.0),
+            context,
+        )?
+        .unwrap_or(1.0) as u8;
+
+        // 6. Let mnfd be ? Get(options, "minimumFractionDigits").
+        let mnfd = options.get("minimumFractionDigits", context)?;
+
+        // 7. Let mxfd be ? Get(options, "maximumFractionDigits").
+        let mxfd = options.get("maximumFractionDigits", context)?;
+
+        // 8. Let mnsd be ? Get(options, "minimumSignificantDigits").
+        let mnsd = options.get("minimumSignificantDigits", context)?;
+
+        // 9. Let mxsd be ? Get(options, "maximumSignificantDigits").
+        let mxsd = options.get("maximumSignificantDigits", context)?;
+
+        // 10. Set intlObj.[[MinimumIntegerDigits]] to mnid.
+        // 11. If mnsd is not undefined or mxsd is not undefined, then
+        let rounding = if !mnsd.is_undefined() || !mxsd.is_undefined() {
+            // a. Set intlObj.[[RoundingType]] to significantDigits.
+            // b. Let mnsd be ? DefaultNumberOption(mnsd, 1, 21, 1).
+            let min = default_number_option(&mnsd, 1.0, 21.0, Some(1.0), context)?.unwrap_or(1.0);
+
+            // c. Let mxsd be ? DefaultNumberOption(mxsd, mnsd, 21, 21).
+            let max = default_number_option(&mxsd, min, 21.0, Some(21.0), context)?.unwrap_or(21.0);
+
+            // d. Set intlObj.[[MinimumSignificantDigits]] to mnsd.
+            // e. Set intlObj.[[MaximumSignificantDigits]] to mxsd.
+            RoundingType::SignificantDigits {
+                min: min as u8,
+                max: max as u8,
+            }
+        }
+        // 12. Else if mnfd is not undefined or mxfd is not undefined, then
+        else if !mnfd.is_undefined() || !mxfd.is_undefined() {
+            // a. Set intlObj.[[RoundingType]] to fractionDigits.
+            // b. Let mnfd be ? DefaultNumberOption(mnfd, 0, 20, undefined).
+            let min = default_number_option(&mnfd, 0.0, 20.0, None, context)?;
+
+            // c. Let mxfd be ? DefaultNumberOption(mxfd, 0, 20, undefined).
+            let max = default_number_option(&mxfd, 0.0, 20.0, None, context)?;
+
+            let (min, max) = match (min, max) {
+                // d. If mnfd is undefined, set mnfd to min(mnfdDefault, mxfd).
+                (None, Some(max)) => (f64::from(mnfd_default).min(max), max),
+                // e. Else if mxfd is undefined, set mxfd to max(mxfdDefault, mnfd).
+                (Some(min), None) => (min, f64::from(mxfd_default).max(min)),
+                // f. Else if mnfd is greater than mxfd, throw a RangeError exception.
+                (Some(min), Some(max)) if min > max => {
+                    return Err(JsNativeError::range()
+                        .with_message("minimumFractionDigits is greater than maximumFractionDigits")
+                        .into());
+                }
+                (Some(min), Some(max)) => (min, max),
+                (None, None) => (f64::from(mnfd_default), f64::from(mxfd_default)),
+            };
+
+            // g. Set intlObj.[[MinimumFractionDigits]] to mnfd.
+            // h. Set intlObj.[[MaximumFractionDigits]] to mxfd.
+            RoundingType::FractionDigits {
+                min: min as u8,
+                max: max as u8,
+            }
+        }
+        // 13. Else if notation is "compact", then
+        else if compact {
+            // a. Set intlObj.[[RoundingType]] to compactRounding.
+            RoundingType::Compact
+        }
+        // 14. Else,
+        else {
+            // a. Set intlObj.[[RoundingType]] to fractionDigits.
+            // b. Set intlObj.[[MinimumFractionDigits]] to mnfdDefault.
+            // c. Set intlObj.[[MaximumFractionDigits]] to mxfdDefault.
+            RoundingType::FractionDigits {
+                min: mnfd_default,
+                max: mxfd_default,
+            }
+        };
+
+        Ok((mnid, rounding))
+    }
+
+    /// Gets the `NumberFormat` object of `this`, or throws a `TypeError` if it's not one.
+    fn this_number_format(this: &JsValue, method: &str) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|object| object.borrow().is_number_format())
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "Intl.NumberFormat.prototype.{method} called on a non-NumberFormat object"
+                    ))
+                    .into()
+            })
+    }
+
+    /// `Intl.NumberFormat.supportedLocalesOf ( locales [ , options ] )`
+    ///
+    /// Returns the requested locales that are supported without falling back to the default
+    /// locale.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.supportedlocalesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let availableLocales be %NumberFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(args, context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        // SupportedLocales ( availableLocales, requestedLocales, options )
+        // 1. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(args.get_or_undefined(1), context)?;
+
+        // 2. Let matcher be ? GetOption(options, "localeMatcher", "string", « "lookup", "best fit" », "best fit").
+        get_string_option(&options, "localeMatcher", &["lookup", "best fit"], context)?;
+
+        // 3-4. Let supportedLocales be the requested locales that have an available locale.
+        let supported = requested_locales
+            .into_iter()
+            .filter(|locale| {
+                DecimalSymbols::lookup(&locale.id, context)
+                    .map_or(false, |(langid, _)| langid != LanguageIdentifier::default())
+            })
+            .map(|locale| JsValue::new(JsString::new(locale.to_string())))
+            .collect::<Vec<_>>();
+
+        // 5. Return CreateArrayFromList(supportedLocales).
+        Ok(Array::create_array_from_list(supported, context).into())
+    }
+
+    /// `get Intl.NumberFormat.prototype.format`
+    ///
+    /// Returns a function that formats a number according to the locale and options of the
+    /// `NumberFormat`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/format
+    fn get_format(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let nf be the this value.
+        // 2. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Set nf to ? UnwrapNumberFormat(nf).
+        // 3. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        let nf = Self::this_number_format(this, "format")?;
+
+        // 4. If nf.[[BoundFormat]] is undefined, then
+        let bound_format = nf
+            .borrow()
+            .as_number_format()
+            .and_then(|number_format| number_format.bound_format.clone());
+        if let Some(bound_format) = bound_format {
+            // 5. Return nf.[[BoundFormat]].
+            return Ok(bound_format.into());
+        }
+
+        // a. Let F be a new built-in function object as defined in Number Format Functions (15.1.4).
+        // b. Set F.[[NumberFormat]] to nf.
+        let function = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args, nf: &mut JsObject, context| {
+                // 1. Let nf be F.[[NumberFormat]].
+                // 2. Assert: Type(nf) is Object and nf has an [[InitializedNumberFormat]] internal slot.
+                // 3. If value is not provided, let value be undefined.
+                // 4. Let x be ? ToNumeric(value).
+                let x = args.get_or_undefined(0).to_numeric(context)?;
+
+                // 5. Return ? FormatNumeric(nf, x).
+                let nf = nf.borrow();
+                let nf = nf
+                    .as_number_format()
+                    .expect("the captured object must be a NumberFormat");
+                Ok(JsString::new(nf.format_numeric(&x)).into())
+            },
+            nf.clone(),
+        )
+        .name("")
+        .length(1)
+        .build();
+
+        // c. Set nf.[[BoundFormat]] to F.
+        if let Some(number_format) = nf.borrow_mut().as_number_format_mut() {
+            number_format.bound_format = Some(function.clone().into());
+        }
+
+        // 5. Return nf.[[BoundFormat]].
+        Ok(function.into())
+    }
+
+    /// `Intl.NumberFormat.prototype.formatToParts ( value )`
+    ///
+    /// Returns an array with the parts of the formatted number.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.formattoparts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let nf be the this value.
+        // 2. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        let nf = Self::this_number_format(this, "formatToParts")?;
+
+        // 3. Let x be ? ToNumeric(value).
+        let x = args.get_or_undefined(0).to_numeric(context)?;
+
+        // 4. Return ? FormatNumericToParts(nf, x).
+        // FormatNumericToParts ( numberFormat, x )
+        // 1. Let parts be ? PartitionNumberPattern(numberFormat, x).
+        let parts = nf
+            .borrow()
+            .as_number_format()
+            .expect("the object must be a NumberFormat")
+            .partition_number_pattern(&x);
+
+        // 2. Let result be ! ArrayCreate(0).
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]] } part in parts, do
+        let mut result = Vec::with_capacity(parts.len());
+        for part in parts {
+            // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+            let o = JsObject::from_proto_and_data(
+                context.intrinsics().constructors().object().prototype(),
+                ObjectData::ordinary(),
+            );
+
+            // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+            o.create_data_property_or_throw("type", part.kind, context)
+                .expect("CreateDataPropertyOrThrow must not fail on a new object");
+
+            // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+            o.create_data_property_or_throw("value", JsString::new(part.value), context)
+                .expect("CreateDataPropertyOrThrow must not fail on a new object");
+
+            // d. Perform ! CreateDataPropertyOrThrow(result, ! ToString(n), O).
+            // e. Increment n by 1.
+            result.push(o.into());
+        }
+
+        // 5. Return result.
+        Ok(Array::create_array_from_list(result, context).into())
+    }
+
+    /// `Intl.NumberFormat.prototype.resolvedOptions ( )`
+    ///
+    /// Returns an object with the locale and options computed during the initialization of the
+    /// `NumberFormat`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let nf be the this value.
+        // 2. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Set nf to ? UnwrapNumberFormat(nf).
+        // 3. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        let nf = Self::this_number_format(this, "resolvedOptions")?;
+        let nf = nf.borrow();
+        let nf = nf
+            .as_number_format()
+            .expect("the object must be a NumberFormat");
+
+        // 5. For each row of Table 11, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of nf's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut properties: Vec<(&str, JsValue)> = vec![
+            ("locale", JsString::new(nf.locale.to_string()).into()),
+            (
+                "numberingSystem",
+                JsString::new(&nf.numbering_system).into(),
+            ),
+            (
+                "style",
+                match nf.style {
+                    Style::Decimal => "decimal",
+                    Style::Percent => "percent",
+                    Style::Currency => "currency",
+                    Style::Unit => "unit",
+                }
+                .into(),
+            ),
+        ];
+        if let Some(currency) = &nf.currency {
+            properties.push(("currency", JsString::new(currency).into()));
+            properties.push((
+                "currencyDisplay",
+                match nf.currency_display {
+                    CurrencyDisplay::Code => "code",
+                    CurrencyDisplay::Symbol => "symbol",
+                    CurrencyDisplay::NarrowSymbol => "narrowSymbol",
+                    CurrencyDisplay::Name => "name",
+                }
+                .into(),
+            ));
+            properties.push((
+                "currencySign",
+                match nf.currency_sign {
+                    CurrencySign::Standard => "standard",
+                    CurrencySign::Accounting => "accounting",
+                }
+                .into(),
+            ));
+        }
+        if let Some(unit) = &nf.unit {
+            properties.push(("unit", JsString::new(unit).into()));
+            properties.push((
+                "unitDisplay",
+                match nf.unit_display {
+                    UnitDisplay::Short => "short",
+                    UnitDisplay::Narrow => "narrow",
+                    UnitDisplay::Long => "long",
+                }
+                .into(),
+            ));
+        }
+        properties.push(("minimumIntegerDigits", nf.minimum_integer_digits.into()));
+        match nf.rounding {
+            RoundingType::FractionDigits { min, max } => {
+                properties.push(("minimumFractionDigits", min.into()));
+                properties.push(("maximumFractionDigits", max.into()));
+            }
+            RoundingType::SignificantDigits { min, max } => {
+                properties.push(("minimumSignificantDigits", min.into()));
+                properties.push(("maximumSignificantDigits", max.into()));
+            }
+            RoundingType::Compact => {}
+        }
+        properties.push(("useGrouping", nf.use_grouping.into()));
+        let (notation, compact_display) = match nf.notation {
+            Notation::Standard => ("standard", None),
+            Notation::Scientific => ("scientific", None),
+            Notation::Engineering => ("engineering", None),
+            Notation::Compact(CompactDisplay::Short) => ("compact", Some("short")),
+            Notation::Compact(CompactDisplay::Long) => ("compact", Some("long")),
+        };
+        properties.push(("notation", notation.into()));
+        if let Some(compact_display) = compact_display {
+            properties.push(("compactDisplay", compact_display.into()));
+        }
+        properties.push((
+            "signDisplay",
+            match nf.sign_display {
+                SignDisplay::Auto => "auto",
+                SignDisplay::Never => "never",
+                SignDisplay::Always => "always",
+                SignDisplay::ExceptZero => "exceptZero",
+            }
+            .into(),
+        ));
+
+        // 4. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = JsObject::from_proto_and_data(
+            context.intrinsics().constructors().object().prototype(),
+            ObjectData::ordinary(),
+        );
+        for (property, value) in properties {
+            options
+                .create_data_property_or_throw(property, value, context)
+                .expect("CreateDataPropertyOrThrow must not fail on a new object");
+        }
+
+        // 6. Return options.
+        Ok(options.into())
+    }
+}
+
+/// Formats `x` with a new `NumberFormat` created from `locales` and `options`, as done by the
+/// `toLocaleString` methods of `Number` and `BigInt`.
+pub(crate) fn format_numeric_to_locale_string(
+    x: Numeric,
+    locales: &JsValue,
+    options: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 2. Let numberFormat be ? Construct(%NumberFormat%, « locales, options »).
+    let number_format = NumberFormat::initialize(&[locales.clone()], options, context)?;
+
+    // 3. Return ? FormatNumeric(numberFormat, x).
+    Ok(JsString::new(number_format.format_numeric(&x)).into())
+}
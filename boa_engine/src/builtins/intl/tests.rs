@@ -5,6 +5,7 @@ use crate::{
         get_number_option, get_option, insert_unicode_extension_and_canonicalize, lookup_matcher,
         resolve_locale, unicode_extension_components, DateTimeFormatRecord, GetOptionType,
     },
+    forward,
     object::JsObject,
     Context, JsString, JsValue,
 };
@@ -284,7 +285,7 @@ fn get_opt() {
     let mut context = Context::default();
 
     let values = Vec::<JsString>::new();
-    let fallback = JsValue::String(JsString::new("fallback"));
+    let fallback = JsValue::new(JsString::new("fallback"));
     let options_obj = JsObject::empty();
     let option_type = GetOptionType::String;
     let get_option_result = get_option(
@@ -299,9 +300,9 @@ fn get_opt() {
     assert_eq!(get_option_result, fallback);
 
     let values = Vec::<JsString>::new();
-    let fallback = JsValue::String(JsString::new("fallback"));
+    let fallback = JsValue::new(JsString::new("fallback"));
     let options_obj = JsObject::empty();
-    let locale_value = JsValue::String(JsString::new("en-US"));
+    let locale_value = JsValue::new(JsString::new("en-US"));
     options_obj
         .set("Locale", locale_value.clone(), true, &mut context)
         .expect("Setting a property should not fail");
@@ -317,10 +318,10 @@ fn get_opt() {
     .expect("GetOption should not fail on string test");
     assert_eq!(get_option_result, locale_value);
 
-    let fallback = JsValue::String(JsString::new("fallback"));
+    let fallback = JsValue::new(JsString::new("fallback"));
     let options_obj = JsObject::empty();
     let locale_string = JsString::new("en-US");
-    let locale_value = JsValue::String(locale_string.clone());
+    let locale_value = JsValue::new(locale_string.clone());
     let values = vec![locale_string];
     options_obj
         .set("Locale", locale_value.clone(), true, &mut context)
@@ -356,9 +357,9 @@ fn get_opt() {
     .expect("GetOption should not fail on boolean test");
     assert_eq!(get_option_result, boolean_value);
 
-    let fallback = JsValue::String(JsString::new("fallback"));
+    let fallback = JsValue::new(JsString::new("fallback"));
     let options_obj = JsObject::empty();
-    let locale_value = JsValue::String(JsString::new("en-US"));
+    let locale_value = JsValue::new(JsString::new("en-US"));
     let other_locale_str = JsString::new("de-DE");
     let values = vec![other_locale_str];
     options_obj
@@ -473,7 +474,7 @@ fn to_date_time_opts() {
     )
     .expect("toDateTimeOptions should not fail in date test");
 
-    let numeric_jsstring = JsValue::String(JsString::new("numeric"));
+    let numeric_jsstring = JsValue::new(JsString::new("numeric"));
     assert_eq!(
         date_time_opts.get("year", &mut context),
         Ok(numeric_jsstring.clone())
@@ -495,7 +496,7 @@ fn to_date_time_opts() {
     )
     .expect("toDateTimeOptions should not fail in time test");
 
-    let numeric_jsstring = JsValue::String(JsString::new("numeric"));
+    let numeric_jsstring = JsValue::new(JsString::new("numeric"));
     assert_eq!(
         date_time_opts.get("hour", &mut context),
         Ok(numeric_jsstring.clone())
@@ -517,7 +518,7 @@ fn to_date_time_opts() {
     )
     .expect("toDateTimeOptions should not fail when testing required = 'any'");
 
-    let numeric_jsstring = JsValue::String(JsString::new("numeric"));
+    let numeric_jsstring = JsValue::new(JsString::new("numeric"));
     assert_eq!(
         date_time_opts.get("year", &mut context),
         Ok(numeric_jsstring.clone())
@@ -543,3 +544,182 @@ fn to_date_time_opts() {
         Ok(numeric_jsstring)
     );
 }
+
+#[test]
+fn number_format() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('en').format(1234567.891)"
+        ),
+        "\"1,234,567.891\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('en', { style: 'percent' }).format(0.256)"
+        ),
+        "\"26%\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('en', { style: 'currency', currency: 'usd', currencySign: 'accounting' }).format(-1234.5)"
+        ),
+        "\"($1,234.50)\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('en', { notation: 'compact' }).format(1234)"
+        ),
+        "\"1.2K\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('en', { notation: 'scientific' }).format(0.00012)"
+        ),
+        "\"1.2E-4\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('en', { style: 'currency' })"
+        ),
+        "Uncaught \"TypeError\": \"the currency option is required with the currency style\""
+    );
+
+    // The names of currencies and units and the compact notation fall back to the root locale
+    // for the locales other than English.
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('fr', { style: 'currency', currency: 'EUR' }).format(-1234.5)"
+        ),
+        "\"-EUR\u{a0}1\u{202f}234,50\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "(1234.5).toLocaleString('fr', { style: 'unit', unit: 'kilometer-per-hour', unitDisplay: 'long' })"
+        ),
+        "\"1\u{202f}234,5 km/h\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('fr', { notation: 'compact', compactDisplay: 'long' }).format(1234567890)"
+        ),
+        "\"1,2G\""
+    );
+}
+
+#[test]
+fn number_format_to_parts() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('en').formatToParts(-1234.5).map(p => p.type).join()"
+        ),
+        "\"minusSign,integer,group,integer,decimal,fraction\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('en-u-nu-arab').resolvedOptions().locale"
+        ),
+        "\"en-u-nu-arab\""
+    );
+    assert_eq!(
+        forward(&mut context, "(12345.6).toLocaleString('en-u-nu-fullwide')"),
+        "\"１２,３４５.６\""
+    );
+    assert_eq!(
+        forward(&mut context, "(12345678901234567890n).toLocaleString('en')"),
+        "\"12,345,678,901,234,567,890\""
+    );
+}
+
+#[test]
+fn date_time_format() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DateTimeFormat('en', { timeZone: 'UTC' }).format(0)"
+        ),
+        "\"1/1/1970\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Date(0).toLocaleString('en', { timeZone: 'UTC' })"
+        ),
+        "\"1/1/1970, 12:00:00 AM\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Date(0).toLocaleTimeString('en', { timeZone: 'UTC', hour12: false })"
+        ),
+        "\"00:00:00\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Date(NaN).toLocaleDateString('en')"),
+        "\"Invalid Date\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DateTimeFormat('en', { dateStyle: 'short', year: 'numeric' })"
+        ),
+        "Uncaught \"TypeError\": \"the year option can't be used with dateStyle or timeStyle\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DateTimeFormat('en', { timeZone: 'Mars/Olympus' })"
+        ),
+        "Uncaught \"RangeError\": \"invalid time zone `Mars/Olympus`\""
+    );
+}
+
+#[test]
+fn date_time_format_to_parts() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DateTimeFormat('en', { timeZone: 'UTC' }).formatToParts(0).map(p => p.type).join()"
+        ),
+        "\"month,literal,day,literal,year\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DateTimeFormat('en', { timeZone: 'UTC' }).formatRangeToParts(0, 86400000).map(p => p.source).join()"
+        ),
+        "\"startRange,startRange,startRange,startRange,startRange,shared,endRange,endRange,endRange,endRange,endRange\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DateTimeFormat('en', { timeZone: 'utc', hour: 'numeric' }).resolvedOptions().timeZone"
+        ),
+        "\"UTC\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.keys(new Intl.DateTimeFormat('en', { timeStyle: 'short' }).resolvedOptions()).join()"
+        ),
+        "\"locale,calendar,numberingSystem,timeZone,hourCycle,hour12,timeStyle\""
+    );
+}
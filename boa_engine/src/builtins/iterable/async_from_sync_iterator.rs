@@ -6,7 +6,7 @@ use crate::{
     },
     object::{FunctionBuilder, JsObject, ObjectData},
     property::PropertyDescriptor,
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue,
 };
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
@@ -17,17 +17,13 @@ use boa_profiler::Profiler;
 ///  - [ECMA reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-%asyncfromsynciteratorprototype%-object
-pub(crate) fn create_async_from_sync_iterator_prototype(context: &mut Context) -> JsObject {
+pub(crate) fn create_async_from_sync_iterator_prototype(
+    async_iterator_prototype: JsObject,
+    context: &mut Context,
+) -> JsObject {
     let _timer = Profiler::global().start_event("AsyncFromSyncIteratorPrototype", "init");
 
-    let prototype = JsObject::from_proto_and_data(
-        context
-            .intrinsics()
-            .objects()
-            .iterator_prototypes()
-            .async_iterator_prototype(),
-        ObjectData::ordinary(),
-    );
+    let prototype = JsObject::from_proto_and_data(async_iterator_prototype, ObjectData::ordinary());
 
     let next_function = FunctionBuilder::native(context, AsyncFromSyncIterator::next)
         .name("next")
@@ -212,7 +208,7 @@ impl AsyncFromSyncIterator {
                 // b. Perform ! Call(promiseCapability.[[Resolve]], undefined, « iterResult »).
                 promise_capability
                     .resolve()
-                    .call(&JsValue::Undefined, &[iter_result], context)
+                    .call(&JsValue::undefined(), &[iter_result], context)
                     .expect("cannot fail according to spec");
 
                 // c. Return promiseCapability.[[Promise]].
@@ -234,24 +230,25 @@ impl AsyncFromSyncIterator {
         if_abrupt_reject_promise!(result, promise_capability, context);
 
         // 11. If Type(result) is not Object, then
-        let result =
-            if let Some(result) = result.as_object() {
-                result
-            } else {
-                // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
-                promise_capability
-                    .reject()
-                    .call(
-                        &JsValue::Undefined,
-                        &[context
-                            .construct_type_error("iterator return function returned non-object")],
-                        context,
-                    )
-                    .expect("cannot fail according to spec");
+        let result = if let Some(result) = result.as_object() {
+            result
+        } else {
+            // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+            promise_capability
+                .reject()
+                .call(
+                    &JsValue::undefined(),
+                    &[JsNativeError::typ()
+                        .with_message("iterator return function returned non-object")
+                        .to_opaque(context)
+                        .into()],
+                    context,
+                )
+                .expect("cannot fail according to spec");
 
-                // b. Return promiseCapability.[[Promise]].
-                return Ok(promise_capability.promise().clone().into());
-            };
+            // b. Return promiseCapability.[[Promise]].
+            return Ok(promise_capability.promise().clone().into());
+        };
 
         // 12. Return AsyncFromSyncIteratorContinuation(result, promiseCapability).
         Self::continuation(
@@ -308,7 +305,7 @@ impl AsyncFromSyncIterator {
                 promise_capability
                     .reject()
                     .call(
-                        &JsValue::Undefined,
+                        &JsValue::undefined(),
                         &[args.get_or_undefined(0).clone()],
                         context,
                     )
@@ -340,8 +337,11 @@ impl AsyncFromSyncIterator {
             promise_capability
                 .reject()
                 .call(
-                    &JsValue::Undefined,
-                    &[context.construct_type_error("iterator throw function returned non-object")],
+                    &JsValue::undefined(),
+                    &[JsNativeError::typ()
+                        .with_message("iterator throw function returned non-object")
+                        .to_opaque(context)
+                        .into()],
                     context,
                 )
                 .expect("cannot fail according to spec");
@@ -429,7 +429,7 @@ impl AsyncFromSyncIterator {
             .expect("constructed promise must be a promise")
             .perform_promise_then(
                 &on_fulfilled.into(),
-                &JsValue::Undefined,
+                &JsValue::undefined(),
                 Some(promise_capability.clone()),
                 context,
             );
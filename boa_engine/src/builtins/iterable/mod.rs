@@ -8,7 +8,7 @@ use crate::{
     },
     object::{JsObject, ObjectInitializer},
     symbol::WellKnownSymbols,
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue,
 };
 use async_from_sync_iterator::create_async_from_sync_iterator_prototype;
 use boa_gc::{Finalize, Trace};
@@ -16,7 +16,7 @@ use boa_profiler::Profiler;
 
 pub(crate) use async_from_sync_iterator::AsyncFromSyncIterator;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Trace, Finalize)]
 pub struct IteratorPrototypes {
     /// %IteratorPrototype%
     iterator_prototype: JsObject,
@@ -39,12 +39,49 @@ pub struct IteratorPrototypes {
 }
 
 impl IteratorPrototypes {
+    /// The number of iterator prototypes.
+    pub(crate) const COUNT: usize = 9;
+
+    /// Returns all the iterator prototypes, in a fixed order.
+    pub(crate) fn slots(&self) -> [&JsObject; Self::COUNT] {
+        [
+            &self.iterator_prototype,
+            &self.async_iterator_prototype,
+            &self.async_from_sync_iterator_prototype,
+            &self.array_iterator,
+            &self.set_iterator,
+            &self.string_iterator,
+            &self.regexp_string_iterator,
+            &self.map_iterator,
+            &self.for_in_iterator,
+        ]
+    }
+
+    /// Creates the iterator prototypes from objects in the order of
+    /// [`IteratorPrototypes::slots`].
+    pub(crate) fn from_slots(slots: [JsObject; Self::COUNT]) -> Self {
+        let [iterator_prototype, async_iterator_prototype, async_from_sync_iterator_prototype, array_iterator, set_iterator, string_iterator, regexp_string_iterator, map_iterator, for_in_iterator] =
+            slots;
+        Self {
+            iterator_prototype,
+            async_iterator_prototype,
+            async_from_sync_iterator_prototype,
+            array_iterator,
+            set_iterator,
+            string_iterator,
+            regexp_string_iterator,
+            map_iterator,
+            for_in_iterator,
+        }
+    }
+
     pub(crate) fn init(context: &mut Context) -> Self {
         let _timer = Profiler::global().start_event("IteratorPrototypes::init", "init");
 
         let iterator_prototype = create_iterator_prototype(context);
         let async_iterator_prototype = create_async_iterator_prototype(context);
-        let async_from_sync_iterator_prototype = create_async_from_sync_iterator_prototype(context);
+        let async_from_sync_iterator_prototype =
+            create_async_from_sync_iterator_prototype(async_iterator_prototype.clone(), context);
         Self {
             array_iterator: ArrayIterator::create_prototype(iterator_prototype.clone(), context),
             set_iterator: SetIterator::create_prototype(iterator_prototype.clone(), context),
@@ -168,7 +205,7 @@ impl JsValue {
                     // 1. Let syncMethod be ? GetMethod(obj, @@iterator).
                     let sync_method = self
                         .get_method(WellKnownSymbols::iterator(), context)?
-                        .map_or(Self::Undefined, Self::from);
+                        .map_or_else(Self::undefined, Self::from);
 
                     // 2. Let syncIteratorRecord be ? GetIterator(obj, sync, syncMethod).
                     let sync_iterator_record =
@@ -180,7 +217,7 @@ impl JsValue {
             } else {
                 // b. Otherwise, set method to ? GetMethod(obj, @@iterator).
                 self.get_method(WellKnownSymbols::iterator(), context)?
-                    .map_or(Self::Undefined, Self::from)
+                    .map_or_else(Self::undefined, Self::from)
             }
         };
 
@@ -190,7 +227,7 @@ impl JsValue {
         // 4. If Type(iterator) is not Object, throw a TypeError exception.
         let iterator_obj = iterator
             .as_object()
-            .ok_or_else(|| context.construct_type_error("the iterator is not an object"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("the iterator is not an object"))?;
 
         // 5. Let nextMethod be ? GetV(iterator, "next").
         let next_method = iterator.get_v("next", context)?;
@@ -355,7 +392,9 @@ impl IteratorRecord {
         let next_method = if let Some(next_method) = self.next_method.as_callable() {
             next_method
         } else {
-            return context.throw_type_error("iterable next method not a function");
+            return Err(JsNativeError::typ()
+                .with_message("iterable next method not a function")
+                .into());
         };
 
         let result = if let Some(value) = value {
@@ -373,7 +412,9 @@ impl IteratorRecord {
         if let Some(o) = result.as_object() {
             Ok(IteratorResult { object: o.clone() })
         } else {
-            context.throw_type_error("next value should be an object")
+            Err(JsNativeError::typ()
+                .with_message("next value should be an object")
+                .into())
         }
     }
 
@@ -468,7 +509,9 @@ impl IteratorRecord {
             Ok(completion)
         } else {
             // 7. If Type(innerResult.[[Value]]) is not Object, throw a TypeError exception.
-            context.throw_type_error("inner result was not an object")
+            Err(JsNativeError::typ()
+                .with_message("inner result was not an object")
+                .into())
         }
     }
 }
@@ -15,18 +15,16 @@
 
 use super::JsArgs;
 use crate::{
-    builtins::{
-        string::{is_leading_surrogate, is_trailing_surrogate},
-        BuiltIn,
-    },
+    builtins::BuiltIn,
     object::{JsObject, ObjectInitializer, RecursionLimiter},
     property::{Attribute, PropertyNameKind},
+    string::{utf16, CodePoint},
     symbol::WellKnownSymbols,
     value::IntegerOrInfinity,
-    Context, JsResult, JsString, JsValue,
+    Context, JsNativeError, JsResult, JsString, JsValue,
 };
 use boa_profiler::Profiler;
-use serde_json::{self, Value as JSONValue};
+use serde::de::IgnoredAny;
 use tap::{Conv, Pipe};
 
 #[cfg(test)]
@@ -78,12 +76,15 @@ impl Json {
 
         // 2. Parse ! StringToCodePoints(jsonString) as a JSON text as specified in ECMA-404.
         //    Throw a SyntaxError exception if it is not a valid JSON text as defined in that specification.
-        if let Err(e) = serde_json::from_str::<JSONValue>(&json_string) {
-            return context.throw_syntax_error(e.to_string());
+        // The text is only validated here, so that escaped lone surrogates in its strings are
+        // accepted.
+        let json_string = json_string.to_std_string_escaped();
+        if let Err(e) = serde_json::from_str::<IgnoredAny>(&json_string) {
+            return Err(JsNativeError::syntax().with_message(e.to_string()).into());
         }
 
         // 3. Let scriptString be the string-concatenation of "(", jsonString, and ");".
-        let script_string = JsString::concat_array(&["(", json_string.as_str(), ");"]);
+        let script_string = format!("({json_string});");
 
         // 4. Let script be ParseText(! StringToCodePoints(scriptString), Script).
         // 5. NOTE: The early error rules defined in 13.2.5.1 have special handling for the above invocation of ParseText.
@@ -132,7 +133,7 @@ impl Json {
         if let Some(obj) = val.as_object() {
             // a. Let isArray be ? IsArray(val).
             // b. If isArray is true, then
-            if obj.is_array_abstract(context)? {
+            if obj.is_array_abstract()? {
                 // i. Let I be 0.
                 // ii. Let len be ? LengthOfArrayLike(val).
                 // iii. Repeat, while I < len,
@@ -183,7 +184,7 @@ impl Json {
                     // 3. Else,
                     else {
                         // a. Perform ? CreateDataProperty(val, P, newElement).
-                        obj.create_data_property(p.as_str(), new_element, context)?;
+                        obj.create_data_property(p.clone(), new_element, context)?;
                     }
                 }
             }
@@ -236,7 +237,7 @@ impl Json {
             } else {
                 // i. Let isArray be ? IsArray(replacer).
                 // ii. If isArray is true, then
-                if replacer_obj.is_array_abstract(context)? {
+                if replacer_obj.is_array_abstract()? {
                     // 1. Set PropertyList to a new empty List.
                     let mut property_set = indexmap::IndexSet::new();
 
@@ -320,7 +321,7 @@ impl Json {
         // 7. Else if Type(space) is String, then
         } else if let Some(s) = space.as_string() {
             // a. If the length of space is 10 or less, let gap be space; otherwise let gap be the substring of space from 0 to 10.
-            String::from_utf16_lossy(&s.encode_utf16().take(10).collect::<Vec<u16>>()).into()
+            JsString::from(&s[..std::cmp::min(10, s.len())])
         // 8. Else,
         } else {
             // a. Let gap be the empty String.
@@ -448,7 +449,9 @@ impl Json {
 
         // 10. If Type(value) is BigInt, throw a TypeError exception.
         if value.is_bigint() {
-            return context.throw_type_error("cannot serialize bigint to JSON");
+            return Err(JsNativeError::typ()
+                .with_message("cannot serialize bigint to JSON")
+                .into());
         }
 
         // 11. If Type(value) is Object and IsCallable(value) is false, then
@@ -457,7 +460,7 @@ impl Json {
                 // a. Let isArray be ? IsArray(value).
                 // b. If isArray is true, return ? SerializeJSONArray(state, value).
                 // c. Return ? SerializeJSONObject(state, value).
-                return if obj.is_array_abstract(context)? {
+                return if obj.is_array_abstract()? {
                     Ok(Some(Self::serialize_json_array(state, obj, context)?))
                 } else {
                     Ok(Some(Self::serialize_json_object(state, obj, context)?))
@@ -477,44 +480,44 @@ impl Json {
     /// [spec]: https://tc39.es/ecma262/#sec-quotejsonstring
     fn quote_json_string(value: &JsString) -> JsString {
         // 1. Let product be the String value consisting solely of the code unit 0x0022 (QUOTATION MARK).
-        let mut product = String::from('"');
+        let mut product = vec![u16::from(b'"')];
 
         // 2. For each code point C of ! StringToCodePoints(value), do
-        for code_point in value.encode_utf16() {
+        for code_point in value.code_points() {
             match code_point {
                 // a. If C is listed in the “Code Point” column of Table 73, then
                 // i. Set product to the string-concatenation of product and the escape sequence for C as specified in the “Escape Sequence” column of the corresponding row.
-                0x8 => product.push_str("\\b"),
-                0x9 => product.push_str("\\t"),
-                0xA => product.push_str("\\n"),
-                0xC => product.push_str("\\f"),
-                0xD => product.push_str("\\r"),
-                0x22 => product.push_str("\\\""),
-                0x5C => product.push_str("\\\\"),
+                CodePoint::Unicode('\u{8}') => product.extend_from_slice(utf16!("\\b")),
+                CodePoint::Unicode('\t') => product.extend_from_slice(utf16!("\\t")),
+                CodePoint::Unicode('\n') => product.extend_from_slice(utf16!("\\n")),
+                CodePoint::Unicode('\u{C}') => product.extend_from_slice(utf16!("\\f")),
+                CodePoint::Unicode('\r') => product.extend_from_slice(utf16!("\\r")),
+                CodePoint::Unicode('"') => product.extend_from_slice(utf16!("\\\"")),
+                CodePoint::Unicode('\\') => product.extend_from_slice(utf16!("\\\\")),
                 // b. Else if C has a numeric value less than 0x0020 (SPACE), or if C has the same numeric value as a leading surrogate or trailing surrogate, then
-                code_point
-                    if is_leading_surrogate(code_point) || is_trailing_surrogate(code_point) =>
-                {
+                CodePoint::Unicode(c) if c < ' ' => {
                     // i. Let unit be the code unit whose numeric value is that of C.
                     // ii. Set product to the string-concatenation of product and UnicodeEscape(unit).
-                    product.push_str(&format!("\\\\uAA{code_point:x}"));
+                    product.extend(format!("\\u{:04x}", u32::from(c)).encode_utf16());
+                }
+                CodePoint::UnpairedSurrogate(unit) => {
+                    // i. Let unit be the code unit whose numeric value is that of C.
+                    // ii. Set product to the string-concatenation of product and UnicodeEscape(unit).
+                    product.extend(format!("\\u{unit:04x}").encode_utf16());
                 }
                 // c. Else,
-                code_point => {
+                CodePoint::Unicode(c) => {
                     // i. Set product to the string-concatenation of product and ! UTF16EncodeCodePoint(C).
-                    product.push(
-                        char::from_u32(u32::from(code_point))
-                            .expect("char from code point cannot fail here"),
-                    );
+                    product.extend_from_slice(c.encode_utf16(&mut [0; 2]));
                 }
             }
         }
 
         // 3. Set product to the string-concatenation of product and the code unit 0x0022 (QUOTATION MARK).
-        product.push('"');
+        product.push(u16::from(b'"'));
 
         // 4. Return product.
-        product.into()
+        JsString::from(product)
     }
 
     /// `25.5.2.4 SerializeJSONObject ( state, value )`
@@ -531,7 +534,9 @@ impl Json {
         // 1. If state.[[Stack]] contains value, throw a TypeError exception because the structure is cyclical.
         let limiter = RecursionLimiter::new(value);
         if limiter.live {
-            return context.throw_type_error("cyclic object value");
+            return Err(JsNativeError::typ()
+                .with_message("cyclic object value")
+                .into());
         }
 
         // 2. Append value to state.[[Stack]].
@@ -576,13 +581,9 @@ impl Json {
                 // 1. Set member to the string-concatenation of member and the code unit 0x0020 (SPACE).
                 // iv. Set member to the string-concatenation of member and strP.
                 let member = if state.gap.is_empty() {
-                    format!("{}:{}", Self::quote_json_string(p).as_str(), str_p.as_str())
+                    JsString::concat_array(&[&Self::quote_json_string(p), utf16!(":"), &str_p])
                 } else {
-                    format!(
-                        "{}: {}",
-                        Self::quote_json_string(p).as_str(),
-                        str_p.as_str()
-                    )
+                    JsString::concat_array(&[&Self::quote_json_string(p), utf16!(": "), &str_p])
                 };
 
                 // v. Append member to partial.
@@ -602,23 +603,26 @@ impl Json {
                 //    with each adjacent pair of Strings separated with the code unit 0x002C (COMMA).
                 //    A comma is not inserted either before the first String or after the last String.
                 // ii. Let final be the string-concatenation of "{", properties, and "}".
-                format!("{{{}}}", partial.join(",")).into()
+                let properties = partial.join(utf16!(","));
+                JsString::concat_array(&[utf16!("{"), &properties, utf16!("}")])
             // b. Else,
             } else {
                 // i. Let separator be the string-concatenation of the code unit 0x002C (COMMA),
                 //    the code unit 0x000A (LINE FEED), and state.[[Indent]].
-                let separator = format!(",\n{}", state.indent.as_str());
+                let separator = JsString::concat(utf16!(",\n"), &state.indent);
                 // ii. Let properties be the String value formed by concatenating all the element Strings of partial
                 //     with each adjacent pair of Strings separated with separator.
                 //     The separator String is not inserted either before the first String or after the last String.
-                let properties = partial.join(&separator);
+                let properties = partial.join(&separator[..]);
                 // iii. Let final be the string-concatenation of "{", the code unit 0x000A (LINE FEED), state.[[Indent]], properties, the code unit 0x000A (LINE FEED), stepback, and "}".
-                format!(
-                    "{{\n{}{properties}\n{}}}",
-                    state.indent.as_str(),
-                    stepback.as_str()
-                )
-                .into()
+                JsString::concat_array(&[
+                    utf16!("{\n"),
+                    &state.indent,
+                    &properties,
+                    utf16!("\n"),
+                    &stepback,
+                    utf16!("}"),
+                ])
             }
         };
 
@@ -646,7 +650,9 @@ impl Json {
         // 1. If state.[[Stack]] contains value, throw a TypeError exception because the structure is cyclical.
         let limiter = RecursionLimiter::new(value);
         if limiter.live {
-            return context.throw_type_error("cyclic object value");
+            return Err(JsNativeError::typ()
+                .with_message("cyclic object value")
+                .into());
         }
 
         // 2. Append value to state.[[Stack]].
@@ -699,23 +705,26 @@ impl Json {
                 //    with each adjacent pair of Strings separated with the code unit 0x002C (COMMA).
                 //    A comma is not inserted either before the first String or after the last String.
                 // ii. Let final be the string-concatenation of "[", properties, and "]".
-                format!("[{}]", partial.join(",")).into()
+                let properties = partial.join(utf16!(","));
+                JsString::concat_array(&[utf16!("["), &properties, utf16!("]")])
             // b. Else,
             } else {
                 // i. Let separator be the string-concatenation of the code unit 0x002C (COMMA),
                 //    the code unit 0x000A (LINE FEED), and state.[[Indent]].
-                let separator = format!(",\n{}", state.indent.as_str());
+                let separator = JsString::concat(utf16!(",\n"), &state.indent);
                 // ii. Let properties be the String value formed by concatenating all the element Strings of partial
                 //     with each adjacent pair of Strings separated with separator.
                 //     The separator String is not inserted either before the first String or after the last String.
-                let properties = partial.join(&separator);
+                let properties = partial.join(&separator[..]);
                 // iii. Let final be the string-concatenation of "[", the code unit 0x000A (LINE FEED), state.[[Indent]], properties, the code unit 0x000A (LINE FEED), stepback, and "]".
-                format!(
-                    "[\n{}{properties}\n{}]",
-                    state.indent.as_str(),
-                    stepback.as_str()
-                )
-                .into()
+                JsString::concat_array(&[
+                    utf16!("[\n"),
+                    &state.indent,
+                    &properties,
+                    utf16!("\n"),
+                    &stepback,
+                    utf16!("]"),
+                ])
             }
         };
 
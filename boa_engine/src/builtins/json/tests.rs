@@ -466,3 +466,27 @@ fn json_parse_with_no_args_throws_syntax_error() {
     let result = forward(&mut context, "JSON.parse();");
     assert!(result.contains("SyntaxError"));
 }
+
+#[test]
+fn json_stringify_lone_surrogates() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(&mut context, r#"JSON.stringify('\uD800') === '"\\ud800"'"#),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"JSON.stringify('a\uDC00𝌆') === '"a\\udc00𝌆"'"#
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"JSON.parse(JSON.stringify('\uD800')) === '\uD800'"#
+        ),
+        "true"
+    );
+}
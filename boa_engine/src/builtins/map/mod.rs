@@ -21,7 +21,7 @@ use crate::{
     },
     property::{Attribute, PropertyNameKind},
     symbol::WellKnownSymbols,
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue, JsVariant,
 };
 use boa_profiler::Profiler;
 use num_traits::Zero;
@@ -121,8 +121,9 @@ impl Map {
     ) -> JsResult<JsValue> {
         // 1. If NewTarget is undefined, throw a TypeError exception.
         if new_target.is_undefined() {
-            return context
-                .throw_type_error("calling a builtin Map constructor without new is forbidden");
+            return Err(JsNativeError::typ()
+                .with_message("calling a builtin Map constructor without new is forbidden")
+                .into());
         }
 
         // 2. Let map be ? OrdinaryCreateFromConstructor(NewTarget, "%Map.prototype%", « [[MapData]] »).
@@ -206,11 +207,7 @@ impl Map {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-map.prototype.set
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/set
-    pub(crate) fn set(
-        this: &JsValue,
-        args: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
+    pub(crate) fn set(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         let key = args.get_or_undefined(0);
         let value = args.get_or_undefined(1);
 
@@ -219,11 +216,11 @@ impl Map {
             // 2. Perform ? RequireInternalSlot(M, [[MapData]]).
             // 3. Let entries be the List that is M.[[MapData]].
             if let Some(map) = object.borrow_mut().as_map_mut() {
-                let key = match key {
-                    JsValue::Rational(r) => {
+                let key = match key.variant() {
+                    JsVariant::Rational(r) => {
                         // 5. If key is -0𝔽, set key to +0𝔽.
                         if r.is_zero() {
-                            JsValue::Rational(0f64)
+                            JsValue::rational(0f64)
                         } else {
                             key.clone()
                         }
@@ -241,7 +238,9 @@ impl Map {
                 return Ok(this.clone());
             }
         }
-        context.throw_type_error("'this' is not a Map")
+        Err(JsNativeError::typ()
+            .with_message("'this' is not a Map")
+            .into())
     }
 
     /// `get Map.prototype.size`
@@ -255,11 +254,7 @@ impl Map {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-get-map.prototype.size
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/size
-    pub(crate) fn get_size(
-        this: &JsValue,
-        _: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
+    pub(crate) fn get_size(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         // 1. Let M be the this value.
         if let Some(object) = this.as_object() {
             // 2. Perform ? RequireInternalSlot(M, [[MapData]]).
@@ -272,7 +267,9 @@ impl Map {
                 return Ok(map.len().into());
             }
         }
-        context.throw_type_error("'this' is not a Map")
+        Err(JsNativeError::typ()
+            .with_message("'this' is not a Map")
+            .into())
     }
 
     /// `Map.prototype.delete( key )`
@@ -286,11 +283,7 @@ impl Map {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-map.prototype.delete
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/delete
-    pub(crate) fn delete(
-        this: &JsValue,
-        args: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
+    pub(crate) fn delete(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         let key = args.get_or_undefined(0);
 
         // 1. Let M be the this value.
@@ -306,7 +299,9 @@ impl Map {
                 return Ok(map.remove(key).is_some().into());
             }
         }
-        context.throw_type_error("'this' is not a Map")
+        Err(JsNativeError::typ()
+            .with_message("'this' is not a Map")
+            .into())
     }
 
     /// `Map.prototype.get( key )`
@@ -319,18 +314,14 @@ impl Map {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-map.prototype.get
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/get
-    pub(crate) fn get(
-        this: &JsValue,
-        args: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
-        const JS_ZERO: &JsValue = &JsValue::Rational(0f64);
+    pub(crate) fn get(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let zero = JsValue::rational(0f64);
 
         let key = args.get_or_undefined(0);
-        let key = match key {
-            JsValue::Rational(r) => {
+        let key = match key.variant() {
+            JsVariant::Rational(r) => {
                 if r.is_zero() {
-                    JS_ZERO
+                    &zero
                 } else {
                     key
                 }
@@ -339,7 +330,7 @@ impl Map {
         };
 
         // 1. Let M be the this value.
-        if let JsValue::Object(ref object) = this {
+        if let JsVariant::Object(object) = this.variant() {
             // 2. Perform ? RequireInternalSlot(M, [[MapData]]).
             // 3. Let entries be the List that is M.[[MapData]].
             if let Some(map) = object.borrow().as_map_ref() {
@@ -350,7 +341,9 @@ impl Map {
             }
         }
 
-        context.throw_type_error("'this' is not a Map")
+        Err(JsNativeError::typ()
+            .with_message("'this' is not a Map")
+            .into())
     }
 
     /// `Map.prototype.clear( )`
@@ -363,7 +356,7 @@ impl Map {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-map.prototype.clear
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/clear
-    pub(crate) fn clear(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    pub(crate) fn clear(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         // 1. Let M be the this value.
         // 2. Perform ? RequireInternalSlot(M, [[MapData]]).
         if let Some(object) = this.as_object() {
@@ -378,7 +371,9 @@ impl Map {
                 return Ok(JsValue::undefined());
             }
         }
-        context.throw_type_error("'this' is not a Map")
+        Err(JsNativeError::typ()
+            .with_message("'this' is not a Map")
+            .into())
     }
 
     /// `Map.prototype.has( key )`
@@ -391,18 +386,14 @@ impl Map {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-map.prototype.has
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/has
-    pub(crate) fn has(
-        this: &JsValue,
-        args: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
-        const JS_ZERO: &JsValue = &JsValue::Rational(0f64);
+    pub(crate) fn has(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let zero = JsValue::rational(0f64);
 
         let key = args.get_or_undefined(0);
-        let key = match key {
-            JsValue::Rational(r) => {
+        let key = match key.variant() {
+            JsVariant::Rational(r) => {
                 if r.is_zero() {
-                    JS_ZERO
+                    &zero
                 } else {
                     key
                 }
@@ -411,7 +402,7 @@ impl Map {
         };
 
         // 1. Let M be the this value.
-        if let JsValue::Object(ref object) = this {
+        if let JsVariant::Object(object) = this.variant() {
             // 2. Perform ? RequireInternalSlot(M, [[MapData]]).
             // 3. Let entries be the List that is M.[[MapData]].
             if let Some(map) = object.borrow().as_map_ref() {
@@ -422,7 +413,9 @@ impl Map {
             }
         }
 
-        context.throw_type_error("'this' is not a Map")
+        Err(JsNativeError::typ()
+            .with_message("'this' is not a Map")
+            .into())
     }
 
     /// `Map.prototype.forEach( callbackFn [ , thisArg ] )`
@@ -445,12 +438,12 @@ impl Map {
         let map = this
             .as_object()
             .filter(|obj| obj.is_map())
-            .ok_or_else(|| context.construct_type_error("`this` is not a Map"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a Map"))?;
 
         // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
         let callback = args.get_or_undefined(0);
         let callback = callback.as_callable().ok_or_else(|| {
-            context.construct_type_error(format!("{} is not a function", callback.display()))
+            JsNativeError::typ().with_message(format!("{} is not a function", callback.display()))
         })?;
 
         let this_arg = args.get_or_undefined(1);
@@ -533,7 +526,7 @@ pub(crate) fn add_entries_from_iterable(
 ) -> JsResult<JsValue> {
     // 1. If IsCallable(adder) is false, throw a TypeError exception.
     let adder = adder.as_callable().ok_or_else(|| {
-        context.construct_type_error("property `set` of `NewTarget` is not callable")
+        JsNativeError::typ().with_message("property `set` of `NewTarget` is not callable")
     })?;
 
     // 2. Let iteratorRecord be ? GetIterator(iterable).
@@ -557,8 +550,9 @@ pub(crate) fn add_entries_from_iterable(
         // d. If Type(nextItem) is not Object, then
         } else {
             // i. Let error be ThrowCompletion(a newly created TypeError object).
-            let err = context
-                .throw_type_error("cannot get key and value from primitive item of `iterable`");
+            let err = Err(JsNativeError::typ()
+                .with_message("cannot get key and value from primitive item of `iterable`")
+                .into());
 
             // ii. Return ? IteratorClose(iteratorRecord, error).
             return iterator_record.close(err, context);
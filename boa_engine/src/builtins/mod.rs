@@ -5,6 +5,7 @@ pub mod array_buffer;
 pub mod async_function;
 pub mod async_generator;
 pub mod async_generator_function;
+pub mod atomics;
 pub mod bigint;
 pub mod boolean;
 pub mod dataview;
@@ -28,11 +29,14 @@ pub mod proxy;
 pub mod reflect;
 pub mod regexp;
 pub mod set;
+pub mod shadow_realm;
+pub mod shared_array_buffer;
 pub mod string;
 pub mod symbol;
 pub mod typed_array;
 pub mod undefined;
 pub mod uri;
+pub mod weak;
 
 #[cfg(feature = "console")]
 pub mod console;
@@ -40,9 +44,16 @@ pub mod console;
 #[cfg(feature = "intl")]
 pub mod intl;
 
+#[cfg(feature = "temporal")]
+pub mod temporal;
+
+#[cfg(any(feature = "intl", feature = "temporal"))]
+pub(crate) mod time_zone;
+
 pub(crate) use self::{
     array::{array_iterator::ArrayIterator, Array},
     async_function::AsyncFunction,
+    atomics::Atomics,
     bigint::BigInt,
     boolean::Boolean,
     dataview::DataView,
@@ -69,6 +80,8 @@ pub(crate) use self::{
     regexp::RegExp,
     set::set_iterator::SetIterator,
     set::Set,
+    shadow_realm::ShadowRealm,
+    shared_array_buffer::SharedArrayBuffer,
     string::String,
     symbol::Symbol,
     typed_array::{
@@ -76,6 +89,7 @@ pub(crate) use self::{
         Int8Array, Uint16Array, Uint32Array, Uint8Array, Uint8ClampedArray,
     },
     undefined::Undefined,
+    weak::{FinalizationRegistry, WeakMap, WeakRef, WeakSet},
 };
 
 use crate::{
@@ -157,6 +171,7 @@ pub fn init(context: &mut Context) {
         Array,
         Proxy,
         ArrayBuffer,
+        SharedArrayBuffer,
         BigInt,
         Boolean,
         Date,
@@ -189,25 +204,34 @@ pub fn init(context: &mut Context) {
         UriError,
         AggregateError,
         Reflect,
+        Atomics,
         Generator,
         GeneratorFunction,
         Promise,
         AsyncFunction,
         AsyncGenerator,
         AsyncGeneratorFunction,
-        Uri
+        Uri,
+        WeakRef,
+        WeakMap,
+        WeakSet,
+        FinalizationRegistry,
+        ShadowRealm
     };
 
     #[cfg(feature = "intl")]
     init_builtin::<intl::Intl>(context);
 
+    #[cfg(feature = "temporal")]
+    init_builtin::<temporal::Temporal>(context);
+
     #[cfg(feature = "console")]
     init_builtin::<console::Console>(context);
 }
 
 pub trait JsArgs {
     /// Utility function to `get` a parameter from
-    /// a `[JsValue]` or default to `JsValue::Undefined`
+    /// a `[JsValue]` or default to `JsValue::undefined()`
     /// if `get` returns `None`.
     ///
     /// Call this if you are thinking of calling something similar to
@@ -222,7 +246,12 @@ pub trait JsArgs {
 
 impl JsArgs for [JsValue] {
     fn get_or_undefined(&self, index: usize) -> &JsValue {
-        const UNDEFINED: &JsValue = &JsValue::Undefined;
-        self.get(index).unwrap_or(UNDEFINED)
+        struct Undefined(JsValue);
+
+        // SAFETY: `undefined` has no payload and is never mutated, so it can be shared.
+        unsafe impl Sync for Undefined {}
+
+        static UNDEFINED: Undefined = Undefined(JsValue::undefined());
+        self.get(index).unwrap_or(&UNDEFINED.0)
     }
 }
@@ -14,15 +14,15 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number
 
 use crate::{
-    builtins::{string::is_trimmable_whitespace, BuiltIn, JsArgs},
+    builtins::{BuiltIn, JsArgs},
     context::intrinsics::StandardConstructors,
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
         JsObject, ObjectData,
     },
     property::Attribute,
-    value::{AbstractRelation, IntegerOrInfinity, JsValue},
-    Context, JsResult,
+    value::{AbstractRelation, IntegerOrInfinity, JsValue, JsVariant},
+    Context, JsNativeError, JsResult,
 };
 use boa_profiler::Profiler;
 use num_traits::{float::FloatCore, Num};
@@ -194,11 +194,15 @@ impl Number {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-thisnumbervalue
-    fn this_number_value(value: &JsValue, context: &mut Context) -> JsResult<f64> {
+    fn this_number_value(value: &JsValue) -> JsResult<f64> {
         value
             .as_number()
             .or_else(|| value.as_object().and_then(|obj| obj.borrow().as_number()))
-            .ok_or_else(|| context.construct_type_error("'this' is not a number"))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("'this' is not a number")
+                    .into()
+            })
     }
 
     /// `Number.prototype.toExponential( [fractionDigits] )`
@@ -218,9 +222,10 @@ impl Number {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let x be ? thisNumberValue(this value).
-        let this_num = Self::this_number_value(this, context)?;
+        let this_num = Self::this_number_value(this)?;
         let precision = match args.get(0) {
-            None | Some(JsValue::Undefined) => None,
+            None => None,
+            Some(n) if n.is_undefined() => None,
             // 2. Let f be ? ToIntegerOrInfinity(fractionDigits).
             Some(n) => Some(n.to_integer_or_infinity(context)?),
         };
@@ -238,8 +243,9 @@ impl Number {
                 f64_to_exponential_with_precision(this_num, precision as usize)
             }
             _ => {
-                return context
-                    .throw_range_error("toExponential() argument must be between 0 and 100")
+                return Err(JsNativeError::range()
+                    .with_message("toExponential() argument must be between 0 and 100")
+                    .into())
             }
         };
         Ok(JsValue::new(this_str_num))
@@ -262,7 +268,7 @@ impl Number {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let this_num be ? thisNumberValue(this value).
-        let this_num = Self::this_number_value(this, context)?;
+        let this_num = Self::this_number_value(this)?;
 
         // 2. Let f be ? ToIntegerOrInfinity(fractionDigits).
         // 3. Assert: If fractionDigits is undefined, then f is 0.
@@ -273,7 +279,8 @@ impl Number {
             .as_integer()
             .filter(|i| (0..=100).contains(i))
             .ok_or_else(|| {
-                context.construct_range_error("toFixed() digits argument must be between 0 and 100")
+                JsNativeError::range()
+                    .with_message("toFixed() digits argument must be between 0 and 100")
             })? as usize;
 
         // 6. If x is not finite, return ! Number::toString(x).
@@ -294,24 +301,42 @@ impl Number {
     ///
     /// The `toLocaleString()` method returns a string with a language-sensitive representation of this number.
     ///
-    /// Note that while this technically conforms to the Ecma standard, it does no actual
-    /// internationalization logic.
+    /// Without the `intl` feature, this returns the same string as `toString()`, which
+    /// technically conforms to the Ecma standard but does no actual internationalization logic.
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///  - [MDN documentation][mdn]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-number.prototype.tolocalestring
+    /// [spec]: https://tc39.es/ecma402/#sup-number.prototype.tolocalestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toLocaleString
     #[allow(clippy::wrong_self_convention)]
+    #[cfg_attr(not(feature = "intl"), allow(unused_variables))]
     pub(crate) fn to_locale_string(
         this: &JsValue,
-        _: &[JsValue],
+        args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let this_num = Self::this_number_value(this, context)?;
-        let this_str_num = this_num.to_string();
-        Ok(JsValue::new(this_str_num))
+        // 1. Let x be ? thisNumberValue(this value).
+        let this_num = Self::this_number_value(this)?;
+
+        // 2. Let numberFormat be ? Construct(%NumberFormat%, « locales, options »).
+        // 3. Return ? FormatNumeric(numberFormat, x).
+        #[cfg(feature = "intl")]
+        {
+            crate::builtins::intl::number_format::format_numeric_to_locale_string(
+                this_num.into(),
+                args.get_or_undefined(0),
+                args.get_or_undefined(1),
+                context,
+            )
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let this_str_num = this_num.to_string();
+            Ok(JsValue::new(this_str_num))
+        }
     }
 
     /// `flt_str_to_exp` - used in `to_precision`
@@ -422,7 +447,7 @@ impl Number {
         let precision = args.get_or_undefined(0);
 
         // 1 & 6
-        let mut this_num = Self::this_number_value(this, context)?;
+        let mut this_num = Self::this_number_value(this)?;
         // 2
         if precision.is_undefined() {
             return Self::to_string(this, &[], context);
@@ -440,9 +465,9 @@ impl Number {
             IntegerOrInfinity::Integer(x) if (1..=100).contains(&x) => x as usize,
             _ => {
                 // 5
-                return context.throw_range_error(
-                    "precision must be an integer at least 1 and no greater than 100",
-                );
+                return Err(JsNativeError::range()
+                    .with_message("precision must be an integer at least 1 and no greater than 100")
+                    .into());
             }
         };
         let precision_i32 = precision as i32;
@@ -672,7 +697,7 @@ impl Number {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let x be ? thisNumberValue(this value).
-        let x = Self::this_number_value(this, context)?;
+        let x = Self::this_number_value(this)?;
 
         let radix = args.get_or_undefined(0);
         let radix_number = if radix.is_undefined() {
@@ -686,9 +711,8 @@ impl Number {
                 // 4. If radixNumber < 2 or radixNumber > 36, throw a RangeError exception.
                 .filter(|i| (2..=36).contains(i))
                 .ok_or_else(|| {
-                    context.construct_range_error(
-                        "radix must be an integer at least 2 and no greater than 36",
-                    )
+                    JsNativeError::range()
+                        .with_message("radix must be an integer at least 2 and no greater than 36")
                 })?
         } as u8;
 
@@ -729,12 +753,8 @@ impl Number {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-number.prototype.valueof
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/valueOf
-    pub(crate) fn value_of(
-        this: &JsValue,
-        _: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
-        Ok(JsValue::new(Self::this_number_value(this, context)?))
+    pub(crate) fn value_of(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(JsValue::new(Self::this_number_value(this)?))
     }
 
     /// Builtin javascript 'parseInt(str, radix)' function.
@@ -761,7 +781,10 @@ impl Number {
             let input_string = val.to_string(context)?;
 
             // 2. Let S be ! TrimString(inputString, start).
-            let mut var_s = input_string.trim_start_matches(is_trimmable_whitespace);
+            // Unpaired surrogates are never radix digits, so a lossy conversion doesn't change
+            // the result.
+            let trimmed = String::from_utf16_lossy(input_string.trim_start());
+            let mut var_s = trimmed.as_str();
 
             // 3. Let sign be 1.
             // 4. If S is not empty and the first code unit of S is the code unit 0x002D (HYPHEN-MINUS),
@@ -885,7 +908,8 @@ impl Number {
     ) -> JsResult<JsValue> {
         if let Some(val) = args.get(0) {
             let input_string = val.to_string(context)?;
-            let s = input_string.trim_start_matches(is_trimmable_whitespace);
+            let trimmed = String::from_utf16_lossy(input_string.trim_start());
+            let s = trimmed.as_str();
             let s_prefix_lower = s.chars().take(4).collect::<String>().to_ascii_lowercase();
 
             // TODO: write our own lexer to match syntax StrDecimalLiteral
@@ -992,9 +1016,9 @@ impl Number {
         _ctx: &mut Context,
     ) -> JsResult<JsValue> {
         Ok(JsValue::new(if let Some(val) = args.get(0) {
-            match val {
-                JsValue::Integer(_) => true,
-                JsValue::Rational(number) => number.is_finite(),
+            match val.variant() {
+                JsVariant::Integer(_) => true,
+                JsVariant::Rational(number) => number.is_finite(),
                 _ => false,
             }
         } else {
@@ -1042,7 +1066,7 @@ impl Number {
         _ctx: &mut Context,
     ) -> JsResult<JsValue> {
         Ok(JsValue::new(
-            if let Some(&JsValue::Rational(number)) = args.get(0) {
+            if let Some(JsVariant::Rational(number)) = args.get(0).map(JsValue::variant) {
                 number.is_nan()
             } else {
                 false
@@ -1070,9 +1094,9 @@ impl Number {
         args: &[JsValue],
         _ctx: &mut Context,
     ) -> JsResult<JsValue> {
-        Ok(JsValue::new(match args.get(0) {
-            Some(JsValue::Integer(_)) => true,
-            Some(JsValue::Rational(number)) if Self::is_float_integer(*number) => {
+        Ok(JsValue::new(match args.get(0).map(JsValue::variant) {
+            Some(JsVariant::Integer(_)) => true,
+            Some(JsVariant::Rational(number)) if Self::is_float_integer(number) => {
                 number.abs() <= Self::MAX_SAFE_INTEGER
             }
             _ => false,
@@ -1087,9 +1111,9 @@ impl Number {
     /// [spec]: https://tc39.es/ecma262/#sec-isinteger
     #[inline]
     pub(crate) fn is_integer(val: &JsValue) -> bool {
-        match val {
-            JsValue::Integer(_) => true,
-            JsValue::Rational(number) => Self::is_float_integer(*number),
+        match val.variant() {
+            JsVariant::Integer(_) => true,
+            JsVariant::Rational(number) => Self::is_float_integer(number),
             _ => false,
         }
     }
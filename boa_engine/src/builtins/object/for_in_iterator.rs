@@ -4,7 +4,7 @@ use crate::{
     property::PropertyDescriptor,
     property::PropertyKey,
     symbol::WellKnownSymbols,
-    Context, JsResult, JsString, JsValue,
+    Context, JsNativeError, JsResult, JsString, JsValue,
 };
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
@@ -71,7 +71,7 @@ impl ForInIterator {
         let iterator = iterator
             .as_mut()
             .and_then(|obj| obj.as_for_in_iterator_mut())
-            .ok_or_else(|| context.construct_type_error("`this` is not a ForInIterator"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a ForInIterator"))?;
         let mut object = iterator.object.to_object(context)?;
         loop {
             if !iterator.object_was_visited {
@@ -22,9 +22,10 @@ use crate::{
         IntegrityLevel, JsObject, ObjectData, ObjectKind,
     },
     property::{Attribute, PropertyDescriptor, PropertyKey, PropertyNameKind},
+    string::utf16,
     symbol::WellKnownSymbols,
-    value::JsValue,
-    Context, JsResult, JsString,
+    value::{JsValue, JsVariant},
+    Context, JsNativeError, JsResult, JsString,
 };
 use boa_profiler::Profiler;
 use tap::{Conv, Pipe};
@@ -155,7 +156,7 @@ impl Object {
         // 2. Return ? O.[[GetPrototypeOf]]().
         let proto = obj.__get_prototype_of__(context)?;
 
-        Ok(proto.map_or(JsValue::Null, JsValue::new))
+        Ok(proto.map_or(JsValue::null(), JsValue::new))
     }
 
     /// `set Object.prototype.__proto__`
@@ -178,15 +179,15 @@ impl Object {
         let this = this.require_object_coercible(context)?;
 
         // 2. If Type(proto) is neither Object nor Null, return undefined.
-        let proto = match args.get_or_undefined(0) {
-            JsValue::Object(proto) => Some(proto.clone()),
-            JsValue::Null => None,
+        let proto = match args.get_or_undefined(0).variant() {
+            JsVariant::Object(proto) => Some(proto.clone()),
+            JsVariant::Null => None,
             _ => return Ok(JsValue::undefined()),
         };
 
         // 3. If Type(O) is not Object, return undefined.
-        let object = match this {
-            JsValue::Object(object) => object,
+        let object = match this.variant() {
+            JsVariant::Object(object) => object,
             _ => return Ok(JsValue::undefined()),
         };
 
@@ -195,7 +196,9 @@ impl Object {
 
         // 5. If status is false, throw a TypeError exception.
         if !status {
-            return context.throw_type_error("__proto__ called on null or undefined");
+            return Err(JsNativeError::typ()
+                .with_message("__proto__ called on null or undefined")
+                .into());
         }
 
         // 6. Return undefined.
@@ -224,8 +227,9 @@ impl Object {
 
         // 2. If IsCallable(getter) is false, throw a TypeError exception.
         if !getter.is_callable() {
-            return context
-                .throw_type_error("Object.prototype.__defineGetter__: Expecting function");
+            return Err(JsNativeError::typ()
+                .with_message("Object.prototype.__defineGetter__: Expecting function")
+                .into());
         }
 
         // 3. Let desc be PropertyDescriptor { [[Get]]: getter, [[Enumerable]]: true, [[Configurable]]: true }.
@@ -266,8 +270,9 @@ impl Object {
 
         // 2. If IsCallable(setter) is false, throw a TypeError exception.
         if !setter.is_callable() {
-            return context
-                .throw_type_error("Object.prototype.__defineSetter__: Expecting function");
+            return Err(JsNativeError::typ()
+                .with_message("Object.prototype.__defineSetter__: Expecting function")
+                .into());
         }
 
         // 3. Let desc be PropertyDescriptor { [[Set]]: setter, [[Enumerable]]: true, [[Configurable]]: true }.
@@ -389,16 +394,18 @@ impl Object {
         let prototype = args.get_or_undefined(0);
         let properties = args.get_or_undefined(1);
 
-        let obj = match prototype {
-            JsValue::Object(_) | JsValue::Null => JsObject::from_proto_and_data(
+        let obj = match prototype.variant() {
+            JsVariant::Object(_) | JsVariant::Null => JsObject::from_proto_and_data(
                 prototype.as_object().cloned(),
                 ObjectData::ordinary(),
             ),
             _ => {
-                return context.throw_type_error(format!(
-                    "Object prototype may only be an Object or null: {}",
-                    prototype.display()
-                ))
+                return Err(JsNativeError::typ()
+                    .with_message(format!(
+                        "Object prototype may only be an Object or null: {}",
+                        prototype.display()
+                    ))
+                    .into())
             }
         };
 
@@ -563,9 +570,11 @@ impl Object {
     /// [spec]: https://tc39.es/ecma262/#sec-object.setprototypeof
     pub fn get_prototype_of(_: &JsValue, args: &[JsValue], ctx: &mut Context) -> JsResult<JsValue> {
         if args.is_empty() {
-            return ctx.throw_type_error(
-                "Object.getPrototypeOf: At least 1 argument required, but only 0 passed",
-            );
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "Object.getPrototypeOf: At least 1 argument required, but only 0 passed",
+                )
+                .into());
         }
 
         // 1. Let obj be ? ToObject(O).
@@ -574,7 +583,7 @@ impl Object {
         // 2. Return ? obj.[[GetPrototypeOf]]().
         Ok(obj
             .__get_prototype_of__(ctx)?
-            .map_or(JsValue::Null, JsValue::new))
+            .map_or(JsValue::null(), JsValue::new))
     }
 
     /// Set the `prototype` of an object.
@@ -584,10 +593,12 @@ impl Object {
     /// [spec]: https://tc39.es/ecma262/#sec-object.setprototypeof
     pub fn set_prototype_of(_: &JsValue, args: &[JsValue], ctx: &mut Context) -> JsResult<JsValue> {
         if args.len() < 2 {
-            return ctx.throw_type_error(format!(
-                "Object.setPrototypeOf: At least 2 arguments required, but only {} passed",
-                args.len()
-            ));
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "Object.setPrototypeOf: At least 2 arguments required, but only {} passed",
+                    args.len()
+                ))
+                .into());
         }
 
         // 1. Set O to ? RequireObjectCoercible(O).
@@ -598,13 +609,17 @@ impl Object {
             .require_object_coercible(ctx)?
             .clone();
 
-        let proto = match args.get_or_undefined(1) {
-            JsValue::Object(obj) => Some(obj.clone()),
-            JsValue::Null => None,
+        let proto = match args.get_or_undefined(1).variant() {
+            JsVariant::Object(obj) => Some(obj.clone()),
+            JsVariant::Null => None,
             // 2. If Type(proto) is neither Object nor Null, throw a TypeError exception.
-            val => {
-                return ctx
-                    .throw_type_error(format!("expected an object or null, got {}", val.type_of()))
+            _ => {
+                return Err(JsNativeError::typ()
+                    .with_message(format!(
+                        "expected an object or null, got {}",
+                        args.get_or_undefined(1).type_of()
+                    ))
+                    .into())
             }
         };
 
@@ -620,7 +635,9 @@ impl Object {
 
         // 5. If status is false, throw a TypeError exception.
         if !status {
-            return ctx.throw_type_error("can't set prototype of this object");
+            return Err(JsNativeError::typ()
+                .with_message("can't set prototype of this object")
+                .into());
         }
 
         // 6. Return O.
@@ -666,21 +683,23 @@ impl Object {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         let object = args.get_or_undefined(0);
-        if let JsValue::Object(object) = object {
+        if let JsVariant::Object(object) = object.variant() {
             let key = args
                 .get(1)
-                .unwrap_or(&JsValue::Undefined)
+                .unwrap_or(&JsValue::undefined())
                 .to_property_key(context)?;
             let desc = args
                 .get(2)
-                .unwrap_or(&JsValue::Undefined)
+                .unwrap_or(&JsValue::undefined())
                 .to_property_descriptor(context)?;
 
             object.define_property_or_throw(key, desc, context)?;
 
             Ok(object.clone().into())
         } else {
-            context.throw_type_error("Object.defineProperty called on non-object")
+            Err(JsNativeError::typ()
+                .with_message("Object.defineProperty called on non-object")
+                .into())
         }
     }
 
@@ -700,12 +719,14 @@ impl Object {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         let arg = args.get_or_undefined(0);
-        if let JsValue::Object(obj) = arg {
+        if let JsVariant::Object(obj) = arg.variant() {
             let props = args.get_or_undefined(1);
             object_define_properties(obj, props, context)?;
             Ok(arg.clone())
         } else {
-            context.throw_type_error("Expected an object")
+            Err(JsNativeError::typ()
+                .with_message("Expected an object")
+                .into())
         }
     }
 
@@ -747,7 +768,7 @@ impl Object {
 
         //  4. Let isArray be ? IsArray(O).
         //  5. If isArray is true, let builtinTag be "Array".
-        let builtin_tag = if JsValue::from(o.clone()).is_array(context)? {
+        let builtin_tag = if JsValue::from(o.clone()).is_array()? {
             "Array"
         } else {
             // 6. Else if O has a [[ParameterMap]] internal slot, let builtinTag be "Arguments".
@@ -764,7 +785,7 @@ impl Object {
                 ObjectKind::Array => "Array",
                 ObjectKind::Arguments(_) => "Arguments",
                 ObjectKind::Function(_) => "Function",
-                ObjectKind::Error => "Error",
+                ObjectKind::Error(_) => "Error",
                 ObjectKind::Boolean(_) => "Boolean",
                 ObjectKind::Number(_) => "Number",
                 ObjectKind::String(_) => "String",
@@ -778,10 +799,12 @@ impl Object {
         let tag = o.get(WellKnownSymbols::to_string_tag(), context)?;
 
         // 16. If Type(tag) is not String, set tag to builtinTag.
-        let tag_str = tag.as_string().map_or(builtin_tag, JsString::as_str);
+        let tag_str = tag
+            .as_string()
+            .map_or_else(|| JsString::new(builtin_tag), Clone::clone);
 
         // 17. Return the string-concatenation of "[object ", tag, and "]".
-        Ok(format!("[object {tag_str}]").into())
+        Ok(JsString::concat_array(&[utf16!("[object "), &tag_str, utf16!("]")]).into())
     }
 
     /// `Object.prototype.toLocaleString( [ reserved1 [ , reserved2 ] ] )`
@@ -1012,7 +1035,9 @@ impl Object {
             let status = o.set_integrity_level(IntegrityLevel::Sealed, context)?;
             // 3. If status is false, throw a TypeError exception.
             if !status {
-                return context.throw_type_error("cannot seal object");
+                return Err(JsNativeError::typ()
+                    .with_message("cannot seal object")
+                    .into());
             }
         }
         // 1. If Type(O) is not Object, return O.
@@ -1057,7 +1082,9 @@ impl Object {
             let status = o.set_integrity_level(IntegrityLevel::Frozen, context)?;
             // 3. If status is false, throw a TypeError exception.
             if !status {
-                return context.throw_type_error("cannot freeze object");
+                return Err(JsNativeError::typ()
+                    .with_message("cannot freeze object")
+                    .into());
             }
         }
         // 1. If Type(O) is not Object, return O.
@@ -1106,7 +1133,9 @@ impl Object {
             let status = o.__prevent_extensions__(context)?;
             // 3. If status is false, throw a TypeError exception.
             if !status {
-                return context.throw_type_error("cannot prevent extensions");
+                return Err(JsNativeError::typ()
+                    .with_message("cannot prevent extensions")
+                    .into());
             }
         }
         // 1. If Type(O) is not Object, return O.
@@ -8,7 +8,7 @@ mod promise_job;
 use self::promise_job::PromiseJob;
 use super::{iterable::IteratorRecord, JsArgs};
 use crate::{
-    builtins::{Array, BuiltIn},
+    builtins::{error::ErrorKind, Array, BuiltIn},
     context::intrinsics::StandardConstructors,
     job::JobCallback,
     object::{
@@ -18,9 +18,9 @@ use crate::{
     property::{Attribute, PropertyDescriptorBuilder},
     symbol::WellKnownSymbols,
     value::JsValue,
-    Context, JsResult,
+    Context, JsError, JsNativeError, JsResult,
 };
-use boa_gc::{Cell as GcCell, Finalize, Gc, Trace};
+use boa_gc::{custom_trace, Cell as GcCell, Finalize, Gc, Trace};
 use boa_profiler::Profiler;
 use std::{cell::Cell, rc::Rc};
 use tap::{Conv, Pipe};
@@ -37,12 +37,13 @@ macro_rules! if_abrupt_reject_promise {
     ($value:ident, $capability:expr, $context: expr) => {
         let $value = match $value {
             // 1. If value is an abrupt completion, then
-            Err(value) => {
+            Err(err) => {
+                let err = err.to_opaque($context);
                 // a. Perform ? Call(capability.[[Reject]], undefined, « value.[[Value]] »).
                 $context.call(
                     &$capability.reject().clone().into(),
                     &JsValue::undefined(),
-                    &[value],
+                    &[err.into()],
                 )?;
 
                 // b. Return capability.[[Promise]].
@@ -56,17 +57,32 @@ macro_rules! if_abrupt_reject_promise {
 
 pub(crate) use if_abrupt_reject_promise;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum PromiseState {
+/// The current state of a [`Promise`].
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-properties-of-promise-instances
+#[derive(Debug, Clone, PartialEq, Eq, Finalize)]
+pub enum PromiseState {
+    /// The promise is neither fulfilled nor rejected.
     Pending,
-    Fulfilled,
-    Rejected,
+    /// The promise was fulfilled with the contained value.
+    Fulfilled(JsValue),
+    /// The promise was rejected with the contained reason.
+    Rejected(JsValue),
+}
+
+unsafe impl Trace for PromiseState {
+    custom_trace!(this, {
+        if let Self::Fulfilled(v) | Self::Rejected(v) = this {
+            mark(v);
+        }
+    });
 }
 
 #[derive(Debug, Clone, Trace, Finalize)]
 pub struct Promise {
-    promise_result: Option<JsValue>,
-    #[unsafe_ignore_trace]
     promise_state: PromiseState,
     promise_fulfill_reactions: Vec<ReactionRecord>,
     promise_reject_reactions: Vec<ReactionRecord>,
@@ -110,7 +126,9 @@ impl PromiseCapability {
 
         match c.as_constructor() {
             // 1. If IsConstructor(C) is false, throw a TypeError exception.
-            None => context.throw_type_error("PromiseCapability: expected constructor"),
+            None => Err(JsNativeError::typ()
+                .with_message("PromiseCapability: expected constructor")
+                .into()),
             Some(c) => {
                 let c = c.clone();
 
@@ -125,19 +143,20 @@ impl PromiseCapability {
                 // 5. Let executor be CreateBuiltinFunction(executorClosure, 2, "", « »).
                 let executor = FunctionBuilder::closure_with_captures(
                     context,
-                    |_this, args: &[JsValue], captures, context| {
+                    |_this, args: &[JsValue], captures, _context| {
                         let mut promise_capability = captures.borrow_mut();
                         // a. If promiseCapability.[[Resolve]] is not undefined, throw a TypeError exception.
                         if !promise_capability.resolve.is_undefined() {
-                            return context.throw_type_error(
-                                "promiseCapability.[[Resolve]] is not undefined",
-                            );
+                            return Err(JsNativeError::typ()
+                                .with_message("promiseCapability.[[Resolve]] is not undefined")
+                                .into());
                         }
 
                         // b. If promiseCapability.[[Reject]] is not undefined, throw a TypeError exception.
                         if !promise_capability.reject.is_undefined() {
-                            return context
-                                .throw_type_error("promiseCapability.[[Reject]] is not undefined");
+                            return Err(JsNativeError::typ()
+                                .with_message("promiseCapability.[[Reject]] is not undefined")
+                                .into());
                         }
 
                         let resolve = args.get_or_undefined(0);
@@ -150,7 +169,7 @@ impl PromiseCapability {
                         promise_capability.reject = reject.clone();
 
                         // e. Return undefined.
-                        Ok(JsValue::Undefined)
+                        Ok(JsValue::undefined())
                     },
                     promise_capability.clone(),
                 )
@@ -173,8 +192,8 @@ impl PromiseCapability {
                     .cloned()
                     .and_then(JsFunction::from_object)
                     .ok_or_else(|| {
-                        context
-                            .construct_type_error("promiseCapability.[[Resolve]] is not callable")
+                        JsNativeError::typ()
+                            .with_message("promiseCapability.[[Resolve]] is not callable")
                     })?;
 
                 // 8. If IsCallable(promiseCapability.[[Reject]]) is false, throw a TypeError exception.
@@ -183,7 +202,8 @@ impl PromiseCapability {
                     .cloned()
                     .and_then(JsFunction::from_object)
                     .ok_or_else(|| {
-                        context.construct_type_error("promiseCapability.[[Reject]] is not callable")
+                        JsNativeError::typ()
+                            .with_message("promiseCapability.[[Reject]] is not callable")
                     })?;
 
                 // 9. Set promiseCapability.[[Promise]] to promise.
@@ -262,15 +282,45 @@ impl BuiltIn for Promise {
     }
 }
 
-#[derive(Debug)]
-struct ResolvingFunctionsRecord {
-    resolve: JsValue,
-    reject: JsValue,
+/// The resolving functions of a promise, returned by `CreateResolvingFunctions`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-createresolvingfunctions
+#[derive(Debug, Clone, Finalize)]
+pub struct ResolvingFunctions {
+    /// Resolves the promise with the first argument passed to the function.
+    pub resolve: JsFunction,
+    /// Rejects the promise with the first argument passed to the function.
+    pub reject: JsFunction,
+}
+
+unsafe impl Trace for ResolvingFunctions {
+    custom_trace!(this, {
+        mark(&this.resolve);
+        mark(&this.reject);
+    });
 }
 
 impl Promise {
     const LENGTH: usize = 1;
 
+    /// Creates the data of a new pending promise.
+    pub(crate) const fn new() -> Self {
+        Self {
+            promise_state: PromiseState::Pending,
+            promise_fulfill_reactions: Vec::new(),
+            promise_reject_reactions: Vec::new(),
+            promise_is_handled: false,
+        }
+    }
+
+    /// Gets the current state of the promise.
+    pub fn state(&self) -> &PromiseState {
+        &self.promise_state
+    }
+
     /// `Promise ( executor )`
     ///
     /// More information:
@@ -284,14 +334,18 @@ impl Promise {
     ) -> JsResult<JsValue> {
         // 1. If NewTarget is undefined, throw a TypeError exception.
         if new_target.is_undefined() {
-            return context.throw_type_error("Promise NewTarget cannot be undefined");
+            return Err(JsNativeError::typ()
+                .with_message("Promise NewTarget cannot be undefined")
+                .into());
         }
 
         let executor = args.get_or_undefined(0);
 
         // 2. If IsCallable(executor) is false, throw a TypeError exception.
         if !executor.is_callable() {
-            return context.throw_type_error("Promise executor is not callable");
+            return Err(JsNativeError::typ()
+                .with_message("Promise executor is not callable")
+                .into());
         }
 
         // 3. Let promise be ? OrdinaryCreateFromConstructor(NewTarget, "%Promise.prototype%", « [[PromiseState]], [[PromiseResult]], [[PromiseFulfillReactions]], [[PromiseRejectReactions]], [[PromiseIsHandled]] »).
@@ -301,7 +355,6 @@ impl Promise {
         let promise = JsObject::from_proto_and_data(
             promise,
             ObjectData::promise(Self {
-                promise_result: None,
                 // 4. Set promise.[[PromiseState]] to pending.
                 promise_state: PromiseState::Pending,
                 // 5. Set promise.[[PromiseFulfillReactions]] to a new empty List.
@@ -319,17 +372,20 @@ impl Promise {
         // 9. Let completion Completion(Call(executor, undefined, « resolvingFunctions.[[Resolve]], resolvingFunctions.[[Reject]] »)be ).
         let completion = context.call(
             executor,
-            &JsValue::Undefined,
+            &JsValue::undefined(),
             &[
-                resolving_functions.resolve,
-                resolving_functions.reject.clone(),
+                resolving_functions.resolve.clone().into(),
+                resolving_functions.reject.clone().into(),
             ],
         );
 
         // 10. If completion is an abrupt completion, then
-        if let Err(value) = completion {
+        if let Err(e) = completion {
+            let e = e.to_opaque(context);
             // a. Perform ? Call(resolvingFunctions.[[Reject]], undefined, « completion.[[Value]] »).
-            context.call(&resolving_functions.reject, &JsValue::Undefined, &[value])?;
+            resolving_functions
+                .reject
+                .call(&JsValue::undefined(), &[e], context)?;
         }
 
         // 11. Return promise.
@@ -489,7 +545,7 @@ impl Promise {
             };
 
             // h. Append undefined to values.
-            values.borrow_mut().push(JsValue::Undefined);
+            values.borrow_mut().push(JsValue::undefined());
 
             // i. Let nextPromise be ? Call(promiseResolve, constructor, « nextValue »).
             let next_promise =
@@ -1033,7 +1089,7 @@ impl Promise {
                                 .constructors()
                                 .aggregate_error()
                                 .prototype(),
-                            ObjectData::error(),
+                            ObjectData::error(ErrorKind::Aggregate),
                         );
 
                         // 2. Perform ! DefinePropertyOrThrow(error, "errors", PropertyDescriptor { [[Configurable]]: true, [[Enumerable]]: false, [[Writable]]: true, [[Value]]: CreateArrayFromList(errors) }).
@@ -1053,7 +1109,7 @@ impl Promise {
                             .expect("cannot fail per spec");
 
                         // 3. Return ThrowCompletion(error).
-                        return Err(error.into());
+                        return Err(JsError::from_opaque(error.into()));
                     }
 
                     // iv. Return resultCapability.[[Promise]].
@@ -1128,7 +1184,7 @@ impl Promise {
                                 .constructors()
                                 .aggregate_error()
                                 .prototype(),
-                            ObjectData::error(),
+                            ObjectData::error(ErrorKind::Aggregate),
                         );
 
                         // b. Perform ! DefinePropertyOrThrow(error, "errors", PropertyDescriptor { [[Configurable]]: true, [[Enumerable]]: false, [[Writable]]: true, [[Value]]: CreateArrayFromList(errors) }).
@@ -1192,10 +1248,10 @@ impl Promise {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-createresolvingfunctions
-    fn create_resolving_functions(
+    pub(crate) fn create_resolving_functions(
         promise: &JsObject,
         context: &mut Context,
-    ) -> ResolvingFunctionsRecord {
+    ) -> ResolvingFunctions {
         #[derive(Debug, Trace, Finalize)]
         struct RejectResolveCaptures {
             promise: JsObject,
@@ -1232,7 +1288,7 @@ impl Promise {
 
                 // 5. If alreadyResolved.[[Value]] is true, return undefined.
                 if already_resolved.get() {
-                    return Ok(JsValue::Undefined);
+                    return Ok(JsValue::undefined());
                 }
 
                 // 6. Set alreadyResolved.[[Value]] to true.
@@ -1243,18 +1299,19 @@ impl Promise {
                 // 7. If SameValue(resolution, promise) is true, then
                 if JsValue::same_value(resolution, &promise.clone().into()) {
                     //   a. Let selfResolutionError be a newly created TypeError object.
-                    let self_resolution_error =
-                        context.construct_type_error("SameValue(resolution, promise) is true");
+                    let self_resolution_error = JsNativeError::typ()
+                        .with_message("SameValue(resolution, promise) is true")
+                        .to_opaque(context);
 
                     //   b. Perform RejectPromise(promise, selfResolutionError).
                     promise
                         .borrow_mut()
                         .as_promise_mut()
                         .expect("Expected promise to be a Promise")
-                        .reject_promise(&self_resolution_error, context);
+                        .reject_promise(&self_resolution_error.into(), context);
 
                     //   c. Return undefined.
-                    return Ok(JsValue::Undefined);
+                    return Ok(JsValue::undefined());
                 }
 
                 let then = if let Some(resolution) = resolution.as_object() {
@@ -1270,12 +1327,13 @@ impl Promise {
                         .fulfill_promise(resolution, context)?;
 
                     //   b. Return undefined.
-                    return Ok(JsValue::Undefined);
+                    return Ok(JsValue::undefined());
                 };
 
                 let then_action = match then {
                     // 10. If then is an abrupt completion, then
-                    Err(value) => {
+                    Err(e) => {
+                        let value = e.to_opaque(context);
                         //   a. Perform RejectPromise(promise, then.[[Value]]).
                         promise
                             .borrow_mut()
@@ -1284,7 +1342,7 @@ impl Promise {
                             .reject_promise(&value, context);
 
                         //   b. Return undefined.
-                        return Ok(JsValue::Undefined);
+                        return Ok(JsValue::undefined());
                     }
                     // 11. Let thenAction be then.[[Value]].
                     Ok(then) => then,
@@ -1302,7 +1360,7 @@ impl Promise {
                             .fulfill_promise(resolution, context)?;
 
                         //   b. Return undefined.
-                        return Ok(JsValue::Undefined);
+                        return Ok(JsValue::undefined());
                     }
                 };
 
@@ -1310,18 +1368,17 @@ impl Promise {
                 let then_job_callback = JobCallback::make_job_callback(then_action.clone());
 
                 // 14. Let job be NewPromiseResolveThenableJob(promise, resolution, thenJobCallback).
-                let job: JobCallback = PromiseJob::new_promise_resolve_thenable_job(
+                let job = PromiseJob::new_promise_resolve_thenable_job(
                     promise.clone(),
                     resolution.clone(),
                     then_job_callback,
-                    context,
                 );
 
                 // 15. Perform HostEnqueuePromiseJob(job.[[Job]], job.[[Realm]]).
                 context.host_enqueue_promise_job(job);
 
                 // 16. Return undefined.
-                Ok(JsValue::Undefined)
+                Ok(JsValue::undefined())
             },
             resolve_captures,
         )
@@ -1356,7 +1413,7 @@ impl Promise {
 
                 // 5. If alreadyResolved.[[Value]] is true, return undefined.
                 if already_resolved.get() {
-                    return Ok(JsValue::Undefined);
+                    return Ok(JsValue::undefined());
                 }
 
                 // 6. Set alreadyResolved.[[Value]] to true.
@@ -1371,7 +1428,7 @@ impl Promise {
                     .reject_promise(args.get_or_undefined(0), context);
 
                 // 8. Return undefined.
-                Ok(JsValue::Undefined)
+                Ok(JsValue::undefined())
             },
             reject_captures,
         )
@@ -1381,9 +1438,7 @@ impl Promise {
         .build();
 
         // 12. Return the Record { [[Resolve]]: resolve, [[Reject]]: reject }.
-        let resolve = resolve.conv::<JsValue>();
-        let reject = reject.conv::<JsValue>();
-        ResolvingFunctionsRecord { resolve, reject }
+        ResolvingFunctions { resolve, reject }
     }
 
     /// `FulfillPromise ( promise, value )`
@@ -1397,9 +1452,8 @@ impl Promise {
     /// [spec]: https://tc39.es/ecma262/#sec-fulfillpromise
     pub fn fulfill_promise(&mut self, value: &JsValue, context: &mut Context) -> JsResult<()> {
         // 1. Assert: The value of promise.[[PromiseState]] is pending.
-        assert_eq!(
-            self.promise_state,
-            PromiseState::Pending,
+        assert!(
+            matches!(self.promise_state, PromiseState::Pending),
             "promise was not pending"
         );
 
@@ -1410,17 +1464,15 @@ impl Promise {
         Self::trigger_promise_reactions(reactions, value, context);
         // reordering this statement does not affect the semantics
 
-        // 3. Set promise.[[PromiseResult]] to value.
-        self.promise_result = Some(value.clone());
-
         // 4. Set promise.[[PromiseFulfillReactions]] to undefined.
         self.promise_fulfill_reactions = Vec::new();
 
         // 5. Set promise.[[PromiseRejectReactions]] to undefined.
         self.promise_reject_reactions = Vec::new();
 
+        // 3. Set promise.[[PromiseResult]] to value.
         // 6. Set promise.[[PromiseState]] to fulfilled.
-        self.promise_state = PromiseState::Fulfilled;
+        self.promise_state = PromiseState::Fulfilled(value.clone());
 
         // 8. Return unused.
         Ok(())
@@ -1437,9 +1489,8 @@ impl Promise {
     /// [spec]: https://tc39.es/ecma262/#sec-rejectpromise
     pub fn reject_promise(&mut self, reason: &JsValue, context: &mut Context) {
         // 1. Assert: The value of promise.[[PromiseState]] is pending.
-        assert_eq!(
-            self.promise_state,
-            PromiseState::Pending,
+        assert!(
+            matches!(self.promise_state, PromiseState::Pending),
             "Expected promise.[[PromiseState]] to be pending"
         );
 
@@ -1450,17 +1501,15 @@ impl Promise {
         Self::trigger_promise_reactions(reactions, reason, context);
         // reordering this statement does not affect the semantics
 
-        // 3. Set promise.[[PromiseResult]] to reason.
-        self.promise_result = Some(reason.clone());
-
         // 4. Set promise.[[PromiseFulfillReactions]] to undefined.
         self.promise_fulfill_reactions = Vec::new();
 
         // 5. Set promise.[[PromiseRejectReactions]] to undefined.
         self.promise_reject_reactions = Vec::new();
 
+        // 3. Set promise.[[PromiseResult]] to reason.
         // 6. Set promise.[[PromiseState]] to rejected.
-        self.promise_state = PromiseState::Rejected;
+        self.promise_state = PromiseState::Rejected(reason.clone());
 
         // 7. If promise.[[PromiseIsHandled]] is false, perform HostPromiseRejectionTracker(promise, "reject").
         if !self.promise_is_handled {
@@ -1491,8 +1540,7 @@ impl Promise {
         // 1. For each element reaction of reactions, do
         for reaction in reactions {
             // a. Let job be NewPromiseReactionJob(reaction, argument).
-            let job =
-                PromiseJob::new_promise_reaction_job(reaction.clone(), argument.clone(), context);
+            let job = PromiseJob::new_promise_reaction_job(reaction.clone(), argument.clone());
 
             // b. Perform HostEnqueuePromiseJob(job.[[Job]], job.[[Realm]]).
             context.host_enqueue_promise_job(job);
@@ -1674,7 +1722,9 @@ impl Promise {
             Self::promise_resolve(c.clone(), x.clone(), context)
         } else {
             // 2. If Type(C) is not Object, throw a TypeError exception.
-            context.throw_type_error("Promise.resolve() called on a non-object")
+            Err(JsNativeError::typ()
+                .with_message("Promise.resolve() called on a non-object")
+                .into())
         }
     }
 
@@ -1731,7 +1781,9 @@ impl Promise {
         let promise_obj = if let Some(p) = promise.as_object() {
             p
         } else {
-            return context.throw_type_error("finally called with a non-object promise");
+            return Err(JsNativeError::typ()
+                .with_message("finally called with a non-object promise")
+                .into());
         };
 
         // 3. Let C be ? SpeciesConstructor(promise, %Promise%).
@@ -1819,7 +1871,7 @@ impl Promise {
                         context,
                         |_this, _args, captures, _context| {
                             // 1. Return ThrowCompletion(reason).
-                            Err(captures.reason.clone())
+                            Err(JsError::from_opaque(captures.reason.clone()))
                         },
                         ThrowReasonCaptures {
                             reason: reason.clone(),
@@ -1868,7 +1920,11 @@ impl Promise {
         // 2. If IsPromise(promise) is false, throw a TypeError exception.
         let promise_obj = match promise.as_promise() {
             Some(obj) => obj,
-            None => return context.throw_type_error("IsPromise(promise) is false"),
+            None => {
+                return Err(JsNativeError::typ()
+                    .with_message("IsPromise(promise) is false")
+                    .into())
+            }
         };
 
         // 3. Let C be ? SpeciesConstructor(promise, %Promise%).
@@ -1943,7 +1999,7 @@ impl Promise {
             handler: on_rejected_job_callback,
         };
 
-        match self.promise_state {
+        match &self.promise_state {
             // 9. If promise.[[PromiseState]] is pending, then
             PromiseState::Pending => {
                 //   a. Append fulfillReaction as the last element of the List that is promise.[[PromiseFulfillReactions]].
@@ -1954,16 +2010,12 @@ impl Promise {
             }
 
             // 10. Else if promise.[[PromiseState]] is fulfilled, then
-            PromiseState::Fulfilled => {
+            PromiseState::Fulfilled(value) => {
                 //   a. Let value be promise.[[PromiseResult]].
-                let value = self
-                    .promise_result
-                    .clone()
-                    .expect("promise.[[PromiseResult]] cannot be empty");
+                let value = value.clone();
 
                 //   b. Let fulfillJob be NewPromiseReactionJob(fulfillReaction, value).
-                let fulfill_job =
-                    PromiseJob::new_promise_reaction_job(fulfill_reaction, value, context);
+                let fulfill_job = PromiseJob::new_promise_reaction_job(fulfill_reaction, value);
 
                 //   c. Perform HostEnqueuePromiseJob(fulfillJob.[[Job]], fulfillJob.[[Realm]]).
                 context.host_enqueue_promise_job(fulfill_job);
@@ -1971,12 +2023,9 @@ impl Promise {
 
             // 11. Else,
             //   a. Assert: The value of promise.[[PromiseState]] is rejected.
-            PromiseState::Rejected => {
+            PromiseState::Rejected(reason) => {
                 //   b. Let reason be promise.[[PromiseResult]].
-                let reason = self
-                    .promise_result
-                    .clone()
-                    .expect("promise.[[PromiseResult]] cannot be empty");
+                let reason = reason.clone();
 
                 //   c. If promise.[[PromiseIsHandled]] is false, perform HostPromiseRejectionTracker(promise, "handle").
                 if !self.promise_is_handled {
@@ -1985,8 +2034,7 @@ impl Promise {
                 }
 
                 //   d. Let rejectJob be NewPromiseReactionJob(rejectReaction, reason).
-                let reject_job =
-                    PromiseJob::new_promise_reaction_job(reject_reaction, reason, context);
+                let reject_job = PromiseJob::new_promise_reaction_job(reject_reaction, reason);
 
                 //   e. Perform HostEnqueuePromiseJob(rejectJob.[[Job]], rejectJob.[[Realm]]).
                 context.host_enqueue_promise_job(reject_job);
@@ -1999,7 +2047,7 @@ impl Promise {
         match result_capability {
             // 13. If resultCapability is undefined, then
             //   a. Return undefined.
-            None => JsValue::Undefined,
+            None => JsValue::undefined(),
 
             // 14. Else,
             //   a. Return resultCapability.[[Promise]].
@@ -2068,7 +2116,9 @@ impl Promise {
             // 3. Return promiseResolve.
             Ok(promise_resolve.clone())
         } else {
-            context.throw_type_error("retrieving a non-callable promise resolver")
+            Err(JsNativeError::typ()
+                .with_message("retrieving a non-callable promise resolver")
+                .into())
         }
     }
 }
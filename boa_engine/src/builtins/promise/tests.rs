@@ -1,4 +1,21 @@
-use crate::{forward, Context};
+use super::PromiseState;
+use crate::{
+    forward,
+    job::{FutureJob, IdleJobQueue, JobQueue, NativeJob, SimpleJobQueue},
+    object::{FunctionBuilder, JsPromise},
+    Context, JsNativeError, JsNativeErrorKind, JsResult, JsValue,
+};
+use std::{
+    cell::Cell,
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::{self, Poll, Wake, Waker},
+};
 
 #[test]
 fn promise() {
@@ -17,3 +34,198 @@ fn promise() {
     let after_completion = forward(&mut context, "count");
     assert_eq!(after_completion, String::from("3"));
 }
+
+#[derive(Debug, Default)]
+struct PausedJobQueue {
+    paused: Cell<bool>,
+    inner: SimpleJobQueue,
+}
+
+impl JobQueue for PausedJobQueue {
+    fn enqueue_promise_job(&self, job: NativeJob, context: &mut Context) {
+        self.inner.enqueue_promise_job(job, context);
+    }
+
+    fn enqueue_future_job(&self, future: FutureJob, context: &mut Context) {
+        self.inner.enqueue_future_job(future, context);
+    }
+
+    fn run_jobs(&self, context: &mut Context) -> JsResult<()> {
+        if self.paused.get() {
+            return Ok(());
+        }
+        self.inner.run_jobs(context)
+    }
+}
+
+#[test]
+fn custom_job_queue() {
+    let queue = Rc::new(PausedJobQueue::default());
+    queue.paused.set(true);
+    let mut context = Context::builder().job_queue(queue.clone()).build();
+
+    let init = r#"
+        let count = 0;
+        Promise.resolve().then(() => (count += 1));
+        "#;
+    context.eval(init).unwrap();
+    assert_eq!(forward(&mut context, "count"), "0");
+    assert!(!queue.inner.is_empty());
+
+    queue.paused.set(false);
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "count"), "1");
+    assert!(queue.inner.is_empty());
+}
+
+#[test]
+fn idle_job_queue() {
+    let mut context = Context::builder().job_queue(Rc::new(IdleJobQueue)).build();
+
+    let init = r#"
+        let count = 0;
+        Promise.resolve().then(() => (count += 1));
+        "#;
+    context.eval(init).unwrap();
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "count"), "0");
+}
+
+#[test]
+fn future_job() {
+    /// A future that is pending the first time it is polled.
+    struct YieldNow(bool);
+
+    impl Future for YieldNow {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<()> {
+            if self.0 {
+                Poll::Ready(())
+            } else {
+                self.0 = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    let mut context = Context::default();
+    context.eval("var log = [];").unwrap();
+
+    context.enqueue_future_job(Box::pin(async {
+        YieldNow(false).await;
+        NativeJob::new(|context| context.eval("log.push('future')"))
+    }));
+
+    let init = r#"
+        Promise.resolve().then(() => log.push('promise'));
+        "#;
+    context.eval(init).unwrap();
+    assert_eq!(forward(&mut context, "log.join()"), "\"promise,future\"");
+}
+
+#[test]
+fn js_promise_resolve_adopts_thenable() {
+    let mut context = Context::default();
+    let thenable = context
+        .eval("({ then(resolve) { resolve('adopted'); } })")
+        .unwrap();
+
+    let promise = JsPromise::resolve(thenable, &mut context).unwrap();
+    assert_eq!(promise.state(), PromiseState::Pending);
+
+    context.run_jobs().unwrap();
+    assert_eq!(promise.state(), PromiseState::Fulfilled("adopted".into()));
+}
+
+#[test]
+fn js_promise_reject_and_finally() {
+    let mut context = Context::default();
+    let calls = Rc::new(Cell::new(0));
+
+    let on_finally = FunctionBuilder::closure_with_state(
+        &mut context,
+        |_, _, calls, _| {
+            calls.set(calls.get() + 1);
+            Ok(JsValue::undefined())
+        },
+        calls.clone(),
+    )
+    .build();
+
+    let promise = JsPromise::reject(JsNativeError::range().with_message("oops"), &mut context)
+        .finally(on_finally, &mut context)
+        .unwrap();
+
+    let err = promise.await_blocking(&mut context).unwrap_err();
+    let err = err.try_native(&mut context).unwrap();
+    assert_eq!(err.kind, JsNativeErrorKind::Range);
+    assert_eq!(err.message(), "oops");
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn js_promise_from_object() {
+    let mut context = Context::default();
+    let object = context.eval("new Promise(() => {})").unwrap();
+    let promise =
+        JsPromise::from_object(object.as_object().unwrap().clone(), &mut context).unwrap();
+    assert_eq!(promise.state(), PromiseState::Pending);
+
+    let object = context.eval("({})").unwrap();
+    assert!(JsPromise::from_object(object.as_object().unwrap().clone(), &mut context).is_err());
+}
+
+#[test]
+fn js_promise_into_future() {
+    #[derive(Default)]
+    struct FlagWaker(AtomicBool);
+
+    impl Wake for FlagWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    let mut context = Context::default();
+    let (promise, resolvers) = JsPromise::new_pending(&mut context);
+    let mut future = promise.into_js_future(&mut context);
+
+    let flag = Arc::new(FlagWaker::default());
+    let waker = Waker::from(flag.clone());
+    let mut cx = task::Context::from_waker(&waker);
+    assert!(Pin::new(&mut future).poll(&mut cx).is_pending());
+
+    resolvers
+        .resolve
+        .call(&JsValue::undefined(), &[5.into()], &mut context)
+        .unwrap();
+    assert!(!flag.0.load(Ordering::SeqCst));
+
+    context.run_jobs().unwrap();
+    assert!(flag.0.load(Ordering::SeqCst));
+    match Pin::new(&mut future).poll(&mut cx) {
+        Poll::Ready(result) => assert_eq!(result.unwrap(), 5.into()),
+        Poll::Pending => panic!("the future should be ready after running the jobs"),
+    }
+}
+
+#[test]
+fn js_promise_future_dropped_before_settling() {
+    let mut context = Context::default();
+    let (promise, resolvers) = JsPromise::new_pending(&mut context);
+    drop(promise.into_js_future(&mut context));
+
+    let object = context.construct_object();
+    resolvers
+        .resolve
+        .call(&JsValue::undefined(), &[object.into()], &mut context)
+        .unwrap();
+    context.run_jobs().unwrap();
+
+    // The reactions and the result they stored are collected without the future.
+    drop(resolvers);
+    boa_gc::force_collect();
+    assert_eq!(context.eval("1 + 1"), Ok(JsValue::new(2)));
+}
@@ -21,13 +21,14 @@ use crate::{
         JsObject, ObjectData,
     },
     property::{Attribute, PropertyDescriptorBuilder},
+    string::{utf16, CodePoint},
     symbol::WellKnownSymbols,
     syntax::lexer::regex::RegExpFlags,
     value::{IntegerOrInfinity, JsValue},
-    Context, JsResult, JsString,
+    Context, JsNativeError, JsResult, JsString,
 };
 use boa_profiler::Profiler;
-use regress::Regex;
+use regress::{Flags, Regex};
 use std::str::FromStr;
 use tap::{Conv, Pipe};
 
@@ -279,15 +280,28 @@ impl RegExp {
             flags.to_string(context)?
         };
 
+        // 5. - 15.
+        let regexp = Self::from_source(p, f)?;
+        obj.borrow_mut().data = ObjectData::reg_exp(Box::new(regexp));
+
+        // 16. Perform ? Set(obj, "lastIndex", +0𝔽, true).
+        obj.set("lastIndex", 0, true, context)?;
+
+        // 16. Return obj.
+        Ok(obj.into())
+    }
+
+    /// Compiles the regular expression `p` with the flags `f`.
+    ///
+    /// These are the steps 5 to 15 of `RegExpInitialize`, which create the matcher.
+    pub(crate) fn from_source(p: JsString, f: JsString) -> JsResult<Self> {
         // 5. If F contains any code unit other than "g", "i", "m", "s", "u", or "y"
         //    or if it contains the same code unit more than once, throw a SyntaxError exception.
-        let flags = match RegExpFlags::from_str(&f) {
-            Err(msg) => return context.throw_syntax_error(msg),
+        let flags = match RegExpFlags::from_str(&f.to_std_string_escaped()) {
+            Err(msg) => return Err(JsNativeError::syntax().with_message(msg).into()),
             Ok(result) => result,
         };
 
-        // TODO: Correct UTF-16 handling in 6. - 8.
-
         // 9. Let parseResult be ParsePattern(patternText, u).
         // 10. If parseResult is a non-empty List of SyntaxError objects, throw a SyntaxError exception.
         // 11. Assert: parseResult is a Pattern Parse Node.
@@ -295,26 +309,33 @@ impl RegExp {
         // 13. Set obj.[[OriginalFlags]] to F.
         // 14. NOTE: The definitions of DotAll, IgnoreCase, Multiline, and Unicode in 22.2.2.1 refer to this value of obj.[[OriginalFlags]].
         // 15. Set obj.[[RegExpMatcher]] to CompilePattern of parseResult.
-        let matcher = match Regex::with_flags(&p, f.as_ref()) {
+        let matcher = match Regex::from_unicode(
+            p.code_points().map(CodePoint::as_u32),
+            Flags::new(f.iter().copied().map(u32::from)),
+        ) {
             Err(error) => {
-                return context
-                    .throw_syntax_error(format!("failed to create matcher: {}", error.text));
+                return Err(JsNativeError::syntax()
+                    .with_message(format!("failed to create matcher: {}", error.text))
+                    .into());
             }
             Ok(val) => val,
         };
-        let regexp = Self {
+        Ok(Self {
             matcher,
             flags,
             original_source: p,
             original_flags: f,
-        };
-        obj.borrow_mut().data = ObjectData::reg_exp(Box::new(regexp));
+        })
+    }
 
-        // 16. Perform ? Set(obj, "lastIndex", +0𝔽, true).
-        obj.set("lastIndex", 0, true, context)?;
+    /// Returns the `[[OriginalSource]]` internal slot.
+    pub(crate) fn original_source(&self) -> &JsString {
+        &self.original_source
+    }
 
-        // 16. Return obj.
-        Ok(obj.into())
+    /// Returns the `[[OriginalFlags]]` internal slot.
+    pub(crate) fn original_flags(&self) -> &JsString {
+        &self.original_flags
     }
 
     /// `22.2.3.2.4 RegExpCreate ( P, F )`
@@ -385,9 +406,11 @@ impl RegExp {
             _ => unreachable!(),
         };
 
-        context.throw_type_error(format!(
-            "RegExp.prototype.{name} getter called on non-RegExp object",
-        ))
+        Err(JsNativeError::typ()
+            .with_message(format!(
+                "RegExp.prototype.{name} getter called on non-RegExp object",
+            ))
+            .into())
     }
 
     /// `get RegExp.prototype.hasIndices`
@@ -582,7 +605,9 @@ impl RegExp {
             return Ok(result.into());
         }
 
-        context.throw_type_error("RegExp.prototype.flags getter called on non-object")
+        Err(JsNativeError::typ()
+            .with_message("RegExp.prototype.flags getter called on non-object")
+            .into())
     }
 
     /// `get RegExp.prototype.source`
@@ -617,9 +642,11 @@ impl RegExp {
                     ) {
                         Ok(JsValue::new("(?:)"))
                     } else {
-                        context.throw_type_error(
-                            "RegExp.prototype.source method called on incompatible value",
-                        )
+                        Err(JsNativeError::typ()
+                            .with_message(
+                                "RegExp.prototype.source method called on incompatible value",
+                            )
+                            .into())
                     }
                 }
                 // 4. Assert: R has an [[OriginalFlags]] internal slot.
@@ -634,7 +661,9 @@ impl RegExp {
                 }
             }
         } else {
-            context.throw_type_error("RegExp.prototype.source method called on incompatible value")
+            Err(JsNativeError::typ()
+                .with_message("RegExp.prototype.source method called on incompatible value")
+                .into())
         }
     }
 
@@ -644,22 +673,22 @@ impl RegExp {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-escaperegexppattern
-    fn escape_pattern(src: &str, _flags: &str) -> JsValue {
+    fn escape_pattern(src: &[u16], _flags: &[u16]) -> JsValue {
         if src.is_empty() {
             JsValue::new("(?:)")
         } else {
-            let mut s = String::from("");
-
-            for c in src.chars() {
-                match c {
-                    '/' => s.push_str("\\/"),
-                    '\n' => s.push_str("\\\\n"),
-                    '\r' => s.push_str("\\\\r"),
-                    _ => s.push(c),
+            let mut s = Vec::with_capacity(src.len());
+
+            for &unit in src {
+                match unit {
+                    0x2F => s.extend_from_slice(utf16!("\\/")),
+                    0x0A => s.extend_from_slice(utf16!("\\\\n")),
+                    0x0D => s.extend_from_slice(utf16!("\\\\r")),
+                    _ => s.push(unit),
                 }
             }
 
-            JsValue::new(s)
+            JsValue::new(JsString::from(s))
         }
     }
 
@@ -683,8 +712,8 @@ impl RegExp {
         // 1. Let R be the this value.
         // 2. If Type(R) is not Object, throw a TypeError exception.
         let this = this.as_object().ok_or_else(|| {
-            context
-                .construct_type_error("RegExp.prototype.test method called on incompatible value")
+            JsNativeError::typ()
+                .with_message("RegExp.prototype.test method called on incompatible value")
         })?;
 
         // 3. Let string be ? ToString(S).
@@ -728,7 +757,7 @@ impl RegExp {
             .as_object()
             .filter(|obj| obj.is_regexp())
             .ok_or_else(|| {
-                context.construct_type_error("RegExp.prototype.exec called with invalid value")
+                JsNativeError::typ().with_message("RegExp.prototype.exec called with invalid value")
             })?;
 
         // 3. Let S be ? ToString(string).
@@ -766,7 +795,9 @@ impl RegExp {
 
             // b. If Type(result) is neither Object nor Null, throw a TypeError exception.
             if !result.is_object() && !result.is_null() {
-                return context.throw_type_error("regexp exec returned neither object nor null");
+                return Err(JsNativeError::typ()
+                    .with_message("regexp exec returned neither object nor null")
+                    .into());
             }
 
             // c. Return result.
@@ -775,7 +806,9 @@ impl RegExp {
 
         // 5. Perform ? RequireInternalSlot(R, [[RegExpMatcher]]).
         if !this.is_regexp() {
-            return context.throw_type_error("RegExpExec called with invalid value");
+            return Err(JsNativeError::typ()
+                .with_message("RegExpExec called with invalid value")
+                .into());
         }
 
         // 6. Return ? RegExpBuiltinExec(R, S).
@@ -799,14 +832,16 @@ impl RegExp {
             if let Some(rx) = obj.as_regexp() {
                 rx.clone()
             } else {
-                return context.throw_type_error("RegExpBuiltinExec called with invalid value");
+                return Err(JsNativeError::typ()
+                    .with_message("RegExpBuiltinExec called with invalid value")
+                    .into());
             }
         };
 
         // 2. Assert: Type(S) is String.
 
         // 3. Let length be the number of code units in S.
-        let length = input.encode_utf16().count() as u64;
+        let length = input.len() as u64;
 
         // 4. Let lastIndex be ℝ(? ToLength(? Get(R, "lastIndex"))).
         let mut last_index = this.get("lastIndex", context)?.to_length(context)?;
@@ -815,10 +850,10 @@ impl RegExp {
         let flags = &rx.original_flags;
 
         // 6. If flags contains "g", let global be true; else let global be false.
-        let global = flags.contains('g');
+        let global = flags.contains(&u16::from(b'g'));
 
         // 7. If flags contains "y", let sticky be true; else let sticky be false.
-        let sticky = flags.contains('y');
+        let sticky = flags.contains(&u16::from(b'y'));
 
         // 8. If global is false and sticky is false, set lastIndex to 0.
         if !global && !sticky {
@@ -829,7 +864,7 @@ impl RegExp {
         let matcher = &rx.matcher;
 
         // 10. If flags contains "u", let fullUnicode be true; else let fullUnicode be false.
-        let unicode = flags.contains('u');
+        let unicode = flags.contains(&u16::from(b'u'));
 
         // 11. Let matchSucceeded be false.
         // 12. Repeat, while matchSucceeded is false,
@@ -847,20 +882,12 @@ impl RegExp {
             }
 
             // b. Let r be matcher(S, lastIndex).
-            // Check if last_index is a valid utf8 index into input.
-            let last_byte_index = match String::from_utf16(
-                &input
-                    .encode_utf16()
-                    .take(last_index as usize)
-                    .collect::<Vec<u16>>(),
-            ) {
-                Ok(s) => s.len(),
-                Err(_) => {
-                    return context
-                        .throw_type_error("Failed to get byte index from utf16 encoded string")
-                }
+            // With fullUnicode the input is matched by code points, otherwise by code units.
+            let r = if unicode {
+                matcher.find_from_utf16(input, last_index as usize).next()
+            } else {
+                matcher.find_from_ucs2(input, last_index as usize).next()
             };
-            let r = matcher.find_from(input, last_byte_index).next();
 
             match r {
                 // c. If r is failure, then
@@ -904,16 +931,11 @@ impl RegExp {
         };
 
         // 13. Let e be r's endIndex value.
-        let mut e = match_value.end();
-
         // 14. If fullUnicode is true, then
-        if unicode {
-            // e is an index into the Input character list, derived from S, matched by matcher.
-            // Let eUTF be the smallest index into S that corresponds to the character at element e of Input.
-            // If e is greater than or equal to the number of elements in Input, then eUTF is the number of code units in S.
-            // b. Set e to eUTF.
-            e = input.split_at(e).0.encode_utf16().count();
-        }
+        //     a. Let eUTF be the smallest index into S that corresponds to the character at element e of Input.
+        //     b. Set e to eUTF.
+        // The matcher already returns indices into the code units of S.
+        let e = match_value.end();
 
         // 15. If global is true or sticky is true, then
         if global || sticky {
@@ -939,11 +961,10 @@ impl RegExp {
             .expect("this CreateDataPropertyOrThrow call must not fail");
 
         // 22. Let matchedSubstr be the substring of S from lastIndex to e.
-        let matched_substr = if let Some(s) = input.get(match_value.range()) {
-            s
-        } else {
-            ""
-        };
+        let matched_substr = input
+            .get(match_value.range())
+            .map(JsString::from)
+            .unwrap_or_default();
 
         // 23. Perform ! CreateDataPropertyOrThrow(A, "0", matchedSubstr).
         a.create_data_property_or_throw(0, matched_substr, context)
@@ -962,11 +983,10 @@ impl RegExp {
             // ii. Perform ! CreateDataPropertyOrThrow(groups, s, capturedValue).
             for (name, range) in named_groups {
                 if let Some(range) = range {
-                    let value = if let Some(s) = input.get(range.clone()) {
-                        s
-                    } else {
-                        ""
-                    };
+                    let value = input
+                        .get(range.clone())
+                        .map(JsString::from)
+                        .unwrap_or_default();
 
                     groups
                         .to_object(context)?
@@ -994,13 +1014,11 @@ impl RegExp {
                 None => JsValue::undefined(),
                 // c. Else if fullUnicode is true, then
                 // d. Else,
-                Some(range) => {
-                    if let Some(s) = input.get(range) {
-                        s.into()
-                    } else {
-                        "".into()
-                    }
-                }
+                Some(range) => input
+                    .get(range)
+                    .map(JsString::from)
+                    .unwrap_or_default()
+                    .into(),
             };
 
             // e. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(i)), capturedValue).
@@ -1032,8 +1050,9 @@ impl RegExp {
         let rx = if let Some(rx) = this.as_object() {
             rx
         } else {
-            return context
-                .throw_type_error("RegExp.prototype.match method called on incompatible value");
+            return Err(JsNativeError::typ()
+                .with_message("RegExp.prototype.match method called on incompatible value")
+                .into());
         };
 
         // 3. Let S be ? ToString(string).
@@ -1124,27 +1143,25 @@ impl RegExp {
     /// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype.tostring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/toString
     #[allow(clippy::wrong_self_convention)]
-    pub(crate) fn to_string(
-        this: &JsValue,
-        _: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
+    pub(crate) fn to_string(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         let (body, flags) = if let Some(object) = this.as_object() {
             let object = object.borrow();
             let regex = object.as_regexp().ok_or_else(|| {
-                context.construct_type_error(format!(
+                JsNativeError::typ().with_message(format!(
                     "Method RegExp.prototype.toString called on incompatible receiver {}",
                     this.display()
                 ))
             })?;
             (regex.original_source.clone(), regex.original_flags.clone())
         } else {
-            return context.throw_type_error(format!(
-                "Method RegExp.prototype.toString called on incompatible receiver {}",
-                this.display()
-            ));
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "Method RegExp.prototype.toString called on incompatible receiver {}",
+                    this.display()
+                ))
+                .into());
         };
-        Ok(format!("/{body}/{flags}").into())
+        Ok(JsString::concat_array(&[utf16!("/"), &body, utf16!("/"), &flags]).into())
     }
 
     /// `RegExp.prototype[ @@matchAll ]( string )`
@@ -1165,9 +1182,8 @@ impl RegExp {
         // 1. Let R be the this value.
         // 2. If Type(R) is not Object, throw a TypeError exception.
         let regexp = this.as_object().ok_or_else(|| {
-            context.construct_type_error(
-                "RegExp.prototype.match_all method called on incompatible value",
-            )
+            JsNativeError::typ()
+                .with_message("RegExp.prototype.match_all method called on incompatible value")
         })?;
 
         // 3. Let S be ? ToString(string).
@@ -1190,11 +1206,11 @@ impl RegExp {
 
         // 9. If flags contains "g", let global be true.
         // 10. Else, let global be false.
-        let global = flags.contains('g');
+        let global = flags.contains(&u16::from(b'g'));
 
         // 11. If flags contains "u", let fullUnicode be true.
         // 12. Else, let fullUnicode be false.
-        let unicode = flags.contains('u');
+        let unicode = flags.contains(&u16::from(b'u'));
 
         // 13. Return ! CreateRegExpStringIterator(matcher, S, global, fullUnicode).
         Ok(RegExpStringIterator::create_regexp_string_iterator(
@@ -1228,9 +1244,11 @@ impl RegExp {
         let rx = if let Some(rx) = this.as_object() {
             rx
         } else {
-            return context.throw_type_error(
-                "RegExp.prototype[Symbol.replace] method called on incompatible value",
-            );
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "RegExp.prototype[Symbol.replace] method called on incompatible value",
+                )
+                .into());
         };
 
         // 3. Let S be ? ToString(string).
@@ -1241,7 +1259,7 @@ impl RegExp {
             .to_string(context)?;
 
         // 4. Let lengthS be the number of code unit elements in S.
-        let length_arg_str = arg_str.encode_utf16().count();
+        let length_arg_str = arg_str.len();
 
         // 5. Let functionalReplace be IsCallable(replaceValue).
         let mut replace_value = args.get_or_undefined(1).clone();
@@ -1310,7 +1328,7 @@ impl RegExp {
         }
 
         // 12. Let accumulatedResult be the empty String.
-        let mut accumulated_result = JsString::new("");
+        let mut accumulated_result = Vec::new();
 
         // 13. Let nextSourcePosition be 0.
         let mut next_source_position = 0;
@@ -1327,7 +1345,7 @@ impl RegExp {
             let matched = result.get("0", context)?.to_string(context)?;
 
             // d. Let matchLength be the number of code units in matched.
-            let match_length = matched.encode_utf16().count();
+            let match_length = matched.len();
 
             // e. Let position be ? ToIntegerOrInfinity(? Get(result, "index")).
             let position = result
@@ -1409,8 +1427,8 @@ impl RegExp {
 
                 // ii. Let replacement be ? GetSubstitution(matched, S, position, captures, namedCaptures, replaceValue).
                 replacement = string::get_substitution(
-                    matched.as_str(),
-                    arg_str.as_str(),
+                    &matched,
+                    &arg_str,
                     position,
                     &captures,
                     &named_captures,
@@ -1427,13 +1445,12 @@ impl RegExp {
                 //    In such cases, the corresponding substitution is ignored.
                 // ii. Set accumulatedResult to the string-concatenation of accumulatedResult,
                 //     the substring of S from nextSourcePosition to position, and replacement.
-                accumulated_result = format!(
-                    "{accumulated_result}{}{replacement}",
+                accumulated_result.extend_from_slice(
                     arg_str
                         .get(next_source_position..position)
                         .expect("index of a regexp match cannot be greater than the input string"),
-                )
-                .into();
+                );
+                accumulated_result.extend_from_slice(&replacement);
 
                 // iii. Set nextSourcePosition to position + matchLength.
                 next_source_position = position + match_length;
@@ -1442,18 +1459,16 @@ impl RegExp {
 
         // 15. If nextSourcePosition ≥ lengthS, return accumulatedResult.
         if next_source_position >= length_arg_str {
-            return Ok(accumulated_result.into());
+            return Ok(JsString::from(accumulated_result).into());
         }
 
         // 16. Return the string-concatenation of accumulatedResult and the substring of S from nextSourcePosition.
-        Ok(format!(
-            "{}{}",
-            accumulated_result,
+        accumulated_result.extend_from_slice(
             arg_str
                 .get(next_source_position..)
-                .expect("next_source_position cannot be greater than the input string")
-        )
-        .into())
+                .expect("next_source_position cannot be greater than the input string"),
+        );
+        Ok(JsString::from(accumulated_result).into())
     }
 
     /// `RegExp.prototype[ @@search ]( string )`
@@ -1476,9 +1491,9 @@ impl RegExp {
         let rx = if let Some(rx) = this.as_object() {
             rx
         } else {
-            return context.throw_type_error(
-                "RegExp.prototype[Symbol.search] method called on incompatible value",
-            );
+            return Err(JsNativeError::typ()
+                .with_message("RegExp.prototype[Symbol.search] method called on incompatible value")
+                .into());
         };
 
         // 3. Let S be ? ToString(string).
@@ -1538,8 +1553,9 @@ impl RegExp {
         let rx = if let Some(rx) = this.as_object() {
             rx
         } else {
-            return context
-                .throw_type_error("RegExp.prototype.split method called on incompatible value");
+            return Err(JsNativeError::typ()
+                .with_message("RegExp.prototype.split method called on incompatible value")
+                .into());
         };
 
         // 3. Let S be ? ToString(string).
@@ -1557,14 +1573,14 @@ impl RegExp {
 
         // 6. If flags contains "u", let unicodeMatching be true.
         // 7. Else, let unicodeMatching be false.
-        let unicode = flags.contains('u');
+        let unicode = flags.contains(&u16::from(b'u'));
 
         // 8. If flags contains "y", let newFlags be flags.
         // 9. Else, let newFlags be the string-concatenation of flags and "y".
-        let new_flags = if flags.contains('y') {
-            flags.to_string()
+        let new_flags = if flags.contains(&u16::from(b'y')) {
+            flags
         } else {
-            format!("{flags}y")
+            JsString::concat(&flags, utf16!("y"))
         };
 
         // 10. Let splitter be ? Construct(C, « rx, newFlags »).
@@ -1594,7 +1610,7 @@ impl RegExp {
         }
 
         // 15. Let size be the length of S.
-        let size = arg_str.encode_utf16().count() as u64;
+        let size = arg_str.len() as u64;
 
         // 16. If size is 0, then
         if size == 0 {
@@ -1642,13 +1658,7 @@ impl RegExp {
                     q = advance_string_index(&arg_str, q, unicode);
                 } else {
                     // 1. Let T be the substring of S from p to q.
-                    let arg_str_substring = String::from_utf16_lossy(
-                        &arg_str
-                            .encode_utf16()
-                            .skip(p as usize)
-                            .take((q - p) as usize)
-                            .collect::<Vec<u16>>(),
-                    );
+                    let arg_str_substring = JsString::from(&arg_str[p as usize..q as usize]);
 
                     // 2. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
                     a.create_data_property_or_throw(length_a, arg_str_substring, context)
@@ -1703,13 +1713,7 @@ impl RegExp {
         }
 
         // 20. Let T be the substring of S from p to size.
-        let arg_str_substring = String::from_utf16_lossy(
-            &arg_str
-                .encode_utf16()
-                .skip(p as usize)
-                .take((size - p) as usize)
-                .collect::<Vec<u16>>(),
-        );
+        let arg_str_substring = JsString::from(&arg_str[p as usize..]);
 
         // 21. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
         a.create_data_property_or_throw(length_a, arg_str_substring, context)
@@ -1727,8 +1731,6 @@ impl RegExp {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-advancestringindex
 fn advance_string_index(s: &JsString, index: u64, unicode: bool) -> u64 {
-    // Regress only works with utf8, so this function differs from the spec.
-
     // 1. Assert: index ≤ 2^53 - 1.
 
     // 2. If unicode is false, return index + 1.
@@ -1737,7 +1739,7 @@ fn advance_string_index(s: &JsString, index: u64, unicode: bool) -> u64 {
     }
 
     // 3. Let length be the number of code units in S.
-    let length = s.encode_utf16().count() as u64;
+    let length = s.len() as u64;
 
     // 4. If index + 1 ≥ length, return index + 1.
     if index + 1 > length {
@@ -15,7 +15,7 @@ use crate::{
     object::{JsObject, ObjectData},
     property::PropertyDescriptor,
     symbol::WellKnownSymbols,
-    Context, JsResult, JsString, JsValue,
+    Context, JsNativeError, JsResult, JsString, JsValue,
 };
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
@@ -85,7 +85,9 @@ impl RegExpStringIterator {
         let iterator = iterator
             .as_mut()
             .and_then(|obj| obj.as_regexp_string_iterator_mut())
-            .ok_or_else(|| context.construct_type_error("`this` is not a RegExpStringIterator"))?;
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("`this` is not a RegExpStringIterator")
+            })?;
         if iterator.completed {
             return Ok(create_iter_result_object(
                 JsValue::undefined(),
@@ -237,3 +237,28 @@ fn search() {
     assert_eq!(forward(&mut context, "/u/[Symbol.search](null)"), "1");
     assert_eq!(forward(&mut context, "/d/[Symbol.search](undefined)"), "2");
 }
+
+#[test]
+fn code_unit_matching() {
+    let mut context = Context::default();
+
+    // Without the unicode flag the input is matched by code units.
+    assert_eq!(
+        forward(&mut context, "/\\udf06/.exec('\\ud834\\udf06').index"),
+        "1"
+    );
+    assert_eq!(forward(&mut context, "'😀'.match(/./)[0].length"), "1");
+    assert_eq!(forward(&mut context, "'😀'.match(/./u)[0].length"), "2");
+    assert_eq!(
+        forward(&mut context, "'a\\uD800b'.replace(/\\uD800/, '-')"),
+        "\"a-b\""
+    );
+    assert_eq!(
+        forward(&mut context, "'x😀y'.replace(/y/, '!')"),
+        "\"x😀!\""
+    );
+    assert_eq!(
+        forward(&mut context, "var re = /b/g; re.exec('😀b'); re.lastIndex"),
+        "3"
+    );
+}
@@ -0,0 +1,495 @@
+//! This module implements the global `ShadowRealm` object.
+//!
+//! A `ShadowRealm` evaluates code in a new realm with its own intrinsics and global object.
+//! Only primitives and callable objects can cross the boundary between the realms, and
+//! callable objects are wrapped in a function of the receiving realm.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-shadowrealm/
+
+#[cfg(test)]
+mod tests;
+
+use super::function::set_function_name;
+use crate::{
+    builtins::{BuiltIn, JsArgs},
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, JsPromise,
+        ObjectData,
+    },
+    property::{Attribute, PropertyDescriptor},
+    realm::Realm,
+    symbol::WellKnownSymbols,
+    syntax::Parser,
+    value::IntegerOrInfinity,
+    Context, JsNativeError, JsResult, JsString, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use tap::{Conv, Pipe};
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ShadowRealm;
+
+impl BuiltIn for ShadowRealm {
+    const NAME: &'static str = "ShadowRealm";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().shadow_realm().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(Self::evaluate, "evaluate", 1)
+        .method(Self::import_value, "importValue", 2)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+impl ShadowRealm {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `ShadowRealm ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("calling a builtin ShadowRealm constructor without new is forbidden")
+                .into());
+        }
+
+        // 2. Let O be ? OrdinaryCreateFromConstructor(NewTarget, "%ShadowRealm.prototype%", « [[ShadowRealm]], [[ExecutionContext]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::shadow_realm,
+            context,
+        )?;
+
+        // 3. Let realmRec be CreateRealm().
+        // 4. Set O.[[ShadowRealm]] to realmRec.
+        // 5. Let context be a new execution context.
+        // 6. Set the Function of context to null.
+        // 7. Set the Realm of context to realmRec.
+        // 8. Set the ScriptOrModule of context to null.
+        // 9. Set O.[[ExecutionContext]] to context.
+        // 10. Perform ? SetRealmGlobalObject(realmRec, undefined, undefined).
+        // 11. Perform ? SetDefaultGlobalBindings(O.[[ShadowRealm]]).
+        // 12. Perform ? HostInitializeShadowRealm(O.[[ShadowRealm]]).
+        let realm = context.create_realm();
+
+        // 13. Return O.
+        Ok(JsObject::from_proto_and_data(prototype, ObjectData::shadow_realm(realm)).into())
+    }
+
+    /// `ShadowRealm.prototype.evaluate ( sourceText )`
+    ///
+    /// Evaluates `sourceText` as a script in the realm of the `ShadowRealm`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm.prototype.evaluate
+    pub(crate) fn evaluate(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? ValidateShadowRealmObject(O).
+        let eval_realm = validate_shadow_realm_object(this, "evaluate")?;
+
+        // 3. If Type(sourceText) is not String, throw a TypeError exception.
+        let source_text = args.get_or_undefined(0).as_string().ok_or_else(|| {
+            JsNativeError::typ().with_message("ShadowRealm.evaluate: source text must be a string")
+        })?;
+
+        // 4. Let callerRealm be the current Realm Record.
+        let caller_realm = context.realm().clone();
+
+        // 5. Let evalRealm be O.[[ShadowRealm]].
+        // 6. Return ? PerformShadowRealmEval(sourceText, callerRealm, evalRealm).
+        perform_shadow_realm_eval(source_text, &caller_realm, eval_realm, context)
+    }
+
+    /// `ShadowRealm.prototype.importValue ( specifier, exportName )`
+    ///
+    /// Imports the module `specifier` in the realm of the `ShadowRealm`, returning a promise
+    /// for the value of its export `exportName`.
+    ///
+    /// The module is loaded with [`ModuleLoader::load_realm_module`].
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm.prototype.importvalue
+    /// [`ModuleLoader::load_realm_module`]: crate::module::ModuleLoader::load_realm_module
+    pub(crate) fn import_value(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? ValidateShadowRealmObject(O).
+        let eval_realm = validate_shadow_realm_object(this, "importValue")?;
+
+        // 3. Let specifierString be ? ToString(specifier).
+        let specifier = args.get_or_undefined(0).to_string(context)?;
+
+        // 4. If Type(exportName) is not String, throw a TypeError exception.
+        let export_name = args.get_or_undefined(1).as_string().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("ShadowRealm.importValue: export name must be a string")
+        })?;
+
+        // 5. Let callerRealm be the current Realm Record.
+        let caller_realm = context.realm().clone();
+
+        // 6. Let evalRealm be O.[[ShadowRealm]].
+        // 7. Let evalContext be O.[[ExecutionContext]].
+        // 8. Return ? ShadowRealmImportValue(specifierString, exportName, callerRealm, evalRealm, evalContext).
+        Ok(
+            shadow_realm_import_value(specifier, export_name, &caller_realm, &eval_realm, context)?
+                .into(),
+        )
+    }
+}
+
+/// Abstract operation `ValidateShadowRealmObject ( O )`
+///
+/// Returns the realm of the `ShadowRealm` object `O`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-validateshadowrealmobject
+fn validate_shadow_realm_object(this: &JsValue, method: &str) -> JsResult<Realm> {
+    // 1. Perform ? RequireInternalSlot(O, [[ShadowRealm]]).
+    // 2. Perform ? RequireInternalSlot(O, [[ExecutionContext]]).
+    this.as_object()
+        .and_then(|object| object.borrow().as_shadow_realm().cloned())
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message(format!(
+                    "ShadowRealm.{method}: 'this' is not a ShadowRealm object"
+                ))
+                .into()
+        })
+}
+
+/// Abstract operation `PerformShadowRealmEval ( sourceText, callerRealm, evalRealm )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-performshadowrealmeval
+fn perform_shadow_realm_eval(
+    source_text: &JsString,
+    caller_realm: &Realm,
+    eval_realm: Realm,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // The script is parsed with the eval realm active, since parsing declares the global
+    // bindings of the script.
+    let old_realm = context.enter_realm(eval_realm);
+
+    // 1. Perform ? HostEnsureCanCompileStrings(callerRealm, evalRealm).
+    // 2. Perform the following substeps in an implementation-defined order, possibly interleaving parsing and error detection:
+    //     a. Let script be ParseText(StringToCodePoints(sourceText), Script).
+    //     b. If script is a List of errors, throw a SyntaxError exception.
+    //     c. If script Contains ScriptBody is false, return undefined.
+    //     d. Let body be the ScriptBody of script.
+    //     e. If body Contains NewTarget is true, throw a SyntaxError exception.
+    //     f. If body Contains SuperProperty is true, throw a SyntaxError exception.
+    //     g. If body Contains SuperCall is true, throw a SyntaxError exception.
+    let statement_list =
+        match Parser::new(source_text.to_std_string_escaped().as_bytes()).parse_all(context) {
+            Ok(statement_list) => statement_list,
+            Err(e) => {
+                context.enter_realm(old_realm);
+                return Err(JsNativeError::syntax().with_message(e.to_string()).into());
+            }
+        };
+
+    // 3. Let strictEval be IsStrict of script.
+    // 4. Let runningContext be the running execution context.
+    // 5. If runningContext is not already suspended, suspend runningContext.
+    // 6. Let evalContext be a new ECMAScript code execution context.
+    // ...
+    // 16. Push evalContext onto the execution context stack; evalContext is now the running execution context.
+    // 17. Let result be Completion(EvalDeclarationInstantiation(body, varEnv, lexEnv, null, strictEval)).
+    // 18. If result.[[Type]] is normal, then
+    //     a. Set result to the result of evaluating body.
+    // 19. If result.[[Type]] is normal and result.[[Value]] is empty, then
+    //     a. Set result to NormalCompletion(undefined).
+    let result = context.execute_script(&statement_list, None);
+
+    // 20. Suspend evalContext and remove it from the execution context stack.
+    // 21. Resume the context that is now on the top of the execution context stack as the running execution context.
+    context.enter_realm(old_realm);
+
+    match result {
+        // 23. Return ? GetWrappedValue(callerRealm, result.[[Value]]).
+        Ok(result) => get_wrapped_value(caller_realm, &result, context),
+        // 22. If result.[[Type]] is not normal, throw a TypeError exception.
+        Err(_) => Err(JsNativeError::typ()
+            .with_message("the code evaluated in a ShadowRealm threw an error")
+            .into()),
+    }
+}
+
+/// Abstract operation `ShadowRealmImportValue ( specifierString, exportNameString, callerRealm, evalRealm, evalContext )`
+///
+/// Modules are loaded, linked and evaluated synchronously, so the returned promise is already
+/// settled.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealmimportvalue
+fn shadow_realm_import_value(
+    specifier: JsString,
+    export_name: &JsString,
+    caller_realm: &Realm,
+    eval_realm: &Realm,
+    context: &mut Context,
+) -> JsResult<JsPromise> {
+    // 1. Assert: evalContext is an execution context associated to a ShadowRealm instance's [[ExecutionContext]].
+    // 2. Let innerCapability be ! NewPromiseCapability(%Promise%).
+    // 3. Let runningContext be the running execution context.
+    // 4. If runningContext is not already suspended, suspend runningContext.
+    // 5. Push evalContext onto the execution context stack; evalContext is now the running execution context.
+    let old_realm = context.enter_realm(eval_realm.clone());
+
+    // 6. Perform HostLoadImportedModule(evalRealm, specifierString, empty, innerCapability).
+    let value = load_export(eval_realm, specifier, export_name, context);
+
+    // 7. Suspend evalContext and remove it from the execution context stack.
+    // 8. Resume the context that is now on the top of the execution context stack as the running execution context.
+    context.enter_realm(old_realm);
+
+    // 9. Let steps be the steps of an ExportGetter function as described below.
+    // 10. Let onFulfilled be CreateBuiltinFunction(steps, 1, "", « [[ExportNameString]] », callerRealm).
+    // 11. Set onFulfilled.[[ExportNameString]] to exportNameString.
+    // 12. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+    // 13. Return PerformPromiseThen(innerCapability.[[Promise]], onFulfilled, callerRealm.[[Intrinsics]].[[%ThrowTypeError%]], promiseCapability).
+    match value.and_then(|value| get_wrapped_value(caller_realm, &value, context)) {
+        Ok(value) => JsPromise::resolve(value, context),
+        Err(_) => Ok(JsPromise::reject(
+            JsNativeError::typ().with_message(format!(
+                "ShadowRealm.importValue: could not import `{export_name}`"
+            )),
+            context,
+        )),
+    }
+}
+
+/// Loads the module `specifier` in the active realm and gets the value of its export
+/// `export_name`.
+fn load_export(
+    realm: &Realm,
+    specifier: JsString,
+    export_name: &JsString,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    let module = context
+        .module_loader()
+        .load_realm_module(realm, specifier, context)?;
+    module.load_link_evaluate(context)?;
+
+    // ExportGetter functions
+    // 1. Let f be the active function object.
+    // 2. Let string be f.[[ExportNameString]].
+    // 3. Assert: Type(string) is String.
+    // 4. Let exports be moduleNamespace.[[Exports]].
+    // 5. If exports does not contain string, throw a TypeError exception.
+    // 6. Let value be ? Get(moduleNamespace, string).
+    let namespace = module.namespace(context);
+    if !namespace.has_own_property(export_name.clone(), context)? {
+        return Err(JsNativeError::typ()
+            .with_message(format!("the module does not export `{export_name}`"))
+            .into());
+    }
+    namespace.get(export_name.clone(), context)
+}
+
+/// Abstract operation `GetWrappedValue ( callerRealm, value )`
+///
+/// Wraps `value` to be passed into `caller_realm`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-getwrappedvalue
+pub(crate) fn get_wrapped_value(
+    caller_realm: &Realm,
+    value: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. If Type(value) is Object, then
+    if let Some(object) = value.as_object() {
+        // a. If IsCallable(value) is false, throw a TypeError exception.
+        if !object.is_callable() {
+            return Err(JsNativeError::typ()
+                .with_message("only primitives and callable objects can cross a ShadowRealm")
+                .into());
+        }
+
+        // b. Return ? WrappedFunctionCreate(callerRealm, value).
+        return WrappedFunction::create(caller_realm, object, context).map(Into::into);
+    }
+
+    // 2. Return value.
+    Ok(value.clone())
+}
+
+/// A callable object of one realm, wrapped to be called from another realm.
+#[derive(Debug, Trace, Finalize)]
+pub struct WrappedFunction {
+    target_function: JsObject,
+    realm: Realm,
+}
+
+impl WrappedFunction {
+    /// Abstract operation `WrappedFunctionCreate ( callerRealm, Target )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrappedfunctioncreate
+    pub(crate) fn create(
+        caller_realm: &Realm,
+        target_function: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Let internalSlotsList be the internal slots listed in Table 2, plus [[Prototype]] and [[Extensible]].
+        // 2. Let wrapped be ! MakeBasicObject(internalSlotsList).
+        // 3. Set wrapped.[[Prototype]] to callerRealm.[[Intrinsics]].[[%Function.prototype%]].
+        // 4. Set wrapped.[[Call]] as described in 2.1.
+        // 5. Set wrapped.[[WrappedTargetFunction]] to Target.
+        // 6. Set wrapped.[[Realm]] to callerRealm.
+        let wrapped = JsObject::from_proto_and_data(
+            caller_realm
+                .intrinsics()
+                .constructors()
+                .function()
+                .prototype(),
+            ObjectData::wrapped_function(Self {
+                target_function: target_function.clone(),
+                realm: caller_realm.clone(),
+            }),
+        );
+
+        // 7. Let result be CopyNameAndLength(wrapped, Target).
+        // 8. If result is an Abrupt Completion, throw a TypeError exception.
+        if copy_name_and_length(&wrapped, target_function, context).is_err() {
+            return Err(JsNativeError::typ()
+                .with_message("could not copy the name and length of a wrapped function")
+                .into());
+        }
+
+        // 9. Return wrapped.
+        Ok(wrapped)
+    }
+
+    /// Gets the function wrapped by this object.
+    #[inline]
+    pub fn target_function(&self) -> &JsObject {
+        &self.target_function
+    }
+
+    /// Gets the realm this function was wrapped for.
+    #[inline]
+    pub fn realm(&self) -> &Realm {
+        &self.realm
+    }
+}
+
+/// Abstract operation `CopyNameAndLength ( F, Target [ , prefix [ , argCount ] ] )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-copynameandlength
+fn copy_name_and_length(
+    function: &JsObject,
+    target: &JsObject,
+    context: &mut Context,
+) -> JsResult<()> {
+    // 1. If argCount is undefined, then set argCount to 0.
+    // 2. Let L be 0.
+    let mut l = JsValue::new(0);
+
+    // 3. Let targetHasLength be ? HasOwnProperty(Target, "length").
+    // 4. If targetHasLength is true, then
+    if target.has_own_property("length", context)? {
+        // a. Let targetLen be ? Get(Target, "length").
+        let target_len = target.get("length", context)?;
+
+        // b. If Type(targetLen) is Number, then
+        if target_len.is_number() {
+            match target_len
+                .to_integer_or_infinity(context)
+                .expect("to_integer_or_infinity cannot fail for a number")
+            {
+                // i. If targetLen is +∞𝔽, set L to +∞.
+                IntegerOrInfinity::PositiveInfinity => l = f64::INFINITY.into(),
+                // ii. Else if targetLen is -∞𝔽, set L to 0.
+                IntegerOrInfinity::NegativeInfinity => {}
+                // iii. Else,
+                IntegerOrInfinity::Integer(target_len) => {
+                    // 1. Let targetLenAsInt be ! ToIntegerOrInfinity(targetLen).
+                    // 2. Assert: targetLenAsInt is finite.
+                    // 3. Set L to max(targetLenAsInt - argCount, 0).
+                    l = target_len.max(0).into();
+                }
+            }
+        }
+    }
+
+    // 5. Perform SetFunctionLength(F, L).
+    function.define_property_or_throw(
+        "length",
+        PropertyDescriptor::builder()
+            .value(l)
+            .writable(false)
+            .enumerable(false)
+            .configurable(true),
+        context,
+    )?;
+
+    // 6. Let targetName be ? Get(Target, "name").
+    let target_name = target.get("name", context)?;
+
+    // 7. If Type(targetName) is not String, set targetName to the empty String.
+    let target_name = target_name
+        .as_string()
+        .map_or(JsString::new(""), Clone::clone);
+
+    // 8. Perform SetFunctionName(F, targetName, prefix).
+    set_function_name(function, &target_name.into(), None, context);
+
+    Ok(())
+}
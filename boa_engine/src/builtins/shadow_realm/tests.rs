@@ -0,0 +1,140 @@
+use crate::{
+    builtins::promise::PromiseState, forward, module::MapModuleLoader, object::JsPromise, Context,
+};
+use std::rc::Rc;
+
+#[test]
+fn evaluate_in_separate_realm() {
+    let mut context = Context::default();
+    let init = r#"
+        var x = 1;
+        var realm = new ShadowRealm();
+        realm.evaluate("var x = 2;");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "x"), "1");
+    assert_eq!(forward(&mut context, "realm.evaluate('x')"), "2");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { realm.evaluate('globalThis') } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "realm.evaluate('Array') === Array"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(realm)"),
+        "\"[object ShadowRealm]\""
+    );
+}
+
+#[test]
+fn evaluate_errors() {
+    let mut context = Context::default();
+    forward(&mut context, "var realm = new ShadowRealm();");
+    assert_eq!(
+        forward(&mut context, "try { ShadowRealm() } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { realm.evaluate(1) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { realm.evaluate('(') } catch (e) { e.name }"
+        ),
+        "\"SyntaxError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { realm.evaluate('throw 1') } catch (e) { e.constructor === TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn wrapped_functions() {
+    let mut context = Context::default();
+    let init = r#"
+        var realm = new ShadowRealm();
+        var add = realm.evaluate("(function add(a, b) { return a + b; })");
+        var apply = realm.evaluate("(f, x) => f(x)");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "add(1, 2)"), "3");
+    assert_eq!(forward(&mut context, "add.name"), "\"add\"");
+    assert_eq!(forward(&mut context, "add.length"), "2");
+    assert_eq!(forward(&mut context, "typeof add"), "\"function\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(add) === Function.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "apply(x => x * 2, 21)"), "42");
+    assert_eq!(
+        forward(&mut context, "try { new add() } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { apply(() => { throw new RangeError() }, 1) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { apply(x => x, {}) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn import_value() {
+    let loader = MapModuleLoader::new().with_module(
+        "counter",
+        "export let count = 0; export function inc() { return ++count; }",
+    );
+    let mut context = Context::builder().module_loader(Rc::new(loader)).build();
+
+    let init = r#"
+        var realm = new ShadowRealm();
+        var inc = realm.importValue("counter", "inc");
+        var missing = realm.importValue("counter", "missing");
+        "#;
+    forward(&mut context, init);
+
+    let inc = JsPromise::from_object(
+        context.eval("inc").unwrap().as_object().unwrap().clone(),
+        &mut context,
+    )
+    .unwrap();
+    assert!(matches!(inc.state(), PromiseState::Fulfilled(_)));
+    forward(
+        &mut context,
+        "inc.then(f => { f(); globalThis.count = f(); })",
+    );
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "count"), "2");
+
+    forward(
+        &mut context,
+        "missing.catch(e => { globalThis.error = e.name; })",
+    );
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "error"), "\"TypeError\"");
+}
@@ -0,0 +1,558 @@
+//! This module implements the global `SharedArrayBuffer` object.
+//!
+//! A `SharedArrayBuffer` is a fixed-length raw binary data buffer, like an `ArrayBuffer`, whose
+//! data block can be shared with `SharedArrayBuffer` objects of other agents, which may run on
+//! other threads.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-sharedarraybuffer-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SharedArrayBuffer
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::{
+        array_buffer::{copy_data_block_bytes, ArrayBuffer, DataBlock, SharedMemoryOrder},
+        BuiltIn, JsArgs,
+    },
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::IntegerOrInfinity,
+    Context, JsNativeError, JsResult, JsValue,
+};
+use boa_gc::ExternalMemory;
+use boa_profiler::Profiler;
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Arc, Condvar, Mutex, MutexGuard,
+    },
+    time::{Duration, Instant},
+};
+use tap::{Conv, Pipe};
+
+/// JavaScript `SharedArrayBuffer` built-in implementation.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SharedArrayBuffer;
+
+impl BuiltIn for SharedArrayBuffer {
+    const NAME: &'static str = "SharedArrayBuffer";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let flag_attributes = Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE;
+
+        let get_species = FunctionBuilder::native(context, Self::get_species)
+            .name("get [Symbol.species]")
+            .constructor(false)
+            .build();
+
+        let get_byte_length = FunctionBuilder::native(context, Self::get_byte_length)
+            .name("get byteLength")
+            .build();
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context
+                .intrinsics()
+                .constructors()
+                .shared_array_buffer()
+                .clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .accessor("byteLength", Some(get_byte_length), None, flag_attributes)
+        .static_accessor(
+            WellKnownSymbols::species(),
+            Some(get_species),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .method(Self::slice, "slice", 2)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+impl SharedArrayBuffer {
+    const LENGTH: usize = 1;
+
+    /// `25.2.3.1 SharedArrayBuffer ( length )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sharedarraybuffer-length
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("SharedArrayBuffer.constructor called with undefined new target")
+                .into());
+        }
+
+        // 2. Let byteLength be ? ToIndex(length).
+        let byte_length = args.get_or_undefined(0).to_index(context)?;
+
+        // 3. Return ? AllocateSharedArrayBuffer(NewTarget, byteLength).
+        Ok(Self::allocate(new_target, byte_length, context)?.into())
+    }
+
+    /// `25.2.4.2 get SharedArrayBuffer [ @@species ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sharedarraybuffer-@@species
+    #[allow(clippy::unnecessary_wraps)]
+    fn get_species(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Return the this value.
+        Ok(this.clone())
+    }
+
+    /// `25.2.5.1 get SharedArrayBuffer.prototype.byteLength`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-sharedarraybuffer.prototype.bytelength
+    fn get_byte_length(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is false, throw a TypeError exception.
+        let obj = this.as_object().map(JsObject::borrow);
+        let o = obj
+            .as_ref()
+            .and_then(|obj| obj.as_array_buffer())
+            .filter(|o| o.is_shared())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("SharedArrayBuffer.byteLength called with invalid object")
+            })?;
+
+        // 4. Let length be O.[[ArrayBufferByteLength]].
+        // 5. Return 𝔽(length).
+        Ok(o.array_buffer_byte_length.into())
+    }
+
+    /// `25.2.5.3 SharedArrayBuffer.prototype.slice ( start, end )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sharedarraybuffer.prototype.slice
+    fn slice(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is false, throw a TypeError exception.
+        let obj = this
+            .as_object()
+            .filter(|obj| {
+                obj.borrow()
+                    .as_array_buffer()
+                    .map_or(false, ArrayBuffer::is_shared)
+            })
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("SharedArrayBuffer.slice called with invalid object")
+            })?;
+        let block = obj
+            .borrow()
+            .as_array_buffer()
+            .and_then(|o| o.array_buffer_data.clone())
+            .expect("a SharedArrayBuffer cannot be detached");
+
+        // 4. Let len be O.[[ArrayBufferByteLength]].
+        let len = block.len() as i64;
+
+        // 5. Let relativeStart be ? ToIntegerOrInfinity(start).
+        // 6. If relativeStart is -∞, let first be 0.
+        // 7. Else if relativeStart < 0, let first be max(len + relativeStart, 0).
+        // 8. Else, let first be min(relativeStart, len).
+        let first = match args.get_or_undefined(0).to_integer_or_infinity(context)? {
+            IntegerOrInfinity::NegativeInfinity => 0,
+            IntegerOrInfinity::Integer(i) if i < 0 => std::cmp::max(len + i, 0),
+            IntegerOrInfinity::Integer(i) => std::cmp::min(i, len),
+            IntegerOrInfinity::PositiveInfinity => len,
+        };
+
+        // 9. If end is undefined, let relativeEnd be len; else let relativeEnd be ? ToIntegerOrInfinity(end).
+        let end = args.get_or_undefined(1);
+        let relative_end = if end.is_undefined() {
+            IntegerOrInfinity::Integer(len)
+        } else {
+            end.to_integer_or_infinity(context)?
+        };
+
+        // 10. If relativeEnd is -∞, let final be 0.
+        // 11. Else if relativeEnd < 0, let final be max(len + relativeEnd, 0).
+        // 12. Else, let final be min(relativeEnd, len).
+        let r#final = match relative_end {
+            IntegerOrInfinity::NegativeInfinity => 0,
+            IntegerOrInfinity::Integer(i) if i < 0 => std::cmp::max(len + i, 0),
+            IntegerOrInfinity::Integer(i) => std::cmp::min(i, len),
+            IntegerOrInfinity::PositiveInfinity => len,
+        };
+
+        // 13. Let newLen be max(final - first, 0).
+        let new_len = std::cmp::max(r#final - first, 0) as u64;
+
+        // 14. Let ctor be ? SpeciesConstructor(O, %SharedArrayBuffer%).
+        let ctor = obj.species_constructor(StandardConstructors::shared_array_buffer, context)?;
+
+        // 15. Let new be ? Construct(ctor, « 𝔽(newLen) »).
+        let new = ctor.construct(&[new_len.into()], Some(&ctor), context)?;
+
+        let mut new_obj = new.borrow_mut();
+        // 16. Perform ? RequireInternalSlot(new, [[ArrayBufferData]]).
+        // 17. If IsSharedArrayBuffer(new) is false, throw a TypeError exception.
+        let new_buffer = new_obj
+            .as_array_buffer_mut()
+            .filter(|new_buffer| new_buffer.is_shared())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("SharedArrayBuffer constructor returned invalid object")
+            })?;
+
+        // 18. If new.[[ArrayBufferData]] and O.[[ArrayBufferData]] are the same Shared Data Block values, throw a TypeError exception.
+        if new_buffer
+            .array_buffer_data
+            .as_ref()
+            .map_or(false, |new_block| new_block.same_block(&block))
+        {
+            return Err(JsNativeError::typ()
+                .with_message("New SharedArrayBuffer shares the block of this SharedArrayBuffer")
+                .into());
+        }
+
+        // 19. If new.[[ArrayBufferByteLength]] < newLen, throw a TypeError exception.
+        if new_buffer.array_buffer_byte_length < new_len {
+            return Err(JsNativeError::typ()
+                .with_message("New SharedArrayBuffer length too small")
+                .into());
+        }
+
+        // 20. Let fromBuf be O.[[ArrayBufferData]].
+        // 21. Let toBuf be new.[[ArrayBufferData]].
+        // 22. Perform CopyDataBlockBytes(toBuf, 0, fromBuf, first, newLen).
+        let to_block = new_buffer
+            .array_buffer_data
+            .as_mut()
+            .expect("a SharedArrayBuffer cannot be detached");
+        copy_data_block_bytes(to_block, 0, &block, first as usize, new_len as usize);
+        drop(new_obj);
+
+        // 23. Return new.
+        Ok(new.into())
+    }
+
+    /// `25.2.1.1 AllocateSharedArrayBuffer ( constructor, byteLength )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-allocatesharedarraybuffer
+    pub(crate) fn allocate(
+        constructor: &JsValue,
+        byte_length: u64,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Let obj be ? OrdinaryCreateFromConstructor(constructor, "%SharedArrayBuffer.prototype%", « [[ArrayBufferData]], [[ArrayBufferByteLength]] »).
+        let prototype = get_prototype_from_constructor(
+            constructor,
+            StandardConstructors::shared_array_buffer,
+            context,
+        )?;
+
+        // 2. Let block be ? CreateSharedByteDataBlock(byteLength).
+        let block = SharedDataBlock::new(byte_length)?;
+
+        // 3. Set obj.[[ArrayBufferData]] to block.
+        // 4. Set obj.[[ArrayBufferByteLength]] to byteLength.
+        // 5. Return obj.
+        Ok(Self::from_block(block, prototype, context))
+    }
+
+    /// Creates a `SharedArrayBuffer` object with the given prototype, which uses `block` as its
+    /// `[[ArrayBufferData]]`.
+    pub(crate) fn from_block(
+        block: SharedDataBlock,
+        prototype: JsObject,
+        context: &mut Context,
+    ) -> JsObject {
+        let obj = context.construct_object();
+        obj.set_prototype(prototype.into());
+        obj.borrow_mut().data = ObjectData::array_buffer(ArrayBuffer {
+            array_buffer_byte_length: block.len() as u64,
+            array_buffer_data: Some(DataBlock::Shared(block)),
+            array_buffer_max_byte_length: None,
+            array_buffer_detach_key: JsValue::undefined(),
+            data_block_memory: ExternalMemory::default(),
+        });
+        obj
+    }
+}
+
+/// A Shared Data Block, the `[[ArrayBufferData]]` of a `SharedArrayBuffer`.
+///
+/// Cloning a block gives another handle to the same bytes, which can be sent to another thread
+/// to create a `SharedArrayBuffer` object in the context of another agent with
+/// [`JsSharedArrayBuffer::from_data_block`][crate::object::JsSharedArrayBuffer::from_data_block].
+///
+/// The bytes are only accessed with atomic operations. Unordered accesses are made byte by byte,
+/// so they may tear, and the sequentially consistent accesses of `Atomics` are serialized by a
+/// lock of the block.
+#[derive(Debug, Clone)]
+pub struct SharedDataBlock {
+    inner: Arc<SharedDataBlockInner>,
+}
+
+#[derive(Debug)]
+struct SharedDataBlockInner {
+    bytes: Box<[AtomicU8]>,
+
+    /// Held during the sequentially consistent accesses to the block.
+    atomics: Mutex<()>,
+
+    /// The waiter lists of the block, which are protected by their critical section.
+    waiters: Mutex<WaiterList>,
+
+    /// Wakes up the agents that wait on the block.
+    woken: Condvar,
+}
+
+/// The agents waiting on the indices of a block, in the order they started waiting.
+#[derive(Debug, Default)]
+struct WaiterList {
+    next_id: u64,
+    waiters: VecDeque<Waiter>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Waiter {
+    id: u64,
+    byte_index: usize,
+}
+
+/// The result of [`SharedDataBlock::wait`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WaitResult {
+    /// The agent was notified.
+    Ok,
+
+    /// The value at the waited index was not the expected one.
+    NotEqual,
+
+    /// The timeout expired before the agent was notified.
+    TimedOut,
+}
+
+impl SharedDataBlock {
+    /// `CreateSharedByteDataBlock ( size )`
+    ///
+    /// Creates a block of `size` bytes set to 0, or throws a `RangeError` if it cannot be
+    /// allocated.
+    pub fn new(size: u64) -> JsResult<Self> {
+        let error = |e: &dyn std::fmt::Display| {
+            JsNativeError::range().with_message(format!("couldn't allocate the data block: {e}"))
+        };
+        let size = usize::try_from(size).map_err(|e| error(&e))?;
+
+        let mut bytes = Vec::new();
+        bytes.try_reserve(size).map_err(|e| error(&e))?;
+        bytes.resize_with(size, AtomicU8::default);
+
+        Ok(Self::from_bytes(bytes.into_boxed_slice()))
+    }
+
+    fn from_bytes(bytes: Box<[AtomicU8]>) -> Self {
+        Self {
+            inner: Arc::new(SharedDataBlockInner {
+                bytes,
+                atomics: Mutex::default(),
+                waiters: Mutex::default(),
+                woken: Condvar::new(),
+            }),
+        }
+    }
+
+    /// Creates a block holding a copy of `bytes`.
+    pub(crate) fn from_slice(bytes: &[u8]) -> Self {
+        Self::from_bytes(bytes.iter().copied().map(AtomicU8::new).collect())
+    }
+
+    /// Gets the number of bytes of the block.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.bytes.len()
+    }
+
+    /// Returns `true` if the block has no bytes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.bytes.is_empty()
+    }
+
+    /// Returns `true` if both handles refer to the same block.
+    #[inline]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Copies the bytes of the block.
+    pub fn to_vec(&self) -> Vec<u8> {
+        let mut bytes = vec![0; self.len()];
+        self.read(0, &mut bytes, SharedMemoryOrder::Unordered);
+        bytes
+    }
+
+    /// Locks the block for a sequentially consistent access, if `order` requires it.
+    fn lock(&self, order: SharedMemoryOrder) -> Option<MutexGuard<'_, ()>> {
+        (order == SharedMemoryOrder::SeqCst).then(|| {
+            self.inner
+                .atomics
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+        })
+    }
+
+    /// Reads the bytes starting at `index` into `buffer`.
+    pub(crate) fn read(&self, index: usize, buffer: &mut [u8], order: SharedMemoryOrder) {
+        let _guard = self.lock(order);
+        let bytes = &self.inner.bytes[index..index + buffer.len()];
+        for (byte, atomic) in buffer.iter_mut().zip(bytes) {
+            *byte = atomic.load(Ordering::SeqCst);
+        }
+    }
+
+    /// Writes `buffer` into the bytes starting at `index`.
+    pub(crate) fn write(&self, index: usize, buffer: &[u8], order: SharedMemoryOrder) {
+        let _guard = self.lock(order);
+        let bytes = &self.inner.bytes[index..index + buffer.len()];
+        for (byte, atomic) in buffer.iter().zip(bytes) {
+            atomic.store(*byte, Ordering::SeqCst);
+        }
+    }
+
+    /// Atomically replaces the `size` bytes starting at `index` with the result of `op`, which
+    /// receives the bytes read, and returns the bytes read.
+    pub(crate) fn modify<F>(&self, index: usize, size: usize, op: F) -> Vec<u8>
+    where
+        F: FnOnce(&[u8]) -> Vec<u8>,
+    {
+        let _guard = self.lock(SharedMemoryOrder::SeqCst);
+        let bytes = &self.inner.bytes[index..index + size];
+        let read: Vec<u8> = bytes.iter().map(|b| b.load(Ordering::SeqCst)).collect();
+        for (byte, atomic) in op(&read).iter().zip(bytes) {
+            atomic.store(*byte, Ordering::SeqCst);
+        }
+        read
+    }
+
+    /// Suspends the current thread until another agent notifies the byte at `byte_index`, or
+    /// until `timeout` expires, if the bytes starting at `byte_index` are equal to `expected`.
+    ///
+    /// The bytes are compared in the critical section of the waiter list, so an agent that
+    /// writes them before notifying the index cannot be missed.
+    pub(crate) fn wait(
+        &self,
+        byte_index: usize,
+        expected: &[u8],
+        timeout: Option<Duration>,
+    ) -> WaitResult {
+        let deadline = timeout.and_then(|timeout| Instant::now().checked_add(timeout));
+
+        let mut list = self.waiter_list();
+
+        let mut read = vec![0; expected.len()];
+        self.read(byte_index, &mut read, SharedMemoryOrder::SeqCst);
+        if read != expected {
+            return WaitResult::NotEqual;
+        }
+
+        let id = list.next_id;
+        list.next_id += 1;
+        list.waiters.push_back(Waiter { id, byte_index });
+
+        loop {
+            // Waiters are removed from the list when they are notified.
+            if !list.waiters.iter().any(|waiter| waiter.id == id) {
+                return WaitResult::Ok;
+            }
+
+            list = match deadline {
+                // Without a timeout, or with a timeout too long to be represented, the agent
+                // waits until it is notified.
+                None => self
+                    .inner
+                    .woken
+                    .wait(list)
+                    .unwrap_or_else(std::sync::PoisonError::into_inner),
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        list.waiters.retain(|waiter| waiter.id != id);
+                        return WaitResult::TimedOut;
+                    }
+                    self.inner
+                        .woken
+                        .wait_timeout(list, deadline - now)
+                        .unwrap_or_else(std::sync::PoisonError::into_inner)
+                        .0
+                }
+            };
+        }
+    }
+
+    /// Wakes up at most `count` agents waiting on the byte at `byte_index`, in the order they
+    /// started waiting, and returns the number of agents woken up.
+    pub(crate) fn notify(&self, byte_index: usize, count: u64) -> u64 {
+        let mut list = self.waiter_list();
+
+        let mut notified = 0;
+        list.waiters.retain(|waiter| {
+            if notified < count && waiter.byte_index == byte_index {
+                notified += 1;
+                false
+            } else {
+                true
+            }
+        });
+        drop(list);
+
+        if notified > 0 {
+            self.inner.woken.notify_all();
+        }
+        notified
+    }
+
+    /// Enters the critical section of the waiter lists.
+    fn waiter_list(&self) -> MutexGuard<'_, WaiterList> {
+        self.inner
+            .waiters
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
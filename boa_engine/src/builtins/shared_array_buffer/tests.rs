@@ -0,0 +1,113 @@
+use crate::{forward, Context};
+
+#[test]
+fn constructor() {
+    let mut context = Context::default();
+
+    let init = r#"
+        var sab = new SharedArrayBuffer(8);
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "sab.byteLength"), "8");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(sab)"),
+        "\"[object SharedArrayBuffer]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new SharedArrayBuffer(-1) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn slice() {
+    let mut context = Context::default();
+
+    let init = r#"
+        var sab = new SharedArrayBuffer(8);
+        new Uint8Array(sab).set([1, 2, 3, 4, 5, 6, 7, 8]);
+        var sliced = sab.slice(2, -2);
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "sliced.byteLength"), "4");
+    assert_eq!(
+        forward(&mut context, "Array.from(new Uint8Array(sliced)).join()"),
+        "\"3,4,5,6\""
+    );
+    assert_eq!(
+        forward(&mut context, "sliced instanceof SharedArrayBuffer"),
+        "true"
+    );
+}
+
+#[test]
+fn not_an_array_buffer() {
+    let mut context = Context::default();
+
+    let init = r#"
+        var sab = new SharedArrayBuffer(8);
+        var ab = new ArrayBuffer(8);
+        "#;
+
+    forward(&mut context, init);
+
+    let array_buffer_byte_length =
+        "Object.getOwnPropertyDescriptor(ArrayBuffer.prototype, 'byteLength').get";
+    let shared_byte_length =
+        "Object.getOwnPropertyDescriptor(SharedArrayBuffer.prototype, 'byteLength').get";
+
+    assert_eq!(
+        forward(
+            &mut context,
+            format!("try {{ {array_buffer_byte_length}.call(sab) }} catch (e) {{ e.name }}")
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            format!("try {{ {shared_byte_length}.call(ab) }} catch (e) {{ e.name }}")
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { ArrayBuffer.prototype.slice.call(sab) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn typed_array_views() {
+    let mut context = Context::default();
+
+    let init = r#"
+        var sab = new SharedArrayBuffer(8);
+        var a = new Int32Array(sab);
+        var b = new Int32Array(sab, 4);
+        a[1] = 42;
+        var copy = new Int32Array(a);
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "b[0]"), "42");
+    assert_eq!(forward(&mut context, "a.buffer === sab"), "true");
+    assert_eq!(
+        forward(&mut context, "copy.buffer instanceof ArrayBuffer"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "new DataView(sab).getInt32(4, true)"),
+        "42"
+    );
+}
@@ -21,9 +21,10 @@ use crate::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
     },
     property::{Attribute, PropertyDescriptor},
+    string::{utf16, CodePoint},
     symbol::WellKnownSymbols,
     value::IntegerOrInfinity,
-    Context, JsResult, JsString, JsValue,
+    Context, JsNativeError, JsResult, JsString, JsValue, JsVariant,
 };
 use boa_profiler::Profiler;
 use std::{
@@ -61,18 +62,14 @@ pub(crate) struct CodePointInfo {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-codepointat
 pub(crate) fn code_point_at(string: &JsString, position: u64) -> CodePointInfo {
-    let mut encoded = string.encode_utf16();
-
     // 1. Let size be the length of string.
-    let size = encoded.clone().count() as u64;
+    let size = string.len() as u64;
 
     // 2. Assert: position ≥ 0 and position < size.
     assert!(position < size);
 
     // 3. Let first be the code unit at index position within string.
-    let first = encoded
-        .nth(position as usize)
-        .expect("The callers of this function must've already checked bounds.");
+    let first = string[position as usize];
 
     // 4. Let cp be the code point whose numeric value is that of first.
     let cp = u32::from(first);
@@ -98,9 +95,7 @@ pub(crate) fn code_point_at(string: &JsString, position: u64) -> CodePointInfo {
     }
 
     // 7. Let second be the code unit at index position + 1 within string.
-    let second = encoded
-        .next()
-        .expect("The callers of this function must've already checked bounds.");
+    let second = string[position as usize + 1];
 
     // 8. If second is not a trailing surrogate, then
     if !is_trailing_surrogate(second) {
@@ -219,6 +214,8 @@ impl BuiltIn for String {
         .method(Self::iterator, (symbol_iterator, "[Symbol.iterator]"), 0)
         .method(Self::search, "search", 1)
         .method(Self::at, "at", 1)
+        .method(Self::is_well_formed, "isWellFormed", 0)
+        .method(Self::to_well_formed, "toWellFormed", 0)
         .build()
         .conv::<JsValue>()
         .pipe(Some)
@@ -246,14 +243,14 @@ impl String {
     ) -> JsResult<JsValue> {
         // This value is used by console.log and other routines to match Object type
         // to its Javascript Identifier (global constructor method name)
-        let string = match args.get(0) {
+        let string = match args.get(0).map(|value| (value, value.variant())) {
             // 2. Else,
             // a. If NewTarget is undefined and Type(value) is Symbol, return SymbolDescriptiveString(value).
-            Some(JsValue::Symbol(ref sym)) if new_target.is_undefined() => {
+            Some((_, JsVariant::Symbol(sym))) if new_target.is_undefined() => {
                 return Ok(sym.descriptive_string().into())
             }
             // b. Let s be ? ToString(value).
-            Some(value) => value.to_string(context)?,
+            Some((value, _)) => value.to_string(context)?,
             // 1. If value is not present, let s be the empty String.
             None => JsString::default(),
         };
@@ -278,7 +275,7 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-stringcreate
     fn string_create(value: JsString, prototype: JsObject, context: &mut Context) -> JsObject {
         // 7. Let length be the number of code unit elements in value.
-        let len = value.encode_utf16().count();
+        let len = value.len();
 
         // 1. Let S be ! MakeBasicObject(« [[Prototype]], [[Extensible]], [[StringData]] »).
         // 2. Set S.[[Prototype]] to prototype.
@@ -311,7 +308,7 @@ impl String {
     /// - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#thisstringvalue
-    fn this_string_value(this: &JsValue, context: &mut Context) -> JsResult<JsString> {
+    fn this_string_value(this: &JsValue) -> JsResult<JsString> {
         // 1. If Type(value) is String, return value.
         this.as_string()
             .cloned()
@@ -321,7 +318,11 @@ impl String {
             //     c. Return s.
             .or_else(|| this.as_object().and_then(|obj| obj.borrow().as_string()))
             // 3. Throw a TypeError exception.
-            .ok_or_else(|| context.construct_type_error("'this' is not a string"))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("'this' is not a string")
+                    .into()
+            })
     }
 
     /// `String.fromCodePoint(num1[, ...[, numN]])`
@@ -340,7 +341,7 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let result be the empty String.
-        let mut result = StdString::new();
+        let mut result = Vec::with_capacity(args.len());
 
         // 2. For each element next of codePoints, do
         for arg in args.iter() {
@@ -349,22 +350,31 @@ impl String {
 
             // b. If ! IsIntegralNumber(nextCP) is false, throw a RangeError exception.
             if !Number::is_float_integer(nextcp) {
-                return Err(context.construct_range_error(format!("invalid code point: {nextcp}")));
+                return Err(JsNativeError::range()
+                    .with_message(format!("invalid code point: {nextcp}"))
+                    .into());
             }
 
             // c. If ℝ(nextCP) < 0 or ℝ(nextCP) > 0x10FFFF, throw a RangeError exception.
             if nextcp < 0.0 || nextcp > f64::from(0x10FFFF) {
-                return Err(context.construct_range_error(format!("invalid code point: {nextcp}")));
+                return Err(JsNativeError::range()
+                    .with_message(format!("invalid code point: {nextcp}"))
+                    .into());
             }
 
-            // TODO: Full UTF-16 support
             // d. Set result to the string-concatenation of result and ! UTF16EncodeCodePoint(ℝ(nextCP)).
-            result.push(char::try_from(nextcp as u32).unwrap_or('\u{FFFD}' /* replacement char */));
+            let nextcp = nextcp as u32;
+            if let Some(c) = from_u32(nextcp) {
+                result.extend_from_slice(c.encode_utf16(&mut [0; 2]));
+            } else {
+                // Surrogates are not valid `char`s, but they are encoded as a single code unit.
+                result.push(nextcp as u16);
+            }
         }
 
         // 3. Assert: If codePoints is empty, then result is the empty String.
         // 4. Return result.
-        Ok(result.into())
+        Ok(JsString::from(result).into())
     }
 
     /// `String.prototype.raw( template, ...substitutions )`
@@ -410,13 +420,13 @@ impl String {
             let next_seg = raw.get(next_key, context)?.to_string(context)?;
 
             // c. Append the code unit elements of nextSeg to the end of stringElements.
-            string_elements.extend(next_seg.encode_utf16());
+            string_elements.extend_from_slice(&next_seg);
 
             // d. If nextIndex + 1 = literalSegments, then
             if next_index + 1 == literal_segments {
                 // i. Return the String value whose code units are the elements in the List stringElements.
                 //    If stringElements has no elements, the empty String is returned.
-                return Ok(StdString::from_utf16_lossy(&string_elements).into());
+                return Ok(JsString::from(string_elements).into());
             }
 
             // e. If nextIndex < numberOfSubstitutions, let next be substitutions[nextIndex].
@@ -432,7 +442,7 @@ impl String {
             let next_sub = next.to_string(context)?;
 
             // h. Append the code unit elements of nextSub to the end of stringElements.
-            string_elements.extend(next_sub.encode_utf16());
+            string_elements.extend_from_slice(&next_sub);
 
             // i. Set nextIndex to nextIndex + 1.
             next_index += 1;
@@ -464,9 +474,7 @@ impl String {
         }
 
         // 3. Return result.
-        Ok(JsValue::String(JsString::new(
-            std::string::String::from_utf16_lossy(&result),
-        )))
+        Ok(JsValue::new(JsString::from(result)))
     }
 
     /// `String.prototype.toString ( )`
@@ -477,13 +485,9 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.tostring
     #[allow(clippy::wrong_self_convention)]
     #[inline]
-    pub(crate) fn to_string(
-        this: &JsValue,
-        _: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
+    pub(crate) fn to_string(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         // 1. Return ? thisStringValue(this value).
-        Ok(Self::this_string_value(this, context)?.into())
+        Ok(Self::this_string_value(this)?.into())
     }
 
     /// `String.prototype.charAt( index )`
@@ -514,20 +518,14 @@ impl String {
         let string = this.to_string(context)?;
 
         // 4. Let size be the length of S.
-        let size = string.encode_utf16().count() as i64;
+        let size = string.len() as i64;
 
         // 3. Let position be ? ToIntegerOrInfinity(pos).
         match args.get_or_undefined(0).to_integer_or_infinity(context)? {
             IntegerOrInfinity::Integer(position) if (0..size).contains(&position) => {
                 // 6. Return the substring of S from position to position + 1.
-                let char = string
-                    .encode_utf16()
-                    .nth(position as usize)
-                    .expect("Already checked bounds above");
-
-                Ok(char::try_from(u32::from(char))
-                    .unwrap_or('\u{FFFD}' /* replacement char */)
-                    .into())
+                let position = position as usize;
+                Ok(JsString::from(&string[position..=position]).into())
             }
             _ => {
                 // 5. If position < 0 or position ≥ size, return the empty String.
@@ -555,7 +553,7 @@ impl String {
         let s = this.to_string(context)?;
 
         // 3. Let len be the length of S.
-        let len = s.encode_utf16().count() as i64;
+        let len = s.len() as i64;
 
         // 4. Let relativeIndex be ? ToIntegerOrInfinity(index).
         let relative_index = args.get_or_undefined(0).to_integer_or_infinity(context)?;
@@ -571,13 +569,7 @@ impl String {
         };
 
         // 8. Return the substring of S from k to k + 1.
-        if let Some(utf16_val) = s.encode_utf16().nth(k) {
-            Ok(JsValue::new(
-                from_u32(u32::from(utf16_val)).expect("invalid utf-16 character"),
-            ))
-        } else {
-            Ok(JsValue::undefined())
-        }
+        Ok(JsString::from(&s[k..=k]).into())
     }
 
     /// `String.prototype.codePointAt( index )`
@@ -609,7 +601,7 @@ impl String {
         let position = args.get_or_undefined(0).to_integer_or_infinity(context)?;
 
         // 4. Let size be the length of S.
-        let size = string.encode_utf16().count() as i64;
+        let size = string.len() as i64;
 
         match position {
             IntegerOrInfinity::Integer(position) if (0..size).contains(&position) => {
@@ -651,18 +643,12 @@ impl String {
         let position = args.get_or_undefined(0).to_integer_or_infinity(context)?;
 
         // 4. Let size be the length of S.
-        let size = string.encode_utf16().count() as i64;
+        let size = string.len() as i64;
 
         match position {
             IntegerOrInfinity::Integer(position) if (0..size).contains(&position) => {
                 // 6. Return the Number value for the numeric value of the code unit at index position within the String S.
-                let char_code = u32::from(
-                    string
-                        .encode_utf16()
-                        .nth(position as usize)
-                        .expect("Already checked bounds above."),
-                );
-                Ok(char_code.into())
+                Ok(string[position as usize].into())
             }
             // 5. If position < 0 or position ≥ size, return NaN.
             _ => Ok(JsValue::nan()),
@@ -692,18 +678,20 @@ impl String {
         let this = this.require_object_coercible(context)?;
 
         // 2. Let S be ? ToString(O).
-        let mut string = this.to_string(context)?.to_string();
-
         // 3. Let R be S.
+        let mut string = this.to_string(context)?;
+
         // 4. For each element next of args, do
         for arg in args {
             // a. Let nextString be ? ToString(next).
+            let next_string = arg.to_string(context)?;
+
             // b. Set R to the string-concatenation of R and nextString.
-            string.push_str(&arg.to_string(context)?);
+            string = JsString::concat_rope(&string, &next_string);
         }
 
         // 5. Return R.
-        Ok(JsValue::new(string))
+        Ok(string.into())
     }
 
     /// `String.prototype.repeat( count )`
@@ -728,7 +716,7 @@ impl String {
         // 2. Let S be ? ToString(O).
         let string = this.to_string(context)?;
 
-        let len = string.encode_utf16().count();
+        let len = string.len();
 
         // 3. Let n be ? ToIntegerOrInfinity(count).
         match args.get_or_undefined(0).to_integer_or_infinity(context)? {
@@ -738,23 +726,18 @@ impl String {
                 if string.is_empty() {
                     return Ok("".into());
                 }
-                let n = n as usize;
-                let mut result = std::string::String::with_capacity(n * len);
-
-                std::iter::repeat(&string[..])
-                    .take(n)
-                    .for_each(|s| result.push_str(s));
-
                 // 6. Return the String value that is made from n copies of S appended together.
-                Ok(result.into())
+                Ok(JsString::from(string.repeat(n as usize)).into())
             }
             // 5. If n is 0, return the empty String.
             IntegerOrInfinity::Integer(n) if n == 0 => Ok("".into()),
             // 4. If n < 0 or n is +∞, throw a RangeError exception.
-            _ => context.throw_range_error(
-                "repeat count must be a positive finite number \
+            _ => Err(JsNativeError::range()
+                .with_message(
+                    "repeat count must be a positive finite number \
                         that doesn't overflow the maximum string length (2^32 - 1)",
-            ),
+                )
+                .into()),
         }
     }
 
@@ -780,7 +763,7 @@ impl String {
         let string = this.to_string(context)?;
 
         // 3. Let len be the length of S.
-        let len = string.encode_utf16().count() as i64;
+        let len = string.len() as i64;
 
         // 4. Let intStart be ? ToIntegerOrInfinity(start).
         let from = match args.get_or_undefined(0).to_integer_or_infinity(context)? {
@@ -819,10 +802,7 @@ impl String {
             Ok("".into())
         } else {
             // 13. Return the substring of S from from to to.
-            let span = to - from;
-            let substring_utf16: Vec<u16> = string.encode_utf16().skip(from).take(span).collect();
-            let substring_lossy = StdString::from_utf16_lossy(&substring_utf16);
-            Ok(substring_lossy.into())
+            Ok(JsString::from(&string[from..to]).into())
         }
     }
 
@@ -852,28 +832,28 @@ impl String {
         // 3. Let isRegExp be ? IsRegExp(searchString).
         // 4. If isRegExp is true, throw a TypeError exception.
         if is_reg_exp(search_string, context)? {
-            context.throw_type_error(
-                "First argument to String.prototype.startsWith must not be a regular expression",
-            )?;
+            return Err(JsNativeError::typ().with_message("First argument to String.prototype.startsWith must not be a regular expression").into());
         }
 
         // 5. Let searchStr be ? ToString(searchString).
         let search_string = search_string.to_string(context)?;
 
         // 6. Let len be the length of S.
-        let len = string.encode_utf16().count() as i64;
+        let len = string.len() as i64;
 
         // 7. If position is undefined, let pos be 0; else let pos be ? ToIntegerOrInfinity(position).
-        let pos = match args.get_or_undefined(1) {
-            &JsValue::Undefined => IntegerOrInfinity::Integer(0),
-            position => position.to_integer_or_infinity(context)?,
+        let position = args.get_or_undefined(1);
+        let pos = if position.is_undefined() {
+            IntegerOrInfinity::Integer(0)
+        } else {
+            position.to_integer_or_infinity(context)?
         };
 
         // 8. Let start be the result of clamping pos between 0 and len.
         let start = pos.clamp_finite(0, len) as usize;
 
         // 9. Let searchLength be the length of searchStr.
-        let search_length = search_string.encode_utf16().count();
+        let search_length = search_string.len();
 
         // 10. If searchLength = 0, return true.
         if search_length == 0 {
@@ -891,9 +871,7 @@ impl String {
             // 14. Return ! SameValueNonNumeric(substring, searchStr).
             // `SameValueNonNumeric` forwards to `==`, so directly check
             // equality to avoid converting to `JsValue`
-            let substring_utf16 = string.encode_utf16().skip(start).take(search_length);
-            let search_str_utf16 = search_string.encode_utf16();
-            Ok(JsValue::new(substring_utf16.eq(search_str_utf16)))
+            Ok(JsValue::new(string[start..end] == search_string[..]))
         }
     }
 
@@ -922,16 +900,14 @@ impl String {
             // 3. Let isRegExp be ? IsRegExp(searchString).
             // 4. If isRegExp is true, throw a TypeError exception.
             search_string if is_reg_exp(search_string, context)? => {
-                return context.throw_type_error(
-                    "First argument to String.prototype.endsWith must not be a regular expression",
-                );
+                return Err(JsNativeError::typ().with_message("First argument to String.prototype.endsWith must not be a regular expression").into());
             }
             // 5. Let searchStr be ? ToString(searchString).
             search_string => search_string.to_string(context)?,
         };
 
         // 6. Let len be the length of S.
-        let len = string.encode_utf16().count() as i64;
+        let len = string.len() as i64;
 
         // 7. If endPosition is undefined, let pos be len; else let pos be ? ToIntegerOrInfinity(endPosition).
         let end = match args.get_or_undefined(1) {
@@ -943,7 +919,7 @@ impl String {
         let end = end.clamp_finite(0, len) as usize;
 
         // 9. Let searchLength be the length of searchStr.
-        let search_length = search_str.encode_utf16().count();
+        let search_length = search_str.len();
 
         // 10. If searchLength = 0, return true.
         if search_length == 0 {
@@ -956,11 +932,7 @@ impl String {
             // 14. Return ! SameValueNonNumeric(substring, searchStr).
             // `SameValueNonNumeric` forwards to `==`, so directly check
             // equality to avoid converting to `JsValue`
-
-            let substring_utf16 = string.encode_utf16().skip(start).take(search_length);
-            let search_str_utf16 = search_str.encode_utf16();
-
-            Ok(JsValue::new(substring_utf16.eq(search_str_utf16)))
+            Ok(JsValue::new(string[start..end] == search_str[..]))
         } else {
             // 12. If start < 0, return false.
             Ok(false.into())
@@ -991,10 +963,8 @@ impl String {
         let search_str = match args.get_or_undefined(0) {
             // 3. Let isRegExp be ? IsRegExp(searchString).
             search_string if is_reg_exp(search_string, context)? => {
-                return context.throw_type_error(
-                    // 4. If isRegExp is true, throw a TypeError exception.
-                    "First argument to String.prototype.includes must not be a regular expression",
-                );
+                return Err(JsNativeError::typ().with_message(// 4. If isRegExp is true, throw a TypeError exception.
+                    "First argument to String.prototype.includes must not be a regular expression").into());
             }
             // 5. Let searchStr be ? ToString(searchString).
             search_string => search_string.to_string(context)?,
@@ -1006,7 +976,7 @@ impl String {
 
         // 8. Let len be the length of S.
         // 9. Let start be the result of clamping pos between 0 and len.
-        let start = pos.clamp_finite(0, string.encode_utf16().count() as i64) as usize;
+        let start = pos.clamp_finite(0, string.len() as i64) as usize;
 
         // 10. Let index be ! StringIndexOf(S, searchStr, start).
         // 11. If index is not -1, return true.
@@ -1084,9 +1054,7 @@ impl String {
         };
 
         // 10. Let preserved be the substring of string from 0 to position.
-        let preserved = StdString::from_utf16_lossy(
-            &this_str.encode_utf16().take(position).collect::<Vec<u16>>(),
-        );
+        let preserved = &this_str[..position];
 
         // 11. If functionalReplace is true, then
         // 12. Else,
@@ -1106,8 +1074,8 @@ impl String {
 
             // c. Let replacement be ! GetSubstitution(searchString, string, position, captures, undefined, replaceValue).
             get_substitution(
-                search_str.as_str(),
-                this_str.as_str(),
+                &search_str,
+                &this_str,
                 position,
                 &captures,
                 &JsValue::undefined(),
@@ -1117,15 +1085,11 @@ impl String {
         };
 
         // 13. Return the string-concatenation of preserved, replacement, and the substring of string from position + searchLength.
-        Ok(format!(
-            "{preserved}{replacement}{}",
-            StdString::from_utf16_lossy(
-                &this_str
-                    .encode_utf16()
-                    .skip(position + search_length)
-                    .collect::<Vec<u16>>()
-            )
-        )
+        Ok(JsString::concat_array(&[
+            preserved,
+            &replacement,
+            &this_str[position + search_length..],
+        ])
         .into())
     }
 
@@ -1169,10 +1133,8 @@ impl String {
                     flags.require_object_coercible(context)?;
 
                     // iii. If ? ToString(flags) does not contain "g", throw a TypeError exception.
-                    if !flags.to_string(context)?.contains('g') {
-                        return context.throw_type_error(
-                            "String.prototype.replaceAll called with a non-global RegExp argument",
-                        );
+                    if !flags.to_string(context)?.contains(&u16::from(b'g')) {
+                        return Err(JsNativeError::typ().with_message("String.prototype.replaceAll called with a non-global RegExp argument").into());
                     }
                 }
             }
@@ -1208,7 +1170,7 @@ impl String {
         };
 
         // 7. Let searchLength be the length of searchString.
-        let search_length = search_string.encode_utf16().count();
+        let search_length = search_string.len();
 
         // 8. Let advanceBy be max(1, searchLength).
         let advance_by = max(1, search_length);
@@ -1232,19 +1194,12 @@ impl String {
         let mut end_of_last_match = 0;
 
         // 13. Let result be the empty String.
-        let mut result = JsString::new("");
+        let mut result = Vec::with_capacity(string.len());
 
         // 14. For each element p of matchPositions, do
         for p in match_positions {
             // a. Let preserved be the substring of string from endOfLastMatch to p.
-            let preserved = StdString::from_utf16_lossy(
-                &string
-                    .clone()
-                    .encode_utf16()
-                    .skip(end_of_last_match)
-                    .take(p - end_of_last_match)
-                    .collect::<Vec<u16>>(),
-            );
+            let preserved = &string[end_of_last_match..p];
 
             // c. Else,
             let replacement = if let Some(ref replace_value) = replace_value_string {
@@ -1279,29 +1234,21 @@ impl String {
             };
 
             // d. Set result to the string-concatenation of result, preserved, and replacement.
-            result = JsString::new(format!("{}{preserved}{replacement}", result.as_str()));
+            result.extend_from_slice(preserved);
+            result.extend_from_slice(&replacement);
 
             // e. Set endOfLastMatch to p + searchLength.
             end_of_last_match = p + search_length;
         }
 
         // 15. If endOfLastMatch < the length of string, then
-        if end_of_last_match < string.encode_utf16().count() {
+        if end_of_last_match < string.len() {
             // a. Set result to the string-concatenation of result and the substring of string from endOfLastMatch.
-            result = JsString::new(format!(
-                "{}{}",
-                result.as_str(),
-                &StdString::from_utf16_lossy(
-                    &string
-                        .encode_utf16()
-                        .skip(end_of_last_match)
-                        .collect::<Vec<u16>>()
-                )
-            ));
+            result.extend_from_slice(&string[end_of_last_match..]);
         }
 
         // 16. Return result.
-        Ok(result.into())
+        Ok(JsString::from(result).into())
     }
 
     /// `String.prototype.indexOf( searchValue[, fromIndex] )`
@@ -1336,7 +1283,7 @@ impl String {
         let pos = args.get_or_undefined(1).to_integer_or_infinity(context)?;
 
         // 6. Let len be the length of S.
-        let len = string.encode_utf16().count() as i64;
+        let len = string.len() as i64;
 
         // 7. Let start be the result of clamping pos between 0 and len.
         let start = pos.clamp_finite(0, len) as usize;
@@ -1389,7 +1336,7 @@ impl String {
         };
 
         // 7. Let len be the length of S.
-        let len = string.encode_utf16().count();
+        let len = string.len();
         // 8. Let start be the result of clamping pos between 0 and len.
         let start = pos.clamp_finite(0, len as i64) as usize;
 
@@ -1399,17 +1346,17 @@ impl String {
         }
 
         // 10. Let searchLen be the length of searchStr.
-        let search_len = search_str.encode_utf16().count();
+        let search_len = search_str.len();
 
         // 11. For each non-negative integer i starting with start such that i ≤ len - searchLen, in descending order, do
-        // a. Let candidate be the substring of S from i to i + searchLen.
-        let substring_utf16: Vec<u16> = string.encode_utf16().take(start + search_len).collect();
-        let substring_lossy = StdString::from_utf16_lossy(&substring_utf16);
-        if let Some(position) = substring_lossy.rfind(search_str.as_str()) {
-            // b. If candidate is the same sequence of code units as searchStr, return 𝔽(i).
-            return Ok(JsValue::new(
-                substring_lossy[..position].encode_utf16().count(),
-            ));
+        if let Some(end) = len.checked_sub(search_len) {
+            for i in (0..=min(start, end)).rev() {
+                // a. Let candidate be the substring of S from i to i + searchLen.
+                // b. If candidate is the same sequence of code units as searchStr, return 𝔽(i).
+                if string[i..i + search_len] == search_str[..] {
+                    return Ok(JsValue::new(i));
+                }
+            }
         }
 
         // 12. Return -1𝔽.
@@ -1451,7 +1398,7 @@ impl String {
         let s = o.to_string(context)?;
 
         // 4. Let rx be ? RegExpCreate(regexp, undefined).
-        let rx = RegExp::create(regexp, &JsValue::Undefined, context)?;
+        let rx = RegExp::create(regexp, &JsValue::undefined(), context)?;
 
         // 5. Return ? Invoke(rx, @@match, « S »).
         rx.invoke(WellKnownSymbols::r#match(), &[JsValue::new(s)], context)
@@ -1479,7 +1426,7 @@ impl String {
         let int_max_length = max_length.to_length(context)?;
 
         // 3. Let stringLength be the length of S.
-        let string_length = string.encode_utf16().count() as u64;
+        let string_length = string.len() as u64;
 
         // 4. If intMaxLength ≤ stringLength, return S.
         if int_max_length <= string_length {
@@ -1488,7 +1435,7 @@ impl String {
 
         // 5. If fillString is undefined, let filler be the String value consisting solely of the code unit 0x0020 (SPACE).
         let filler = if fill_string.is_undefined() {
-            "\u{0020}".into()
+            JsString::from(utf16!("\u{0020}"))
         } else {
             // 6. Else, let filler be ? ToString(fillString).
             fill_string.to_string(context)?
@@ -1501,7 +1448,7 @@ impl String {
 
         // 8. Let fillLen be intMaxLength - stringLength.
         let fill_len = int_max_length - string_length;
-        let filler_len = filler.encode_utf16().count() as u64;
+        let filler_len = filler.len() as u64;
 
         // 9. Let truncatedStringFiller be the String value consisting of repeated
         // concatenations of filler truncated to length fillLen.
@@ -1515,20 +1462,15 @@ impl String {
             }
         };
 
-        let truncated_string_filler = filler
-            .repeat(repetitions as usize)
-            .encode_utf16()
-            .take(fill_len as usize)
-            .collect::<Vec<_>>();
-        let truncated_string_filler =
-            std::string::String::from_utf16_lossy(truncated_string_filler.as_slice());
+        let mut truncated_string_filler = filler.repeat(repetitions as usize);
+        truncated_string_filler.truncate(fill_len as usize);
 
         // 10. If placement is start, return the string-concatenation of truncatedStringFiller and S.
         if placement == Placement::Start {
-            Ok(format!("{truncated_string_filler}{string}").into())
+            Ok(JsString::concat(&truncated_string_filler, &string).into())
         } else {
             // 11. Else, return the string-concatenation of S and truncatedStringFiller.
-            Ok(format!("{string}{truncated_string_filler}").into())
+            Ok(JsString::concat(&string, &truncated_string_filler).into())
         }
     }
 
@@ -1601,7 +1543,7 @@ impl String {
     pub(crate) fn trim(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         let object = this.require_object_coercible(context)?;
         let string = object.to_string(context)?;
-        Ok(JsValue::new(string.trim_matches(is_trimmable_whitespace)))
+        Ok(JsString::from(string.trim()).into())
     }
 
     /// `String.prototype.trimStart()`
@@ -1623,9 +1565,7 @@ impl String {
     ) -> JsResult<JsValue> {
         let this = this.require_object_coercible(context)?;
         let string = this.to_string(context)?;
-        Ok(JsValue::new(
-            string.trim_start_matches(is_trimmable_whitespace),
-        ))
+        Ok(JsString::from(string.trim_start()).into())
     }
 
     /// String.prototype.trimEnd()
@@ -1647,9 +1587,7 @@ impl String {
     ) -> JsResult<JsValue> {
         let this = this.require_object_coercible(context)?;
         let string = this.to_string(context)?;
-        Ok(JsValue::new(
-            string.trim_end_matches(is_trimmable_whitespace),
-        ))
+        Ok(JsString::from(string.trim_end()).into())
     }
 
     /// `String.prototype.toLowerCase()`
@@ -1679,7 +1617,7 @@ impl String {
         // the Unicode Default Case Conversion algorithm.
         // 5. Let L be ! CodePointsToString(lowerText).
         // 6. Return L.
-        Ok(JsValue::new(string.to_lowercase()))
+        Ok(convert_code_points(&string, str::to_lowercase).into())
     }
 
     /// `String.prototype.toUpperCase()`
@@ -1716,7 +1654,7 @@ impl String {
         // the Unicode Default Case Conversion algorithm.
         // 5. Let L be ! CodePointsToString(upperText).
         // 6. Return L.
-        Ok(JsValue::new(string.to_uppercase()))
+        Ok(convert_code_points(&string, str::to_uppercase).into())
     }
 
     /// `String.prototype.substring( indexStart[, indexEnd] )`
@@ -1741,15 +1679,17 @@ impl String {
         let string = this.to_string(context)?;
 
         // 3. Let len be the length of S.
-        let len = string.encode_utf16().count() as i64;
+        let len = string.len() as i64;
 
         // 4. Let intStart be ? ToIntegerOrInfinity(start).
         let int_start = args.get_or_undefined(0).to_integer_or_infinity(context)?;
 
         // 5. If end is undefined, let intEnd be len; else let intEnd be ? ToIntegerOrInfinity(end).
-        let int_end = match args.get_or_undefined(1) {
-            &JsValue::Undefined => IntegerOrInfinity::Integer(len),
-            end => end.to_integer_or_infinity(context)?,
+        let end = args.get_or_undefined(1);
+        let int_end = if end.is_undefined() {
+            IntegerOrInfinity::Integer(len)
+        } else {
+            end.to_integer_or_infinity(context)?
         };
 
         // 6. Let finalStart be the result of clamping intStart between 0 and len.
@@ -1767,11 +1707,7 @@ impl String {
         // 10. Return the substring of S from from to to.
         // Extract the part of the string contained between the from index and the to index
         // where from is guaranteed to be smaller or equal to to
-        // TODO: Full UTF-16 support
-        let substring_utf16: Vec<u16> = string.encode_utf16().skip(from).take(to - from).collect();
-        let substring = StdString::from_utf16_lossy(&substring_utf16);
-
-        Ok(substring.into())
+        Ok(JsString::from(&string[from..to]).into())
     }
 
     /// `String.prototype.substr( start[, length] )`
@@ -1797,16 +1733,18 @@ impl String {
         let string = this.to_string(context)?;
 
         // 3. Let size be the length of S.
-        let size = string.encode_utf16().count() as i64;
+        let size = string.len() as i64;
 
         // 4. Let intStart be ? ToIntegerOrInfinity(start).
         let int_start = args.get_or_undefined(0).to_integer_or_infinity(context)?;
 
         // 7. If length is undefined, let intLength be size; otherwise let intLength be ? ToIntegerOrInfinity(length).
         // Moved it before to ensure an error throws before returning the empty string on `match int_start`
-        let int_length = match args.get_or_undefined(1) {
-            &JsValue::Undefined => IntegerOrInfinity::Integer(size),
-            val => val.to_integer_or_infinity(context)?,
+        let val = args.get_or_undefined(1);
+        let int_length = if val.is_undefined() {
+            IntegerOrInfinity::Integer(size)
+        } else {
+            val.to_integer_or_infinity(context)?
         };
 
         let int_start = match int_start {
@@ -1828,16 +1766,13 @@ impl String {
         // 9. Let intEnd be min(intStart + intLength, size).
         let int_end = min(int_start + int_length, size as usize);
 
-        // 11. Return the substring of S from intStart to intEnd.
         // 10. If intStart ≥ intEnd, return the empty String.
-        let substring_utf16: Vec<u16> = string
-            .encode_utf16()
-            .skip(int_start)
-            .take(int_end - int_start)
-            .collect();
-        let substring = StdString::from_utf16_lossy(&substring_utf16);
-
-        Ok(substring.into())
+        if int_start >= int_end {
+            return Ok("".into());
+        }
+
+        // 11. Return the substring of S from intStart to intEnd.
+        Ok(JsString::from(&string[int_start..int_end]).into())
     }
 
     /// `String.prototype.split ( separator, limit )`
@@ -1908,7 +1843,7 @@ impl String {
         }
 
         // 10. Let s be the length of S.
-        let this_str_length = this_str.encode_utf16().count();
+        let this_str_length = this_str.len();
 
         // 11. If s = 0, then
         if this_str_length == 0 {
@@ -1945,13 +1880,7 @@ impl String {
                         q += 1;
                     } else {
                         // 1. Let T be the substring of S from p to q.
-                        let this_str_substring = StdString::from_utf16_lossy(
-                            &this_str
-                                .encode_utf16()
-                                .skip(p)
-                                .take(q - p)
-                                .collect::<Vec<u16>>(),
-                        );
+                        let this_str_substring = JsString::from(&this_str[p..q]);
 
                         // 2. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
                         a.create_data_property_or_throw(length_a, this_str_substring, context)
@@ -1976,13 +1905,7 @@ impl String {
         }
 
         // 15. Let T be the substring of S from p to s.
-        let this_str_substring = StdString::from_utf16_lossy(
-            &this_str
-                .encode_utf16()
-                .skip(p)
-                .take(this_str_length - p)
-                .collect::<Vec<u16>>(),
-        );
+        let this_str_substring = JsString::from(&this_str[p..]);
 
         // 16. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
         a.create_data_property_or_throw(length_a, this_str_substring, context)
@@ -2002,13 +1925,9 @@ impl String {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.value_of
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/valueOf
-    pub(crate) fn value_of(
-        this: &JsValue,
-        _: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
+    pub(crate) fn value_of(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         // 1. Return ? thisStringValue(this value).
-        Self::this_string_value(this, context).map(JsValue::from)
+        Self::this_string_value(this).map(JsValue::from)
     }
 
     /// `String.prototype.matchAll( regexp )`
@@ -2044,10 +1963,12 @@ impl String {
                 flags.require_object_coercible(context)?;
 
                 // iii. If ? ToString(flags) does not contain "g", throw a TypeError exception.
-                if !flags.to_string(context)?.contains('g') {
-                    return context.throw_type_error(
-                        "String.prototype.matchAll called with a non-global RegExp argument",
-                    );
+                if !flags.to_string(context)?.contains(&u16::from(b'g')) {
+                    return Err(JsNativeError::typ()
+                        .with_message(
+                            "String.prototype.matchAll called with a non-global RegExp argument",
+                        )
+                        .into());
                 }
             }
             // c. Let matcher be ? GetMethod(regexp, @@matchAll).
@@ -2091,30 +2012,32 @@ impl String {
 
         let form = args.get_or_undefined(0);
 
-        let f_str;
-
         let f = if form.is_undefined() {
             // 3. If form is undefined, let f be "NFC".
-            "NFC"
+            JsString::from(utf16!("NFC"))
         } else {
             // 4. Else, let f be ? ToString(form).
-            f_str = form.to_string(context)?;
-            f_str.as_str()
+            form.to_string(context)?
         };
 
         // 6. Let ns be the String value that is the result of normalizing S
         // into the normalization form named by f as specified in
         // https://unicode.org/reports/tr15/.
         // 7. Return ns.
-        match f {
-            "NFC" => Ok(JsValue::new(s.nfc().collect::<StdString>())),
-            "NFD" => Ok(JsValue::new(s.nfd().collect::<StdString>())),
-            "NFKC" => Ok(JsValue::new(s.nfkc().collect::<StdString>())),
-            "NFKD" => Ok(JsValue::new(s.nfkd().collect::<StdString>())),
+        let normalize: fn(&str) -> StdString = match f.as_slice() {
+            f if f == utf16!("NFC") => |s| s.nfc().collect(),
+            f if f == utf16!("NFD") => |s| s.nfd().collect(),
+            f if f == utf16!("NFKC") => |s| s.nfkc().collect(),
+            f if f == utf16!("NFKD") => |s| s.nfkd().collect(),
             // 5. If f is not one of "NFC", "NFD", "NFKC", or "NFKD", throw a RangeError exception.
-            _ => context
-                .throw_range_error("The normalization form should be one of NFC, NFD, NFKC, NFKD."),
-        }
+            _ => {
+                return Err(JsNativeError::range()
+                    .with_message("The normalization form should be one of NFC, NFD, NFKC, NFKD.")
+                    .into())
+            }
+        };
+
+        Ok(convert_code_points(&s, normalize).into())
     }
 
     /// `String.prototype.search( regexp )`
@@ -2151,7 +2074,7 @@ impl String {
         let string = o.to_string(context)?;
 
         // 4. Let rx be ? RegExpCreate(regexp, undefined).
-        let rx = RegExp::create(regexp, &JsValue::Undefined, context)?;
+        let rx = RegExp::create(regexp, &JsValue::undefined(), context)?;
 
         // 5. Return ? Invoke(rx, @@search, « string »).
         rx.invoke(WellKnownSymbols::search(), &[JsValue::new(string)], context)
@@ -2164,6 +2087,81 @@ impl String {
     ) -> JsResult<JsValue> {
         StringIterator::create_string_iterator(this.clone(), context)
     }
+
+    /// `String.prototype.isWellFormed ( )`
+    ///
+    /// The `isWellFormed()` method returns a boolean indicating whether the string contains any
+    /// unpaired surrogates.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-is-usv-string/#sec-string.prototype.iswellformed
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/isWellFormed
+    pub(crate) fn is_well_formed(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let o = this.require_object_coercible(context)?;
+
+        // 2. Let S be ? ToString(O).
+        let s = o.to_string(context)?;
+
+        // 3. Return IsStringWellFormedUnicode(S).
+        Ok(s.is_well_formed().into())
+    }
+
+    /// `String.prototype.toWellFormed ( )`
+    ///
+    /// The `toWellFormed()` method returns a string where all unpaired surrogates of this string
+    /// are replaced with the Unicode replacement character U+FFFD.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-is-usv-string/#sec-string.prototype.towellformed
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/toWellFormed
+    pub(crate) fn to_well_formed(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let o = this.require_object_coercible(context)?;
+
+        // 2. Let S be ? ToString(O).
+        let s = o.to_string(context)?;
+
+        if s.is_well_formed() {
+            return Ok(s.into());
+        }
+
+        // 3. Let strLen be the length of S.
+        // 4. Let k be 0.
+        // 5. Let result be the empty String.
+        let mut result = Vec::with_capacity(s.len());
+
+        // 6. Repeat, while k < strLen,
+        //     a. Let cp be CodePointAt(S, k).
+        for code_point in s.code_points() {
+            match code_point {
+                // b. If cp.[[IsUnpairedSurrogate]] is true, then
+                //     i. Set result to the string-concatenation of result and 0xFFFD (REPLACEMENT CHARACTER).
+                CodePoint::UnpairedSurrogate(_) => result.push(0xFFFD),
+                // c. Else,
+                //     i. Set result to the string-concatenation of result and UTF16EncodeCodePoint(cp.[[CodePoint]]).
+                CodePoint::Unicode(c) => result.extend_from_slice(c.encode_utf16(&mut [0; 2])),
+            }
+            // d. Set k to k + cp.[[CodeUnitCount]].
+        }
+
+        // 7. Return result.
+        Ok(JsString::from(result).into())
+    }
 }
 
 /// Abstract operation `GetSubstitution ( matched, str, position, captures, namedCaptures, replacement )`
@@ -2173,23 +2171,36 @@ impl String {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-getsubstitution
 pub(crate) fn get_substitution(
-    matched: &str,
-    str: &str,
+    matched: &[u16],
+    str: &[u16],
     position: usize,
     captures: &[JsValue],
     named_captures: &JsValue,
-    replacement: &JsString,
+    replacement: &[u16],
     context: &mut Context,
 ) -> JsResult<JsString> {
+    /// Returns the ASCII character stored in the code unit, if any.
+    fn ascii(unit: Option<&u16>) -> Option<u8> {
+        unit.and_then(|&unit| u8::try_from(unit).ok())
+            .filter(u8::is_ascii)
+    }
+
+    /// Returns the value of the ASCII digit stored in the code unit, if any.
+    fn digit(unit: Option<&u16>) -> Option<usize> {
+        ascii(unit)
+            .filter(u8::is_ascii_digit)
+            .map(|digit| usize::from(digit - b'0'))
+    }
+
     // 1. Assert: Type(matched) is String.
 
     // 2. Let matchLength be the number of code units in matched.
-    let match_length = matched.encode_utf16().count();
+    let match_length = matched.len();
 
     // 3. Assert: Type(str) is String.
 
     // 4. Let stringLength be the number of code units in str.
-    let str_length = str.encode_utf16().count();
+    let str_length = str.len();
 
     // 5. Assert: position ≤ stringLength.
     // 6. Assert: captures is a possibly empty List of Strings.
@@ -2205,146 +2216,126 @@ pub(crate) fn get_substitution(
     //     from replacement to result while performing replacements as specified in Table 58.
     //     These $ replacements are done left-to-right, and, once such a replacement is performed,
     //     the new replacement text is not subject to further replacements.
-    let mut result = StdString::new();
-    let mut chars = replacement.chars().peekable();
-
-    while let Some(first) = chars.next() {
-        if first == '$' {
-            let second = chars.next();
-            let second_is_digit = second.as_ref().map_or(false, char::is_ascii_digit);
-            // we use peek so that it is still in the iterator if not used
-            let third = if second_is_digit { chars.peek() } else { None };
-            let third_is_digit = third.map_or(false, char::is_ascii_digit);
-
-            match (second, third) {
-                // $$
-                (Some('$'), _) => {
-                    // $
-                    result.push('$');
-                }
-                // $&
-                (Some('&'), _) => {
-                    // matched
-                    result.push_str(matched);
-                }
-                // $`
-                (Some('`'), _) => {
-                    // The replacement is the substring of str from 0 to position.
-                    result.push_str(&StdString::from_utf16_lossy(
-                        &str.encode_utf16().take(position).collect::<Vec<u16>>(),
-                    ));
+    let mut result = Vec::with_capacity(replacement.len());
+    let mut i = 0;
+
+    while i < replacement.len() {
+        let first = replacement[i];
+        i += 1;
+
+        if first != u16::from(b'$') {
+            result.push(first);
+            continue;
+        }
+
+        let second = replacement.get(i);
+
+        match (ascii(second), digit(second), digit(replacement.get(i + 1))) {
+            // $$
+            (Some(b'$'), _, _) => {
+                // $
+                result.push(u16::from(b'$'));
+                i += 1;
+            }
+            // $&
+            (Some(b'&'), _, _) => {
+                // matched
+                result.extend_from_slice(matched);
+                i += 1;
+            }
+            // $`
+            (Some(b'`'), _, _) => {
+                // The replacement is the substring of str from 0 to position.
+                result.extend_from_slice(&str[..position]);
+                i += 1;
+            }
+            // $'
+            (Some(b'\''), _, _) => {
+                // If tailPos ≥ stringLength, the replacement is the empty String.
+                // Otherwise the replacement is the substring of str from tailPos.
+                if tail_pos < str_length {
+                    result.extend_from_slice(&str[tail_pos..]);
                 }
-                // $'
-                (Some('\''), _) => {
-                    // If tailPos ≥ stringLength, the replacement is the empty String.
-                    // Otherwise the replacement is the substring of str from tailPos.
-                    if tail_pos < str_length {
-                        result.push_str(&StdString::from_utf16_lossy(
-                            &str.encode_utf16().skip(tail_pos).collect::<Vec<u16>>(),
-                        ));
+                i += 1;
+            }
+            // $nn
+            (_, Some(tens), Some(units)) => {
+                // The nnth element of captures, where nn is a two-digit decimal number in the range 01 to 99.
+                let nn = 10 * tens + units;
+
+                // If nn ≤ m and the nnth element of captures is undefined, use the empty String instead.
+                // If nn is 00 or nn > m, no replacement is done.
+                if nn == 0 || nn > m {
+                    result.extend_from_slice(&replacement[i - 1..i + 2]);
+                } else if let Some(capture) = captures.get(nn - 1) {
+                    if let Some(s) = capture.as_string() {
+                        result.extend_from_slice(s);
                     }
                 }
-                // $nn
-                (Some(second), Some(third)) if second_is_digit && third_is_digit => {
-                    // The nnth element of captures, where nn is a two-digit decimal number in the range 01 to 99.
-                    let tens = second
-                        .to_digit(10)
-                        .expect("could not convert character to digit after checking it")
-                        as usize;
-                    let units = third
-                        .to_digit(10)
-                        .expect("could not convert character to digit after checking it")
-                        as usize;
-                    let nn = 10 * tens + units;
-
-                    // If nn ≤ m and the nnth element of captures is undefined, use the empty String instead.
-                    // If nn is 00 or nn > m, no replacement is done.
-                    if nn == 0 || nn > m {
-                        result.push('$');
-                        result.push(second);
-                        result.push(*third);
-                    } else if let Some(capture) = captures.get(nn - 1) {
-                        if let Some(s) = capture.as_string() {
-                            result.push_str(s);
-                        }
-                    }
 
-                    chars.next();
-                }
-                // $n
-                (Some(second), _) if second_is_digit => {
-                    // The nth element of captures, where n is a single digit in the range 1 to 9.
-                    let n = second
-                        .to_digit(10)
-                        .expect("could not convert character to digit after checking it")
-                        as usize;
-
-                    // If n ≤ m and the nth element of captures is undefined, use the empty String instead.
-                    // If n > m, no replacement is done.
-                    if n == 0 || n > m {
-                        result.push('$');
-                        result.push(second);
-                    } else if let Some(capture) = captures.get(n - 1) {
-                        if let Some(s) = capture.as_string() {
-                            result.push_str(s);
-                        }
+                i += 2;
+            }
+            // $n
+            (_, Some(n), _) => {
+                // The nth element of captures, where n is a single digit in the range 1 to 9.
+                // If n ≤ m and the nth element of captures is undefined, use the empty String instead.
+                // If n > m, no replacement is done.
+                if n == 0 || n > m {
+                    result.extend_from_slice(&replacement[i - 1..=i]);
+                } else if let Some(capture) = captures.get(n - 1) {
+                    if let Some(s) = capture.as_string() {
+                        result.extend_from_slice(s);
                     }
                 }
-                // $<
-                (Some('<'), _) => {
-                    // 1. If namedCaptures is undefined, the replacement text is the String "$<".
-                    // 2. Else,
-                    if named_captures.is_undefined() {
-                        result.push_str("$<");
-                    } else {
-                        // a. Assert: Type(namedCaptures) is Object.
-                        let named_captures = named_captures
-                            .as_object()
-                            .expect("should be an object according to spec");
-
-                        // b. Scan until the next > U+003E (GREATER-THAN SIGN).
-                        let mut group_name = StdString::new();
-                        let mut found = false;
-                        loop {
-                            match chars.next() {
-                                Some('>') => {
-                                    found = true;
-                                    break;
-                                }
-                                Some(c) => group_name.push(c),
-                                None => break,
-                            }
-                        }
 
-                        // c. If none is found, the replacement text is the String "$<".
-                        #[allow(clippy::if_not_else)]
-                        if !found {
-                            result.push_str("$<");
-                            result.push_str(&group_name);
-                        // d. Else,
-                        } else {
-                            // i. Let groupName be the enclosed substring.
-                            // ii. Let capture be ? Get(namedCaptures, groupName).
-                            let capture = named_captures.get(group_name, context)?;
-
-                            // iii. If capture is undefined, replace the text through > with the empty String.
-                            // iv. Otherwise, replace the text through > with ? ToString(capture).
-                            if !capture.is_undefined() {
-                                result.push_str(capture.to_string(context)?.as_str());
-                            }
+                i += 1;
+            }
+            // $<
+            (Some(b'<'), _, _) => {
+                // 1. If namedCaptures is undefined, the replacement text is the String "$<".
+                // 2. Else,
+                if named_captures.is_undefined() {
+                    result.extend_from_slice(utf16!("$<"));
+                    i += 1;
+                } else {
+                    // a. Assert: Type(namedCaptures) is Object.
+                    let named_captures = named_captures
+                        .as_object()
+                        .expect("should be an object according to spec");
+
+                    // b. Scan until the next > U+003E (GREATER-THAN SIGN).
+                    let group_start = i + 1;
+                    let group_end = replacement[group_start..]
+                        .iter()
+                        .position(|&unit| unit == u16::from(b'>'))
+                        .map(|end| group_start + end);
+
+                    // c. If none is found, the replacement text is the String "$<".
+                    // d. Else,
+                    if let Some(group_end) = group_end {
+                        // i. Let groupName be the enclosed substring.
+                        let group_name = JsString::from(&replacement[group_start..group_end]);
+
+                        // ii. Let capture be ? Get(namedCaptures, groupName).
+                        let capture = named_captures.get(group_name, context)?;
+
+                        // iii. If capture is undefined, replace the text through > with the empty String.
+                        // iv. Otherwise, replace the text through > with ? ToString(capture).
+                        if !capture.is_undefined() {
+                            result.extend_from_slice(&capture.to_string(context)?);
                         }
-                    }
-                }
-                // $?, ? is none of the above
-                _ => {
-                    result.push('$');
-                    if let Some(second) = second {
-                        result.push(second);
+
+                        i = group_end + 1;
+                    } else {
+                        result.extend_from_slice(&replacement[i - 1..]);
+                        i = replacement.len();
                     }
                 }
             }
-        } else {
-            result.push(first);
+            // $?, ? is none of the above
+            _ => {
+                result.push(u16::from(b'$'));
+            }
         }
     }
 
@@ -2352,18 +2343,42 @@ pub(crate) fn get_substitution(
     Ok(result.into())
 }
 
+/// Converts the code points of a string with a Unicode algorithm, such as case conversion or
+/// normalization.
+///
+/// Unpaired surrogates are not affected by those algorithms, so they are kept as they are, and
+/// the code points between them are converted with `convert`.
+fn convert_code_points(string: &JsString, convert: fn(&str) -> StdString) -> JsString {
+    let mut result = Vec::with_capacity(string.len());
+    let mut text = StdString::new();
+
+    for code_point in string.code_points() {
+        match code_point {
+            CodePoint::Unicode(c) => text.push(c),
+            CodePoint::UnpairedSurrogate(surr) => {
+                result.extend(convert(&text).encode_utf16());
+                text.clear();
+                result.push(surr);
+            }
+        }
+    }
+    result.extend(convert(&text).encode_utf16());
+
+    result.into()
+}
+
 /// `22.1.3.21.1 SplitMatch ( S, q, R )`
 ///
 /// More information:
 ///  - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-splitmatch
-fn split_match(s_str: &str, q: usize, r_str: &str) -> Option<usize> {
+fn split_match(s_str: &[u16], q: usize, r_str: &[u16]) -> Option<usize> {
     // 1. Let r be the number of code units in R.
-    let r = r_str.encode_utf16().count();
+    let r = r_str.len();
 
     // 2. Let s be the number of code units in S.
-    let s = s_str.encode_utf16().count();
+    let s = s_str.len();
 
     // 3. If q + r > s, return not-matched.
     if q + r > s {
@@ -2373,14 +2388,8 @@ fn split_match(s_str: &str, q: usize, r_str: &str) -> Option<usize> {
     // 4. If there exists an integer i between 0 (inclusive) and r (exclusive)
     //    such that the code unit at index q + i within S is different from the code unit at index i within R,
     //    return not-matched.
-    for i in 0..r {
-        if let Some(s_char) = s_str.encode_utf16().nth(q + i) {
-            if let Some(r_char) = r_str.encode_utf16().nth(i) {
-                if s_char != r_char {
-                    return None;
-                }
-            }
-        }
+    if s_str[q..q + r] != *r_str {
+        return None;
     }
 
     // 5. Return q + r.
@@ -2395,8 +2404,8 @@ fn split_match(s_str: &str, q: usize, r_str: &str) -> Option<usize> {
 /// [spec]: https://tc39.es/ecma262/#sec-isregexp
 fn is_reg_exp(argument: &JsValue, context: &mut Context) -> JsResult<bool> {
     // 1. If Type(argument) is not Object, return false.
-    let argument = match argument {
-        JsValue::Object(o) => o,
+    let argument = match argument.variant() {
+        JsVariant::Object(o) => o,
         _ => return Ok(false),
     };
 
@@ -5,7 +5,7 @@ use crate::{
     object::{JsObject, ObjectData},
     property::PropertyDescriptor,
     symbol::WellKnownSymbols,
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue,
 };
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
@@ -43,7 +43,7 @@ impl StringIterator {
         let string_iterator = string_iterator
             .as_mut()
             .and_then(|obj| obj.as_string_iterator_mut())
-            .ok_or_else(|| context.construct_type_error("`this` is not an ArrayIterator"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not an ArrayIterator"))?;
 
         if string_iterator.string.is_undefined() {
             return Ok(create_iter_result_object(
@@ -53,7 +53,7 @@ impl StringIterator {
             ));
         }
         let native_string = string_iterator.string.to_string(context)?;
-        let len = native_string.encode_utf16().count() as i32;
+        let len = native_string.len() as i32;
         let position = string_iterator.next_index;
         if position >= len {
             string_iterator.string = JsValue::undefined();
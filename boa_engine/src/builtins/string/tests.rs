@@ -80,6 +80,25 @@ fn concat() {
     assert_eq!(b, "\"Hello, world! Have a nice day.\"");
 }
 
+#[test]
+fn concat_in_loop() {
+    let mut context = Context::default();
+    let init = r#"
+        var s = "";
+        for (let i = 0; i < 10000; i++) {
+            s += "line " + i + "\n";
+        }
+        var t = "".concat(s, "end of the output", s);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "s.length"), "98890");
+    assert_eq!(forward(&mut context, "s.slice(-10, -1)"), "\"line 9999\"");
+    assert_eq!(forward(&mut context, "s[5]"), "\"0\"");
+    assert_eq!(forward(&mut context, "t.length"), "197797");
+    assert_eq!(forward(&mut context, "t.indexOf('end')"), "98890");
+}
+
 #[test]
 fn generic_concat() {
     let mut context = Context::default();
@@ -682,13 +701,15 @@ fn split() {
         forward(&mut context, "['']")
     );
 
-    // TODO: Support keeping invalid code point in string
     assert_eq!(
         forward(
             &mut context,
             "\'\u{1d7d8}\u{1d7d9}\u{1d7da}\u{1d7db}\'.split(\'\')"
         ),
-        forward(&mut context, "['�','�','�','�','�','�','�','�']")
+        forward(
+            &mut context,
+            "['\\uD835','\\uDFD8','\\uD835','\\uDFD9','\\uD835','\\uDFDA','\\uD835','\\uDFDB']"
+        )
     );
 }
 
@@ -966,7 +987,7 @@ fn char_at() {
     assert_eq!(forward(&mut context, "'abc'.charAt(9)"), "\"\"");
     assert_eq!(forward(&mut context, "'abc'.charAt()"), "\"a\"");
     assert_eq!(forward(&mut context, "'abc'.charAt(null)"), "\"a\"");
-    assert_eq!(forward(&mut context, "'\\uDBFF'.charAt(0)"), "\"\u{FFFD}\"");
+    assert_eq!(forward(&mut context, "'\\uDBFF'.charAt(0)"), "\"\\uDBFF\"");
 }
 
 #[test]
@@ -1139,7 +1160,7 @@ fn string_get_property() {
     assert_eq!(forward(&mut context, "'abc'[2]"), "\"c\"");
     assert_eq!(forward(&mut context, "'abc'[3]"), "undefined");
     assert_eq!(forward(&mut context, "'abc'['foo']"), "undefined");
-    assert_eq!(forward(&mut context, "'😀'[0]"), "\"�\"");
+    assert_eq!(forward(&mut context, "'😀'[0]"), "\"\\uD83D\"");
 }
 
 #[test]
@@ -1152,6 +1173,72 @@ fn search() {
     assert_eq!(forward(&mut context, "'ba'.search(/a/)"), "1");
 }
 
+#[test]
+fn lone_surrogates() {
+    let mut context = Context::default();
+
+    assert_eq!(forward(&mut context, "'\\uD800'.length"), "1");
+    assert_eq!(forward(&mut context, "'\\uD800'.charCodeAt(0)"), "55296");
+    assert_eq!(forward(&mut context, "'a\\uDC00b'.codePointAt(1)"), "56320");
+    assert_eq!(
+        forward(&mut context, "'\\uD83D' + '\\uDE00' === '😀'"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "'😀'.slice(1).charCodeAt(0)"),
+        "56832"
+    );
+    assert_eq!(
+        forward(&mut context, "'\\uD800\\uD800' < '\\uD800\\uDC00'"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "'\\uDC00'.toUpperCase()"),
+        "\"\\uDC00\""
+    );
+    assert_eq!(
+        forward(&mut context, "'a\\uD800b'.toUpperCase()"),
+        "\"A\\uD800B\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "'\\u0041\\u030A\\uD800'.normalize() === '\\u00C5\\uD800'"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn is_well_formed() {
+    let mut context = Context::default();
+
+    assert_eq!(forward(&mut context, "'abc'.isWellFormed()"), "true");
+    assert_eq!(forward(&mut context, "'😀'.isWellFormed()"), "true");
+    assert_eq!(forward(&mut context, "'a\\uD800'.isWellFormed()"), "false");
+    assert_eq!(forward(&mut context, "'\\uDC00a'.isWellFormed()"), "false");
+    assert_eq!(
+        forward(&mut context, "'\\uDC00\\uD800'.isWellFormed()"),
+        "false"
+    );
+}
+
+#[test]
+fn to_well_formed() {
+    let mut context = Context::default();
+
+    assert_eq!(forward(&mut context, "'abc'.toWellFormed()"), "\"abc\"");
+    assert_eq!(forward(&mut context, "'😀'.toWellFormed()"), "\"😀\"");
+    assert_eq!(
+        forward(&mut context, "'a\\uD800b\\uDC00'.toWellFormed()"),
+        "\"a\u{FFFD}b\u{FFFD}\""
+    );
+    assert_eq!(
+        forward(&mut context, "'\\uDC00\\uD800'.toWellFormed().length"),
+        "2"
+    );
+}
+
 #[test]
 fn ut_is_leading_surrogate() {
     for cp in 0xD800..=0xDBFF {
@@ -25,7 +25,7 @@ use crate::{
     property::Attribute,
     symbol::{JsSymbol, WellKnownSymbols},
     value::JsValue,
-    Context, JsResult, JsString,
+    Context, JsNativeError, JsResult, JsString,
 };
 use boa_profiler::Profiler;
 use rustc_hash::FxHashMap;
@@ -154,6 +154,17 @@ impl BuiltIn for Symbol {
 }
 
 impl Symbol {
+    /// Returns the key of `symbol` in the `GlobalSymbolRegistry`, if it was created by
+    /// `Symbol.for`.
+    pub(crate) fn registry_key(symbol: &JsSymbol) -> Option<JsString> {
+        GLOBAL_SYMBOL_REGISTRY.with(|registry| registry.borrow().get_symbol(symbol))
+    }
+
+    /// Returns the symbol of `key` in the `GlobalSymbolRegistry`, like `Symbol.for` does.
+    pub(crate) fn registry_symbol(key: JsString) -> JsSymbol {
+        GLOBAL_SYMBOL_REGISTRY.with(|registry| registry.borrow_mut().get_or_insert_key(key))
+    }
+
     /// The amount of arguments this function object takes.
     pub(crate) const LENGTH: usize = 0;
 
@@ -175,7 +186,9 @@ impl Symbol {
     ) -> JsResult<JsValue> {
         // 1. If NewTarget is not undefined, throw a TypeError exception.
         if !new_target.is_undefined() {
-            return context.throw_type_error("Symbol is not a constructor");
+            return Err(JsNativeError::typ()
+                .with_message("Symbol is not a constructor")
+                .into());
         }
 
         // 2. If description is undefined, let descString be undefined.
@@ -189,11 +202,15 @@ impl Symbol {
         Ok(JsSymbol::new(description).into())
     }
 
-    fn this_symbol_value(value: &JsValue, context: &mut Context) -> JsResult<JsSymbol> {
+    fn this_symbol_value(value: &JsValue) -> JsResult<JsSymbol> {
         value
             .as_symbol()
             .or_else(|| value.as_object().and_then(|obj| obj.borrow().as_symbol()))
-            .ok_or_else(|| context.construct_type_error("'this' is not a Symbol"))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("'this' is not a Symbol")
+                    .into()
+            })
     }
 
     /// `Symbol.prototype.toString()`
@@ -207,13 +224,9 @@ impl Symbol {
     /// [spec]: https://tc39.es/ecma262/#sec-symbol.prototype.tostring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/toString
     #[allow(clippy::wrong_self_convention)]
-    pub(crate) fn to_string(
-        this: &JsValue,
-        _: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
+    pub(crate) fn to_string(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         // 1. Let sym be ? thisSymbolValue(this value).
-        let symbol = Self::this_symbol_value(this, context)?;
+        let symbol = Self::this_symbol_value(this)?;
 
         // 2. Return SymbolDescriptiveString(sym).
         Ok(symbol.descriptive_string().into())
@@ -229,14 +242,10 @@ impl Symbol {
     ///
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/valueOf
     /// [spec]: https://tc39.es/ecma262/#sec-symbol.prototype.valueof
-    pub(crate) fn value_of(
-        this: &JsValue,
-        _: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
+    pub(crate) fn value_of(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         // 1. Return ? thisSymbolValue(this value).
-        let symbol = Self::this_symbol_value(this, context)?;
-        Ok(JsValue::Symbol(symbol))
+        let symbol = Self::this_symbol_value(this)?;
+        Ok(JsValue::new(symbol))
     }
 
     /// `get Symbol.prototype.description`
@@ -252,9 +261,9 @@ impl Symbol {
     pub(crate) fn get_description(
         this: &JsValue,
         _: &[JsValue],
-        context: &mut Context,
+        _: &mut Context,
     ) -> JsResult<JsValue> {
-        let symbol = Self::this_symbol_value(this, context)?;
+        let symbol = Self::this_symbol_value(this)?;
         if let Some(ref description) = symbol.description() {
             Ok(description.clone().into())
         } else {
@@ -300,11 +309,7 @@ impl Symbol {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-symbol.prototype.keyfor
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/keyFor
-    pub(crate) fn key_for(
-        _: &JsValue,
-        args: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
+    pub(crate) fn key_for(_: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         let sym = args.get_or_undefined(0);
         // 1. If Type(sym) is not Symbol, throw a TypeError exception.
         if let Some(sym) = sym.as_symbol() {
@@ -319,7 +324,9 @@ impl Symbol {
 
             Ok(symbol.map(JsValue::from).unwrap_or_default())
         } else {
-            context.throw_type_error("Symbol.keyFor: sym is not a symbol")
+            Err(JsNativeError::typ()
+                .with_message("Symbol.keyFor: sym is not a symbol")
+                .into())
         }
     }
 
@@ -337,9 +344,9 @@ impl Symbol {
     pub(crate) fn to_primitive(
         this: &JsValue,
         _: &[JsValue],
-        context: &mut Context,
+        _: &mut Context,
     ) -> JsResult<JsValue> {
-        let sym = Self::this_symbol_value(this, context)?;
+        let sym = Self::this_symbol_value(this)?;
         // 1. Return ? thisSymbolValue(this value).
         Ok(sym.into())
     }
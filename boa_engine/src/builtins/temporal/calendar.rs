@@ -0,0 +1,591 @@
+//! This module implements the `Temporal.Calendar` object.
+//!
+//! Only the ISO 8601 calendar, `iso8601`, is supported.
+//!
+//! More information:
+//!  - [Temporal reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-calendar-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Calendar
+
+use super::{
+    duration::{to_temporal_duration_record, DurationRecord},
+    fields::{FieldName, TemporalFields, DATE_FIELDS},
+    getter,
+    iso::{days_in_month, days_in_year, is_leap_year, IsoDate},
+    options::{
+        get_options_object, get_temporal_unit, to_temporal_overflow, ShowCalendar, UnitGroup,
+    },
+    parser::parse_date_time,
+    plain_date::to_temporal_date,
+    Duration, PlainDate, TemporalUnit,
+};
+use crate::{
+    builtins::{Array, JsArgs},
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsFunction,
+        JsObject, ObjectData,
+    },
+    property::{Attribute, PropertyKey},
+    symbol::WellKnownSymbols,
+    Context, JsNativeError, JsResult, JsValue, JsVariant,
+};
+use boa_gc::{unsafe_empty_trace, Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// The identifier of the ISO 8601 calendar.
+const ISO8601: &str = "iso8601";
+
+/// Returns `true` if `identifier` is the identifier of a supported calendar.
+///
+/// This is `IsBuiltinCalendar`.
+fn is_builtin_calendar(identifier: &str) -> bool {
+    identifier.eq_ignore_ascii_case(ISO8601)
+}
+
+/// Throws a `RangeError` if the calendar annotation of a parsed string isn't a supported
+/// calendar.
+pub(crate) fn validate_calendar_annotation(calendar: Option<&str>) -> JsResult<()> {
+    match calendar {
+        Some(calendar) if !is_builtin_calendar(calendar) => Err(unknown_calendar(calendar)),
+        _ => Ok(()),
+    }
+}
+
+/// The `RangeError` thrown for the identifiers of unsupported calendars.
+fn unknown_calendar(identifier: &str) -> crate::JsError {
+    JsNativeError::range()
+        .with_message(format!("Temporal: unknown calendar `{identifier}`"))
+        .into()
+}
+
+/// Gets the `[[Calendar]]` internal slot of a `Temporal` object, if it has one.
+fn calendar_slot(object: &JsObject) -> Option<JsObject> {
+    let object = object.borrow();
+    if let Some(date) = object.as_plain_date() {
+        Some(date.calendar.clone())
+    } else if let Some(date_time) = object.as_plain_date_time() {
+        Some(date_time.calendar.clone())
+    } else if let Some(time) = object.as_plain_time() {
+        Some(time.calendar.clone())
+    } else {
+        object
+            .as_zoned_date_time()
+            .map(|zoned| zoned.calendar.clone())
+    }
+}
+
+/// Abstract operation `ToTemporalCalendar ( temporalCalendarLike )`
+///
+/// Returns a `Temporal.Calendar` object. Custom calendar objects are not supported.
+pub(crate) fn to_temporal_calendar(value: &JsValue, context: &mut Context) -> JsResult<JsObject> {
+    // 1. If Type(temporalCalendarLike) is Object, then
+    let value = if let Some(object) = value.as_object() {
+        if object.borrow().is_calendar() {
+            return Ok(object.clone());
+        }
+
+        // a. If temporalCalendarLike has an [[InitializedTemporalDate]], [[InitializedTemporalDateTime]],
+        //    [[InitializedTemporalMonthDay]], [[InitializedTemporalTime]], [[InitializedTemporalYearMonth]],
+        //    or [[InitializedTemporalZonedDateTime]] internal slot, then
+        //     i. Return temporalCalendarLike.[[Calendar]].
+        if let Some(calendar) = calendar_slot(object) {
+            return Ok(calendar);
+        }
+
+        // b. If ? HasProperty(temporalCalendarLike, "calendar") is false, return temporalCalendarLike.
+        // c. Set temporalCalendarLike to ? Get(temporalCalendarLike, "calendar").
+        // d. If Type(temporalCalendarLike) is Object and ? HasProperty(temporalCalendarLike, "calendar")
+        //    is false, return temporalCalendarLike.
+        if !object.has_property("calendar", context)? {
+            return Err(unsupported_calendar());
+        }
+        let value = object.get("calendar", context)?;
+        if let Some(object) = value.as_object() {
+            if object.borrow().is_calendar() {
+                return Ok(object.clone());
+            }
+            return Err(unsupported_calendar());
+        }
+        value
+    } else {
+        value.clone()
+    };
+
+    // 2. Let identifier be ? ToString(temporalCalendarLike).
+    let identifier = value.to_string(context)?.to_std_string_escaped();
+
+    // 3. If IsBuiltinCalendar(identifier) is false, then
+    if !is_builtin_calendar(&identifier) {
+        // a. Set identifier to ? ParseTemporalCalendarString(identifier).
+        let parsed = parse_date_time(&identifier).map_err(|_| unknown_calendar(&identifier))?;
+
+        // b. If IsBuiltinCalendar(identifier) is false, throw a RangeError exception.
+        validate_calendar_annotation(parsed.calendar.as_deref())?;
+    }
+
+    // 4. Return ! CreateTemporalCalendar(identifier).
+    Calendar::create(None, context)
+}
+
+/// Abstract operation `ToTemporalCalendarWithISODefault ( temporalCalendarLike )`
+pub(crate) fn to_temporal_calendar_with_iso_default(
+    value: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    // 1. If temporalCalendarLike is undefined, then
+    if value.is_undefined() {
+        // a. Return ! GetISO8601Calendar().
+        return Calendar::create(None, context);
+    }
+
+    // 2. Return ? ToTemporalCalendar(temporalCalendarLike).
+    to_temporal_calendar(value, context)
+}
+
+/// Abstract operation `GetTemporalCalendarWithISODefault ( item )`
+pub(crate) fn get_temporal_calendar_with_iso_default(
+    item: &JsObject,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    // 1. If item has an [[InitializedTemporalDate]], [[InitializedTemporalDateTime]],
+    //    [[InitializedTemporalMonthDay]], [[InitializedTemporalTime]],
+    //    [[InitializedTemporalYearMonth]], or [[InitializedTemporalZonedDateTime]] internal slot, then
+    //     a. Return item.[[Calendar]].
+    if let Some(calendar) = calendar_slot(item) {
+        return Ok(calendar);
+    }
+
+    // 2. Let calendarLike be ? Get(item, "calendar").
+    let calendar_like = item.get("calendar", context)?;
+
+    // 3. Return ? ToTemporalCalendarWithISODefault(calendarLike).
+    to_temporal_calendar_with_iso_default(&calendar_like, context)
+}
+
+/// The `TypeError` thrown for custom calendar objects.
+fn unsupported_calendar() -> crate::JsError {
+    JsNativeError::typ()
+        .with_message("Temporal: custom calendars are not supported")
+        .into()
+}
+
+/// JavaScript `Temporal.Calendar` object.
+#[derive(Debug, Clone, Copy, Finalize)]
+pub struct Calendar;
+
+// SAFETY: A calendar holds no garbage collected values.
+unsafe impl Trace for Calendar {
+    unsafe_empty_trace!();
+}
+
+impl Calendar {
+    const NAME: &'static str = "Calendar";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let mut builder = ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().calendar().clone(),
+        );
+        builder.name(Self::NAME).length(1);
+        getter(&mut builder, "id", Self::to_string);
+        builder
+            .static_method(Self::from, "from", 1)
+            .method(Self::date_from_fields, "dateFromFields", 1)
+            .method(Self::date_add, "dateAdd", 2)
+            .method(Self::date_until, "dateUntil", 2)
+            .method(Self::year, "year", 1)
+            .method(Self::month, "month", 1)
+            .method(Self::month_code, "monthCode", 1)
+            .method(Self::day, "day", 1)
+            .method(Self::day_of_week, "dayOfWeek", 1)
+            .method(Self::day_of_year, "dayOfYear", 1)
+            .method(Self::week_of_year, "weekOfYear", 1)
+            .method(Self::days_in_week, "daysInWeek", 1)
+            .method(Self::days_in_month, "daysInMonth", 1)
+            .method(Self::days_in_year, "daysInYear", 1)
+            .method(Self::months_in_year, "monthsInYear", 1)
+            .method(Self::in_leap_year, "inLeapYear", 1)
+            .method(Self::fields, "fields", 1)
+            .method(Self::merge_fields, "mergeFields", 2)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_string, "toJSON", 0)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                "Temporal.Calendar",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build()
+    }
+
+    /// Abstract operation `CreateTemporalCalendar ( identifier [ , newTarget ] )`
+    pub(crate) fn create(new_target: Option<&JsValue>, context: &mut Context) -> JsResult<JsObject> {
+        // 1-2. Let calendar be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.Calendar.prototype%", « [[InitializedTemporalCalendar]], [[Identifier]] »).
+        let prototype = match new_target {
+            Some(new_target) => {
+                get_prototype_from_constructor(new_target, StandardConstructors::calendar, context)?
+            }
+            None => context.intrinsics().constructors().calendar().prototype(),
+        };
+
+        // 3. Set calendar.[[Identifier]] to identifier.
+        // 4. Return calendar.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::calendar(Self),
+        ))
+    }
+
+    /// Checks that the `this` value is a `Temporal.Calendar`.
+    fn this_calendar(this: &JsValue, method: &str) -> JsResult<()> {
+        if this.as_object().map_or(false, |object| object.borrow().is_calendar()) {
+            return Ok(());
+        }
+        Err(JsNativeError::typ()
+            .with_message(format!(
+                "Temporal.Calendar.prototype.{method}: 'this' is not a Temporal.Calendar"
+            ))
+            .into())
+    }
+
+    /// Checks that the `this` value is a `Temporal.Calendar`, and converts the first argument to
+    /// an ISO date.
+    fn date_argument(
+        this: &JsValue,
+        args: &[JsValue],
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<IsoDate> {
+        Self::this_calendar(this, method)?;
+        Ok(to_temporal_date(args.get_or_undefined(0), None, context)?.0)
+    }
+
+    /// `Temporal.Calendar ( id )`
+    fn constructor(new_target: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        if new_target.is_undefined() {
+            // a. Throw a TypeError exception.
+            return Err(JsNativeError::typ()
+                .with_message("Temporal.Calendar: calling a builtin constructor without new is forbidden")
+                .into());
+        }
+
+        // 2. Set id to ? ToString(id).
+        let id = args.get_or_undefined(0).to_string(context)?.to_std_string_escaped();
+
+        // 3. If IsBuiltinCalendar(id) is false, then
+        if !is_builtin_calendar(&id) {
+            // a. Throw a RangeError exception.
+            return Err(unknown_calendar(&id));
+        }
+
+        // 4. Return ? CreateTemporalCalendar(id, NewTarget).
+        Ok(Self::create(Some(new_target), context)?.into())
+    }
+
+    /// `Temporal.Calendar.from ( calendarLike )`
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? ToTemporalCalendar(calendarLike).
+        Ok(to_temporal_calendar(args.get_or_undefined(0), context)?.into())
+    }
+
+    /// `Temporal.Calendar.prototype.dateFromFields ( fields [ , options ] )`
+    fn date_from_fields(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(calendar, [[InitializedTemporalCalendar]]).
+        Self::this_calendar(this, "dateFromFields")?;
+
+        // 4. If Type(fields) is not Object, throw a TypeError exception.
+        let fields = args.get_or_undefined(0).as_object().cloned().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("Temporal.Calendar.prototype.dateFromFields: fields must be an object")
+        })?;
+
+        // 5. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 6. Let result be ? ISODateFromFields(fields, options).
+        let fields = TemporalFields::prepare(&fields, DATE_FIELDS, false, context)?;
+        let overflow = to_temporal_overflow(&options, context)?;
+        let date = fields.to_date(overflow)?;
+
+        // 7. Return ? CreateTemporalDate(result.[[Year]], result.[[Month]], result.[[Day]], calendar).
+        let calendar = this.as_object().cloned().unwrap_or_else(|| unreachable!());
+        Ok(PlainDate::create(date, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.Calendar.prototype.dateAdd ( date, duration [ , options ] )`
+    fn date_add(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-4. Set date to ? ToTemporalDate(date).
+        let date = Self::date_argument(this, args, "dateAdd", context)?;
+
+        // 5. Set duration to ? ToTemporalDuration(duration).
+        let duration = to_temporal_duration_record(args.get_or_undefined(1), context)?;
+
+        // 6. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(2))?;
+
+        // 7. Let overflow be ? ToTemporalOverflow(options).
+        let overflow = to_temporal_overflow(&options, context)?;
+
+        // 8. Let balanceResult be ? BalanceDuration(duration.[[Days]], duration.[[Hours]], ..., "day").
+        let days = DurationRecord::balance_time(
+            duration.time_ns()?,
+            TemporalUnit::Day,
+        )
+        .days
+            + duration.days;
+
+        // 9. Let result be ? AddISODate(date.[[ISOYear]], date.[[ISOMonth]], date.[[ISODay]], duration.[[Years]], duration.[[Months]], duration.[[Weeks]], balanceResult.[[Days]], overflow).
+        let result = date.add(duration.years, duration.months, duration.weeks, days, overflow)?;
+
+        // 10. Return ? CreateTemporalDate(result.[[Year]], result.[[Month]], result.[[Day]], calendar).
+        let calendar = this.as_object().cloned().unwrap_or_else(|| unreachable!());
+        Ok(PlainDate::create(result, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.Calendar.prototype.dateUntil ( one, two [ , options ] )`
+    fn date_until(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-4. Set one to ? ToTemporalDate(one).
+        let one = Self::date_argument(this, args, "dateUntil", context)?;
+
+        // 5. Set two to ? ToTemporalDate(two).
+        let two = to_temporal_date(args.get_or_undefined(1), None, context)?.0;
+
+        // 6. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(2))?;
+
+        // 7. Let largestUnit be ? GetTemporalUnit(options, "largestUnit", date, "auto").
+        // 8. If largestUnit is "auto", set largestUnit to "day".
+        let largest_unit = get_temporal_unit(&options, "largestUnit", UnitGroup::Date, true, context)?
+            .unwrap_or(TemporalUnit::Day);
+
+        // 9. Let result be DifferenceISODate(one.[[ISOYear]], ..., largestUnit).
+        let result = one.difference(two, largest_unit);
+
+        // 10. Return ! CreateTemporalDuration(result.[[Years]], result.[[Months]], result.[[Weeks]], result.[[Days]], 0, 0, 0, 0, 0, 0).
+        Ok(Duration::create(result, None, context)?.into())
+    }
+
+    /// `Temporal.Calendar.prototype.year ( temporalDateLike )`
+    fn year(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::date_argument(this, args, "year", context)?.year.into())
+    }
+
+    /// `Temporal.Calendar.prototype.month ( temporalDateLike )`
+    fn month(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::date_argument(this, args, "month", context)?.month.into())
+    }
+
+    /// `Temporal.Calendar.prototype.monthCode ( temporalDateLike )`
+    fn month_code(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let date = Self::date_argument(this, args, "monthCode", context)?;
+        Ok(format!("M{:02}", date.month).into())
+    }
+
+    /// `Temporal.Calendar.prototype.day ( temporalDateLike )`
+    fn day(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::date_argument(this, args, "day", context)?.day.into())
+    }
+
+    /// `Temporal.Calendar.prototype.dayOfWeek ( temporalDateLike )`
+    fn day_of_week(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let date = Self::date_argument(this, args, "dayOfWeek", context)?;
+        Ok(date.day_of_week().into())
+    }
+
+    /// `Temporal.Calendar.prototype.dayOfYear ( temporalDateLike )`
+    fn day_of_year(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let date = Self::date_argument(this, args, "dayOfYear", context)?;
+        Ok(date.day_of_year().into())
+    }
+
+    /// `Temporal.Calendar.prototype.weekOfYear ( temporalDateLike )`
+    fn week_of_year(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let date = Self::date_argument(this, args, "weekOfYear", context)?;
+        Ok(date.week_of_year().into())
+    }
+
+    /// `Temporal.Calendar.prototype.daysInWeek ( temporalDateLike )`
+    fn days_in_week(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::date_argument(this, args, "daysInWeek", context)?;
+        Ok(7.into())
+    }
+
+    /// `Temporal.Calendar.prototype.daysInMonth ( temporalDateLike )`
+    fn days_in_month(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let date = Self::date_argument(this, args, "daysInMonth", context)?;
+        Ok(days_in_month(date.year, date.month).into())
+    }
+
+    /// `Temporal.Calendar.prototype.daysInYear ( temporalDateLike )`
+    fn days_in_year(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let date = Self::date_argument(this, args, "daysInYear", context)?;
+        Ok(days_in_year(date.year).into())
+    }
+
+    /// `Temporal.Calendar.prototype.monthsInYear ( temporalDateLike )`
+    fn months_in_year(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::date_argument(this, args, "monthsInYear", context)?;
+        Ok(12.into())
+    }
+
+    /// `Temporal.Calendar.prototype.inLeapYear ( temporalDateLike )`
+    fn in_leap_year(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let date = Self::date_argument(this, args, "inLeapYear", context)?;
+        Ok(is_leap_year(date.year).into())
+    }
+
+    /// `Temporal.Calendar.prototype.fields ( fields )`
+    fn fields(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3. Perform ? RequireInternalSlot(calendar, [[InitializedTemporalCalendar]]).
+        Self::this_calendar(this, "fields")?;
+
+        // 4. Let iteratorRecord be ? GetIterator(fields, sync).
+        let iterator = args.get_or_undefined(0).get_iterator(context, None, None)?;
+
+        // 5. Let fieldNames be a new empty List.
+        let mut field_names: Vec<JsValue> = Vec::new();
+        let mut seen = Vec::new();
+
+        // 6. Let next be true.
+        // 7. Repeat, while next is not false,
+        //     a. Set next to ? IteratorStep(iteratorRecord).
+        while let Some(next) = iterator.step(context)? {
+            // b. If next is not false, then
+            //     i. Let nextValue be ? IteratorValue(next).
+            let next_value = next.value(context)?;
+
+            // ii. If Type(nextValue) is not String, then
+            let name = if let JsVariant::String(name) = next_value.variant() {
+                name.to_std_string_escaped()
+            } else {
+                // 1. Let completion be ThrowCompletion(a newly created TypeError object).
+                // 2. Return ? IteratorClose(iteratorRecord, completion).
+                let completion = Err(JsNativeError::typ()
+                    .with_message("Temporal.Calendar.prototype.fields: field names must be strings")
+                    .into());
+                return iterator.close(completion, context);
+            };
+
+            // iii. If fieldNames contains nextValue, then
+            // iv. If nextValue is not one of "year", "month", "monthCode", "day", "hour",
+            //     "minute", "second", "millisecond", "microsecond", "nanosecond", then
+            match FieldName::from_name(&name) {
+                Some(field)
+                    if !seen.contains(&field)
+                        && !matches!(field, FieldName::Offset | FieldName::TimeZone) =>
+                {
+                    // v. Append nextValue to the end of the List fieldNames.
+                    seen.push(field);
+                    field_names.push(next_value);
+                }
+                _ => {
+                    // 1. Let completion be ThrowCompletion(a newly created RangeError object).
+                    // 2. Return ? IteratorClose(iteratorRecord, completion).
+                    let completion = Err(JsNativeError::range()
+                        .with_message(format!(
+                            "Temporal.Calendar.prototype.fields: invalid or repeated field `{name}`"
+                        ))
+                        .into());
+                    return iterator.close(completion, context);
+                }
+            }
+        }
+
+        // 8. Return CreateArrayFromList(fieldNames).
+        Ok(Array::create_array_from_list(field_names, context).into())
+    }
+
+    /// `Temporal.Calendar.prototype.mergeFields ( fields, additionalFields )`
+    fn merge_fields(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3. Perform ? RequireInternalSlot(calendar, [[InitializedTemporalCalendar]]).
+        Self::this_calendar(this, "mergeFields")?;
+
+        // 4. Set fields to ? ToObject(fields).
+        let fields = args.get_or_undefined(0).to_object(context)?;
+
+        // 5. Set additionalFields to ? ToObject(additionalFields).
+        let additional_fields = args.get_or_undefined(1).to_object(context)?;
+
+        // DefaultMergeCalendarFields ( fields, additionalFields )
+        // 1. Let merged be OrdinaryObjectCreate(%Object.prototype%).
+        let merged = JsObject::from_proto_and_data(
+            context.intrinsics().constructors().object().prototype(),
+            ObjectData::ordinary(),
+        );
+        let month = PropertyKey::from("month");
+        let month_code = PropertyKey::from("monthCode");
+
+        // 2. Let originalKeys be ? fields.[[OwnPropertyKeys]]().
+        // 3. For each element nextKey of originalKeys, do
+        for key in fields.__own_property_keys__(context)? {
+            // a. If nextKey is not "month" or "monthCode", then
+            if key != month && key != month_code {
+                // i. Let propValue be ? Get(fields, nextKey).
+                let value = fields.get(key.clone(), context)?;
+                // ii. If propValue is not undefined, then
+                if !value.is_undefined() {
+                    // 1. Perform ! CreateDataPropertyOrThrow(merged, nextKey, propValue).
+                    merged.create_data_property_or_throw(key, value, context)?;
+                }
+            }
+        }
+
+        // 4. Let newKeys be ? additionalFields.[[OwnPropertyKeys]]().
+        let new_keys = additional_fields.__own_property_keys__(context)?;
+
+        // 5. For each element nextKey of newKeys, do
+        for key in &new_keys {
+            // a. Let propValue be ? Get(additionalFields, nextKey).
+            let value = additional_fields.get(key.clone(), context)?;
+            // b. If propValue is not undefined, then
+            if !value.is_undefined() {
+                // i. Perform ! CreateDataPropertyOrThrow(merged, nextKey, propValue).
+                merged.create_data_property_or_throw(key.clone(), value, context)?;
+            }
+        }
+
+        // 6. If newKeys does not contain either "month" or "monthCode", then
+        if !new_keys.contains(&month) && !new_keys.contains(&month_code) {
+            for key in [month, month_code] {
+                // a-b. Let month be ? Get(fields, "month") and monthCode be ? Get(fields, "monthCode").
+                let value = fields.get(key.clone(), context)?;
+                // c-d. If the value is not undefined, then
+                if !value.is_undefined() {
+                    // i. Perform ! CreateDataPropertyOrThrow(merged, key, value).
+                    merged.create_data_property_or_throw(key, value, context)?;
+                }
+            }
+        }
+
+        // 7. Return merged.
+        Ok(merged.into())
+    }
+
+    /// `Temporal.Calendar.prototype.toString ( )`
+    fn to_string(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(calendar, [[InitializedTemporalCalendar]]).
+        Self::this_calendar(this, "toString")?;
+
+        // 3. Return calendar.[[Identifier]].
+        Ok(ISO8601.into())
+    }
+}
+
+
+/// Formats the calendar annotation of the `toString` methods, which is only shown for the ISO
+/// 8601 calendar if `show` is `always` or `critical`.
+///
+/// This is `MaybeFormatCalendarAnnotation`.
+pub(crate) fn format_calendar_annotation(show: ShowCalendar) -> &'static str {
+    match show {
+        ShowCalendar::Auto | ShowCalendar::Never => "",
+        ShowCalendar::Always => "[u-ca=iso8601]",
+        ShowCalendar::Critical => "[!u-ca=iso8601]",
+    }
+}
@@ -0,0 +1,1600 @@
+//! This module implements the `Temporal.Duration` object.
+//!
+//! A duration is an amount of time, made of years, months, weeks, days, hours, minutes, seconds,
+//! milliseconds, microseconds and nanoseconds. The calendar units and, in time zones with offset
+//! transitions, the days have no fixed length, so the arithmetic of durations with those units
+//! needs a starting point, the `relativeTo` option.
+//!
+//! More information:
+//!  - [Temporal reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-duration-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Duration
+
+use super::{
+    calendar::{get_temporal_calendar_with_iso_default, validate_calendar_annotation},
+    fields::{TemporalFields, ZONED_DATE_TIME_FIELDS},
+    getter,
+    iso::{is_valid_epoch_ns, out_of_range, IsoDateTime, IsoTime, NS_PER_DAY},
+    options::{
+        get_options_object, get_temporal_unit, to_seconds_string_precision,
+        to_temporal_rounding_increment, to_temporal_rounding_mode, Disambiguation, OffsetOption,
+        Overflow, Precision, RoundingMode, UnitGroup,
+    },
+    parser::{parse_date_time, parse_duration, parse_offset_string, ParsedOffset},
+    time_zone::{to_temporal_time_zone, TimeZone, TimeZoneKind},
+    to_integer_if_integral, value_of,
+    zoned_date_time::{interpret_iso_date_time_offset, OffsetBehaviour},
+    TemporalUnit,
+};
+use crate::{
+    bigint::RawBigInt,
+    builtins::JsArgs,
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsFunction,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsNativeError, JsResult, JsValue, JsVariant,
+};
+use boa_gc::{unsafe_empty_trace, Finalize, Trace};
+use boa_profiler::Profiler;
+use num_traits::FromPrimitive;
+
+/// The names of the fields of a duration, from the largest unit to the smallest.
+const FIELD_NAMES: [&str; 10] = [
+    "years",
+    "months",
+    "weeks",
+    "days",
+    "hours",
+    "minutes",
+    "seconds",
+    "milliseconds",
+    "microseconds",
+    "nanoseconds",
+];
+
+/// The indices in [`FIELD_NAMES`] of the fields in alphabetical order, which is the order in
+/// which they are read from property bags.
+const ALPHABETICAL_FIELDS: [usize; 10] = [3, 4, 8, 7, 5, 1, 9, 6, 2, 0];
+
+/// Converts an integral number of a duration field to an `i128`, throwing a `RangeError` if it's
+/// too big for the arithmetic in nanoseconds.
+fn to_i128(value: f64) -> JsResult<i128> {
+    if value.abs() < 1e24 {
+        Ok(value as i128)
+    } else {
+        Err(JsNativeError::range()
+            .with_message("Temporal: duration is out of range")
+            .into())
+    }
+}
+
+/// The fields of a duration.
+///
+/// This is both the `Duration Record` and the `Date Duration Record` of the specification.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct DurationRecord {
+    pub(crate) years: f64,
+    pub(crate) months: f64,
+    pub(crate) weeks: f64,
+    pub(crate) days: f64,
+    pub(crate) hours: f64,
+    pub(crate) minutes: f64,
+    pub(crate) seconds: f64,
+    pub(crate) milliseconds: f64,
+    pub(crate) microseconds: f64,
+    pub(crate) nanoseconds: f64,
+}
+
+impl DurationRecord {
+    /// Abstract operation `CreateDateDurationRecord ( years, months, weeks, days )`
+    pub(crate) fn from_date(years: f64, months: f64, weeks: f64, days: f64) -> Self {
+        Self {
+            years,
+            months,
+            weeks,
+            days,
+            ..Self::default()
+        }
+    }
+
+    /// Creates a duration from its fields, from the largest unit to the smallest.
+    fn from_fields(fields: [f64; 10]) -> Self {
+        Self {
+            years: fields[0],
+            months: fields[1],
+            weeks: fields[2],
+            days: fields[3],
+            hours: fields[4],
+            minutes: fields[5],
+            seconds: fields[6],
+            milliseconds: fields[7],
+            microseconds: fields[8],
+            nanoseconds: fields[9],
+        }
+    }
+
+    /// Gets the fields of the duration, from the largest unit to the smallest.
+    fn fields(&self) -> [f64; 10] {
+        [
+            self.years,
+            self.months,
+            self.weeks,
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+            self.milliseconds,
+            self.microseconds,
+            self.nanoseconds,
+        ]
+    }
+
+    /// Gets the field of `unit`.
+    fn field(&self, unit: TemporalUnit) -> f64 {
+        self.fields()[unit as usize]
+    }
+
+    /// Returns the duration with the field of `unit` set to `value`, the fields of the larger
+    /// units unchanged and the fields of the smaller units set to zero.
+    fn truncated_with(&self, unit: TemporalUnit, value: f64) -> Self {
+        let mut fields = self.fields();
+        fields[unit as usize] = value;
+        for field in &mut fields[unit as usize + 1..] {
+            *field = 0.0;
+        }
+        Self::from_fields(fields)
+    }
+
+    /// Gets the years, months, weeks and days of the duration.
+    pub(crate) fn date(&self) -> Self {
+        Self::from_date(self.years, self.months, self.weeks, self.days)
+    }
+
+    /// Returns `true` if the duration has years, months or weeks.
+    fn has_calendar_units(&self) -> bool {
+        self.years != 0.0 || self.months != 0.0 || self.weeks != 0.0
+    }
+
+    /// Abstract operation `DurationSign ( years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds )`
+    pub(crate) fn sign(&self) -> i32 {
+        // 1. For each value v of « years, months, ..., nanoseconds », do
+        for v in self.fields() {
+            // a. If v < 0, return -1.
+            if v < 0.0 {
+                return -1;
+            }
+            // b. If v > 0, return 1.
+            if v > 0.0 {
+                return 1;
+            }
+        }
+        // 2. Return 0.
+        0
+    }
+
+    /// Abstract operation `IsValidDuration ( years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds )`
+    pub(crate) fn is_valid(&self) -> bool {
+        // 1. Let sign be ! DurationSign(years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds).
+        let sign = self.sign();
+
+        // 2. For each value v of « years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds », do
+        self.fields().iter().all(|v| {
+            // a. If 𝔽(v) is not finite, return false.
+            // b. If v < 0 and sign > 0, return false.
+            // c. If v > 0 and sign < 0, return false.
+            v.is_finite() && !(*v < 0.0 && sign > 0) && !(*v > 0.0 && sign < 0)
+        })
+        // 3. Return true.
+    }
+
+    /// Abstract operation `CreateNegatedTemporalDuration ( duration )`, without creating the
+    /// object.
+    pub(crate) fn negated(&self) -> Self {
+        Self::from_fields(self.fields().map(|v| 0.0 - v))
+    }
+
+    /// Gets the absolute value of the duration.
+    fn abs(&self) -> Self {
+        Self::from_fields(self.fields().map(f64::abs))
+    }
+
+    /// Gets the hours, minutes, seconds, milliseconds, microseconds and nanoseconds of the
+    /// duration in nanoseconds.
+    pub(crate) fn time_ns(&self) -> JsResult<i128> {
+        Ok(to_i128(self.hours)? * 3_600_000_000_000
+            + to_i128(self.minutes)? * 60_000_000_000
+            + to_i128(self.seconds)? * 1_000_000_000
+            + to_i128(self.milliseconds)? * 1_000_000
+            + to_i128(self.microseconds)? * 1_000
+            + to_i128(self.nanoseconds)?)
+    }
+
+    /// Gets the days and the time of the duration in nanoseconds, taking days as 24 hours.
+    fn day_time_ns(&self) -> JsResult<i128> {
+        Ok(to_i128(self.days)? * NS_PER_DAY + self.time_ns()?)
+    }
+
+    /// Abstract operation `BalanceDuration ( days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds, largestUnit )`
+    ///
+    /// Balances `ns` nanoseconds up to `largest_unit`, taking days as 24 hours. The calendar
+    /// units are never used, so any `largest_unit` larger than a day balances up to days.
+    pub(crate) fn balance_time(ns: i128, largest_unit: TemporalUnit) -> Self {
+        let largest_unit = largest_unit.max(TemporalUnit::Day);
+        let mut fields = [0.0; 10];
+        let mut rest = ns;
+        for unit in TemporalUnit::ALL {
+            let length = if let Some(length) = unit.nanoseconds() {
+                length
+            } else {
+                continue;
+            };
+            // Skip the units larger than `largest_unit`.
+            if unit < largest_unit {
+                continue;
+            }
+            fields[unit as usize] = (rest / length) as f64 + 0.0;
+            rest %= length;
+        }
+        Self::from_fields(fields)
+    }
+
+    /// Abstract operation `DefaultTemporalLargestUnit ( years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds )`
+    pub(crate) fn default_largest_unit(&self) -> TemporalUnit {
+        TemporalUnit::ALL
+            .into_iter()
+            .zip(self.fields())
+            .find(|(_, v)| *v != 0.0)
+            .map_or(TemporalUnit::Nanosecond, |(unit, _)| unit)
+    }
+}
+
+/// The starting point of the arithmetic of a duration, given by the `relativeTo` option or by
+/// the object that calls `until` or `since`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum RelativeTo {
+    /// A local date and time, where every day is 24 hours.
+    Plain(IsoDateTime),
+    /// An exact time in a time zone.
+    Zoned {
+        epoch_ns: i128,
+        time_zone: TimeZoneKind,
+    },
+}
+
+impl RelativeTo {
+    /// Gets the position of the starting point on the time line, in nanoseconds since the epoch.
+    ///
+    /// The positions of plain dates and times are local, as if they were in UTC.
+    pub(crate) fn position(&self) -> i128 {
+        match self {
+            Self::Plain(date_time) => date_time.to_local_ns(),
+            Self::Zoned { epoch_ns, .. } => *epoch_ns,
+        }
+    }
+
+    /// Gets the starting point of the same kind at `position`.
+    fn at(&self, position: i128) -> Self {
+        match self {
+            Self::Plain(_) => Self::Plain(IsoDateTime::from_local_ns(position)),
+            Self::Zoned { time_zone, .. } => Self::Zoned {
+                epoch_ns: position,
+                time_zone: *time_zone,
+            },
+        }
+    }
+
+    /// Adds `duration` to the starting point, returning the position of the result.
+    ///
+    /// This is `AddDateTime` or `AddZonedDateTime`.
+    pub(crate) fn add(&self, duration: &DurationRecord, overflow: Overflow) -> JsResult<i128> {
+        match self {
+            Self::Plain(date_time) => Ok(date_time.add(duration, overflow)?.to_local_ns()),
+            Self::Zoned {
+                epoch_ns,
+                time_zone,
+            } => {
+                // 3. If all of years, months, weeks, and days are 0, then
+                let result = if duration.date() == DurationRecord::default() {
+                    // a. Return ? AddInstant(epochNanoseconds, hours, minutes, seconds, milliseconds, microseconds, nanoseconds).
+                    epoch_ns + duration.time_ns()?
+                } else {
+                    // 4. Let instant be ! CreateTemporalInstant(epochNanoseconds).
+                    // 5. Let temporalDateTime be ? BuiltinTimeZoneGetPlainDateTimeFor(timeZone, instant, calendar).
+                    let date_time = time_zone.plain_date_time_for(*epoch_ns);
+
+                    // 6-8. Let addedDate be ? CalendarDateAdd(calendar, datePart, dateDuration, options).
+                    let date = date_time.date.add(
+                        duration.years,
+                        duration.months,
+                        duration.weeks,
+                        duration.days,
+                        overflow,
+                    )?;
+
+                    // 9. Let intermediateDateTime be ? CreateTemporalDateTime(addedDate.[[ISOYear]], ..., temporalDateTime.[[ISONanosecond]], calendar).
+                    // 10. Let intermediateInstant be ? BuiltinTimeZoneGetInstantFor(timeZone, intermediateDateTime, "compatible").
+                    let intermediate = time_zone.instant_for(
+                        IsoDateTime {
+                            date,
+                            time: date_time.time,
+                        },
+                        Disambiguation::Compatible,
+                    )?;
+
+                    // 11. Return ? AddInstant(intermediateInstant.[[Nanoseconds]], hours, minutes, seconds, milliseconds, microseconds, nanoseconds).
+                    intermediate + duration.time_ns()?
+                };
+
+                if !is_valid_epoch_ns(result) {
+                    return out_of_range();
+                }
+                Ok(result)
+            }
+        }
+    }
+
+    /// Gets the duration from the starting point to the position `end`, balanced up to
+    /// `largest_unit`.
+    ///
+    /// This is `DifferenceISODateTime` or `DifferenceZonedDateTime`.
+    pub(crate) fn difference(&self, end: i128, largest_unit: TemporalUnit) -> JsResult<DurationRecord> {
+        let start = self.position();
+        match self {
+            Self::Plain(date_time) => {
+                Ok(date_time.difference(IsoDateTime::from_local_ns(end), largest_unit))
+            }
+            Self::Zoned { time_zone, .. } => {
+                // 1. If ns1 is ns2, then
+                if start == end {
+                    // a. Return ! CreateDurationRecord(0, 0, 0, 0, 0, 0, 0, 0, 0, 0).
+                    return Ok(DurationRecord::default());
+                }
+
+                // The time units are exact.
+                if !largest_unit.is_date_unit() {
+                    return Ok(DurationRecord::balance_time(end - start, largest_unit));
+                }
+
+                // 2-5. Let startDateTime and endDateTime be the local dates and times of ns1 and ns2.
+                let start_date_time = time_zone.plain_date_time_for(start);
+                let end_date_time = time_zone.plain_date_time_for(end);
+
+                // 6. Let dateDifference be ? DifferenceISODateTime(startDateTime, endDateTime, calendar, largestUnit, options).
+                let date_difference = start_date_time.difference(end_date_time, largest_unit);
+
+                // 7. Let intermediateNs be ? AddZonedDateTime(startInstant, timeZone, calendar, dateDifference.[[Years]], dateDifference.[[Months]], dateDifference.[[Weeks]], 0, 0, 0, 0, 0, 0, 0).
+                let intermediate = self.add(
+                    &DurationRecord::from_date(
+                        date_difference.years,
+                        date_difference.months,
+                        date_difference.weeks,
+                        0.0,
+                    ),
+                    Overflow::Constrain,
+                )?;
+
+                // 8. Let timeRemainderNs be ns2 - intermediateNs.
+                // 9. Let intermediate be ! CreateTemporalZonedDateTime(intermediateNs, timeZone, calendar).
+                // 10. Let result be ? NanosecondsToDays(timeRemainderNs, intermediate).
+                let (days, ns, _) = self.at(intermediate).nanoseconds_to_days(end - intermediate)?;
+
+                // 11. Let timeDifference be ! BalanceDuration(0, 0, 0, 0, 0, 0, result.[[Nanoseconds]], "hour").
+                let time_difference = DurationRecord::balance_time(ns, TemporalUnit::Hour);
+
+                // 12. Return ! CreateDurationRecord(dateDifference.[[Years]], dateDifference.[[Months]], dateDifference.[[Weeks]], result.[[Days]], timeDifference.[[Hours]], ...).
+                Ok(DurationRecord {
+                    years: date_difference.years,
+                    months: date_difference.months,
+                    weeks: date_difference.weeks,
+                    days: days as f64,
+                    ..time_difference
+                })
+            }
+        }
+    }
+
+    /// Abstract operation `NanosecondsToDays ( nanoseconds, relativeTo )`
+    ///
+    /// Returns the whole days in `ns` nanoseconds after the starting point, the remaining
+    /// nanoseconds, and the length of the next day in nanoseconds.
+    fn nanoseconds_to_days(&self, ns: i128) -> JsResult<(i128, i128, i128)> {
+        let time_zone = match self {
+            Self::Zoned { time_zone, .. } => *time_zone,
+            Self::Plain(_) => {
+                return Ok((ns / NS_PER_DAY, ns % NS_PER_DAY, NS_PER_DAY));
+            }
+        };
+
+        // 1-3. Let sign be ! ℝ(Sign(𝔽(nanoseconds))).
+        let sign = ns.signum();
+
+        // 4. If sign = 0, then
+        if sign == 0 {
+            // a. Return the Record { [[Days]]: 0, [[Nanoseconds]]: 0, [[DayLength]]: dayLengthNs }.
+            return Ok((0, 0, NS_PER_DAY));
+        }
+
+        // 7. Let startNs be ℝ(relativeTo.[[Nanoseconds]]).
+        let start_ns = self.position();
+
+        // 9. Let endNs be startNs + nanoseconds.
+        let end_ns = start_ns + ns;
+        if !is_valid_epoch_ns(end_ns) {
+            return out_of_range();
+        }
+
+        // 10-12. Let startDateTime and endDateTime be the local dates and times of startNs and endNs.
+        let start_date_time = time_zone.plain_date_time_for(start_ns);
+        let end_date_time = time_zone.plain_date_time_for(end_ns);
+
+        // 13. Let dateDifference be ? DifferenceISODateTime(startDateTime, endDateTime, relativeTo.[[Calendar]], "day", OrdinaryObjectCreate(null)).
+        // 14. Let days be dateDifference.[[Days]].
+        let mut days = start_date_time
+            .difference(end_date_time, TemporalUnit::Day)
+            .days as i128;
+
+        let add_days = |days: i128| {
+            self.add(
+                &DurationRecord::from_date(0.0, 0.0, 0.0, days as f64),
+                Overflow::Constrain,
+            )
+        };
+
+        // 15. Let intermediateNs be ℝ(? AddZonedDateTime(startInstant, relativeTo.[[TimeZone]], relativeTo.[[Calendar]], 0, 0, 0, days, 0, 0, 0, 0, 0, 0)).
+        let mut intermediate_ns = add_days(days)?;
+
+        // 16. If sign is 1, then
+        if sign == 1 {
+            // a. Repeat, while days > 0 and intermediateNs > endNs,
+            while days > 0 && intermediate_ns > end_ns {
+                // i. Set days to days - 1.
+                days -= 1;
+                // ii. Set intermediateNs to ℝ(? AddZonedDateTime(startInstant, relativeTo.[[TimeZone]], relativeTo.[[Calendar]], 0, 0, 0, days, 0, 0, 0, 0, 0, 0)).
+                intermediate_ns = add_days(days)?;
+            }
+        }
+
+        // 17. Set nanoseconds to endNs - intermediateNs.
+        let mut ns = end_ns - intermediate_ns;
+
+        // 18. Let done be false.
+        // 19. Repeat, while done is false,
+        loop {
+            // a. Let oneDayFartherNs be ℝ(? AddZonedDateTime(ℤ(intermediateNs), relativeTo.[[TimeZone]], relativeTo.[[Calendar]], 0, 0, 0, sign, 0, 0, 0, 0, 0, 0)).
+            let one_day_farther_ns = self.at(intermediate_ns).add(
+                &DurationRecord::from_date(0.0, 0.0, 0.0, sign as f64),
+                Overflow::Constrain,
+            )?;
+
+            // b. Set dayLengthNs to oneDayFartherNs - intermediateNs.
+            let day_length_ns = one_day_farther_ns - intermediate_ns;
+
+            // c. If (nanoseconds - dayLengthNs) × sign ≥ 0, then
+            if (ns - day_length_ns) * sign >= 0 {
+                // i. Set nanoseconds to nanoseconds - dayLengthNs.
+                ns -= day_length_ns;
+                // ii. Set intermediateNs to oneDayFartherNs.
+                intermediate_ns = one_day_farther_ns;
+                // iii. Set days to days + sign.
+                days += sign;
+            } else {
+                // d. Else,
+                //     i. Set done to true.
+                // 20. Return the Record { [[Days]]: days, [[Nanoseconds]]: nanoseconds, [[DayLength]]: abs(dayLengthNs) }.
+                return Ok((days, ns, day_length_ns.abs()));
+            }
+        }
+    }
+
+    /// Gets the duration from the starting point to the position `end`, balanced up to
+    /// `largest_unit` and rounded to `increment` times `smallest_unit` with `mode`.
+    ///
+    /// This is the difference followed by `RoundDuration`, `AdjustRoundedDurationDays` and
+    /// `BalanceDurationRelative`, done exactly on the positions of the rounded endpoints.
+    pub(crate) fn round(
+        &self,
+        end: i128,
+        largest_unit: TemporalUnit,
+        smallest_unit: TemporalUnit,
+        increment: f64,
+        mode: RoundingMode,
+    ) -> JsResult<DurationRecord> {
+        let start = self.position();
+        let sign = (end - start).signum();
+        if sign == 0 {
+            return Ok(DurationRecord::default());
+        }
+        let increment_i = increment as i128;
+
+        // With only time units the duration is exact.
+        if !largest_unit.is_date_unit() {
+            let unit_ns = smallest_unit.nanoseconds().unwrap_or(1);
+            let rounded = mode.round(end - start, increment_i * unit_ns);
+            return Ok(DurationRecord::balance_time(rounded, largest_unit));
+        }
+
+        let difference = self.difference(end, largest_unit)?;
+
+        let result = if let Some(unit_ns) = smallest_unit
+            .nanoseconds()
+            .filter(|_| smallest_unit != TemporalUnit::Day)
+        {
+            // Rounding to a time unit only changes the days and the time.
+            let time_ns = difference.time_ns()?;
+            let (days, rounded) = match self {
+                Self::Plain(_) => {
+                    let rounded = mode.round(difference.day_time_ns()?, increment_i * unit_ns);
+                    (rounded / NS_PER_DAY, rounded % NS_PER_DAY)
+                }
+                Self::Zoned { .. } => {
+                    // AdjustRoundedDurationDays: the rounded time can't be longer than its day.
+                    let days = to_i128(difference.days)?;
+                    let rounded = mode.round(time_ns, increment_i * unit_ns);
+                    let direction = rounded.signum();
+                    if direction == 0 {
+                        (days, rounded)
+                    } else {
+                        let day_start = self.add(&difference.date(), Overflow::Constrain)?;
+                        let day_end = self.at(day_start).add(
+                            &DurationRecord::from_date(0.0, 0.0, 0.0, direction as f64),
+                            Overflow::Constrain,
+                        )?;
+                        let day_length = day_end - day_start;
+                        if (rounded - day_length) * direction < 0 {
+                            (days, rounded)
+                        } else {
+                            (
+                                days + direction,
+                                mode.round(rounded - day_length, increment_i * unit_ns),
+                            )
+                        }
+                    }
+                }
+            };
+            DurationRecord {
+                years: difference.years,
+                months: difference.months,
+                weeks: difference.weeks,
+                days: days as f64 + 0.0,
+                ..DurationRecord::balance_time(rounded, TemporalUnit::Hour)
+            }
+        } else {
+            // Rounding to a date unit: find the endpoints of the rounding interval, and round
+            // the position of `end` between them.
+            let larger = if smallest_unit == TemporalUnit::Year {
+                DurationRecord::default()
+            } else {
+                let mut fields = difference.fields();
+                for field in &mut fields[smallest_unit as usize..] {
+                    *field = 0.0;
+                }
+                DurationRecord::from_fields(fields)
+            };
+            let mid = self.at(self.add(&larger, Overflow::Constrain)?);
+            let value = mid.difference(end, smallest_unit)?.field(smallest_unit);
+            let truncated = (value / increment).trunc() * increment;
+            let zero = DurationRecord::default();
+            let p0 = mid.add(&zero.truncated_with(smallest_unit, truncated), Overflow::Constrain)?;
+            let p1 = mid.add(
+                &zero.truncated_with(smallest_unit, truncated + sign as f64 * increment),
+                Overflow::Constrain,
+            )?;
+            let numerator = (end - p0).abs();
+            let denominator = (p1 - p0).abs();
+            let rounded = if denominator == 0 {
+                truncated
+            } else {
+                let scaled = to_i128(truncated)? * denominator + sign * increment_i * numerator;
+                (mode.round(scaled, increment_i * denominator) / denominator) as f64 + 0.0
+            };
+            larger.truncated_with(smallest_unit, rounded)
+        };
+
+        self.bubble(result, largest_unit, smallest_unit)
+    }
+
+    /// Carries the rounded `duration` over to the larger units up to `largest_unit`, if it
+    /// reaches their next value.
+    fn bubble(
+        &self,
+        duration: DurationRecord,
+        largest_unit: TemporalUnit,
+        smallest_unit: TemporalUnit,
+    ) -> JsResult<DurationRecord> {
+        let sign = duration.sign();
+        if sign == 0 {
+            return Ok(duration);
+        }
+        let end = self.add(&duration, Overflow::Constrain)?;
+
+        let mut result = duration;
+        for unit in [TemporalUnit::Week, TemporalUnit::Month, TemporalUnit::Year] {
+            if unit >= smallest_unit || unit < largest_unit {
+                continue;
+            }
+            if unit == TemporalUnit::Week && largest_unit != TemporalUnit::Week {
+                continue;
+            }
+            let candidate = result.truncated_with(unit, result.field(unit) + f64::from(sign));
+            let candidate_end = self.add(&candidate, Overflow::Constrain)?;
+            if (end - candidate_end) * i128::from(sign) >= 0 {
+                result = candidate;
+            } else {
+                break;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Gets the length of the duration from the starting point to the position `end` in
+    /// `unit`s, with a fractional part.
+    ///
+    /// This is the `[[Total]]` of `RoundDuration`.
+    pub(crate) fn total(&self, end: i128, unit: TemporalUnit) -> JsResult<f64> {
+        let start = self.position();
+        let sign = (end - start).signum();
+        if sign == 0 {
+            return Ok(0.0);
+        }
+
+        if !unit.is_date_unit() {
+            let unit_ns = unit.nanoseconds().unwrap_or(1);
+            return Ok(ns_total(end - start, unit_ns));
+        }
+
+        let whole = self.difference(end, unit)?.field(unit);
+        let zero = DurationRecord::default();
+        let p0 = self.add(&zero.truncated_with(unit, whole), Overflow::Constrain)?;
+        let p1 = self.add(
+            &zero.truncated_with(unit, whole + sign as f64),
+            Overflow::Constrain,
+        )?;
+        let fraction = (end - p0).abs() as f64 / (p1 - p0).abs() as f64;
+        Ok(whole + sign as f64 * fraction)
+    }
+}
+
+/// Divides `ns` by `unit_ns`, keeping the integral part exact.
+fn ns_total(ns: i128, unit_ns: i128) -> f64 {
+    (ns / unit_ns) as f64 + (ns % unit_ns) as f64 / unit_ns as f64
+}
+
+/// Abstract operation `ToRelativeTemporalObject ( options )`
+///
+/// More information:
+///  - [Temporal reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-torelativetemporalobject
+pub(crate) fn to_relative_temporal_object(
+    options: &JsObject,
+    context: &mut Context,
+) -> JsResult<Option<RelativeTo>> {
+    // 2. Let value be ? Get(options, "relativeTo").
+    let value = options.get("relativeTo", context)?;
+
+    // 3. If value is undefined, then
+    //     a. Return value.
+    if value.is_undefined() {
+        return Ok(None);
+    }
+
+    // 4. Let offsetBehaviour be option.
+    // 5. Let matchBehaviour be match exactly.
+    let mut match_minutes = false;
+
+    let (date_time, offset_behaviour, offset_ns, time_zone) = if let Some(object) = value.as_object()
+    {
+        // 6. If Type(value) is Object, then
+        {
+            let borrowed = object.borrow();
+            // a. If value has an [[InitializedTemporalZonedDateTime]] internal slot, then
+            //     i. Return value.
+            if let Some(zoned) = borrowed.as_zoned_date_time() {
+                return Ok(Some(RelativeTo::Zoned {
+                    epoch_ns: zoned.nanoseconds,
+                    time_zone: TimeZone::kind_of(&zoned.time_zone),
+                }));
+            }
+            // b. If value has an [[InitializedTemporalDate]] internal slot, then
+            //     i. Return value.
+            if let Some(date) = borrowed.as_plain_date() {
+                return Ok(Some(RelativeTo::Plain(IsoDateTime {
+                    date: date.iso,
+                    time: IsoTime::MIDNIGHT,
+                })));
+            }
+            // c. If value has an [[InitializedTemporalDateTime]] internal slot, then
+            //     i. Return ! CreateTemporalDate(value.[[ISOYear]], value.[[ISOMonth]], value.[[ISODay]], value.[[Calendar]]).
+            if let Some(date_time) = borrowed.as_plain_date_time() {
+                return Ok(Some(RelativeTo::Plain(IsoDateTime {
+                    date: date_time.iso.date,
+                    time: IsoTime::MIDNIGHT,
+                })));
+            }
+        }
+
+        // d. Let calendar be ? GetTemporalCalendarWithISODefault(value).
+        get_temporal_calendar_with_iso_default(object, context)?;
+
+        // e-f. Let fields be ? PrepareTemporalFields(value, fieldNames, «»).
+        let fields = TemporalFields::prepare(object, ZONED_DATE_TIME_FIELDS, false, context)?;
+
+        // g-h. Let result be ? InterpretTemporalDateTimeFields(calendar, fields, dateOptions).
+        let date_time = fields.to_date_time(Overflow::Constrain)?;
+
+        // i. Let offsetString be ! Get(fields, "offset").
+        // j. Let timeZone be ! Get(fields, "timeZone").
+        // k. If timeZone is not undefined, then
+        //     i. Set timeZone to ? ToTemporalTimeZone(timeZone).
+        let time_zone = match &fields.time_zone {
+            Some(time_zone) => Some(TimeZone::kind_of(&to_temporal_time_zone(time_zone, context)?)),
+            None => None,
+        };
+
+        // l. If offsetString is undefined, then
+        //     i. Set offsetBehaviour to wall.
+        let (offset_behaviour, offset_ns) = match &fields.offset {
+            None => (OffsetBehaviour::Wall, 0),
+            Some(offset) => match parse_offset_string(offset) {
+                Some(offset_ns) => (OffsetBehaviour::Option, offset_ns),
+                None => {
+                    return Err(JsNativeError::range()
+                        .with_message(format!("Temporal: invalid offset `{offset}`"))
+                        .into())
+                }
+            },
+        };
+
+        (date_time, offset_behaviour, offset_ns, time_zone)
+    } else {
+        // 7. Else,
+        //     a. Let string be ? ToString(value).
+        let string = match value.variant() {
+            JsVariant::String(string) => string.to_std_string_escaped(),
+            _ => {
+                return Err(JsNativeError::typ()
+                    .with_message("Temporal: relativeTo must be an object or a string")
+                    .into())
+            }
+        };
+
+        //     b. Let result be ? ParseTemporalRelativeToString(string).
+        let parsed = parse_date_time(&string)?;
+        validate_calendar_annotation(parsed.calendar.as_deref())?;
+        let date_time = IsoDateTime {
+            date: parsed.date,
+            time: parsed.time.unwrap_or_default(),
+        };
+
+        //     e. If result.[[TimeZone]] is not undefined, then
+        let time_zone = if let Some(identifier) = &parsed.time_zone {
+            Some(TimeZone::kind_of(&to_temporal_time_zone(
+                &identifier.as_str().into(),
+                context,
+            )?))
+        } else {
+            // A UTC designator needs a time zone to be relative to.
+            if parsed.offset == Some(ParsedOffset::Utc) {
+                return Err(JsNativeError::range()
+                    .with_message("Temporal: a `Z` relativeTo string must have a time zone annotation")
+                    .into());
+            }
+            None
+        };
+
+        //         ii. If result.[[TimeZone]].[[Z]] is true, then
+        //             1. Set offsetBehaviour to exact.
+        //         iii. Else if offsetString is undefined, then
+        //             1. Set offsetBehaviour to wall.
+        //         iv. Set matchBehaviour to match minutes.
+        let (offset_behaviour, offset_ns) = match parsed.offset {
+            Some(ParsedOffset::Utc) => (OffsetBehaviour::Exact, 0),
+            Some(ParsedOffset::Numeric { ns, sub_minute }) => {
+                match_minutes = !sub_minute;
+                (OffsetBehaviour::Option, ns)
+            }
+            None => (OffsetBehaviour::Wall, 0),
+        };
+
+        (date_time, offset_behaviour, offset_ns, time_zone)
+    };
+
+    // 8. If timeZone is undefined, then
+    //     a. Return ? CreateTemporalDate(result.[[Year]], result.[[Month]], result.[[Day]], calendar).
+    let time_zone = if let Some(time_zone) = time_zone {
+        time_zone
+    } else {
+        if !date_time.date.is_within_limits() {
+            return out_of_range();
+        }
+        return Ok(Some(RelativeTo::Plain(IsoDateTime {
+            date: date_time.date,
+            time: IsoTime::MIDNIGHT,
+        })));
+    };
+
+    // 9. If offsetBehaviour is option, then
+    //     a. Let offsetNs be ? ParseTimeZoneOffsetString(offsetString).
+    // 10. Else,
+    //     a. Let offsetNs be 0.
+    // 11. Let epochNanoseconds be ? InterpretISODateTimeOffset(result.[[Year]], ..., offsetBehaviour, offsetNs, timeZone, "compatible", "reject", matchBehaviour).
+    let epoch_ns = interpret_iso_date_time_offset(
+        date_time,
+        offset_behaviour,
+        i128::from(offset_ns),
+        time_zone,
+        Disambiguation::Compatible,
+        OffsetOption::Reject,
+        match_minutes,
+    )?;
+
+    // 12. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+    Ok(Some(RelativeTo::Zoned {
+        epoch_ns,
+        time_zone,
+    }))
+}
+
+/// Abstract operation `ToTemporalDurationRecord ( temporalDurationLike )`
+///
+/// More information:
+///  - [Temporal reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporaldurationrecord
+pub(crate) fn to_temporal_duration_record(
+    value: &JsValue,
+    context: &mut Context,
+) -> JsResult<DurationRecord> {
+    let object = match value.variant() {
+        // 1. If Type(temporalDurationLike) is not Object, then
+        //     a. Let string be ? ToString(temporalDurationLike).
+        //     b. Return ? ParseTemporalDurationString(string).
+        JsVariant::String(string) => return parse_duration(&string.to_std_string_escaped()),
+        JsVariant::Object(object) => object,
+        _ => {
+            return Err(JsNativeError::typ()
+                .with_message("Temporal: expected a Temporal.Duration, an object or a string")
+                .into())
+        }
+    };
+
+    // 2. If temporalDurationLike has an [[InitializedTemporalDuration]] internal slot, then
+    if let Some(duration) = object.borrow().as_duration() {
+        // a. Return ! CreateDurationRecord(temporalDurationLike.[[Years]], ...).
+        return Ok(duration.record);
+    }
+
+    // 3-4. Let partial be ? ToTemporalPartialDurationRecord(temporalDurationLike).
+    let partial = to_temporal_partial_duration_record(object, context)?;
+
+    // 5. For each row of Table 8, except the header row, in table order, do
+    //     a. If partial's field is not undefined, set result's field to it, or else to 0.
+    let result = DurationRecord::from_fields(partial.map(Option::unwrap_or_default));
+
+    // 6. If ! IsValidDuration(result.[[Years]], ...) is false, then
+    if !result.is_valid() {
+        // a. Throw a RangeError exception.
+        return Err(JsNativeError::range()
+            .with_message("Temporal: invalid duration")
+            .into());
+    }
+
+    // 7. Return result.
+    Ok(result)
+}
+
+/// Abstract operation `ToTemporalPartialDurationRecord ( temporalDurationLike )`
+///
+/// Returns the fields from the largest unit to the smallest.
+fn to_temporal_partial_duration_record(
+    object: &JsObject,
+    context: &mut Context,
+) -> JsResult<[Option<f64>; 10]> {
+    // 2. Let result be a new partial Duration Record with each field set to undefined.
+    let mut result = [None; 10];
+
+    // 3. Let any be false.
+    let mut any = false;
+
+    // 4. For each row of Table 8, except the header row, in table order, do
+    for index in ALPHABETICAL_FIELDS {
+        // c. Let value be ? Get(temporalDurationLike, property).
+        let value = object.get(FIELD_NAMES[index], context)?;
+
+        // d. If value is not undefined, then
+        if !value.is_undefined() {
+            // i. Set any to true.
+            any = true;
+            // ii. Set value to ? ToIntegerIfIntegral(value).
+            // iii. Set result's field whose name is the Field Name value of the current row to value.
+            result[index] = Some(to_integer_if_integral(&value, context)?);
+        }
+    }
+
+    // 5. If any is false, then
+    if !any {
+        // a. Throw a TypeError exception.
+        return Err(JsNativeError::typ()
+            .with_message("Temporal: the duration-like object has no duration fields")
+            .into());
+    }
+
+    // 6. Return result.
+    Ok(result)
+}
+
+/// Formats the absolute value of an integral number without losing precision.
+fn format_integer(value: f64) -> String {
+    RawBigInt::from_f64(value.abs()).map_or_else(|| "0".to_owned(), |value| value.to_string())
+}
+
+/// Abstract operation `TemporalDurationToString ( years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds, precision )`
+fn temporal_duration_to_string(duration: &DurationRecord, precision: Precision) -> String {
+    // 1. Let sign be ! DurationSign(years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds).
+    let sign = duration.sign();
+
+    // 2-7. Balance the sub-second units into the seconds.
+    let mut seconds = RawBigInt::from_f64(duration.seconds.abs()).unwrap_or_default();
+    let mut fraction = 0_u128;
+    for (value, scale) in [
+        (duration.milliseconds, 1_000_000_u128),
+        (duration.microseconds, 1_000),
+        (duration.nanoseconds, 1),
+    ] {
+        let value = RawBigInt::from_f64(value.abs()).unwrap_or_default() * scale;
+        let value = value + fraction;
+        seconds += &value / 1_000_000_000_u32;
+        fraction = (value % 1_000_000_000_u32).try_into().unwrap_or_default();
+    }
+
+    // 8. Let datePart be "".
+    let mut date_part = String::new();
+    // 9-12. Append the years, months, weeks and days, if they're not zero.
+    for (value, designator) in [
+        (duration.years, 'Y'),
+        (duration.months, 'M'),
+        (duration.weeks, 'W'),
+        (duration.days, 'D'),
+    ] {
+        if value != 0.0 {
+            date_part.push_str(&format_integer(value));
+            date_part.push(designator);
+        }
+    }
+
+    // 13. Let timePart be "".
+    let mut time_part = String::new();
+    // 14-15. Append the hours and minutes, if they're not zero.
+    for (value, designator) in [(duration.hours, 'H'), (duration.minutes, 'M')] {
+        if value != 0.0 {
+            time_part.push_str(&format_integer(value));
+            time_part.push(designator);
+        }
+    }
+
+    // 16. If any of seconds, milliseconds, microseconds, and nanoseconds are not 0; or years,
+    //     months, weeks, days, hours, and minutes are all 0; or precision is not "auto"; then
+    let seconds_zero = seconds == RawBigInt::default() && fraction == 0;
+    if !seconds_zero || (date_part.is_empty() && time_part.is_empty()) || precision != Precision::Auto {
+        // a. Let fraction be abs(milliseconds) × 10^6 + abs(microseconds) × 10^3 + abs(nanoseconds).
+        // b. Let decimalPart be ToZeroPaddedDecimalString(fraction, 9).
+        let decimal_part = format!("{fraction:09}");
+
+        let decimal_part = match precision {
+            // c. If precision is "auto", then
+            //     i. Set decimalPart to the longest possible substring of decimalPart starting at
+            //        position 0 and not ending with the code unit 0x0030 (DIGIT ZERO).
+            Precision::Auto => decimal_part.trim_end_matches('0'),
+            // d. Else if precision = 0, then
+            //     i. Set decimalPart to "".
+            // e. Else,
+            //     i. Set decimalPart to the substring of decimalPart from 0 to precision.
+            Precision::Digits(digits) => &decimal_part[..usize::from(digits)],
+            Precision::Minute => "",
+        };
+
+        // f. Let secondsPart be abs(seconds) formatted as a decimal number.
+        time_part.push_str(&seconds.to_string());
+
+        // g. If decimalPart is not "", then
+        if !decimal_part.is_empty() {
+            // i. Set secondsPart to the string-concatenation of secondsPart, the code unit 0x002E (FULL STOP), and decimalPart.
+            time_part.push('.');
+            time_part.push_str(decimal_part);
+        }
+
+        // h. Set timePart to the string-concatenation of timePart, secondsPart, and the code unit 0x0053 (LATIN CAPITAL LETTER S).
+        time_part.push('S');
+    }
+
+    // 17. Let signPart be the code unit 0x002D (HYPHEN-MINUS) if sign < 0, and otherwise the empty String.
+    // 18. Let result be the string concatenation of signPart, the code unit 0x0050 (LATIN CAPITAL LETTER P) and datePart.
+    let mut result = if sign < 0 { "-P".to_owned() } else { "P".to_owned() };
+    result.push_str(&date_part);
+
+    // 19. If timePart is not "", then
+    if !time_part.is_empty() {
+        // a. Set result to the string concatenation of result, the code unit 0x0054 (LATIN CAPITAL LETTER T), and timePart.
+        result.push('T');
+        result.push_str(&time_part);
+    }
+
+    // 20. Return result.
+    result
+}
+
+/// The `RangeError` thrown when the calendar units of a duration are used without `relativeTo`.
+fn missing_relative_to<T>() -> JsResult<T> {
+    Err(JsNativeError::range()
+        .with_message("Temporal: a starting point is required for years, months and weeks, use the relativeTo option")
+        .into())
+}
+
+/// JavaScript `Temporal.Duration` object.
+#[derive(Debug, Clone, Copy, Finalize)]
+pub struct Duration {
+    pub(crate) record: DurationRecord,
+}
+
+// SAFETY: A duration holds no garbage collected values.
+unsafe impl Trace for Duration {
+    unsafe_empty_trace!();
+}
+
+impl Duration {
+    const NAME: &'static str = "Duration";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let mut builder = ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().duration().clone(),
+        );
+        builder.name(Self::NAME).length(0);
+        getter(&mut builder, "years", Self::years);
+        getter(&mut builder, "months", Self::months);
+        getter(&mut builder, "weeks", Self::weeks);
+        getter(&mut builder, "days", Self::days);
+        getter(&mut builder, "hours", Self::hours);
+        getter(&mut builder, "minutes", Self::minutes);
+        getter(&mut builder, "seconds", Self::seconds);
+        getter(&mut builder, "milliseconds", Self::milliseconds);
+        getter(&mut builder, "microseconds", Self::microseconds);
+        getter(&mut builder, "nanoseconds", Self::nanoseconds);
+        getter(&mut builder, "sign", Self::sign);
+        getter(&mut builder, "blank", Self::blank);
+        builder
+            .static_method(Self::from, "from", 1)
+            .static_method(Self::compare, "compare", 2)
+            .method(Self::with, "with", 1)
+            .method(Self::negated, "negated", 0)
+            .method(Self::abs, "abs", 0)
+            .method(Self::add, "add", 1)
+            .method(Self::subtract, "subtract", 1)
+            .method(Self::round, "round", 1)
+            .method(Self::total, "total", 1)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_json, "toJSON", 0)
+            .method(Self::to_json, "toLocaleString", 0)
+            .method(value_of, "valueOf", 0)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                "Temporal.Duration",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build()
+    }
+
+    /// Abstract operation `CreateTemporalDuration ( years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds [ , newTarget ] )`
+    pub(crate) fn create(
+        record: DurationRecord,
+        new_target: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. If ! IsValidDuration(years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds) is false, throw a RangeError exception.
+        if !record.is_valid() {
+            return Err(JsNativeError::range()
+                .with_message("Temporal: invalid duration")
+                .into());
+        }
+
+        // 2. If newTarget is not present, set newTarget to %Temporal.Duration%.
+        // 3. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.Duration.prototype%", « ... »).
+        let prototype = match new_target {
+            Some(new_target) => {
+                get_prototype_from_constructor(new_target, StandardConstructors::duration, context)?
+            }
+            None => context.intrinsics().constructors().duration().prototype(),
+        };
+
+        // 4-14. Set the fields of object.
+        // 15. Return object.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::duration(Self { record }),
+        ))
+    }
+
+    /// Gets the duration of the `this` value, throwing a `TypeError` if it's not a
+    /// `Temporal.Duration`.
+    fn this_duration(this: &JsValue, method: &str) -> JsResult<DurationRecord> {
+        this.as_object()
+            .and_then(|object| object.borrow().as_duration().map(|duration| duration.record))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "Temporal.Duration.prototype.{method}: 'this' is not a Temporal.Duration"
+                    ))
+                    .into()
+            })
+    }
+
+    /// `Temporal.Duration ( [ years [ , months [ , weeks [ , days [ , hours [ , minutes [ , seconds [ , milliseconds [ , microseconds [ , nanoseconds ] ] ] ] ] ] ] ] ] ] )`
+    fn constructor(new_target: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        if new_target.is_undefined() {
+            // a. Throw a TypeError exception.
+            return Err(JsNativeError::typ()
+                .with_message("Temporal.Duration: calling a builtin constructor without new is forbidden")
+                .into());
+        }
+
+        // 2-11. Let y be ? ToIntegerIfIntegral(years), and so on for the other fields.
+        let mut fields = [0.0; 10];
+        for (index, field) in fields.iter_mut().enumerate() {
+            let value = args.get_or_undefined(index);
+            if !value.is_undefined() {
+                *field = to_integer_if_integral(value, context)?;
+            }
+        }
+
+        // 12. Return ? CreateTemporalDuration(y, mo, w, d, h, m, s, ms, mis, ns, NewTarget).
+        Ok(Self::create(DurationRecord::from_fields(fields), Some(new_target), context)?.into())
+    }
+
+    /// `Temporal.Duration.from ( item )`
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. If Type(item) is Object and item has an [[InitializedTemporalDuration]] internal slot, then
+        //     a. Return ! CreateTemporalDuration(item.[[Years]], ...).
+        // 2. Return ? ToTemporalDuration(item).
+        let record = to_temporal_duration_record(args.get_or_undefined(0), context)?;
+        Ok(Self::create(record, None, context)?.into())
+    }
+
+    /// `Temporal.Duration.compare ( one, two [ , options ] )`
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set one to ? ToTemporalDuration(one).
+        let one = to_temporal_duration_record(args.get_or_undefined(0), context)?;
+        // 2. Set two to ? ToTemporalDuration(two).
+        let two = to_temporal_duration_record(args.get_or_undefined(1), context)?;
+        // 3. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(2))?;
+        // 4. Let relativeTo be ? ToRelativeTemporalObject(options).
+        let relative_to = to_relative_temporal_object(&options, context)?;
+
+        if one == two {
+            return Ok(0.into());
+        }
+
+        // 5-12. Compute the lengths of both durations from relativeTo, or in nanoseconds if the
+        //       days are 24 hours.
+        let uses_relative_to = |duration: &DurationRecord| {
+            duration.has_calendar_units()
+                || (duration.days != 0.0 && matches!(relative_to, Some(RelativeTo::Zoned { .. })))
+        };
+        let (ns1, ns2) = match relative_to {
+            Some(relative_to) if uses_relative_to(&one) || uses_relative_to(&two) => (
+                relative_to.add(&one, Overflow::Constrain)?,
+                relative_to.add(&two, Overflow::Constrain)?,
+            ),
+            _ => {
+                if one.has_calendar_units() || two.has_calendar_units() {
+                    return missing_relative_to();
+                }
+                (one.day_time_ns()?, two.day_time_ns()?)
+            }
+        };
+
+        // 13-15. Return 𝔽(the comparison of ns1 and ns2).
+        Ok(match ns1.cmp(&ns2) {
+            std::cmp::Ordering::Less => -1,
+            std::cmp::Ordering::Equal => 0,
+            std::cmp::Ordering::Greater => 1,
+        }
+        .into())
+    }
+
+    /// `get Temporal.Duration.prototype.years`
+    fn years(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_duration(this, "years")?.years.into())
+    }
+
+    /// `get Temporal.Duration.prototype.months`
+    fn months(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_duration(this, "months")?.months.into())
+    }
+
+    /// `get Temporal.Duration.prototype.weeks`
+    fn weeks(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_duration(this, "weeks")?.weeks.into())
+    }
+
+    /// `get Temporal.Duration.prototype.days`
+    fn days(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_duration(this, "days")?.days.into())
+    }
+
+    /// `get Temporal.Duration.prototype.hours`
+    fn hours(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_duration(this, "hours")?.hours.into())
+    }
+
+    /// `get Temporal.Duration.prototype.minutes`
+    fn minutes(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_duration(this, "minutes")?.minutes.into())
+    }
+
+    /// `get Temporal.Duration.prototype.seconds`
+    fn seconds(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_duration(this, "seconds")?.seconds.into())
+    }
+
+    /// `get Temporal.Duration.prototype.milliseconds`
+    fn milliseconds(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_duration(this, "milliseconds")?.milliseconds.into())
+    }
+
+    /// `get Temporal.Duration.prototype.microseconds`
+    fn microseconds(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_duration(this, "microseconds")?.microseconds.into())
+    }
+
+    /// `get Temporal.Duration.prototype.nanoseconds`
+    fn nanoseconds(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_duration(this, "nanoseconds")?.nanoseconds.into())
+    }
+
+    /// `get Temporal.Duration.prototype.sign`
+    fn sign(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_duration(this, "sign")?.sign().into())
+    }
+
+    /// `get Temporal.Duration.prototype.blank`
+    fn blank(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok((Self::this_duration(this, "blank")?.sign() == 0).into())
+    }
+
+    /// `Temporal.Duration.prototype.with ( temporalDurationLike )`
+    fn with(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = Self::this_duration(this, "with")?;
+
+        // 3. Let temporalDurationLike be ? ToTemporalPartialDurationRecord(temporalDurationLike).
+        let partial = match args.get_or_undefined(0).variant() {
+            JsVariant::Object(object) => to_temporal_partial_duration_record(object, context)?,
+            _ => {
+                return Err(JsNativeError::typ()
+                    .with_message("Temporal.Duration.prototype.with: expected an object")
+                    .into())
+            }
+        };
+
+        // 4-23. For each field, use the value of temporalDurationLike if it's not undefined, or
+        //       else the value of duration.
+        let mut fields = duration.fields();
+        for (field, value) in fields.iter_mut().zip(partial) {
+            if let Some(value) = value {
+                *field = value;
+            }
+        }
+
+        // 24. Return ? CreateTemporalDuration(years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds).
+        Ok(Self::create(DurationRecord::from_fields(fields), None, context)?.into())
+    }
+
+    /// `Temporal.Duration.prototype.negated ( )`
+    fn negated(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        // 3. Return ! CreateNegatedTemporalDuration(duration).
+        let duration = Self::this_duration(this, "negated")?;
+        Ok(Self::create(duration.negated(), None, context)?.into())
+    }
+
+    /// `Temporal.Duration.prototype.abs ( )`
+    fn abs(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        // 3. Return ! CreateTemporalDuration(abs(duration.[[Years]]), ...).
+        let duration = Self::this_duration(this, "abs")?;
+        Ok(Self::create(duration.abs(), None, context)?.into())
+    }
+
+    /// `Temporal.Duration.prototype.add ( other [ , options ] )`
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let duration = Self::this_duration(this, "add")?;
+        Self::add_or_subtract(duration, 1.0, args, context)
+    }
+
+    /// `Temporal.Duration.prototype.subtract ( other [ , options ] )`
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let duration = Self::this_duration(this, "subtract")?;
+        Self::add_or_subtract(duration, -1.0, args, context)
+    }
+
+    /// Abstract operation `AddDurationToOrSubtractDurationFromDuration ( operation, duration, other, options )`
+    fn add_or_subtract(
+        duration: DurationRecord,
+        sign: f64,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 2. Set other to ? ToTemporalDurationRecord(other).
+        let other = to_temporal_duration_record(args.get_or_undefined(0), context)?;
+        let other = if sign < 0.0 { other.negated() } else { other };
+
+        // 3. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 4. Let relativeTo be ? ToRelativeTemporalObject(options).
+        let relative_to = to_relative_temporal_object(&options, context)?;
+
+        // AddDuration ( y1, mon1, w1, d1, h1, min1, s1, ms1, mus1, ns1, y2, mon2, w2, d2, h2, min2, s2, ms2, mus2, ns2, relativeTo )
+        // 1-3. Let largestUnit be ! LargerOfTwoTemporalUnits of the default largest units.
+        let largest_unit = duration
+            .default_largest_unit()
+            .larger(other.default_largest_unit());
+
+        let result = match relative_to {
+            // 4. If relativeTo is undefined, then
+            None => {
+                // a. If largestUnit is one of "year", "month", or "week", then
+                if largest_unit.is_calendar_unit() {
+                    // i. Throw a RangeError exception.
+                    return missing_relative_to();
+                }
+                // b. Let result be ? BalanceDuration(d1 + d2, h1 + h2, min1 + min2, s1 + s2, ms1 + ms2, mus1 + mus2, ns1 + ns2, largestUnit).
+                DurationRecord::balance_time(
+                    duration.day_time_ns()? + other.day_time_ns()?,
+                    largest_unit,
+                )
+            }
+            // 5-6. Add both durations to relativeTo, and get the difference to the result.
+            Some(relative_to) => {
+                let intermediate = relative_to.add(&duration, Overflow::Constrain)?;
+                let end = relative_to
+                    .at(intermediate)
+                    .add(&other, Overflow::Constrain)?;
+                relative_to.difference(end, largest_unit)?
+            }
+        };
+
+        Ok(Self::create(result, None, context)?.into())
+    }
+
+    /// `Temporal.Duration.prototype.round ( roundTo )`
+    fn round(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = Self::this_duration(this, "round")?;
+
+        let round_to = match args.get_or_undefined(0).variant() {
+            // 3. If roundTo is undefined, then
+            JsVariant::Undefined => {
+                // a. Throw a TypeError exception.
+                return Err(JsNativeError::typ()
+                    .with_message("Temporal.Duration.prototype.round: roundTo is required")
+                    .into());
+            }
+            // 4. If Type(roundTo) is String, then
+            JsVariant::String(string) => {
+                // a. Let paramString be roundTo.
+                // b. Set roundTo to OrdinaryObjectCreate(null).
+                // c. Perform ! CreateDataPropertyOrThrow(roundTo, "smallestUnit", paramString).
+                let round_to = get_options_object(&JsValue::undefined())?;
+                round_to.create_data_property_or_throw("smallestUnit", string.clone(), context)?;
+                round_to
+            }
+            // 5. Else,
+            //     a. Set roundTo to ? GetOptionsObject(roundTo).
+            _ => get_options_object(args.get_or_undefined(0))?,
+        };
+
+        // 6-8. NOTE: The following steps read options in alphabetical order.
+        // 9. Let largestUnit be ? GetTemporalUnit(roundTo, "largestUnit", datetime, undefined, « "auto" »).
+        let largest_unit = get_temporal_unit(&round_to, "largestUnit", UnitGroup::DateTime, true, context)?;
+        let largest_unit_present = largest_unit.is_some();
+
+        // 10. Let relativeTo be ? ToRelativeTemporalObject(roundTo).
+        let relative_to = to_relative_temporal_object(&round_to, context)?;
+
+        // 11. Let roundingIncrement be ? ToTemporalRoundingIncrement(roundTo).
+        let increment = to_temporal_rounding_increment(&round_to, None, false, context)?;
+
+        // 12. Let roundingMode be ? ToTemporalRoundingMode(roundTo, "halfExpand").
+        let mode = to_temporal_rounding_mode(&round_to, RoundingMode::HalfExpand, context)?;
+
+        // 13. Let smallestUnit be ? GetTemporalUnit(roundTo, "smallestUnit", datetime, undefined).
+        let smallest_unit = get_temporal_unit(&round_to, "smallestUnit", UnitGroup::DateTime, false, context)?;
+
+        // 14. If smallestUnit is undefined, then
+        //     a. Set smallestUnitPresent to false.
+        //     b. Set smallestUnit to "nanosecond".
+        let smallest_unit_present = smallest_unit.is_some();
+        let smallest_unit = smallest_unit.unwrap_or(TemporalUnit::Nanosecond);
+
+        // 15. Let defaultLargestUnit be ! DefaultTemporalLargestUnit(duration.[[Years]], ...).
+        // 16. Set defaultLargestUnit to ! LargerOfTwoTemporalUnits(defaultLargestUnit, smallestUnit).
+        let default_largest_unit = duration.default_largest_unit().larger(smallest_unit);
+
+        // 17. If largestUnit is undefined, then
+        //     a. Set largestUnitPresent to false.
+        //     b. Set largestUnit to defaultLargestUnit.
+        // 18. Else if largestUnit is "auto", then
+        //     a. Set largestUnit to defaultLargestUnit.
+        let largest_unit = largest_unit.unwrap_or(default_largest_unit);
+
+        // 19. If smallestUnitPresent is false and largestUnitPresent is false, then
+        if !smallest_unit_present && !largest_unit_present {
+            // a. Throw a RangeError exception.
+            return Err(JsNativeError::range()
+                .with_message("Temporal.Duration.prototype.round: smallestUnit or largestUnit is required")
+                .into());
+        }
+
+        // 20. If LargerOfTwoTemporalUnits(largestUnit, smallestUnit) is not largestUnit, throw a RangeError exception.
+        if largest_unit.larger(smallest_unit) != largest_unit {
+            return Err(JsNativeError::range()
+                .with_message("Temporal: largestUnit can't be smaller than smallestUnit")
+                .into());
+        }
+
+        // 21. Let maximum be ! MaximumTemporalDurationRoundingIncrement(smallestUnit).
+        // 22. If maximum is not undefined, perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, false).
+        if let Some(maximum) = smallest_unit.maximum_rounding_increment() {
+            if increment >= maximum || maximum % increment != 0.0 {
+                return Err(JsNativeError::range()
+                    .with_message("Temporal: roundingIncrement must divide the next larger unit")
+                    .into());
+            }
+        }
+
+        // 23-29. Round the duration relative to relativeTo, and balance it up to largestUnit.
+        let result = if let Some(relative_to) = relative_to {
+            let end = relative_to.add(&duration, Overflow::Constrain)?;
+            relative_to.round(end, largest_unit, smallest_unit, increment, mode)?
+        } else {
+            if duration.has_calendar_units()
+                || largest_unit.is_calendar_unit()
+                || smallest_unit.is_calendar_unit()
+            {
+                return missing_relative_to();
+            }
+            let unit_ns = smallest_unit.nanoseconds().unwrap_or(1);
+            let rounded = mode.round(duration.day_time_ns()?, increment as i128 * unit_ns);
+            DurationRecord::balance_time(rounded, largest_unit)
+        };
+
+        // 30. Return ! CreateTemporalDuration(balanceResult.[[Years]], ...).
+        Ok(Self::create(result, None, context)?.into())
+    }
+
+    /// `Temporal.Duration.prototype.total ( totalOf )`
+    fn total(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = Self::this_duration(this, "total")?;
+
+        let total_of = match args.get_or_undefined(0).variant() {
+            // 3. If totalOf is undefined, throw a TypeError exception.
+            JsVariant::Undefined => {
+                return Err(JsNativeError::typ()
+                    .with_message("Temporal.Duration.prototype.total: totalOf is required")
+                    .into());
+            }
+            // 4. If Type(totalOf) is String, then
+            JsVariant::String(string) => {
+                // a. Let paramString be totalOf.
+                // b. Set totalOf to OrdinaryObjectCreate(null).
+                // c. Perform ! CreateDataPropertyOrThrow(totalOf, "unit", paramString).
+                let total_of = get_options_object(&JsValue::undefined())?;
+                total_of.create_data_property_or_throw("unit", string.clone(), context)?;
+                total_of
+            }
+            // 5. Else,
+            //     a. Set totalOf to ? GetOptionsObject(totalOf).
+            _ => get_options_object(args.get_or_undefined(0))?,
+        };
+
+        // 6. NOTE: The following steps read options in alphabetical order.
+        // 7. Let relativeTo be ? ToRelativeTemporalObject(totalOf).
+        let relative_to = to_relative_temporal_object(&total_of, context)?;
+
+        // 8. Let unit be ? GetTemporalUnit(totalOf, "unit", datetime, required).
+        let unit = get_temporal_unit(&total_of, "unit", UnitGroup::DateTime, false, context)?
+            .ok_or_else(|| {
+                JsNativeError::range()
+                    .with_message("Temporal.Duration.prototype.total: unit is required")
+            })?;
+
+        // 9-18. Let roundRecord be ? RoundDuration(..., 1, unit, "trunc", relativeTo), and return
+        //       its [[Total]].
+        let total = if let Some(relative_to) = relative_to {
+            let end = relative_to.add(&duration, Overflow::Constrain)?;
+            relative_to.total(end, unit)?
+        } else {
+            if duration.has_calendar_units() || unit.is_calendar_unit() {
+                return missing_relative_to();
+            }
+            ns_total(duration.day_time_ns()?, unit.nanoseconds().unwrap_or(1))
+        };
+
+        Ok(total.into())
+    }
+
+    /// `Temporal.Duration.prototype.toString ( [ options ] )`
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = Self::this_duration(this, "toString")?;
+
+        // 3. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(0))?;
+
+        // 4. NOTE: The following steps read options in alphabetical order.
+        // 5. Let precision be ? ToSecondsStringPrecision(options).
+        let precision = to_seconds_string_precision(&options, context)?;
+
+        // 6. Let roundingMode be ? ToTemporalRoundingMode(options, "trunc").
+        let mode = to_temporal_rounding_mode(&options, RoundingMode::Trunc, context)?;
+
+        // 7. If precision.[[Unit]] is "minute", throw a RangeError exception.
+        if precision.precision == Precision::Minute {
+            return Err(JsNativeError::range()
+                .with_message("Temporal.Duration.prototype.toString: smallestUnit can't be minute")
+                .into());
+        }
+
+        // 8. Let result be (? RoundDuration(duration.[[Years]], ..., precision.[[Increment]], precision.[[Unit]], roundingMode)).[[DurationRecord]].
+        let mut result = duration;
+        // The increment is an exact integer.
+        #[allow(clippy::float_cmp)]
+        if precision.unit != TemporalUnit::Nanosecond || precision.increment != 1.0 {
+            let seconds = DurationRecord {
+                seconds: duration.seconds,
+                milliseconds: duration.milliseconds,
+                microseconds: duration.microseconds,
+                nanoseconds: duration.nanoseconds,
+                ..DurationRecord::default()
+            };
+            // Seconds too big for the arithmetic in nanoseconds have no fractional part.
+            if let Ok(ns) = seconds.time_ns() {
+                let unit_ns = precision.unit.nanoseconds().unwrap_or(1);
+                let rounded = mode.round(ns, precision.increment as i128 * unit_ns);
+                let balanced = DurationRecord::balance_time(rounded, TemporalUnit::Second);
+                result = DurationRecord {
+                    seconds: balanced.seconds,
+                    milliseconds: balanced.milliseconds,
+                    microseconds: balanced.microseconds,
+                    nanoseconds: balanced.nanoseconds,
+                    ..duration
+                };
+            }
+        }
+
+        // 9. Return ! TemporalDurationToString(result.[[Years]], ..., precision.[[Precision]]).
+        Ok(temporal_duration_to_string(&result, precision.precision).into())
+    }
+
+    /// `Temporal.Duration.prototype.toJSON ( )`
+    fn to_json(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = Self::this_duration(this, "toJSON")?;
+
+        // 3. Return ! TemporalDurationToString(duration.[[Years]], ..., "auto").
+        Ok(temporal_duration_to_string(&duration, Precision::Auto).into())
+    }
+}
@@ -0,0 +1,368 @@
+//! The property bags used to create and modify the `Temporal` types, like `{ year, month, day }`.
+//!
+//! More information:
+//!  - [Temporal reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-preparetemporalfields
+
+use super::{
+    iso::{IsoDate, IsoDateTime, IsoTime},
+    options::Overflow,
+    to_integer_with_truncation, to_positive_integer_with_truncation,
+};
+use crate::{object::JsObject, Context, JsNativeError, JsResult, JsValue};
+
+/// The name of a field of a property bag.
+///
+/// The variants are in alphabetical order, which is the order in which the fields are read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum FieldName {
+    Day,
+    Hour,
+    Microsecond,
+    Millisecond,
+    Minute,
+    Month,
+    MonthCode,
+    Nanosecond,
+    Offset,
+    Second,
+    TimeZone,
+    Year,
+}
+
+impl FieldName {
+    /// Gets the property key of the field.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Day => "day",
+            Self::Hour => "hour",
+            Self::Microsecond => "microsecond",
+            Self::Millisecond => "millisecond",
+            Self::Minute => "minute",
+            Self::Month => "month",
+            Self::MonthCode => "monthCode",
+            Self::Nanosecond => "nanosecond",
+            Self::Offset => "offset",
+            Self::Second => "second",
+            Self::TimeZone => "timeZone",
+            Self::Year => "year",
+        }
+    }
+
+    /// Parses the property key of a field.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        let field = match name {
+            "day" => Self::Day,
+            "hour" => Self::Hour,
+            "microsecond" => Self::Microsecond,
+            "millisecond" => Self::Millisecond,
+            "minute" => Self::Minute,
+            "month" => Self::Month,
+            "monthCode" => Self::MonthCode,
+            "nanosecond" => Self::Nanosecond,
+            "offset" => Self::Offset,
+            "second" => Self::Second,
+            "timeZone" => Self::TimeZone,
+            "year" => Self::Year,
+            _ => return None,
+        };
+        Some(field)
+    }
+}
+
+/// The fields of a `PlainDate`.
+pub(crate) const DATE_FIELDS: &[FieldName] = &[
+    FieldName::Day,
+    FieldName::Month,
+    FieldName::MonthCode,
+    FieldName::Year,
+];
+
+/// The fields of a `PlainTime`.
+pub(crate) const TIME_FIELDS: &[FieldName] = &[
+    FieldName::Hour,
+    FieldName::Microsecond,
+    FieldName::Millisecond,
+    FieldName::Minute,
+    FieldName::Nanosecond,
+    FieldName::Second,
+];
+
+/// The fields of a `PlainDateTime`.
+pub(crate) const DATE_TIME_FIELDS: &[FieldName] = &[
+    FieldName::Day,
+    FieldName::Hour,
+    FieldName::Microsecond,
+    FieldName::Millisecond,
+    FieldName::Minute,
+    FieldName::Month,
+    FieldName::MonthCode,
+    FieldName::Nanosecond,
+    FieldName::Second,
+    FieldName::Year,
+];
+
+/// The fields of a `ZonedDateTime`.
+pub(crate) const ZONED_DATE_TIME_FIELDS: &[FieldName] = &[
+    FieldName::Day,
+    FieldName::Hour,
+    FieldName::Microsecond,
+    FieldName::Millisecond,
+    FieldName::Minute,
+    FieldName::Month,
+    FieldName::MonthCode,
+    FieldName::Nanosecond,
+    FieldName::Offset,
+    FieldName::Second,
+    FieldName::TimeZone,
+    FieldName::Year,
+];
+
+/// The fields read from a property bag.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TemporalFields {
+    pub(crate) year: Option<f64>,
+    pub(crate) month: Option<f64>,
+    pub(crate) month_code: Option<String>,
+    pub(crate) day: Option<f64>,
+    pub(crate) hour: Option<f64>,
+    pub(crate) minute: Option<f64>,
+    pub(crate) second: Option<f64>,
+    pub(crate) millisecond: Option<f64>,
+    pub(crate) microsecond: Option<f64>,
+    pub(crate) nanosecond: Option<f64>,
+    pub(crate) offset: Option<String>,
+    pub(crate) time_zone: Option<JsValue>,
+}
+
+impl TemporalFields {
+    /// Abstract operation `PrepareTemporalFields ( fields, fieldNames, requiredFields )`
+    ///
+    /// Reads the fields in `names`, which must be sorted. If `partial` is `true`, at least one of
+    /// the fields must be present.
+    pub(crate) fn prepare(
+        object: &JsObject,
+        names: &[FieldName],
+        partial: bool,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 1. Let result be OrdinaryObjectCreate(null).
+        let mut result = Self::default();
+
+        // 2. Let any be false.
+        let mut any = false;
+
+        // 3. For each value property of fieldNames, do
+        for name in names {
+            // a. Let value be ? Get(fields, property).
+            let value = object.get(name.name(), context)?;
+
+            // b. If value is not undefined, then
+            if value.is_undefined() {
+                continue;
+            }
+
+            // i. Set any to true.
+            any = true;
+
+            // ii. If property is in the Property column of Table 15 and there is a Conversion
+            //     value in the same row, then convert the value.
+            match name {
+                FieldName::Month => result.month = Some(to_positive_integer_with_truncation(&value, context)?),
+                FieldName::MonthCode => {
+                    result.month_code = Some(value.to_string(context)?.to_std_string_escaped());
+                }
+                FieldName::Offset => {
+                    result.offset = Some(value.to_string(context)?.to_std_string_escaped());
+                }
+                FieldName::TimeZone => result.time_zone = Some(value),
+                _ => {
+                    let value = Some(to_integer_with_truncation(&value, context)?);
+                    match name {
+                        FieldName::Day => result.day = value,
+                        FieldName::Hour => result.hour = value,
+                        FieldName::Microsecond => result.microsecond = value,
+                        FieldName::Millisecond => result.millisecond = value,
+                        FieldName::Minute => result.minute = value,
+                        FieldName::Nanosecond => result.nanosecond = value,
+                        FieldName::Second => result.second = value,
+                        _ => result.year = value,
+                    }
+                }
+            }
+        }
+
+        // 4. If requiredFields is partial and any is false, then
+        if partial && !any {
+            // a. Throw a TypeError exception.
+            return Err(JsNativeError::typ()
+                .with_message("Temporal: the property bag has none of the expected fields")
+                .into());
+        }
+
+        // 5. Return result.
+        Ok(result)
+    }
+
+    /// Creates the fields of an ISO date.
+    pub(crate) fn from_date(date: IsoDate) -> Self {
+        Self {
+            year: Some(f64::from(date.year)),
+            month: Some(f64::from(date.month)),
+            month_code: Some(format!("M{:02}", date.month)),
+            day: Some(f64::from(date.day)),
+            ..Self::default()
+        }
+    }
+
+    /// Creates the fields of a wall-clock time.
+    pub(crate) fn from_time(time: IsoTime) -> Self {
+        Self {
+            hour: Some(f64::from(time.hour)),
+            minute: Some(f64::from(time.minute)),
+            second: Some(f64::from(time.second)),
+            millisecond: Some(f64::from(time.millisecond)),
+            microsecond: Some(f64::from(time.microsecond)),
+            nanosecond: Some(f64::from(time.nanosecond)),
+            ..Self::default()
+        }
+    }
+
+    /// Creates the fields of an ISO date and time.
+    pub(crate) fn from_date_time(date_time: IsoDateTime) -> Self {
+        Self {
+            year: Some(f64::from(date_time.date.year)),
+            month: Some(f64::from(date_time.date.month)),
+            month_code: Some(format!("M{:02}", date_time.date.month)),
+            day: Some(f64::from(date_time.date.day)),
+            ..Self::from_time(date_time.time)
+        }
+    }
+
+    /// Abstract operation `DefaultMergeCalendarFields ( fields, additionalFields )`
+    ///
+    /// Returns the fields of `self` overridden by the fields present in `additional`. If
+    /// `additional` has a month or a month code, both are taken from it.
+    pub(crate) fn merge(&self, additional: &Self) -> Self {
+        let (month, month_code) = if additional.month.is_some() || additional.month_code.is_some() {
+            (additional.month, additional.month_code.clone())
+        } else {
+            (self.month, self.month_code.clone())
+        };
+        Self {
+            year: additional.year.or(self.year),
+            month,
+            month_code,
+            day: additional.day.or(self.day),
+            hour: additional.hour.or(self.hour),
+            minute: additional.minute.or(self.minute),
+            second: additional.second.or(self.second),
+            millisecond: additional.millisecond.or(self.millisecond),
+            microsecond: additional.microsecond.or(self.microsecond),
+            nanosecond: additional.nanosecond.or(self.nanosecond),
+            offset: additional.offset.clone().or_else(|| self.offset.clone()),
+            time_zone: additional
+                .time_zone
+                .clone()
+                .or_else(|| self.time_zone.clone()),
+        }
+    }
+
+    /// Abstract operation `ISOResolveMonth ( fields )`
+    pub(crate) fn resolve_month(&self) -> JsResult<f64> {
+        // 1. Assert: fields is an ordinary object with no more and no less than the own data
+        //    properties listed in Table 15.
+        // 2. Let month be ! Get(fields, "month").
+        // 3. Let monthCode be ! Get(fields, "monthCode").
+        // 4. If monthCode is undefined, then
+        let month_code = match (&self.month_code, self.month) {
+            (None, Some(month)) => return Ok(month),
+            // a. If month is undefined, throw a TypeError exception.
+            (None, None) => {
+                return Err(JsNativeError::typ()
+                    .with_message("Temporal: month or monthCode is required")
+                    .into())
+            }
+            (Some(month_code), _) => month_code,
+        };
+
+        // 5. Assert: Type(monthCode) is String.
+        // 6. If the length of monthCode is not 3, throw a RangeError exception.
+        // 7. If the first code unit of monthCode is not 0x004D (LATIN CAPITAL LETTER M), throw a RangeError exception.
+        // 8. Let monthCodeDigits be the substring of monthCode from 1.
+        // 9. If ParseText(StringToCodePoints(monthCodeDigits), DateMonth) is a List of errors, throw a RangeError exception.
+        // 10. Let monthCodeNumber be ! ToIntegerOrInfinity(monthCodeDigits).
+        let number = month_code
+            .strip_prefix('M')
+            .filter(|digits| digits.len() == 2 && digits.bytes().all(|b| b.is_ascii_digit()))
+            .and_then(|digits| digits.parse::<u8>().ok())
+            .filter(|number| (1..=12).contains(number))
+            .ok_or_else(|| {
+                JsNativeError::range()
+                    .with_message(format!("Temporal: invalid monthCode `{month_code}`"))
+            })?;
+
+        // 11. If month is not undefined and SameValueNonNumeric(month, monthCodeNumber) is false, throw a RangeError exception.
+        if let Some(month) = self.month {
+            // SameValueNonNumeric is an exact comparison.
+            #[allow(clippy::float_cmp)]
+            if month != f64::from(number) {
+                return Err(JsNativeError::range()
+                    .with_message("Temporal: month and monthCode don't match")
+                    .into());
+            }
+        }
+
+        // 12. Return monthCodeNumber.
+        Ok(f64::from(number))
+    }
+
+    /// Abstract operation `ISODateFromFields ( fields, options )`
+    pub(crate) fn to_date(&self, overflow: Overflow) -> JsResult<IsoDate> {
+        // 1-2. Set fields to ? PrepareTemporalFields(fields, « "day", "month", "monthCode", "year" », « "year", "day" »).
+        let (year, day) = match (self.year, self.day) {
+            (Some(year), Some(day)) => (year, day),
+            (None, _) => {
+                return Err(JsNativeError::typ()
+                    .with_message("Temporal: year is required")
+                    .into())
+            }
+            (_, None) => {
+                return Err(JsNativeError::typ()
+                    .with_message("Temporal: day is required")
+                    .into())
+            }
+        };
+
+        // 3. Let month be ? ResolveISOMonth(fields).
+        let month = self.resolve_month()?;
+
+        // 4. Return ? RegulateISODate(year, month, day, overflow).
+        IsoDate::regulate(year, month, day, overflow)
+    }
+
+    /// Abstract operation `ToTemporalTimeRecord ( temporalTimeLike )`, followed by `RegulateTime`.
+    ///
+    /// The missing fields are zero.
+    pub(crate) fn to_time(&self, overflow: Overflow) -> JsResult<IsoTime> {
+        IsoTime::regulate(
+            [
+                self.hour.unwrap_or_default(),
+                self.minute.unwrap_or_default(),
+                self.second.unwrap_or_default(),
+                self.millisecond.unwrap_or_default(),
+                self.microsecond.unwrap_or_default(),
+                self.nanosecond.unwrap_or_default(),
+            ],
+            overflow,
+        )
+    }
+
+    /// Abstract operation `InterpretTemporalDateTimeFields ( calendar, fields, options )`
+    pub(crate) fn to_date_time(&self, overflow: Overflow) -> JsResult<IsoDateTime> {
+        let date = self.to_date(overflow)?;
+        let time = self.to_time(overflow)?;
+        Ok(IsoDateTime { date, time })
+    }
+}
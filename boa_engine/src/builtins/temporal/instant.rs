@@ -0,0 +1,663 @@
+//! This module implements the `Temporal.Instant` object.
+//!
+//! An instant is an exact point on the time line, with nanosecond precision, without a time zone
+//! or a calendar.
+//!
+//! More information:
+//!  - [Temporal reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-instant-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Instant
+
+use super::{
+    bigint_to_ns,
+    calendar::{to_temporal_calendar, validate_calendar_annotation, Calendar},
+    duration::{to_temporal_duration_record, DurationRecord},
+    getter,
+    iso::{is_valid_epoch_ns, out_of_range, IsoDateTime, NS_PER_DAY},
+    ns_to_bigint,
+    options::{
+        get_difference_settings, get_options_object, get_temporal_unit,
+        to_seconds_string_precision, to_temporal_rounding_increment, to_temporal_rounding_mode,
+        DifferenceOperation, Precision, RoundingMode, UnitGroup,
+    },
+    parser::{parse_date_time, ParsedOffset},
+    time_zone::{format_iso_time_zone_offset_string, to_temporal_time_zone, TimeZone, TimeZoneKind},
+    value_of, Duration, TemporalUnit, ZonedDateTime,
+};
+use crate::{
+    builtins::JsArgs,
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsFunction,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsNativeError, JsResult, JsValue, JsVariant,
+};
+use boa_gc::{unsafe_empty_trace, Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// Abstract operation `ToTemporalInstant ( item )`
+///
+/// Returns the epoch nanoseconds of the instant.
+pub(crate) fn to_temporal_instant(item: &JsValue, context: &mut Context) -> JsResult<i128> {
+    // 1. If Type(item) is Object, then
+    if let Some(object) = item.as_object() {
+        let object = object.borrow();
+        // a. If item has an [[InitializedTemporalInstant]] internal slot, then
+        //     i. Return item.
+        if let Some(instant) = object.as_instant() {
+            return Ok(instant.nanoseconds);
+        }
+        // b. If item has an [[InitializedTemporalZonedDateTime]] internal slot, then
+        //     i. Return ! CreateTemporalInstant(item.[[Nanoseconds]]).
+        if let Some(zoned) = object.as_zoned_date_time() {
+            return Ok(zoned.nanoseconds);
+        }
+    }
+
+    // 2. Let string be ? ToString(item).
+    let string = item.to_string(context)?.to_std_string_escaped();
+
+    // 3. Let epochNanoseconds be ? ParseTemporalInstant(string).
+    // ParseTemporalInstant ( isoString )
+    // 1-2. Let result be ? ParseTemporalInstantString(isoString).
+    let parsed = parse_date_time(&string)?;
+    validate_calendar_annotation(parsed.calendar.as_deref())?;
+
+    // 3. Let offsetString be result.[[TimeZoneOffsetString]].
+    // 4. Assert: offsetString is not undefined.
+    let offset_ns = match parsed.offset {
+        Some(ParsedOffset::Utc) => 0,
+        Some(ParsedOffset::Numeric { ns, .. }) => i128::from(ns),
+        None => {
+            return Err(JsNativeError::range()
+                .with_message(format!("Temporal: the instant string `{string}` has no offset"))
+                .into())
+        }
+    };
+
+    // 5. Let utc be GetUTCEpochNanoseconds(result.[[Year]], ..., result.[[Nanosecond]]).
+    let utc = IsoDateTime {
+        date: parsed.date,
+        time: parsed.time.unwrap_or_default(),
+    }
+    .to_local_ns();
+
+    // 6. Let offsetNanoseconds be ? ParseTimeZoneOffsetString(offsetString).
+    // 7. Let result be utc - ℤ(offsetNanoseconds).
+    let result = utc - offset_ns;
+
+    // 8. If ! IsValidEpochNanoseconds(result) is false, then
+    if !is_valid_epoch_ns(result) {
+        // a. Throw a RangeError exception.
+        return out_of_range();
+    }
+
+    // 9. Return result.
+    Ok(result)
+}
+
+/// Abstract operation `TemporalInstantToString ( instant, timeZone, precision )`
+fn temporal_instant_to_string(
+    epoch_ns: i128,
+    time_zone: Option<&JsObject>,
+    precision: Precision,
+) -> String {
+    // 1-3. Let outputTimeZone be timeZone, or UTC if it's undefined.
+    let output_time_zone = time_zone.map_or(TimeZoneKind::UTC, TimeZone::kind_of);
+
+    // 4-5. Let dateTime be ? BuiltinTimeZoneGetPlainDateTimeFor(outputTimeZone, instant, isoCalendar).
+    let date_time = output_time_zone.plain_date_time_for(epoch_ns);
+
+    // 6. Let dateTimeString be ? TemporalDateTimeToString(dateTime.[[ISOYear]], ..., undefined, precision, "never").
+    let mut result = date_time.to_iso_string(precision);
+
+    // 7. If timeZone is undefined, then
+    //     a. Let timeZoneString be "Z".
+    // 8. Else,
+    //     a. Let offsetNs be ? GetOffsetNanosecondsFor(timeZone, instant).
+    //     b. Let timeZoneString be ! FormatISOTimeZoneOffsetString(offsetNs).
+    if time_zone.is_some() {
+        result.push_str(&format_iso_time_zone_offset_string(
+            output_time_zone.offset_ns(epoch_ns),
+        ));
+    } else {
+        result.push('Z');
+    }
+
+    // 9. Return the string-concatenation of dateTimeString and timeZoneString.
+    result
+}
+
+/// JavaScript `Temporal.Instant` object.
+#[derive(Debug, Clone, Copy, Finalize)]
+pub struct Instant {
+    pub(crate) nanoseconds: i128,
+}
+
+// SAFETY: An instant holds no garbage collected values.
+unsafe impl Trace for Instant {
+    unsafe_empty_trace!();
+}
+
+impl Instant {
+    const NAME: &'static str = "Instant";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let mut builder = ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().instant().clone(),
+        );
+        builder.name(Self::NAME).length(1);
+        getter(&mut builder, "epochSeconds", Self::epoch_seconds);
+        getter(&mut builder, "epochMilliseconds", Self::epoch_milliseconds);
+        getter(&mut builder, "epochMicroseconds", Self::epoch_microseconds);
+        getter(&mut builder, "epochNanoseconds", Self::epoch_nanoseconds);
+        builder
+            .static_method(Self::from, "from", 1)
+            .static_method(Self::from_epoch_seconds, "fromEpochSeconds", 1)
+            .static_method(Self::from_epoch_milliseconds, "fromEpochMilliseconds", 1)
+            .static_method(Self::from_epoch_microseconds, "fromEpochMicroseconds", 1)
+            .static_method(Self::from_epoch_nanoseconds, "fromEpochNanoseconds", 1)
+            .static_method(Self::compare, "compare", 2)
+            .method(Self::add, "add", 1)
+            .method(Self::subtract, "subtract", 1)
+            .method(Self::until, "until", 1)
+            .method(Self::since, "since", 1)
+            .method(Self::round, "round", 1)
+            .method(Self::equals, "equals", 1)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_json, "toLocaleString", 0)
+            .method(Self::to_json, "toJSON", 0)
+            .method(value_of, "valueOf", 0)
+            .method(Self::to_zoned_date_time, "toZonedDateTime", 1)
+            .method(Self::to_zoned_date_time_iso, "toZonedDateTimeISO", 1)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                "Temporal.Instant",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build()
+    }
+
+    /// Abstract operation `CreateTemporalInstant ( epochNanoseconds [ , newTarget ] )`
+    pub(crate) fn create(
+        nanoseconds: i128,
+        new_target: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Assert: ! IsValidEpochNanoseconds(epochNanoseconds) is true.
+        if !is_valid_epoch_ns(nanoseconds) {
+            return out_of_range();
+        }
+
+        // 2. If newTarget is not present, set newTarget to %Temporal.Instant%.
+        // 3. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.Instant.prototype%", « [[InitializedTemporalInstant]], [[Nanoseconds]] »).
+        let prototype = match new_target {
+            Some(new_target) => {
+                get_prototype_from_constructor(new_target, StandardConstructors::instant, context)?
+            }
+            None => context.intrinsics().constructors().instant().prototype(),
+        };
+
+        // 4. Set object.[[Nanoseconds]] to epochNanoseconds.
+        // 5. Return object.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::instant(Self { nanoseconds }),
+        ))
+    }
+
+    /// Gets the epoch nanoseconds of the `this` value, throwing a `TypeError` if it's not a
+    /// `Temporal.Instant`.
+    fn this_instant(this: &JsValue, method: &str) -> JsResult<i128> {
+        this.as_object()
+            .and_then(|object| object.borrow().as_instant().map(|instant| instant.nanoseconds))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "Temporal.Instant.prototype.{method}: 'this' is not a Temporal.Instant"
+                    ))
+                    .into()
+            })
+    }
+
+    /// `Temporal.Instant ( epochNanoseconds )`
+    fn constructor(new_target: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        if new_target.is_undefined() {
+            // a. Throw a TypeError exception.
+            return Err(JsNativeError::typ()
+                .with_message("Temporal.Instant: calling a builtin constructor without new is forbidden")
+                .into());
+        }
+
+        // 2. Let epochNanoseconds be ? ToBigInt(epochNanoseconds).
+        let epoch_ns = args.get_or_undefined(0).to_bigint(context)?;
+
+        // 3. If ! IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+        // 4. Return ? CreateTemporalInstant(epochNanoseconds, NewTarget).
+        let epoch_ns = bigint_to_ns(&epoch_ns)?;
+        Ok(Self::create(epoch_ns, Some(new_target), context)?.into())
+    }
+
+    /// `Temporal.Instant.from ( item )`
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. If Type(item) is Object and item has an [[InitializedTemporalInstant]] internal slot, then
+        //     a. Return ! CreateTemporalInstant(item.[[Nanoseconds]]).
+        // 2. Return ? ToTemporalInstant(item).
+        let epoch_ns = to_temporal_instant(args.get_or_undefined(0), context)?;
+        Ok(Self::create(epoch_ns, None, context)?.into())
+    }
+
+    /// Creates an instant from a number of `unit_ns` nanoseconds since the epoch.
+    fn from_epoch_number(value: &JsValue, unit_ns: i128, context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set epochSeconds to ? ToNumber(epochSeconds).
+        let number = value.to_number(context)?;
+
+        // 2. Set epochSeconds to ? NumberToBigInt(epochSeconds).
+        #[allow(clippy::float_cmp)]
+        if !number.is_finite() || number.trunc() != number {
+            return Err(JsNativeError::range()
+                .with_message("Temporal.Instant: the epoch time must be an integer")
+                .into());
+        }
+
+        // 3. Let epochNanoseconds be epochSeconds × ℤ(10^9).
+        // 4. If ! IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+        if number.abs() > 1e22 {
+            return out_of_range();
+        }
+        let epoch_ns = number as i128 * unit_ns;
+
+        // 5. Return ! CreateTemporalInstant(epochNanoseconds).
+        Ok(Self::create(epoch_ns, None, context)?.into())
+    }
+
+    /// Creates an instant from a `BigInt` number of `unit_ns` nanoseconds since the epoch.
+    fn from_epoch_bigint(value: &JsValue, unit_ns: i128, context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set epochMicroseconds to ? ToBigInt(epochMicroseconds).
+        let bigint = value.to_bigint(context)?;
+
+        // 2. Let epochNanoseconds be epochMicroseconds × 1000ℤ.
+        // 3. If ! IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+        let epoch_ns = bigint_to_ns(&bigint)?
+            .checked_mul(unit_ns)
+            .map_or_else(out_of_range, Ok)?;
+
+        // 4. Return ! CreateTemporalInstant(epochNanoseconds).
+        Ok(Self::create(epoch_ns, None, context)?.into())
+    }
+
+    /// `Temporal.Instant.fromEpochSeconds ( epochSeconds )`
+    fn from_epoch_seconds(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::from_epoch_number(args.get_or_undefined(0), 1_000_000_000, context)
+    }
+
+    /// `Temporal.Instant.fromEpochMilliseconds ( epochMilliseconds )`
+    fn from_epoch_milliseconds(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::from_epoch_number(args.get_or_undefined(0), 1_000_000, context)
+    }
+
+    /// `Temporal.Instant.fromEpochMicroseconds ( epochMicroseconds )`
+    fn from_epoch_microseconds(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::from_epoch_bigint(args.get_or_undefined(0), 1_000, context)
+    }
+
+    /// `Temporal.Instant.fromEpochNanoseconds ( epochNanoseconds )`
+    fn from_epoch_nanoseconds(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::from_epoch_bigint(args.get_or_undefined(0), 1, context)
+    }
+
+    /// `Temporal.Instant.compare ( one, two )`
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set one to ? ToTemporalInstant(one).
+        let one = to_temporal_instant(args.get_or_undefined(0), context)?;
+        // 2. Set two to ? ToTemporalInstant(two).
+        let two = to_temporal_instant(args.get_or_undefined(1), context)?;
+        // 3. Return 𝔽(! CompareEpochNanoseconds(one.[[Nanoseconds]], two.[[Nanoseconds]])).
+        Ok(match one.cmp(&two) {
+            std::cmp::Ordering::Less => -1,
+            std::cmp::Ordering::Equal => 0,
+            std::cmp::Ordering::Greater => 1,
+        }
+        .into())
+    }
+
+    /// `get Temporal.Instant.prototype.epochSeconds`
+    fn epoch_seconds(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 3. Let ns be instant.[[Nanoseconds]].
+        // 4. Let s be floor(ℝ(ns) / 10^9).
+        // 5. Return 𝔽(s).
+        let ns = Self::this_instant(this, "epochSeconds")?;
+        Ok((ns.div_euclid(1_000_000_000) as f64).into())
+    }
+
+    /// `get Temporal.Instant.prototype.epochMilliseconds`
+    fn epoch_milliseconds(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 3. Let ns be instant.[[Nanoseconds]].
+        // 4. Let ms be floor(ℝ(ns) / 10^6).
+        // 5. Return 𝔽(ms).
+        let ns = Self::this_instant(this, "epochMilliseconds")?;
+        Ok((ns.div_euclid(1_000_000) as f64).into())
+    }
+
+    /// `get Temporal.Instant.prototype.epochMicroseconds`
+    fn epoch_microseconds(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 3. Let ns be instant.[[Nanoseconds]].
+        // 4. Let µs be floor(ℝ(ns) / 10^3).
+        // 5. Return ℤ(µs).
+        let ns = Self::this_instant(this, "epochMicroseconds")?;
+        Ok(ns_to_bigint(ns.div_euclid(1_000)))
+    }
+
+    /// `get Temporal.Instant.prototype.epochNanoseconds`
+    fn epoch_nanoseconds(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 3. Let ns be instant.[[Nanoseconds]].
+        // 4. Return ns.
+        let ns = Self::this_instant(this, "epochNanoseconds")?;
+        Ok(ns_to_bigint(ns))
+    }
+
+    /// Abstract operation `AddDurationToOrSubtractDurationFromInstant ( operation, instant, temporalDurationLike )`
+    fn add_or_subtract(
+        epoch_ns: i128,
+        sign: i128,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 2. Let duration be ? ToTemporalDurationRecord(temporalDurationLike).
+        let duration = to_temporal_duration_record(args.get_or_undefined(0), context)?;
+
+        // 3. If duration.[[Days]] is not 0, throw a RangeError exception.
+        // 4. If duration.[[Months]] is not 0, throw a RangeError exception.
+        // 5. If duration.[[Weeks]] is not 0, throw a RangeError exception.
+        // 6. If duration.[[Years]] is not 0, throw a RangeError exception.
+        if duration.date() != DurationRecord::default() {
+            return Err(JsNativeError::range()
+                .with_message("Temporal.Instant: only time units can be added to an instant")
+                .into());
+        }
+
+        // 7. Let ns be ? AddInstant(instant.[[Nanoseconds]], sign × duration.[[Hours]], ...).
+        let ns = epoch_ns + sign * duration.time_ns()?;
+
+        // 8. Return ! CreateTemporalInstant(ns).
+        Ok(Self::create(ns, None, context)?.into())
+    }
+
+    /// `Temporal.Instant.prototype.add ( temporalDurationLike )`
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let epoch_ns = Self::this_instant(this, "add")?;
+        Self::add_or_subtract(epoch_ns, 1, args, context)
+    }
+
+    /// `Temporal.Instant.prototype.subtract ( temporalDurationLike )`
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let epoch_ns = Self::this_instant(this, "subtract")?;
+        Self::add_or_subtract(epoch_ns, -1, args, context)
+    }
+
+    /// Abstract operation `DifferenceTemporalInstant ( operation, instant, other, options )`
+    fn difference(
+        operation: DifferenceOperation,
+        epoch_ns: i128,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 2. Set other to ? ToTemporalInstant(other).
+        let other = to_temporal_instant(args.get_or_undefined(0), context)?;
+
+        // 3. Let settings be ? GetDifferenceSettings(operation, options, time, « », "nanosecond", "second").
+        let settings = get_difference_settings(
+            operation,
+            args.get_or_undefined(1),
+            UnitGroup::Time,
+            TemporalUnit::Nanosecond,
+            TemporalUnit::Second,
+            context,
+        )?;
+
+        // 4. Let roundedNs be ! DifferenceInstant(instant.[[Nanoseconds]], other.[[Nanoseconds]], settings.[[RoundingIncrement]], settings.[[SmallestUnit]], settings.[[RoundingMode]]).
+        let unit_ns = settings.smallest_unit.nanoseconds().unwrap_or(1);
+        let rounded = settings
+            .mode
+            .round(other - epoch_ns, settings.increment as i128 * unit_ns);
+
+        // 5. Let result be ! BalanceDuration(0, 0, 0, 0, 0, 0, roundedNs, settings.[[LargestUnit]]).
+        let result = DurationRecord::balance_time(rounded, settings.largest_unit);
+
+        // 6. Return ! CreateTemporalDuration(0, 0, 0, 0, sign × result.[[Hours]], ...).
+        let result = match operation {
+            DifferenceOperation::Until => result,
+            DifferenceOperation::Since => result.negated(),
+        };
+        Ok(Duration::create(result, None, context)?.into())
+    }
+
+    /// `Temporal.Instant.prototype.until ( other [ , options ] )`
+    fn until(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let epoch_ns = Self::this_instant(this, "until")?;
+        Self::difference(DifferenceOperation::Until, epoch_ns, args, context)
+    }
+
+    /// `Temporal.Instant.prototype.since ( other [ , options ] )`
+    fn since(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let epoch_ns = Self::this_instant(this, "since")?;
+        Self::difference(DifferenceOperation::Since, epoch_ns, args, context)
+    }
+
+    /// `Temporal.Instant.prototype.round ( roundTo )`
+    fn round(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        let epoch_ns = Self::this_instant(this, "round")?;
+
+        let round_to = match args.get_or_undefined(0).variant() {
+            // 3. If roundTo is undefined, then
+            JsVariant::Undefined => {
+                // a. Throw a TypeError exception.
+                return Err(JsNativeError::typ()
+                    .with_message("Temporal.Instant.prototype.round: roundTo is required")
+                    .into());
+            }
+            // 4. If Type(roundTo) is String, then
+            JsVariant::String(string) => {
+                // a. Let paramString be roundTo.
+                // b. Set roundTo to OrdinaryObjectCreate(null).
+                // c. Perform ! CreateDataPropertyOrThrow(roundTo, "smallestUnit", paramString).
+                let round_to = get_options_object(&JsValue::undefined())?;
+                round_to.create_data_property_or_throw("smallestUnit", string.clone(), context)?;
+                round_to
+            }
+            // 5. Else,
+            //     a. Set roundTo to ? GetOptionsObject(roundTo).
+            _ => get_options_object(args.get_or_undefined(0))?,
+        };
+
+        // 6. NOTE: The following steps read options in alphabetical order.
+        // 7. Let roundingIncrement be ? ToTemporalRoundingIncrement(roundTo).
+        let increment = to_temporal_rounding_increment(&round_to, None, false, context)?;
+
+        // 8. Let roundingMode be ? ToTemporalRoundingMode(roundTo, "halfExpand").
+        let mode = to_temporal_rounding_mode(&round_to, RoundingMode::HalfExpand, context)?;
+
+        // 9. Let smallestUnit be ? GetTemporalUnit(roundTo, "smallestUnit", time, required).
+        let smallest_unit = get_temporal_unit(&round_to, "smallestUnit", UnitGroup::Time, false, context)?
+            .ok_or_else(|| {
+                JsNativeError::range()
+                    .with_message("Temporal.Instant.prototype.round: smallestUnit is required")
+            })?;
+
+        // 10-16. Let maximum be the number of smallestUnit in a solar day.
+        let unit_ns = smallest_unit.nanoseconds().unwrap_or(1);
+        let maximum = (NS_PER_DAY / unit_ns) as f64;
+
+        // 17. Perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, true).
+        if increment > maximum || maximum % increment != 0.0 {
+            return Err(JsNativeError::range()
+                .with_message("Temporal: roundingIncrement must divide a solar day")
+                .into());
+        }
+
+        // 18. Let roundedNs be ! RoundTemporalInstant(instant.[[Nanoseconds]], roundingIncrement, smallestUnit, roundingMode).
+        let rounded = mode.round(epoch_ns, increment as i128 * unit_ns);
+
+        // 19. Return ! CreateTemporalInstant(roundedNs).
+        Ok(Self::create(rounded, None, context)?.into())
+    }
+
+    /// `Temporal.Instant.prototype.equals ( other )`
+    fn equals(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        let epoch_ns = Self::this_instant(this, "equals")?;
+        // 3. Set other to ? ToTemporalInstant(other).
+        let other = to_temporal_instant(args.get_or_undefined(0), context)?;
+        // 4-5. Return whether instant.[[Nanoseconds]] equals other.[[Nanoseconds]].
+        Ok((epoch_ns == other).into())
+    }
+
+    /// `Temporal.Instant.prototype.toString ( [ options ] )`
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        let epoch_ns = Self::this_instant(this, "toString")?;
+
+        // 3. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(0))?;
+
+        // 4. NOTE: The following steps read options in alphabetical order.
+        // 5. Let precision be ? ToSecondsStringPrecision(options).
+        let precision = to_seconds_string_precision(&options, context)?;
+
+        // 6. Let roundingMode be ? ToTemporalRoundingMode(options, "trunc").
+        let mode = to_temporal_rounding_mode(&options, RoundingMode::Trunc, context)?;
+
+        // 7. Let timeZone be ? Get(options, "timeZone").
+        let time_zone = options.get("timeZone", context)?;
+
+        // 8. If timeZone is not undefined, then
+        //     a. Set timeZone to ? ToTemporalTimeZone(timeZone).
+        let time_zone = if time_zone.is_undefined() {
+            None
+        } else {
+            Some(to_temporal_time_zone(&time_zone, context)?)
+        };
+
+        // 9. Let roundedNs be ! RoundTemporalInstant(instant.[[Nanoseconds]], precision.[[Increment]], precision.[[Unit]], roundingMode).
+        let unit_ns = precision.unit.nanoseconds().unwrap_or(1);
+        let rounded = mode.round(epoch_ns, precision.increment as i128 * unit_ns);
+        if !is_valid_epoch_ns(rounded) {
+            return out_of_range();
+        }
+
+        // 10. Let roundedInstant be ! CreateTemporalInstant(roundedNs).
+        // 11. Return ? TemporalInstantToString(roundedInstant, timeZone, precision.[[Precision]]).
+        Ok(temporal_instant_to_string(rounded, time_zone.as_ref(), precision.precision).into())
+    }
+
+    /// `Temporal.Instant.prototype.toJSON ( )`
+    fn to_json(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        let epoch_ns = Self::this_instant(this, "toJSON")?;
+
+        // 3. Return ? TemporalInstantToString(instant, undefined, "auto").
+        Ok(temporal_instant_to_string(epoch_ns, None, Precision::Auto).into())
+    }
+
+    /// `Temporal.Instant.prototype.toZonedDateTime ( item )`
+    fn to_zoned_date_time(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        let epoch_ns = Self::this_instant(this, "toZonedDateTime")?;
+
+        // 3. If Type(item) is not Object, then
+        let item = args.get_or_undefined(0).as_object().cloned().ok_or_else(|| {
+            // a. Throw a TypeError exception.
+            JsNativeError::typ()
+                .with_message("Temporal.Instant.prototype.toZonedDateTime: expected an object")
+        })?;
+
+        // 4. Let calendarLike be ? Get(item, "calendar").
+        let calendar_like = item.get("calendar", context)?;
+
+        // 5. If calendarLike is undefined, then
+        if calendar_like.is_undefined() {
+            // a. Throw a TypeError exception.
+            return Err(JsNativeError::typ()
+                .with_message("Temporal.Instant.prototype.toZonedDateTime: calendar is required")
+                .into());
+        }
+
+        // 6. Let calendar be ? ToTemporalCalendar(calendarLike).
+        let calendar = to_temporal_calendar(&calendar_like, context)?;
+
+        // 7. Let temporalTimeZoneLike be ? Get(item, "timeZone").
+        let time_zone_like = item.get("timeZone", context)?;
+
+        // 8. If temporalTimeZoneLike is undefined, then
+        if time_zone_like.is_undefined() {
+            // a. Throw a TypeError exception.
+            return Err(JsNativeError::typ()
+                .with_message("Temporal.Instant.prototype.toZonedDateTime: timeZone is required")
+                .into());
+        }
+
+        // 9. Let timeZone be ? ToTemporalTimeZone(temporalTimeZoneLike).
+        let time_zone = to_temporal_time_zone(&time_zone_like, context)?;
+
+        // 10. Return ? CreateTemporalZonedDateTime(instant.[[Nanoseconds]], timeZone, calendar).
+        Ok(ZonedDateTime::create(epoch_ns, time_zone, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.Instant.prototype.toZonedDateTimeISO ( item )`
+    fn to_zoned_date_time_iso(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        let epoch_ns = Self::this_instant(this, "toZonedDateTimeISO")?;
+
+        // 3. If Type(item) is Object, then
+        let mut item = args.get_or_undefined(0).clone();
+        let object = item.as_object().cloned().filter(|object| {
+            let object = object.borrow();
+            !object.is_time_zone() && !object.is_zoned_date_time()
+        });
+        if let Some(object) = object {
+            // a. Let timeZoneProperty be ? Get(item, "timeZone").
+            let time_zone_property = object.get("timeZone", context)?;
+            // b. If timeZoneProperty is not undefined, then
+            if !time_zone_property.is_undefined() {
+                // i. Set item to timeZoneProperty.
+                item = time_zone_property;
+            }
+        }
+
+        // 4. Let timeZone be ? ToTemporalTimeZone(item).
+        let time_zone = to_temporal_time_zone(&item, context)?;
+
+        // 5. Let calendar be ! GetISO8601Calendar().
+        let calendar = Calendar::create(None, context)?;
+
+        // 6. Return ? CreateTemporalZonedDateTime(instant.[[Nanoseconds]], timeZone, calendar).
+        Ok(ZonedDateTime::create(epoch_ns, time_zone, calendar, None, context)?.into())
+    }
+}
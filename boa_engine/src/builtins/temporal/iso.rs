@@ -0,0 +1,743 @@
+//! The date and time arithmetic of the ISO 8601 calendar, shared by the `Temporal` builtins.
+//!
+//! More information:
+//!  - [Temporal reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-plaindate-abstract-ops
+
+use super::{
+    duration::DurationRecord,
+    options::{Overflow, Precision},
+    TemporalUnit,
+};
+use crate::{JsNativeError, JsResult};
+
+/// The number of nanoseconds in a day.
+pub(crate) const NS_PER_DAY: i128 = 86_400_000_000_000;
+
+/// `nsMaxInstant`, the largest epoch nanoseconds value of an `Instant`, which is 10^8 days
+/// after the epoch.
+pub(crate) const NS_MAX_INSTANT: i128 = 8_640_000_000_000_000_000_000;
+
+/// `nsMinInstant`, the smallest epoch nanoseconds value of an `Instant`.
+pub(crate) const NS_MIN_INSTANT: i128 = -NS_MAX_INSTANT;
+
+/// Abstract operation `IsValidEpochNanoseconds ( epochNanoseconds )`
+pub(crate) fn is_valid_epoch_ns(epoch_ns: i128) -> bool {
+    (NS_MIN_INSTANT..=NS_MAX_INSTANT).contains(&epoch_ns)
+}
+
+/// Throws the `RangeError` for results outside the range supported by `Temporal`.
+pub(crate) fn out_of_range<T>() -> JsResult<T> {
+    Err(JsNativeError::range()
+        .with_message("Temporal: date and time are out of the supported range")
+        .into())
+}
+
+/// Converts an integral number to an `i64`, throwing a `RangeError` if it's too big to
+/// represent a date.
+pub(crate) fn to_i64(value: f64) -> JsResult<i64> {
+    if value.abs() < 9_007_199_254_740_992.0 {
+        Ok(value as i64)
+    } else {
+        out_of_range()
+    }
+}
+
+/// Abstract operation `IsISOLeapYear ( year )`
+pub(crate) fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Abstract operation `ISODaysInYear ( year )`
+pub(crate) fn days_in_year(year: i32) -> u16 {
+    if is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+/// Abstract operation `ISODaysInMonth ( year, month )`
+pub(crate) fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        _ if is_leap_year(year) => 29,
+        _ => 28,
+    }
+}
+
+/// A date of the ISO 8601 calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct IsoDate {
+    pub(crate) year: i32,
+    pub(crate) month: u8,
+    pub(crate) day: u8,
+}
+
+impl IsoDate {
+    /// Abstract operation `IsValidISODate ( year, month, day )`
+    ///
+    /// Creates the date, throwing a `RangeError` if it's not a valid date.
+    pub(crate) fn try_new(year: f64, month: f64, day: f64) -> JsResult<Self> {
+        Self::regulate(year, month, day, Overflow::Reject)
+    }
+
+    /// Abstract operation `RegulateISODate ( year, month, day, overflow )`
+    pub(crate) fn regulate(year: f64, month: f64, day: f64, overflow: Overflow) -> JsResult<Self> {
+        // Years outside of this range are never within the supported limits.
+        if year.abs() > 1_000_000.0 {
+            return out_of_range();
+        }
+        let year = year as i32;
+
+        let (month, day) = match overflow {
+            // 1. If overflow is "constrain", then
+            Overflow::Constrain => {
+                // a. Set month to the result of clamping month between 1 and 12.
+                let month = month.clamp(1.0, 12.0) as u8;
+                // b. Let daysInMonth be ! ISODaysInMonth(year, month).
+                // c. Set day to the result of clamping day between 1 and daysInMonth.
+                let day = day.clamp(1.0, f64::from(days_in_month(year, month))) as u8;
+                (month, day)
+            }
+            // 2. Else,
+            Overflow::Reject => {
+                // a. Assert: overflow is "reject".
+                // b. If IsValidISODate(year, month, day) is false, throw a RangeError exception.
+                if !(1.0..=12.0).contains(&month)
+                    || day < 1.0
+                    || day > f64::from(days_in_month(year, month as u8))
+                {
+                    return Err(JsNativeError::range()
+                        .with_message("Temporal: invalid ISO date")
+                        .into());
+                }
+                (month as u8, day as u8)
+            }
+        };
+
+        // 3. Return the Record { [[Year]]: year, [[Month]]: month, [[Day]]: day }.
+        Ok(Self { year, month, day })
+    }
+
+    /// Gets the number of days from the epoch, `1970-01-01`, to the date.
+    pub(crate) fn epoch_days(self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let month = i64::from(self.month);
+        let month = if month > 2 { month - 3 } else { month + 9 };
+        let day_of_year = (153 * month + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Creates the date that is `days` days after the epoch.
+    ///
+    /// `days` must be small enough for the year to fit in an `i32`.
+    pub(crate) fn from_epoch_days(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month + 2) / 5 + 1;
+        let month = if month < 10 { month + 3 } else { month - 9 };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        Self {
+            year: year as i32,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+
+    /// Abstract operation `BalanceISODate ( year, month, day )`
+    ///
+    /// `month` is one-based, and both `month` and `day` can be out of their usual ranges.
+    pub(crate) fn balance(year: i64, month: i64, day: i64) -> JsResult<Self> {
+        // 1. Let balancedYearMonth be ! BalanceISOYearMonth(year, month).
+        let year = year + (month - 1).div_euclid(12);
+        let month = (month - 1).rem_euclid(12) + 1;
+        if year.abs() > 1_000_000 || day.abs() > 1_000_000_000 {
+            return out_of_range();
+        }
+
+        // 2-3. Let epochDays be MakeDay(year, month - 1, 1) + day - 1.
+        let first = Self {
+            year: year as i32,
+            month: month as u8,
+            day: 1,
+        };
+
+        // 4. Return the date of epochDays.
+        Ok(Self::from_epoch_days(first.epoch_days() + day - 1))
+    }
+
+    /// Abstract operation `ISODateWithinLimits ( year, month, day )`
+    pub(crate) fn is_within_limits(self) -> bool {
+        // 1. Return ISODateTimeWithinLimits(year, month, day, 12, 0, 0, 0, 0, 0).
+        IsoDateTime {
+            date: self,
+            time: IsoTime {
+                hour: 12,
+                ..IsoTime::default()
+            },
+        }
+        .is_within_limits()
+    }
+
+    /// Abstract operation `ToISODayOfWeek ( year, month, day )`
+    ///
+    /// Monday is `1` and Sunday is `7`.
+    pub(crate) fn day_of_week(self) -> u8 {
+        ((self.epoch_days() + 3).rem_euclid(7) + 1) as u8
+    }
+
+    /// Abstract operation `ToISODayOfYear ( year, month, day )`
+    pub(crate) fn day_of_year(self) -> u16 {
+        let first = Self {
+            year: self.year,
+            month: 1,
+            day: 1,
+        };
+        (self.epoch_days() - first.epoch_days() + 1) as u16
+    }
+
+    /// Abstract operation `ToISOWeekOfYear ( year, month, day )`
+    pub(crate) fn week_of_year(self) -> u8 {
+        // Gets the number of ISO weeks in `year`, which is 53 if the year starts on a Thursday,
+        // or is a leap year starting on a Wednesday.
+        fn weeks_in_year(year: i32) -> i32 {
+            let first = IsoDate {
+                year,
+                month: 1,
+                day: 1,
+            }
+            .day_of_week();
+            if first == 4 || (first == 3 && is_leap_year(year)) {
+                53
+            } else {
+                52
+            }
+        }
+
+        let week =
+            (i32::from(self.day_of_year()) - i32::from(self.day_of_week()) + 10).div_euclid(7);
+        if week < 1 {
+            weeks_in_year(self.year - 1) as u8
+        } else if week > weeks_in_year(self.year) {
+            1
+        } else {
+            week as u8
+        }
+    }
+
+    /// Abstract operation `AddISODate ( year, month, day, years, months, weeks, days, overflow )`
+    pub(crate) fn add(
+        self,
+        years: f64,
+        months: f64,
+        weeks: f64,
+        days: f64,
+        overflow: Overflow,
+    ) -> JsResult<Self> {
+        // 1. Assert: year, month, day, years, months, weeks, and days are integers.
+        // 2. Assert: overflow is either "constrain" or "reject".
+        // 3. Let intermediate be ! BalanceISOYearMonth(year + years, month + months).
+        let year = i64::from(self.year) + to_i64(years)?;
+        let month = i64::from(self.month) + to_i64(months)?;
+        let year = year + (month - 1).div_euclid(12);
+        let month = (month - 1).rem_euclid(12) + 1;
+
+        // 4. Let intermediate be ? RegulateISODate(intermediate.[[Year]], intermediate.[[Month]], day, overflow).
+        let intermediate = Self::regulate(
+            year as f64,
+            month as f64,
+            f64::from(self.day),
+            overflow,
+        )?;
+
+        // 5. Set days to days + 7 × weeks.
+        let days = to_i64(days)? + 7 * to_i64(weeks)?;
+
+        // 6. Let d be intermediate.[[Day]] + days.
+        // 7. Return BalanceISODate(intermediate.[[Year]], intermediate.[[Month]], d).
+        let result = Self::balance(
+            i64::from(intermediate.year),
+            i64::from(intermediate.month),
+            i64::from(intermediate.day) + days,
+        )?;
+        if !result.is_within_limits() {
+            return out_of_range();
+        }
+        Ok(result)
+    }
+
+    /// Abstract operation `DifferenceISODate ( y1, m1, d1, y2, m2, d2, largestUnit )`
+    ///
+    /// Returns the years, months, weeks and days from `self` to `other`.
+    pub(crate) fn difference(self, other: Self, largest_unit: TemporalUnit) -> DurationRecord {
+        // 1. If largestUnit is "year" or "month", then
+        if matches!(largest_unit, TemporalUnit::Year | TemporalUnit::Month) {
+            // a. Let sign be -(! CompareISODate(y1, m1, d1, y2, m2, d2)).
+            let sign: i64 = match other.cmp(&self) {
+                std::cmp::Ordering::Less => -1,
+                std::cmp::Ordering::Equal => return DurationRecord::default(),
+                std::cmp::Ordering::Greater => 1,
+            };
+
+            let constrain = |years: i64, months: i64| {
+                let month = i64::from(self.month) + months;
+                let year = i64::from(self.year) + years + (month - 1).div_euclid(12);
+                let month = ((month - 1).rem_euclid(12) + 1) as u8;
+                let year = year as i32;
+                Self {
+                    year,
+                    month,
+                    day: self.day.min(days_in_month(year, month)),
+                }
+            };
+            let compare = |mid: Self| match other.cmp(&mid) {
+                std::cmp::Ordering::Less => -1,
+                std::cmp::Ordering::Equal => 0,
+                std::cmp::Ordering::Greater => 1,
+            };
+
+            // c. Let years be end.[[Year]] - start.[[Year]].
+            let mut years = i64::from(other.year) - i64::from(self.year);
+
+            // d. Let mid be ! AddISODate(y1, m1, d1, years, 0, 0, 0, "constrain").
+            let mut mid = constrain(years, 0);
+
+            // e. Let midSign be -(! CompareISODate(mid.[[Year]], mid.[[Month]], mid.[[Day]], y2, m2, d2)).
+            // f. If midSign is 0, then
+            if compare(mid) == 0 {
+                // i. If largestUnit is "year", return ! CreateDateDurationRecord(years, 0, 0, 0).
+                // ii. Return ! CreateDateDurationRecord(0, years × 12, 0, 0).
+                return if largest_unit == TemporalUnit::Year {
+                    DurationRecord::from_date(years as f64, 0.0, 0.0, 0.0)
+                } else {
+                    DurationRecord::from_date(0.0, (years * 12) as f64, 0.0, 0.0)
+                };
+            }
+
+            // g. Let months be end.[[Month]] - start.[[Month]].
+            let mut months = i64::from(other.month) - i64::from(self.month);
+
+            // h. If midSign is not equal to sign, then
+            if compare(mid) != sign {
+                // i. Set years to years - sign.
+                years -= sign;
+                // ii. Set months to months + (sign × 12).
+                months += sign * 12;
+            }
+
+            // i. Set mid to ! AddISODate(y1, m1, d1, years, months, 0, 0, "constrain").
+            mid = constrain(years, months);
+
+            // j. Set midSign to -(! CompareISODate(mid.[[Year]], mid.[[Month]], mid.[[Day]], y2, m2, d2)).
+            // k. If midSign is 0, then
+            if compare(mid) == 0 {
+                // i. If largestUnit is "year", return ! CreateDateDurationRecord(years, months, 0, 0).
+                // ii. Return ! CreateDateDurationRecord(0, months + years × 12, 0, 0).
+                return if largest_unit == TemporalUnit::Year {
+                    DurationRecord::from_date(years as f64, months as f64, 0.0, 0.0)
+                } else {
+                    DurationRecord::from_date(0.0, (months + years * 12) as f64, 0.0, 0.0)
+                };
+            }
+
+            // l. If midSign is not equal to sign, then
+            if compare(mid) != sign {
+                // i. Set months to months - sign.
+                months -= sign;
+                // ii. If months is equal to -sign, then
+                if months == -sign {
+                    // 1. Set years to years - sign.
+                    years -= sign;
+                    // 2. Set months to 11 × sign.
+                    months = 11 * sign;
+                }
+                // iii. Set mid to ! AddISODate(y1, m1, d1, years, months, 0, 0, "constrain").
+                mid = constrain(years, months);
+            }
+
+            // m. If mid.[[Month]] = end.[[Month]], then
+            let days = if mid.year == other.year && mid.month == other.month {
+                // i. Assert: mid.[[Year]] = end.[[Year]].
+                // ii. Let days be end.[[Day]] - mid.[[Day]].
+                i64::from(other.day) - i64::from(mid.day)
+            // n. Else if sign < 0, let days be -mid.[[Day]] - (! ISODaysInMonth(end.[[Year]], end.[[Month]]) - end.[[Day]]).
+            } else if sign < 0 {
+                -i64::from(mid.day)
+                    - (i64::from(days_in_month(other.year, other.month)) - i64::from(other.day))
+            // o. Else, let days be end.[[Day]] + (! ISODaysInMonth(mid.[[Year]], mid.[[Month]]) - mid.[[Day]]).
+            } else {
+                i64::from(other.day)
+                    + (i64::from(days_in_month(mid.year, mid.month)) - i64::from(mid.day))
+            };
+
+            // p. If largestUnit is "month", then
+            if largest_unit == TemporalUnit::Month {
+                // i. Set months to months + years × 12.
+                months += years * 12;
+                // ii. Set years to 0.
+                years = 0;
+            }
+
+            // q. Return ! CreateDateDurationRecord(years, months, 0, days).
+            return DurationRecord::from_date(years as f64, months as f64, 0.0, days as f64);
+        }
+
+        // 2. Else,
+        //     a. Assert: largestUnit is "day" or "week".
+        //     b-d. Let days be the number of days from the first date to the second.
+        let mut days = other.epoch_days() - self.epoch_days();
+
+        //     e. Let weeks be 0.
+        let mut weeks = 0;
+
+        //     f. If largestUnit is "week", then
+        if largest_unit == TemporalUnit::Week {
+            // i. Set weeks to RoundTowardsZero(days / 7).
+            weeks = days / 7;
+            // ii. Set days to remainder(days, 7).
+            days %= 7;
+        }
+
+        //     g. Return ! CreateDateDurationRecord(0, 0, weeks, days).
+        DurationRecord::from_date(0.0, 0.0, weeks as f64, days as f64)
+    }
+}
+
+/// A wall-clock time, without a date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub(crate) struct IsoTime {
+    pub(crate) hour: u8,
+    pub(crate) minute: u8,
+    pub(crate) second: u8,
+    pub(crate) millisecond: u16,
+    pub(crate) microsecond: u16,
+    pub(crate) nanosecond: u16,
+}
+
+impl IsoTime {
+    /// The time `00:00`.
+    pub(crate) const MIDNIGHT: Self = Self {
+        hour: 0,
+        minute: 0,
+        second: 0,
+        millisecond: 0,
+        microsecond: 0,
+        nanosecond: 0,
+    };
+
+    /// Abstract operation `RegulateTime ( hour, minute, second, millisecond, microsecond, nanosecond, overflow )`
+    ///
+    /// The fields are the hour, minute, second, millisecond, microsecond and nanosecond, in that
+    /// order.
+    pub(crate) fn regulate(fields: [f64; 6], overflow: Overflow) -> JsResult<Self> {
+        const MAXIMUMS: [f64; 6] = [23.0, 59.0, 59.0, 999.0, 999.0, 999.0];
+
+        let mut fields = fields;
+        for (field, maximum) in fields.iter_mut().zip(MAXIMUMS) {
+            match overflow {
+                // 2. If overflow is "constrain", then
+                //     a. Return ! ConstrainTime(hour, minute, second, millisecond, microsecond, nanosecond).
+                Overflow::Constrain => *field = field.clamp(0.0, maximum),
+                // 3. Else,
+                //     b. If IsValidTime(hour, minute, second, millisecond, microsecond, nanosecond) is false,
+                //        throw a RangeError exception.
+                Overflow::Reject => {
+                    if !(0.0..=maximum).contains(field) {
+                        return Err(JsNativeError::range()
+                            .with_message("Temporal: invalid time")
+                            .into());
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            hour: fields[0] as u8,
+            minute: fields[1] as u8,
+            second: fields[2] as u8,
+            millisecond: fields[3] as u16,
+            microsecond: fields[4] as u16,
+            nanosecond: fields[5] as u16,
+        })
+    }
+
+    /// Gets the number of nanoseconds since midnight.
+    pub(crate) fn to_ns(self) -> i128 {
+        i128::from(self.hour) * 3_600_000_000_000
+            + i128::from(self.minute) * 60_000_000_000
+            + i128::from(self.second) * 1_000_000_000
+            + i128::from(self.millisecond) * 1_000_000
+            + i128::from(self.microsecond) * 1_000
+            + i128::from(self.nanosecond)
+    }
+
+    /// Abstract operation `BalanceTime ( hour, minute, second, millisecond, microsecond, nanosecond )`
+    ///
+    /// Returns the number of whole days in `ns` nanoseconds after midnight, and the wall-clock
+    /// time of the remainder.
+    pub(crate) fn balance(ns: i128) -> (i128, Self) {
+        let days = ns.div_euclid(NS_PER_DAY);
+        let ns = ns.rem_euclid(NS_PER_DAY);
+        let time = Self {
+            hour: (ns / 3_600_000_000_000) as u8,
+            minute: (ns / 60_000_000_000 % 60) as u8,
+            second: (ns / 1_000_000_000 % 60) as u8,
+            millisecond: (ns / 1_000_000 % 1000) as u16,
+            microsecond: (ns / 1_000 % 1000) as u16,
+            nanosecond: (ns % 1000) as u16,
+        };
+        (days, time)
+    }
+
+    /// Abstract operation `AddTime ( hour, minute, second, millisecond, microsecond, nanosecond, hours, minutes, seconds, milliseconds, microseconds, nanoseconds )`
+    ///
+    /// Returns the number of days crossed, and the resulting wall-clock time.
+    pub(crate) fn add(self, duration: &DurationRecord) -> JsResult<(i128, Self)> {
+        Ok(Self::balance(self.to_ns() + duration.time_ns()?))
+    }
+
+    /// Abstract operation `RoundTime ( hour, minute, second, millisecond, microsecond, nanosecond, increment, unit, roundingMode [ , dayLengthNs ] )`
+    ///
+    /// Returns the number of days crossed, and the rounded wall-clock time.
+    pub(crate) fn round(
+        self,
+        increment: f64,
+        unit: TemporalUnit,
+        mode: super::options::RoundingMode,
+        day_length_ns: Option<i128>,
+    ) -> (i128, Self) {
+        let unit_ns = match unit {
+            TemporalUnit::Day => day_length_ns.unwrap_or(NS_PER_DAY),
+            unit => unit.nanoseconds().unwrap_or(1),
+        };
+        let rounded = mode.round(self.to_ns(), increment as i128 * unit_ns);
+        if unit == TemporalUnit::Day {
+            return (rounded / unit_ns, Self::MIDNIGHT);
+        }
+        Self::balance(rounded)
+    }
+}
+
+/// A date and a wall-clock time of the ISO 8601 calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct IsoDateTime {
+    pub(crate) date: IsoDate,
+    pub(crate) time: IsoTime,
+}
+
+impl IsoDateTime {
+    /// Gets the number of nanoseconds from the epoch to the date and time, as if they were in UTC.
+    ///
+    /// This is `GetUTCEpochNanoseconds`.
+    pub(crate) fn to_local_ns(self) -> i128 {
+        i128::from(self.date.epoch_days()) * NS_PER_DAY + self.time.to_ns()
+    }
+
+    /// Creates the date and time that is `ns` nanoseconds after the epoch, in UTC.
+    ///
+    /// `ns` must be close enough to the range of the `Instant`s for the year to fit in an `i32`.
+    pub(crate) fn from_local_ns(ns: i128) -> Self {
+        let (days, time) = IsoTime::balance(ns);
+        Self {
+            date: IsoDate::from_epoch_days(days as i64),
+            time,
+        }
+    }
+
+    /// Abstract operation `ISODateTimeWithinLimits ( year, month, day, hour, minute, second, millisecond, microsecond, nanosecond )`
+    pub(crate) fn is_within_limits(self) -> bool {
+        // This check avoids overflowing the computation below.
+        if self.date.year.abs() > 300_000 {
+            return false;
+        }
+
+        // 2. Let ns be ℝ(GetUTCEpochNanoseconds(year, month, day, hour, minute, second, millisecond, microsecond, nanosecond)).
+        let ns = self.to_local_ns();
+
+        // 3. If ns ≤ nsMinInstant - nsPerDay, then return false.
+        // 4. If ns ≥ nsMaxInstant + nsPerDay, then return false.
+        // 5. Return true.
+        ns > NS_MIN_INSTANT - NS_PER_DAY && ns < NS_MAX_INSTANT + NS_PER_DAY
+    }
+
+    /// Abstract operation `AddDateTime ( year, month, day, hour, minute, second, millisecond, microsecond, nanosecond, calendar, years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds, options )`
+    pub(crate) fn add(self, duration: &DurationRecord, overflow: Overflow) -> JsResult<Self> {
+        // 1. Let timeResult be ! AddTime(hour, minute, second, millisecond, microsecond, nanosecond, hours, minutes, seconds, milliseconds, microseconds, nanoseconds).
+        let (days, time) = self.time.add(duration)?;
+
+        // 2-5. Let addedDate be ? CalendarDateAdd(calendar, datePart, dateDuration, options).
+        let days = duration.days + days as f64;
+        let date = self.date.add(
+            duration.years,
+            duration.months,
+            duration.weeks,
+            days,
+            overflow,
+        )?;
+
+        // 6. Return the Record { [[Year]]: addedDate.[[ISOYear]], ... }.
+        let result = Self { date, time };
+        if !result.is_within_limits() {
+            return out_of_range();
+        }
+        Ok(result)
+    }
+
+    /// Abstract operation `DifferenceISODateTime ( y1, mon1, d1, h1, min1, s1, ms1, mus1, ns1, y2, mon2, d2, h2, min2, s2, ms2, mus2, ns2, calendar, largestUnit, options )`
+    pub(crate) fn difference(self, other: Self, largest_unit: TemporalUnit) -> DurationRecord {
+        // 1-2. Let timeDifference be ! DifferenceTime(h1, min1, s1, ms1, mus1, ns1, h2, min2, s2, ms2, mus2, ns2).
+        let mut time_difference = other.time.to_ns() - self.time.to_ns();
+
+        // 3. Let timeSign be ! DurationSign(0, 0, 0, 0, timeDifference.[[Hours]], ...).
+        let time_sign = time_difference.signum();
+
+        // 4. Let dateSign be ! CompareISODate(y2, mon2, d2, y1, mon1, d1).
+        let date_sign = match other.date.cmp(&self.date) {
+            std::cmp::Ordering::Less => -1,
+            std::cmp::Ordering::Equal => 0,
+            std::cmp::Ordering::Greater => 1,
+        };
+
+        // 5. Let adjustedDate be CreateISODateRecord(y1, mon1, d1).
+        let mut adjusted_date = self.date;
+
+        // 6. If timeSign is -dateSign, then
+        if time_sign == -date_sign {
+            // a. Set adjustedDate to BalanceISODate(adjustedDate.[[Year]], adjustedDate.[[Month]], adjustedDate.[[Day]] - timeSign).
+            adjusted_date = IsoDate::from_epoch_days(adjusted_date.epoch_days() - time_sign as i64);
+            // b. Set timeDifference to ! BalanceDuration(-timeSign, timeDifference.[[Hours]], ...,
+            //    largestUnit).
+            time_difference += time_sign * NS_PER_DAY;
+        }
+
+        // 7-10. Let dateDifference be ? CalendarDateUntil(calendar, date1, date2, untilOptions).
+        let date_largest_unit = TemporalUnit::Day.larger(largest_unit);
+        let date_difference = adjusted_date.difference(other.date, date_largest_unit);
+
+        // 11. Let balanceResult be ? BalanceDuration(dateDifference.[[Days]], timeDifference.[[Hours]], ..., largestUnit).
+        let balanced = DurationRecord::balance_time(
+            date_difference.days as i128 * NS_PER_DAY + time_difference,
+            largest_unit,
+        );
+
+        // 12. Return ! CreateDurationRecord(dateDifference.[[Years]], dateDifference.[[Months]], dateDifference.[[Weeks]], balanceResult.[[Days]], ...).
+        DurationRecord {
+            years: date_difference.years,
+            months: date_difference.months,
+            weeks: date_difference.weeks,
+            ..balanced
+        }
+    }
+
+    /// Abstract operation `RoundISODateTime ( year, month, day, hour, minute, second, millisecond, microsecond, nanosecond, increment, unit, roundingMode [ , dayLength ] )`
+    pub(crate) fn round(
+        self,
+        increment: f64,
+        unit: TemporalUnit,
+        mode: super::options::RoundingMode,
+        day_length_ns: Option<i128>,
+    ) -> JsResult<Self> {
+        // 3. Let roundedTime be ! RoundTime(hour, minute, second, millisecond, microsecond, nanosecond, increment, unit, roundingMode, dayLength).
+        let (days, time) = self.time.round(increment, unit, mode, day_length_ns);
+
+        // 4. Let balanceResult be BalanceISODate(year, month, day + roundedTime.[[Days]]).
+        let date = IsoDate::from_epoch_days(self.date.epoch_days() + days as i64);
+
+        // 5. Return the Record { [[Year]]: balanceResult.[[Year]], ... }.
+        let result = Self { date, time };
+        if !result.is_within_limits() {
+            return out_of_range();
+        }
+        Ok(result)
+    }
+}
+
+impl IsoDate {
+    /// Formats the date as `YYYY-MM-DD`, with six digits and a sign for the years outside of
+    /// the range `0..=9999`.
+    ///
+    /// This is `TemporalDateToString` without the calendar annotation.
+    pub(crate) fn to_iso_string(self) -> String {
+        // PadISOYear ( y )
+        let year = if (0..=9999).contains(&self.year) {
+            format!("{:04}", self.year)
+        } else if self.year < 0 {
+            format!("-{:06}", -i64::from(self.year))
+        } else {
+            format!("+{:06}", self.year)
+        };
+        format!("{year}-{:02}-{:02}", self.month, self.day)
+    }
+}
+
+impl IsoTime {
+    /// Formats the time as `HH:MM:SS.fffffffff`, with the given precision of the seconds.
+    ///
+    /// This is `TemporalTimeToString`.
+    pub(crate) fn to_iso_string(self, precision: Precision) -> String {
+        let mut result = format!("{:02}:{:02}", self.hour, self.minute);
+
+        // FormatSecondsStringPart ( second, millisecond, microsecond, nanosecond, precision )
+        // 1. If precision is "minute", return "".
+        if precision == Precision::Minute {
+            return result;
+        }
+
+        // 2. Let secondsString be the string-concatenation of the code unit 0x003A (COLON) and
+        //    ToZeroPaddedDecimalString(second, 2).
+        result.push_str(&format!(":{:02}", self.second));
+
+        // 3. Let fraction be millisecond × 10^6 + microsecond × 10^3 + nanosecond.
+        let fraction = u32::from(self.millisecond) * 1_000_000
+            + u32::from(self.microsecond) * 1_000
+            + u32::from(self.nanosecond);
+        let fraction = format!("{fraction:09}");
+
+        let fraction = match precision {
+            // 4. If precision is "auto", then
+            //     a. If fraction is 0, return secondsString.
+            //     b. Set fraction to ToZeroPaddedDecimalString(fraction, 9).
+            //     c. Set fraction to the longest possible substring of fraction starting at
+            //        position 0 and not ending with the code unit 0x0030 (DIGIT ZERO).
+            Precision::Auto => fraction.trim_end_matches('0'),
+            // 5. Else,
+            //     a. If precision is 0, return secondsString.
+            //     b. Set fraction to ToZeroPaddedDecimalString(fraction, 9)
+            //     c. Set fraction to the substring of fraction from 0 to precision.
+            Precision::Digits(digits) => &fraction[..usize::from(digits)],
+            Precision::Minute => "",
+        };
+
+        // 6. Return the string-concatenation of secondsString, the code unit 0x002E (FULL STOP),
+        //    and fraction.
+        if !fraction.is_empty() {
+            result.push('.');
+            result.push_str(fraction);
+        }
+        result
+    }
+}
+
+impl IsoDateTime {
+    /// Formats the date and time as `YYYY-MM-DDTHH:MM:SS.fffffffff`.
+    ///
+    /// This is `TemporalDateTimeToString` without the calendar annotation.
+    pub(crate) fn to_iso_string(self, precision: Precision) -> String {
+        format!(
+            "{}T{}",
+            self.date.to_iso_string(),
+            self.time.to_iso_string(precision)
+        )
+    }
+}
@@ -0,0 +1,333 @@
+//! This module implements the global `Temporal` object.
+//!
+//! `Temporal` is a built-in namespace object that provides date and time types with correct
+//! calendar and time zone arithmetic. It's not a function object.
+//!
+//! Only the ISO 8601 calendar is supported, and time zones are either UTC offsets or the named
+//! time zones of the IANA time zone database embedded in the engine. User defined calendar and
+//! time zone objects are not supported.
+//!
+//! More information:
+//!  - [Temporal proposal][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal
+
+pub mod calendar;
+pub mod duration;
+pub mod instant;
+pub mod now;
+pub mod plain_date;
+pub mod plain_date_time;
+pub mod plain_time;
+pub mod time_zone;
+pub mod zoned_date_time;
+
+mod fields;
+mod iso;
+mod options;
+mod parser;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::{
+    calendar::Calendar, duration::Duration, instant::Instant, plain_date::PlainDate,
+    plain_date_time::PlainDateTime, plain_time::PlainTime, time_zone::TimeZone,
+    zoned_date_time::ZonedDateTime,
+};
+
+use crate::{
+    bigint::RawBigInt,
+    builtins::{function::NativeFunctionSignature, BuiltIn},
+    object::{ConstructorBuilder, FunctionBuilder, JsObject, ObjectInitializer},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsBigInt, JsNativeError, JsResult, JsValue,
+};
+use boa_profiler::Profiler;
+use num_traits::ToPrimitive;
+use tap::{Conv, Pipe};
+
+/// JavaScript `Temporal` object.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Temporal;
+
+impl BuiltIn for Temporal {
+    const NAME: &'static str = "Temporal";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let now = now::Now::init(context);
+        let instant = Instant::init(context);
+        let plain_date = PlainDate::init(context);
+        let plain_time = PlainTime::init(context);
+        let plain_date_time = PlainDateTime::init(context);
+        let zoned_date_time = ZonedDateTime::init(context);
+        let duration = Duration::init(context);
+        let time_zone = TimeZone::init(context);
+        let calendar = Calendar::init(context);
+
+        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        ObjectInitializer::new(context)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property("Now", now, attribute)
+            .property("Instant", instant, attribute)
+            .property("PlainDate", plain_date, attribute)
+            .property("PlainTime", plain_time, attribute)
+            .property("PlainDateTime", plain_date_time, attribute)
+            .property("ZonedDateTime", zoned_date_time, attribute)
+            .property("Duration", duration, attribute)
+            .property("TimeZone", time_zone, attribute)
+            .property("Calendar", calendar, attribute)
+            .build()
+            .conv::<JsValue>()
+            .pipe(Some)
+    }
+}
+
+/// The units of time used by the `Temporal` builtins, from the largest to the smallest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum TemporalUnit {
+    Year,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    Millisecond,
+    Microsecond,
+    Nanosecond,
+}
+
+impl TemporalUnit {
+    /// All the units, from the largest to the smallest.
+    pub(crate) const ALL: [Self; 10] = [
+        Self::Year,
+        Self::Month,
+        Self::Week,
+        Self::Day,
+        Self::Hour,
+        Self::Minute,
+        Self::Second,
+        Self::Millisecond,
+        Self::Microsecond,
+        Self::Nanosecond,
+    ];
+
+    /// Parses the singular or plural name of a unit.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        let unit = match name {
+            "year" | "years" => Self::Year,
+            "month" | "months" => Self::Month,
+            "week" | "weeks" => Self::Week,
+            "day" | "days" => Self::Day,
+            "hour" | "hours" => Self::Hour,
+            "minute" | "minutes" => Self::Minute,
+            "second" | "seconds" => Self::Second,
+            "millisecond" | "milliseconds" => Self::Millisecond,
+            "microsecond" | "microseconds" => Self::Microsecond,
+            "nanosecond" | "nanoseconds" => Self::Nanosecond,
+            _ => return None,
+        };
+        Some(unit)
+    }
+
+    /// Returns `true` if the length of the unit depends on the calendar.
+    pub(crate) fn is_calendar_unit(self) -> bool {
+        self <= Self::Week
+    }
+
+    /// Returns `true` if the unit is a day or a larger unit.
+    pub(crate) fn is_date_unit(self) -> bool {
+        self <= Self::Day
+    }
+
+    /// `LargerOfTwoTemporalUnits ( u1, u2 )`
+    pub(crate) fn larger(self, other: Self) -> Self {
+        self.min(other)
+    }
+
+    /// Gets the length of the unit in nanoseconds, taking days as 24 hours.
+    ///
+    /// Returns `None` for the calendar units, which have no fixed length.
+    pub(crate) fn nanoseconds(self) -> Option<i128> {
+        let ns = match self {
+            Self::Year | Self::Month | Self::Week => return None,
+            Self::Day => iso::NS_PER_DAY,
+            Self::Hour => 3_600_000_000_000,
+            Self::Minute => 60_000_000_000,
+            Self::Second => 1_000_000_000,
+            Self::Millisecond => 1_000_000,
+            Self::Microsecond => 1_000,
+            Self::Nanosecond => 1,
+        };
+        Some(ns)
+    }
+
+    /// `MaximumTemporalDurationRoundingIncrement ( unit )`
+    pub(crate) fn maximum_rounding_increment(self) -> Option<f64> {
+        match self {
+            Self::Year | Self::Month | Self::Week | Self::Day => None,
+            Self::Hour => Some(24.0),
+            Self::Minute | Self::Second => Some(60.0),
+            Self::Millisecond | Self::Microsecond | Self::Nanosecond => Some(1000.0),
+        }
+    }
+}
+
+/// Abstract operation `ToIntegerWithTruncation ( argument )`
+///
+/// More information:
+///  - [Temporal reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-tointegerwithtruncation
+pub(crate) fn to_integer_with_truncation(value: &JsValue, context: &mut Context) -> JsResult<f64> {
+    // 1. Let number be ? ToNumber(argument).
+    let number = value.to_number(context)?;
+
+    // 2. If number is NaN, +∞𝔽 or -∞𝔽, throw a RangeError exception.
+    if !number.is_finite() {
+        return Err(JsNativeError::range()
+            .with_message("Temporal: expected a finite number")
+            .into());
+    }
+
+    // 3. Return truncate(ℝ(number)).
+    Ok(number.trunc() + 0.0)
+}
+
+/// Abstract operation `ToPositiveIntegerWithTruncation ( argument )`
+///
+/// More information:
+///  - [Temporal reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-topositiveintegerwithtruncation
+pub(crate) fn to_positive_integer_with_truncation(
+    value: &JsValue,
+    context: &mut Context,
+) -> JsResult<f64> {
+    // 1. Let integer be ? ToIntegerWithTruncation(argument).
+    let integer = to_integer_with_truncation(value, context)?;
+
+    // 2. If integer ≤ 0, throw a RangeError exception.
+    if integer <= 0.0 {
+        return Err(JsNativeError::range()
+            .with_message("Temporal: expected a positive integer")
+            .into());
+    }
+
+    // 3. Return integer.
+    Ok(integer)
+}
+
+/// Abstract operation `ToIntegerIfIntegral ( argument )`
+///
+/// More information:
+///  - [Temporal reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-tointegerifintegral
+pub(crate) fn to_integer_if_integral(value: &JsValue, context: &mut Context) -> JsResult<f64> {
+    // 1. Let number be ? ToNumber(argument).
+    let number = value.to_number(context)?;
+
+    // 2. If IsIntegralNumber(number) is false, throw a RangeError exception.
+    #[allow(clippy::float_cmp)]
+    if !number.is_finite() || number.trunc() != number {
+        return Err(JsNativeError::range()
+            .with_message("Temporal: expected an integral number")
+            .into());
+    }
+
+    // 3. Return ℝ(number).
+    Ok(number + 0.0)
+}
+
+/// Converts an amount of nanoseconds to a `BigInt` value.
+pub(crate) fn ns_to_bigint(ns: i128) -> JsValue {
+    JsBigInt::new(RawBigInt::from(ns)).into()
+}
+
+/// Converts a `BigInt` to an amount of nanoseconds, throwing a `RangeError` if it doesn't fit.
+pub(crate) fn bigint_to_ns(bigint: &JsBigInt) -> JsResult<i128> {
+    bigint.as_inner().to_i128().ok_or_else(|| {
+        JsNativeError::range()
+            .with_message("Temporal: epoch nanoseconds are out of range")
+            .into()
+    })
+}
+
+/// Adds a read-only accessor property named `name` with the getter `get` to the prototype of
+/// the constructor being built.
+pub(crate) fn getter(builder: &mut ConstructorBuilder<'_>, name: &str, get: NativeFunctionSignature) {
+    let function = FunctionBuilder::native(builder.context(), get)
+        .name(format!("get {name}"))
+        .build();
+    builder.accessor(name, Some(function), None, Attribute::CONFIGURABLE);
+}
+
+/// Throws the `TypeError` for `valueOf` methods of the `Temporal` types, which can't be
+/// compared with the relational operators.
+pub(crate) fn value_of(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+    // 1. Throw a TypeError exception.
+    Err(JsNativeError::typ()
+        .with_message("Temporal objects cannot be converted to primitive values, use compare or equals instead")
+        .into())
+}
+
+/// Abstract operation `RejectObjectWithCalendarOrTimeZone ( object )`
+///
+/// More information:
+///  - [Temporal reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-rejectobjectwithcalendarortimezone
+pub(crate) fn reject_object_with_calendar_or_time_zone(
+    object: &JsObject,
+    context: &mut Context,
+) -> JsResult<()> {
+    // 1. Assert: Type(object) is Object.
+    // 2. If object has an [[InitializedTemporalDate]], [[InitializedTemporalDateTime]],
+    //    [[InitializedTemporalMonthDay]], [[InitializedTemporalTime]],
+    //    [[InitializedTemporalYearMonth]], or [[InitializedTemporalZonedDateTime]] internal slot,
+    //    then
+    {
+        let object = object.borrow();
+        if object.is_plain_date()
+            || object.is_plain_date_time()
+            || object.is_plain_time()
+            || object.is_zoned_date_time()
+        {
+            // a. Throw a TypeError exception.
+            return Err(JsNativeError::typ()
+                .with_message("Temporal: unexpected Temporal object")
+                .into());
+        }
+    }
+
+    // 3. Let calendarProperty be ? Get(object, "calendar").
+    // 4. If calendarProperty is not undefined, then
+    if !object.get("calendar", context)?.is_undefined() {
+        // a. Throw a TypeError exception.
+        return Err(JsNativeError::typ()
+            .with_message("Temporal: unexpected calendar property")
+            .into());
+    }
+
+    // 5. Let timeZoneProperty be ? Get(object, "timeZone").
+    // 6. If timeZoneProperty is not undefined, then
+    if !object.get("timeZone", context)?.is_undefined() {
+        // a. Throw a TypeError exception.
+        return Err(JsNativeError::typ()
+            .with_message("Temporal: unexpected timeZone property")
+            .into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,184 @@
+//! This module implements the `Temporal.Now` object.
+//!
+//! `Temporal.Now` is a namespace object with functions to get the current instant, the time zone
+//! of the host and the current date and time in a time zone.
+//!
+//! More information:
+//!  - [Temporal reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-now-object
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Now
+
+use super::{
+    calendar::{to_temporal_calendar, Calendar},
+    iso::IsoDateTime,
+    time_zone::{to_temporal_time_zone, TimeZone, TimeZoneKind},
+    Instant, PlainDate, PlainDateTime, PlainTime, ZonedDateTime,
+};
+use crate::{
+    builtins::JsArgs,
+    object::{JsObject, ObjectInitializer},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_profiler::Profiler;
+
+/// JavaScript `Temporal.Now` object.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Now;
+
+impl Now {
+    const NAME: &'static str = "Now";
+
+    pub(super) fn init(context: &mut Context) -> JsObject {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ObjectInitializer::new(context)
+            .function(Self::time_zone, "timeZone", 0)
+            .function(Self::instant, "instant", 0)
+            .function(Self::plain_date_time, "plainDateTime", 1)
+            .function(Self::plain_date_time_iso, "plainDateTimeISO", 0)
+            .function(Self::zoned_date_time, "zonedDateTime", 1)
+            .function(Self::zoned_date_time_iso, "zonedDateTimeISO", 0)
+            .function(Self::plain_date, "plainDate", 1)
+            .function(Self::plain_date_iso, "plainDateISO", 0)
+            .function(Self::plain_time_iso, "plainTimeISO", 0)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                "Temporal.Now",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build()
+    }
+
+    /// Abstract operation `SystemUTCEpochNanoseconds ( )`
+    fn system_utc_epoch_ns() -> i128 {
+        let now = chrono::Utc::now();
+        i128::from(now.timestamp()) * 1_000_000_000 + i128::from(now.timestamp_subsec_nanos())
+    }
+
+    /// Abstract operation `SystemDateTime ( temporalTimeZoneLike, calendarLike )`, without the
+    /// calendar.
+    ///
+    /// Returns the local date and time in the time zone, which is the host time zone if
+    /// `time_zone_like` is `undefined`.
+    fn system_date_time(time_zone_like: &JsValue, context: &mut Context) -> JsResult<IsoDateTime> {
+        // 1. If temporalTimeZoneLike is undefined, then
+        //     a. Let timeZone be ! SystemTimeZone().
+        // 2. Else,
+        //     a. Let timeZone be ? ToTemporalTimeZone(temporalTimeZoneLike).
+        let kind = if time_zone_like.is_undefined() {
+            TimeZoneKind::system()
+        } else {
+            TimeZone::kind_of(&to_temporal_time_zone(time_zone_like, context)?)
+        };
+
+        // 4. Let instant be ! SystemInstant().
+        // 5. Return ? BuiltinTimeZoneGetPlainDateTimeFor(timeZone, instant, calendar).
+        Ok(kind.plain_date_time_for(Self::system_utc_epoch_ns()))
+    }
+
+    /// Abstract operation `SystemZonedDateTime ( temporalTimeZoneLike, calendarLike )`
+    fn system_zoned_date_time(
+        time_zone_like: &JsValue,
+        calendar: JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If temporalTimeZoneLike is undefined, then
+        //     a. Let timeZone be ! SystemTimeZone().
+        // 2. Else,
+        //     a. Let timeZone be ? ToTemporalTimeZone(temporalTimeZoneLike).
+        let time_zone = if time_zone_like.is_undefined() {
+            TimeZone::create(TimeZoneKind::system(), None, context)?
+        } else {
+            to_temporal_time_zone(time_zone_like, context)?
+        };
+
+        // 4. Let ns be ! SystemUTCEpochNanoseconds().
+        // 5. Return ! CreateTemporalZonedDateTime(ns, timeZone, calendar).
+        let epoch_ns = Self::system_utc_epoch_ns();
+        Ok(ZonedDateTime::create(epoch_ns, time_zone, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.Now.timeZone ( )`
+    fn time_zone(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ! SystemTimeZone().
+        Ok(TimeZone::create(TimeZoneKind::system(), None, context)?.into())
+    }
+
+    /// `Temporal.Now.instant ( )`
+    fn instant(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ! SystemInstant().
+        Ok(Instant::create(Self::system_utc_epoch_ns(), None, context)?.into())
+    }
+
+    /// `Temporal.Now.plainDateTime ( calendarLike [ , temporalTimeZoneLike ] )`
+    fn plain_date_time(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? SystemDateTime(temporalTimeZoneLike, calendarLike).
+        let calendar = to_temporal_calendar(args.get_or_undefined(0), context)?;
+        let date_time = Self::system_date_time(args.get_or_undefined(1), context)?;
+        Ok(PlainDateTime::create(date_time, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.Now.plainDateTimeISO ( [ temporalTimeZoneLike ] )`
+    fn plain_date_time_iso(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let calendar be ! GetISO8601Calendar().
+        // 2. Return ? SystemDateTime(temporalTimeZoneLike, calendar).
+        let date_time = Self::system_date_time(args.get_or_undefined(0), context)?;
+        let calendar = Calendar::create(None, context)?;
+        Ok(PlainDateTime::create(date_time, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.Now.zonedDateTime ( calendarLike [ , temporalTimeZoneLike ] )`
+    fn zoned_date_time(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? SystemZonedDateTime(temporalTimeZoneLike, calendarLike).
+        let calendar = to_temporal_calendar(args.get_or_undefined(0), context)?;
+        Self::system_zoned_date_time(args.get_or_undefined(1), calendar, context)
+    }
+
+    /// `Temporal.Now.zonedDateTimeISO ( [ temporalTimeZoneLike ] )`
+    fn zoned_date_time_iso(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let calendar be ! GetISO8601Calendar().
+        // 2. Return ? SystemZonedDateTime(temporalTimeZoneLike, calendar).
+        let calendar = Calendar::create(None, context)?;
+        Self::system_zoned_date_time(args.get_or_undefined(0), calendar, context)
+    }
+
+    /// `Temporal.Now.plainDate ( calendarLike [ , temporalTimeZoneLike ] )`
+    fn plain_date(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be ? SystemDateTime(temporalTimeZoneLike, calendarLike).
+        // 2. Return ! CreateTemporalDate(dateTime.[[ISOYear]], dateTime.[[ISOMonth]], dateTime.[[ISODay]], dateTime.[[Calendar]]).
+        let calendar = to_temporal_calendar(args.get_or_undefined(0), context)?;
+        let date_time = Self::system_date_time(args.get_or_undefined(1), context)?;
+        Ok(PlainDate::create(date_time.date, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.Now.plainDateISO ( [ temporalTimeZoneLike ] )`
+    fn plain_date_iso(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let calendar be ! GetISO8601Calendar().
+        // 2. Let dateTime be ? SystemDateTime(temporalTimeZoneLike, calendar).
+        // 3. Return ! CreateTemporalDate(dateTime.[[ISOYear]], dateTime.[[ISOMonth]], dateTime.[[ISODay]], dateTime.[[Calendar]]).
+        let date_time = Self::system_date_time(args.get_or_undefined(0), context)?;
+        let calendar = Calendar::create(None, context)?;
+        Ok(PlainDate::create(date_time.date, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.Now.plainTimeISO ( [ temporalTimeZoneLike ] )`
+    fn plain_time_iso(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let calendar be ! GetISO8601Calendar().
+        // 2. Let dateTime be ? SystemDateTime(temporalTimeZoneLike, calendar).
+        // 3. Return ! CreateTemporalTime(dateTime.[[ISOHour]], ..., dateTime.[[ISONanosecond]]).
+        let date_time = Self::system_date_time(args.get_or_undefined(0), context)?;
+        Ok(PlainTime::create(date_time.time, None, context)?.into())
+    }
+}
@@ -0,0 +1,660 @@
+//! The abstract operations used by the `Temporal` builtins to read their options objects.
+//!
+//! More information:
+//!  - [Temporal reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-abstract-ops
+
+use super::TemporalUnit;
+use crate::{
+    object::{JsObject, ObjectData},
+    Context, JsNativeError, JsResult, JsValue, JsVariant,
+};
+
+/// A string option that can take one of a fixed set of values.
+pub(crate) trait OptionValue: Sized {
+    /// Parses the value of the option, returning `None` if it's not an allowed value.
+    fn from_option(value: &str) -> Option<Self>;
+}
+
+/// Abstract operation `GetOptionsObject ( options )`
+///
+/// More information:
+///  - [Temporal reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-getoptionsobject
+pub(crate) fn get_options_object(options: &JsValue) -> JsResult<JsObject> {
+    match options.variant() {
+        // 1. If options is undefined, then
+        //     a. Return OrdinaryObjectCreate(null).
+        JsVariant::Undefined => Ok(JsObject::from_proto_and_data(None, ObjectData::ordinary())),
+        // 2. If Type(options) is Object, then
+        //     a. Return options.
+        JsVariant::Object(options) => Ok(options.clone()),
+        // 3. Throw a TypeError exception.
+        _ => Err(JsNativeError::typ()
+            .with_message("Temporal: options must be an object or undefined")
+            .into()),
+    }
+}
+
+/// Abstract operation `GetOption ( options, property, "string", values, default )`
+///
+/// Returns `None` if the option is undefined, so the caller can apply the default.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-getoption
+pub(crate) fn get_option<T: OptionValue>(
+    options: &JsObject,
+    property: &str,
+    context: &mut Context,
+) -> JsResult<Option<T>> {
+    // 1. Let value be ? Get(options, property).
+    let value = options.get(property, context)?;
+
+    // 2. If value is undefined, then
+    //     a. If default is required, throw a RangeError exception.
+    //     b. Return default.
+    if value.is_undefined() {
+        return Ok(None);
+    }
+
+    // 5. Else,
+    //     a. Set value to ? ToString(value).
+    let value = value.to_string(context)?.to_std_string_escaped();
+
+    // 6. If values is not empty and values does not contain an element equal to value, throw a
+    //    RangeError exception.
+    // 7. Return value.
+    T::from_option(&value).map(Some).ok_or_else(|| {
+        JsNativeError::range()
+            .with_message(format!("Temporal: invalid value `{value}` for option {property}"))
+            .into()
+    })
+}
+
+/// The `overflow` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Overflow {
+    Constrain,
+    Reject,
+}
+
+impl OptionValue for Overflow {
+    fn from_option(value: &str) -> Option<Self> {
+        match value {
+            "constrain" => Some(Self::Constrain),
+            "reject" => Some(Self::Reject),
+            _ => None,
+        }
+    }
+}
+
+/// Abstract operation `ToTemporalOverflow ( options )`
+///
+/// More information:
+///  - [Temporal reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporaloverflow
+pub(crate) fn to_temporal_overflow(options: &JsObject, context: &mut Context) -> JsResult<Overflow> {
+    // 1. If options is undefined, return "constrain".
+    // 2. Return ? GetOption(options, "overflow", "string", « "constrain", "reject" », "constrain").
+    Ok(get_option(options, "overflow", context)?.unwrap_or(Overflow::Constrain))
+}
+
+/// The `disambiguation` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Disambiguation {
+    Compatible,
+    Earlier,
+    Later,
+    Reject,
+}
+
+impl OptionValue for Disambiguation {
+    fn from_option(value: &str) -> Option<Self> {
+        match value {
+            "compatible" => Some(Self::Compatible),
+            "earlier" => Some(Self::Earlier),
+            "later" => Some(Self::Later),
+            "reject" => Some(Self::Reject),
+            _ => None,
+        }
+    }
+}
+
+/// Abstract operation `ToTemporalDisambiguation ( options )`
+///
+/// More information:
+///  - [Temporal reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporaldisambiguation
+pub(crate) fn to_temporal_disambiguation(
+    options: &JsObject,
+    context: &mut Context,
+) -> JsResult<Disambiguation> {
+    Ok(get_option(options, "disambiguation", context)?.unwrap_or(Disambiguation::Compatible))
+}
+
+/// The `offset` option of `ZonedDateTime` operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OffsetOption {
+    Prefer,
+    Use,
+    Ignore,
+    Reject,
+}
+
+impl OptionValue for OffsetOption {
+    fn from_option(value: &str) -> Option<Self> {
+        match value {
+            "prefer" => Some(Self::Prefer),
+            "use" => Some(Self::Use),
+            "ignore" => Some(Self::Ignore),
+            "reject" => Some(Self::Reject),
+            _ => None,
+        }
+    }
+}
+
+/// Abstract operation `ToTemporalOffset ( options, fallback )`
+///
+/// More information:
+///  - [Temporal reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporaloffset
+pub(crate) fn to_temporal_offset(
+    options: &JsObject,
+    fallback: OffsetOption,
+    context: &mut Context,
+) -> JsResult<OffsetOption> {
+    Ok(get_option(options, "offset", context)?.unwrap_or(fallback))
+}
+
+/// The `roundingMode` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RoundingMode {
+    Ceil,
+    Floor,
+    Expand,
+    Trunc,
+    HalfCeil,
+    HalfFloor,
+    HalfExpand,
+    HalfTrunc,
+    HalfEven,
+}
+
+impl OptionValue for RoundingMode {
+    fn from_option(value: &str) -> Option<Self> {
+        let mode = match value {
+            "ceil" => Self::Ceil,
+            "floor" => Self::Floor,
+            "expand" => Self::Expand,
+            "trunc" => Self::Trunc,
+            "halfCeil" => Self::HalfCeil,
+            "halfFloor" => Self::HalfFloor,
+            "halfExpand" => Self::HalfExpand,
+            "halfTrunc" => Self::HalfTrunc,
+            "halfEven" => Self::HalfEven,
+            _ => return None,
+        };
+        Some(mode)
+    }
+}
+
+impl RoundingMode {
+    /// Abstract operation `NegateTemporalRoundingMode ( roundingMode )`
+    ///
+    /// More information:
+    ///  - [Temporal reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-negatetemporalroundingmode
+    pub(crate) fn negate(self) -> Self {
+        match self {
+            Self::Ceil => Self::Floor,
+            Self::Floor => Self::Ceil,
+            Self::HalfCeil => Self::HalfFloor,
+            Self::HalfFloor => Self::HalfCeil,
+            mode => mode,
+        }
+    }
+
+    /// Abstract operation `RoundNumberToIncrement ( x, increment, roundingMode )`
+    ///
+    /// Rounds the integer `x` to a multiple of `increment`, which must be positive.
+    ///
+    /// More information:
+    ///  - [Temporal reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-roundnumbertoincrement
+    pub(crate) fn round(self, x: i128, increment: i128) -> i128 {
+        debug_assert!(increment > 0);
+
+        // 1. Let quotient be x / increment.
+        let floor = x.div_euclid(increment);
+        let remainder = x.rem_euclid(increment);
+        if remainder == 0 {
+            return x;
+        }
+
+        // 2. If quotient < 0, then let isNegative be negative, else let isNegative be positive.
+        let is_negative = x < 0;
+
+        // 3-9. Round the quotient to one of its two neighbouring integers.
+        let ceil = match self {
+            Self::Ceil => true,
+            Self::Floor => false,
+            Self::Expand => !is_negative,
+            Self::Trunc => is_negative,
+            _ => match (remainder * 2).cmp(&increment) {
+                std::cmp::Ordering::Less => false,
+                std::cmp::Ordering::Greater => true,
+                std::cmp::Ordering::Equal => match self {
+                    Self::HalfCeil => true,
+                    Self::HalfFloor => false,
+                    Self::HalfExpand => !is_negative,
+                    Self::HalfTrunc => is_negative,
+                    _ => floor.rem_euclid(2) == 1,
+                },
+            },
+        };
+
+        // 10. Return rounded × increment.
+        if ceil {
+            (floor + 1) * increment
+        } else {
+            floor * increment
+        }
+    }
+}
+
+/// Abstract operation `ToTemporalRoundingMode ( normalizedOptions, fallback )`
+///
+/// More information:
+///  - [Temporal reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporalroundingmode
+pub(crate) fn to_temporal_rounding_mode(
+    options: &JsObject,
+    fallback: RoundingMode,
+    context: &mut Context,
+) -> JsResult<RoundingMode> {
+    Ok(get_option(options, "roundingMode", context)?.unwrap_or(fallback))
+}
+
+/// Abstract operation `ToTemporalRoundingIncrement ( normalizedOptions, dividend, inclusive )`
+///
+/// More information:
+///  - [Temporal reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporalroundingincrement
+pub(crate) fn to_temporal_rounding_increment(
+    options: &JsObject,
+    dividend: Option<f64>,
+    inclusive: bool,
+    context: &mut Context,
+) -> JsResult<f64> {
+    // 1. If dividend is undefined, then
+    //     a. Let maximum be +∞𝔽.
+    // 2. Else if inclusive is true, then
+    //     a. Let maximum be 𝔽(dividend).
+    // 3. Else if dividend is more than 1, then
+    //     a. Let maximum be 𝔽(dividend - 1).
+    // 4. Else,
+    //     a. Let maximum be 1𝔽.
+    let maximum = match dividend {
+        None => f64::INFINITY,
+        Some(dividend) if inclusive => dividend,
+        Some(dividend) if dividend > 1.0 => dividend - 1.0,
+        Some(_) => 1.0,
+    };
+
+    // 5. Let increment be ? GetOption(normalizedOptions, "roundingIncrement", "number", undefined, 1𝔽).
+    let increment = options.get("roundingIncrement", context)?;
+    let increment = if increment.is_undefined() {
+        1.0
+    } else {
+        increment.to_number(context)?
+    };
+
+    // 6. If increment < 1𝔽 or increment > maximum, throw a RangeError exception.
+    if increment.is_nan() || !(1.0..=maximum).contains(&increment) {
+        return Err(JsNativeError::range()
+            .with_message("Temporal: roundingIncrement is out of range")
+            .into());
+    }
+
+    // 7. Set increment to floor(ℝ(increment)).
+    let increment = increment.floor();
+
+    // 8. If dividend is not undefined and dividend modulo increment is not zero, then
+    if let Some(dividend) = dividend {
+        if dividend % increment != 0.0 {
+            // a. Throw a RangeError exception.
+            return Err(JsNativeError::range()
+                .with_message("Temporal: roundingIncrement must divide the next larger unit")
+                .into());
+        }
+    }
+
+    // 9. Return increment.
+    Ok(increment)
+}
+
+/// The group of units accepted by a unit option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UnitGroup {
+    Date,
+    Time,
+    DateTime,
+}
+
+/// Abstract operation `GetTemporalUnit ( normalizedOptions, key, unitGroup, default [ , extraValues ] )`
+///
+/// Returns `None` if the option is undefined or, when `allow_auto` is `true`, `"auto"`.
+///
+/// More information:
+///  - [Temporal reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-gettemporalunit
+pub(crate) fn get_temporal_unit(
+    options: &JsObject,
+    key: &str,
+    group: UnitGroup,
+    allow_auto: bool,
+    context: &mut Context,
+) -> JsResult<Option<TemporalUnit>> {
+    let value = options.get(key, context)?;
+    if value.is_undefined() {
+        return Ok(None);
+    }
+    let value = value.to_string(context)?.to_std_string_escaped();
+    if allow_auto && value == "auto" {
+        return Ok(None);
+    }
+
+    let unit = TemporalUnit::from_name(&value).filter(|unit| match group {
+        UnitGroup::Date => unit.is_date_unit(),
+        UnitGroup::Time => !unit.is_date_unit(),
+        UnitGroup::DateTime => true,
+    });
+
+    unit.map(Some).ok_or_else(|| {
+        JsNativeError::range()
+            .with_message(format!("Temporal: invalid unit `{value}` for option {key}"))
+            .into()
+    })
+}
+
+/// The `calendarName` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ShowCalendar {
+    Auto,
+    Always,
+    Never,
+    Critical,
+}
+
+impl OptionValue for ShowCalendar {
+    fn from_option(value: &str) -> Option<Self> {
+        match value {
+            "auto" => Some(Self::Auto),
+            "always" => Some(Self::Always),
+            "never" => Some(Self::Never),
+            "critical" => Some(Self::Critical),
+            _ => None,
+        }
+    }
+}
+
+/// Abstract operation `ToCalendarNameOption ( normalizedOptions )`
+///
+/// More information:
+///  - [Temporal reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-tocalendarnameoption
+pub(crate) fn to_calendar_name_option(
+    options: &JsObject,
+    context: &mut Context,
+) -> JsResult<ShowCalendar> {
+    Ok(get_option(options, "calendarName", context)?.unwrap_or(ShowCalendar::Auto))
+}
+
+/// The `timeZoneName` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ShowTimeZone {
+    Auto,
+    Never,
+    Critical,
+}
+
+impl OptionValue for ShowTimeZone {
+    fn from_option(value: &str) -> Option<Self> {
+        match value {
+            "auto" => Some(Self::Auto),
+            "never" => Some(Self::Never),
+            "critical" => Some(Self::Critical),
+            _ => None,
+        }
+    }
+}
+
+/// The `offset` option of `ZonedDateTime.prototype.toString`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ShowOffset {
+    Auto,
+    Never,
+}
+
+impl OptionValue for ShowOffset {
+    fn from_option(value: &str) -> Option<Self> {
+        match value {
+            "auto" => Some(Self::Auto),
+            "never" => Some(Self::Never),
+            _ => None,
+        }
+    }
+}
+
+/// The precision of the seconds in the strings returned by `toString`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Precision {
+    /// Shows as many fractional digits as needed.
+    Auto,
+    /// Omits the seconds.
+    Minute,
+    /// Shows exactly this number of fractional digits.
+    Digits(u8),
+}
+
+/// The result of `ToSecondsStringPrecision`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SecondsStringPrecision {
+    pub(crate) precision: Precision,
+    pub(crate) unit: TemporalUnit,
+    pub(crate) increment: f64,
+}
+
+/// Abstract operation `ToSecondsStringPrecision ( normalizedOptions )`
+///
+/// More information:
+///  - [Temporal reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-tosecondsstringprecision
+pub(crate) fn to_seconds_string_precision(
+    options: &JsObject,
+    context: &mut Context,
+) -> JsResult<SecondsStringPrecision> {
+    // 1. Let smallestUnit be ? GetTemporalUnit(normalizedOptions, "smallestUnit", time, undefined).
+    let smallest_unit = get_temporal_unit(options, "smallestUnit", UnitGroup::Time, false, context)?;
+
+    let digits = match smallest_unit {
+        // 2. If smallestUnit is "hour", throw a RangeError exception.
+        Some(TemporalUnit::Hour) => {
+            return Err(JsNativeError::range()
+                .with_message("Temporal: smallestUnit can't be hour")
+                .into())
+        }
+        // 3. If smallestUnit is "minute", then
+        Some(TemporalUnit::Minute) => {
+            // a. Return the Record { [[Precision]]: "minute", [[Unit]]: "minute", [[Increment]]: 1 }.
+            return Ok(SecondsStringPrecision {
+                precision: Precision::Minute,
+                unit: TemporalUnit::Minute,
+                increment: 1.0,
+            });
+        }
+        // 4-7. If smallestUnit is "second", "millisecond", "microsecond" or "nanosecond", use
+        //      0, 3, 6 or 9 digits.
+        Some(TemporalUnit::Second) => Some(0),
+        Some(TemporalUnit::Millisecond) => Some(3),
+        Some(TemporalUnit::Microsecond) => Some(6),
+        Some(_) => Some(9),
+        // 8. Assert: smallestUnit is undefined.
+        None => {
+            // 9. Let fractionalDigitsVal be ? Get(normalizedOptions, "fractionalSecondDigits").
+            let value = options.get("fractionalSecondDigits", context)?;
+
+            // 10. If Type(fractionalDigitsVal) is not Number, then
+            #[allow(clippy::if_not_else)]
+            if !value.is_number() {
+                // a. If fractionalDigitsVal is not undefined, then
+                if !value.is_undefined()
+                    && value.to_string(context)?.to_std_string_escaped() != "auto"
+                {
+                    // i. If ? ToString(fractionalDigitsVal) is not "auto", throw a RangeError exception.
+                    return Err(JsNativeError::range()
+                        .with_message("Temporal: invalid fractionalSecondDigits")
+                        .into());
+                }
+
+                // b. Return the Record { [[Precision]]: "auto", [[Unit]]: "nanosecond", [[Increment]]: 1 }.
+                None
+            } else {
+                let value = value.as_number().unwrap_or_default();
+
+                // 11. If fractionalDigitsVal is NaN, +∞𝔽, or -∞𝔽, throw a RangeError exception.
+                // 12. Let fractionalDigitCount be RoundTowardsZero(ℝ(fractionalDigitsVal)).
+                // 13. If fractionalDigitCount < 0 or fractionalDigitCount > 9, throw a RangeError exception.
+                if !value.is_finite() || !(0.0..10.0).contains(&value) {
+                    return Err(JsNativeError::range()
+                        .with_message("Temporal: fractionalSecondDigits is out of range")
+                        .into());
+                }
+                Some(value.trunc() as u8)
+            }
+        }
+    };
+
+    let digits = if let Some(digits) = digits {
+        digits
+    } else {
+        return Ok(SecondsStringPrecision {
+            precision: Precision::Auto,
+            unit: TemporalUnit::Nanosecond,
+            increment: 1.0,
+        });
+    };
+
+    // 14-17. Return the unit and increment that round to that number of digits.
+    let (unit, increment) = match digits {
+        0 => (TemporalUnit::Second, 1),
+        1..=3 => (TemporalUnit::Millisecond, 10_i32.pow(3 - u32::from(digits))),
+        4..=6 => (TemporalUnit::Microsecond, 10_i32.pow(6 - u32::from(digits))),
+        _ => (TemporalUnit::Nanosecond, 10_i32.pow(9 - u32::from(digits))),
+    };
+
+    Ok(SecondsStringPrecision {
+        precision: Precision::Digits(digits),
+        unit,
+        increment: f64::from(increment),
+    })
+}
+
+/// The direction of a difference between two `Temporal` objects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DifferenceOperation {
+    Until,
+    Since,
+}
+
+/// The result of `GetDifferenceSettings`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DifferenceSettings {
+    pub(crate) largest_unit: TemporalUnit,
+    pub(crate) smallest_unit: TemporalUnit,
+    pub(crate) increment: f64,
+    pub(crate) mode: RoundingMode,
+}
+
+/// Abstract operation `GetDifferenceSettings ( operation, options, unitGroup, disallowedUnits, fallbackSmallestUnit, smallestLargestDefaultUnit )`
+///
+/// More information:
+///  - [Temporal reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-getdifferencesettings
+pub(crate) fn get_difference_settings(
+    operation: DifferenceOperation,
+    options: &JsValue,
+    group: UnitGroup,
+    fallback_smallest_unit: TemporalUnit,
+    smallest_largest_default_unit: TemporalUnit,
+    context: &mut Context,
+) -> JsResult<DifferenceSettings> {
+    // 1. NOTE: The following steps read properties in alphabetical order.
+    let options = get_options_object(options)?;
+
+    // 2. Let largestUnit be ? GetTemporalUnit(options, "largestUnit", unitGroup, "auto").
+    let largest_unit = get_temporal_unit(&options, "largestUnit", group, true, context)?;
+
+    // 4. Let roundingIncrement be ? ToTemporalRoundingIncrement(options).
+    let increment = to_temporal_rounding_increment(&options, None, false, context)?;
+
+    // 5. Let roundingMode be ? ToTemporalRoundingMode(options, "trunc").
+    let mode = to_temporal_rounding_mode(&options, RoundingMode::Trunc, context)?;
+
+    // 6. If operation is since, then
+    //     a. Set roundingMode to ! NegateTemporalRoundingMode(roundingMode).
+    let mode = match operation {
+        DifferenceOperation::Until => mode,
+        DifferenceOperation::Since => mode.negate(),
+    };
+
+    // 7. Let smallestUnit be ? GetTemporalUnit(options, "smallestUnit", unitGroup, fallbackSmallestUnit).
+    let smallest_unit = get_temporal_unit(&options, "smallestUnit", group, false, context)?
+        .unwrap_or(fallback_smallest_unit);
+
+    // 9. Let defaultLargestUnit be ! LargerOfTwoTemporalUnits(smallestLargestDefaultUnit, smallestUnit).
+    let default_largest_unit = smallest_largest_default_unit.larger(smallest_unit);
+
+    // 10. If largestUnit is "auto", set largestUnit to defaultLargestUnit.
+    let largest_unit = largest_unit.unwrap_or(default_largest_unit);
+
+    // 11. If LargerOfTwoTemporalUnits(largestUnit, smallestUnit) is not largestUnit, throw a RangeError exception.
+    if largest_unit.larger(smallest_unit) != largest_unit {
+        return Err(JsNativeError::range()
+            .with_message("Temporal: largestUnit can't be smaller than smallestUnit")
+            .into());
+    }
+
+    // 12. Let maximum be ! MaximumTemporalDurationRoundingIncrement(smallestUnit).
+    // 13. If maximum is not undefined, perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, false).
+    if let Some(maximum) = smallest_unit.maximum_rounding_increment() {
+        if increment >= maximum || maximum % increment != 0.0 {
+            return Err(JsNativeError::range()
+                .with_message("Temporal: roundingIncrement must divide the next larger unit")
+                .into());
+        }
+    }
+
+    // 14. Return the Record { [[SmallestUnit]]: smallestUnit, [[LargestUnit]]: largestUnit, [[RoundingMode]]: roundingMode, [[RoundingIncrement]]: roundingIncrement }.
+    Ok(DifferenceSettings {
+        largest_unit,
+        smallest_unit,
+        increment,
+        mode,
+    })
+}
@@ -0,0 +1,579 @@
+//! The parser of the ISO 8601 strings accepted by the `Temporal` builtins.
+//!
+//! More information:
+//!  - [Temporal reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-iso8601grammar
+
+use super::{
+    duration::DurationRecord,
+    iso::{days_in_month, IsoDate, IsoTime},
+};
+use crate::{JsNativeError, JsResult};
+
+/// The UTC offset of a parsed string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ParsedOffset {
+    /// The `Z` designator, meaning that the date and time are in UTC but the local time is unknown.
+    Utc,
+    /// A numeric UTC offset.
+    Numeric {
+        /// The offset in nanoseconds.
+        ns: i64,
+        /// Whether the offset has seconds or fractional seconds.
+        sub_minute: bool,
+    },
+}
+
+/// The result of parsing a date and time string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParsedDateTime {
+    pub(crate) date: IsoDate,
+    pub(crate) time: Option<IsoTime>,
+    pub(crate) offset: Option<ParsedOffset>,
+    pub(crate) time_zone: Option<String>,
+    pub(crate) calendar: Option<String>,
+}
+
+/// The `RangeError` thrown for strings that can't be parsed.
+fn syntax_error<T>(string: &str) -> JsResult<T> {
+    Err(JsNativeError::range()
+        .with_message(format!("Temporal: invalid ISO 8601 string `{string}`"))
+        .into())
+}
+
+/// A cursor over the characters of the parsed string.
+#[derive(Debug)]
+struct Cursor {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Cursor {
+    fn new(string: &str) -> Self {
+        Self {
+            chars: string.chars().collect(),
+            pos: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_digit(&self) -> bool {
+        self.peek().map_or(false, |c| c.is_ascii_digit())
+    }
+
+    fn is_done(&self) -> bool {
+        self.pos >= self.chars.len()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_any(&mut self, chars: &[char]) -> Option<char> {
+        let c = self.peek().filter(|c| chars.contains(c))?;
+        self.pos += 1;
+        Some(c)
+    }
+
+    /// Parses the sign of a number, which can be `+`, `-` or the Unicode minus sign.
+    fn sign(&mut self) -> Option<i64> {
+        match self.eat_any(&['+', '-', '\u{2212}'])? {
+            '+' => Some(1),
+            _ => Some(-1),
+        }
+    }
+
+    /// Parses exactly `count` decimal digits.
+    fn digits(&mut self, count: usize) -> Option<u32> {
+        let mut value = 0;
+        for _ in 0..count {
+            let digit = self.peek()?.to_digit(10)?;
+            self.pos += 1;
+            value = value * 10 + digit;
+        }
+        Some(value)
+    }
+
+    /// Parses a fraction with up to nine digits after a `.` or `,` separator, returning it in
+    /// nanoseconds.
+    #[allow(clippy::option_option)]
+    fn fraction(&mut self) -> Option<Option<u32>> {
+        if self.eat_any(&['.', ',']).is_none() {
+            return Some(None);
+        }
+        let mut value = 0;
+        let mut count = 0;
+        while let Some(digit) = self.peek().and_then(|c| c.to_digit(10)) {
+            if count == 9 {
+                return None;
+            }
+            self.pos += 1;
+            value = value * 10 + digit;
+            count += 1;
+        }
+        if count == 0 {
+            return None;
+        }
+        Some(Some(value * 10_u32.pow(9 - count)))
+    }
+}
+
+/// Parses a `DateYear`, `DateMonth` and `DateDay`, in the extended or the basic format.
+fn parse_date(cursor: &mut Cursor) -> Option<(i64, u32, u32)> {
+    let year = if let Some(sign) = cursor.sign() {
+        let year = i64::from(cursor.digits(6)?);
+        // The year -000000 is not allowed.
+        if year == 0 && sign < 0 {
+            return None;
+        }
+        sign * year
+    } else {
+        i64::from(cursor.digits(4)?)
+    };
+    let extended = cursor.eat('-');
+    let month = cursor.digits(2)?;
+    if extended && !cursor.eat('-') {
+        return None;
+    }
+    let day = cursor.digits(2)?;
+    Some((year, month, day))
+}
+
+/// Parses a `TimeSpec` in the extended or the basic format.
+fn parse_time(cursor: &mut Cursor) -> Option<IsoTime> {
+    let hour = cursor.digits(2)?;
+    let mut minute = 0;
+    let mut second = 0;
+    let mut fraction = None;
+    if cursor.eat(':') {
+        minute = cursor.digits(2)?;
+        if cursor.eat(':') {
+            second = cursor.digits(2)?;
+            fraction = cursor.fraction()?;
+        }
+    } else if cursor.peek_digit() {
+        minute = cursor.digits(2)?;
+        if cursor.peek_digit() {
+            second = cursor.digits(2)?;
+            fraction = cursor.fraction()?;
+        }
+    }
+
+    // A leap second is parsed as the last second of the minute.
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+    let fraction = fraction.unwrap_or_default();
+    Some(IsoTime {
+        hour: hour as u8,
+        minute: minute as u8,
+        second: second.min(59) as u8,
+        millisecond: (fraction / 1_000_000) as u16,
+        microsecond: (fraction / 1_000 % 1000) as u16,
+        nanosecond: (fraction % 1000) as u16,
+    })
+}
+
+/// Parses a numeric UTC offset, like `+01:00` or `-0530`.
+fn parse_numeric_offset(cursor: &mut Cursor) -> Option<ParsedOffset> {
+    let sign = cursor.sign()?;
+    let hours = cursor.digits(2)?;
+    let mut minutes = 0;
+    let mut seconds = None;
+    let mut fraction = None;
+    if cursor.eat(':') {
+        minutes = cursor.digits(2)?;
+        if cursor.eat(':') {
+            seconds = Some(cursor.digits(2)?);
+            fraction = cursor.fraction()?;
+        }
+    } else if cursor.peek_digit() {
+        minutes = cursor.digits(2)?;
+        if cursor.peek_digit() {
+            seconds = Some(cursor.digits(2)?);
+            fraction = cursor.fraction()?;
+        }
+    }
+    if hours > 23 || minutes > 59 || seconds.unwrap_or_default() > 59 {
+        return None;
+    }
+
+    let ns = (i64::from(hours) * 3600 + i64::from(minutes) * 60 + i64::from(seconds.unwrap_or(0)))
+        * 1_000_000_000
+        + i64::from(fraction.unwrap_or(0));
+    Some(ParsedOffset::Numeric {
+        ns: sign * ns,
+        sub_minute: seconds.is_some(),
+    })
+}
+
+/// Parses a `TimeZoneUTCOffset`, which is either `Z` or a numeric offset.
+#[allow(clippy::option_option)]
+fn parse_offset(cursor: &mut Cursor) -> Option<Option<ParsedOffset>> {
+    if cursor.eat_any(&['Z', 'z']).is_some() {
+        return Some(Some(ParsedOffset::Utc));
+    }
+    if matches!(cursor.peek(), Some('+' | '-' | '\u{2212}')) {
+        return parse_numeric_offset(cursor).map(Some);
+    }
+    Some(None)
+}
+
+/// Parses a numeric UTC offset string, like the identifier of an offset time zone.
+///
+/// This is `ParseTimeZoneOffsetString`, and returns the offset in nanoseconds.
+pub(crate) fn parse_offset_string(string: &str) -> Option<i64> {
+    let mut cursor = Cursor::new(string);
+    match parse_numeric_offset(&mut cursor)? {
+        ParsedOffset::Numeric { ns, .. } if cursor.is_done() => Some(ns),
+        _ => None,
+    }
+}
+
+/// Parses the annotations in square brackets at the end of a string, like `[Europe/Paris]` or
+/// `[u-ca=iso8601]`.
+///
+/// Returns the time zone and the calendar annotations.
+fn parse_annotations(
+    cursor: &mut Cursor,
+    string: &str,
+) -> JsResult<(Option<String>, Option<String>)> {
+    let mut time_zone = None;
+    let mut calendar: Option<String> = None;
+    let mut calendar_critical = false;
+    let mut first = true;
+
+    while cursor.eat('[') {
+        let critical = cursor.eat('!');
+        let start = cursor.pos;
+        while cursor.peek().map_or(false, |c| c != ']' && c != '[') {
+            cursor.pos += 1;
+        }
+        let content: String = cursor.chars[start..cursor.pos].iter().collect();
+        if !cursor.eat(']') || content.is_empty() {
+            return syntax_error(string);
+        }
+
+        if let Some((key, value)) = content.split_once('=') {
+            // An annotation key starts with a lowercase letter or `_`, and has only lowercase
+            // letters, digits, `_` and `-`.
+            let valid_key = key.starts_with(|c: char| c.is_ascii_lowercase() || c == '_')
+                && key
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-');
+            let valid_value = !value.is_empty()
+                && value
+                    .split('-')
+                    .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric()));
+            if !valid_key || !valid_value {
+                return syntax_error(string);
+            }
+
+            if key == "u-ca" {
+                if calendar.is_none() {
+                    calendar = Some(value.to_string());
+                    calendar_critical = critical;
+                } else if critical || calendar_critical {
+                    return Err(JsNativeError::range()
+                        .with_message("Temporal: conflicting critical calendar annotations")
+                        .into());
+                }
+            } else if critical {
+                return Err(JsNativeError::range()
+                    .with_message(format!("Temporal: unknown critical annotation `{key}`"))
+                    .into());
+            }
+        } else {
+            // The time zone annotation must be the first one.
+            if !first {
+                return syntax_error(string);
+            }
+            time_zone = Some(content);
+        }
+        first = false;
+    }
+
+    Ok((time_zone, calendar))
+}
+
+/// Parses an ISO 8601 date and time string, with an optional time, UTC offset, time zone and
+/// calendar.
+///
+/// The callers check that the parts their string grammar requires or forbids are present.
+pub(crate) fn parse_date_time(string: &str) -> JsResult<ParsedDateTime> {
+    let mut cursor = Cursor::new(string);
+
+    let (year, month, day) = match parse_date(&mut cursor) {
+        Some(date) => date,
+        None => return syntax_error(string),
+    };
+    if !(1..=12).contains(&month) || day < 1 {
+        return syntax_error(string);
+    }
+    let year = year as i32;
+    let month = month as u8;
+    if day > u32::from(days_in_month(year, month)) {
+        return syntax_error(string);
+    }
+    let date = IsoDate {
+        year,
+        month,
+        day: day as u8,
+    };
+
+    let time = if cursor.eat_any(&['T', 't', ' ']).is_some() {
+        match parse_time(&mut cursor) {
+            Some(time) => Some(time),
+            None => return syntax_error(string),
+        }
+    } else {
+        None
+    };
+
+    let offset = match parse_offset(&mut cursor) {
+        Some(offset) => offset,
+        None => return syntax_error(string),
+    };
+
+    let (time_zone, calendar) = parse_annotations(&mut cursor, string)?;
+    if !cursor.is_done() {
+        return syntax_error(string);
+    }
+
+    Ok(ParsedDateTime {
+        date,
+        time,
+        offset,
+        time_zone,
+        calendar,
+    })
+}
+
+/// Returns `true` if `string` is a valid `DateSpecYearMonth` or `DateSpecMonthDay`, which makes
+/// a time string without the `T` designator ambiguous.
+fn is_year_month_or_month_day(string: &str) -> bool {
+    let string = string.split('[').next().unwrap_or_default();
+
+    // `DateSpecYearMonth`: `YYYY-MM`, `YYYYMM`, `±YYYYYY-MM` or `±YYYYYYMM`.
+    let mut cursor = Cursor::new(string);
+    let year = if let Some(sign) = cursor.sign() {
+        cursor.digits(6).map(|year| sign * i64::from(year))
+    } else {
+        cursor.digits(4).map(i64::from)
+    };
+    if year.is_some() {
+        cursor.eat('-');
+        if let Some(month) = cursor.digits(2) {
+            if cursor.is_done() && (1..=12).contains(&month) {
+                return true;
+            }
+        }
+    }
+
+    // `DateSpecMonthDay`: `--MM-DD`, `--MMDD`, `MM-DD` or `MMDD`.
+    let mut cursor = Cursor::new(string);
+    if cursor.eat('-') && !cursor.eat('-') {
+        return false;
+    }
+    let month = cursor.digits(2);
+    cursor.eat('-');
+    let day = cursor.digits(2);
+    if let (Some(month), Some(day)) = (month, day) {
+        if cursor.is_done() && (1..=12).contains(&month) {
+            // The month day is checked against a leap year.
+            return day >= 1 && day <= u32::from(days_in_month(1972, month as u8));
+        }
+    }
+
+    false
+}
+
+/// Parses a `TemporalTimeString`, returning the time and the calendar annotation.
+///
+/// This is `ParseTemporalTimeString`.
+pub(crate) fn parse_time_string(string: &str) -> JsResult<(IsoTime, Option<String>)> {
+    // A date and time string is a valid time string.
+    if let Ok(parsed) = parse_date_time(string) {
+        return match (parsed.time, parsed.offset) {
+            (_, Some(ParsedOffset::Utc)) => Err(JsNativeError::range()
+                .with_message("Temporal: the UTC designator is not allowed in a plain time")
+                .into()),
+            (Some(time), _) => Ok((time, parsed.calendar)),
+            (None, _) => syntax_error(string),
+        };
+    }
+
+    let mut cursor = Cursor::new(string);
+    let designator = cursor.eat_any(&['T', 't']).is_some();
+    let time = match parse_time(&mut cursor) {
+        Some(time) => time,
+        None => return syntax_error(string),
+    };
+    match parse_offset(&mut cursor) {
+        Some(Some(ParsedOffset::Utc)) => {
+            return Err(JsNativeError::range()
+                .with_message("Temporal: the UTC designator is not allowed in a plain time")
+                .into())
+        }
+        Some(_) => {}
+        None => return syntax_error(string),
+    }
+    let (_, calendar) = parse_annotations(&mut cursor, string)?;
+    if !cursor.is_done() {
+        return syntax_error(string);
+    }
+
+    // Without the time designator the string must not be a valid year-month or month-day.
+    if !designator && is_year_month_or_month_day(string) {
+        return Err(JsNativeError::range()
+            .with_message(format!("Temporal: ambiguous time string `{string}`"))
+            .into());
+    }
+
+    Ok((time, calendar))
+}
+
+/// Parses a `TemporalDurationString`.
+///
+/// This is `ParseTemporalDurationString`.
+pub(crate) fn parse_duration(string: &str) -> JsResult<DurationRecord> {
+    /// The length of the units of the time part, in nanoseconds.
+    const TIME_UNITS: [(char, i128); 3] = [
+        ('H', 3_600_000_000_000),
+        ('M', 60_000_000_000),
+        ('S', 1_000_000_000),
+    ];
+
+    // Parses a whole number, returning its digits.
+    fn number(cursor: &mut Cursor) -> Option<String> {
+        let start = cursor.pos;
+        while cursor.peek_digit() {
+            cursor.pos += 1;
+        }
+        (cursor.pos > start).then(|| cursor.chars[start..cursor.pos].iter().collect())
+    }
+
+    let mut cursor = Cursor::new(string);
+    let sign = cursor.sign().unwrap_or(1);
+    if cursor.eat_any(&['P', 'p']).is_none() {
+        return syntax_error(string);
+    }
+
+    let mut record = DurationRecord::default();
+    let mut any = false;
+
+    // The date part, with the units in order.
+    let date_units = ['Y', 'M', 'W', 'D'];
+    let mut next_unit = 0;
+    loop {
+        let start = cursor.pos;
+        let digits = if let Some(digits) = number(&mut cursor) {
+            digits
+        } else {
+            break;
+        };
+        let unit = cursor.peek().map(|c| c.to_ascii_uppercase());
+        let index = date_units[next_unit..]
+            .iter()
+            .position(|u| Some(*u) == unit)
+            .map(|i| i + next_unit);
+        let index = if let Some(index) = index {
+            index
+        } else {
+            // Not a date unit, so this is an error.
+            cursor.pos = start;
+            return syntax_error(string);
+        };
+        cursor.pos += 1;
+        let value: f64 = digits.parse().unwrap_or(f64::INFINITY);
+        match index {
+            0 => record.years = value,
+            1 => record.months = value,
+            2 => record.weeks = value,
+            _ => record.days = value,
+        }
+        next_unit = index + 1;
+        any = true;
+    }
+
+    // The time part.
+    if cursor.eat_any(&['T', 't']).is_some() {
+        let mut any_time = false;
+        let mut next_unit = 0;
+        let mut fraction_ns: i128 = 0;
+        let mut had_fraction = false;
+        while let Some(digits) = number(&mut cursor) {
+            // A fraction is only allowed in the last unit.
+            if had_fraction {
+                return syntax_error(string);
+            }
+            let fraction = match cursor.fraction() {
+                Some(fraction) => fraction,
+                None => return syntax_error(string),
+            };
+            let unit = cursor.peek().map(|c| c.to_ascii_uppercase());
+            let index = TIME_UNITS[next_unit..]
+                .iter()
+                .position(|(u, _)| Some(*u) == unit)
+                .map(|i| i + next_unit);
+            let index = if let Some(index) = index {
+                index
+            } else {
+                return syntax_error(string);
+            };
+            cursor.pos += 1;
+
+            let value: f64 = digits.parse().unwrap_or(f64::INFINITY);
+            match index {
+                0 => record.hours = value,
+                1 => record.minutes = value,
+                _ => record.seconds = value,
+            }
+            if let Some(fraction) = fraction {
+                // The fraction of the unit, in nanoseconds.
+                fraction_ns = i128::from(fraction) * TIME_UNITS[index].1 / 1_000_000_000;
+                had_fraction = true;
+                // The fraction of an hour or minute is balanced into the smaller units.
+                if index < 2 {
+                    let minutes = fraction_ns / 60_000_000_000;
+                    if index == 0 {
+                        record.minutes = minutes as f64;
+                    }
+                    fraction_ns -= minutes * 60_000_000_000;
+                    record.seconds = (fraction_ns / 1_000_000_000) as f64;
+                    fraction_ns %= 1_000_000_000;
+                }
+            }
+            next_unit = index + 1;
+            any_time = true;
+        }
+        if !any_time {
+            return syntax_error(string);
+        }
+        record.milliseconds = (fraction_ns / 1_000_000) as f64;
+        record.microseconds = (fraction_ns / 1_000 % 1000) as f64;
+        record.nanoseconds = (fraction_ns % 1000) as f64;
+        any = true;
+    }
+
+    if !any || !cursor.is_done() {
+        return syntax_error(string);
+    }
+
+    let record = if sign < 0 { record.negated() } else { record };
+    if !record.is_valid() {
+        return Err(JsNativeError::range()
+            .with_message("Temporal: invalid duration")
+            .into());
+    }
+    Ok(record)
+}
@@ -0,0 +1,653 @@
+//! This module implements the `Temporal.PlainDate` object.
+//!
+//! A plain date is a calendar date, without a time or a time zone.
+//!
+//! More information:
+//!  - [Temporal reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-plaindate-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDate
+
+use super::{
+    calendar::{
+        format_calendar_annotation, get_temporal_calendar_with_iso_default, to_temporal_calendar,
+        to_temporal_calendar_with_iso_default, validate_calendar_annotation,
+    },
+    duration::{to_temporal_duration_record, DurationRecord, RelativeTo},
+    fields::{TemporalFields, DATE_FIELDS},
+    getter,
+    iso::{days_in_month, days_in_year, is_leap_year, out_of_range, IsoDate, IsoDateTime, IsoTime},
+    options::{
+        get_difference_settings, get_options_object, to_calendar_name_option,
+        to_temporal_overflow, DifferenceOperation, Disambiguation, Overflow, UnitGroup,
+    },
+    parser::{parse_date_time, ParsedOffset},
+    plain_time::to_temporal_time,
+    reject_object_with_calendar_or_time_zone,
+    time_zone::{to_temporal_time_zone, TimeZone},
+    to_integer_with_truncation, value_of, Duration, PlainDateTime, TemporalUnit, ZonedDateTime,
+};
+use crate::{
+    builtins::JsArgs,
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsFunction,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsNativeError, JsResult, JsValue, JsVariant,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// Abstract operation `ToTemporalDate ( item [ , options ] )`
+///
+/// Returns the ISO date and the calendar of the date.
+pub(crate) fn to_temporal_date(
+    item: &JsValue,
+    options: Option<&JsObject>,
+    context: &mut Context,
+) -> JsResult<(IsoDate, JsObject)> {
+    // 1. If options is not present, set options to undefined.
+    // 2. Assert: Type(options) is Object or Undefined.
+    let options = match options {
+        Some(options) => options.clone(),
+        None => get_options_object(&JsValue::undefined())?,
+    };
+
+    // 3. If Type(item) is Object, then
+    if let Some(object) = item.as_object() {
+        {
+            let borrowed = object.borrow();
+            // a. If item has an [[InitializedTemporalDate]] internal slot, then
+            //     i. Return item.
+            if let Some(date) = borrowed.as_plain_date() {
+                return Ok((date.iso, date.calendar.clone()));
+            }
+        }
+
+        // b. If item has an [[InitializedTemporalZonedDateTime]] internal slot, then
+        let zoned = object
+            .borrow()
+            .as_zoned_date_time()
+            .map(|zoned| (zoned.nanoseconds, zoned.time_zone.clone(), zoned.calendar.clone()));
+        if let Some((epoch_ns, time_zone, calendar)) = zoned {
+            // i. Perform ? ToTemporalOverflow(options).
+            to_temporal_overflow(&options, context)?;
+            // ii. Let instant be ! CreateTemporalInstant(item.[[Nanoseconds]]).
+            // iii. Let plainDateTime be ? BuiltinTimeZoneGetPlainDateTimeFor(item.[[TimeZone]], instant, item.[[Calendar]]).
+            let date_time = TimeZone::kind_of(&time_zone).plain_date_time_for(epoch_ns);
+            // iv. Return ! CreateTemporalDate(plainDateTime.[[ISOYear]], plainDateTime.[[ISOMonth]], plainDateTime.[[ISODay]], plainDateTime.[[Calendar]]).
+            return Ok((date_time.date, calendar));
+        }
+
+        // c. If item has an [[InitializedTemporalDateTime]] internal slot, then
+        let date_time = object
+            .borrow()
+            .as_plain_date_time()
+            .map(|date_time| (date_time.iso.date, date_time.calendar.clone()));
+        if let Some(date_time) = date_time {
+            // i. Perform ? ToTemporalOverflow(options).
+            to_temporal_overflow(&options, context)?;
+            // ii. Return ! CreateTemporalDate(item.[[ISOYear]], item.[[ISOMonth]], item.[[ISODay]], item.[[Calendar]]).
+            return Ok(date_time);
+        }
+
+        // d. Let calendar be ? GetTemporalCalendarWithISODefault(item).
+        let calendar = get_temporal_calendar_with_iso_default(object, context)?;
+
+        // e. Let fieldNames be ? CalendarFields(calendar, « "day", "month", "monthCode", "year" »).
+        // f. Let fields be ? PrepareTemporalFields(item, fieldNames, «»).
+        let fields = TemporalFields::prepare(object, DATE_FIELDS, false, context)?;
+
+        // g. Return ? CalendarDateFromFields(calendar, fields, options).
+        let overflow = to_temporal_overflow(&options, context)?;
+        return Ok((fields.to_date(overflow)?, calendar));
+    }
+
+    // 4. Perform ? ToTemporalOverflow(options).
+    to_temporal_overflow(&options, context)?;
+
+    // 5. Let string be ? ToString(item).
+    let string = match item.variant() {
+        JsVariant::String(string) => string.to_std_string_escaped(),
+        _ => {
+            return Err(JsNativeError::typ()
+                .with_message("Temporal: expected a PlainDate, a property bag or a string")
+                .into())
+        }
+    };
+
+    // 6. Let result be ? ParseTemporalDateString(string).
+    let parsed = parse_date_time(&string)?;
+    if parsed.offset == Some(ParsedOffset::Utc) {
+        return Err(JsNativeError::range()
+            .with_message("Temporal: a plain date string cannot have a `Z` designator")
+            .into());
+    }
+
+    // 7. Assert: IsValidISODate(result.[[Year]], result.[[Month]], result.[[Day]]) is true.
+    // 8. Let calendar be ? ToTemporalCalendarWithISODefault(result.[[Calendar]]).
+    validate_calendar_annotation(parsed.calendar.as_deref())?;
+    let calendar = to_temporal_calendar_with_iso_default(&JsValue::undefined(), context)?;
+
+    // 9. Return ? CreateTemporalDate(result.[[Year]], result.[[Month]], result.[[Day]], calendar).
+    if !parsed.date.is_within_limits() {
+        return out_of_range();
+    }
+    Ok((parsed.date, calendar))
+}
+
+/// JavaScript `Temporal.PlainDate` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct PlainDate {
+    #[unsafe_ignore_trace]
+    pub(crate) iso: IsoDate,
+    pub(crate) calendar: JsObject,
+}
+
+impl PlainDate {
+    const NAME: &'static str = "PlainDate";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let mut builder = ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().plain_date().clone(),
+        );
+        builder.name(Self::NAME).length(3);
+        getter(&mut builder, "calendar", Self::calendar);
+        getter(&mut builder, "year", Self::year);
+        getter(&mut builder, "month", Self::month);
+        getter(&mut builder, "monthCode", Self::month_code);
+        getter(&mut builder, "day", Self::day);
+        getter(&mut builder, "dayOfWeek", Self::day_of_week);
+        getter(&mut builder, "dayOfYear", Self::day_of_year);
+        getter(&mut builder, "weekOfYear", Self::week_of_year);
+        getter(&mut builder, "daysInWeek", Self::days_in_week);
+        getter(&mut builder, "daysInMonth", Self::days_in_month);
+        getter(&mut builder, "daysInYear", Self::days_in_year);
+        getter(&mut builder, "monthsInYear", Self::months_in_year);
+        getter(&mut builder, "inLeapYear", Self::in_leap_year);
+        builder
+            .static_method(Self::from, "from", 1)
+            .static_method(Self::compare, "compare", 2)
+            .method(Self::to_plain_date_time, "toPlainDateTime", 0)
+            .method(Self::to_zoned_date_time, "toZonedDateTime", 1)
+            .method(Self::get_iso_fields, "getISOFields", 0)
+            .method(Self::add, "add", 1)
+            .method(Self::subtract, "subtract", 1)
+            .method(Self::with, "with", 1)
+            .method(Self::with_calendar, "withCalendar", 1)
+            .method(Self::until, "until", 1)
+            .method(Self::since, "since", 1)
+            .method(Self::equals, "equals", 1)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_json, "toLocaleString", 0)
+            .method(Self::to_json, "toJSON", 0)
+            .method(value_of, "valueOf", 0)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                "Temporal.PlainDate",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build()
+    }
+
+    /// Abstract operation `CreateTemporalDate ( isoYear, isoMonth, isoDay, calendar [ , newTarget ] )`
+    pub(crate) fn create(
+        iso: IsoDate,
+        calendar: JsObject,
+        new_target: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Assert: isoYear, isoMonth, and isoDay are integers.
+        // 2. Assert: Type(calendar) is Object.
+        // 3. If IsValidISODate(isoYear, isoMonth, isoDay) is false, throw a RangeError exception.
+        // 4. If ISODateTimeWithinLimits(isoYear, isoMonth, isoDay, 12, 0, 0, 0, 0, 0) is false, throw a RangeError exception.
+        if !iso.is_within_limits() {
+            return out_of_range();
+        }
+
+        // 5. If newTarget is not present, set newTarget to %Temporal.PlainDate%.
+        // 6. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.PlainDate.prototype%", « ... »).
+        let prototype = match new_target {
+            Some(new_target) => {
+                get_prototype_from_constructor(new_target, StandardConstructors::plain_date, context)?
+            }
+            None => context.intrinsics().constructors().plain_date().prototype(),
+        };
+
+        // 7-10. Set object.[[ISOYear]], [[ISOMonth]], [[ISODay]] and [[Calendar]].
+        // 11. Return object.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::plain_date(Self { iso, calendar }),
+        ))
+    }
+
+    /// Gets the ISO date and the calendar of the `this` value, throwing a `TypeError` if it's
+    /// not a `Temporal.PlainDate`.
+    fn this_date(this: &JsValue, method: &str) -> JsResult<(IsoDate, JsObject)> {
+        this.as_object()
+            .and_then(|object| {
+                object
+                    .borrow()
+                    .as_plain_date()
+                    .map(|date| (date.iso, date.calendar.clone()))
+            })
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "Temporal.PlainDate.prototype.{method}: 'this' is not a Temporal.PlainDate"
+                    ))
+                    .into()
+            })
+    }
+
+    /// `Temporal.PlainDate ( isoYear, isoMonth, isoDay [ , calendarLike ] )`
+    fn constructor(new_target: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("Temporal.PlainDate: calling a builtin constructor without new is forbidden")
+                .into());
+        }
+
+        // 2. Let y be ? ToIntegerWithTruncation(isoYear).
+        let year = to_integer_with_truncation(args.get_or_undefined(0), context)?;
+        // 3. Let m be ? ToIntegerWithTruncation(isoMonth).
+        let month = to_integer_with_truncation(args.get_or_undefined(1), context)?;
+        // 4. Let d be ? ToIntegerWithTruncation(isoDay).
+        let day = to_integer_with_truncation(args.get_or_undefined(2), context)?;
+
+        // 5. Let calendar be ? ToTemporalCalendarWithISODefault(calendarLike).
+        let calendar = to_temporal_calendar_with_iso_default(args.get_or_undefined(3), context)?;
+
+        // 6. Return ? CreateTemporalDate(y, m, d, calendar, NewTarget).
+        let iso = IsoDate::try_new(year, month, day)?;
+        Ok(Self::create(iso, calendar, Some(new_target), context)?.into())
+    }
+
+    /// `Temporal.PlainDate.from ( item [ , options ] )`
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 2. If Type(item) is Object and item has an [[InitializedTemporalDate]] internal slot, then
+        //     a. Perform ? ToTemporalOverflow(options).
+        //     b. Return ! CreateTemporalDate(item.[[ISOYear]], item.[[ISOMonth]], item.[[ISODay]], item.[[Calendar]]).
+        let item = args.get_or_undefined(0);
+        if item
+            .as_object()
+            .map_or(false, |object| object.borrow().is_plain_date())
+        {
+            to_temporal_overflow(&options, context)?;
+        }
+
+        // 3. Return ? ToTemporalDate(item, options).
+        let (iso, calendar) = to_temporal_date(item, Some(&options), context)?;
+        Ok(Self::create(iso, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.PlainDate.compare ( one, two )`
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set one to ? ToTemporalDate(one).
+        let one = to_temporal_date(args.get_or_undefined(0), None, context)?.0;
+        // 2. Set two to ? ToTemporalDate(two).
+        let two = to_temporal_date(args.get_or_undefined(1), None, context)?.0;
+        // 3. Return 𝔽(! CompareISODate(one.[[ISOYear]], ..., two.[[ISODay]])).
+        Ok(match one.cmp(&two) {
+            std::cmp::Ordering::Less => -1,
+            std::cmp::Ordering::Equal => 0,
+            std::cmp::Ordering::Greater => 1,
+        }
+        .into())
+    }
+
+    /// `get Temporal.PlainDate.prototype.calendar`
+    fn calendar(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_date(this, "calendar")?.1.into())
+    }
+
+    /// `get Temporal.PlainDate.prototype.year`
+    fn year(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_date(this, "year")?.0.year.into())
+    }
+
+    /// `get Temporal.PlainDate.prototype.month`
+    fn month(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_date(this, "month")?.0.month.into())
+    }
+
+    /// `get Temporal.PlainDate.prototype.monthCode`
+    fn month_code(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let date = Self::this_date(this, "monthCode")?.0;
+        Ok(format!("M{:02}", date.month).into())
+    }
+
+    /// `get Temporal.PlainDate.prototype.day`
+    fn day(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_date(this, "day")?.0.day.into())
+    }
+
+    /// `get Temporal.PlainDate.prototype.dayOfWeek`
+    fn day_of_week(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_date(this, "dayOfWeek")?.0.day_of_week().into())
+    }
+
+    /// `get Temporal.PlainDate.prototype.dayOfYear`
+    fn day_of_year(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_date(this, "dayOfYear")?.0.day_of_year().into())
+    }
+
+    /// `get Temporal.PlainDate.prototype.weekOfYear`
+    fn week_of_year(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_date(this, "weekOfYear")?.0.week_of_year().into())
+    }
+
+    /// `get Temporal.PlainDate.prototype.daysInWeek`
+    fn days_in_week(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::this_date(this, "daysInWeek")?;
+        Ok(7.into())
+    }
+
+    /// `get Temporal.PlainDate.prototype.daysInMonth`
+    fn days_in_month(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let date = Self::this_date(this, "daysInMonth")?.0;
+        Ok(days_in_month(date.year, date.month).into())
+    }
+
+    /// `get Temporal.PlainDate.prototype.daysInYear`
+    fn days_in_year(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let date = Self::this_date(this, "daysInYear")?.0;
+        Ok(days_in_year(date.year).into())
+    }
+
+    /// `get Temporal.PlainDate.prototype.monthsInYear`
+    fn months_in_year(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::this_date(this, "monthsInYear")?;
+        Ok(12.into())
+    }
+
+    /// `get Temporal.PlainDate.prototype.inLeapYear`
+    fn in_leap_year(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let date = Self::this_date(this, "inLeapYear")?.0;
+        Ok(is_leap_year(date.year).into())
+    }
+
+    /// `Temporal.PlainDate.prototype.toPlainDateTime ( [ temporalTime ] )`
+    fn to_plain_date_time(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let (date, calendar) = Self::this_date(this, "toPlainDateTime")?;
+
+        // 3. If temporalTime is undefined, then
+        //     a. Return ? CreateTemporalDateTime(temporalDate.[[ISOYear]], temporalDate.[[ISOMonth]], temporalDate.[[ISODay]], 0, 0, 0, 0, 0, 0, temporalDate.[[Calendar]]).
+        // 4. Set temporalTime to ? ToTemporalTime(temporalTime).
+        let time = match args.get_or_undefined(0).variant() {
+            JsVariant::Undefined => IsoTime::MIDNIGHT,
+            _ => to_temporal_time(args.get_or_undefined(0), Overflow::Constrain, context)?,
+        };
+
+        // 5. Return ? CreateTemporalDateTime(temporalDate.[[ISOYear]], ..., temporalTime.[[ISONanosecond]], temporalDate.[[Calendar]]).
+        Ok(PlainDateTime::create(IsoDateTime { date, time }, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.PlainDate.prototype.toZonedDateTime ( item )`
+    fn to_zoned_date_time(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let (date, calendar) = Self::this_date(this, "toZonedDateTime")?;
+
+        let item = args.get_or_undefined(0);
+        let (time_zone, temporal_time) = match item.as_object() {
+            // 3. If Type(item) is Object, then
+            Some(object) if !object.borrow().is_time_zone() => {
+                // a. Let timeZoneLike be ? Get(item, "timeZone").
+                let time_zone_like = object.get("timeZone", context)?;
+                if time_zone_like.is_undefined() {
+                    // b. If timeZoneLike is undefined, then
+                    //     i. Let timeZone be ? ToTemporalTimeZone(item).
+                    //     ii. Let temporalTime be undefined.
+                    (to_temporal_time_zone(item, context)?, JsValue::undefined())
+                } else {
+                    // c. Else,
+                    //     i. Let timeZone be ? ToTemporalTimeZone(timeZoneLike).
+                    //     ii. Let temporalTime be ? Get(item, "plainTime").
+                    let time_zone = to_temporal_time_zone(&time_zone_like, context)?;
+                    (time_zone, object.get("plainTime", context)?)
+                }
+            }
+            // 4. Else,
+            //     a. Let timeZone be ? ToTemporalTimeZone(item).
+            //     b. Let temporalTime be undefined.
+            _ => (to_temporal_time_zone(item, context)?, JsValue::undefined()),
+        };
+
+        // 5. If temporalTime is undefined, then
+        //     a. Let temporalDateTime be ? CreateTemporalDateTime(temporalDate.[[ISOYear]], temporalDate.[[ISOMonth]], temporalDate.[[ISODay]], 0, 0, 0, 0, 0, 0, temporalDate.[[Calendar]]).
+        // 6. Else,
+        //     a. Set temporalTime to ? ToTemporalTime(temporalTime).
+        //     b. Let temporalDateTime be ? CreateTemporalDateTime(temporalDate.[[ISOYear]], ..., temporalTime.[[ISONanosecond]], temporalTime.[[Calendar]]).
+        let time = match temporal_time.variant() {
+            JsVariant::Undefined => IsoTime::MIDNIGHT,
+            _ => to_temporal_time(&temporal_time, Overflow::Constrain, context)?,
+        };
+        let date_time = IsoDateTime { date, time };
+        if !date_time.is_within_limits() {
+            return out_of_range();
+        }
+
+        // 7. Let instant be ? BuiltinTimeZoneGetInstantFor(timeZone, temporalDateTime, "compatible").
+        let epoch_ns =
+            TimeZone::kind_of(&time_zone).instant_for(date_time, Disambiguation::Compatible)?;
+
+        // 8. Return ! CreateTemporalZonedDateTime(instant.[[Nanoseconds]], timeZone, temporalDate.[[Calendar]]).
+        Ok(ZonedDateTime::create(epoch_ns, time_zone, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.PlainDate.prototype.getISOFields ( )`
+    fn get_iso_fields(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let (date, calendar) = Self::this_date(this, "getISOFields")?;
+
+        // 3. Let fields be OrdinaryObjectCreate(%Object.prototype%).
+        let fields = JsObject::from_proto_and_data(
+            context.intrinsics().constructors().object().prototype(),
+            ObjectData::ordinary(),
+        );
+
+        // 4-7. Perform ! CreateDataPropertyOrThrow(fields, "calendar", "isoDay", "isoMonth", "isoYear").
+        fields.create_data_property_or_throw("calendar", calendar, context)?;
+        fields.create_data_property_or_throw("isoDay", date.day, context)?;
+        fields.create_data_property_or_throw("isoMonth", date.month, context)?;
+        fields.create_data_property_or_throw("isoYear", date.year, context)?;
+
+        // 8. Return fields.
+        Ok(fields.into())
+    }
+
+    /// Adds `sign` times the duration in the arguments to the `this` date.
+    fn add_or_subtract(
+        this: &JsValue,
+        args: &[JsValue],
+        sign: f64,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let (date, calendar) = Self::this_date(this, method)?;
+
+        // 3. Let duration be ? ToTemporalDuration(temporalDurationLike).
+        let mut duration = to_temporal_duration_record(args.get_or_undefined(0), context)?;
+        if sign < 0.0 {
+            duration = duration.negated();
+        }
+
+        // 4. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 5. Return ? CalendarDateAdd(temporalDate.[[Calendar]], temporalDate, duration, options).
+        let overflow = to_temporal_overflow(&options, context)?;
+        let days = DurationRecord::balance_time(duration.time_ns()?, TemporalUnit::Day).days
+            + duration.days;
+        let result = date.add(duration.years, duration.months, duration.weeks, days, overflow)?;
+        Ok(Self::create(result, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.PlainDate.prototype.add ( temporalDurationLike [ , options ] )`
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::add_or_subtract(this, args, 1.0, "add", context)
+    }
+
+    /// `Temporal.PlainDate.prototype.subtract ( temporalDurationLike [ , options ] )`
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::add_or_subtract(this, args, -1.0, "subtract", context)
+    }
+
+    /// `Temporal.PlainDate.prototype.with ( temporalDateLike [ , options ] )`
+    fn with(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let (date, calendar) = Self::this_date(this, "with")?;
+
+        // 3. If Type(temporalDateLike) is not Object, then
+        let date_like = args.get_or_undefined(0).as_object().cloned().ok_or_else(|| {
+            // a. Throw a TypeError exception.
+            JsNativeError::typ().with_message("Temporal.PlainDate.prototype.with: expected an object")
+        })?;
+
+        // 4. Perform ? RejectObjectWithCalendarOrTimeZone(temporalDateLike).
+        reject_object_with_calendar_or_time_zone(&date_like, context)?;
+
+        // 5-6. Let fieldNames be ? CalendarFields(calendar, « "day", "month", "monthCode", "year" »).
+        // 7. Let partialDate be ? PrepareTemporalFields(temporalDateLike, fieldNames, partial).
+        let partial = TemporalFields::prepare(&date_like, DATE_FIELDS, true, context)?;
+
+        // 8. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 9. Let fields be ? PrepareTemporalFields(temporalDate, fieldNames, «»).
+        // 10. Set fields to ? CalendarMergeFields(calendar, fields, partialDate).
+        // 11. Set fields to ? PrepareTemporalFields(fields, fieldNames, «»).
+        let fields = TemporalFields::from_date(date).merge(&partial);
+
+        // 12. Return ? CalendarDateFromFields(calendar, fields, options).
+        let overflow = to_temporal_overflow(&options, context)?;
+        let result = fields.to_date(overflow)?;
+        Ok(Self::create(result, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.PlainDate.prototype.withCalendar ( calendarLike )`
+    fn with_calendar(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let (date, _) = Self::this_date(this, "withCalendar")?;
+
+        // 3. Let calendar be ? ToTemporalCalendar(calendarLike).
+        let calendar = to_temporal_calendar(args.get_or_undefined(0), context)?;
+
+        // 4. Return ! CreateTemporalDate(temporalDate.[[ISOYear]], temporalDate.[[ISOMonth]], temporalDate.[[ISODay]], calendar).
+        Ok(Self::create(date, calendar, None, context)?.into())
+    }
+
+    /// Abstract operation `DifferenceTemporalPlainDate ( operation, temporalDate, other, options )`
+    fn difference(
+        this: &JsValue,
+        args: &[JsValue],
+        operation: DifferenceOperation,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let (date, _) = Self::this_date(this, method)?;
+
+        // 2. Set other to ? ToTemporalDate(other).
+        let other = to_temporal_date(args.get_or_undefined(0), None, context)?.0;
+
+        // 3. If ? CalendarEquals(temporalDate.[[Calendar]], other.[[Calendar]]) is false, throw a RangeError exception.
+        // 4. Let settings be ? GetDifferenceSettings(operation, options, date, « », "day", "day").
+        let settings = get_difference_settings(
+            operation,
+            args.get_or_undefined(1),
+            UnitGroup::Date,
+            TemporalUnit::Day,
+            TemporalUnit::Day,
+            context,
+        )?;
+
+        // 5. Let untilOptions be ? MergeLargestUnitOption(settings.[[Options]], settings.[[LargestUnit]]).
+        // 6. Let result be ? CalendarDateUntil(temporalDate.[[Calendar]], temporalDate, other, untilOptions).
+        // 7. If settings.[[SmallestUnit]] is not "day" or settings.[[RoundingIncrement]] ≠ 1, then
+        //     a. Set result to (? RoundDuration(result.[[Years]], result.[[Months]], result.[[Weeks]], result.[[Days]], 0, 0, 0, 0, 0, 0, settings.[[RoundingIncrement]], settings.[[SmallestUnit]], settings.[[RoundingMode]], temporalDate)).[[DurationRecord]].
+        let start = IsoDateTime {
+            date,
+            time: IsoTime::MIDNIGHT,
+        };
+        let end = IsoDateTime {
+            date: other,
+            time: IsoTime::MIDNIGHT,
+        };
+        let result = RelativeTo::Plain(start).round(
+            end.to_local_ns(),
+            settings.largest_unit,
+            settings.smallest_unit,
+            settings.increment,
+            settings.mode,
+        )?;
+
+        // 8. Return ! CreateTemporalDuration(sign × result.[[Years]], sign × result.[[Months]], sign × result.[[Weeks]], sign × result.[[Days]], 0, 0, 0, 0, 0, 0).
+        let result = match operation {
+            DifferenceOperation::Until => result,
+            DifferenceOperation::Since => result.negated(),
+        };
+        Ok(Duration::create(result, None, context)?.into())
+    }
+
+    /// `Temporal.PlainDate.prototype.until ( other [ , options ] )`
+    fn until(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::difference(this, args, DifferenceOperation::Until, "until", context)
+    }
+
+    /// `Temporal.PlainDate.prototype.since ( other [ , options ] )`
+    fn since(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::difference(this, args, DifferenceOperation::Since, "since", context)
+    }
+
+    /// `Temporal.PlainDate.prototype.equals ( other )`
+    fn equals(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let (date, _) = Self::this_date(this, "equals")?;
+
+        // 3. Set other to ? ToTemporalDate(other).
+        let other = to_temporal_date(args.get_or_undefined(0), None, context)?.0;
+
+        // 4-7. Return ? CalendarEquals(temporalDate.[[Calendar]], other.[[Calendar]]) if the ISO
+        //      fields are equal, and false otherwise.
+        Ok((date == other).into())
+    }
+
+    /// `Temporal.PlainDate.prototype.toString ( [ options ] )`
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let (date, _) = Self::this_date(this, "toString")?;
+
+        // 3. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(0))?;
+
+        // 4. Let showCalendar be ? ToCalendarNameOption(options).
+        let show_calendar = to_calendar_name_option(&options, context)?;
+
+        // 5. Return ? TemporalDateToString(temporalDate, showCalendar).
+        Ok(format!("{}{}", date.to_iso_string(), format_calendar_annotation(show_calendar)).into())
+    }
+
+    /// `Temporal.PlainDate.prototype.toJSON ( )`
+    fn to_json(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let (date, _) = Self::this_date(this, "toJSON")?;
+
+        // 3. Return ? TemporalDateToString(temporalDate, "auto").
+        Ok(date.to_iso_string().into())
+    }
+}
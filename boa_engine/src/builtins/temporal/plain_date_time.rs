@@ -0,0 +1,813 @@
+//! This module implements the `Temporal.PlainDateTime` object.
+//!
+//! A plain date-time is a calendar date and a wall-clock time, without a time zone.
+//!
+//! More information:
+//!  - [Temporal reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-plaindatetime-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime
+
+use super::{
+    calendar::{
+        format_calendar_annotation, get_temporal_calendar_with_iso_default, to_temporal_calendar,
+        to_temporal_calendar_with_iso_default, validate_calendar_annotation,
+    },
+    duration::{to_temporal_duration_record, RelativeTo},
+    fields::{TemporalFields, DATE_TIME_FIELDS},
+    getter,
+    iso::{days_in_month, days_in_year, is_leap_year, out_of_range, IsoDate, IsoDateTime, IsoTime},
+    options::{
+        get_difference_settings, get_options_object, get_temporal_unit, to_calendar_name_option,
+        to_seconds_string_precision, to_temporal_disambiguation, to_temporal_overflow,
+        to_temporal_rounding_increment, to_temporal_rounding_mode, DifferenceOperation,
+        Overflow, Precision, RoundingMode, UnitGroup,
+    },
+    parser::{parse_date_time, ParsedOffset},
+    plain_date::to_temporal_date,
+    plain_time::to_temporal_time,
+    reject_object_with_calendar_or_time_zone,
+    time_zone::{to_temporal_time_zone, TimeZone},
+    to_integer_with_truncation, value_of, Duration, PlainDate, PlainTime, TemporalUnit,
+    ZonedDateTime,
+};
+use crate::{
+    builtins::JsArgs,
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsFunction,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsNativeError, JsResult, JsValue, JsVariant,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// Abstract operation `ToTemporalDateTime ( item [ , options ] )`
+///
+/// Returns the ISO date and time and the calendar of the date-time.
+pub(crate) fn to_temporal_date_time(
+    item: &JsValue,
+    options: Option<&JsObject>,
+    context: &mut Context,
+) -> JsResult<(IsoDateTime, JsObject)> {
+    // 1. If options is not present, set options to undefined.
+    // 2. Assert: Type(options) is Object or Undefined.
+    let options = match options {
+        Some(options) => options.clone(),
+        None => get_options_object(&JsValue::undefined())?,
+    };
+
+    // 3. If Type(item) is Object, then
+    if let Some(object) = item.as_object() {
+        {
+            let borrowed = object.borrow();
+            // a. If item has an [[InitializedTemporalDateTime]] internal slot, then
+            //     i. Return item.
+            if let Some(date_time) = borrowed.as_plain_date_time() {
+                return Ok((date_time.iso, date_time.calendar.clone()));
+            }
+        }
+
+        // b. If item has an [[InitializedTemporalZonedDateTime]] internal slot, then
+        let zoned = object
+            .borrow()
+            .as_zoned_date_time()
+            .map(|zoned| (zoned.nanoseconds, zoned.time_zone.clone(), zoned.calendar.clone()));
+        if let Some((epoch_ns, time_zone, calendar)) = zoned {
+            // i. Perform ? ToTemporalOverflow(options).
+            to_temporal_overflow(&options, context)?;
+            // ii. Let instant be ! CreateTemporalInstant(item.[[Nanoseconds]]).
+            // iii. Return ? BuiltinTimeZoneGetPlainDateTimeFor(item.[[TimeZone]], instant, item.[[Calendar]]).
+            let date_time = TimeZone::kind_of(&time_zone).plain_date_time_for(epoch_ns);
+            return Ok((date_time, calendar));
+        }
+
+        // c. If item has an [[InitializedTemporalDate]] internal slot, then
+        let date = object
+            .borrow()
+            .as_plain_date()
+            .map(|date| (date.iso, date.calendar.clone()));
+        if let Some((date, calendar)) = date {
+            // i. Perform ? ToTemporalOverflow(options).
+            to_temporal_overflow(&options, context)?;
+            // ii. Return ? CreateTemporalDateTime(item.[[ISOYear]], item.[[ISOMonth]], item.[[ISODay]], 0, 0, 0, 0, 0, 0, item.[[Calendar]]).
+            let date_time = IsoDateTime {
+                date,
+                time: IsoTime::MIDNIGHT,
+            };
+            if !date_time.is_within_limits() {
+                return out_of_range();
+            }
+            return Ok((date_time, calendar));
+        }
+
+        // d. Let calendar be ? GetTemporalCalendarWithISODefault(item).
+        let calendar = get_temporal_calendar_with_iso_default(object, context)?;
+
+        // e. Let fieldNames be ? CalendarFields(calendar, « "day", "hour", "microsecond", "millisecond", "minute", "month", "monthCode", "nanosecond", "second", "year" »).
+        // f. Let fields be ? PrepareTemporalFields(item, fieldNames, «»).
+        let fields = TemporalFields::prepare(object, DATE_TIME_FIELDS, false, context)?;
+
+        // g. Let result be ? InterpretTemporalDateTimeFields(calendar, fields, options).
+        let overflow = to_temporal_overflow(&options, context)?;
+        let date_time = fields.to_date_time(overflow)?;
+        if !date_time.is_within_limits() {
+            return out_of_range();
+        }
+        return Ok((date_time, calendar));
+    }
+
+    // 4. Else,
+    //     a. Perform ? ToTemporalOverflow(options).
+    to_temporal_overflow(&options, context)?;
+
+    //     b. Let string be ? ToString(item).
+    let string = match item.variant() {
+        JsVariant::String(string) => string.to_std_string_escaped(),
+        _ => {
+            return Err(JsNativeError::typ()
+                .with_message("Temporal: expected a PlainDateTime, a property bag or a string")
+                .into())
+        }
+    };
+
+    //     c. Let result be ? ParseTemporalDateTimeString(string).
+    let parsed = parse_date_time(&string)?;
+    if parsed.offset == Some(ParsedOffset::Utc) {
+        return Err(JsNativeError::range()
+            .with_message("Temporal: a plain date-time string cannot have a `Z` designator")
+            .into());
+    }
+
+    //     d. Assert: IsValidISODate(result.[[Year]], result.[[Month]], result.[[Day]]) is true.
+    //     e. Assert: IsValidTime(result.[[Hour]], ..., result.[[Nanosecond]]) is true.
+    //     f. Let calendar be ? ToTemporalCalendarWithISODefault(result.[[Calendar]]).
+    validate_calendar_annotation(parsed.calendar.as_deref())?;
+    let calendar = to_temporal_calendar_with_iso_default(&JsValue::undefined(), context)?;
+
+    // 5. Return ? CreateTemporalDateTime(result.[[Year]], ..., result.[[Nanosecond]], calendar).
+    let date_time = IsoDateTime {
+        date: parsed.date,
+        time: parsed.time.unwrap_or_default(),
+    };
+    if !date_time.is_within_limits() {
+        return out_of_range();
+    }
+    Ok((date_time, calendar))
+}
+
+/// JavaScript `Temporal.PlainDateTime` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct PlainDateTime {
+    #[unsafe_ignore_trace]
+    pub(crate) iso: IsoDateTime,
+    pub(crate) calendar: JsObject,
+}
+
+impl PlainDateTime {
+    const NAME: &'static str = "PlainDateTime";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let mut builder = ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().plain_date_time().clone(),
+        );
+        builder.name(Self::NAME).length(3);
+        getter(&mut builder, "calendar", Self::calendar);
+        getter(&mut builder, "year", Self::year);
+        getter(&mut builder, "month", Self::month);
+        getter(&mut builder, "monthCode", Self::month_code);
+        getter(&mut builder, "day", Self::day);
+        getter(&mut builder, "hour", Self::hour);
+        getter(&mut builder, "minute", Self::minute);
+        getter(&mut builder, "second", Self::second);
+        getter(&mut builder, "millisecond", Self::millisecond);
+        getter(&mut builder, "microsecond", Self::microsecond);
+        getter(&mut builder, "nanosecond", Self::nanosecond);
+        getter(&mut builder, "dayOfWeek", Self::day_of_week);
+        getter(&mut builder, "dayOfYear", Self::day_of_year);
+        getter(&mut builder, "weekOfYear", Self::week_of_year);
+        getter(&mut builder, "daysInWeek", Self::days_in_week);
+        getter(&mut builder, "daysInMonth", Self::days_in_month);
+        getter(&mut builder, "daysInYear", Self::days_in_year);
+        getter(&mut builder, "monthsInYear", Self::months_in_year);
+        getter(&mut builder, "inLeapYear", Self::in_leap_year);
+        builder
+            .static_method(Self::from, "from", 1)
+            .static_method(Self::compare, "compare", 2)
+            .method(Self::with, "with", 1)
+            .method(Self::with_plain_time, "withPlainTime", 0)
+            .method(Self::with_plain_date, "withPlainDate", 1)
+            .method(Self::with_calendar, "withCalendar", 1)
+            .method(Self::add, "add", 1)
+            .method(Self::subtract, "subtract", 1)
+            .method(Self::until, "until", 1)
+            .method(Self::since, "since", 1)
+            .method(Self::round, "round", 1)
+            .method(Self::equals, "equals", 1)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_json, "toLocaleString", 0)
+            .method(Self::to_json, "toJSON", 0)
+            .method(value_of, "valueOf", 0)
+            .method(Self::to_zoned_date_time, "toZonedDateTime", 1)
+            .method(Self::to_plain_date, "toPlainDate", 0)
+            .method(Self::to_plain_time, "toPlainTime", 0)
+            .method(Self::get_iso_fields, "getISOFields", 0)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                "Temporal.PlainDateTime",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build()
+    }
+
+    /// Abstract operation `CreateTemporalDateTime ( isoYear, isoMonth, isoDay, hour, minute, second, millisecond, microsecond, nanosecond, calendar [ , newTarget ] )`
+    pub(crate) fn create(
+        iso: IsoDateTime,
+        calendar: JsObject,
+        new_target: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1-5. If ISODateTimeWithinLimits(isoYear, isoMonth, isoDay, hour, minute, second, millisecond, microsecond, nanosecond) is false, then
+        //     a. Throw a RangeError exception.
+        if !iso.is_within_limits() {
+            return out_of_range();
+        }
+
+        // 6. If newTarget is not present, set newTarget to %Temporal.PlainDateTime%.
+        // 7. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.PlainDateTime.prototype%", « ... »).
+        let prototype = match new_target {
+            Some(new_target) => get_prototype_from_constructor(
+                new_target,
+                StandardConstructors::plain_date_time,
+                context,
+            )?,
+            None => context.intrinsics().constructors().plain_date_time().prototype(),
+        };
+
+        // 8-17. Set object.[[ISOYear]], ..., object.[[ISONanosecond]] and object.[[Calendar]].
+        // 18. Return object.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::plain_date_time(Self { iso, calendar }),
+        ))
+    }
+
+    /// Gets the ISO date and time and the calendar of the `this` value, throwing a `TypeError` if
+    /// it's not a `Temporal.PlainDateTime`.
+    fn this_date_time(this: &JsValue, method: &str) -> JsResult<(IsoDateTime, JsObject)> {
+        this.as_object()
+            .and_then(|object| {
+                object
+                    .borrow()
+                    .as_plain_date_time()
+                    .map(|date_time| (date_time.iso, date_time.calendar.clone()))
+            })
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "Temporal.PlainDateTime.prototype.{method}: 'this' is not a Temporal.PlainDateTime"
+                    ))
+                    .into()
+            })
+    }
+
+    /// `Temporal.PlainDateTime ( isoYear, isoMonth, isoDay [ , hour [ , minute [ , second [ , millisecond [ , microsecond [ , nanosecond [ , calendarLike ] ] ] ] ] ] ] )`
+    fn constructor(new_target: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        if new_target.is_undefined() {
+            // a. Throw a TypeError exception.
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "Temporal.PlainDateTime: calling a builtin constructor without new is forbidden",
+                )
+                .into());
+        }
+
+        // 2-10. Let isoYear, ..., nanosecond be ? ToIntegerWithTruncation of the arguments.
+        let mut fields = [0.0; 9];
+        for (i, field) in fields.iter_mut().enumerate() {
+            *field = to_integer_with_truncation(args.get_or_undefined(i), context)?;
+        }
+
+        // 11. Let calendar be ? ToTemporalCalendarWithISODefault(calendarLike).
+        let calendar = to_temporal_calendar_with_iso_default(args.get_or_undefined(9), context)?;
+
+        // 12. Return ? CreateTemporalDateTime(isoYear, ..., nanosecond, calendar, NewTarget).
+        let date = IsoDate::try_new(fields[0], fields[1], fields[2])?;
+        let time = IsoTime::regulate(
+            [fields[3], fields[4], fields[5], fields[6], fields[7], fields[8]],
+            Overflow::Reject,
+        )?;
+        Ok(Self::create(IsoDateTime { date, time }, calendar, Some(new_target), context)?.into())
+    }
+
+    /// `Temporal.PlainDateTime.from ( item [ , options ] )`
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 2. If Type(item) is Object and item has an [[InitializedTemporalDateTime]] internal slot, then
+        //     a. Perform ? ToTemporalOverflow(options).
+        //     b. Return ! CreateTemporalDateTime(item.[[ISOYear]], ..., item.[[Calendar]]).
+        let item = args.get_or_undefined(0);
+        if item
+            .as_object()
+            .map_or(false, |object| object.borrow().is_plain_date_time())
+        {
+            to_temporal_overflow(&options, context)?;
+        }
+
+        // 3. Return ? ToTemporalDateTime(item, options).
+        let (iso, calendar) = to_temporal_date_time(item, Some(&options), context)?;
+        Ok(Self::create(iso, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.PlainDateTime.compare ( one, two )`
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set one to ? ToTemporalDateTime(one).
+        let one = to_temporal_date_time(args.get_or_undefined(0), None, context)?.0;
+        // 2. Set two to ? ToTemporalDateTime(two).
+        let two = to_temporal_date_time(args.get_or_undefined(1), None, context)?.0;
+        // 3. Return 𝔽(! CompareISODateTime(one.[[ISOYear]], ..., two.[[ISONanosecond]])).
+        Ok(match one.cmp(&two) {
+            std::cmp::Ordering::Less => -1,
+            std::cmp::Ordering::Equal => 0,
+            std::cmp::Ordering::Greater => 1,
+        }
+        .into())
+    }
+
+    /// `get Temporal.PlainDateTime.prototype.calendar`
+    fn calendar(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_date_time(this, "calendar")?.1.into())
+    }
+
+    /// `get Temporal.PlainDateTime.prototype.year`
+    fn year(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_date_time(this, "year")?.0.date.year.into())
+    }
+
+    /// `get Temporal.PlainDateTime.prototype.month`
+    fn month(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_date_time(this, "month")?.0.date.month.into())
+    }
+
+    /// `get Temporal.PlainDateTime.prototype.monthCode`
+    fn month_code(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let date = Self::this_date_time(this, "monthCode")?.0.date;
+        Ok(format!("M{:02}", date.month).into())
+    }
+
+    /// `get Temporal.PlainDateTime.prototype.day`
+    fn day(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_date_time(this, "day")?.0.date.day.into())
+    }
+
+    /// `get Temporal.PlainDateTime.prototype.hour`
+    fn hour(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_date_time(this, "hour")?.0.time.hour.into())
+    }
+
+    /// `get Temporal.PlainDateTime.prototype.minute`
+    fn minute(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_date_time(this, "minute")?.0.time.minute.into())
+    }
+
+    /// `get Temporal.PlainDateTime.prototype.second`
+    fn second(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_date_time(this, "second")?.0.time.second.into())
+    }
+
+    /// `get Temporal.PlainDateTime.prototype.millisecond`
+    fn millisecond(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_date_time(this, "millisecond")?.0.time.millisecond.into())
+    }
+
+    /// `get Temporal.PlainDateTime.prototype.microsecond`
+    fn microsecond(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_date_time(this, "microsecond")?.0.time.microsecond.into())
+    }
+
+    /// `get Temporal.PlainDateTime.prototype.nanosecond`
+    fn nanosecond(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_date_time(this, "nanosecond")?.0.time.nanosecond.into())
+    }
+
+    /// `get Temporal.PlainDateTime.prototype.dayOfWeek`
+    fn day_of_week(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let date = Self::this_date_time(this, "dayOfWeek")?.0.date;
+        Ok(date.day_of_week().into())
+    }
+
+    /// `get Temporal.PlainDateTime.prototype.dayOfYear`
+    fn day_of_year(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let date = Self::this_date_time(this, "dayOfYear")?.0.date;
+        Ok(date.day_of_year().into())
+    }
+
+    /// `get Temporal.PlainDateTime.prototype.weekOfYear`
+    fn week_of_year(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let date = Self::this_date_time(this, "weekOfYear")?.0.date;
+        Ok(date.week_of_year().into())
+    }
+
+    /// `get Temporal.PlainDateTime.prototype.daysInWeek`
+    fn days_in_week(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::this_date_time(this, "daysInWeek")?;
+        Ok(7.into())
+    }
+
+    /// `get Temporal.PlainDateTime.prototype.daysInMonth`
+    fn days_in_month(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let date = Self::this_date_time(this, "daysInMonth")?.0.date;
+        Ok(days_in_month(date.year, date.month).into())
+    }
+
+    /// `get Temporal.PlainDateTime.prototype.daysInYear`
+    fn days_in_year(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let date = Self::this_date_time(this, "daysInYear")?.0.date;
+        Ok(days_in_year(date.year).into())
+    }
+
+    /// `get Temporal.PlainDateTime.prototype.monthsInYear`
+    fn months_in_year(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::this_date_time(this, "monthsInYear")?;
+        Ok(12.into())
+    }
+
+    /// `get Temporal.PlainDateTime.prototype.inLeapYear`
+    fn in_leap_year(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let date = Self::this_date_time(this, "inLeapYear")?.0.date;
+        Ok(is_leap_year(date.year).into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.with ( temporalDateTimeLike [ , options ] )`
+    fn with(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let (date_time, calendar) = Self::this_date_time(this, "with")?;
+
+        // 3. If Type(temporalDateTimeLike) is not Object, then
+        let date_time_like = args.get_or_undefined(0).as_object().cloned().ok_or_else(|| {
+            // a. Throw a TypeError exception.
+            JsNativeError::typ()
+                .with_message("Temporal.PlainDateTime.prototype.with: expected an object")
+        })?;
+
+        // 4. Perform ? RejectObjectWithCalendarOrTimeZone(temporalDateTimeLike).
+        reject_object_with_calendar_or_time_zone(&date_time_like, context)?;
+
+        // 5-6. Let fieldNames be ? CalendarFields(calendar, « "day", "hour", ..., "year" »).
+        // 7. Let partialDateTime be ? PrepareTemporalFields(temporalDateTimeLike, fieldNames, partial).
+        let partial = TemporalFields::prepare(&date_time_like, DATE_TIME_FIELDS, true, context)?;
+
+        // 8. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 9. Let fields be ? PrepareTemporalFields(dateTime, fieldNames, «»).
+        // 10. Set fields to ? CalendarMergeFields(calendar, fields, partialDateTime).
+        // 11. Set fields to ? PrepareTemporalFields(fields, fieldNames, «»).
+        let fields = TemporalFields::from_date_time(date_time).merge(&partial);
+
+        // 12. Let result be ? InterpretTemporalDateTimeFields(calendar, fields, options).
+        let overflow = to_temporal_overflow(&options, context)?;
+        let result = fields.to_date_time(overflow)?;
+
+        // 13-15. Return ? CreateTemporalDateTime(result.[[Year]], ..., result.[[Nanosecond]], calendar).
+        Ok(Self::create(result, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.withPlainTime ( [ plainTimeLike ] )`
+    fn with_plain_time(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(temporalDateTime, [[InitializedTemporalDateTime]]).
+        let (date_time, calendar) = Self::this_date_time(this, "withPlainTime")?;
+
+        // 3. If plainTimeLike is undefined, then
+        //     a. Return ? CreateTemporalDateTime(temporalDateTime.[[ISOYear]], temporalDateTime.[[ISOMonth]], temporalDateTime.[[ISODay]], 0, 0, 0, 0, 0, 0, temporalDateTime.[[Calendar]]).
+        // 4. Let plainTime be ? ToTemporalTime(plainTimeLike).
+        let time = match args.get_or_undefined(0).variant() {
+            JsVariant::Undefined => IsoTime::MIDNIGHT,
+            _ => to_temporal_time(args.get_or_undefined(0), Overflow::Constrain, context)?,
+        };
+
+        // 5. Return ? CreateTemporalDateTime(temporalDateTime.[[ISOYear]], ..., plainTime.[[ISONanosecond]], temporalDateTime.[[Calendar]]).
+        let result = IsoDateTime {
+            date: date_time.date,
+            time,
+        };
+        Ok(Self::create(result, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.withPlainDate ( plainDateLike )`
+    fn with_plain_date(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(temporalDateTime, [[InitializedTemporalDateTime]]).
+        let (date_time, _) = Self::this_date_time(this, "withPlainDate")?;
+
+        // 3. Let plainDate be ? ToTemporalDate(plainDateLike).
+        // 4. Let calendar be ? ConsolidateCalendars(temporalDateTime.[[Calendar]], plainDate.[[Calendar]]).
+        let (date, calendar) = to_temporal_date(args.get_or_undefined(0), None, context)?;
+
+        // 5. Return ? CreateTemporalDateTime(plainDate.[[ISOYear]], ..., temporalDateTime.[[ISONanosecond]], calendar).
+        let result = IsoDateTime {
+            date,
+            time: date_time.time,
+        };
+        Ok(Self::create(result, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.withCalendar ( calendarLike )`
+    fn with_calendar(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(temporalDateTime, [[InitializedTemporalDateTime]]).
+        let (date_time, _) = Self::this_date_time(this, "withCalendar")?;
+
+        // 3. Let calendar be ? ToTemporalCalendar(calendarLike).
+        let calendar = to_temporal_calendar(args.get_or_undefined(0), context)?;
+
+        // 4. Return ? CreateTemporalDateTime(temporalDateTime.[[ISOYear]], ..., calendar).
+        Ok(Self::create(date_time, calendar, None, context)?.into())
+    }
+
+    /// Abstract operation `AddDurationToOrSubtractDurationFromPlainDateTime ( operation, dateTime, temporalDurationLike, options )`
+    fn add_or_subtract(
+        this: &JsValue,
+        args: &[JsValue],
+        sign: f64,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let (date_time, calendar) = Self::this_date_time(this, method)?;
+
+        // 2. Let duration be ? ToTemporalDurationRecord(temporalDurationLike).
+        let mut duration = to_temporal_duration_record(args.get_or_undefined(0), context)?;
+        if sign < 0.0 {
+            duration = duration.negated();
+        }
+
+        // 3. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 4. Let result be ? AddDateTime(dateTime.[[ISOYear]], ..., sign × duration.[[Nanoseconds]], options).
+        let overflow = to_temporal_overflow(&options, context)?;
+        let result = date_time.add(&duration, overflow)?;
+
+        // 5-6. Return ? CreateTemporalDateTime(result.[[Year]], ..., result.[[Nanosecond]], dateTime.[[Calendar]]).
+        Ok(Self::create(result, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.add ( temporalDurationLike [ , options ] )`
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::add_or_subtract(this, args, 1.0, "add", context)
+    }
+
+    /// `Temporal.PlainDateTime.prototype.subtract ( temporalDurationLike [ , options ] )`
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::add_or_subtract(this, args, -1.0, "subtract", context)
+    }
+
+    /// Abstract operation `DifferenceTemporalPlainDateTime ( operation, dateTime, other, options )`
+    fn difference(
+        this: &JsValue,
+        args: &[JsValue],
+        operation: DifferenceOperation,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let (date_time, _) = Self::this_date_time(this, method)?;
+
+        // 2. Set other to ? ToTemporalDateTime(other).
+        let other = to_temporal_date_time(args.get_or_undefined(0), None, context)?.0;
+
+        // 3. If ? CalendarEquals(dateTime.[[Calendar]], other.[[Calendar]]) is false, throw a RangeError exception.
+        // 4. Let settings be ? GetDifferenceSettings(operation, options, datetime, « », "nanosecond", "day").
+        let settings = get_difference_settings(
+            operation,
+            args.get_or_undefined(1),
+            UnitGroup::DateTime,
+            TemporalUnit::Nanosecond,
+            TemporalUnit::Day,
+            context,
+        )?;
+
+        // 5. Let diff be ? DifferenceISODateTime(dateTime.[[ISOYear]], ..., other.[[ISONanosecond]], dateTime.[[Calendar]], settings.[[LargestUnit]], settings.[[Options]]).
+        // 6. Let relativeTo be ! CreateTemporalDate(dateTime.[[ISOYear]], dateTime.[[ISOMonth]], dateTime.[[ISODay]], dateTime.[[Calendar]]).
+        // 7. Let roundResult be (? RoundDuration(diff.[[Years]], ..., settings.[[RoundingIncrement]], settings.[[SmallestUnit]], settings.[[RoundingMode]], relativeTo)).[[DurationRecord]].
+        // 8. Let result be ? BalanceDuration(roundResult.[[Days]], ..., settings.[[LargestUnit]]).
+        let result = RelativeTo::Plain(date_time).round(
+            other.to_local_ns(),
+            settings.largest_unit,
+            settings.smallest_unit,
+            settings.increment,
+            settings.mode,
+        )?;
+
+        // 9. Return ! CreateTemporalDuration(sign × roundResult.[[Years]], ..., sign × result.[[Nanoseconds]]).
+        let result = match operation {
+            DifferenceOperation::Until => result,
+            DifferenceOperation::Since => result.negated(),
+        };
+        Ok(Duration::create(result, None, context)?.into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.until ( other [ , options ] )`
+    fn until(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::difference(this, args, DifferenceOperation::Until, "until", context)
+    }
+
+    /// `Temporal.PlainDateTime.prototype.since ( other [ , options ] )`
+    fn since(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::difference(this, args, DifferenceOperation::Since, "since", context)
+    }
+
+    /// `Temporal.PlainDateTime.prototype.round ( roundTo )`
+    fn round(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let (date_time, calendar) = Self::this_date_time(this, "round")?;
+
+        let round_to = match args.get_or_undefined(0).variant() {
+            // 3. If roundTo is undefined, then
+            JsVariant::Undefined => {
+                // a. Throw a TypeError exception.
+                return Err(JsNativeError::typ()
+                    .with_message("Temporal.PlainDateTime.prototype.round: roundTo is required")
+                    .into());
+            }
+            // 4. If Type(roundTo) is String, then
+            JsVariant::String(string) => {
+                // a-c. Set roundTo to an object with the smallestUnit property set to paramString.
+                let round_to = get_options_object(&JsValue::undefined())?;
+                round_to.create_data_property_or_throw("smallestUnit", string.clone(), context)?;
+                round_to
+            }
+            // 5. Else,
+            //     a. Set roundTo to ? GetOptionsObject(roundTo).
+            _ => get_options_object(args.get_or_undefined(0))?,
+        };
+
+        // 6. NOTE: The following steps read options in alphabetical order.
+        // 7. Let roundingIncrement be ? ToTemporalRoundingIncrement(roundTo).
+        let increment = to_temporal_rounding_increment(&round_to, None, false, context)?;
+
+        // 8. Let roundingMode be ? ToTemporalRoundingMode(roundTo, "halfExpand").
+        let mode = to_temporal_rounding_mode(&round_to, RoundingMode::HalfExpand, context)?;
+
+        // 9. Let smallestUnit be ? GetTemporalUnit(roundTo, "smallestUnit", time, required, « "day" »).
+        let smallest_unit = get_temporal_unit(&round_to, "smallestUnit", UnitGroup::DateTime, false, context)?
+            .filter(|unit| !unit.is_calendar_unit())
+            .ok_or_else(|| {
+                JsNativeError::range().with_message(
+                    "Temporal.PlainDateTime.prototype.round: smallestUnit must be a day or a time unit",
+                )
+            })?;
+
+        // 10. If smallestUnit is "day", then
+        //     a. Let maximum be 1.
+        //     b. Let inclusive be true.
+        // 11. Else,
+        //     a. Let maximum be ! MaximumTemporalDurationRoundingIncrement(smallestUnit).
+        //     b. Assert: maximum is not undefined.
+        //     c. Let inclusive be false.
+        // 12. Perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, inclusive).
+        let valid = match smallest_unit.maximum_rounding_increment() {
+            None => increment <= 1.0,
+            Some(maximum) => increment < maximum && maximum % increment == 0.0,
+        };
+        if !valid {
+            return Err(JsNativeError::range()
+                .with_message("Temporal: invalid roundingIncrement for the smallestUnit")
+                .into());
+        }
+
+        // 13. Let result be ! RoundISODateTime(dateTime.[[ISOYear]], ..., roundingIncrement, smallestUnit, roundingMode).
+        let result = date_time.round(increment, smallest_unit, mode, None)?;
+
+        // 14. Return ? CreateTemporalDateTime(result.[[Year]], ..., result.[[Nanosecond]], dateTime.[[Calendar]]).
+        Ok(Self::create(result, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.equals ( other )`
+    fn equals(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let (date_time, _) = Self::this_date_time(this, "equals")?;
+
+        // 3. Set other to ? ToTemporalDateTime(other).
+        let other = to_temporal_date_time(args.get_or_undefined(0), None, context)?.0;
+
+        // 4-6. Return whether the ISO fields and the calendars are equal.
+        Ok((date_time == other).into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.toString ( [ options ] )`
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let (date_time, _) = Self::this_date_time(this, "toString")?;
+
+        // 3. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(0))?;
+
+        // 4. NOTE: The following steps read options in alphabetical order.
+        // 5. Let showCalendar be ? ToCalendarNameOption(options).
+        let show_calendar = to_calendar_name_option(&options, context)?;
+
+        // 6. Let precision be ? ToSecondsStringPrecision(options).
+        let precision = to_seconds_string_precision(&options, context)?;
+
+        // 7. Let roundingMode be ? ToTemporalRoundingMode(options, "trunc").
+        let mode = to_temporal_rounding_mode(&options, RoundingMode::Trunc, context)?;
+
+        // 8. Let result be ! RoundISODateTime(dateTime.[[ISOYear]], ..., precision.[[Increment]], precision.[[Unit]], roundingMode).
+        let rounded = date_time.round(precision.increment, precision.unit, mode, None)?;
+
+        // 9. Return ? TemporalDateTimeToString(result.[[Year]], ..., dateTime.[[Calendar]], precision.[[Precision]], showCalendar).
+        Ok(format!(
+            "{}{}",
+            rounded.to_iso_string(precision.precision),
+            format_calendar_annotation(show_calendar)
+        )
+        .into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.toJSON ( )`
+    fn to_json(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let (date_time, _) = Self::this_date_time(this, "toJSON")?;
+
+        // 3. Return ? TemporalDateTimeToString(dateTime.[[ISOYear]], ..., dateTime.[[Calendar]], "auto", "auto").
+        Ok(date_time.to_iso_string(Precision::Auto).into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.toZonedDateTime ( temporalTimeZoneLike [ , options ] )`
+    fn to_zoned_date_time(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let (date_time, calendar) = Self::this_date_time(this, "toZonedDateTime")?;
+
+        // 3. Let timeZone be ? ToTemporalTimeZone(temporalTimeZoneLike).
+        let time_zone = to_temporal_time_zone(args.get_or_undefined(0), context)?;
+
+        // 4. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 5. Let disambiguation be ? ToTemporalDisambiguation(options).
+        let disambiguation = to_temporal_disambiguation(&options, context)?;
+
+        // 6. Let instant be ? BuiltinTimeZoneGetInstantFor(timeZone, dateTime, disambiguation).
+        let epoch_ns = TimeZone::kind_of(&time_zone).instant_for(date_time, disambiguation)?;
+
+        // 7. Return ! CreateTemporalZonedDateTime(instant.[[Nanoseconds]], timeZone, dateTime.[[Calendar]]).
+        Ok(ZonedDateTime::create(epoch_ns, time_zone, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.toPlainDate ( )`
+    fn to_plain_date(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let (date_time, calendar) = Self::this_date_time(this, "toPlainDate")?;
+
+        // 3. Return ! CreateTemporalDate(dateTime.[[ISOYear]], dateTime.[[ISOMonth]], dateTime.[[ISODay]], dateTime.[[Calendar]]).
+        Ok(PlainDate::create(date_time.date, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.toPlainTime ( )`
+    fn to_plain_time(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let (date_time, _) = Self::this_date_time(this, "toPlainTime")?;
+
+        // 3. Return ! CreateTemporalTime(dateTime.[[ISOHour]], ..., dateTime.[[ISONanosecond]]).
+        Ok(PlainTime::create(date_time.time, None, context)?.into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.getISOFields ( )`
+    fn get_iso_fields(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let (date_time, calendar) = Self::this_date_time(this, "getISOFields")?;
+
+        // 3. Let fields be OrdinaryObjectCreate(%Object.prototype%).
+        let fields = JsObject::from_proto_and_data(
+            context.intrinsics().constructors().object().prototype(),
+            ObjectData::ordinary(),
+        );
+
+        // 4-13. Perform ! CreateDataPropertyOrThrow(fields, "calendar", "isoDay", "isoHour",
+        //       "isoMicrosecond", "isoMillisecond", "isoMinute", "isoMonth", "isoNanosecond",
+        //       "isoSecond", "isoYear").
+        let IsoDateTime { date, time } = date_time;
+        fields.create_data_property_or_throw("calendar", calendar, context)?;
+        fields.create_data_property_or_throw("isoDay", date.day, context)?;
+        fields.create_data_property_or_throw("isoHour", time.hour, context)?;
+        fields.create_data_property_or_throw("isoMicrosecond", time.microsecond, context)?;
+        fields.create_data_property_or_throw("isoMillisecond", time.millisecond, context)?;
+        fields.create_data_property_or_throw("isoMinute", time.minute, context)?;
+        fields.create_data_property_or_throw("isoMonth", date.month, context)?;
+        fields.create_data_property_or_throw("isoNanosecond", time.nanosecond, context)?;
+        fields.create_data_property_or_throw("isoSecond", time.second, context)?;
+        fields.create_data_property_or_throw("isoYear", date.year, context)?;
+
+        // 14. Return fields.
+        Ok(fields.into())
+    }
+}
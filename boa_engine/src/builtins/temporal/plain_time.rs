@@ -0,0 +1,605 @@
+//! This module implements the `Temporal.PlainTime` object.
+//!
+//! A plain time is a wall-clock time, without a date or a time zone.
+//!
+//! More information:
+//!  - [Temporal reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-plaintime-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainTime
+
+use super::{
+    calendar::{get_temporal_calendar_with_iso_default, validate_calendar_annotation, Calendar},
+    duration::{to_temporal_duration_record, DurationRecord},
+    fields::{TemporalFields, TIME_FIELDS},
+    getter,
+    iso::{out_of_range, IsoDateTime, IsoTime},
+    options::{
+        get_difference_settings, get_options_object, get_temporal_unit,
+        to_seconds_string_precision, to_temporal_overflow, to_temporal_rounding_increment,
+        to_temporal_rounding_mode, DifferenceOperation, Disambiguation, Overflow, Precision,
+        RoundingMode, UnitGroup,
+    },
+    parser::parse_time_string,
+    plain_date::to_temporal_date,
+    reject_object_with_calendar_or_time_zone,
+    time_zone::{to_temporal_time_zone, TimeZone},
+    to_integer_with_truncation, value_of, Duration, PlainDateTime, TemporalUnit, ZonedDateTime,
+};
+use crate::{
+    builtins::JsArgs,
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsFunction,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsNativeError, JsResult, JsValue, JsVariant,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// Abstract operation `ToTemporalTime ( item [ , overflow ] )`
+pub(crate) fn to_temporal_time(
+    item: &JsValue,
+    overflow: Overflow,
+    context: &mut Context,
+) -> JsResult<IsoTime> {
+    // 1. If overflow is not present, set overflow to "constrain".
+    // 2. Assert: overflow is either "constrain" or "reject".
+    // 3. If Type(item) is Object, then
+    if let Some(object) = item.as_object() {
+        {
+            let borrowed = object.borrow();
+            // a. If item has an [[InitializedTemporalTime]] internal slot, then
+            //     i. Return item.
+            if let Some(time) = borrowed.as_plain_time() {
+                return Ok(time.iso);
+            }
+            // b. If item has an [[InitializedTemporalZonedDateTime]] internal slot, then
+            //     i. Let instant be ! CreateTemporalInstant(item.[[Nanoseconds]]).
+            //     ii. Set plainDateTime to ? BuiltinTimeZoneGetPlainDateTimeFor(item.[[TimeZone]], instant, item.[[Calendar]]).
+            //     iii. Return ! CreateTemporalTime(plainDateTime.[[ISOHour]], ...).
+            if let Some(zoned) = borrowed.as_zoned_date_time() {
+                return Ok(TimeZone::kind_of(&zoned.time_zone)
+                    .plain_date_time_for(zoned.nanoseconds)
+                    .time);
+            }
+            // c. If item has an [[InitializedTemporalDateTime]] internal slot, then
+            //     i. Return ! CreateTemporalTime(item.[[ISOHour]], ...).
+            if let Some(date_time) = borrowed.as_plain_date_time() {
+                return Ok(date_time.iso.time);
+            }
+        }
+
+        // d. Let calendar be ? GetTemporalCalendarWithISODefault(item).
+        // e. If ? ToString(calendar) is not "iso8601", then
+        //     i. Throw a RangeError exception.
+        get_temporal_calendar_with_iso_default(object, context)?;
+
+        // f. Let result be ? ToTemporalTimeRecord(item).
+        let fields = TemporalFields::prepare(object, TIME_FIELDS, true, context)?;
+
+        // g. Set result to ? RegulateTime(result.[[Hour]], ..., result.[[Nanosecond]], overflow).
+        return fields.to_time(overflow);
+    }
+
+    // 4. Else,
+    //     a. Let string be ? ToString(item).
+    let string = match item.variant() {
+        JsVariant::String(string) => string.to_std_string_escaped(),
+        _ => {
+            return Err(JsNativeError::typ()
+                .with_message("Temporal: expected a PlainTime, a property bag or a string")
+                .into())
+        }
+    };
+
+    //     b. Let result be ? ParseTemporalTimeString(string).
+    let (time, calendar) = parse_time_string(&string)?;
+
+    //     c. Assert: IsValidTime(result.[[Hour]], ..., result.[[Nanosecond]]) is true.
+    //     d. If result.[[Calendar]] is not one of undefined or "iso8601", then
+    //         i. Throw a RangeError exception.
+    validate_calendar_annotation(calendar.as_deref())?;
+
+    // 5. Return ! CreateTemporalTime(result.[[Hour]], ..., result.[[Nanosecond]]).
+    Ok(time)
+}
+
+/// JavaScript `Temporal.PlainTime` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct PlainTime {
+    #[unsafe_ignore_trace]
+    pub(crate) iso: IsoTime,
+    pub(crate) calendar: JsObject,
+}
+
+impl PlainTime {
+    const NAME: &'static str = "PlainTime";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let mut builder = ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().plain_time().clone(),
+        );
+        builder.name(Self::NAME).length(0);
+        getter(&mut builder, "calendar", Self::calendar);
+        getter(&mut builder, "hour", Self::hour);
+        getter(&mut builder, "minute", Self::minute);
+        getter(&mut builder, "second", Self::second);
+        getter(&mut builder, "millisecond", Self::millisecond);
+        getter(&mut builder, "microsecond", Self::microsecond);
+        getter(&mut builder, "nanosecond", Self::nanosecond);
+        builder
+            .static_method(Self::from, "from", 1)
+            .static_method(Self::compare, "compare", 2)
+            .method(Self::add, "add", 1)
+            .method(Self::subtract, "subtract", 1)
+            .method(Self::with, "with", 1)
+            .method(Self::until, "until", 1)
+            .method(Self::since, "since", 1)
+            .method(Self::round, "round", 1)
+            .method(Self::equals, "equals", 1)
+            .method(Self::to_plain_date_time, "toPlainDateTime", 1)
+            .method(Self::to_zoned_date_time, "toZonedDateTime", 1)
+            .method(Self::get_iso_fields, "getISOFields", 0)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_json, "toLocaleString", 0)
+            .method(Self::to_json, "toJSON", 0)
+            .method(value_of, "valueOf", 0)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                "Temporal.PlainTime",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build()
+    }
+
+    /// Abstract operation `CreateTemporalTime ( hour, minute, second, millisecond, microsecond, nanosecond [ , newTarget ] )`
+    pub(crate) fn create(
+        iso: IsoTime,
+        new_target: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Assert: hour, minute, second, millisecond, microsecond and nanosecond are integers.
+        // 2. If IsValidTime(hour, minute, second, millisecond, microsecond, nanosecond) is false, throw a RangeError exception.
+        // 3. If newTarget is not present, set newTarget to %Temporal.PlainTime%.
+        // 4. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.PlainTime.prototype%", « ... »).
+        let prototype = match new_target {
+            Some(new_target) => {
+                get_prototype_from_constructor(new_target, StandardConstructors::plain_time, context)?
+            }
+            None => context.intrinsics().constructors().plain_time().prototype(),
+        };
+
+        // 5-10. Set object.[[ISOHour]], ..., object.[[ISONanosecond]].
+        // 11. Set object.[[Calendar]] to ! GetISO8601Calendar().
+        let calendar = Calendar::create(None, context)?;
+
+        // 12. Return object.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::plain_time(Self { iso, calendar }),
+        ))
+    }
+
+    /// Gets the ISO time of the `this` value, throwing a `TypeError` if it's not a
+    /// `Temporal.PlainTime`.
+    fn this_time(this: &JsValue, method: &str) -> JsResult<IsoTime> {
+        this.as_object()
+            .and_then(|object| object.borrow().as_plain_time().map(|time| time.iso))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "Temporal.PlainTime.prototype.{method}: 'this' is not a Temporal.PlainTime"
+                    ))
+                    .into()
+            })
+    }
+
+    /// `Temporal.PlainTime ( [ hour [ , minute [ , second [ , millisecond [ , microsecond [ , nanosecond ] ] ] ] ] ] )`
+    fn constructor(new_target: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        if new_target.is_undefined() {
+            // a. Throw a TypeError exception.
+            return Err(JsNativeError::typ()
+                .with_message("Temporal.PlainTime: calling a builtin constructor without new is forbidden")
+                .into());
+        }
+
+        // 2-7. Let hour, ..., nanosecond be ? ToIntegerWithTruncation of the arguments.
+        let mut fields = [0.0; 6];
+        for (i, field) in fields.iter_mut().enumerate() {
+            *field = to_integer_with_truncation(args.get_or_undefined(i), context)?;
+        }
+
+        // 8. Return ? CreateTemporalTime(hour, minute, second, millisecond, microsecond, nanosecond, NewTarget).
+        let iso = IsoTime::regulate(fields, Overflow::Reject)?;
+        Ok(Self::create(iso, Some(new_target), context)?.into())
+    }
+
+    /// `Temporal.PlainTime.from ( item [ , options ] )`
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 2. Let overflow be ? ToTemporalOverflow(options).
+        let overflow = to_temporal_overflow(&options, context)?;
+
+        // 3. If Type(item) is Object and item has an [[InitializedTemporalTime]] internal slot, then
+        //     a. Return ! CreateTemporalTime(item.[[ISOHour]], ..., item.[[ISONanosecond]]).
+        // 4. Return ? ToTemporalTime(item, overflow).
+        let iso = to_temporal_time(args.get_or_undefined(0), overflow, context)?;
+        Ok(Self::create(iso, None, context)?.into())
+    }
+
+    /// `Temporal.PlainTime.compare ( one, two )`
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set one to ? ToTemporalTime(one).
+        let one = to_temporal_time(args.get_or_undefined(0), Overflow::Constrain, context)?;
+        // 2. Set two to ? ToTemporalTime(two).
+        let two = to_temporal_time(args.get_or_undefined(1), Overflow::Constrain, context)?;
+        // 3. Return 𝔽(! CompareTemporalTime(one.[[ISOHour]], ..., two.[[ISONanosecond]])).
+        Ok(match one.cmp(&two) {
+            std::cmp::Ordering::Less => -1,
+            std::cmp::Ordering::Equal => 0,
+            std::cmp::Ordering::Greater => 1,
+        }
+        .into())
+    }
+
+    /// `get Temporal.PlainTime.prototype.calendar`
+    fn calendar(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        this.as_object()
+            .and_then(|object| object.borrow().as_plain_time().map(|time| time.calendar.clone()))
+            .map(JsValue::from)
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("Temporal.PlainTime.prototype.calendar: 'this' is not a Temporal.PlainTime")
+                    .into()
+            })
+    }
+
+    /// `get Temporal.PlainTime.prototype.hour`
+    fn hour(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_time(this, "hour")?.hour.into())
+    }
+
+    /// `get Temporal.PlainTime.prototype.minute`
+    fn minute(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_time(this, "minute")?.minute.into())
+    }
+
+    /// `get Temporal.PlainTime.prototype.second`
+    fn second(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_time(this, "second")?.second.into())
+    }
+
+    /// `get Temporal.PlainTime.prototype.millisecond`
+    fn millisecond(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_time(this, "millisecond")?.millisecond.into())
+    }
+
+    /// `get Temporal.PlainTime.prototype.microsecond`
+    fn microsecond(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_time(this, "microsecond")?.microsecond.into())
+    }
+
+    /// `get Temporal.PlainTime.prototype.nanosecond`
+    fn nanosecond(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_time(this, "nanosecond")?.nanosecond.into())
+    }
+
+    /// Abstract operation `AddDurationToOrSubtractDurationFromPlainTime ( operation, temporalTime, temporalDurationLike )`
+    fn add_or_subtract(
+        this: &JsValue,
+        args: &[JsValue],
+        sign: f64,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let time = Self::this_time(this, method)?;
+
+        // 2. Let duration be ? ToTemporalDurationRecord(temporalDurationLike).
+        let mut duration = to_temporal_duration_record(args.get_or_undefined(0), context)?;
+        if sign < 0.0 {
+            duration = duration.negated();
+        }
+
+        // 3. Let result be ! AddTime(temporalTime.[[ISOHour]], ..., sign × duration.[[Nanoseconds]]).
+        let (_, result) = time.add(&duration)?;
+
+        // 4-5. Return ! CreateTemporalTime(result.[[Hour]], ..., result.[[Nanosecond]]).
+        Ok(Self::create(result, None, context)?.into())
+    }
+
+    /// `Temporal.PlainTime.prototype.add ( temporalDurationLike )`
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::add_or_subtract(this, args, 1.0, "add", context)
+    }
+
+    /// `Temporal.PlainTime.prototype.subtract ( temporalDurationLike )`
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::add_or_subtract(this, args, -1.0, "subtract", context)
+    }
+
+    /// `Temporal.PlainTime.prototype.with ( temporalTimeLike [ , options ] )`
+    fn with(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        let time = Self::this_time(this, "with")?;
+
+        // 3. If Type(temporalTimeLike) is not Object, then
+        let time_like = args.get_or_undefined(0).as_object().cloned().ok_or_else(|| {
+            // a. Throw a TypeError exception.
+            JsNativeError::typ().with_message("Temporal.PlainTime.prototype.with: expected an object")
+        })?;
+
+        // 4. Perform ? RejectObjectWithCalendarOrTimeZone(temporalTimeLike).
+        reject_object_with_calendar_or_time_zone(&time_like, context)?;
+
+        // 5. Let partialTime be ? ToTemporalTimeRecord(temporalTimeLike, partial).
+        let partial = TemporalFields::prepare(&time_like, TIME_FIELDS, true, context)?;
+
+        // 6. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 7. Let overflow be ? ToTemporalOverflow(options).
+        let overflow = to_temporal_overflow(&options, context)?;
+
+        // 8-19. Use the fields of partialTime, or of temporalTime when they're undefined.
+        let fields = TemporalFields::from_time(time).merge(&partial);
+
+        // 20. Let result be ? RegulateTime(hour, minute, second, millisecond, microsecond, nanosecond, overflow).
+        let result = fields.to_time(overflow)?;
+
+        // 21. Return ! CreateTemporalTime(result.[[Hour]], ..., result.[[Nanosecond]]).
+        Ok(Self::create(result, None, context)?.into())
+    }
+
+    /// Abstract operation `DifferenceTemporalPlainTime ( operation, temporalTime, other, options )`
+    fn difference(
+        this: &JsValue,
+        args: &[JsValue],
+        operation: DifferenceOperation,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let time = Self::this_time(this, method)?;
+
+        // 2. Set other to ? ToTemporalTime(other).
+        let other = to_temporal_time(args.get_or_undefined(0), Overflow::Constrain, context)?;
+
+        // 3. Let settings be ? GetDifferenceSettings(operation, options, time, « », "nanosecond", "hour").
+        let settings = get_difference_settings(
+            operation,
+            args.get_or_undefined(1),
+            UnitGroup::Time,
+            TemporalUnit::Nanosecond,
+            TemporalUnit::Hour,
+            context,
+        )?;
+
+        // 4. Let result be ! DifferenceTime(temporalTime.[[ISOHour]], ..., other.[[ISONanosecond]]).
+        // 5. Set result to (! RoundDuration(0, 0, 0, 0, result.[[Hours]], ..., settings.[[RoundingIncrement]], settings.[[SmallestUnit]], settings.[[RoundingMode]])).[[DurationRecord]].
+        let unit_ns = settings.smallest_unit.nanoseconds().unwrap_or(1);
+        let rounded = settings
+            .mode
+            .round(other.to_ns() - time.to_ns(), settings.increment as i128 * unit_ns);
+
+        // 6. Set result to ! BalanceDuration(0, result.[[Hours]], ..., settings.[[LargestUnit]]).
+        let result = DurationRecord::balance_time(rounded, settings.largest_unit);
+
+        // 7. Return ! CreateTemporalDuration(0, 0, 0, 0, sign × result.[[Hours]], ..., sign × result.[[Nanoseconds]]).
+        let result = match operation {
+            DifferenceOperation::Until => result,
+            DifferenceOperation::Since => result.negated(),
+        };
+        Ok(Duration::create(result, None, context)?.into())
+    }
+
+    /// `Temporal.PlainTime.prototype.until ( other [ , options ] )`
+    fn until(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::difference(this, args, DifferenceOperation::Until, "until", context)
+    }
+
+    /// `Temporal.PlainTime.prototype.since ( other [ , options ] )`
+    fn since(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::difference(this, args, DifferenceOperation::Since, "since", context)
+    }
+
+    /// `Temporal.PlainTime.prototype.round ( roundTo )`
+    fn round(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        let time = Self::this_time(this, "round")?;
+
+        let round_to = match args.get_or_undefined(0).variant() {
+            // 3. If roundTo is undefined, then
+            JsVariant::Undefined => {
+                // a. Throw a TypeError exception.
+                return Err(JsNativeError::typ()
+                    .with_message("Temporal.PlainTime.prototype.round: roundTo is required")
+                    .into());
+            }
+            // 4. If Type(roundTo) is String, then
+            JsVariant::String(string) => {
+                // a-c. Set roundTo to an object with the smallestUnit property set to paramString.
+                let round_to = get_options_object(&JsValue::undefined())?;
+                round_to.create_data_property_or_throw("smallestUnit", string.clone(), context)?;
+                round_to
+            }
+            // 5. Else,
+            //     a. Set roundTo to ? GetOptionsObject(roundTo).
+            _ => get_options_object(args.get_or_undefined(0))?,
+        };
+
+        // 6. NOTE: The following steps read options in alphabetical order.
+        // 7. Let roundingIncrement be ? ToTemporalRoundingIncrement(roundTo).
+        let increment = to_temporal_rounding_increment(&round_to, None, false, context)?;
+
+        // 8. Let roundingMode be ? ToTemporalRoundingMode(roundTo, "halfExpand").
+        let mode = to_temporal_rounding_mode(&round_to, RoundingMode::HalfExpand, context)?;
+
+        // 9. Let smallestUnit be ? GetTemporalUnit(roundTo, "smallestUnit", time, required).
+        let smallest_unit = get_temporal_unit(&round_to, "smallestUnit", UnitGroup::Time, false, context)?
+            .ok_or_else(|| {
+                JsNativeError::range()
+                    .with_message("Temporal.PlainTime.prototype.round: smallestUnit is required")
+            })?;
+
+        // 10. Let maximum be ! MaximumTemporalDurationRoundingIncrement(smallestUnit).
+        // 11. Perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, false).
+        let maximum = smallest_unit.maximum_rounding_increment().unwrap_or(1.0);
+        if increment >= maximum || maximum % increment != 0.0 {
+            return Err(JsNativeError::range()
+                .with_message(format!("Temporal: roundingIncrement must divide {maximum}"))
+                .into());
+        }
+
+        // 12. Let result be ! RoundTime(temporalTime.[[ISOHour]], ..., roundingIncrement, smallestUnit, roundingMode).
+        let (_, result) = time.round(increment, smallest_unit, mode, None);
+
+        // 13. Return ! CreateTemporalTime(result.[[Hour]], ..., result.[[Nanosecond]]).
+        Ok(Self::create(result, None, context)?.into())
+    }
+
+    /// `Temporal.PlainTime.prototype.equals ( other )`
+    fn equals(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        let time = Self::this_time(this, "equals")?;
+
+        // 3. Set other to ? ToTemporalTime(other).
+        let other = to_temporal_time(args.get_or_undefined(0), Overflow::Constrain, context)?;
+
+        // 4-10. Return whether all the ISO fields are equal.
+        Ok((time == other).into())
+    }
+
+    /// `Temporal.PlainTime.prototype.toPlainDateTime ( temporalDate )`
+    fn to_plain_date_time(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        let time = Self::this_time(this, "toPlainDateTime")?;
+
+        // 3. Set temporalDate to ? ToTemporalDate(temporalDate).
+        let (date, calendar) = to_temporal_date(args.get_or_undefined(0), None, context)?;
+
+        // 4. Return ? CreateTemporalDateTime(temporalDate.[[ISOYear]], ..., temporalTime.[[ISONanosecond]], temporalDate.[[Calendar]]).
+        Ok(PlainDateTime::create(IsoDateTime { date, time }, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.PlainTime.prototype.toZonedDateTime ( item )`
+    fn to_zoned_date_time(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        let time = Self::this_time(this, "toZonedDateTime")?;
+
+        // 3. If Type(item) is not Object, then
+        let item = args.get_or_undefined(0).as_object().cloned().ok_or_else(|| {
+            // a. Throw a TypeError exception.
+            JsNativeError::typ()
+                .with_message("Temporal.PlainTime.prototype.toZonedDateTime: expected an object")
+        })?;
+
+        // 4. Let temporalDateLike be ? Get(item, "plainDate").
+        let date_like = item.get("plainDate", context)?;
+
+        // 5. If temporalDateLike is undefined, then
+        if date_like.is_undefined() {
+            // a. Throw a TypeError exception.
+            return Err(JsNativeError::typ()
+                .with_message("Temporal.PlainTime.prototype.toZonedDateTime: plainDate is required")
+                .into());
+        }
+
+        // 6. Let temporalDate be ? ToTemporalDate(temporalDateLike).
+        let (date, calendar) = to_temporal_date(&date_like, None, context)?;
+
+        // 7. Let temporalTimeZoneLike be ? Get(item, "timeZone").
+        let time_zone_like = item.get("timeZone", context)?;
+
+        // 8. If temporalTimeZoneLike is undefined, then
+        if time_zone_like.is_undefined() {
+            // a. Throw a TypeError exception.
+            return Err(JsNativeError::typ()
+                .with_message("Temporal.PlainTime.prototype.toZonedDateTime: timeZone is required")
+                .into());
+        }
+
+        // 9. Let timeZone be ? ToTemporalTimeZone(temporalTimeZoneLike).
+        let time_zone = to_temporal_time_zone(&time_zone_like, context)?;
+
+        // 10. Let temporalDateTime be ? CreateTemporalDateTime(temporalDate.[[ISOYear]], ..., temporalTime.[[ISONanosecond]], temporalDate.[[Calendar]]).
+        let date_time = IsoDateTime { date, time };
+        if !date_time.is_within_limits() {
+            return out_of_range();
+        }
+
+        // 11. Let instant be ? BuiltinTimeZoneGetInstantFor(timeZone, temporalDateTime, "compatible").
+        let epoch_ns =
+            TimeZone::kind_of(&time_zone).instant_for(date_time, Disambiguation::Compatible)?;
+
+        // 12. Return ! CreateTemporalZonedDateTime(instant.[[Nanoseconds]], timeZone, temporalDate.[[Calendar]]).
+        Ok(ZonedDateTime::create(epoch_ns, time_zone, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.PlainTime.prototype.getISOFields ( )`
+    fn get_iso_fields(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        let time = Self::this_time(this, "getISOFields")?;
+
+        // 3. Let fields be OrdinaryObjectCreate(%Object.prototype%).
+        let fields = JsObject::from_proto_and_data(
+            context.intrinsics().constructors().object().prototype(),
+            ObjectData::ordinary(),
+        );
+
+        // 4-10. Perform ! CreateDataPropertyOrThrow(fields, "calendar", "isoHour", "isoMicrosecond",
+        //       "isoMillisecond", "isoMinute", "isoNanosecond", "isoSecond").
+        let calendar = Calendar::create(None, context)?;
+        fields.create_data_property_or_throw("calendar", calendar, context)?;
+        fields.create_data_property_or_throw("isoHour", time.hour, context)?;
+        fields.create_data_property_or_throw("isoMicrosecond", time.microsecond, context)?;
+        fields.create_data_property_or_throw("isoMillisecond", time.millisecond, context)?;
+        fields.create_data_property_or_throw("isoMinute", time.minute, context)?;
+        fields.create_data_property_or_throw("isoNanosecond", time.nanosecond, context)?;
+        fields.create_data_property_or_throw("isoSecond", time.second, context)?;
+
+        // 11. Return fields.
+        Ok(fields.into())
+    }
+
+    /// `Temporal.PlainTime.prototype.toString ( [ options ] )`
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        let time = Self::this_time(this, "toString")?;
+
+        // 3. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(0))?;
+
+        // 4. NOTE: The following steps read options in alphabetical order.
+        // 5. Let precision be ? ToSecondsStringPrecision(options).
+        let precision = to_seconds_string_precision(&options, context)?;
+
+        // 6. Let roundingMode be ? ToTemporalRoundingMode(options, "trunc").
+        let mode = to_temporal_rounding_mode(&options, RoundingMode::Trunc, context)?;
+
+        // 7. Let roundResult be ! RoundTime(temporalTime.[[ISOHour]], ..., precision.[[Increment]], precision.[[Unit]], roundingMode).
+        let (_, rounded) = time.round(precision.increment, precision.unit, mode, None);
+
+        // 8. Return ! TemporalTimeToString(roundResult.[[Hour]], ..., precision.[[Precision]]).
+        Ok(rounded.to_iso_string(precision.precision).into())
+    }
+
+    /// `Temporal.PlainTime.prototype.toJSON ( )`
+    fn to_json(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        let time = Self::this_time(this, "toJSON")?;
+
+        // 3. Return ! TemporalTimeToString(temporalTime.[[ISOHour]], ..., "auto").
+        Ok(time.to_iso_string(Precision::Auto).into())
+    }
+}
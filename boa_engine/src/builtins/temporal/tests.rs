@@ -0,0 +1,143 @@
+use crate::{forward, Context};
+
+#[test]
+fn to_string_tag() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(Temporal)"),
+        "\"[object Temporal]\""
+    );
+    assert_eq!(
+        forward(&mut context, "Temporal.Now[Symbol.toStringTag]"),
+        "\"Temporal.Now\""
+    );
+}
+
+#[test]
+fn instant() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.Instant.from('2020-01-01T00:00:00.5+01:00').toString()"
+        ),
+        "\"2019-12-31T23:00:00.5Z\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Temporal.Instant(1_000_000_000n).add({ hours: 1 }).epochSeconds"
+        ),
+        "3601"
+    );
+}
+
+#[test]
+fn plain_date() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.PlainDate.from('2020-01-31').add({ months: 1 }).toString()"
+        ),
+        "\"2020-02-29\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.PlainDate.from('2020-01-01').until('2021-03-05', { largestUnit: 'months' }).toString()"
+        ),
+        "\"P14M4D\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.PlainDate.from({ year: 2021, month: 13, day: 1 }, { overflow: 'reject' })"
+        ),
+        "Uncaught \"RangeError\": \"Temporal: invalid ISO date\""
+    );
+}
+
+#[test]
+fn plain_time() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.PlainTime.from('23:30').add({ hours: 1 }).toString()"
+        ),
+        "\"00:30:00\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.PlainTime.from('12:34:56.789').round('second').toString()"
+        ),
+        "\"12:34:57\""
+    );
+}
+
+#[test]
+fn plain_date_time() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.PlainDateTime.from('2020-02-28T12:00').add({ days: 1, hours: 13 }).toString()"
+        ),
+        "\"2020-03-01T01:00:00\""
+    );
+}
+
+#[test]
+fn zoned_date_time() {
+    let mut context = Context::default();
+
+    // Daylight saving time starts at 02:00 on 2021-03-14 in New York.
+    forward(
+        &mut context,
+        "var zdt = Temporal.ZonedDateTime.from('2021-03-14T01:30[America/New_York]');",
+    );
+    assert_eq!(forward(&mut context, "zdt.offset"), "\"-05:00\"");
+    assert_eq!(forward(&mut context, "zdt.hoursInDay"), "23");
+    assert_eq!(
+        forward(&mut context, "zdt.add({ hours: 1 }).toString()"),
+        "\"2021-03-14T03:30:00-04:00[America/New_York]\""
+    );
+    assert_eq!(
+        forward(&mut context, "zdt.add({ days: 1 }).toString()"),
+        "\"2021-03-15T01:30:00-04:00[America/New_York]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.ZonedDateTime.from('2021-03-14T02:30[America/New_York]').toString()"
+        ),
+        "\"2021-03-14T03:30:00-04:00[America/New_York]\""
+    );
+}
+
+#[test]
+fn duration() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.Duration.from({ minutes: 90 }).round({ largestUnit: 'hours' }).toString()"
+        ),
+        "\"PT1H30M\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.Duration.from('P1M').total({ unit: 'days', relativeTo: '2020-02-01' })"
+        ),
+        "29"
+    );
+}
@@ -0,0 +1,725 @@
+//! This module implements the `Temporal.TimeZone` object.
+//!
+//! A time zone is either a fixed UTC offset, or one of the named time zones of the IANA time zone
+//! database, which is embedded in the engine.
+//!
+//! More information:
+//!  - [Temporal reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-timezone-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/TimeZone
+
+use super::{
+    calendar::to_temporal_calendar_with_iso_default,
+    getter,
+    instant::to_temporal_instant,
+    iso::{is_valid_epoch_ns, out_of_range, IsoDateTime, IsoTime, NS_PER_DAY},
+    options::{get_options_object, to_temporal_disambiguation, Disambiguation, RoundingMode},
+    parser::{parse_date_time, parse_offset_string, ParsedOffset},
+    plain_date_time::to_temporal_date_time,
+    Instant, PlainDateTime,
+};
+use crate::{
+    builtins::{
+        time_zone::{host_time_zone, named_time_zone},
+        Array, JsArgs,
+    },
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsFunction,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsNativeError, JsResult, JsValue,
+};
+use boa_gc::{unsafe_empty_trace, Finalize, Trace};
+use boa_profiler::Profiler;
+use chrono::{NaiveDate, Offset, TimeZone as _};
+use chrono_tz::Tz;
+
+/// The first and last seconds since the epoch with an offset transition in the embedded time
+/// zone database. The offsets of all time zones are constant outside of this range.
+const TRANSITIONS_RANGE: (i64, i64) = (-4_000_000_000, 4_200_000_000);
+
+/// The kind of a time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TimeZoneKind {
+    /// A fixed UTC offset, in nanoseconds.
+    Offset(i64),
+    /// A time zone of the IANA time zone database.
+    Named(Tz),
+}
+
+impl TimeZoneKind {
+    /// The `UTC` time zone.
+    pub(crate) const UTC: Self = Self::Named(Tz::UTC);
+
+    /// Creates the time zone with the given identifier, which is either a numeric UTC offset or
+    /// the case-insensitive name of an IANA time zone.
+    ///
+    /// This is `IsValidTimeZoneName` and `CanonicalizeTimeZoneName`.
+    pub(crate) fn from_identifier(identifier: &str) -> Option<Self> {
+        if let Some(offset) = parse_offset_string(identifier) {
+            return Some(Self::Offset(offset));
+        }
+        named_time_zone(identifier).map(Self::Named)
+    }
+
+    /// Gets the time zone of the host.
+    ///
+    /// This is `DefaultTimeZone`, which uses the `TZ` environment variable or the `/etc/localtime`
+    /// link if they name an IANA time zone, and the current UTC offset of the host otherwise.
+    pub(crate) fn system() -> Self {
+        if let Some(tz) = host_time_zone() {
+            return Self::Named(tz);
+        }
+
+        let offset = chrono::Local::now().offset().local_minus_utc();
+        Self::Offset(i64::from(offset) * 1_000_000_000)
+    }
+
+    /// Gets the identifier of the time zone.
+    pub(crate) fn identifier(self) -> String {
+        match self {
+            Self::Offset(ns) => format_time_zone_offset_string(ns),
+            Self::Named(Tz::UTC) => "UTC".to_string(),
+            Self::Named(tz) => tz.name().to_string(),
+        }
+    }
+
+    /// Abstract operation `GetOffsetNanosecondsFor ( timeZone, instant )`
+    pub(crate) fn offset_ns(self, epoch_ns: i128) -> i128 {
+        match self {
+            Self::Offset(ns) => i128::from(ns),
+            Self::Named(tz) => {
+                let seconds = epoch_ns.div_euclid(1_000_000_000);
+                let seconds = seconds.clamp(
+                    i128::from(TRANSITIONS_RANGE.0),
+                    i128::from(TRANSITIONS_RANGE.1),
+                );
+                let utc = IsoDateTime::from_local_ns(seconds * 1_000_000_000);
+                let offset = NaiveDate::from_ymd_opt(
+                    utc.date.year,
+                    u32::from(utc.date.month),
+                    u32::from(utc.date.day),
+                )
+                .and_then(|date| {
+                    date.and_hms_opt(
+                        u32::from(utc.time.hour),
+                        u32::from(utc.time.minute),
+                        u32::from(utc.time.second),
+                    )
+                })
+                .map_or(0, |utc| {
+                    tz.offset_from_utc_datetime(&utc).fix().local_minus_utc()
+                });
+                i128::from(offset) * 1_000_000_000
+            }
+        }
+    }
+
+    /// Abstract operation `GetPlainDateTimeFor ( timeZone, instant, calendar )`
+    pub(crate) fn plain_date_time_for(self, epoch_ns: i128) -> IsoDateTime {
+        IsoDateTime::from_local_ns(epoch_ns + self.offset_ns(epoch_ns))
+    }
+
+    /// Abstract operation `GetPossibleInstantsFor ( timeZone, dateTime )`
+    ///
+    /// Returns the epoch nanoseconds of the instants with the given local date and time, in
+    /// ascending order.
+    pub(crate) fn possible_instants(self, date_time: IsoDateTime) -> JsResult<Vec<i128>> {
+        let local_ns = date_time.to_local_ns();
+        let candidates = match self {
+            Self::Offset(ns) => vec![local_ns - i128::from(ns)],
+            Self::Named(_) => {
+                // The offsets around the local time are the only candidates.
+                let before = self.offset_ns(local_ns - NS_PER_DAY);
+                let after = self.offset_ns(local_ns + NS_PER_DAY);
+                let mut candidates: Vec<_> = [local_ns - before, local_ns - after]
+                    .into_iter()
+                    .filter(|candidate| candidate + self.offset_ns(*candidate) == local_ns)
+                    .collect();
+                candidates.sort_unstable();
+                candidates.dedup();
+                candidates
+            }
+        };
+
+        if candidates.iter().any(|ns| !is_valid_epoch_ns(*ns)) {
+            return out_of_range();
+        }
+        Ok(candidates)
+    }
+
+    /// Abstract operation `BuiltinTimeZoneGetInstantFor ( timeZone, dateTime, disambiguation )`
+    ///
+    /// Returns the epoch nanoseconds of the instant with the given local date and time.
+    pub(crate) fn instant_for(
+        self,
+        date_time: IsoDateTime,
+        disambiguation: Disambiguation,
+    ) -> JsResult<i128> {
+        // 1. Let possibleInstants be ? GetPossibleInstantsFor(timeZone, dateTime).
+        let possible = self.possible_instants(date_time)?;
+
+        // 2. Return ? DisambiguatePossibleInstants(possibleInstants, timeZone, dateTime, disambiguation).
+        self.disambiguate(&possible, date_time, disambiguation)
+    }
+
+    /// Abstract operation `DisambiguatePossibleInstants ( possibleInstants, timeZone, dateTime, disambiguation )`
+    pub(crate) fn disambiguate(
+        self,
+        possible: &[i128],
+        date_time: IsoDateTime,
+        disambiguation: Disambiguation,
+    ) -> JsResult<i128> {
+        // 2. Let n be possibleInstants's length.
+        // 3. If n = 1, then
+        if let [instant] = possible {
+            // a. Return possibleInstants[0].
+            return Ok(*instant);
+        }
+
+        // 4. If n ≠ 0, then
+        if let (Some(first), Some(last)) = (possible.first(), possible.last()) {
+            return match disambiguation {
+                // a. If disambiguation is "earlier" or "compatible", then
+                //     i. Return possibleInstants[0].
+                Disambiguation::Earlier | Disambiguation::Compatible => Ok(*first),
+                // b. If disambiguation is "later", then
+                //     i. Return possibleInstants[n - 1].
+                Disambiguation::Later => Ok(*last),
+                // c. Assert: disambiguation is "reject".
+                // d. Throw a RangeError exception.
+                Disambiguation::Reject => Err(JsNativeError::range()
+                    .with_message("Temporal: the local time is ambiguous in the time zone")
+                    .into()),
+            };
+        }
+
+        // 5. Assert: n = 0.
+        // 6. If disambiguation is "reject", then
+        if disambiguation == Disambiguation::Reject {
+            // a. Throw a RangeError exception.
+            return Err(JsNativeError::range()
+                .with_message("Temporal: the local time doesn't exist in the time zone")
+                .into());
+        }
+
+        // 7. Let epochNanoseconds be GetUTCEpochNanoseconds(dateTime.[[ISOYear]], ...).
+        let epoch_ns = date_time.to_local_ns();
+
+        // 8-11. Let dayBefore be epochNanoseconds - nsPerDay, and dayAfter be epochNanoseconds + nsPerDay.
+        // 12. Let offsetBefore be ? GetOffsetNanosecondsFor(timeZone, dayBefore).
+        let offset_before = self.offset_ns(epoch_ns - NS_PER_DAY);
+
+        // 13. Let offsetAfter be ? GetOffsetNanosecondsFor(timeZone, dayAfter).
+        let offset_after = self.offset_ns(epoch_ns + NS_PER_DAY);
+
+        // 14. Let nanoseconds be offsetAfter - offsetBefore.
+        let nanoseconds = offset_after - offset_before;
+
+        // 15. If disambiguation is "earlier", then
+        if disambiguation == Disambiguation::Earlier {
+            // a. Let earlier be ? AddDateTime(dateTime.[[ISOYear]], ..., -nanoseconds, undefined).
+            let earlier = IsoDateTime::from_local_ns(epoch_ns - nanoseconds);
+            // c. Let possibleInstants be ? GetPossibleInstantsFor(timeZone, earlierDateTime).
+            // d. If possibleInstants is empty, throw a RangeError exception.
+            // e. Return possibleInstants[0].
+            return match self.possible_instants(earlier)?.first() {
+                Some(instant) => Ok(*instant),
+                None => out_of_range(),
+            };
+        }
+
+        // 16. Assert: disambiguation is "compatible" or "later".
+        // 17. Let later be ? AddDateTime(dateTime.[[ISOYear]], ..., nanoseconds, undefined).
+        let later = IsoDateTime::from_local_ns(epoch_ns + nanoseconds);
+
+        // 19. Let possibleInstants be ? GetPossibleInstantsFor(timeZone, laterDateTime).
+        // 20. Set n to possibleInstants's length.
+        // 21. If n = 0, throw a RangeError exception.
+        // 22. Return possibleInstants[n - 1].
+        match self.possible_instants(later)?.last() {
+            Some(instant) => Ok(*instant),
+            None => out_of_range(),
+        }
+    }
+
+    /// Abstract operation `GetNamedTimeZoneNextTransition ( timeZoneIdentifier, epochNanoseconds )`
+    ///
+    /// Returns the epoch nanoseconds of the first offset transition after `epoch_ns`, if any.
+    pub(crate) fn next_transition(self, epoch_ns: i128) -> Option<i128> {
+        if matches!(self, Self::Offset(_)) {
+            return None;
+        }
+
+        let end = i128::from(TRANSITIONS_RANGE.1) * 1_000_000_000;
+        let mut start = epoch_ns.max(i128::from(TRANSITIONS_RANGE.0 - 1) * 1_000_000_000);
+        while start < end {
+            let next = (start + NS_PER_DAY).min(end);
+            if self.offset_ns(next) != self.offset_ns(start) {
+                return Some(self.bisect_transition(start, next));
+            }
+            start = next;
+        }
+        None
+    }
+
+    /// Abstract operation `GetNamedTimeZonePreviousTransition ( timeZoneIdentifier, epochNanoseconds )`
+    ///
+    /// Returns the epoch nanoseconds of the last offset transition before `epoch_ns`, if any.
+    pub(crate) fn previous_transition(self, epoch_ns: i128) -> Option<i128> {
+        if matches!(self, Self::Offset(_)) {
+            return None;
+        }
+
+        let start = i128::from(TRANSITIONS_RANGE.0 - 1) * 1_000_000_000;
+        let mut end = (epoch_ns - 1).min(i128::from(TRANSITIONS_RANGE.1 + 1) * 1_000_000_000);
+        while end > start {
+            let previous = (end - NS_PER_DAY).max(start);
+            if self.offset_ns(previous) != self.offset_ns(end) {
+                return Some(self.bisect_transition(previous, end));
+            }
+            end = previous;
+        }
+        None
+    }
+
+    /// Finds the instant of the transition between `start` and `end`, which have different
+    /// offsets. Transitions always happen at a whole second.
+    fn bisect_transition(self, start: i128, end: i128) -> i128 {
+        let offset = self.offset_ns(start);
+        let mut low = start.div_euclid(1_000_000_000);
+        let mut high = (end + 999_999_999).div_euclid(1_000_000_000);
+        while high - low > 1 {
+            let mid = low + (high - low) / 2;
+            if self.offset_ns(mid * 1_000_000_000) == offset {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        high * 1_000_000_000
+    }
+}
+
+/// Abstract operation `FormatTimeZoneOffsetString ( offsetNanoseconds )`
+pub(crate) fn format_time_zone_offset_string(offset_ns: i64) -> String {
+    // 1. Assert: offsetNanoseconds is an integer.
+    // 2. If offsetNanoseconds ≥ 0, let sign be "+"; otherwise, let sign be "-".
+    let sign = if offset_ns >= 0 { '+' } else { '-' };
+
+    // 3. Let offsetNanoseconds be abs(offsetNanoseconds).
+    let offset_ns = offset_ns.unsigned_abs();
+
+    // 4-7. Let nanoseconds, seconds, minutes and hours be the parts of the offset.
+    let nanoseconds = offset_ns % 1_000_000_000;
+    let seconds = offset_ns / 1_000_000_000 % 60;
+    let minutes = offset_ns / 60_000_000_000 % 60;
+    let hours = offset_ns / 3_600_000_000_000;
+
+    // 8-11. Let post be the seconds part, with the fraction if needed.
+    let post = if nanoseconds != 0 {
+        let fraction = format!("{nanoseconds:09}");
+        format!(":{seconds:02}.{}", fraction.trim_end_matches('0'))
+    } else if seconds != 0 {
+        format!(":{seconds:02}")
+    } else {
+        String::new()
+    };
+
+    // 12. Return the string-concatenation of sign, h, the code unit 0x003A (COLON), m, and post.
+    format!("{sign}{hours:02}:{minutes:02}{post}")
+}
+
+/// Abstract operation `FormatISOTimeZoneOffsetString ( offsetNanoseconds )`
+pub(crate) fn format_iso_time_zone_offset_string(offset_ns: i128) -> String {
+    // 1. Assert: offsetNanoseconds is an integer.
+    // 2. Set offsetNanoseconds to RoundNumberToIncrement(offsetNanoseconds, 60 × 10^9, "halfExpand").
+    let offset_ns = RoundingMode::HalfExpand.round(offset_ns, 60_000_000_000);
+
+    // 3-7. Return the offset as `±HH:MM`.
+    let sign = if offset_ns >= 0 { '+' } else { '-' };
+    let minutes = offset_ns.abs() / 60_000_000_000;
+    format!("{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+}
+
+/// Abstract operation `ParseTemporalTimeZoneString ( timeZoneString )`
+fn parse_temporal_time_zone_string(string: &str) -> JsResult<TimeZoneKind> {
+    // 1. If ParseText(timeZoneString, TimeZoneIdentifier) is a Parse Node, then
+    //     a. Return the Record { [[Z]]: false, [[OffsetString]]: undefined, [[Name]]: timeZoneString }.
+    if let Some(time_zone) = TimeZoneKind::from_identifier(string) {
+        return Ok(time_zone);
+    }
+
+    // 2-3. Let parseResult be ParseText(timeZoneString, TemporalTimeZoneString).
+    let parsed = parse_date_time(string)?;
+    match (parsed.time_zone, parsed.offset) {
+        (Some(name), _) => TimeZoneKind::from_identifier(&name).ok_or_else(|| {
+            JsNativeError::range()
+                .with_message(format!("Temporal: unknown time zone `{name}`"))
+                .into()
+        }),
+        (None, Some(ParsedOffset::Utc)) => Ok(TimeZoneKind::UTC),
+        (None, Some(ParsedOffset::Numeric { ns, .. })) => Ok(TimeZoneKind::Offset(ns)),
+        (None, None) => Err(JsNativeError::range()
+            .with_message(format!("Temporal: `{string}` has no time zone"))
+            .into()),
+    }
+}
+
+/// Abstract operation `ToTemporalTimeZone ( temporalTimeZoneLike )`
+///
+/// Returns a `Temporal.TimeZone` object. Custom time zone objects are not supported.
+pub(crate) fn to_temporal_time_zone(value: &JsValue, context: &mut Context) -> JsResult<JsObject> {
+    // 1. If Type(temporalTimeZoneLike) is Object, then
+    let value = if let Some(object) = value.as_object() {
+        {
+            let borrowed = object.borrow();
+            if borrowed.is_time_zone() {
+                return Ok(object.clone());
+            }
+            // a. If temporalTimeZoneLike has an [[InitializedTemporalZonedDateTime]] internal slot, then
+            //     i. Return temporalTimeZoneLike.[[TimeZone]].
+            if let Some(zoned) = borrowed.as_zoned_date_time() {
+                return Ok(zoned.time_zone.clone());
+            }
+        }
+
+        // b. If ? HasProperty(temporalTimeZoneLike, "timeZone") is false, return temporalTimeZoneLike.
+        // c. Set temporalTimeZoneLike to ? Get(temporalTimeZoneLike, "timeZone").
+        // d. If Type(temporalTimeZoneLike) is Object and ? HasProperty(temporalTimeZoneLike, "timeZone")
+        //    is false, return temporalTimeZoneLike.
+        if !object.has_property("timeZone", context)? {
+            return Err(unsupported_time_zone().into());
+        }
+        let value = object.get("timeZone", context)?;
+        if let Some(object) = value.as_object() {
+            if object.borrow().is_time_zone() {
+                return Ok(object.clone());
+            }
+            return Err(unsupported_time_zone().into());
+        }
+        value
+    } else {
+        value.clone()
+    };
+
+    // 2. Let identifier be ? ToString(temporalTimeZoneLike).
+    let identifier = value.to_string(context)?.to_std_string_escaped();
+
+    // 3-6. Return ! CreateTemporalTimeZone of the parsed identifier.
+    let kind = parse_temporal_time_zone_string(&identifier)?;
+    TimeZone::create(kind, None, context)
+}
+
+/// The `TypeError` thrown for custom time zone objects.
+fn unsupported_time_zone() -> JsNativeError {
+    JsNativeError::typ().with_message("Temporal: custom time zones are not supported")
+}
+
+/// JavaScript `Temporal.TimeZone` object.
+#[derive(Debug, Clone, Copy, Finalize)]
+pub struct TimeZone {
+    pub(crate) kind: TimeZoneKind,
+}
+
+// SAFETY: A time zone holds no garbage collected values.
+unsafe impl Trace for TimeZone {
+    unsafe_empty_trace!();
+}
+
+impl TimeZone {
+    const NAME: &'static str = "TimeZone";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let mut builder = ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().time_zone().clone(),
+        );
+        builder.name(Self::NAME).length(1);
+        getter(&mut builder, "id", Self::id);
+        builder
+            .static_method(Self::from, "from", 1)
+            .method(
+                Self::get_offset_nanoseconds_for,
+                "getOffsetNanosecondsFor",
+                1,
+            )
+            .method(Self::get_offset_string_for, "getOffsetStringFor", 1)
+            .method(Self::get_plain_date_time_for, "getPlainDateTimeFor", 1)
+            .method(Self::get_instant_for, "getInstantFor", 1)
+            .method(Self::get_possible_instants_for, "getPossibleInstantsFor", 1)
+            .method(Self::get_next_transition, "getNextTransition", 1)
+            .method(Self::get_previous_transition, "getPreviousTransition", 1)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_string, "toJSON", 0)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                "Temporal.TimeZone",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build()
+    }
+
+    /// Abstract operation `CreateTemporalTimeZone ( identifier [ , newTarget ] )`
+    pub(crate) fn create(
+        kind: TimeZoneKind,
+        new_target: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. If newTarget is not present, set newTarget to %Temporal.TimeZone%.
+        // 2. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.TimeZone.prototype%", « [[InitializedTemporalTimeZone]], [[Identifier]], [[OffsetNanoseconds]] »).
+        let prototype = match new_target {
+            Some(new_target) => {
+                get_prototype_from_constructor(new_target, StandardConstructors::time_zone, context)?
+            }
+            None => context.intrinsics().constructors().time_zone().prototype(),
+        };
+
+        // 3-4. Set object.[[Identifier]] and object.[[OffsetNanoseconds]].
+        // 5. Return object.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::time_zone(Self { kind }),
+        ))
+    }
+
+    /// Gets the kind of the `Temporal.TimeZone` object `object`.
+    pub(crate) fn kind_of(object: &JsObject) -> TimeZoneKind {
+        object
+            .borrow()
+            .as_time_zone()
+            .map_or(TimeZoneKind::UTC, |time_zone| time_zone.kind)
+    }
+
+    /// Gets the time zone kind of the `this` value, throwing a `TypeError` if it's not a
+    /// `Temporal.TimeZone`.
+    fn this_time_zone(this: &JsValue, method: &str) -> JsResult<TimeZoneKind> {
+        this.as_object()
+            .and_then(|object| object.borrow().as_time_zone().map(|time_zone| time_zone.kind))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "Temporal.TimeZone.prototype.{method}: 'this' is not a Temporal.TimeZone"
+                    ))
+                    .into()
+            })
+    }
+
+    /// `Temporal.TimeZone ( identifier )`
+    fn constructor(new_target: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        if new_target.is_undefined() {
+            // a. Throw a TypeError exception.
+            return Err(JsNativeError::typ()
+                .with_message("Temporal.TimeZone: calling a builtin constructor without new is forbidden")
+                .into());
+        }
+
+        // 2. Set identifier to ? ToString(identifier).
+        let identifier = args.get_or_undefined(0).to_string(context)?.to_std_string_escaped();
+
+        // 3. If IsTimeZoneOffsetString(identifier) is false, then
+        //     a. If IsValidTimeZoneName(identifier) is false, then
+        //         i. Throw a RangeError exception.
+        //     b. Set identifier to ! CanonicalizeTimeZoneName(identifier).
+        let kind = TimeZoneKind::from_identifier(&identifier).ok_or_else(|| {
+            JsNativeError::range()
+                .with_message(format!("Temporal.TimeZone: unknown time zone `{identifier}`"))
+        })?;
+
+        // 4. Return ? CreateTemporalTimeZone(identifier, NewTarget).
+        Ok(Self::create(kind, Some(new_target), context)?.into())
+    }
+
+    /// `Temporal.TimeZone.from ( item )`
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? ToTemporalTimeZone(item).
+        Ok(to_temporal_time_zone(args.get_or_undefined(0), context)?.into())
+    }
+
+    /// `get Temporal.TimeZone.prototype.id`
+    fn id(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let timeZone be the this value.
+        // 2. Return ? ToString(timeZone).
+        let kind = Self::this_time_zone(this, "id")?;
+        Ok(kind.identifier().into())
+    }
+
+    /// `Temporal.TimeZone.prototype.getOffsetNanosecondsFor ( instant )`
+    fn get_offset_nanoseconds_for(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(timeZone, [[InitializedTemporalTimeZone]]).
+        let kind = Self::this_time_zone(this, "getOffsetNanosecondsFor")?;
+
+        // 3. Set instant to ? ToTemporalInstant(instant).
+        let instant = to_temporal_instant(args.get_or_undefined(0), context)?;
+
+        // 4-5. Return 𝔽(offsetNanoseconds).
+        Ok((kind.offset_ns(instant) as f64).into())
+    }
+
+    /// `Temporal.TimeZone.prototype.getOffsetStringFor ( instant )`
+    fn get_offset_string_for(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(timeZone, [[InitializedTemporalTimeZone]]).
+        let kind = Self::this_time_zone(this, "getOffsetStringFor")?;
+
+        // 3. Set instant to ? ToTemporalInstant(instant).
+        let instant = to_temporal_instant(args.get_or_undefined(0), context)?;
+
+        // 4. Return ? BuiltinTimeZoneGetOffsetStringFor(timeZone, instant).
+        Ok(format_time_zone_offset_string(kind.offset_ns(instant) as i64).into())
+    }
+
+    /// `Temporal.TimeZone.prototype.getPlainDateTimeFor ( instant [ , calendarLike ] )`
+    fn get_plain_date_time_for(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(timeZone, [[InitializedTemporalTimeZone]]).
+        let kind = Self::this_time_zone(this, "getPlainDateTimeFor")?;
+
+        // 3. Set instant to ? ToTemporalInstant(instant).
+        let instant = to_temporal_instant(args.get_or_undefined(0), context)?;
+
+        // 4. Let calendar be ? ToTemporalCalendarWithISODefault(calendarLike).
+        let calendar = to_temporal_calendar_with_iso_default(args.get_or_undefined(1), context)?;
+
+        // 5. Return ? BuiltinTimeZoneGetPlainDateTimeFor(timeZone, instant, calendar).
+        let date_time = kind.plain_date_time_for(instant);
+        Ok(PlainDateTime::create(date_time, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.TimeZone.prototype.getInstantFor ( dateTime [ , options ] )`
+    fn get_instant_for(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(timeZone, [[InitializedTemporalTimeZone]]).
+        let kind = Self::this_time_zone(this, "getInstantFor")?;
+
+        // 3. Set dateTime to ? ToTemporalDateTime(dateTime).
+        let (date_time, _) = to_temporal_date_time(args.get_or_undefined(0), None, context)?;
+
+        // 4. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 5. Let disambiguation be ? ToTemporalDisambiguation(options).
+        let disambiguation = to_temporal_disambiguation(&options, context)?;
+
+        // 6. Return ? BuiltinTimeZoneGetInstantFor(timeZone, dateTime, disambiguation).
+        let instant = kind.instant_for(date_time, disambiguation)?;
+        Ok(Instant::create(instant, None, context)?.into())
+    }
+
+    /// `Temporal.TimeZone.prototype.getPossibleInstantsFor ( dateTime )`
+    fn get_possible_instants_for(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(timeZone, [[InitializedTemporalTimeZone]]).
+        let kind = Self::this_time_zone(this, "getPossibleInstantsFor")?;
+
+        // 3. Set dateTime to ? ToTemporalDateTime(dateTime).
+        let (date_time, _) = to_temporal_date_time(args.get_or_undefined(0), None, context)?;
+
+        // 4-6. Let possibleEpochNanoseconds be the epoch nanoseconds of the instants.
+        let possible = kind.possible_instants(date_time)?;
+
+        // 7. Let possibleInstants be a new empty List.
+        // 8. For each value epochNanoseconds in possibleEpochNanoseconds, do
+        //     a. Let instant be ! CreateTemporalInstant(epochNanoseconds).
+        //     b. Append instant to possibleInstants.
+        let mut instants = Vec::with_capacity(possible.len());
+        for ns in possible {
+            instants.push(Instant::create(ns, None, context)?.into());
+        }
+
+        // 9. Return CreateArrayFromList(possibleInstants).
+        Ok(Array::create_array_from_list(instants, context).into())
+    }
+
+    /// `Temporal.TimeZone.prototype.getNextTransition ( startingPoint )`
+    fn get_next_transition(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(timeZone, [[InitializedTemporalTimeZone]]).
+        let kind = Self::this_time_zone(this, "getNextTransition")?;
+
+        // 3. Set startingPoint to ? ToTemporalInstant(startingPoint).
+        let starting_point = to_temporal_instant(args.get_or_undefined(0), context)?;
+
+        // 4. If timeZone.[[OffsetNanoseconds]] is not undefined, return null.
+        // 5. Let transition be GetNamedTimeZoneNextTransition(timeZone.[[Identifier]], startingPoint.[[Nanoseconds]]).
+        // 6. If transition is null, return null.
+        // 7. Return ! CreateTemporalInstant(transition).
+        match kind.next_transition(starting_point) {
+            Some(ns) if is_valid_epoch_ns(ns) => Ok(Instant::create(ns, None, context)?.into()),
+            _ => Ok(JsValue::null()),
+        }
+    }
+
+    /// `Temporal.TimeZone.prototype.getPreviousTransition ( startingPoint )`
+    fn get_previous_transition(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(timeZone, [[InitializedTemporalTimeZone]]).
+        let kind = Self::this_time_zone(this, "getPreviousTransition")?;
+
+        // 3. Set startingPoint to ? ToTemporalInstant(startingPoint).
+        let starting_point = to_temporal_instant(args.get_or_undefined(0), context)?;
+
+        // 4. If timeZone.[[OffsetNanoseconds]] is not undefined, return null.
+        // 5. Let transition be GetNamedTimeZonePreviousTransition(timeZone.[[Identifier]], startingPoint.[[Nanoseconds]]).
+        // 6. If transition is null, return null.
+        // 7. Return ! CreateTemporalInstant(transition).
+        match kind.previous_transition(starting_point) {
+            Some(ns) if is_valid_epoch_ns(ns) => Ok(Instant::create(ns, None, context)?.into()),
+            _ => Ok(JsValue::null()),
+        }
+    }
+
+    /// `Temporal.TimeZone.prototype.toString ( )`
+    ///
+    /// This is also `Temporal.TimeZone.prototype.toJSON ( )`.
+    fn to_string(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let timeZone be the this value.
+        // 2. Perform ? RequireInternalSlot(timeZone, [[InitializedTemporalTimeZone]]).
+        // 3. Return timeZone.[[Identifier]].
+        let kind = Self::this_time_zone(this, "toString")?;
+        Ok(kind.identifier().into())
+    }
+}
+
+/// The time of the day of `epoch_ns` in `time_zone`, used to find the start of its day.
+pub(crate) fn start_of_day(time_zone: TimeZoneKind, epoch_ns: i128) -> JsResult<i128> {
+    let date_time = time_zone.plain_date_time_for(epoch_ns);
+    time_zone.instant_for(
+        IsoDateTime {
+            date: date_time.date,
+            time: IsoTime::MIDNIGHT,
+        },
+        Disambiguation::Compatible,
+    )
+}
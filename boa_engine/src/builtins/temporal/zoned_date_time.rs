@@ -0,0 +1,1297 @@
+//! This module implements the `Temporal.ZonedDateTime` object.
+//!
+//! A zoned date-time is an exact point on the time line, with a time zone and a calendar used
+//! to compute its local date and time.
+//!
+//! More information:
+//!  - [Temporal reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-zoneddatetime-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime
+
+use super::{
+    bigint_to_ns,
+    calendar::{
+        format_calendar_annotation, get_temporal_calendar_with_iso_default, to_temporal_calendar,
+        to_temporal_calendar_with_iso_default, validate_calendar_annotation,
+    },
+    duration::{to_temporal_duration_record, DurationRecord, RelativeTo},
+    fields::{FieldName, TemporalFields, ZONED_DATE_TIME_FIELDS},
+    getter,
+    iso::{
+        days_in_month, days_in_year, is_leap_year, is_valid_epoch_ns, out_of_range, IsoDateTime,
+        IsoTime,
+    },
+    ns_to_bigint,
+    options::{
+        get_difference_settings, get_option, get_options_object, get_temporal_unit,
+        to_calendar_name_option, to_seconds_string_precision, to_temporal_disambiguation,
+        to_temporal_offset, to_temporal_overflow, to_temporal_rounding_increment,
+        to_temporal_rounding_mode, DifferenceOperation, Disambiguation, OffsetOption, Overflow,
+        Precision, RoundingMode, ShowCalendar, ShowOffset, ShowTimeZone, UnitGroup,
+    },
+    parser::{parse_date_time, parse_offset_string, ParsedOffset},
+    plain_date::to_temporal_date,
+    plain_time::to_temporal_time,
+    reject_object_with_calendar_or_time_zone,
+    time_zone::{
+        format_iso_time_zone_offset_string, format_time_zone_offset_string, start_of_day,
+        to_temporal_time_zone, TimeZone, TimeZoneKind,
+    },
+    value_of, Duration, Instant, PlainDate, PlainDateTime, PlainTime, TemporalUnit,
+};
+use crate::{
+    builtins::JsArgs,
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsFunction,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsNativeError, JsResult, JsValue, JsVariant,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// How the UTC offset of a date-time string or property bag is used to find the instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OffsetBehaviour {
+    /// The offset is used according to the `offset` option.
+    Option,
+    /// The date-time is in UTC, given by the `Z` designator.
+    Exact,
+    /// There is no offset, so the date-time is a wall-clock time of the time zone.
+    Wall,
+}
+
+/// Abstract operation `InterpretISODateTimeOffset ( year, month, day, hour, minute, second, millisecond, microsecond, nanosecond, offsetBehaviour, offsetNanoseconds, timeZone, disambiguation, offsetOption, matchBehaviour )`
+///
+/// Returns the epoch nanoseconds of the instant. If `match_minutes` is `true`, an offset equal
+/// to the possible offsets rounded to the minute is accepted.
+pub(crate) fn interpret_iso_date_time_offset(
+    date_time: IsoDateTime,
+    offset_behaviour: OffsetBehaviour,
+    offset_ns: i128,
+    time_zone: TimeZoneKind,
+    disambiguation: Disambiguation,
+    offset_option: OffsetOption,
+    match_minutes: bool,
+) -> JsResult<i128> {
+    // 1. Let calendar be ! GetISO8601Calendar().
+    // 2. Let dateTime be ? CreateTemporalDateTime(year, month, day, hour, minute, second, millisecond, microsecond, nanosecond, calendar).
+    if !date_time.is_within_limits() {
+        return out_of_range();
+    }
+
+    // 3. If offsetBehaviour is wall or offsetOption is "ignore", then
+    if offset_behaviour == OffsetBehaviour::Wall || offset_option == OffsetOption::Ignore {
+        // a. Let instant be ? BuiltinTimeZoneGetInstantFor(timeZone, dateTime, disambiguation).
+        // b. Return instant.[[Nanoseconds]].
+        return time_zone.instant_for(date_time, disambiguation);
+    }
+
+    // 4. If offsetBehaviour is exact or offsetOption is "use", then
+    if offset_behaviour == OffsetBehaviour::Exact || offset_option == OffsetOption::Use {
+        // a. Let epochNanoseconds be GetUTCEpochNanoseconds(year, ..., nanosecond).
+        // b. Set epochNanoseconds to epochNanoseconds - ℤ(offsetNanoseconds).
+        let epoch_ns = date_time.to_local_ns() - offset_ns;
+
+        // c. If ! IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+        if !is_valid_epoch_ns(epoch_ns) {
+            return out_of_range();
+        }
+
+        // d. Return epochNanoseconds.
+        return Ok(epoch_ns);
+    }
+
+    // 5. Assert: offsetBehaviour is option.
+    // 6. Assert: offsetOption is "prefer" or "reject".
+    // 7. Let possibleInstants be ? GetPossibleInstantsFor(timeZone, dateTime).
+    let possible = time_zone.possible_instants(date_time)?;
+
+    // 8. For each element candidate of possibleInstants, do
+    for candidate in &possible {
+        // a. Let candidateNanoseconds be ? GetOffsetNanosecondsFor(timeZone, candidate).
+        let candidate_offset = time_zone.offset_ns(*candidate);
+
+        // b. If candidateNanoseconds = offsetNanoseconds, then
+        if candidate_offset == offset_ns {
+            // i. Return candidate.[[Nanoseconds]].
+            return Ok(*candidate);
+        }
+
+        // c. If matchBehaviour is match minutes, then
+        if match_minutes {
+            // i. Let roundedCandidateNanoseconds be RoundNumberToIncrement(candidateNanoseconds, 60 × 10^9, "halfExpand").
+            let rounded = RoundingMode::HalfExpand.round(candidate_offset, 60_000_000_000);
+
+            // ii. If roundedCandidateNanoseconds = offsetNanoseconds, then
+            if rounded == offset_ns {
+                // 1. Return candidate.[[Nanoseconds]].
+                return Ok(*candidate);
+            }
+        }
+    }
+
+    // 9. If offsetOption is "reject", throw a RangeError exception.
+    if offset_option == OffsetOption::Reject {
+        return Err(JsNativeError::range()
+            .with_message("Temporal: the offset is not valid for the time zone")
+            .into());
+    }
+
+    // 10. Let instant be ? DisambiguatePossibleInstants(possibleInstants, timeZone, dateTime, disambiguation).
+    // 11. Return instant.[[Nanoseconds]].
+    time_zone.disambiguate(&possible, date_time, disambiguation)
+}
+
+/// Abstract operation `ToTemporalZonedDateTime ( item [ , options ] )`
+///
+/// Returns the epoch nanoseconds, the time zone and the calendar of the zoned date-time.
+pub(crate) fn to_temporal_zoned_date_time(
+    item: &JsValue,
+    options: Option<&JsObject>,
+    context: &mut Context,
+) -> JsResult<(i128, JsObject, JsObject)> {
+    // 1. If options is not present, set options to undefined.
+    // 2. Assert: Type(options) is Object or Undefined.
+    let options = match options {
+        Some(options) => options.clone(),
+        None => get_options_object(&JsValue::undefined())?,
+    };
+
+    // 3. Let offsetBehaviour be option.
+    // 4. Let matchBehaviour be match exactly.
+    let mut match_minutes = false;
+
+    // 5. If Type(item) is Object, then
+    let (date_time, offset_behaviour, offset_ns, time_zone, calendar) =
+        if let Some(object) = item.as_object() {
+            // a. If item has an [[InitializedTemporalZonedDateTime]] internal slot, then
+            //     i. Return item.
+            if let Some(zoned) = object.borrow().as_zoned_date_time() {
+                return Ok((
+                    zoned.nanoseconds,
+                    zoned.time_zone.clone(),
+                    zoned.calendar.clone(),
+                ));
+            }
+
+            // b. Let calendar be ? GetTemporalCalendarWithISODefault(item).
+            let calendar = get_temporal_calendar_with_iso_default(object, context)?;
+
+            // c. Let fieldNames be ? CalendarFields(calendar, « "day", "hour", "microsecond", "millisecond", "minute", "month", "monthCode", "nanosecond", "second", "year" »).
+            // d. Append "timeZone" to fieldNames.
+            // e. Append "offset" to fieldNames.
+            // f. Let fields be ? PrepareTemporalFields(item, fieldNames, « "timeZone" »).
+            let fields = TemporalFields::prepare(object, ZONED_DATE_TIME_FIELDS, false, context)?;
+
+            // g. Let timeZone be ! Get(fields, "timeZone").
+            // h. Set timeZone to ? ToTemporalTimeZone(timeZone).
+            let time_zone = match &fields.time_zone {
+                Some(time_zone) => to_temporal_time_zone(time_zone, context)?,
+                None => {
+                    return Err(JsNativeError::typ()
+                        .with_message("Temporal: timeZone is required")
+                        .into())
+                }
+            };
+
+            // i. Let offsetString be ! Get(fields, "offset").
+            // j. If offsetString is undefined, then
+            //     i. Set offsetBehaviour to wall.
+            let (offset_behaviour, offset_ns) = match &fields.offset {
+                None => (OffsetBehaviour::Wall, 0),
+                Some(offset) => match parse_offset_string(offset) {
+                    Some(offset_ns) => (OffsetBehaviour::Option, offset_ns),
+                    None => {
+                        return Err(JsNativeError::range()
+                            .with_message(format!("Temporal: invalid offset `{offset}`"))
+                            .into())
+                    }
+                },
+            };
+
+            // k. Let result be ? InterpretTemporalDateTimeFields(calendar, fields, options).
+            let overflow = to_temporal_overflow(&options, context)?;
+            let date_time = fields.to_date_time(overflow)?;
+
+            (date_time, offset_behaviour, offset_ns, time_zone, calendar)
+        } else {
+            // 6. Else,
+            //     a. Perform ? ToTemporalOverflow(options).
+            to_temporal_overflow(&options, context)?;
+
+            //     b. Let string be ? ToString(item).
+            let string = match item.variant() {
+                JsVariant::String(string) => string.to_std_string_escaped(),
+                _ => {
+                    return Err(JsNativeError::typ()
+                        .with_message(
+                            "Temporal: expected a ZonedDateTime, a property bag or a string",
+                        )
+                        .into())
+                }
+            };
+
+            //     c. Let result be ? ParseTemporalZonedDateTimeString(string).
+            let parsed = parse_date_time(&string)?;
+
+            //     d. Let timeZoneName be result.[[TimeZone]].[[Name]].
+            //     e. Assert: timeZoneName is not undefined.
+            //     f. If ParseText(StringToCodePoints(timeZoneName), TimeZoneNumericUTCOffset) is a List of errors, then
+            //         i. If IsValidTimeZoneName(timeZoneName) is false, throw a RangeError exception.
+            //         ii. Set timeZoneName to ! CanonicalizeTimeZoneName(timeZoneName).
+            //     g. Let offsetString be result.[[TimeZone]].[[OffsetString]].
+            //     h. If result.[[TimeZone]].[[Z]] is true, then
+            //         i. Set offsetBehaviour to exact.
+            //     i. Else if offsetString is undefined, then
+            //         i. Set offsetBehaviour to wall.
+            //     j. Let timeZone be ! CreateTemporalTimeZone(timeZoneName).
+            let time_zone = match &parsed.time_zone {
+                Some(identifier) => to_temporal_time_zone(&identifier.as_str().into(), context)?,
+                None => {
+                    return Err(JsNativeError::range()
+                        .with_message(format!(
+                            "Temporal: the zoned date-time string `{string}` has no time zone annotation"
+                        ))
+                        .into())
+                }
+            };
+            let (offset_behaviour, offset_ns) = match parsed.offset {
+                Some(ParsedOffset::Utc) => (OffsetBehaviour::Exact, 0),
+                Some(ParsedOffset::Numeric { ns, sub_minute }) => {
+                    //     l. Set matchBehaviour to match minutes.
+                    match_minutes = !sub_minute;
+                    (OffsetBehaviour::Option, ns)
+                }
+                None => (OffsetBehaviour::Wall, 0),
+            };
+
+            //     k. Let calendar be ? ToTemporalCalendarWithISODefault(result.[[Calendar]]).
+            validate_calendar_annotation(parsed.calendar.as_deref())?;
+            let calendar = to_temporal_calendar_with_iso_default(&JsValue::undefined(), context)?;
+
+            let date_time = IsoDateTime {
+                date: parsed.date,
+                time: parsed.time.unwrap_or_default(),
+            };
+            (date_time, offset_behaviour, offset_ns, time_zone, calendar)
+        };
+
+    // 7. Let disambiguation be ? ToTemporalDisambiguation(options).
+    let disambiguation = to_temporal_disambiguation(&options, context)?;
+
+    // 8. Let offsetOption be ? ToTemporalOffset(options, "reject").
+    let offset_option = to_temporal_offset(&options, OffsetOption::Reject, context)?;
+
+    // 9. Let epochNanoseconds be ? InterpretISODateTimeOffset(result.[[Year]], ..., offsetBehaviour, offsetNanoseconds, timeZone, disambiguation, offsetOption, matchBehaviour).
+    let epoch_ns = interpret_iso_date_time_offset(
+        date_time,
+        offset_behaviour,
+        i128::from(offset_ns),
+        TimeZone::kind_of(&time_zone),
+        disambiguation,
+        offset_option,
+        match_minutes,
+    )?;
+
+    // 10. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+    Ok((epoch_ns, time_zone, calendar))
+}
+
+/// JavaScript `Temporal.ZonedDateTime` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct ZonedDateTime {
+    #[unsafe_ignore_trace]
+    pub(crate) nanoseconds: i128,
+    pub(crate) time_zone: JsObject,
+    pub(crate) calendar: JsObject,
+}
+
+/// The internal slots of a `Temporal.ZonedDateTime`, with the time zone resolved.
+struct Slots {
+    epoch_ns: i128,
+    time_zone: JsObject,
+    kind: TimeZoneKind,
+    calendar: JsObject,
+}
+
+impl Slots {
+    /// Gets the local date and time.
+    fn date_time(&self) -> IsoDateTime {
+        self.kind.plain_date_time_for(self.epoch_ns)
+    }
+}
+
+impl ZonedDateTime {
+    const NAME: &'static str = "ZonedDateTime";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let mut builder = ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().zoned_date_time().clone(),
+        );
+        builder.name(Self::NAME).length(2);
+        getter(&mut builder, "calendar", Self::calendar);
+        getter(&mut builder, "timeZone", Self::time_zone);
+        getter(&mut builder, "year", Self::year);
+        getter(&mut builder, "month", Self::month);
+        getter(&mut builder, "monthCode", Self::month_code);
+        getter(&mut builder, "day", Self::day);
+        getter(&mut builder, "hour", Self::hour);
+        getter(&mut builder, "minute", Self::minute);
+        getter(&mut builder, "second", Self::second);
+        getter(&mut builder, "millisecond", Self::millisecond);
+        getter(&mut builder, "microsecond", Self::microsecond);
+        getter(&mut builder, "nanosecond", Self::nanosecond);
+        getter(&mut builder, "epochSeconds", Self::epoch_seconds);
+        getter(&mut builder, "epochMilliseconds", Self::epoch_milliseconds);
+        getter(&mut builder, "epochMicroseconds", Self::epoch_microseconds);
+        getter(&mut builder, "epochNanoseconds", Self::epoch_nanoseconds);
+        getter(&mut builder, "dayOfWeek", Self::day_of_week);
+        getter(&mut builder, "dayOfYear", Self::day_of_year);
+        getter(&mut builder, "weekOfYear", Self::week_of_year);
+        getter(&mut builder, "hoursInDay", Self::hours_in_day);
+        getter(&mut builder, "daysInWeek", Self::days_in_week);
+        getter(&mut builder, "daysInMonth", Self::days_in_month);
+        getter(&mut builder, "daysInYear", Self::days_in_year);
+        getter(&mut builder, "monthsInYear", Self::months_in_year);
+        getter(&mut builder, "inLeapYear", Self::in_leap_year);
+        getter(&mut builder, "offsetNanoseconds", Self::offset_nanoseconds);
+        getter(&mut builder, "offset", Self::offset);
+        builder
+            .static_method(Self::from, "from", 1)
+            .static_method(Self::compare, "compare", 2)
+            .method(Self::with, "with", 1)
+            .method(Self::with_plain_time, "withPlainTime", 0)
+            .method(Self::with_plain_date, "withPlainDate", 1)
+            .method(Self::with_time_zone, "withTimeZone", 1)
+            .method(Self::with_calendar, "withCalendar", 1)
+            .method(Self::add, "add", 1)
+            .method(Self::subtract, "subtract", 1)
+            .method(Self::until, "until", 1)
+            .method(Self::since, "since", 1)
+            .method(Self::round, "round", 1)
+            .method(Self::equals, "equals", 1)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_json, "toLocaleString", 0)
+            .method(Self::to_json, "toJSON", 0)
+            .method(value_of, "valueOf", 0)
+            .method(Self::start_of_day, "startOfDay", 0)
+            .method(Self::to_instant, "toInstant", 0)
+            .method(Self::to_plain_date, "toPlainDate", 0)
+            .method(Self::to_plain_time, "toPlainTime", 0)
+            .method(Self::to_plain_date_time, "toPlainDateTime", 0)
+            .method(Self::get_iso_fields, "getISOFields", 0)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                "Temporal.ZonedDateTime",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build()
+    }
+
+    /// Abstract operation `CreateTemporalZonedDateTime ( epochNanoseconds, timeZone, calendar [ , newTarget ] )`
+    pub(crate) fn create(
+        nanoseconds: i128,
+        time_zone: JsObject,
+        calendar: JsObject,
+        new_target: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Assert: ! IsValidEpochNanoseconds(epochNanoseconds) is true.
+        if !is_valid_epoch_ns(nanoseconds) {
+            return out_of_range();
+        }
+
+        // 2. If newTarget is not present, set newTarget to %Temporal.ZonedDateTime%.
+        // 3. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.ZonedDateTime.prototype%", « ... »).
+        let prototype = match new_target {
+            Some(new_target) => get_prototype_from_constructor(
+                new_target,
+                StandardConstructors::zoned_date_time,
+                context,
+            )?,
+            None => context.intrinsics().constructors().zoned_date_time().prototype(),
+        };
+
+        // 4. Set object.[[Nanoseconds]] to epochNanoseconds.
+        // 5. Set object.[[TimeZone]] to timeZone.
+        // 6. Set object.[[Calendar]] to calendar.
+        // 7. Return object.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::zoned_date_time(Self {
+                nanoseconds,
+                time_zone,
+                calendar,
+            }),
+        ))
+    }
+
+    /// Gets the internal slots of the `this` value, throwing a `TypeError` if it's not a
+    /// `Temporal.ZonedDateTime`.
+    fn this_zoned(this: &JsValue, method: &str) -> JsResult<Slots> {
+        this.as_object()
+            .and_then(|object| {
+                object.borrow().as_zoned_date_time().map(|zoned| Slots {
+                    epoch_ns: zoned.nanoseconds,
+                    time_zone: zoned.time_zone.clone(),
+                    kind: TimeZone::kind_of(&zoned.time_zone),
+                    calendar: zoned.calendar.clone(),
+                })
+            })
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "Temporal.ZonedDateTime.prototype.{method}: 'this' is not a Temporal.ZonedDateTime"
+                    ))
+                    .into()
+            })
+    }
+
+    /// `Temporal.ZonedDateTime ( epochNanoseconds, timeZoneLike [ , calendarLike ] )`
+    fn constructor(new_target: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        if new_target.is_undefined() {
+            // a. Throw a TypeError exception.
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "Temporal.ZonedDateTime: calling a builtin constructor without new is forbidden",
+                )
+                .into());
+        }
+
+        // 2. Set epochNanoseconds to ? ToBigInt(epochNanoseconds).
+        let epoch_ns = args.get_or_undefined(0).to_bigint(context)?;
+
+        // 3. If ! IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+        let epoch_ns = bigint_to_ns(&epoch_ns)?;
+        if !is_valid_epoch_ns(epoch_ns) {
+            return out_of_range();
+        }
+
+        // 4. Let timeZone be ? ToTemporalTimeZone(timeZoneLike).
+        let time_zone = to_temporal_time_zone(args.get_or_undefined(1), context)?;
+
+        // 5. Let calendar be ? ToTemporalCalendarWithISODefault(calendarLike).
+        let calendar = to_temporal_calendar_with_iso_default(args.get_or_undefined(2), context)?;
+
+        // 6. Return ? CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar, NewTarget).
+        Ok(Self::create(epoch_ns, time_zone, calendar, Some(new_target), context)?.into())
+    }
+
+    /// `Temporal.ZonedDateTime.from ( item [ , options ] )`
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 2. If Type(item) is Object and item has an [[InitializedTemporalZonedDateTime]] internal slot, then
+        //     a. Perform ? ToTemporalOverflow(options).
+        //     b. Perform ? ToTemporalDisambiguation(options).
+        //     c. Perform ? ToTemporalOffset(options, "reject").
+        //     d. Return ! CreateTemporalZonedDateTime(item.[[Nanoseconds]], item.[[TimeZone]], item.[[Calendar]]).
+        let item = args.get_or_undefined(0);
+        if item
+            .as_object()
+            .map_or(false, |object| object.borrow().is_zoned_date_time())
+        {
+            to_temporal_overflow(&options, context)?;
+            to_temporal_disambiguation(&options, context)?;
+            to_temporal_offset(&options, OffsetOption::Reject, context)?;
+        }
+
+        // 3. Return ? ToTemporalZonedDateTime(item, options).
+        let (epoch_ns, time_zone, calendar) =
+            to_temporal_zoned_date_time(item, Some(&options), context)?;
+        Ok(Self::create(epoch_ns, time_zone, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.ZonedDateTime.compare ( one, two )`
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set one to ? ToTemporalZonedDateTime(one).
+        let one = to_temporal_zoned_date_time(args.get_or_undefined(0), None, context)?.0;
+        // 2. Set two to ? ToTemporalZonedDateTime(two).
+        let two = to_temporal_zoned_date_time(args.get_or_undefined(1), None, context)?.0;
+        // 3. Return 𝔽(! CompareEpochNanoseconds(one.[[Nanoseconds]], two.[[Nanoseconds]])).
+        Ok(match one.cmp(&two) {
+            std::cmp::Ordering::Less => -1,
+            std::cmp::Ordering::Equal => 0,
+            std::cmp::Ordering::Greater => 1,
+        }
+        .into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.calendar`
+    fn calendar(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_zoned(this, "calendar")?.calendar.into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.timeZone`
+    fn time_zone(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_zoned(this, "timeZone")?.time_zone.into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.year`
+    fn year(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_zoned(this, "year")?.date_time().date.year.into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.month`
+    fn month(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_zoned(this, "month")?.date_time().date.month.into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.monthCode`
+    fn month_code(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let date = Self::this_zoned(this, "monthCode")?.date_time().date;
+        Ok(format!("M{:02}", date.month).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.day`
+    fn day(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_zoned(this, "day")?.date_time().date.day.into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.hour`
+    fn hour(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_zoned(this, "hour")?.date_time().time.hour.into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.minute`
+    fn minute(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_zoned(this, "minute")?.date_time().time.minute.into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.second`
+    fn second(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::this_zoned(this, "second")?.date_time().time.second.into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.millisecond`
+    fn millisecond(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let time = Self::this_zoned(this, "millisecond")?.date_time().time;
+        Ok(time.millisecond.into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.microsecond`
+    fn microsecond(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let time = Self::this_zoned(this, "microsecond")?.date_time().time;
+        Ok(time.microsecond.into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.nanosecond`
+    fn nanosecond(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let time = Self::this_zoned(this, "nanosecond")?.date_time().time;
+        Ok(time.nanosecond.into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.epochSeconds`
+    fn epoch_seconds(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let ns = Self::this_zoned(this, "epochSeconds")?.epoch_ns;
+        Ok((ns.div_euclid(1_000_000_000) as f64).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.epochMilliseconds`
+    fn epoch_milliseconds(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let ns = Self::this_zoned(this, "epochMilliseconds")?.epoch_ns;
+        Ok((ns.div_euclid(1_000_000) as f64).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.epochMicroseconds`
+    fn epoch_microseconds(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let ns = Self::this_zoned(this, "epochMicroseconds")?.epoch_ns;
+        Ok(ns_to_bigint(ns.div_euclid(1_000)))
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.epochNanoseconds`
+    fn epoch_nanoseconds(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let ns = Self::this_zoned(this, "epochNanoseconds")?.epoch_ns;
+        Ok(ns_to_bigint(ns))
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.dayOfWeek`
+    fn day_of_week(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let date = Self::this_zoned(this, "dayOfWeek")?.date_time().date;
+        Ok(date.day_of_week().into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.dayOfYear`
+    fn day_of_year(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let date = Self::this_zoned(this, "dayOfYear")?.date_time().date;
+        Ok(date.day_of_year().into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.weekOfYear`
+    fn week_of_year(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let date = Self::this_zoned(this, "weekOfYear")?.date_time().date;
+        Ok(date.week_of_year().into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.hoursInDay`
+    fn hours_in_day(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1-3. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = Self::this_zoned(this, "hoursInDay")?;
+
+        // 4-6. Let temporalDateTime be ? BuiltinTimeZoneGetPlainDateTimeFor(timeZone, instant, isoCalendar).
+        let date = zoned.date_time().date;
+
+        // 7-11. Let today be ? CreateTemporalDateTime(year, month, day, 0, 0, 0, 0, 0, 0, isoCalendar).
+        // 12. Let tomorrowFields be BalanceISODate(year, month, day + 1).
+        // 13. Let tomorrow be ? CreateTemporalDateTime(tomorrowFields.[[Year]], tomorrowFields.[[Month]], tomorrowFields.[[Day]], 0, 0, 0, 0, 0, 0, isoCalendar).
+        // 14. Let todayInstant be ? BuiltinTimeZoneGetInstantFor(timeZone, today, "compatible").
+        let today = zoned.kind.instant_for(
+            IsoDateTime {
+                date,
+                time: IsoTime::MIDNIGHT,
+            },
+            Disambiguation::Compatible,
+        )?;
+
+        // 15. Let tomorrowInstant be ? BuiltinTimeZoneGetInstantFor(timeZone, tomorrow, "compatible").
+        let tomorrow = zoned.kind.instant_for(
+            IsoDateTime {
+                date: super::iso::IsoDate::from_epoch_days(date.epoch_days() + 1),
+                time: IsoTime::MIDNIGHT,
+            },
+            Disambiguation::Compatible,
+        )?;
+
+        // 16. Let diffNs be tomorrowInstant.[[Nanoseconds]] - todayInstant.[[Nanoseconds]].
+        // 17. Return 𝔽(diffNs / (3.6 × 10^12)).
+        Ok(((tomorrow - today) as f64 / 3.6e12).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.daysInWeek`
+    fn days_in_week(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::this_zoned(this, "daysInWeek")?;
+        Ok(7.into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.daysInMonth`
+    fn days_in_month(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let date = Self::this_zoned(this, "daysInMonth")?.date_time().date;
+        Ok(days_in_month(date.year, date.month).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.daysInYear`
+    fn days_in_year(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let date = Self::this_zoned(this, "daysInYear")?.date_time().date;
+        Ok(days_in_year(date.year).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.monthsInYear`
+    fn months_in_year(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::this_zoned(this, "monthsInYear")?;
+        Ok(12.into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.inLeapYear`
+    fn in_leap_year(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let date = Self::this_zoned(this, "inLeapYear")?.date_time().date;
+        Ok(is_leap_year(date.year).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.offsetNanoseconds`
+    fn offset_nanoseconds(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1-4. Return 𝔽(? GetOffsetNanosecondsFor(timeZone, instant)).
+        let zoned = Self::this_zoned(this, "offsetNanoseconds")?;
+        Ok((zoned.kind.offset_ns(zoned.epoch_ns) as f64).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.offset`
+    fn offset(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1-4. Return ? BuiltinTimeZoneGetOffsetStringFor(zonedDateTime.[[TimeZone]], instant).
+        let zoned = Self::this_zoned(this, "offset")?;
+        let offset_ns = zoned.kind.offset_ns(zoned.epoch_ns) as i64;
+        Ok(format_time_zone_offset_string(offset_ns).into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.with ( temporalZonedDateTimeLike [ , options ] )`
+    fn with(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = Self::this_zoned(this, "with")?;
+
+        // 3. If Type(temporalZonedDateTimeLike) is not Object, then
+        let zoned_like = args.get_or_undefined(0).as_object().cloned().ok_or_else(|| {
+            // a. Throw a TypeError exception.
+            JsNativeError::typ()
+                .with_message("Temporal.ZonedDateTime.prototype.with: expected an object")
+        })?;
+
+        // 4. Perform ? RejectObjectWithCalendarOrTimeZone(temporalZonedDateTimeLike).
+        reject_object_with_calendar_or_time_zone(&zoned_like, context)?;
+
+        // 5-6. Let fieldNames be ? CalendarFields(calendar, « "day", "hour", ..., "year" »).
+        // 7. Append "offset" to fieldNames.
+        // 8. Let partialZonedDateTime be ? PrepareTemporalFields(temporalZonedDateTimeLike, fieldNames, partial).
+        let field_names: Vec<_> = ZONED_DATE_TIME_FIELDS
+            .iter()
+            .copied()
+            .filter(|name| *name != FieldName::TimeZone)
+            .collect();
+        let partial = TemporalFields::prepare(&zoned_like, &field_names, true, context)?;
+
+        // 9. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 10. Let disambiguation be ? ToTemporalDisambiguation(options).
+        let disambiguation = to_temporal_disambiguation(&options, context)?;
+
+        // 11. Let offset be ? ToTemporalOffset(options, "prefer").
+        let offset_option = to_temporal_offset(&options, OffsetOption::Prefer, context)?;
+
+        // 12-13. Let fields be ? PrepareTemporalFields(zonedDateTime, fieldNames, « "timeZone", "offset" »).
+        // 14. Set fields to ? CalendarMergeFields(calendar, fields, partialZonedDateTime).
+        // 15. Set fields to ? PrepareTemporalFields(fields, fieldNames, « "timeZone", "offset" »).
+        let current = TemporalFields {
+            offset: Some(format_time_zone_offset_string(
+                zoned.kind.offset_ns(zoned.epoch_ns) as i64,
+            )),
+            ..TemporalFields::from_date_time(zoned.date_time())
+        };
+        let fields = current.merge(&partial);
+
+        // 16. Let offsetString be ! Get(fields, "offset").
+        // 17. Let dateTimeResult be ? InterpretTemporalDateTimeFields(calendar, fields, options).
+        let overflow = to_temporal_overflow(&options, context)?;
+        let date_time = fields.to_date_time(overflow)?;
+
+        // 18. Let offsetNanoseconds be ? ParseTimeZoneOffsetString(offsetString).
+        let offset = fields.offset.unwrap_or_default();
+        let offset_ns = parse_offset_string(&offset).ok_or_else(|| {
+            JsNativeError::range().with_message(format!("Temporal: invalid offset `{offset}`"))
+        })?;
+
+        // 19. Let epochNanoseconds be ? InterpretISODateTimeOffset(dateTimeResult.[[Year]], ..., option, offsetNanoseconds, timeZone, disambiguation, offset, match exactly).
+        let epoch_ns = interpret_iso_date_time_offset(
+            date_time,
+            OffsetBehaviour::Option,
+            i128::from(offset_ns),
+            zoned.kind,
+            disambiguation,
+            offset_option,
+            false,
+        )?;
+
+        // 20. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+        Ok(Self::create(epoch_ns, zoned.time_zone, zoned.calendar, None, context)?.into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.withPlainTime ( [ plainTimeLike ] )`
+    fn with_plain_time(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = Self::this_zoned(this, "withPlainTime")?;
+
+        // 3. If plainTimeLike is undefined, then
+        //     a. Let plainTime be ! CreateTemporalTime(0, 0, 0, 0, 0, 0).
+        // 4. Else,
+        //     a. Let plainTime be ? ToTemporalTime(plainTimeLike).
+        let time = match args.get_or_undefined(0).variant() {
+            JsVariant::Undefined => IsoTime::MIDNIGHT,
+            _ => to_temporal_time(args.get_or_undefined(0), Overflow::Constrain, context)?,
+        };
+
+        // 5-8. Let plainDateTime be ? BuiltinTimeZoneGetPlainDateTimeFor(timeZone, instant, calendar).
+        // 9. Let resultPlainDateTime be ? CreateTemporalDateTime(plainDateTime.[[ISOYear]], ..., plainTime.[[ISONanosecond]], calendar).
+        let date_time = IsoDateTime {
+            date: zoned.date_time().date,
+            time,
+        };
+        if !date_time.is_within_limits() {
+            return out_of_range();
+        }
+
+        // 10. Let instant be ? BuiltinTimeZoneGetInstantFor(timeZone, resultPlainDateTime, "compatible").
+        let epoch_ns = zoned.kind.instant_for(date_time, Disambiguation::Compatible)?;
+
+        // 11. Return ! CreateTemporalZonedDateTime(instant.[[Nanoseconds]], timeZone, calendar).
+        Ok(Self::create(epoch_ns, zoned.time_zone, zoned.calendar, None, context)?.into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.withPlainDate ( plainDateLike )`
+    fn with_plain_date(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = Self::this_zoned(this, "withPlainDate")?;
+
+        // 3. Let plainDate be ? ToTemporalDate(plainDateLike).
+        // 7. Let calendar be ? ConsolidateCalendars(zonedDateTime.[[Calendar]], plainDate.[[Calendar]]).
+        let (date, calendar) = to_temporal_date(args.get_or_undefined(0), None, context)?;
+
+        // 4-6. Let plainDateTime be ? BuiltinTimeZoneGetPlainDateTimeFor(timeZone, instant, zonedDateTime.[[Calendar]]).
+        // 8. Let resultPlainDateTime be ? CreateTemporalDateTime(plainDate.[[ISOYear]], ..., plainDateTime.[[ISONanosecond]], calendar).
+        let date_time = IsoDateTime {
+            date,
+            time: zoned.date_time().time,
+        };
+        if !date_time.is_within_limits() {
+            return out_of_range();
+        }
+
+        // 9. Let instant be ? BuiltinTimeZoneGetInstantFor(timeZone, resultPlainDateTime, "compatible").
+        let epoch_ns = zoned.kind.instant_for(date_time, Disambiguation::Compatible)?;
+
+        // 10. Return ! CreateTemporalZonedDateTime(instant.[[Nanoseconds]], timeZone, calendar).
+        Ok(Self::create(epoch_ns, zoned.time_zone, calendar, None, context)?.into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.withTimeZone ( timeZoneLike )`
+    fn with_time_zone(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = Self::this_zoned(this, "withTimeZone")?;
+
+        // 3. Let timeZone be ? ToTemporalTimeZone(timeZoneLike).
+        let time_zone = to_temporal_time_zone(args.get_or_undefined(0), context)?;
+
+        // 4. Return ! CreateTemporalZonedDateTime(zonedDateTime.[[Nanoseconds]], timeZone, zonedDateTime.[[Calendar]]).
+        Ok(Self::create(zoned.epoch_ns, time_zone, zoned.calendar, None, context)?.into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.withCalendar ( calendarLike )`
+    fn with_calendar(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = Self::this_zoned(this, "withCalendar")?;
+
+        // 3. Let calendar be ? ToTemporalCalendar(calendarLike).
+        let calendar = to_temporal_calendar(args.get_or_undefined(0), context)?;
+
+        // 4. Return ! CreateTemporalZonedDateTime(zonedDateTime.[[Nanoseconds]], zonedDateTime.[[TimeZone]], calendar).
+        Ok(Self::create(zoned.epoch_ns, zoned.time_zone, calendar, None, context)?.into())
+    }
+
+    /// Abstract operation `AddDurationToOrSubtractDurationFromZonedDateTime ( operation, zonedDateTime, temporalDurationLike, options )`
+    fn add_or_subtract(
+        this: &JsValue,
+        args: &[JsValue],
+        sign: f64,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let zoned = Self::this_zoned(this, method)?;
+
+        // 2. Let duration be ? ToTemporalDurationRecord(temporalDurationLike).
+        let mut duration = to_temporal_duration_record(args.get_or_undefined(0), context)?;
+        if sign < 0.0 {
+            duration = duration.negated();
+        }
+
+        // 3. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 4-5. Let epochNanoseconds be ? AddZonedDateTime(zonedDateTime.[[Nanoseconds]], timeZone, calendar, sign × duration.[[Years]], ..., options).
+        // The overflow option is only read when the calendar adds the date part.
+        let overflow = if duration.date() == DurationRecord::default() {
+            Overflow::Constrain
+        } else {
+            to_temporal_overflow(&options, context)?
+        };
+        let relative_to = RelativeTo::Zoned {
+            epoch_ns: zoned.epoch_ns,
+            time_zone: zoned.kind,
+        };
+        let epoch_ns = relative_to.add(&duration, overflow)?;
+
+        // 6. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+        Ok(Self::create(epoch_ns, zoned.time_zone, zoned.calendar, None, context)?.into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.add ( temporalDurationLike [ , options ] )`
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::add_or_subtract(this, args, 1.0, "add", context)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.subtract ( temporalDurationLike [ , options ] )`
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::add_or_subtract(this, args, -1.0, "subtract", context)
+    }
+
+    /// Abstract operation `DifferenceTemporalZonedDateTime ( operation, zonedDateTime, other, options )`
+    fn difference(
+        this: &JsValue,
+        args: &[JsValue],
+        operation: DifferenceOperation,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let zoned = Self::this_zoned(this, method)?;
+
+        // 2. Set other to ? ToTemporalZonedDateTime(other).
+        let (other, other_time_zone, _) =
+            to_temporal_zoned_date_time(args.get_or_undefined(0), None, context)?;
+
+        // 3. If ? CalendarEquals(zonedDateTime.[[Calendar]], other.[[Calendar]]) is false, then
+        //     a. Throw a RangeError exception.
+        // 4. Let settings be ? GetDifferenceSettings(operation, options, datetime, « », "nanosecond", "hour").
+        let settings = get_difference_settings(
+            operation,
+            args.get_or_undefined(1),
+            UnitGroup::DateTime,
+            TemporalUnit::Nanosecond,
+            TemporalUnit::Hour,
+            context,
+        )?;
+
+        // 5. If settings.[[LargestUnit]] is not one of "year", "month", "week", or "day", then
+        //     a-c. Return the rounded difference of the instants.
+        // 6. If ? TimeZoneEquals(zonedDateTime.[[TimeZone]], other.[[TimeZone]]) is false, then
+        //     a. Throw a RangeError exception.
+        if settings.largest_unit.is_date_unit() && TimeZone::kind_of(&other_time_zone) != zoned.kind {
+            return Err(JsNativeError::range()
+                .with_message("Temporal: the time zones must be equal to compute a difference in days or larger units")
+                .into());
+        }
+
+        // 7-11. Let difference be ? DifferenceZonedDateTime(zonedDateTime.[[Nanoseconds]], other.[[Nanoseconds]], zonedDateTime.[[TimeZone]], zonedDateTime.[[Calendar]], settings.[[LargestUnit]], untilOptions).
+        //       Let roundResult be (? RoundDuration(..., zonedDateTime)).[[DurationRecord]].
+        //       Let result be ? AdjustRoundedDurationDays(..., zonedDateTime).
+        let relative_to = RelativeTo::Zoned {
+            epoch_ns: zoned.epoch_ns,
+            time_zone: zoned.kind,
+        };
+        let result = relative_to.round(
+            other,
+            settings.largest_unit,
+            settings.smallest_unit,
+            settings.increment,
+            settings.mode,
+        )?;
+
+        // 12. Return ! CreateTemporalDuration(sign × result.[[Years]], ..., sign × result.[[Nanoseconds]]).
+        let result = match operation {
+            DifferenceOperation::Until => result,
+            DifferenceOperation::Since => result.negated(),
+        };
+        Ok(Duration::create(result, None, context)?.into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.until ( other [ , options ] )`
+    fn until(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::difference(this, args, DifferenceOperation::Until, "until", context)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.since ( other [ , options ] )`
+    fn since(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::difference(this, args, DifferenceOperation::Since, "since", context)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.round ( roundTo )`
+    fn round(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = Self::this_zoned(this, "round")?;
+
+        let round_to = match args.get_or_undefined(0).variant() {
+            // 3. If roundTo is undefined, then
+            JsVariant::Undefined => {
+                // a. Throw a TypeError exception.
+                return Err(JsNativeError::typ()
+                    .with_message("Temporal.ZonedDateTime.prototype.round: roundTo is required")
+                    .into());
+            }
+            // 4. If Type(roundTo) is String, then
+            JsVariant::String(string) => {
+                // a-c. Set roundTo to an object with the smallestUnit property set to paramString.
+                let round_to = get_options_object(&JsValue::undefined())?;
+                round_to.create_data_property_or_throw("smallestUnit", string.clone(), context)?;
+                round_to
+            }
+            // 5. Else,
+            //     a. Set roundTo to ? GetOptionsObject(roundTo).
+            _ => get_options_object(args.get_or_undefined(0))?,
+        };
+
+        // 6. NOTE: The following steps read options in alphabetical order.
+        // 7. Let roundingIncrement be ? ToTemporalRoundingIncrement(roundTo).
+        let increment = to_temporal_rounding_increment(&round_to, None, false, context)?;
+
+        // 8. Let roundingMode be ? ToTemporalRoundingMode(roundTo, "halfExpand").
+        let mode = to_temporal_rounding_mode(&round_to, RoundingMode::HalfExpand, context)?;
+
+        // 9. Let smallestUnit be ? GetTemporalUnit(roundTo, "smallestUnit", time, required, « "day" »).
+        let smallest_unit = get_temporal_unit(&round_to, "smallestUnit", UnitGroup::DateTime, false, context)?
+            .filter(|unit| !unit.is_calendar_unit())
+            .ok_or_else(|| {
+                JsNativeError::range().with_message(
+                    "Temporal.ZonedDateTime.prototype.round: smallestUnit must be a day or a time unit",
+                )
+            })?;
+
+        // 10-12. Perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, inclusive).
+        let valid = match smallest_unit.maximum_rounding_increment() {
+            None => increment <= 1.0,
+            Some(maximum) => increment < maximum && maximum % increment == 0.0,
+        };
+        if !valid {
+            return Err(JsNativeError::range()
+                .with_message("Temporal: invalid roundingIncrement for the smallestUnit")
+                .into());
+        }
+
+        // 13-17. Let temporalDateTime be ? BuiltinTimeZoneGetPlainDateTimeFor(timeZone, instant, calendar).
+        let date_time = zoned.date_time();
+
+        // 18-20. Let dtStart be ? CreateTemporalDateTime(temporalDateTime.[[ISOYear]], temporalDateTime.[[ISOMonth]], temporalDateTime.[[ISODay]], 0, 0, 0, 0, 0, 0, isoCalendar).
+        //        Let instantStart be ? BuiltinTimeZoneGetInstantFor(timeZone, dtStart, "compatible").
+        let start = start_of_day(zoned.kind, zoned.epoch_ns)?;
+
+        // 21. Let endNs be ? AddZonedDateTime(startNs, timeZone, calendar, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0).
+        let end = RelativeTo::Zoned {
+            epoch_ns: start,
+            time_zone: zoned.kind,
+        }
+        .add(&DurationRecord::from_date(0.0, 0.0, 0.0, 1.0), Overflow::Constrain)?;
+
+        // 22. Let dayLengthNs be ℝ(endNs - startNs).
+        // 23. If dayLengthNs ≤ 0, then
+        //     a. Throw a RangeError exception.
+        let day_length = end - start;
+        if day_length <= 0 {
+            return Err(JsNativeError::range()
+                .with_message("Temporal: the day has a non-positive length")
+                .into());
+        }
+
+        // 24. Let roundResult be ! RoundISODateTime(temporalDateTime.[[ISOYear]], ..., roundingIncrement, smallestUnit, roundingMode, dayLengthNs).
+        let rounded = date_time.round(increment, smallest_unit, mode, Some(day_length))?;
+
+        // 25. Let offsetNanoseconds be ? GetOffsetNanosecondsFor(timeZone, instant).
+        let offset_ns = zoned.kind.offset_ns(zoned.epoch_ns);
+
+        // 26. Let epochNanoseconds be ? InterpretISODateTimeOffset(roundResult.[[Year]], ..., option, offsetNanoseconds, timeZone, "compatible", "prefer", match exactly).
+        let epoch_ns = interpret_iso_date_time_offset(
+            rounded,
+            OffsetBehaviour::Option,
+            offset_ns,
+            zoned.kind,
+            Disambiguation::Compatible,
+            OffsetOption::Prefer,
+            false,
+        )?;
+
+        // 27. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+        Ok(Self::create(epoch_ns, zoned.time_zone, zoned.calendar, None, context)?.into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.equals ( other )`
+    fn equals(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = Self::this_zoned(this, "equals")?;
+
+        // 3. Set other to ? ToTemporalZonedDateTime(other).
+        let (other, other_time_zone, _) =
+            to_temporal_zoned_date_time(args.get_or_undefined(0), None, context)?;
+
+        // 4. If zonedDateTime.[[Nanoseconds]] ≠ other.[[Nanoseconds]], then
+        //     a. Return false.
+        // 5. If ? TimeZoneEquals(zonedDateTime.[[TimeZone]], other.[[TimeZone]]) is false, then
+        //     a. Return false.
+        // 6. Return ? CalendarEquals(zonedDateTime.[[Calendar]], other.[[Calendar]]).
+        Ok((zoned.epoch_ns == other && zoned.kind == TimeZone::kind_of(&other_time_zone)).into())
+    }
+
+    /// Abstract operation `TemporalZonedDateTimeToString ( zonedDateTime, precision, showCalendar, showTimeZone, showOffset [ , increment, unit, roundingMode ] )`
+    fn to_iso_string(
+        zoned: &Slots,
+        precision: Precision,
+        show_calendar: ShowCalendar,
+        show_time_zone: ShowTimeZone,
+        show_offset: ShowOffset,
+        rounding: Option<(f64, TemporalUnit, RoundingMode)>,
+    ) -> JsResult<String> {
+        // 1-4. Let ns be ! RoundTemporalInstant(zonedDateTime.[[Nanoseconds]], increment, unit, roundingMode).
+        let epoch_ns = match rounding {
+            Some((increment, unit, mode)) => {
+                let unit_ns = unit.nanoseconds().unwrap_or(1);
+                mode.round(zoned.epoch_ns, increment as i128 * unit_ns)
+            }
+            None => zoned.epoch_ns,
+        };
+        if !is_valid_epoch_ns(epoch_ns) {
+            return out_of_range();
+        }
+
+        // 5-8. Let offsetNs be ? GetOffsetNanosecondsFor(timeZone, instant).
+        let offset_ns = zoned.kind.offset_ns(epoch_ns);
+
+        // 9. Let temporalDateTime be ! BuiltinTimeZoneGetPlainDateTimeFor(timeZone, instant, isoCalendar).
+        let date_time = zoned.kind.plain_date_time_for(epoch_ns);
+
+        // 10. Let dateTimeString be ? TemporalDateTimeToString(temporalDateTime.[[ISOYear]], ..., isoCalendar, precision, "never").
+        let mut result = date_time.to_iso_string(precision);
+
+        // 11. If showOffset is "never", then
+        //     a. Let offsetString be the empty String.
+        // 12. Else,
+        //     a. Let offsetString be ! FormatISOTimeZoneOffsetString(offsetNs).
+        if show_offset != ShowOffset::Never {
+            result.push_str(&format_iso_time_zone_offset_string(offset_ns));
+        }
+
+        // 13. If showTimeZone is "never", then
+        //     a. Let timeZoneString be the empty String.
+        // 14. Else,
+        //     a. Let timeZoneID be ? ToString(timeZone).
+        //     b. If showTimeZone is "critical", let flag be "!"; else let flag be the empty String.
+        //     c. Let timeZoneString be the string-concatenation of "[", flag, timeZoneID, and "]".
+        match show_time_zone {
+            ShowTimeZone::Never => {}
+            ShowTimeZone::Auto => result.push_str(&format!("[{}]", zoned.kind.identifier())),
+            ShowTimeZone::Critical => result.push_str(&format!("[!{}]", zoned.kind.identifier())),
+        }
+
+        // 15. Let calendarString be ? MaybeFormatCalendarAnnotation(zonedDateTime.[[Calendar]], showCalendar).
+        result.push_str(format_calendar_annotation(show_calendar));
+
+        // 16. Return the string-concatenation of dateTimeString, offsetString, timeZoneString, and calendarString.
+        Ok(result)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toString ( [ options ] )`
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = Self::this_zoned(this, "toString")?;
+
+        // 3. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(0))?;
+
+        // 4. NOTE: The following steps read options in alphabetical order.
+        // 5. Let showCalendar be ? ToCalendarNameOption(options).
+        let show_calendar = to_calendar_name_option(&options, context)?;
+
+        // 6. Let precision be ? ToSecondsStringPrecision(options).
+        let precision = to_seconds_string_precision(&options, context)?;
+
+        // 7. Let showOffset be ? ToShowOffsetOption(options).
+        let show_offset = get_option(&options, "offset", context)?.unwrap_or(ShowOffset::Auto);
+
+        // 8. Let roundingMode be ? ToTemporalRoundingMode(options, "trunc").
+        let mode = to_temporal_rounding_mode(&options, RoundingMode::Trunc, context)?;
+
+        // 9. Let showTimeZone be ? ToTimeZoneNameOption(options).
+        let show_time_zone =
+            get_option(&options, "timeZoneName", context)?.unwrap_or(ShowTimeZone::Auto);
+
+        // 10. Return ? TemporalZonedDateTimeToString(zonedDateTime, precision.[[Precision]], showCalendar, showTimeZone, showOffset, precision.[[Increment]], precision.[[Unit]], roundingMode).
+        Ok(Self::to_iso_string(
+            &zoned,
+            precision.precision,
+            show_calendar,
+            show_time_zone,
+            show_offset,
+            Some((precision.increment, precision.unit, mode)),
+        )?
+        .into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toJSON ( )`
+    fn to_json(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = Self::this_zoned(this, "toJSON")?;
+
+        // 3. Return ? TemporalZonedDateTimeToString(zonedDateTime, "auto", "auto", "auto", "auto").
+        Ok(Self::to_iso_string(
+            &zoned,
+            Precision::Auto,
+            ShowCalendar::Auto,
+            ShowTimeZone::Auto,
+            ShowOffset::Auto,
+            None,
+        )?
+        .into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.startOfDay ( )`
+    fn start_of_day(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = Self::this_zoned(this, "startOfDay")?;
+
+        // 3-7. Let startDateTime be ? CreateTemporalDateTime(temporalDateTime.[[ISOYear]], temporalDateTime.[[ISOMonth]], temporalDateTime.[[ISODay]], 0, 0, 0, 0, 0, 0, calendar).
+        // 8. Let startInstant be ? BuiltinTimeZoneGetInstantFor(timeZone, startDateTime, "compatible").
+        let epoch_ns = start_of_day(zoned.kind, zoned.epoch_ns)?;
+
+        // 9. Return ! CreateTemporalZonedDateTime(startInstant.[[Nanoseconds]], timeZone, calendar).
+        Ok(Self::create(epoch_ns, zoned.time_zone, zoned.calendar, None, context)?.into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toInstant ( )`
+    fn to_instant(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = Self::this_zoned(this, "toInstant")?;
+
+        // 3. Return ! CreateTemporalInstant(zonedDateTime.[[Nanoseconds]]).
+        Ok(Instant::create(zoned.epoch_ns, None, context)?.into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toPlainDate ( )`
+    fn to_plain_date(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = Self::this_zoned(this, "toPlainDate")?;
+
+        // 3-6. Let temporalDateTime be ? BuiltinTimeZoneGetPlainDateTimeFor(timeZone, instant, calendar).
+        // 7. Return ! CreateTemporalDate(temporalDateTime.[[ISOYear]], temporalDateTime.[[ISOMonth]], temporalDateTime.[[ISODay]], calendar).
+        let date = zoned.date_time().date;
+        Ok(PlainDate::create(date, zoned.calendar, None, context)?.into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toPlainTime ( )`
+    fn to_plain_time(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = Self::this_zoned(this, "toPlainTime")?;
+
+        // 3-6. Let temporalDateTime be ? BuiltinTimeZoneGetPlainDateTimeFor(timeZone, instant, calendar).
+        // 7. Return ! CreateTemporalTime(temporalDateTime.[[ISOHour]], ..., temporalDateTime.[[ISONanosecond]]).
+        Ok(PlainTime::create(zoned.date_time().time, None, context)?.into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toPlainDateTime ( )`
+    fn to_plain_date_time(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = Self::this_zoned(this, "toPlainDateTime")?;
+
+        // 3-5. Return ? BuiltinTimeZoneGetPlainDateTimeFor(timeZone, instant, zonedDateTime.[[Calendar]]).
+        let date_time = zoned.date_time();
+        Ok(PlainDateTime::create(date_time, zoned.calendar, None, context)?.into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.getISOFields ( )`
+    fn get_iso_fields(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = Self::this_zoned(this, "getISOFields")?;
+
+        // 3. Let fields be OrdinaryObjectCreate(%Object.prototype%).
+        let fields = JsObject::from_proto_and_data(
+            context.intrinsics().constructors().object().prototype(),
+            ObjectData::ordinary(),
+        );
+
+        // 4-8. Let dateTime be ? BuiltinTimeZoneGetPlainDateTimeFor(timeZone, instant, calendar).
+        // 9. Let offset be ? BuiltinTimeZoneGetOffsetStringFor(timeZone, instant).
+        let IsoDateTime { date, time } = zoned.date_time();
+        let offset = format_time_zone_offset_string(zoned.kind.offset_ns(zoned.epoch_ns) as i64);
+
+        // 10-21. Perform ! CreateDataPropertyOrThrow(fields, "calendar", "isoDay", "isoHour",
+        //        "isoMicrosecond", "isoMillisecond", "isoMinute", "isoMonth", "isoNanosecond",
+        //        "isoSecond", "isoYear", "offset", "timeZone").
+        fields.create_data_property_or_throw("calendar", zoned.calendar, context)?;
+        fields.create_data_property_or_throw("isoDay", date.day, context)?;
+        fields.create_data_property_or_throw("isoHour", time.hour, context)?;
+        fields.create_data_property_or_throw("isoMicrosecond", time.microsecond, context)?;
+        fields.create_data_property_or_throw("isoMillisecond", time.millisecond, context)?;
+        fields.create_data_property_or_throw("isoMinute", time.minute, context)?;
+        fields.create_data_property_or_throw("isoMonth", date.month, context)?;
+        fields.create_data_property_or_throw("isoNanosecond", time.nanosecond, context)?;
+        fields.create_data_property_or_throw("isoSecond", time.second, context)?;
+        fields.create_data_property_or_throw("isoYear", date.year, context)?;
+        fields.create_data_property_or_throw("offset", offset, context)?;
+        fields.create_data_property_or_throw("timeZone", zoned.time_zone, context)?;
+
+        // 22. Return fields.
+        Ok(fields.into())
+    }
+}
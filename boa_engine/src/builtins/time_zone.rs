@@ -0,0 +1,57 @@
+//! The named time zones of the IANA time zone database, which is embedded in the engine.
+//!
+//! This is shared by the `Intl.DateTimeFormat` and the `Temporal` builtins.
+
+use chrono_tz::Tz;
+
+/// The identifiers of the `UTC` time zone.
+const UTC_ALIASES: [&str; 9] = [
+    "UTC",
+    "Etc/UTC",
+    "Etc/GMT",
+    "GMT",
+    "Etc/UCT",
+    "UCT",
+    "Etc/Universal",
+    "Universal",
+    "Etc/Zulu",
+];
+
+/// Gets the IANA time zone with the case-insensitive name `name`.
+///
+/// This is `IsValidTimeZoneName` and `CanonicalizeTimeZoneName`, where all the aliases of `UTC`
+/// are canonicalized to [`Tz::UTC`].
+pub(crate) fn named_time_zone(name: &str) -> Option<Tz> {
+    if UTC_ALIASES
+        .iter()
+        .any(|alias| alias.eq_ignore_ascii_case(name))
+    {
+        return Some(Tz::UTC);
+    }
+
+    chrono_tz::TZ_VARIANTS
+        .iter()
+        .find(|tz| tz.name().eq_ignore_ascii_case(name))
+        .copied()
+}
+
+/// Gets the IANA time zone of the host, from the `TZ` environment variable or the
+/// `/etc/localtime` link.
+///
+/// Returns `None` if neither names an IANA time zone, in which case the callers fall back to the
+/// current UTC offset of the host.
+pub(crate) fn host_time_zone() -> Option<Tz> {
+    let from_env = std::env::var("TZ")
+        .ok()
+        .map(|tz| tz.trim_start_matches(':').to_string());
+    let from_link = || {
+        std::fs::read_link("/etc/localtime").ok().and_then(|path| {
+            let path = path.to_string_lossy().into_owned();
+            path.split_once("zoneinfo/")
+                .map(|(_, name)| name.to_string())
+        })
+    };
+    from_env
+        .or_else(from_link)
+        .and_then(|name| named_time_zone(&name))
+}
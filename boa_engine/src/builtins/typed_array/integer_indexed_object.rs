@@ -9,7 +9,7 @@
 //! [spec]: https://tc39.es/ecma262/#sec-integer-indexed-exotic-objects
 
 use crate::{
-    builtins::typed_array::TypedArrayKind,
+    builtins::{typed_array::TypedArrayKind, ArrayBuffer},
     object::{JsObject, ObjectData},
     Context,
 };
@@ -31,6 +31,8 @@ pub struct IntegerIndexed {
     byte_offset: u64,
     byte_length: u64,
     array_length: u64,
+    resizable_buffer: bool,
+    length_tracking: bool,
 }
 
 impl IntegerIndexed {
@@ -41,15 +43,28 @@ impl IntegerIndexed {
         byte_length: u64,
         array_length: u64,
     ) -> Self {
+        let resizable_buffer = viewed_array_buffer
+            .as_ref()
+            .map_or(false, Self::is_resizable_buffer);
         Self {
             viewed_array_buffer,
             typed_array_name,
             byte_offset,
             byte_length,
             array_length,
+            resizable_buffer,
+            length_tracking: false,
         }
     }
 
+    /// Returns `true` if `buffer` is a resizable `ArrayBuffer`.
+    fn is_resizable_buffer(buffer: &JsObject) -> bool {
+        buffer
+            .borrow()
+            .as_array_buffer()
+            .map_or(false, |buffer| !buffer.is_fixed_length())
+    }
+
     /// `IntegerIndexedObjectCreate ( prototype )`
     ///
     /// Create a new `JsObject` from a prototype and a `IntegerIndexedObject`
@@ -100,6 +115,73 @@ impl IntegerIndexed {
         }
     }
 
+    /// Abstract operation `IsTypedArrayOutOfBounds ( taRecord )`.
+    ///
+    /// Checks if the viewed buffer is detached, or if it was shrunk so that the integer indexed
+    /// object doesn't fit in it anymore.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-istypedarrayoutofbounds
+    pub(crate) fn is_out_of_bounds(&self) -> bool {
+        // 1. Let O be taRecord.[[Object]].
+        // 2. Let bufferByteLength be taRecord.[[CachedBufferByteLength]].
+        // 3. Assert: IsDetachedBuffer(O.[[ViewedArrayBuffer]]) is true if and only if bufferByteLength is detached.
+        // 4. If bufferByteLength is detached, return true.
+        if self.is_detached() {
+            return true;
+        }
+
+        // The views of fixed length buffers can only go out of bounds by detaching the buffer.
+        if !self.resizable_buffer {
+            return false;
+        }
+        let buffer_byte_length = self.buffer_byte_length();
+
+        // 5. Let byteOffsetStart be O.[[ByteOffset]].
+        let byte_offset_start = self.byte_offset;
+
+        // 6. If O.[[ArrayLength]] is auto, then
+        let byte_offset_end = if self.length_tracking {
+            // a. Let byteOffsetEnd be bufferByteLength.
+            buffer_byte_length
+        // 7. Else,
+        } else {
+            // a. Let elementSize be TypedArrayElementSize(O).
+            // b. Let byteOffsetEnd be byteOffsetStart + O.[[ArrayLength]] × elementSize.
+            byte_offset_start + self.array_length * self.typed_array_name.element_size()
+        };
+
+        // 8. If byteOffsetStart > bufferByteLength or byteOffsetEnd > bufferByteLength, return true.
+        // 9. NOTE: 0-length TypedArrays are not considered out-of-bounds.
+        // 10. Return false.
+        byte_offset_start > buffer_byte_length || byte_offset_end > buffer_byte_length
+    }
+
+    /// Gets the current byte length of the viewed buffer.
+    fn buffer_byte_length(&self) -> u64 {
+        self.viewed_array_buffer
+            .as_ref()
+            .and_then(|obj| {
+                obj.borrow()
+                    .as_array_buffer()
+                    .map(ArrayBuffer::array_buffer_byte_length)
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if the length of the integer indexed object tracks the length of its
+    /// viewed buffer, i.e. if its `[[ArrayLength]]` is `auto`.
+    pub(crate) fn is_length_tracking(&self) -> bool {
+        self.length_tracking
+    }
+
+    /// Makes the length of the integer indexed object track the length of its viewed buffer.
+    pub(crate) fn set_length_tracking(&mut self, length_tracking: bool) {
+        self.length_tracking = length_tracking;
+    }
+
     /// Get the integer indexed object's byte offset.
     pub(crate) fn byte_offset(&self) -> u64 {
         self.byte_offset
@@ -122,12 +204,21 @@ impl IntegerIndexed {
 
     ///(crate) Set the integer indexed object's viewed array buffer.
     pub fn set_viewed_array_buffer(&mut self, viewed_array_buffer: Option<JsObject>) {
+        self.resizable_buffer = viewed_array_buffer
+            .as_ref()
+            .map_or(false, Self::is_resizable_buffer);
         self.viewed_array_buffer = viewed_array_buffer;
     }
 
     /// Get the integer indexed object's byte length.
+    ///
+    /// This is `TypedArrayByteLength`, which is `0` if the object is out of bounds of a resizable
+    /// buffer.
     pub fn byte_length(&self) -> u64 {
-        self.byte_length
+        if !self.resizable_buffer {
+            return self.byte_length;
+        }
+        self.array_length() * self.typed_array_name.element_size()
     }
 
     /// Set the integer indexed object's byte length.
@@ -136,8 +227,32 @@ impl IntegerIndexed {
     }
 
     /// Get the integer indexed object's array length.
+    ///
+    /// This is `TypedArrayLength`, which is `0` if the object is out of bounds of a resizable
+    /// buffer.
     pub fn array_length(&self) -> u64 {
-        self.array_length
+        if !self.resizable_buffer {
+            return self.array_length;
+        }
+
+        // 1. Assert: IsTypedArrayOutOfBounds(taRecord) is false.
+        if self.is_out_of_bounds() {
+            return 0;
+        }
+
+        // 2. Let O be taRecord.[[Object]].
+        // 3. If O.[[ArrayLength]] is not auto, return O.[[ArrayLength]].
+        if !self.length_tracking {
+            return self.array_length;
+        }
+
+        // 4. Assert: IsFixedLengthArrayBuffer(O.[[ViewedArrayBuffer]]) is false.
+        // 5. Let byteOffset be O.[[ByteOffset]].
+        // 6. Let elementSize be TypedArrayElementSize(O).
+        // 7. Let byteLength be taRecord.[[CachedBufferByteLength]].
+        // 8. Assert: byteLength is not detached.
+        // 9. Return floor((byteLength - byteOffset) / elementSize).
+        (self.buffer_byte_length() - self.byte_offset) / self.typed_array_name.element_size()
     }
 
     /// Set the integer indexed object's array length.
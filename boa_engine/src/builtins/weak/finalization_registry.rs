@@ -0,0 +1,312 @@
+//! This module implements the global `FinalizationRegistry` object.
+//!
+//! A `FinalizationRegistry` calls a cleanup callback after the objects registered in it are
+//! garbage collected.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-finalization-registry-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry
+
+use super::can_be_held_weakly;
+use crate::{
+    builtins::{BuiltIn, JsArgs},
+    context::intrinsics::StandardConstructors,
+    job::NativeJob,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsFunction, JsObject,
+        Object, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsNativeError, JsResult, JsValue,
+};
+use boa_gc::{Cell as GcCell, Finalize, Trace, WeakGc};
+use boa_profiler::Profiler;
+use tap::{Conv, Pipe};
+
+/// A target registered in a `FinalizationRegistry`.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct RegistryCell {
+    /// The registered object, held weakly.
+    target: WeakGc<GcCell<Object>>,
+    /// The value passed to the cleanup callback once `target` is collected.
+    held_value: JsValue,
+    /// The token used to unregister the cell, held weakly.
+    unregister_token: Option<WeakGc<GcCell<Object>>>,
+}
+
+/// The internal representation of a `FinalizationRegistry` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct FinalizationRegistry {
+    cleanup_callback: JsFunction,
+    cells: Vec<RegistryCell>,
+}
+
+impl BuiltIn for FinalizationRegistry {
+    const NAME: &'static str = "FinalizationRegistry";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context
+                .intrinsics()
+                .constructors()
+                .finalization_registry()
+                .clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(Self::register, "register", 2)
+        .method(Self::unregister, "unregister", 1)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+impl FinalizationRegistry {
+    pub(crate) const LENGTH: usize = 1;
+
+    /// `FinalizationRegistry ( cleanupCallback )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry-cleanup-callback
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/FinalizationRegistry
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "calling a builtin FinalizationRegistry constructor without new is forbidden",
+                )
+                .into());
+        }
+
+        // 2. If IsCallable(cleanupCallback) is false, throw a TypeError exception.
+        let cleanup_callback = args
+            .get_or_undefined(0)
+            .as_callable()
+            .cloned()
+            .map(JsFunction::from_object_unchecked)
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("FinalizationRegistry: cleanup must be callable")
+            })?;
+
+        // 3. Let finalizationRegistry be ? OrdinaryCreateFromConstructor(NewTarget, "%FinalizationRegistry.prototype%", « [[Realm]], [[CleanupCallback]], [[Cells]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::finalization_registry,
+            context,
+        )?;
+
+        // 4. Let fn be the active function object.
+        // 5. Set finalizationRegistry.[[Realm]] to fn.[[Realm]].
+        // 6. Set finalizationRegistry.[[CleanupCallback]] to HostMakeJobCallback(cleanupCallback).
+        // 7. Set finalizationRegistry.[[Cells]] to a new empty List.
+        let registry = JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::finalization_registry(Self {
+                cleanup_callback,
+                cells: Vec::new(),
+            }),
+        );
+        context.register_finalization_registry(&registry);
+
+        // 8. Return finalizationRegistry.
+        Ok(registry.into())
+    }
+
+    /// `FinalizationRegistry.prototype.register ( target, heldValue [ , unregisterToken ] )`
+    ///
+    /// Registers `target` in the registry, so the cleanup callback is called with `heldValue`
+    /// once it is collected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.register
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/register
+    pub(crate) fn register(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let target = args.get_or_undefined(0);
+        let held_value = args.get_or_undefined(1);
+        let unregister_token = args.get_or_undefined(2);
+
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let object = this.as_object();
+        let mut object = object.map(JsObject::borrow_mut);
+        let registry = object
+            .as_mut()
+            .and_then(|object| object.as_finalization_registry_mut())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "FinalizationRegistry.register: 'this' is not a FinalizationRegistry",
+                )
+            })?;
+
+        // 3. If CanBeHeldWeakly(target) is false, throw a TypeError exception.
+        let target = can_be_held_weakly(target).ok_or_else(|| {
+            JsNativeError::typ().with_message("FinalizationRegistry.register: invalid target")
+        })?;
+
+        // 4. If SameValue(target, heldValue) is true, throw a TypeError exception.
+        if matches!(held_value.as_object(), Some(held) if JsObject::equals(held, target)) {
+            return Err(JsNativeError::typ()
+                .with_message("FinalizationRegistry.register: target and held value are the same")
+                .into());
+        }
+
+        // 5. If CanBeHeldWeakly(unregisterToken) is false, then
+        //     a. If unregisterToken is not undefined, throw a TypeError exception.
+        //     b. Set unregisterToken to empty.
+        let unregister_token = match can_be_held_weakly(unregister_token) {
+            Some(token) => Some(WeakGc::new(token.inner())),
+            None if unregister_token.is_undefined() => None,
+            None => {
+                return Err(JsNativeError::typ()
+                    .with_message("FinalizationRegistry.register: invalid unregister token")
+                    .into())
+            }
+        };
+
+        // 6. Let cell be the Record { [[WeakRefTarget]]: target, [[HeldValue]]: heldValue, [[UnregisterToken]]: unregisterToken }.
+        // 7. Append cell to finalizationRegistry.[[Cells]].
+        registry.cells.push(RegistryCell {
+            target: WeakGc::new(target.inner()),
+            held_value: held_value.clone(),
+            unregister_token,
+        });
+
+        // 8. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `FinalizationRegistry.prototype.unregister ( unregisterToken )`
+    ///
+    /// Removes every target registered with `unregisterToken`, returning `true` if any was
+    /// removed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.unregister
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/unregister
+    pub(crate) fn unregister(
+        this: &JsValue,
+        args: &[JsValue],
+        _: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let object = this.as_object();
+        let mut object = object.map(JsObject::borrow_mut);
+        let registry = object
+            .as_mut()
+            .and_then(|object| object.as_finalization_registry_mut())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "FinalizationRegistry.unregister: 'this' is not a FinalizationRegistry",
+                )
+            })?;
+
+        // 3. If CanBeHeldWeakly(unregisterToken) is false, throw a TypeError exception.
+        let token = can_be_held_weakly(args.get_or_undefined(0)).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("FinalizationRegistry.unregister: invalid unregister token")
+        })?;
+
+        // 4. Let removed be false.
+        // 5. For each Record { [[WeakRefTarget]], [[HeldValue]], [[UnregisterToken]] } cell of finalizationRegistry.[[Cells]], do
+        //     a. If cell.[[UnregisterToken]] is not empty and SameValue(cell.[[UnregisterToken]], unregisterToken) is true, then
+        //         i. Remove cell from finalizationRegistry.[[Cells]].
+        //         ii. Set removed to true.
+        // 6. Return removed.
+        let len = registry.cells.len();
+        registry.cells.retain(|cell| {
+            !cell
+                .unregister_token
+                .as_ref()
+                .map_or(false, |cell_token| cell_token.ptr_eq(token.inner()))
+        });
+
+        Ok((registry.cells.len() != len).into())
+    }
+
+    /// Removes the cells whose target was collected, returning their held values.
+    fn take_collected_cells(&mut self) -> Vec<JsValue> {
+        let mut held_values = Vec::new();
+        self.cells.retain(|cell| {
+            if cell.target.is_alive() {
+                true
+            } else {
+                held_values.push(cell.held_value.clone());
+                false
+            }
+        });
+        held_values
+    }
+
+    /// Enqueues a cleanup job for every registry with targets that were collected.
+    ///
+    /// This is the engine side of `HostEnqueueFinalizationRegistryCleanupJob`, which the
+    /// context calls before running its jobs, since the collector cannot call into the engine
+    /// while collecting.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-cleanup-finalization-registry
+    pub(crate) fn enqueue_cleanup_jobs(registries: &[JsObject], context: &mut Context) {
+        for registry in registries {
+            let (callback, held_values) = {
+                let mut registry = registry.borrow_mut();
+                let registry = registry
+                    .as_finalization_registry_mut()
+                    .expect("registered object must be a FinalizationRegistry");
+                (
+                    registry.cleanup_callback.clone(),
+                    registry.take_collected_cells(),
+                )
+            };
+
+            if held_values.is_empty() {
+                continue;
+            }
+
+            // `CleanupFinalizationRegistry ( finalizationRegistry )`
+            let job = NativeJob::new(move |context| {
+                // 3. While finalizationRegistry.[[Cells]] contains a Record cell such that cell.[[WeakRefTarget]] is empty, ...
+                //     a. Choose any such cell.
+                //     b. Remove cell from finalizationRegistry.[[Cells]].
+                //     c. Perform ? HostCallJobCallback(callback, undefined, « cell.[[HeldValue]] »).
+                for held_value in held_values {
+                    callback.call(&JsValue::undefined(), &[held_value], context)?;
+                }
+
+                // 4. Return unused.
+                Ok(JsValue::undefined())
+            });
+            context.host_enqueue_promise_job(job);
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! This module implements the built-ins that hold weak references to objects: `WeakRef`,
+//! `WeakMap`, `WeakSet` and `FinalizationRegistry`.
+//!
+//! All of them are implemented on top of the ephemerons of `boa_gc`, so an object only referenced
+//! from them can be collected.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-managing-memory
+
+pub(crate) mod finalization_registry;
+pub(crate) mod weak_map;
+pub(crate) mod weak_ref;
+pub(crate) mod weak_set;
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) use self::{weak_map::WeakMap, weak_ref::WeakRef, weak_set::WeakSet};
+pub use finalization_registry::FinalizationRegistry;
+
+use crate::{object::JsObject, JsValue};
+
+/// Abstract operation `CanBeHeldWeakly ( v )`
+///
+/// Returns the object that `value` refers to, if it can be the target of a weak reference.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-canbeheldweakly
+fn can_be_held_weakly(value: &JsValue) -> Option<&JsObject> {
+    value.as_object()
+}
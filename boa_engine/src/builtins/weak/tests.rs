@@ -0,0 +1,151 @@
+use crate::{forward, Context};
+
+#[test]
+fn weak_ref_deref() {
+    let mut context = Context::default();
+    let init = r"
+        var target = { value: 1 };
+        var weak = new WeakRef(target);
+        ";
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "weak.deref() === target"), "true");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(weak)"),
+        "\"[object WeakRef]\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { new WeakRef(1) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn weak_ref_collected_target() {
+    let mut context = Context::default();
+    let init = r"
+        var weak = (() => new WeakRef({}))();
+        ";
+    forward(&mut context, init);
+
+    // The kept objects are cleared once the script finishes.
+    boa_gc::force_collect();
+    assert_eq!(forward(&mut context, "weak.deref()"), "undefined");
+}
+
+#[test]
+fn weak_map_entries() {
+    let mut context = Context::default();
+    let init = r#"
+        var key = {};
+        var map = new WeakMap([[key, "value"]]);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "map.get(key)"), "\"value\"");
+    assert_eq!(forward(&mut context, "map.has({})"), "false");
+    assert_eq!(forward(&mut context, "map.set(key, 2) === map"), "true");
+    assert_eq!(forward(&mut context, "map.get(key)"), "2");
+    assert_eq!(forward(&mut context, "map.delete(key)"), "true");
+    assert_eq!(forward(&mut context, "map.has(key)"), "false");
+    assert_eq!(forward(&mut context, "map.get(1)"), "undefined");
+    assert_eq!(
+        forward(&mut context, "try { map.set(1, 2) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn weak_map_does_not_keep_keys_alive() {
+    let mut context = Context::default();
+    let init = r"
+        var map = new WeakMap();
+        var weak = (() => {
+            const key = {};
+            map.set(key, { payload: key });
+            return new WeakRef(key);
+        })();
+        ";
+    forward(&mut context, init);
+
+    boa_gc::force_collect();
+    assert_eq!(forward(&mut context, "weak.deref()"), "undefined");
+}
+
+#[test]
+fn weak_set_values() {
+    let mut context = Context::default();
+    let init = r"
+        var value = {};
+        var set = new WeakSet([value]);
+        ";
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "set.has(value)"), "true");
+    assert_eq!(forward(&mut context, "set.add(value) === set"), "true");
+    assert_eq!(forward(&mut context, "set.delete(value)"), "true");
+    assert_eq!(forward(&mut context, "set.delete(value)"), "false");
+    assert_eq!(forward(&mut context, "set.has(1)"), "false");
+    assert_eq!(
+        forward(&mut context, "try { set.add(1) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn finalization_registry_cleanup() {
+    let mut context = Context::default();
+    let init = r#"
+        var cleaned = [];
+        var registry = new FinalizationRegistry(held => cleaned.push(held));
+        var token = {};
+        (() => {
+            registry.register({}, "collected");
+            registry.register({}, "unregistered", token);
+        })();
+        var kept = {};
+        registry.register(kept, "kept");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "registry.unregister(token)"), "true");
+    assert_eq!(forward(&mut context, "registry.unregister(token)"), "false");
+
+    boa_gc::force_collect();
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "cleaned.join()"), "\"collected\"");
+}
+
+#[test]
+fn finalization_registry_register_errors() {
+    let mut context = Context::default();
+    let init = r"
+        var registry = new FinalizationRegistry(() => {});
+        var target = {};
+        ";
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { registry.register(1, 'held') } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { registry.register(target, target) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { registry.register(target, 'held', 1) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new FinalizationRegistry(1) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
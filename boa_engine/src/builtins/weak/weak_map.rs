@@ -0,0 +1,248 @@
+//! This module implements the global `WeakMap` object.
+//!
+//! A `WeakMap` is a collection of key/value pairs whose keys are objects held weakly, so an entry
+//! is removed once its key is garbage collected.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weakmap-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap
+
+use super::can_be_held_weakly;
+use crate::{
+    builtins::{map::add_entries_from_iterable, BuiltIn, JsArgs},
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsNativeError, JsResult, JsValue,
+};
+use boa_profiler::Profiler;
+use tap::{Conv, Pipe};
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakMap;
+
+impl BuiltIn for WeakMap {
+    const NAME: &'static str = "WeakMap";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().weak_map().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(Self::delete, "delete", 1)
+        .method(Self::get, "get", 1)
+        .method(Self::has, "has", 1)
+        .method(Self::set, "set", 2)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+impl WeakMap {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `WeakMap ( [ iterable ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap-iterable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/WeakMap
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("calling a builtin WeakMap constructor without new is forbidden")
+                .into());
+        }
+
+        // 2. Let map be ? OrdinaryCreateFromConstructor(NewTarget, "%WeakMap.prototype%", « [[WeakMapData]] »).
+        // 3. Set map.[[WeakMapData]] to a new empty List.
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardConstructors::weak_map, context)?;
+        let map = JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::weak_map(boa_gc::WeakMap::default()),
+        );
+
+        // 4. If iterable is either undefined or null, return map.
+        let iterable = match args.get_or_undefined(0) {
+            val if !val.is_null_or_undefined() => val,
+            _ => return Ok(map.into()),
+        };
+
+        // 5. Let adder be ? Get(map, "set").
+        let adder = map.get("set", context)?;
+
+        // 6. Return ? AddEntriesFromIterable(map, iterable, adder).
+        add_entries_from_iterable(&map, iterable, &adder, context)
+    }
+
+    /// `WeakMap.prototype.delete ( key )`
+    ///
+    /// Removes the entry of `key` from the map, returning `true` if it existed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/delete
+    pub(crate) fn delete(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let object = this.as_object();
+        let mut object = object.map(JsObject::borrow_mut);
+        let map = object
+            .as_mut()
+            .and_then(|object| object.as_weak_map_mut())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("WeakMap.delete: 'this' is not a WeakMap")
+            })?;
+
+        // 3. Let entries be the List that is M.[[WeakMapData]].
+        // 4. If CanBeHeldWeakly(key) is false, return false.
+        let key = match can_be_held_weakly(args.get_or_undefined(0)) {
+            Some(key) => key,
+            None => return Ok(false.into()),
+        };
+
+        // 5. For each Record { [[Key]], [[Value]] } p of entries, do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, then
+        //         i. Set p.[[Key]] to empty.
+        //         ii. Set p.[[Value]] to empty.
+        //         iii. Return true.
+        // 6. Return false.
+        Ok(map.remove(key.inner()).is_some().into())
+    }
+
+    /// `WeakMap.prototype.get ( key )`
+    ///
+    /// Returns the value of `key` in the map, or `undefined` if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.get
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/get
+    pub(crate) fn get(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let object = this.as_object().map(JsObject::borrow);
+        let map = object
+            .as_ref()
+            .and_then(|object| object.as_weak_map())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("WeakMap.get: 'this' is not a WeakMap")
+            })?;
+
+        // 3. Let entries be the List that is M.[[WeakMapData]].
+        // 4. If CanBeHeldWeakly(key) is false, return undefined.
+        let key = match can_be_held_weakly(args.get_or_undefined(0)) {
+            Some(key) => key,
+            None => return Ok(JsValue::undefined()),
+        };
+
+        // 5. For each Record { [[Key]], [[Value]] } p of entries, do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, return p.[[Value]].
+        // 6. Return undefined.
+        Ok(map.get(key.inner()).unwrap_or_default())
+    }
+
+    /// `WeakMap.prototype.has ( key )`
+    ///
+    /// Returns `true` if the map contains an entry for `key`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/has
+    pub(crate) fn has(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let object = this.as_object().map(JsObject::borrow);
+        let map = object
+            .as_ref()
+            .and_then(|object| object.as_weak_map())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("WeakMap.has: 'this' is not a WeakMap")
+            })?;
+
+        // 3. Let entries be the List that is M.[[WeakMapData]].
+        // 4. If CanBeHeldWeakly(key) is false, return false.
+        let key = match can_be_held_weakly(args.get_or_undefined(0)) {
+            Some(key) => key,
+            None => return Ok(false.into()),
+        };
+
+        // 5. For each Record { [[Key]], [[Value]] } p of entries, do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, return true.
+        // 6. Return false.
+        Ok(map.contains_key(key.inner()).into())
+    }
+
+    /// `WeakMap.prototype.set ( key, value )`
+    ///
+    /// Sets the value of `key` in the map, returning the map.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.set
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/set
+    pub(crate) fn set(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let object = this.as_object();
+        let mut object = object.map(JsObject::borrow_mut);
+        let map = object
+            .as_mut()
+            .and_then(|object| object.as_weak_map_mut())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("WeakMap.set: 'this' is not a WeakMap")
+            })?;
+
+        // 3. Let entries be the List that is M.[[WeakMapData]].
+        // 4. If CanBeHeldWeakly(key) is false, throw a TypeError exception.
+        let key = can_be_held_weakly(args.get_or_undefined(0)).ok_or_else(|| {
+            JsNativeError::typ().with_message("WeakMap.set: invalid value used as weak map key")
+        })?;
+
+        // 5. For each Record { [[Key]], [[Value]] } p of entries, do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, then
+        //         i. Set p.[[Value]] to value.
+        //         ii. Return M.
+        // 6. Let p be the Record { [[Key]]: key, [[Value]]: value }.
+        // 7. Append p to entries.
+        map.insert(key.inner(), args.get_or_undefined(1).clone());
+
+        // 8. Return M.
+        Ok(this.clone())
+    }
+}
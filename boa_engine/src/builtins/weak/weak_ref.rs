@@ -0,0 +1,139 @@
+//! This module implements the global `WeakRef` object.
+//!
+//! A `WeakRef` holds a weak reference to an object, which doesn't prevent the object from being
+//! garbage collected.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weak-ref-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef
+
+use super::can_be_held_weakly;
+use crate::{
+    builtins::{BuiltIn, JsArgs},
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsNativeError, JsResult, JsValue,
+};
+use boa_gc::WeakGc;
+use boa_profiler::Profiler;
+use tap::{Conv, Pipe};
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakRef;
+
+impl BuiltIn for WeakRef {
+    const NAME: &'static str = "WeakRef";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().weak_ref().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(Self::deref, "deref", 0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+impl WeakRef {
+    pub(crate) const LENGTH: usize = 1;
+
+    /// `WeakRef ( target )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weak-ref-target
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef/WeakRef
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("calling a builtin WeakRef constructor without new is forbidden")
+                .into());
+        }
+
+        // 2. If CanBeHeldWeakly(target) is false, throw a TypeError exception.
+        let target = can_be_held_weakly(args.get_or_undefined(0)).ok_or_else(|| {
+            JsNativeError::typ().with_message("WeakRef: target must be an object")
+        })?;
+
+        // 3. Let weakRef be ? OrdinaryCreateFromConstructor(NewTarget, "%WeakRef.prototype%", « [[WeakRefTarget]] »).
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardConstructors::weak_ref, context)?;
+
+        // 5. Set weakRef.[[WeakRefTarget]] to target.
+        let weak_ref = JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::weak_ref(WeakGc::new(target.inner())),
+        );
+
+        // 4. Perform AddToKeptObjects(target).
+        context.add_to_kept_objects(target.clone());
+
+        // 6. Return weakRef.
+        Ok(weak_ref.into())
+    }
+
+    /// `WeakRef.prototype.deref ( )`
+    ///
+    /// Returns the target of the `WeakRef`, or `undefined` if it was collected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weak-ref.prototype.deref
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef/deref
+    pub(crate) fn deref(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let weakRef be the this value.
+        // 2. Perform ? RequireInternalSlot(weakRef, [[WeakRefTarget]]).
+        let target = this
+            .as_object()
+            .and_then(|object| object.borrow().as_weak_ref().map(WeakGc::upgrade))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("WeakRef.prototype.deref: 'this' is not a WeakRef")
+            })?;
+
+        // 3. Return WeakRefDeref(weakRef).
+        // `WeakRefDeref ( weakRef )`
+        // 1. Let target be weakRef.[[WeakRefTarget]].
+        // 2. If target is not empty, then
+        if let Some(target) = target {
+            let target = JsObject::from(target);
+
+            // a. Perform AddToKeptObjects(target).
+            context.add_to_kept_objects(target.clone());
+
+            // b. Return target.
+            return Ok(target.into());
+        }
+
+        // 3. Return undefined.
+        Ok(JsValue::undefined())
+    }
+}
@@ -3,20 +3,21 @@ use crate::{
     bytecompiler::{ByteCompiler, FunctionKind},
     syntax::ast::node::{Declaration, FormalParameterList, StatementList},
     vm::{BindingOpcode, CodeBlock, Opcode},
-    Context, JsResult,
+    Context, JsResult, JsString,
 };
 use boa_gc::Gc;
 use boa_interner::Sym;
 use rustc_hash::FxHashMap;
 
 /// `FunctionCompiler` is used to compile AST functions to bytecode.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub(crate) struct FunctionCompiler {
     name: Sym,
     generator: bool,
     r#async: bool,
     strict: bool,
     kind: FunctionKind,
+    source_name: Option<JsString>,
 }
 
 impl FunctionCompiler {
@@ -29,6 +30,7 @@ impl FunctionCompiler {
             r#async: false,
             strict: false,
             kind: FunctionKind::Declaration,
+            source_name: None,
         }
     }
 
@@ -73,6 +75,13 @@ impl FunctionCompiler {
         self
     }
 
+    /// Set the name of the script or module the function is defined in.
+    #[inline]
+    pub(crate) fn source_name(mut self, source_name: Option<JsString>) -> Self {
+        self.source_name = source_name;
+        self
+    }
+
     /// Compile a function statement list and it's parameters into bytecode.
     pub(crate) fn compile(
         mut self,
@@ -84,6 +93,7 @@ impl FunctionCompiler {
 
         let length = parameters.length();
         let mut code = CodeBlock::new(self.name, length, self.strict);
+        code.source_name = self.source_name.take();
 
         if self.kind == FunctionKind::Arrow {
             code.this_mode = ThisMode::Lexical;
@@ -96,6 +106,7 @@ impl FunctionCompiler {
             bindings_map: FxHashMap::default(),
             jump_info: Vec::new(),
             in_async_generator: self.generator && self.r#async,
+            allow_tail_calls: self.strict && !self.generator && !self.r#async,
             context,
         };
 
@@ -162,7 +173,7 @@ impl FunctionCompiler {
         }
 
         compiler.create_declarations(body.items())?;
-        compiler.compile_statement_list(body.items(), false)?;
+        compiler.compile_statement_list(body, false)?;
 
         if let Some(env_label) = env_label {
             let (num_bindings, compile_environment) =
@@ -1,4 +1,8 @@
 mod function;
+mod optimizer;
+
+#[cfg(test)]
+mod tests;
 
 use crate::{
     environments::{BindingLocator, CompileTimeEnvironment},
@@ -12,20 +16,22 @@ use crate::{
             object::{MethodDefinition, PropertyDefinition, PropertyName},
             operator::assign::AssignTarget,
             template::TemplateElement,
-            Class, Declaration, GetConstField, GetField, GetSuperField,
+            Class, Declaration, ExportDeclaration, GetConstField, GetField, GetSuperField,
+            ModuleItem, ModuleItemList, StatementList,
         },
         op::{AssignOp, BinOp, BitOp, CompOp, LogOp, NumOp, UnaryOp},
-        Const, Node,
+        Const, Node, Span,
     },
-    vm::{BindingOpcode, CodeBlock, Opcode},
-    Context, JsBigInt, JsResult, JsString, JsValue,
+    vm::{BindingOpcode, CodeBlock, InlineCache, Opcode},
+    Context, JsBigInt, JsNativeError, JsResult, JsString, JsValue,
 };
 use boa_gc::Gc;
 use boa_interner::{Interner, Sym};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::mem::size_of;
 
 pub(crate) use function::FunctionCompiler;
+pub use optimizer::OptimizationLevel;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum Literal {
@@ -76,6 +82,7 @@ pub struct ByteCompiler<'b> {
     bindings_map: FxHashMap<BindingLocator, u32>,
     jump_info: Vec<JumpControlInfo>,
     in_async_generator: bool,
+    allow_tail_calls: bool,
     context: &'b mut Context,
 }
 
@@ -92,10 +99,17 @@ impl<'b> ByteCompiler<'b> {
             bindings_map: FxHashMap::default(),
             jump_info: Vec::new(),
             in_async_generator: false,
+            allow_tail_calls: false,
             context,
         }
     }
 
+    /// Sets the name of the script or module that is compiled.
+    #[inline]
+    pub fn set_source_name(&mut self, source_name: Option<JsString>) {
+        self.code_block.source_name = source_name;
+    }
+
     #[inline]
     fn interner(&self) -> &Interner {
         self.context.interner()
@@ -141,6 +155,26 @@ impl<'b> ByteCompiler<'b> {
         index
     }
 
+    #[inline]
+    fn insert_inline_cache(&mut self, name: Sym) -> u32 {
+        let index = self.code_block.inline_caches.len() as u32;
+        let key = JsString::from(&*self.interner().resolve_expect_utf16(name));
+        self.code_block
+            .inline_caches
+            .push(InlineCache::new(name, key));
+        index
+    }
+
+    /// Creates a `var` scoped binding, remembering the names that are bound on the global
+    /// object, since the bindings on the global object are created at compile time.
+    #[inline]
+    fn create_var_binding(&mut self, name: Sym) {
+        self.context.create_mutable_binding(name, true);
+        if !self.context.has_binding(name) {
+            self.code_block.global_var_names.push(name);
+        }
+    }
+
     #[inline]
     fn get_or_insert_binding(&mut self, binding: BindingLocator) -> u32 {
         if let Some(index) = self.bindings_map.get(&binding) {
@@ -204,6 +238,19 @@ impl<'b> ByteCompiler<'b> {
         self.code_block.code.len() as u32
     }
 
+    /// Maps the next emitted instructions to the start of the given source span.
+    #[inline]
+    fn emit_position(&mut self, span: Option<Span>) {
+        if let Some(span) = span {
+            let location = self.next_opcode_location();
+            match self.code_block.positions.last_mut() {
+                Some((last, position)) if *last == location => *position = span.start(),
+                Some((_, position)) if *position == span.start() => {}
+                _ => self.code_block.positions.push((location, span.start())),
+            }
+        }
+    }
+
     #[inline]
     fn emit(&mut self, opcode: Opcode, operands: &[u32]) {
         self.emit_opcode(opcode);
@@ -273,9 +320,9 @@ impl<'b> ByteCompiler<'b> {
             return self.emit_opcode(Opcode::PushNegativeInfinity);
         }
 
-        // Check if the f64 value can fit in an i32.
+        // Check if the f64 value can fit in an i32, which cannot hold negative zero.
         #[allow(clippy::float_cmp)]
-        if f64::from(value as i32) == value {
+        if f64::from(value as i32) == value && !(value == 0.0 && value.is_sign_negative()) {
             self.emit_push_integer(value as i32);
         } else {
             self.emit_opcode(Opcode::PushRational);
@@ -542,7 +589,7 @@ impl<'b> ByteCompiler<'b> {
                 self.emit(Opcode::GetName, &[index]);
             }
             Access::ByName { node } => {
-                let index = self.get_or_insert_name(node.field());
+                let index = self.insert_inline_cache(node.field());
                 self.compile_expr(node.obj(), true)?;
                 self.emit(Opcode::GetPropertyByName, &[index]);
             }
@@ -585,7 +632,7 @@ impl<'b> ByteCompiler<'b> {
             }
             Access::ByName { node } => {
                 self.compile_expr(node.obj(), true)?;
-                let index = self.get_or_insert_name(node.field());
+                let index = self.insert_inline_cache(node.field());
                 self.emit(Opcode::SetPropertyByName, &[index]);
             }
             Access::ByValue { node } => {
@@ -599,12 +646,100 @@ impl<'b> ByteCompiler<'b> {
     }
 
     #[inline]
-    pub fn compile_statement_list(&mut self, list: &[Node], use_expr: bool) -> JsResult<()> {
-        if let Some((last, items)) = list.split_last() {
-            for node in items {
-                self.compile_stmt(node, false)?;
+    pub fn compile_statement_list(&mut self, list: &StatementList, use_expr: bool) -> JsResult<()> {
+        let items = list.items();
+        let mut reachable = true;
+        for (index, node) in items.iter().enumerate() {
+            let use_expr = use_expr && index + 1 == items.len();
+            if !reachable && optimizer::is_removable(node) {
+                self.compile_discarded(|compiler| compiler.compile_stmt(node, use_expr))?;
+                continue;
+            }
+            self.emit_position(list.span(index));
+            self.compile_stmt(node, use_expr)?;
+            if self.optimizes_ast() && optimizer::ends_control_flow(node) {
+                reachable = false;
+            }
+        }
+        Ok(())
+    }
+
+    /// Compiles the instantiation of the `var` declarations and the hoisted function
+    /// declarations of a module.
+    ///
+    /// This also creates the bindings of all declarations of the module in the current
+    /// compile time environment, which must be the module environment.
+    pub(crate) fn compile_module_declarations(&mut self, module: &ModuleItemList) -> JsResult<()> {
+        let mut vars = FxHashSet::default();
+        module.var_declared_names(&mut vars);
+        for var in &vars {
+            self.create_var_binding(*var);
+        }
+
+        let statements = module.statements();
+        for node in &statements {
+            self.create_decls_from_stmt(node)?;
+        }
+
+        let has_default_expression = module.items().iter().any(|item| {
+            matches!(
+                item,
+                ModuleItem::ExportDeclaration(ExportDeclaration::DefaultAssignmentExpression(_))
+            )
+        });
+        if has_default_expression {
+            self.context.create_mutable_binding(Sym::DEFAULT, false);
+        }
+
+        for var in vars {
+            self.emit_binding(BindingOpcode::Var, var);
+        }
+
+        for node in statements {
+            if matches!(
+                node,
+                Node::FunctionDecl(_)
+                    | Node::GeneratorDecl(_)
+                    | Node::AsyncFunctionDecl(_)
+                    | Node::AsyncGeneratorDecl(_)
+            ) {
+                self.function(node, false)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Compiles the body of a module.
+    ///
+    /// Import declarations, re-exports and function declarations are skipped, since they
+    /// are handled when the module environment is initialized.
+    pub(crate) fn compile_module_body(&mut self, module: &ModuleItemList) -> JsResult<()> {
+        for (index, item) in module.items().iter().enumerate() {
+            self.emit_position(module.span(index));
+            match item {
+                ModuleItem::ExportDeclaration(ExportDeclaration::DefaultAssignmentExpression(
+                    expr,
+                )) => {
+                    self.compile_expr(expr, true)?;
+                    self.emit_binding(BindingOpcode::InitLet, Sym::DEFAULT);
+                }
+                ModuleItem::ExportDeclaration(
+                    ExportDeclaration::Declaration(node)
+                    | ExportDeclaration::DefaultDeclaration(node),
+                )
+                | ModuleItem::StatementListItem(node) => match node {
+                    Node::FunctionDecl(_)
+                    | Node::GeneratorDecl(_)
+                    | Node::AsyncFunctionDecl(_)
+                    | Node::AsyncGeneratorDecl(_) => {}
+                    node => self.compile_stmt(node, false)?,
+                },
+                ModuleItem::ImportDeclaration(_)
+                | ModuleItem::ExportDeclaration(
+                    ExportDeclaration::ReExport { .. } | ExportDeclaration::List(_),
+                ) => {}
             }
-            self.compile_stmt(last, use_expr)?;
         }
         Ok(())
     }
@@ -613,21 +748,15 @@ impl<'b> ByteCompiler<'b> {
     #[inline]
     pub(crate) fn compile_statement_list_with_new_declarative(
         &mut self,
-        list: &[Node],
+        list: &StatementList,
         use_expr: bool,
         strict: bool,
     ) -> JsResult<()> {
         self.context.push_compile_time_environment(strict);
         let push_env = self.emit_opcode_with_two_operands(Opcode::PushDeclarativeEnvironment);
 
-        self.create_declarations(list)?;
-
-        if let Some((last, items)) = list.split_last() {
-            for node in items {
-                self.compile_stmt(node, false)?;
-            }
-            self.compile_stmt(last, use_expr)?;
-        }
+        self.create_declarations(list.items())?;
+        self.compile_statement_list(list, use_expr)?;
 
         let (num_bindings, compile_environment) = self.context.pop_compile_time_environment();
         let index_compile_environment = self.push_compile_environment(compile_environment);
@@ -640,12 +769,16 @@ impl<'b> ByteCompiler<'b> {
 
     #[inline]
     pub fn compile_expr(&mut self, expr: &Node, use_expr: bool) -> JsResult<()> {
+        if self.optimizes_ast() && self.compile_folded_expr(expr, use_expr)? {
+            return Ok(());
+        }
+
         match expr {
             Node::Const(c) => {
                 match c {
-                    Const::String(v) => self.emit_push_literal(Literal::String(
-                        self.interner().resolve_expect(*v).into(),
-                    )),
+                    Const::String(v) => self.emit_push_literal(Literal::String(JsString::from(
+                        &*self.interner().resolve_expect_utf16(*v),
+                    ))),
                     Const::Int(v) => self.emit_push_integer(*v),
                     Const::Num(v) => self.emit_push_rational(*v),
                     Const::BigInt(v) => self.emit_push_literal(Literal::BigInt(v.clone().into())),
@@ -666,8 +799,7 @@ impl<'b> ByteCompiler<'b> {
                         self.emit(Opcode::Inc, &[]);
 
                         let access = Self::compile_access(unary.target()).ok_or_else(|| {
-                            self.context
-                                .construct_syntax_error("Invalid increment operand")
+                            JsNativeError::syntax().with_message("Invalid increment operand")
                         })?;
                         self.access_set(access, None, true)?;
                         None
@@ -677,8 +809,7 @@ impl<'b> ByteCompiler<'b> {
                         self.emit(Opcode::Dec, &[]);
 
                         let access = Self::compile_access(unary.target()).ok_or_else(|| {
-                            self.context
-                                .construct_syntax_error("Invalid decrement operand")
+                            JsNativeError::syntax().with_message("Invalid decrement operand")
                         })?;
                         self.access_set(access, None, true)?;
                         None
@@ -688,8 +819,7 @@ impl<'b> ByteCompiler<'b> {
                         self.emit(Opcode::IncPost, &[]);
 
                         let access = Self::compile_access(unary.target()).ok_or_else(|| {
-                            self.context
-                                .construct_syntax_error("Invalid increment operand")
+                            JsNativeError::syntax().with_message("Invalid increment operand")
                         })?;
                         self.access_set(access, None, false)?;
 
@@ -700,8 +830,7 @@ impl<'b> ByteCompiler<'b> {
                         self.emit(Opcode::DecPost, &[]);
 
                         let access = Self::compile_access(unary.target()).ok_or_else(|| {
-                            self.context
-                                .construct_syntax_error("Invalid decrement operand")
+                            JsNativeError::syntax().with_message("Invalid decrement operand")
                         })?;
                         self.access_set(access, None, false)?;
 
@@ -852,9 +981,8 @@ impl<'b> ByteCompiler<'b> {
                                 self.compile_expr(binary.rhs(), true)?;
                                 let access =
                                     Self::compile_access(binary.lhs()).ok_or_else(|| {
-                                        self.context.construct_syntax_error(
-                                            "Invalid left-hand side in assignment",
-                                        )
+                                        JsNativeError::syntax()
+                                            .with_message("Invalid left-hand side in assignment")
                                     })?;
                                 self.access_set(access, None, use_expr)?;
                                 self.patch_jump(exit);
@@ -865,9 +993,8 @@ impl<'b> ByteCompiler<'b> {
                                 self.compile_expr(binary.rhs(), true)?;
                                 let access =
                                     Self::compile_access(binary.lhs()).ok_or_else(|| {
-                                        self.context.construct_syntax_error(
-                                            "Invalid left-hand side in assignment",
-                                        )
+                                        JsNativeError::syntax()
+                                            .with_message("Invalid left-hand side in assignment")
                                     })?;
                                 self.access_set(access, None, use_expr)?;
                                 self.patch_jump(exit);
@@ -878,9 +1005,8 @@ impl<'b> ByteCompiler<'b> {
                                 self.compile_expr(binary.rhs(), true)?;
                                 let access =
                                     Self::compile_access(binary.lhs()).ok_or_else(|| {
-                                        self.context.construct_syntax_error(
-                                            "Invalid left-hand side in assignment",
-                                        )
+                                        JsNativeError::syntax()
+                                            .with_message("Invalid left-hand side in assignment")
                                     })?;
                                 self.access_set(access, None, use_expr)?;
                                 self.patch_jump(exit);
@@ -892,8 +1018,8 @@ impl<'b> ByteCompiler<'b> {
                             self.compile_expr(binary.rhs(), true)?;
                             self.emit(opcode, &[]);
                             let access = Self::compile_access(binary.lhs()).ok_or_else(|| {
-                                self.context
-                                    .construct_syntax_error("Invalid left-hand side in assignment")
+                                JsNativeError::syntax()
+                                    .with_message("Invalid left-hand side in assignment")
                             })?;
                             self.access_set(access, None, use_expr)?;
                         }
@@ -1024,9 +1150,9 @@ impl<'b> ByteCompiler<'b> {
                             self.emit_opcode(Opcode::Pop);
                         }
                         PropertyDefinition::CoverInitializedName(_, _) => {
-                            return self.context.throw_syntax_error(
-                                "invalid assignment pattern in object literal",
-                            );
+                            return Err(JsNativeError::syntax()
+                                .with_message("invalid assignment pattern in object literal")
+                                .into());
                         }
                     }
                 }
@@ -1084,7 +1210,7 @@ impl<'b> ByteCompiler<'b> {
             }
             Node::GetSuperField(get_super_field) => match get_super_field {
                 GetSuperField::Const(field) => {
-                    let index = self.get_or_insert_name(*field);
+                    let index = self.insert_inline_cache(*field);
                     self.emit_opcode(Opcode::Super);
                     self.emit(Opcode::GetPropertyByName, &[index]);
                     if !use_expr {
@@ -1148,7 +1274,7 @@ impl<'b> ByteCompiler<'b> {
                 for element in template_literal.elements() {
                     match element {
                         TemplateElement::String(s) => self.emit_push_literal(Literal::String(
-                            self.interner().resolve_expect(*s).into(),
+                            JsString::from(&*self.interner().resolve_expect_utf16(*s)),
                         )),
                         TemplateElement::Expr(expr) => {
                             self.compile_expr(expr, true)?;
@@ -1219,7 +1345,7 @@ impl<'b> ByteCompiler<'b> {
                     Node::GetConstField(field) => {
                         self.compile_expr(field.obj(), true)?;
                         self.emit(Opcode::Dup, &[]);
-                        let index = self.get_or_insert_name(field.field());
+                        let index = self.insert_inline_cache(field.field());
                         self.emit(Opcode::GetPropertyByName, &[index]);
                     }
                     Node::GetField(field) => {
@@ -1239,9 +1365,9 @@ impl<'b> ByteCompiler<'b> {
                 self.emit_opcode(Opcode::PushNewArray);
                 for cooked in template.cookeds() {
                     if let Some(cooked) = cooked {
-                        self.emit_push_literal(Literal::String(
-                            self.interner().resolve_expect(*cooked).into(),
-                        ));
+                        self.emit_push_literal(Literal::String(JsString::from(
+                            &*self.interner().resolve_expect_utf16(*cooked),
+                        )));
                     } else {
                         self.emit_opcode(Opcode::PushUndefined);
                     }
@@ -1251,14 +1377,14 @@ impl<'b> ByteCompiler<'b> {
 
                 self.emit_opcode(Opcode::PushNewArray);
                 for raw in template.raws() {
-                    self.emit_push_literal(Literal::String(
-                        self.interner().resolve_expect(*raw).into(),
-                    ));
+                    self.emit_push_literal(Literal::String(JsString::from(
+                        &*self.interner().resolve_expect_utf16(*raw),
+                    )));
                     self.emit_opcode(Opcode::PushValueToArray);
                 }
 
                 self.emit_opcode(Opcode::Swap);
-                let index = self.get_or_insert_name(Sym::RAW);
+                let index = self.insert_inline_cache(Sym::RAW);
                 self.emit(Opcode::SetPropertyByName, &[index]);
 
                 for expr in template.exprs() {
@@ -1384,6 +1510,10 @@ impl<'b> ByteCompiler<'b> {
                 }
             }
             Node::If(node) => {
+                if self.compile_folded_if(node)? {
+                    return Ok(());
+                }
+
                 self.compile_expr(node.cond(), true)?;
                 let jelse = self.jump_if_false();
 
@@ -1493,19 +1623,19 @@ impl<'b> ByteCompiler<'b> {
 
                 match for_in_loop.init() {
                     IterableLoopInitializer::Identifier(ref ident) => {
-                        self.context.create_mutable_binding(ident.sym(), true);
+                        self.create_var_binding(ident.sym());
                         let binding = self.context.set_mutable_binding(ident.sym());
                         let index = self.get_or_insert_binding(binding);
                         self.emit(Opcode::DefInitVar, &[index]);
                     }
                     IterableLoopInitializer::Var(declaration) => match declaration {
                         Declaration::Identifier { ident, .. } => {
-                            self.context.create_mutable_binding(ident.sym(), true);
+                            self.create_var_binding(ident.sym());
                             self.emit_binding(BindingOpcode::InitVar, ident.sym());
                         }
                         Declaration::Pattern(pattern) => {
                             for ident in pattern.idents() {
-                                self.context.create_mutable_binding(ident, true);
+                                self.create_var_binding(ident);
                             }
                             self.compile_declaration_pattern(pattern, BindingOpcode::InitVar)?;
                         }
@@ -1536,7 +1666,7 @@ impl<'b> ByteCompiler<'b> {
                     },
                     IterableLoopInitializer::DeclarationPattern(pattern) => {
                         for ident in pattern.idents() {
-                            self.context.create_mutable_binding(ident, true);
+                            self.create_var_binding(ident);
                         }
                         self.compile_declaration_pattern(pattern, BindingOpcode::InitVar)?;
                     }
@@ -1609,19 +1739,19 @@ impl<'b> ByteCompiler<'b> {
 
                 match for_of_loop.init() {
                     IterableLoopInitializer::Identifier(ref ident) => {
-                        self.context.create_mutable_binding(ident.sym(), true);
+                        self.create_var_binding(ident.sym());
                         let binding = self.context.set_mutable_binding(ident.sym());
                         let index = self.get_or_insert_binding(binding);
                         self.emit(Opcode::DefInitVar, &[index]);
                     }
                     IterableLoopInitializer::Var(declaration) => match declaration {
                         Declaration::Identifier { ident, .. } => {
-                            self.context.create_mutable_binding(ident.sym(), true);
+                            self.create_var_binding(ident.sym());
                             self.emit_binding(BindingOpcode::InitVar, ident.sym());
                         }
                         Declaration::Pattern(pattern) => {
                             for ident in pattern.idents() {
-                                self.context.create_mutable_binding(ident, true);
+                                self.create_var_binding(ident);
                             }
                             self.compile_declaration_pattern(pattern, BindingOpcode::InitVar)?;
                         }
@@ -1652,7 +1782,7 @@ impl<'b> ByteCompiler<'b> {
                     },
                     IterableLoopInitializer::DeclarationPattern(pattern) => {
                         for ident in pattern.idents() {
-                            self.context.create_mutable_binding(ident, true);
+                            self.create_var_binding(ident);
                         }
                         self.compile_declaration_pattern(pattern, BindingOpcode::InitVar)?;
                     }
@@ -1675,6 +1805,10 @@ impl<'b> ByteCompiler<'b> {
                 self.emit_opcode(Opcode::IteratorClose);
             }
             Node::WhileLoop(while_) => {
+                if self.compile_folded_while(node, while_)? {
+                    return Ok(());
+                }
+
                 self.emit_opcode(Opcode::LoopStart);
                 let start_address = self.next_opcode_location();
                 self.push_loop_control_info(while_.label(), start_address);
@@ -1763,7 +1897,7 @@ impl<'b> ByteCompiler<'b> {
                         }
                         let address = address_info
                             .ok_or_else(|| {
-                                self.context.construct_syntax_error(format!(
+                                JsNativeError::syntax().with_message(format!(
                                     "Cannot use the undeclared label '{}'",
                                     self.context.interner().resolve_expect(label_name)
                                 ))
@@ -1782,8 +1916,7 @@ impl<'b> ByteCompiler<'b> {
                         items
                             .next()
                             .ok_or_else(|| {
-                                self.context
-                                    .construct_syntax_error("continue must be inside loop")
+                                JsNativeError::syntax().with_message("continue must be inside loop")
                             })?
                             .start_address
                     };
@@ -1827,18 +1960,19 @@ impl<'b> ByteCompiler<'b> {
                         }
                     }
                     if !found {
-                        return self.context.throw_syntax_error(format!(
-                            "Cannot use the undeclared label '{}'",
-                            self.interner().resolve_expect(label_name)
-                        ));
+                        return Err(JsNativeError::syntax()
+                            .with_message(format!(
+                                "Cannot use the undeclared label '{}'",
+                                self.interner().resolve_expect(label_name)
+                            ))
+                            .into());
                     }
                 } else {
                     self.jump_info
                         .last_mut()
                         .ok_or_else(|| {
-                            self.context.construct_syntax_error(
-                                "unlabeled break must be inside loop or switch",
-                            )
+                            JsNativeError::syntax()
+                                .with_message("unlabeled break must be inside loop or switch")
                         })?
                         .breaks
                         .push(label);
@@ -1854,7 +1988,7 @@ impl<'b> ByteCompiler<'b> {
                 let push_env =
                     self.emit_opcode_with_two_operands(Opcode::PushDeclarativeEnvironment);
                 self.create_declarations(block.items())?;
-                self.compile_statement_list(block.items(), use_expr)?;
+                self.compile_statement_list(block.statement_list(), use_expr)?;
                 let (num_bindings, compile_environment) =
                     self.context.pop_compile_time_environment();
                 let index_compile_environment = self.push_compile_environment(compile_environment);
@@ -1894,12 +2028,12 @@ impl<'b> ByteCompiler<'b> {
 
                 for (label, case) in labels.into_iter().zip(switch.cases()) {
                     self.patch_jump(label);
-                    self.compile_statement_list(case.body().items(), false)?;
+                    self.compile_statement_list(case.body(), false)?;
                 }
 
                 self.patch_jump(exit);
                 if let Some(body) = switch.default() {
-                    self.create_declarations(body)?;
+                    self.create_declarations(body.items())?;
                     self.compile_statement_list(body, false)?;
                 }
 
@@ -1917,7 +2051,11 @@ impl<'b> ByteCompiler<'b> {
             Node::FunctionDecl(_function) => self.function(node, false)?,
             Node::Return(ret) => {
                 if let Some(expr) = ret.expr() {
-                    self.compile_expr(expr, true)?;
+                    if self.allow_tail_calls {
+                        self.compile_tail_expr(expr)?;
+                    } else {
+                        self.compile_expr(expr, true)?;
+                    }
                 } else {
                     self.emit(Opcode::PushUndefined, &[]);
                 }
@@ -1932,7 +2070,7 @@ impl<'b> ByteCompiler<'b> {
                     self.emit_opcode_with_two_operands(Opcode::PushDeclarativeEnvironment);
 
                 self.create_declarations(t.block().items())?;
-                self.compile_statement_list(t.block().items(), use_expr)?;
+                self.compile_statement_list(t.block().statement_list(), use_expr)?;
 
                 let (num_bindings, compile_environment) =
                     self.context.pop_compile_time_environment();
@@ -1973,7 +2111,7 @@ impl<'b> ByteCompiler<'b> {
                     }
 
                     self.create_declarations(catch.block().items())?;
-                    self.compile_statement_list(catch.block().items(), use_expr)?;
+                    self.compile_statement_list(catch.block().statement_list(), use_expr)?;
 
                     let (num_bindings, compile_environment) =
                         self.context.pop_compile_time_environment();
@@ -2010,7 +2148,7 @@ impl<'b> ByteCompiler<'b> {
                         self.emit_opcode_with_two_operands(Opcode::PushDeclarativeEnvironment);
 
                     self.create_declarations(finally.items())?;
-                    self.compile_statement_list(finally.items(), false)?;
+                    self.compile_statement_list(finally.statement_list(), false)?;
 
                     let (num_bindings, compile_environment) =
                         self.context.pop_compile_time_environment();
@@ -2036,6 +2174,63 @@ impl<'b> ByteCompiler<'b> {
         Ok(())
     }
 
+    /// Compiles an expression in tail position, emitting the calls in tail position of it as
+    /// tail calls.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-hascallintailposition
+    fn compile_tail_expr(&mut self, expr: &Node) -> JsResult<()> {
+        match expr {
+            Node::Call(call)
+                if !matches!(call.expr(), Node::Identifier(ident) if ident.sym() == Sym::EVAL)
+                    && !call.args().iter().any(|arg| matches!(arg, Node::Spread(_))) =>
+            {
+                self.compile_tail_call(expr)?;
+            }
+            Node::TaggedTemplate(_) => self.compile_tail_call(expr)?,
+            Node::ConditionalOp(op) => {
+                self.compile_expr(op.cond(), true)?;
+                let jelse = self.jump_if_false();
+                self.compile_tail_expr(op.if_true())?;
+                let exit = self.jump();
+                self.patch_jump(jelse);
+                self.compile_tail_expr(op.if_false())?;
+                self.patch_jump(exit);
+            }
+            Node::BinOp(binary) => match binary.op() {
+                BinOp::Log(op) => {
+                    self.compile_expr(binary.lhs(), true)?;
+                    let exit = match op {
+                        LogOp::And => self.emit_opcode_with_operand(Opcode::LogicalAnd),
+                        LogOp::Or => self.emit_opcode_with_operand(Opcode::LogicalOr),
+                        LogOp::Coalesce => self.emit_opcode_with_operand(Opcode::Coalesce),
+                    };
+                    self.compile_tail_expr(binary.rhs())?;
+                    self.patch_jump(exit);
+                }
+                BinOp::Comma => {
+                    self.compile_expr(binary.lhs(), false)?;
+                    self.compile_tail_expr(binary.rhs())?;
+                }
+                _ => self.compile_expr(expr, true)?,
+            },
+            _ => self.compile_expr(expr, true)?,
+        }
+        Ok(())
+    }
+
+    /// Compiles a call or a tagged template, which both end with a `Call` instruction, and
+    /// turns that instruction into a `TailCall`.
+    fn compile_tail_call(&mut self, expr: &Node) -> JsResult<()> {
+        self.compile_expr(expr, true)?;
+        let call = self.code_block.code.len() - size_of::<Opcode>() - size_of::<u32>();
+        debug_assert_eq!(self.code_block.code[call], Opcode::Call as u8);
+        self.code_block.code[call] = Opcode::TailCall as u8;
+        Ok(())
+    }
+
     /// Compile a function AST Node into bytecode.
     pub(crate) fn function(&mut self, function: &Node, use_expr: bool) -> JsResult<()> {
         let (kind, name, parameters, body, generator, r#async) = match function {
@@ -2120,6 +2315,7 @@ impl<'b> ByteCompiler<'b> {
             .r#async(r#async)
             .strict(self.code_block.strict)
             .kind(kind)
+            .source_name(self.code_block.source_name.clone())
             .compile(parameters, body, self.context)?;
 
         let index = self.code_block.functions.len() as u32;
@@ -2175,7 +2371,7 @@ impl<'b> ByteCompiler<'b> {
                 if kind == CallKind::Call {
                     self.emit(Opcode::Dup, &[]);
                 }
-                let index = self.get_or_insert_name(field.field());
+                let index = self.insert_inline_cache(field.field());
                 self.emit(Opcode::GetPropertyByName, &[index]);
             }
             Node::GetField(field) => {
@@ -2194,7 +2390,7 @@ impl<'b> ByteCompiler<'b> {
                 self.emit_opcode(Opcode::Super);
                 match get_super_field {
                     GetSuperField::Const(field) => {
-                        let index = self.get_or_insert_name(*field);
+                        let index = self.insert_inline_cache(*field);
                         self.emit(Opcode::GetPropertyByName, &[index]);
                     }
                     GetSuperField::Expr(expr) => {
@@ -2256,7 +2452,10 @@ impl<'b> ByteCompiler<'b> {
     }
 
     #[inline]
-    pub fn finish(self) -> CodeBlock {
+    pub fn finish(mut self) -> CodeBlock {
+        if self.optimizes_bytecode() {
+            optimizer::optimize_code_block(&mut self.code_block);
+        }
         self.code_block
     }
 
@@ -2300,7 +2499,7 @@ impl<'b> ByteCompiler<'b> {
                             self.emit_opcode(Opcode::Dup);
                             match property_name {
                                 PropertyName::Literal(name) => {
-                                    let index = self.get_or_insert_name(*name);
+                                    let index = self.insert_inline_cache(*name);
                                     self.emit(Opcode::GetPropertyByName, &[index]);
                                 }
                                 PropertyName::Computed(node) => {
@@ -2335,9 +2534,9 @@ impl<'b> ByteCompiler<'b> {
                             self.emit_opcode(Opcode::PushEmptyObject);
 
                             for key in excluded_keys {
-                                self.emit_push_literal(Literal::String(
-                                    self.interner().resolve_expect(*key).into(),
-                                ));
+                                self.emit_push_literal(Literal::String(JsString::from(
+                                    &*self.interner().resolve_expect_utf16(*key),
+                                )));
                             }
 
                             self.emit(
@@ -2353,9 +2552,9 @@ impl<'b> ByteCompiler<'b> {
                             self.emit_opcode(Opcode::Dup);
                             self.emit_opcode(Opcode::PushEmptyObject);
                             for key in excluded_keys {
-                                self.emit_push_literal(Literal::String(
-                                    self.interner().resolve_expect(*key).into(),
-                                ));
+                                self.emit_push_literal(Literal::String(JsString::from(
+                                    &*self.interner().resolve_expect_utf16(*key),
+                                )));
                             }
                             self.emit(Opcode::CopyDataProperties, &[excluded_keys.len() as u32, 0]);
                             self.access_set(
@@ -2374,7 +2573,7 @@ impl<'b> ByteCompiler<'b> {
                             self.emit_opcode(Opcode::Dup);
                             match property_name {
                                 PropertyName::Literal(name) => {
-                                    let index = self.get_or_insert_name(*name);
+                                    let index = self.insert_inline_cache(*name);
                                     self.emit(Opcode::GetPropertyByName, &[index]);
                                 }
                                 PropertyName::Computed(node) => {
@@ -2416,7 +2615,7 @@ impl<'b> ByteCompiler<'b> {
                             self.emit_opcode(Opcode::Dup);
                             match property_name {
                                 PropertyName::Literal(name) => {
-                                    let index = self.get_or_insert_name(*name);
+                                    let index = self.insert_inline_cache(*name);
                                     self.emit(Opcode::GetPropertyByName, &[index]);
                                 }
                                 PropertyName::Computed(node) => {
@@ -2452,7 +2651,7 @@ impl<'b> ByteCompiler<'b> {
                             self.emit_opcode(Opcode::Dup);
                             match ident {
                                 PropertyName::Literal(name) => {
-                                    let index = self.get_or_insert_name(*name);
+                                    let index = self.insert_inline_cache(*name);
                                     self.emit(Opcode::GetPropertyByName, &[index]);
                                 }
                                 PropertyName::Computed(node) => {
@@ -2588,14 +2787,14 @@ impl<'b> ByteCompiler<'b> {
                             if ident == Sym::ARGUMENTS {
                                 has_identifier_argument = true;
                             }
-                            self.context.create_mutable_binding(ident, true);
+                            self.create_var_binding(ident);
                         }
                         Declaration::Pattern(pattern) => {
                             for ident in pattern.idents() {
                                 if ident == Sym::ARGUMENTS {
                                     has_identifier_argument = true;
                                 }
-                                self.context.create_mutable_binding(ident, true);
+                                self.create_var_binding(ident);
                             }
                         }
                     }
@@ -2651,28 +2850,28 @@ impl<'b> ByteCompiler<'b> {
                 if ident == Sym::ARGUMENTS {
                     has_identifier_argument = true;
                 }
-                self.context.create_mutable_binding(ident, true);
+                self.create_var_binding(ident);
             }
             Node::GeneratorDecl(decl) => {
                 let ident = decl.name();
                 if ident == Sym::ARGUMENTS {
                     has_identifier_argument = true;
                 }
-                self.context.create_mutable_binding(ident, true);
+                self.create_var_binding(ident);
             }
             Node::AsyncFunctionDecl(decl) => {
                 let ident = decl.name();
                 if ident == Sym::ARGUMENTS {
                     has_identifier_argument = true;
                 }
-                self.context.create_mutable_binding(ident, true);
+                self.create_var_binding(ident);
             }
             Node::AsyncGeneratorDecl(decl) => {
                 let ident = decl.name();
                 if ident == Sym::ARGUMENTS {
                     has_identifier_argument = true;
                 }
-                self.context.create_mutable_binding(ident, true);
+                self.create_var_binding(ident);
             }
             Node::DoWhileLoop(do_while_loop) => {
                 if !matches!(do_while_loop.body(), Node::Block(_)) {
@@ -2700,7 +2899,8 @@ impl<'b> ByteCompiler<'b> {
     /// A class declaration binds the resulting class object to it's identifier.
     /// A class expression leaves the resulting class object on the stack for following operations.
     fn class(&mut self, class: &Class, expression: bool) -> JsResult<()> {
-        let code = CodeBlock::new(class.name(), 0, true);
+        let mut code = CodeBlock::new(class.name(), 0, true);
+        code.source_name.clone_from(&self.code_block.source_name);
         let mut compiler = ByteCompiler {
             code_block: code,
             literals_map: FxHashMap::default(),
@@ -2708,6 +2908,7 @@ impl<'b> ByteCompiler<'b> {
             bindings_map: FxHashMap::default(),
             jump_info: Vec::new(),
             in_async_generator: false,
+            allow_tail_calls: false,
             context: self.context,
         };
         compiler.context.push_compile_time_environment(true);
@@ -2760,7 +2961,7 @@ impl<'b> ByteCompiler<'b> {
                 None
             };
             compiler.create_declarations(expr.body().items())?;
-            compiler.compile_statement_list(expr.body().items(), false)?;
+            compiler.compile_statement_list(expr.body(), false)?;
             if let Some(env_label) = env_label {
                 let (num_bindings, compile_environment) =
                     compiler.context.pop_compile_time_environment();
@@ -2942,15 +3143,18 @@ impl<'b> ByteCompiler<'b> {
                     self.emit_opcode(Opcode::Dup);
                     match name {
                         PropertyName::Literal(name) => {
-                            self.emit_push_literal(Literal::String(
-                                self.interner().resolve_expect(*name).into(),
-                            ));
+                            self.emit_push_literal(Literal::String(JsString::from(
+                                &*self.interner().resolve_expect_utf16(*name),
+                            )));
                         }
                         PropertyName::Computed(name) => {
                             self.compile_expr(name, true)?;
                         }
                     }
-                    let field_code = CodeBlock::new(Sym::EMPTY_STRING, 0, true);
+                    let mut field_code = CodeBlock::new(Sym::EMPTY_STRING, 0, true);
+                    field_code
+                        .source_name
+                        .clone_from(&self.code_block.source_name);
                     let mut field_compiler = ByteCompiler {
                         code_block: field_code,
                         literals_map: FxHashMap::default(),
@@ -2958,6 +3162,7 @@ impl<'b> ByteCompiler<'b> {
                         bindings_map: FxHashMap::default(),
                         jump_info: Vec::new(),
                         in_async_generator: false,
+                        allow_tail_calls: false,
                         context: self.context,
                     };
                     field_compiler.context.push_compile_time_environment(true);
@@ -2984,7 +3189,10 @@ impl<'b> ByteCompiler<'b> {
                 ClassElement::PrivateFieldDefinition(name, field) => {
                     self.emit_opcode(Opcode::Dup);
                     let name_index = self.get_or_insert_name(*name);
-                    let field_code = CodeBlock::new(Sym::EMPTY_STRING, 0, true);
+                    let mut field_code = CodeBlock::new(Sym::EMPTY_STRING, 0, true);
+                    field_code
+                        .source_name
+                        .clone_from(&self.code_block.source_name);
                     let mut field_compiler = ByteCompiler {
                         code_block: field_code,
                         literals_map: FxHashMap::default(),
@@ -2992,6 +3200,7 @@ impl<'b> ByteCompiler<'b> {
                         bindings_map: FxHashMap::default(),
                         jump_info: Vec::new(),
                         in_async_generator: false,
+                        allow_tail_calls: false,
                         context: self.context,
                     };
                     field_compiler.context.push_compile_time_environment(true);
@@ -3053,9 +3262,13 @@ impl<'b> ByteCompiler<'b> {
                 ClassElement::StaticBlock(statement_list) => {
                     self.emit_opcode(Opcode::Dup);
                     let mut compiler = ByteCompiler::new(Sym::EMPTY_STRING, true, self.context);
+                    compiler
+                        .code_block
+                        .source_name
+                        .clone_from(&self.code_block.source_name);
                     compiler.context.push_compile_time_environment(true);
                     compiler.create_declarations(statement_list.items())?;
-                    compiler.compile_statement_list(statement_list.items(), false)?;
+                    compiler.compile_statement_list(statement_list, false)?;
                     let (num_bindings, compile_environment) =
                         compiler.context.pop_compile_time_environment();
                     compiler
@@ -0,0 +1,629 @@
+//! Optimizations applied while compiling code to bytecode.
+//!
+//! The [`OptimizationLevel`] of the [`Context`](crate::Context) selects the optimizations:
+//!  - [`OptimizationLevel::Basic`] folds the constant operations of the AST and removes the
+//!    branches that can never run, when compiling them.
+//!  - [`OptimizationLevel::Full`] also threads the jumps of the bytecode and removes the
+//!    instructions without effect, when finishing a [`CodeBlock`].
+
+use super::ByteCompiler;
+use crate::{
+    builtins::Number,
+    syntax::ast::{
+        node::{If, WhileLoop},
+        op::{BinOp, BitOp, CompOp, LogOp, NumOp, UnaryOp},
+        Const, Node,
+    },
+    value::Numeric,
+    vm::{CodeBlock, Opcode},
+    JsBigInt, JsResult, JsString, JsValue, JsVariant,
+};
+use rustc_hash::FxHashSet;
+use std::mem::size_of;
+
+/// The optimizations applied when compiling code.
+///
+/// Optimizations never change the behaviour of the code, only the bytecode it compiles to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum OptimizationLevel {
+    /// Compiles the code as written.
+    #[default]
+    None,
+
+    /// Folds operations on constants, like `1 + 2`, and removes the branches of conditions
+    /// that are always true or always false, like `if (false) { ... }`.
+    Basic,
+
+    /// Applies the optimizations of [`OptimizationLevel::Basic`], threads jumps to
+    /// unconditional jumps and removes redundant instructions, like values pushed to the
+    /// stack and popped right away.
+    Full,
+}
+
+impl ByteCompiler<'_> {
+    /// Returns `true` if the AST optimizations are enabled.
+    #[inline]
+    pub(super) fn optimizes_ast(&self) -> bool {
+        self.context.optimization_level() >= OptimizationLevel::Basic
+    }
+
+    /// Returns `true` if the bytecode optimizations are enabled.
+    #[inline]
+    pub(super) fn optimizes_bytecode(&self) -> bool {
+        self.context.optimization_level() >= OptimizationLevel::Full
+    }
+
+    /// Compiles an expression that operates on constants to the constant it evaluates to, or
+    /// only its branch that runs.
+    ///
+    /// Returns `false` if the expression cannot be folded, in which case nothing is emitted.
+    pub(super) fn compile_folded_expr(&mut self, expr: &Node, use_expr: bool) -> JsResult<bool> {
+        if matches!(expr, Node::UnaryOp(_) | Node::BinOp(_)) {
+            if let Some(value) = self.constant_value(expr) {
+                self.emit_constant(&value);
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+                return Ok(true);
+            }
+        }
+
+        match expr {
+            Node::ConditionalOp(op) => {
+                if let Some(condition) = self.constant_condition(op.cond()) {
+                    let (live, dead) = if condition {
+                        (op.if_true(), op.if_false())
+                    } else {
+                        (op.if_false(), op.if_true())
+                    };
+                    self.compile_expr(live, use_expr)?;
+                    self.compile_discarded(|compiler| compiler.compile_expr(dead, false))?;
+                    return Ok(true);
+                }
+            }
+            // A logical operation with a constant left-hand side evaluates to either that
+            // constant or its right-hand side.
+            Node::BinOp(binary) => {
+                if let BinOp::Log(op) = binary.op() {
+                    if let Some(lhs) = self.constant_value(binary.lhs()) {
+                        if Self::short_circuits(op, &lhs) {
+                            self.emit_constant(&lhs);
+                            if !use_expr {
+                                self.emit(Opcode::Pop, &[]);
+                            }
+                            self.compile_discarded(|compiler| {
+                                compiler.compile_expr(binary.rhs(), false)
+                            })?;
+                        } else {
+                            self.compile_expr(binary.rhs(), use_expr)?;
+                        }
+                        return Ok(true);
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        Ok(false)
+    }
+
+    /// Compiles an `if` statement with a constant condition to only its branch that runs.
+    ///
+    /// Returns `false` if the statement cannot be folded, in which case nothing is emitted.
+    pub(super) fn compile_folded_if(&mut self, node: &If) -> JsResult<bool> {
+        let (live, dead) = match self.constant_condition(node.cond()) {
+            Some(true) => (Some(node.body()), node.else_node()),
+            Some(false) => (node.else_node(), Some(node.body())),
+            None => return Ok(false),
+        };
+        if !dead.map_or(true, is_removable) {
+            return Ok(false);
+        }
+
+        if let Some(live) = live {
+            if !matches!(live, Node::Block(_)) {
+                self.create_decls_from_stmt(live)?;
+            }
+            self.compile_stmt(live, false)?;
+        }
+        if let Some(dead) = dead {
+            self.compile_discarded(|compiler| compiler.compile_stmt(dead, false))?;
+        }
+        Ok(true)
+    }
+
+    /// Compiles a `while` loop whose condition is always false to nothing.
+    ///
+    /// Returns `false` if the loop cannot be removed, in which case nothing is emitted.
+    pub(super) fn compile_folded_while(
+        &mut self,
+        node: &Node,
+        while_: &WhileLoop,
+    ) -> JsResult<bool> {
+        if self.constant_condition(while_.cond()) != Some(false) || !is_removable(while_.body()) {
+            return Ok(false);
+        }
+        self.compile_discarded(|compiler| compiler.compile_stmt(node, false))?;
+        Ok(true)
+    }
+
+    /// Compiles code that never runs without optimizations, and discards it.
+    ///
+    /// The code is still compiled to report its early errors, like a `break` to a label that
+    /// does not exist, as if it was not removed.
+    pub(super) fn compile_discarded<F>(&mut self, compile: F) -> JsResult<()>
+    where
+        F: FnOnce(&mut Self) -> JsResult<()>,
+    {
+        let level = self.context.optimization_level();
+        let code_len = self.code_block.code.len();
+        let positions_len = self.code_block.positions.len();
+        let last_position = self.code_block.positions.last().copied();
+        let jump_info = self.jump_info.clone();
+
+        self.context.set_optimization_level(OptimizationLevel::None);
+        let result = compile(self);
+        self.context.set_optimization_level(level);
+
+        self.code_block.code.truncate(code_len);
+        self.code_block.positions.truncate(positions_len);
+        if let (Some(last), Some(position)) = (self.code_block.positions.last_mut(), last_position)
+        {
+            *last = position;
+        }
+        self.jump_info = jump_info;
+        result
+    }
+
+    /// Returns the value of an expression if it only operates on constants.
+    ///
+    /// Operations that would throw are not folded, so that they throw when the code runs.
+    pub(super) fn constant_value(&mut self, node: &Node) -> Option<JsValue> {
+        match node {
+            Node::Const(constant) => Some(match constant {
+                Const::String(string) => {
+                    JsString::from(&*self.interner().resolve_expect_utf16(*string)).into()
+                }
+                Const::Num(number) => JsValue::new(*number),
+                Const::Int(integer) => JsValue::new(*integer),
+                Const::BigInt(bigint) => JsValue::new(JsBigInt::new(bigint.clone())),
+                Const::Bool(boolean) => JsValue::new(*boolean),
+                Const::Null => JsValue::null(),
+                Const::Undefined => JsValue::undefined(),
+            }),
+            Node::UnaryOp(unary) => {
+                let value = match unary.op() {
+                    UnaryOp::Minus
+                    | UnaryOp::Plus
+                    | UnaryOp::Not
+                    | UnaryOp::Tilde
+                    | UnaryOp::TypeOf
+                    | UnaryOp::Void => self.constant_value(unary.target())?,
+                    _ => return None,
+                };
+                self.fold_unary(unary.op(), &value).ok()
+            }
+            Node::BinOp(binary) => {
+                let lhs = self.constant_value(binary.lhs())?;
+                match binary.op() {
+                    BinOp::Log(op) => {
+                        if Self::short_circuits(op, &lhs) {
+                            Some(lhs)
+                        } else {
+                            self.constant_value(binary.rhs())
+                        }
+                    }
+                    op @ (BinOp::Num(_) | BinOp::Bit(_) | BinOp::Comp(_)) => {
+                        let rhs = self.constant_value(binary.rhs())?;
+                        self.fold_binary(op, &lhs, &rhs)?.ok()
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the truthiness of a condition if it only operates on constants.
+    pub(super) fn constant_condition(&mut self, node: &Node) -> Option<bool> {
+        if self.optimizes_ast() {
+            self.constant_value(node).map(|value| value.to_boolean())
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the left-hand side of a logical operation is its result.
+    pub(super) fn short_circuits(op: LogOp, lhs: &JsValue) -> bool {
+        match op {
+            LogOp::And => !lhs.to_boolean(),
+            LogOp::Or => lhs.to_boolean(),
+            LogOp::Coalesce => !lhs.is_null_or_undefined(),
+        }
+    }
+
+    /// Applies a unary operator to a constant, as the instruction of the operator would.
+    fn fold_unary(&mut self, op: UnaryOp, value: &JsValue) -> JsResult<JsValue> {
+        Ok(match op {
+            UnaryOp::Minus => match value.to_numeric(self.context)? {
+                Numeric::Number(number) => JsValue::new(-number),
+                Numeric::BigInt(bigint) => JsValue::new(JsBigInt::neg(&bigint)),
+            },
+            UnaryOp::Plus => JsValue::new(value.to_number(self.context)?),
+            UnaryOp::Not => JsValue::new(!value.to_boolean()),
+            UnaryOp::Tilde => match value.to_numeric(self.context)? {
+                Numeric::Number(number) => JsValue::new(Number::not(number)),
+                Numeric::BigInt(bigint) => JsValue::new(JsBigInt::not(&bigint)),
+            },
+            UnaryOp::TypeOf => value.type_of().into(),
+            UnaryOp::Void => JsValue::undefined(),
+            _ => unreachable!("only operators without side effects are folded"),
+        })
+    }
+
+    /// Applies a binary operator to constants, as the instruction of the operator would.
+    ///
+    /// Returns `None` for operators that are not folded.
+    fn fold_binary(
+        &mut self,
+        op: BinOp,
+        lhs: &JsValue,
+        rhs: &JsValue,
+    ) -> Option<JsResult<JsValue>> {
+        let context = &mut *self.context;
+        Some(match op {
+            BinOp::Num(op) => match op {
+                NumOp::Add => lhs.add(rhs, context),
+                NumOp::Sub => lhs.sub(rhs, context),
+                NumOp::Div => lhs.div(rhs, context),
+                NumOp::Mul => lhs.mul(rhs, context),
+                NumOp::Exp => lhs.pow(rhs, context),
+                NumOp::Mod => lhs.rem(rhs, context),
+            },
+            BinOp::Bit(op) => match op {
+                BitOp::And => lhs.bitand(rhs, context),
+                BitOp::Or => lhs.bitor(rhs, context),
+                BitOp::Xor => lhs.bitxor(rhs, context),
+                BitOp::Shl => lhs.shl(rhs, context),
+                BitOp::Shr => lhs.shr(rhs, context),
+                BitOp::UShr => lhs.ushr(rhs, context),
+            },
+            BinOp::Comp(op) => match op {
+                CompOp::Equal => lhs.equals(rhs, context).map(JsValue::new),
+                CompOp::NotEqual => lhs.equals(rhs, context).map(|value| JsValue::new(!value)),
+                CompOp::StrictEqual => Ok(JsValue::new(lhs.strict_equals(rhs))),
+                CompOp::StrictNotEqual => Ok(JsValue::new(!lhs.strict_equals(rhs))),
+                CompOp::GreaterThan => lhs.gt(rhs, context).map(JsValue::new),
+                CompOp::GreaterThanOrEqual => lhs.ge(rhs, context).map(JsValue::new),
+                CompOp::LessThan => lhs.lt(rhs, context).map(JsValue::new),
+                CompOp::LessThanOrEqual => lhs.le(rhs, context).map(JsValue::new),
+                CompOp::In | CompOp::InstanceOf => return None,
+            },
+            _ => return None,
+        })
+    }
+
+    /// Emits the instructions pushing a folded constant.
+    pub(super) fn emit_constant(&mut self, value: &JsValue) {
+        match value.variant() {
+            JsVariant::Undefined => self.emit_opcode(Opcode::PushUndefined),
+            JsVariant::Null => self.emit_opcode(Opcode::PushNull),
+            JsVariant::Boolean(true) => self.emit_opcode(Opcode::PushTrue),
+            JsVariant::Boolean(false) => self.emit_opcode(Opcode::PushFalse),
+            JsVariant::Integer(integer) => self.emit_push_integer(integer),
+            JsVariant::Rational(number) => self.emit_push_rational(number),
+            JsVariant::String(string) => {
+                self.emit_push_literal(super::Literal::String(string.clone()));
+            }
+            JsVariant::BigInt(bigint) => {
+                self.emit_push_literal(super::Literal::BigInt(bigint.clone()));
+            }
+            JsVariant::Object(_) | JsVariant::Symbol(_) => {
+                unreachable!("only primitives without identity are folded")
+            }
+        }
+    }
+}
+
+/// Returns `true` if a statement that never runs can be left out of the bytecode.
+///
+/// Statements declaring `var` bindings or functions must still be compiled, since their
+/// bindings are visible from the code that runs.
+pub(super) fn is_removable(node: &Node) -> bool {
+    if matches!(
+        node,
+        Node::FunctionDecl(_)
+            | Node::GeneratorDecl(_)
+            | Node::AsyncFunctionDecl(_)
+            | Node::AsyncGeneratorDecl(_)
+            | Node::ClassDecl(_)
+            | Node::LetDeclList(_)
+            | Node::ConstDeclList(_)
+            | Node::VarDeclList(_)
+    ) {
+        return false;
+    }
+
+    let mut vars = FxHashSet::default();
+    node.var_declared_names(&mut vars);
+    vars.is_empty()
+}
+
+/// Returns `true` if control never reaches the statement after this one.
+pub(super) fn ends_control_flow(node: &Node) -> bool {
+    matches!(
+        node,
+        Node::Return(_) | Node::Throw(_) | Node::Break(_) | Node::Continue(_)
+    )
+}
+
+/// Applies the bytecode optimizations to a compiled code block.
+pub(super) fn optimize_code_block(code_block: &mut CodeBlock) {
+    thread_jumps(&mut code_block.code);
+    while remove_redundant_instructions(code_block) {}
+}
+
+/// Returns the size of the operands of an instruction.
+fn operands_size(opcode: Opcode) -> usize {
+    match opcode {
+        Opcode::PushInt8 => size_of::<i8>(),
+        Opcode::PushInt16 => size_of::<i16>(),
+        Opcode::PushRational => size_of::<f64>(),
+        Opcode::TryStart
+        | Opcode::PushDeclarativeEnvironment
+        | Opcode::PushFunctionEnvironment
+        | Opcode::CopyDataProperties => 2 * size_of::<u32>(),
+        Opcode::PushInt32
+        | Opcode::PushLiteral
+        | Opcode::Jump
+        | Opcode::JumpIfFalse
+        | Opcode::JumpIfNotUndefined
+        | Opcode::CatchStart
+        | Opcode::FinallySetJump
+        | Opcode::Case
+        | Opcode::Default
+        | Opcode::LogicalAnd
+        | Opcode::LogicalOr
+        | Opcode::Coalesce
+        | Opcode::CallEval
+        | Opcode::Call
+        | Opcode::TailCall
+        | Opcode::New
+        | Opcode::SuperCall
+        | Opcode::ForInLoopInitIterator
+        | Opcode::ForInLoopNext
+        | Opcode::ForAwaitOfLoopNext
+        | Opcode::ConcatToString
+        | Opcode::GeneratorNextDelegate
+        | Opcode::GetFunction
+        | Opcode::GetFunctionAsync
+        | Opcode::GetGenerator
+        | Opcode::GetGeneratorAsync
+        | Opcode::DefInitArg
+        | Opcode::DefVar
+        | Opcode::DefInitVar
+        | Opcode::DefLet
+        | Opcode::DefInitLet
+        | Opcode::DefInitConst
+        | Opcode::GetName
+        | Opcode::GetNameOrUndefined
+        | Opcode::SetName
+        | Opcode::GetPropertyByName
+        | Opcode::SetPropertyByName
+        | Opcode::DefineOwnPropertyByName
+        | Opcode::DefineClassMethodByName
+        | Opcode::SetPropertyGetterByName
+        | Opcode::DefineClassGetterByName
+        | Opcode::SetPropertySetterByName
+        | Opcode::DefineClassSetterByName
+        | Opcode::AssignPrivateField
+        | Opcode::SetPrivateField
+        | Opcode::SetPrivateMethod
+        | Opcode::SetPrivateSetter
+        | Opcode::SetPrivateGetter
+        | Opcode::GetPrivateField
+        | Opcode::DeletePropertyByName
+        | Opcode::PushClassFieldPrivate
+        | Opcode::PushClassPrivateGetter
+        | Opcode::PushClassPrivateSetter
+        | Opcode::PushClassPrivateMethod => size_of::<u32>(),
+        _ => 0,
+    }
+}
+
+/// Returns the offsets of the operands of an instruction that are bytecode addresses, relative
+/// to the opcode.
+fn address_operands(opcode: Opcode) -> &'static [usize] {
+    match opcode {
+        Opcode::Jump
+        | Opcode::JumpIfFalse
+        | Opcode::JumpIfNotUndefined
+        | Opcode::CatchStart
+        | Opcode::FinallySetJump
+        | Opcode::Case
+        | Opcode::Default
+        | Opcode::LogicalAnd
+        | Opcode::LogicalOr
+        | Opcode::Coalesce
+        | Opcode::ForInLoopInitIterator
+        | Opcode::ForInLoopNext
+        | Opcode::ForAwaitOfLoopNext
+        | Opcode::GeneratorNextDelegate => &[1],
+        Opcode::TryStart => &[1, 5],
+        _ => &[],
+    }
+}
+
+/// Returns `true` if an instruction only pushes a value to the stack.
+fn only_pushes(opcode: Opcode) -> bool {
+    matches!(
+        opcode,
+        Opcode::Dup
+            | Opcode::PushZero
+            | Opcode::PushOne
+            | Opcode::PushInt8
+            | Opcode::PushInt16
+            | Opcode::PushInt32
+            | Opcode::PushRational
+            | Opcode::PushNaN
+            | Opcode::PushPositiveInfinity
+            | Opcode::PushNegativeInfinity
+            | Opcode::PushNull
+            | Opcode::PushTrue
+            | Opcode::PushFalse
+            | Opcode::PushUndefined
+            | Opcode::PushLiteral
+    )
+}
+
+fn opcode_at(code: &[u8], location: usize) -> Opcode {
+    code[location].try_into().expect("invalid opcode")
+}
+
+fn read_u32(code: &[u8], location: usize) -> u32 {
+    let mut bytes = [0; size_of::<u32>()];
+    bytes.copy_from_slice(&code[location..location + size_of::<u32>()]);
+    u32::from_ne_bytes(bytes)
+}
+
+fn write_u32(code: &mut [u8], location: usize, value: u32) {
+    code[location..location + size_of::<u32>()].copy_from_slice(&value.to_ne_bytes());
+}
+
+/// Returns the locations of the instructions of the bytecode.
+fn instructions(code: &[u8]) -> Vec<usize> {
+    let mut locations = Vec::new();
+    let mut location = 0;
+    while location < code.len() {
+        locations.push(location);
+        location += size_of::<Opcode>() + operands_size(opcode_at(code, location));
+    }
+    debug_assert_eq!(location, code.len(), "the last instruction is truncated");
+    locations
+}
+
+/// Makes the jumps to unconditional jumps jump to their final target.
+fn thread_jumps(code: &mut [u8]) {
+    for location in instructions(code) {
+        let threaded = matches!(
+            opcode_at(code, location),
+            Opcode::Jump
+                | Opcode::JumpIfFalse
+                | Opcode::JumpIfNotUndefined
+                | Opcode::LogicalAnd
+                | Opcode::LogicalOr
+                | Opcode::Coalesce
+                | Opcode::Case
+                | Opcode::Default
+        );
+        if !threaded {
+            continue;
+        }
+
+        let mut target = read_u32(code, location + 1);
+        // Bounded to stop at loops made of jumps only.
+        for _ in 0..code.len() {
+            let target_location = target as usize;
+            if target_location >= code.len() || opcode_at(code, target_location) != Opcode::Jump {
+                break;
+            }
+            let next = read_u32(code, target_location + 1);
+            if next == target {
+                break;
+            }
+            target = next;
+        }
+        write_u32(code, location + 1, target);
+    }
+}
+
+/// Removes the jumps to the next instruction and the values pushed to the stack only to be
+/// popped right away.
+///
+/// Returns `true` if instructions were removed.
+fn remove_redundant_instructions(code_block: &mut CodeBlock) -> bool {
+    let code = &code_block.code;
+    let locations = instructions(code);
+
+    let mut targets = FxHashSet::default();
+    for &location in &locations {
+        for offset in address_operands(opcode_at(code, location)) {
+            targets.insert(read_u32(code, location + offset) as usize);
+        }
+    }
+
+    let mut removed = vec![false; locations.len()];
+    let mut index = 0;
+    while index < locations.len() {
+        let location = locations[index];
+        let next = locations.get(index + 1).copied().unwrap_or(code.len());
+        let opcode = opcode_at(code, location);
+        if opcode == Opcode::Jump && read_u32(code, location + 1) as usize == next {
+            removed[index] = true;
+        } else if only_pushes(opcode)
+            && next < code.len()
+            && opcode_at(code, next) == Opcode::Pop
+            && !targets.contains(&next)
+        {
+            removed[index] = true;
+            removed[index + 1] = true;
+            index += 1;
+        }
+        index += 1;
+    }
+    if !removed.contains(&true) {
+        return false;
+    }
+
+    // The new location of each instruction, followed by the new end of the code. Removed
+    // instructions are mapped to the next instruction that is kept.
+    let mut new_locations = Vec::with_capacity(locations.len() + 1);
+    let mut new_code = Vec::with_capacity(code.len());
+    for (index, &location) in locations.iter().enumerate() {
+        new_locations.push(new_code.len() as u32);
+        if !removed[index] {
+            let end = locations.get(index + 1).copied().unwrap_or(code.len());
+            new_code.extend_from_slice(&code[location..end]);
+        }
+    }
+    new_locations.push(new_code.len() as u32);
+
+    let relocate = |address: u32| -> u32 {
+        let index = locations
+            .binary_search(&(address as usize))
+            .unwrap_or_else(|index| index);
+        new_locations[index]
+    };
+
+    for (index, &location) in locations.iter().enumerate() {
+        if removed[index] {
+            continue;
+        }
+        let opcode = opcode_at(code, location);
+        let new_location = new_locations[index] as usize;
+        for &offset in address_operands(opcode) {
+            let address = read_u32(code, location + offset);
+            // The `finally` address of a `TryStart` is zero if there is no `finally` block.
+            if opcode == Opcode::TryStart && offset == 5 && address == 0 {
+                continue;
+            }
+            write_u32(&mut new_code, new_location + offset, relocate(address));
+        }
+    }
+
+    let mut positions: Vec<(u32, _)> = Vec::with_capacity(code_block.positions.len());
+    for &(location, position) in &code_block.positions {
+        let location = relocate(location);
+        match positions.last_mut() {
+            Some((last, last_position)) if *last == location => *last_position = position,
+            Some((_, last_position)) if *last_position == position => {}
+            _ => positions.push((location, position)),
+        }
+    }
+
+    code_block.function_environment_push_location =
+        relocate(code_block.function_environment_push_location);
+    code_block.positions = positions;
+    code_block.code = new_code;
+    true
+}
@@ -0,0 +1,180 @@
+use crate::{
+    bytecompiler::OptimizationLevel, context::ContextBuilder, forward, syntax::Parser, Context,
+};
+
+const LEVELS: [OptimizationLevel; 3] = [
+    OptimizationLevel::None,
+    OptimizationLevel::Basic,
+    OptimizationLevel::Full,
+];
+
+fn context(level: OptimizationLevel) -> Context {
+    ContextBuilder::default().optimization_level(level).build()
+}
+
+/// Checks that `src` evaluates to the same result at every optimization level.
+fn assert_same_result(src: &str) {
+    let expected = forward(&mut context(OptimizationLevel::None), src);
+    for level in LEVELS {
+        assert_eq!(
+            forward(&mut context(level), src),
+            expected,
+            "{src} evaluates to another value at {level:?}"
+        );
+    }
+}
+
+/// Returns the size of the bytecode of `src`, and of the functions it declares.
+fn code_size(src: &str, level: OptimizationLevel) -> usize {
+    let mut context = context(level);
+    let statement_list = Parser::new(src.as_bytes()).parse_all(&mut context).unwrap();
+    let code_block = context.compile(&statement_list).unwrap();
+    let mut size = code_block.code.len();
+    let mut functions = code_block.functions.clone();
+    while let Some(function) = functions.pop() {
+        size += function.code.len();
+        functions.extend(function.functions.iter().cloned());
+    }
+    size
+}
+
+#[test]
+fn constant_folding() {
+    let scripts = [
+        "1 + 2 * 3 - 4 / 5",
+        "2 ** 10 % 7",
+        "'a' + 1 + 2",
+        "1 + 2 + 'a'",
+        "-0",
+        "1 / -0",
+        "1 / -(0)",
+        "-(-0)",
+        "+'12' - ~5",
+        "!0 + !'' + !null",
+        "typeof 1 + typeof 'a' + typeof null + typeof 1n + typeof void 0",
+        "(1 << 31) >>> 0 | 0 ^ 7 & 3",
+        "1 < 2 && 2 <= 2 && 3 > 2 && 3 >= 3",
+        "1 == '1' && 1 != 2 && 1 === 1 && 1 !== '1'",
+        "null == undefined",
+        "NaN === NaN",
+        "0.1 + 0.2",
+        "2147483647 + 1",
+        "1e21 + 'x'",
+        "10n ** 20n + 1n",
+        "-(1n)",
+        "~1n",
+        "1n / 0n",
+        "1n + 1",
+        "+1n",
+        "0 && x",
+        "1 || x",
+        "null ?? 'default'",
+        "0 ?? 'unused'",
+        "true && 'rhs'",
+        "'' || 'rhs'",
+        "1 ? 'yes' : x",
+        "0 ? x : 'no'",
+        "let x = 5; 1 + 2 + x",
+        "let y = 5; y + 1 + 2",
+    ];
+    for script in scripts {
+        assert_same_result(script);
+    }
+}
+
+#[test]
+fn dead_branch_removal() {
+    let scripts = [
+        "let a = 1; if (false) { a = 2; } a",
+        "let a = 1; if (true) { a = 2; } else { a = 3; } a",
+        "let a = 1; if (0) a = 2; else a = 3; a",
+        "if (false) { var hoisted = 1; } hoisted",
+        "if (false) { function declared() {} } typeof declared",
+        "if (true) function declared() { return 1; } declared()",
+        "if (false) { let scoped = 1; } typeof scoped",
+        "let n = 0; while (false) { n++; } n",
+        "let n = 0; while (false) { var w = 1; } typeof w",
+        "let n = 0; while (1 > 2) n++; n",
+        "(function () { return 1; var after = 2; })()",
+        "(function () { return typeof after; var after = 2; })()",
+        "(function () { return inner(); function inner() { return 'hoisted'; } })()",
+        "(function () { throw 1; console.log('dead'); })()",
+        "let s = ''; for (let i = 0; i < 3; i++) { if (i == 1) continue; s += i; } s",
+        "let s = ''; for (let i = 0; i < 3; i++) { s += i; break; s += 'dead'; } s",
+        "a: { 1; break a; 2 }",
+        "if (false) { break nowhere; }",
+        "while (false) { continue nowhere; }",
+        "(function () { return; break; })()",
+        "false && x++",
+        "switch (2) { case 1: 'one'; case 2: 'two'; break; 'dead'; default: 'default' }",
+    ];
+    for script in scripts {
+        assert_same_result(script);
+    }
+}
+
+#[test]
+fn bytecode_optimizations() {
+    let scripts = [
+        "let n = 0; for (;;) { if (n++ > 5) { break; } } n",
+        "let n = 0; do { n++; } while (n < 3); n",
+        "let o = { a: 1 }; o.a = 2; o.b = 3; o.a + o.b",
+        "let x; x = 1; x += 2; x++; x",
+        "1; 2; 'unused'; 3",
+        "let [a, , b = 5] = [1, 2]; a + b",
+        "let { p, ...rest } = { p: 1, q: 2 }; p + rest.q",
+        "try { throw new Error('e'); } catch (e) { e.message } finally { 'finally' }",
+        "(function () { try { return 1; } finally { 'cleanup'; } })()",
+        "let s = ''; for (const k in { a: 1, b: 2 }) { s += k; } s",
+        "let s = 0; for (const v of [1, 2, 3]) { if (v == 2) continue; s += v; } s",
+        "function* g() { yield 1; yield* [2, 3]; } [...g()].join()",
+        "class A { #p = 1; get p() { return this.#p; } } new A().p",
+        "class B extends Array { constructor() { super(3); } } new B().length",
+        "let t = 0; outer: for (let i = 0; i < 3; i++) { for (;;) { t++; continue outer; } } t",
+        "(function (a = 1, b = a + 1) { return a + b; })()",
+        "(function () { try { null.x; } catch (e) { return e.stack; } })()",
+        "new Error('positions').stack",
+    ];
+    for script in scripts {
+        assert_same_result(script);
+    }
+}
+
+#[test]
+fn optimized_code_is_smaller() {
+    let src = r#"
+        const answer = 6 * 7;
+        if (false) { console.log("never"); }
+        function f(x) {
+            if (true) { x = x + (1 + 2); }
+            return x;
+            x = 0;
+        }
+        1;
+        let value = f(answer) || "fallback";
+    "#;
+
+    let none = code_size(src, OptimizationLevel::None);
+    let basic = code_size(src, OptimizationLevel::Basic);
+    let full = code_size(src, OptimizationLevel::Full);
+    assert!(basic < none, "{basic} >= {none}");
+    assert!(full < basic, "{full} >= {basic}");
+}
+
+#[test]
+fn async_optimized_code() {
+    let src = r#"
+        var result;
+        async function run() {
+            await null;
+            result = (1 + 1) ? await Promise.resolve("done") : "unreachable";
+        }
+        run();
+    "#;
+    for level in LEVELS {
+        let mut context = context(level);
+        forward(&mut context, src);
+        context.run_jobs().unwrap();
+        assert_eq!(forward(&mut context, "result"), "\"done\"");
+    }
+}
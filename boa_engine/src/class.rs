@@ -30,7 +30,7 @@
 //!         // This is equivalent to `String(arg)`.
 //!         let kind = args.get_or_undefined(0).to_string(context)?;
 //!
-//!         let animal = match kind.as_str() {
+//!         let animal = match kind.to_std_string_escaped().as_str() {
 //!             "cat" => Self::Cat,
 //!             "dog" => Self::Dog,
 //!             _ => Self::Other,
@@ -65,7 +65,7 @@ use crate::{
     builtins::function::NativeFunctionSignature,
     object::{ConstructorBuilder, JsFunction, JsObject, NativeObject, ObjectData, PROTOTYPE},
     property::{Attribute, PropertyDescriptor, PropertyKey},
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue,
 };
 
 /// Native class.
@@ -104,29 +104,35 @@ impl<T: Class> ClassConstructor for T {
         Self: Sized,
     {
         if this.is_undefined() {
-            return context.throw_type_error(format!(
-                "cannot call constructor of native class `{}` without new",
-                T::NAME
-            ));
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "cannot call constructor of native class `{}` without new",
+                    T::NAME
+                ))
+                .into());
         }
 
         let class_constructor = context.global_object().clone().get(T::NAME, context)?;
-        let class_constructor = if let JsValue::Object(ref obj) = class_constructor {
+        let class_constructor = if let Some(obj) = class_constructor.as_object() {
             obj
         } else {
-            return context.throw_type_error(format!(
-                "invalid constructor for native class `{}` ",
-                T::NAME
-            ));
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "invalid constructor for native class `{}` ",
+                    T::NAME
+                ))
+                .into());
         };
         let class_prototype =
-            if let JsValue::Object(ref obj) = class_constructor.get(PROTOTYPE, context)? {
+            if let Some(obj) = class_constructor.get(PROTOTYPE, context)?.as_object() {
                 obj.clone()
             } else {
-                return context.throw_type_error(format!(
-                    "invalid default prototype for native class `{}`",
-                    T::NAME
-                ));
+                return Err(JsNativeError::typ()
+                    .with_message(format!(
+                        "invalid default prototype for native class `{}`",
+                        T::NAME
+                    ))
+                    .into());
             };
 
         let prototype = this
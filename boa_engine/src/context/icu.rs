@@ -2,6 +2,7 @@ use icu_datetime::provider::{
     calendar::{DatePatternsV1Marker, DateSkeletonPatternsV1Marker, DateSymbolsV1Marker},
     week_data::WeekDataV1Marker,
 };
+use icu_decimal::provider::DecimalSymbolsV1Marker;
 use icu_locale_canonicalizer::{
     provider::{AliasesV1Marker, LikelySubtagsV1Marker},
     LocaleCanonicalizer,
@@ -19,6 +20,7 @@ pub trait BoaProvider:
     + ResourceProvider<DateSkeletonPatternsV1Marker>
     + ResourceProvider<OrdinalV1Marker>
     + ResourceProvider<WeekDataV1Marker>
+    + ResourceProvider<DecimalSymbolsV1Marker>
 {
 }
 
@@ -30,6 +32,7 @@ impl<T> BoaProvider for T where
         + ResourceProvider<DateSkeletonPatternsV1Marker>
         + ResourceProvider<OrdinalV1Marker>
         + ResourceProvider<WeekDataV1Marker>
+        + ResourceProvider<DecimalSymbolsV1Marker>
         + ?Sized
 {
 }
@@ -72,7 +75,6 @@ impl Icu {
     }
 
     /// Get the inner icu data provider
-    #[allow(unused)]
     pub(crate) fn provider(&self) -> &dyn BoaProvider {
         self.provider.as_ref()
     }
@@ -6,20 +6,41 @@ use crate::{
     property::PropertyDescriptorBuilder,
     Context,
 };
+use boa_gc::{custom_trace, Finalize, Trace};
+use once_cell::unsync::OnceCell;
 
-#[derive(Debug, Default)]
+/// The intrinsic objects of a [`Realm`](crate::realm::Realm).
+#[derive(Debug, Default, Finalize)]
 pub struct Intrinsics {
     /// Cached standard constructors
     pub(super) constructors: StandardConstructors,
     /// Cached intrinsic objects
-    pub(super) objects: IntrinsicObjects,
+    ///
+    /// These are set once the builtins of the realm are initialized, since creating them
+    /// requires the standard constructors.
+    pub(super) objects: OnceCell<IntrinsicObjects>,
+}
+
+unsafe impl Trace for Intrinsics {
+    custom_trace!(this, {
+        mark(&this.constructors);
+        if let Some(objects) = this.objects.get() {
+            mark(objects);
+        }
+    });
 }
 
 impl Intrinsics {
     /// Return the cached intrinsic objects.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the builtins of the realm were not initialized yet.
     #[inline]
     pub fn objects(&self) -> &IntrinsicObjects {
-        &self.objects
+        self.objects
+            .get()
+            .expect("the intrinsic objects must be initialized")
     }
 
     /// Return the cached standard constructors.
@@ -30,12 +51,19 @@ impl Intrinsics {
 }
 
 /// Store a builtin constructor (such as `Object`) and its corresponding prototype.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Finalize)]
 pub struct StandardConstructor {
     pub(crate) constructor: JsObject,
     pub(crate) prototype: JsObject,
 }
 
+unsafe impl Trace for StandardConstructor {
+    custom_trace!(this, {
+        mark(&this.constructor);
+        mark(&this.prototype);
+    });
+}
+
 impl Default for StandardConstructor {
     fn default() -> Self {
         Self {
@@ -72,7 +100,7 @@ impl StandardConstructor {
 }
 
 /// Cached core standard constructors.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Trace, Finalize)]
 pub struct StandardConstructors {
     async_generator_function: StandardConstructor,
     async_generator: StandardConstructor,
@@ -113,9 +141,24 @@ pub struct StandardConstructors {
     typed_float32_array: StandardConstructor,
     typed_float64_array: StandardConstructor,
     array_buffer: StandardConstructor,
+    shared_array_buffer: StandardConstructor,
     data_view: StandardConstructor,
     date_time_format: StandardConstructor,
+    number_format: StandardConstructor,
     promise: StandardConstructor,
+    weak_ref: StandardConstructor,
+    weak_map: StandardConstructor,
+    weak_set: StandardConstructor,
+    finalization_registry: StandardConstructor,
+    shadow_realm: StandardConstructor,
+    instant: StandardConstructor,
+    plain_date: StandardConstructor,
+    plain_time: StandardConstructor,
+    plain_date_time: StandardConstructor,
+    zoned_date_time: StandardConstructor,
+    duration: StandardConstructor,
+    time_zone: StandardConstructor,
+    calendar: StandardConstructor,
 }
 
 impl Default for StandardConstructors {
@@ -172,9 +215,24 @@ impl Default for StandardConstructors {
             typed_float32_array: StandardConstructor::default(),
             typed_float64_array: StandardConstructor::default(),
             array_buffer: StandardConstructor::default(),
+            shared_array_buffer: StandardConstructor::default(),
             data_view: StandardConstructor::default(),
             date_time_format: StandardConstructor::default(),
+            number_format: StandardConstructor::default(),
             promise: StandardConstructor::default(),
+            weak_ref: StandardConstructor::default(),
+            weak_map: StandardConstructor::default(),
+            weak_set: StandardConstructor::default(),
+            finalization_registry: StandardConstructor::default(),
+            shadow_realm: StandardConstructor::default(),
+            instant: StandardConstructor::default(),
+            plain_date: StandardConstructor::default(),
+            plain_time: StandardConstructor::default(),
+            plain_date_time: StandardConstructor::default(),
+            zoned_date_time: StandardConstructor::default(),
+            duration: StandardConstructor::default(),
+            time_zone: StandardConstructor::default(),
+            calendar: StandardConstructor::default(),
         };
 
         // The value of `Array.prototype` is the Array prototype object.
@@ -193,6 +251,136 @@ impl Default for StandardConstructors {
 }
 
 impl StandardConstructors {
+    /// The number of standard constructors.
+    pub(crate) const COUNT: usize = 57;
+
+    /// Returns all the standard constructors, in a fixed order.
+    pub(crate) fn slots(&self) -> [&StandardConstructor; Self::COUNT] {
+        [
+            &self.async_generator_function,
+            &self.async_generator,
+            &self.object,
+            &self.proxy,
+            &self.date,
+            &self.function,
+            &self.async_function,
+            &self.generator,
+            &self.generator_function,
+            &self.array,
+            &self.bigint,
+            &self.number,
+            &self.boolean,
+            &self.string,
+            &self.regexp,
+            &self.symbol,
+            &self.error,
+            &self.type_error,
+            &self.reference_error,
+            &self.range_error,
+            &self.syntax_error,
+            &self.eval_error,
+            &self.uri_error,
+            &self.aggregate_error,
+            &self.map,
+            &self.set,
+            &self.typed_array,
+            &self.typed_int8_array,
+            &self.typed_uint8_array,
+            &self.typed_uint8clamped_array,
+            &self.typed_int16_array,
+            &self.typed_uint16_array,
+            &self.typed_int32_array,
+            &self.typed_uint32_array,
+            &self.typed_bigint64_array,
+            &self.typed_biguint64_array,
+            &self.typed_float32_array,
+            &self.typed_float64_array,
+            &self.array_buffer,
+            &self.shared_array_buffer,
+            &self.data_view,
+            &self.date_time_format,
+            &self.number_format,
+            &self.promise,
+            &self.weak_ref,
+            &self.weak_map,
+            &self.weak_set,
+            &self.finalization_registry,
+            &self.shadow_realm,
+            &self.instant,
+            &self.plain_date,
+            &self.plain_time,
+            &self.plain_date_time,
+            &self.zoned_date_time,
+            &self.duration,
+            &self.time_zone,
+            &self.calendar,
+        ]
+    }
+
+    /// Returns mutable references to all the standard constructors, in the same order as
+    /// [`StandardConstructors::slots`].
+    pub(crate) fn slots_mut(&mut self) -> [&mut StandardConstructor; Self::COUNT] {
+        [
+            &mut self.async_generator_function,
+            &mut self.async_generator,
+            &mut self.object,
+            &mut self.proxy,
+            &mut self.date,
+            &mut self.function,
+            &mut self.async_function,
+            &mut self.generator,
+            &mut self.generator_function,
+            &mut self.array,
+            &mut self.bigint,
+            &mut self.number,
+            &mut self.boolean,
+            &mut self.string,
+            &mut self.regexp,
+            &mut self.symbol,
+            &mut self.error,
+            &mut self.type_error,
+            &mut self.reference_error,
+            &mut self.range_error,
+            &mut self.syntax_error,
+            &mut self.eval_error,
+            &mut self.uri_error,
+            &mut self.aggregate_error,
+            &mut self.map,
+            &mut self.set,
+            &mut self.typed_array,
+            &mut self.typed_int8_array,
+            &mut self.typed_uint8_array,
+            &mut self.typed_uint8clamped_array,
+            &mut self.typed_int16_array,
+            &mut self.typed_uint16_array,
+            &mut self.typed_int32_array,
+            &mut self.typed_uint32_array,
+            &mut self.typed_bigint64_array,
+            &mut self.typed_biguint64_array,
+            &mut self.typed_float32_array,
+            &mut self.typed_float64_array,
+            &mut self.array_buffer,
+            &mut self.shared_array_buffer,
+            &mut self.data_view,
+            &mut self.date_time_format,
+            &mut self.number_format,
+            &mut self.promise,
+            &mut self.weak_ref,
+            &mut self.weak_map,
+            &mut self.weak_set,
+            &mut self.finalization_registry,
+            &mut self.shadow_realm,
+            &mut self.instant,
+            &mut self.plain_date,
+            &mut self.plain_time,
+            &mut self.plain_date_time,
+            &mut self.zoned_date_time,
+            &mut self.duration,
+            &mut self.time_zone,
+            &mut self.calendar,
+        ]
+    }
+
     #[inline]
     pub fn async_generator_function(&self) -> &StandardConstructor {
         &self.async_generator_function
@@ -388,6 +576,11 @@ impl StandardConstructors {
         &self.array_buffer
     }
 
+    #[inline]
+    pub fn shared_array_buffer(&self) -> &StandardConstructor {
+        &self.shared_array_buffer
+    }
+
     #[inline]
     pub fn data_view(&self) -> &StandardConstructor {
         &self.data_view
@@ -398,14 +591,84 @@ impl StandardConstructors {
         &self.date_time_format
     }
 
+    #[inline]
+    pub fn number_format(&self) -> &StandardConstructor {
+        &self.number_format
+    }
+
     #[inline]
     pub fn promise(&self) -> &StandardConstructor {
         &self.promise
     }
+
+    #[inline]
+    pub fn weak_ref(&self) -> &StandardConstructor {
+        &self.weak_ref
+    }
+
+    #[inline]
+    pub fn weak_map(&self) -> &StandardConstructor {
+        &self.weak_map
+    }
+
+    #[inline]
+    pub fn weak_set(&self) -> &StandardConstructor {
+        &self.weak_set
+    }
+
+    #[inline]
+    pub fn finalization_registry(&self) -> &StandardConstructor {
+        &self.finalization_registry
+    }
+
+    #[inline]
+    pub fn shadow_realm(&self) -> &StandardConstructor {
+        &self.shadow_realm
+    }
+
+    #[inline]
+    pub fn instant(&self) -> &StandardConstructor {
+        &self.instant
+    }
+
+    #[inline]
+    pub fn plain_date(&self) -> &StandardConstructor {
+        &self.plain_date
+    }
+
+    #[inline]
+    pub fn plain_time(&self) -> &StandardConstructor {
+        &self.plain_time
+    }
+
+    #[inline]
+    pub fn plain_date_time(&self) -> &StandardConstructor {
+        &self.plain_date_time
+    }
+
+    #[inline]
+    pub fn zoned_date_time(&self) -> &StandardConstructor {
+        &self.zoned_date_time
+    }
+
+    #[inline]
+    pub fn duration(&self) -> &StandardConstructor {
+        &self.duration
+    }
+
+    #[inline]
+    pub fn time_zone(&self) -> &StandardConstructor {
+        &self.time_zone
+    }
+
+    #[inline]
+    pub fn calendar(&self) -> &StandardConstructor {
+        &self.calendar
+    }
 }
 
 /// Cached intrinsic objects
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Trace, Finalize)]
 pub struct IntrinsicObjects {
     /// %ThrowTypeError% intrinsic object
     throw_type_error: JsObject,
@@ -429,7 +692,7 @@ impl IntrinsicObjects {
 
     /// Get the `%ThrowTypeError%` intrinsic object
     #[inline]
-    pub fn throw_type_error(&self) -> JsObject {
+    pub fn throw_type_error_function(&self) -> JsObject {
         self.throw_type_error.clone()
     }
 
@@ -444,4 +707,47 @@ impl IntrinsicObjects {
     pub fn iterator_prototypes(&self) -> &IteratorPrototypes {
         &self.iterator_prototypes
     }
+
+    /// The number of intrinsic objects.
+    pub(crate) const COUNT: usize = 2 + IteratorPrototypes::COUNT;
+
+    /// Returns all the intrinsic objects, in a fixed order.
+    pub(crate) fn slots(&self) -> [&JsObject; Self::COUNT] {
+        let [iterator, async_iterator, async_from_sync_iterator, array_iterator, set_iterator, string_iterator, regexp_string_iterator, map_iterator, for_in_iterator] =
+            self.iterator_prototypes.slots();
+        [
+            &self.throw_type_error,
+            &self.array_prototype_values,
+            iterator,
+            async_iterator,
+            async_from_sync_iterator,
+            array_iterator,
+            set_iterator,
+            string_iterator,
+            regexp_string_iterator,
+            map_iterator,
+            for_in_iterator,
+        ]
+    }
+
+    /// Creates the intrinsic objects from objects in the order of [`IntrinsicObjects::slots`].
+    pub(crate) fn from_slots(slots: [JsObject; Self::COUNT]) -> Self {
+        let [throw_type_error, array_prototype_values, iterator, async_iterator, async_from_sync_iterator, array_iterator, set_iterator, string_iterator, regexp_string_iterator, map_iterator, for_in_iterator] =
+            slots;
+        Self {
+            throw_type_error,
+            array_prototype_values,
+            iterator_prototypes: IteratorPrototypes::from_slots([
+                iterator,
+                async_iterator,
+                async_from_sync_iterator,
+                array_iterator,
+                set_iterator,
+                string_iterator,
+                regexp_string_iterator,
+                map_iterator,
+                for_in_iterator,
+            ]),
+        }
+    }
 }
@@ -1,30 +1,39 @@
 //! Javascript context.
 
 pub mod intrinsics;
+pub mod snapshot;
 
 #[cfg(feature = "intl")]
 mod icu;
 
-use std::collections::VecDeque;
+#[cfg(test)]
+mod tests;
+
+use std::rc::Rc;
 
 use intrinsics::{IntrinsicObjects, Intrinsics};
 
 #[cfg(feature = "console")]
 use crate::builtins::console::Console;
 use crate::{
-    builtins::{self, function::NativeFunctionSignature},
-    bytecompiler::ByteCompiler,
+    builtins::{self, function::NativeFunctionSignature, weak::FinalizationRegistry},
+    bytecompiler::{ByteCompiler, OptimizationLevel},
     class::{Class, ClassBuilder},
-    job::JobCallback,
-    object::{FunctionBuilder, GlobalPropertyMap, JsObject, ObjectData},
+    environments::CompileTimeEnvironment,
+    job::{FutureJob, JobQueue, NativeJob, SimpleJobQueue},
+    module::{IdleModuleLoader, ModuleLoader},
+    object::{FunctionBuilder, GlobalPropertyMap, JsObject, Object, ObjectData, RefMut},
     property::{Attribute, PropertyDescriptor, PropertyKey},
     realm::Realm,
     syntax::{ast::node::StatementList, parser::ParseError, Parser},
-    vm::{CallFrame, CodeBlock, FinallyReturn, GeneratorResumeKind, Vm},
-    JsResult, JsValue,
+    vm::{
+        CallFrame, CodeBlock, DebuggerState, ExecutionBudget, FinallyReturn, GeneratorResumeKind,
+        InterruptHandle, RuntimeLimits, Vm,
+    },
+    JsNativeError, JsResult, JsString, JsValue,
 };
 
-use boa_gc::Gc;
+use boa_gc::{Cell as GcCell, Gc, Trace, WeakGc};
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
 
@@ -82,8 +91,11 @@ pub use icu::BoaProvider;
 /// ```
 #[derive(Debug)]
 pub struct Context {
-    /// realm holds both the global object and the environment
-    pub(crate) realm: Realm,
+    /// The active realm, which holds the intrinsics and the global object.
+    realm: Realm,
+
+    /// The compile time environment of the code that is being compiled.
+    pub(crate) compile_env: Gc<GcCell<CompileTimeEnvironment>>,
 
     /// String interner in the context.
     interner: Interner,
@@ -92,16 +104,29 @@ pub struct Context {
     #[cfg(feature = "console")]
     console: Console,
 
-    /// Intrinsic objects
-    intrinsics: Intrinsics,
-
     /// ICU related utilities
     #[cfg(feature = "intl")]
     icu: icu::Icu,
 
     pub(crate) vm: Vm,
 
-    pub(crate) promise_job_queue: VecDeque<JobCallback>,
+    /// The host hook used to schedule and run jobs.
+    job_queue: Rc<dyn JobQueue>,
+
+    /// The host hook used to load imported modules.
+    module_loader: Rc<dyn ModuleLoader>,
+
+    /// The targets of `WeakRef`s that must be kept alive until the current job finishes.
+    kept_alive: Vec<JsObject>,
+
+    /// The `FinalizationRegistry` objects created in this context.
+    finalization_registries: Vec<WeakGc<GcCell<Object>>>,
+
+    /// The optimizations applied when compiling code.
+    optimization_level: OptimizationLevel,
+
+    /// Whether the agent of this context can be suspended, e.g. by `Atomics.wait`.
+    can_block: bool,
 }
 
 impl Default for Context {
@@ -146,9 +171,66 @@ impl Context {
     fn create_intrinsics(&mut self) {
         let _timer = Profiler::global().start_event("create_intrinsics", "interpreter");
         // Create intrinsics, add global objects here
+        let objects = IntrinsicObjects::init(self);
+        let cell = &self.realm.intrinsics().objects;
+        assert!(
+            cell.set(objects).is_ok(),
+            "the intrinsics of a realm must be initialized once"
+        );
+        // SAFETY: The objects were moved into the realm, which is stored in the heap and
+        // traces them, so they must not be roots anymore.
+        unsafe {
+            cell.get()
+                .expect("the intrinsic objects were just initialized")
+                .unroot();
+        }
         builtins::init(self);
     }
 
+    /// Creates a new [`Realm`] with its own intrinsics and global object.
+    ///
+    /// Objects created in the new realm use its intrinsics, so e.g. an array created in it is
+    /// not an instance of the `Array` constructor of the current realm. The active realm of
+    /// the context is not changed.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa_engine::Context;
+    /// let mut context = Context::default();
+    ///
+    /// let realm = context.create_realm();
+    /// let old_realm = context.enter_realm(realm);
+    /// context.eval("var x = 1").unwrap();
+    /// context.enter_realm(old_realm);
+    ///
+    /// assert!(context.eval("typeof x").unwrap().as_string().unwrap() == "undefined");
+    /// ```
+    pub fn create_realm(&mut self) -> Realm {
+        let _timer = Profiler::global().start_event("Context::create_realm", "realm");
+
+        let realm = Realm::create();
+        let old_realm = self.enter_realm(realm);
+        self.create_intrinsics();
+        self.enter_realm(old_realm)
+    }
+
+    /// Makes `realm` the active realm of the context, returning the previously active realm.
+    ///
+    /// Scripts evaluated with [`Context::eval`] run in the global environment of the active
+    /// realm, and builtin objects are created with its intrinsics. Functions always run in the
+    /// realm they were created in, regardless of the active realm.
+    #[inline]
+    pub fn enter_realm(&mut self, realm: Realm) -> Realm {
+        self.compile_env = realm.compile_environment();
+        std::mem::replace(&mut self.realm, realm)
+    }
+
+    /// Returns the active realm of the context.
+    #[inline]
+    pub fn realm(&self) -> &Realm {
+        &self.realm
+    }
+
     /// Constructs an object with the `%Object.prototype%` prototype.
     #[inline]
     pub fn construct_object(&self) -> JsObject {
@@ -210,7 +292,11 @@ impl Context {
         // 2. If IsCallable(F) is false, throw a TypeError exception.
         // 3. Return ? F.[[Call]](V, argumentsList).
         f.as_callable()
-            .ok_or_else(|| self.construct_type_error("Value is not callable"))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("Value is not callable")
+                    .into()
+            })
             .and_then(|f| f.call(v, arguments_list, self))
     }
 
@@ -222,200 +308,53 @@ impl Context {
 
     /// Return a mutable reference to the global object string bindings.
     #[inline]
-    pub(crate) fn global_bindings_mut(&mut self) -> &mut GlobalPropertyMap {
-        self.realm.global_bindings_mut()
-    }
-
-    /// Constructs a `Error` with the specified message.
-    #[inline]
-    pub fn construct_error<M>(&mut self, message: M) -> JsValue
-    where
-        M: Into<Box<str>>,
-    {
-        crate::builtins::error::Error::constructor(
-            &self
-                .intrinsics()
-                .constructors()
-                .error()
-                .constructor()
-                .into(),
-            &[message.into().into()],
-            self,
-        )
-        .expect("Into<String> used as message")
-    }
-
-    /// Throws a `Error` with the specified message.
-    #[inline]
-    pub fn throw_error<M, R>(&mut self, message: M) -> JsResult<R>
-    where
-        M: Into<Box<str>>,
-    {
-        Err(self.construct_error(message))
-    }
-
-    /// Constructs a `RangeError` with the specified message.
-    #[inline]
-    pub fn construct_range_error<M>(&mut self, message: M) -> JsValue
-    where
-        M: Into<Box<str>>,
-    {
-        crate::builtins::error::RangeError::constructor(
-            &self
-                .intrinsics()
-                .constructors()
-                .range_error()
-                .constructor()
-                .into(),
-            &[message.into().into()],
-            self,
-        )
-        .expect("Into<String> used as message")
-    }
-
-    /// Throws a `RangeError` with the specified message.
-    #[inline]
-    pub fn throw_range_error<M, R>(&mut self, message: M) -> JsResult<R>
-    where
-        M: Into<Box<str>>,
-    {
-        Err(self.construct_range_error(message))
-    }
-
-    /// Constructs a `TypeError` with the specified message.
-    #[inline]
-    pub fn construct_type_error<M>(&mut self, message: M) -> JsValue
-    where
-        M: Into<Box<str>>,
-    {
-        crate::builtins::error::TypeError::constructor(
-            &self
-                .intrinsics()
-                .constructors()
-                .type_error()
-                .constructor()
-                .into(),
-            &[message.into().into()],
-            self,
-        )
-        .expect("Into<String> used as message")
-    }
-
-    /// Throws a `TypeError` with the specified message.
-    #[inline]
-    pub fn throw_type_error<M, R>(&mut self, message: M) -> JsResult<R>
-    where
-        M: Into<Box<str>>,
-    {
-        Err(self.construct_type_error(message))
-    }
-
-    /// Constructs a `ReferenceError` with the specified message.
-    #[inline]
-    pub fn construct_reference_error<M>(&mut self, message: M) -> JsValue
-    where
-        M: Into<Box<str>>,
-    {
-        crate::builtins::error::ReferenceError::constructor(
-            &self
-                .intrinsics()
-                .constructors()
-                .reference_error()
-                .constructor()
-                .into(),
-            &[message.into().into()],
-            self,
-        )
-        .expect("Into<String> used as message")
-    }
-
-    /// Throws a `ReferenceError` with the specified message.
-    #[inline]
-    pub fn throw_reference_error<M, R>(&mut self, message: M) -> JsResult<R>
-    where
-        M: Into<Box<str>>,
-    {
-        Err(self.construct_reference_error(message))
+    pub(crate) fn global_bindings_mut(&mut self) -> RefMut<'_, Object, GlobalPropertyMap> {
+        RefMut::map(self.realm.global_object().borrow_mut(), |global| {
+            global.properties_mut().string_property_map_mut()
+        })
     }
 
-    /// Constructs a `SyntaxError` with the specified message.
-    #[inline]
-    pub fn construct_syntax_error<M>(&mut self, message: M) -> JsValue
-    where
-        M: Into<Box<str>>,
-    {
-        crate::builtins::error::SyntaxError::constructor(
-            &self
-                .intrinsics()
-                .constructors()
-                .syntax_error()
-                .constructor()
-                .into(),
-            &[message.into().into()],
-            self,
-        )
-        .expect("Into<String> used as message")
-    }
-
-    /// Throws a `SyntaxError` with the specified message.
+    /// Gets a global binding of the current realm, i.e. a string keyed own property of its
+    /// global object.
+    ///
+    /// The property map of the global object is read directly, since going through its internal
+    /// methods would make every access to a global variable much slower.
     #[inline]
-    pub fn throw_syntax_error<M, R>(&mut self, message: M) -> JsResult<R>
-    where
-        M: Into<Box<str>>,
-    {
-        Err(self.construct_syntax_error(message))
-    }
-
-    /// Constructs a `EvalError` with the specified message.
-    pub fn construct_eval_error<M>(&mut self, message: M) -> JsValue
-    where
-        M: Into<Box<str>>,
-    {
-        crate::builtins::error::EvalError::constructor(
-            &self
-                .intrinsics()
-                .constructors()
-                .eval_error()
-                .constructor()
-                .into(),
-            &[message.into().into()],
-            self,
-        )
-        .expect("Into<String> used as message")
-    }
-
-    /// Constructs a `URIError` with the specified message.
-    pub fn construct_uri_error<M>(&mut self, message: M) -> JsValue
-    where
-        M: Into<Box<str>>,
-    {
-        crate::builtins::error::UriError::constructor(
-            &self
-                .intrinsics()
-                .constructors()
-                .uri_error()
-                .constructor()
-                .into(),
-            &[message.into().into()],
-            self,
-        )
-        .expect("Into<String> used as message")
+    pub(crate) fn global_binding(&self, key: &JsString) -> Option<PropertyDescriptor> {
+        self.realm
+            .global_object()
+            .borrow()
+            .properties()
+            .string_property(key)
+            .cloned()
     }
 
-    /// Throws a `EvalError` with the specified message.
-    pub fn throw_eval_error<M, R>(&mut self, message: M) -> JsResult<R>
-    where
-        M: Into<Box<str>>,
-    {
-        Err(self.construct_eval_error(message))
-    }
+    /// Sets a global binding of the current realm to `value`, like calling the `[[Set]]`
+    /// internal method of its global object with the global object as the receiver.
+    ///
+    /// Writable data properties, e.g. the ones created by `var` declarations, are updated in
+    /// place in the property map of the global object, without rooting all its other
+    /// properties like a mutable borrow would. Any other property goes through `[[Set]]`.
+    pub(crate) fn set_global_binding(&mut self, key: JsString, value: JsValue) -> JsResult<bool> {
+        let global = self.realm.global_object().clone();
+
+        // SAFETY: Looking up a property doesn't allocate, so it can't trigger a collection.
+        let replaced = unsafe {
+            global.inner().replace_component(
+                |object| {
+                    object
+                        .properties_mut()
+                        .string_property_mut(&key)
+                        .and_then(PropertyDescriptor::writable_value_mut)
+                },
+                value,
+            )
+        };
 
-    /// Throws a `URIError` with the specified message.
-    pub fn throw_uri_error<M>(&mut self, message: M) -> JsResult<JsValue>
-    where
-        M: Into<Box<str>>,
-    {
-        Err(self.construct_uri_error(message))
+        match replaced {
+            Ok(_) => Ok(true),
+            Err(value) => global.__set__(key.into(), value, global.clone().into(), self),
+        }
     }
 
     /// Register a global native function.
@@ -513,18 +452,75 @@ impl Context {
     ///
     /// # Note #2
     ///
-    /// This function will only accept `Copy` closures, meaning you cannot
-    /// move `Clone` types, just `Copy` types. If you need to move `Clone` types
-    /// as captures, see [`FunctionBuilder::closure_with_captures`].
-    ///
-    /// See <https://github.com/boa-dev/boa/issues/1515> for an explanation on
-    /// why we need to restrict the set of accepted closures.
+    /// This function will only accept `Copy` closures, which cannot own garbage collected
+    /// values. If you need to capture `JsValue`s, see
+    /// [`FunctionBuilder::closure_with_captures`], and for closures that own non-`Copy` Rust
+    /// state, see [`Context::register_global_closure_with_state`].
     #[inline]
     pub fn register_global_closure<F>(&mut self, name: &str, length: usize, body: F) -> JsResult<()>
     where
         F: Fn(&JsValue, &[JsValue], &mut Self) -> JsResult<JsValue> + Copy + 'static,
     {
-        let function = FunctionBuilder::closure(self, body)
+        // SAFETY: `Copy` closures cannot own garbage collected values.
+        unsafe { self.register_global_closure_unchecked(name, length, body) }
+    }
+
+    /// Register a global closure function that owns Rust state, like an `Rc<RefCell<T>>` or a
+    /// channel sender.
+    ///
+    /// This is the same as [`Context::register_global_closure`], but the closure also receives
+    /// a reference to `state`, which is traced by the garbage collector. See
+    /// [`FunctionBuilder::closure_with_state`] for the details.
+    #[inline]
+    pub fn register_global_closure_with_state<F, T>(
+        &mut self,
+        name: &str,
+        length: usize,
+        body: F,
+        state: T,
+    ) -> JsResult<()>
+    where
+        F: Fn(&JsValue, &[JsValue], &T, &mut Self) -> JsResult<JsValue> + Copy + 'static,
+        T: Trace + 'static,
+    {
+        let function = FunctionBuilder::closure_with_state(self, body, state)
+            .name(name)
+            .length(length)
+            .constructor(true)
+            .build();
+
+        self.global_bindings_mut().insert(
+            name.into(),
+            PropertyDescriptor::builder()
+                .value(function)
+                .writable(true)
+                .enumerable(false)
+                .configurable(true)
+                .build(),
+        );
+        Ok(())
+    }
+
+    /// Register a global closure function that owns arbitrary Rust state, without tracing it.
+    ///
+    /// This is the same as [`Context::register_global_closure`], but it also accepts
+    /// closures that are not `Copy`. Prefer [`Context::register_global_closure_with_state`].
+    ///
+    /// # Safety
+    ///
+    /// The closure must not own any garbage collected value. See
+    /// [`FunctionBuilder::closure_unchecked`] for the details.
+    #[inline]
+    pub unsafe fn register_global_closure_unchecked<F>(
+        &mut self,
+        name: &str,
+        length: usize,
+        body: F,
+    ) -> JsResult<()>
+    where
+        F: Fn(&JsValue, &[JsValue], &mut Self) -> JsResult<JsValue> + 'static,
+    {
+        let function = FunctionBuilder::closure_unchecked(self, body)
             .name(name)
             .length(length)
             .constructor(true)
@@ -627,8 +623,8 @@ impl Context {
         K: Into<PropertyKey>,
         V: Into<JsValue>,
     {
-        self.realm.global_property_map.insert(
-            &key.into(),
+        self.realm.global_object().borrow_mut().insert(
+            key,
             PropertyDescriptor::builder()
                 .value(value)
                 .writable(attribute.writable())
@@ -650,24 +646,55 @@ impl Context {
     /// assert!(value.is_number());
     /// assert_eq!(value.as_number().unwrap(), 4.0);
     /// ```
-    #[allow(clippy::unit_arg, clippy::drop_copy)]
+    #[inline]
     pub fn eval<S>(&mut self, src: S) -> JsResult<JsValue>
     where
         S: AsRef<[u8]>,
     {
+        self.eval_script(src.as_ref(), None)
+    }
+
+    /// Evaluates the given code like [`Context::eval`], naming the script `source_name`.
+    ///
+    /// The name is shown with the source positions in the stack traces of errors thrown
+    /// by the script, e.g. the path of the file the code was read from.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa_engine::Context;
+    /// let mut context = Context::default();
+    ///
+    /// let stack = context
+    ///     .eval_with_source_name("new Error('boom').stack", "main.js")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     stack.as_string().unwrap().to_std_string_escaped(),
+    ///     "Error: boom\n    at <main> (main.js:1:1)"
+    /// );
+    /// ```
+    #[inline]
+    pub fn eval_with_source_name<S, N>(&mut self, src: S, source_name: N) -> JsResult<JsValue>
+    where
+        S: AsRef<[u8]>,
+        N: AsRef<str>,
+    {
+        self.eval_script(src.as_ref(), Some(JsString::new(source_name)))
+    }
+
+    /// Parses, compiles and executes a script.
+    #[allow(clippy::unit_arg, clippy::drop_copy)]
+    fn eval_script(&mut self, src: &[u8], source_name: Option<JsString>) -> JsResult<JsValue> {
         let main_timer = Profiler::global().start_event("Evaluation", "Main");
 
-        let parsing_result = Parser::new(src.as_ref())
-            .parse_all(self)
-            .map_err(|e| e.to_string());
+        let parsing_result = Parser::new(src).parse_all(self).map_err(|e| e.to_string());
 
         let statement_list = match parsing_result {
             Ok(statement_list) => statement_list,
-            Err(e) => return self.throw_syntax_error(e),
+            Err(e) => return Err(JsNativeError::syntax().with_message(e).into()),
         };
 
-        let code_block = self.compile(&statement_list)?;
-        let result = self.execute(code_block);
+        let result = self.execute_script(&statement_list, source_name);
 
         // The main_timer needs to be dropped before the Profiler is.
         drop(main_timer);
@@ -676,13 +703,58 @@ impl Context {
         result
     }
 
+    /// Compiles and executes a parsed script in the global environment of the active realm.
+    pub(crate) fn execute_script(
+        &mut self,
+        statement_list: &StatementList,
+        source_name: Option<JsString>,
+    ) -> JsResult<JsValue> {
+        let code_block = self.compile_script(statement_list, source_name)?;
+
+        // Scripts run in the global environment of the active realm, even if they are
+        // evaluated while other code is running.
+        let environments =
+            std::mem::replace(&mut self.vm.environments, self.realm.environment().clone());
+        let result = self.execute(code_block);
+        self.vm.environments = environments;
+
+        result
+    }
+
     /// Compile the AST into a `CodeBlock` ready to be executed by the VM.
     #[inline]
     pub fn compile(&mut self, statement_list: &StatementList) -> JsResult<Gc<CodeBlock>> {
+        self.compile_script(statement_list, None)
+    }
+
+    /// Compile the AST into a `CodeBlock` like [`Self::compile()`], naming the script
+    /// `source_name`.
+    ///
+    /// The name is shown with the source positions in the stack traces of errors thrown
+    /// by the script.
+    #[inline]
+    pub fn compile_with_source_name<N>(
+        &mut self,
+        statement_list: &StatementList,
+        source_name: N,
+    ) -> JsResult<Gc<CodeBlock>>
+    where
+        N: AsRef<str>,
+    {
+        self.compile_script(statement_list, Some(JsString::new(source_name)))
+    }
+
+    /// Compile the AST of a script with the given name into a `CodeBlock`.
+    fn compile_script(
+        &mut self,
+        statement_list: &StatementList,
+        source_name: Option<JsString>,
+    ) -> JsResult<Gc<CodeBlock>> {
         let _timer = Profiler::global().start_event("Compilation", "Main");
         let mut compiler = ByteCompiler::new(Sym::MAIN, statement_list.strict(), self);
+        compiler.set_source_name(source_name);
         compiler.create_declarations(statement_list.items())?;
-        compiler.compile_statement_list(statement_list.items(), true)?;
+        compiler.compile_statement_list(statement_list, true)?;
         Ok(Gc::new(compiler.finish()))
     }
 
@@ -696,7 +768,7 @@ impl Context {
         let _timer = Profiler::global().start_event("Compilation", "Main");
         let mut compiler = ByteCompiler::new(Sym::MAIN, statement_list.strict(), self);
         compiler.compile_statement_list_with_new_declarative(
-            statement_list.items(),
+            statement_list,
             true,
             strict || statement_list.strict(),
         )?;
@@ -730,28 +802,115 @@ impl Context {
             generator_resume_kind: GeneratorResumeKind::Normal,
             thrown: false,
             async_generator: None,
+            tail_call: None,
+            suspended_environments: None,
         });
 
-        self.realm.set_global_binding_number();
+        self.set_global_binding_number();
         let result = self.run();
         self.vm.pop_frame();
-        self.run_queued_jobs()?;
+        self.run_jobs()?;
         let (result, _) = result?;
         Ok(result)
     }
 
-    /// Runs all the jobs in the job queue.
-    fn run_queued_jobs(&mut self) -> JsResult<()> {
-        while let Some(job) = self.promise_job_queue.pop_front() {
-            job.call_job_callback(&JsValue::Undefined, &[], self)?;
-        }
-        Ok(())
+    /// Set the number of bindings on the global environment.
+    #[inline]
+    pub(crate) fn set_global_binding_number(&mut self) {
+        let binding_number = self.realm.compile_environment().borrow().num_bindings();
+        self.vm
+            .environments
+            .set_global_binding_number(binding_number);
+    }
+
+    /// Runs all the jobs in the job queue of the context.
+    ///
+    /// This is done automatically after evaluating a script or module, but hosts can call it
+    /// to run the reactions of promises settled from Rust code.
+    pub fn run_jobs(&mut self) -> JsResult<()> {
+        self.clear_kept_objects();
+        self.enqueue_finalization_cleanup_jobs();
+        self.job_queue().run_jobs(self)
+    }
+
+    /// Asynchronously runs all the jobs in the job queue of the context.
+    ///
+    /// See [`JobQueue::run_jobs_async`] for more information.
+    pub async fn run_jobs_async(&mut self) -> JsResult<()> {
+        self.clear_kept_objects();
+        self.enqueue_finalization_cleanup_jobs();
+        let job_queue = self.job_queue();
+        job_queue.run_jobs_async(self).await
+    }
+
+    /// Abstract operation `AddToKeptObjects ( value )`
+    ///
+    /// Keeps `object` alive until [`Context::clear_kept_objects`] is called, so a `WeakRef`
+    /// created or dereferenced in the current job keeps returning its target.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-addtokeptobjects
+    pub(crate) fn add_to_kept_objects(&mut self, object: JsObject) {
+        self.kept_alive.push(object);
     }
 
-    /// Return the intrinsic constructors and objects.
+    /// Abstract operation `ClearKeptObjects ( )`
+    ///
+    /// Releases the objects kept alive by `WeakRef`s since the last call. This is done
+    /// automatically before running jobs, but hosts that run code in other ways can call it
+    /// when a synchronous sequence of ECMAScript executions completes.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-clear-kept-objects
+    pub fn clear_kept_objects(&mut self) {
+        self.kept_alive.clear();
+    }
+
+    /// Tracks a new `FinalizationRegistry`, so its cleanup jobs are enqueued once its targets
+    /// are collected.
+    pub(crate) fn register_finalization_registry(&mut self, registry: &JsObject) {
+        self.finalization_registries
+            .push(WeakGc::new(registry.inner()));
+    }
+
+    /// Enqueues the cleanup jobs of the live `FinalizationRegistry` objects whose targets were
+    /// collected.
+    fn enqueue_finalization_cleanup_jobs(&mut self) {
+        self.finalization_registries.retain(WeakGc::is_alive);
+        let registries: Vec<_> = self
+            .finalization_registries
+            .iter()
+            .filter_map(WeakGc::upgrade)
+            .map(JsObject::from)
+            .collect();
+        FinalizationRegistry::enqueue_cleanup_jobs(&registries, self);
+    }
+
+    /// Enqueues a [`FutureJob`] in the job queue of the context.
+    pub fn enqueue_future_job(&mut self, future: FutureJob) {
+        self.job_queue().enqueue_future_job(future, self);
+    }
+
+    /// Return the intrinsic constructors and objects of the active realm.
     #[inline]
     pub fn intrinsics(&self) -> &Intrinsics {
-        &self.intrinsics
+        self.realm.intrinsics()
+    }
+
+    /// Returns the module loader of the context.
+    #[inline]
+    pub fn module_loader(&self) -> Rc<dyn ModuleLoader> {
+        self.module_loader.clone()
+    }
+
+    /// Returns the job queue of the context.
+    #[inline]
+    pub fn job_queue(&self) -> Rc<dyn JobQueue> {
+        self.job_queue.clone()
     }
 
     /// Set the value of trace on the context
@@ -759,6 +918,37 @@ impl Context {
         self.vm.trace = trace;
     }
 
+    /// Gets the optimizations applied when compiling code.
+    #[inline]
+    pub fn optimization_level(&self) -> OptimizationLevel {
+        self.optimization_level
+    }
+
+    /// Sets the optimizations applied when compiling code.
+    ///
+    /// Code that was already compiled is not optimized again.
+    #[inline]
+    pub fn set_optimization_level(&mut self, level: OptimizationLevel) {
+        self.optimization_level = level;
+    }
+
+    /// Returns `true` if the agent of this context can be suspended.
+    ///
+    /// This is the [`AgentCanSuspend`][spec] host hook, which `Atomics.wait` checks before
+    /// blocking the current thread.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-agentcansuspend
+    #[inline]
+    pub fn can_block(&self) -> bool {
+        self.can_block
+    }
+
+    /// Sets whether the agent of this context can be suspended.
+    #[inline]
+    pub fn set_can_block(&mut self, can_block: bool) {
+        self.can_block = can_block;
+    }
+
     #[cfg(feature = "intl")]
     #[inline]
     /// Get the ICU related utilities
@@ -770,12 +960,12 @@ impl Context {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-hostenqueuepromisejob
-    pub fn host_enqueue_promise_job(&mut self, job: JobCallback /* , realm: Realm */) {
+    pub fn host_enqueue_promise_job(&mut self, job: NativeJob /* , realm: Realm */) {
         // If realm is not null ...
         // TODO
         // Let scriptOrModule be ...
         // TODO
-        self.promise_job_queue.push_back(job);
+        self.job_queue().enqueue_promise_job(job, self);
     }
 }
 /// Builder for the [`Context`] type.
@@ -795,6 +985,13 @@ pub struct ContextBuilder {
     interner: Option<Interner>,
     #[cfg(feature = "intl")]
     icu: Option<icu::Icu>,
+    module_loader: Option<Rc<dyn ModuleLoader>>,
+    job_queue: Option<Rc<dyn JobQueue>>,
+    runtime_limits: Option<RuntimeLimits>,
+    interrupt_handle: Option<InterruptHandle>,
+    heap_limit: Option<usize>,
+    optimization_level: OptimizationLevel,
+    can_block: Option<bool>,
 }
 
 impl ContextBuilder {
@@ -808,6 +1005,78 @@ impl ContextBuilder {
         self
     }
 
+    /// Initializes the [`ModuleLoader`] used by the context to load imported modules.
+    ///
+    /// By default, the context uses an [`IdleModuleLoader`], which fails to load any module.
+    #[must_use]
+    pub fn module_loader(mut self, module_loader: Rc<dyn ModuleLoader>) -> Self {
+        self.module_loader = Some(module_loader);
+        self
+    }
+
+    /// Initializes the [`JobQueue`] used by the context to schedule and run jobs.
+    ///
+    /// By default, the context uses a [`SimpleJobQueue`], which runs all pending jobs after
+    /// each evaluation.
+    #[must_use]
+    pub fn job_queue(mut self, job_queue: Rc<dyn JobQueue>) -> Self {
+        self.job_queue = Some(job_queue);
+        self
+    }
+
+    /// Initializes the [`RuntimeLimits`] of the context.
+    ///
+    /// By default, scripts can run without any limit.
+    #[must_use]
+    pub fn runtime_limits(mut self, runtime_limits: RuntimeLimits) -> Self {
+        self.runtime_limits = Some(runtime_limits);
+        self
+    }
+
+    /// Sets the maximum size in bytes of the heap when the context is built.
+    ///
+    /// The heap is shared by all the contexts of the current thread, so the limit applies to all
+    /// of them. It includes the memory of strings, array buffers and array elements. Scripts that
+    /// outgrow it are terminated with an uncatchable out of memory error.
+    ///
+    /// By default, the heap limit of the thread is left unchanged. See
+    /// [`boa_gc::set_heap_limit`].
+    #[must_use]
+    pub fn heap_limit(mut self, heap_limit: usize) -> Self {
+        self.heap_limit = Some(heap_limit);
+        self
+    }
+
+    /// Initializes the [`OptimizationLevel`] of the context.
+    ///
+    /// By default, code is compiled without optimizations.
+    #[must_use]
+    pub fn optimization_level(mut self, optimization_level: OptimizationLevel) -> Self {
+        self.optimization_level = optimization_level;
+        self
+    }
+
+    /// Sets whether the agent of the context can be suspended, e.g. by `Atomics.wait`.
+    ///
+    /// By default, the agent can be suspended. Hosts running the context on a thread that must
+    /// not block, like the main thread of an event loop, should disable this.
+    #[must_use]
+    pub fn can_block(mut self, can_block: bool) -> Self {
+        self.can_block = Some(can_block);
+        self
+    }
+
+    /// Initializes the [`InterruptHandle`] used to interrupt the execution of the context.
+    ///
+    /// This allows creating the handle before the context, e.g. to share it with a
+    /// watchdog thread. The handle can also be retrieved later with
+    /// [`Context::interrupt_handle`].
+    #[must_use]
+    pub fn interrupt_handle(mut self, interrupt_handle: InterruptHandle) -> Self {
+        self.interrupt_handle = Some(interrupt_handle);
+        self
+    }
+
     /// Provides an icu data provider to the [`Context`].
     ///
     /// This function is only available if the `intl` feature is enabled.
@@ -826,18 +1095,37 @@ impl ContextBuilder {
     /// Builds a new [`Context`] with the provided parameters, and defaults
     /// all missing parameters to their default values.
     pub fn build(self) -> Context {
-        let intrinsics = Intrinsics::default();
-        let mut context = Context {
-            realm: Realm::create(intrinsics.constructors().object().prototype().into()),
+        let mut context = self.build_with_realm(Realm::create());
+
+        // Add new builtIns to Context Realm
+        // At a later date this can be removed from here and called explicitly,
+        // but for now we almost always want these default builtins
+        context.create_intrinsics();
+        context
+    }
+
+    /// Builds a new [`Context`] running in `realm`, without initializing its intrinsics.
+    fn build_with_realm(self, realm: Realm) -> Context {
+        if let Some(heap_limit) = self.heap_limit {
+            boa_gc::set_heap_limit(Some(heap_limit));
+        }
+
+        Context {
+            compile_env: realm.compile_environment(),
             interner: self.interner.unwrap_or_default(),
             #[cfg(feature = "console")]
             console: Console::default(),
-            intrinsics,
             vm: Vm {
+                environments: realm.environment().clone(),
                 frames: Vec::with_capacity(16),
                 stack: Vec::with_capacity(1024),
                 trace: false,
                 stack_size_limit: 1024,
+                budget: ExecutionBudget::new(
+                    self.runtime_limits.unwrap_or_default(),
+                    self.interrupt_handle.unwrap_or_default(),
+                ),
+                debugger: DebuggerState::default(),
             },
             #[cfg(feature = "intl")]
             icu: self.icu.unwrap_or_else(|| {
@@ -845,14 +1133,17 @@ impl ContextBuilder {
                 icu::Icu::new(Box::new(icu_testdata::get_provider()))
                     .expect("Failed to initialize default icu data.")
             }),
-            promise_job_queue: VecDeque::new(),
-        };
-
-        // Add new builtIns to Context Realm
-        // At a later date this can be removed from here and called explicitly,
-        // but for now we almost always want these default builtins
-        context.intrinsics.objects = IntrinsicObjects::init(&mut context);
-        context.create_intrinsics();
-        context
+            job_queue: self
+                .job_queue
+                .unwrap_or_else(|| Rc::new(SimpleJobQueue::new())),
+            module_loader: self
+                .module_loader
+                .unwrap_or_else(|| Rc::new(IdleModuleLoader)),
+            kept_alive: Vec::new(),
+            finalization_registries: Vec::new(),
+            optimization_level: self.optimization_level,
+            can_block: self.can_block.unwrap_or(true),
+            realm,
+        }
     }
 }
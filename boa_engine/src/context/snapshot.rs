@@ -0,0 +1,1273 @@
+//! Snapshots of the heap of a [`Context`].
+//!
+//! A snapshot holds the realm of a context, with its intrinsics, its global object and every
+//! object reachable from them, its global environment and the string interner of the context.
+//! It is written with [`Context::snapshot`], usually after running the setup code of an
+//! application, and new contexts are restored from it with
+//! [`ContextBuilder::build_from_snapshot`], which is much faster than building a context and
+//! running the setup code again.
+//!
+//! Native functions are Rust function pointers, which cannot be written to a snapshot, so they
+//! are written as the name they have in a [`NativeFunctionTable`].
+//!
+//! Not every object can be written to a snapshot. Closures and objects holding an execution
+//! state (like generators, iterators and pending promises) make writing the snapshot fail.
+//! Jobs queued in the job queue of the context are not part of the snapshot.
+
+use std::collections::VecDeque;
+
+use crate::{
+    builtins::{
+        array_buffer::{ArrayBuffer, DataBlock},
+        date::Date,
+        error::ErrorKind,
+        function::{
+            BoundFunction, ClassFieldDefinition, ConstructorKind, Function, NativeFunctionSignature,
+        },
+        map::ordered_map::OrderedMap,
+        promise::PromiseCapability,
+        regexp::RegExp,
+        set::ordered_set::OrderedSet,
+        shared_array_buffer::SharedDataBlock,
+        symbol::Symbol,
+    },
+    context::{
+        intrinsics::{IntrinsicObjects, Intrinsics, StandardConstructor, StandardConstructors},
+        Context, ContextBuilder,
+    },
+    environments::{
+        CompileTimeEnvironment, DeclarativeEnvironment, DeclarativeEnvironmentStack,
+        EnvironmentSlots, FunctionSlots, ThisBindingStatus,
+    },
+    object::{JsFunction, JsObject, Object, ObjectData, ObjectKind, PrivateElement, PropertyMap},
+    property::{PropertyDescriptor, PropertyKey},
+    realm::Realm,
+    vm::{CodeBlock, Decoder, Encoder},
+    JsBigInt, JsNativeError, JsResult, JsSymbol, JsValue, JsVariant,
+};
+use boa_gc::{Cell as GcCell, ExternalMemory, Gc};
+use boa_interner::Sym;
+use chrono::NaiveDateTime;
+use once_cell::unsync::OnceCell;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// The bytes every snapshot starts with.
+const MAGIC: [u8; 4] = *b"BOAS";
+
+/// A table of the native functions that can be written to a snapshot, by name.
+///
+/// The table created by [`NativeFunctionTable::new`] holds the native functions of the
+/// builtins. Native functions of the host, e.g. those registered with
+/// [`Context::register_global_function`], must be registered with
+/// [`NativeFunctionTable::register`] before writing and restoring a snapshot.
+///
+/// The same names must be registered when writing and when restoring the snapshot, but the
+/// functions are looked up again, so the table can be created in another process.
+#[derive(Debug, Clone)]
+pub struct NativeFunctionTable {
+    names: FxHashMap<usize, String>,
+    functions: FxHashMap<String, NativeFunctionSignature>,
+}
+
+impl Default for NativeFunctionTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NativeFunctionTable {
+    /// Creates a table with the native functions of the builtins.
+    ///
+    /// The builtins are found by creating a new context and visiting the objects reachable from
+    /// its realm. The names of the functions are the paths to them, like
+    /// `globalThis.Array.prototype.map`. Creating the table is about as slow as creating a
+    /// context, so a table should be created once and reused.
+    pub fn new() -> Self {
+        let mut table = Self {
+            names: FxHashMap::default(),
+            functions: FxHashMap::default(),
+        };
+
+        let context = Context::default();
+        let intrinsics = context.intrinsics();
+        let mut queue = VecDeque::new();
+        queue.push_back((context.global_object().clone(), "globalThis".to_owned()));
+        for (index, slot) in intrinsics.constructors().slots().into_iter().enumerate() {
+            queue.push_back((slot.constructor(), format!("%constructors[{index}]%")));
+            queue.push_back((slot.prototype(), format!("%prototypes[{index}]%")));
+        }
+        for (index, object) in intrinsics.objects().slots().into_iter().enumerate() {
+            queue.push_back((object.clone(), format!("%objects[{index}]%")));
+        }
+
+        let mut visited = FxHashSet::default();
+        while let Some((object, path)) = queue.pop_front() {
+            let key: *const GcCell<Object> = object.as_ref();
+            if !visited.insert(key) {
+                continue;
+            }
+
+            let object = object.borrow();
+            if let Some(Function::Native { function, .. }) = object.as_function() {
+                table.register(&path, *function);
+            }
+            if let Some(prototype) = object.prototype() {
+                queue.push_back((prototype.clone(), format!("{path}.<prototype>")));
+            }
+            for (key, descriptor) in object.properties().iter() {
+                let children = [
+                    (descriptor.value(), format!("{path}.{key}")),
+                    (descriptor.get(), format!("{path}.<get {key}>")),
+                    (descriptor.set(), format!("{path}.<set {key}>")),
+                ];
+                for (value, path) in children {
+                    if let Some(object) = value.and_then(JsValue::as_object) {
+                        queue.push_back((object.clone(), path));
+                    }
+                }
+            }
+        }
+
+        table
+    }
+
+    /// Registers a native function with the given name.
+    ///
+    /// Registering a name again replaces the function of the name. A function registered with
+    /// many names is written to snapshots with the first of them.
+    pub fn register(&mut self, name: &str, function: NativeFunctionSignature) -> &mut Self {
+        self.names
+            .entry(function as usize)
+            .or_insert_with(|| name.to_owned());
+        self.functions.insert(name.to_owned(), function);
+        self
+    }
+
+    /// Returns the name of a native function, if it is registered.
+    fn name(&self, function: NativeFunctionSignature) -> Option<&str> {
+        self.names.get(&(function as usize)).map(String::as_str)
+    }
+
+    /// Returns the native function with the given name, if it is registered.
+    fn function(&self, name: &str) -> Option<NativeFunctionSignature> {
+        self.functions.get(name).copied()
+    }
+}
+
+impl Context {
+    /// Writes a snapshot of the heap of the context.
+    ///
+    /// New contexts can be restored from the snapshot with
+    /// [`ContextBuilder::build_from_snapshot`], with a table holding the same native
+    /// functions as `table`.
+    ///
+    /// Closures cannot be written to a snapshot, since the Rust state they capture is opaque to
+    /// the engine. This includes the host closures created with
+    /// [`Context::register_global_closure`] or [`FunctionBuilder::closure`][closure] and its
+    /// variants, so the functions of the host that must survive a snapshot have to be native
+    /// functions registered in `table`, with their state kept in JavaScript objects or in
+    /// the host.
+    ///
+    /// [closure]: crate::object::FunctionBuilder::closure
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the context is running code, if a native function is not
+    /// registered in `table`, or if a closure or another object that cannot be written to a
+    /// snapshot is reachable from the realm of the context.
+    ///
+    /// Returns a `RangeError` if the heap is too large for the format, which stores lengths
+    /// and indices as 32-bit numbers.
+    pub fn snapshot(&self, table: &NativeFunctionTable) -> JsResult<Vec<u8>> {
+        if !self.vm.frames.is_empty() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot snapshot a context while it is running code")
+                .into());
+        }
+
+        let mut encoder = SnapshotEncoder {
+            encoder: Encoder::new(self.interner()),
+            table,
+            realm: self.realm(),
+            objects: FxHashMap::default(),
+            queue: VecDeque::new(),
+            symbols: FxHashMap::default(),
+            environments: FxHashMap::default(),
+            code_blocks: FxHashMap::default(),
+        };
+
+        let symbols: Vec<_> = self.interner().iter().map(|(sym, _)| sym).collect();
+        encoder.encoder.syms(&symbols);
+        encoder.realm()?;
+        while let Some(object) = encoder.queue.pop_front() {
+            encoder.object_record(&object)?;
+        }
+
+        encoder.encoder.finish(MAGIC)
+    }
+}
+
+impl ContextBuilder {
+    /// Builds a new [`Context`] from a snapshot written by [`Context::snapshot`].
+    ///
+    /// The realm of the context is restored from the snapshot instead of initializing the
+    /// builtins. The other parameters of the builder are used as in [`ContextBuilder::build`],
+    /// and the strings of the snapshot are interned in the interner of the builder.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the snapshot is malformed, if it was written by another version
+    /// of the engine or on another platform, or if a native function of the snapshot is not
+    /// registered in `table`.
+    pub fn build_from_snapshot(
+        mut self,
+        snapshot: &[u8],
+        table: &NativeFunctionTable,
+    ) -> JsResult<Context> {
+        let mut interner = self.interner.take().unwrap_or_default();
+        let mut decoder = SnapshotDecoder {
+            decoder: Decoder::new(snapshot, MAGIC, "snapshot", &mut interner)?,
+            table,
+            realm: None,
+            global: Gc::new(GcCell::new(CompileTimeEnvironment::new_global())),
+            objects: Vec::new(),
+            symbols: Vec::new(),
+            environments: Vec::new(),
+            code_blocks: Vec::new(),
+            async_functions: Vec::new(),
+        };
+
+        decoder.decoder.syms()?;
+        let realm = decoder.realm()?;
+        let mut next = 0;
+        while next < decoder.objects.len() {
+            let object = decoder.objects[next].clone();
+            decoder.object_record(&object)?;
+            next += 1;
+        }
+        decoder.decoder.finish()?;
+
+        self.interner = Some(interner);
+        let mut context = self.build_with_realm(realm);
+
+        // Async functions hold a promise capability, which holds closures, so it is created
+        // again when the context exists.
+        for (object, code, environments) in decoder.async_functions {
+            let promise_capability = PromiseCapability::new(
+                &context
+                    .intrinsics()
+                    .constructors()
+                    .promise()
+                    .constructor()
+                    .into(),
+                &mut context,
+            )?;
+            object.borrow_mut().data = ObjectData::function(Function::Async {
+                code,
+                environments,
+                promise_capability,
+                realm: context.realm().clone(),
+            });
+        }
+
+        Ok(context)
+    }
+}
+
+/// Returns the error of an object that cannot be written to a snapshot.
+fn unsupported(what: &str) -> JsNativeError {
+    JsNativeError::typ().with_message(format!("cannot snapshot {what}"))
+}
+
+/// Writes a snapshot.
+///
+/// Objects, symbols, environments and code blocks are written as an index, which is assigned
+/// when they are first found. Symbols, environments and code blocks are written in full after
+/// their index the first time they are found, while objects are queued and written after the
+/// realm, in the order of their indices.
+struct SnapshotEncoder<'a> {
+    encoder: Encoder<'a>,
+    table: &'a NativeFunctionTable,
+    realm: &'a Realm,
+    objects: FxHashMap<*const GcCell<Object>, usize>,
+    queue: VecDeque<JsObject>,
+    symbols: FxHashMap<u64, usize>,
+    environments: FxHashMap<*const DeclarativeEnvironment, usize>,
+    code_blocks: FxHashMap<*const CodeBlock, usize>,
+}
+
+impl SnapshotEncoder<'_> {
+    fn realm(&mut self) -> JsResult<()> {
+        let realm = self.realm;
+
+        let global = realm.compile_environment();
+        let global = global.borrow();
+        let mut bindings: Vec<_> = global.bindings.iter().collect();
+        bindings.sort_by_key(|(_, binding)| binding.index);
+        self.encoder.usize(bindings.len());
+        for (name, binding) in bindings {
+            self.encoder.sym(*name);
+            self.encoder.binding(binding);
+        }
+
+        self.object(realm.global_object());
+        for slot in realm.intrinsics().constructors().slots() {
+            self.object(&slot.constructor);
+            self.object(&slot.prototype);
+        }
+        for object in realm.intrinsics().objects().slots() {
+            self.object(object);
+        }
+
+        self.environment_stack(realm.environment())
+    }
+
+    fn object(&mut self, object: &JsObject) {
+        let key: *const GcCell<Object> = object.as_ref();
+        let next = self.objects.len();
+        let index = *self.objects.entry(key).or_insert(next);
+        if index == next {
+            self.queue.push_back(object.clone());
+        }
+        self.encoder.usize(index);
+    }
+
+    fn optional_object(&mut self, object: Option<&JsObject>) {
+        self.encoder.bool(object.is_some());
+        if let Some(object) = object {
+            self.object(object);
+        }
+    }
+
+    fn symbol(&mut self, symbol: &JsSymbol) {
+        let next = self.symbols.len();
+        let index = *self.symbols.entry(symbol.hash()).or_insert(next);
+        self.encoder.usize(index);
+        if index != next {
+            return;
+        }
+
+        if let Some(key) = Symbol::registry_key(symbol) {
+            self.encoder.u8(0);
+            self.encoder.utf16(&key);
+            return;
+        }
+        if JsSymbol::reserved(symbol.hash(), None).is_some() {
+            self.encoder.u8(1);
+            self.encoder.u32(symbol.hash() as u32);
+        } else {
+            self.encoder.u8(2);
+        }
+        let description = symbol.description();
+        self.encoder.bool(description.is_some());
+        if let Some(description) = description {
+            self.encoder.utf16(&description);
+        }
+    }
+
+    fn value(&mut self, value: &JsValue) {
+        match value.variant() {
+            JsVariant::Undefined => self.encoder.u8(0),
+            JsVariant::Null => self.encoder.u8(1),
+            JsVariant::Boolean(boolean) => {
+                self.encoder.u8(2);
+                self.encoder.bool(boolean);
+            }
+            JsVariant::String(string) => {
+                self.encoder.u8(3);
+                self.encoder.utf16(string);
+            }
+            JsVariant::Rational(number) => {
+                self.encoder.u8(4);
+                self.encoder.f64(number);
+            }
+            JsVariant::Integer(integer) => {
+                self.encoder.u8(5);
+                self.encoder.u32(integer as u32);
+            }
+            JsVariant::BigInt(bigint) => {
+                self.encoder.u8(6);
+                self.encoder.str(&bigint.to_string_radix(16));
+            }
+            JsVariant::Object(object) => {
+                self.encoder.u8(7);
+                self.object(object);
+            }
+            JsVariant::Symbol(symbol) => {
+                self.encoder.u8(8);
+                self.symbol(symbol);
+            }
+        }
+    }
+
+    fn values(&mut self, values: &[JsValue]) {
+        self.encoder.usize(values.len());
+        for value in values {
+            self.value(value);
+        }
+    }
+
+    fn property_key(&mut self, key: &PropertyKey) {
+        match key {
+            PropertyKey::Index(index) => {
+                self.encoder.u8(0);
+                self.encoder.u32(*index);
+            }
+            PropertyKey::String(string) => {
+                self.encoder.u8(1);
+                self.encoder.utf16(string);
+            }
+            PropertyKey::Symbol(symbol) => {
+                self.encoder.u8(2);
+                self.symbol(symbol);
+            }
+        }
+    }
+
+    fn optional_value(&mut self, value: Option<&JsValue>) {
+        self.encoder.bool(value.is_some());
+        if let Some(value) = value {
+            self.value(value);
+        }
+    }
+
+    fn optional_bool(&mut self, value: Option<bool>) {
+        self.encoder.u8(match value {
+            None => 0,
+            Some(false) => 1,
+            Some(true) => 2,
+        });
+    }
+
+    /// Writes a property descriptor, which may be incomplete for some builtin properties.
+    fn property_descriptor(&mut self, descriptor: &PropertyDescriptor) {
+        if descriptor.is_accessor_descriptor() {
+            self.encoder.u8(1);
+            self.optional_value(descriptor.get());
+            self.optional_value(descriptor.set());
+        } else {
+            self.encoder.u8(0);
+            self.optional_value(descriptor.value());
+            self.optional_bool(descriptor.writable());
+        }
+        self.optional_bool(descriptor.enumerable());
+        self.optional_bool(descriptor.configurable());
+    }
+
+    fn private_element(&mut self, element: &PrivateElement) {
+        match element {
+            PrivateElement::Field(value) => {
+                self.encoder.u8(0);
+                self.value(value);
+            }
+            PrivateElement::Method(method) => {
+                self.encoder.u8(1);
+                self.object(method);
+            }
+            PrivateElement::Accessor { getter, setter } => {
+                self.encoder.u8(2);
+                self.optional_object(getter.as_ref());
+                self.optional_object(setter.as_ref());
+            }
+        }
+    }
+
+    fn check_realm(&self, realm: &Realm) -> JsResult<()> {
+        if realm == self.realm {
+            Ok(())
+        } else {
+            Err(unsupported("functions of other realms").into())
+        }
+    }
+
+    fn environment_stack(&mut self, stack: &DeclarativeEnvironmentStack) -> JsResult<()> {
+        let environments = stack.environments();
+        self.encoder.usize(environments.len());
+        for environment in environments {
+            self.environment(environment)?;
+        }
+        Ok(())
+    }
+
+    fn environment(&mut self, environment: &Gc<DeclarativeEnvironment>) -> JsResult<()> {
+        let key: *const DeclarativeEnvironment = &**environment;
+        let next = self.environments.len();
+        let index = *self.environments.entry(key).or_insert(next);
+        self.encoder.usize(index);
+        if index != next {
+            return Ok(());
+        }
+
+        self.encoder.environment(&environment.compile);
+        self.encoder.bool(*environment.poisoned.borrow());
+        let bindings = environment.bindings.borrow();
+        self.encoder.usize(bindings.len());
+        for binding in bindings.iter() {
+            self.encoder.bool(binding.is_some());
+            if let Some(value) = binding {
+                self.value(value);
+            }
+        }
+
+        match &environment.slots {
+            None => self.encoder.u8(0),
+            Some(EnvironmentSlots::Global) => self.encoder.u8(1),
+            Some(EnvironmentSlots::Function(slots)) => {
+                let slots = slots.borrow();
+                self.encoder.u8(2);
+                self.value(&slots.this);
+                self.encoder.u8(match slots.this_binding_status {
+                    ThisBindingStatus::Lexical => 0,
+                    ThisBindingStatus::Initialized => 1,
+                    ThisBindingStatus::Uninitialized => 2,
+                });
+                self.object(&slots.function_object);
+                self.optional_object(slots.new_target.as_ref());
+            }
+            Some(EnvironmentSlots::Module(_)) => {
+                return Err(unsupported("module environments").into())
+            }
+        }
+        Ok(())
+    }
+
+    fn code_block(&mut self, code: &Gc<CodeBlock>) {
+        let key: *const CodeBlock = &**code;
+        let next = self.code_blocks.len();
+        let index = *self.code_blocks.entry(key).or_insert(next);
+        self.encoder.usize(index);
+        if index == next {
+            self.encoder.code_block(code);
+        }
+    }
+
+    fn function(&mut self, function: &Function) -> JsResult<()> {
+        match function {
+            Function::Native {
+                function,
+                constructor,
+                realm,
+            } => {
+                self.check_realm(realm)?;
+                let name = self
+                    .table
+                    .name(*function)
+                    .ok_or_else(|| unsupported("native functions missing from the table"))?;
+                self.encoder.u8(0);
+                self.encoder.str(name);
+                self.encoder.u8(match constructor {
+                    None => 0,
+                    Some(ConstructorKind::Base) => 1,
+                    Some(ConstructorKind::Derived) => 2,
+                });
+            }
+            Function::Closure { .. } => return Err(unsupported("closures").into()),
+            Function::Ordinary {
+                code,
+                environments,
+                realm,
+                constructor_kind,
+                home_object,
+                fields,
+                private_methods,
+            } => {
+                self.check_realm(realm)?;
+                self.encoder.u8(1);
+                self.code_block(code);
+                self.environment_stack(environments)?;
+                self.encoder.bool(constructor_kind.is_derived());
+                self.optional_object(home_object.as_ref());
+                self.encoder.usize(fields.len());
+                for field in fields {
+                    match field {
+                        ClassFieldDefinition::Public(key, function) => {
+                            self.encoder.u8(0);
+                            self.property_key(key);
+                            self.object(function);
+                        }
+                        ClassFieldDefinition::Private(name, function) => {
+                            self.encoder.u8(1);
+                            self.encoder.sym(*name);
+                            self.object(function);
+                        }
+                    }
+                }
+                self.encoder.usize(private_methods.len());
+                for (name, method) in private_methods {
+                    self.encoder.sym(*name);
+                    self.private_element(method);
+                }
+            }
+            Function::Async {
+                code,
+                environments,
+                realm,
+                ..
+            } => {
+                self.check_realm(realm)?;
+                self.encoder.u8(2);
+                self.code_block(code);
+                self.environment_stack(environments)?;
+            }
+            Function::Generator {
+                code,
+                environments,
+                realm,
+            } => {
+                self.check_realm(realm)?;
+                self.encoder.u8(3);
+                self.code_block(code);
+                self.environment_stack(environments)?;
+            }
+            Function::AsyncGenerator {
+                code,
+                environments,
+                realm,
+            } => {
+                self.check_realm(realm)?;
+                self.encoder.u8(4);
+                self.code_block(code);
+                self.environment_stack(environments)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn object_data(&mut self, object: &JsObject, kind: &ObjectKind) -> JsResult<()> {
+        match kind {
+            ObjectKind::Ordinary => self.encoder.u8(0),
+            ObjectKind::Global => self.encoder.u8(1),
+            ObjectKind::Array => self.encoder.u8(2),
+            ObjectKind::Boolean(boolean) => {
+                self.encoder.u8(3);
+                self.encoder.bool(*boolean);
+            }
+            ObjectKind::Number(number) => {
+                self.encoder.u8(4);
+                self.encoder.f64(*number);
+            }
+            ObjectKind::String(string) => {
+                self.encoder.u8(5);
+                self.encoder.utf16(string);
+            }
+            ObjectKind::Symbol(symbol) => {
+                self.encoder.u8(6);
+                self.symbol(symbol);
+            }
+            ObjectKind::BigInt(bigint) => {
+                self.encoder.u8(7);
+                self.encoder.str(&bigint.to_string_radix(16));
+            }
+            ObjectKind::Error(kind) => {
+                self.encoder.u8(8);
+                self.encoder.u8(match kind {
+                    ErrorKind::Aggregate => 0,
+                    ErrorKind::Error => 1,
+                    ErrorKind::Eval => 2,
+                    ErrorKind::Type => 3,
+                    ErrorKind::Range => 4,
+                    ErrorKind::Reference => 5,
+                    ErrorKind::Syntax => 6,
+                    ErrorKind::Uri => 7,
+                    ErrorKind::Termination => 8,
+                });
+            }
+            ObjectKind::Date(date) => {
+                self.encoder.u8(9);
+                self.encoder.bool(date.0.is_some());
+                if let Some(date) = date.0 {
+                    self.encoder.f64(date.timestamp() as f64);
+                    self.encoder.u32(date.timestamp_subsec_nanos());
+                }
+            }
+            ObjectKind::RegExp(regexp) => {
+                self.encoder.u8(10);
+                self.encoder.utf16(regexp.original_source());
+                self.encoder.utf16(regexp.original_flags());
+            }
+            ObjectKind::Map(map) => {
+                self.encoder.u8(11);
+                self.encoder.usize(map.len());
+                for (key, value) in map.iter() {
+                    self.value(key);
+                    self.value(value);
+                }
+            }
+            ObjectKind::Set(set) => {
+                self.encoder.u8(12);
+                self.encoder.usize(set.size());
+                for value in set {
+                    self.value(value);
+                }
+            }
+            ObjectKind::ArrayBuffer(buffer) => {
+                self.encoder.u8(13);
+                match &buffer.array_buffer_data {
+                    None => self.encoder.u8(0),
+                    Some(DataBlock::Unshared(data)) => {
+                        self.encoder.u8(1);
+                        self.encoder.data(data);
+                    }
+                    // Shared blocks are saved by value; the restored buffer no longer shares its
+                    // bytes with other agents.
+                    Some(DataBlock::Shared(data)) => {
+                        self.encoder.u8(2);
+                        self.encoder.data(&data.to_vec());
+                    }
+                }
+                self.encoder.f64(buffer.array_buffer_byte_length as f64);
+                self.encoder
+                    .bool(buffer.array_buffer_max_byte_length.is_some());
+                if let Some(max_byte_length) = buffer.array_buffer_max_byte_length {
+                    self.encoder.f64(max_byte_length as f64);
+                }
+                self.value(&buffer.array_buffer_detach_key);
+            }
+            ObjectKind::Function(function) => {
+                self.encoder.u8(14);
+                self.function(function)?;
+            }
+            ObjectKind::GeneratorFunction(function) => {
+                self.encoder.u8(15);
+                self.function(function)?;
+            }
+            ObjectKind::AsyncGeneratorFunction(function) => {
+                self.encoder.u8(16);
+                self.function(function)?;
+            }
+            ObjectKind::BoundFunction(function) => {
+                self.encoder.u8(17);
+                self.encoder.bool(object.is_constructor());
+                self.object(function.target_function());
+                self.value(function.this());
+                self.values(function.args());
+            }
+            kind => return Err(unsupported(&format!("{kind} objects")).into()),
+        }
+        Ok(())
+    }
+
+    fn object_record(&mut self, object: &JsObject) -> JsResult<()> {
+        let object_ref = object.borrow();
+
+        self.optional_object(object_ref.prototype().as_ref());
+        self.encoder.bool(object_ref.extensible());
+        self.object_data(object, object_ref.kind())?;
+
+        let properties = object_ref.properties();
+        self.encoder.usize(properties.iter().len());
+        for (key, descriptor) in properties.iter() {
+            self.property_key(&key);
+            self.property_descriptor(&descriptor);
+        }
+
+        let private_elements = object_ref.private_elements();
+        self.encoder.usize(private_elements.len());
+        for (name, element) in private_elements {
+            self.encoder.sym(*name);
+            self.private_element(element);
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads a snapshot written by a [`SnapshotEncoder`].
+///
+/// Objects are created empty when their index is first read, and overwritten in place when
+/// their record is read.
+struct SnapshotDecoder<'a> {
+    decoder: Decoder<'a>,
+    table: &'a NativeFunctionTable,
+    realm: Option<Realm>,
+    global: Gc<GcCell<CompileTimeEnvironment>>,
+    objects: Vec<JsObject>,
+    symbols: Vec<JsSymbol>,
+    environments: Vec<Gc<DeclarativeEnvironment>>,
+    code_blocks: Vec<Gc<CodeBlock>>,
+    async_functions: Vec<(JsObject, Gc<CodeBlock>, DeclarativeEnvironmentStack)>,
+}
+
+impl SnapshotDecoder<'_> {
+    fn realm(&mut self) -> JsResult<Realm> {
+        let count = self.decoder.usize()?;
+        {
+            let mut global = self.global.borrow_mut();
+            for _ in 0..count {
+                let name = self.decoder.sym()?;
+                global.bindings.insert(name, self.decoder.binding()?);
+            }
+        }
+
+        let global_object = self.object()?;
+        let mut constructors = StandardConstructors::default();
+        for slot in constructors.slots_mut() {
+            *slot = StandardConstructor {
+                constructor: self.object()?,
+                prototype: self.object()?,
+            };
+        }
+        let mut objects = Vec::with_capacity(IntrinsicObjects::COUNT);
+        for _ in 0..IntrinsicObjects::COUNT {
+            objects.push(self.object()?);
+        }
+        let objects = objects
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("the number of intrinsic objects is fixed"));
+
+        let intrinsics = Intrinsics {
+            constructors,
+            objects: OnceCell::from(IntrinsicObjects::from_slots(objects)),
+        };
+        let environment = self.environment_stack()?;
+        let realm = Realm::from_parts(intrinsics, global_object, environment, self.global.clone());
+        self.realm = Some(realm.clone());
+        Ok(realm)
+    }
+
+    fn realm_ref(&self) -> JsResult<Realm> {
+        self.realm
+            .clone()
+            .ok_or_else(|| self.decoder.malformed().into())
+    }
+
+    fn object(&mut self) -> JsResult<JsObject> {
+        let index = self.decoder.usize()?;
+        if index == self.objects.len() {
+            self.objects.push(JsObject::empty());
+        }
+        self.objects
+            .get(index)
+            .cloned()
+            .ok_or_else(|| self.decoder.malformed().into())
+    }
+
+    fn optional_object(&mut self) -> JsResult<Option<JsObject>> {
+        if self.decoder.bool()? {
+            self.object().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn symbol(&mut self) -> JsResult<JsSymbol> {
+        let index = self.decoder.usize()?;
+        if index != self.symbols.len() {
+            return self
+                .symbols
+                .get(index)
+                .cloned()
+                .ok_or_else(|| self.decoder.malformed().into());
+        }
+
+        let tag = self.decoder.u8()?;
+        let symbol = if tag == 0 {
+            Symbol::registry_symbol(self.decoder.utf16()?)
+        } else {
+            let hash = (tag == 1).then(|| self.decoder.u32()).transpose()?;
+            let description = if self.decoder.bool()? {
+                Some(self.decoder.utf16()?)
+            } else {
+                None
+            };
+            match (tag, hash) {
+                (1, Some(hash)) => JsSymbol::reserved(u64::from(hash), description)
+                    .ok_or_else(|| self.decoder.malformed())?,
+                (2, None) => JsSymbol::new(description),
+                _ => return Err(self.decoder.malformed().into()),
+            }
+        };
+        self.symbols.push(symbol.clone());
+        Ok(symbol)
+    }
+
+    fn value(&mut self) -> JsResult<JsValue> {
+        Ok(match self.decoder.u8()? {
+            0 => JsValue::undefined(),
+            1 => JsValue::null(),
+            2 => JsValue::new(self.decoder.bool()?),
+            3 => JsValue::new(self.decoder.utf16()?),
+            4 => JsValue::rational(self.decoder.f64()?),
+            5 => JsValue::integer(self.decoder.u32()? as i32),
+            6 => JsValue::new(self.bigint()?),
+            7 => JsValue::new(self.object()?),
+            8 => JsValue::new(self.symbol()?),
+            _ => return Err(self.decoder.malformed().into()),
+        })
+    }
+
+    fn values(&mut self) -> JsResult<Vec<JsValue>> {
+        let len = self.decoder.usize()?;
+        (0..len).map(|_| self.value()).collect()
+    }
+
+    fn bigint(&mut self) -> JsResult<JsBigInt> {
+        JsBigInt::from_string_radix(self.decoder.str()?, 16)
+            .ok_or_else(|| self.decoder.malformed().into())
+    }
+
+    fn property_key(&mut self) -> JsResult<PropertyKey> {
+        Ok(match self.decoder.u8()? {
+            0 => PropertyKey::Index(self.decoder.u32()?),
+            1 => PropertyKey::String(self.decoder.utf16()?),
+            2 => PropertyKey::Symbol(self.symbol()?),
+            _ => return Err(self.decoder.malformed().into()),
+        })
+    }
+
+    fn optional_value(&mut self) -> JsResult<Option<JsValue>> {
+        if self.decoder.bool()? {
+            self.value().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn optional_bool(&mut self) -> JsResult<Option<bool>> {
+        Ok(match self.decoder.u8()? {
+            0 => None,
+            1 => Some(false),
+            2 => Some(true),
+            _ => return Err(self.decoder.malformed().into()),
+        })
+    }
+
+    fn property_descriptor(&mut self) -> JsResult<PropertyDescriptor> {
+        let descriptor = match self.decoder.u8()? {
+            0 => PropertyDescriptor::builder()
+                .maybe_value(self.optional_value()?)
+                .maybe_writable(self.optional_bool()?),
+            1 => PropertyDescriptor::builder()
+                .maybe_get(self.optional_value()?)
+                .maybe_set(self.optional_value()?),
+            _ => return Err(self.decoder.malformed().into()),
+        };
+        Ok(descriptor
+            .maybe_enumerable(self.optional_bool()?)
+            .maybe_configurable(self.optional_bool()?)
+            .build())
+    }
+
+    fn private_element(&mut self) -> JsResult<PrivateElement> {
+        Ok(match self.decoder.u8()? {
+            0 => PrivateElement::Field(self.value()?),
+            1 => PrivateElement::Method(self.object()?),
+            2 => PrivateElement::Accessor {
+                getter: self.optional_object()?,
+                setter: self.optional_object()?,
+            },
+            _ => return Err(self.decoder.malformed().into()),
+        })
+    }
+
+    fn environment_stack(&mut self) -> JsResult<DeclarativeEnvironmentStack> {
+        let len = self.decoder.usize()?;
+        if len == 0 {
+            return Err(self.decoder.malformed().into());
+        }
+        let environments = (0..len)
+            .map(|_| self.environment())
+            .collect::<JsResult<_>>()?;
+        Ok(DeclarativeEnvironmentStack::from_environments(environments))
+    }
+
+    fn environment(&mut self) -> JsResult<Gc<DeclarativeEnvironment>> {
+        let index = self.decoder.usize()?;
+        if index != self.environments.len() {
+            return self
+                .environments
+                .get(index)
+                .cloned()
+                .ok_or_else(|| self.decoder.malformed().into());
+        }
+
+        let compile = self.decoder.environment(&self.global)?;
+        let poisoned = self.decoder.bool()?;
+        let len = self.decoder.usize()?;
+        let mut bindings = Vec::with_capacity(len);
+        for _ in 0..len {
+            let binding = if self.decoder.bool()? {
+                Some(self.value()?)
+            } else {
+                None
+            };
+            bindings.push(binding);
+        }
+
+        let slots = match self.decoder.u8()? {
+            0 => None,
+            1 => Some(EnvironmentSlots::Global),
+            2 => {
+                let this = self.value()?;
+                let this_binding_status = match self.decoder.u8()? {
+                    0 => ThisBindingStatus::Lexical,
+                    1 => ThisBindingStatus::Initialized,
+                    2 => ThisBindingStatus::Uninitialized,
+                    _ => return Err(self.decoder.malformed().into()),
+                };
+                Some(EnvironmentSlots::Function(GcCell::new(FunctionSlots {
+                    this,
+                    this_binding_status,
+                    function_object: self.object()?,
+                    new_target: self.optional_object()?,
+                })))
+            }
+            _ => return Err(self.decoder.malformed().into()),
+        };
+
+        let environment = Gc::new(DeclarativeEnvironment {
+            bindings: GcCell::new(bindings),
+            compile,
+            poisoned: GcCell::new(poisoned),
+            slots,
+        });
+        self.environments.push(environment.clone());
+        Ok(environment)
+    }
+
+    fn code_block(&mut self) -> JsResult<Gc<CodeBlock>> {
+        let index = self.decoder.usize()?;
+        if index != self.code_blocks.len() {
+            return self
+                .code_blocks
+                .get(index)
+                .cloned()
+                .ok_or_else(|| self.decoder.malformed().into());
+        }
+
+        let code = Gc::new(self.decoder.code_block(&self.global)?);
+        self.code_blocks.push(code.clone());
+        Ok(code)
+    }
+
+    /// Reads a function, returning `None` for async functions, which are created after the
+    /// context is built.
+    fn function(&mut self, object: &JsObject) -> JsResult<Option<Function>> {
+        let realm = self.realm_ref()?;
+        Ok(Some(match self.decoder.u8()? {
+            0 => {
+                let name = self.decoder.str()?;
+                let function = self.table.function(name).ok_or_else(|| {
+                    JsNativeError::typ().with_message(format!(
+                        "native function `{name}` of the snapshot is not registered"
+                    ))
+                })?;
+                let constructor = match self.decoder.u8()? {
+                    0 => None,
+                    1 => Some(ConstructorKind::Base),
+                    2 => Some(ConstructorKind::Derived),
+                    _ => return Err(self.decoder.malformed().into()),
+                };
+                Function::Native {
+                    function,
+                    constructor,
+                    realm,
+                }
+            }
+            1 => {
+                let code = self.code_block()?;
+                let environments = self.environment_stack()?;
+                let constructor_kind = if self.decoder.bool()? {
+                    ConstructorKind::Derived
+                } else {
+                    ConstructorKind::Base
+                };
+                let home_object = self.optional_object()?;
+                let len = self.decoder.usize()?;
+                let mut fields = Vec::with_capacity(len);
+                for _ in 0..len {
+                    let field = match self.decoder.u8()? {
+                        0 => {
+                            let key = self.property_key()?;
+                            let function = JsFunction::from_object_unchecked(self.object()?);
+                            ClassFieldDefinition::Public(key, function)
+                        }
+                        1 => {
+                            let name = self.decoder.sym()?;
+                            let function = JsFunction::from_object_unchecked(self.object()?);
+                            ClassFieldDefinition::Private(name, function)
+                        }
+                        _ => return Err(self.decoder.malformed().into()),
+                    };
+                    fields.push(field);
+                }
+                let len = self.decoder.usize()?;
+                let mut private_methods = Vec::with_capacity(len);
+                for _ in 0..len {
+                    let name = self.decoder.sym()?;
+                    private_methods.push((name, self.private_element()?));
+                }
+                Function::Ordinary {
+                    code,
+                    environments,
+                    realm,
+                    constructor_kind,
+                    home_object,
+                    fields,
+                    private_methods,
+                }
+            }
+            2 => {
+                let code = self.code_block()?;
+                let environments = self.environment_stack()?;
+                self.async_functions
+                    .push((object.clone(), code, environments));
+                return Ok(None);
+            }
+            3 => Function::Generator {
+                code: self.code_block()?,
+                environments: self.environment_stack()?,
+                realm,
+            },
+            4 => Function::AsyncGenerator {
+                code: self.code_block()?,
+                environments: self.environment_stack()?,
+                realm,
+            },
+            _ => return Err(self.decoder.malformed().into()),
+        }))
+    }
+
+    /// Reads the data of an object, returning `None` if the data is set later.
+    fn object_data(&mut self, object: &JsObject) -> JsResult<Option<ObjectData>> {
+        Ok(Some(match self.decoder.u8()? {
+            0 => ObjectData::ordinary(),
+            1 => ObjectData::global(),
+            2 => ObjectData::array(),
+            3 => ObjectData::boolean(self.decoder.bool()?),
+            4 => ObjectData::number(self.decoder.f64()?),
+            5 => ObjectData::string(self.decoder.utf16()?),
+            6 => ObjectData::symbol(self.symbol()?),
+            7 => ObjectData::big_int(self.bigint()?),
+            8 => ObjectData::error(match self.decoder.u8()? {
+                0 => ErrorKind::Aggregate,
+                1 => ErrorKind::Error,
+                2 => ErrorKind::Eval,
+                3 => ErrorKind::Type,
+                4 => ErrorKind::Range,
+                5 => ErrorKind::Reference,
+                6 => ErrorKind::Syntax,
+                7 => ErrorKind::Uri,
+                8 => ErrorKind::Termination,
+                _ => return Err(self.decoder.malformed().into()),
+            }),
+            9 => {
+                let date = if self.decoder.bool()? {
+                    let seconds = self.decoder.f64()? as i64;
+                    let nanoseconds = self.decoder.u32()?;
+                    Some(
+                        NaiveDateTime::from_timestamp_opt(seconds, nanoseconds)
+                            .ok_or_else(|| self.decoder.malformed())?,
+                    )
+                } else {
+                    None
+                };
+                ObjectData::date(Date(date))
+            }
+            10 => {
+                let source = self.decoder.utf16()?;
+                let flags = self.decoder.utf16()?;
+                ObjectData::reg_exp(Box::new(RegExp::from_source(source, flags)?))
+            }
+            11 => {
+                let len = self.decoder.usize()?;
+                let mut map = OrderedMap::with_capacity(len);
+                for _ in 0..len {
+                    let key = self.value()?;
+                    map.insert(key, self.value()?);
+                }
+                ObjectData::map(map)
+            }
+            12 => {
+                let len = self.decoder.usize()?;
+                let mut set = OrderedSet::with_capacity(len);
+                for _ in 0..len {
+                    set.add(self.value()?);
+                }
+                ObjectData::set(set)
+            }
+            13 => {
+                let array_buffer_data = match self.decoder.u8()? {
+                    0 => None,
+                    1 => Some(DataBlock::Unshared(self.decoder.data()?.to_vec())),
+                    2 => Some(DataBlock::Shared(SharedDataBlock::from_slice(
+                        self.decoder.data()?,
+                    ))),
+                    _ => return Err(self.decoder.malformed().into()),
+                };
+                let data_block_memory = ExternalMemory::new(
+                    array_buffer_data
+                        .as_ref()
+                        .map_or(0, DataBlock::owned_memory),
+                );
+                ObjectData::array_buffer(ArrayBuffer {
+                    array_buffer_data,
+                    array_buffer_byte_length: self.decoder.f64()? as u64,
+                    array_buffer_max_byte_length: if self.decoder.bool()? {
+                        Some(self.decoder.f64()? as u64)
+                    } else {
+                        None
+                    },
+                    array_buffer_detach_key: self.value()?,
+                    data_block_memory,
+                })
+            }
+            14 => match self.function(object)? {
+                Some(function) => ObjectData::function(function),
+                None => return Ok(None),
+            },
+            15 => match self.function(object)? {
+                Some(function) => ObjectData::generator_function(function),
+                None => return Err(self.decoder.malformed().into()),
+            },
+            16 => match self.function(object)? {
+                Some(function) => ObjectData::async_generator_function(function),
+                None => return Err(self.decoder.malformed().into()),
+            },
+            17 => {
+                let constructor = self.decoder.bool()?;
+                let target_function = self.object()?;
+                let this = self.value()?;
+                let args = self.values()?;
+                ObjectData::bound_function(
+                    BoundFunction::new(target_function, this, args),
+                    constructor,
+                )
+            }
+            _ => return Err(self.decoder.malformed().into()),
+        }))
+    }
+
+    fn object_record(&mut self, object: &JsObject) -> JsResult<()> {
+        let prototype = self.optional_object()?;
+        let extensible = self.decoder.bool()?;
+        let data = self.object_data(object)?;
+
+        let len = self.decoder.usize()?;
+        let mut properties = PropertyMap::default();
+        for _ in 0..len {
+            let key = self.property_key()?;
+            let descriptor = self.property_descriptor()?;
+            properties.insert(&key, descriptor);
+        }
+
+        let len = self.decoder.usize()?;
+        let mut private_elements = Vec::with_capacity(len);
+        for _ in 0..len {
+            let name: Sym = self.decoder.sym()?;
+            private_elements.push((name, self.private_element()?));
+        }
+
+        let mut object = object.borrow_mut();
+        if let Some(data) = data {
+            object.data = data;
+        }
+        object.set_prototype(prototype);
+        *object.properties_mut() = properties;
+        for (name, element) in private_elements {
+            object.set_private_element(name, element);
+        }
+        object.set_extensible(extensible);
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use crate::{
+    context::{snapshot::NativeFunctionTable, ContextBuilder},
+    forward, Context, JsResult, JsValue,
+};
+
+fn restore(snapshot: &[u8], table: &NativeFunctionTable) -> Context {
+    ContextBuilder::default()
+        .build_from_snapshot(snapshot, table)
+        .unwrap()
+}
+
+#[test]
+fn snapshot_of_new_context() {
+    let table = NativeFunctionTable::new();
+    let snapshot = Context::default().snapshot(&table).unwrap();
+
+    let mut context = restore(&snapshot, &table);
+    assert_eq!(
+        forward(&mut context, "[1, 2, 3].map(x => x * 2).join()"),
+        "\"2,4,6\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf([]) === Array.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[][Symbol.iterator] === Array.prototype.values"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Map([[1, 2]]).get(1) + JSON.stringify({ a: 1 })"
+        ),
+        r#""2{"a":1}""#
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Promise.resolve(1).then(x => globalThis.resolved = x); 0"
+        ),
+        "0"
+    );
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "resolved"), "1");
+}
+
+#[test]
+fn snapshot_after_setup() {
+    let table = NativeFunctionTable::new();
+    let mut context = Context::default();
+    let setup = r#"
+        let counter = 0;
+        const registered = Symbol.for("registered");
+        const unique = Symbol("unique");
+        function increment() { return ++counter; }
+        class Point {
+            #x;
+            constructor(x) { this.#x = x; }
+            get x() { return this.#x; }
+        }
+        var state = {
+            map: new Map([["a", 1]]),
+            set: new Set([1, 2]),
+            date: new Date(0),
+            regexp: /b+/g,
+            bound: increment.bind(null),
+            point: new Point(5),
+            [unique]: "by unique symbol",
+        };
+        async function later() { return counter; }
+        function* range() { yield 1; yield 2; }
+        increment();
+    "#;
+    forward(&mut context, setup);
+    let snapshot = context.snapshot(&table).unwrap();
+
+    for _ in 0..2 {
+        let mut context = restore(&snapshot, &table);
+        assert_eq!(forward(&mut context, "increment()"), "2");
+        assert_eq!(forward(&mut context, "state.bound()"), "3");
+        assert_eq!(forward(&mut context, "counter"), "3");
+        assert_eq!(
+            forward(&mut context, "Symbol.for('registered') === registered"),
+            "true"
+        );
+        assert_eq!(
+            forward(&mut context, "state[unique]"),
+            "\"by unique symbol\""
+        );
+        assert_eq!(forward(&mut context, "state.map.get('a')"), "1");
+        assert_eq!(forward(&mut context, "state.set.has(2)"), "true");
+        assert_eq!(forward(&mut context, "state.date.getTime()"), "0");
+        assert_eq!(
+            forward(&mut context, "'abbbc'.replace(state.regexp, '-')"),
+            "\"a-c\""
+        );
+        assert_eq!(forward(&mut context, "state.point.x"), "5");
+        assert_eq!(forward(&mut context, "new Point(7).x"), "7");
+        assert_eq!(forward(&mut context, "[...range()].join()"), "\"1,2\"");
+        assert_eq!(
+            forward(&mut context, "later().then(x => globalThis.result = x); 0"),
+            "0"
+        );
+        context.run_jobs().unwrap();
+        assert_eq!(forward(&mut context, "result"), "3");
+    }
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn answer(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+    Ok(JsValue::new(42))
+}
+
+#[test]
+fn snapshot_with_host_functions() {
+    let mut context = Context::default();
+    context.register_global_function("answer", 0, answer);
+
+    let mut table = NativeFunctionTable::new();
+    assert!(context.snapshot(&table).is_err());
+
+    table.register("answer", answer);
+    let snapshot = context.snapshot(&table).unwrap();
+    let mut context = restore(&snapshot, &table);
+    assert_eq!(forward(&mut context, "answer()"), "42");
+
+    let table = NativeFunctionTable::new();
+    assert!(ContextBuilder::default()
+        .build_from_snapshot(&snapshot, &table)
+        .is_err());
+}
+
+#[test]
+fn snapshot_with_host_closures() {
+    let table = NativeFunctionTable::new();
+    let mut context = Context::default();
+    context
+        .register_global_closure("closure", 0, |_, _, _| Ok(JsValue::new(42)))
+        .unwrap();
+
+    let error = context.snapshot(&table).unwrap_err();
+    assert_eq!(
+        error.as_native().map(|error| error.message()),
+        Some("cannot snapshot closures")
+    );
+}
+
+#[test]
+fn snapshot_errors() {
+    let table = NativeFunctionTable::new();
+
+    let mut context = Context::default();
+    forward(&mut context, "var iterator = [1, 2][Symbol.iterator]();");
+    assert!(context.snapshot(&table).is_err());
+
+    let snapshot = Context::default().snapshot(&table).unwrap();
+    assert!(ContextBuilder::default()
+        .build_from_snapshot(b"not a snapshot", &table)
+        .is_err());
+    assert!(ContextBuilder::default()
+        .build_from_snapshot(&snapshot[..snapshot.len() - 1], &table)
+        .is_err());
+}
@@ -9,10 +9,10 @@ use rustc_hash::FxHashMap;
 ///
 /// It contains the binding index and a flag to indicate if this is a mutable binding or not.
 #[derive(Debug)]
-struct CompileTimeBinding {
-    index: usize,
-    mutable: bool,
-    lex: bool,
+pub(crate) struct CompileTimeBinding {
+    pub(crate) index: usize,
+    pub(crate) mutable: bool,
+    pub(crate) lex: bool,
 }
 
 /// A compile time environment maps bound identifiers to their binding positions.
@@ -20,11 +20,11 @@ struct CompileTimeBinding {
 /// A compile time environment also indicates, if it is a function environment.
 #[derive(Debug, Finalize, Trace)]
 pub(crate) struct CompileTimeEnvironment {
-    outer: Option<Gc<Cell<Self>>>,
-    environment_index: usize,
+    pub(crate) outer: Option<Gc<Cell<Self>>>,
+    pub(crate) environment_index: usize,
     #[unsafe_ignore_trace]
-    bindings: FxHashMap<Sym, CompileTimeBinding>,
-    function_scope: bool,
+    pub(crate) bindings: FxHashMap<Sym, CompileTimeBinding>,
+    pub(crate) function_scope: bool,
 }
 
 impl CompileTimeEnvironment {
@@ -210,10 +210,10 @@ impl Context {
     /// Note: This function only works at bytecode compile time!
     #[inline]
     pub(crate) fn push_compile_time_environment(&mut self, function_scope: bool) {
-        let environment_index = self.realm.compile_env.borrow().environment_index + 1;
-        let outer = self.realm.compile_env.clone();
+        let environment_index = self.compile_env.borrow().environment_index + 1;
+        let outer = self.compile_env.clone();
 
-        self.realm.compile_env = Gc::new(Cell::new(CompileTimeEnvironment {
+        self.compile_env = Gc::new(Cell::new(CompileTimeEnvironment {
             outer: Some(outer),
             environment_index,
             bindings: FxHashMap::default(),
@@ -232,13 +232,13 @@ impl Context {
     pub(crate) fn pop_compile_time_environment(
         &mut self,
     ) -> (usize, Gc<Cell<CompileTimeEnvironment>>) {
-        let current_env_borrow = self.realm.compile_env.borrow();
+        let current_env_borrow = self.compile_env.borrow();
         if let Some(outer) = &current_env_borrow.outer {
             let outer_clone = outer.clone();
             let num_bindings = current_env_borrow.num_bindings();
             drop(current_env_borrow);
-            let current = self.realm.compile_env.clone();
-            self.realm.compile_env = outer_clone;
+            let current = self.compile_env.clone();
+            self.compile_env = outer_clone;
             (num_bindings, current)
         } else {
             panic!("cannot pop global environment")
@@ -254,7 +254,7 @@ impl Context {
     /// Panics if there are no environments on the compile time environment stack.
     #[inline]
     pub(crate) fn get_binding_number(&self) -> usize {
-        self.realm.compile_env.borrow().num_bindings()
+        self.compile_env.borrow().num_bindings()
     }
 
     /// Get the binding locator of the binding at bytecode compile time.
@@ -262,7 +262,7 @@ impl Context {
     /// Note: This function only works at bytecode compile time!
     #[inline]
     pub(crate) fn get_binding_value(&self, name: Sym) -> BindingLocator {
-        self.realm.compile_env.borrow().get_binding_recursive(name)
+        self.compile_env.borrow().get_binding_recursive(name)
     }
 
     /// Return if a declarative binding exists at bytecode compile time.
@@ -271,7 +271,7 @@ impl Context {
     /// Note: This function only works at bytecode compile time!
     #[inline]
     pub(crate) fn has_binding(&self, name: Sym) -> bool {
-        self.realm.compile_env.borrow().has_binding_recursive(name)
+        self.compile_env.borrow().has_binding_recursive(name)
     }
 
     /// Create a mutable binding at bytecode compile time.
@@ -285,22 +285,21 @@ impl Context {
     #[inline]
     pub(crate) fn create_mutable_binding(&mut self, name: Sym, function_scope: bool) {
         if !self
-            .realm
             .compile_env
             .borrow_mut()
             .create_mutable_binding(name, function_scope)
         {
             let name_str = JsString::from(self.interner().resolve_expect(name));
-            let desc = self
-                .realm
-                .global_property_map
-                .string_property_map()
-                .get(&name_str);
-            if desc.is_none() {
+            let exists = self
+                .global_object()
+                .borrow()
+                .properties()
+                .contains_key(&name_str.clone().into());
+            if !exists {
                 self.global_bindings_mut().insert(
                     name_str,
                     PropertyDescriptor::builder()
-                        .value(JsValue::Undefined)
+                        .value(JsValue::undefined())
                         .writable(true)
                         .enumerable(true)
                         .configurable(true)
@@ -319,8 +318,7 @@ impl Context {
         name: Sym,
         function_scope: bool,
     ) -> BindingLocator {
-        self.realm
-            .compile_env
+        self.compile_env
             .borrow()
             .initialize_mutable_binding(name, function_scope)
     }
@@ -335,10 +333,7 @@ impl Context {
     /// Panics if the global environment does not exist.
     #[inline]
     pub(crate) fn create_immutable_binding(&mut self, name: Sym) {
-        self.realm
-            .compile_env
-            .borrow_mut()
-            .create_immutable_binding(name);
+        self.compile_env.borrow_mut().create_immutable_binding(name);
     }
 
     /// Initialize an immutable binding at bytecode compile time and return it's binding locator.
@@ -350,10 +345,7 @@ impl Context {
     /// Panics if the global environment does not exist or a the binding was not created on the current environment.
     #[inline]
     pub(crate) fn initialize_immutable_binding(&self, name: Sym) -> BindingLocator {
-        self.realm
-            .compile_env
-            .borrow()
-            .initialize_immutable_binding(name)
+        self.compile_env.borrow().initialize_immutable_binding(name)
     }
 
     /// Return the binding locator for a set operation on an existing binding.
@@ -361,8 +353,7 @@ impl Context {
     /// Note: This function only works at bytecode compile time!
     #[inline]
     pub(crate) fn set_mutable_binding(&self, name: Sym) -> BindingLocator {
-        self.realm
-            .compile_env
+        self.compile_env
             .borrow()
             .set_mutable_binding_recursive(name)
     }
@@ -28,9 +28,10 @@ mod compile;
 mod runtime;
 
 pub(crate) use {
-    compile::CompileTimeEnvironment,
+    compile::{CompileTimeBinding, CompileTimeEnvironment},
     runtime::{
         BindingLocator, DeclarativeEnvironment, DeclarativeEnvironmentStack, EnvironmentSlots,
+        FunctionSlots, ThisBindingStatus,
     },
 };
 
@@ -1,7 +1,10 @@
-use crate::{environments::CompileTimeEnvironment, object::JsObject, Context, JsResult, JsValue};
+use crate::{
+    environments::CompileTimeEnvironment, object::JsObject, Context, JsNativeError, JsResult,
+    JsValue,
+};
 use boa_gc::{Cell, Finalize, Gc, Trace};
 use boa_interner::Sym;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 /// A declarative environment holds binding values at runtime.
 ///
@@ -11,6 +14,9 @@ use rustc_hash::FxHashSet;
 /// Optionally, an environment can hold a `this` value.
 /// The `this` value is present only if the environment is a function environment.
 ///
+/// Module environments can additionally hold indirect bindings.
+/// Reading an indirect binding reads the binding of another module environment.
+///
 /// Code evaluation at runtime (e.g. the `eval` built-in function) can add
 /// bindings to existing, compiled function environments.
 /// This makes it impossible to determine the location of all bindings at compile time.
@@ -25,10 +31,10 @@ use rustc_hash::FxHashSet;
 /// All poisoned environments have to be checked for added bindings.
 #[derive(Debug, Trace, Finalize)]
 pub(crate) struct DeclarativeEnvironment {
-    bindings: Cell<Vec<Option<JsValue>>>,
-    compile: Gc<Cell<CompileTimeEnvironment>>,
-    poisoned: Cell<bool>,
-    slots: Option<EnvironmentSlots>,
+    pub(crate) bindings: Cell<Vec<Option<JsValue>>>,
+    pub(crate) compile: Gc<Cell<CompileTimeEnvironment>>,
+    pub(crate) poisoned: Cell<bool>,
+    pub(crate) slots: Option<EnvironmentSlots>,
 }
 
 /// Describes the different types of internal slot data that an environment can hold.
@@ -36,6 +42,7 @@ pub(crate) struct DeclarativeEnvironment {
 pub(crate) enum EnvironmentSlots {
     Function(Cell<FunctionSlots>),
     Global,
+    Module(Cell<ModuleSlots>),
 }
 
 impl EnvironmentSlots {
@@ -49,21 +56,28 @@ impl EnvironmentSlots {
     }
 }
 
+/// Holds the internal slots of a module environment.
+#[derive(Clone, Debug, Default, Trace, Finalize)]
+pub(crate) struct ModuleSlots {
+    /// Indirect import bindings, mapping a binding index to a binding of another module environment.
+    indirect_bindings: FxHashMap<usize, (Gc<DeclarativeEnvironment>, usize)>,
+}
+
 /// Holds the internal slots of a function environment.
 #[derive(Clone, Debug, Trace, Finalize)]
 pub(crate) struct FunctionSlots {
     /// The `[[ThisValue]]` internal slot.
-    this: JsValue,
+    pub(crate) this: JsValue,
 
     /// The `[[ThisBindingStatus]]` internal slot.
     #[unsafe_ignore_trace]
-    this_binding_status: ThisBindingStatus,
+    pub(crate) this_binding_status: ThisBindingStatus,
 
     /// The `[[FunctionObject]]` internal slot.
-    function_object: JsObject,
+    pub(crate) function_object: JsObject,
 
     /// The `[[NewTarget]]` internal slot.
-    new_target: Option<JsObject>,
+    pub(crate) new_target: Option<JsObject>,
 }
 
 impl FunctionSlots {
@@ -169,13 +183,29 @@ impl FunctionSlots {
 
 /// Describes the status of a `this` binding in function environments.
 #[derive(Clone, Copy, Debug, PartialEq)]
-enum ThisBindingStatus {
+pub(crate) enum ThisBindingStatus {
     Lexical,
     Initialized,
     Uninitialized,
 }
 
 impl DeclarativeEnvironment {
+    /// Create a new module environment with the given number of bindings.
+    ///
+    /// Module environments are not pushed on the environment stack on creation,
+    /// because they must exist before the module is evaluated.
+    pub(crate) fn new_module(
+        num_bindings: usize,
+        compile_environment: Gc<Cell<CompileTimeEnvironment>>,
+    ) -> Gc<Self> {
+        Gc::new(Self {
+            bindings: Cell::new(vec![None; num_bindings]),
+            compile: compile_environment,
+            poisoned: Cell::new(false),
+            slots: Some(EnvironmentSlots::Module(Cell::new(ModuleSlots::default()))),
+        })
+    }
+
     /// Returns the internal slot data of the current environment.
     pub(crate) fn slots(&self) -> Option<&EnvironmentSlots> {
         self.slots.as_ref()
@@ -196,6 +226,28 @@ impl DeclarativeEnvironment {
             .expect("binding must be initialized")
     }
 
+    /// Get the binding value from the environment by it's index.
+    ///
+    /// If the binding is an indirect module binding, the value of the target binding is returned.
+    /// Returns `None` if the binding is not initialized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the binding value is out of range.
+    #[inline]
+    pub(crate) fn get_optional(&self, index: usize) -> Option<JsValue> {
+        if let Some(EnvironmentSlots::Module(slots)) = &self.slots {
+            if let Some((env, index)) = slots.borrow().indirect_bindings.get(&index) {
+                return env.get_optional(*index);
+            }
+        }
+        self.bindings
+            .borrow()
+            .get(index)
+            .expect("binding index must be in range")
+            .clone()
+    }
+
     /// Set the binding value at the specified index.
     ///
     /// # Panics
@@ -210,6 +262,50 @@ impl DeclarativeEnvironment {
         assert!(!binding.is_none(), "binding must be initialized");
         *binding = Some(value);
     }
+
+    /// Initialize the binding at the specified index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the binding value is out of range.
+    #[inline]
+    pub(crate) fn initialize(&self, index: usize, value: JsValue) {
+        *self
+            .bindings
+            .borrow_mut()
+            .get_mut(index)
+            .expect("binding index must be in range") = Some(value);
+    }
+
+    /// `CreateImportBinding`
+    ///
+    /// Make the binding at `index` of this module environment an indirect binding
+    /// to the binding at `target_index` of the `target` module environment.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createimportbinding
+    ///
+    /// # Panics
+    ///
+    /// Panics if this environment is not a module environment.
+    pub(crate) fn create_import_binding(
+        &self,
+        index: usize,
+        target: Gc<Self>,
+        target_index: usize,
+    ) {
+        match &self.slots {
+            Some(EnvironmentSlots::Module(slots)) => {
+                slots
+                    .borrow_mut()
+                    .indirect_bindings
+                    .insert(index, (target, target_index));
+            }
+            _ => panic!("import bindings can only be created in module environments"),
+        }
+    }
 }
 
 /// A declarative environment stack holds all declarative environments at runtime.
@@ -275,6 +371,18 @@ impl DeclarativeEnvironmentStack {
         None
     }
 
+    /// Create an environment stack from the given environments, the first of which must be the
+    /// global environment.
+    pub(crate) fn from_environments(stack: Vec<Gc<DeclarativeEnvironment>>) -> Self {
+        debug_assert!(!stack.is_empty(), "global environment must always exist");
+        Self { stack }
+    }
+
+    /// Get the environments of the stack, starting with the global environment.
+    pub(crate) fn environments(&self) -> &[Gc<DeclarativeEnvironment>] {
+        &self.stack
+    }
+
     /// Pop all current environments except the global environment.
     pub(crate) fn pop_to_global(&mut self) -> Vec<Gc<DeclarativeEnvironment>> {
         self.stack.split_off(1)
@@ -334,7 +442,7 @@ impl DeclarativeEnvironmentStack {
                             return slots;
                         }
                     }
-                    EnvironmentSlots::Global => return slots,
+                    EnvironmentSlots::Global | EnvironmentSlots::Module(_) => return slots,
                 }
             }
         }
@@ -402,7 +510,7 @@ impl DeclarativeEnvironmentStack {
         let this = if let Some(this) = this {
             this
         } else {
-            JsValue::Null
+            JsValue::null()
         };
 
         self.stack.push(Gc::new(DeclarativeEnvironment {
@@ -542,11 +650,7 @@ impl DeclarativeEnvironmentStack {
         self.stack
             .get(environment_index)
             .expect("environment index must be in range")
-            .bindings
-            .borrow()
-            .get(binding_index)
-            .expect("binding index must be in range")
-            .clone()
+            .get_optional(binding_index)
     }
 
     /// Get the value of a binding by it's name.
@@ -728,11 +832,7 @@ pub(crate) struct BindingLocator {
 impl BindingLocator {
     /// Creates a new declarative binding locator that has knows indices.
     #[inline]
-    pub(in crate::environments) fn declarative(
-        name: Sym,
-        environment_index: usize,
-        binding_index: usize,
-    ) -> Self {
+    pub(crate) fn declarative(name: Sym, environment_index: usize, binding_index: usize) -> Self {
         Self {
             name,
             environment_index,
@@ -744,7 +844,7 @@ impl BindingLocator {
 
     /// Creates a binding locator that indicates that the binding is on the global object.
     #[inline]
-    pub(in crate::environments) fn global(name: Sym) -> Self {
+    pub(crate) fn global(name: Sym) -> Self {
         Self {
             name,
             environment_index: 0,
@@ -757,7 +857,7 @@ impl BindingLocator {
     /// Creates a binding locator that indicates that it was attempted to mutate an immutable binding.
     /// At runtime this should always produce a type error.
     #[inline]
-    pub(in crate::environments) fn mutate_immutable(name: Sym) -> Self {
+    pub(crate) fn mutate_immutable(name: Sym) -> Self {
         Self {
             name,
             environment_index: 0,
@@ -779,6 +879,12 @@ impl BindingLocator {
         self.global
     }
 
+    /// Returns if the binding locator indicates an attempt to mutate an immutable binding.
+    #[inline]
+    pub(crate) fn is_mutate_immutable(&self) -> bool {
+        self.mutate_immutable
+    }
+
     /// Returns the environment index of the binding.
     #[inline]
     pub(crate) fn environment_index(&self) -> usize {
@@ -795,10 +901,12 @@ impl BindingLocator {
     #[inline]
     pub(crate) fn throw_mutate_immutable(&self, context: &mut Context) -> JsResult<()> {
         if self.mutate_immutable {
-            context.throw_type_error(format!(
-                "cannot mutate an immutable binding '{}'",
-                context.interner().resolve_expect(self.name)
-            ))
+            Err(JsNativeError::typ()
+                .with_message(format!(
+                    "cannot mutate an immutable binding '{}'",
+                    context.interner().resolve_expect(self.name)
+                ))
+                .into())
         } else {
             Ok(())
         }
@@ -0,0 +1,615 @@
+//! Error-related types and conversions.
+//!
+//! Errors of the engine are represented by [`JsError`], which is either an opaque
+//! [`JsValue`] thrown by a script, or a [`JsNativeError`] created by the engine or the host.
+//! Native errors are only converted into JavaScript error objects when a script needs to
+//! access them, e.g. in a `catch` block.
+
+use crate::{
+    builtins::{error::ErrorKind, Array, Error},
+    object::JsObject,
+    object::ObjectData,
+    property::PropertyDescriptor,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use std::fmt;
+
+/// The error type returned by all operations related to the execution of JavaScript code.
+///
+/// This is essentially an enum that can store either [`JsNativeError`]s (if the error
+/// was created by a native function or the engine), or opaque [`JsValue`]s (if the error
+/// was thrown by a script).
+///
+/// # Examples
+///
+/// ```
+/// # use boa_engine::{Context, JsError, JsNativeError, JsValue};
+/// let cause = JsError::from_opaque("error!".into());
+///
+/// assert!(cause.as_opaque().is_some());
+/// assert_eq!(cause.as_opaque().unwrap(), &JsValue::from("error!"));
+///
+/// let native_error: JsError = JsNativeError::typ()
+///     .with_message("invalid type!")
+///     .with_cause(cause)
+///     .into();
+///
+/// assert!(native_error.as_native().is_some());
+///
+/// let kind = &native_error.as_native().unwrap().kind;
+/// assert!(matches!(kind, boa_engine::JsNativeErrorKind::Type));
+/// ```
+#[derive(Debug, Clone, Trace, Finalize, PartialEq)]
+pub struct JsError {
+    inner: Repr,
+}
+
+/// Internal representation of a [`JsError`].
+#[derive(Debug, Clone, Trace, Finalize, PartialEq)]
+enum Repr {
+    Native(JsNativeError),
+    Opaque(JsValue),
+}
+
+/// The error returned by [`JsError::try_native`].
+#[derive(Debug, Clone)]
+pub enum TryNativeError {
+    /// The thrown value is not an error object.
+    NotAnErrorObject(JsValue),
+
+    /// A property of the error object could not be accessed.
+    InaccessibleProperty {
+        /// The name of the property.
+        property: &'static str,
+        /// The error thrown when accessing the property.
+        source: JsError,
+    },
+
+    /// A property of the error object does not have the expected type.
+    InvalidPropertyType(&'static str),
+}
+
+impl fmt::Display for TryNativeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotAnErrorObject(value) => {
+                write!(
+                    f,
+                    "opaque error of type `{}` is not an error object",
+                    value.type_of()
+                )
+            }
+            Self::InaccessibleProperty { property, .. } => {
+                write!(
+                    f,
+                    "could not access property `{property}` of the error object"
+                )
+            }
+            Self::InvalidPropertyType(property) => {
+                write!(
+                    f,
+                    "invalid type of property `{property}` of the error object"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for TryNativeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InaccessibleProperty { source, .. } => Some(source),
+            Self::NotAnErrorObject(_) | Self::InvalidPropertyType(_) => None,
+        }
+    }
+}
+
+impl std::error::Error for JsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.inner {
+            Repr::Native(err) => err.source(),
+            Repr::Opaque(_) => None,
+        }
+    }
+}
+
+impl JsError {
+    /// Creates a new `JsError` from a native error.
+    #[inline]
+    pub fn from_native(err: JsNativeError) -> Self {
+        Self {
+            inner: Repr::Native(err),
+        }
+    }
+
+    /// Creates a new `JsError` from an opaque value thrown by a script.
+    #[inline]
+    pub fn from_opaque(value: JsValue) -> Self {
+        Self {
+            inner: Repr::Opaque(value),
+        }
+    }
+
+    /// Converts the error to an opaque [`JsValue`], which can be handled by scripts.
+    ///
+    /// Native errors are converted into new error objects, so converting the same native
+    /// error twice creates two different objects.
+    pub fn to_opaque(&self, context: &mut Context) -> JsValue {
+        match &self.inner {
+            Repr::Native(e) => e.to_opaque(context).into(),
+            Repr::Opaque(v) => v.clone(),
+        }
+    }
+
+    /// Converts the error to a [`JsNativeError`], if possible.
+    ///
+    /// Opaque values are converted if they are error objects created by an error
+    /// constructor, like `TypeError`, even if they were thrown by a script.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not an error object, or if the `message`, `cause`
+    /// or `errors` properties of the object cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_engine::{Context, JsNativeErrorKind};
+    /// let mut context = Context::default();
+    ///
+    /// let error = context.eval("null.prop").unwrap_err();
+    /// let native = error.try_native(&mut context).unwrap();
+    /// assert!(matches!(native.kind, JsNativeErrorKind::Type));
+    ///
+    /// let error = context.eval("throw 42").unwrap_err();
+    /// assert!(error.try_native(&mut context).is_err());
+    /// ```
+    pub fn try_native(&self, context: &mut Context) -> Result<JsNativeError, TryNativeError> {
+        let value = match &self.inner {
+            Repr::Native(e) => return Ok(e.clone()),
+            Repr::Opaque(value) => value,
+        };
+
+        let obj = value
+            .as_object()
+            .ok_or_else(|| TryNativeError::NotAnErrorObject(value.clone()))?;
+        let error = obj
+            .borrow()
+            .as_error()
+            .ok_or_else(|| TryNativeError::NotAnErrorObject(value.clone()))?;
+
+        let try_get = |property: &'static str, context: &mut Context| {
+            obj.has_property(property, context)
+                .and_then(|has| has.then(|| obj.get(property, context)).transpose())
+                .map_err(|source| TryNativeError::InaccessibleProperty { property, source })
+        };
+
+        let message = match try_get("message", context)? {
+            Some(message) => message
+                .as_string()
+                .map(|message| message.to_std_string_escaped().into_boxed_str())
+                .ok_or(TryNativeError::InvalidPropertyType("message"))?,
+            None => Box::default(),
+        };
+
+        let cause = try_get("cause", context)?.map(|cause| Box::new(Self::from_opaque(cause)));
+
+        let kind = match error {
+            ErrorKind::Error => JsNativeErrorKind::Error,
+            ErrorKind::Eval => JsNativeErrorKind::Eval,
+            ErrorKind::Type => JsNativeErrorKind::Type,
+            ErrorKind::Range => JsNativeErrorKind::Range,
+            ErrorKind::Reference => JsNativeErrorKind::Reference,
+            ErrorKind::Syntax => JsNativeErrorKind::Syntax,
+            ErrorKind::Uri => JsNativeErrorKind::Uri,
+            ErrorKind::Termination => JsNativeErrorKind::Termination,
+            ErrorKind::Aggregate => {
+                let errors = try_get("errors", context)?
+                    .ok_or(TryNativeError::InvalidPropertyType("errors"))?;
+                let errors = errors
+                    .as_object()
+                    .filter(|errors| errors.is_array())
+                    .ok_or(TryNativeError::InvalidPropertyType("errors"))?;
+                let length = errors.length_of_array_like(context).map_err(|source| {
+                    TryNativeError::InaccessibleProperty {
+                        property: "errors",
+                        source,
+                    }
+                })?;
+                let errors = (0..length)
+                    .map(|index| errors.get(index, context).map(Self::from_opaque))
+                    .collect::<JsResult<Vec<_>>>()
+                    .map_err(|source| TryNativeError::InaccessibleProperty {
+                        property: "errors",
+                        source,
+                    })?;
+                JsNativeErrorKind::Aggregate(errors)
+            }
+        };
+
+        Ok(JsNativeError {
+            kind,
+            message,
+            cause,
+        })
+    }
+
+    /// Gets the inner [`JsValue`] if the error is an opaque value thrown by a script.
+    ///
+    /// Unlike [`JsError::to_opaque`], this does not convert native errors.
+    #[inline]
+    pub fn as_opaque(&self) -> Option<&JsValue> {
+        match &self.inner {
+            Repr::Native(_) => None,
+            Repr::Opaque(v) => Some(v),
+        }
+    }
+
+    /// Checks if the error terminated the script because it exceeded a runtime limit of
+    /// the context, ran out of memory or was interrupted.
+    ///
+    /// Catchable terminations are seen by scripts as `RangeError`s, so this is also `true`
+    /// for a termination error that was caught and rethrown by the script.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_engine::{vm::RuntimeLimits, Context};
+    /// let mut limits = RuntimeLimits::new();
+    /// limits.set_loop_iteration_limit(10);
+    /// let mut context = Context::builder().runtime_limits(limits).build();
+    ///
+    /// assert!(context.eval("while (true) {}").unwrap_err().is_termination());
+    /// assert!(!context.eval("throw new RangeError()").unwrap_err().is_termination());
+    /// ```
+    pub fn is_termination(&self) -> bool {
+        match &self.inner {
+            Repr::Native(e) => matches!(e.kind, JsNativeErrorKind::Termination),
+            Repr::Opaque(value) => value.as_object().map_or(false, |obj| {
+                matches!(obj.borrow().as_error(), Some(ErrorKind::Termination))
+            }),
+        }
+    }
+
+    /// Gets the inner [`JsNativeError`] if the error is a native error.
+    ///
+    /// Unlike [`JsError::try_native`], this does not convert opaque values.
+    #[inline]
+    pub fn as_native(&self) -> Option<&JsNativeError> {
+        match &self.inner {
+            Repr::Native(e) => Some(e),
+            Repr::Opaque(_) => None,
+        }
+    }
+}
+
+impl From<JsNativeError> for JsError {
+    #[inline]
+    fn from(error: JsNativeError) -> Self {
+        Self::from_native(error)
+    }
+}
+
+impl fmt::Display for JsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.inner {
+            Repr::Native(e) => e.fmt(f),
+            Repr::Opaque(v) => v.display().fmt(f),
+        }
+    }
+}
+
+/// A native error, created by the engine or the host, that has not been converted into
+/// a JavaScript error object yet.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_engine::{JsNativeError, JsNativeErrorKind};
+/// let native_error = JsNativeError::uri().with_message("cannot decode uri");
+///
+/// assert!(matches!(native_error.kind, JsNativeErrorKind::Uri));
+/// assert_eq!(native_error.message(), "cannot decode uri");
+/// assert_eq!(native_error.to_string(), "URIError: cannot decode uri");
+/// ```
+#[derive(Debug, Clone, Trace, Finalize, PartialEq)]
+pub struct JsNativeError {
+    /// The kind of native error, e.g. `TypeError` or `SyntaxError`.
+    pub kind: JsNativeErrorKind,
+    #[unsafe_ignore_trace]
+    message: Box<str>,
+    cause: Option<Box<JsError>>,
+}
+
+impl JsNativeError {
+    /// Creates a new `JsNativeError` of the given kind, without a message or cause.
+    #[inline]
+    fn new(kind: JsNativeErrorKind) -> Self {
+        Self {
+            kind,
+            message: Box::default(),
+            cause: None,
+        }
+    }
+
+    /// Creates a new `AggregateError` from a list of [`JsError`]s.
+    #[inline]
+    pub fn aggregate(errors: Vec<JsError>) -> Self {
+        Self::new(JsNativeErrorKind::Aggregate(errors))
+    }
+
+    /// Creates a new `Error`.
+    #[inline]
+    pub fn error() -> Self {
+        Self::new(JsNativeErrorKind::Error)
+    }
+
+    /// Creates a new `EvalError`.
+    #[inline]
+    pub fn eval() -> Self {
+        Self::new(JsNativeErrorKind::Eval)
+    }
+
+    /// Creates a new `RangeError`.
+    #[inline]
+    pub fn range() -> Self {
+        Self::new(JsNativeErrorKind::Range)
+    }
+
+    /// Creates a new `ReferenceError`.
+    #[inline]
+    pub fn reference() -> Self {
+        Self::new(JsNativeErrorKind::Reference)
+    }
+
+    /// Creates a new `SyntaxError`.
+    #[inline]
+    pub fn syntax() -> Self {
+        Self::new(JsNativeErrorKind::Syntax)
+    }
+
+    /// Creates a new `TypeError`.
+    #[inline]
+    pub fn typ() -> Self {
+        Self::new(JsNativeErrorKind::Type)
+    }
+
+    /// Creates a new `URIError`.
+    #[inline]
+    pub fn uri() -> Self {
+        Self::new(JsNativeErrorKind::Uri)
+    }
+
+    /// Creates a new termination error, which is a `RangeError` for scripts.
+    #[inline]
+    pub fn termination() -> Self {
+        Self::new(JsNativeErrorKind::Termination)
+    }
+
+    /// Sets the message of the error.
+    #[inline]
+    #[must_use]
+    pub fn with_message<S>(mut self, message: S) -> Self
+    where
+        S: Into<Box<str>>,
+    {
+        self.message = message.into();
+        self
+    }
+
+    /// Sets the cause of the error.
+    #[inline]
+    #[must_use]
+    pub fn with_cause<E>(mut self, cause: E) -> Self
+    where
+        E: Into<JsError>,
+    {
+        self.cause = Some(Box::new(cause.into()));
+        self
+    }
+
+    /// Gets the message of the error.
+    #[inline]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Gets the cause of the error, if any.
+    #[inline]
+    pub fn cause(&self) -> Option<&JsError> {
+        self.cause.as_deref()
+    }
+
+    /// Converts the error into a new JavaScript error object.
+    ///
+    /// The object uses the intrinsic prototype of the error kind, and it has the
+    /// `message`, `cause` and `stack` properties of an object created by the error
+    /// constructor in the running script.
+    pub fn to_opaque(&self, context: &mut Context) -> JsObject {
+        let constructors = context.intrinsics().constructors();
+        let (prototype, kind) = match self.kind {
+            JsNativeErrorKind::Aggregate(_) => (
+                constructors.aggregate_error().prototype(),
+                ErrorKind::Aggregate,
+            ),
+            JsNativeErrorKind::Error => (constructors.error().prototype(), ErrorKind::Error),
+            JsNativeErrorKind::Eval => (constructors.eval_error().prototype(), ErrorKind::Eval),
+            JsNativeErrorKind::Range => (constructors.range_error().prototype(), ErrorKind::Range),
+            JsNativeErrorKind::Reference => (
+                constructors.reference_error().prototype(),
+                ErrorKind::Reference,
+            ),
+            JsNativeErrorKind::Syntax => {
+                (constructors.syntax_error().prototype(), ErrorKind::Syntax)
+            }
+            JsNativeErrorKind::Type => (constructors.type_error().prototype(), ErrorKind::Type),
+            JsNativeErrorKind::Uri => (constructors.uri_error().prototype(), ErrorKind::Uri),
+            JsNativeErrorKind::Termination => (
+                constructors.range_error().prototype(),
+                ErrorKind::Termination,
+            ),
+        };
+
+        let o = JsObject::from_proto_and_data(prototype, ObjectData::error(kind));
+
+        if !self.message.is_empty() {
+            o.create_non_enumerable_data_property_or_throw("message", &*self.message, context);
+        }
+
+        if let Some(cause) = &self.cause {
+            let cause = cause.to_opaque(context);
+            o.create_non_enumerable_data_property_or_throw("cause", cause, context);
+        }
+
+        if let JsNativeErrorKind::Aggregate(errors) = &self.kind {
+            let errors = errors
+                .iter()
+                .map(|e| e.to_opaque(context))
+                .collect::<Vec<_>>();
+            let errors = Array::create_array_from_list(errors, context);
+            o.define_property_or_throw(
+                "errors",
+                PropertyDescriptor::builder()
+                    .configurable(true)
+                    .enumerable(false)
+                    .writable(true)
+                    .value(errors),
+                context,
+            )
+            .expect("The spec guarantees this succeeds for a newly created object");
+        }
+
+        Error::define_error_stack(&o, &self.to_string(), context);
+
+        o
+    }
+
+    /// Gets the name of the error kind, e.g. `TypeError`.
+    fn name(&self) -> &'static str {
+        match self.kind {
+            JsNativeErrorKind::Aggregate(_) => "AggregateError",
+            JsNativeErrorKind::Error => "Error",
+            JsNativeErrorKind::Eval => "EvalError",
+            JsNativeErrorKind::Range | JsNativeErrorKind::Termination => "RangeError",
+            JsNativeErrorKind::Reference => "ReferenceError",
+            JsNativeErrorKind::Syntax => "SyntaxError",
+            JsNativeErrorKind::Type => "TypeError",
+            JsNativeErrorKind::Uri => "URIError",
+        }
+    }
+}
+
+impl fmt::Display for JsNativeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.message.is_empty() {
+            write!(f, "{}", self.name())
+        } else {
+            write!(f, "{}: {}", self.name(), self.message)
+        }
+    }
+}
+
+impl std::error::Error for JsNativeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.cause
+            .as_deref()
+            .map(|cause| cause as &(dyn std::error::Error + 'static))
+    }
+}
+
+/// The kind of a [`JsNativeError`].
+#[derive(Debug, Clone, Trace, Finalize, PartialEq)]
+#[non_exhaustive]
+pub enum JsNativeErrorKind {
+    /// A collection of errors wrapped in a single error.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-aggregate-error-objects
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AggregateError
+    Aggregate(Vec<JsError>),
+
+    /// A generic error. Commonly used as the base for custom exceptions.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-error-constructor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error
+    Error,
+
+    /// An error related to the global function [`eval()`][eval].
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard-evalerror
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/EvalError
+    /// [eval]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/eval
+    Eval,
+
+    /// An error thrown when a value is outside its valid range.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard-rangeerror
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RangeError
+    Range,
+
+    /// An error representing an invalid de-reference of a variable.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard-referenceerror
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ReferenceError
+    Reference,
+
+    /// An error representing an invalid syntax in the JavaScript language.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard-syntaxerror
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SyntaxError
+    Syntax,
+
+    /// An error thrown when a variable or argument is not of a valid type.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard-typeerror
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypeError
+    Type,
+
+    /// An error thrown when the [`encodeURI()`][e_uri] and [`decodeURI()`][d_uri] functions
+    /// receive invalid parameters.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard-urierror
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/URIError
+    /// [e_uri]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/encodeURI
+    /// [d_uri]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/decodeURI
+    Uri,
+
+    /// An error that stops a script that exceeded a runtime limit of the context, ran out of
+    /// memory or was interrupted by the host.
+    ///
+    /// Scripts that can catch it see a `RangeError`. Use [`JsError::is_termination`] to check
+    /// for it after it was converted to an opaque value.
+    Termination,
+}
@@ -1,5 +1,251 @@
+//! Jobs and the host hooks used to schedule them.
+//!
+//! Promise reactions are not run as soon as a promise settles; instead, they are enqueued as
+//! jobs and run once the currently executing script has finished. The [`JobQueue`] trait lets
+//! hosts decide where those jobs are stored and when they are run, which allows integrating
+//! a [`Context`] with an existing event loop.
+
 use crate::{prelude::JsObject, Context, JsResult, JsValue};
-use gc::{Finalize, Trace};
+use boa_gc::{Finalize, Trace};
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    fmt::{self, Debug},
+    future::{self, Future},
+    mem,
+    pin::Pin,
+    sync::Arc,
+    task::{self, Poll, Wake, Waker},
+    thread::{self, Thread},
+};
+
+/// A job that can be run by a [`Context`].
+///
+/// This is the Rust equivalent of the ECMAScript [Job Abstract Closure][spec]. Native jobs are
+/// created by the engine for promise reactions, but hosts can also create their own jobs to run
+/// code in the context after the current script has finished.
+///
+/// Values captured by the job closure are kept alive until the job is run or dropped.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-jobs
+pub struct NativeJob {
+    #[allow(clippy::type_complexity)]
+    f: Box<dyn FnOnce(&mut Context) -> JsResult<JsValue>>,
+}
+
+impl Debug for NativeJob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NativeJob").finish_non_exhaustive()
+    }
+}
+
+impl NativeJob {
+    /// Creates a new `NativeJob` from a closure.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnOnce(&mut Context) -> JsResult<JsValue> + 'static,
+    {
+        Self { f: Box::new(f) }
+    }
+
+    /// Runs the job with the provided context.
+    pub fn call(self, context: &mut Context) -> JsResult<JsValue> {
+        (self.f)(context)
+    }
+}
+
+/// A future that resolves to a [`NativeJob`].
+///
+/// Future jobs are used by hosts to run code in the context once some asynchronous operation,
+/// like a timer or a network request, has completed. The job returned by the future is run by
+/// the [`JobQueue`] as soon as the future resolves.
+pub type FutureJob = Pin<Box<dyn Future<Output = NativeJob>>>;
+
+/// The host hook used by a [`Context`] to schedule and run jobs.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-hostenqueuepromisejob
+pub trait JobQueue: Debug {
+    /// Enqueues a job created by a promise operation.
+    ///
+    /// Jobs must be run in the same order in which they were enqueued.
+    fn enqueue_promise_job(&self, job: NativeJob, context: &mut Context);
+
+    /// Enqueues a future that resolves to a job, which must be run once the future resolves.
+    fn enqueue_future_job(&self, future: FutureJob, context: &mut Context);
+
+    /// Runs all the jobs in the queue, including the jobs enqueued while running them.
+    ///
+    /// This is called by the [`Context`] after each script or module evaluation. If running a
+    /// job fails, the error is returned and the remaining jobs stay in the queue.
+    fn run_jobs(&self, context: &mut Context) -> JsResult<()>;
+
+    /// Asynchronously runs all the jobs in the queue.
+    ///
+    /// This allows hosts using an async executor to wait for the pending future jobs without
+    /// blocking the thread. By default, this just calls [`JobQueue::run_jobs`].
+    fn run_jobs_async<'a, 'ctx, 'fut>(
+        &'a self,
+        context: &'ctx mut Context,
+    ) -> Pin<Box<dyn Future<Output = JsResult<()>> + 'fut>>
+    where
+        'a: 'fut,
+        'ctx: 'fut,
+    {
+        Box::pin(async { self.run_jobs(context) })
+    }
+}
+
+/// A job queue that discards every job.
+///
+/// This is useful for hosts that only run synchronous code, where promise reactions must never
+/// be run.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IdleJobQueue;
+
+impl JobQueue for IdleJobQueue {
+    fn enqueue_promise_job(&self, _job: NativeJob, _context: &mut Context) {}
+
+    fn enqueue_future_job(&self, _future: FutureJob, _context: &mut Context) {}
+
+    fn run_jobs(&self, _context: &mut Context) -> JsResult<()> {
+        Ok(())
+    }
+}
+
+/// A job queue that runs jobs in FIFO order.
+///
+/// Promise jobs are run first. Once there are no promise jobs left, the queue waits for the
+/// pending future jobs, running each job as soon as its future resolves, until both queues are
+/// empty. [`JobQueue::run_jobs`] blocks the current thread while waiting for futures, while
+/// [`JobQueue::run_jobs_async`] yields to the executor instead.
+///
+/// This is the default job queue of a [`Context`].
+#[derive(Default)]
+pub struct SimpleJobQueue {
+    jobs: RefCell<VecDeque<NativeJob>>,
+    futures: RefCell<Vec<FutureJob>>,
+}
+
+impl Debug for SimpleJobQueue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SimpleJobQueue")
+            .field("jobs", &self.jobs.borrow().len())
+            .field("futures", &self.futures.borrow().len())
+            .finish()
+    }
+}
+
+impl SimpleJobQueue {
+    /// Creates a new, empty `SimpleJobQueue`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if there are no pending jobs or futures in the queue.
+    pub fn is_empty(&self) -> bool {
+        self.jobs.borrow().is_empty() && self.futures.borrow().is_empty()
+    }
+
+    /// Runs the promise jobs until the queue is empty.
+    fn run_promise_jobs(&self, context: &mut Context) -> JsResult<()> {
+        loop {
+            // The borrow must end before running the job, since it can enqueue new jobs.
+            let job = self.jobs.borrow_mut().pop_front();
+            match job {
+                Some(job) => {
+                    job.call(context)?;
+                }
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+impl JobQueue for SimpleJobQueue {
+    fn enqueue_promise_job(&self, job: NativeJob, _context: &mut Context) {
+        self.jobs.borrow_mut().push_back(job);
+    }
+
+    fn enqueue_future_job(&self, future: FutureJob, _context: &mut Context) {
+        self.futures.borrow_mut().push(future);
+    }
+
+    fn run_jobs(&self, context: &mut Context) -> JsResult<()> {
+        block_on(self.run_jobs_async(context))
+    }
+
+    fn run_jobs_async<'a, 'ctx, 'fut>(
+        &'a self,
+        context: &'ctx mut Context,
+    ) -> Pin<Box<dyn Future<Output = JsResult<()>> + 'fut>>
+    where
+        'a: 'fut,
+        'ctx: 'fut,
+    {
+        Box::pin(async move {
+            loop {
+                self.run_promise_jobs(context)?;
+
+                let mut futures = mem::take(&mut *self.futures.borrow_mut());
+                if futures.is_empty() {
+                    return Ok(());
+                }
+
+                // Wait until at least one of the pending futures resolves.
+                let ready = future::poll_fn(|cx| {
+                    let mut ready = Vec::new();
+                    futures.retain_mut(|future| match future.as_mut().poll(cx) {
+                        Poll::Ready(job) => {
+                            ready.push(job);
+                            false
+                        }
+                        Poll::Pending => true,
+                    });
+                    if ready.is_empty() {
+                        Poll::Pending
+                    } else {
+                        Poll::Ready(ready)
+                    }
+                })
+                .await;
+
+                // Keep the futures enqueued while waiting after the ones that are still pending.
+                {
+                    let mut pending = self.futures.borrow_mut();
+                    futures.append(&mut pending);
+                    *pending = futures;
+                }
+
+                // The jobs of the resolved futures are run as regular jobs in the next iteration.
+                self.jobs.borrow_mut().extend(ready);
+            }
+        })
+    }
+}
+
+/// Drives a future to completion, parking the current thread while it is pending.
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let mut future = Box::pin(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = task::Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
 
 /// `JobCallback` records
 ///
@@ -0,0 +1,251 @@
+//! Host hooks to load the modules imported by other modules.
+
+use super::Module;
+use crate::{realm::Realm, Context, JsNativeError, JsResult, JsString};
+use rustc_hash::FxHashMap;
+use std::{
+    cell::RefCell,
+    fmt::Debug,
+    path::{Component, Path, PathBuf},
+};
+
+/// The host hook used by a [`Context`] to load imported modules.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-HostLoadImportedModule
+pub trait ModuleLoader: Debug {
+    /// Loads the module requested by `referrer` with the given module specifier.
+    ///
+    /// Loading the same specifier from the same referrer must always return the same [`Module`].
+    fn load_imported_module(
+        &self,
+        referrer: &Module,
+        specifier: JsString,
+        context: &mut Context,
+    ) -> JsResult<Module>;
+
+    /// Loads the module imported by `ShadowRealm.prototype.importValue` in the given realm.
+    ///
+    /// The realm is active while the module is loaded. Modules are not shared between realms,
+    /// so loading the same specifier in another realm must return a different [`Module`].
+    ///
+    /// The default implementation throws a `TypeError`.
+    fn load_realm_module(
+        &self,
+        _realm: &Realm,
+        specifier: JsString,
+        _context: &mut Context,
+    ) -> JsResult<Module> {
+        Err(JsNativeError::typ()
+            .with_message(format!("module `{specifier}` cannot be loaded"))
+            .into())
+    }
+}
+
+/// A module loader that throws a `TypeError` for every imported module.
+///
+/// This is the default module loader of a [`Context`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IdleModuleLoader;
+
+impl ModuleLoader for IdleModuleLoader {
+    fn load_imported_module(
+        &self,
+        _referrer: &Module,
+        specifier: JsString,
+        _context: &mut Context,
+    ) -> JsResult<Module> {
+        Err(JsNativeError::typ()
+            .with_message(format!("module `{specifier}` cannot be loaded"))
+            .into())
+    }
+}
+
+/// A module loader that loads modules from the file system.
+///
+/// Module specifiers starting with `./` or `../` are resolved relative to the path of the
+/// importing module, and all other specifiers are resolved relative to the root directory
+/// of the loader. Absolute specifiers and specifiers that resolve to a file outside of the
+/// root directory throw a `TypeError`. Each file is only loaded once, so the paths of modules
+/// parsed by the host should be canonicalized.
+#[derive(Debug)]
+pub struct SimpleModuleLoader {
+    root: PathBuf,
+    module_map: RefCell<FxHashMap<PathBuf, Module>>,
+}
+
+impl SimpleModuleLoader {
+    /// Creates a new `SimpleModuleLoader` that resolves modules from the given root directory.
+    pub fn new<P: Into<PathBuf>>(root: P) -> Self {
+        let root = root.into();
+        Self {
+            root: root.canonicalize().unwrap_or(root),
+            module_map: RefCell::default(),
+        }
+    }
+
+    /// Inserts an already parsed module for the given path, so that later imports of
+    /// that path return it.
+    pub fn insert(&self, path: PathBuf, module: Module) {
+        self.module_map.borrow_mut().insert(path, module);
+    }
+
+    /// Gets the module loaded for the given path, if any.
+    pub fn get(&self, path: &Path) -> Option<Module> {
+        self.module_map.borrow().get(path).cloned()
+    }
+}
+
+impl ModuleLoader for SimpleModuleLoader {
+    fn load_imported_module(
+        &self,
+        referrer: &Module,
+        specifier: JsString,
+        context: &mut Context,
+    ) -> JsResult<Module> {
+        // The referrer might be the entry point of the module graph, which was not loaded
+        // through this loader. Register it so that imports of the entry point return it.
+        if let Some(path) = referrer.path() {
+            self.module_map
+                .borrow_mut()
+                .entry(path.to_path_buf())
+                .or_insert_with(|| referrer.clone());
+        }
+
+        let specifier = specifier.to_std_string_escaped();
+        let relative = specifier.starts_with("./") || specifier.starts_with("../");
+        let base = match referrer.path().and_then(Path::parent) {
+            Some(parent) if relative => parent,
+            _ => &self.root,
+        };
+
+        let outside_root = || {
+            JsNativeError::typ()
+                .with_message(format!(
+                    "module `{specifier}` resolves outside of the module root"
+                ))
+                .into()
+        };
+
+        let mut path = base.to_path_buf();
+        for component in Path::new(&specifier).components() {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    path.pop();
+                }
+                Component::Normal(name) => path.push(name),
+                Component::RootDir | Component::Prefix(_) => return Err(outside_root()),
+            }
+        }
+
+        // Symbolic links can also point outside of the root, so the check is done on the
+        // canonicalized path.
+        let path = path.canonicalize().unwrap_or(path);
+        if !path.starts_with(&self.root) {
+            return Err(outside_root());
+        }
+
+        if let Some(module) = self.get(&path) {
+            return Ok(module);
+        }
+
+        let source = match std::fs::read(&path) {
+            Ok(source) => source,
+            Err(e) => {
+                return Err(JsNativeError::typ()
+                    .with_message(format!("could not read module `{}`: {e}", path.display()))
+                    .into())
+            }
+        };
+        let module = Module::parse(source, Some(path.clone()), context)?;
+        self.insert(path, module.clone());
+        Ok(module)
+    }
+}
+
+/// A module loader that loads modules from an in-memory map of module specifiers to
+/// source texts.
+///
+/// Each module is parsed the first time it is imported in a realm, and later imports of the
+/// same specifier in that realm return the same module.
+#[derive(Debug, Default)]
+pub struct MapModuleLoader {
+    sources: FxHashMap<String, String>,
+    module_maps: RefCell<Vec<(Realm, FxHashMap<String, Module>)>>,
+}
+
+impl MapModuleLoader {
+    /// Creates a new, empty `MapModuleLoader`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the source text of the module with the given specifier.
+    #[must_use]
+    pub fn with_module<K, S>(mut self, specifier: K, source: S) -> Self
+    where
+        K: Into<String>,
+        S: Into<String>,
+    {
+        self.sources.insert(specifier.into(), source.into());
+        self
+    }
+
+    /// Loads the module with the given specifier in the active realm.
+    fn load(&self, specifier: &JsString, context: &mut Context) -> JsResult<Module> {
+        let realm = context.realm().clone();
+        let specifier = specifier.to_std_string_escaped();
+        let cached = self
+            .module_maps
+            .borrow()
+            .iter()
+            .find(|(r, _)| *r == realm)
+            .and_then(|(_, map)| map.get(&specifier).cloned());
+        if let Some(module) = cached {
+            return Ok(module);
+        }
+
+        let source = match self.sources.get(&specifier) {
+            Some(source) => source,
+            None => {
+                return Err(JsNativeError::typ()
+                    .with_message(format!("module `{specifier}` cannot be found"))
+                    .into())
+            }
+        };
+        let module = Module::parse(source, None, context)?;
+
+        let mut module_maps = self.module_maps.borrow_mut();
+        let index = if let Some(index) = module_maps.iter().position(|(r, _)| *r == realm) {
+            index
+        } else {
+            module_maps.push((realm, FxHashMap::default()));
+            module_maps.len() - 1
+        };
+        module_maps[index].1.insert(specifier, module.clone());
+        Ok(module)
+    }
+}
+
+impl ModuleLoader for MapModuleLoader {
+    fn load_imported_module(
+        &self,
+        _referrer: &Module,
+        specifier: JsString,
+        context: &mut Context,
+    ) -> JsResult<Module> {
+        self.load(&specifier, context)
+    }
+
+    fn load_realm_module(
+        &self,
+        _realm: &Realm,
+        specifier: JsString,
+        context: &mut Context,
+    ) -> JsResult<Module> {
+        self.load(&specifier, context)
+    }
+}
@@ -0,0 +1,1173 @@
+//! Boa's implementation of ECMAScript modules.
+//!
+//! A [`Module`] is created by parsing the source text of a module with [`Module::parse`].
+//! Before it can be evaluated, all the modules it imports must be loaded with [`Module::load`],
+//! which asks the [`ModuleLoader`] of the [`Context`] to resolve each module specifier.
+//! After that, the module graph is linked with [`Module::link`] and finally evaluated with
+//! [`Module::evaluate`].
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-modules
+
+mod loader;
+
+#[cfg(test)]
+mod tests;
+
+pub use loader::{IdleModuleLoader, MapModuleLoader, ModuleLoader, SimpleModuleLoader};
+
+use crate::{
+    bytecompiler::ByteCompiler,
+    environments::{CompileTimeEnvironment, DeclarativeEnvironment},
+    object::{JsObject, ObjectData},
+    property::{PropertyDescriptor, PropertyKey},
+    symbol::WellKnownSymbols,
+    syntax::{
+        ast::node::{
+            module::default_export_local_name, ExportDeclaration, ImportKind, ModuleItem,
+            ModuleItemList, ReExportKind,
+        },
+        Parser,
+    },
+    vm::{CallFrame, CodeBlock, FinallyReturn, GeneratorResumeKind},
+    Context, JsError, JsNativeError, JsResult, JsString, JsValue,
+};
+use boa_gc::{Cell, Finalize, Gc, Trace};
+use boa_interner::Sym;
+use std::{
+    cell::Cell as StdCell,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+/// The status of a module record.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#table-cyclic-module-fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Unlinked,
+    Linking,
+    Linked,
+    Evaluating,
+    Evaluated,
+}
+
+/// The name imported by an import entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImportName {
+    /// The namespace object of the imported module.
+    Namespace,
+    /// A single exported name of the imported module.
+    Name(Sym),
+}
+
+/// The name re-exported by an indirect export entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReExportName {
+    /// All names of the imported module, re-exported as a namespace object.
+    All,
+    /// A single exported name of the imported module.
+    Name(Sym),
+}
+
+/// An `ImportEntry` record.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#table-importentry-record-fields
+#[derive(Debug, Clone, Copy)]
+struct ImportEntry {
+    module_request: Sym,
+    import_name: ImportName,
+    local_name: Sym,
+}
+
+/// An `ExportEntry` record of a binding declared in the module itself.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#table-exportentry-records
+#[derive(Debug, Clone, Copy)]
+struct LocalExportEntry {
+    export_name: Sym,
+    local_name: Sym,
+}
+
+/// An `ExportEntry` record of a binding re-exported from another module.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#table-exportentry-records
+#[derive(Debug, Clone, Copy)]
+struct IndirectExportEntry {
+    export_name: Sym,
+    module_request: Sym,
+    import_name: ReExportName,
+}
+
+/// The result of resolving an exported name of a module.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#resolvedbinding-record
+#[derive(Debug, Clone)]
+enum ResolvedBinding {
+    /// The binding `name` of the environment of `module`.
+    Binding { module: Module, name: Sym },
+    /// The namespace object of the module.
+    Namespace(Module),
+}
+
+impl PartialEq for ResolvedBinding {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::Binding { module, name },
+                Self::Binding {
+                    module: other_module,
+                    name: other_name,
+                },
+            ) => module == other_module && name == other_name,
+            (Self::Namespace(module), Self::Namespace(other)) => module == other,
+            _ => false,
+        }
+    }
+}
+
+/// The reasons why resolving an exported name can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ResolveExportError {
+    /// The name is not exported, or the resolution is circular.
+    NotFound,
+    /// The name is exported by multiple `export *` declarations.
+    Ambiguous,
+}
+
+/// The internal data of a [`Module`].
+#[derive(Trace, Finalize)]
+struct ModuleRecord {
+    #[unsafe_ignore_trace]
+    path: Option<PathBuf>,
+    #[unsafe_ignore_trace]
+    status: StdCell<Status>,
+    #[unsafe_ignore_trace]
+    dfs_index: StdCell<usize>,
+    #[unsafe_ignore_trace]
+    dfs_ancestor_index: StdCell<usize>,
+    evaluation_error: Cell<Option<JsError>>,
+    environment: Gc<DeclarativeEnvironment>,
+    compile_environment: Gc<Cell<CompileTimeEnvironment>>,
+    declarations: Gc<CodeBlock>,
+    code: Gc<CodeBlock>,
+    #[unsafe_ignore_trace]
+    requested_modules: Vec<Sym>,
+    loaded_modules: Cell<Vec<Option<Module>>>,
+    #[unsafe_ignore_trace]
+    import_entries: Vec<ImportEntry>,
+    #[unsafe_ignore_trace]
+    local_export_entries: Vec<LocalExportEntry>,
+    #[unsafe_ignore_trace]
+    indirect_export_entries: Vec<IndirectExportEntry>,
+    #[unsafe_ignore_trace]
+    star_export_entries: Vec<Sym>,
+    namespace: Cell<Option<JsObject>>,
+}
+
+/// An ECMAScript Source Text Module Record.
+///
+/// Cloning a `Module` is cheap, since it only clones a pointer to the module record.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-source-text-module-records
+#[derive(Clone, Trace, Finalize)]
+pub struct Module {
+    inner: Gc<ModuleRecord>,
+}
+
+impl fmt::Debug for Module {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Module")
+            .field("path", &self.inner.path)
+            .field("status", &self.inner.status.get())
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for Module {
+    fn eq(&self, other: &Self) -> bool {
+        Gc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for Module {}
+
+impl Module {
+    /// Abstract operation `ParseModule ( sourceText, realm, hostDefined )`.
+    ///
+    /// Parses and compiles the source text of a module.
+    /// The optional `path` is the location the module was loaded from.
+    /// It is used by the [`SimpleModuleLoader`] to resolve relative module specifiers.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-parsemodule
+    pub fn parse<S>(src: S, path: Option<PathBuf>, context: &mut Context) -> JsResult<Self>
+    where
+        S: AsRef<[u8]>,
+    {
+        let module = match Parser::new(src.as_ref()).parse_module(context) {
+            Ok(module) => module,
+            Err(e) => return Err(JsNativeError::syntax().with_message(e.to_string()).into()),
+        };
+
+        let requested_modules = module.requests();
+
+        let mut import_entries = Vec::new();
+        for item in module.items() {
+            if let ModuleItem::ImportDeclaration(import) = item {
+                if let Some(default) = import.default() {
+                    import_entries.push(ImportEntry {
+                        module_request: import.specifier(),
+                        import_name: ImportName::Name(Sym::DEFAULT),
+                        local_name: default,
+                    });
+                }
+                match import.kind() {
+                    ImportKind::DefaultOrUnnamed => {}
+                    ImportKind::Namespaced { binding } => import_entries.push(ImportEntry {
+                        module_request: import.specifier(),
+                        import_name: ImportName::Namespace,
+                        local_name: *binding,
+                    }),
+                    ImportKind::Named { names } => {
+                        for name in names.iter() {
+                            import_entries.push(ImportEntry {
+                                module_request: import.specifier(),
+                                import_name: ImportName::Name(name.export_name()),
+                                local_name: name.binding(),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut local_export_entries = Vec::new();
+        let mut indirect_export_entries = Vec::new();
+        let mut star_export_entries = Vec::new();
+        for item in module.items() {
+            let export = match item {
+                ModuleItem::ExportDeclaration(export) => export,
+                _ => continue,
+            };
+            match export {
+                ExportDeclaration::ReExport {
+                    kind: ReExportKind::Namespaced { name: None },
+                    specifier,
+                } => star_export_entries.push(*specifier),
+                ExportDeclaration::ReExport {
+                    kind: ReExportKind::Namespaced { name: Some(name) },
+                    specifier,
+                } => indirect_export_entries.push(IndirectExportEntry {
+                    export_name: *name,
+                    module_request: *specifier,
+                    import_name: ReExportName::All,
+                }),
+                ExportDeclaration::ReExport {
+                    kind: ReExportKind::Named { names },
+                    specifier,
+                } => {
+                    for name in names.iter() {
+                        indirect_export_entries.push(IndirectExportEntry {
+                            export_name: name.alias(),
+                            module_request: *specifier,
+                            import_name: ReExportName::Name(name.private_name()),
+                        });
+                    }
+                }
+                ExportDeclaration::List(names) => {
+                    for name in names.iter() {
+                        // Exports of imported bindings are re-exports of the imported module,
+                        // unless the imported binding is a namespace object.
+                        let import = import_entries
+                            .iter()
+                            .find(|entry| entry.local_name == name.private_name());
+                        match import {
+                            Some(ImportEntry {
+                                module_request,
+                                import_name: ImportName::Name(import_name),
+                                ..
+                            }) => indirect_export_entries.push(IndirectExportEntry {
+                                export_name: name.alias(),
+                                module_request: *module_request,
+                                import_name: ReExportName::Name(*import_name),
+                            }),
+                            _ => local_export_entries.push(LocalExportEntry {
+                                export_name: name.alias(),
+                                local_name: name.private_name(),
+                            }),
+                        }
+                    }
+                }
+                ExportDeclaration::Declaration(_) => {
+                    let mut names = Vec::new();
+                    export.exported_names(&mut names);
+                    local_export_entries.extend(names.into_iter().map(|name| LocalExportEntry {
+                        export_name: name,
+                        local_name: name,
+                    }));
+                }
+                ExportDeclaration::DefaultDeclaration(node)
+                | ExportDeclaration::DefaultAssignmentExpression(node) => {
+                    local_export_entries.push(LocalExportEntry {
+                        export_name: Sym::DEFAULT,
+                        local_name: default_export_local_name(node),
+                    });
+                }
+            }
+        }
+
+        let source_name = path
+            .as_deref()
+            .map(|path| JsString::new(path.display().to_string()));
+        let (declarations, code, num_bindings, compile_environment) =
+            Self::compile(&module, &import_entries, source_name, context)?;
+
+        let loaded_modules = vec![None; requested_modules.len()];
+
+        Ok(Self {
+            inner: Gc::new(ModuleRecord {
+                path,
+                status: StdCell::new(Status::Unlinked),
+                dfs_index: StdCell::new(0),
+                dfs_ancestor_index: StdCell::new(0),
+                evaluation_error: Cell::new(None),
+                environment: DeclarativeEnvironment::new_module(
+                    num_bindings,
+                    compile_environment.clone(),
+                ),
+                compile_environment,
+                declarations,
+                code,
+                requested_modules,
+                loaded_modules: Cell::new(loaded_modules),
+                import_entries,
+                local_export_entries,
+                indirect_export_entries,
+                star_export_entries,
+                namespace: Cell::new(None),
+            }),
+        })
+    }
+
+    /// Compiles the module into the code that instantiates the hoisted declarations and
+    /// the code of the module body.
+    #[allow(clippy::type_complexity)]
+    fn compile(
+        module: &ModuleItemList,
+        import_entries: &[ImportEntry],
+        source_name: Option<JsString>,
+        context: &mut Context,
+    ) -> JsResult<(
+        Gc<CodeBlock>,
+        Gc<CodeBlock>,
+        usize,
+        Gc<Cell<CompileTimeEnvironment>>,
+    )> {
+        context.push_compile_time_environment(true);
+
+        for entry in import_entries {
+            context.create_immutable_binding(entry.local_name);
+        }
+
+        let result = Self::compile_code(module, source_name, context);
+
+        let (num_bindings, compile_environment) = context.pop_compile_time_environment();
+        let (declarations, code) = result?;
+
+        Ok((declarations, code, num_bindings, compile_environment))
+    }
+
+    /// Compiles the code blocks of the module in the current compile time environment.
+    fn compile_code(
+        module: &ModuleItemList,
+        source_name: Option<JsString>,
+        context: &mut Context,
+    ) -> JsResult<(Gc<CodeBlock>, Gc<CodeBlock>)> {
+        let mut compiler = ByteCompiler::new(Sym::MAIN, true, context);
+        compiler.set_source_name(source_name.clone());
+        compiler.compile_module_declarations(module)?;
+        let declarations = Gc::new(compiler.finish());
+
+        let mut compiler = ByteCompiler::new(Sym::MAIN, true, context);
+        compiler.set_source_name(source_name);
+        compiler.compile_module_body(module)?;
+        let code = Gc::new(compiler.finish());
+
+        Ok((declarations, code))
+    }
+
+    /// Returns the path this module was loaded from, if any.
+    #[inline]
+    pub fn path(&self) -> Option<&Path> {
+        self.inner.path.as_deref()
+    }
+
+    /// Returns the module loaded for the given module specifier.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the module was not loaded yet.
+    fn loaded_module(&self, specifier: Sym) -> Self {
+        let index = self
+            .inner
+            .requested_modules
+            .iter()
+            .position(|request| *request == specifier)
+            .expect("module specifier must have been requested");
+        self.inner.loaded_modules.borrow()[index]
+            .clone()
+            .expect("requested module must have been loaded")
+    }
+
+    /// Returns the index of the binding with the given name in the module environment.
+    fn binding_index(&self, name: Sym) -> usize {
+        self.inner
+            .compile_environment
+            .borrow()
+            .get_binding(name)
+            .expect("module binding must exist")
+            .binding_index()
+    }
+
+    /// Abstract operation `LoadRequestedModules ( [ hostDefined ] )`.
+    ///
+    /// Loads all modules that are imported by this module and by the imported modules,
+    /// using the [`ModuleLoader`] of the context.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-LoadRequestedModules
+    pub fn load(&self, context: &mut Context) -> JsResult<()> {
+        let mut visited = Vec::new();
+        self.inner_load(&mut visited, context)
+    }
+
+    /// Abstract operation `InnerModuleLoading ( state, module )`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-InnerModuleLoading
+    fn inner_load(&self, visited: &mut Vec<Self>, context: &mut Context) -> JsResult<()> {
+        if visited.contains(self) {
+            return Ok(());
+        }
+        visited.push(self.clone());
+
+        if self.inner.status.get() != Status::Unlinked {
+            return Ok(());
+        }
+
+        for (index, request) in self.inner.requested_modules.iter().enumerate() {
+            let cached = self.inner.loaded_modules.borrow()[index].clone();
+            let module = if let Some(module) = cached {
+                module
+            } else {
+                let specifier = JsString::from(&*context.interner().resolve_expect_utf16(*request));
+                let loader = context.module_loader();
+                let module = loader.load_imported_module(self, specifier, context)?;
+                self.inner.loaded_modules.borrow_mut()[index] = Some(module.clone());
+                module
+            };
+            module.inner_load(visited, context)?;
+        }
+
+        Ok(())
+    }
+
+    /// Abstract operation `GetExportedNames ( [ exportStarSet ] )`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getexportednames
+    fn get_exported_names(&self, export_star_set: &mut Vec<Self>) -> Vec<Sym> {
+        // 1. If exportStarSet is not present, set exportStarSet to a new empty List.
+        // 2. If exportStarSet contains module, then
+        if export_star_set.contains(self) {
+            // a. Assert: We've reached the starting point of an export * circularity.
+            // b. Return a new empty List.
+            return Vec::new();
+        }
+
+        // 3. Append module to exportStarSet.
+        export_star_set.push(self.clone());
+
+        // 4. Let exportedNames be a new empty List.
+        // 5. For each ExportEntry Record e of module.[[LocalExportEntries]], do
+        //     a. Append e.[[ExportName]] to exportedNames.
+        // 6. For each ExportEntry Record e of module.[[IndirectExportEntries]], do
+        //     a. Append e.[[ExportName]] to exportedNames.
+        let mut exported_names: Vec<_> = self
+            .inner
+            .local_export_entries
+            .iter()
+            .map(|entry| entry.export_name)
+            .chain(
+                self.inner
+                    .indirect_export_entries
+                    .iter()
+                    .map(|entry| entry.export_name),
+            )
+            .collect();
+
+        // 7. For each ExportEntry Record e of module.[[StarExportEntries]], do
+        for request in &self.inner.star_export_entries {
+            // a. Let requestedModule be GetImportedModule(module, e.[[ModuleRequest]]).
+            let requested_module = self.loaded_module(*request);
+
+            // b. Let starNames be requestedModule.GetExportedNames(exportStarSet).
+            // c. For each element n of starNames, do
+            for name in requested_module.get_exported_names(export_star_set) {
+                // i. If SameValue(n, "default") is false, then
+                //     1. If exportedNames does not contain n, then
+                //         a. Append n to exportedNames.
+                if name != Sym::DEFAULT && !exported_names.contains(&name) {
+                    exported_names.push(name);
+                }
+            }
+        }
+
+        // 8. Return exportedNames.
+        exported_names
+    }
+
+    /// Abstract operation `ResolveExport ( exportName [ , resolveSet ] )`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-resolveexport
+    fn resolve_export(
+        &self,
+        export_name: Sym,
+        resolve_set: &mut Vec<(Self, Sym)>,
+    ) -> Result<ResolvedBinding, ResolveExportError> {
+        // 1. If resolveSet is not present, set resolveSet to a new empty List.
+        // 2. For each Record { [[Module]], [[ExportName]] } r of resolveSet, do
+        //     a. If module and r.[[Module]] are the same Module Record and SameValue(exportName, r.[[ExportName]]) is true, then
+        //         i. Assert: This is a circular import request.
+        //         ii. Return null.
+        if resolve_set
+            .iter()
+            .any(|(module, name)| module == self && *name == export_name)
+        {
+            return Err(ResolveExportError::NotFound);
+        }
+
+        // 3. Append the Record { [[Module]]: module, [[ExportName]]: exportName } to resolveSet.
+        resolve_set.push((self.clone(), export_name));
+
+        // 4. For each ExportEntry Record e of module.[[LocalExportEntries]], do
+        for entry in &self.inner.local_export_entries {
+            // a. If SameValue(exportName, e.[[ExportName]]) is true, then
+            if entry.export_name == export_name {
+                // i. Assert: module provides the direct binding for this export.
+                // ii. Return ResolvedBinding Record { [[Module]]: module, [[BindingName]]: e.[[LocalName]] }.
+                return Ok(ResolvedBinding::Binding {
+                    module: self.clone(),
+                    name: entry.local_name,
+                });
+            }
+        }
+
+        // 5. For each ExportEntry Record e of module.[[IndirectExportEntries]], do
+        for entry in &self.inner.indirect_export_entries {
+            // a. If SameValue(exportName, e.[[ExportName]]) is true, then
+            if entry.export_name == export_name {
+                // i. Let importedModule be GetImportedModule(module, e.[[ModuleRequest]]).
+                let imported_module = self.loaded_module(entry.module_request);
+
+                return match entry.import_name {
+                    // ii. If e.[[ImportName]] is all, then
+                    //     1. Assert: module does not provide the direct binding for this export.
+                    //     2. Return ResolvedBinding Record { [[Module]]: importedModule, [[BindingName]]: namespace }.
+                    ReExportName::All => Ok(ResolvedBinding::Namespace(imported_module)),
+                    // iii. Else,
+                    //     1. Assert: module imports a specific binding for this export.
+                    //     2. Return importedModule.ResolveExport(e.[[ImportName]], resolveSet).
+                    ReExportName::Name(name) => imported_module.resolve_export(name, resolve_set),
+                };
+            }
+        }
+
+        // 6. If SameValue(exportName, "default") is true, then
+        if export_name == Sym::DEFAULT {
+            // a. Assert: A default export was not explicitly defined by this module.
+            // b. Return null.
+            // c. NOTE: A default export cannot be provided by an export * from "mod" declaration.
+            return Err(ResolveExportError::NotFound);
+        }
+
+        // 7. Let starResolution be null.
+        let mut star_resolution: Option<ResolvedBinding> = None;
+
+        // 8. For each ExportEntry Record e of module.[[StarExportEntries]], do
+        for request in &self.inner.star_export_entries {
+            // a. Let importedModule be GetImportedModule(module, e.[[ModuleRequest]]).
+            let imported_module = self.loaded_module(*request);
+
+            // b. Let resolution be importedModule.ResolveExport(exportName, resolveSet).
+            match imported_module.resolve_export(export_name, resolve_set) {
+                // c. If resolution is ambiguous, return ambiguous.
+                Err(ResolveExportError::Ambiguous) => {
+                    return Err(ResolveExportError::Ambiguous);
+                }
+                // d. If resolution is not null, then
+                Ok(resolution) => match &star_resolution {
+                    // ii. If starResolution is null, then
+                    //     1. Set starResolution to resolution.
+                    None => star_resolution = Some(resolution),
+                    // iii. Else,
+                    //     1. Assert: There is more than one * import that includes the requested name.
+                    //     2. If resolution.[[Module]] and starResolution.[[Module]] are not the same Module Record, return ambiguous.
+                    //     3. If resolution.[[BindingName]] is not starResolution.[[BindingName]], return ambiguous.
+                    Some(star_resolution) => {
+                        if *star_resolution != resolution {
+                            return Err(ResolveExportError::Ambiguous);
+                        }
+                    }
+                },
+                Err(ResolveExportError::NotFound) => {}
+            }
+        }
+
+        // 9. Return starResolution.
+        star_resolution.ok_or(ResolveExportError::NotFound)
+    }
+
+    /// Abstract operation `Link ( )`.
+    ///
+    /// Links this module and all modules it imports. All imported modules must be loaded.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-moduledeclarationlinking
+    pub fn link(&self, context: &mut Context) -> JsResult<()> {
+        // 1. Assert: module.[[Status]] is one of unlinked, linked, evaluating-async, or evaluated.
+        debug_assert!(!matches!(
+            self.inner.status.get(),
+            Status::Linking | Status::Evaluating
+        ));
+
+        // 2. Let stack be a new empty List.
+        let mut stack = Vec::new();
+
+        // 3. Let result be Completion(InnerModuleLinking(module, stack, 0)).
+        // 4. If result is an abrupt completion, then
+        if let Err(err) = self.inner_link(&mut stack, 0, context) {
+            // a. For each Cyclic Module Record m of stack, do
+            for module in stack {
+                // i. Assert: m.[[Status]] is linking.
+                debug_assert_eq!(module.inner.status.get(), Status::Linking);
+                // ii. Set m.[[Status]] to unlinked.
+                module.inner.status.set(Status::Unlinked);
+            }
+            // b. Assert: module.[[Status]] is unlinked.
+            // c. Return ? result.
+            return Err(err);
+        }
+
+        // 5. Assert: module.[[Status]] is one of linked, evaluating-async, or evaluated.
+        // 6. Assert: stack is empty.
+        // 7. Return unused.
+        Ok(())
+    }
+
+    /// Abstract operation `InnerModuleLinking ( module, stack, index )`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-InnerModuleLinking
+    fn inner_link(
+        &self,
+        stack: &mut Vec<Self>,
+        mut index: usize,
+        context: &mut Context,
+    ) -> JsResult<usize> {
+        // 2. If module.[[Status]] is one of linking, linked, evaluating-async, or evaluated, then
+        if self.inner.status.get() != Status::Unlinked {
+            // a. Return index.
+            return Ok(index);
+        }
+
+        // 3. Assert: module.[[Status]] is unlinked.
+        // 4. Set module.[[Status]] to linking.
+        self.inner.status.set(Status::Linking);
+        // 5. Set module.[[DFSIndex]] to index.
+        self.inner.dfs_index.set(index);
+        // 6. Set module.[[DFSAncestorIndex]] to index.
+        self.inner.dfs_ancestor_index.set(index);
+        // 7. Set index to index + 1.
+        index += 1;
+        // 8. Append module to stack.
+        stack.push(self.clone());
+
+        // 9. For each String required of module.[[RequestedModules]], do
+        for request in &self.inner.requested_modules {
+            // a. Let requiredModule be GetImportedModule(module, required).
+            let required_module = self.loaded_module(*request);
+
+            // b. Set index to ? InnerModuleLinking(requiredModule, stack, index).
+            index = required_module.inner_link(stack, index, context)?;
+
+            // c. If requiredModule is a Cyclic Module Record, then
+            //     i. Assert: requiredModule.[[Status]] is one of linking, linked, evaluating-async, or evaluated.
+            //     ii. Assert: requiredModule.[[Status]] is linking if and only if stack contains requiredModule.
+            //     iii. If requiredModule.[[Status]] is linking, then
+            if required_module.inner.status.get() == Status::Linking {
+                // 1. Set module.[[DFSAncestorIndex]] to min(module.[[DFSAncestorIndex]], requiredModule.[[DFSAncestorIndex]]).
+                self.inner.dfs_ancestor_index.set(usize::min(
+                    self.inner.dfs_ancestor_index.get(),
+                    required_module.inner.dfs_ancestor_index.get(),
+                ));
+            }
+        }
+
+        // 10. Perform ? module.InitializeEnvironment().
+        self.initialize_environment(context)?;
+
+        // 11. Assert: module occurs exactly once in stack.
+        // 12. Assert: module.[[DFSAncestorIndex]] ≤ module.[[DFSIndex]].
+        // 13. If module.[[DFSAncestorIndex]] = module.[[DFSIndex]], then
+        if self.inner.dfs_ancestor_index.get() == self.inner.dfs_index.get() {
+            // a. Let done be false.
+            // b. Repeat, while done is false,
+            while let Some(required_module) = stack.pop() {
+                // i. Let requiredModule be the last element of stack.
+                // ii. Remove the last element of stack.
+                // iii. Assert: requiredModule is a Cyclic Module Record.
+                // iv. Set requiredModule.[[Status]] to linked.
+                required_module.inner.status.set(Status::Linked);
+                // v. If requiredModule and module are the same Module Record, set done to true.
+                if required_module == *self {
+                    break;
+                }
+            }
+        }
+
+        // 14. Return index.
+        Ok(index)
+    }
+
+    /// Abstract operation `InitializeEnvironment ( )`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-source-text-module-record-initialize-environment
+    fn initialize_environment(&self, context: &mut Context) -> JsResult<()> {
+        // 1. For each ExportEntry Record e of module.[[IndirectExportEntries]], do
+        for entry in &self.inner.indirect_export_entries {
+            // a. Let resolution be module.ResolveExport(e.[[ExportName]]).
+            // b. If resolution is either null or ambiguous, throw a SyntaxError exception.
+            // c. Assert: resolution is a ResolvedBinding Record.
+            if let Err(err) = self.resolve_export(entry.export_name, &mut Vec::new()) {
+                return Self::throw_resolve_error(err, entry.export_name, context);
+            }
+        }
+
+        // 2. Assert: All named exports from module are resolvable.
+        // 3. Let realm be module.[[Realm]].
+        // 4. Assert: realm is not undefined.
+        // 5. Let env be NewModuleEnvironment(realm.[[GlobalEnv]]).
+        // 6. Set module.[[Environment]] to env.
+        let env = &self.inner.environment;
+
+        // 7. For each ImportEntry Record in of module.[[ImportEntries]], do
+        for entry in &self.inner.import_entries {
+            // a. Let importedModule be GetImportedModule(module, in.[[ModuleRequest]]).
+            let imported_module = self.loaded_module(entry.module_request);
+            let index = self.binding_index(entry.local_name);
+
+            match entry.import_name {
+                // b. If in.[[ImportName]] is namespace-object, then
+                ImportName::Namespace => {
+                    // i. Let namespace be GetModuleNamespace(importedModule).
+                    let namespace = imported_module.namespace(context);
+                    // ii. Perform ! env.CreateImmutableBinding(in.[[LocalName]], true).
+                    // iii. Perform ! env.InitializeBinding(in.[[LocalName]], namespace).
+                    env.initialize(index, namespace.into());
+                }
+                // c. Else,
+                ImportName::Name(name) => {
+                    // i. Let resolution be importedModule.ResolveExport(in.[[ImportName]]).
+                    match imported_module.resolve_export(name, &mut Vec::new()) {
+                        // ii. If resolution is either null or ambiguous, throw a SyntaxError exception.
+                        Err(err) => return Self::throw_resolve_error(err, name, context),
+                        // iii. If resolution.[[BindingName]] is namespace, then
+                        Ok(ResolvedBinding::Namespace(module)) => {
+                            // 1. Let namespace be GetModuleNamespace(resolution.[[Module]]).
+                            let namespace = module.namespace(context);
+                            // 2. Perform ! env.CreateImmutableBinding(in.[[LocalName]], true).
+                            // 3. Perform ! env.InitializeBinding(in.[[LocalName]], namespace).
+                            env.initialize(index, namespace.into());
+                        }
+                        // iv. Else,
+                        Ok(ResolvedBinding::Binding { module, name }) => {
+                            // 1. Perform env.CreateImportBinding(in.[[LocalName]], resolution.[[Module]], resolution.[[BindingName]]).
+                            let target_index = module.binding_index(name);
+                            env.create_import_binding(
+                                index,
+                                module.inner.environment.clone(),
+                                target_index,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        // 8-26. Instantiate the `var` bindings and the hoisted function declarations.
+        self.execute(self.inner.declarations.clone(), context)
+    }
+
+    /// Throws the `SyntaxError` for a failed export resolution.
+    fn throw_resolve_error(
+        err: ResolveExportError,
+        name: Sym,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let name = context.interner().resolve_expect(name).to_owned();
+        match err {
+            ResolveExportError::NotFound => Err(JsNativeError::syntax()
+                .with_message(format!("could not find export `{name}`"))
+                .into()),
+            ResolveExportError::Ambiguous => Err(JsNativeError::syntax()
+                .with_message(format!("ambiguous export `{name}`"))
+                .into()),
+        }
+    }
+
+    /// Abstract operation `Evaluate ( )`.
+    ///
+    /// Evaluates this module and all modules it imports. The module must be linked.
+    ///
+    /// Evaluating a module more than once only runs its code the first time,
+    /// later evaluations return the result of the first evaluation.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-moduleevaluation
+    pub fn evaluate(&self, context: &mut Context) -> JsResult<()> {
+        if !matches!(self.inner.status.get(), Status::Linked | Status::Evaluated) {
+            return Err(JsNativeError::typ()
+                .with_message("cannot evaluate a module that is not linked")
+                .into());
+        }
+
+        let mut stack = Vec::new();
+
+        let result = self.inner_evaluate(&mut stack, 0, context);
+
+        if let Err(err) = &result {
+            for module in stack {
+                module.inner.status.set(Status::Evaluated);
+                *module.inner.evaluation_error.borrow_mut() = Some(err.clone());
+            }
+        }
+
+        context.run_jobs()?;
+        result.map(|_| ())
+    }
+
+    /// Abstract operation `InnerModuleEvaluation ( module, stack, index )`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-innermoduleevaluation
+    fn inner_evaluate(
+        &self,
+        stack: &mut Vec<Self>,
+        mut index: usize,
+        context: &mut Context,
+    ) -> JsResult<usize> {
+        match self.inner.status.get() {
+            // 2. If module.[[Status]] is either evaluating-async or evaluated, then
+            Status::Evaluated => {
+                // a. If module.[[EvaluationError]] is empty, return index.
+                // b. Otherwise, return ? module.[[EvaluationError]].
+                return match self.inner.evaluation_error.borrow().clone() {
+                    Some(err) => Err(err),
+                    None => Ok(index),
+                };
+            }
+            // 3. If module.[[Status]] is evaluating, return index.
+            Status::Evaluating => return Ok(index),
+            // 4. Assert: module.[[Status]] is linked.
+            Status::Linked => {}
+            Status::Unlinked | Status::Linking => {
+                return Err(JsNativeError::typ()
+                    .with_message("cannot evaluate a module that is not linked")
+                    .into())
+            }
+        }
+
+        // 5. Set module.[[Status]] to evaluating.
+        self.inner.status.set(Status::Evaluating);
+        // 6. Set module.[[DFSIndex]] to index.
+        self.inner.dfs_index.set(index);
+        // 7. Set module.[[DFSAncestorIndex]] to index.
+        self.inner.dfs_ancestor_index.set(index);
+        // 9. Set index to index + 1.
+        index += 1;
+        // 10. Append module to stack.
+        stack.push(self.clone());
+
+        // 11. For each String required of module.[[RequestedModules]], do
+        for request in &self.inner.requested_modules {
+            // a. Let requiredModule be GetImportedModule(module, required).
+            let required_module = self.loaded_module(*request);
+
+            // b. Set index to ? InnerModuleEvaluation(requiredModule, stack, index).
+            index = required_module.inner_evaluate(stack, index, context)?;
+
+            // c. If requiredModule is a Cyclic Module Record, then
+            //     i. Assert: requiredModule.[[Status]] is either evaluating, evaluating-async, or evaluated.
+            //     ii. Assert: requiredModule.[[Status]] is evaluating if and only if stack contains requiredModule.
+            //     iii. If requiredModule.[[Status]] is evaluating, then
+            if required_module.inner.status.get() == Status::Evaluating {
+                // 1. Set module.[[DFSAncestorIndex]] to min(module.[[DFSAncestorIndex]], requiredModule.[[DFSAncestorIndex]]).
+                self.inner.dfs_ancestor_index.set(usize::min(
+                    self.inner.dfs_ancestor_index.get(),
+                    required_module.inner.dfs_ancestor_index.get(),
+                ));
+            }
+        }
+
+        // 12-13. Perform ? module.ExecuteModule().
+        self.execute(self.inner.code.clone(), context)?;
+
+        // 14. Assert: module occurs exactly once in stack.
+        // 15. Assert: module.[[DFSAncestorIndex]] ≤ module.[[DFSIndex]].
+        // 16. If module.[[DFSAncestorIndex]] = module.[[DFSIndex]], then
+        if self.inner.dfs_ancestor_index.get() == self.inner.dfs_index.get() {
+            // a. Let done be false.
+            // b. Repeat, while done is false,
+            while let Some(required_module) = stack.pop() {
+                // i. Let requiredModule be the last element of stack.
+                // ii. Remove the last element of stack.
+                // iii. Assert: requiredModule is a Cyclic Module Record.
+                // iv. If requiredModule.[[AsyncEvaluation]] is false, set requiredModule.[[Status]] to evaluated.
+                required_module.inner.status.set(Status::Evaluated);
+                // vi. If requiredModule and module are the same Module Record, set done to true.
+                if required_module == *self {
+                    break;
+                }
+            }
+        }
+
+        // 17. Return index.
+        Ok(index)
+    }
+
+    /// Runs the given code block of this module inside the module environment.
+    fn execute(&self, code: Gc<CodeBlock>, context: &mut Context) -> JsResult<()> {
+        // Modules run in the global environment of the active realm.
+        let mut module_environments = context.realm().environment().clone();
+        module_environments.extend(vec![self.inner.environment.clone()]);
+        context.debugger_suspend_frame();
+        let environments = std::mem::replace(&mut context.vm.environments, module_environments);
+
+        context.vm.push_frame(CallFrame {
+            code,
+            pc: 0,
+            catch: Vec::new(),
+            finally_return: FinallyReturn::None,
+            finally_jump: Vec::new(),
+            pop_on_return: 0,
+            loop_env_stack: Vec::from([0]),
+            try_env_stack: Vec::from([crate::vm::TryStackEntry {
+                num_env: 0,
+                num_loop_stack_entries: 0,
+            }]),
+            param_count: 0,
+            arg_count: 0,
+            generator_resume_kind: GeneratorResumeKind::Normal,
+            thrown: false,
+            async_generator: None,
+            tail_call: None,
+            suspended_environments: None,
+        });
+
+        context.set_global_binding_number();
+        let result = context.run();
+        context.vm.pop_frame();
+
+        context.vm.environments = environments;
+
+        result.map(|_| ())
+    }
+
+    /// Loads, links and evaluates this module.
+    ///
+    /// This is a shortcut for calling [`Module::load`], [`Module::link`]
+    /// and [`Module::evaluate`] in order.
+    pub fn load_link_evaluate(&self, context: &mut Context) -> JsResult<()> {
+        self.load(context)?;
+        self.link(context)?;
+        self.evaluate(context)
+    }
+
+    /// Abstract operation `GetModuleNamespace ( module )`.
+    ///
+    /// Returns the module namespace object of this module, which holds all exports of the module.
+    /// All modules imported by this module must be loaded.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getmodulenamespace
+    pub fn namespace(&self, context: &mut Context) -> JsObject {
+        // 1. Assert: If module is a Cyclic Module Record, then module.[[Status]] is not new or unlinked.
+        // 2. Let namespace be module.[[Namespace]].
+        if let Some(namespace) = self.inner.namespace.borrow().as_ref() {
+            return namespace.clone();
+        }
+
+        // 3. If namespace is empty, then
+        //     a. Let exportedNames be module.GetExportedNames().
+        let exported_names = self.get_exported_names(&mut Vec::new());
+
+        //     b. Let unambiguousNames be a new empty List.
+        //     c. For each element name of exportedNames, do
+        //         i. Let resolution be module.ResolveExport(name).
+        //         ii. If resolution is a ResolvedBinding Record, append name to unambiguousNames.
+        let mut exports: Vec<_> = exported_names
+            .into_iter()
+            .filter(|name| self.resolve_export(*name, &mut Vec::new()).is_ok())
+            .map(|name| {
+                (
+                    JsString::from(&*context.interner().resolve_expect_utf16(name)),
+                    name,
+                )
+            })
+            .collect();
+
+        //     d. Set namespace to ModuleNamespaceCreate(module, unambiguousNames).
+        exports.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let namespace = JsObject::from_proto_and_data(
+            None,
+            ObjectData::module_namespace(ModuleNamespace {
+                module: self.clone(),
+                exports,
+            }),
+        );
+        namespace.insert_property(
+            WellKnownSymbols::to_string_tag(),
+            PropertyDescriptor::builder()
+                .value("Module")
+                .writable(false)
+                .enumerable(false)
+                .configurable(false),
+        );
+
+        *self.inner.namespace.borrow_mut() = Some(namespace.clone());
+
+        // 4. Return namespace.
+        namespace
+    }
+
+    /// Returns the current value of the exported binding with the given name, as read
+    /// through the namespace object of this module.
+    ///
+    /// Throws a `ReferenceError` if the binding is not initialized yet.
+    pub(crate) fn namespace_get(&self, name: Sym, context: &mut Context) -> JsResult<JsValue> {
+        // 4. Let m be O.[[Module]].
+        // 5. Let binding be m.ResolveExport(P).
+        // 6. Assert: binding is a ResolvedBinding Record.
+        let binding = self
+            .resolve_export(name, &mut Vec::new())
+            .expect("exported names of a namespace must be resolvable");
+
+        match binding {
+            // 9. If binding.[[BindingName]] is namespace, then
+            //     a. Return GetModuleNamespace(targetModule).
+            ResolvedBinding::Namespace(module) => Ok(module.namespace(context).into()),
+            // 7. Let targetModule be binding.[[Module]].
+            // 10. Let targetEnv be targetModule.[[Environment]].
+            // 12. Return ? targetEnv.GetBindingValue(binding.[[BindingName]], true).
+            ResolvedBinding::Binding { module, name } => {
+                let index = module.binding_index(name);
+                if let Some(value) = module.inner.environment.get_optional(index) {
+                    Ok(value)
+                } else {
+                    let name = context.interner().resolve_expect(name).to_owned();
+                    Err(JsNativeError::reference()
+                        .with_message(format!("{name} is not initialized"))
+                        .into())
+                }
+            }
+        }
+    }
+}
+
+/// The internal data of a module namespace exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects
+#[derive(Debug, Trace, Finalize)]
+pub struct ModuleNamespace {
+    /// The `[[Module]]` internal slot.
+    module: Module,
+
+    /// The `[[Exports]]` internal slot, ordered by the code units of the export names.
+    #[unsafe_ignore_trace]
+    exports: Vec<(JsString, Sym)>,
+}
+
+impl ModuleNamespace {
+    /// Returns the module of this namespace object.
+    #[inline]
+    pub fn module(&self) -> &Module {
+        &self.module
+    }
+
+    /// Returns the exported names of the module, in the order of the `[[Exports]]` slot.
+    pub(crate) fn exports(&self) -> impl Iterator<Item = &JsString> {
+        self.exports.iter().map(|(name, _)| name)
+    }
+
+    /// Returns the export name corresponding to the property key, if the key is exported.
+    pub(crate) fn export_name(&self, key: &PropertyKey) -> Option<Sym> {
+        let key = match key {
+            PropertyKey::String(key) => key.clone(),
+            PropertyKey::Index(index) => JsString::from(index.to_string()),
+            PropertyKey::Symbol(_) => return None,
+        };
+        self.exports
+            .iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, sym)| *sym)
+    }
+}
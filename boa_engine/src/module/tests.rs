@@ -0,0 +1,206 @@
+use super::{MapModuleLoader, Module, SimpleModuleLoader};
+use crate::{context::ContextBuilder, forward, Context, JsResult};
+use std::rc::Rc;
+
+/// Creates a context whose module loader knows the given modules.
+fn context_with_modules(modules: &[(&str, &str)]) -> Context {
+    let loader = modules
+        .iter()
+        .fold(MapModuleLoader::new(), |loader, (specifier, source)| {
+            loader.with_module(*specifier, *source)
+        });
+    ContextBuilder::default()
+        .module_loader(Rc::new(loader))
+        .build()
+}
+
+/// Loads, links and evaluates the given module source.
+fn run_module(src: &str, context: &mut Context) -> JsResult<Module> {
+    let module = Module::parse(src, None, context)?;
+    module.load_link_evaluate(context)?;
+    Ok(module)
+}
+
+#[test]
+fn import_bindings() {
+    let mut context = context_with_modules(&[(
+        "lib",
+        r#"
+            export default function add(a, b) { return a + b; }
+            export const one = 1;
+            let two = 2;
+            export { two as "2" };
+        "#,
+    )]);
+    run_module(
+        r#"
+            import add, { one, "2" as two } from "lib";
+            globalThis.result = add(one, two);
+        "#,
+        &mut context,
+    )
+    .unwrap();
+    assert_eq!(forward(&mut context, "result"), "3");
+}
+
+#[test]
+fn live_bindings() {
+    let mut context = context_with_modules(&[(
+        "counter",
+        r"
+            export let count = 0;
+            export function increment() { count++; }
+        ",
+    )]);
+    run_module(
+        r#"
+            import { count, increment } from "counter";
+            increment();
+            increment();
+            globalThis.result = count;
+        "#,
+        &mut context,
+    )
+    .unwrap();
+    assert_eq!(forward(&mut context, "result"), "2");
+}
+
+#[test]
+fn imports_are_immutable() {
+    let mut context = context_with_modules(&[("lib", "export let a = 1;")]);
+    let result = run_module(r#"import { a } from "lib"; a = 2;"#, &mut context);
+    assert!(result.is_err());
+}
+
+#[test]
+fn module_scope() {
+    let mut context = Context::default();
+    run_module(
+        r"
+            var a = 1;
+            let b = 2;
+            globalThis.result = [typeof this, a + b].join();
+        ",
+        &mut context,
+    )
+    .unwrap();
+    assert_eq!(forward(&mut context, "result"), "\"undefined,3\"");
+    assert_eq!(forward(&mut context, "typeof a"), "\"undefined\"");
+    assert_eq!(forward(&mut context, "typeof b"), "\"undefined\"");
+}
+
+#[test]
+fn cyclic_imports() {
+    let mut context = context_with_modules(&[
+        (
+            "a",
+            r#"
+                import { b } from "b";
+                export function a() { return "a"; }
+                globalThis.result = b();
+            "#,
+        ),
+        (
+            "b",
+            r#"
+                import { a } from "a";
+                export function b() { return a() + "b"; }
+            "#,
+        ),
+    ]);
+    run_module(r#"import "a";"#, &mut context).unwrap();
+    assert_eq!(forward(&mut context, "result"), "\"ab\"");
+}
+
+#[test]
+fn modules_are_evaluated_once() {
+    let mut context = context_with_modules(&[
+        ("counter", "globalThis.count = (globalThis.count ?? 0) + 1;"),
+        ("a", r#"import "counter";"#),
+        ("b", r#"import "counter";"#),
+    ]);
+    run_module(r#"import "a"; import "b"; import "counter";"#, &mut context).unwrap();
+    assert_eq!(forward(&mut context, "count"), "1");
+}
+
+#[test]
+fn namespace_object() {
+    let mut context = context_with_modules(&[
+        (
+            "lib",
+            "export let b = 2; export const a = 1; export default 3;",
+        ),
+        (
+            "reexport",
+            r#"export * from "lib"; export * as lib from "lib";"#,
+        ),
+    ]);
+    run_module(
+        r#"
+            import * as ns from "reexport";
+            globalThis.ns = ns;
+        "#,
+        &mut context,
+    )
+    .unwrap();
+    assert_eq!(
+        forward(&mut context, "Object.keys(ns).join()"),
+        "\"a,b,lib\""
+    );
+    assert_eq!(forward(&mut context, "ns.lib.default"), "3");
+    assert_eq!(forward(&mut context, "ns.default"), "undefined");
+    assert_eq!(forward(&mut context, "Object.getPrototypeOf(ns)"), "null");
+    assert_eq!(forward(&mut context, "Object.isExtensible(ns)"), "false");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(ns)"),
+        "\"[object Module]\""
+    );
+    assert_eq!(forward(&mut context, "delete ns.a"), "false");
+    assert_eq!(forward(&mut context, "ns.a = 5; ns.a"), "1");
+}
+
+#[test]
+fn link_errors() {
+    let mut context = context_with_modules(&[("lib", "export const a = 1;")]);
+    assert!(run_module(r#"import { b } from "lib";"#, &mut context).is_err());
+    assert!(run_module(r#"import { a } from "missing";"#, &mut context).is_err());
+    assert!(run_module(r#"export { b } from "lib";"#, &mut context).is_err());
+}
+
+#[test]
+fn evaluation_errors_are_cached() {
+    let mut context = context_with_modules(&[(
+        "throws",
+        "globalThis.count = (globalThis.count ?? 0) + 1; throw new Error('boom');",
+    )]);
+    assert!(run_module(r#"import "throws";"#, &mut context).is_err());
+    assert!(run_module(r#"import "throws";"#, &mut context).is_err());
+    assert_eq!(forward(&mut context, "count"), "1");
+}
+
+#[test]
+fn simple_loader_stays_in_root() {
+    let dir = std::env::temp_dir().join(format!("boa_module_root_{}", std::process::id()));
+    let root = dir.join("root");
+    std::fs::create_dir_all(&root).unwrap();
+    std::fs::write(root.join("lib.js"), "export const a = 1;").unwrap();
+    std::fs::write(dir.join("secret.js"), "globalThis.leaked = true;").unwrap();
+
+    let mut context = ContextBuilder::default()
+        .module_loader(Rc::new(SimpleModuleLoader::new(&root)))
+        .build();
+    let entry = root.canonicalize().unwrap().join("main.js");
+    let run = |src: &str, context: &mut Context| {
+        Module::parse(src, Some(entry.clone()), context)
+            .and_then(|module| module.load_link_evaluate(context))
+    };
+
+    assert!(run(r#"import { a } from "./lib.js";"#, &mut context).is_ok());
+    assert!(run(r#"import "../secret.js";"#, &mut context).is_err());
+    assert!(run(r#"import "./../../secret.js";"#, &mut context).is_err());
+    let absolute = dir.join("secret.js").to_string_lossy().replace('\\', "/");
+    assert!(run(&format!("import {absolute:?};"), &mut context).is_err());
+    assert_eq!(forward(&mut context, "typeof leaked"), "\"undefined\"");
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -225,7 +225,7 @@ pub(crate) fn integer_indexed_exotic_own_property_keys(
     );
 
     // 1. Let keys be a new empty List.
-    let mut keys = if inner.is_detached() {
+    let mut keys = if inner.is_out_of_bounds() {
         vec![]
     } else {
         // 2. If IsDetachedBuffer(O.[[ViewedArrayBuffer]]) is false, then
@@ -275,7 +275,7 @@ pub(crate) fn is_valid_integer_index(obj: &JsObject, index: u64) -> bool {
     // 3. If index is -0𝔽, return false.
     // 4. If ℝ(index) < 0 or ℝ(index) ≥ O.[[ArrayLength]], return false.
     // 5. Return true.
-    !inner.is_detached() && index < inner.array_length()
+    !inner.is_out_of_bounds() && index < inner.array_length()
 }
 
 /// Abstract operation `IntegerIndexedElementGet ( O, index )`.
@@ -19,10 +19,11 @@ pub(super) mod arguments;
 pub(super) mod array;
 pub(super) mod bound_function;
 pub(super) mod function;
-pub(crate) mod global;
 pub(super) mod integer_indexed;
+pub(super) mod module_namespace;
 pub(super) mod proxy;
 pub(super) mod string;
+pub(super) mod wrapped_function;
 
 impl JsObject {
     /// Internal method `[[GetPrototypeOf]]`
@@ -960,8 +961,12 @@ where
         }
     }
     // 3. If Type(proto) is not Object, then
-    // TODO: handle realms
     // a. Let realm be ? GetFunctionRealm(constructor).
+    let realm = match constructor.as_object() {
+        Some(object) => object.get_function_realm(context)?,
+        None => context.realm().clone(),
+    };
+
     // b. Set proto to realm's intrinsic object named intrinsicDefaultProto.
-    Ok(default(context.intrinsics().constructors()).prototype())
+    Ok(default(realm.intrinsics().constructors()).prototype())
 }
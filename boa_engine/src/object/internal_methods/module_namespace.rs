@@ -0,0 +1,315 @@
+use crate::{
+    object::{JsObject, JsPrototype},
+    property::{PropertyDescriptor, PropertyKey},
+    Context, JsResult, JsValue,
+};
+use boa_interner::Sym;
+
+use super::{InternalObjectMethods, ORDINARY_INTERNAL_METHODS};
+
+/// Definitions of the internal object methods for module namespace exotic objects.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects
+pub(crate) static MODULE_NAMESPACE_EXOTIC_INTERNAL_METHODS: InternalObjectMethods =
+    InternalObjectMethods {
+        __get_prototype_of__: module_namespace_exotic_get_prototype_of,
+        __set_prototype_of__: module_namespace_exotic_set_prototype_of,
+        __is_extensible__: module_namespace_exotic_is_extensible,
+        __prevent_extensions__: module_namespace_exotic_prevent_extensions,
+        __get_own_property__: module_namespace_exotic_get_own_property,
+        __define_own_property__: module_namespace_exotic_define_own_property,
+        __has_property__: module_namespace_exotic_has_property,
+        __get__: module_namespace_exotic_get,
+        __set__: module_namespace_exotic_set,
+        __delete__: module_namespace_exotic_delete,
+        __own_property_keys__: module_namespace_exotic_own_property_keys,
+        ..ORDINARY_INTERNAL_METHODS
+    };
+
+/// Returns the export name of the namespace corresponding to `key`, if `key` is exported.
+fn export_name(obj: &JsObject, key: &PropertyKey) -> Option<Sym> {
+    obj.borrow()
+        .as_module_namespace()
+        .expect("object must be a module namespace")
+        .export_name(key)
+}
+
+/// Gets the prototype of a module namespace exotic object, which is always `null`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-getprototypeof
+#[inline]
+#[allow(clippy::unnecessary_wraps)]
+pub(crate) fn module_namespace_exotic_get_prototype_of(
+    _obj: &JsObject,
+    _context: &mut Context,
+) -> JsResult<JsPrototype> {
+    // 1. Return null.
+    Ok(None)
+}
+
+/// Sets the prototype of a module namespace exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-setprototypeof-v
+#[inline]
+#[allow(clippy::unnecessary_wraps, clippy::needless_pass_by_value)]
+pub(crate) fn module_namespace_exotic_set_prototype_of(
+    _obj: &JsObject,
+    val: JsPrototype,
+    _context: &mut Context,
+) -> JsResult<bool> {
+    // 1. Return ! SetImmutablePrototype(O, V).
+    Ok(val.is_none())
+}
+
+/// Checks if a module namespace exotic object is extensible, which is never the case.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-isextensible
+#[inline]
+#[allow(clippy::unnecessary_wraps)]
+pub(crate) fn module_namespace_exotic_is_extensible(
+    _obj: &JsObject,
+    _context: &mut Context,
+) -> JsResult<bool> {
+    // 1. Return false.
+    Ok(false)
+}
+
+/// Prevents extensions of a module namespace exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-preventextensions
+#[inline]
+#[allow(clippy::unnecessary_wraps)]
+pub(crate) fn module_namespace_exotic_prevent_extensions(
+    _obj: &JsObject,
+    _context: &mut Context,
+) -> JsResult<bool> {
+    // 1. Return true.
+    Ok(true)
+}
+
+/// Gets own property of a module namespace exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-getownproperty-p
+#[inline]
+pub(crate) fn module_namespace_exotic_get_own_property(
+    obj: &JsObject,
+    key: &PropertyKey,
+    context: &mut Context,
+) -> JsResult<Option<PropertyDescriptor>> {
+    // 1. If Type(P) is Symbol, return OrdinaryGetOwnProperty(O, P).
+    if let PropertyKey::Symbol(_) = key {
+        return super::ordinary_get_own_property(obj, key, context);
+    }
+
+    // 2. Let exports be O.[[Exports]].
+    // 3. If P is not an element of exports, return undefined.
+    if export_name(obj, key).is_none() {
+        return Ok(None);
+    }
+
+    // 4. Let value be ? O.[[Get]](P, O).
+    let value = obj.__get__(key, obj.clone().into(), context)?;
+
+    // 5. Return PropertyDescriptor { [[Value]]: value, [[Writable]]: true, [[Enumerable]]: true, [[Configurable]]: false }.
+    Ok(Some(
+        PropertyDescriptor::builder()
+            .value(value)
+            .writable(true)
+            .enumerable(true)
+            .configurable(false)
+            .build(),
+    ))
+}
+
+/// Defines own property of a module namespace exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-defineownproperty-p-desc
+#[inline]
+pub(crate) fn module_namespace_exotic_define_own_property(
+    obj: &JsObject,
+    key: PropertyKey,
+    desc: PropertyDescriptor,
+    context: &mut Context,
+) -> JsResult<bool> {
+    // 1. If Type(P) is Symbol, return ! OrdinaryDefineOwnProperty(O, P, Desc).
+    if let PropertyKey::Symbol(_) = key {
+        return super::ordinary_define_own_property(obj, key, desc, context);
+    }
+
+    // 2. Let current be ? O.[[GetOwnProperty]](P).
+    // 3. If current is undefined, return false.
+    let current = match obj.__get_own_property__(&key, context)? {
+        Some(current) => current,
+        None => return Ok(false),
+    };
+
+    // 4. If Desc has a [[Configurable]] field and Desc.[[Configurable]] is true, return false.
+    // 5. If Desc has an [[Enumerable]] field and Desc.[[Enumerable]] is false, return false.
+    // 6. If IsAccessorDescriptor(Desc) is true, return false.
+    // 7. If Desc has a [[Writable]] field and Desc.[[Writable]] is false, return false.
+    if desc.configurable() == Some(true)
+        || desc.enumerable() == Some(false)
+        || desc.is_accessor_descriptor()
+        || desc.writable() == Some(false)
+    {
+        return Ok(false);
+    }
+
+    // 8. If Desc has a [[Value]] field, return SameValue(Desc.[[Value]], current.[[Value]]).
+    // 9. Return true.
+    Ok(desc.value().map_or(true, |value| {
+        JsValue::same_value(value, current.expect_value())
+    }))
+}
+
+/// Checks if a module namespace exotic object has the given property.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-hasproperty-p
+#[inline]
+pub(crate) fn module_namespace_exotic_has_property(
+    obj: &JsObject,
+    key: &PropertyKey,
+    context: &mut Context,
+) -> JsResult<bool> {
+    // 1. If Type(P) is Symbol, return ! OrdinaryHasProperty(O, P).
+    if let PropertyKey::Symbol(_) = key {
+        return super::ordinary_has_property(obj, key, context);
+    }
+
+    // 2. Let exports be O.[[Exports]].
+    // 3. If P is an element of exports, return true.
+    // 4. Return false.
+    Ok(export_name(obj, key).is_some())
+}
+
+/// Gets a property of a module namespace exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-get-p-receiver
+#[inline]
+pub(crate) fn module_namespace_exotic_get(
+    obj: &JsObject,
+    key: &PropertyKey,
+    receiver: JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. If Type(P) is Symbol, then
+    if let PropertyKey::Symbol(_) = key {
+        // a. Return ! OrdinaryGet(O, P, Receiver).
+        return super::ordinary_get(obj, key, receiver, context);
+    }
+
+    // 2. Let exports be O.[[Exports]].
+    // 3. If P is not an element of exports, return undefined.
+    let name = match export_name(obj, key) {
+        Some(name) => name,
+        None => return Ok(JsValue::undefined()),
+    };
+
+    // 4-12. Return ? targetEnv.GetBindingValue(binding.[[BindingName]], true).
+    let module = obj
+        .borrow()
+        .as_module_namespace()
+        .expect("object must be a module namespace")
+        .module()
+        .clone();
+    module.namespace_get(name, context)
+}
+
+/// Sets a property of a module namespace exotic object, which always fails.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-set-p-v-receiver
+#[inline]
+#[allow(clippy::unnecessary_wraps)]
+pub(crate) fn module_namespace_exotic_set(
+    _obj: &JsObject,
+    _key: PropertyKey,
+    _value: JsValue,
+    _receiver: JsValue,
+    _context: &mut Context,
+) -> JsResult<bool> {
+    // 1. Return false.
+    Ok(false)
+}
+
+/// Deletes a property of a module namespace exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-delete-p
+#[inline]
+pub(crate) fn module_namespace_exotic_delete(
+    obj: &JsObject,
+    key: &PropertyKey,
+    context: &mut Context,
+) -> JsResult<bool> {
+    // 1. If Type(P) is Symbol, then
+    if let PropertyKey::Symbol(_) = key {
+        // a. Return ! OrdinaryDelete(O, P).
+        return super::ordinary_delete(obj, key, context);
+    }
+
+    // 2. Let exports be O.[[Exports]].
+    // 3. If P is an element of exports, return false.
+    // 4. Return true.
+    Ok(export_name(obj, key).is_none())
+}
+
+/// Gets own property keys of a module namespace exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-ownpropertykeys
+#[inline]
+pub(crate) fn module_namespace_exotic_own_property_keys(
+    obj: &JsObject,
+    context: &mut Context,
+) -> JsResult<Vec<PropertyKey>> {
+    // 1. Let exports be O.[[Exports]].
+    let mut keys: Vec<PropertyKey> = obj
+        .borrow()
+        .as_module_namespace()
+        .expect("object must be a module namespace")
+        .exports()
+        .cloned()
+        .map(Into::into)
+        .collect();
+
+    // 2. Let symbolKeys be OrdinaryOwnPropertyKeys(O).
+    let symbol_keys = super::ordinary_own_property_keys(obj, context)?;
+
+    // 3. Return the list-concatenation of exports and symbolKeys.
+    keys.extend(symbol_keys);
+    Ok(keys)
+}
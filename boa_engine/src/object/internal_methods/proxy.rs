@@ -3,7 +3,7 @@ use crate::{
     object::{InternalObjectMethods, JsObject, JsPrototype},
     property::{PropertyDescriptor, PropertyKey},
     value::Type,
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue, JsVariant,
 };
 use rustc_hash::FxHashSet;
 
@@ -61,7 +61,7 @@ pub(crate) fn proxy_exotic_get_prototype_of(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "getPrototypeOf").
     let trap = if let Some(trap) = handler.get_method("getPrototypeOf", context)? {
@@ -76,10 +76,14 @@ pub(crate) fn proxy_exotic_get_prototype_of(
     let handler_proto = trap.call(&handler.into(), &[target.clone().into()], context)?;
 
     // 8. If Type(handlerProto) is neither Object nor Null, throw a TypeError exception.
-    let handler_proto = match &handler_proto {
-        JsValue::Object(obj) => Some(obj.clone()),
-        JsValue::Null => None,
-        _ => return context.throw_type_error("Proxy trap result is neither object nor null"),
+    let handler_proto = match handler_proto.variant() {
+        JsVariant::Object(obj) => Some(obj.clone()),
+        JsVariant::Null => None,
+        _ => {
+            return Err(JsNativeError::typ()
+                .with_message("Proxy trap result is neither object nor null")
+                .into())
+        }
     };
 
     // 9. Let extensibleTarget be ? IsExtensible(target).
@@ -93,7 +97,9 @@ pub(crate) fn proxy_exotic_get_prototype_of(
 
     // 12. If SameValue(handlerProto, targetProto) is false, throw a TypeError exception.
     if handler_proto != target_proto {
-        return context.throw_type_error("Proxy trap returned unexpected prototype");
+        return Err(JsNativeError::typ()
+            .with_message("Proxy trap returned unexpected prototype")
+            .into());
     }
 
     // 13. Return handlerProto.
@@ -120,7 +126,7 @@ pub(crate) fn proxy_exotic_set_prototype_of(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "setPrototypeOf").
     let trap = if let Some(trap) = handler.get_method("setPrototypeOf", context)? {
@@ -138,7 +144,7 @@ pub(crate) fn proxy_exotic_set_prototype_of(
             &handler.into(),
             &[
                 target.clone().into(),
-                val.clone().map_or(JsValue::Null, Into::into),
+                val.clone().map_or(JsValue::null(), Into::into),
             ],
             context,
         )?
@@ -158,7 +164,9 @@ pub(crate) fn proxy_exotic_set_prototype_of(
 
     // 12. If SameValue(V, targetProto) is false, throw a TypeError exception.
     if val != target_proto {
-        return context.throw_type_error("Proxy trap failed to set prototype");
+        return Err(JsNativeError::typ()
+            .with_message("Proxy trap failed to set prototype")
+            .into());
     }
 
     // 13. Return true.
@@ -181,7 +189,7 @@ pub(crate) fn proxy_exotic_is_extensible(obj: &JsObject, context: &mut Context)
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "isExtensible").
     let trap = if let Some(trap) = handler.get_method("isExtensible", context)? {
@@ -202,7 +210,9 @@ pub(crate) fn proxy_exotic_is_extensible(obj: &JsObject, context: &mut Context)
 
     // 9. If SameValue(booleanTrapResult, targetResult) is false, throw a TypeError exception.
     if boolean_trap_result != target_result {
-        return context.throw_type_error("Proxy trap returned unexpected extensible value");
+        return Err(JsNativeError::typ()
+            .with_message("Proxy trap returned unexpected extensible value")
+            .into());
     }
 
     // 10. Return booleanTrapResult.
@@ -228,7 +238,7 @@ pub(crate) fn proxy_exotic_prevent_extensions(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "preventExtensions").
     let trap = if let Some(trap) = handler.get_method("preventExtensions", context)? {
@@ -248,7 +258,9 @@ pub(crate) fn proxy_exotic_prevent_extensions(
     if boolean_trap_result && target.is_extensible(context)? {
         // a. Let extensibleTarget be ? IsExtensible(target).
         // b. If extensibleTarget is true, throw a TypeError exception.
-        return context.throw_type_error("Proxy trap failed to set extensible");
+        return Err(JsNativeError::typ()
+            .with_message("Proxy trap failed to set extensible")
+            .into());
     }
 
     // 9. Return booleanTrapResult.
@@ -275,7 +287,7 @@ pub(crate) fn proxy_exotic_get_own_property(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "getOwnPropertyDescriptor").
     let trap = if let Some(trap) = handler.get_method("getOwnPropertyDescriptor", context)? {
@@ -295,7 +307,9 @@ pub(crate) fn proxy_exotic_get_own_property(
 
     // 8. If Type(trapResultObj) is neither Object nor Undefined, throw a TypeError exception.
     if !trap_result_obj.is_object() && !trap_result_obj.is_undefined() {
-        return context.throw_type_error("Proxy trap result is neither object nor undefined");
+        return Err(JsNativeError::typ()
+            .with_message("Proxy trap result is neither object nor undefined")
+            .into());
     }
 
     // 9. Let targetDesc be ? target.[[GetOwnProperty]](P).
@@ -306,17 +320,19 @@ pub(crate) fn proxy_exotic_get_own_property(
         if let Some(desc) = target_desc {
             // b. If targetDesc.[[Configurable]] is false, throw a TypeError exception.
             if !desc.expect_configurable() {
-                return context.throw_type_error(
-                    "Proxy trap result is undefined adn target result is not configurable",
-                );
+                return Err(JsNativeError::typ()
+                    .with_message(
+                        "Proxy trap result is undefined adn target result is not configurable",
+                    )
+                    .into());
             }
 
             // c. Let extensibleTarget be ? IsExtensible(target).
             // d. If extensibleTarget is false, throw a TypeError exception.
             if !target.is_extensible(context)? {
-                return context.throw_type_error(
-                    "Proxy trap result is undefined and target is not extensible",
-                );
+                return Err(JsNativeError::typ()
+                    .with_message("Proxy trap result is undefined and target is not extensible")
+                    .into());
             }
             // e. Return undefined.
             return Ok(None);
@@ -342,7 +358,9 @@ pub(crate) fn proxy_exotic_get_own_property(
         result_desc.clone(),
         target_desc.clone(),
     ) {
-        return context.throw_type_error("Proxy trap returned unexpected property");
+        return Err(JsNativeError::typ()
+            .with_message("Proxy trap returned unexpected property")
+            .into());
     }
 
     // 16. If resultDesc.[[Configurable]] is false, then
@@ -355,16 +373,18 @@ pub(crate) fn proxy_exotic_get_own_property(
                     // i. If targetDesc.[[Writable]] is true, throw a TypeError exception.
                     if desc.expect_writable() {
                         return
-                            context.throw_type_error("Proxy trap result is writable and not configurable while target result is not configurable")
+                            Err(JsNativeError::typ().with_message("Proxy trap result is writable and not configurable while target result is not configurable").into())
                         ;
                     }
                 }
             }
             // i. Throw a TypeError exception.
             _ => {
-                return context.throw_type_error(
-                    "Proxy trap result is not configurable and target result is undefined",
-                )
+                return Err(JsNativeError::typ()
+                    .with_message(
+                        "Proxy trap result is not configurable and target result is undefined",
+                    )
+                    .into())
             }
         }
     }
@@ -394,7 +414,7 @@ pub(crate) fn proxy_exotic_define_own_property(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "defineProperty").
     let trap = if let Some(trap) = handler.get_method("defineProperty", context)? {
@@ -435,12 +455,16 @@ pub(crate) fn proxy_exotic_define_own_property(
         None => {
             // a. If extensibleTarget is false, throw a TypeError exception.
             if !extensible_target {
-                return context.throw_type_error("Proxy trap failed to set property");
+                return Err(JsNativeError::typ()
+                    .with_message("Proxy trap failed to set property")
+                    .into());
             }
 
             // b. If settingConfigFalse is true, throw a TypeError exception.
             if setting_config_false {
-                return context.throw_type_error("Proxy trap failed to set property");
+                return Err(JsNativeError::typ()
+                    .with_message("Proxy trap failed to set property")
+                    .into());
             }
         }
         // 15. Else,
@@ -451,14 +475,16 @@ pub(crate) fn proxy_exotic_define_own_property(
                 desc.clone(),
                 Some(target_desc.clone()),
             ) {
-                return context.throw_type_error("Proxy trap set property to unexpected value");
+                return Err(JsNativeError::typ()
+                    .with_message("Proxy trap set property to unexpected value")
+                    .into());
             }
 
             // b. If settingConfigFalse is true and targetDesc.[[Configurable]] is true, throw a TypeError exception.
             if setting_config_false && target_desc.expect_configurable() {
-                return context.throw_type_error(
-                    "Proxy trap set property with unexpected configurable field",
-                );
+                return Err(JsNativeError::typ()
+                    .with_message("Proxy trap set property with unexpected configurable field")
+                    .into());
             }
 
             // c. If IsDataDescriptor(targetDesc) is true, targetDesc.[[Configurable]] is false, and targetDesc.[[Writable]] is true, then
@@ -469,9 +495,9 @@ pub(crate) fn proxy_exotic_define_own_property(
                 // i. If Desc has a [[Writable]] field and Desc.[[Writable]] is false, throw a TypeError exception.
                 if let Some(writable) = desc.writable() {
                     if !writable {
-                        return context.throw_type_error(
-                            "Proxy trap set property with unexpected writable field",
-                        );
+                        return Err(JsNativeError::typ()
+                            .with_message("Proxy trap set property with unexpected writable field")
+                            .into());
                     }
                 }
             }
@@ -502,7 +528,7 @@ pub(crate) fn proxy_exotic_has_property(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "has").
     let trap = if let Some(trap) = handler.get_method("has", context)? {
@@ -531,13 +557,17 @@ pub(crate) fn proxy_exotic_has_property(
         if let Some(target_desc) = target_desc {
             // i. If targetDesc.[[Configurable]] is false, throw a TypeError exception.
             if !target_desc.expect_configurable() {
-                return context.throw_type_error("Proxy trap returned unexpected property");
+                return Err(JsNativeError::typ()
+                    .with_message("Proxy trap returned unexpected property")
+                    .into());
             }
 
             // ii. Let extensibleTarget be ? IsExtensible(target).
             // iii. If extensibleTarget is false, throw a TypeError exception.
             if !target.is_extensible(context)? {
-                return context.throw_type_error("Proxy trap returned unexpected property");
+                return Err(JsNativeError::typ()
+                    .with_message("Proxy trap returned unexpected property")
+                    .into());
             }
         }
     }
@@ -567,7 +597,7 @@ pub(crate) fn proxy_exotic_get(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "get").
     let trap = if let Some(trap) = handler.get_method("get", context)? {
@@ -595,8 +625,9 @@ pub(crate) fn proxy_exotic_get(
             if target_desc.is_data_descriptor() && !target_desc.expect_writable() {
                 // i. If SameValue(trapResult, targetDesc.[[Value]]) is false, throw a TypeError exception.
                 if !JsValue::same_value(&trap_result, target_desc.expect_value()) {
-                    return context
-                        .throw_type_error("Proxy trap returned unexpected data descriptor");
+                    return Err(JsNativeError::typ()
+                        .with_message("Proxy trap returned unexpected data descriptor")
+                        .into());
                 }
             }
 
@@ -604,8 +635,9 @@ pub(crate) fn proxy_exotic_get(
             if target_desc.is_accessor_descriptor() && target_desc.expect_get().is_undefined() {
                 // i. If trapResult is not undefined, throw a TypeError exception.
                 if !trap_result.is_undefined() {
-                    return context
-                        .throw_type_error("Proxy trap returned unexpected accessor descriptor");
+                    return Err(JsNativeError::typ()
+                        .with_message("Proxy trap returned unexpected accessor descriptor")
+                        .into());
                 }
             }
         }
@@ -637,7 +669,7 @@ pub(crate) fn proxy_exotic_set(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "set").
     let trap = if let Some(trap) = handler.get_method("set", context)? {
@@ -671,17 +703,20 @@ pub(crate) fn proxy_exotic_set(
             if target_desc.is_data_descriptor() && !target_desc.expect_writable() {
                 // i. If SameValue(V, targetDesc.[[Value]]) is false, throw a TypeError exception.
                 if !JsValue::same_value(&value, target_desc.expect_value()) {
-                    return context.throw_type_error("Proxy trap set unexpected data descriptor");
+                    return Err(JsNativeError::typ()
+                        .with_message("Proxy trap set unexpected data descriptor")
+                        .into());
                 }
             }
 
             // b. If IsAccessorDescriptor(targetDesc) is true, then
             if target_desc.is_accessor_descriptor() {
                 // i. If targetDesc.[[Set]] is undefined, throw a TypeError exception.
-                match target_desc.set() {
-                    None | Some(&JsValue::Undefined) => {
-                        return context
-                            .throw_type_error("Proxy trap set unexpected accessor descriptor");
+                match target_desc.set().map(JsValue::variant) {
+                    None | Some(JsVariant::Undefined) => {
+                        return Err(JsNativeError::typ()
+                            .with_message("Proxy trap set unexpected accessor descriptor")
+                            .into());
                     }
                     _ => {}
                 }
@@ -713,7 +748,7 @@ pub(crate) fn proxy_exotic_delete(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "deleteProperty").
     let trap = if let Some(trap) = handler.get_method("deleteProperty", context)? {
@@ -744,7 +779,9 @@ pub(crate) fn proxy_exotic_delete(
         // 11. If targetDesc.[[Configurable]] is false, throw a TypeError exception.
         Some(target_desc) => {
             if !target_desc.expect_configurable() {
-                return context.throw_type_error("Proxy trap failed to delete property");
+                return Err(JsNativeError::typ()
+                    .with_message("Proxy trap failed to delete property")
+                    .into());
             }
         }
     }
@@ -752,7 +789,9 @@ pub(crate) fn proxy_exotic_delete(
     // 12. Let extensibleTarget be ? IsExtensible(target).
     // 13. If extensibleTarget is false, throw a TypeError exception.
     if !target.is_extensible(context)? {
-        return context.throw_type_error("Proxy trap failed to delete property");
+        return Err(JsNativeError::typ()
+            .with_message("Proxy trap failed to delete property")
+            .into());
     }
 
     // 14. Return true.
@@ -778,7 +817,7 @@ pub(crate) fn proxy_exotic_own_property_keys(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "ownKeys").
     let trap = if let Some(trap) = handler.get_method("ownKeys", context)? {
@@ -800,20 +839,20 @@ pub(crate) fn proxy_exotic_own_property_keys(
     let mut unchecked_result_keys: FxHashSet<PropertyKey> = FxHashSet::default();
     let mut trap_result = Vec::new();
     for value in &trap_result_raw {
-        match value {
-            JsValue::String(s) => {
+        match value.variant() {
+            JsVariant::String(s) => {
                 if !unchecked_result_keys.insert(s.clone().into()) {
-                    return context.throw_type_error(
-                        "Proxy trap result contains duplicate string property keys",
-                    );
+                    return Err(JsNativeError::typ()
+                        .with_message("Proxy trap result contains duplicate string property keys")
+                        .into());
                 }
                 trap_result.push(s.clone().into());
             }
-            JsValue::Symbol(s) => {
+            JsVariant::Symbol(s) => {
                 if !unchecked_result_keys.insert(s.clone().into()) {
-                    return context.throw_type_error(
-                        "Proxy trap result contains duplicate symbol property keys",
-                    );
+                    return Err(JsNativeError::typ()
+                        .with_message("Proxy trap result contains duplicate symbol property keys")
+                        .into());
                 }
                 trap_result.push(s.clone().into());
             }
@@ -863,9 +902,9 @@ pub(crate) fn proxy_exotic_own_property_keys(
         // a. If key is not an element of uncheckedResultKeys, throw a TypeError exception.
         // b. Remove key from uncheckedResultKeys.
         if !unchecked_result_keys.remove(&key) {
-            return context.throw_type_error(
-                "Proxy trap failed to return all non-configurable property keys",
-            );
+            return Err(JsNativeError::typ()
+                .with_message("Proxy trap failed to return all non-configurable property keys")
+                .into());
         }
     }
 
@@ -879,14 +918,17 @@ pub(crate) fn proxy_exotic_own_property_keys(
         // a. If key is not an element of uncheckedResultKeys, throw a TypeError exception.
         // b. Remove key from uncheckedResultKeys.
         if !unchecked_result_keys.remove(&key) {
-            return context
-                .throw_type_error("Proxy trap failed to return all configurable property keys");
+            return Err(JsNativeError::typ()
+                .with_message("Proxy trap failed to return all configurable property keys")
+                .into());
         }
     }
 
     // 22. If uncheckedResultKeys is not empty, throw a TypeError exception.
     if !unchecked_result_keys.is_empty() {
-        return context.throw_type_error("Proxy trap failed to return all property keys");
+        return Err(JsNativeError::typ()
+            .with_message("Proxy trap failed to return all property keys")
+            .into());
     }
 
     // 23. Return trapResult.
@@ -913,7 +955,7 @@ fn proxy_exotic_call(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "apply").
     let trap = if let Some(trap) = handler.get_method("apply", context)? {
@@ -955,7 +997,7 @@ fn proxy_exotic_construct(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Assert: IsConstructor(target) is true.
     assert!(target.is_constructor());
@@ -985,7 +1027,7 @@ fn proxy_exotic_construct(
 
     // 10. If Type(newObj) is not Object, throw a TypeError exception.
     let new_obj = new_obj.as_object().cloned().ok_or_else(|| {
-        context.construct_type_error("Proxy trap constructor returned non-object value")
+        JsNativeError::typ().with_message("Proxy trap constructor returned non-object value")
     })?;
 
     // 11. Return newObj.
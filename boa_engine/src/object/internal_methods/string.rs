@@ -1,7 +1,7 @@
 use crate::{
     object::JsObject,
     property::{PropertyDescriptor, PropertyKey},
-    Context, JsResult, JsValue,
+    Context, JsResult, JsString, JsValue,
 };
 
 use super::{InternalObjectMethods, ORDINARY_INTERNAL_METHODS};
@@ -97,7 +97,7 @@ pub(crate) fn string_exotic_own_property_keys(
         .as_string()
         .expect("string exotic method should only be callable from string objects");
     // 4. Let len be the length of str.
-    let len = string.encode_utf16().count();
+    let len = string.len();
 
     // 1. Let keys be a new empty List.
     let mut keys = Vec::with_capacity(len);
@@ -173,9 +173,8 @@ fn string_get_own_property(obj: &JsObject, key: &PropertyKey) -> Option<Property
     // 11. If ℝ(index) < 0 or len ≤ ℝ(index), return undefined.
     // 12. Let resultStr be the String value of length 1, containing one code unit from str, specifically the code unit at index ℝ(index).
     let result_str = string
-        .encode_utf16()
-        .nth(pos)
-        .map(|c| JsValue::from(String::from_utf16_lossy(&[c])))?;
+        .get(pos)
+        .map(|c| JsValue::from(JsString::from(&[*c][..])))?;
 
     // 13. Return the PropertyDescriptor { [[Value]]: resultStr, [[Writable]]: false, [[Enumerable]]: true, [[Configurable]]: false }.
     let desc = PropertyDescriptor::builder()
@@ -0,0 +1,106 @@
+use crate::{
+    builtins::shadow_realm::get_wrapped_value, object::JsObject, realm::Realm, Context,
+    JsNativeError, JsResult, JsValue,
+};
+
+use super::{InternalObjectMethods, ORDINARY_INTERNAL_METHODS};
+
+/// Definitions of the internal object methods for wrapped function exotic objects.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrapped-function-exotic-objects
+pub(crate) static WRAPPED_FUNCTION_EXOTIC_INTERNAL_METHODS: InternalObjectMethods =
+    InternalObjectMethods {
+        __call__: Some(wrapped_function_exotic_call),
+        ..ORDINARY_INTERNAL_METHODS
+    };
+
+/// Internal method `[[Call]]` for Wrapped Function Exotic Objects
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrapped-function-exotic-objects-call-thisargument-argumentslist
+#[track_caller]
+#[inline]
+fn wrapped_function_exotic_call(
+    obj: &JsObject,
+    this: &JsValue,
+    arguments_list: &[JsValue],
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    let (target, caller_realm) = {
+        let obj = obj.borrow();
+        let wrapped_function = obj.as_wrapped_function().expect(
+            "wrapped function exotic method should only be callable from wrapped function objects",
+        );
+        (
+            wrapped_function.target_function().clone(),
+            wrapped_function.realm().clone(),
+        )
+    };
+
+    // 1. Let callerContext be the running execution context.
+    // 2. If callerContext is not already suspended, suspend callerContext.
+    // 3. Let calleeContext be PrepareForWrappedFunctionCall(F).
+    // 4. Assert: calleeContext is now the running execution context.
+    let old_realm = context.enter_realm(caller_realm.clone());
+
+    // 5. Let result be Completion(OrdinaryWrappedFunctionCall(F, thisArgument, argumentsList)).
+    let result =
+        ordinary_wrapped_function_call(&target, &caller_realm, this, arguments_list, context);
+
+    // 6. Remove calleeContext from the execution context stack and restore callerContext as
+    //    the running execution context.
+    context.enter_realm(old_realm);
+
+    // 7. Return ? result.
+    result
+}
+
+/// Abstract operation `OrdinaryWrappedFunctionCall ( F, thisArgument, argumentsList )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-ordinary-wrapped-function-call
+fn ordinary_wrapped_function_call(
+    target: &JsObject,
+    caller_realm: &Realm,
+    this: &JsValue,
+    arguments_list: &[JsValue],
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. Let target be F.[[WrappedTargetFunction]].
+    // 2. Assert: IsCallable(target) is true.
+    // 3. Let callerRealm be F.[[Realm]].
+    // 4. NOTE: Any exception objects produced after this point are associated with callerRealm.
+    // 5. Let targetRealm be ? GetFunctionRealm(target).
+    let target_realm = target.get_function_realm(context)?;
+
+    // 6. Let wrappedArgs be a new empty List.
+    // 7. For each element arg of argumentsList, do
+    //     a. Let wrappedValue be ? GetWrappedValue(targetRealm, arg).
+    //     b. Append wrappedValue to wrappedArgs.
+    let wrapped_args = arguments_list
+        .iter()
+        .map(|arg| get_wrapped_value(&target_realm, arg, context))
+        .collect::<JsResult<Vec<_>>>()?;
+
+    // 8. Let wrappedThisArgument be ? GetWrappedValue(targetRealm, thisArgument).
+    let wrapped_this = get_wrapped_value(&target_realm, this, context)?;
+
+    // 9. Let result be the Completion Record of Call(target, wrappedThisArgument, wrappedArgs).
+    match target.call(&wrapped_this, &wrapped_args, context) {
+        // 10. If result.[[Type]] is normal or result.[[Type]] is return, then
+        //     a. Return ? GetWrappedValue(callerRealm, result.[[Value]]).
+        Ok(result) => get_wrapped_value(caller_realm, &result, context),
+        // 11. Else,
+        //     a. Throw a TypeError exception.
+        Err(_) => Err(JsNativeError::typ()
+            .with_message("the function wrapped by a ShadowRealm threw an error")
+            .into()),
+    }
+}
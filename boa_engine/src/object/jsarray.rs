@@ -2,7 +2,7 @@ use crate::{
     builtins::Array,
     object::{JsFunction, JsObject, JsObjectType},
     value::IntoOrUndefined,
-    Context, JsResult, JsString, JsValue,
+    Context, JsNativeError, JsResult, JsString, JsValue,
 };
 use boa_gc::{Finalize, Trace};
 use std::ops::Deref;
@@ -38,11 +38,13 @@ impl JsArray {
     ///
     /// This does not clone the fields of the array, it only does a shallow clone of the object.
     #[inline]
-    pub fn from_object(object: JsObject, context: &mut Context) -> JsResult<Self> {
+    pub fn from_object(object: JsObject, _context: &mut Context) -> JsResult<Self> {
         if object.borrow().is_array() {
             Ok(Self { inner: object })
         } else {
-            context.throw_type_error("object is not an Array")
+            Err(JsNativeError::typ()
+                .with_message("object is not an Array")
+                .into())
         }
     }
 
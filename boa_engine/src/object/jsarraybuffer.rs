@@ -1,12 +1,12 @@
 use crate::{
-    builtins::array_buffer::ArrayBuffer,
+    builtins::array_buffer::{ArrayBuffer, DataBlock},
     context::intrinsics::StandardConstructors,
     object::{
         internal_methods::get_prototype_from_constructor, JsObject, JsObjectType, ObjectData,
     },
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue,
 };
-use boa_gc::{Finalize, Trace};
+use boa_gc::{ExternalMemory, Finalize, Trace};
 use std::ops::Deref;
 
 /// JavaScript `ArrayBuffer` rust object.
@@ -27,6 +27,7 @@ impl JsArrayBuffer {
                 .constructor()
                 .into(),
             byte_length as u64,
+            None,
             context,
         )?;
 
@@ -66,10 +67,13 @@ impl JsArrayBuffer {
 
         // 3. Set obj.[[ArrayBufferData]] to block.
         // 4. Set obj.[[ArrayBufferByteLength]] to byteLength.
+        let data_block_memory = ExternalMemory::new(block.capacity());
         obj.borrow_mut().data = ObjectData::array_buffer(ArrayBuffer {
-            array_buffer_data: Some(block),
+            array_buffer_data: Some(DataBlock::Unshared(block)),
             array_buffer_byte_length: byte_length as u64,
-            array_buffer_detach_key: JsValue::Undefined,
+            array_buffer_max_byte_length: None,
+            array_buffer_detach_key: JsValue::undefined(),
+            data_block_memory,
         });
 
         Ok(Self { inner: obj })
@@ -77,13 +81,21 @@ impl JsArrayBuffer {
 
     /// Create a [`JsArrayBuffer`] from a [`JsObject`], if the object is not an array buffer throw a `TypeError`.
     ///
+    /// `SharedArrayBuffer` objects are not array buffers, see [`JsSharedArrayBuffer`][super::JsSharedArrayBuffer].
+    ///
     /// This does not clone the fields of the array buffer, it only does a shallow clone of the object.
     #[inline]
-    pub fn from_object(object: JsObject, context: &mut Context) -> JsResult<Self> {
-        if object.borrow().is_array_buffer() {
+    pub fn from_object(object: JsObject, _context: &mut Context) -> JsResult<Self> {
+        if object
+            .borrow()
+            .as_array_buffer()
+            .map_or(false, |buffer| !buffer.is_shared())
+        {
             Ok(Self { inner: object })
         } else {
-            context.throw_type_error("object is not an ArrayBuffer")
+            Err(JsNativeError::typ()
+                .with_message("object is not an ArrayBuffer")
+                .into())
         }
     }
 
@@ -3,7 +3,7 @@ use crate::{
     builtins::map::{add_entries_from_iterable, ordered_map::OrderedMap},
     builtins::Map,
     object::{JsFunction, JsMapIterator, JsObject, JsObjectType, ObjectData},
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue,
 };
 
 use boa_gc::{Finalize, Trace};
@@ -169,11 +169,13 @@ impl JsMap {
     ///     
     /// ```
     #[inline]
-    pub fn from_object(object: JsObject, context: &mut Context) -> JsResult<Self> {
+    pub fn from_object(object: JsObject, _context: &mut Context) -> JsResult<Self> {
         if object.borrow().is_map() {
             Ok(Self { inner: object })
         } else {
-            context.throw_type_error("object is not a Map")
+            Err(JsNativeError::typ()
+                .with_message("object is not a Map")
+                .into())
         }
     }
 
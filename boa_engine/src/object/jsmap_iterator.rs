@@ -2,7 +2,7 @@
 use crate::{
     builtins::map::map_iterator::MapIterator,
     object::{JsObject, JsObjectType},
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue,
 };
 
 use boa_gc::{Finalize, Trace};
@@ -17,11 +17,13 @@ pub struct JsMapIterator {
 impl JsMapIterator {
     /// Create a [`JsMapIterator`] from a [`JsObject`]. If object is not a `MapIterator`, throw `TypeError`
     #[inline]
-    pub fn from_object(object: JsObject, context: &mut Context) -> JsResult<Self> {
+    pub fn from_object(object: JsObject, _context: &mut Context) -> JsResult<Self> {
         if object.borrow().is_map_iterator() {
             Ok(Self { inner: object })
         } else {
-            context.throw_type_error("object is not a MapIterator")
+            Err(JsNativeError::typ()
+                .with_message("object is not a MapIterator")
+                .into())
         }
     }
 
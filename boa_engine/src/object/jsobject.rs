@@ -7,7 +7,7 @@ use crate::{
     object::{ObjectData, ObjectKind},
     property::{PropertyDescriptor, PropertyKey},
     value::PreferredType,
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue,
 };
 use boa_gc::{self, Finalize, Gc, Trace};
 use rustc_hash::FxHashMap;
@@ -27,6 +27,7 @@ pub type RefMut<'a, T, U> = boa_gc::RefMut<'a, T, U>;
 
 /// Garbage collected `Object`.
 #[derive(Trace, Finalize, Clone, Default)]
+#[repr(transparent)]
 pub struct JsObject {
     inner: Gc<boa_gc::Cell<Object>>,
 }
@@ -40,7 +41,7 @@ impl JsObject {
         }
     }
 
-    /// Create a new empty `JsObject`, with `prototype` set to `JsValue::Null`
+    /// Create a new empty `JsObject`, with `prototype` set to `JsValue::null()`
     /// and `data` set to `ObjectData::ordinary`
     pub fn empty() -> Self {
         Self::from_object(Object::default())
@@ -110,6 +111,14 @@ impl JsObject {
         self.inner.try_borrow_mut().map_err(|_| BorrowMutError)
     }
 
+    /// Returns the garbage collected pointer to the inner `Object`.
+    ///
+    /// This is used to create weak references to the object.
+    #[inline]
+    pub(crate) fn inner(&self) -> &Gc<boa_gc::Cell<Object>> {
+        &self.inner
+    }
+
     /// Checks if the garbage collected memory is the same.
     #[inline]
     pub fn equals(lhs: &Self, rhs: &Self) -> bool {
@@ -186,7 +195,9 @@ impl JsObject {
         }
 
         // 6. Throw a TypeError exception.
-        context.throw_type_error("cannot convert object to primitive value")
+        Err(JsNativeError::typ()
+            .with_message("cannot convert object to primitive value")
+            .into())
     }
 
     /// Return `true` if it is a native object and the native type is `T`.
@@ -546,7 +557,9 @@ impl JsObject {
             // b. If IsCallable(getter) is false and getter is not undefined, throw a TypeError exception.
             // todo: extract IsCallable to be callable from Value
             if !getter.is_undefined() && getter.as_object().map_or(true, |o| !o.is_callable()) {
-                return context.throw_type_error("Property descriptor getter must be callable");
+                return Err(JsNativeError::typ()
+                    .with_message("Property descriptor getter must be callable")
+                    .into());
             }
             // c. Set desc.[[Get]] to getter.
             Some(getter)
@@ -562,7 +575,9 @@ impl JsObject {
             // 14.b. If IsCallable(setter) is false and setter is not undefined, throw a TypeError exception.
             // todo: extract IsCallable to be callable from Value
             if !setter.is_undefined() && setter.as_object().map_or(true, |o| !o.is_callable()) {
-                return context.throw_type_error("Property descriptor setter must be callable");
+                return Err(JsNativeError::typ()
+                    .with_message("Property descriptor setter must be callable")
+                    .into());
             }
             // 14.c. Set desc.[[Set]] to setter.
             Some(setter)
@@ -573,10 +588,12 @@ impl JsObject {
         // 15. If desc.[[Get]] is present or desc.[[Set]] is present, then ...
         // a. If desc.[[Value]] is present or desc.[[Writable]] is present, throw a TypeError exception.
         if get.as_ref().or(set.as_ref()).is_some() && desc.inner().is_data_descriptor() {
-            return context.throw_type_error(
-                "Invalid property descriptor.\
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "Invalid property descriptor.\
 Cannot both specify accessors and a value or writable attribute",
-            );
+                )
+                .into());
         }
 
         desc = desc.maybe_get(get).maybe_set(set);
@@ -723,6 +740,13 @@ impl AsRef<boa_gc::Cell<Object>> for JsObject {
     }
 }
 
+impl From<Gc<boa_gc::Cell<Object>>> for JsObject {
+    #[inline]
+    fn from(inner: Gc<boa_gc::Cell<Object>>) -> Self {
+        Self { inner }
+    }
+}
+
 impl PartialEq for JsObject {
     fn eq(&self, other: &Self) -> bool {
         Self::equals(self, other)
@@ -0,0 +1,406 @@
+//! This module implements a wrapper for the `Promise` Builtin JavaScript Object.
+use crate::{
+    builtins::{
+        promise::{PromiseState, ResolvingFunctions},
+        JsArgs, Promise,
+    },
+    object::{FunctionBuilder, JsFunction, JsObject, JsObjectType, ObjectData},
+    Context, JsError, JsNativeError, JsResult, JsValue, JsVariant,
+};
+
+use boa_gc::{Cell, Finalize, Gc, Trace};
+use std::{
+    future::Future,
+    ops::Deref,
+    pin::Pin,
+    task::{self, Poll, Waker},
+};
+
+/// `JsPromise` provides a wrapper for Boa's implementation of the JavaScript `Promise` object.
+///
+/// # Examples
+///
+/// Create a pending `JsPromise` and resolve it from Rust
+/// ```
+/// # use boa_engine::{
+/// #     builtins::promise::PromiseState,
+/// #     object::JsPromise,
+/// #     Context, JsValue,
+/// # };
+///
+/// // Create default `Context`
+/// let context = &mut Context::default();
+///
+/// // Create a new pending `JsPromise` and its resolving functions.
+/// let (promise, resolvers) = JsPromise::new_pending(context);
+/// assert_eq!(promise.state(), PromiseState::Pending);
+///
+/// // Resolve the promise with a value.
+/// resolvers
+///     .resolve
+///     .call(&JsValue::undefined(), &[10.into()], context)
+///     .unwrap();
+///
+/// assert_eq!(promise.state(), PromiseState::Fulfilled(10.into()));
+/// ```
+///
+/// Chain reactions to a `JsPromise`
+/// ```
+/// # use boa_engine::{
+/// #     builtins::{promise::PromiseState, JsArgs},
+/// #     object::{FunctionBuilder, JsPromise},
+/// #     Context, JsValue,
+/// # };
+///
+/// // Create default `Context`
+/// let context = &mut Context::default();
+///
+/// let double = FunctionBuilder::closure(context, |_, args, _| {
+///     let value = args.get_or_undefined(0).clone();
+///     Ok((value.as_number().unwrap_or_default() * 2.0).into())
+/// })
+/// .build();
+///
+/// let promise = JsPromise::resolve(21, context)
+///     .unwrap()
+///     .then(Some(double), None, context)
+///     .unwrap();
+///
+/// // Reactions are only run by the job queue of the context.
+/// assert_eq!(promise.state(), PromiseState::Pending);
+/// context.run_jobs().unwrap();
+///
+/// assert_eq!(promise.state(), PromiseState::Fulfilled(42.into()));
+/// ```
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsPromise {
+    inner: JsObject,
+}
+
+impl JsPromise {
+    /// Creates a new [`JsPromise`] and calls `executor` with its resolving functions.
+    ///
+    /// This is equivalent to `new Promise(executor)` in JavaScript; if `executor` returns an
+    /// error, the promise is rejected with it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #     builtins::promise::PromiseState,
+    /// #     object::JsPromise,
+    /// #     Context, JsNativeError, JsValue,
+    /// # };
+    ///
+    /// // Create a new context.
+    /// let context = &mut Context::default();
+    ///
+    /// let promise = JsPromise::new(
+    ///     |_resolvers, _context| Err(JsNativeError::typ().with_message("failed").into()),
+    ///     context,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert!(matches!(promise.state(), PromiseState::Rejected(_)));
+    /// ```
+    #[inline]
+    pub fn new<F>(executor: F, context: &mut Context) -> JsResult<Self>
+    where
+        F: FnOnce(&ResolvingFunctions, &mut Context) -> JsResult<JsValue>,
+    {
+        let (promise, resolvers) = Self::new_pending(context);
+
+        if let Err(e) = executor(&resolvers, context) {
+            let e = e.to_opaque(context);
+            resolvers
+                .reject
+                .call(&JsValue::undefined(), &[e], context)?;
+        }
+
+        Ok(promise)
+    }
+
+    /// Creates a new pending [`JsPromise`], returning it together with its resolving functions.
+    ///
+    /// The promise stays pending until one of the resolving functions is called.
+    #[inline]
+    pub fn new_pending(context: &mut Context) -> (Self, ResolvingFunctions) {
+        let promise = JsObject::from_proto_and_data(
+            context.intrinsics().constructors().promise().prototype(),
+            ObjectData::promise(Promise::new()),
+        );
+        let resolvers = Promise::create_resolving_functions(&promise, context);
+
+        (Self { inner: promise }, resolvers)
+    }
+
+    /// Creates a [`JsPromise`] from a [`JsObject`], if the object is not a promise throw a `TypeError`.
+    ///
+    /// This does not clone the fields of the promise, it only does a shallow clone of the object.
+    #[inline]
+    pub fn from_object(object: JsObject, _context: &mut Context) -> JsResult<Self> {
+        if object.borrow().is_promise() {
+            Ok(Self { inner: object })
+        } else {
+            Err(JsNativeError::typ()
+                .with_message("object is not a Promise")
+                .into())
+        }
+    }
+
+    /// Creates a [`JsPromise`] resolved with `value`.
+    ///
+    /// This is equivalent to `Promise.resolve(value)` in JavaScript, so resolving with another
+    /// promise or a thenable adopts its state.
+    #[inline]
+    pub fn resolve<V: Into<JsValue>>(value: V, context: &mut Context) -> JsResult<Self> {
+        let constructor = context.intrinsics().constructors().promise().constructor();
+        let promise = Promise::promise_resolve(constructor, value.into(), context)?;
+
+        Ok(Self {
+            inner: promise
+                .as_object()
+                .expect("PromiseResolve with %Promise% must return a promise")
+                .clone(),
+        })
+    }
+
+    /// Creates a [`JsPromise`] rejected with `error`.
+    ///
+    /// This is equivalent to `Promise.reject(error)` in JavaScript.
+    #[inline]
+    pub fn reject<E: Into<JsError>>(error: E, context: &mut Context) -> Self {
+        let (promise, resolvers) = Self::new_pending(context);
+        let error = error.into().to_opaque(context);
+
+        resolvers
+            .reject
+            .call(&JsValue::undefined(), &[error], context)
+            .expect("calling the reject function of a promise must not fail");
+
+        promise
+    }
+
+    /// Gets the current state of the promise.
+    #[inline]
+    pub fn state(&self) -> PromiseState {
+        self.inner
+            .borrow()
+            .as_promise()
+            .expect("`JsPromise` must always contain a promise")
+            .state()
+            .clone()
+    }
+
+    /// Schedules callbacks to run when the promise is settled, returning the derived promise.
+    ///
+    /// This is equivalent to `promise.then(onFulfilled, onRejected)` in JavaScript.
+    #[inline]
+    pub fn then(
+        &self,
+        on_fulfilled: Option<JsFunction>,
+        on_rejected: Option<JsFunction>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let result = Promise::then(
+            &self.inner.clone().into(),
+            &[
+                on_fulfilled.map_or_else(JsValue::undefined, JsValue::from),
+                on_rejected.map_or_else(JsValue::undefined, JsValue::from),
+            ],
+            context,
+        )?;
+
+        Self::from_result(&result, context)
+    }
+
+    /// Schedules a callback to run when the promise is rejected, returning the derived promise.
+    ///
+    /// This is equivalent to `promise.catch(onRejected)` in JavaScript.
+    #[inline]
+    pub fn catch(&self, on_rejected: JsFunction, context: &mut Context) -> JsResult<Self> {
+        let result = Promise::catch(&self.inner.clone().into(), &[on_rejected.into()], context)?;
+
+        Self::from_result(&result, context)
+    }
+
+    /// Schedules a callback to run when the promise is settled, returning the derived promise.
+    ///
+    /// This is equivalent to `promise.finally(onFinally)` in JavaScript.
+    #[inline]
+    pub fn finally(&self, on_finally: JsFunction, context: &mut Context) -> JsResult<Self> {
+        let result = Promise::finally(&self.inner.clone().into(), &[on_finally.into()], context)?;
+
+        Self::from_result(&result, context)
+    }
+
+    /// Converts the promise into a [`JsFuture`] that resolves once the promise is settled.
+    ///
+    /// The future does not drive the context: it only makes progress when the jobs of the
+    /// context are run, with [`Context::run_jobs`] or [`Context::run_jobs_async`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{object::JsPromise, Context, JsValue};
+    /// # use std::{future::Future, pin::Pin, sync::Arc, task::{self, Poll, Wake, Waker}};
+    /// # struct NoopWaker;
+    /// # impl Wake for NoopWaker {
+    /// #     fn wake(self: Arc<Self>) {}
+    /// # }
+    /// // Create a new context.
+    /// let context = &mut Context::default();
+    ///
+    /// let (promise, resolvers) = JsPromise::new_pending(context);
+    /// let mut future = promise.into_js_future(context);
+    ///
+    /// resolvers
+    ///     .resolve
+    ///     .call(&JsValue::undefined(), &["done".into()], context)
+    ///     .unwrap();
+    /// context.run_jobs().unwrap();
+    ///
+    /// let waker = Waker::from(Arc::new(NoopWaker));
+    /// let result = Pin::new(&mut future).poll(&mut task::Context::from_waker(&waker));
+    /// assert!(matches!(result, Poll::Ready(Ok(value)) if value == "done".into()));
+    /// ```
+    pub fn into_js_future(self, context: &mut Context) -> JsFuture {
+        // The state is garbage collected, since the reactions can outlive the future and
+        // the result of the promise is a `JsValue`.
+        let state = Gc::new(Cell::new(FutureState::default()));
+
+        match self.state() {
+            PromiseState::Fulfilled(value) => state.borrow_mut().result = Some(Ok(value)),
+            PromiseState::Rejected(reason) => {
+                state.borrow_mut().result = Some(Err(JsError::from_opaque(reason)));
+            }
+            PromiseState::Pending => {
+                let on_fulfilled = FunctionBuilder::closure_with_captures(
+                    context,
+                    |_, args, state, _| {
+                        let value = args.get_or_undefined(0).clone();
+                        FutureState::settle(state, Ok(value));
+                        Ok(JsValue::undefined())
+                    },
+                    state.clone(),
+                )
+                .build();
+                let on_rejected = FunctionBuilder::closure_with_captures(
+                    context,
+                    |_, args, state, _| {
+                        let reason = args.get_or_undefined(0).clone();
+                        FutureState::settle(state, Err(JsError::from_opaque(reason)));
+                        Ok(JsValue::undefined())
+                    },
+                    state.clone(),
+                )
+                .build();
+
+                self.inner
+                    .borrow_mut()
+                    .as_promise_mut()
+                    .expect("`JsPromise` must always contain a promise")
+                    .perform_promise_then(&on_fulfilled.into(), &on_rejected.into(), None, context);
+            }
+        }
+
+        JsFuture { state }
+    }
+
+    /// Runs the jobs of the context and returns the result of the promise.
+    ///
+    /// Returns an error if the promise was rejected, or if it is still pending after running
+    /// all the jobs.
+    pub fn await_blocking(&self, context: &mut Context) -> JsResult<JsValue> {
+        context.run_jobs()?;
+
+        match self.state() {
+            PromiseState::Fulfilled(value) => Ok(value),
+            PromiseState::Rejected(reason) => Err(JsError::from_opaque(reason)),
+            PromiseState::Pending => Err(JsNativeError::error()
+                .with_message("promise is still pending after running all jobs")
+                .into()),
+        }
+    }
+
+    /// Converts the result of a promise builtin into a [`JsPromise`].
+    fn from_result(result: &JsValue, context: &mut Context) -> JsResult<Self> {
+        match result.variant() {
+            JsVariant::Object(object) => Self::from_object(object.clone(), context),
+            _ => Err(JsNativeError::typ()
+                .with_message("value is not a Promise")
+                .into()),
+        }
+    }
+}
+
+impl From<JsPromise> for JsObject {
+    #[inline]
+    fn from(o: JsPromise) -> Self {
+        o.inner.clone()
+    }
+}
+
+impl From<JsPromise> for JsValue {
+    #[inline]
+    fn from(o: JsPromise) -> Self {
+        o.inner.clone().into()
+    }
+}
+
+impl Deref for JsPromise {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl JsObjectType for JsPromise {}
+
+/// A [`Future`] that resolves to the result of a [`JsPromise`].
+///
+/// Created by [`JsPromise::into_js_future`].
+#[derive(Debug)]
+pub struct JsFuture {
+    state: Gc<Cell<FutureState>>,
+}
+
+#[derive(Debug, Default, Trace, Finalize)]
+struct FutureState {
+    result: Option<JsResult<JsValue>>,
+    // A `Waker` is `Send`, so it cannot hold garbage collected values.
+    #[unsafe_ignore_trace]
+    waker: Option<Waker>,
+}
+
+impl FutureState {
+    /// Stores the result of the promise and wakes the task waiting for it.
+    fn settle(state: &Cell<Self>, result: JsResult<JsValue>) {
+        let waker = {
+            let mut state = state.borrow_mut();
+            state.result = Some(result);
+            state.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl Future for JsFuture {
+    type Output = JsResult<JsValue>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.borrow_mut();
+
+        if let Some(result) = state.result.take() {
+            Poll::Ready(result)
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
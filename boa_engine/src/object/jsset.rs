@@ -5,7 +5,7 @@ use boa_gc::{Finalize, Trace};
 use crate::{
     builtins::Set,
     object::{JsFunction, JsObject, JsObjectType, JsSetIterator},
-    Context, JsResult, JsValue,
+    Context, JsError, JsNativeError, JsResult, JsValue,
 };
 
 // This is an wrapper for `JsSet`
@@ -30,8 +30,8 @@ impl JsSet {
     ///
     /// Same as JavaScript's `set.size`.
     #[inline]
-    pub fn size(&self, context: &mut Context) -> JsResult<usize> {
-        Set::get_size(&self.inner.clone().into(), context)
+    pub fn size(&self, _context: &mut Context) -> JsResult<usize> {
+        Set::get_size(&self.inner.clone().into())
     }
 
     /// Appends value to the Set object.
@@ -61,7 +61,7 @@ impl JsSet {
     /// Same as JavaScript's `set.clear()`.
     #[inline]
     pub fn clear(&self, context: &mut Context) -> JsResult<JsValue> {
-        Set::clear(&self.inner.clone().into(), &[JsValue::Null], context)
+        Set::clear(&self.inner.clone().into(), &[JsValue::null()], context)
     }
 
     /// Removes the element associated to the value.
@@ -74,9 +74,9 @@ impl JsSet {
     where
         T: Into<JsValue>,
     {
-        match Set::delete(&self.inner.clone().into(), &[value.into()], context)? {
-            JsValue::Boolean(bool) => Ok(bool),
-            _ => Err(JsValue::Undefined),
+        match Set::delete(&self.inner.clone().into(), &[value.into()], context)?.as_boolean() {
+            Some(bool) => Ok(bool),
+            None => Err(JsError::from_opaque(JsValue::undefined())),
         }
     }
 
@@ -89,9 +89,9 @@ impl JsSet {
     where
         T: Into<JsValue>,
     {
-        match Set::has(&self.inner.clone().into(), &[value.into()], context)? {
-            JsValue::Boolean(bool) => Ok(bool),
-            _ => Err(JsValue::Undefined),
+        match Set::has(&self.inner.clone().into(), &[value.into()], context)?.as_boolean() {
+            Some(bool) => Ok(bool),
+            None => Err(JsError::from_opaque(JsValue::undefined())),
         }
     }
 
@@ -101,7 +101,7 @@ impl JsSet {
     /// Same as JavaScript's `set.values()`.
     #[inline]
     pub fn values(&self, context: &mut Context) -> JsResult<JsSetIterator> {
-        let iterator_object = Set::values(&self.inner.clone().into(), &[JsValue::Null], context)?
+        let iterator_object = Set::values(&self.inner.clone().into(), &[JsValue::null()], context)?
             .get_iterator(context, None, None)?;
 
         JsSetIterator::from_object(iterator_object.iterator().clone(), context)
@@ -114,7 +114,7 @@ impl JsSet {
     /// Same as JavaScript's `set.keys()`.
     #[inline]
     pub fn keys(&self, context: &mut Context) -> JsResult<JsSetIterator> {
-        let iterator_object = Set::values(&self.inner.clone().into(), &[JsValue::Null], context)?
+        let iterator_object = Set::values(&self.inner.clone().into(), &[JsValue::null()], context)?
             .get_iterator(context, None, None)?;
 
         JsSetIterator::from_object(iterator_object.iterator().clone(), context)
@@ -141,11 +141,13 @@ impl JsSet {
 
     /// Utility: Creates `JsSet` from `JsObject`, if not a Set throw `TypeError`.
     #[inline]
-    pub fn from_object(object: JsObject, context: &mut Context) -> JsResult<Self> {
+    pub fn from_object(object: JsObject, _context: &mut Context) -> JsResult<Self> {
         if object.borrow().is_set() {
             Ok(Self { inner: object })
         } else {
-            context.throw_error("Object is not a Set")
+            Err(JsNativeError::error()
+                .with_message("Object is not a Set")
+                .into())
         }
     }
 
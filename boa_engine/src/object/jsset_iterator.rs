@@ -5,7 +5,7 @@ use boa_gc::{Finalize, Trace};
 use crate::{
     builtins::SetIterator,
     object::{JsObject, JsObjectType},
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue,
 };
 
 /// JavaScript `SetIterator` rust object
@@ -17,16 +17,18 @@ pub struct JsSetIterator {
 impl JsSetIterator {
     /// Create a `JsSetIterator` from a `JsObject`.
     /// If object is not a `SetIterator`, throw `TypeError`.
-    pub fn from_object(object: JsObject, context: &mut Context) -> JsResult<Self> {
+    pub fn from_object(object: JsObject, _context: &mut Context) -> JsResult<Self> {
         if object.borrow().is_set_iterator() {
             Ok(Self { inner: object })
         } else {
-            context.throw_type_error("object is not a SetIterator")
+            Err(JsNativeError::typ()
+                .with_message("object is not a SetIterator")
+                .into())
         }
     }
     /// Advances the `JsSetIterator` and gets the next result in the `JsSet`.
     pub fn next(&self, context: &mut Context) -> JsResult<JsValue> {
-        SetIterator::next(&self.inner.clone().into(), &[JsValue::Null], context)
+        SetIterator::next(&self.inner.clone().into(), &[JsValue::null()], context)
     }
 }
 
@@ -0,0 +1,119 @@
+use crate::{
+    builtins::{
+        array_buffer::ArrayBuffer,
+        shared_array_buffer::{SharedArrayBuffer, SharedDataBlock},
+    },
+    object::{JsObject, JsObjectType},
+    Context, JsNativeError, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use std::ops::Deref;
+
+/// JavaScript `SharedArrayBuffer` rust object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsSharedArrayBuffer {
+    inner: JsObject,
+}
+
+impl JsSharedArrayBuffer {
+    /// Create a new shared array buffer with byte length.
+    #[inline]
+    pub fn new(byte_length: usize, context: &mut Context) -> JsResult<Self> {
+        let inner = SharedArrayBuffer::allocate(
+            &context
+                .intrinsics()
+                .constructors()
+                .shared_array_buffer()
+                .constructor()
+                .into(),
+            byte_length as u64,
+            context,
+        )?;
+
+        Ok(Self { inner })
+    }
+
+    /// Create a new shared array buffer from a shared data block.
+    ///
+    /// This uses the passed block as the internal storage, it does not clone the bytes!
+    /// The block can come from another [`Context`], possibly running on another thread, which
+    /// will see the changes made to the buffer.
+    #[inline]
+    pub fn from_data_block(block: SharedDataBlock, context: &mut Context) -> Self {
+        let prototype = context
+            .intrinsics()
+            .constructors()
+            .shared_array_buffer()
+            .prototype();
+
+        Self {
+            inner: SharedArrayBuffer::from_block(block, prototype, context),
+        }
+    }
+
+    /// Create a [`JsSharedArrayBuffer`] from a [`JsObject`], if the object is not a shared array
+    /// buffer throw a `TypeError`.
+    ///
+    /// This does not clone the fields of the shared array buffer, it only does a shallow clone of
+    /// the object.
+    #[inline]
+    pub fn from_object(object: JsObject, _context: &mut Context) -> JsResult<Self> {
+        if object
+            .borrow()
+            .as_array_buffer()
+            .map_or(false, ArrayBuffer::is_shared)
+        {
+            Ok(Self { inner: object })
+        } else {
+            Err(JsNativeError::typ()
+                .with_message("object is not a SharedArrayBuffer")
+                .into())
+        }
+    }
+
+    /// Returns the shared data block of the buffer.
+    ///
+    /// The block can be sent to another thread and passed to
+    /// [`JsSharedArrayBuffer::from_data_block`] to share the buffer with another [`Context`].
+    #[inline]
+    pub fn data_block(&self) -> SharedDataBlock {
+        self.inner
+            .borrow()
+            .as_array_buffer()
+            .and_then(|buffer| buffer.array_buffer_data.as_ref())
+            .and_then(|block| block.as_shared())
+            .expect("a shared array buffer must have a shared data block")
+            .clone()
+    }
+
+    /// Returns the byte length of the shared array buffer.
+    #[inline]
+    pub fn byte_length(&self) -> usize {
+        self.data_block().len()
+    }
+}
+
+impl From<JsSharedArrayBuffer> for JsObject {
+    #[inline]
+    fn from(o: JsSharedArrayBuffer) -> Self {
+        o.inner.clone()
+    }
+}
+
+impl From<JsSharedArrayBuffer> for JsValue {
+    #[inline]
+    fn from(o: JsSharedArrayBuffer) -> Self {
+        o.inner.clone().into()
+    }
+}
+
+impl Deref for JsSharedArrayBuffer {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl JsObjectType for JsSharedArrayBuffer {}
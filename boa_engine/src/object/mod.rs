@@ -11,22 +11,28 @@ use self::internal_methods::{
         BOUND_CONSTRUCTOR_EXOTIC_INTERNAL_METHODS, BOUND_FUNCTION_EXOTIC_INTERNAL_METHODS,
     },
     function::{CONSTRUCTOR_INTERNAL_METHODS, FUNCTION_INTERNAL_METHODS},
-    global::GLOBAL_INTERNAL_METHODS,
     integer_indexed::INTEGER_INDEXED_EXOTIC_INTERNAL_METHODS,
+    module_namespace::MODULE_NAMESPACE_EXOTIC_INTERNAL_METHODS,
     proxy::{
         PROXY_EXOTIC_INTERNAL_METHODS_ALL, PROXY_EXOTIC_INTERNAL_METHODS_BASIC,
         PROXY_EXOTIC_INTERNAL_METHODS_WITH_CALL,
     },
     string::STRING_EXOTIC_INTERNAL_METHODS,
+    wrapped_function::WRAPPED_FUNCTION_EXOTIC_INTERNAL_METHODS,
     InternalObjectMethods, ORDINARY_INTERNAL_METHODS,
 };
 #[cfg(feature = "intl")]
-use crate::builtins::intl::date_time_format::DateTimeFormat;
+use crate::builtins::intl::{date_time_format::DateTimeFormat, number_format::NumberFormat};
+#[cfg(feature = "temporal")]
+use crate::builtins::temporal::{
+    Calendar, Duration, Instant, PlainDate, PlainDateTime, PlainTime, TimeZone, ZonedDateTime,
+};
 use crate::{
     builtins::{
         array::array_iterator::ArrayIterator,
         array_buffer::ArrayBuffer,
         async_generator::AsyncGenerator,
+        error::ErrorKind,
         function::arguments::Arguments,
         function::{
             arguments::ParameterMap, BoundFunction, Captures, ConstructorKind, Function,
@@ -41,22 +47,27 @@ use crate::{
         regexp::regexp_string_iterator::RegExpStringIterator,
         set::ordered_set::OrderedSet,
         set::set_iterator::SetIterator,
+        shadow_realm::WrappedFunction,
         string::string_iterator::StringIterator,
         typed_array::integer_indexed_object::IntegerIndexed,
+        weak::FinalizationRegistry,
         DataView, Date, Promise, RegExp,
     },
     context::intrinsics::StandardConstructor,
+    module::ModuleNamespace,
     property::{Attribute, PropertyDescriptor, PropertyKey},
-    Context, JsBigInt, JsResult, JsString, JsSymbol, JsValue,
+    realm::Realm,
+    Context, JsBigInt, JsNativeError, JsResult, JsString, JsSymbol, JsValue,
 };
 
-use boa_gc::{custom_trace, Finalize, Trace};
+use boa_gc::{custom_trace, Cell as GcCell, Finalize, Trace, WeakGc, WeakMap};
 use boa_interner::Sym;
 use rustc_hash::FxHashMap;
 use std::{
     any::Any,
     fmt::{self, Debug, Display},
     ops::{Deref, DerefMut},
+    rc::Rc,
 };
 
 #[cfg(test)]
@@ -70,12 +81,15 @@ mod jsfunction;
 mod jsmap;
 mod jsmap_iterator;
 mod jsobject;
+mod jspromise;
 mod jsproxy;
 mod jsset;
 mod jsset_iterator;
+mod jssharedarraybuffer;
 mod jstypedarray;
 mod operations;
 mod property_map;
+pub(crate) mod shape;
 
 pub use jsarray::*;
 pub use jsarraybuffer::*;
@@ -83,9 +97,11 @@ pub use jsdataview::*;
 pub use jsfunction::*;
 pub use jsmap::*;
 pub use jsmap_iterator::*;
+pub use jspromise::*;
 pub use jsproxy::*;
 pub use jsset::*;
 pub use jsset_iterator::*;
+pub use jssharedarraybuffer::*;
 pub use jstypedarray::*;
 
 pub(crate) trait JsObjectType:
@@ -176,6 +192,7 @@ pub enum ObjectKind {
     ArrayBuffer(ArrayBuffer),
     Map(OrderedMap<JsValue>),
     MapIterator(MapIterator),
+    ModuleNamespace(ModuleNamespace),
     RegExp(Box<RegExp>),
     RegExpStringIterator(RegExpStringIterator),
     BigInt(JsBigInt),
@@ -184,6 +201,7 @@ pub enum ObjectKind {
     ForInIterator(ForInIterator),
     Function(Function),
     BoundFunction(BoundFunction),
+    WrappedFunction(WrappedFunction),
     Generator(Generator),
     GeneratorFunction(Function),
     Set(OrderedSet<JsValue>),
@@ -192,7 +210,7 @@ pub enum ObjectKind {
     StringIterator(StringIterator),
     Number(f64),
     Symbol(JsSymbol),
-    Error,
+    Error(ErrorKind),
     Ordinary,
     Proxy(Proxy),
     Date(Date),
@@ -202,7 +220,30 @@ pub enum ObjectKind {
     IntegerIndexed(IntegerIndexed),
     #[cfg(feature = "intl")]
     DateTimeFormat(Box<DateTimeFormat>),
+    #[cfg(feature = "intl")]
+    NumberFormat(Box<NumberFormat>),
     Promise(Promise),
+    WeakRef(WeakGc<GcCell<Object>>),
+    WeakMap(WeakMap<GcCell<Object>, JsValue>),
+    WeakSet(WeakMap<GcCell<Object>, ()>),
+    FinalizationRegistry(FinalizationRegistry),
+    ShadowRealm(Realm),
+    #[cfg(feature = "temporal")]
+    Instant(Instant),
+    #[cfg(feature = "temporal")]
+    PlainDate(PlainDate),
+    #[cfg(feature = "temporal")]
+    PlainTime(PlainTime),
+    #[cfg(feature = "temporal")]
+    PlainDateTime(PlainDateTime),
+    #[cfg(feature = "temporal")]
+    ZonedDateTime(ZonedDateTime),
+    #[cfg(feature = "temporal")]
+    Duration(Duration),
+    #[cfg(feature = "temporal")]
+    TimeZone(TimeZone),
+    #[cfg(feature = "temporal")]
+    Calendar(Calendar),
 }
 
 unsafe impl Trace for ObjectKind {
@@ -213,11 +254,13 @@ unsafe impl Trace for ObjectKind {
             Self::ArrayBuffer(b) => mark(b),
             Self::Map(m) => mark(m),
             Self::MapIterator(i) => mark(i),
+            Self::ModuleNamespace(n) => mark(n),
             Self::RegExpStringIterator(i) => mark(i),
             Self::DataView(v) => mark(v),
             Self::ForInIterator(i) => mark(i),
             Self::Function(f) | Self::GeneratorFunction(f) | Self::AsyncGeneratorFunction(f) => mark(f),
             Self::BoundFunction(f) => mark(f),
+            Self::WrappedFunction(f) => mark(f),
             Self::Generator(g) => mark(g),
             Self::Set(s) => mark(s),
             Self::SetIterator(i) => mark(i),
@@ -228,7 +271,30 @@ unsafe impl Trace for ObjectKind {
             Self::IntegerIndexed(i) => mark(i),
             #[cfg(feature = "intl")]
             Self::DateTimeFormat(f) => mark(f),
+            #[cfg(feature = "intl")]
+            Self::NumberFormat(f) => mark(f),
             Self::Promise(p) => mark(p),
+            Self::WeakRef(w) => mark(w),
+            Self::WeakMap(m) => mark(m),
+            Self::WeakSet(s) => mark(s),
+            Self::FinalizationRegistry(r) => mark(r),
+            Self::ShadowRealm(r) => mark(r),
+            #[cfg(feature = "temporal")]
+            Self::Instant(x) => mark(x),
+            #[cfg(feature = "temporal")]
+            Self::PlainDate(x) => mark(x),
+            #[cfg(feature = "temporal")]
+            Self::PlainTime(x) => mark(x),
+            #[cfg(feature = "temporal")]
+            Self::PlainDateTime(x) => mark(x),
+            #[cfg(feature = "temporal")]
+            Self::ZonedDateTime(x) => mark(x),
+            #[cfg(feature = "temporal")]
+            Self::Duration(x) => mark(x),
+            #[cfg(feature = "temporal")]
+            Self::TimeZone(x) => mark(x),
+            #[cfg(feature = "temporal")]
+            Self::Calendar(x) => mark(x),
             Self::AsyncGenerator(g) => mark(g),
             Self::RegExp(_)
             | Self::BigInt(_)
@@ -236,7 +302,7 @@ unsafe impl Trace for ObjectKind {
             | Self::String(_)
             | Self::Date(_)
             | Self::Array
-            | Self::Error
+            | Self::Error(_)
             | Self::Ordinary
             | Self::Global
             | Self::Number(_)
@@ -362,6 +428,46 @@ impl ObjectData {
         }
     }
 
+    /// Create the `WeakRef` object data
+    pub fn weak_ref(weak_ref: WeakGc<GcCell<Object>>) -> Self {
+        Self {
+            kind: ObjectKind::WeakRef(weak_ref),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `WeakMap` object data
+    pub fn weak_map(weak_map: WeakMap<GcCell<Object>, JsValue>) -> Self {
+        Self {
+            kind: ObjectKind::WeakMap(weak_map),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `WeakSet` object data
+    pub fn weak_set(weak_set: WeakMap<GcCell<Object>, ()>) -> Self {
+        Self {
+            kind: ObjectKind::WeakSet(weak_set),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `FinalizationRegistry` object data
+    pub fn finalization_registry(registry: FinalizationRegistry) -> Self {
+        Self {
+            kind: ObjectKind::FinalizationRegistry(registry),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `ShadowRealm` object data
+    pub fn shadow_realm(realm: Realm) -> Self {
+        Self {
+            kind: ObjectKind::ShadowRealm(realm),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `ForInIterator` object data
     pub fn for_in_iterator(for_in_iterator: ForInIterator) -> Self {
         Self {
@@ -394,6 +500,14 @@ impl ObjectData {
         }
     }
 
+    /// Create the `WrappedFunction` object data
+    pub fn wrapped_function(wrapped_function: WrappedFunction) -> Self {
+        Self {
+            kind: ObjectKind::WrappedFunction(wrapped_function),
+            internal_methods: &WRAPPED_FUNCTION_EXOTIC_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `Generator` object data
     pub fn generator(generator: Generator) -> Self {
         Self {
@@ -446,6 +560,14 @@ impl ObjectData {
         }
     }
 
+    /// Create the `ModuleNamespace` object data and reference its exclusive internal methods
+    pub fn module_namespace(namespace: ModuleNamespace) -> Self {
+        Self {
+            kind: ObjectKind::ModuleNamespace(namespace),
+            internal_methods: &MODULE_NAMESPACE_EXOTIC_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `Number` object data
     pub fn number(number: f64) -> Self {
         Self {
@@ -463,9 +585,9 @@ impl ObjectData {
     }
 
     /// Create the `Error` object data
-    pub fn error() -> Self {
+    pub fn error(error: ErrorKind) -> Self {
         Self {
-            kind: ObjectKind::Error,
+            kind: ObjectKind::Error(error),
             internal_methods: &ORDINARY_INTERNAL_METHODS,
         }
     }
@@ -504,7 +626,7 @@ impl ObjectData {
     pub fn global() -> Self {
         Self {
             kind: ObjectKind::Global,
-            internal_methods: &GLOBAL_INTERNAL_METHODS,
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
         }
     }
 
@@ -544,6 +666,87 @@ impl ObjectData {
             internal_methods: &ORDINARY_INTERNAL_METHODS,
         }
     }
+
+    /// Create the `NumberFormat` object data
+    #[cfg(feature = "intl")]
+    pub fn number_format(number_format: Box<NumberFormat>) -> Self {
+        Self {
+            kind: ObjectKind::NumberFormat(number_format),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Temporal.Instant` object data
+    #[cfg(feature = "temporal")]
+    pub fn instant(instant: Instant) -> Self {
+        Self {
+            kind: ObjectKind::Instant(instant),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Temporal.PlainDate` object data
+    #[cfg(feature = "temporal")]
+    pub fn plain_date(plain_date: PlainDate) -> Self {
+        Self {
+            kind: ObjectKind::PlainDate(plain_date),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Temporal.PlainTime` object data
+    #[cfg(feature = "temporal")]
+    pub fn plain_time(plain_time: PlainTime) -> Self {
+        Self {
+            kind: ObjectKind::PlainTime(plain_time),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Temporal.PlainDateTime` object data
+    #[cfg(feature = "temporal")]
+    pub fn plain_date_time(plain_date_time: PlainDateTime) -> Self {
+        Self {
+            kind: ObjectKind::PlainDateTime(plain_date_time),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Temporal.ZonedDateTime` object data
+    #[cfg(feature = "temporal")]
+    pub fn zoned_date_time(zoned_date_time: ZonedDateTime) -> Self {
+        Self {
+            kind: ObjectKind::ZonedDateTime(zoned_date_time),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Temporal.Duration` object data
+    #[cfg(feature = "temporal")]
+    pub fn duration(duration: Duration) -> Self {
+        Self {
+            kind: ObjectKind::Duration(duration),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Temporal.TimeZone` object data
+    #[cfg(feature = "temporal")]
+    pub fn time_zone(time_zone: TimeZone) -> Self {
+        Self {
+            kind: ObjectKind::TimeZone(time_zone),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Temporal.Calendar` object data
+    #[cfg(feature = "temporal")]
+    pub fn calendar(calendar: Calendar) -> Self {
+        Self {
+            kind: ObjectKind::Calendar(calendar),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
 }
 
 impl Display for ObjectKind {
@@ -558,18 +761,20 @@ impl Display for ObjectKind {
             Self::ForInIterator(_) => "ForInIterator",
             Self::Function(_) => "Function",
             Self::BoundFunction(_) => "BoundFunction",
+            Self::WrappedFunction(_) => "WrappedFunction",
             Self::Generator(_) => "Generator",
             Self::GeneratorFunction(_) => "GeneratorFunction",
             Self::RegExp(_) => "RegExp",
             Self::RegExpStringIterator(_) => "RegExpStringIterator",
             Self::Map(_) => "Map",
             Self::MapIterator(_) => "MapIterator",
+            Self::ModuleNamespace(_) => "ModuleNamespace",
             Self::Set(_) => "Set",
             Self::SetIterator(_) => "SetIterator",
             Self::String(_) => "String",
             Self::StringIterator(_) => "StringIterator",
             Self::Symbol(_) => "Symbol",
-            Self::Error => "Error",
+            Self::Error(_) => "Error",
             Self::Ordinary => "Ordinary",
             Self::Proxy(_) => "Proxy",
             Self::Boolean(_) => "Boolean",
@@ -583,7 +788,30 @@ impl Display for ObjectKind {
             Self::DataView(_) => "DataView",
             #[cfg(feature = "intl")]
             Self::DateTimeFormat(_) => "DateTimeFormat",
+            #[cfg(feature = "intl")]
+            Self::NumberFormat(_) => "NumberFormat",
             Self::Promise(_) => "Promise",
+            Self::WeakRef(_) => "WeakRef",
+            Self::WeakMap(_) => "WeakMap",
+            Self::WeakSet(_) => "WeakSet",
+            Self::FinalizationRegistry(_) => "FinalizationRegistry",
+            Self::ShadowRealm(_) => "ShadowRealm",
+            #[cfg(feature = "temporal")]
+            Self::Instant(_) => "Temporal.Instant",
+            #[cfg(feature = "temporal")]
+            Self::PlainDate(_) => "Temporal.PlainDate",
+            #[cfg(feature = "temporal")]
+            Self::PlainTime(_) => "Temporal.PlainTime",
+            #[cfg(feature = "temporal")]
+            Self::PlainDateTime(_) => "Temporal.PlainDateTime",
+            #[cfg(feature = "temporal")]
+            Self::ZonedDateTime(_) => "Temporal.ZonedDateTime",
+            #[cfg(feature = "temporal")]
+            Self::Duration(_) => "Temporal.Duration",
+            #[cfg(feature = "temporal")]
+            Self::TimeZone(_) => "Temporal.TimeZone",
+            #[cfg(feature = "temporal")]
+            Self::Calendar(_) => "Temporal.Calendar",
         })
     }
 }
@@ -885,6 +1113,30 @@ impl Object {
         }
     }
 
+    /// Checks if it is a module namespace object.
+    #[inline]
+    pub fn is_module_namespace(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::ModuleNamespace(_),
+                ..
+            }
+        )
+    }
+
+    /// Gets the module namespace data if the object is a module namespace object.
+    #[inline]
+    pub fn as_module_namespace(&self) -> Option<&ModuleNamespace> {
+        match &self.data {
+            ObjectData {
+                kind: ObjectKind::ModuleNamespace(namespace),
+                ..
+            } => Some(namespace),
+            _ => None,
+        }
+    }
+
     #[inline]
     pub fn is_set(&self) -> bool {
         matches!(
@@ -1077,12 +1329,24 @@ impl Object {
         matches!(
             self.data,
             ObjectData {
-                kind: ObjectKind::Error,
+                kind: ObjectKind::Error(_),
                 ..
             }
         )
     }
 
+    /// Gets the error kind of the object, if it is an Error object.
+    #[inline]
+    pub fn as_error(&self) -> Option<ErrorKind> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Error(error),
+                ..
+            } => Some(error),
+            _ => None,
+        }
+    }
+
     /// Checks if it a Boolean object.
     #[inline]
     pub fn is_boolean(&self) -> bool {
@@ -1338,6 +1602,35 @@ impl Object {
         }
     }
 
+    /// Returns `true` if the string keyed properties of the object are read with the ordinary
+    /// internal methods, so reads of them can be cached by the shape of the object.
+    #[inline]
+    pub(crate) fn has_ordinary_named_get(&self) -> bool {
+        self.has_ordinary_named_set()
+            || std::ptr::eq(self.data.internal_methods, &ARRAY_EXOTIC_INTERNAL_METHODS)
+    }
+
+    /// Returns `true` if the string keyed properties of the object are read and written with
+    /// the ordinary internal methods, so writes to them can be cached by the shape of the object.
+    #[inline]
+    pub(crate) fn has_ordinary_named_set(&self) -> bool {
+        [
+            &ORDINARY_INTERNAL_METHODS,
+            &FUNCTION_INTERNAL_METHODS,
+            &CONSTRUCTOR_INTERNAL_METHODS,
+            &BOUND_FUNCTION_EXOTIC_INTERNAL_METHODS,
+            &BOUND_CONSTRUCTOR_EXOTIC_INTERNAL_METHODS,
+        ]
+        .into_iter()
+        .any(|methods| std::ptr::eq(self.data.internal_methods, methods))
+    }
+
+    /// Returns `true` if new properties can be added to the object.
+    #[inline]
+    pub(crate) fn extensible(&self) -> bool {
+        self.extensible
+    }
+
     /// Gets the prototype instance of this object.
     #[inline]
     pub fn prototype(&self) -> &JsPrototype {
@@ -1421,6 +1714,400 @@ impl Object {
         }
     }
 
+    /// Gets the `WeakRef` data if the object is a `WeakRef`.
+    #[inline]
+    pub fn as_weak_ref(&self) -> Option<&WeakGc<GcCell<Object>>> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::WeakRef(ref weak_ref),
+                ..
+            } => Some(weak_ref),
+            _ => None,
+        }
+    }
+
+    /// Gets the `WeakMap` data if the object is a `WeakMap`.
+    #[inline]
+    pub fn as_weak_map(&self) -> Option<&WeakMap<GcCell<Object>, JsValue>> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::WeakMap(ref weak_map),
+                ..
+            } => Some(weak_map),
+            _ => None,
+        }
+    }
+
+    /// Gets the mutable `WeakMap` data if the object is a `WeakMap`.
+    #[inline]
+    pub fn as_weak_map_mut(&mut self) -> Option<&mut WeakMap<GcCell<Object>, JsValue>> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::WeakMap(ref mut weak_map),
+                ..
+            } => Some(weak_map),
+            _ => None,
+        }
+    }
+
+    /// Gets the `WeakSet` data if the object is a `WeakSet`.
+    #[inline]
+    pub fn as_weak_set(&self) -> Option<&WeakMap<GcCell<Object>, ()>> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::WeakSet(ref weak_set),
+                ..
+            } => Some(weak_set),
+            _ => None,
+        }
+    }
+
+    /// Gets the mutable `WeakSet` data if the object is a `WeakSet`.
+    #[inline]
+    pub fn as_weak_set_mut(&mut self) -> Option<&mut WeakMap<GcCell<Object>, ()>> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::WeakSet(ref mut weak_set),
+                ..
+            } => Some(weak_set),
+            _ => None,
+        }
+    }
+
+    /// Gets the `FinalizationRegistry` data if the object is a `FinalizationRegistry`.
+    #[inline]
+    pub fn as_finalization_registry(&self) -> Option<&FinalizationRegistry> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::FinalizationRegistry(ref finalization_registry),
+                ..
+            } => Some(finalization_registry),
+            _ => None,
+        }
+    }
+
+    /// Gets the mutable `FinalizationRegistry` data if the object is a `FinalizationRegistry`.
+    #[inline]
+    pub fn as_finalization_registry_mut(&mut self) -> Option<&mut FinalizationRegistry> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::FinalizationRegistry(ref mut finalization_registry),
+                ..
+            } => Some(finalization_registry),
+            _ => None,
+        }
+    }
+
+    /// Gets the realm of the `ShadowRealm` if the object is a `ShadowRealm`.
+    #[inline]
+    pub fn as_shadow_realm(&self) -> Option<&Realm> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::ShadowRealm(ref realm),
+                ..
+            } => Some(realm),
+            _ => None,
+        }
+    }
+
+    /// Checks if it an `Intl.DateTimeFormat` object.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub fn is_date_time_format(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::DateTimeFormat(_),
+                ..
+            }
+        )
+    }
+
+    /// Gets the `Intl.DateTimeFormat` data if the object is an `Intl.DateTimeFormat`.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub fn as_date_time_format(&self) -> Option<&DateTimeFormat> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::DateTimeFormat(ref date_time_format),
+                ..
+            } => Some(date_time_format),
+            _ => None,
+        }
+    }
+
+    /// Gets the mutable `Intl.DateTimeFormat` data if the object is an `Intl.DateTimeFormat`.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub fn as_date_time_format_mut(&mut self) -> Option<&mut DateTimeFormat> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::DateTimeFormat(ref mut date_time_format),
+                ..
+            } => Some(date_time_format),
+            _ => None,
+        }
+    }
+
+    /// Checks if it an `Intl.NumberFormat` object.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub fn is_number_format(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::NumberFormat(_),
+                ..
+            }
+        )
+    }
+
+    /// Gets the `Intl.NumberFormat` data if the object is an `Intl.NumberFormat`.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub fn as_number_format(&self) -> Option<&NumberFormat> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::NumberFormat(ref number_format),
+                ..
+            } => Some(number_format),
+            _ => None,
+        }
+    }
+
+    /// Gets the mutable `Intl.NumberFormat` data if the object is an `Intl.NumberFormat`.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub fn as_number_format_mut(&mut self) -> Option<&mut NumberFormat> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::NumberFormat(ref mut number_format),
+                ..
+            } => Some(number_format),
+            _ => None,
+        }
+    }
+
+    /// Checks if it a `Temporal.Instant` object.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub fn is_instant(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::Instant(_),
+                ..
+            }
+        )
+    }
+
+    /// Gets the `Temporal.Instant` data if the object is a `Temporal.Instant`.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub fn as_instant(&self) -> Option<&Instant> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Instant(ref instant),
+                ..
+            } => Some(instant),
+            _ => None,
+        }
+    }
+
+    /// Checks if it a `Temporal.PlainDate` object.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub fn is_plain_date(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::PlainDate(_),
+                ..
+            }
+        )
+    }
+
+    /// Gets the `Temporal.PlainDate` data if the object is a `Temporal.PlainDate`.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub fn as_plain_date(&self) -> Option<&PlainDate> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::PlainDate(ref plain_date),
+                ..
+            } => Some(plain_date),
+            _ => None,
+        }
+    }
+
+    /// Checks if it a `Temporal.PlainTime` object.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub fn is_plain_time(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::PlainTime(_),
+                ..
+            }
+        )
+    }
+
+    /// Gets the `Temporal.PlainTime` data if the object is a `Temporal.PlainTime`.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub fn as_plain_time(&self) -> Option<&PlainTime> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::PlainTime(ref plain_time),
+                ..
+            } => Some(plain_time),
+            _ => None,
+        }
+    }
+
+    /// Checks if it a `Temporal.PlainDateTime` object.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub fn is_plain_date_time(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::PlainDateTime(_),
+                ..
+            }
+        )
+    }
+
+    /// Gets the `Temporal.PlainDateTime` data if the object is a `Temporal.PlainDateTime`.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub fn as_plain_date_time(&self) -> Option<&PlainDateTime> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::PlainDateTime(ref plain_date_time),
+                ..
+            } => Some(plain_date_time),
+            _ => None,
+        }
+    }
+
+    /// Checks if it a `Temporal.ZonedDateTime` object.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub fn is_zoned_date_time(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::ZonedDateTime(_),
+                ..
+            }
+        )
+    }
+
+    /// Gets the `Temporal.ZonedDateTime` data if the object is a `Temporal.ZonedDateTime`.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub fn as_zoned_date_time(&self) -> Option<&ZonedDateTime> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::ZonedDateTime(ref zoned_date_time),
+                ..
+            } => Some(zoned_date_time),
+            _ => None,
+        }
+    }
+
+    /// Checks if it a `Temporal.Duration` object.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub fn is_duration(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::Duration(_),
+                ..
+            }
+        )
+    }
+
+    /// Gets the `Temporal.Duration` data if the object is a `Temporal.Duration`.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub fn as_duration(&self) -> Option<&Duration> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Duration(ref duration),
+                ..
+            } => Some(duration),
+            _ => None,
+        }
+    }
+
+    /// Checks if it a `Temporal.TimeZone` object.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub fn is_time_zone(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::TimeZone(_),
+                ..
+            }
+        )
+    }
+
+    /// Gets the `Temporal.TimeZone` data if the object is a `Temporal.TimeZone`.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub fn as_time_zone(&self) -> Option<&TimeZone> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::TimeZone(ref time_zone),
+                ..
+            } => Some(time_zone),
+            _ => None,
+        }
+    }
+
+    /// Checks if it a `Temporal.Calendar` object.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub fn is_calendar(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::Calendar(_),
+                ..
+            }
+        )
+    }
+
+    /// Gets the `Temporal.Calendar` data if the object is a `Temporal.Calendar`.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub fn as_calendar(&self) -> Option<&Calendar> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Calendar(ref calendar),
+                ..
+            } => Some(calendar),
+            _ => None,
+        }
+    }
+
+    /// Gets the `WrappedFunction` data if the object is a wrapped function.
+    #[inline]
+    pub fn as_wrapped_function(&self) -> Option<&WrappedFunction> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::WrappedFunction(ref wrapped_function),
+                ..
+            } => Some(wrapped_function),
+            _ => None,
+        }
+    }
+
     /// Return `true` if it is a native object and the native type is `T`.
     #[inline]
     pub fn is<T>(&self) -> bool
@@ -1497,6 +2184,18 @@ impl Object {
         self.properties.remove(key)
     }
 
+    /// Sets whether new properties can be added to the object.
+    #[inline]
+    pub(crate) fn set_extensible(&mut self, extensible: bool) {
+        self.extensible = extensible;
+    }
+
+    /// Get the private elements of the object.
+    #[inline]
+    pub(crate) fn private_elements(&self) -> &FxHashMap<Sym, PrivateElement> {
+        &self.private_elements
+    }
+
     /// Get a private element.
     #[inline]
     pub(crate) fn get_private_element(&self, name: Sym) -> Option<&PrivateElement> {
@@ -1621,6 +2320,24 @@ where
     }
 }
 
+/// The state of a closure created with [`FunctionBuilder::closure_with_state`].
+struct ClosureState<T>(T);
+
+impl<T> Debug for ClosureState<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ClosureState")
+    }
+}
+
+impl<T: Trace> Finalize for ClosureState<T> {}
+
+// SAFETY: The state is traced.
+unsafe impl<T: Trace> Trace for ClosureState<T> {
+    custom_trace!(this, {
+        mark(&this.0);
+    });
+}
+
 /// Builder for creating native function objects
 #[derive(Debug)]
 pub struct FunctionBuilder<'context> {
@@ -1635,29 +2352,60 @@ impl<'context> FunctionBuilder<'context> {
     #[inline]
     pub fn native(context: &'context mut Context, function: NativeFunctionSignature) -> Self {
         Self {
-            context,
             function: Function::Native {
                 function,
                 constructor: None,
+                realm: context.realm().clone(),
             },
+            context,
             name: JsString::default(),
             length: 0,
         }
     }
 
     /// Create a new `FunctionBuilder` for creating a closure function.
+    ///
+    /// # Note
+    ///
+    /// This function will only accept `Copy` closures, which cannot own garbage collected
+    /// values. Use [`FunctionBuilder::closure_with_captures`] to pass `JsValue`s to the
+    /// closure, or [`FunctionBuilder::closure_with_state`] for closures that own non-`Copy`
+    /// Rust state.
     #[inline]
     pub fn closure<F>(context: &'context mut Context, function: F) -> Self
     where
         F: Fn(&JsValue, &[JsValue], &mut Context) -> JsResult<JsValue> + Copy + 'static,
+    {
+        // SAFETY: `Copy` closures cannot own garbage collected values.
+        unsafe { Self::closure_unchecked(context, function) }
+    }
+
+    /// Create a new `FunctionBuilder` for creating a closure function that owns arbitrary
+    /// Rust state, without tracing it.
+    ///
+    /// Prefer [`FunctionBuilder::closure_with_state`], which traces the state of the closure
+    /// and only needs it to implement [`Trace`].
+    ///
+    /// # Safety
+    ///
+    /// The values owned by the closure are not traced by the garbage collector, so the closure
+    /// must not own any garbage collected value, like a `JsValue`, a `JsObject` or a `Gc`,
+    /// either directly or through other state like an `Rc<RefCell<Vec<JsValue>>>`. Dropping
+    /// such a value while the function object is being collected is undefined behaviour.
+    /// Pass garbage collected values with [`FunctionBuilder::closure_with_captures`] instead.
+    #[inline]
+    pub unsafe fn closure_unchecked<F>(context: &'context mut Context, function: F) -> Self
+    where
+        F: Fn(&JsValue, &[JsValue], &mut Context) -> JsResult<JsValue> + 'static,
     {
         Self {
-            context,
             function: Function::Closure {
-                function: Box::new(move |this, args, _, context| function(this, args, context)),
+                function: Rc::new(move |this, args, _, context| function(this, args, context)),
                 constructor: None,
                 captures: Captures::new(()),
+                realm: context.realm().clone(),
             },
+            context,
             name: JsString::default(),
             length: 0,
         }
@@ -1665,10 +2413,14 @@ impl<'context> FunctionBuilder<'context> {
 
     /// Create a new closure function with additional captures.
     ///
+    /// The captures are traced by the garbage collector, so they can hold any `JsValue` or
+    /// `JsObject` needed by the closure.
+    ///
     /// # Note
     ///
-    /// You can only move variables that implement `Debug + Any + Trace + Clone`.
-    /// In other words, only `NativeObject + Clone` objects are movable.
+    /// This function will only accept `Copy` closures, and you can only move captures that
+    /// implement `Debug + Any + Trace`. In other words, only `NativeObject` objects are
+    /// movable.
     #[inline]
     pub fn closure_with_captures<F, C>(
         context: &'context mut Context,
@@ -1680,18 +2432,57 @@ impl<'context> FunctionBuilder<'context> {
         C: NativeObject,
     {
         Self {
-            context,
             function: Function::Closure {
-                function: Box::new(move |this, args, captures: Captures, context| {
+                function: Rc::new(move |this, args, captures: Captures, context| {
                     let mut captures = captures.as_mut_any();
                     let captures = captures.downcast_mut::<C>().ok_or_else(|| {
-                        context.construct_type_error("cannot downcast `Captures` to given type")
+                        JsNativeError::typ()
+                            .with_message("cannot downcast `Captures` to given type")
                     })?;
                     function(this, args, captures, context)
                 }),
                 constructor: None,
                 captures: Captures::new(captures),
+                realm: context.realm().clone(),
             },
+            context,
+            name: JsString::default(),
+            length: 0,
+        }
+    }
+
+    /// Create a new closure function that owns Rust state, like an `Rc<RefCell<T>>` or a
+    /// channel sender.
+    ///
+    /// The state is stored in the captures of the function, where it is traced by the garbage
+    /// collector, so it can be any type implementing [`Trace`]. The standard types that can be
+    /// sent to another thread, like `Arc`, `Mutex`, `RefCell` or `Sender`, implement it when
+    /// their contents are `Send`.
+    ///
+    /// # Note
+    ///
+    /// This function will only accept `Copy` closures, which receive a reference to the state.
+    #[inline]
+    pub fn closure_with_state<F, T>(context: &'context mut Context, function: F, state: T) -> Self
+    where
+        F: Fn(&JsValue, &[JsValue], &T, &mut Context) -> JsResult<JsValue> + Copy + 'static,
+        T: Trace + 'static,
+    {
+        Self {
+            function: Function::Closure {
+                function: Rc::new(move |this, args, captures: Captures, context| {
+                    let captures = captures.as_any();
+                    let state = captures.downcast_ref::<ClosureState<T>>().ok_or_else(|| {
+                        JsNativeError::typ()
+                            .with_message("cannot downcast `Captures` to given type")
+                    })?;
+                    function(this, args, &state.0, context)
+                }),
+                constructor: None,
+                captures: Captures::new(ClosureState(state)),
+                realm: context.realm().clone(),
+            },
+            context,
             name: JsString::default(),
             length: 0,
         }
@@ -1704,9 +2495,9 @@ impl<'context> FunctionBuilder<'context> {
     #[must_use]
     pub fn name<N>(mut self, name: N) -> Self
     where
-        N: AsRef<str>,
+        N: Into<JsString>,
     {
-        self.name = name.as_ref().into();
+        self.name = name.into();
         self
     }
 
@@ -2128,9 +2919,9 @@ impl<'context> ConstructorBuilder<'context> {
     #[inline]
     pub fn name<N>(&mut self, name: N) -> &mut Self
     where
-        N: AsRef<str>,
+        N: Into<JsString>,
     {
-        self.name = name.as_ref().into();
+        self.name = name.into();
         self
     }
 
@@ -2192,6 +2983,7 @@ impl<'context> ConstructorBuilder<'context> {
         let function = Function::Native {
             function: self.function,
             constructor: self.constructor,
+            realm: self.context.realm().clone(),
         };
 
         let length = PropertyDescriptor::builder()
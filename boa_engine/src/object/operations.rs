@@ -3,9 +3,10 @@ use crate::{
     context::intrinsics::{StandardConstructor, StandardConstructors},
     object::JsObject,
     property::{PropertyDescriptor, PropertyDescriptorBuilder, PropertyKey, PropertyNameKind},
+    realm::Realm,
     symbol::WellKnownSymbols,
     value::Type,
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue, JsVariant,
 };
 
 /// Object integrity level.
@@ -91,7 +92,9 @@ impl JsObject {
         let success = self.__set__(key.clone(), value.into(), self.clone().into(), context)?;
         // 5. If success is false and Throw is true, throw a TypeError exception.
         if !success && throw {
-            return context.throw_type_error(format!("cannot set non-writable property: {key}"));
+            return Err(JsNativeError::typ()
+                .with_message(format!("cannot set non-writable property: {key}"))
+                .into());
         }
         // 6. Return success.
         Ok(success)
@@ -150,7 +153,9 @@ impl JsObject {
         let success = self.create_data_property(key.clone(), value, context)?;
         // 4. If success is false, throw a TypeError exception.
         if !success {
-            return context.throw_type_error(format!("cannot redefine property: {key}"));
+            return Err(JsNativeError::typ()
+                .with_message(format!("cannot redefine property: {key}"))
+                .into());
         }
         // 5. Return success.
         Ok(success)
@@ -217,7 +222,9 @@ impl JsObject {
         let success = self.__define_own_property__(key.clone(), desc.into(), context)?;
         // 4. If success is false, throw a TypeError exception.
         if !success {
-            return context.throw_type_error(format!("cannot redefine property: {key}"));
+            return Err(JsNativeError::typ()
+                .with_message(format!("cannot redefine property: {key}"))
+                .into());
         }
         // 5. Return success.
         Ok(success)
@@ -241,7 +248,9 @@ impl JsObject {
         let success = self.__delete__(&key, context)?;
         // 4. If success is false, throw a TypeError exception.
         if !success {
-            return context.throw_type_error(format!("cannot delete property: {key}"));
+            return Err(JsNativeError::typ()
+                .with_message(format!("cannot delete property: {key}"))
+                .into());
         }
         // 5. Return success.
         Ok(success)
@@ -303,7 +312,7 @@ impl JsObject {
         // 1. If argumentsList is not present, set argumentsList to a new empty List.
         // 2. If IsCallable(F) is false, throw a TypeError exception.
         if !self.is_callable() {
-            return context.throw_type_error("not a function");
+            return Err(JsNativeError::typ().with_message("not a function").into());
         }
         // 3. Return ? F.[[Call]](V, argumentsList).
         self.__call__(this, args, context)
@@ -496,7 +505,9 @@ impl JsObject {
         let c = if let Some(c) = c.as_object() {
             c
         } else {
-            return context.throw_type_error("property 'constructor' is not an object");
+            return Err(JsNativeError::typ()
+                .with_message("property 'constructor' is not an object")
+                .into());
         };
 
         // 5. Let S be ? Get(C, @@species).
@@ -511,7 +522,9 @@ impl JsObject {
         // 8. Throw a TypeError exception.
         match s.as_object() {
             Some(obj) if obj.is_constructor() => Ok(obj.clone()),
-            _ => context.throw_type_error("property 'constructor' is not a constructor"),
+            _ => Err(JsNativeError::typ()
+                .with_message("property 'constructor' is not a constructor")
+                .into()),
         }
     }
 
@@ -594,15 +607,18 @@ impl JsObject {
 
         // 1. Assert: IsPropertyKey(P) is true.
         // 2. Let func be ? GetV(V, P).
-        match &self.__get__(&key.into(), self.clone().into(), context)? {
+        match self
+            .__get__(&key.into(), self.clone().into(), context)?
+            .variant()
+        {
             // 3. If func is either undefined or null, return undefined.
-            JsValue::Undefined | JsValue::Null => Ok(None),
+            JsVariant::Undefined | JsVariant::Null => Ok(None),
             // 5. Return func.
-            JsValue::Object(obj) if obj.is_callable() => Ok(Some(obj.clone())),
+            JsVariant::Object(obj) if obj.is_callable() => Ok(Some(obj.clone())),
             // 4. If IsCallable(func) is false, throw a TypeError exception.
-            _ => {
-                context.throw_type_error("value returned for property of object is not a function")
-            }
+            _ => Err(JsNativeError::typ()
+                .with_message("value returned for property of object is not a function")
+                .into()),
         }
     }
 
@@ -614,7 +630,7 @@ impl JsObject {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-isarray
-    pub(crate) fn is_array_abstract(&self, context: &mut Context) -> JsResult<bool> {
+    pub(crate) fn is_array_abstract(&self) -> JsResult<bool> {
         // Note: The spec specifies this function for JsValue.
         // It is implemented for JsObject for convenience.
 
@@ -628,17 +644,57 @@ impl JsObject {
         if let Some(proxy) = object.as_proxy() {
             // a. If argument.[[ProxyHandler]] is null, throw a TypeError exception.
             // b. Let target be argument.[[ProxyTarget]].
-            let (target, _) = proxy.try_data(context)?;
+            let (target, _) = proxy.try_data()?;
 
             // c. Return ? IsArray(target).
-            return target.is_array_abstract(context);
+            return target.is_array_abstract();
         }
 
         // 4. Return false.
         Ok(false)
     }
 
-    // todo: GetFunctionRealm
+    /// Abstract operation `GetFunctionRealm ( obj )`
+    ///
+    /// Returns the realm of a function object, or the active realm if the object is not a
+    /// function that has a realm.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getfunctionrealm
+    pub(crate) fn get_function_realm(&self, context: &Context) -> JsResult<Realm> {
+        let object = self.borrow();
+
+        // 1. If obj has a [[Realm]] internal slot, then
+        //     a. Return obj.[[Realm]].
+        if let Some(function) = object.as_function() {
+            return Ok(function.realm().clone());
+        }
+        if let Some(wrapped) = object.as_wrapped_function() {
+            return Ok(wrapped.realm().clone());
+        }
+
+        // 2. If obj is a bound function exotic object, then
+        if let Some(bound) = object.as_bound_function() {
+            // a. Let boundTargetFunction be obj.[[BoundTargetFunction]].
+            // b. Return ? GetFunctionRealm(boundTargetFunction).
+            return bound.target_function().get_function_realm(context);
+        }
+
+        // 3. If obj is a Proxy exotic object, then
+        if let Some(proxy) = object.as_proxy() {
+            // a. If obj.[[ProxyHandler]] is null, throw a TypeError exception.
+            // b. Let proxyTarget be obj.[[ProxyTarget]].
+            let (target, _) = proxy.try_data()?;
+
+            // c. Return ? GetFunctionRealm(proxyTarget).
+            return target.get_function_realm(context);
+        }
+
+        // 4. Return the current Realm Record.
+        Ok(context.realm().clone())
+    }
 
     // todo: CopyDataProperties
 
@@ -727,9 +783,9 @@ impl JsValue {
         };
 
         // 2. If Type(obj) is not Object, throw a TypeError exception.
-        let obj = self
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("cannot create list from a primitive"))?;
+        let obj = self.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("cannot create list from a primitive")
+        })?;
 
         // 3. Let len be ? LengthOfArrayLike(obj).
         let len = obj.length_of_array_like(context)?;
@@ -745,7 +801,7 @@ impl JsValue {
             let next = obj.get(index, context)?;
             // c. If Type(next) is not an element of elementTypes, throw a TypeError exception.
             if !types.contains(&next.get_type()) {
-                return context.throw_type_error("bad type");
+                return Err(JsNativeError::typ().with_message("bad type").into());
             }
             // d. Append next as the last element of list.
             list.push(next.clone());
@@ -819,8 +875,9 @@ impl JsValue {
             obj
         } else {
             // 5. If Type(P) is not Object, throw a TypeError exception.
-            return context
-                .throw_type_error("function has non-object prototype in instanceof check");
+            return Err(JsNativeError::typ()
+                .with_message("function has non-object prototype in instanceof check")
+                .into());
         };
 
         // 6. Repeat,
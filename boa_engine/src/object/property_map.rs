@@ -1,6 +1,9 @@
-use super::{PropertyDescriptor, PropertyKey};
+use super::{
+    shape::{Shape, MAX_SHAPED_PROPERTIES},
+    PropertyDescriptor, PropertyKey,
+};
 use crate::{property::PropertyDescriptorBuilder, JsString, JsSymbol, JsValue};
-use boa_gc::{custom_trace, Finalize, Trace};
+use boa_gc::{custom_trace, ExternalMemory, Finalize, Trace};
 use indexmap::IndexMap;
 use rustc_hash::{FxHashMap, FxHasher};
 use std::{collections::hash_map, hash::BuildHasherDefault, iter::FusedIterator};
@@ -213,6 +216,14 @@ impl IndexedProperties {
         }
     }
 
+    /// Gets the number of bytes allocated by the storage.
+    fn allocated_bytes(&self) -> usize {
+        match self {
+            Self::Sparse(map) => map.capacity() * std::mem::size_of::<(u32, PropertyDescriptor)>(),
+            Self::Dense(vec) => vec.capacity() * std::mem::size_of::<JsValue>(),
+        }
+    }
+
     fn values(&self) -> IndexPropertyValues<'_> {
         match self {
             Self::Dense(vec) => IndexPropertyValues::Dense(vec.iter()),
@@ -221,11 +232,138 @@ impl IndexedProperties {
     }
 }
 
+/// The storage of the string keyed properties of an object.
+///
+/// Objects start with a shape, which they share with the other objects that had the same keys
+/// added in the same order. They switch to dictionary mode when a property is removed, or
+/// when they have too many properties.
+#[derive(Debug, Finalize)]
+enum StringPropertyStorage {
+    /// The properties are stored in slots, laid out as described by the shape.
+    Shaped {
+        shape: Shape,
+        slots: Vec<PropertyDescriptor>,
+    },
+
+    /// The properties are stored in a hash map of their own.
+    Dictionary(OrderedHashMap<JsString>),
+}
+
+impl Default for StringPropertyStorage {
+    #[inline]
+    fn default() -> Self {
+        Self::Shaped {
+            shape: Shape::root(),
+            slots: Vec::new(),
+        }
+    }
+}
+
+unsafe impl Trace for StringPropertyStorage {
+    custom_trace!(this, {
+        match this {
+            Self::Shaped { slots, .. } => {
+                for slot in slots {
+                    mark(slot);
+                }
+            }
+            Self::Dictionary(map) => mark(map),
+        }
+    });
+}
+
+impl StringPropertyStorage {
+    #[inline]
+    fn get(&self, key: &JsString) -> Option<&PropertyDescriptor> {
+        match self {
+            Self::Shaped { shape, slots } => shape.lookup(key).map(|slot| &slots[slot as usize]),
+            Self::Dictionary(map) => map.0.get(key),
+        }
+    }
+
+    #[inline]
+    fn get_mut(&mut self, key: &JsString) -> Option<&mut PropertyDescriptor> {
+        match self {
+            Self::Shaped { shape, slots } => {
+                shape.lookup(key).map(|slot| &mut slots[slot as usize])
+            }
+            Self::Dictionary(map) => map.0.get_mut(key),
+        }
+    }
+
+    #[inline]
+    fn contains_key(&self, key: &JsString) -> bool {
+        match self {
+            Self::Shaped { shape, .. } => shape.lookup(key).is_some(),
+            Self::Dictionary(map) => map.0.contains_key(key),
+        }
+    }
+
+    fn insert(
+        &mut self,
+        key: &JsString,
+        property: PropertyDescriptor,
+    ) -> Option<PropertyDescriptor> {
+        match self {
+            Self::Shaped { shape, slots } => {
+                if let Some(slot) = shape.lookup(key) {
+                    return Some(std::mem::replace(&mut slots[slot as usize], property));
+                }
+                if shape.len() < MAX_SHAPED_PROPERTIES {
+                    *shape = shape.insert_key(key);
+                    slots.push(property);
+                    return None;
+                }
+            }
+            Self::Dictionary(map) => return map.0.insert(key.clone(), property),
+        }
+
+        self.dictionary_mut().insert(key.clone(), property)
+    }
+
+    fn remove(&mut self, key: &JsString) -> Option<PropertyDescriptor> {
+        if !self.contains_key(key) {
+            return None;
+        }
+        self.dictionary_mut().shift_remove(key)
+    }
+
+    /// Switches to dictionary mode if the properties are stored in slots, and returns the map
+    /// of the properties.
+    fn dictionary_mut(&mut self) -> &mut GlobalPropertyMap {
+        if let Self::Shaped { shape, slots } = self {
+            let mut map = OrderedHashMap::default();
+            map.0.reserve(slots.len());
+            for (key, property) in shape.keys().iter().zip(std::mem::take(slots)) {
+                map.0.insert(key.clone(), property);
+            }
+            *self = Self::Dictionary(map);
+        }
+
+        match self {
+            Self::Dictionary(map) => &mut map.0,
+            Self::Shaped { .. } => unreachable!("the properties were just switched to a map"),
+        }
+    }
+
+    #[inline]
+    fn iter(&self) -> StringPropertiesIter<'_> {
+        match self {
+            Self::Shaped { shape, slots } => {
+                StringPropertiesIter::Shaped(shape.keys().iter().zip(slots.iter()))
+            }
+            Self::Dictionary(map) => StringPropertiesIter::Dictionary(map.0.iter()),
+        }
+    }
+}
+
 #[derive(Default, Debug, Trace, Finalize)]
 pub struct PropertyMap {
     indexed_properties: IndexedProperties,
+    /// The memory allocated by the indexed properties, accounted as part of the heap.
+    indexed_properties_memory: ExternalMemory,
     /// Properties
-    string_properties: OrderedHashMap<JsString>,
+    string_properties: StringPropertyStorage,
     /// Symbol Properties
     symbol_properties: OrderedHashMap<JsSymbol>,
 }
@@ -238,7 +376,7 @@ impl PropertyMap {
     pub fn get(&self, key: &PropertyKey) -> Option<PropertyDescriptor> {
         match key {
             PropertyKey::Index(index) => self.indexed_properties.get(*index),
-            PropertyKey::String(string) => self.string_properties.0.get(string).cloned(),
+            PropertyKey::String(string) => self.string_properties.get(string).cloned(),
             PropertyKey::Symbol(symbol) => self.symbol_properties.0.get(symbol).cloned(),
         }
     }
@@ -249,10 +387,12 @@ impl PropertyMap {
         property: PropertyDescriptor,
     ) -> Option<PropertyDescriptor> {
         match &key {
-            PropertyKey::Index(index) => self.indexed_properties.insert(*index, property),
-            PropertyKey::String(string) => {
-                self.string_properties.0.insert(string.clone(), property)
+            PropertyKey::Index(index) => {
+                let old_property = self.indexed_properties.insert(*index, property);
+                self.update_indexed_properties_memory();
+                old_property
             }
+            PropertyKey::String(string) => self.string_properties.insert(string, property),
             PropertyKey::Symbol(symbol) => {
                 self.symbol_properties.0.insert(symbol.clone(), property)
             }
@@ -261,8 +401,12 @@ impl PropertyMap {
 
     pub fn remove(&mut self, key: &PropertyKey) -> Option<PropertyDescriptor> {
         match key {
-            PropertyKey::Index(index) => self.indexed_properties.remove(*index),
-            PropertyKey::String(string) => self.string_properties.0.shift_remove(string),
+            PropertyKey::Index(index) => {
+                let old_property = self.indexed_properties.remove(*index);
+                self.update_indexed_properties_memory();
+                old_property
+            }
+            PropertyKey::String(string) => self.string_properties.remove(string),
             PropertyKey::Symbol(symbol) => self.symbol_properties.0.shift_remove(symbol),
         }
     }
@@ -270,6 +414,16 @@ impl PropertyMap {
     /// Overrides all the indexed properties, setting it to dense storage.
     pub(crate) fn override_indexed_properties(&mut self, properties: Vec<JsValue>) {
         self.indexed_properties = IndexedProperties::Dense(properties);
+        self.update_indexed_properties_memory();
+    }
+
+    /// Accounts the memory allocated by the indexed properties after they change.
+    #[inline]
+    fn update_indexed_properties_memory(&mut self) {
+        let bytes = self.indexed_properties.allocated_bytes();
+        if bytes != self.indexed_properties_memory.bytes() {
+            self.indexed_properties_memory.set(bytes);
+        }
     }
 
     /// Returns the vec of dense indexed properties if they exist.
@@ -288,7 +442,7 @@ impl PropertyMap {
     pub fn iter(&self) -> Iter<'_> {
         Iter {
             indexed_properties: self.indexed_properties.iter(),
-            string_properties: self.string_properties.0.iter(),
+            string_properties: self.string_properties.iter(),
             symbol_properties: self.symbol_properties.0.iter(),
         }
     }
@@ -363,7 +517,7 @@ impl PropertyMap {
     /// This iterator does not recurse down the prototype chain.
     #[inline]
     pub fn string_properties(&self) -> StringProperties<'_> {
-        StringProperties(self.string_properties.0.iter())
+        StringProperties(self.string_properties.iter())
     }
 
     /// An iterator visiting all string keys in arbitrary order. The iterator element type is `&'a RcString`.
@@ -371,7 +525,7 @@ impl PropertyMap {
     /// This iterator does not recurse down the prototype chain.
     #[inline]
     pub fn string_property_keys(&self) -> StringPropertyKeys<'_> {
-        StringPropertyKeys(self.string_properties.0.keys())
+        StringPropertyKeys(self.string_properties.iter())
     }
 
     /// An iterator visiting all string values in arbitrary order. The iterator element type is `&'a Property`.
@@ -379,26 +533,76 @@ impl PropertyMap {
     /// This iterator does not recurse down the prototype chain.
     #[inline]
     pub fn string_property_values(&self) -> StringPropertyValues<'_> {
-        StringPropertyValues(self.string_properties.0.values())
+        StringPropertyValues(self.string_properties.iter())
     }
 
     #[inline]
     pub fn contains_key(&self, key: &PropertyKey) -> bool {
         match key {
             PropertyKey::Index(index) => self.indexed_properties.contains_key(*index),
-            PropertyKey::String(string) => self.string_properties.0.contains_key(string),
+            PropertyKey::String(string) => self.string_properties.contains_key(string),
             PropertyKey::Symbol(symbol) => self.symbol_properties.0.contains_key(symbol),
         }
     }
 
+    /// Gets the string keyed property `key`, without cloning it.
+    #[inline]
+    pub(crate) fn string_property(&self, key: &JsString) -> Option<&PropertyDescriptor> {
+        self.string_properties.get(key)
+    }
+
+    /// Gets a mutable reference to the string keyed property `key`.
     #[inline]
-    pub(crate) fn string_property_map(&self) -> &GlobalPropertyMap {
-        &self.string_properties.0
+    pub(crate) fn string_property_mut(
+        &mut self,
+        key: &JsString,
+    ) -> Option<&mut PropertyDescriptor> {
+        self.string_properties.get_mut(key)
     }
 
+    /// Gets the map of the string keyed properties, switching them to dictionary mode.
     #[inline]
     pub(crate) fn string_property_map_mut(&mut self) -> &mut GlobalPropertyMap {
-        &mut self.string_properties.0
+        self.string_properties.dictionary_mut()
+    }
+
+    /// Gets the shape of the string keyed properties, if they are not in dictionary mode.
+    #[inline]
+    pub(crate) fn shape(&self) -> Option<&Shape> {
+        match &self.string_properties {
+            StringPropertyStorage::Shaped { shape, .. } => Some(shape),
+            StringPropertyStorage::Dictionary(_) => None,
+        }
+    }
+
+    /// Gets the string keyed property stored in the given slot of the shape.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the properties are in dictionary mode, or if the slot is out of range.
+    #[inline]
+    pub(crate) fn slot(&self, slot: u32) -> &PropertyDescriptor {
+        match &self.string_properties {
+            StringPropertyStorage::Shaped { slots, .. } => &slots[slot as usize],
+            StringPropertyStorage::Dictionary(_) => {
+                panic!("properties in dictionary mode have no slots")
+            }
+        }
+    }
+
+    /// Gets the mutable string keyed property stored in the given slot of the shape.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the properties are in dictionary mode, or if the slot is out of range.
+    #[inline]
+    pub(crate) fn slot_mut(&mut self, slot: u32) -> &mut PropertyDescriptor {
+        match &mut self.string_properties {
+            StringPropertyStorage::Shaped { slots, .. } => &mut slots[slot as usize],
+            StringPropertyStorage::Dictionary(_) => {
+                panic!("properties in dictionary mode have no slots")
+            }
+        }
     }
 }
 
@@ -406,7 +610,7 @@ impl PropertyMap {
 #[derive(Debug, Clone)]
 pub struct Iter<'a> {
     indexed_properties: IndexProperties<'a>,
-    string_properties: indexmap::map::Iter<'a, JsString, PropertyDescriptor>,
+    string_properties: StringPropertiesIter<'a>,
     symbol_properties: indexmap::map::Iter<'a, JsSymbol, PropertyDescriptor>,
 }
 
@@ -690,9 +894,50 @@ impl ExactSizeIterator for IndexPropertyValues<'_> {
 
 impl FusedIterator for IndexPropertyValues<'_> {}
 
+/// An iterator over the string keyed properties, in either storage mode.
+#[derive(Debug, Clone)]
+enum StringPropertiesIter<'a> {
+    Shaped(
+        std::iter::Zip<std::slice::Iter<'a, JsString>, std::slice::Iter<'a, PropertyDescriptor>>,
+    ),
+    Dictionary(indexmap::map::Iter<'a, JsString, PropertyDescriptor>),
+}
+
+impl<'a> Iterator for StringPropertiesIter<'a> {
+    type Item = (&'a JsString, &'a PropertyDescriptor);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Shaped(iter) => iter.next(),
+            Self::Dictionary(iter) => iter.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Shaped(iter) => iter.size_hint(),
+            Self::Dictionary(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl ExactSizeIterator for StringPropertiesIter<'_> {
+    #[inline]
+    fn len(&self) -> usize {
+        match self {
+            Self::Shaped(iter) => iter.len(),
+            Self::Dictionary(iter) => iter.len(),
+        }
+    }
+}
+
+impl FusedIterator for StringPropertiesIter<'_> {}
+
 /// An iterator over the `String` property entries of an `Object`
 #[derive(Debug, Clone)]
-pub struct StringProperties<'a>(indexmap::map::Iter<'a, JsString, PropertyDescriptor>);
+pub struct StringProperties<'a>(StringPropertiesIter<'a>);
 
 impl<'a> Iterator for StringProperties<'a> {
     type Item = (&'a JsString, &'a PropertyDescriptor);
@@ -719,14 +964,15 @@ impl FusedIterator for StringProperties<'_> {}
 
 /// An iterator over the string keys (`RcString`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct StringPropertyKeys<'a>(indexmap::map::Keys<'a, JsString, PropertyDescriptor>);
+pub struct StringPropertyKeys<'a>(StringPropertiesIter<'a>);
 
 impl<'a> Iterator for StringPropertyKeys<'a> {
     type Item = &'a JsString;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next()
+        let (key, _) = self.0.next()?;
+        Some(key)
     }
 
     #[inline]
@@ -746,14 +992,15 @@ impl FusedIterator for StringPropertyKeys<'_> {}
 
 /// An iterator over the string values (`Property`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct StringPropertyValues<'a>(indexmap::map::Values<'a, JsString, PropertyDescriptor>);
+pub struct StringPropertyValues<'a>(StringPropertiesIter<'a>);
 
 impl<'a> Iterator for StringPropertyValues<'a> {
     type Item = &'a PropertyDescriptor;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next()
+        let (_, value) = self.0.next()?;
+        Some(value)
     }
 
     #[inline]
@@ -0,0 +1,150 @@
+//! Shapes (also known as hidden classes) of objects.
+//!
+//! A [`Shape`] describes the string keyed properties of an object: which keys it has, and in
+//! which slot the property of each key is stored. Objects that had the same keys added in the
+//! same order share a shape, since every shape remembers the shapes reached by adding a key to
+//! it (its transitions).
+//!
+//! Two objects with the same shape store the property of a key in the same slot, so the
+//! result of a property lookup can be cached by the shape and reused for every object of
+//! that shape. This is what the inline caches of the VM do.
+
+use crate::JsString;
+use boa_gc::{unsafe_empty_trace, Finalize, Trace};
+use rustc_hash::FxHashMap;
+use std::{
+    cell::RefCell,
+    fmt,
+    rc::{Rc, Weak},
+};
+
+/// The maximum number of string keyed properties an object can have while it has a shape.
+///
+/// Adding more properties switches the object to dictionary mode, where its properties are
+/// stored in a hash map of its own.
+pub(crate) const MAX_SHAPED_PROPERTIES: usize = 64;
+
+thread_local! {
+    static ROOT_SHAPE: Shape = Shape {
+        inner: Rc::new(Inner {
+            parent: None,
+            keys: Vec::new(),
+            slots: FxHashMap::default(),
+            transitions: RefCell::default(),
+        }),
+    };
+}
+
+/// The shape of an object with string keyed properties stored in slots.
+///
+/// Cloning a `Shape` is cheap, and two shapes are equal if they are the same shape.
+#[derive(Clone)]
+pub(crate) struct Shape {
+    inner: Rc<Inner>,
+}
+
+struct Inner {
+    /// The shape this shape was reached from, kept alive so that the objects reaching this
+    /// shape again later get the same shape.
+    #[allow(dead_code)]
+    parent: Option<Rc<Inner>>,
+    /// The keys of the properties, in the order of their slots.
+    keys: Vec<JsString>,
+    /// Maps each key to its slot.
+    slots: FxHashMap<JsString, u32>,
+    /// The shapes reached by adding a key to this shape.
+    transitions: RefCell<FxHashMap<JsString, Weak<Inner>>>,
+}
+
+impl fmt::Debug for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Shape")
+            .field("keys", &self.inner.keys)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Finalize for Shape {}
+
+// SAFETY: Shapes don't hold garbage collected values.
+unsafe impl Trace for Shape {
+    unsafe_empty_trace!();
+}
+
+impl PartialEq for Shape {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for Shape {}
+
+impl Default for Shape {
+    #[inline]
+    fn default() -> Self {
+        Self::root()
+    }
+}
+
+impl Shape {
+    /// Gets the shape of objects without string keyed properties.
+    #[inline]
+    pub(crate) fn root() -> Self {
+        ROOT_SHAPE.with(Clone::clone)
+    }
+
+    /// Gets the number of properties of this shape.
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.inner.keys.len()
+    }
+
+    /// Gets the keys of this shape, in the order of their slots.
+    #[inline]
+    pub(crate) fn keys(&self) -> &[JsString] {
+        &self.inner.keys
+    }
+
+    /// Gets the slot of the property with the given key, if this shape has the key.
+    #[inline]
+    pub(crate) fn lookup(&self, key: &JsString) -> Option<u32> {
+        self.inner.slots.get(key).copied()
+    }
+
+    /// Gets the shape reached by adding `key` to this shape.
+    ///
+    /// The property of the new key is stored in the slot after the last slot of this shape.
+    pub(crate) fn insert_key(&self, key: &JsString) -> Self {
+        debug_assert!(
+            self.lookup(key).is_none(),
+            "the key must not be in the shape"
+        );
+
+        let mut transitions = self.inner.transitions.borrow_mut();
+        if let Some(inner) = transitions.get(key).and_then(Weak::upgrade) {
+            return Self { inner };
+        }
+
+        let mut keys = Vec::with_capacity(self.inner.keys.len() + 1);
+        keys.extend_from_slice(&self.inner.keys);
+        keys.push(key.clone());
+
+        let mut slots = self.inner.slots.clone();
+        slots.insert(key.clone(), self.inner.keys.len() as u32);
+
+        let inner = Rc::new(Inner {
+            parent: Some(self.inner.clone()),
+            keys,
+            slots,
+            transitions: RefCell::default(),
+        });
+
+        // Transitions to shapes without objects are only removed when they are replaced,
+        // so remove them here to keep the table from growing.
+        transitions.retain(|_, shape| shape.strong_count() > 0);
+        transitions.insert(key.clone(), Rc::downgrade(&inner));
+
+        Self { inner }
+    }
+}
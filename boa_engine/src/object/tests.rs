@@ -44,3 +44,35 @@ fn object_properties_return_order() {
         ),
     ]);
 }
+
+#[test]
+fn objects_with_same_keys_share_shape() {
+    let mut context = crate::Context::default();
+    let objects = context
+        .eval("[{ a: 1, b: 2 }, { a: 3, b: 4 }, { b: 5, a: 6 }]")
+        .unwrap();
+    let objects = objects.as_object().unwrap();
+    let shape = |index: u32, context: &mut crate::Context| {
+        let object = objects.get(index, context).unwrap();
+        let object = object.as_object().unwrap().borrow();
+        object.properties().shape().cloned()
+    };
+
+    let first = shape(0, &mut context).unwrap();
+    assert_eq!(Some(first.clone()), shape(1, &mut context));
+    assert_ne!(Some(first), shape(2, &mut context));
+}
+
+#[test]
+fn shaped_properties_keep_order() {
+    let scenario = r#"
+        var o = { b: 1, a: 2 };
+        o.c = 3;
+        o[0] = 4;
+        delete o.b;
+        o.b = 5;
+        Object.keys(o).join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"0,a,c,b\"");
+}
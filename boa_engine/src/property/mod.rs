@@ -135,6 +135,19 @@ impl PropertyDescriptor {
         }
     }
 
+    /// Gets a mutable reference to the value of a writable data property, which can be
+    /// updated in place without changing its attributes.
+    #[inline]
+    pub(crate) fn writable_value_mut(&mut self) -> Option<&mut JsValue> {
+        match &mut self.kind {
+            DescriptorKind::Data {
+                value: Some(value),
+                writable: Some(true),
+            } => Some(value),
+            _ => None,
+        }
+    }
+
     #[inline]
     pub fn get(&self) -> Option<&JsValue> {
         match &self.kind {
@@ -498,7 +511,7 @@ pub enum PropertyKey {
 impl From<JsString> for PropertyKey {
     #[inline]
     fn from(string: JsString) -> Self {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = string.to_std_string().ok().and_then(|s| s.parse().ok()) {
             Self::Index(index)
         } else {
             Self::String(string)
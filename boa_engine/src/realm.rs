@@ -4,61 +4,127 @@
 //!
 //! A realm is represented in this implementation as a Realm struct with the fields specified from the spec.
 
+use std::fmt;
+
 use crate::{
+    context::intrinsics::Intrinsics,
     environments::{CompileTimeEnvironment, DeclarativeEnvironmentStack},
-    object::{GlobalPropertyMap, JsObject, JsPrototype, ObjectData, PropertyMap},
+    object::{JsObject, ObjectData},
 };
-use boa_gc::{Cell, Gc};
+use boa_gc::{Cell, Finalize, Gc, Trace};
 use boa_profiler::Profiler;
 
 /// Representation of a Realm.
 ///
 /// In the specification these are called Realm Records.
-#[derive(Debug)]
+///
+/// A `Realm` is a cheap to clone handle, so it can be shared between the [`Context`] and the
+/// function objects created in it. Realms are created with [`Context::create_realm`], and made
+/// active with [`Context::enter_realm`].
+///
+/// [`Context`]: crate::Context
+/// [`Context::create_realm`]: crate::Context::create_realm
+/// [`Context::enter_realm`]: crate::Context::enter_realm
+#[derive(Clone, Trace, Finalize)]
 pub struct Realm {
+    inner: Gc<Inner>,
+}
+
+#[derive(Trace, Finalize)]
+struct Inner {
+    intrinsics: Intrinsics,
     global_object: JsObject,
-    pub(crate) global_extensible: bool,
-    pub(crate) global_property_map: PropertyMap,
-    pub(crate) global_prototype: JsPrototype,
-    pub(crate) environments: DeclarativeEnvironmentStack,
-    pub(crate) compile_env: Gc<Cell<CompileTimeEnvironment>>,
+    environment: DeclarativeEnvironmentStack,
+    compile_environment: Gc<Cell<CompileTimeEnvironment>>,
+}
+
+impl fmt::Debug for Realm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Realm")
+            .field("global_object", &self.inner.global_object)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for Realm {
+    fn eq(&self, other: &Self) -> bool {
+        Gc::ptr_eq(&self.inner, &other.inner)
+    }
 }
 
+impl Eq for Realm {}
+
 impl Realm {
+    /// Creates a new realm with uninitialized intrinsics.
+    ///
+    /// The builtins of the realm must be initialized with the realm active, which is done by
+    /// [`Context::create_realm`](crate::Context::create_realm).
     #[inline]
-    pub fn create(global_prototype: JsPrototype) -> Self {
+    pub(crate) fn create() -> Self {
         let _timer = Profiler::global().start_event("Realm::create", "realm");
-        // Create brand new global object
-        // Global has no prototype to pass None to new_obj
+
+        let intrinsics = Intrinsics::default();
+
         // Allow identification of the global object easily
-        let global_object = JsObject::from_proto_and_data(None, ObjectData::global());
+        let global_object = JsObject::from_proto_and_data(
+            intrinsics.constructors().object().prototype(),
+            ObjectData::global(),
+        );
 
         let global_compile_environment = Gc::new(Cell::new(CompileTimeEnvironment::new_global()));
 
         Self {
-            global_object,
-            global_extensible: true,
-            global_property_map: PropertyMap::default(),
-            global_prototype,
-            environments: DeclarativeEnvironmentStack::new(global_compile_environment.clone()),
-            compile_env: global_compile_environment,
+            inner: Gc::new(Inner {
+                intrinsics,
+                global_object,
+                environment: DeclarativeEnvironmentStack::new(global_compile_environment.clone()),
+                compile_environment: global_compile_environment,
+            }),
+        }
+    }
+
+    /// Creates a realm from its parts, e.g. when restoring a snapshot of a context.
+    ///
+    /// The environment stack must only contain the global environment, whose compile time
+    /// environment must be `compile_environment`.
+    pub(crate) fn from_parts(
+        intrinsics: Intrinsics,
+        global_object: JsObject,
+        environment: DeclarativeEnvironmentStack,
+        compile_environment: Gc<Cell<CompileTimeEnvironment>>,
+    ) -> Self {
+        Self {
+            inner: Gc::new(Inner {
+                intrinsics,
+                global_object,
+                environment,
+                compile_environment,
+            }),
         }
     }
 
+    /// Returns the intrinsic constructors and objects of this realm.
+    #[inline]
+    pub fn intrinsics(&self) -> &Intrinsics {
+        &self.inner.intrinsics
+    }
+
+    /// Returns the global object of this realm.
     #[inline]
-    pub(crate) fn global_object(&self) -> &JsObject {
-        &self.global_object
+    pub fn global_object(&self) -> &JsObject {
+        &self.inner.global_object
     }
 
+    /// Returns the runtime environment stack that only contains the global environment of this
+    /// realm.
     #[inline]
-    pub(crate) fn global_bindings_mut(&mut self) -> &mut GlobalPropertyMap {
-        self.global_property_map.string_property_map_mut()
+    pub(crate) fn environment(&self) -> &DeclarativeEnvironmentStack {
+        &self.inner.environment
     }
 
-    /// Set the number of bindings on the global environment.
+    /// Returns the compile time environment of the global environment of this realm.
     #[inline]
-    pub(crate) fn set_global_binding_number(&mut self) {
-        let binding_number = self.compile_env.borrow().num_bindings();
-        self.environments.set_global_binding_number(binding_number);
+    pub(crate) fn compile_environment(&self) -> Gc<Cell<CompileTimeEnvironment>> {
+        self.inner.compile_environment.clone()
     }
 }
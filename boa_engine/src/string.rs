@@ -1,467 +1,504 @@
-use crate::builtins::string::is_trimmable_whitespace;
+use crate::{builtins::string::is_trimmable_whitespace, JsBigInt};
 use boa_gc::{unsafe_empty_trace, Finalize, Trace};
 use rustc_hash::{FxHashMap, FxHasher};
 use std::{
     alloc::{alloc, dealloc, handle_alloc_error, Layout},
     borrow::Borrow,
-    cell::Cell,
+    cell::{Cell, UnsafeCell},
+    char::decode_utf16,
+    fmt::Write,
     hash::BuildHasherDefault,
     hash::{Hash, Hasher},
     marker::PhantomData,
     ops::Deref,
     ptr::{copy_nonoverlapping, NonNull},
     rc::Rc,
+    string::FromUtf16Error,
 };
 
-const CONSTANTS_ARRAY: [&str; 419] = [
+/// Utility macro to create a static UTF-16 string slice from an ASCII string literal.
+///
+/// The conversion is done at compile time, so the resulting `&'static [u16]` can be used in
+/// constant contexts.
+macro_rules! utf16 {
+    ($s:literal) => {{
+        const LITERAL: &str = $s;
+        const UTF16: [u16; LITERAL.len()] = $crate::string::ascii_to_utf16(LITERAL);
+        &UTF16 as &'static [u16]
+    }};
+}
+
+pub(crate) use utf16;
+
+/// Converts an ASCII string into an array of UTF-16 code units.
+///
+/// # Panics
+///
+/// Panics if `ascii` is not an ASCII string or if its length is not `LEN`.
+#[doc(hidden)]
+pub(crate) const fn ascii_to_utf16<const LEN: usize>(ascii: &str) -> [u16; LEN] {
+    let bytes = ascii.as_bytes();
+    assert!(bytes.len() == LEN, "length mismatch");
+
+    let mut units = [0; LEN];
+    let mut i = 0;
+    while i < LEN {
+        assert!(bytes[i].is_ascii(), "`utf16!` only accepts ASCII strings");
+        units[i] = bytes[i] as u16;
+        i += 1;
+    }
+    units
+}
+
+const CONSTANTS_ARRAY: [&[u16]; 419] = [
     // Empty string
-    "",
+    utf16!(""),
     // Misc
-    ",",
-    ":",
+    utf16!(","),
+    utf16!(":"),
     // Generic use
-    "name",
-    "length",
-    "arguments",
-    "prototype",
-    "constructor",
-    "return",
-    "throw",
-    "global",
-    "globalThis",
+    utf16!("name"),
+    utf16!("length"),
+    utf16!("arguments"),
+    utf16!("prototype"),
+    utf16!("constructor"),
+    utf16!("return"),
+    utf16!("throw"),
+    utf16!("global"),
+    utf16!("globalThis"),
     // typeof
-    "null",
-    "undefined",
-    "number",
-    "string",
-    "symbol",
-    "bigint",
-    "object",
-    "function",
+    utf16!("null"),
+    utf16!("undefined"),
+    utf16!("number"),
+    utf16!("string"),
+    utf16!("symbol"),
+    utf16!("bigint"),
+    utf16!("object"),
+    utf16!("function"),
     // Property descriptor
-    "value",
-    "get",
-    "set",
-    "writable",
-    "enumerable",
-    "configurable",
+    utf16!("value"),
+    utf16!("get"),
+    utf16!("set"),
+    utf16!("writable"),
+    utf16!("enumerable"),
+    utf16!("configurable"),
     // Object object
-    "Object",
-    "assign",
-    "create",
-    "toString",
-    "valueOf",
-    "is",
-    "seal",
-    "isSealed",
-    "freeze",
-    "isFrozen",
-    "isExtensible",
-    "hasOwnProperty",
-    "isPrototypeOf",
-    "setPrototypeOf",
-    "getPrototypeOf",
-    "defineProperty",
-    "defineProperties",
-    "deleteProperty",
-    "construct",
-    "hasOwn",
-    "ownKeys",
-    "keys",
-    "values",
-    "entries",
-    "fromEntries",
+    utf16!("Object"),
+    utf16!("assign"),
+    utf16!("create"),
+    utf16!("toString"),
+    utf16!("valueOf"),
+    utf16!("is"),
+    utf16!("seal"),
+    utf16!("isSealed"),
+    utf16!("freeze"),
+    utf16!("isFrozen"),
+    utf16!("isExtensible"),
+    utf16!("hasOwnProperty"),
+    utf16!("isPrototypeOf"),
+    utf16!("setPrototypeOf"),
+    utf16!("getPrototypeOf"),
+    utf16!("defineProperty"),
+    utf16!("defineProperties"),
+    utf16!("deleteProperty"),
+    utf16!("construct"),
+    utf16!("hasOwn"),
+    utf16!("ownKeys"),
+    utf16!("keys"),
+    utf16!("values"),
+    utf16!("entries"),
+    utf16!("fromEntries"),
     // Function object
-    "Function",
-    "apply",
-    "bind",
-    "call",
+    utf16!("Function"),
+    utf16!("apply"),
+    utf16!("bind"),
+    utf16!("call"),
     // Generator object
-    "Generator",
+    utf16!("Generator"),
     // Array object
-    "Array",
-    "at",
-    "from",
-    "isArray",
-    "of",
-    "copyWithin",
-    "entries",
-    "every",
-    "fill",
-    "filter",
-    "find",
-    "findIndex",
-    "findLast",
-    "findLastIndex",
-    "flat",
-    "flatMap",
-    "forEach",
-    "includes",
-    "indexOf",
-    "join",
-    "map",
-    "next",
-    "reduce",
-    "reduceRight",
-    "reverse",
-    "shift",
-    "slice",
-    "splice",
-    "some",
-    "sort",
-    "unshift",
-    "push",
-    "pop",
+    utf16!("Array"),
+    utf16!("at"),
+    utf16!("from"),
+    utf16!("isArray"),
+    utf16!("of"),
+    utf16!("copyWithin"),
+    utf16!("entries"),
+    utf16!("every"),
+    utf16!("fill"),
+    utf16!("filter"),
+    utf16!("find"),
+    utf16!("findIndex"),
+    utf16!("findLast"),
+    utf16!("findLastIndex"),
+    utf16!("flat"),
+    utf16!("flatMap"),
+    utf16!("forEach"),
+    utf16!("includes"),
+    utf16!("indexOf"),
+    utf16!("join"),
+    utf16!("map"),
+    utf16!("next"),
+    utf16!("reduce"),
+    utf16!("reduceRight"),
+    utf16!("reverse"),
+    utf16!("shift"),
+    utf16!("slice"),
+    utf16!("splice"),
+    utf16!("some"),
+    utf16!("sort"),
+    utf16!("unshift"),
+    utf16!("push"),
+    utf16!("pop"),
     // String object
-    "String",
-    "charAt",
-    "charCodeAt",
-    "codePointAt",
-    "concat",
-    "endsWith",
-    "fromCharCode",
-    "fromCodePoint",
-    "includes",
-    "indexOf",
-    "lastIndexOf",
-    "match",
-    "matchAll",
-    "normalize",
-    "padEnd",
-    "padStart",
-    "raw",
-    "repeat",
-    "replace",
-    "replaceAll",
-    "search",
-    "slice",
-    "split",
-    "startsWith",
-    "substr",
-    "substring",
-    "toLocaleString",
-    "toLowerCase",
-    "toUpperCase",
-    "trim",
-    "trimEnd",
-    "trimStart",
+    utf16!("String"),
+    utf16!("charAt"),
+    utf16!("charCodeAt"),
+    utf16!("codePointAt"),
+    utf16!("concat"),
+    utf16!("endsWith"),
+    utf16!("fromCharCode"),
+    utf16!("fromCodePoint"),
+    utf16!("includes"),
+    utf16!("indexOf"),
+    utf16!("lastIndexOf"),
+    utf16!("match"),
+    utf16!("matchAll"),
+    utf16!("normalize"),
+    utf16!("padEnd"),
+    utf16!("padStart"),
+    utf16!("raw"),
+    utf16!("repeat"),
+    utf16!("replace"),
+    utf16!("replaceAll"),
+    utf16!("search"),
+    utf16!("slice"),
+    utf16!("split"),
+    utf16!("startsWith"),
+    utf16!("substr"),
+    utf16!("substring"),
+    utf16!("toLocaleString"),
+    utf16!("toLowerCase"),
+    utf16!("toUpperCase"),
+    utf16!("trim"),
+    utf16!("trimEnd"),
+    utf16!("trimStart"),
     // Number object
-    "Number",
-    "Infinity",
-    "NaN",
-    "parseInt",
-    "parseFloat",
-    "isFinite",
-    "isNaN",
-    "parseInt",
-    "EPSILON",
-    "MAX_SAFE_INTEGER",
-    "MIN_SAFE_INTEGER",
-    "MAX_VALUE",
-    "MIN_VALUE",
-    "isSafeInteger",
-    "isInteger",
-    "toExponential",
-    "toFixed",
-    "toPrecision",
+    utf16!("Number"),
+    utf16!("Infinity"),
+    utf16!("NaN"),
+    utf16!("parseInt"),
+    utf16!("parseFloat"),
+    utf16!("isFinite"),
+    utf16!("isNaN"),
+    utf16!("parseInt"),
+    utf16!("EPSILON"),
+    utf16!("MAX_SAFE_INTEGER"),
+    utf16!("MIN_SAFE_INTEGER"),
+    utf16!("MAX_VALUE"),
+    utf16!("MIN_VALUE"),
+    utf16!("isSafeInteger"),
+    utf16!("isInteger"),
+    utf16!("toExponential"),
+    utf16!("toFixed"),
+    utf16!("toPrecision"),
     // Boolean object
-    "Boolean",
+    utf16!("Boolean"),
     // BigInt object
-    "BigInt",
-    "asIntN",
-    "asUintN",
+    utf16!("BigInt"),
+    utf16!("asIntN"),
+    utf16!("asUintN"),
     // RegExp object
-    "RegExp",
-    "exec",
-    "test",
-    "flags",
-    "index",
-    "lastIndex",
-    "hasIndices",
-    "ignoreCase",
-    "multiline",
-    "dotAll",
-    "unicode",
-    "sticky",
-    "source",
-    "get hasIndices",
-    "get global",
-    "get ignoreCase",
-    "get multiline",
-    "get dotAll",
-    "get unicode",
-    "get sticky",
-    "get flags",
-    "get source",
+    utf16!("RegExp"),
+    utf16!("exec"),
+    utf16!("test"),
+    utf16!("flags"),
+    utf16!("index"),
+    utf16!("lastIndex"),
+    utf16!("hasIndices"),
+    utf16!("ignoreCase"),
+    utf16!("multiline"),
+    utf16!("dotAll"),
+    utf16!("unicode"),
+    utf16!("sticky"),
+    utf16!("source"),
+    utf16!("get hasIndices"),
+    utf16!("get global"),
+    utf16!("get ignoreCase"),
+    utf16!("get multiline"),
+    utf16!("get dotAll"),
+    utf16!("get unicode"),
+    utf16!("get sticky"),
+    utf16!("get flags"),
+    utf16!("get source"),
     // Symbol object
-    "Symbol",
-    "for",
-    "keyFor",
-    "description",
-    "asyncIterator",
-    "hasInstance",
-    "species",
-    "Symbol.species",
-    "unscopables",
-    "iterator",
-    "Symbol.iterator",
-    "Symbol.match",
-    "[Symbol.match]",
-    "Symbol.matchAll",
-    "Symbol.replace",
-    "[Symbol.replace]",
-    "Symbol.search",
-    "[Symbol.search]",
-    "Symbol.split",
-    "[Symbol.split]",
-    "toStringTag",
-    "toPrimitive",
-    "get description",
+    utf16!("Symbol"),
+    utf16!("for"),
+    utf16!("keyFor"),
+    utf16!("description"),
+    utf16!("asyncIterator"),
+    utf16!("hasInstance"),
+    utf16!("species"),
+    utf16!("Symbol.species"),
+    utf16!("unscopables"),
+    utf16!("iterator"),
+    utf16!("Symbol.iterator"),
+    utf16!("Symbol.match"),
+    utf16!("[Symbol.match]"),
+    utf16!("Symbol.matchAll"),
+    utf16!("Symbol.replace"),
+    utf16!("[Symbol.replace]"),
+    utf16!("Symbol.search"),
+    utf16!("[Symbol.search]"),
+    utf16!("Symbol.split"),
+    utf16!("[Symbol.split]"),
+    utf16!("toStringTag"),
+    utf16!("toPrimitive"),
+    utf16!("get description"),
     // Map object
-    "Map",
-    "clear",
-    "delete",
-    "get",
-    "has",
-    "set",
-    "size",
+    utf16!("Map"),
+    utf16!("clear"),
+    utf16!("delete"),
+    utf16!("get"),
+    utf16!("has"),
+    utf16!("set"),
+    utf16!("size"),
     // Set object
-    "Set",
-    "add",
+    utf16!("Set"),
+    utf16!("add"),
     // Reflect object
-    "Reflect",
+    utf16!("Reflect"),
     // Proxy object
-    "Proxy",
-    "revocable",
+    utf16!("Proxy"),
+    utf16!("revocable"),
     // Error objects
-    "Error",
-    "AggregateError",
-    "TypeError",
-    "RangeError",
-    "SyntaxError",
-    "ReferenceError",
-    "EvalError",
-    "ThrowTypeError",
-    "URIError",
-    "message",
+    utf16!("Error"),
+    utf16!("AggregateError"),
+    utf16!("TypeError"),
+    utf16!("RangeError"),
+    utf16!("SyntaxError"),
+    utf16!("ReferenceError"),
+    utf16!("EvalError"),
+    utf16!("ThrowTypeError"),
+    utf16!("URIError"),
+    utf16!("message"),
     // Date object
-    "Date",
-    "toJSON",
-    "getDate",
-    "getDay",
-    "getFullYear",
-    "getHours",
-    "getMilliseconds",
-    "getMinutes",
-    "getMonth",
-    "getSeconds",
-    "getTime",
-    "getYear",
-    "getUTCDate",
-    "getUTCDay",
-    "getUTCFullYear",
-    "getUTCHours",
-    "getUTCMinutes",
-    "getUTCMonth",
-    "getUTCSeconds",
-    "setDate",
-    "setFullYear",
-    "setHours",
-    "setMilliseconds",
-    "setMinutes",
-    "setMonth",
-    "setSeconds",
-    "setYear",
-    "setTime",
-    "setUTCDate",
-    "setUTCFullYear",
-    "setUTCHours",
-    "setUTCMinutes",
-    "setUTCMonth",
-    "setUTCSeconds",
-    "toDateString",
-    "toGMTString",
-    "toISOString",
-    "toTimeString",
-    "toUTCString",
-    "now",
-    "UTC",
+    utf16!("Date"),
+    utf16!("toJSON"),
+    utf16!("getDate"),
+    utf16!("getDay"),
+    utf16!("getFullYear"),
+    utf16!("getHours"),
+    utf16!("getMilliseconds"),
+    utf16!("getMinutes"),
+    utf16!("getMonth"),
+    utf16!("getSeconds"),
+    utf16!("getTime"),
+    utf16!("getYear"),
+    utf16!("getUTCDate"),
+    utf16!("getUTCDay"),
+    utf16!("getUTCFullYear"),
+    utf16!("getUTCHours"),
+    utf16!("getUTCMinutes"),
+    utf16!("getUTCMonth"),
+    utf16!("getUTCSeconds"),
+    utf16!("setDate"),
+    utf16!("setFullYear"),
+    utf16!("setHours"),
+    utf16!("setMilliseconds"),
+    utf16!("setMinutes"),
+    utf16!("setMonth"),
+    utf16!("setSeconds"),
+    utf16!("setYear"),
+    utf16!("setTime"),
+    utf16!("setUTCDate"),
+    utf16!("setUTCFullYear"),
+    utf16!("setUTCHours"),
+    utf16!("setUTCMinutes"),
+    utf16!("setUTCMonth"),
+    utf16!("setUTCSeconds"),
+    utf16!("toDateString"),
+    utf16!("toGMTString"),
+    utf16!("toISOString"),
+    utf16!("toTimeString"),
+    utf16!("toUTCString"),
+    utf16!("now"),
+    utf16!("UTC"),
     // JSON object
-    "JSON",
-    "parse",
-    "stringify",
+    utf16!("JSON"),
+    utf16!("parse"),
+    utf16!("stringify"),
     // Iterator object
-    "Array Iterator",
-    "Set Iterator",
-    "String Iterator",
-    "Map Iterator",
-    "For In Iterator",
+    utf16!("Array Iterator"),
+    utf16!("Set Iterator"),
+    utf16!("String Iterator"),
+    utf16!("Map Iterator"),
+    utf16!("For In Iterator"),
     // Math object
-    "Math",
-    "LN10",
-    "LN2",
-    "LOG10E",
-    "LOG2E",
-    "PI",
-    "SQRT1_2",
-    "SQRT2",
-    "abs",
-    "acos",
-    "acosh",
-    "asin",
-    "asinh",
-    "atan",
-    "atanh",
-    "atan2",
-    "cbrt",
-    "ceil",
-    "clz32",
-    "cos",
-    "cosh",
-    "exp",
-    "expm1",
-    "floor",
-    "fround",
-    "hypot",
-    "imul",
-    "log",
-    "log1p",
-    "log10",
-    "log2",
-    "max",
-    "min",
-    "pow",
-    "random",
-    "round",
-    "sign",
-    "sin",
-    "sinh",
-    "sqrt",
-    "tan",
-    "tanh",
-    "trunc",
+    utf16!("Math"),
+    utf16!("LN10"),
+    utf16!("LN2"),
+    utf16!("LOG10E"),
+    utf16!("LOG2E"),
+    utf16!("PI"),
+    utf16!("SQRT1_2"),
+    utf16!("SQRT2"),
+    utf16!("abs"),
+    utf16!("acos"),
+    utf16!("acosh"),
+    utf16!("asin"),
+    utf16!("asinh"),
+    utf16!("atan"),
+    utf16!("atanh"),
+    utf16!("atan2"),
+    utf16!("cbrt"),
+    utf16!("ceil"),
+    utf16!("clz32"),
+    utf16!("cos"),
+    utf16!("cosh"),
+    utf16!("exp"),
+    utf16!("expm1"),
+    utf16!("floor"),
+    utf16!("fround"),
+    utf16!("hypot"),
+    utf16!("imul"),
+    utf16!("log"),
+    utf16!("log1p"),
+    utf16!("log10"),
+    utf16!("log2"),
+    utf16!("max"),
+    utf16!("min"),
+    utf16!("pow"),
+    utf16!("random"),
+    utf16!("round"),
+    utf16!("sign"),
+    utf16!("sin"),
+    utf16!("sinh"),
+    utf16!("sqrt"),
+    utf16!("tan"),
+    utf16!("tanh"),
+    utf16!("trunc"),
     // Intl object
-    "Intl",
-    "DateTimeFormat",
+    utf16!("Intl"),
+    utf16!("DateTimeFormat"),
     // TypedArray object
-    "TypedArray",
-    "ArrayBuffer",
-    "Int8Array",
-    "Uint8Array",
-    "Int16Array",
-    "Uint16Array",
-    "Int32Array",
-    "Uint32Array",
-    "BigInt64Array",
-    "BigUint64Array",
-    "Float32Array",
-    "Float64Array",
-    "buffer",
-    "byteLength",
-    "byteOffset",
-    "isView",
-    "subarray",
-    "get byteLength",
-    "get buffer",
-    "get byteOffset",
-    "get size",
-    "get length",
+    utf16!("TypedArray"),
+    utf16!("ArrayBuffer"),
+    utf16!("Int8Array"),
+    utf16!("Uint8Array"),
+    utf16!("Int16Array"),
+    utf16!("Uint16Array"),
+    utf16!("Int32Array"),
+    utf16!("Uint32Array"),
+    utf16!("BigInt64Array"),
+    utf16!("BigUint64Array"),
+    utf16!("Float32Array"),
+    utf16!("Float64Array"),
+    utf16!("buffer"),
+    utf16!("byteLength"),
+    utf16!("byteOffset"),
+    utf16!("isView"),
+    utf16!("subarray"),
+    utf16!("get byteLength"),
+    utf16!("get buffer"),
+    utf16!("get byteOffset"),
+    utf16!("get size"),
+    utf16!("get length"),
     // DataView object
-    "DataView",
-    "getBigInt64",
-    "getBigUint64",
-    "getFloat32",
-    "getFloat64",
-    "getInt8",
-    "getInt16",
-    "getInt32",
-    "getUint8",
-    "getUint16",
-    "getUint32",
-    "setBigInt64",
-    "setBigUint64",
-    "setFloat32",
-    "setFloat64",
-    "setInt8",
-    "setInt16",
-    "setInt32",
-    "setUint8",
-    "setUint16",
-    "setUint32",
+    utf16!("DataView"),
+    utf16!("getBigInt64"),
+    utf16!("getBigUint64"),
+    utf16!("getFloat32"),
+    utf16!("getFloat64"),
+    utf16!("getInt8"),
+    utf16!("getInt16"),
+    utf16!("getInt32"),
+    utf16!("getUint8"),
+    utf16!("getUint16"),
+    utf16!("getUint32"),
+    utf16!("setBigInt64"),
+    utf16!("setBigUint64"),
+    utf16!("setFloat32"),
+    utf16!("setFloat64"),
+    utf16!("setInt8"),
+    utf16!("setInt16"),
+    utf16!("setInt32"),
+    utf16!("setUint8"),
+    utf16!("setUint16"),
+    utf16!("setUint32"),
     // Console object
-    "console",
-    "assert",
-    "debug",
-    "error",
-    "info",
-    "trace",
-    "warn",
-    "exception",
-    "count",
-    "countReset",
-    "group",
-    "groupCollapsed",
-    "groupEnd",
-    "time",
-    "timeLog",
-    "timeEnd",
-    "dir",
-    "dirxml",
+    utf16!("console"),
+    utf16!("assert"),
+    utf16!("debug"),
+    utf16!("error"),
+    utf16!("info"),
+    utf16!("trace"),
+    utf16!("warn"),
+    utf16!("exception"),
+    utf16!("count"),
+    utf16!("countReset"),
+    utf16!("group"),
+    utf16!("groupCollapsed"),
+    utf16!("groupEnd"),
+    utf16!("time"),
+    utf16!("timeLog"),
+    utf16!("timeEnd"),
+    utf16!("dir"),
+    utf16!("dirxml"),
     // Minified name
-    "a",
-    "b",
-    "c",
-    "d",
-    "e",
-    "f",
-    "g",
-    "h",
-    "i",
-    "j",
-    "k",
-    "l",
-    "m",
-    "n",
-    "o",
-    "p",
-    "q",
-    "r",
-    "s",
-    "t",
-    "u",
-    "v",
-    "w",
-    "x",
-    "y",
-    "z",
-    "A",
-    "B",
-    "C",
-    "D",
-    "E",
-    "F",
-    "G",
-    "H",
-    "I",
-    "J",
-    "K",
-    "L",
-    "M",
-    "N",
-    "O",
-    "P",
-    "Q",
-    "R",
-    "S",
-    "T",
-    "U",
-    "V",
-    "W",
-    "X",
-    "Y",
-    "Z",
-    "_",
-    "$",
+    utf16!("a"),
+    utf16!("b"),
+    utf16!("c"),
+    utf16!("d"),
+    utf16!("e"),
+    utf16!("f"),
+    utf16!("g"),
+    utf16!("h"),
+    utf16!("i"),
+    utf16!("j"),
+    utf16!("k"),
+    utf16!("l"),
+    utf16!("m"),
+    utf16!("n"),
+    utf16!("o"),
+    utf16!("p"),
+    utf16!("q"),
+    utf16!("r"),
+    utf16!("s"),
+    utf16!("t"),
+    utf16!("u"),
+    utf16!("v"),
+    utf16!("w"),
+    utf16!("x"),
+    utf16!("y"),
+    utf16!("z"),
+    utf16!("A"),
+    utf16!("B"),
+    utf16!("C"),
+    utf16!("D"),
+    utf16!("E"),
+    utf16!("F"),
+    utf16!("G"),
+    utf16!("H"),
+    utf16!("I"),
+    utf16!("J"),
+    utf16!("K"),
+    utf16!("L"),
+    utf16!("M"),
+    utf16!("N"),
+    utf16!("O"),
+    utf16!("P"),
+    utf16!("Q"),
+    utf16!("R"),
+    utf16!("S"),
+    utf16!("T"),
+    utf16!("U"),
+    utf16!("V"),
+    utf16!("W"),
+    utf16!("X"),
+    utf16!("Y"),
+    utf16!("Z"),
+    utf16!("_"),
+    utf16!("$"),
 ];
 
 const MAX_CONSTANT_STRING_LENGTH: usize = {
@@ -477,16 +514,23 @@ const MAX_CONSTANT_STRING_LENGTH: usize = {
     max
 };
 
+/// The minimum length of the result of [`JsString::concat_rope`] for it to be stored as a rope.
+///
+/// Shorter strings are cheaper to copy than to keep around as a tree of pieces.
+const ROPE_MIN_LENGTH: usize = 32;
+
+/// Allocates the memory of a string, accounting it as part of the heap of the current thread.
 unsafe fn try_alloc(layout: Layout) -> *mut u8 {
     let ptr = alloc(layout);
     if ptr.is_null() {
         handle_alloc_error(layout);
     }
+    boa_gc::track_external_memory(layout.size());
     ptr
 }
 
 thread_local! {
-    static CONSTANTS: FxHashMap<&'static str, JsString> = {
+    static CONSTANTS: FxHashMap<&'static [u16], JsString> = {
         let mut constants = FxHashMap::with_capacity_and_hasher(
             CONSTANTS_ARRAY.len(),
             BuildHasherDefault::<FxHasher>::default(),
@@ -505,7 +549,7 @@ thread_local! {
 /// The inner representation of a [`JsString`].
 #[repr(C)]
 struct Inner {
-    /// The utf8 length, the number of bytes.
+    /// The utf16 length, the number of code units.
     len: usize,
 
     /// The number of references to the string.
@@ -513,19 +557,36 @@ struct Inner {
     /// When this reaches `0` the string is deallocated.
     refcount: Cell<usize>,
 
+    /// The pieces of the string if this is a rope node, `None` if the code units are stored in
+    /// `data`.
+    ///
+    /// Rope nodes have no string data of their own. Their state only ever changes from
+    /// [`Rope::Pending`] to [`Rope::Flat`], so a slice returned by [`Inner::as_slice`] stays
+    /// valid for as long as the `Inner` is alive.
+    rope: UnsafeCell<Option<Box<Rope>>>,
+
     /// An empty array which is used to get the offset of string data.
-    data: [u8; 0],
+    data: [u16; 0],
+}
+
+/// The state of a rope node, the lazy result of concatenating two strings.
+enum Rope {
+    /// The concatenation has not been done yet.
+    Pending(JsString, JsString),
+
+    /// The concatenation has been done, and the result is stored in a flat string.
+    Flat(JsString),
 }
 
 impl Inner {
-    /// Create a new `Inner` from `&str`.
+    /// Create a new `Inner` from `&[u16]`.
     #[inline]
-    fn new(s: &str) -> NonNull<Self> {
+    fn new(s: &[u16]) -> NonNull<Self> {
         // We get the layout of the `Inner` type and we extend by the size
         // of the string array.
         let inner_layout = Layout::new::<Self>();
         let (layout, offset) = inner_layout
-            .extend(Layout::array::<u8>(s.len()).expect("failed to create memory layout"))
+            .extend(Layout::array::<u16>(s.len()).expect("failed to create memory layout"))
             .expect("failed to extend memory layout");
 
         let inner = unsafe {
@@ -535,13 +596,14 @@ impl Inner {
             inner.write(Self {
                 len: s.len(),
                 refcount: Cell::new(1),
+                rope: UnsafeCell::new(None),
                 data: [0; 0],
             });
 
             // Get offset into the string data.
             let data = (*inner).data.as_mut_ptr();
 
-            debug_assert!(std::ptr::eq(inner.cast::<u8>().add(offset), data));
+            debug_assert!(std::ptr::eq(inner.cast::<u8>().add(offset).cast(), data));
 
             // Copy string data into data offset.
             copy_nonoverlapping(s.as_ptr(), data, s.len());
@@ -553,9 +615,31 @@ impl Inner {
         unsafe { NonNull::new_unchecked(inner) }
     }
 
+    /// Create a new rope node `Inner` for the concatenation of `left` and `right`.
+    #[inline]
+    fn new_rope(left: JsString, right: JsString) -> NonNull<Self> {
+        let layout = Layout::new::<Self>();
+
+        let inner = unsafe {
+            let inner = try_alloc(layout).cast::<Self>();
+
+            inner.write(Self {
+                len: left.len() + right.len(),
+                refcount: Cell::new(1),
+                rope: UnsafeCell::new(Some(Box::new(Rope::Pending(left, right)))),
+                data: [0; 0],
+            });
+
+            inner
+        };
+
+        // Safety: We already know it's not null, so this is safe.
+        unsafe { NonNull::new_unchecked(inner) }
+    }
+
     /// Concatenate array of strings.
     #[inline]
-    fn concat_array(strings: &[&str]) -> NonNull<Self> {
+    fn concat_array(strings: &[&[u16]]) -> NonNull<Self> {
         let mut total_string_size = 0;
         for string in strings {
             total_string_size += string.len();
@@ -565,7 +649,9 @@ impl Inner {
         // of the string array.
         let inner_layout = Layout::new::<Self>();
         let (layout, offset) = inner_layout
-            .extend(Layout::array::<u8>(total_string_size).expect("failed to create memory layout"))
+            .extend(
+                Layout::array::<u16>(total_string_size).expect("failed to create memory layout"),
+            )
             .expect("failed to extend memory layout");
 
         let inner = unsafe {
@@ -575,13 +661,14 @@ impl Inner {
             inner.write(Self {
                 len: total_string_size,
                 refcount: Cell::new(1),
+                rope: UnsafeCell::new(None),
                 data: [0; 0],
             });
 
             // Get offset into the string data.
             let data = (*inner).data.as_mut_ptr();
 
-            debug_assert!(std::ptr::eq(inner.cast::<u8>().add(offset), data));
+            debug_assert!(std::ptr::eq(inner.cast::<u8>().add(offset).cast(), data));
 
             // Copy the two string data into data offset.
             let mut offset = 0;
@@ -601,27 +688,98 @@ impl Inner {
     #[inline]
     unsafe fn dealloc(x: NonNull<Self>) {
         let len = (*x.as_ptr()).len;
+        let rope = (*(*x.as_ptr()).rope.get()).take();
+
+        // Rope nodes don't store any string data.
+        let capacity = if rope.is_some() { 0 } else { len };
 
         let inner_layout = Layout::new::<Self>();
         let (layout, _offset) = inner_layout
-            .extend(Layout::array::<u8>(len).expect("failed to create memory layout"))
+            .extend(Layout::array::<u16>(capacity).expect("failed to create memory layout"))
             .expect("failed to extend memory layout");
 
         dealloc(x.as_ptr().cast::<_>(), layout);
+        boa_gc::untrack_external_memory(layout.size());
+
+        if let Some(rope) = rope {
+            (*rope).release();
+        }
     }
 
     #[inline]
-    fn as_str(&self) -> &str {
-        unsafe {
-            let slice = std::slice::from_raw_parts(self.data.as_ptr(), self.len);
-            std::str::from_utf8_unchecked(slice)
+    fn as_slice(&self) -> &[u16] {
+        // Safety: The rope state is only replaced below, while no reference into it is alive.
+        let flat = match unsafe { &*self.rope.get() } {
+            None => return unsafe { std::slice::from_raw_parts(self.data.as_ptr(), self.len) },
+            Some(rope) => match &**rope {
+                Rope::Flat(flat) => return flat.as_slice(),
+                Rope::Pending(left, right) => Rope::flatten(left, right),
+            },
+        };
+
+        // Safety: The references into the pending rope are no longer used, and the new
+        // `Rope::Flat` state is never replaced, so the returned slice lives as long as `self`.
+        let pending = unsafe {
+            let rope = (*self.rope.get())
+                .as_mut()
+                .expect("rope nodes never become flat strings");
+            std::mem::replace(&mut **rope, Rope::Flat(flat))
+        };
+        pending.release();
+
+        match unsafe { &*self.rope.get() }.as_deref() {
+            Some(Rope::Flat(flat)) => flat.as_slice(),
+            _ => unreachable!("the rope was just flattened"),
+        }
+    }
+}
+
+impl Rope {
+    /// Copies the code units of all the pieces of a pending rope into a new flat string.
+    ///
+    /// The tree is walked with an explicit stack, because ropes built in a loop are as deep as
+    /// the number of iterations.
+    fn flatten(left: &JsString, right: &JsString) -> JsString {
+        let mut pieces = Vec::new();
+        let mut stack = vec![right, left];
+        while let Some(string) = stack.pop() {
+            match string.rope() {
+                Some(Self::Pending(left, right)) => {
+                    stack.push(right);
+                    stack.push(left);
+                }
+                _ => pieces.push(string.as_slice()),
+            }
+        }
+
+        JsString::concat_array(&pieces)
+    }
+
+    /// Drops the pieces of the rope without recursing into the rope nodes that are not
+    /// referenced anywhere else, to avoid overflowing the stack on deep ropes.
+    fn release(self) {
+        let mut stack = match self {
+            Self::Pending(left, right) => vec![left, right],
+            Self::Flat(_) => return,
+        };
+
+        while let Some(string) = stack.pop() {
+            if let Some((left, right)) = string.take_unique_pending_rope() {
+                stack.push(left);
+                stack.push(right);
+            }
         }
     }
 }
 
 /// This represents a JavaScript primitive string.
 ///
-/// This is similar to `Rc<str>`. But unlike `Rc<str>` which stores the length
+/// A JavaScript string is a sequence of UTF-16 code units, which may contain unpaired
+/// surrogates, so it dereferences to a `[u16]` instead of a `str`. Use
+/// [`JsString::to_std_string`] or [`JsString::to_std_string_escaped`] to convert it to a
+/// Rust [`String`], and [`JsString::code_points`] to iterate over its code points.
+///
+/// This is similar to `Rc<[u16]>`. But unlike `Rc<[u16]>` which stores the length
 /// on the stack and a pointer to the data (this is also known as fat pointers).
 /// The `JsString` length and data is stored on the heap. and just an non-null
 /// pointer is kept, so its size is the size of a pointer.
@@ -629,10 +787,16 @@ impl Inner {
 /// We define some commonly used string constants in an interner. For these
 /// strings, we no longer allocate memory on the heap to reduce the overhead of
 /// memory allocation and reference counting.
+///
+/// Strings created by [`JsString::concat_rope`] are ropes, which only keep references to the
+/// two concatenated strings. Their code units are copied into a single buffer the first time
+/// they are accessed as a slice, for example when the string is indexed or passed to a
+/// native function.
 #[derive(Finalize)]
+#[repr(transparent)]
 pub struct JsString {
     inner: TaggedInner,
-    _marker: PhantomData<Rc<str>>,
+    _marker: PhantomData<Rc<[u16]>>,
 }
 
 // Safety: JsString does not contain any objects which needs to be traced,
@@ -655,6 +819,11 @@ unsafe impl Trace for JsString {
 /// It uses [`NonNull`], which guarantees that `TaggedInner` (and subsequently [`JsString`])
 /// can use the "null pointer optimization" to optimize the size of [`Option<TaggedInner>`].
 ///
+/// On 64-bit platforms, a [`JsString`] stored inside a [`JsValue`][crate::JsValue] is accessed
+/// in place, with the type tag of the value in the 16 most significant bits of the pointer.
+/// Those bits are never part of a valid address, so they are masked out with [`ADDRESS_MASK`]
+/// before reading the pointer.
+///
 /// # Provenance
 ///
 /// This struct stores a [`NonNull<Inner>`] instead of a [`NonZeroUsize`][std::num::NonZeroUsize]
@@ -664,9 +833,17 @@ unsafe impl Trace for JsString {
 ///
 /// [tagged_wp]: https://en.wikipedia.org/wiki/Tagged_pointer
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Debug, Copy, Clone)]
 struct TaggedInner(NonNull<Inner>);
 
+/// The bits of a [`TaggedInner`] that belong to the address or the index it stores.
+#[cfg(target_pointer_width = "64")]
+const ADDRESS_MASK: usize = 0x0000_FFFF_FFFF_FFFF;
+
+/// The bits of a [`TaggedInner`] that belong to the address or the index it stores.
+#[cfg(not(target_pointer_width = "64"))]
+const ADDRESS_MASK: usize = usize::MAX;
+
 impl TaggedInner {
     #[inline]
     unsafe fn new_heap(inner: NonNull<Inner>) -> Self {
@@ -681,6 +858,17 @@ impl TaggedInner {
         Self(NonNull::new_unchecked(((idx << 1) | 1) as *mut _))
     }
 
+    /// Returns the pointer without the bits outside of [`ADDRESS_MASK`].
+    #[inline]
+    fn address(self) -> NonNull<Inner> {
+        let ptr = self.0.as_ptr().cast::<u8>();
+        let tag = ptr as usize & !ADDRESS_MASK;
+
+        // Safety: Only the tag bits are removed, and the remaining address or index is never zero.
+        // `wrapping_sub` is used to preserve the provenance of heap pointers.
+        unsafe { NonNull::new_unchecked(ptr.wrapping_sub(tag).cast()) }
+    }
+
     /// Check if `TaggedInner` contains an index for [`CONSTANTS_ARRAY`].
     #[inline]
     fn is_static(self) -> bool {
@@ -694,8 +882,8 @@ impl TaggedInner {
     ///
     /// Calling this method with a static `TaggedInner` results in Undefined Behaviour.
     #[inline]
-    const unsafe fn get_heap_unchecked(self) -> NonNull<Inner> {
-        self.0
+    unsafe fn get_heap_unchecked(self) -> NonNull<Inner> {
+        self.address()
     }
 
     /// Returns the string inside [`CONSTANTS_ARRAY`] corresponding to the
@@ -707,9 +895,9 @@ impl TaggedInner {
     /// for [`CONSTANTS_ARRAY`] or a valid pointer to a heap allocated [`Inner`]
     /// results in Undefined Behaviour.
     #[inline]
-    unsafe fn get_static_unchecked(self) -> &'static str {
+    unsafe fn get_static_unchecked(self) -> &'static [u16] {
         // shift right to get the index.
-        CONSTANTS_ARRAY.get_unchecked((self.0.as_ptr() as usize) >> 1)
+        CONSTANTS_ARRAY.get_unchecked((self.address().as_ptr() as usize) >> 1)
     }
 }
 
@@ -722,12 +910,65 @@ impl Default for JsString {
 }
 
 /// Enum representing either a reference to a heap allocated [`Inner`]
-/// or a static reference to a [`str`] inside [`CONSTANTS_ARRAY`].
+/// or a static reference to a `[u16]` inside [`CONSTANTS_ARRAY`].
 enum InnerKind<'a> {
     // A string allocated on the heap.
     Heap(&'a Inner),
     // A static string slice.
-    Static(&'static str),
+    Static(&'static [u16]),
+}
+
+/// Represents a Unicode code point within a [`JsString`], which could be a valid
+/// Unicode code point or an unpaired surrogate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CodePoint {
+    /// A valid Unicode scalar value.
+    Unicode(char),
+
+    /// A surrogate code unit that is not part of a surrogate pair.
+    UnpairedSurrogate(u16),
+}
+
+impl CodePoint {
+    /// Returns the number of UTF-16 code units needed to encode this code point.
+    #[inline]
+    pub fn code_unit_count(self) -> usize {
+        match self {
+            Self::Unicode(c) => c.len_utf16(),
+            Self::UnpairedSurrogate(_) => 1,
+        }
+    }
+
+    /// Returns the numeric value of this code point.
+    #[inline]
+    pub fn as_u32(self) -> u32 {
+        match self {
+            Self::Unicode(c) => u32::from(c),
+            Self::UnpairedSurrogate(surr) => u32::from(surr),
+        }
+    }
+
+    /// Returns the code point as a [`char`], or `None` if it is an unpaired surrogate.
+    #[inline]
+    pub fn as_char(self) -> Option<char> {
+        match self {
+            Self::Unicode(c) => Some(c),
+            Self::UnpairedSurrogate(_) => None,
+        }
+    }
+
+    /// Encodes this code point as UTF-16 into the provided buffer, and then returns the
+    /// subslice of the buffer that contains the encoded code point.
+    #[inline]
+    pub fn encode_utf16(self, dst: &mut [u16]) -> &mut [u16] {
+        match self {
+            Self::Unicode(c) => c.encode_utf16(dst),
+            Self::UnpairedSurrogate(surr) => {
+                dst[0] = surr;
+                &mut dst[0..=0]
+            }
+        }
+    }
 }
 
 impl JsString {
@@ -746,11 +987,16 @@ impl JsString {
         Self::default()
     }
 
-    /// Create a new JavaScript string.
+    /// Create a new JavaScript string from a Rust string, encoding it as UTF-16.
     #[inline]
     pub fn new<S: AsRef<str>>(s: S) -> Self {
-        let s = s.as_ref();
+        let s: Vec<u16> = s.as_ref().encode_utf16().collect();
+        Self::from_slice(&s)
+    }
 
+    /// Create a new JavaScript string from a slice of UTF-16 code units.
+    #[inline]
+    pub fn from_slice(s: &[u16]) -> Self {
         if s.len() <= MAX_CONSTANT_STRING_LENGTH {
             if let Some(constant) = CONSTANTS.with(|c| c.get(s).cloned()) {
                 return constant;
@@ -765,16 +1011,9 @@ impl JsString {
     }
 
     /// Concatenate two string.
-    pub fn concat<T, U>(x: T, y: U) -> Self
-    where
-        T: AsRef<str>,
-        U: AsRef<str>,
-    {
-        let x = x.as_ref();
-        let y = y.as_ref();
-
+    pub fn concat(x: &[u16], y: &[u16]) -> Self {
         let inner = Inner::concat_array(&[x, y]);
-        let s = unsafe { inner.as_ref() }.as_str();
+        let s = unsafe { inner.as_ref() }.as_slice();
 
         if s.len() <= MAX_CONSTANT_STRING_LENGTH {
             if let Some(constant) = CONSTANTS.with(|c| c.get(s).cloned()) {
@@ -791,9 +1030,9 @@ impl JsString {
     }
 
     /// Concatenate array of string.
-    pub fn concat_array(strings: &[&str]) -> Self {
+    pub fn concat_array(strings: &[&[u16]]) -> Self {
         let inner = Inner::concat_array(strings);
-        let s = unsafe { inner.as_ref() }.as_str();
+        let s = unsafe { inner.as_ref() }.as_slice();
 
         if s.len() <= MAX_CONSTANT_STRING_LENGTH {
             if let Some(constant) = CONSTANTS.with(|c| c.get(s).cloned()) {
@@ -809,6 +1048,78 @@ impl JsString {
         }
     }
 
+    /// Concatenate two strings without copying their code units.
+    ///
+    /// The result is a rope that keeps a reference to `x` and `y`, and is flattened into a
+    /// single buffer on its first access as a slice. This makes building a string by
+    /// repeatedly appending to it linear instead of quadratic.
+    pub fn concat_rope(x: &Self, y: &Self) -> Self {
+        if x.is_empty() {
+            return y.clone();
+        }
+        if y.is_empty() {
+            return x.clone();
+        }
+        if x.len() + y.len() < ROPE_MIN_LENGTH {
+            return Self::concat(x, y);
+        }
+
+        Self {
+            // Safety: We already know it's a valid heap pointer.
+            inner: unsafe { TaggedInner::new_heap(Inner::new_rope(x.clone(), y.clone())) },
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of UTF-16 code units of the string.
+    ///
+    /// Unlike the length of [`JsString::as_slice`], this doesn't flatten ropes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        match self.inner() {
+            InnerKind::Heap(inner) => inner.len,
+            InnerKind::Static(inner) => inner.len(),
+        }
+    }
+
+    /// Returns `true` if the string has a length of zero code units.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the rope state of the string, or `None` if it is not a rope.
+    #[inline]
+    fn rope(&self) -> Option<&Rope> {
+        match self.inner() {
+            // Safety: The rope state is only replaced while flattening, which never happens
+            // while a reference returned by this method is alive.
+            InnerKind::Heap(inner) => unsafe { &*inner.rope.get() }.as_deref(),
+            InnerKind::Static(_) => None,
+        }
+    }
+
+    /// Takes the pieces out of a pending rope which is only referenced by `self`, leaving it
+    /// without pieces, so it can be dropped without recursion.
+    #[inline]
+    fn take_unique_pending_rope(&self) -> Option<(Self, Self)> {
+        let inner = match self.inner() {
+            InnerKind::Heap(inner) if inner.refcount.get() == 1 => inner,
+            _ => return None,
+        };
+
+        // Safety: There are no other references to this string, so there are no references
+        // into its rope state.
+        let rope = unsafe { &mut *inner.rope.get() }.as_deref_mut()?;
+        if !matches!(rope, Rope::Pending(..)) {
+            return None;
+        }
+        match std::mem::replace(rope, Rope::Flat(Self::empty())) {
+            Rope::Pending(left, right) => Some((left, right)),
+            Rope::Flat(_) => unreachable!("the rope state was checked above"),
+        }
+    }
+
     /// Return the inner representation.
     #[inline]
     fn inner(&self) -> InnerKind<'_> {
@@ -822,15 +1133,103 @@ impl JsString {
         }
     }
 
-    /// Return the JavaScript string as a rust `&str`.
+    /// Return the JavaScript string as a slice of UTF-16 code units.
     #[inline]
-    pub fn as_str(&self) -> &str {
+    pub fn as_slice(&self) -> &[u16] {
         match self.inner() {
-            InnerKind::Heap(inner) => inner.as_str(),
+            InnerKind::Heap(inner) => inner.as_slice(),
             InnerKind::Static(inner) => inner,
         }
     }
 
+    /// Decodes the JavaScript string into a Rust `String`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string contains unpaired surrogates.
+    #[inline]
+    pub fn to_std_string(&self) -> Result<String, FromUtf16Error> {
+        String::from_utf16(self)
+    }
+
+    /// Decodes the JavaScript string into a Rust `String`, replacing every unpaired
+    /// surrogate with its escape sequence (`\uXXXX`).
+    pub fn to_std_string_escaped(&self) -> String {
+        let mut string = String::with_capacity(self.len());
+        for code_point in self.code_points() {
+            match code_point {
+                CodePoint::Unicode(c) => string.push(c),
+                CodePoint::UnpairedSurrogate(surr) => {
+                    write!(string, "\\u{surr:04X}").expect("writing to a `String` cannot fail");
+                }
+            }
+        }
+        string
+    }
+
+    /// Encodes the JavaScript string as [WTF-8], which is UTF-8 extended to encode unpaired
+    /// surrogates like any other code point.
+    ///
+    /// Unlike [`JsString::to_std_string_escaped`], this keeps every code unit of the string, so
+    /// it can be used to pass the string as source text to the parser.
+    ///
+    /// [WTF-8]: https://simonsapin.github.io/wtf-8/
+    pub fn to_wtf8(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.len());
+        for code_point in self.code_points() {
+            match code_point {
+                CodePoint::Unicode(c) => {
+                    bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                }
+                CodePoint::UnpairedSurrogate(surr) => bytes.extend_from_slice(&[
+                    0xE0 | (surr >> 12) as u8,
+                    0x80 | (surr >> 6 & 0x3F) as u8,
+                    0x80 | (surr & 0x3F) as u8,
+                ]),
+            }
+        }
+        bytes
+    }
+
+    /// Gets an iterator over the code points of the string, as described by the
+    /// [`CodePointAt`][spec] abstract operation.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-codepointat
+    #[inline]
+    pub fn code_points(&self) -> impl Iterator<Item = CodePoint> + Clone + '_ {
+        decode_utf16(self.iter().copied()).map(|result| match result {
+            Ok(c) => CodePoint::Unicode(c),
+            Err(e) => CodePoint::UnpairedSurrogate(e.unpaired_surrogate()),
+        })
+    }
+
+    /// Returns `true` if the string doesn't contain unpaired surrogates.
+    #[inline]
+    pub fn is_well_formed(&self) -> bool {
+        decode_utf16(self.iter().copied()).all(|result| result.is_ok())
+    }
+
+    /// Returns the string without its leading and trailing whitespace, as defined by
+    /// [`is_trimmable_whitespace`].
+    #[inline]
+    pub(crate) fn trim(&self) -> &[u16] {
+        trim_end(trim_start(self))
+    }
+
+    /// Returns the string without its leading whitespace, as defined by
+    /// [`is_trimmable_whitespace`].
+    #[inline]
+    pub(crate) fn trim_start(&self) -> &[u16] {
+        trim_start(self)
+    }
+
+    /// Returns the string without its trailing whitespace, as defined by
+    /// [`is_trimmable_whitespace`].
+    #[inline]
+    pub(crate) fn trim_end(&self) -> &[u16] {
+        trim_end(self)
+    }
+
     /// Gets the number of `JsString`s which point to this allocation.
     #[inline]
     pub fn refcount(this: &Self) -> Option<usize> {
@@ -845,7 +1244,7 @@ impl JsString {
     /// [`ptr::eq`]: std::ptr::eq
     #[inline]
     pub fn ptr_eq(x: &Self, y: &Self) -> bool {
-        x.inner == y.inner
+        x.inner.address() == y.inner.address()
     }
 
     /// `6.1.4.1 StringIndexOf ( string, searchValue, fromIndex )`
@@ -857,49 +1256,49 @@ impl JsString {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-stringindexof
-    pub(crate) fn index_of(&self, search_value: &Self, from_index: usize) -> Option<usize> {
+    pub(crate) fn index_of(&self, search_value: &[u16], from_index: usize) -> Option<usize> {
         // 1. Assert: Type(string) is String.
         // 2. Assert: Type(searchValue) is String.
         // 3. Assert: fromIndex is a non-negative integer.
 
         // 4. Let len be the length of string.
-        let len = self.encode_utf16().count();
+        let len = self.len();
 
         // 5. If searchValue is the empty String and fromIndex ≤ len, return fromIndex.
-        if search_value.is_empty() && from_index <= len {
-            return Some(from_index);
+        if search_value.is_empty() {
+            return (from_index <= len).then_some(from_index);
         }
 
         // 6. Let searchLen be the length of searchValue.
-        let search_len = search_value.encode_utf16().count();
-
         // 7. For each integer i starting with fromIndex such that i ≤ len - searchLen, in ascending order, do
-        for i in from_index..=len {
-            if i as isize > (len as isize - search_len as isize) {
-                break;
-            }
-
-            // a. Let candidate be the substring of string from i to i + searchLen.
-            let candidate = String::from_utf16_lossy(
-                &self
-                    .encode_utf16()
-                    .skip(i)
-                    .take(search_len)
-                    .collect::<Vec<u16>>(),
-            );
-
-            // b. If candidate is the same sequence of code units as searchValue, return i.
-            if candidate == search_value.as_str() {
-                return Some(i);
-            }
-        }
-
+        //     a. Let candidate be the substring of string from i to i + searchLen.
+        //     b. If candidate is the same sequence of code units as searchValue, return i.
         // 8. Return -1.
-        None
+        self.get(from_index..)?
+            .windows(search_value.len())
+            .position(|candidate| candidate == search_value)
+            .map(|i| i + from_index)
+    }
+
+    /// Abstract operation `StringToBigInt ( str )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-stringtobigint
+    pub(crate) fn to_big_int(&self) -> Option<JsBigInt> {
+        // Strings with unpaired surrogates can never be valid numeric literals.
+        JsBigInt::from_string(&self.to_std_string().ok()?)
     }
 
     pub(crate) fn string_to_number(&self) -> f64 {
-        let string = self.trim_matches(is_trimmable_whitespace);
+        // Strings with unpaired surrogates can never be valid numeric literals.
+        let string = if let Ok(string) = String::from_utf16(self.trim()) {
+            string
+        } else {
+            return f64::NAN;
+        };
+        let string = string.as_str();
 
         match string {
             "" => return 0.0,
@@ -955,12 +1354,39 @@ impl Clone for JsString {
             inner.refcount.set(inner.refcount.get() + 1);
         }
         Self {
-            inner: self.inner,
+            inner: TaggedInner(self.inner.address()),
             _marker: PhantomData,
         }
     }
 }
 
+/// Checks if the code unit is a trimmable whitespace, as defined by
+/// [`is_trimmable_whitespace`].
+#[inline]
+fn is_trimmable_code_unit(unit: u16) -> bool {
+    char::from_u32(u32::from(unit)).map_or(false, is_trimmable_whitespace)
+}
+
+/// Returns the string without its leading whitespace.
+#[inline]
+pub(crate) fn trim_start(s: &[u16]) -> &[u16] {
+    let start = s
+        .iter()
+        .position(|&unit| !is_trimmable_code_unit(unit))
+        .unwrap_or(s.len());
+    &s[start..]
+}
+
+/// Returns the string without its trailing whitespace.
+#[inline]
+pub(crate) fn trim_end(s: &[u16]) -> &[u16] {
+    let end = s
+        .iter()
+        .rposition(|&unit| !is_trimmable_code_unit(unit))
+        .map_or(0, |i| i + 1);
+    &s[..end]
+}
+
 impl Drop for JsString {
     #[inline]
     fn drop(&mut self) {
@@ -981,14 +1407,15 @@ impl Drop for JsString {
 impl std::fmt::Debug for JsString {
     #[inline]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.as_str().fmt(f)
+        self.to_std_string_escaped().fmt(f)
     }
 }
 
+/// Displays the string, with every unpaired surrogate replaced by its escape sequence.
 impl std::fmt::Display for JsString {
     #[inline]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.as_str().fmt(f)
+        self.to_std_string_escaped().fmt(f)
     }
 }
 
@@ -1013,26 +1440,40 @@ impl From<String> for JsString {
     }
 }
 
-impl AsRef<str> for JsString {
+impl From<&[u16]> for JsString {
+    #[inline]
+    fn from(s: &[u16]) -> Self {
+        Self::from_slice(s)
+    }
+}
+
+impl From<Vec<u16>> for JsString {
+    #[inline]
+    fn from(s: Vec<u16>) -> Self {
+        Self::from_slice(&s)
+    }
+}
+
+impl AsRef<[u16]> for JsString {
     #[inline]
-    fn as_ref(&self) -> &str {
-        self.as_str()
+    fn as_ref(&self) -> &[u16] {
+        self.as_slice()
     }
 }
 
-impl Borrow<str> for JsString {
+impl Borrow<[u16]> for JsString {
     #[inline]
-    fn borrow(&self) -> &str {
-        self.as_str()
+    fn borrow(&self) -> &[u16] {
+        self.as_slice()
     }
 }
 
 impl Deref for JsString {
-    type Target = str;
+    type Target = [u16];
 
     #[inline]
     fn deref(&self) -> &Self::Target {
-        self.as_str()
+        self.as_slice()
     }
 }
 
@@ -1044,7 +1485,7 @@ impl PartialEq<Self> for JsString {
             return true;
         }
 
-        self.as_str() == other.as_str()
+        self.as_slice() == other.as_slice()
     }
 }
 
@@ -1053,55 +1494,72 @@ impl Eq for JsString {}
 impl Hash for JsString {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.as_str().hash(state);
+        self.as_slice().hash(state);
     }
 }
 
+/// Strings are ordered by their code units, as described in [`IsLessThan`][spec].
+///
+/// [spec]: https://tc39.es/ecma262/#sec-islessthan
 impl PartialOrd for JsString {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.as_str().partial_cmp(other.as_str())
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for JsString {
     #[inline]
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.as_str().cmp(other)
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl PartialEq<[u16]> for JsString {
+    #[inline]
+    fn eq(&self, other: &[u16]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl PartialEq<JsString> for [u16] {
+    #[inline]
+    fn eq(&self, other: &JsString) -> bool {
+        self == other.as_slice()
     }
 }
 
 impl PartialEq<str> for JsString {
     #[inline]
     fn eq(&self, other: &str) -> bool {
-        self.as_str() == other
+        self.iter().copied().eq(other.encode_utf16())
     }
 }
 
 impl PartialEq<JsString> for str {
     #[inline]
     fn eq(&self, other: &JsString) -> bool {
-        self == other.as_str()
+        other == self
     }
 }
 
 impl PartialEq<&str> for JsString {
     #[inline]
     fn eq(&self, other: &&str) -> bool {
-        self.as_str() == *other
+        self == *other
     }
 }
 
 impl PartialEq<JsString> for &str {
     #[inline]
     fn eq(&self, other: &JsString) -> bool {
-        *self == other.as_str()
+        other == *self
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::JsString;
+    use super::{CodePoint, JsString};
     use std::mem::size_of;
 
     #[test]
@@ -1178,11 +1636,56 @@ mod tests {
     }
 
     #[test]
-    fn as_str() {
+    fn to_std_string() {
         let s = "Hello";
         let x = JsString::new(s);
 
-        assert_eq!(x.as_str(), s);
+        assert_eq!(x.to_std_string().unwrap(), s);
+        assert_eq!(x.len(), 5);
+
+        let x = JsString::from(&[0x0048, 0xD800, 0x0069][..]);
+        assert!(x.to_std_string().is_err());
+        assert_eq!(x.to_std_string_escaped(), "H\\uD800i");
+    }
+
+    #[test]
+    fn to_wtf8() {
+        let x = JsString::from(&[0x0048, 0xD83D, 0xDE00, 0xDFFF, 0xD800][..]);
+        assert_eq!(
+            x.to_wtf8(),
+            [0x48, 0xF0, 0x9F, 0x98, 0x80, 0xED, 0xBF, 0xBF, 0xED, 0xA0, 0x80]
+        );
+    }
+
+    #[test]
+    fn code_points() {
+        let x = JsString::from(&[0x0061, 0xD83D, 0xDE00, 0xDC00, 0xD800][..]);
+
+        assert_eq!(x.len(), 5);
+        assert!(!x.is_well_formed());
+        assert_eq!(
+            x.code_points().collect::<Vec<_>>(),
+            [
+                CodePoint::Unicode('a'),
+                CodePoint::Unicode('😀'),
+                CodePoint::UnpairedSurrogate(0xDC00),
+                CodePoint::UnpairedSurrogate(0xD800),
+            ]
+        );
+
+        assert!(JsString::new("a😀b").is_well_formed());
+    }
+
+    #[test]
+    fn index_of() {
+        let x = JsString::new("a😀b😀");
+
+        assert_eq!(x.index_of(utf16!("b"), 0), Some(3));
+        assert_eq!(x.index_of(&[0xDE00], 2), Some(2));
+        assert_eq!(x.index_of(&[0xDE00], 3), Some(5));
+        assert_eq!(x.index_of(utf16!(""), 6), Some(6));
+        assert_eq!(x.index_of(utf16!(""), 7), None);
+        assert_eq!(x.index_of(utf16!("c"), 0), None);
     }
 
     #[test]
@@ -1190,10 +1693,10 @@ mod tests {
         use std::collections::hash_map::DefaultHasher;
         use std::hash::{Hash, Hasher};
 
-        let s = "Hello, world!";
-        let x = JsString::new(s);
+        let s = utf16!("Hello, world!");
+        let x = JsString::from(s);
 
-        assert_eq!(x.as_str(), s);
+        assert_eq!(x, "Hello, world!");
 
         let mut hasher = DefaultHasher::new();
         s.hash(&mut hasher);
@@ -1208,20 +1711,64 @@ mod tests {
     #[test]
     fn concat() {
         let x = JsString::new("hello");
-        let y = ", ";
+        let y = utf16!(", ");
         let z = JsString::new("world");
-        let w = String::from("!");
+        let w = JsString::new("!");
 
-        let xy = JsString::concat(x, y);
+        let xy = JsString::concat(&x, y);
         assert_eq!(xy, "hello, ");
         assert_eq!(JsString::refcount(&xy), Some(1));
 
-        let xyz = JsString::concat(xy, z);
+        let xyz = JsString::concat(&xy, &z);
         assert_eq!(xyz, "hello, world");
         assert_eq!(JsString::refcount(&xyz), Some(1));
 
-        let xyzw = JsString::concat(xyz, w);
+        let xyzw = JsString::concat(&xyz, &w);
         assert_eq!(xyzw, "hello, world!");
         assert_eq!(JsString::refcount(&xyzw), Some(1));
     }
+
+    #[test]
+    fn concat_rope() {
+        let x = JsString::new("the quick brown fox ");
+        let y = JsString::new("jumps over the lazy dog");
+
+        let xy = JsString::concat_rope(&x, &y);
+        assert!(xy.rope().is_some());
+        assert_eq!(xy.len(), 43);
+        assert_eq!(JsString::refcount(&x), Some(2));
+        assert_eq!(JsString::refcount(&y), Some(2));
+
+        assert_eq!(xy, "the quick brown fox jumps over the lazy dog");
+
+        // Flattening releases the pieces of the rope.
+        assert_eq!(JsString::refcount(&x), Some(1));
+        assert_eq!(JsString::refcount(&y), Some(1));
+
+        let short = JsString::concat_rope(&JsString::new("a"), &JsString::new("b"));
+        assert!(short.rope().is_none());
+        assert_eq!(short, "ab");
+    }
+
+    #[test]
+    fn deep_rope() {
+        let piece = JsString::new("0123456789abcdef0123456789abcdef");
+
+        let mut string = JsString::empty();
+        for _ in 0..100_000 {
+            string = JsString::concat_rope(&string, &piece);
+        }
+        assert_eq!(string.len(), 3_200_000);
+
+        let shared = string.clone();
+        assert_eq!(&shared[..4], utf16!("0123"));
+        assert_eq!(&string[3_199_996..], utf16!("cdef"));
+        drop(shared);
+
+        let mut string = JsString::empty();
+        for _ in 0..100_000 {
+            string = JsString::concat_rope(&piece, &string);
+        }
+        drop(string);
+    }
 }
@@ -15,13 +15,12 @@
 //! [spec]: https://tc39.es/ecma262/#sec-symbol-value
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol
 
-use crate::JsString;
+use crate::{value::TaggedRc, JsString};
 use boa_gc::{unsafe_empty_trace, Finalize, Trace};
 use std::{
     cell::Cell,
     fmt::{self, Display},
     hash::{Hash, Hasher},
-    rc::Rc,
 };
 
 /// A structure that contains the JavaScript well known symbols.
@@ -31,7 +30,7 @@ use std::{
 ///# use boa_engine::symbol::WellKnownSymbols;
 ///
 /// let iterator = WellKnownSymbols::iterator();
-/// assert_eq!(iterator.description().as_deref(), Some("Symbol.iterator"));
+/// assert_eq!(iterator.description().unwrap(), "Symbol.iterator");
 /// ```
 /// This is equivalent to `let iterator = Symbol.iterator` in JavaScript.
 #[derive(Debug, Clone)]
@@ -249,8 +248,9 @@ struct Inner {
 
 /// This represents a JavaScript symbol primitive.
 #[derive(Debug, Clone, Finalize)]
+#[repr(transparent)]
 pub struct JsSymbol {
-    inner: Rc<Inner>,
+    inner: TaggedRc<Inner>,
 }
 
 // Safety: JsSymbol does not contain any objects which needs to be traced,
@@ -270,7 +270,7 @@ impl JsSymbol {
         });
 
         Self {
-            inner: Rc::new(Inner { hash, description }),
+            inner: TaggedRc::new(Inner { hash, description }),
         }
     }
 
@@ -278,10 +278,19 @@ impl JsSymbol {
     #[inline]
     fn with_hash(hash: u64, description: Option<JsString>) -> Self {
         Self {
-            inner: Rc::new(Inner { hash, description }),
+            inner: TaggedRc::new(Inner { hash, description }),
         }
     }
 
+    /// Returns the symbol with the given hash if the hash is reserved for a well known symbol.
+    ///
+    /// Symbols are compared by their hash, so the returned symbol is the same symbol as the
+    /// well known symbol with the hash.
+    #[inline]
+    pub(crate) fn reserved(hash: u64, description: Option<JsString>) -> Option<Self> {
+        (hash < RESERVED_SYMBOL_HASHES).then(|| Self::with_hash(hash, description))
+    }
+
     /// Returns the `Symbol`s description.
     #[inline]
     pub fn description(&self) -> Option<JsString> {
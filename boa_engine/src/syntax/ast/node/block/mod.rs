@@ -38,6 +38,11 @@ impl Block {
         self.statements.items()
     }
 
+    /// Gets the statement list of this block.
+    pub(crate) fn statement_list(&self) -> &StatementList {
+        &self.statements
+    }
+
     /// Get the lexically declared names of the block.
     pub(crate) fn lexically_declared_names(&self) -> Vec<(Sym, bool)> {
         self.statements.lexically_declared_names()
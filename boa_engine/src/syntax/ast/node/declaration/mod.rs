@@ -180,7 +180,7 @@ impl ToInternedString for Declaration {
 impl Declaration {
     /// Creates a new variable declaration with a `BindingIdentifier`.
     #[inline]
-    pub(in crate::syntax) fn new_with_identifier<N, I>(ident: N, init: I) -> Self
+    pub(crate) fn new_with_identifier<N, I>(ident: N, init: I) -> Self
     where
         N: Into<Identifier>,
         I: Into<Option<Node>>,
@@ -208,10 +208,7 @@ impl Declaration {
 
     /// Creates a new variable declaration with an `ArrayBindingPattern`.
     #[inline]
-    pub(in crate::syntax) fn new_with_array_pattern<I>(
-        bindings: Vec<BindingPatternTypeArray>,
-        init: I,
-    ) -> Self
+    pub(crate) fn new_with_array_pattern<I>(bindings: Vec<BindingPatternTypeArray>, init: I) -> Self
     where
         I: Into<Option<Node>>,
     {
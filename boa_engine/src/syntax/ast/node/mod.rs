@@ -11,6 +11,7 @@ pub mod declaration;
 pub mod field;
 pub mod identifier;
 pub mod iteration;
+pub mod module;
 pub mod new;
 pub mod object;
 pub mod operator;
@@ -39,6 +40,10 @@ pub use self::{
     field::{get_private_field::GetPrivateField, GetConstField, GetField, GetSuperField},
     identifier::Identifier,
     iteration::{Break, Continue, DoWhileLoop, ForInLoop, ForLoop, ForOfLoop, WhileLoop},
+    module::{
+        ExportDeclaration, ExportSpecifier, ImportDeclaration, ImportKind, ImportSpecifier,
+        ModuleItem, ModuleItemList, ReExportKind,
+    },
     new::New,
     object::Object,
     operator::{Assign, BinOp, UnaryOp},
@@ -460,7 +465,7 @@ impl Node {
                     }
                 }
                 if let Some(nodes) = switch.default() {
-                    for node in nodes {
+                    for node in nodes.items() {
                         node.var_declared_names(vars);
                     }
                 }
@@ -1120,7 +1125,7 @@ impl Node {
                     }
                 }
                 if let Some(default) = switch.default() {
-                    for node in default {
+                    for node in default.items() {
                         if node.contains(symbol) {
                             return true;
                         }
@@ -0,0 +1,634 @@
+//! Module item list and import/export declaration nodes.
+//!
+//! More information:
+//!  - [ECMAScript specification][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-modules
+
+use crate::syntax::ast::{
+    node::{Declaration, DeclarationList, Node},
+    Span,
+};
+use boa_interner::{Interner, Sym, ToInternedString};
+use rustc_hash::FxHashSet;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The body of a module.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItemList
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default)]
+pub struct ModuleItemList {
+    items: Box<[ModuleItem]>,
+    #[cfg_attr(feature = "deser", serde(default))]
+    spans: Box<[Span]>,
+}
+
+impl ModuleItemList {
+    /// Gets the list of module items.
+    #[inline]
+    pub fn items(&self) -> &[ModuleItem] {
+        &self.items
+    }
+
+    /// Gets the source spans of the module items.
+    ///
+    /// This is either empty, if the list was not created by the parser, or it contains
+    /// the span of each item in [`items`](Self::items), in the same order.
+    #[inline]
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+
+    /// Sets the source spans of the module items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of spans does not match the number of items.
+    #[inline]
+    #[must_use]
+    pub fn with_spans<S>(mut self, spans: S) -> Self
+    where
+        S: Into<Box<[Span]>>,
+    {
+        let spans = spans.into();
+        assert_eq!(
+            spans.len(),
+            self.items.len(),
+            "there must be one span per module item"
+        );
+        self.spans = spans;
+        self
+    }
+
+    /// Gets the source span of the module item at the given index, if known.
+    #[inline]
+    pub(crate) fn span(&self, index: usize) -> Option<Span> {
+        self.spans.get(index).copied()
+    }
+
+    /// Return the lexically declared names of a `ModuleItemList`.
+    ///
+    /// This includes the local names of all import bindings.
+    /// The returned list may contain duplicates.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-lexicallydeclarednames
+    pub(crate) fn lexically_declared_names(&self) -> Vec<Sym> {
+        let mut names = Vec::new();
+        for item in self.items() {
+            match item {
+                ModuleItem::ImportDeclaration(import) => names.extend(import.bound_names()),
+                ModuleItem::ExportDeclaration(ExportDeclaration::Declaration(node))
+                | ModuleItem::StatementListItem(node) => {
+                    lexically_declared_names(node, &mut names);
+                }
+                ModuleItem::ExportDeclaration(
+                    ExportDeclaration::DefaultDeclaration(node)
+                    | ExportDeclaration::DefaultAssignmentExpression(node),
+                ) => names.push(default_export_local_name(node)),
+                ModuleItem::ExportDeclaration(
+                    ExportDeclaration::ReExport { .. } | ExportDeclaration::List(_),
+                ) => {}
+            }
+        }
+        names
+    }
+
+    /// Return the variable declared names of a `ModuleItemList`.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-vardeclarednames
+    pub(crate) fn var_declared_names(&self, vars: &mut FxHashSet<Sym>) {
+        for item in self.items() {
+            match item {
+                ModuleItem::ExportDeclaration(ExportDeclaration::Declaration(node))
+                | ModuleItem::StatementListItem(node) => node.var_declared_names(vars),
+                _ => {}
+            }
+        }
+    }
+
+    /// Return the names exported by a `ModuleItemList`.
+    ///
+    /// The returned list may contain duplicates.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-exportednames
+    pub(crate) fn exported_names(&self) -> Vec<Sym> {
+        let mut names = Vec::new();
+        for item in self.items() {
+            if let ModuleItem::ExportDeclaration(export) = item {
+                export.exported_names(&mut names);
+            }
+        }
+        names
+    }
+
+    /// Return the module specifiers requested by a `ModuleItemList`, in source text order
+    /// and without duplicates.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-modulerequests
+    pub(crate) fn requests(&self) -> Vec<Sym> {
+        let mut requests = Vec::new();
+        for item in self.items() {
+            let specifier = match item {
+                ModuleItem::ImportDeclaration(import) => import.specifier(),
+                ModuleItem::ExportDeclaration(ExportDeclaration::ReExport {
+                    specifier, ..
+                }) => *specifier,
+                _ => continue,
+            };
+            if !requests.contains(&specifier) {
+                requests.push(specifier);
+            }
+        }
+        requests
+    }
+
+    /// Returns a statement list of all statement list items of the module.
+    ///
+    /// Import declarations and re-exports are skipped, export declarations are
+    /// replaced by the declaration or expression they contain.
+    pub(crate) fn statements(&self) -> Vec<&Node> {
+        let mut statements = Vec::new();
+        for item in self.items() {
+            match item {
+                ModuleItem::StatementListItem(node)
+                | ModuleItem::ExportDeclaration(
+                    ExportDeclaration::Declaration(node)
+                    | ExportDeclaration::DefaultDeclaration(node)
+                    | ExportDeclaration::DefaultAssignmentExpression(node),
+                ) => statements.push(node),
+                _ => {}
+            }
+        }
+        statements
+    }
+}
+
+impl<T> From<T> for ModuleItemList
+where
+    T: Into<Box<[ModuleItem]>>,
+{
+    fn from(items: T) -> Self {
+        Self {
+            items: items.into(),
+            spans: Box::default(),
+        }
+    }
+}
+
+impl PartialEq for ModuleItemList {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
+    }
+}
+
+impl ToInternedString for ModuleItemList {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        let mut buf = String::new();
+        for item in self.items() {
+            buf.push_str(&item.to_interned_string(interner));
+            match item {
+                ModuleItem::StatementListItem(
+                    Node::Block(_) | Node::If(_) | Node::Switch(_) | Node::WhileLoop(_),
+                ) => {}
+                _ => buf.push(';'),
+            }
+            buf.push('\n');
+        }
+        buf
+    }
+}
+
+/// A single item of a module body.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItem
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ModuleItem {
+    /// An `import` declaration.
+    ImportDeclaration(ImportDeclaration),
+
+    /// An `export` declaration.
+    ExportDeclaration(ExportDeclaration),
+
+    /// A statement or declaration, as found in scripts.
+    StatementListItem(Node),
+}
+
+impl ToInternedString for ModuleItem {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        match self {
+            Self::ImportDeclaration(import) => import.to_interned_string(interner),
+            Self::ExportDeclaration(export) => export.to_interned_string(interner),
+            Self::StatementListItem(node) => node.to_interned_string(interner),
+        }
+    }
+}
+
+impl From<ImportDeclaration> for ModuleItem {
+    fn from(import: ImportDeclaration) -> Self {
+        Self::ImportDeclaration(import)
+    }
+}
+
+impl From<ExportDeclaration> for ModuleItem {
+    fn from(export: ExportDeclaration) -> Self {
+        Self::ExportDeclaration(export)
+    }
+}
+
+/// The bindings created by an `import` declaration, besides the default binding.
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ImportKind {
+    /// `import defaultBinding from "module"` or `import "module"`.
+    DefaultOrUnnamed,
+
+    /// `import * as binding from "module"`.
+    Namespaced {
+        /// The local binding of the namespace object.
+        binding: Sym,
+    },
+
+    /// `import { a, b as c } from "module"`.
+    Named {
+        /// The list of named imports.
+        names: Box<[ImportSpecifier]>,
+    },
+}
+
+/// An `import` declaration.
+///
+/// Syntax: `import defaultBinding, { name as binding } from "module";`
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImportDeclaration {
+    default: Option<Sym>,
+    kind: ImportKind,
+    specifier: Sym,
+}
+
+impl ImportDeclaration {
+    /// Creates a new `ImportDeclaration` AST node.
+    #[inline]
+    pub fn new(default: Option<Sym>, kind: ImportKind, specifier: Sym) -> Self {
+        Self {
+            default,
+            kind,
+            specifier,
+        }
+    }
+
+    /// Gets the local binding of the default export, if any.
+    #[inline]
+    pub fn default(&self) -> Option<Sym> {
+        self.default
+    }
+
+    /// Gets the kind of the remaining import bindings.
+    #[inline]
+    pub fn kind(&self) -> &ImportKind {
+        &self.kind
+    }
+
+    /// Gets the module specifier of the imported module.
+    #[inline]
+    pub fn specifier(&self) -> Sym {
+        self.specifier
+    }
+
+    /// Returns the local names bound by this import declaration.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-boundnames
+    pub(crate) fn bound_names(&self) -> Vec<Sym> {
+        let mut names: Vec<_> = self.default.into_iter().collect();
+        match &self.kind {
+            ImportKind::DefaultOrUnnamed => {}
+            ImportKind::Namespaced { binding } => names.push(*binding),
+            ImportKind::Named { names: specifiers } => {
+                names.extend(specifiers.iter().map(ImportSpecifier::binding));
+            }
+        }
+        names
+    }
+}
+
+impl ToInternedString for ImportDeclaration {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        let mut buf = "import ".to_owned();
+        let mut bindings = Vec::new();
+        if let Some(default) = self.default {
+            bindings.push(interner.resolve_expect(default).to_owned());
+        }
+        match &self.kind {
+            ImportKind::DefaultOrUnnamed => {}
+            ImportKind::Namespaced { binding } => {
+                bindings.push(format!("* as {}", interner.resolve_expect(*binding)));
+            }
+            ImportKind::Named { names } => {
+                let names = names
+                    .iter()
+                    .map(|name| name.to_interned_string(interner))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                bindings.push(format!("{{ {names} }}"));
+            }
+        }
+        if !bindings.is_empty() {
+            buf.push_str(&bindings.join(", "));
+            buf.push_str(" from ");
+        }
+        buf.push_str(&format!("\"{}\"", interner.resolve_expect(self.specifier)));
+        buf
+    }
+}
+
+/// A single named import, for example `name as binding`.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportSpecifier
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ImportSpecifier {
+    binding: Sym,
+    export_name: Sym,
+}
+
+impl ImportSpecifier {
+    /// Creates a new `ImportSpecifier` AST node.
+    #[inline]
+    pub fn new(binding: Sym, export_name: Sym) -> Self {
+        Self {
+            binding,
+            export_name,
+        }
+    }
+
+    /// Gets the local binding of the import.
+    #[inline]
+    pub fn binding(&self) -> Sym {
+        self.binding
+    }
+
+    /// Gets the name under which the imported binding is exported by the imported module.
+    #[inline]
+    pub fn export_name(&self) -> Sym {
+        self.export_name
+    }
+}
+
+impl ToInternedString for ImportSpecifier {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        if self.binding == self.export_name {
+            interner.resolve_expect(self.binding).to_owned()
+        } else {
+            format!(
+                "{} as {}",
+                interner.resolve_expect(self.export_name),
+                interner.resolve_expect(self.binding)
+            )
+        }
+    }
+}
+
+/// The names re-exported from another module.
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReExportKind {
+    /// `export * from "module"` or `export * as name from "module"`.
+    Namespaced {
+        /// The name under which the namespace object is exported, if any.
+        name: Option<Sym>,
+    },
+
+    /// `export { a, b as c } from "module"`.
+    Named {
+        /// The list of re-exported names.
+        names: Box<[ExportSpecifier]>,
+    },
+}
+
+/// An `export` declaration.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExportDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/export
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExportDeclaration {
+    /// Re-export of names of another module, for example `export { a } from "module"`.
+    ReExport {
+        /// The re-exported names.
+        kind: ReExportKind,
+        /// The module specifier of the re-exported module.
+        specifier: Sym,
+    },
+
+    /// Export of local bindings, for example `export { a, b as c }`.
+    List(Box<[ExportSpecifier]>),
+
+    /// Export of a variable statement or a declaration, for example `export let a = 1`.
+    Declaration(Node),
+
+    /// `export default` of a hoistable or class declaration.
+    DefaultDeclaration(Node),
+
+    /// `export default` of an assignment expression.
+    DefaultAssignmentExpression(Node),
+}
+
+impl ExportDeclaration {
+    /// Appends the names exported by this declaration to `names`.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-exportednames
+    pub(crate) fn exported_names(&self, names: &mut Vec<Sym>) {
+        match self {
+            Self::ReExport {
+                kind: ReExportKind::Namespaced { name },
+                ..
+            } => names.extend(*name),
+            Self::ReExport {
+                kind: ReExportKind::Named { names: specifiers },
+                ..
+            }
+            | Self::List(specifiers) => {
+                names.extend(specifiers.iter().map(ExportSpecifier::alias));
+            }
+            Self::Declaration(node) => {
+                if let Node::VarDeclList(list) = node {
+                    declaration_list_names(list, names);
+                } else {
+                    lexically_declared_names(node, names);
+                }
+            }
+            Self::DefaultDeclaration(_) | Self::DefaultAssignmentExpression(_) => {
+                names.push(Sym::DEFAULT);
+            }
+        }
+    }
+}
+
+impl ToInternedString for ExportDeclaration {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        let list = |names: &[ExportSpecifier]| {
+            let names = names
+                .iter()
+                .map(|name| name.to_interned_string(interner))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("{{ {names} }}")
+        };
+        match self {
+            Self::ReExport { kind, specifier } => {
+                let names = match kind {
+                    ReExportKind::Namespaced { name: None } => "*".to_owned(),
+                    ReExportKind::Namespaced { name: Some(name) } => {
+                        format!("* as {}", interner.resolve_expect(*name))
+                    }
+                    ReExportKind::Named { names } => list(names),
+                };
+                format!(
+                    "export {names} from \"{}\"",
+                    interner.resolve_expect(*specifier)
+                )
+            }
+            Self::List(names) => format!("export {}", list(names)),
+            Self::Declaration(node) => format!("export {}", node.to_interned_string(interner)),
+            Self::DefaultDeclaration(node) | Self::DefaultAssignmentExpression(node) => {
+                format!("export default {}", node.to_interned_string(interner))
+            }
+        }
+    }
+}
+
+/// A single exported name, for example `binding as name`.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExportSpecifier
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExportSpecifier {
+    alias: Sym,
+    private_name: Sym,
+}
+
+impl ExportSpecifier {
+    /// Creates a new `ExportSpecifier` AST node.
+    #[inline]
+    pub fn new(alias: Sym, private_name: Sym) -> Self {
+        Self {
+            alias,
+            private_name,
+        }
+    }
+
+    /// Gets the name under which the binding is exported.
+    #[inline]
+    pub fn alias(&self) -> Sym {
+        self.alias
+    }
+
+    /// Gets the name of the exported binding, or the export name of the
+    /// re-exported module.
+    #[inline]
+    pub fn private_name(&self) -> Sym {
+        self.private_name
+    }
+}
+
+impl ToInternedString for ExportSpecifier {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        if self.alias == self.private_name {
+            interner.resolve_expect(self.alias).to_owned()
+        } else {
+            format!(
+                "{} as {}",
+                interner.resolve_expect(self.private_name),
+                interner.resolve_expect(self.alias)
+            )
+        }
+    }
+}
+
+/// Returns the local binding name created by an `export default` declaration or expression.
+///
+/// Anonymous default exports are bound to the `default` name, which can never be
+/// referenced by user code because it is a reserved word.
+pub(crate) fn default_export_local_name(node: &Node) -> Sym {
+    match node {
+        Node::FunctionDecl(decl) => decl.name(),
+        Node::GeneratorDecl(decl) => decl.name(),
+        Node::AsyncFunctionDecl(decl) => decl.name(),
+        Node::AsyncGeneratorDecl(decl) => decl.name(),
+        Node::ClassDecl(decl) => decl.name(),
+        _ => Sym::DEFAULT,
+    }
+}
+
+/// Appends the lexically declared names of a single statement list item to `names`.
+fn lexically_declared_names(node: &Node, names: &mut Vec<Sym>) {
+    match node {
+        Node::FunctionDecl(decl) => names.push(decl.name()),
+        Node::GeneratorDecl(decl) => names.push(decl.name()),
+        Node::AsyncFunctionDecl(decl) => names.push(decl.name()),
+        Node::AsyncGeneratorDecl(decl) => names.push(decl.name()),
+        Node::ClassDecl(decl) => names.push(decl.name()),
+        Node::LetDeclList(list) | Node::ConstDeclList(list) => {
+            declaration_list_names(list, names);
+        }
+        _ => {}
+    }
+}
+
+/// Appends the bound names of a declaration list to `names`.
+fn declaration_list_names(list: &DeclarationList, names: &mut Vec<Sym>) {
+    for decl in list.as_ref() {
+        match decl {
+            Declaration::Identifier { ident, .. } => names.push(ident.sym()),
+            Declaration::Pattern(pattern) => names.extend(pattern.idents()),
+        }
+    }
+}
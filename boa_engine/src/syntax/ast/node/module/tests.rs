@@ -0,0 +1,22 @@
+#[test]
+fn fmt() {
+    use crate::{syntax::Parser, Context};
+    use boa_interner::ToInternedString;
+
+    let scenario = r#"import "side-effect";
+import a, { b, c as d } from "mod";
+import * as ns from "mod";
+export { a, d as e };
+export * from "other";
+export * as other from "other";
+export { x as y } from "other";
+export let f = 1;
+export default a + 1;
+"#;
+    let mut context = Context::default();
+    let result = Parser::new(scenario.as_bytes())
+        .parse_module(&mut context)
+        .expect("parsing failed")
+        .to_interned_string(context.interner());
+    assert_eq!(scenario, result);
+}
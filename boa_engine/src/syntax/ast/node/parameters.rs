@@ -246,7 +246,7 @@ pub struct FormalParameter {
 
 impl FormalParameter {
     /// Creates a new formal parameter.
-    pub(in crate::syntax) fn new<D>(declaration: D, is_rest_param: bool) -> Self
+    pub(crate) fn new<D>(declaration: D, is_rest_param: bool) -> Self
     where
         D: Into<Declaration>,
     {
@@ -1,6 +1,9 @@
 //! Statement list node.
 
-use crate::syntax::ast::node::{Declaration, Node};
+use crate::syntax::ast::{
+    node::{Declaration, Node},
+    Span,
+};
 use boa_interner::{Interner, Sym, ToInternedString};
 
 use rustc_hash::FxHashSet;
@@ -19,10 +22,12 @@ mod tests;
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-StatementList
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default)]
 pub struct StatementList {
     items: Box<[Node]>,
     strict: bool,
+    #[cfg_attr(feature = "deser", serde(default))]
+    spans: Box<[Span]>,
 }
 
 impl StatementList {
@@ -32,6 +37,42 @@ impl StatementList {
         &self.items
     }
 
+    /// Gets the source spans of the items.
+    ///
+    /// This is either empty, if the list was not created by the parser, or it contains
+    /// the span of each item in [`items`](Self::items), in the same order.
+    #[inline]
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+
+    /// Sets the source spans of the items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of spans does not match the number of items.
+    #[inline]
+    #[must_use]
+    pub fn with_spans<S>(mut self, spans: S) -> Self
+    where
+        S: Into<Box<[Span]>>,
+    {
+        let spans = spans.into();
+        assert_eq!(
+            spans.len(),
+            self.items.len(),
+            "there must be one span per statement list item"
+        );
+        self.spans = spans;
+        self
+    }
+
+    /// Gets the source span of the item at the given index, if known.
+    #[inline]
+    pub(crate) fn span(&self, index: usize) -> Option<Span> {
+        self.spans.get(index).copied()
+    }
+
     /// Get the strict mode.
     #[inline]
     pub fn strict(&self) -> bool {
@@ -181,10 +222,19 @@ where
         Self {
             items: stm.into(),
             strict: false,
+            spans: Box::default(),
         }
     }
 }
 
+// The spans are not part of the syntax of the list, so two lists parsed from differently
+// formatted source texts are equal.
+impl PartialEq for StatementList {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items && self.strict == other.strict
+    }
+}
+
 impl ToInternedString for StatementList {
     fn to_interned_string(&self, interner: &Interner) -> String {
         self.to_indented_string(interner, 0)
@@ -92,8 +92,8 @@ impl Switch {
     }
 
     /// Gets the default statement list, if any.
-    pub fn default(&self) -> Option<&[Node]> {
-        self.default.as_ref().map(StatementList::items)
+    pub fn default(&self) -> Option<&StatementList> {
+        self.default.as_ref()
     }
 
     /// Implements the display formatting with indentation.
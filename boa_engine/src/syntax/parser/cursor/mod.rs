@@ -30,6 +30,9 @@ pub(super) struct Cursor<R> {
 
     /// Tracks if the cursor is in a arrow function declaration.
     arrow: bool,
+
+    /// The end position of the last token consumed by the cursor.
+    last_token_end: Position,
 }
 
 impl<R> Cursor<R>
@@ -43,6 +46,7 @@ where
             buffered_lexer: Lexer::new(reader).into(),
             private_environments_stack: Vec::new(),
             arrow: false,
+            last_token_end: Position::new(1, 1),
         }
     }
 
@@ -57,7 +61,9 @@ where
         start: Position,
         interner: &mut Interner,
     ) -> Result<Token, ParseError> {
-        self.buffered_lexer.lex_regex(start, interner)
+        let token = self.buffered_lexer.lex_regex(start, interner)?;
+        self.last_token_end = token.span().end();
+        Ok(token)
     }
 
     #[inline]
@@ -66,12 +72,24 @@ where
         start: Position,
         interner: &mut Interner,
     ) -> Result<Token, ParseError> {
-        self.buffered_lexer.lex_template(start, interner)
+        let token = self.buffered_lexer.lex_template(start, interner)?;
+        self.last_token_end = token.span().end();
+        Ok(token)
     }
 
     #[inline]
     pub(super) fn next(&mut self, interner: &mut Interner) -> Result<Option<Token>, ParseError> {
-        self.buffered_lexer.next(true, interner)
+        let token = self.buffered_lexer.next(true, interner)?;
+        if let Some(token) = &token {
+            self.last_token_end = token.span().end();
+        }
+        Ok(token)
+    }
+
+    /// Gets the end position of the last token consumed by the cursor.
+    #[inline]
+    pub(super) fn last_token_end(&self) -> Position {
+        self.last_token_end
     }
 
     #[inline]
@@ -14,7 +14,7 @@ use crate::syntax::{
             declaration::Declaration, ArrowFunctionDecl, FormalParameter, FormalParameterList,
             FormalParameterListFlags, Node, Return, StatementList,
         },
-        Punctuator,
+        Punctuator, Span,
     },
     lexer::{Error as LexError, TokenKind},
     parser::{
@@ -185,23 +185,21 @@ where
         cursor: &mut Cursor<R>,
         interner: &mut Interner,
     ) -> Result<Self::Output, ParseError> {
-        match cursor
-            .peek(0, interner)?
-            .ok_or(ParseError::AbruptEnd)?
-            .kind()
-        {
-            TokenKind::Punctuator(Punctuator::OpenBlock) => {
-                let _next = cursor.next(interner)?;
-                let body = FunctionBody::new(false, false).parse(cursor, interner)?;
-                cursor.expect(Punctuator::CloseBlock, "arrow function", interner)?;
-                Ok(body)
-            }
-            _ => Ok(StatementList::from(vec![Return::new(
-                ExpressionBody::new(self.allow_in, false).parse(cursor, interner)?,
-                None,
-            )
-            .into()])),
+        let token = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+        if token.kind() == &TokenKind::Punctuator(Punctuator::OpenBlock) {
+            let _next = cursor.next(interner)?;
+            let body = FunctionBody::new(false, false).parse(cursor, interner)?;
+            cursor.expect(Punctuator::CloseBlock, "arrow function", interner)?;
+            return Ok(body);
         }
+
+        let start = token.span().start();
+        let body = Return::new(
+            ExpressionBody::new(self.allow_in, false).parse(cursor, interner)?,
+            None,
+        );
+        Ok(StatementList::from(vec![body.into()])
+            .with_spans(vec![Span::new(start, cursor.last_token_end())]))
     }
 }
 
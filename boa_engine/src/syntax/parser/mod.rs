@@ -2,6 +2,7 @@
 
 mod cursor;
 mod expression;
+mod module;
 mod statement;
 
 pub(crate) mod function;
@@ -14,13 +15,14 @@ mod tests;
 use crate::{
     syntax::{
         ast::{
-            node::{ContainsSymbol, FormalParameterList, StatementList},
+            node::{ContainsSymbol, FormalParameterList, ModuleItemList, StatementList},
             Position,
         },
         lexer::TokenKind,
         parser::{
             cursor::Cursor,
             function::{FormalParameters, FunctionStatementList},
+            module::Module,
         },
     },
     Context,
@@ -138,6 +140,17 @@ impl<R> Parser<R> {
         Script::new(false).parse(&mut self.cursor, context)
     }
 
+    /// Parse the full input as an [ECMAScript Module][spec] into the boa AST representation.
+    /// The resulting `ModuleItemList` can be turned into a [`Module`][crate::module::Module] record.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-Module
+    pub fn parse_module(&mut self, context: &mut Context) -> Result<ModuleItemList, ParseError>
+    where
+        R: Read,
+    {
+        Module.parse(&mut self.cursor, context.interner_mut())
+    }
+
     pub(crate) fn parse_eval(
         &mut self,
         direct: bool,
@@ -147,7 +160,7 @@ impl<R> Parser<R> {
         R: Read,
     {
         let (in_function, in_method, in_derived_constructor) = if let Some(function_env) = context
-            .realm
+            .vm
             .environments
             .get_this_environment()
             .as_function_slots()
@@ -313,10 +326,10 @@ impl Script {
                     if !is_function_declaration {
                         let name_str = context.interner().resolve_expect(*name);
                         let desc = context
-                            .realm
-                            .global_property_map
-                            .string_property_map()
-                            .get(name_str);
+                            .global_object()
+                            .borrow()
+                            .properties()
+                            .get(&name_str.into());
                         let non_configurable_binding_exists = match desc {
                             Some(desc) => !matches!(desc.configurable(), Some(true)),
                             None => false,
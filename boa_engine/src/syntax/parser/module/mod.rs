@@ -0,0 +1,604 @@
+//! Module parsing.
+//!
+//! More information:
+//!  - [ECMAScript specification][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-modules
+
+#[cfg(test)]
+mod tests;
+
+use crate::syntax::{
+    ast::{
+        node::{
+            self, ContainsSymbol, ExportDeclaration, ExportSpecifier, ImportDeclaration,
+            ImportKind, ImportSpecifier, ModuleItem, ReExportKind,
+        },
+        Keyword, Position, Punctuator, Span,
+    },
+    lexer::{Token, TokenKind},
+    parser::{
+        expression::{AssignmentExpression, BindingIdentifier},
+        statement::{HoistableDeclaration, StatementListItem},
+        Cursor, ParseError, TokenParser, RESERVED_IDENTIFIERS_STRICT,
+    },
+};
+use boa_interner::{Interner, Sym};
+use boa_profiler::Profiler;
+use rustc_hash::FxHashSet;
+use std::io::Read;
+
+/// Parses a full module.
+///
+/// Module code is always strict mode code.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-Module
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Module;
+
+impl<R> TokenParser<R> for Module
+where
+    R: Read,
+{
+    type Output = node::ModuleItemList;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let _timer = Profiler::global().start_event("Module", "Parsing");
+        cursor.set_strict_mode(true);
+
+        let module = ModuleItemList.parse(cursor, interner)?;
+
+        // It is a Syntax Error if the LexicallyDeclaredNames of ModuleItemList contains any duplicate entries.
+        let mut lexically_declared_names = FxHashSet::default();
+        for name in module.lexically_declared_names() {
+            if !lexically_declared_names.insert(name) {
+                return Err(ParseError::general(
+                    "lexical name declared multiple times",
+                    Position::new(1, 1),
+                ));
+            }
+        }
+
+        // It is a Syntax Error if any element of the LexicallyDeclaredNames of ModuleItemList also occurs in the
+        // VarDeclaredNames of ModuleItemList.
+        let mut var_declared_names = FxHashSet::default();
+        module.var_declared_names(&mut var_declared_names);
+        if lexically_declared_names
+            .iter()
+            .any(|name| var_declared_names.contains(name))
+        {
+            return Err(ParseError::general(
+                "lexical name declared in var names",
+                Position::new(1, 1),
+            ));
+        }
+
+        // It is a Syntax Error if the ExportedNames of ModuleItemList contains any duplicate entries.
+        let mut exported_names = FxHashSet::default();
+        for name in module.exported_names() {
+            if !exported_names.insert(name) {
+                return Err(ParseError::general(
+                    "duplicate export name",
+                    Position::new(1, 1),
+                ));
+            }
+        }
+
+        for item in module.items() {
+            match item {
+                // It is a Syntax Error if any element of the ExportedBindings of ModuleItemList does not also occur
+                // in either the VarDeclaredNames of ModuleItemList, or the LexicallyDeclaredNames of ModuleItemList.
+                ModuleItem::ExportDeclaration(ExportDeclaration::List(specifiers)) => {
+                    for specifier in specifiers.iter() {
+                        let name = specifier.private_name();
+                        if !lexically_declared_names.contains(&name)
+                            && !var_declared_names.contains(&name)
+                        {
+                            return Err(ParseError::general(
+                                "exported binding is not declared",
+                                Position::new(1, 1),
+                            ));
+                        }
+                    }
+                }
+                // It is a Syntax Error if ModuleItemList Contains super or NewTarget.
+                ModuleItem::ExportDeclaration(
+                    ExportDeclaration::Declaration(node)
+                    | ExportDeclaration::DefaultDeclaration(node)
+                    | ExportDeclaration::DefaultAssignmentExpression(node),
+                )
+                | ModuleItem::StatementListItem(node) => {
+                    if node.contains(ContainsSymbol::SuperCall)
+                        || node.contains(ContainsSymbol::SuperProperty)
+                    {
+                        return Err(ParseError::general(
+                            "invalid super usage",
+                            Position::new(1, 1),
+                        ));
+                    }
+                    if node.contains(ContainsSymbol::NewTarget) {
+                        return Err(ParseError::general(
+                            "invalid new.target usage",
+                            Position::new(1, 1),
+                        ));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(module)
+    }
+}
+
+/// Parses a module item list.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItemList
+#[derive(Debug, Clone, Copy)]
+struct ModuleItemList;
+
+impl<R> TokenParser<R> for ModuleItemList
+where
+    R: Read,
+{
+    type Output = node::ModuleItemList;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let _timer = Profiler::global().start_event("ModuleItemList", "Parsing");
+        let mut items = Vec::new();
+
+        let mut spans = Vec::new();
+
+        while let Some(token) = cursor.peek(0, interner)? {
+            let start = token.span().start();
+            items.push(ModuleItemParser.parse(cursor, interner)?);
+            spans.push(Span::new(start, cursor.last_token_end()));
+
+            // move the cursor forward for any consecutive semicolon.
+            while cursor.next_if(Punctuator::Semicolon, interner)?.is_some() {}
+        }
+
+        Ok(node::ModuleItemList::from(items).with_spans(spans))
+    }
+}
+
+/// Parses a single module item.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItem
+#[derive(Debug, Clone, Copy)]
+struct ModuleItemParser;
+
+impl<R> TokenParser<R> for ModuleItemParser
+where
+    R: Read,
+{
+    type Output = ModuleItem;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let tok = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+
+        match tok.kind() {
+            TokenKind::Keyword((Keyword::Import, false)) => {
+                // `import(...)` and `import.meta` are expressions.
+                match cursor.peek(1, interner)?.map(Token::kind) {
+                    Some(TokenKind::Punctuator(Punctuator::OpenParen | Punctuator::Dot)) => {
+                        StatementListItem::new(false, false, false)
+                            .parse(cursor, interner)
+                            .map(ModuleItem::StatementListItem)
+                    }
+                    _ => ImportDeclarationParser
+                        .parse(cursor, interner)
+                        .map(ModuleItem::ImportDeclaration),
+                }
+            }
+            TokenKind::Keyword((Keyword::Export, false)) => ExportDeclarationParser
+                .parse(cursor, interner)
+                .map(ModuleItem::ExportDeclaration),
+            TokenKind::Keyword((Keyword::Import | Keyword::Export, true)) => {
+                Err(ParseError::general(
+                    "Keyword must not contain escaped characters",
+                    tok.span().start(),
+                ))
+            }
+            _ => StatementListItem::new(false, false, false)
+                .parse(cursor, interner)
+                .map(ModuleItem::StatementListItem),
+        }
+    }
+}
+
+/// Parses an import declaration.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportDeclaration
+#[derive(Debug, Clone, Copy)]
+struct ImportDeclarationParser;
+
+impl<R> TokenParser<R> for ImportDeclarationParser
+where
+    R: Read,
+{
+    type Output = ImportDeclaration;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let _timer = Profiler::global().start_event("ImportDeclaration", "Parsing");
+        cursor.expect((Keyword::Import, false), "import declaration", interner)?;
+
+        let tok = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+
+        // import ModuleSpecifier ;
+        if let TokenKind::StringLiteral(specifier) = tok.kind() {
+            let specifier = *specifier;
+            cursor.next(interner)?.expect("token disappeared");
+            cursor.expect_semicolon("import declaration", interner)?;
+            return Ok(ImportDeclaration::new(
+                None,
+                ImportKind::DefaultOrUnnamed,
+                specifier,
+            ));
+        }
+
+        // ImportedDefaultBinding
+        let default = if matches!(
+            tok.kind(),
+            TokenKind::Punctuator(Punctuator::Mul | Punctuator::OpenBlock)
+        ) {
+            None
+        } else {
+            let binding = BindingIdentifier::new(false, true).parse(cursor, interner)?;
+            if cursor.next_if(Punctuator::Comma, interner)?.is_none() {
+                let specifier = FromClause.parse(cursor, interner)?;
+                cursor.expect_semicolon("import declaration", interner)?;
+                return Ok(ImportDeclaration::new(
+                    Some(binding),
+                    ImportKind::DefaultOrUnnamed,
+                    specifier,
+                ));
+            }
+            Some(binding)
+        };
+
+        let tok = cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?;
+        let kind = match tok.kind() {
+            // NameSpaceImport : * as ImportedBinding
+            TokenKind::Punctuator(Punctuator::Mul) => {
+                cursor.expect(TokenKind::identifier(Sym::AS), "namespace import", interner)?;
+                let binding = BindingIdentifier::new(false, true).parse(cursor, interner)?;
+                ImportKind::Namespaced { binding }
+            }
+            // NamedImports : { ImportsList[opt] ,[opt] }
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                let mut names = Vec::new();
+                loop {
+                    if cursor.next_if(Punctuator::CloseBlock, interner)?.is_some() {
+                        break;
+                    }
+                    names.push(ImportSpecifierParser.parse(cursor, interner)?);
+                    if cursor.next_if(Punctuator::Comma, interner)?.is_none() {
+                        cursor.expect(Punctuator::CloseBlock, "named imports", interner)?;
+                        break;
+                    }
+                }
+                ImportKind::Named {
+                    names: names.into_boxed_slice(),
+                }
+            }
+            _ => {
+                return Err(ParseError::expected(
+                    ["*".to_owned(), "{".to_owned()],
+                    tok.to_string(interner),
+                    tok.span(),
+                    "import clause",
+                ))
+            }
+        };
+
+        let specifier = FromClause.parse(cursor, interner)?;
+        cursor.expect_semicolon("import declaration", interner)?;
+
+        Ok(ImportDeclaration::new(default, kind, specifier))
+    }
+}
+
+/// Parses a single named import.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportSpecifier
+#[derive(Debug, Clone, Copy)]
+struct ImportSpecifierParser;
+
+impl<R> TokenParser<R> for ImportSpecifierParser
+where
+    R: Read,
+{
+    type Output = ImportSpecifier;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let is_identifier = matches!(
+            cursor
+                .peek(0, interner)?
+                .ok_or(ParseError::AbruptEnd)?
+                .kind(),
+            TokenKind::Identifier(_)
+        );
+        let followed_by_as = matches!(
+            cursor.peek(1, interner)?.map(Token::kind),
+            Some(TokenKind::Identifier(Sym::AS))
+        );
+
+        // ImportSpecifier : ImportedBinding
+        if is_identifier && !followed_by_as {
+            let binding = BindingIdentifier::new(false, true).parse(cursor, interner)?;
+            return Ok(ImportSpecifier::new(binding, binding));
+        }
+
+        // ImportSpecifier : ModuleExportName as ImportedBinding
+        let (export_name, _) = ModuleExportName.parse(cursor, interner)?;
+        cursor.expect(TokenKind::identifier(Sym::AS), "import specifier", interner)?;
+        let binding = BindingIdentifier::new(false, true).parse(cursor, interner)?;
+
+        Ok(ImportSpecifier::new(binding, export_name))
+    }
+}
+
+/// Parses an export declaration.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExportDeclaration
+#[derive(Debug, Clone, Copy)]
+struct ExportDeclarationParser;
+
+impl<R> TokenParser<R> for ExportDeclarationParser
+where
+    R: Read,
+{
+    type Output = ExportDeclaration;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let _timer = Profiler::global().start_event("ExportDeclaration", "Parsing");
+        cursor.expect((Keyword::Export, false), "export declaration", interner)?;
+
+        let tok = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+        let position = tok.span().start();
+
+        let export = match tok.kind() {
+            // export * FromClause ;
+            // export * as ModuleExportName FromClause ;
+            TokenKind::Punctuator(Punctuator::Mul) => {
+                cursor.next(interner)?.expect("token disappeared");
+                let name = if cursor
+                    .next_if(TokenKind::identifier(Sym::AS), interner)?
+                    .is_some()
+                {
+                    Some(ModuleExportName.parse(cursor, interner)?.0)
+                } else {
+                    None
+                };
+                let specifier = FromClause.parse(cursor, interner)?;
+                cursor.expect_semicolon("export declaration", interner)?;
+                ExportDeclaration::ReExport {
+                    kind: ReExportKind::Namespaced { name },
+                    specifier,
+                }
+            }
+            // export NamedExports FromClause ;
+            // export NamedExports ;
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                cursor.next(interner)?.expect("token disappeared");
+                let mut names = Vec::new();
+                let mut local_names_valid = true;
+                loop {
+                    if cursor.next_if(Punctuator::CloseBlock, interner)?.is_some() {
+                        break;
+                    }
+                    let (private_name, is_identifier) = ModuleExportName.parse(cursor, interner)?;
+                    local_names_valid &= is_identifier;
+                    let alias = if cursor
+                        .next_if(TokenKind::identifier(Sym::AS), interner)?
+                        .is_some()
+                    {
+                        ModuleExportName.parse(cursor, interner)?.0
+                    } else {
+                        private_name
+                    };
+                    names.push(ExportSpecifier::new(alias, private_name));
+                    if cursor.next_if(Punctuator::Comma, interner)?.is_none() {
+                        cursor.expect(Punctuator::CloseBlock, "named exports", interner)?;
+                        break;
+                    }
+                }
+                let names = names.into_boxed_slice();
+
+                if matches!(
+                    cursor.peek(0, interner)?.map(Token::kind),
+                    Some(TokenKind::Identifier(Sym::FROM))
+                ) {
+                    let specifier = FromClause.parse(cursor, interner)?;
+                    cursor.expect_semicolon("export declaration", interner)?;
+                    ExportDeclaration::ReExport {
+                        kind: ReExportKind::Named { names },
+                        specifier,
+                    }
+                } else {
+                    // It is a Syntax Error if ReferencedBindings of NamedExports contains any StringLiterals
+                    // or any reserved words.
+                    if !local_names_valid {
+                        return Err(ParseError::general(
+                            "exported local name must be an identifier",
+                            position,
+                        ));
+                    }
+                    cursor.expect_semicolon("export declaration", interner)?;
+                    ExportDeclaration::List(names)
+                }
+            }
+            // export default HoistableDeclaration[+Default]
+            // export default ClassDeclaration[+Default]
+            // export default AssignmentExpression[+In] ;
+            TokenKind::Keyword((Keyword::Default, false)) => {
+                cursor.next(interner)?.expect("token disappeared");
+                let tok = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+                let is_declaration = match tok.kind() {
+                    TokenKind::Keyword((Keyword::Function | Keyword::Class, _)) => true,
+                    TokenKind::Keyword((Keyword::Async, _)) => {
+                        matches!(
+                            cursor.peek(1, interner)?.map(Token::kind),
+                            Some(TokenKind::Keyword((Keyword::Function, _)))
+                        )
+                    }
+                    _ => false,
+                };
+                if is_declaration {
+                    ExportDeclaration::DefaultDeclaration(
+                        HoistableDeclaration::new(false, false, true).parse(cursor, interner)?,
+                    )
+                } else {
+                    let expr = AssignmentExpression::new(Sym::DEFAULT, true, false, false)
+                        .parse(cursor, interner)?;
+                    cursor.expect_semicolon("export declaration", interner)?;
+                    ExportDeclaration::DefaultAssignmentExpression(expr)
+                }
+            }
+            // export VariableStatement
+            // export Declaration
+            TokenKind::Keyword((
+                Keyword::Var
+                | Keyword::Let
+                | Keyword::Const
+                | Keyword::Function
+                | Keyword::Class
+                | Keyword::Async,
+                _,
+            )) => ExportDeclaration::Declaration(
+                StatementListItem::new(false, false, false).parse(cursor, interner)?,
+            ),
+            _ => {
+                return Err(ParseError::unexpected(
+                    tok.to_string(interner),
+                    tok.span(),
+                    "export declaration",
+                ))
+            }
+        };
+
+        Ok(export)
+    }
+}
+
+/// Parses a module export name.
+///
+/// Returns the name and a flag that is `true` if the name is a valid identifier reference.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleExportName
+#[derive(Debug, Clone, Copy)]
+struct ModuleExportName;
+
+impl<R> TokenParser<R> for ModuleExportName
+where
+    R: Read,
+{
+    type Output = (Sym, bool);
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let tok = cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?;
+        match tok.kind() {
+            TokenKind::Identifier(name) => Ok((*name, !RESERVED_IDENTIFIERS_STRICT.contains(name))),
+            TokenKind::StringLiteral(name) => Ok((*name, false)),
+            TokenKind::Keyword((Keyword::Async, _)) => Ok((Sym::ASYNC, true)),
+            TokenKind::Keyword((Keyword::Of, _)) => Ok((Sym::OF, true)),
+            TokenKind::Keyword((word, _)) => {
+                Ok((interner.get_or_intern_static(word.as_str()), false))
+            }
+            TokenKind::NullLiteral => Ok((Sym::NULL, false)),
+            TokenKind::BooleanLiteral(true) => Ok((Sym::TRUE, false)),
+            TokenKind::BooleanLiteral(false) => Ok((Sym::FALSE, false)),
+            _ => Err(ParseError::expected(
+                ["identifier".to_owned(), "string literal".to_owned()],
+                tok.to_string(interner),
+                tok.span(),
+                "module export name",
+            )),
+        }
+    }
+}
+
+/// Parses a from clause.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-FromClause
+#[derive(Debug, Clone, Copy)]
+struct FromClause;
+
+impl<R> TokenParser<R> for FromClause
+where
+    R: Read,
+{
+    type Output = Sym;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        cursor.expect(TokenKind::identifier(Sym::FROM), "from clause", interner)?;
+        let tok = cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?;
+        if let TokenKind::StringLiteral(specifier) = tok.kind() {
+            Ok(*specifier)
+        } else {
+            Err(ParseError::expected(
+                ["string literal".to_owned()],
+                tok.to_string(interner),
+                tok.span(),
+                "module specifier",
+            ))
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{
+    context::ContextBuilder,
+    syntax::{
+        ast::node::{
+            ExportDeclaration, ExportSpecifier, ImportDeclaration, ImportKind, ImportSpecifier,
+            ModuleItem, ModuleItemList, ReExportKind,
+        },
+        Parser,
+    },
+    Context,
+};
+use boa_interner::{Interner, Sym};
+
+/// Checks that the given javascript string gives the expected module items.
+#[track_caller]
+fn check_module_parser<L>(js: &str, items: L, interner: Interner)
+where
+    L: Into<Box<[ModuleItem]>>,
+{
+    let mut context = ContextBuilder::default().interner(interner).build();
+    assert_eq!(
+        Parser::new(js.as_bytes())
+            .parse_module(&mut context)
+            .expect("failed to parse"),
+        ModuleItemList::from(items)
+    );
+}
+
+/// Checks that the given javascript string creates a parse error when parsed as a module.
+#[track_caller]
+fn check_invalid_module(js: &str) {
+    let mut context = Context::default();
+    assert!(Parser::new(js.as_bytes())
+        .parse_module(&mut context)
+        .is_err());
+}
+
+#[test]
+fn import_declarations() {
+    let mut interner = Interner::default();
+    let module = interner.get_or_intern_static("mod");
+    let a = interner.get_or_intern_static("a");
+    let b = interner.get_or_intern_static("b");
+    let c = interner.get_or_intern_static("c");
+    let d = interner.get_or_intern_static("d");
+    check_module_parser(
+        r#"import "mod"; import a, * as b from "mod"; import { a as c, d } from "mod";"#,
+        vec![
+            ImportDeclaration::new(None, ImportKind::DefaultOrUnnamed, module).into(),
+            ImportDeclaration::new(Some(a), ImportKind::Namespaced { binding: b }, module).into(),
+            ImportDeclaration::new(
+                None,
+                ImportKind::Named {
+                    names: vec![ImportSpecifier::new(c, a), ImportSpecifier::new(d, d)].into(),
+                },
+                module,
+            )
+            .into(),
+        ],
+        interner,
+    );
+}
+
+#[test]
+fn export_declarations() {
+    let mut interner = Interner::default();
+    let module = interner.get_or_intern_static("mod");
+    let a = interner.get_or_intern_static("a");
+    let b = interner.get_or_intern_static("b");
+    check_module_parser(
+        r#"export * from "mod"; export * as a from "mod"; export { a as default, b } from "mod";"#,
+        vec![
+            ExportDeclaration::ReExport {
+                kind: ReExportKind::Namespaced { name: None },
+                specifier: module,
+            }
+            .into(),
+            ExportDeclaration::ReExport {
+                kind: ReExportKind::Namespaced { name: Some(a) },
+                specifier: module,
+            }
+            .into(),
+            ExportDeclaration::ReExport {
+                kind: ReExportKind::Named {
+                    names: vec![
+                        ExportSpecifier::new(Sym::DEFAULT, a),
+                        ExportSpecifier::new(b, b),
+                    ]
+                    .into(),
+                },
+                specifier: module,
+            }
+            .into(),
+        ],
+        interner,
+    );
+}
+
+#[test]
+fn module_is_strict() {
+    check_invalid_module("with (a) {}");
+    check_invalid_module("var let = 1;");
+}
+
+#[test]
+fn duplicate_declarations() {
+    check_invalid_module("let a; let a;");
+    check_invalid_module("function a() {} function a() {}");
+    check_invalid_module(r#"import a from "mod"; let a;"#);
+    check_invalid_module("var a; let a;");
+}
+
+#[test]
+fn invalid_exports() {
+    check_invalid_module("let a; export { a }; export { a };");
+    check_invalid_module("export default 1; export default 2;");
+    check_invalid_module("export { a };");
+    check_invalid_module(r#"export { "a" };"#);
+    check_invalid_module("export { default };");
+    check_invalid_module("new.target;");
+}
@@ -43,7 +43,7 @@ pub(in crate::syntax::parser) use function_decl::FunctionDeclaration;
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-FunctionDeclaration
 #[derive(Debug, Clone, Copy)]
-pub(super) struct HoistableDeclaration {
+pub(in crate::syntax::parser) struct HoistableDeclaration {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     is_default: AllowDefault,
@@ -51,7 +51,11 @@ pub(super) struct HoistableDeclaration {
 
 impl HoistableDeclaration {
     /// Creates a new `HoistableDeclaration` parser.
-    pub(super) fn new<Y, A, D>(allow_yield: Y, allow_await: A, is_default: D) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A, D>(
+        allow_yield: Y,
+        allow_await: A,
+        is_default: D,
+    ) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -105,13 +109,17 @@ where
                     .parse(cursor, interner)
                     .map(Node::from)
                 } else {
-                    AsyncFunctionDeclaration::new(self.allow_yield, self.allow_await, false)
-                        .parse(cursor, interner)
-                        .map(Node::from)
+                    AsyncFunctionDeclaration::new(
+                        self.allow_yield,
+                        self.allow_await,
+                        self.is_default,
+                    )
+                    .parse(cursor, interner)
+                    .map(Node::from)
                 }
             }
             TokenKind::Keyword((Keyword::Class, false)) => {
-                ClassDeclaration::new(self.allow_yield, self.allow_await, false)
+                ClassDeclaration::new(self.allow_yield, self.allow_await, self.is_default)
                     .parse(cursor, interner)
                     .map(Node::from)
             }
@@ -12,7 +12,7 @@ mod lexical;
 #[cfg(test)]
 mod tests;
 
-use self::{hoistable::HoistableDeclaration, lexical::LexicalDeclaration};
+use self::lexical::LexicalDeclaration;
 use crate::syntax::{
     ast::{Keyword, Node},
     lexer::TokenKind,
@@ -22,8 +22,8 @@ use boa_interner::Interner;
 use boa_profiler::Profiler;
 use std::io::Read;
 
-pub(in crate::syntax::parser) use hoistable::class_decl::ClassTail;
 pub(in crate::syntax) use hoistable::class_decl::PrivateElement;
+pub(in crate::syntax::parser) use hoistable::{class_decl::ClassTail, HoistableDeclaration};
 
 /// Parses a declaration.
 ///
@@ -48,7 +48,7 @@ use crate::syntax::{
                 DeclarationPatternArray, DeclarationPatternObject,
             },
         },
-        Keyword, Node, Punctuator,
+        Keyword, Node, Punctuator, Span,
     },
     lexer::{Error as LexError, InputElement, Token, TokenKind},
     parser::expression::{await_expr::AwaitExpression, BindingIdentifier, Initializer},
@@ -57,8 +57,8 @@ use boa_interner::Interner;
 use boa_profiler::Profiler;
 use std::{io::Read, vec};
 
-pub(in crate::syntax::parser) use declaration::ClassTail;
 pub(in crate::syntax) use declaration::PrivateElement;
+pub(in crate::syntax::parser) use declaration::{ClassTail, HoistableDeclaration};
 
 /// Statement parsing.
 ///
@@ -289,24 +289,25 @@ where
         let mut items = Vec::new();
 
         loop {
-            match cursor.peek(0, interner)? {
+            let start = match cursor.peek(0, interner)? {
                 Some(token) if self.break_nodes.contains(token.kind()) => break,
                 None => break,
-                _ => {}
-            }
+                Some(token) => token.span().start(),
+            };
 
             let item =
                 StatementListItem::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor, interner)?;
-            items.push(item);
+            items.push((item, Span::new(start, cursor.last_token_end())));
 
             // move the cursor forward for any consecutive semicolon.
             while cursor.next_if(Punctuator::Semicolon, interner)?.is_some() {}
         }
 
-        items.sort_by(Node::hoistable_order);
+        items.sort_by(|(a, _), (b, _)| Node::hoistable_order(a, b));
 
-        Ok(items.into())
+        let (items, spans): (Vec<_>, Vec<_>) = items.into_iter().unzip();
+        Ok(node::StatementList::from(items).with_spans(spans))
     }
 }
 
@@ -321,7 +322,7 @@ where
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements
 /// [spec]: https://tc39.es/ecma262/#prod-StatementListItem
 #[derive(Debug, Clone, Copy)]
-struct StatementListItem {
+pub(in crate::syntax::parser) struct StatementListItem {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     allow_return: AllowReturn,
@@ -329,7 +330,11 @@ struct StatementListItem {
 
 impl StatementListItem {
     /// Creates a new `StatementListItem` parser.
-    fn new<Y, A, R>(allow_yield: Y, allow_await: A, allow_return: R) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A, R>(
+        allow_yield: Y,
+        allow_await: A,
+        allow_return: R,
+    ) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -11,7 +11,7 @@ use crate::{
             StatementList, UnaryOp,
         },
         op::{self, CompOp, LogOp, NumOp},
-        Const,
+        Const, Position, Span,
     },
     Context,
 };
@@ -482,3 +482,22 @@ fn hashbang_use_strict_with_with_statement() {
 fn hashbang_comment() {
     check_parser(r"#!Comment Here", vec![], Interner::default());
 }
+
+#[test]
+fn statement_spans() {
+    let mut context = Context::default();
+    let statement_list =
+        Parser::new("let a = 1;\n  if (a) {\n    a++;\n  }\nfunction f() {}".as_bytes())
+            .parse_all(&mut context)
+            .expect("failed to parse");
+
+    // Function declarations are hoisted with their span.
+    assert_eq!(
+        statement_list.spans(),
+        [
+            Span::new(Position::new(5, 1), Position::new(5, 16)),
+            Span::new(Position::new(1, 1), Position::new(1, 10)),
+            Span::new(Position::new(2, 3), Position::new(4, 4)),
+        ]
+    );
+}
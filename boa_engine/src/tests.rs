@@ -446,6 +446,18 @@ fn for_loop_iteration_variable_does_not_leak() {
     assert_eq!(&exec(inner_scope), "\"i is not defined\"");
 }
 
+#[test]
+fn eval_keeps_unpaired_surrogates() {
+    // Escaping the surrogate would make this a string of six characters, starting with a
+    // backslash.
+    let src = r#"
+        const result = eval("'\\\uD800'");
+        result.length === 1 && result.charCodeAt(0) === 0xD800
+        "#;
+
+    assert_eq!(&exec(src), "true");
+}
+
 #[test]
 fn test_invalid_break_target() {
     let src = r#"
@@ -1235,10 +1247,12 @@ fn to_object() {
     assert!(JsValue::undefined()
         .to_object(&mut context)
         .unwrap_err()
+        .to_opaque(&mut context)
         .is_object());
     assert!(JsValue::null()
         .to_object(&mut context)
         .unwrap_err()
+        .to_opaque(&mut context)
         .is_object());
 }
 
@@ -1651,3 +1665,50 @@ fn test_labelled_block() {
     "#;
     assert_eq!(&exec(src), "true");
 }
+
+#[test]
+fn functions_run_in_their_realm() {
+    let mut context = Context::default();
+
+    let realm = context.create_realm();
+    let old_realm = context.enter_realm(realm.clone());
+    forward(
+        &mut context,
+        "var x = 'other'; function getX() { return x; }",
+    );
+    let get_x = realm.global_object().get("getX", &mut context).unwrap();
+    let other_array = realm.global_object().get("Array", &mut context).unwrap();
+    context.enter_realm(old_realm);
+
+    context
+        .global_object()
+        .clone()
+        .set("getX", get_x, true, &mut context)
+        .unwrap();
+    context
+        .global_object()
+        .clone()
+        .set("OtherArray", other_array, true, &mut context)
+        .unwrap();
+
+    forward(&mut context, "var x = 'main';");
+    assert_eq!(forward(&mut context, "getX()"), "\"other\"");
+    assert_eq!(
+        forward(&mut context, "new OtherArray() instanceof Array"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "Array.isArray(new OtherArray())"),
+        "true"
+    );
+
+    // The prototype of a constructed object defaults to the intrinsics of the realm of the
+    // `new.target`.
+    assert_eq!(
+        forward(
+            &mut context,
+            "function F() {} F.prototype = null; Reflect.construct(OtherArray, [], F) instanceof Array"
+        ),
+        "true"
+    );
+}
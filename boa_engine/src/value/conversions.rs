@@ -1,4 +1,4 @@
-use super::{Display, JsBigInt, JsObject, JsString, JsSymbol, JsValue, Profiler};
+use super::{Display, InnerValue, JsBigInt, JsObject, JsString, JsSymbol, JsValue, Profiler};
 
 impl From<&Self> for JsValue {
     #[inline]
@@ -15,7 +15,9 @@ where
     fn from(value: T) -> Self {
         let _timer = Profiler::global().start_event("From<String>", "value");
 
-        Self::String(value.into())
+        Self {
+            inner: InnerValue::string(value.into()),
+        }
     }
 }
 
@@ -29,7 +31,9 @@ impl From<char> for JsValue {
 impl From<JsSymbol> for JsValue {
     #[inline]
     fn from(value: JsSymbol) -> Self {
-        Self::Symbol(value)
+        Self {
+            inner: InnerValue::symbol(value),
+        }
     }
 }
 
@@ -49,7 +53,7 @@ impl From<f32> for JsValue {
         // if value as i32 as f64 == value {
         //     Self::Integer(value as i32)
         // } else {
-        Self::Rational(value.into())
+        Self::rational(value.into())
         // }
     }
 }
@@ -61,7 +65,7 @@ impl From<f64> for JsValue {
         // if value as i32 as f64 == value {
         //     Self::Integer(value as i32)
         // } else {
-        Self::Rational(value)
+        Self::rational(value)
         // }
     }
 }
@@ -69,28 +73,28 @@ impl From<f64> for JsValue {
 impl From<u8> for JsValue {
     #[inline]
     fn from(value: u8) -> Self {
-        Self::Integer(value.into())
+        Self::integer(value.into())
     }
 }
 
 impl From<i8> for JsValue {
     #[inline]
     fn from(value: i8) -> Self {
-        Self::Integer(value.into())
+        Self::integer(value.into())
     }
 }
 
 impl From<u16> for JsValue {
     #[inline]
     fn from(value: u16) -> Self {
-        Self::Integer(value.into())
+        Self::integer(value.into())
     }
 }
 
 impl From<i16> for JsValue {
     #[inline]
     fn from(value: i16) -> Self {
-        Self::Integer(value.into())
+        Self::integer(value.into())
     }
 }
 
@@ -98,9 +102,9 @@ impl From<u32> for JsValue {
     #[inline]
     fn from(value: u32) -> Self {
         if let Ok(integer) = i32::try_from(value) {
-            Self::Integer(integer)
+            Self::integer(integer)
         } else {
-            Self::Rational(value.into())
+            Self::rational(value.into())
         }
     }
 }
@@ -108,14 +112,16 @@ impl From<u32> for JsValue {
 impl From<i32> for JsValue {
     #[inline]
     fn from(value: i32) -> Self {
-        Self::Integer(value)
+        Self::integer(value)
     }
 }
 
 impl From<JsBigInt> for JsValue {
     #[inline]
     fn from(value: JsBigInt) -> Self {
-        Self::BigInt(value)
+        Self {
+            inner: InnerValue::bigint(value),
+        }
     }
 }
 
@@ -123,9 +129,9 @@ impl From<usize> for JsValue {
     #[inline]
     fn from(value: usize) -> Self {
         if let Ok(value) = i32::try_from(value) {
-            Self::Integer(value)
+            Self::integer(value)
         } else {
-            Self::Rational(value as f64)
+            Self::rational(value as f64)
         }
     }
 }
@@ -134,9 +140,9 @@ impl From<u64> for JsValue {
     #[inline]
     fn from(value: u64) -> Self {
         if let Ok(value) = i32::try_from(value) {
-            Self::Integer(value)
+            Self::integer(value)
         } else {
-            Self::Rational(value as f64)
+            Self::rational(value as f64)
         }
     }
 }
@@ -145,9 +151,9 @@ impl From<i64> for JsValue {
     #[inline]
     fn from(value: i64) -> Self {
         if let Ok(value) = i32::try_from(value) {
-            Self::Integer(value)
+            Self::integer(value)
         } else {
-            Self::Rational(value as f64)
+            Self::rational(value as f64)
         }
     }
 }
@@ -155,7 +161,9 @@ impl From<i64> for JsValue {
 impl From<bool> for JsValue {
     #[inline]
     fn from(value: bool) -> Self {
-        Self::Boolean(value)
+        Self {
+            inner: InnerValue::boolean(value),
+        }
     }
 }
 
@@ -163,7 +171,9 @@ impl From<JsObject> for JsValue {
     #[inline]
     fn from(object: JsObject) -> Self {
         let _timer = Profiler::global().start_event("From<JsObject>", "value");
-        Self::Object(object)
+        Self {
+            inner: InnerValue::object(object),
+        }
     }
 }
 
@@ -1,6 +1,6 @@
 use crate::{object::ObjectKind, property::PropertyDescriptor};
 
-use super::{fmt, Display, HashSet, JsValue, PropertyKey};
+use super::{fmt, Display, HashSet, JsValue, JsVariant, PropertyKey};
 
 /// This object is used for displaying a `Value`.
 #[derive(Debug, Clone, Copy)]
@@ -55,7 +55,7 @@ macro_rules! print_obj_value {
                 vec![format!(
                     "{:>width$}: {}",
                     "__proto__",
-                    JsValue::Null.display(),
+                    JsValue::null().display(),
                     width = $indent,
                 )]
             }
@@ -96,9 +96,9 @@ macro_rules! print_obj_value {
 }
 
 pub(crate) fn log_string_from(x: &JsValue, print_internals: bool, print_children: bool) -> String {
-    match x {
+    match x.variant() {
         // We don't want to print private (compiler) or prototype properties
-        JsValue::Object(ref v) => {
+        JsVariant::Object(v) => {
             // Can use the private "type" field of an Object to match on
             // which type of Object it represents for special printing
             match v.borrow().kind() {
@@ -196,7 +196,7 @@ pub(crate) fn log_string_from(x: &JsValue, print_internals: bool, print_children
                 _ => display_obj(x, print_internals),
             }
         }
-        JsValue::Symbol(ref symbol) => symbol.to_string(),
+        JsVariant::Symbol(symbol) => symbol.to_string(),
         _ => x.display().to_string(),
     }
 }
@@ -216,7 +216,7 @@ pub(crate) fn display_obj(v: &JsValue, print_internals: bool) -> String {
         indent: usize,
         print_internals: bool,
     ) -> String {
-        if let JsValue::Object(ref v) = *data {
+        if let Some(v) = data.as_object() {
             // The in-memory address of the current object
             let addr = address_of(v.as_ref());
 
@@ -254,20 +254,20 @@ pub(crate) fn display_obj(v: &JsValue, print_internals: bool) -> String {
     // in-memory address in this set
     let mut encounters = HashSet::new();
 
-    if let JsValue::Object(object) = v {
+    if let Some(object) = v.as_object() {
         if object.borrow().is_error() {
             let name = v
                 .get_property("name")
                 .as_ref()
                 .and_then(PropertyDescriptor::value)
-                .unwrap_or(&JsValue::Undefined)
+                .unwrap_or(&JsValue::undefined())
                 .display()
                 .to_string();
             let message = v
                 .get_property("message")
                 .as_ref()
                 .and_then(PropertyDescriptor::value)
-                .unwrap_or(&JsValue::Undefined)
+                .unwrap_or(&JsValue::undefined())
                 .display()
                 .to_string();
             return format!("{name}: {message}");
@@ -279,21 +279,21 @@ pub(crate) fn display_obj(v: &JsValue, print_internals: bool) -> String {
 
 impl Display for ValueDisplay<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self.value {
-            JsValue::Null => write!(f, "null"),
-            JsValue::Undefined => write!(f, "undefined"),
-            JsValue::Boolean(v) => write!(f, "{v}"),
-            JsValue::Symbol(ref symbol) => match symbol.description() {
+        match self.value.variant() {
+            JsVariant::Null => write!(f, "null"),
+            JsVariant::Undefined => write!(f, "undefined"),
+            JsVariant::Boolean(v) => write!(f, "{v}"),
+            JsVariant::Symbol(symbol) => match symbol.description() {
                 Some(description) => write!(f, "Symbol({description})"),
                 None => write!(f, "Symbol()"),
             },
-            JsValue::String(ref v) => write!(f, "\"{v}\""),
-            JsValue::Rational(v) => format_rational(*v, f),
-            JsValue::Object(_) => {
+            JsVariant::String(v) => write!(f, "\"{v}\""),
+            JsVariant::Rational(v) => format_rational(v, f),
+            JsVariant::Object(_) => {
                 write!(f, "{}", log_string_from(self.value, self.internals, true))
             }
-            JsValue::Integer(v) => write!(f, "{v}"),
-            JsValue::BigInt(ref num) => write!(f, "{num}n"),
+            JsVariant::Integer(v) => write!(f, "{v}"),
+            JsVariant::BigInt(num) => write!(f, "{num}n"),
         }
     }
 }
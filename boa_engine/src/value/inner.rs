@@ -0,0 +1,418 @@
+//! This module implements the NaN-boxed storage of a [`JsValue`][super::JsValue].
+//!
+//! Every value is stored in 64 bits, and the 16 most significant bits select its type:
+//!
+//! | Tag               | Payload                                                    |
+//! |-------------------|------------------------------------------------------------|
+//! | `0x0000`          | The pointer of a [`JsObject`].                             |
+//! | `0x0002..=0xFFF2` | A `Number`, stored as its IEEE 754 bits plus 2<sup>49</sup>. |
+//! | `0xFFF9`          | `undefined`.                                               |
+//! | `0xFFFA`          | `null`.                                                    |
+//! | `0xFFFB`          | A `Boolean`, in the lowest bit.                            |
+//! | `0xFFFC`          | A `Number` that is an `i32`, in the lowest 32 bits.        |
+//! | `0xFFFD`          | The pointer of a [`JsString`].                             |
+//! | `0xFFFE`          | The pointer of a [`JsSymbol`].                             |
+//! | `0xFFFF`          | The pointer of a [`JsBigInt`].                             |
+//!
+//! The offset added to doubles moves them out of the range of pointers, and every `NaN` is
+//! replaced by the canonical [`f64::NAN`], so the tags above `0xFFF2` are never used by numbers.
+//!
+//! Pointers must fit in the 48 least significant bits, which is the case for user space
+//! addresses on the 64-bit platforms we support. All the pointers are accessed in place, which
+//! requires [`JsObject`], [`JsString`], [`JsSymbol`] and [`JsBigInt`] to have the layout of a
+//! pointer, and all of them but [`JsObject`] to ignore the tag stored in their most significant
+//! bits. Symbols and bigints do so by storing their contents in a [`TaggedRc`].
+
+use crate::{object::JsObject, symbol::JsSymbol, JsBigInt, JsString};
+use std::{
+    cell::Cell,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    mem,
+    ops::Deref,
+    ptr::{self, NonNull},
+    rc::Rc,
+};
+
+/// The offset added to the bits of a double.
+const DOUBLE_OFFSET: u64 = 1 << 49;
+
+/// The largest encoded double, which is `-Infinity`.
+const MAX_DOUBLE: u64 = 0xFFF0_0000_0000_0000 + DOUBLE_OFFSET;
+
+const TAG_MASK: u64 = 0xFFFF_0000_0000_0000;
+const PAYLOAD_MASK: u64 = !TAG_MASK;
+
+const UNDEFINED: u64 = 0xFFF9_0000_0000_0000;
+const NULL: u64 = 0xFFFA_0000_0000_0000;
+const TAG_BOOLEAN: u64 = 0xFFFB_0000_0000_0000;
+const TAG_INTEGER: u64 = 0xFFFC_0000_0000_0000;
+const TAG_STRING: u64 = 0xFFFD_0000_0000_0000;
+const TAG_SYMBOL: u64 = 0xFFFE_0000_0000_0000;
+const TAG_BIGINT: u64 = 0xFFFF_0000_0000_0000;
+
+/// The offset in bytes of the pointer of an object or string inside the storage.
+#[cfg(any(target_pointer_width = "64", target_endian = "little"))]
+const POINTER_OFFSET: usize = 0;
+
+/// The offset in bytes of the pointer of an object or string inside the storage.
+#[cfg(all(not(target_pointer_width = "64"), target_endian = "big"))]
+const POINTER_OFFSET: usize = 8 - mem::size_of::<usize>();
+
+/// The NaN-boxed representation of a [`JsValue`][super::JsValue].
+///
+/// The bits are stored in a [`Cell`] because the garbage collector updates the root flag of
+/// objects in place.
+pub(super) struct InnerValue {
+    bits: Cell<u64>,
+    _marker: PhantomData<JsObject>,
+}
+
+impl InnerValue {
+    #[inline]
+    const fn from_bits(bits: u64) -> Self {
+        Self {
+            bits: Cell::new(bits),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the bits of a pointer, checking that they don't overlap with the tag.
+    #[inline]
+    fn from_pointer(address: usize, tag: u64) -> Self {
+        let address = address as u64;
+        assert_eq!(
+            address & TAG_MASK,
+            0,
+            "pointer does not fit in the payload of a value"
+        );
+        Self::from_bits(address | tag)
+    }
+
+    #[inline]
+    pub(super) const fn undefined() -> Self {
+        Self::from_bits(UNDEFINED)
+    }
+
+    #[inline]
+    pub(super) const fn null() -> Self {
+        Self::from_bits(NULL)
+    }
+
+    #[inline]
+    pub(super) const fn boolean(value: bool) -> Self {
+        Self::from_bits(TAG_BOOLEAN | value as u64)
+    }
+
+    #[inline]
+    pub(super) const fn integer(value: i32) -> Self {
+        Self::from_bits(TAG_INTEGER | value as u32 as u64)
+    }
+
+    #[inline]
+    pub(super) fn rational(value: f64) -> Self {
+        let value = if value.is_nan() { f64::NAN } else { value };
+        Self::from_bits(value.to_bits() + DOUBLE_OFFSET)
+    }
+
+    #[inline]
+    pub(super) fn object(object: JsObject) -> Self {
+        // SAFETY: `JsObject` is a transparent wrapper over a thin pointer.
+        let address = unsafe { mem::transmute::<JsObject, usize>(object) };
+        Self::from_pointer(address, 0)
+    }
+
+    #[inline]
+    pub(super) fn string(string: JsString) -> Self {
+        // SAFETY: `JsString` is a transparent wrapper over a thin pointer.
+        let address = unsafe { mem::transmute::<JsString, usize>(string) };
+        Self::from_pointer(address, TAG_STRING)
+    }
+
+    #[inline]
+    pub(super) fn symbol(symbol: JsSymbol) -> Self {
+        // SAFETY: `JsSymbol` is a transparent wrapper over a `TaggedRc`.
+        let address = unsafe { mem::transmute::<JsSymbol, usize>(symbol) };
+        Self::from_pointer(address, TAG_SYMBOL)
+    }
+
+    #[inline]
+    pub(super) fn bigint(bigint: JsBigInt) -> Self {
+        // SAFETY: `JsBigInt` is a transparent wrapper over a `TaggedRc`.
+        let address = unsafe { mem::transmute::<JsBigInt, usize>(bigint) };
+        Self::from_pointer(address, TAG_BIGINT)
+    }
+
+    #[inline]
+    fn tag(&self) -> u64 {
+        self.bits.get() & TAG_MASK
+    }
+
+    /// Returns a pointer to the object, string, symbol or bigint stored in place.
+    #[inline]
+    fn pointer<T>(&self) -> *const T {
+        self.bits
+            .as_ptr()
+            .cast::<u8>()
+            .wrapping_add(POINTER_OFFSET)
+            .cast()
+    }
+
+    #[inline]
+    pub(super) fn is_undefined(&self) -> bool {
+        self.bits.get() == UNDEFINED
+    }
+
+    #[inline]
+    pub(super) fn is_null(&self) -> bool {
+        self.bits.get() == NULL
+    }
+
+    #[inline]
+    pub(super) fn is_object(&self) -> bool {
+        self.tag() == 0
+    }
+
+    #[inline]
+    pub(super) fn is_rational(&self) -> bool {
+        self.bits.get().wrapping_sub(DOUBLE_OFFSET) <= MAX_DOUBLE - DOUBLE_OFFSET
+    }
+
+    #[inline]
+    pub(super) fn is_integer(&self) -> bool {
+        self.tag() == TAG_INTEGER
+    }
+
+    #[inline]
+    pub(super) fn is_boolean(&self) -> bool {
+        self.tag() == TAG_BOOLEAN
+    }
+
+    #[inline]
+    pub(super) fn is_string(&self) -> bool {
+        self.tag() == TAG_STRING
+    }
+
+    #[inline]
+    pub(super) fn is_symbol(&self) -> bool {
+        self.tag() == TAG_SYMBOL
+    }
+
+    #[inline]
+    pub(super) fn is_bigint(&self) -> bool {
+        self.tag() == TAG_BIGINT
+    }
+
+    #[inline]
+    pub(super) fn as_boolean(&self) -> Option<bool> {
+        self.is_boolean().then(|| self.bits.get() & 1 != 0)
+    }
+
+    #[inline]
+    pub(super) fn as_integer(&self) -> Option<i32> {
+        self.is_integer().then(|| self.bits.get() as u32 as i32)
+    }
+
+    #[inline]
+    pub(super) fn as_rational(&self) -> Option<f64> {
+        self.is_rational()
+            .then(|| f64::from_bits(self.bits.get() - DOUBLE_OFFSET))
+    }
+
+    #[inline]
+    pub(super) fn as_object(&self) -> Option<&JsObject> {
+        // SAFETY: The storage holds the bits of a `JsObject`, which has the layout of a pointer.
+        self.is_object()
+            .then(|| unsafe { &*self.pointer::<JsObject>() })
+    }
+
+    #[inline]
+    pub(super) fn as_string(&self) -> Option<&JsString> {
+        // SAFETY: The storage holds the bits of a `JsString`, which has the layout of a pointer
+        // and ignores the tag.
+        self.is_string()
+            .then(|| unsafe { &*self.pointer::<JsString>() })
+    }
+
+    #[inline]
+    pub(super) fn as_symbol(&self) -> Option<&JsSymbol> {
+        // SAFETY: The storage holds the bits of a `JsSymbol`, which has the layout of a pointer
+        // and ignores the tag.
+        self.is_symbol()
+            .then(|| unsafe { &*self.pointer::<JsSymbol>() })
+    }
+
+    #[inline]
+    pub(super) fn as_bigint(&self) -> Option<&JsBigInt> {
+        // SAFETY: The storage holds the bits of a `JsBigInt`, which has the layout of a pointer
+        // and ignores the tag.
+        self.is_bigint()
+            .then(|| unsafe { &*self.pointer::<JsBigInt>() })
+    }
+}
+
+impl Clone for InnerValue {
+    #[inline]
+    fn clone(&self) -> Self {
+        if let Some(object) = self.as_object() {
+            return Self::object(object.clone());
+        }
+        match self.tag() {
+            TAG_STRING => Self::string(self.as_string().expect("tag checked").clone()),
+            TAG_SYMBOL => Self::symbol(self.as_symbol().expect("tag checked").clone()),
+            TAG_BIGINT => Self::bigint(self.as_bigint().expect("tag checked").clone()),
+            _ => Self::from_bits(self.bits.get()),
+        }
+    }
+}
+
+impl Drop for InnerValue {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: The payload is owned by this value, and is never used again.
+        unsafe {
+            match self.tag() {
+                0 => drop(ptr::read(self.pointer::<JsObject>())),
+                TAG_STRING => drop(ptr::read(self.pointer::<JsString>())),
+                TAG_SYMBOL => drop(ptr::read(self.pointer::<JsSymbol>())),
+                TAG_BIGINT => drop(ptr::read(self.pointer::<JsBigInt>())),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// The bits of a pointer stored in a value that belong to the address.
+#[cfg(target_pointer_width = "64")]
+const ADDRESS_MASK: usize = PAYLOAD_MASK as usize;
+
+/// The bits of a pointer stored in a value that belong to the address.
+#[cfg(not(target_pointer_width = "64"))]
+const ADDRESS_MASK: usize = usize::MAX;
+
+/// A reference counted pointer, like an [`Rc`], that ignores the type tag of a value in its most
+/// significant bits.
+///
+/// This lets the primitives holding one be stored in place in a value, like [`JsString`].
+#[repr(transparent)]
+pub(crate) struct TaggedRc<T> {
+    ptr: NonNull<T>,
+    _marker: PhantomData<Rc<T>>,
+}
+
+impl<T> TaggedRc<T> {
+    /// Creates a new `TaggedRc` holding `value`.
+    #[inline]
+    pub(crate) fn new(value: T) -> Self {
+        // SAFETY: The pointer of an `Rc` is never null.
+        let ptr = unsafe { NonNull::new_unchecked(Rc::into_raw(Rc::new(value)) as *mut T) };
+        Self {
+            ptr,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the pointer without the bits outside of [`ADDRESS_MASK`].
+    #[inline]
+    fn address(&self) -> *const T {
+        let ptr = self.ptr.as_ptr().cast::<u8>();
+        let tag = ptr as usize & !ADDRESS_MASK;
+
+        // `wrapping_sub` is used to preserve the provenance of the pointer.
+        ptr.wrapping_sub(tag).cast()
+    }
+}
+
+impl<T> Deref for TaggedRc<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        // SAFETY: The address comes from `Rc::into_raw`, and the `Rc` is alive until `self` is
+        // dropped.
+        unsafe { &*self.address() }
+    }
+}
+
+impl<T> AsRef<T> for TaggedRc<T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T> Clone for TaggedRc<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        let address = self.address();
+        // SAFETY: The address comes from `Rc::into_raw`, and the `Rc` is alive.
+        unsafe { Rc::increment_strong_count(address) };
+        Self {
+            // SAFETY: The address of an `Rc` is never null.
+            ptr: unsafe { NonNull::new_unchecked(address as *mut T) },
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Drop for TaggedRc<T> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: The address comes from `Rc::into_raw`, and this reference is never used again.
+        unsafe { drop(Rc::from_raw(self.address())) }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for TaggedRc<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T: PartialEq> PartialEq for TaggedRc<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Eq> Eq for TaggedRc<T> {}
+
+impl<T: PartialOrd> PartialOrd for TaggedRc<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<T: Ord> Ord for TaggedRc<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<T: Hash> Hash for TaggedRc<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
+#[cfg(feature = "deser")]
+impl<T: serde::Serialize> serde::Serialize for TaggedRc<T> {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (**self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "deser")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for TaggedRc<T> {
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
@@ -13,7 +13,7 @@ use crate::{
     object::{JsObject, ObjectData},
     property::{PropertyDescriptor, PropertyKey},
     symbol::{JsSymbol, WellKnownSymbols},
-    Context, JsBigInt, JsResult, JsString,
+    Context, JsBigInt, JsNativeError, JsResult, JsString,
 };
 use boa_gc::{custom_trace, Finalize, Trace};
 use boa_profiler::Profiler;
@@ -25,18 +25,21 @@ use std::{
     collections::HashSet,
     fmt::{self, Display},
     ops::Sub,
-    str::FromStr,
 };
 
 mod conversions;
 pub(crate) mod display;
 mod equality;
 mod hash;
+mod inner;
 mod integer;
 mod operations;
 mod serde_json;
 mod r#type;
 
+use inner::InnerValue;
+pub(crate) use inner::TaggedRc;
+
 pub use conversions::*;
 pub use display::ValueDisplay;
 pub use equality::*;
@@ -56,36 +59,54 @@ static TWO_E_63: Lazy<BigInt> = Lazy::new(|| {
 });
 
 /// A Javascript value
-#[derive(Finalize, Debug, Clone)]
-pub enum JsValue {
+///
+/// Values are NaN-boxed: numbers, booleans, `null`, `undefined` and the pointers to strings,
+/// symbols, bigints and objects are all stored in 64 bits. Use [`JsValue::variant`] to match on
+/// the type of a value.
+#[derive(Finalize, Clone)]
+pub struct JsValue {
+    inner: InnerValue,
+}
+
+/// A borrowed view of the type and contents of a [`JsValue`].
+///
+/// This is returned by [`JsValue::variant`].
+#[derive(Debug, Clone, Copy)]
+pub enum JsVariant<'a> {
     /// `null` - A null value, for when a value doesn't exist.
     Null,
     /// `undefined` - An undefined value, for when a field or index doesn't exist.
     Undefined,
     /// `boolean` - A `true` / `false` value, for if a certain criteria is met.
     Boolean(bool),
-    /// `String` - A UTF-8 string, such as `"Hello, world"`.
-    String(JsString),
+    /// `String` - A UTF-16 string, such as `"Hello, world"`.
+    String(&'a JsString),
     /// `Number` - A 64-bit floating point number, such as `3.1415`
     Rational(f64),
     /// `Number` - A 32-bit integer, such as `42`.
     Integer(i32),
     /// `BigInt` - holds any arbitrary large signed integer.
-    BigInt(JsBigInt),
+    BigInt(&'a JsBigInt),
     /// `Object` - An object, such as `Math`, represented by a binary tree of string keys to Javascript values.
-    Object(JsObject),
+    Object(&'a JsObject),
     /// `Symbol` - A Symbol Primitive type.
-    Symbol(JsSymbol),
+    Symbol(&'a JsSymbol),
 }
 
 unsafe impl Trace for JsValue {
     custom_trace! {this, {
-        if let Self::Object(o) = this {
+        if let Some(o) = this.as_object() {
             mark(o);
         }
     }}
 }
 
+impl fmt::Debug for JsValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.variant().fmt(f)
+    }
+}
+
 impl JsValue {
     /// Create a new [`JsValue`].
     #[inline]
@@ -98,46 +119,88 @@ impl JsValue {
 
     /// Creates a new `undefined` value.
     #[inline]
-    pub fn undefined() -> Self {
-        Self::Undefined
+    pub const fn undefined() -> Self {
+        Self {
+            inner: InnerValue::undefined(),
+        }
     }
 
     /// Creates a new `null` value.
     #[inline]
-    pub fn null() -> Self {
-        Self::Null
+    pub const fn null() -> Self {
+        Self {
+            inner: InnerValue::null(),
+        }
     }
 
     /// Creates a new number with `NaN` value.
     #[inline]
     pub fn nan() -> Self {
-        Self::Rational(f64::NAN)
+        Self::rational(f64::NAN)
     }
 
     /// Creates a new number with `Infinity` value.
     #[inline]
     pub fn positive_infinity() -> Self {
-        Self::Rational(f64::INFINITY)
+        Self::rational(f64::INFINITY)
     }
 
     /// Creates a new number with `-Infinity` value.
     #[inline]
     pub fn negative_infinity() -> Self {
-        Self::Rational(f64::NEG_INFINITY)
+        Self::rational(f64::NEG_INFINITY)
+    }
+
+    /// Creates a new number from a `f64`, without converting it to an integer.
+    #[inline]
+    pub(crate) fn rational(value: f64) -> Self {
+        Self {
+            inner: InnerValue::rational(value),
+        }
+    }
+
+    /// Creates a new number from an `i32`.
+    #[inline]
+    pub(crate) const fn integer(value: i32) -> Self {
+        Self {
+            inner: InnerValue::integer(value),
+        }
+    }
+
+    /// Returns a view of the type and contents of the value, to match on it.
+    #[inline]
+    pub fn variant(&self) -> JsVariant<'_> {
+        let inner = &self.inner;
+        if let Some(object) = inner.as_object() {
+            JsVariant::Object(object)
+        } else if let Some(rational) = inner.as_rational() {
+            JsVariant::Rational(rational)
+        } else if let Some(integer) = inner.as_integer() {
+            JsVariant::Integer(integer)
+        } else if let Some(string) = inner.as_string() {
+            JsVariant::String(string)
+        } else if let Some(boolean) = inner.as_boolean() {
+            JsVariant::Boolean(boolean)
+        } else if let Some(symbol) = inner.as_symbol() {
+            JsVariant::Symbol(symbol)
+        } else if let Some(bigint) = inner.as_bigint() {
+            JsVariant::BigInt(bigint)
+        } else if inner.is_null() {
+            JsVariant::Null
+        } else {
+            JsVariant::Undefined
+        }
     }
 
     /// Returns true if the value is an object
     #[inline]
     pub fn is_object(&self) -> bool {
-        matches!(self, Self::Object(_))
+        self.inner.is_object()
     }
 
     #[inline]
     pub fn as_object(&self) -> Option<&JsObject> {
-        match *self {
-            Self::Object(ref o) => Some(o),
-            _ => None,
-        }
+        self.inner.as_object()
     }
 
     /// It determines if the value is a callable function with a `[[Call]]` internal method.
@@ -148,7 +211,7 @@ impl JsValue {
     /// [spec]: https://tc39.es/ecma262/#sec-iscallable
     #[inline]
     pub fn is_callable(&self) -> bool {
-        matches!(self, Self::Object(obj) if obj.is_callable())
+        self.as_callable().is_some()
     }
 
     #[inline]
@@ -159,7 +222,7 @@ impl JsValue {
     /// Returns true if the value is a constructor object.
     #[inline]
     pub fn is_constructor(&self) -> bool {
-        matches!(self, Self::Object(obj) if obj.is_constructor())
+        self.as_constructor().is_some()
     }
 
     #[inline]
@@ -170,7 +233,7 @@ impl JsValue {
     /// Returns true if the value is a promise object.
     #[inline]
     pub fn is_promise(&self) -> bool {
-        matches!(self, Self::Object(obj) if obj.is_promise())
+        self.as_promise().is_some()
     }
 
     #[inline]
@@ -181,38 +244,35 @@ impl JsValue {
     /// Returns true if the value is a symbol.
     #[inline]
     pub fn is_symbol(&self) -> bool {
-        matches!(self, Self::Symbol(_))
+        self.inner.is_symbol()
     }
 
     pub fn as_symbol(&self) -> Option<JsSymbol> {
-        match self {
-            Self::Symbol(symbol) => Some(symbol.clone()),
-            _ => None,
-        }
+        self.inner.as_symbol().cloned()
     }
 
     /// Returns true if the value is undefined.
     #[inline]
     pub fn is_undefined(&self) -> bool {
-        matches!(self, Self::Undefined)
+        self.inner.is_undefined()
     }
 
     /// Returns true if the value is null.
     #[inline]
     pub fn is_null(&self) -> bool {
-        matches!(self, Self::Null)
+        self.inner.is_null()
     }
 
     /// Returns true if the value is null or undefined.
     #[inline]
     pub fn is_null_or_undefined(&self) -> bool {
-        matches!(self, Self::Null | Self::Undefined)
+        self.is_null() || self.is_undefined()
     }
 
     /// Returns true if the value is a 64-bit floating-point number.
     #[inline]
     pub fn is_double(&self) -> bool {
-        matches!(self, Self::Rational(_))
+        self.inner.is_rational()
     }
 
     /// Returns true if the value is integer.
@@ -223,24 +283,20 @@ impl JsValue {
         // equal to the original then it is an integer.
         let is_rational_integer = |n: f64| n == f64::from(n as i32);
 
-        match *self {
-            Self::Integer(_) => true,
-            Self::Rational(n) if is_rational_integer(n) => true,
-            _ => false,
-        }
+        self.inner.is_integer() || self.inner.as_rational().map_or(false, is_rational_integer)
     }
 
     /// Returns true if the value is a number.
     #[inline]
     pub fn is_number(&self) -> bool {
-        matches!(self, Self::Rational(_) | Self::Integer(_))
+        self.inner.is_rational() || self.inner.is_integer()
     }
 
     #[inline]
     pub fn as_number(&self) -> Option<f64> {
-        match *self {
-            Self::Integer(integer) => Some(integer.into()),
-            Self::Rational(rational) => Some(rational),
+        match self.variant() {
+            JsVariant::Integer(integer) => Some(integer.into()),
+            JsVariant::Rational(rational) => Some(rational),
             _ => None,
         }
     }
@@ -248,45 +304,36 @@ impl JsValue {
     /// Returns true if the value is a string.
     #[inline]
     pub fn is_string(&self) -> bool {
-        matches!(self, Self::String(_))
+        self.inner.is_string()
     }
 
     /// Returns the string if the values is a string, otherwise `None`.
     #[inline]
     pub fn as_string(&self) -> Option<&JsString> {
-        match self {
-            Self::String(ref string) => Some(string),
-            _ => None,
-        }
+        self.inner.as_string()
     }
 
     /// Returns true if the value is a boolean.
     #[inline]
     pub fn is_boolean(&self) -> bool {
-        matches!(self, Self::Boolean(_))
+        self.inner.is_boolean()
     }
 
     #[inline]
     pub fn as_boolean(&self) -> Option<bool> {
-        match self {
-            Self::Boolean(boolean) => Some(*boolean),
-            _ => None,
-        }
+        self.inner.as_boolean()
     }
 
     /// Returns true if the value is a bigint.
     #[inline]
     pub fn is_bigint(&self) -> bool {
-        matches!(self, Self::BigInt(_))
+        self.inner.is_bigint()
     }
 
     /// Returns an optional reference to a `BigInt` if the value is a `BigInt` primitive.
     #[inline]
     pub fn as_bigint(&self) -> Option<&JsBigInt> {
-        match self {
-            Self::BigInt(bigint) => Some(bigint),
-            _ => None,
-        }
+        self.inner.as_bigint()
     }
 
     /// Converts the value to a `bool` type.
@@ -296,13 +343,13 @@ impl JsValue {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-toboolean
     pub fn to_boolean(&self) -> bool {
-        match *self {
-            Self::Symbol(_) | Self::Object(_) => true,
-            Self::String(ref s) if !s.is_empty() => true,
-            Self::Rational(n) if n != 0.0 && !n.is_nan() => true,
-            Self::Integer(n) if n != 0 => true,
-            Self::BigInt(ref n) if !n.is_zero() => true,
-            Self::Boolean(v) => v,
+        match self.variant() {
+            JsVariant::Symbol(_) | JsVariant::Object(_) => true,
+            JsVariant::String(s) if !s.is_empty() => true,
+            JsVariant::Rational(n) if n != 0.0 && !n.is_nan() => true,
+            JsVariant::Integer(n) if n != 0 => true,
+            JsVariant::BigInt(n) if !n.is_zero() => true,
+            JsVariant::Boolean(v) => v,
             _ => false,
         }
     }
@@ -316,8 +363,8 @@ impl JsValue {
     {
         let key = key.into();
         let _timer = Profiler::global().start_event("Value::get_property", "value");
-        match self {
-            Self::Object(ref object) => {
+        match self.variant() {
+            JsVariant::Object(object) => {
                 // TODO: had to skip `__get_own_properties__` since we don't have context here
                 let property = object.borrow().properties().get(&key);
                 if property.is_some() {
@@ -327,7 +374,7 @@ impl JsValue {
                 object
                     .prototype()
                     .as_ref()
-                    .map_or(Self::Null, |obj| obj.clone().into())
+                    .map_or(Self::null(), |obj| obj.clone().into())
                     .get_property(key)
             }
             _ => None,
@@ -337,7 +384,7 @@ impl JsValue {
     /// Set the kind of an object.
     #[inline]
     pub fn set_data(&self, data: ObjectData) {
-        if let Self::Object(ref obj) = *self {
+        if let JsVariant::Object(obj) = self.variant() {
             obj.borrow_mut().data = data;
         }
     }
@@ -375,7 +422,9 @@ impl JsValue {
                 // v. If Type(result) is not Object, return result.
                 // vi. Throw a TypeError exception.
                 return if result.is_object() {
-                    context.throw_type_error("Symbol.toPrimitive cannot return an object")
+                    Err(JsNativeError::typ()
+                        .with_message("Symbol.toPrimitive cannot return an object")
+                        .into())
                 } else {
                     Ok(result)
                 };
@@ -402,29 +451,37 @@ impl JsValue {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-tobigint
     pub fn to_bigint(&self, context: &mut Context) -> JsResult<JsBigInt> {
-        match self {
-            Self::Null => context.throw_type_error("cannot convert null to a BigInt"),
-            Self::Undefined => context.throw_type_error("cannot convert undefined to a BigInt"),
-            Self::String(ref string) => {
-                if let Some(value) = JsBigInt::from_string(string) {
+        match self.variant() {
+            JsVariant::Null => Err(JsNativeError::typ()
+                .with_message("cannot convert null to a BigInt")
+                .into()),
+            JsVariant::Undefined => Err(JsNativeError::typ()
+                .with_message("cannot convert undefined to a BigInt")
+                .into()),
+            JsVariant::String(string) => {
+                if let Some(value) = string.to_big_int() {
                     Ok(value)
                 } else {
-                    context.throw_syntax_error(format!(
-                        "cannot convert string '{string}' to bigint primitive",
-                    ))
+                    Err(JsNativeError::syntax()
+                        .with_message(format!(
+                            "cannot convert string '{string}' to bigint primitive",
+                        ))
+                        .into())
                 }
             }
-            Self::Boolean(true) => Ok(JsBigInt::one()),
-            Self::Boolean(false) => Ok(JsBigInt::zero()),
-            Self::Integer(_) | Self::Rational(_) => {
-                context.throw_type_error("cannot convert Number to a BigInt")
-            }
-            Self::BigInt(b) => Ok(b.clone()),
-            Self::Object(_) => {
+            JsVariant::Boolean(true) => Ok(JsBigInt::one()),
+            JsVariant::Boolean(false) => Ok(JsBigInt::zero()),
+            JsVariant::Integer(_) | JsVariant::Rational(_) => Err(JsNativeError::typ()
+                .with_message("cannot convert Number to a BigInt")
+                .into()),
+            JsVariant::BigInt(b) => Ok(b.clone()),
+            JsVariant::Object(_) => {
                 let primitive = self.to_primitive(context, PreferredType::Number)?;
                 primitive.to_bigint(context)
             }
-            Self::Symbol(_) => context.throw_type_error("cannot convert Symbol to a BigInt"),
+            JsVariant::Symbol(_) => Err(JsNativeError::typ()
+                .with_message("cannot convert Symbol to a BigInt")
+                .into()),
         }
     }
 
@@ -454,16 +511,18 @@ impl JsValue {
     ///
     /// This function is equivalent to `String(value)` in JavaScript.
     pub fn to_string(&self, context: &mut Context) -> JsResult<JsString> {
-        match self {
-            Self::Null => Ok("null".into()),
-            Self::Undefined => Ok("undefined".into()),
-            Self::Boolean(boolean) => Ok(boolean.to_string().into()),
-            Self::Rational(rational) => Ok(Number::to_native_string(*rational).into()),
-            Self::Integer(integer) => Ok(integer.to_string().into()),
-            Self::String(string) => Ok(string.clone()),
-            Self::Symbol(_) => context.throw_type_error("can't convert symbol to string"),
-            Self::BigInt(ref bigint) => Ok(bigint.to_string().into()),
-            Self::Object(_) => {
+        match self.variant() {
+            JsVariant::Null => Ok("null".into()),
+            JsVariant::Undefined => Ok("undefined".into()),
+            JsVariant::Boolean(boolean) => Ok(boolean.to_string().into()),
+            JsVariant::Rational(rational) => Ok(Number::to_native_string(rational).into()),
+            JsVariant::Integer(integer) => Ok(integer.to_string().into()),
+            JsVariant::String(string) => Ok(string.clone()),
+            JsVariant::Symbol(_) => Err(JsNativeError::typ()
+                .with_message("can't convert symbol to string")
+                .into()),
+            JsVariant::BigInt(bigint) => Ok(bigint.to_string().into()),
+            JsVariant::Object(_) => {
                 let primitive = self.to_primitive(context, PreferredType::String)?;
                 primitive.to_string(context)
             }
@@ -476,32 +535,32 @@ impl JsValue {
     ///
     /// See: <https://tc39.es/ecma262/#sec-toobject>
     pub fn to_object(&self, context: &mut Context) -> JsResult<JsObject> {
-        match self {
-            Self::Undefined | Self::Null => {
-                context.throw_type_error("cannot convert 'null' or 'undefined' to object")
-            }
-            Self::Boolean(boolean) => {
+        match self.variant() {
+            JsVariant::Undefined | JsVariant::Null => Err(JsNativeError::typ()
+                .with_message("cannot convert 'null' or 'undefined' to object")
+                .into()),
+            JsVariant::Boolean(boolean) => {
                 let prototype = context.intrinsics().constructors().boolean().prototype();
                 Ok(JsObject::from_proto_and_data(
                     prototype,
-                    ObjectData::boolean(*boolean),
+                    ObjectData::boolean(boolean),
                 ))
             }
-            Self::Integer(integer) => {
+            JsVariant::Integer(integer) => {
                 let prototype = context.intrinsics().constructors().number().prototype();
                 Ok(JsObject::from_proto_and_data(
                     prototype,
-                    ObjectData::number(f64::from(*integer)),
+                    ObjectData::number(f64::from(integer)),
                 ))
             }
-            Self::Rational(rational) => {
+            JsVariant::Rational(rational) => {
                 let prototype = context.intrinsics().constructors().number().prototype();
                 Ok(JsObject::from_proto_and_data(
                     prototype,
-                    ObjectData::number(*rational),
+                    ObjectData::number(rational),
                 ))
             }
-            Self::String(ref string) => {
+            JsVariant::String(string) => {
                 let prototype = context.intrinsics().constructors().string().prototype();
 
                 let object =
@@ -510,21 +569,21 @@ impl JsValue {
                 object.insert_property(
                     "length",
                     PropertyDescriptor::builder()
-                        .value(string.encode_utf16().count())
+                        .value(string.len())
                         .writable(false)
                         .enumerable(false)
                         .configurable(false),
                 );
                 Ok(object)
             }
-            Self::Symbol(ref symbol) => {
+            JsVariant::Symbol(symbol) => {
                 let prototype = context.intrinsics().constructors().symbol().prototype();
                 Ok(JsObject::from_proto_and_data(
                     prototype,
                     ObjectData::symbol(symbol.clone()),
                 ))
             }
-            Self::BigInt(ref bigint) => {
+            JsVariant::BigInt(bigint) => {
                 let prototype = context
                     .intrinsics()
                     .constructors()
@@ -535,7 +594,7 @@ impl JsValue {
                     ObjectData::big_int(bigint.clone()),
                 ))
             }
-            Self::Object(jsobject) => Ok(jsobject.clone()),
+            JsVariant::Object(jsobject) => Ok(jsobject.clone()),
         }
     }
 
@@ -543,19 +602,22 @@ impl JsValue {
     ///
     /// See <https://tc39.es/ecma262/#sec-topropertykey>
     pub fn to_property_key(&self, context: &mut Context) -> JsResult<PropertyKey> {
-        Ok(match self {
+        Ok(match self.variant() {
             // Fast path:
-            Self::String(string) => string.clone().into(),
-            Self::Symbol(symbol) => symbol.clone().into(),
-            Self::Integer(integer) => (*integer).into(),
+            JsVariant::String(string) => string.clone().into(),
+            JsVariant::Symbol(symbol) => symbol.clone().into(),
+            JsVariant::Integer(integer) => integer.into(),
             // Slow path:
-            Self::Object(_) => match self.to_primitive(context, PreferredType::String)? {
-                Self::String(ref string) => string.clone().into(),
-                Self::Symbol(ref symbol) => symbol.clone().into(),
-                Self::Integer(integer) => integer.into(),
-                primitive => primitive.to_string(context)?.into(),
-            },
-            primitive => primitive.to_string(context)?.into(),
+            JsVariant::Object(_) => {
+                let primitive = self.to_primitive(context, PreferredType::String)?;
+                match primitive.variant() {
+                    JsVariant::String(string) => string.clone().into(),
+                    JsVariant::Symbol(symbol) => symbol.clone().into(),
+                    JsVariant::Integer(integer) => integer.into(),
+                    _ => primitive.to_string(context)?.into(),
+                }
+            }
+            _ => self.to_string(context)?.into(),
         })
     }
 
@@ -582,7 +644,7 @@ impl JsValue {
     /// See: <https://tc39.es/ecma262/#sec-touint32>
     pub fn to_u32(&self, context: &mut Context) -> JsResult<u32> {
         // This is the fast path, if the value is Integer we can just return it.
-        if let JsValue::Integer(number) = *self {
+        if let JsVariant::Integer(number) = self.variant() {
             return Ok(number as u32);
         }
         let number = self.to_number(context)?;
@@ -595,7 +657,7 @@ impl JsValue {
     /// See: <https://tc39.es/ecma262/#sec-toint32>
     pub fn to_i32(&self, context: &mut Context) -> JsResult<i32> {
         // This is the fast path, if the value is Integer we can just return it.
-        if let JsValue::Integer(number) = *self {
+        if let JsVariant::Integer(number) = self.variant() {
             return Ok(number);
         }
         let number = self.to_number(context)?;
@@ -816,7 +878,9 @@ impl JsValue {
 
         // c. If ! SameValue(𝔽(integer), clamped) is false, throw a RangeError exception.
         if integer != clamped {
-            return context.throw_range_error("Index must be between 0 and  2^53 - 1");
+            return Err(JsNativeError::range()
+                .with_message("Index must be between 0 and  2^53 - 1")
+                .into());
         }
 
         // d. Assert: 0 ≤ integer ≤ 2^53 - 1.
@@ -876,16 +940,20 @@ impl JsValue {
     ///
     /// See: <https://tc39.es/ecma262/#sec-tonumber>
     pub fn to_number(&self, context: &mut Context) -> JsResult<f64> {
-        match *self {
-            Self::Null => Ok(0.0),
-            Self::Undefined => Ok(f64::NAN),
-            Self::Boolean(b) => Ok(if b { 1.0 } else { 0.0 }),
-            Self::String(ref string) => Ok(string.string_to_number()),
-            Self::Rational(number) => Ok(number),
-            Self::Integer(integer) => Ok(f64::from(integer)),
-            Self::Symbol(_) => context.throw_type_error("argument must not be a symbol"),
-            Self::BigInt(_) => context.throw_type_error("argument must not be a bigint"),
-            Self::Object(_) => {
+        match self.variant() {
+            JsVariant::Null => Ok(0.0),
+            JsVariant::Undefined => Ok(f64::NAN),
+            JsVariant::Boolean(b) => Ok(if b { 1.0 } else { 0.0 }),
+            JsVariant::String(string) => Ok(string.string_to_number()),
+            JsVariant::Rational(number) => Ok(number),
+            JsVariant::Integer(integer) => Ok(f64::from(integer)),
+            JsVariant::Symbol(_) => Err(JsNativeError::typ()
+                .with_message("argument must not be a symbol")
+                .into()),
+            JsVariant::BigInt(_) => Err(JsNativeError::typ()
+                .with_message("argument must not be a bigint")
+                .into()),
+            JsVariant::Object(_) => {
                 let primitive = self.to_primitive(context, PreferredType::Number)?;
                 primitive.to_number(context)
             }
@@ -917,9 +985,11 @@ impl JsValue {
     /// [table]: https://tc39.es/ecma262/#table-14
     /// [spec]: https://tc39.es/ecma262/#sec-requireobjectcoercible
     #[inline]
-    pub fn require_object_coercible(&self, context: &mut Context) -> JsResult<&Self> {
+    pub fn require_object_coercible(&self, _context: &mut Context) -> JsResult<&Self> {
         if self.is_null_or_undefined() {
-            context.throw_type_error("cannot convert null or undefined to Object")
+            Err(JsNativeError::typ()
+                .with_message("cannot convert null or undefined to Object")
+                .into())
         } else {
             Ok(self)
         }
@@ -930,9 +1000,9 @@ impl JsValue {
         // 1. If Type(Obj) is not Object, throw a TypeError exception.
         self.as_object()
             .ok_or_else(|| {
-                context.construct_type_error(
-                    "Cannot construct a property descriptor from a non-object",
-                )
+                JsNativeError::typ()
+                    .with_message("Cannot construct a property descriptor from a non-object")
+                    .into()
             })
             .and_then(|obj| obj.to_property_descriptor(context))
     }
@@ -945,15 +1015,15 @@ impl JsValue {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-typeof-operator
     pub fn type_of(&self) -> JsString {
-        match *self {
-            Self::Rational(_) | Self::Integer(_) => "number",
-            Self::String(_) => "string",
-            Self::Boolean(_) => "boolean",
-            Self::Symbol(_) => "symbol",
-            Self::Null => "object",
-            Self::Undefined => "undefined",
-            Self::BigInt(_) => "bigint",
-            Self::Object(ref object) => {
+        match self.variant() {
+            JsVariant::Rational(_) | JsVariant::Integer(_) => "number",
+            JsVariant::String(_) => "string",
+            JsVariant::Boolean(_) => "boolean",
+            JsVariant::Symbol(_) => "symbol",
+            JsVariant::Null => "object",
+            JsVariant::Undefined => "undefined",
+            JsVariant::BigInt(_) => "bigint",
+            JsVariant::Object(object) => {
                 if object.is_callable() {
                     "function"
                 } else {
@@ -972,13 +1042,13 @@ impl JsValue {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-isarray
-    pub(crate) fn is_array(&self, context: &mut Context) -> JsResult<bool> {
+    pub(crate) fn is_array(&self) -> JsResult<bool> {
         // Note: The spec specifies this function for JsValue.
         // The main part of the function is implemented for JsObject.
 
         // 1. If Type(argument) is not Object, return false.
         if let Some(object) = self.as_object() {
-            object.is_array_abstract(context)
+            object.is_array_abstract()
         }
         // 4. Return false.
         else {
@@ -989,7 +1059,7 @@ impl JsValue {
 
 impl Default for JsValue {
     fn default() -> Self {
-        Self::Undefined
+        Self::undefined()
     }
 }
 
@@ -1,10 +1,10 @@
 //! This module implements the conversions from and into [`serde_json::Value`].
 
-use super::JsValue;
+use super::{JsValue, JsVariant};
 use crate::{
     builtins::Array,
     property::{PropertyDescriptor, PropertyKey},
-    Context, JsResult,
+    Context, JsNativeError, JsResult,
 };
 use serde_json::{Map, Value};
 
@@ -41,17 +41,17 @@ impl JsValue {
         const MIN_INT: i64 = i32::MIN as i64;
 
         match json {
-            Value::Null => Ok(Self::Null),
-            Value::Bool(b) => Ok(Self::Boolean(*b)),
+            Value::Null => Ok(Self::null()),
+            Value::Bool(b) => Ok(Self::new(*b)),
             Value::Number(num) => num
                 .as_i64()
                 .filter(|n| (MIN_INT..=MAX_INT).contains(n))
-                .map(|i| Self::Integer(i as i32))
-                .or_else(|| num.as_f64().map(Self::Rational))
+                .map(|i| Self::integer(i as i32))
+                .or_else(|| num.as_f64().map(Self::rational))
                 .ok_or_else(|| {
-                    context.construct_type_error(format!(
-                        "could not convert JSON number {num} to JsValue"
-                    ))
+                    JsNativeError::typ()
+                        .with_message(format!("could not convert JSON number {num} to JsValue"))
+                        .into()
                 }),
             Value::String(string) => Ok(Self::from(string.as_str())),
             Value::Array(vec) => {
@@ -104,15 +104,17 @@ impl JsValue {
     /// # assert_eq!(json, back_to_json);
     /// ```
     pub fn to_json(&self, context: &mut Context) -> JsResult<Value> {
-        match self {
-            Self::Null => Ok(Value::Null),
-            Self::Undefined => todo!("undefined to JSON"),
-            &Self::Boolean(b) => Ok(b.into()),
-            Self::String(string) => Ok(string.as_str().into()),
-            &Self::Rational(rat) => Ok(rat.into()),
-            &Self::Integer(int) => Ok(int.into()),
-            Self::BigInt(_bigint) => context.throw_type_error("cannot convert bigint to JSON"),
-            Self::Object(obj) => {
+        match self.variant() {
+            JsVariant::Null => Ok(Value::Null),
+            JsVariant::Undefined => todo!("undefined to JSON"),
+            JsVariant::Boolean(b) => Ok(b.into()),
+            JsVariant::String(string) => Ok(string.to_std_string_escaped().into()),
+            JsVariant::Rational(rat) => Ok(rat.into()),
+            JsVariant::Integer(int) => Ok(int.into()),
+            JsVariant::BigInt(_bigint) => Err(JsNativeError::typ()
+                .with_message("cannot convert bigint to JSON")
+                .into()),
+            JsVariant::Object(obj) => {
                 if obj.is_array() {
                     let len = obj.length_of_array_like(context)?;
                     let mut arr = Vec::with_capacity(len as usize);
@@ -120,9 +122,12 @@ impl JsValue {
                     let obj = obj.borrow();
 
                     for k in 0..len as u32 {
-                        let val = obj.properties().get(&k.into()).map_or(Self::Null, |desc| {
-                            desc.value().cloned().unwrap_or(Self::Null)
-                        });
+                        let val = obj
+                            .properties()
+                            .get(&k.into())
+                            .map_or(Self::null(), |desc| {
+                                desc.value().cloned().unwrap_or(Self::null())
+                            });
                         arr.push(val.to_json(context)?);
                     }
 
@@ -131,10 +136,12 @@ impl JsValue {
                     let mut map = Map::new();
                     for (key, property) in obj.borrow().properties().iter() {
                         let key = match &key {
-                            PropertyKey::String(string) => string.as_str().to_owned(),
+                            PropertyKey::String(string) => string.to_std_string_escaped(),
                             PropertyKey::Index(i) => i.to_string(),
                             PropertyKey::Symbol(_sym) => {
-                                return context.throw_type_error("cannot convert Symbol to JSON")
+                                return Err(JsNativeError::typ()
+                                    .with_message("cannot convert Symbol to JSON")
+                                    .into())
                             }
                         };
 
@@ -149,7 +156,9 @@ impl JsValue {
                     Ok(Value::Object(map))
                 }
             }
-            Self::Symbol(_sym) => context.throw_type_error("cannot convert Symbol to JSON"),
+            JsVariant::Symbol(_sym) => Err(JsNativeError::typ()
+                .with_message("cannot convert Symbol to JSON")
+                .into()),
         }
     }
 }
@@ -1,10 +1,11 @@
 #![allow(clippy::float_cmp)]
 
 use super::*;
-use crate::{check_output, forward, forward_val, Context, TestAction};
+use crate::{check_output, forward, forward_val, property::Attribute, Context, TestAction};
 
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
+use std::mem::size_of;
 
 #[test]
 fn string_to_value() {
@@ -14,6 +15,114 @@ fn string_to_value() {
     assert!(!v.is_null());
 }
 
+#[test]
+fn value_size() {
+    assert_eq!(size_of::<JsValue>(), 8);
+
+    // The primitives stored behind a pointer are stored in place.
+    assert_eq!(size_of::<JsString>(), size_of::<usize>());
+    assert_eq!(size_of::<JsSymbol>(), size_of::<usize>());
+    assert_eq!(size_of::<JsBigInt>(), size_of::<usize>());
+}
+
+#[test]
+fn variants() {
+    let context = Context::default();
+    let object = context.construct_object();
+    let symbol = JsSymbol::new(Some("s".into()));
+    let bigint = JsBigInt::new(1_i64 << 60);
+
+    assert!(matches!(
+        JsValue::undefined().variant(),
+        JsVariant::Undefined
+    ));
+    assert!(matches!(JsValue::null().variant(), JsVariant::Null));
+    assert!(matches!(
+        JsValue::new(false).variant(),
+        JsVariant::Boolean(false)
+    ));
+    assert!(matches!(
+        JsValue::new(true).variant(),
+        JsVariant::Boolean(true)
+    ));
+    for integer in [0, 1, -1, i32::MIN, i32::MAX] {
+        assert!(matches!(JsValue::new(integer).variant(), JsVariant::Integer(i) if i == integer));
+    }
+    for rational in [
+        0.0,
+        1.5,
+        f64::MAX,
+        f64::MIN_POSITIVE,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ] {
+        assert!(
+            matches!(JsValue::new(rational).variant(), JsVariant::Rational(r) if r == rational)
+        );
+    }
+    assert!(
+        matches!(JsValue::new(-0.0).variant(), JsVariant::Rational(r) if r == 0.0 && r.is_sign_negative())
+    );
+    for nan in [f64::NAN, -f64::NAN, f64::from_bits(0x7FF0_0000_0000_0001)] {
+        assert!(matches!(JsValue::new(nan).variant(), JsVariant::Rational(r) if r.is_nan()));
+    }
+    assert!(matches!(JsValue::new("").variant(), JsVariant::String(s) if s.is_empty()));
+    assert!(
+        matches!(JsValue::new("a longer string").variant(), JsVariant::String(s) if s == "a longer string")
+    );
+    assert!(matches!(JsValue::new(symbol.clone()).variant(), JsVariant::Symbol(s) if *s == symbol));
+    assert!(matches!(JsValue::new(bigint.clone()).variant(), JsVariant::BigInt(b) if *b == bigint));
+    assert!(
+        matches!(JsValue::new(object.clone()).variant(), JsVariant::Object(o) if JsObject::equals(o, &object))
+    );
+}
+
+#[test]
+fn clone_and_drop_values() {
+    let string = JsString::new("a heap allocated string");
+    let value = JsValue::new(string.clone());
+    assert_eq!(JsString::refcount(&string), Some(2));
+    let copy = value.clone();
+    assert_eq!(JsString::refcount(&string), Some(3));
+    assert!(JsString::ptr_eq(copy.as_string().unwrap(), &string));
+    drop(value);
+    drop(copy);
+    assert_eq!(JsString::refcount(&string), Some(1));
+
+    let symbol = JsValue::new(JsSymbol::new(None));
+    assert_eq!(symbol.clone().as_symbol(), symbol.as_symbol());
+    let bigint = JsValue::new(JsBigInt::new(7));
+    assert_eq!(bigint.clone().as_bigint(), bigint.as_bigint());
+}
+
+#[test]
+fn values_in_garbage_collected_objects() {
+    let mut context = Context::default();
+    let object = context.construct_object();
+    object
+        .set("inner", context.construct_object(), false, &mut context)
+        .unwrap();
+    object
+        .set("string", "a heap allocated string", false, &mut context)
+        .unwrap();
+    boa_gc::force_collect();
+
+    let inner = object.get("inner", &mut context).unwrap();
+    inner
+        .as_object()
+        .unwrap()
+        .set("value", 1, false, &mut context)
+        .unwrap();
+    context.register_global_property("o", object, Attribute::all());
+    drop(inner);
+    boa_gc::force_collect();
+
+    assert_eq!(
+        forward(&mut context, "o.inner.value + o.string"),
+        "\"1a heap allocated string\""
+    );
+}
+
 #[test]
 fn undefined() {
     let u = JsValue::undefined();
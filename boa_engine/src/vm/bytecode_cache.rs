@@ -0,0 +1,763 @@
+//! Serialization of compiled scripts to a bytecode cache.
+//!
+//! A script compiled to a [`CodeBlock`] can be serialized with
+//! [`Context::serialize_code_block`] and loaded back with [`Context::deserialize_code_block`],
+//! which skips parsing and compiling the script again, e.g. on the next start of a process.
+//!
+//! The format is a versioned binary format. It starts with a header with the version of the
+//! format and of the engine, and caches written by other versions are rejected. The interned
+//! strings of the code are stored as UTF-16 strings, and interned again in the interner of the
+//! context loading the cache.
+//!
+//! The numbers of the format are little-endian, but the bytecode is stored as it is in memory,
+//! with its operands in the byte order of the platform. Because of this, the header also holds
+//! the byte order and the pointer width of the platform writing the cache, and caches written
+//! on other platforms are rejected.
+//!
+//! The bytecode is not verified when it is loaded, so only caches written by
+//! [`Context::serialize_code_block`] should be loaded.
+
+use crate::{
+    builtins::function::ThisMode,
+    environments::{BindingLocator, CompileTimeBinding, CompileTimeEnvironment},
+    property::PropertyDescriptor,
+    syntax::ast::{
+        node::{
+            declaration::{BindingPatternTypeArray, Declaration},
+            FormalParameter, FormalParameterList, FormalParameterListFlags, Node,
+        },
+        Position,
+    },
+    vm::{CodeBlock, InlineCache},
+    Context, JsBigInt, JsNativeError, JsResult, JsString, JsValue, JsVariant,
+};
+use boa_gc::{Cell, Gc};
+use boa_interner::{Interner, Sym};
+use rustc_hash::FxHashMap;
+
+/// The bytes every bytecode cache starts with.
+const MAGIC: [u8; 4] = *b"BOAC";
+
+/// The version of the format of the bytecode cache and of heap snapshots.
+///
+/// This must be incremented whenever the format changes.
+const FORMAT_VERSION: u32 = 4;
+
+/// The version of the engine, since the bytecode changes between versions.
+const ENGINE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+impl Context {
+    /// Serializes a script compiled by this context to the bytecode cache format.
+    ///
+    /// The cache can be loaded with [`Context::deserialize_code_block`], in this context or in
+    /// another one. Besides the code of the script, the cache holds the lexically declared
+    /// bindings of the global scope, so scripts that depend on each other's global `let` and
+    /// `const` declarations must be loaded in the same order as they were compiled.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `RangeError` if the script is too large for the format, which stores lengths
+    /// and indices as 32-bit numbers.
+    pub fn serialize_code_block(&self, code_block: &CodeBlock) -> JsResult<Vec<u8>> {
+        let mut encoder = Encoder::new(self.interner());
+
+        let global = self.realm().compile_environment();
+        let global = global.borrow();
+        let mut bindings: Vec<_> = global.bindings.iter().collect();
+        bindings.sort_by_key(|(_, binding)| binding.index);
+        encoder.usize(bindings.len());
+        for (name, binding) in bindings {
+            encoder.sym(*name);
+            encoder.binding(binding);
+        }
+
+        encoder.code_block(code_block);
+        encoder.finish(MAGIC)
+    }
+
+    /// Loads a script serialized with [`Context::serialize_code_block`].
+    ///
+    /// This declares the global bindings of the script, like compiling the script does, and
+    /// returns the code of the script, which can be run with [`Context::execute`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the cache is malformed, if it was written by another version
+    /// of the engine or on another platform, or if the global bindings it declares conflict
+    /// with the global bindings of this context.
+    pub fn deserialize_code_block(&mut self, bytes: &[u8]) -> JsResult<Gc<CodeBlock>> {
+        let mut decoder = Decoder::new(bytes, MAGIC, "bytecode cache", self.interner_mut())?;
+
+        let global = self.realm().compile_environment();
+        let count = decoder.usize()?;
+        let mut bindings = Vec::with_capacity(count);
+        for _ in 0..count {
+            let name = decoder.sym()?;
+            bindings.push((name, decoder.binding()?));
+        }
+
+        let code_block = decoder.code_block(&global)?;
+        decoder.finish()?;
+
+        {
+            let mut global = global.borrow_mut();
+            for (name, binding) in bindings {
+                match global.bindings.get(&name) {
+                    Some(existing)
+                        if existing.index == binding.index
+                            && existing.mutable == binding.mutable
+                            && existing.lex == binding.lex => {}
+                    Some(_) => {
+                        return Err(JsNativeError::typ()
+                            .with_message(format!(
+                                "bytecode cache conflicts with the global binding '{}'",
+                                self.interner().resolve_expect(name)
+                            ))
+                            .into())
+                    }
+                    None if binding.index == global.bindings.len() => {
+                        global.bindings.insert(name, binding);
+                    }
+                    None => {
+                        return Err(JsNativeError::typ()
+                            .with_message(
+                                "bytecode cache was compiled with other global declarations",
+                            )
+                            .into())
+                    }
+                }
+            }
+        }
+
+        for name in &code_block.global_var_names {
+            let name = JsString::from(self.interner().resolve_expect(*name));
+            self.global_bindings_mut().entry(name).or_insert_with(|| {
+                PropertyDescriptor::builder()
+                    .value(JsValue::undefined())
+                    .writable(true)
+                    .enumerable(true)
+                    .configurable(true)
+                    .build()
+            });
+        }
+
+        Ok(Gc::new(code_block))
+    }
+}
+
+/// Writes a bytecode cache.
+///
+/// This is also used to write heap snapshots, which contain the code of the functions in the
+/// heap.
+///
+/// Lengths and indices are written as 32-bit numbers. A larger value is remembered and
+/// reported as an error by [`Encoder::finish`], so the writing methods don't need to return
+/// errors.
+pub(crate) struct Encoder<'a> {
+    interner: &'a Interner,
+    bytes: Vec<u8>,
+    symbols: FxHashMap<Sym, usize>,
+    symbol_table: Vec<Sym>,
+    environments: FxHashMap<*const Cell<CompileTimeEnvironment>, usize>,
+    /// Whether a length or an index didn't fit in 32 bits.
+    overflow: bool,
+}
+
+impl<'a> Encoder<'a> {
+    pub(crate) fn new(interner: &'a Interner) -> Self {
+        Self {
+            interner,
+            bytes: Vec::new(),
+            symbols: FxHashMap::default(),
+            symbol_table: Vec::new(),
+            environments: FxHashMap::default(),
+            overflow: false,
+        }
+    }
+
+    /// Returns the written bytes, with a header starting with `magic` and the table of the
+    /// interned strings.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `RangeError` if a written length or index didn't fit in 32 bits.
+    pub(crate) fn finish(self, magic: [u8; 4]) -> JsResult<Vec<u8>> {
+        let mut header = Self::new(self.interner);
+        header.bytes.extend_from_slice(&magic);
+        header.u32(FORMAT_VERSION);
+        header.str(ENGINE_VERSION);
+        header.bool(cfg!(target_endian = "big"));
+        header.usize(std::mem::size_of::<usize>());
+        header.usize(self.symbol_table.len());
+        for sym in &self.symbol_table {
+            header.utf16(&self.interner.resolve_expect_utf16(*sym));
+        }
+
+        if self.overflow || header.overflow {
+            return Err(JsNativeError::range()
+                .with_message("the code is too large to be serialized")
+                .into());
+        }
+
+        let mut bytes = header.bytes;
+        bytes.extend_from_slice(&self.bytes);
+        Ok(bytes)
+    }
+
+    pub(crate) fn u8(&mut self, value: u8) {
+        self.bytes.push(value);
+    }
+
+    pub(crate) fn bool(&mut self, value: bool) {
+        self.u8(u8::from(value));
+    }
+
+    pub(crate) fn u32(&mut self, value: u32) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub(crate) fn usize(&mut self, value: usize) {
+        let value = u32::try_from(value).unwrap_or_else(|_| {
+            self.overflow = true;
+            u32::MAX
+        });
+        self.u32(value);
+    }
+
+    pub(crate) fn f64(&mut self, value: f64) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub(crate) fn data(&mut self, data: &[u8]) {
+        self.usize(data.len());
+        self.bytes.extend_from_slice(data);
+    }
+
+    pub(crate) fn str(&mut self, value: &str) {
+        self.usize(value.len());
+        self.bytes.extend_from_slice(value.as_bytes());
+    }
+
+    pub(crate) fn utf16(&mut self, value: &[u16]) {
+        self.usize(value.len());
+        for unit in value {
+            self.bytes.extend_from_slice(&unit.to_le_bytes());
+        }
+    }
+
+    pub(crate) fn sym(&mut self, sym: Sym) {
+        let next = self.symbol_table.len();
+        let index = *self.symbols.entry(sym).or_insert(next);
+        if index == next {
+            self.symbol_table.push(sym);
+        }
+        self.usize(index);
+    }
+
+    pub(crate) fn syms(&mut self, syms: &[Sym]) {
+        self.usize(syms.len());
+        for sym in syms {
+            self.sym(*sym);
+        }
+    }
+
+    pub(crate) fn binding(&mut self, binding: &CompileTimeBinding) {
+        self.usize(binding.index);
+        self.bool(binding.mutable);
+        self.bool(binding.lex);
+    }
+
+    fn binding_locator(&mut self, locator: BindingLocator) {
+        self.sym(locator.name());
+        if locator.is_global() {
+            self.u8(0);
+        } else if locator.is_mutate_immutable() {
+            self.u8(1);
+        } else {
+            self.u8(2);
+            self.usize(locator.environment_index());
+            self.usize(locator.binding_index());
+        }
+    }
+
+    /// Writes a compile time environment.
+    ///
+    /// The global environment is written as a reference to the global environment of the
+    /// context loading the cache. Every other environment is written the first time it is
+    /// found, after its outer environment, and as its index afterwards.
+    pub(crate) fn environment(&mut self, environment: &Gc<Cell<CompileTimeEnvironment>>) {
+        let environment_ref = environment.borrow();
+        let outer = if let Some(outer) = &environment_ref.outer {
+            outer
+        } else {
+            self.u8(0);
+            return;
+        };
+
+        let key: *const Cell<CompileTimeEnvironment> = &**environment;
+        if let Some(index) = self.environments.get(&key).copied() {
+            self.u8(1);
+            self.usize(index);
+            return;
+        }
+
+        self.u8(2);
+        self.environment(outer);
+        let index = self.environments.len();
+        self.environments.insert(key, index);
+
+        self.usize(environment_ref.environment_index);
+        self.bool(environment_ref.function_scope);
+        let mut bindings: Vec<_> = environment_ref.bindings.iter().collect();
+        bindings.sort_by_key(|(_, binding)| binding.index);
+        self.usize(bindings.len());
+        for (name, binding) in bindings {
+            self.sym(*name);
+            self.binding(binding);
+        }
+    }
+
+    /// Writes the parameters of a function.
+    ///
+    /// The initializers of the parameters are part of the bytecode of the function, so only
+    /// whether a parameter has an initializer is written.
+    fn parameters(&mut self, parameters: &FormalParameterList) {
+        self.u8(parameters.flags.bits());
+        self.u32(parameters.length());
+        self.usize(parameters.parameters.len());
+        for parameter in parameters.parameters.iter() {
+            self.bool(parameter.is_rest_param());
+            self.bool(parameter.is_identifier());
+            self.bool(parameter.init().is_some());
+            self.syms(&parameter.names());
+        }
+    }
+
+    fn literal(&mut self, literal: &JsValue) {
+        match literal.variant() {
+            JsVariant::String(string) => {
+                self.u8(0);
+                self.utf16(string);
+            }
+            JsVariant::BigInt(bigint) => {
+                self.u8(1);
+                self.str(&bigint.to_string_radix(16));
+            }
+            _ => unreachable!("literals are strings or bigints"),
+        }
+    }
+
+    pub(crate) fn code_block(&mut self, code_block: &CodeBlock) {
+        self.sym(code_block.name);
+        self.u32(code_block.length);
+        self.bool(code_block.strict);
+        self.u8(match code_block.this_mode {
+            ThisMode::Lexical => 0,
+            ThisMode::Strict => 1,
+            ThisMode::Global => 2,
+        });
+        self.parameters(&code_block.params);
+
+        self.usize(code_block.code.len());
+        self.bytes.extend_from_slice(&code_block.code);
+
+        self.usize(code_block.literals.len());
+        for literal in &code_block.literals {
+            self.literal(literal);
+        }
+
+        self.syms(&code_block.names);
+
+        self.usize(code_block.inline_caches.len());
+        for cache in &code_block.inline_caches {
+            self.sym(cache.name());
+        }
+
+        self.usize(code_block.bindings.len());
+        for binding in &code_block.bindings {
+            self.binding_locator(*binding);
+        }
+
+        self.syms(&code_block.global_var_names);
+        self.usize(code_block.num_bindings);
+
+        self.usize(code_block.functions.len());
+        for function in &code_block.functions {
+            self.code_block(function);
+        }
+
+        match code_block.arguments_binding {
+            Some(binding) => {
+                self.bool(true);
+                self.binding_locator(binding);
+            }
+            None => self.bool(false),
+        }
+
+        self.usize(code_block.compile_environments.len());
+        for environment in &code_block.compile_environments {
+            self.environment(environment);
+        }
+
+        self.bool(code_block.is_class_constructor);
+        self.u32(code_block.function_environment_push_location);
+
+        match &code_block.source_name {
+            Some(source_name) => {
+                self.bool(true);
+                self.utf16(source_name);
+            }
+            None => self.bool(false),
+        }
+
+        self.usize(code_block.positions.len());
+        for (location, position) in &code_block.positions {
+            self.u32(*location);
+            self.u32(position.line_number());
+            self.u32(position.column_number());
+        }
+    }
+}
+
+/// Reads a bytecode cache.
+///
+/// This is also used to read heap snapshots.
+pub(crate) struct Decoder<'a> {
+    bytes: &'a [u8],
+    /// What is being read, for error messages.
+    name: &'static str,
+    symbols: Vec<Sym>,
+    strings: Vec<JsString>,
+    environments: Vec<Gc<Cell<CompileTimeEnvironment>>>,
+}
+
+impl<'a> Decoder<'a> {
+    /// Reads the header and the table of the interned strings of the bytes written by an
+    /// [`Encoder`], which must start with `magic`.
+    pub(crate) fn new(
+        bytes: &'a [u8],
+        magic: [u8; 4],
+        name: &'static str,
+        interner: &mut Interner,
+    ) -> JsResult<Self> {
+        let mut decoder = Self {
+            bytes,
+            name,
+            symbols: Vec::new(),
+            strings: Vec::new(),
+            environments: Vec::new(),
+        };
+
+        if decoder.take(magic.len())? != magic.as_slice() {
+            return Err(JsNativeError::typ()
+                .with_message(format!("not a {name}"))
+                .into());
+        }
+        let format_version = decoder.u32()?;
+        let engine_version = decoder.str()?;
+        if format_version != FORMAT_VERSION || engine_version != ENGINE_VERSION {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "{name} was written by version {engine_version} (format \
+                     {format_version}) of the engine"
+                ))
+                .into());
+        }
+        let big_endian = decoder.bool()?;
+        let pointer_width = decoder.usize()?;
+        if big_endian != cfg!(target_endian = "big")
+            || pointer_width != std::mem::size_of::<usize>()
+        {
+            return Err(JsNativeError::typ()
+                .with_message(format!("{name} was written on another platform"))
+                .into());
+        }
+
+        let count = decoder.usize()?;
+        for _ in 0..count {
+            let string = decoder.utf16()?;
+            decoder.symbols.push(interner.get_or_intern_utf16(&string));
+            decoder.strings.push(string);
+        }
+
+        Ok(decoder)
+    }
+
+    /// Returns the error of malformed bytes.
+    pub(crate) fn malformed(&self) -> JsNativeError {
+        JsNativeError::typ().with_message(format!("malformed {}", self.name))
+    }
+
+    /// Checks that all the bytes were read.
+    pub(crate) fn finish(&self) -> JsResult<()> {
+        if self.bytes.is_empty() {
+            Ok(())
+        } else {
+            Err(self.malformed().into())
+        }
+    }
+
+    fn take(&mut self, len: usize) -> JsResult<&'a [u8]> {
+        if len > self.bytes.len() {
+            return Err(self.malformed().into());
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    pub(crate) fn u8(&mut self) -> JsResult<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub(crate) fn bool(&mut self) -> JsResult<bool> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(self.malformed().into()),
+        }
+    }
+
+    pub(crate) fn u32(&mut self) -> JsResult<u32> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    pub(crate) fn usize(&mut self) -> JsResult<usize> {
+        Ok(self.u32()? as usize)
+    }
+
+    pub(crate) fn f64(&mut self) -> JsResult<f64> {
+        let bytes = self.take(8)?;
+        let mut array = [0; 8];
+        array.copy_from_slice(bytes);
+        Ok(f64::from_le_bytes(array))
+    }
+
+    pub(crate) fn data(&mut self) -> JsResult<&'a [u8]> {
+        let len = self.usize()?;
+        self.take(len)
+    }
+
+    pub(crate) fn str(&mut self) -> JsResult<&'a str> {
+        let len = self.usize()?;
+        std::str::from_utf8(self.take(len)?).map_err(|_| self.malformed().into())
+    }
+
+    pub(crate) fn utf16(&mut self) -> JsResult<JsString> {
+        let len = self.usize()?;
+        let bytes = self.take(len.checked_mul(2).ok_or_else(|| self.malformed())?)?;
+        Ok(JsString::from(
+            bytes
+                .chunks_exact(2)
+                .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+                .collect::<Vec<_>>(),
+        ))
+    }
+
+    pub(crate) fn sym(&mut self) -> JsResult<Sym> {
+        let index = self.usize()?;
+        self.symbols
+            .get(index)
+            .copied()
+            .ok_or_else(|| self.malformed().into())
+    }
+
+    pub(crate) fn syms(&mut self) -> JsResult<Vec<Sym>> {
+        let len = self.usize()?;
+        (0..len).map(|_| self.sym()).collect()
+    }
+
+    pub(crate) fn binding(&mut self) -> JsResult<CompileTimeBinding> {
+        Ok(CompileTimeBinding {
+            index: self.usize()?,
+            mutable: self.bool()?,
+            lex: self.bool()?,
+        })
+    }
+
+    fn binding_locator(&mut self) -> JsResult<BindingLocator> {
+        let name = self.sym()?;
+        match self.u8()? {
+            0 => Ok(BindingLocator::global(name)),
+            1 => Ok(BindingLocator::mutate_immutable(name)),
+            2 => Ok(BindingLocator::declarative(
+                name,
+                self.usize()?,
+                self.usize()?,
+            )),
+            _ => Err(self.malformed().into()),
+        }
+    }
+
+    pub(crate) fn environment(
+        &mut self,
+        global: &Gc<Cell<CompileTimeEnvironment>>,
+    ) -> JsResult<Gc<Cell<CompileTimeEnvironment>>> {
+        match self.u8()? {
+            0 => Ok(global.clone()),
+            1 => {
+                let index = self.usize()?;
+                self.environments
+                    .get(index)
+                    .cloned()
+                    .ok_or_else(|| self.malformed().into())
+            }
+            2 => {
+                let outer = self.environment(global)?;
+                let environment_index = self.usize()?;
+                let function_scope = self.bool()?;
+                let len = self.usize()?;
+                let mut bindings = FxHashMap::default();
+                for _ in 0..len {
+                    let name = self.sym()?;
+                    bindings.insert(name, self.binding()?);
+                }
+
+                let environment = Gc::new(Cell::new(CompileTimeEnvironment {
+                    outer: Some(outer),
+                    environment_index,
+                    bindings,
+                    function_scope,
+                }));
+                self.environments.push(environment.clone());
+                Ok(environment)
+            }
+            _ => Err(self.malformed().into()),
+        }
+    }
+
+    /// Reads the parameters of a function.
+    ///
+    /// Parameters with initializers get an empty initializer, since the initializers are part
+    /// of the bytecode of the function. Binding patterns are read as array binding patterns
+    /// with the names bound by the pattern.
+    fn parameters(&mut self) -> JsResult<FormalParameterList> {
+        let flags =
+            FormalParameterListFlags::from_bits(self.u8()?).ok_or_else(|| self.malformed())?;
+        let length = self.u32()?;
+        let len = self.usize()?;
+        let mut parameters = Vec::with_capacity(len);
+        for _ in 0..len {
+            let is_rest = self.bool()?;
+            let is_identifier = self.bool()?;
+            let init = self.bool()?.then_some(Node::Empty);
+            let names = self.syms()?;
+            let declaration = if is_identifier {
+                let name = *names.first().ok_or_else(|| self.malformed())?;
+                Declaration::new_with_identifier(name, init)
+            } else {
+                let bindings = names
+                    .into_iter()
+                    .map(|ident| BindingPatternTypeArray::SingleName {
+                        ident,
+                        default_init: None,
+                    })
+                    .collect();
+                Declaration::new_with_array_pattern(bindings, init)
+            };
+            parameters.push(FormalParameter::new(declaration, is_rest));
+        }
+
+        Ok(FormalParameterList::new(
+            parameters.into_boxed_slice(),
+            flags,
+            length,
+        ))
+    }
+
+    fn literal(&mut self) -> JsResult<JsValue> {
+        match self.u8()? {
+            0 => Ok(JsValue::new(self.utf16()?)),
+            1 => JsBigInt::from_string_radix(self.str()?, 16)
+                .map(JsValue::new)
+                .ok_or_else(|| self.malformed().into()),
+            _ => Err(self.malformed().into()),
+        }
+    }
+
+    pub(crate) fn code_block(
+        &mut self,
+        global: &Gc<Cell<CompileTimeEnvironment>>,
+    ) -> JsResult<CodeBlock> {
+        let name = self.sym()?;
+        let length = self.u32()?;
+        let strict = self.bool()?;
+        let mut code_block = CodeBlock::new(name, length, strict);
+
+        code_block.this_mode = match self.u8()? {
+            0 => ThisMode::Lexical,
+            1 => ThisMode::Strict,
+            2 => ThisMode::Global,
+            _ => return Err(self.malformed().into()),
+        };
+        code_block.params = self.parameters()?;
+
+        let len = self.usize()?;
+        code_block.code = self.take(len)?.to_vec();
+
+        let len = self.usize()?;
+        code_block.literals = (0..len).map(|_| self.literal()).collect::<JsResult<_>>()?;
+
+        code_block.names = self.syms()?;
+
+        let len = self.usize()?;
+        for _ in 0..len {
+            let index = self.usize()?;
+            let (name, key) = self
+                .symbols
+                .get(index)
+                .zip(self.strings.get(index))
+                .ok_or_else(|| self.malformed())?;
+            code_block
+                .inline_caches
+                .push(InlineCache::new(*name, key.clone()));
+        }
+
+        let len = self.usize()?;
+        code_block.bindings = (0..len)
+            .map(|_| self.binding_locator())
+            .collect::<JsResult<_>>()?;
+
+        code_block.global_var_names = self.syms()?;
+        code_block.num_bindings = self.usize()?;
+
+        let len = self.usize()?;
+        for _ in 0..len {
+            let function = self.code_block(global)?;
+            code_block.functions.push(Gc::new(function));
+        }
+
+        if self.bool()? {
+            code_block.arguments_binding = Some(self.binding_locator()?);
+        }
+
+        let len = self.usize()?;
+        for _ in 0..len {
+            let environment = self.environment(global)?;
+            code_block.compile_environments.push(environment);
+        }
+
+        code_block.is_class_constructor = self.bool()?;
+        code_block.function_environment_push_location = self.u32()?;
+
+        if self.bool()? {
+            code_block.source_name = Some(self.utf16()?);
+        }
+
+        let len = self.usize()?;
+        for _ in 0..len {
+            let location = self.u32()?;
+            let line_number = self.u32()?;
+            let column_number = self.u32()?;
+            if line_number == 0 || column_number == 0 {
+                return Err(self.malformed().into());
+            }
+            code_block
+                .positions
+                .push((location, Position::new(line_number, column_number)));
+        }
+
+        Ok(code_block)
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! This module will provides everything needed to implement the `CallFrame`
 
-use crate::{object::JsObject, vm::CodeBlock};
+use crate::{environments::DeclarativeEnvironmentStack, object::JsObject, vm::CodeBlock, JsValue};
 use boa_gc::{Finalize, Gc, Trace};
 
 #[derive(Clone, Debug, Finalize, Trace)]
@@ -36,6 +36,14 @@ pub struct CallFrame {
     // When an async generator is resumed, the generator object is needed
     // to fulfill the steps 4.e-j in [AsyncGeneratorStart](https://tc39.es/ecma262/#sec-asyncgeneratorstart).
     pub(crate) async_generator: Option<JsObject>,
+
+    // A call in tail position that ended the execution of the frame, and that has to be made
+    // by the caller of the frame once it has been popped.
+    pub(crate) tail_call: Option<TailCall>,
+
+    // The environments of the frame while it is suspended by a call to another function.
+    // They are only saved while a debugger is attached, to let it read the locals of the frame.
+    pub(crate) suspended_environments: Option<DeclarativeEnvironmentStack>,
 }
 
 impl CallFrame {
@@ -88,6 +96,14 @@ impl CallFrame {
     }
 }
 
+/// A call in tail position, which replaces the frame it was made from.
+#[derive(Clone, Debug, Finalize, Trace)]
+pub(crate) struct TailCall {
+    pub(crate) function: JsObject,
+    pub(crate) this: JsValue,
+    pub(crate) arguments: Vec<JsValue>,
+}
+
 /// Tracks the number of environments in the current try-catch-finally block.
 ///
 /// Because of the interactions between loops and try-catch-finally blocks,
@@ -12,15 +12,19 @@ use crate::{
         promise::PromiseCapability,
     },
     context::intrinsics::StandardConstructors,
-    environments::{BindingLocator, CompileTimeEnvironment},
+    environments::{BindingLocator, CompileTimeEnvironment, DeclarativeEnvironmentStack},
     object::{
         internal_methods::get_prototype_from_constructor, JsObject, ObjectData, PrivateElement,
     },
     property::PropertyDescriptor,
-    syntax::ast::node::FormalParameterList,
+    realm::Realm,
+    syntax::ast::{node::FormalParameterList, Position},
     vm::call_frame::GeneratorResumeKind,
-    vm::{call_frame::FinallyReturn, CallFrame, Opcode},
-    Context, JsResult, JsValue,
+    vm::{
+        call_frame::{FinallyReturn, TailCall},
+        CallFrame, InlineCache, Opcode,
+    },
+    Context, JsNativeError, JsResult, JsString, JsValue, JsVariant,
 };
 use boa_gc::{Cell, Finalize, Gc, Trace};
 use boa_interner::{Interner, Sym, ToInternedString};
@@ -83,10 +87,17 @@ pub struct CodeBlock {
     #[unsafe_ignore_trace]
     pub(crate) names: Vec<Sym>,
 
+    /// Inline caches of the named property accesses.
+    pub(crate) inline_caches: Vec<InlineCache>,
+
     /// Locators for all bindings in the codeblock.
     #[unsafe_ignore_trace]
     pub(crate) bindings: Vec<BindingLocator>,
 
+    /// The names of the `var` declarations bound on the global object.
+    #[unsafe_ignore_trace]
+    pub(crate) global_var_names: Vec<Sym>,
+
     /// Number of binding for the function environment.
     pub(crate) num_bindings: usize,
 
@@ -108,6 +119,16 @@ pub struct CodeBlock {
     /// We execute the parameter expressions in the function code and push the function environment afterward.
     /// When the execution of the parameter expressions throws an error, we do not need to pop the function environment.
     pub(crate) function_environment_push_location: u32,
+
+    /// The name of the script or module this function was defined in, if known.
+    pub(crate) source_name: Option<JsString>,
+
+    /// Maps bytecode locations to source positions.
+    ///
+    /// Each entry holds the location of the first instruction compiled from the source
+    /// position, and the entries are sorted by location.
+    #[unsafe_ignore_trace]
+    pub(crate) positions: Vec<(u32, Position)>,
 }
 
 impl CodeBlock {
@@ -117,7 +138,9 @@ impl CodeBlock {
             code: Vec::new(),
             literals: Vec::new(),
             names: Vec::new(),
+            inline_caches: Vec::new(),
             bindings: Vec::new(),
+            global_var_names: Vec::new(),
             num_bindings: 0,
             functions: Vec::new(),
             name,
@@ -129,9 +152,30 @@ impl CodeBlock {
             compile_environments: Vec::new(),
             is_class_constructor: false,
             function_environment_push_location: 0,
+            source_name: None,
+            positions: Vec::new(),
         }
     }
 
+    /// Gets the compile time environment of the environment pushed when the function is called.
+    ///
+    /// Compile time environments are added to the code block at the end of their scope, so this
+    /// is always the last one, after the environments of the blocks of the function.
+    pub(crate) fn function_compile_environment(&self) -> Gc<Cell<CompileTimeEnvironment>> {
+        self.compile_environments
+            .last()
+            .expect("function code must have a compile time environment")
+            .clone()
+    }
+
+    /// Gets the source position of the instruction at the given bytecode location, if known.
+    pub(crate) fn position(&self, pc: u32) -> Option<Position> {
+        let index = self
+            .positions
+            .partition_point(|(location, _)| *location <= pc);
+        index.checked_sub(1).map(|index| self.positions[index].1)
+    }
+
     /// Read type T from code.
     ///
     /// # Safety
@@ -200,6 +244,7 @@ impl CodeBlock {
             | Opcode::Coalesce
             | Opcode::CallEval
             | Opcode::Call
+            | Opcode::TailCall
             | Opcode::New
             | Opcode::SuperCall
             | Opcode::ForInLoopInitIterator
@@ -250,9 +295,15 @@ impl CodeBlock {
                     interner.resolve_expect(self.bindings[operand as usize].name()),
                 )
             }
-            Opcode::GetPropertyByName
-            | Opcode::SetPropertyByName
-            | Opcode::DefineOwnPropertyByName
+            Opcode::GetPropertyByName | Opcode::SetPropertyByName => {
+                let operand = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                format!(
+                    "{operand:04}: '{}'",
+                    interner.resolve_expect(self.inline_caches[operand as usize].name()),
+                )
+            }
+            Opcode::DefineOwnPropertyByName
             | Opcode::DefineClassMethodByName
             | Opcode::SetPropertyGetterByName
             | Opcode::DefineClassGetterByName
@@ -503,13 +554,15 @@ pub(crate) fn create_function_object(
 
         Function::Async {
             code,
-            environments: context.realm.environments.clone(),
+            environments: context.vm.environments.clone(),
             promise_capability,
+            realm: context.realm().clone(),
         }
     } else {
         Function::Ordinary {
             code,
-            environments: context.realm.environments.clone(),
+            environments: context.vm.environments.clone(),
+            realm: context.realm().clone(),
             constructor_kind: ConstructorKind::Base,
             home_object: None,
             fields: Vec::new(),
@@ -603,7 +656,8 @@ pub(crate) fn create_generator_function_object(
     let constructor = if r#async {
         let function = Function::AsyncGenerator {
             code,
-            environments: context.realm.environments.clone(),
+            environments: context.vm.environments.clone(),
+            realm: context.realm().clone(),
         };
         JsObject::from_proto_and_data(
             function_prototype,
@@ -612,7 +666,8 @@ pub(crate) fn create_generator_function_object(
     } else {
         let function = Function::Generator {
             code,
-            environments: context.realm.environments.clone(),
+            environments: context.vm.environments.clone(),
+            realm: context.realm().clone(),
         };
         JsObject::from_proto_and_data(function_prototype, ObjectData::generator_function(function))
     };
@@ -638,16 +693,44 @@ pub(crate) fn create_generator_function_object(
 }
 
 impl JsObject {
+    /// Returns the realm of this function object, if it is different from the active realm.
+    fn foreign_function_realm(&self, context: &Context) -> Option<Realm> {
+        self.borrow()
+            .as_function()
+            .map(Function::realm)
+            .filter(|realm| *realm != context.realm())
+            .cloned()
+    }
+
     pub(crate) fn call_internal(
         &self,
         this: &JsValue,
         args: &[JsValue],
         context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // Functions are always executed in the realm they were created in.
+        if let Some(realm) = self.foreign_function_realm(context) {
+            let old_realm = context.enter_realm(realm);
+            let result = self.call_in_active_realm(this, args, context);
+            context.enter_realm(old_realm);
+            return result;
+        }
+
+        self.call_in_active_realm(this, args, context)
+    }
+
+    fn call_in_active_realm(
+        &self,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
     ) -> JsResult<JsValue> {
         let this_function_object = self.clone();
 
         if !self.is_callable() {
-            return context.throw_type_error("not a callable function");
+            return Err(JsNativeError::typ()
+                .with_message("not a callable function")
+                .into());
         }
 
         let object = self.borrow();
@@ -657,6 +740,7 @@ impl JsObject {
             Function::Native {
                 function,
                 constructor,
+                ..
             } => {
                 let function = *function;
                 let constructor = *constructor;
@@ -681,138 +765,61 @@ impl JsObject {
                 code, environments, ..
             } => {
                 let code = code.clone();
-                let mut environments = environments.clone();
+                let environments = environments.clone();
                 drop(object);
 
                 if code.is_class_constructor {
-                    return context
-                        .throw_type_error("Class constructor cannot be invoked without 'new'");
+                    return Err(JsNativeError::typ()
+                        .with_message("Class constructor cannot be invoked without 'new'")
+                        .into());
                 }
 
-                std::mem::swap(&mut environments, &mut context.realm.environments);
-
-                let lexical_this_mode = code.this_mode == ThisMode::Lexical;
+                let (mut result, mut tail_call) =
+                    self.call_ordinary(code, environments, this, args, context)?;
 
-                let this = if lexical_this_mode {
-                    None
-                } else if code.strict {
-                    Some(this.clone())
-                } else if this.is_null_or_undefined() {
-                    Some(context.global_object().clone().into())
-                } else {
-                    Some(
-                        this.to_object(context)
-                            .expect("conversion cannot fail")
-                            .into(),
-                    )
-                };
-
-                if code.params.has_expressions() {
-                    context.realm.environments.push_function(
-                        code.num_bindings,
-                        code.compile_environments[1].clone(),
-                        this,
-                        self.clone(),
-                        None,
-                        lexical_this_mode,
-                    );
-                } else {
-                    context.realm.environments.push_function(
-                        code.num_bindings,
-                        code.compile_environments[0].clone(),
-                        this,
-                        self.clone(),
-                        None,
-                        lexical_this_mode,
-                    );
-                }
-
-                if let Some(binding) = code.arguments_binding {
-                    let arguments_obj = if code.strict || !code.params.is_simple() {
-                        Arguments::create_unmapped_arguments_object(args, context)
+                // Calls in tail position are made once the frame of the calling function has
+                // been popped, so that tail recursion does not grow the call stack.
+                while let Some(call) = tail_call {
+                    let ordinary = if call.function.foreign_function_realm(context).is_some() {
+                        None
                     } else {
-                        let env = context.realm.environments.current();
-                        Arguments::create_mapped_arguments_object(
-                            &this_function_object,
-                            &code.params,
-                            args,
-                            &env,
-                            context,
-                        )
+                        match call.function.borrow().as_function() {
+                            Some(Function::Ordinary {
+                                code, environments, ..
+                            }) if !code.is_class_constructor => {
+                                Some((code.clone(), environments.clone()))
+                            }
+                            _ => None,
+                        }
                     };
-                    context.realm.environments.put_value(
-                        binding.environment_index(),
-                        binding.binding_index(),
-                        arguments_obj.into(),
-                    );
-                }
-
-                let arg_count = args.len();
-
-                // Push function arguments to the stack.
-                let args = if code.params.parameters.len() > args.len() {
-                    let mut v = args.to_vec();
-                    v.extend(vec![
-                        JsValue::Undefined;
-                        code.params.parameters.len() - args.len()
-                    ]);
-                    v
-                } else {
-                    args.to_vec()
-                };
-
-                for arg in args.iter().rev() {
-                    context.vm.push(arg);
-                }
-
-                let param_count = code.params.parameters.len();
-                let has_expressions = code.params.has_expressions();
-
-                context.vm.push_frame(CallFrame {
-                    code,
-                    pc: 0,
-                    catch: Vec::new(),
-                    finally_return: FinallyReturn::None,
-                    finally_jump: Vec::new(),
-                    pop_on_return: 0,
-                    loop_env_stack: Vec::from([0]),
-                    try_env_stack: Vec::from([crate::vm::TryStackEntry {
-                        num_env: 0,
-                        num_loop_stack_entries: 0,
-                    }]),
-                    param_count,
-                    arg_count,
-                    generator_resume_kind: GeneratorResumeKind::Normal,
-                    thrown: false,
-                    async_generator: None,
-                });
-
-                let result = context.run();
-                let frame = context.vm.pop_frame().expect("must have frame");
-
-                context.realm.environments.pop();
-                if has_expressions
-                    && frame.pc > frame.code.function_environment_push_location as usize
-                {
-                    context.realm.environments.pop();
+                    let (code, environments) = match ordinary {
+                        Some(ordinary) => ordinary,
+                        None => return call.function.call(&call.this, &call.arguments, context),
+                    };
+                    (result, tail_call) = call.function.call_ordinary(
+                        code,
+                        environments,
+                        &call.this,
+                        &call.arguments,
+                        context,
+                    )?;
                 }
 
-                std::mem::swap(&mut environments, &mut context.realm.environments);
-
-                let (result, _) = result?;
                 Ok(result)
             }
             Function::Async {
                 code,
                 environments,
                 promise_capability,
+                ..
             } => {
                 let code = code.clone();
                 let mut environments = environments.clone();
                 let promise = promise_capability.promise().clone();
                 drop(object);
 
-                std::mem::swap(&mut environments, &mut context.realm.environments);
+                context.debugger_suspend_frame();
+                std::mem::swap(&mut environments, &mut context.vm.environments);
 
                 let lexical_this_mode = code.this_mode == ThisMode::Lexical;
 
@@ -830,31 +837,20 @@ impl JsObject {
                     )
                 };
 
-                if code.params.has_expressions() {
-                    context.realm.environments.push_function(
-                        code.num_bindings,
-                        code.compile_environments[1].clone(),
-                        this,
-                        self.clone(),
-                        None,
-                        lexical_this_mode,
-                    );
-                } else {
-                    context.realm.environments.push_function(
-                        code.num_bindings,
-                        code.compile_environments[0].clone(),
-                        this,
-                        self.clone(),
-                        None,
-                        lexical_this_mode,
-                    );
-                }
+                context.vm.environments.push_function(
+                    code.num_bindings,
+                    code.function_compile_environment(),
+                    this,
+                    self.clone(),
+                    None,
+                    lexical_this_mode,
+                );
 
                 if let Some(binding) = code.arguments_binding {
                     let arguments_obj = if code.strict || !code.params.is_simple() {
                         Arguments::create_unmapped_arguments_object(args, context)
                     } else {
-                        let env = context.realm.environments.current();
+                        let env = context.vm.environments.current();
                         Arguments::create_mapped_arguments_object(
                             &this_function_object,
                             &code.params,
@@ -863,7 +859,7 @@ impl JsObject {
                             context,
                         )
                     };
-                    context.realm.environments.put_value(
+                    context.vm.environments.put_value(
                         binding.environment_index(),
                         binding.binding_index(),
                         arguments_obj.into(),
@@ -876,7 +872,7 @@ impl JsObject {
                 let args = if code.params.parameters.len() > args.len() {
                     let mut v = args.to_vec();
                     v.extend(vec![
-                        JsValue::Undefined;
+                        JsValue::undefined();
                         code.params.parameters.len() - args.len()
                     ]);
                     v
@@ -908,28 +904,33 @@ impl JsObject {
                     generator_resume_kind: GeneratorResumeKind::Normal,
                     thrown: false,
                     async_generator: None,
+                    tail_call: None,
+                    suspended_environments: None,
                 });
 
                 let _result = context.run();
                 let frame = context.vm.pop_frame().expect("must have frame");
 
-                context.realm.environments.pop();
+                context.vm.environments.pop();
                 if has_expressions
                     && frame.pc > frame.code.function_environment_push_location as usize
                 {
-                    context.realm.environments.pop();
+                    context.vm.environments.pop();
                 }
 
-                std::mem::swap(&mut environments, &mut context.realm.environments);
+                std::mem::swap(&mut environments, &mut context.vm.environments);
 
                 Ok(promise.into())
             }
-            Function::Generator { code, environments } => {
+            Function::Generator {
+                code, environments, ..
+            } => {
                 let code = code.clone();
                 let mut environments = environments.clone();
                 drop(object);
 
-                std::mem::swap(&mut environments, &mut context.realm.environments);
+                context.debugger_suspend_frame();
+                std::mem::swap(&mut environments, &mut context.vm.environments);
 
                 let lexical_this_mode = code.this_mode == ThisMode::Lexical;
 
@@ -947,31 +948,20 @@ impl JsObject {
                     )
                 };
 
-                if code.params.has_expressions() {
-                    context.realm.environments.push_function(
-                        code.num_bindings,
-                        code.compile_environments[1].clone(),
-                        this,
-                        self.clone(),
-                        None,
-                        lexical_this_mode,
-                    );
-                } else {
-                    context.realm.environments.push_function(
-                        code.num_bindings,
-                        code.compile_environments[0].clone(),
-                        this,
-                        self.clone(),
-                        None,
-                        lexical_this_mode,
-                    );
-                }
+                context.vm.environments.push_function(
+                    code.num_bindings,
+                    code.function_compile_environment(),
+                    this,
+                    self.clone(),
+                    None,
+                    lexical_this_mode,
+                );
 
                 if let Some(binding) = code.arguments_binding {
                     let arguments_obj = if code.strict || !code.params.is_simple() {
                         Arguments::create_unmapped_arguments_object(args, context)
                     } else {
-                        let env = context.realm.environments.current();
+                        let env = context.vm.environments.current();
                         Arguments::create_mapped_arguments_object(
                             &this_function_object,
                             &code.params,
@@ -980,7 +970,7 @@ impl JsObject {
                             context,
                         )
                     };
-                    context.realm.environments.put_value(
+                    context.vm.environments.put_value(
                         binding.environment_index(),
                         binding.binding_index(),
                         arguments_obj.into(),
@@ -993,7 +983,7 @@ impl JsObject {
                 let mut args = if code.params.parameters.len() > args.len() {
                     let mut v = args.to_vec();
                     v.extend(vec![
-                        JsValue::Undefined;
+                        JsValue::undefined();
                         code.params.parameters.len() - args.len()
                     ]);
                     v
@@ -1021,6 +1011,8 @@ impl JsObject {
                     generator_resume_kind: GeneratorResumeKind::Normal,
                     thrown: false,
                     async_generator: None,
+                    tail_call: None,
+                    suspended_environments: None,
                 };
                 let mut stack = args;
 
@@ -1030,7 +1022,7 @@ impl JsObject {
                 let init_result = context.run();
 
                 let call_frame = context.vm.pop_frame().expect("frame must exist");
-                std::mem::swap(&mut environments, &mut context.realm.environments);
+                std::mem::swap(&mut environments, &mut context.vm.environments);
                 std::mem::swap(&mut context.vm.stack, &mut stack);
 
                 let prototype = if let Some(prototype) = this_function_object
@@ -1059,12 +1051,15 @@ impl JsObject {
 
                 Ok(generator.into())
             }
-            Function::AsyncGenerator { code, environments } => {
+            Function::AsyncGenerator {
+                code, environments, ..
+            } => {
                 let code = code.clone();
                 let mut environments = environments.clone();
                 drop(object);
 
-                std::mem::swap(&mut environments, &mut context.realm.environments);
+                context.debugger_suspend_frame();
+                std::mem::swap(&mut environments, &mut context.vm.environments);
 
                 let lexical_this_mode = code.this_mode == ThisMode::Lexical;
 
@@ -1082,31 +1077,20 @@ impl JsObject {
                     )
                 };
 
-                if code.params.has_expressions() {
-                    context.realm.environments.push_function(
-                        code.num_bindings,
-                        code.compile_environments[1].clone(),
-                        this,
-                        self.clone(),
-                        None,
-                        lexical_this_mode,
-                    );
-                } else {
-                    context.realm.environments.push_function(
-                        code.num_bindings,
-                        code.compile_environments[0].clone(),
-                        this,
-                        self.clone(),
-                        None,
-                        lexical_this_mode,
-                    );
-                }
+                context.vm.environments.push_function(
+                    code.num_bindings,
+                    code.function_compile_environment(),
+                    this,
+                    self.clone(),
+                    None,
+                    lexical_this_mode,
+                );
 
                 if let Some(binding) = code.arguments_binding {
                     let arguments_obj = if code.strict || !code.params.is_simple() {
                         Arguments::create_unmapped_arguments_object(args, context)
                     } else {
-                        let env = context.realm.environments.current();
+                        let env = context.vm.environments.current();
                         Arguments::create_mapped_arguments_object(
                             &this_function_object,
                             &code.params,
@@ -1115,7 +1099,7 @@ impl JsObject {
                             context,
                         )
                     };
-                    context.realm.environments.put_value(
+                    context.vm.environments.put_value(
                         binding.environment_index(),
                         binding.binding_index(),
                         arguments_obj.into(),
@@ -1128,7 +1112,7 @@ impl JsObject {
                 let mut args = if code.params.parameters.len() > args.len() {
                     let mut v = args.to_vec();
                     v.extend(vec![
-                        JsValue::Undefined;
+                        JsValue::undefined();
                         code.params.parameters.len() - args.len()
                     ]);
                     v
@@ -1156,6 +1140,8 @@ impl JsObject {
                     generator_resume_kind: GeneratorResumeKind::Normal,
                     thrown: false,
                     async_generator: None,
+                    tail_call: None,
+                    suspended_environments: None,
                 };
                 let mut stack = args;
 
@@ -1165,7 +1151,7 @@ impl JsObject {
                 let init_result = context.run();
 
                 let call_frame = context.vm.pop_frame().expect("frame must exist");
-                std::mem::swap(&mut environments, &mut context.realm.environments);
+                std::mem::swap(&mut environments, &mut context.vm.environments);
                 std::mem::swap(&mut context.vm.stack, &mut stack);
 
                 let prototype = if let Some(prototype) = this_function_object
@@ -1211,11 +1197,138 @@ impl JsObject {
         }
     }
 
+    /// Runs the code of an ordinary function in a new frame.
+    ///
+    /// If the function ends with a call in tail position, that call is returned instead of
+    /// being made, so that it can be made by the caller after the frame has been popped.
+    fn call_ordinary(
+        &self,
+        code: Gc<CodeBlock>,
+        mut environments: DeclarativeEnvironmentStack,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<(JsValue, Option<TailCall>)> {
+        context.debugger_suspend_frame();
+        std::mem::swap(&mut environments, &mut context.vm.environments);
+
+        let lexical_this_mode = code.this_mode == ThisMode::Lexical;
+
+        let this = if lexical_this_mode {
+            None
+        } else if code.strict {
+            Some(this.clone())
+        } else if this.is_null_or_undefined() {
+            Some(context.global_object().clone().into())
+        } else {
+            Some(
+                this.to_object(context)
+                    .expect("conversion cannot fail")
+                    .into(),
+            )
+        };
+
+        context.vm.environments.push_function(
+            code.num_bindings,
+            code.function_compile_environment(),
+            this,
+            self.clone(),
+            None,
+            lexical_this_mode,
+        );
+
+        if let Some(binding) = code.arguments_binding {
+            let arguments_obj = if code.strict || !code.params.is_simple() {
+                Arguments::create_unmapped_arguments_object(args, context)
+            } else {
+                let env = context.vm.environments.current();
+                Arguments::create_mapped_arguments_object(self, &code.params, args, &env, context)
+            };
+            context.vm.environments.put_value(
+                binding.environment_index(),
+                binding.binding_index(),
+                arguments_obj.into(),
+            );
+        }
+
+        let arg_count = args.len();
+
+        // Push function arguments to the stack.
+        let args = if code.params.parameters.len() > args.len() {
+            let mut v = args.to_vec();
+            v.extend(vec![
+                JsValue::undefined();
+                code.params.parameters.len() - args.len()
+            ]);
+            v
+        } else {
+            args.to_vec()
+        };
+
+        for arg in args.iter().rev() {
+            context.vm.push(arg);
+        }
+
+        let param_count = code.params.parameters.len();
+        let has_expressions = code.params.has_expressions();
+
+        context.vm.push_frame(CallFrame {
+            code,
+            pc: 0,
+            catch: Vec::new(),
+            finally_return: FinallyReturn::None,
+            finally_jump: Vec::new(),
+            pop_on_return: 0,
+            loop_env_stack: Vec::from([0]),
+            try_env_stack: Vec::from([crate::vm::TryStackEntry {
+                num_env: 0,
+                num_loop_stack_entries: 0,
+            }]),
+            param_count,
+            arg_count,
+            generator_resume_kind: GeneratorResumeKind::Normal,
+            thrown: false,
+            async_generator: None,
+            tail_call: None,
+            suspended_environments: None,
+        });
+
+        let result = context.run();
+        let mut frame = context.vm.pop_frame().expect("must have frame");
+
+        context.vm.environments.pop();
+        if has_expressions && frame.pc > frame.code.function_environment_push_location as usize {
+            context.vm.environments.pop();
+        }
+
+        std::mem::swap(&mut environments, &mut context.vm.environments);
+
+        let (result, _) = result?;
+        Ok((result, frame.tail_call.take()))
+    }
+
     pub(crate) fn construct_internal(
         &self,
         args: &[JsValue],
         this_target: &JsValue,
         context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // Functions are always executed in the realm they were created in.
+        if let Some(realm) = self.foreign_function_realm(context) {
+            let old_realm = context.enter_realm(realm);
+            let result = self.construct_in_active_realm(args, this_target, context);
+            context.enter_realm(old_realm);
+            return result;
+        }
+
+        self.construct_in_active_realm(args, this_target, context)
+    }
+
+    fn construct_in_active_realm(
+        &self,
+        args: &[JsValue],
+        this_target: &JsValue,
+        context: &mut Context,
     ) -> JsResult<JsObject> {
         let this_function_object = self.clone();
 
@@ -1226,7 +1339,9 @@ impl JsObject {
         };
 
         if !self.is_constructor() {
-            return context.throw_type_error("not a constructor function");
+            return Err(JsNativeError::typ()
+                .with_message("not a constructor function")
+                .into());
         }
 
         let object = self.borrow();
@@ -1242,15 +1357,18 @@ impl JsObject {
                 let constructor = *constructor;
                 drop(object);
 
-                match function(this_target, args, context)? {
-                    JsValue::Object(ref o) => Ok(o.clone()),
-                    val => {
+                let val = function(this_target, args, context)?;
+                match val.variant() {
+                    JsVariant::Object(o) => Ok(o.clone()),
+                    _ => {
                         if constructor.expect("hmm").is_base() || val.is_undefined() {
                             create_this(context)
                         } else {
-                            context.throw_type_error(
-                                "Derived constructor can only return an Object or undefined",
-                            )
+                            Err(JsNativeError::typ()
+                                .with_message(
+                                    "Derived constructor can only return an Object or undefined",
+                                )
+                                .into())
                         }
                     }
                 }
@@ -1266,15 +1384,18 @@ impl JsObject {
                 let constructor = *constructor;
                 drop(object);
 
-                match (function)(this_target, args, captures, context)? {
-                    JsValue::Object(ref o) => Ok(o.clone()),
-                    val => {
+                let val = (function)(this_target, args, captures, context)?;
+                match val.variant() {
+                    JsVariant::Object(o) => Ok(o.clone()),
+                    _ => {
                         if constructor.expect("hmma").is_base() || val.is_undefined() {
                             create_this(context)
                         } else {
-                            context.throw_type_error(
-                                "Derived constructor can only return an Object or undefined",
-                            )
+                            Err(JsNativeError::typ()
+                                .with_message(
+                                    "Derived constructor can only return an Object or undefined",
+                                )
+                                .into())
                         }
                     }
                 }
@@ -1290,7 +1411,8 @@ impl JsObject {
                 let constructor_kind = *constructor_kind;
                 drop(object);
 
-                std::mem::swap(&mut environments, &mut context.realm.environments);
+                context.debugger_suspend_frame();
+                std::mem::swap(&mut environments, &mut context.vm.environments);
 
                 let this = if constructor_kind.is_base() {
                     // If the prototype of the constructor is not an object, then use the default object
@@ -1313,25 +1435,14 @@ impl JsObject {
 
                 let new_target = this_target.as_object().expect("must be object");
 
-                if code.params.has_expressions() {
-                    context.realm.environments.push_function(
-                        code.num_bindings,
-                        code.compile_environments[1].clone(),
-                        this.clone().map(Into::into),
-                        self.clone(),
-                        Some(new_target.clone()),
-                        false,
-                    );
-                } else {
-                    context.realm.environments.push_function(
-                        code.num_bindings,
-                        code.compile_environments[0].clone(),
-                        this.clone().map(Into::into),
-                        self.clone(),
-                        Some(new_target.clone()),
-                        false,
-                    );
-                }
+                context.vm.environments.push_function(
+                    code.num_bindings,
+                    code.function_compile_environment(),
+                    this.clone().map(Into::into),
+                    self.clone(),
+                    Some(new_target.clone()),
+                    false,
+                );
 
                 let mut arguments_in_parameter_names = false;
                 let mut is_simple_parameter_list = true;
@@ -1351,7 +1462,7 @@ impl JsObject {
                     let arguments_obj = if code.strict || !code.params.is_simple() {
                         Arguments::create_unmapped_arguments_object(args, context)
                     } else {
-                        let env = context.realm.environments.current();
+                        let env = context.vm.environments.current();
                         Arguments::create_mapped_arguments_object(
                             &this_function_object,
                             &code.params,
@@ -1360,7 +1471,7 @@ impl JsObject {
                             context,
                         )
                     };
-                    context.realm.environments.put_value(
+                    context.vm.environments.put_value(
                         binding.environment_index(),
                         binding.binding_index(),
                         arguments_obj.into(),
@@ -1373,7 +1484,7 @@ impl JsObject {
                 let args = if code.params.parameters.len() > args.len() {
                     let mut v = args.to_vec();
                     v.extend(vec![
-                        JsValue::Undefined;
+                        JsValue::undefined();
                         code.params.parameters.len() - args.len()
                     ]);
                     v
@@ -1404,27 +1515,34 @@ impl JsObject {
                     generator_resume_kind: GeneratorResumeKind::Normal,
                     thrown: false,
                     async_generator: None,
+                    tail_call: None,
+                    suspended_environments: None,
                 });
 
                 let result = context.run();
 
-                context.vm.pop_frame();
+                let mut frame = context.vm.pop_frame().expect("must have frame");
 
-                let mut environment = context.realm.environments.pop();
+                let mut environment = context.vm.environments.pop();
                 if has_parameter_expressions {
-                    environment = context.realm.environments.pop();
+                    environment = context.vm.environments.pop();
                 }
 
-                std::mem::swap(&mut environments, &mut context.realm.environments);
+                std::mem::swap(&mut environments, &mut context.vm.environments);
 
-                let (result, _) = result?;
+                let (mut result, _) = result?;
+                if let Some(call) = frame.tail_call.take() {
+                    result = call.function.call(&call.this, &call.arguments, context)?;
+                }
 
                 if let Some(result) = result.as_object() {
                     Ok(result.clone())
                 } else if let Some(this) = this {
                     Ok(this)
                 } else if !result.is_undefined() {
-                    context.throw_type_error("Function constructor must not return non-object")
+                    Err(JsNativeError::typ()
+                        .with_message("Function constructor must not return non-object")
+                        .into())
                 } else {
                     let function_env = environment
                         .slots()
@@ -1437,8 +1555,8 @@ impl JsObject {
                             .expect("this binding must be object")
                             .clone())
                     } else {
-                        //context.throw_type_error("Function constructor must not return non-object")
-                        context.throw_reference_error("Must call super constructor in derived class before accessing 'this' or returning from derived constructor")
+                        //Err(JsNativeError::typ().with_message("Function constructor must not return non-object").into())
+                        Err(JsNativeError::reference().with_message("Must call super constructor in derived class before accessing 'this' or returning from derived constructor").into())
                     }
                 }
             }
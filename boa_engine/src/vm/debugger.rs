@@ -0,0 +1,510 @@
+//! Hooks to debug the scripts executed by the virtual machine.
+//!
+//! A [`Debugger`] attached to a [`Context`] is notified when a frame starts or stops
+//! executing and when an exception is thrown. The virtual machine pauses before a statement
+//! when it hits a breakpoint or completes a step, and lets the debugger inspect the call stack
+//! with [`Context::stack_frames`], read the locals of any frame with [`Context::frame_locals`]
+//! and evaluate code in a frame with [`Context::eval_in_frame`] until it resumes.
+
+use crate::{
+    environments::DeclarativeEnvironmentStack,
+    syntax::ast::{node::StatementList, Position},
+    vm::{CallFrame, FinallyReturn, GeneratorResumeKind, TryStackEntry},
+    Context, JsNativeError, JsResult, JsString, JsValue,
+};
+use boa_interner::Sym;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::{
+    fmt::Debug,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// The host hooks used by a [`Context`] to report the progress of the execution to a debugger.
+///
+/// All hooks are called with the frame that triggered them as the innermost frame of the
+/// context. While a hook runs, breakpoints and steps are disabled, so the hook can run code in
+/// the context without being paused again.
+pub trait Debugger: Debug {
+    /// Called when the execution pauses before a statement.
+    ///
+    /// The execution stays paused until the method returns, and resumes as requested by the
+    /// returned [`ResumeMode`]. To stop the script instead, interrupt it with the
+    /// [`InterruptHandle`][crate::vm::InterruptHandle] of the context before returning.
+    fn on_pause(&self, reason: &PauseReason, context: &mut Context) -> ResumeMode;
+
+    /// Called when a frame starts executing, or resumes after a `yield` or an `await`.
+    #[inline]
+    fn on_enter_frame(&self, _context: &mut Context) {}
+
+    /// Called when a frame stops executing, because it returned or threw, or because it was
+    /// suspended by a `yield` or an `await`.
+    #[inline]
+    fn on_exit_frame(&self, _context: &mut Context) {}
+
+    /// Called when an exception is thrown, before it is caught.
+    ///
+    /// Returning `true` pauses the execution at the instruction that threw, with the
+    /// [`PauseReason::Exception`] reason.
+    #[inline]
+    fn on_exception(&self, _exception: &JsValue, _context: &mut Context) -> bool {
+        false
+    }
+}
+
+/// The reason why the execution was paused.
+#[derive(Debug, Clone)]
+pub enum PauseReason {
+    /// A breakpoint on the line of the next statement was hit.
+    Breakpoint,
+
+    /// A step requested by the previous [`ResumeMode`] was completed.
+    Step,
+
+    /// An exception was thrown.
+    Exception(JsValue),
+
+    /// A pause was requested with the [`PauseHandle`] of the context.
+    Request,
+}
+
+/// How the execution resumes after a pause.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResumeMode {
+    /// Runs until the next breakpoint.
+    #[default]
+    Continue,
+
+    /// Pauses before the next statement, including statements of called functions.
+    StepIn,
+
+    /// Pauses before the next statement of the paused frame or of one of its callers.
+    StepOver,
+
+    /// Pauses before the next statement of a caller of the paused frame.
+    StepOut,
+}
+
+/// A frame of the call stack, as seen by a debugger.
+#[derive(Debug, Clone)]
+pub struct StackFrame {
+    function_name: JsString,
+    source_name: Option<JsString>,
+    line_number: Option<u32>,
+    column_number: Option<u32>,
+}
+
+impl StackFrame {
+    /// Gets the name of the function of the frame, which is empty for anonymous functions and
+    /// `<main>` for scripts.
+    #[inline]
+    pub fn function_name(&self) -> &JsString {
+        &self.function_name
+    }
+
+    /// Gets the name of the script or module the code of the frame was defined in, if known.
+    #[inline]
+    pub fn source_name(&self) -> Option<&JsString> {
+        self.source_name.as_ref()
+    }
+
+    /// Gets the line of the instruction being executed in the frame, if known.
+    #[inline]
+    pub fn line_number(&self) -> Option<u32> {
+        self.line_number
+    }
+
+    /// Gets the column of the instruction being executed in the frame, if known.
+    #[inline]
+    pub fn column_number(&self) -> Option<u32> {
+        self.column_number
+    }
+}
+
+/// A handle to pause the execution of a [`Context`] before its next statement, possibly from
+/// another thread.
+///
+/// The request is only handled while a [`Debugger`] is attached to the context, and is kept
+/// until the next statement starts.
+#[derive(Debug, Clone, Default)]
+pub struct PauseHandle {
+    requested: Arc<AtomicBool>,
+}
+
+impl PauseHandle {
+    /// Requests a pause before the next statement.
+    #[inline]
+    pub fn request_pause(&self) {
+        self.requested.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if a pause was requested and not handled yet.
+    #[inline]
+    pub fn is_pause_requested(&self) -> bool {
+        self.requested.load(Ordering::Relaxed)
+    }
+
+    /// Consumes a pending pause request, returning `true` if there was one.
+    #[inline]
+    fn take(&self) -> bool {
+        self.requested.swap(false, Ordering::Relaxed)
+    }
+}
+
+/// The debugger state of the virtual machine.
+#[derive(Debug, Default)]
+pub(crate) struct DebuggerState {
+    debugger: Option<Rc<dyn Debugger>>,
+    breakpoints: FxHashMap<JsString, FxHashSet<u32>>,
+    resume_mode: ResumeMode,
+    pause_handle: PauseHandle,
+
+    /// The number of frames when the execution was last resumed.
+    resume_depth: usize,
+
+    /// The last exception reported to the debugger that has not been caught yet, so it is not
+    /// reported again by every frame it propagates through.
+    uncaught_exception: Option<JsValue>,
+
+    /// Set while a hook runs, to prevent nested pauses.
+    in_hook: bool,
+
+    /// Set while the execution is paused before the instruction at the program counter of the
+    /// innermost frame, instead of after the instruction that precedes it.
+    before_instruction: bool,
+}
+
+impl DebuggerState {
+    /// Returns `true` if a debugger is attached and no hook is running.
+    #[inline]
+    pub(crate) fn is_active(&self) -> bool {
+        self.debugger.is_some() && !self.in_hook
+    }
+}
+
+impl Context {
+    /// Attaches a debugger to the context, or detaches the current one with `None`.
+    ///
+    /// The locals of the frames that are suspended by a call can only be read if the call was
+    /// made while the debugger was attached.
+    #[inline]
+    pub fn set_debugger(&mut self, debugger: Option<Rc<dyn Debugger>>) {
+        self.vm.debugger.debugger = debugger;
+        self.vm.debugger.resume_mode = ResumeMode::Continue;
+    }
+
+    /// Gets the debugger attached to the context.
+    #[inline]
+    pub fn debugger(&self) -> Option<Rc<dyn Debugger>> {
+        self.vm.debugger.debugger.clone()
+    }
+
+    /// Gets a handle to pause the execution of the context from a host hook or another thread.
+    #[inline]
+    pub fn pause_handle(&self) -> PauseHandle {
+        self.vm.debugger.pause_handle.clone()
+    }
+
+    /// Sets a breakpoint on the statements that start on `line` of the script or module named
+    /// `source_name`.
+    ///
+    /// Lines are numbered from 1. Returns `false` if the breakpoint was already set.
+    #[inline]
+    pub fn set_breakpoint<N>(&mut self, source_name: N, line: u32) -> bool
+    where
+        N: AsRef<str>,
+    {
+        self.vm
+            .debugger
+            .breakpoints
+            .entry(JsString::new(source_name))
+            .or_default()
+            .insert(line)
+    }
+
+    /// Removes a breakpoint set with [`Context::set_breakpoint`].
+    ///
+    /// Returns `false` if the breakpoint was not set.
+    #[inline]
+    pub fn remove_breakpoint<N>(&mut self, source_name: N, line: u32) -> bool
+    where
+        N: AsRef<str>,
+    {
+        self.vm
+            .debugger
+            .breakpoints
+            .get_mut(&JsString::new(source_name))
+            .map_or(false, |lines| lines.remove(&line))
+    }
+
+    /// Removes all the breakpoints of the script or module named `source_name`.
+    #[inline]
+    pub fn clear_breakpoints<N>(&mut self, source_name: N)
+    where
+        N: AsRef<str>,
+    {
+        self.vm
+            .debugger
+            .breakpoints
+            .remove(&JsString::new(source_name));
+    }
+
+    /// Describes the frames of the call stack, starting with the innermost frame.
+    ///
+    /// The index of a frame in the returned list is its depth, as used by
+    /// [`Context::frame_locals`] and [`Context::eval_in_frame`].
+    pub fn stack_frames(&self) -> Vec<StackFrame> {
+        self.vm
+            .frames
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(depth, frame)| {
+                let code = &frame.code;
+                let function_name = match code.name {
+                    Sym::EMPTY_STRING => JsString::empty(),
+                    name => JsString::from(&*self.interner().resolve_expect_utf16(name)),
+                };
+                let pc = if depth == 0 && self.vm.debugger.before_instruction {
+                    frame.pc as u32
+                } else {
+                    // The program counter points past the instruction being executed.
+                    (frame.pc as u32).saturating_sub(1)
+                };
+                let position = code.position(pc);
+                StackFrame {
+                    function_name,
+                    source_name: code.source_name.clone(),
+                    line_number: position.map(Position::line_number),
+                    column_number: position.map(Position::column_number),
+                }
+            })
+            .collect()
+    }
+
+    /// Reads the initialized bindings of the declarative environments visible from the frame
+    /// at `depth`, starting with the innermost environment.
+    ///
+    /// A binding that is shadowed by an inner binding with the same name is skipped. Returns
+    /// `None` if there is no frame at `depth`, or if its environments are not available.
+    pub fn frame_locals(&self, depth: usize) -> Option<Vec<(JsString, JsValue)>> {
+        let environments = self.frame_environments(depth)?;
+
+        let mut names = FxHashSet::default();
+        let mut locals = Vec::new();
+        for environment in environments.environments().iter().rev() {
+            let compile = environment.compile.borrow();
+            let mut bindings: Vec<_> = compile.bindings.iter().collect();
+            bindings.sort_by_key(|(_, binding)| binding.index);
+            for (&name, binding) in bindings {
+                if !names.insert(name) {
+                    continue;
+                }
+                if let Some(value) = environment.get_optional(binding.index) {
+                    let name = JsString::from(&*self.interner().resolve_expect_utf16(name));
+                    locals.push((name, value));
+                }
+            }
+        }
+        Some(locals)
+    }
+
+    /// Evaluates `src` in the frame at `depth`, as if it was the argument of a direct `eval`
+    /// call made by the frame.
+    ///
+    /// Pending jobs are not run, and hooks are not called during the evaluation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no frame at `depth` or if its environments are not
+    /// available, and otherwise any error thrown by the evaluated code.
+    pub fn eval_in_frame<S>(&mut self, depth: usize, src: S) -> JsResult<JsValue>
+    where
+        S: AsRef<[u8]>,
+    {
+        let mut environments = self.frame_environments(depth).ok_or_else(|| {
+            JsNativeError::error().with_message("the environments of the frame are not available")
+        })?;
+        let strict = self.vm.frames[self.vm.frames.len() - 1 - depth].code.strict;
+
+        let body = self
+            .parse_eval(src.as_ref(), true, strict)
+            .map_err(|e| JsNativeError::syntax().with_message(e.to_string()))?;
+
+        let in_hook = std::mem::replace(&mut self.vm.debugger.in_hook, true);
+        std::mem::swap(&mut environments, &mut self.vm.environments);
+        let compile_env = self.vm.environments.current_compile_environment();
+        let compile_env = std::mem::replace(&mut self.compile_env, compile_env);
+
+        let result = self.eval_in_environments(&body, strict);
+
+        self.compile_env = compile_env;
+        std::mem::swap(&mut environments, &mut self.vm.environments);
+        self.vm.debugger.in_hook = in_hook;
+
+        result
+    }
+
+    /// Compiles and runs the body of a debugger evaluation in the current environments.
+    fn eval_in_environments(&mut self, body: &StatementList, strict: bool) -> JsResult<JsValue> {
+        self.vm.environments.poison_current();
+        let environments_len = self.vm.environments.len();
+
+        let code_block = self.compile_with_new_declarative(body, strict)?;
+        self.vm.environments.extend_outer_function_environment();
+
+        self.vm.push_frame(CallFrame {
+            code: code_block,
+            pc: 0,
+            catch: Vec::new(),
+            finally_return: FinallyReturn::None,
+            finally_jump: Vec::new(),
+            pop_on_return: 0,
+            loop_env_stack: Vec::from([0]),
+            try_env_stack: Vec::from([TryStackEntry {
+                num_env: 0,
+                num_loop_stack_entries: 0,
+            }]),
+            param_count: 0,
+            arg_count: 0,
+            generator_resume_kind: GeneratorResumeKind::Normal,
+            thrown: false,
+            async_generator: None,
+            tail_call: None,
+            suspended_environments: None,
+        });
+        let result = self.run();
+        self.vm.pop_frame();
+        self.vm.environments.truncate(environments_len);
+
+        result.map(|(value, _)| value)
+    }
+
+    /// Gets the environments of the frame at `depth`.
+    fn frame_environments(&self, depth: usize) -> Option<DeclarativeEnvironmentStack> {
+        if depth == 0 {
+            return self.vm.frames.last().map(|_| self.vm.environments.clone());
+        }
+        let index = self.vm.frames.len().checked_sub(depth + 1)?;
+        self.vm.frames[index].suspended_environments.clone()
+    }
+
+    /// Calls the pause hook of the debugger if a breakpoint or a step ends on the instruction
+    /// that is about to be executed.
+    pub(crate) fn debugger_check_statement(&mut self) {
+        let state = &self.vm.debugger;
+        let frame = self.vm.frame();
+        let code = &frame.code;
+
+        // Statements start at the locations recorded in the source positions.
+        let pc = frame.pc as u32;
+        let line = match code
+            .positions
+            .binary_search_by_key(&pc, |(location, _)| *location)
+        {
+            Ok(index) => code.positions[index].1.line_number(),
+            Err(_) => return,
+        };
+
+        let depth = self.vm.frames.len();
+        let step_completed = match state.resume_mode {
+            ResumeMode::Continue => false,
+            ResumeMode::StepIn => true,
+            ResumeMode::StepOver => depth <= state.resume_depth,
+            ResumeMode::StepOut => depth < state.resume_depth,
+        };
+
+        let reason = if state.pause_handle.take() {
+            PauseReason::Request
+        } else if step_completed {
+            PauseReason::Step
+        } else if code
+            .source_name
+            .as_ref()
+            .and_then(|name| state.breakpoints.get(name))
+            .map_or(false, |lines| lines.contains(&line))
+        {
+            PauseReason::Breakpoint
+        } else {
+            return;
+        };
+
+        self.vm.debugger.before_instruction = true;
+        self.debugger_pause(&reason);
+        self.vm.debugger.before_instruction = false;
+    }
+
+    /// Calls the pause hook of the debugger, and saves how the execution must resume.
+    fn debugger_pause(&mut self, reason: &PauseReason) {
+        let resume_mode =
+            self.debugger_hook(|debugger, context| debugger.on_pause(reason, context));
+        if let Some(resume_mode) = resume_mode {
+            self.vm.debugger.resume_mode = resume_mode;
+            self.vm.debugger.resume_depth = self.vm.frames.len();
+        }
+    }
+
+    /// Calls the hook of the debugger for a frame that starts executing.
+    pub(crate) fn debugger_enter_frame(&mut self) {
+        self.debugger_hook(|debugger, context| debugger.on_enter_frame(context));
+    }
+
+    /// Calls the hook of the debugger for a frame that stops executing.
+    pub(crate) fn debugger_exit_frame(&mut self) {
+        self.debugger_hook(|debugger, context| debugger.on_exit_frame(context));
+
+        // A step that is not completed when the host regains control is abandoned.
+        if self.vm.frames.len() == 1 {
+            self.vm.debugger.resume_mode = ResumeMode::Continue;
+        }
+    }
+
+    /// Calls the hook of the debugger for a thrown exception, and pauses if requested.
+    ///
+    /// `caught` tells if the exception is caught by the frame that is running.
+    pub(crate) fn debugger_exception(&mut self, exception: &JsValue, caught: bool) {
+        let reported = std::mem::take(&mut self.vm.debugger.uncaught_exception)
+            .map_or(false, |uncaught| uncaught.strict_equals(exception));
+        if !caught {
+            self.vm.debugger.uncaught_exception = Some(exception.clone());
+        }
+        if reported {
+            return;
+        }
+
+        let pause =
+            self.debugger_hook(|debugger, context| debugger.on_exception(exception, context));
+        if pause == Some(true) {
+            self.debugger_pause(&PauseReason::Exception(exception.clone()));
+        }
+    }
+
+    /// Calls a hook of the attached debugger, disabling the other hooks while it runs.
+    fn debugger_hook<F, R>(&mut self, hook: F) -> Option<R>
+    where
+        F: FnOnce(&dyn Debugger, &mut Self) -> R,
+    {
+        if !self.vm.debugger.is_active() {
+            return None;
+        }
+        let debugger = self.vm.debugger.debugger.clone()?;
+        self.vm.debugger.in_hook = true;
+        let result = hook(&*debugger, self);
+        self.vm.debugger.in_hook = false;
+        Some(result)
+    }
+
+    /// Saves the environments of the running frame before it is suspended by a call, so the
+    /// debugger can still read its locals.
+    #[inline]
+    pub(crate) fn debugger_suspend_frame(&mut self) {
+        if self.vm.debugger.debugger.is_some() {
+            if let Some(frame) = self.vm.frames.last_mut() {
+                frame.suspended_environments = Some(self.vm.environments.clone());
+            }
+        }
+    }
+}
@@ -0,0 +1,348 @@
+//! Inline caches of the named property accesses of the VM.
+//!
+//! Every `GetPropertyByName` and `SetPropertyByName` instruction has an [`InlineCache`], which
+//! remembers where the property was found the last time the instruction ran, by the shapes
+//! of the objects involved. When the instruction runs again with objects of the same shapes,
+//! the property is accessed directly in its slot, without looking it up.
+
+use crate::{
+    object::{shape::Shape, JsObject},
+    property::{DescriptorKind, PropertyDescriptor},
+    Context, JsResult, JsString, JsValue,
+};
+use boa_gc::{Cell, Finalize, Trace};
+use boa_interner::Sym;
+
+/// The number of times the cache of an instruction is updated, before giving up on caching
+/// the accesses of the instruction.
+const MAX_UPDATES: u8 = 8;
+
+/// The longest prototype chain walked to cache a property access.
+const MAX_PROTOTYPE_CHAIN_LENGTH: usize = 8;
+
+/// A cached property access.
+#[derive(Clone, Debug, Trace, Finalize)]
+enum CacheEntry {
+    /// The property is read from a slot of the receiver or of one of its prototypes.
+    ///
+    /// `prototypes` holds the prototype chain of the receiver up to the object with the
+    /// property, with the shape each of them must have.
+    Get {
+        shape: Shape,
+        prototypes: Box<[(JsObject, Shape)]>,
+        slot: u32,
+    },
+
+    /// The property is a writable data property in a slot of the receiver.
+    Set { shape: Shape, slot: u32 },
+
+    /// The property is added to the receiver.
+    ///
+    /// `prototypes` holds the whole prototype chain of the receiver, with the shape each of
+    /// them must have to not have the property.
+    Add {
+        shape: Shape,
+        prototypes: Box<[(JsObject, Shape)]>,
+    },
+}
+
+/// The inline cache of a named property access instruction.
+#[derive(Clone, Debug, Trace, Finalize)]
+pub(crate) struct InlineCache {
+    /// The name of the property.
+    #[unsafe_ignore_trace]
+    name: Sym,
+
+    /// The key of the property.
+    key: JsString,
+
+    /// The cached property access, if any.
+    entry: Cell<Option<CacheEntry>>,
+
+    /// The number of times the entry was updated.
+    #[unsafe_ignore_trace]
+    updates: std::cell::Cell<u8>,
+}
+
+impl InlineCache {
+    /// Creates a new, empty inline cache for the property with the given name.
+    pub(crate) fn new(name: Sym, key: JsString) -> Self {
+        Self {
+            name,
+            key,
+            entry: Cell::new(None),
+            updates: std::cell::Cell::new(0),
+        }
+    }
+
+    /// Gets the name of the property.
+    #[inline]
+    pub(crate) fn name(&self) -> Sym {
+        self.name
+    }
+
+    /// Gets the key of the property.
+    #[inline]
+    pub(crate) fn key(&self) -> &JsString {
+        &self.key
+    }
+
+    /// Reads the property from `object` if the cached access applies to it.
+    ///
+    /// Returns `None` if the cache misses, in which case the property must be looked up.
+    pub(crate) fn get(
+        &self,
+        object: &JsObject,
+        context: &mut Context,
+    ) -> Option<JsResult<JsValue>> {
+        let kind = {
+            let entry = self.entry.borrow();
+            let (shape, prototypes, slot) = match &*entry {
+                Some(CacheEntry::Get {
+                    shape,
+                    prototypes,
+                    slot,
+                }) => (shape, prototypes, *slot),
+                _ => return None,
+            };
+
+            let receiver = object.borrow();
+            if !receiver.has_ordinary_named_get() || receiver.properties().shape() != Some(shape) {
+                return None;
+            }
+
+            if prototypes.is_empty() {
+                receiver.properties().slot(slot).kind().clone()
+            } else {
+                let mut prototype = receiver.prototype().clone();
+                for (expected, expected_shape) in prototypes.iter() {
+                    let current = match &prototype {
+                        Some(current) if JsObject::equals(current, expected) => current.clone(),
+                        _ => return None,
+                    };
+                    let current = current.borrow();
+                    if !current.has_ordinary_named_get()
+                        || current.properties().shape() != Some(expected_shape)
+                    {
+                        return None;
+                    }
+                    prototype.clone_from(current.prototype());
+                }
+
+                let (holder, _) = prototypes.last().expect("checked that it is not empty");
+                let holder = holder.borrow();
+                holder.properties().slot(slot).kind().clone()
+            }
+        };
+
+        // The cache must not be borrowed while the getter runs, since it can run this
+        // instruction again.
+        Some(match &kind {
+            DescriptorKind::Data { value, .. } => Ok(value.clone().unwrap_or_default()),
+            DescriptorKind::Accessor { get: Some(get), .. } if !get.is_undefined() => {
+                context.call(get, &object.clone().into(), &[])
+            }
+            DescriptorKind::Accessor { .. } => Ok(JsValue::undefined()),
+            DescriptorKind::Generic => return None,
+        })
+    }
+
+    /// Caches the read of the property from `object`, after it was looked up.
+    pub(crate) fn update_get(&self, object: &JsObject) {
+        if !self.can_update() {
+            return;
+        }
+
+        let mut chain: Vec<(JsObject, Shape)> = Vec::new();
+        let mut current = object.clone();
+        loop {
+            let next = {
+                let borrowed = current.borrow();
+                if !borrowed.has_ordinary_named_get() {
+                    return;
+                }
+                let shape = match borrowed.properties().shape() {
+                    Some(shape) => shape.clone(),
+                    None => return,
+                };
+
+                if let Some(slot) = shape.lookup(&self.key) {
+                    let receiver_shape = chain.first().map_or(&shape, |(_, shape)| shape).clone();
+                    let prototypes = chain
+                        .iter()
+                        .skip(1)
+                        .cloned()
+                        .chain((!chain.is_empty()).then(|| (current.clone(), shape)))
+                        .collect();
+                    drop(borrowed);
+                    self.set_entry(CacheEntry::Get {
+                        shape: receiver_shape,
+                        prototypes,
+                        slot,
+                    });
+                    return;
+                }
+
+                match borrowed.prototype() {
+                    Some(prototype) if chain.len() < MAX_PROTOTYPE_CHAIN_LENGTH => {
+                        let prototype = prototype.clone();
+                        drop(borrowed);
+                        chain.push((current, shape));
+                        prototype
+                    }
+                    _ => return,
+                }
+            };
+            current = next;
+        }
+    }
+
+    /// Writes `value` to the property of `object` if the cached access applies to it.
+    ///
+    /// Returns `false` if the cache misses, in which case the property must be set by
+    /// looking it up.
+    pub(crate) fn set(&self, object: &JsObject, value: &JsValue) -> bool {
+        let entry = self.entry.borrow();
+        match &*entry {
+            Some(CacheEntry::Set { shape, slot }) => {
+                let mut receiver = object.borrow_mut();
+                if !receiver.has_ordinary_named_set()
+                    || receiver.properties().shape() != Some(shape)
+                {
+                    return false;
+                }
+                let property = receiver.properties_mut().slot_mut(*slot);
+                if property.writable() != Some(true) || property.value().is_none() {
+                    return false;
+                }
+                *property = PropertyDescriptor::builder()
+                    .value(value.clone())
+                    .writable(true)
+                    .enumerable(property.expect_enumerable())
+                    .configurable(property.expect_configurable())
+                    .build();
+                true
+            }
+            Some(CacheEntry::Add { shape, prototypes }) => {
+                {
+                    let receiver = object.borrow();
+                    if !receiver.has_ordinary_named_set()
+                        || !receiver.extensible()
+                        || receiver.properties().shape() != Some(shape)
+                    {
+                        return false;
+                    }
+
+                    let mut prototype = receiver.prototype().clone();
+                    for (expected, expected_shape) in prototypes.iter() {
+                        let current = match &prototype {
+                            Some(current) if JsObject::equals(current, expected) => current.clone(),
+                            _ => return false,
+                        };
+                        let current = current.borrow();
+                        if !current.has_ordinary_named_get()
+                            || current.properties().shape() != Some(expected_shape)
+                        {
+                            return false;
+                        }
+                        prototype.clone_from(current.prototype());
+                    }
+                    if prototype.is_some() {
+                        return false;
+                    }
+                }
+
+                object.borrow_mut().properties_mut().insert(
+                    &self.key.clone().into(),
+                    PropertyDescriptor::builder()
+                        .value(value.clone())
+                        .writable(true)
+                        .enumerable(true)
+                        .configurable(true)
+                        .build(),
+                );
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Caches the write of the property to `object`, after it was set by looking it up.
+    ///
+    /// `shape` is the shape `object` had before the property was set.
+    pub(crate) fn update_set(&self, object: &JsObject, shape: Option<Shape>) {
+        if !self.can_update() {
+            return;
+        }
+        let shape = match shape {
+            Some(shape) => shape,
+            None => return,
+        };
+
+        let receiver = object.borrow();
+        if !receiver.has_ordinary_named_set() {
+            return;
+        }
+        let new_shape = match receiver.properties().shape() {
+            Some(new_shape) => new_shape,
+            None => return,
+        };
+        let slot = match new_shape.lookup(&self.key) {
+            Some(slot) => slot,
+            None => return,
+        };
+
+        // The property already existed, so it was written in its slot.
+        if *new_shape == shape {
+            let property = receiver.properties().slot(slot);
+            if property.writable() == Some(true) && property.value().is_some() {
+                drop(receiver);
+                self.set_entry(CacheEntry::Set { shape, slot });
+            }
+            return;
+        }
+
+        // The property was added, so none of the prototypes had it.
+        if slot as usize != shape.len() || new_shape.len() != shape.len() + 1 {
+            return;
+        }
+        let mut prototypes = Vec::new();
+        let mut prototype = receiver.prototype().clone();
+        drop(receiver);
+        while let Some(current) = prototype {
+            if prototypes.len() == MAX_PROTOTYPE_CHAIN_LENGTH {
+                return;
+            }
+            let borrowed = current.borrow();
+            if !borrowed.has_ordinary_named_get() {
+                return;
+            }
+            let current_shape = match borrowed.properties().shape() {
+                Some(current_shape) if current_shape.lookup(&self.key).is_none() => {
+                    current_shape.clone()
+                }
+                _ => return,
+            };
+            let next = borrowed.prototype().clone();
+            drop(borrowed);
+            prototypes.push((current, current_shape));
+            prototype = next;
+        }
+
+        self.set_entry(CacheEntry::Add {
+            shape,
+            prototypes: prototypes.into_boxed_slice(),
+        });
+    }
+
+    /// Returns `true` if the entry can be updated.
+    fn can_update(&self) -> bool {
+        self.updates.get() < MAX_UPDATES
+    }
+
+    /// Replaces the entry of the cache.
+    fn set_entry(&self, entry: CacheEntry) {
+        self.updates.set(self.updates.get() + 1);
+        *self.entry.borrow_mut() = Some(entry);
+    }
+}
@@ -9,30 +9,44 @@ use crate::{
         iterable::{IteratorHint, IteratorRecord, IteratorResult},
         Array, ForInIterator, JsArgs, Number, Promise,
     },
-    environments::EnvironmentSlots,
+    environments::{DeclarativeEnvironmentStack, EnvironmentSlots},
     object::{FunctionBuilder, JsFunction, JsObject, ObjectData, PrivateElement},
-    property::{DescriptorKind, PropertyDescriptor, PropertyDescriptorBuilder, PropertyKey},
+    property::{DescriptorKind, PropertyDescriptor, PropertyDescriptorBuilder},
     value::Numeric,
     vm::{
         call_frame::CatchAddresses,
         code_block::{initialize_instance_elements, Readable},
     },
-    Context, JsBigInt, JsResult, JsString, JsValue,
+    Context, JsBigInt, JsError, JsNativeError, JsResult, JsString, JsValue, JsVariant,
 };
-use boa_interner::ToInternedString;
+use boa_interner::{Interner, Sym, ToInternedString};
 use boa_profiler::Profiler;
 use std::{convert::TryInto, mem::size_of, ops::Neg, time::Instant};
 
+mod bytecode_cache;
 mod call_frame;
 mod code_block;
+mod debugger;
+mod inline_cache;
 mod opcode;
-
-pub use {call_frame::CallFrame, code_block::CodeBlock, opcode::Opcode};
+mod runtime_limits;
+
+pub use {
+    call_frame::CallFrame,
+    code_block::CodeBlock,
+    debugger::{Debugger, PauseHandle, PauseReason, ResumeMode, StackFrame},
+    opcode::Opcode,
+    runtime_limits::{InterruptHandle, RuntimeLimits},
+};
 
 pub(crate) use {
-    call_frame::{FinallyReturn, GeneratorResumeKind, TryStackEntry},
+    bytecode_cache::{Decoder, Encoder},
+    call_frame::{FinallyReturn, GeneratorResumeKind, TailCall, TryStackEntry},
     code_block::{create_function_object, create_generator_function_object},
+    debugger::DebuggerState,
+    inline_cache::InlineCache,
     opcode::BindingOpcode,
+    runtime_limits::ExecutionBudget,
 };
 
 #[cfg(test)]
@@ -40,10 +54,13 @@ mod tests;
 /// Virtual Machine.
 #[derive(Debug)]
 pub struct Vm {
+    pub(crate) environments: DeclarativeEnvironmentStack,
     pub(crate) frames: Vec<CallFrame>,
     pub(crate) stack: Vec<JsValue>,
     pub(crate) trace: bool,
     pub(crate) stack_size_limit: usize,
+    pub(crate) budget: ExecutionBudget,
+    pub(crate) debugger: DebuggerState,
 }
 
 impl Vm {
@@ -104,6 +121,38 @@ impl Vm {
     pub(crate) fn pop_frame(&mut self) -> Option<CallFrame> {
         self.frames.pop()
     }
+
+    /// Describes the frames of the call stack, starting with the innermost frame.
+    ///
+    /// Each frame is described by the name of its function, the name of the script and
+    /// the line and column of the instruction being executed, e.g. `add (main.js:2:5)`.
+    pub(crate) fn stack_trace(&self, interner: &Interner) -> Vec<String> {
+        self.frames
+            .iter()
+            .rev()
+            .map(|frame| {
+                let code = &frame.code;
+                let name = match code.name {
+                    Sym::EMPTY_STRING => "<anonymous>",
+                    name => interner.resolve_expect(name),
+                };
+                let source_name = code
+                    .source_name
+                    .as_ref()
+                    .map_or_else(|| "<anonymous>".to_owned(), JsString::to_std_string_escaped);
+
+                // The program counter points past the instruction being executed.
+                match code.position((frame.pc as u32).saturating_sub(1)) {
+                    Some(position) => format!(
+                        "{name} ({source_name}:{}:{})",
+                        position.line_number(),
+                        position.column_number()
+                    ),
+                    None => format!("{name} ({source_name})"),
+                }
+            })
+            .collect()
+    }
 }
 
 /// Indicates if the execution should continue, exit or yield.
@@ -133,6 +182,8 @@ impl Context {
             }};
         }
 
+        self.check_instruction_budget()?;
+
         let opcode: Opcode = {
             let _timer = Profiler::global().start_event("Opcode retrieval", "vm");
             let opcode = self.vm.frame().code.code[self.vm.frame().pc]
@@ -205,8 +256,9 @@ impl Context {
                 if let Some(superclass) = superclass.as_constructor() {
                     let proto = superclass.get("prototype", self)?;
                     if !proto.is_object() && !proto.is_null() {
-                        return self
-                            .throw_type_error("superclass prototype must be an object or null");
+                        return Err(JsNativeError::typ()
+                            .with_message("superclass prototype must be an object or null")
+                            .into());
                     }
 
                     let class = self.vm.pop();
@@ -229,17 +281,19 @@ impl Context {
                     self.vm.push(class);
                     self.vm.push(proto);
                 } else if superclass.is_null() {
-                    self.vm.push(JsValue::Null);
+                    self.vm.push(JsValue::null());
                 } else {
-                    return self.throw_type_error("superclass must be a constructor");
+                    return Err(JsNativeError::typ()
+                        .with_message("superclass must be a constructor")
+                        .into());
                 }
             }
             Opcode::SetClassPrototype => {
                 let prototype_value = self.vm.pop();
-                let prototype = match &prototype_value {
-                    JsValue::Object(proto) => Some(proto.clone()),
-                    JsValue::Null => None,
-                    JsValue::Undefined => {
+                let prototype = match prototype_value.variant() {
+                    JsVariant::Object(proto) => Some(proto.clone()),
+                    JsVariant::Null => None,
+                    JsVariant::Undefined => {
                         Some(self.intrinsics().constructors().object().prototype.clone())
                     }
                     _ => unreachable!(),
@@ -386,10 +440,12 @@ impl Context {
                 let lhs = self.vm.pop();
 
                 if !rhs.is_object() {
-                    return self.throw_type_error(format!(
-                        "right-hand side of 'in' should be an object, got {}",
-                        rhs.type_of()
-                    ));
+                    return Err(JsNativeError::typ()
+                        .with_message(format!(
+                            "right-hand side of 'in' should be an object, got {}",
+                            rhs.type_of()
+                        ))
+                        .into());
                 }
                 let key = lhs.to_property_key(self)?;
                 let value = self.has_property(&rhs, &key)?;
@@ -484,17 +540,17 @@ impl Context {
                         .into();
                     self.global_bindings_mut().entry(key).or_insert(
                         PropertyDescriptor::builder()
-                            .value(JsValue::Undefined)
+                            .value(JsValue::undefined())
                             .writable(true)
                             .enumerable(true)
                             .configurable(true)
                             .build(),
                     );
                 } else {
-                    self.realm.environments.put_value_if_uninitialized(
+                    self.vm.environments.put_value_if_uninitialized(
                         binding_locator.environment_index(),
                         binding_locator.binding_index(),
-                        JsValue::Undefined,
+                        JsValue::undefined(),
                     );
                 }
             }
@@ -509,11 +565,9 @@ impl Context {
                         .interner()
                         .resolve_expect(binding_locator.name())
                         .into();
-                    crate::object::internal_methods::global::global_set_no_receiver(
-                        &key, value, self,
-                    )?;
+                    self.set_global_binding(key, value)?;
                 } else {
-                    self.realm.environments.put_value(
+                    self.vm.environments.put_value(
                         binding_locator.environment_index(),
                         binding_locator.binding_index(),
                         value,
@@ -523,17 +577,17 @@ impl Context {
             Opcode::DefLet => {
                 let index = self.vm.read::<u32>();
                 let binding_locator = self.vm.frame().code.bindings[index as usize];
-                self.realm.environments.put_value(
+                self.vm.environments.put_value(
                     binding_locator.environment_index(),
                     binding_locator.binding_index(),
-                    JsValue::Undefined,
+                    JsValue::undefined(),
                 );
             }
             Opcode::DefInitLet | Opcode::DefInitConst | Opcode::DefInitArg => {
                 let index = self.vm.read::<u32>();
                 let value = self.vm.pop();
                 let binding_locator = self.vm.frame().code.bindings[index as usize];
-                self.realm.environments.put_value(
+                self.vm.environments.put_value(
                     binding_locator.environment_index(),
                     binding_locator.binding_index(),
                     value,
@@ -546,7 +600,7 @@ impl Context {
 
                 let value = if binding_locator.is_global() {
                     if let Some(value) = self
-                        .realm
+                        .vm
                         .environments
                         .get_value_global_poisoned(binding_locator.name())
                     {
@@ -556,7 +610,7 @@ impl Context {
                             .interner()
                             .resolve_expect(binding_locator.name())
                             .into();
-                        match self.global_bindings_mut().get(&key) {
+                        match self.global_binding(&key) {
                             Some(desc) => match desc.kind() {
                                 DescriptorKind::Data {
                                     value: Some(value), ..
@@ -568,16 +622,19 @@ impl Context {
                                     self.call(&get, &self.global_object().clone().into(), &[])?
                                 }
                                 _ => {
-                                    return self
-                                        .throw_reference_error(format!("{key} is not defined"))
+                                    return Err(JsNativeError::reference()
+                                        .with_message(format!("{key} is not defined"))
+                                        .into())
                                 }
                             },
                             _ => {
-                                return self.throw_reference_error(format!("{key} is not defined"))
+                                return Err(JsNativeError::reference()
+                                    .with_message(format!("{key} is not defined"))
+                                    .into())
                             }
                         }
                     }
-                } else if let Some(value) = self.realm.environments.get_value_optional(
+                } else if let Some(value) = self.vm.environments.get_value_optional(
                     binding_locator.environment_index(),
                     binding_locator.binding_index(),
                     binding_locator.name(),
@@ -586,7 +643,9 @@ impl Context {
                 } else {
                     let name =
                         JsString::from(self.interner().resolve_expect(binding_locator.name()));
-                    return self.throw_reference_error(format!("{name} is not initialized"));
+                    return Err(JsNativeError::reference()
+                        .with_message(format!("{name} is not initialized"))
+                        .into());
                 };
 
                 self.vm.push(value);
@@ -597,7 +656,7 @@ impl Context {
                 binding_locator.throw_mutate_immutable(self)?;
                 let value = if binding_locator.is_global() {
                     if let Some(value) = self
-                        .realm
+                        .vm
                         .environments
                         .get_value_global_poisoned(binding_locator.name())
                     {
@@ -607,7 +666,7 @@ impl Context {
                             .interner()
                             .resolve_expect(binding_locator.name())
                             .into();
-                        match self.global_bindings_mut().get(&key) {
+                        match self.global_binding(&key) {
                             Some(desc) => match desc.kind() {
                                 DescriptorKind::Data {
                                     value: Some(value), ..
@@ -623,14 +682,15 @@ impl Context {
                             _ => JsValue::undefined(),
                         }
                     }
-                } else if let Some(value) = self.realm.environments.get_value_optional(
-                    binding_locator.environment_index(),
-                    binding_locator.binding_index(),
-                    binding_locator.name(),
-                ) {
-                    value
                 } else {
-                    JsValue::undefined()
+                    self.vm
+                        .environments
+                        .get_value_optional(
+                            binding_locator.environment_index(),
+                            binding_locator.binding_index(),
+                            binding_locator.name(),
+                        )
+                        .unwrap_or_default()
                 };
 
                 self.vm.push(value);
@@ -643,7 +703,7 @@ impl Context {
 
                 if binding_locator.is_global() {
                     if !self
-                        .realm
+                        .vm
                         .environments
                         .put_value_global_poisoned(binding_locator.name(), &value)
                     {
@@ -651,37 +711,34 @@ impl Context {
                             .interner()
                             .resolve_expect(binding_locator.name())
                             .into();
-                        let exists = self.global_bindings_mut().contains_key(&key);
+                        let exists = self.global_binding(&key).is_some();
 
                         if !exists && self.vm.frame().code.strict {
-                            return self.throw_reference_error(format!(
-                                "assignment to undeclared variable {key}"
-                            ));
+                            return Err(JsNativeError::reference()
+                                .with_message(format!("assignment to undeclared variable {key}"))
+                                .into());
                         }
 
-                        let success =
-                            crate::object::internal_methods::global::global_set_no_receiver(
-                                &key.clone().into(),
-                                value,
-                                self,
-                            )?;
+                        let success = self.set_global_binding(key.clone(), value)?;
 
                         if !success && self.vm.frame().code.strict {
-                            return self.throw_type_error(format!(
-                                "cannot set non-writable property: {key}",
-                            ));
+                            return Err(JsNativeError::typ()
+                                .with_message(format!("cannot set non-writable property: {key}"))
+                                .into());
                         }
                     }
-                } else if !self.realm.environments.put_value_if_initialized(
+                } else if !self.vm.environments.put_value_if_initialized(
                     binding_locator.environment_index(),
                     binding_locator.binding_index(),
                     binding_locator.name(),
                     value,
                 ) {
-                    self.throw_reference_error(format!(
-                        "cannot access '{}' before initialization",
-                        self.interner().resolve_expect(binding_locator.name())
-                    ))?;
+                    return Err(JsNativeError::reference()
+                        .with_message(format!(
+                            "cannot access '{}' before initialization",
+                            self.interner().resolve_expect(binding_locator.name())
+                        ))
+                        .into());
                 }
             }
             Opcode::Jump => {
@@ -740,9 +797,15 @@ impl Context {
                     value.to_object(self)?
                 };
 
-                let name = self.vm.frame().code.names[index as usize];
-                let name: PropertyKey = self.interner().resolve_expect(name).into();
-                let result = object.get(name, self)?;
+                let code = self.vm.frame().code.clone();
+                let cache = &code.inline_caches[index as usize];
+                let result = if let Some(result) = cache.get(&object, self) {
+                    result?
+                } else {
+                    let result = object.get(cache.key().clone(), self)?;
+                    cache.update_get(&object);
+                    result
+                };
 
                 self.vm.push(result);
             }
@@ -786,10 +849,13 @@ impl Context {
                     object.to_object(self)?
                 };
 
-                let name = self.vm.frame().code.names[index as usize];
-                let name: PropertyKey = self.interner().resolve_expect(name).into();
-
-                object.set(name, value, self.vm.frame().code.strict, self)?;
+                let code = self.vm.frame().code.clone();
+                let cache = &code.inline_caches[index as usize];
+                if !cache.set(&object, &value) {
+                    let shape = object.borrow().properties().shape().cloned();
+                    object.set(cache.key().clone(), value, code.strict, self)?;
+                    cache.update_set(&object, shape);
+                }
             }
             Opcode::DefineOwnPropertyByName => {
                 let index = self.vm.read::<u32>();
@@ -910,7 +976,7 @@ impl Context {
                 let value = self.vm.pop();
                 let object = object.to_object(self)?;
                 let name = self.vm.frame().code.names[index as usize];
-                let name = self.interner().resolve_expect(name).into();
+                let name = JsString::from(&*self.interner().resolve_expect_utf16(name)).into();
                 let set = object
                     .__get_own_property__(&name, self)?
                     .as_ref()
@@ -940,7 +1006,7 @@ impl Context {
                     .expect("method must be function object")
                     .set_home_object(object.clone());
                 let name = self.vm.frame().code.names[index as usize];
-                let name = self.interner().resolve_expect(name).into();
+                let name = JsString::from(&*self.interner().resolve_expect_utf16(name)).into();
                 let set = object
                     .__get_own_property__(&name, self)?
                     .as_ref()
@@ -1014,7 +1080,7 @@ impl Context {
                 let value = self.vm.pop();
                 let object = object.to_object(self)?;
                 let name = self.vm.frame().code.names[index as usize];
-                let name = self.interner().resolve_expect(name).into();
+                let name = JsString::from(&*self.interner().resolve_expect_utf16(name)).into();
                 let get = object
                     .__get_own_property__(&name, self)?
                     .as_ref()
@@ -1044,7 +1110,7 @@ impl Context {
                     .expect("method must be function object")
                     .set_home_object(object.clone());
                 let name = self.vm.frame().code.names[index as usize];
-                let name = self.interner().resolve_expect(name).into();
+                let name = JsString::from(&*self.interner().resolve_expect_utf16(name)).into();
                 let get = object
                     .__get_own_property__(&name, self)?
                     .as_ref()
@@ -1125,7 +1191,9 @@ impl Context {
                                 .set_private_element(name, PrivateElement::Field(value));
                         }
                         Some(PrivateElement::Method(_)) => {
-                            return self.throw_type_error("private method is not writable");
+                            return Err(JsNativeError::typ()
+                                .with_message("private method is not writable")
+                                .into());
                         }
                         Some(PrivateElement::Accessor {
                             setter: Some(setter),
@@ -1136,14 +1204,20 @@ impl Context {
                             setter.call(&object.clone().into(), &[value], self)?;
                         }
                         None => {
-                            return self.throw_type_error("private field not defined");
+                            return Err(JsNativeError::typ()
+                                .with_message("private field not defined")
+                                .into());
                         }
                         _ => {
-                            return self.throw_type_error("private field defined without a setter");
+                            return Err(JsNativeError::typ()
+                                .with_message("private field defined without a setter")
+                                .into());
                         }
                     }
                 } else {
-                    return self.throw_type_error("cannot set private property on non-object");
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot set private property on non-object")
+                        .into());
                 }
             }
             Opcode::SetPrivateField => {
@@ -1165,7 +1239,9 @@ impl Context {
                         object_borrow_mut.set_private_element(name, PrivateElement::Field(value));
                     }
                 } else {
-                    return self.throw_type_error("cannot set private property on non-object");
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot set private property on non-object")
+                        .into());
                 }
             }
             Opcode::SetPrivateMethod => {
@@ -1179,7 +1255,9 @@ impl Context {
                     object_borrow_mut
                         .set_private_element(name, PrivateElement::Method(value.clone()));
                 } else {
-                    return self.throw_type_error("cannot set private setter on non-object");
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot set private setter on non-object")
+                        .into());
                 }
             }
             Opcode::SetPrivateSetter => {
@@ -1192,7 +1270,9 @@ impl Context {
                     let mut object_borrow_mut = object.borrow_mut();
                     object_borrow_mut.set_private_element_setter(name, value.clone());
                 } else {
-                    return self.throw_type_error("cannot set private setter on non-object");
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot set private setter on non-object")
+                        .into());
                 }
             }
             Opcode::SetPrivateGetter => {
@@ -1205,7 +1285,9 @@ impl Context {
                     let mut object_borrow_mut = object.borrow_mut();
                     object_borrow_mut.set_private_element_getter(name, value.clone());
                 } else {
-                    return self.throw_type_error("cannot set private getter on non-object");
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot set private getter on non-object")
+                        .into());
                 }
             }
             Opcode::GetPrivateField => {
@@ -1226,16 +1308,20 @@ impl Context {
                                 self.vm.push(value);
                             }
                             PrivateElement::Accessor { .. } => {
-                                return self.throw_type_error(
-                                    "private property was defined without a getter",
-                                );
+                                return Err(JsNativeError::typ()
+                                    .with_message("private property was defined without a getter")
+                                    .into());
                             }
                         }
                     } else {
-                        return self.throw_type_error("private property does not exist");
+                        return Err(JsNativeError::typ()
+                            .with_message("private property does not exist")
+                            .into());
                     }
                 } else {
-                    return self.throw_type_error("cannot read private property from non-object");
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot read private property from non-object")
+                        .into());
                 }
             }
             Opcode::PushClassField => {
@@ -1347,11 +1433,13 @@ impl Context {
             Opcode::DeletePropertyByName => {
                 let index = self.vm.read::<u32>();
                 let key = self.vm.frame().code.names[index as usize];
-                let key = self.interner().resolve_expect(key).into();
+                let key = JsString::from(&*self.interner().resolve_expect_utf16(key)).into();
                 let object = self.vm.pop();
                 let result = object.to_object(self)?.__delete__(&key, self)?;
                 if !result && self.vm.frame().code.strict {
-                    return Err(self.construct_type_error("Cannot delete property"));
+                    return Err(JsNativeError::typ()
+                        .with_message("Cannot delete property")
+                        .into());
                 }
                 self.vm.push(result);
             }
@@ -1362,7 +1450,9 @@ impl Context {
                     .to_object(self)?
                     .__delete__(&key.to_property_key(self)?, self)?;
                 if !result && self.vm.frame().code.strict {
-                    return Err(self.construct_type_error("Cannot delete property"));
+                    return Err(JsNativeError::typ()
+                        .with_message("Cannot delete property")
+                        .into());
                 }
                 self.vm.push(result);
             }
@@ -1393,7 +1483,7 @@ impl Context {
             }
             Opcode::Throw => {
                 let value = self.vm.pop();
-                return Err(value);
+                return Err(JsError::from_opaque(value));
             }
             Opcode::TryStart => {
                 let next = self.vm.read::<u32>();
@@ -1414,7 +1504,7 @@ impl Context {
                 self.vm.frame_mut().catch.pop();
                 let try_stack_entry = self.vm.frame_mut().try_env_stack.pop().expect("must exist");
                 for _ in 0..try_stack_entry.num_env {
-                    self.realm.environments.pop();
+                    self.vm.environments.pop();
                 }
                 let mut num_env = try_stack_entry.num_env;
                 for _ in 0..try_stack_entry.num_loop_stack_entries {
@@ -1476,7 +1566,7 @@ impl Context {
                         return Ok(ShouldExit::True);
                     }
                     FinallyReturn::Err => {
-                        return Err(self.vm.pop());
+                        return Err(JsError::from_opaque(self.vm.pop()));
                     }
                 }
             }
@@ -1490,26 +1580,29 @@ impl Context {
                     .expect("finally jump must exist here") = Some(address);
             }
             Opcode::This => {
-                let env = self.realm.environments.get_this_environment();
-                match env {
+                let env = self.vm.environments.get_this_environment().clone();
+                match &env {
                     EnvironmentSlots::Function(env) => {
                         let env_b = env.borrow();
                         if let Some(this) = env_b.get_this_binding() {
                             self.vm.push(this);
                         } else {
                             drop(env_b);
-                            return self.throw_reference_error("Must call super constructor in derived class before accessing 'this' or returning from derived constructor");
+                            return Err(JsNativeError::reference().with_message("Must call super constructor in derived class before accessing 'this' or returning from derived constructor").into());
                         }
                     }
                     EnvironmentSlots::Global => {
-                        let this = self.realm.global_object();
+                        let this = self.realm().global_object();
                         self.vm.push(this.clone());
                     }
+                    EnvironmentSlots::Module(_) => {
+                        self.vm.push(JsValue::undefined());
+                    }
                 }
             }
             Opcode::Super => {
                 let env = self
-                    .realm
+                    .vm
                     .environments
                     .get_this_environment()
                     .as_function_slots()
@@ -1533,17 +1626,17 @@ impl Context {
 
                     home_object
                 } else {
-                    return self.throw_range_error("Must call super constructor in derived class before accessing 'this' or returning from derived constructor");
+                    return Err(JsNativeError::range().with_message("Must call super constructor in derived class before accessing 'this' or returning from derived constructor").into());
                 };
 
                 if let Some(home) = home {
                     if let Some(proto) = home.__get_prototype_of__(self)? {
                         self.vm.push(JsValue::from(proto));
                     } else {
-                        self.vm.push(JsValue::Null);
+                        self.vm.push(JsValue::null());
                     }
                 } else {
-                    self.vm.push(JsValue::Null);
+                    self.vm.push(JsValue::null());
                 };
             }
             Opcode::SuperCall => {
@@ -1556,7 +1649,7 @@ impl Context {
 
                 let (new_target, active_function) = {
                     let this_env = self
-                        .realm
+                        .vm
                         .environments
                         .get_this_environment()
                         .as_function_slots()
@@ -1575,7 +1668,9 @@ impl Context {
                     .expect("function object must have prototype");
 
                 if !super_constructor.is_constructor() {
-                    return self.throw_type_error("super constructor object must be constructor");
+                    return Err(JsNativeError::typ()
+                        .with_message("super constructor object must be constructor")
+                        .into());
                 }
 
                 let result = super_constructor.__construct__(&arguments, &new_target, self)?;
@@ -1583,14 +1678,16 @@ impl Context {
                 initialize_instance_elements(&result, &active_function, self)?;
 
                 let this_env = self
-                    .realm
+                    .vm
                     .environments
                     .get_this_environment()
                     .as_function_slots()
                     .expect("super call must be in function environment");
 
                 if !this_env.borrow_mut().bind_this_value(&result) {
-                    return self.throw_reference_error("this already initialized");
+                    return Err(JsNativeError::reference()
+                        .with_message("this already initialized")
+                        .into());
                 }
                 self.vm.push(result);
             }
@@ -1609,7 +1706,7 @@ impl Context {
 
                 let (new_target, active_function) = {
                     let this_env = self
-                        .realm
+                        .vm
                         .environments
                         .get_this_environment()
                         .as_function_slots()
@@ -1628,7 +1725,9 @@ impl Context {
                     .expect("function object must have prototype");
 
                 if !super_constructor.is_constructor() {
-                    return self.throw_type_error("super constructor object must be constructor");
+                    return Err(JsNativeError::typ()
+                        .with_message("super constructor object must be constructor")
+                        .into());
                 }
 
                 let result = super_constructor.__construct__(&arguments, &new_target, self)?;
@@ -1636,14 +1735,16 @@ impl Context {
                 initialize_instance_elements(&result, &active_function, self)?;
 
                 let this_env = self
-                    .realm
+                    .vm
                     .environments
                     .get_this_environment()
                     .as_function_slots()
                     .expect("super call must be in function environment");
 
                 if !this_env.borrow_mut().bind_this_value(&result) {
-                    return self.throw_reference_error("this already initialized");
+                    return Err(JsNativeError::reference()
+                        .with_message("this already initialized")
+                        .into());
                 }
                 self.vm.push(result);
             }
@@ -1657,7 +1758,7 @@ impl Context {
 
                 let (new_target, active_function) = {
                     let this_env = self
-                        .realm
+                        .vm
                         .environments
                         .get_this_environment()
                         .as_function_slots()
@@ -1676,7 +1777,9 @@ impl Context {
                     .expect("function object must have prototype");
 
                 if !super_constructor.is_constructor() {
-                    return self.throw_type_error("super constructor object must be constructor");
+                    return Err(JsNativeError::typ()
+                        .with_message("super constructor object must be constructor")
+                        .into());
                 }
 
                 let result = super_constructor.__construct__(&arguments, &new_target, self)?;
@@ -1684,13 +1787,15 @@ impl Context {
                 initialize_instance_elements(&result, &active_function, self)?;
 
                 let this_env = self
-                    .realm
+                    .vm
                     .environments
                     .get_this_environment()
                     .as_function_slots()
                     .expect("super call must be in function environment");
                 if !this_env.borrow_mut().bind_this_value(&result) {
-                    return self.throw_reference_error("this already initialized");
+                    return Err(JsNativeError::reference()
+                        .with_message("this already initialized")
+                        .into());
                 }
 
                 self.vm.push(result);
@@ -1737,7 +1842,9 @@ impl Context {
             }
             Opcode::CallEval => {
                 if self.vm.stack_size_limit <= self.vm.stack.len() {
-                    return self.throw_range_error("Maximum call stack size exceeded");
+                    return Err(JsNativeError::range()
+                        .with_message("Maximum call stack size exceeded")
+                        .into());
                 }
                 let argument_count = self.vm.read::<u32>();
                 let mut arguments = Vec::with_capacity(argument_count as usize);
@@ -1749,9 +1856,13 @@ impl Context {
                 let func = self.vm.pop();
                 let this = self.vm.pop();
 
-                let object = match func {
-                    JsValue::Object(ref object) if object.is_callable() => object.clone(),
-                    _ => return self.throw_type_error("not a callable function"),
+                let object = match func.variant() {
+                    JsVariant::Object(object) if object.is_callable() => object.clone(),
+                    _ => {
+                        return Err(JsNativeError::typ()
+                            .with_message("not a callable function")
+                            .into())
+                    }
                 };
 
                 // A native function with the name "eval" implies, that is this the built-in eval function.
@@ -1765,7 +1876,7 @@ impl Context {
                             crate::builtins::eval::Eval::perform_eval(x, true, strict, self)?;
                         self.vm.push(result);
                     } else {
-                        self.vm.push(JsValue::Undefined);
+                        self.vm.push(JsValue::undefined());
                     }
                 } else {
                     let result = object.__call__(&this, &arguments, self)?;
@@ -1774,7 +1885,9 @@ impl Context {
             }
             Opcode::CallEvalSpread => {
                 if self.vm.stack_size_limit <= self.vm.stack.len() {
-                    return self.throw_range_error("Maximum call stack size exceeded");
+                    return Err(JsNativeError::range()
+                        .with_message("Maximum call stack size exceeded")
+                        .into());
                 }
 
                 // Get the arguments that are stored as an array object on the stack.
@@ -1792,9 +1905,13 @@ impl Context {
                 let func = self.vm.pop();
                 let this = self.vm.pop();
 
-                let object = match func {
-                    JsValue::Object(ref object) if object.is_callable() => object.clone(),
-                    _ => return self.throw_type_error("not a callable function"),
+                let object = match func.variant() {
+                    JsVariant::Object(object) if object.is_callable() => object.clone(),
+                    _ => {
+                        return Err(JsNativeError::typ()
+                            .with_message("not a callable function")
+                            .into())
+                    }
                 };
 
                 // A native function with the name "eval" implies, that is this the built-in eval function.
@@ -1808,7 +1925,7 @@ impl Context {
                             crate::builtins::eval::Eval::perform_eval(x, true, strict, self)?;
                         self.vm.push(result);
                     } else {
-                        self.vm.push(JsValue::Undefined);
+                        self.vm.push(JsValue::undefined());
                     }
                 } else {
                     let result = object.__call__(&this, &arguments, self)?;
@@ -1817,7 +1934,9 @@ impl Context {
             }
             Opcode::Call => {
                 if self.vm.stack_size_limit <= self.vm.stack.len() {
-                    return self.throw_range_error("Maximum call stack size exceeded");
+                    return Err(JsNativeError::range()
+                        .with_message("Maximum call stack size exceeded")
+                        .into());
                 }
                 let argument_count = self.vm.read::<u32>();
                 let mut arguments = Vec::with_capacity(argument_count as usize);
@@ -1829,18 +1948,64 @@ impl Context {
                 let func = self.vm.pop();
                 let this = self.vm.pop();
 
-                let object = match func {
-                    JsValue::Object(ref object) if object.is_callable() => object.clone(),
-                    _ => return self.throw_type_error("not a callable function"),
+                let object = match func.variant() {
+                    JsVariant::Object(object) if object.is_callable() => object.clone(),
+                    _ => {
+                        return Err(JsNativeError::typ()
+                            .with_message("not a callable function")
+                            .into())
+                    }
                 };
 
                 let result = object.__call__(&this, &arguments, self)?;
 
                 self.vm.push(result);
             }
+            Opcode::TailCall => {
+                if self.vm.stack_size_limit <= self.vm.stack.len() {
+                    return Err(JsNativeError::range()
+                        .with_message("Maximum call stack size exceeded")
+                        .into());
+                }
+                let argument_count = self.vm.read::<u32>();
+                let mut arguments = Vec::with_capacity(argument_count as usize);
+                for _ in 0..argument_count {
+                    arguments.push(self.vm.pop());
+                }
+                arguments.reverse();
+
+                let func = self.vm.pop();
+                let this = self.vm.pop();
+
+                let object = match func.variant() {
+                    JsVariant::Object(object) if object.is_callable() => object.clone(),
+                    _ => {
+                        return Err(JsNativeError::typ()
+                            .with_message("not a callable function")
+                            .into())
+                    }
+                };
+
+                // Errors of a call inside of a `try` block have to be handled by this frame.
+                if self.vm.frame().catch.is_empty() {
+                    self.vm.frame_mut().tail_call = Some(TailCall {
+                        function: object,
+                        this,
+                        arguments,
+                    });
+                    self.vm.push(JsValue::undefined());
+                    return Ok(ShouldExit::True);
+                }
+
+                let result = object.__call__(&this, &arguments, self)?;
+
+                self.vm.push(result);
+            }
             Opcode::CallSpread => {
                 if self.vm.stack_size_limit <= self.vm.stack.len() {
-                    return self.throw_range_error("Maximum call stack size exceeded");
+                    return Err(JsNativeError::range()
+                        .with_message("Maximum call stack size exceeded")
+                        .into());
                 }
 
                 // Get the arguments that are stored as an array object on the stack.
@@ -1858,9 +2023,13 @@ impl Context {
                 let func = self.vm.pop();
                 let this = self.vm.pop();
 
-                let object = match func {
-                    JsValue::Object(ref object) if object.is_callable() => object.clone(),
-                    _ => return self.throw_type_error("not a callable function"),
+                let object = match func.variant() {
+                    JsVariant::Object(object) if object.is_callable() => object.clone(),
+                    _ => {
+                        return Err(JsNativeError::typ()
+                            .with_message("not a callable function")
+                            .into())
+                    }
                 };
 
                 let result = object.__call__(&this, &arguments, self)?;
@@ -1869,7 +2038,9 @@ impl Context {
             }
             Opcode::New => {
                 if self.vm.stack_size_limit <= self.vm.stack.len() {
-                    return self.throw_range_error("Maximum call stack size exceeded");
+                    return Err(JsNativeError::range()
+                        .with_message("Maximum call stack size exceeded")
+                        .into());
                 }
                 let argument_count = self.vm.read::<u32>();
                 let mut arguments = Vec::with_capacity(argument_count as usize);
@@ -1881,14 +2052,20 @@ impl Context {
 
                 let result = func
                     .as_constructor()
-                    .ok_or_else(|| self.construct_type_error("not a constructor"))
+                    .ok_or_else(|| {
+                        JsNativeError::typ()
+                            .with_message("not a constructor")
+                            .into()
+                    })
                     .and_then(|cons| cons.__construct__(&arguments, cons, self))?;
 
                 self.vm.push(result);
             }
             Opcode::NewSpread => {
                 if self.vm.stack_size_limit <= self.vm.stack.len() {
-                    return self.throw_range_error("Maximum call stack size exceeded");
+                    return Err(JsNativeError::range()
+                        .with_message("Maximum call stack size exceeded")
+                        .into());
                 }
                 // Get the arguments that are stored as an array object on the stack.
                 let arguments_array = self.vm.pop();
@@ -1906,7 +2083,11 @@ impl Context {
 
                 let result = func
                     .as_constructor()
-                    .ok_or_else(|| self.construct_type_error("not a constructor"))
+                    .ok_or_else(|| {
+                        JsNativeError::typ()
+                            .with_message("not a constructor")
+                            .into()
+                    })
                     .and_then(|cons| cons.__construct__(&arguments, cons, self))?;
 
                 self.vm.push(result);
@@ -1921,7 +2102,7 @@ impl Context {
                     let try_stack_entry =
                         self.vm.frame_mut().try_env_stack.pop().expect("must exist");
                     for _ in 0..try_stack_entry.num_env {
-                        self.realm.environments.pop();
+                        self.vm.environments.pop();
                     }
                     let mut num_env = try_stack_entry.num_env;
                     for _ in 0..try_stack_entry.num_loop_stack_entries {
@@ -1948,7 +2129,7 @@ impl Context {
                 let compile_environment = self.vm.frame().code.compile_environments
                     [compile_environments_index as usize]
                     .clone();
-                self.realm
+                self.vm
                     .environments
                     .push_declarative(num_bindings as usize, compile_environment);
                 self.vm.frame_mut().loop_env_stack_inc();
@@ -1960,12 +2141,12 @@ impl Context {
                 let compile_environment = self.vm.frame().code.compile_environments
                     [compile_environments_index as usize]
                     .clone();
-                self.realm
+                self.vm
                     .environments
                     .push_function_inherit(num_bindings as usize, compile_environment);
             }
             Opcode::PopEnvironment => {
-                self.realm.environments.pop();
+                self.vm.environments.pop();
                 self.vm.frame_mut().loop_env_stack_dec();
                 self.vm.frame_mut().try_env_stack_dec();
             }
@@ -1974,6 +2155,7 @@ impl Context {
                 self.vm.frame_mut().try_env_stack_loop_inc();
             }
             Opcode::LoopContinue => {
+                self.check_loop_iteration_budget()?;
                 let env_num = self
                     .vm
                     .frame_mut()
@@ -1983,7 +2165,7 @@ impl Context {
                 let env_num_copy = *env_num;
                 *env_num = 0;
                 for _ in 0..env_num_copy {
-                    self.realm.environments.pop();
+                    self.vm.environments.pop();
                 }
             }
             Opcode::LoopEnd => {
@@ -1994,7 +2176,7 @@ impl Context {
                     .pop()
                     .expect("loop env stack entry must exist");
                 for _ in 0..env_num {
-                    self.realm.environments.pop();
+                    self.vm.environments.pop();
                     self.vm.frame_mut().try_env_stack_dec();
                 }
                 self.vm.frame_mut().try_env_stack_loop_dec();
@@ -2015,7 +2197,9 @@ impl Context {
                     .as_ref()
                     .map(PropertyDescriptor::expect_value)
                     .cloned()
-                    .ok_or_else(|| self.construct_type_error("Could not find property `next`"))?;
+                    .ok_or_else(|| {
+                        JsNativeError::typ().with_message("Could not find property `next`")
+                    })?;
 
                 self.vm.push(iterator);
                 self.vm.push(next_method);
@@ -2071,7 +2255,7 @@ impl Context {
                 let iterator = iterator.as_object().expect("iterator was not an object");
                 if !done {
                     let iterator_record = IteratorRecord::new(iterator.clone(), next_method, done);
-                    iterator_record.close(Ok(JsValue::Null), self)?;
+                    iterator_record.close(Ok(JsValue::null()), self)?;
                 }
             }
             Opcode::IteratorToArray => {
@@ -2123,7 +2307,7 @@ impl Context {
                     self.vm.frame_mut().pc = address as usize;
                     self.vm.frame_mut().loop_env_stack_dec();
                     self.vm.frame_mut().try_env_stack_dec();
-                    self.realm.environments.pop();
+                    self.vm.environments.pop();
                     self.vm.push(iterator.clone());
                     self.vm.push(next_method);
                     self.vm.push(done);
@@ -2139,7 +2323,9 @@ impl Context {
                 let next_method_object = if let Some(object) = next_method.as_callable() {
                     object
                 } else {
-                    return self.throw_type_error("iterable next method not a function");
+                    return Err(JsNativeError::typ()
+                        .with_message("iterable next method not a function")
+                        .into());
                 };
                 let iterator = self.vm.pop();
                 let next_result = next_method_object.call(&iterator, &[], self)?;
@@ -2154,14 +2340,16 @@ impl Context {
                 let next_result = if let Some(next_result) = next_result.as_object() {
                     IteratorResult::new(next_result.clone())
                 } else {
-                    return self.throw_type_error("next value should be an object");
+                    return Err(JsNativeError::typ()
+                        .with_message("next value should be an object")
+                        .into());
                 };
 
                 if next_result.complete(self)? {
                     self.vm.frame_mut().pc = address as usize;
                     self.vm.frame_mut().loop_env_stack_dec();
                     self.vm.frame_mut().try_env_stack_dec();
-                    self.realm.environments.pop();
+                    self.vm.environments.pop();
                     self.vm.push(true);
                 } else {
                     self.vm.push(false);
@@ -2177,7 +2365,10 @@ impl Context {
                 }
                 strings.reverse();
                 let s = JsString::concat_array(
-                    &strings.iter().map(JsString::as_str).collect::<Vec<&str>>(),
+                    &strings
+                        .iter()
+                        .map(JsString::as_slice)
+                        .collect::<Vec<&[u16]>>(),
                 );
                 self.vm.push(s);
             }
@@ -2189,10 +2380,14 @@ impl Context {
             Opcode::ValueNotNullOrUndefined => {
                 let value = self.vm.pop();
                 if value.is_null() {
-                    return self.throw_type_error("Cannot destructure 'null' value");
+                    return Err(JsNativeError::typ()
+                        .with_message("Cannot destructure 'null' value")
+                        .into());
                 }
                 if value.is_undefined() {
-                    return self.throw_type_error("Cannot destructure 'undefined' value");
+                    return Err(JsNativeError::typ()
+                        .with_message("Cannot destructure 'undefined' value")
+                        .into());
                 }
                 self.vm.push(value);
             }
@@ -2236,7 +2431,7 @@ impl Context {
                 GeneratorResumeKind::Normal => return Ok(ShouldExit::False),
                 GeneratorResumeKind::Throw => {
                     let received = self.vm.pop();
-                    return Err(received);
+                    return Err(JsError::from_opaque(received));
                 }
                 GeneratorResumeKind::Return => {
                     let mut finally_left = false;
@@ -2263,7 +2458,7 @@ impl Context {
                 let value = self.vm.pop();
 
                 if self.vm.frame().generator_resume_kind == GeneratorResumeKind::Throw {
-                    return Err(value);
+                    return Err(JsError::from_opaque(value));
                 }
 
                 let completion = Ok(value);
@@ -2291,9 +2486,11 @@ impl Context {
                 if let Some(next) = gen.queue.front() {
                     let (completion, r#return) = &next.completion;
                     if *r#return {
-                        match completion {
-                            Ok(value) | Err(value) => self.vm.push(value),
-                        }
+                        let value = match completion {
+                            Ok(value) => value.clone(),
+                            Err(e) => e.to_opaque(self),
+                        };
+                        self.vm.push(value);
                         self.vm.push(true);
                     } else {
                         self.vm.push(completion.clone()?);
@@ -2324,7 +2521,8 @@ impl Context {
                         let result =
                             self.call(&next_method, &iterator.clone().into(), &[received])?;
                         let result_object = result.as_object().ok_or_else(|| {
-                            self.construct_type_error("generator next method returned non-object")
+                            JsNativeError::typ()
+                                .with_message("generator next method returned non-object")
                         })?;
                         let done = result_object.get("done", self)?.to_boolean();
                         if done {
@@ -2345,9 +2543,8 @@ impl Context {
                         if let Some(throw) = throw {
                             let result = throw.call(&iterator.clone().into(), &[received], self)?;
                             let result_object = result.as_object().ok_or_else(|| {
-                                self.construct_type_error(
-                                    "generator throw method returned non-object",
-                                )
+                                JsNativeError::typ()
+                                    .with_message("generator throw method returned non-object")
                             })?;
                             let done = result_object.get("done", self)?.to_boolean();
                             if done {
@@ -2366,10 +2563,10 @@ impl Context {
                         self.vm.frame_mut().pc = done_address as usize;
                         let iterator_record =
                             IteratorRecord::new(iterator.clone(), next_method, done);
-                        iterator_record.close(Ok(JsValue::Undefined), self)?;
-                        let error =
-                            self.construct_type_error("iterator does not have a throw method");
-                        return Err(error);
+                        iterator_record.close(Ok(JsValue::undefined()), self)?;
+                        let error = JsNativeError::typ()
+                            .with_message("iterator does not have a throw method");
+                        return Err(error.into());
                     }
                     GeneratorResumeKind::Return => {
                         let r#return = iterator.get_method("return", self)?;
@@ -2377,9 +2574,8 @@ impl Context {
                             let result =
                                 r#return.call(&iterator.clone().into(), &[received], self)?;
                             let result_object = result.as_object().ok_or_else(|| {
-                                self.construct_type_error(
-                                    "generator return method returned non-object",
-                                )
+                                JsNativeError::typ()
+                                    .with_message("generator return method returned non-object")
                             })?;
                             let done = result_object.get("done", self)?.to_boolean();
                             if done {
@@ -2423,7 +2619,8 @@ impl Context {
                         // e. Assert: When we reach this step, asyncContext has already been removed from the execution context stack and prevContext is the currently running execution context.
                         // f. Return undefined.
 
-                        std::mem::swap(&mut context.realm.environments, environment);
+                        context.debugger_suspend_frame();
+                        std::mem::swap(&mut context.vm.environments, environment);
                         std::mem::swap(&mut context.vm.stack, stack);
                         context.vm.push_frame(frame.clone());
 
@@ -2435,13 +2632,13 @@ impl Context {
                             .vm
                             .pop_frame()
                             .expect("generator call frame must exist");
-                        std::mem::swap(&mut context.realm.environments, environment);
+                        std::mem::swap(&mut context.vm.environments, environment);
                         std::mem::swap(&mut context.vm.stack, stack);
 
                         Ok(JsValue::undefined())
                     },
                     (
-                        self.realm.environments.clone(),
+                        self.vm.environments.clone(),
                         self.vm.stack.clone(),
                         self.vm.frame().clone(),
                     ),
@@ -2462,7 +2659,8 @@ impl Context {
                         // e. Assert: When we reach this step, asyncContext has already been removed from the execution context stack and prevContext is the currently running execution context.
                         // f. Return undefined.
 
-                        std::mem::swap(&mut context.realm.environments, environment);
+                        context.debugger_suspend_frame();
+                        std::mem::swap(&mut context.vm.environments, environment);
                         std::mem::swap(&mut context.vm.stack, stack);
                         context.vm.push_frame(frame.clone());
 
@@ -2474,13 +2672,13 @@ impl Context {
                             .vm
                             .pop_frame()
                             .expect("generator call frame must exist");
-                        std::mem::swap(&mut context.realm.environments, environment);
+                        std::mem::swap(&mut context.vm.environments, environment);
                         std::mem::swap(&mut context.vm.stack, stack);
 
                         Ok(JsValue::undefined())
                     },
                     (
-                        self.realm.environments.clone(),
+                        self.vm.environments.clone(),
                         self.vm.stack.clone(),
                         self.vm.frame().clone(),
                     ),
@@ -2502,17 +2700,14 @@ impl Context {
                 return Ok(ShouldExit::Await);
             }
             Opcode::PushNewTarget => {
-                if let Some(env) = self
-                    .realm
+                let new_target = self
+                    .vm
                     .environments
                     .get_this_environment()
                     .as_function_slots()
-                {
-                    if let Some(new_target) = env.borrow().new_target() {
-                        self.vm.push(new_target.clone());
-                    } else {
-                        self.vm.push(JsValue::undefined());
-                    }
+                    .and_then(|env| env.borrow().new_target().cloned());
+                if let Some(new_target) = new_target {
+                    self.vm.push(new_target);
                 } else {
                     self.vm.push(JsValue::undefined());
                 }
@@ -2523,6 +2718,17 @@ impl Context {
     }
 
     pub(crate) fn run(&mut self) -> JsResult<(JsValue, ReturnType)> {
+        if !self.vm.debugger.is_active() {
+            return self.run_instructions();
+        }
+
+        self.debugger_enter_frame();
+        let result = self.run_instructions();
+        self.debugger_exit_frame();
+        result
+    }
+
+    fn run_instructions(&mut self) -> JsResult<(JsValue, ReturnType)> {
         const COLUMN_WIDTH: usize = 26;
         const TIME_COLUMN_WIDTH: usize = COLUMN_WIDTH / 2;
         const OPCODE_COLUMN_WIDTH: usize = COLUMN_WIDTH;
@@ -2554,12 +2760,23 @@ impl Context {
             );
         }
 
+        // The budget is counted from the moment the host enters the engine.
+        if self.vm.frames.len() == 1 {
+            self.vm.budget.restart();
+
+            // A script terminated for exhausting the heap leaves its garbage behind, which can
+            // be reclaimed now that it finished.
+            if boa_gc::heap_limit_exceeded() {
+                boa_gc::force_collect();
+            }
+        }
+
         let start_stack_size = self.vm.stack.len();
 
         // If the current executing function is an async function we have to resolve/reject it's promise at the end.
         // The relevant spec section is 3. in [AsyncBlockStart](https://tc39.es/ecma262/#sec-asyncblockstart).
         let promise_capability = self
-            .realm
+            .vm
             .environments
             .get_this_environment()
             .as_function_slots()
@@ -2573,6 +2790,10 @@ impl Context {
             });
 
         while self.vm.frame().pc < self.vm.frame().code.code.len() {
+            if self.vm.debugger.is_active() {
+                self.debugger_check_statement();
+            }
+
             let result = if self.vm.trace {
                 let mut pc = self.vm.frame().pc;
                 let opcode: Opcode = self
@@ -2655,10 +2876,25 @@ impl Context {
                 }
                 Ok(ShouldExit::False) => {}
                 Ok(ShouldExit::Yield) => {
-                    let result = self.vm.stack.pop().unwrap_or(JsValue::Undefined);
+                    let result = self.vm.stack.pop().unwrap_or_default();
                     return Ok((result, ReturnType::Yield));
                 }
                 Err(e) => {
+                    // An uncatchable termination skips all handlers.
+                    if self.vm.budget.is_terminating() {
+                        self.vm.stack.truncate(start_stack_size);
+                        return Err(e);
+                    }
+
+                    // Materialize native errors while the throwing frame is still on the
+                    // frame stack, so the captured stack trace starts at the right place.
+                    let e = e.to_opaque(self);
+
+                    if self.vm.debugger.is_active() {
+                        let caught = !self.vm.frame().catch.is_empty();
+                        self.debugger_exception(&e, caught);
+                    }
+
                     if let Some(address) = self.vm.frame().catch.last() {
                         let address = address.next;
                         let try_stack_entry = self
@@ -2671,7 +2907,7 @@ impl Context {
                         try_stack_entry.num_env = 0;
                         try_stack_entry.num_loop_stack_entries = 0;
                         for _ in 0..try_stack_entry_copy.num_env {
-                            self.realm.environments.pop();
+                            self.vm.environments.pop();
                         }
                         let mut num_env = try_stack_entry_copy.num_env;
                         for _ in 0..try_stack_entry_copy.num_loop_stack_entries {
@@ -2726,12 +2962,17 @@ impl Context {
                                 .pop_front()
                                 .expect("must have item in queue");
                             drop(generator_object_mut);
-                            AsyncGenerator::complete_step(&next, Err(e), true, self);
+                            AsyncGenerator::complete_step(
+                                &next,
+                                Err(JsError::from_opaque(e)),
+                                true,
+                                self,
+                            );
                             AsyncGenerator::drain_queue(&generator_object, self);
                             return Ok((JsValue::undefined(), ReturnType::Normal));
                         }
 
-                        return Err(e);
+                        return Err(JsError::from_opaque(e));
                     }
                 }
             }
@@ -506,7 +506,7 @@ pub enum Opcode {
     ///
     /// Like `object.name`
     ///
-    /// Operands: name_index: `u32`
+    /// Operands: cache_index: `u32`
     ///
     /// Stack: object **=>** value
     GetPropertyByName,
@@ -533,7 +533,7 @@ pub enum Opcode {
     ///
     /// Like `object.name = value`
     ///
-    /// Operands: name_index: `u32`
+    /// Operands: cache_index: `u32`
     ///
     /// Stack: value, object **=>**
     SetPropertyByName,
@@ -964,6 +964,16 @@ pub enum Opcode {
     /// Stack: func, this, argument_1, ... argument_n **=>** result
     Call,
 
+    /// Call a function in tail position.
+    ///
+    /// If the current frame has no active exception handlers, it ends with the call, which is
+    /// made by the caller of the frame once the frame has been popped.
+    ///
+    /// Operands: argument_count: `u32`
+    ///
+    /// Stack: func, this, argument_1, ... argument_n **=>** result
+    TailCall,
+
     /// Call a function where the arguments contain spreads.
     ///
     /// Operands:
@@ -1341,6 +1351,7 @@ impl Opcode {
             Self::CallEval => "CallEval",
             Self::CallEvalSpread => "CallEvalSpread",
             Self::Call => "Call",
+            Self::TailCall => "TailCall",
             Self::CallSpread => "CallSpread",
             Self::New => "New",
             Self::NewSpread => "NewSpread",
@@ -1488,6 +1499,7 @@ impl Opcode {
             Self::CallEval => "INST - CallEval",
             Self::CallEvalSpread => "INST - CallEvalSpread",
             Self::Call => "INST - Call",
+            Self::TailCall => "INST - TailCall",
             Self::CallSpread => "INST - CallSpread",
             Self::New => "INST - New",
             Self::NewSpread => "INST - NewSpread",
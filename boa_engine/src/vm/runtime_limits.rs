@@ -0,0 +1,252 @@
+//! Execution budgets and cooperative interruption of the virtual machine.
+//!
+//! The heap limit of `boa_gc` is also enforced here: once the heap outgrows it, the running
+//! script is terminated with an uncatchable out of memory error.
+
+use crate::{Context, JsNativeError, JsResult};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// Limits on the amount of work a [`Context`] is allowed to do.
+///
+/// The limits are counted from the moment the host enters the engine, e.g. with
+/// [`Context::eval`] or by calling a function object, until the engine returns to the host.
+/// When a limit is exceeded, the running script is stopped with a `RangeError`.
+///
+/// By default there are no limits and termination errors are uncatchable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuntimeLimits {
+    instruction_limit: u64,
+    loop_iteration_limit: u64,
+    catchable: bool,
+}
+
+impl Default for RuntimeLimits {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            instruction_limit: u64::MAX,
+            loop_iteration_limit: u64::MAX,
+            catchable: false,
+        }
+    }
+}
+
+impl RuntimeLimits {
+    /// Creates a new `RuntimeLimits` without any limit.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the maximum number of bytecode instructions that can be executed.
+    #[inline]
+    pub fn instruction_limit(&self) -> u64 {
+        self.instruction_limit
+    }
+
+    /// Sets the maximum number of bytecode instructions that can be executed.
+    #[inline]
+    pub fn set_instruction_limit(&mut self, limit: u64) {
+        self.instruction_limit = limit;
+    }
+
+    /// Gets the maximum number of loop iterations, counted across all loops.
+    #[inline]
+    pub fn loop_iteration_limit(&self) -> u64 {
+        self.loop_iteration_limit
+    }
+
+    /// Sets the maximum number of loop iterations, counted across all loops.
+    #[inline]
+    pub fn set_loop_iteration_limit(&mut self, limit: u64) {
+        self.loop_iteration_limit = limit;
+    }
+
+    /// Returns `true` if the termination errors can be caught by the script.
+    #[inline]
+    pub fn catchable_termination(&self) -> bool {
+        self.catchable
+    }
+
+    /// Sets whether the termination errors can be caught by the script.
+    ///
+    /// An uncatchable termination error skips all `catch` and `finally` blocks and is
+    /// returned to the host. A catchable termination error is thrown like any other error,
+    /// but the budgets are not restarted: a script that catches it only gets a few more
+    /// instructions to handle it, and the next limit error is uncatchable. The budgets are
+    /// restarted when the engine returns to the host.
+    ///
+    /// Interruptions requested with an [`InterruptHandle`] are always uncatchable.
+    #[inline]
+    pub fn set_catchable_termination(&mut self, catchable: bool) {
+        self.catchable = catchable;
+    }
+}
+
+/// A handle to interrupt the execution of a [`Context`], possibly from another thread.
+///
+/// The virtual machine polls the handle before every instruction. Once interrupted, the
+/// running script is stopped with an uncatchable `RangeError` and the handle is reset.
+#[derive(Debug, Clone, Default)]
+pub struct InterruptHandle {
+    interrupted: Arc<AtomicBool>,
+}
+
+impl InterruptHandle {
+    /// Creates a new `InterruptHandle`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests the interruption of the running script.
+    #[inline]
+    pub fn interrupt(&self) {
+        self.interrupted.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if an interruption was requested and not handled yet.
+    #[inline]
+    pub fn is_interrupted(&self) -> bool {
+        self.interrupted.load(Ordering::Relaxed)
+    }
+
+    /// Withdraws a pending interruption request.
+    #[inline]
+    pub fn reset(&self) {
+        self.interrupted.store(false, Ordering::Relaxed);
+    }
+
+    /// Consumes a pending interruption request, returning `true` if there was one.
+    #[inline]
+    fn take(&self) -> bool {
+        self.interrupted.swap(false, Ordering::Relaxed)
+    }
+}
+
+/// The number of instructions a script can execute to handle a caught limit error, before the
+/// execution is terminated for good.
+const CAUGHT_LIMIT_GRACE: u64 = 10_000;
+
+/// The execution budget state of the virtual machine.
+#[derive(Debug, Default)]
+pub(crate) struct ExecutionBudget {
+    pub(crate) limits: RuntimeLimits,
+    pub(crate) interrupt_handle: InterruptHandle,
+    instruction_count: u64,
+    loop_iteration_count: u64,
+    /// The instructions left to handle a caught limit error, if one was thrown.
+    grace: Option<u64>,
+    terminating: bool,
+}
+
+impl ExecutionBudget {
+    /// Creates a new execution budget with the given limits.
+    #[inline]
+    pub(crate) fn new(limits: RuntimeLimits, interrupt_handle: InterruptHandle) -> Self {
+        Self {
+            limits,
+            interrupt_handle,
+            ..Self::default()
+        }
+    }
+
+    /// Restarts the counters when the host enters the engine.
+    #[inline]
+    pub(crate) fn restart(&mut self) {
+        self.instruction_count = 0;
+        self.loop_iteration_count = 0;
+        self.grace = None;
+        self.terminating = false;
+    }
+
+    /// Returns `true` if an uncatchable termination is propagating.
+    #[inline]
+    pub(crate) fn is_terminating(&self) -> bool {
+        self.terminating
+    }
+}
+
+impl Context {
+    /// Counts an executed instruction and checks the interrupt handle.
+    #[inline]
+    pub(crate) fn check_instruction_budget(&mut self) -> JsResult<()> {
+        let budget = &mut self.vm.budget;
+        if budget.terminating {
+            return self.throw_termination("execution terminated");
+        }
+        if budget.interrupt_handle.take() {
+            return self.throw_uncatchable("execution interrupted");
+        }
+        if boa_gc::heap_limit_exceeded() {
+            return self.throw_uncatchable("out of memory");
+        }
+        if let Some(grace) = &mut budget.grace {
+            if *grace == 0 {
+                return self.throw_termination("instruction limit exceeded");
+            }
+            *grace -= 1;
+            return Ok(());
+        }
+        budget.instruction_count += 1;
+        if budget.instruction_count > budget.limits.instruction_limit {
+            return self.throw_termination("instruction limit exceeded");
+        }
+        Ok(())
+    }
+
+    /// Counts a loop iteration.
+    #[inline]
+    pub(crate) fn check_loop_iteration_budget(&mut self) -> JsResult<()> {
+        let budget = &mut self.vm.budget;
+        budget.loop_iteration_count += 1;
+        if budget.loop_iteration_count > budget.limits.loop_iteration_limit {
+            return self.throw_termination("loop iteration limit exceeded");
+        }
+        Ok(())
+    }
+
+    /// Throws the error that stops a script that exceeded a limit.
+    ///
+    /// Only the first limit error is catchable, if the limits allow it. The counters are not
+    /// restarted, so the script must not be able to catch another one until the engine returns
+    /// to the host.
+    fn throw_termination(&mut self, message: &str) -> JsResult<()> {
+        let budget = &mut self.vm.budget;
+        if !budget.limits.catchable || budget.grace.is_some() {
+            return self.throw_uncatchable(message);
+        }
+        budget.grace = Some(CAUGHT_LIMIT_GRACE);
+        Err(JsNativeError::termination().with_message(message).into())
+    }
+
+    /// Throws a termination error that skips all handlers.
+    ///
+    /// Interruptions and heap exhaustion are always uncatchable, since the host must get the
+    /// control back and the script could keep allocating in a `catch` block.
+    fn throw_uncatchable(&mut self, message: &str) -> JsResult<()> {
+        self.vm.budget.terminating = true;
+        Err(JsNativeError::termination().with_message(message).into())
+    }
+
+    /// Gets the runtime limits of the context.
+    #[inline]
+    pub fn runtime_limits(&self) -> RuntimeLimits {
+        self.vm.budget.limits
+    }
+
+    /// Sets the runtime limits of the context.
+    #[inline]
+    pub fn set_runtime_limits(&mut self, limits: RuntimeLimits) {
+        self.vm.budget.limits = limits;
+    }
+
+    /// Gets a handle to interrupt the execution of this context.
+    #[inline]
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        self.vm.budget.interrupt_handle.clone()
+    }
+}
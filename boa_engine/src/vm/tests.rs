@@ -1,4 +1,12 @@
-use crate::{exec, Context, JsValue};
+use crate::{
+    context::ContextBuilder,
+    exec,
+    object::JsArray,
+    syntax::Parser,
+    vm::{Debugger, Encoder, InterruptHandle, PauseReason, ResumeMode, RuntimeLimits},
+    Context, JsError, JsValue,
+};
+use std::{cell::RefCell, collections::VecDeque, rc::Rc};
 
 #[test]
 fn typeof_string() {
@@ -43,7 +51,10 @@ fn try_catch_finally_from_init() {
         }
     "#;
 
-    assert_eq!(Context::default().eval(source.as_bytes()), Err("h".into()));
+    assert_eq!(
+        Context::default().eval(source.as_bytes()),
+        Err(JsError::from_opaque("h".into()))
+    );
 }
 
 #[test]
@@ -62,7 +73,7 @@ fn multiple_catches() {
 
     assert_eq!(
         Context::default().eval(source.as_bytes()),
-        Ok(JsValue::Undefined)
+        Ok(JsValue::undefined())
     );
 }
 
@@ -182,3 +193,719 @@ fn get_reference_by_super() {
         Ok(JsValue::from("ab"))
     );
 }
+
+#[test]
+fn loop_iteration_limit() {
+    let mut limits = RuntimeLimits::new();
+    limits.set_loop_iteration_limit(10);
+    let mut context = ContextBuilder::default().runtime_limits(limits).build();
+
+    let source = r#"
+        let i = 0;
+        while (i < 5) { i++; }
+        i
+    "#;
+    assert_eq!(context.eval(source.as_bytes()), Ok(JsValue::new(5)));
+
+    let source = r#"
+        try {
+            while (true) {}
+        } catch (e) {
+            "caught"
+        } finally {
+            "finally"
+        }
+    "#;
+    assert!(context.eval(source.as_bytes()).is_err());
+
+    // The budget is restarted on every entry into the engine.
+    assert_eq!(context.eval("1 + 1"), Ok(JsValue::new(2)));
+}
+
+#[test]
+fn catchable_instruction_limit() {
+    let mut limits = RuntimeLimits::new();
+    limits.set_instruction_limit(1000);
+    limits.set_catchable_termination(true);
+    let mut context = ContextBuilder::default().runtime_limits(limits).build();
+
+    let source = r#"
+        let result;
+        try {
+            for (;;) {}
+        } catch (e) {
+            result = e instanceof RangeError;
+        }
+        result
+    "#;
+    assert_eq!(context.eval(source.as_bytes()), Ok(JsValue::new(true)));
+
+    // The host can tell a termination apart from the errors thrown by the script, even
+    // if the script rethrows it.
+    let error = context.eval("for (;;) {}").unwrap_err();
+    assert!(error.is_termination());
+    let error = context
+        .eval("try { for (;;) {} } catch (e) { throw e; }")
+        .unwrap_err();
+    assert!(error.is_termination());
+    let error = context.eval("throw new RangeError('boom')").unwrap_err();
+    assert!(!error.is_termination());
+}
+
+#[test]
+fn caught_limit_errors_are_not_restarted() {
+    let source = r"
+        while (true) {
+            try {
+                for (;;) {}
+            } catch (e) {}
+        }
+    ";
+
+    let mut limits = RuntimeLimits::new();
+    limits.set_instruction_limit(1000);
+    limits.set_catchable_termination(true);
+    let mut context = ContextBuilder::default().runtime_limits(limits).build();
+    let error = context.eval(source.as_bytes()).unwrap_err();
+    assert!(error.is_termination());
+
+    let mut limits = RuntimeLimits::new();
+    limits.set_loop_iteration_limit(100);
+    limits.set_catchable_termination(true);
+    let mut context = ContextBuilder::default().runtime_limits(limits).build();
+    let error = context.eval(source.as_bytes()).unwrap_err();
+    assert!(error.is_termination());
+
+    // The budget is restarted when the engine returns to the host.
+    assert_eq!(context.eval("1 + 1"), Ok(JsValue::new(2)));
+}
+
+#[test]
+fn interrupt_handle() {
+    let handle = InterruptHandle::new();
+    let mut context = ContextBuilder::default()
+        .interrupt_handle(handle.clone())
+        .build();
+
+    handle.interrupt();
+    assert!(context.eval("while (true) {}").is_err());
+    assert!(!handle.is_interrupted());
+    assert_eq!(context.eval("1 + 1"), Ok(JsValue::new(2)));
+}
+
+#[test]
+fn interrupt_is_uncatchable() {
+    let handle = InterruptHandle::new();
+    let mut limits = RuntimeLimits::new();
+    limits.set_catchable_termination(true);
+    let mut context = ContextBuilder::default()
+        .runtime_limits(limits)
+        .interrupt_handle(handle.clone())
+        .build();
+
+    handle.interrupt();
+    let error = context
+        .eval("try { while (true) {} } catch (e) { 'caught' }")
+        .unwrap_err();
+    assert!(error.is_termination());
+}
+
+#[test]
+fn heap_limit() {
+    let mut context = Context::default();
+    boa_gc::force_collect();
+    boa_gc::set_heap_limit(Some(boa_gc::stats().heap_size() + 1024 * 1024));
+
+    let source = r#"
+        (() => {
+            const objects = [];
+            try {
+                while (true) { objects.push({}); }
+            } catch (e) {
+                return "caught";
+            }
+        })()
+    "#;
+    let error = context
+        .eval(source.as_bytes())
+        .expect_err("the heap limit must stop the script");
+    assert!(error.is_termination());
+    assert!(error.to_string().contains("out of memory"));
+
+    // The garbage of the terminated script is collected on the next entry into the engine.
+    assert_eq!(context.eval("1 + 1"), Ok(JsValue::new(2)));
+    assert!(!boa_gc::heap_limit_exceeded());
+
+    boa_gc::set_heap_limit(None);
+}
+
+#[test]
+fn heap_limit_accounts_external_memory() {
+    const LIMIT: usize = 4 * 1024 * 1024;
+
+    let mut context = Context::builder()
+        .heap_limit(boa_gc::stats().heap_size() + LIMIT)
+        .build();
+
+    // Buffers that don't fit are refused with a catchable error.
+    let error = context
+        .eval("new ArrayBuffer(8 * 1024 * 1024)")
+        .expect_err("the buffer must not fit in the heap");
+    assert!(!error.is_termination());
+    assert_eq!(
+        context.eval("try { new ArrayBuffer(8 * 1024 * 1024) } catch (e) { 'refused' }"),
+        Ok(JsValue::new("refused"))
+    );
+
+    // The garbage of each terminated script must be collected before the next one runs.
+    let sources = [
+        "(() => { const buffers = []; while (true) buffers.push(new ArrayBuffer(1 << 18)); })()",
+        r#"(() => "x".repeat(1 << 22).length)()"#,
+        r#"(() => { const a = []; while (true) a.push(Object.keys("x".repeat(1 << 16))); })()"#,
+    ];
+    for source in sources {
+        assert!(context.eval(source).is_err(), "{source}");
+        assert!(context.eval("1 + 1").is_ok());
+    }
+
+    let before = boa_gc::stats().external_memory();
+    let elements = JsArray::from_iter((0..1024).map(JsValue::new), &mut context);
+    assert!(boa_gc::stats().external_memory() >= before + 1024 * std::mem::size_of::<JsValue>());
+    drop(elements);
+
+    boa_gc::set_heap_limit(None);
+}
+
+#[test]
+fn inline_cache_get() {
+    let scenario = r#"
+        function get(o) { return o.x; }
+        var proto = { x: "proto" };
+        var results = [];
+        for (var i = 0; i < 3; i++) {
+            results.push(get({ x: i }));
+        }
+        var o = Object.create(proto);
+        results.push(get(o));
+        proto.x = "changed";
+        results.push(get(o));
+        Object.defineProperty(proto, "x", { get() { return "getter"; } });
+        results.push(get(o));
+        o.x = "own";
+        results.push(get(o));
+        results.join()
+    "#;
+    assert_eq!(&exec(scenario), "\"0,1,2,proto,changed,getter,getter\"");
+}
+
+#[test]
+fn inline_cache_set() {
+    let scenario = r#"
+        function set(o, v) { o.x = v; return o.x; }
+        var results = [];
+        for (var i = 0; i < 3; i++) {
+            results.push(set({}, i));
+        }
+        var frozen = Object.freeze({ x: 1 });
+        results.push(set(frozen, 2));
+        var proto = {};
+        var o = Object.create(proto);
+        results.push(set(o, 3));
+        Object.defineProperty(proto, "x", { set(v) { this.y = v; }, get() { return "setter"; } });
+        var p = Object.create(proto);
+        results.push(set(p, 4), p.y, p.hasOwnProperty("x"));
+        results.push(set(Object.preventExtensions({}), 5));
+        results.join()
+    "#;
+    assert_eq!(&exec(scenario), "\"0,1,2,1,3,setter,4,false,\"");
+}
+
+/// Compiles `src` in a new context and serializes it to a bytecode cache.
+fn compile_to_cache(src: &str) -> Vec<u8> {
+    let mut context = Context::default();
+    let statement_list = Parser::new(src.as_bytes()).parse_all(&mut context).unwrap();
+    let code_block = context
+        .compile_with_source_name(&statement_list, "cached.js")
+        .unwrap();
+    context.serialize_code_block(&code_block).unwrap()
+}
+
+#[test]
+fn bytecode_cache_round_trip() {
+    let cache = compile_to_cache(
+        r#"
+        "use strict";
+        var hoisted = typeof later;
+        let counter = 0;
+        function add({ a, b = 2 }, ...rest) { return a + b + rest.length; }
+        const make = (x = 10) => () => x + counter++;
+        class Point {
+            #x;
+            constructor(x) { this.#x = x; }
+            get x() { return this.#x; }
+        }
+        var later = 1;
+        [
+            hoisted,
+            add({ a: 1 }, 0, 0),
+            make()(),
+            make(5)(),
+            new Point(3).x,
+            `${12345678901234567890n}`,
+            String.raw`a\nb`,
+        ].join()
+        "#,
+    );
+
+    // The cache is loaded in another context, whose interner has other strings.
+    let mut context = Context::default();
+    context.eval("var unrelated = 'other', names = 1;").unwrap();
+    let code_block = context.deserialize_code_block(&cache).unwrap();
+    let result = context.execute(code_block).unwrap();
+    assert_eq!(
+        result.display().to_string(),
+        "\"undefined,5,10,6,3,12345678901234567890,a\\nb\""
+    );
+    assert_eq!(context.eval("counter").unwrap(), JsValue::new(2));
+    assert_eq!(context.eval("later").unwrap(), JsValue::new(1));
+}
+
+#[test]
+fn bytecode_cache_arguments_and_positions() {
+    let cache = compile_to_cache(
+        r#"
+        function mapped(a, b) { arguments[0] = 10; return a + b; }
+        function thrower() {
+            throw new Error("boom");
+        }
+        "#,
+    );
+
+    let mut context = Context::default();
+    let code_block = context.deserialize_code_block(&cache).unwrap();
+    context.execute(code_block).unwrap();
+    assert_eq!(context.eval("mapped(1, 2)").unwrap(), JsValue::new(12));
+    let stack = context
+        .eval("try { thrower() } catch (e) { e.stack }")
+        .unwrap();
+    assert!(stack
+        .as_string()
+        .unwrap()
+        .to_std_string_escaped()
+        .contains("at thrower (cached.js:4:13)"));
+}
+
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn bytecode_cache_rejects_large_lengths() {
+    let context = Context::default();
+    let mut encoder = Encoder::new(context.interner());
+    encoder.usize(u32::MAX as usize + 1);
+    assert!(encoder.finish(*b"BOAC").is_err());
+}
+
+#[test]
+fn bytecode_cache_errors() {
+    let cache = compile_to_cache("let x = 1; x");
+    let mut context = Context::default();
+
+    assert!(context.deserialize_code_block(b"not a cache").is_err());
+    assert!(context
+        .deserialize_code_block(&cache[..cache.len() - 1])
+        .is_err());
+
+    let mut other_version = cache.clone();
+    other_version[4] += 1;
+    assert!(context.deserialize_code_block(&other_version).is_err());
+
+    // The byte order of the platform follows the versions in the header.
+    let version_len = usize::from(cache[8]);
+    let mut other_platform = cache.clone();
+    other_platform[12 + version_len] ^= 1;
+    assert!(context.deserialize_code_block(&other_platform).is_err());
+
+    // The global `let` declaration of the cache takes the first global binding.
+    context.eval("let y = 2;").unwrap();
+    assert!(context.deserialize_code_block(&cache).is_err());
+
+    let mut context = Context::default();
+    let code_block = context.deserialize_code_block(&cache).unwrap();
+    assert_eq!(context.execute(code_block).unwrap(), JsValue::new(1));
+}
+
+#[test]
+fn tail_calls() {
+    let scripts = [
+        (
+            "function f(n) { 'use strict'; return n === 0 ? 'done' : f(n - 1); } f(100000)",
+            "\"done\"",
+        ),
+        (
+            "'use strict'; function f(n) { if (n === 0) { return 'done'; } return f(n - 1); } f(100000)",
+            "\"done\"",
+        ),
+        (
+            "'use strict'; function f(n) { return n === 0 || f(n - 1); } f(100000)",
+            "true",
+        ),
+        (
+            "'use strict'; function f(n) { return n !== 0 && f(n - 1); } f(100000)",
+            "false",
+        ),
+        (
+            "'use strict'; function f(n) { return (n === 0 ? 'done' : null) ?? f(n - 1); } f(100000)",
+            "\"done\"",
+        ),
+        (
+            "'use strict'; function f(n) { return n === 0 ? 'done' : (0, f(n - 1)); } f(100000)",
+            "\"done\"",
+        ),
+        (
+            "'use strict'; function f(s, n) { return n === 0 ? 'done' : f`${n - 1}`; } f`${100000}`",
+            "\"done\"",
+        ),
+        (
+            "'use strict'; const f = n => n === 0 ? 'done' : f(n - 1); f(100000)",
+            "\"done\"",
+        ),
+        (
+            r#"
+                'use strict';
+                const counter = {
+                    count: 0,
+                    down(n) { this.count++; return n === 0 ? this.count : this.down(n - 1); },
+                };
+                counter.down(100000)
+            "#,
+            "100001",
+        ),
+        (
+            r#"
+                'use strict';
+                function isEven(n) { return n === 0 ? true : isOdd(n - 1); }
+                function isOdd(n) { return n === 0 ? false : isEven(n - 1); }
+                isEven(100001)
+            "#,
+            "false",
+        ),
+        (
+            r#"
+                'use strict';
+                function f(n) {
+                    if (n === 0) { return 'done'; }
+                    try { throw n; } catch (e) { return f(e - 1); }
+                }
+                f(100000)
+            "#,
+            "\"done\"",
+        ),
+        (
+            r#"
+                'use strict';
+                function f(n) {
+                    if (n === 0) { return 'done'; }
+                    try {} finally { return f(n - 1); }
+                }
+                f(100000)
+            "#,
+            "\"done\"",
+        ),
+        (
+            r#"
+                'use strict';
+                function Point(x) { return init(this, x); }
+                function init(point, x) { point.x = x; }
+                new Point(1).x
+            "#,
+            "1",
+        ),
+    ];
+    for (script, expected) in scripts {
+        assert_eq!(exec(script), expected, "{script}");
+    }
+}
+
+#[test]
+fn calls_not_in_tail_position() {
+    // Counts the frames of `f` in the stack trace at the end of the recursion.
+    let frames = |body: &str| {
+        exec(format!(
+            "{body}; f(3).split('at f (').length - 1",
+            body = body.replace("BASE", "n === 0 ? new Error().stack")
+        ))
+    };
+
+    assert_eq!(
+        frames("'use strict'; function f(n) { return BASE : f(n - 1); }"),
+        "1"
+    );
+    assert_eq!(frames("function f(n) { return BASE : f(n - 1); }"), "4");
+    assert_eq!(
+        frames("'use strict'; function f(n) { return BASE : '' + f(n - 1); }"),
+        "4"
+    );
+    assert_eq!(
+        frames("'use strict'; function f(n) { return BASE : f(...[n - 1]); }"),
+        "4"
+    );
+    assert_eq!(
+        frames("'use strict'; function f(n) { try { return BASE : f(n - 1); } finally {} }"),
+        "4"
+    );
+
+    let caught = r#"
+        'use strict';
+        function thrower() { throw new Error('caught'); }
+        function f() { try { return thrower(); } catch (e) { return e.message; } }
+        f()
+    "#;
+    assert_eq!(exec(caught), "\"caught\"");
+}
+
+/// A debugger that records what it sees on every pause, and resumes with the next mode of a
+/// predefined list.
+#[derive(Debug, Default)]
+struct RecordingDebugger {
+    resume_modes: RefCell<VecDeque<ResumeMode>>,
+    pauses: RefCell<Vec<String>>,
+    exceptions: RefCell<Vec<String>>,
+}
+
+impl RecordingDebugger {
+    fn new(resume_modes: &[ResumeMode]) -> Rc<Self> {
+        Rc::new(Self {
+            resume_modes: RefCell::new(resume_modes.iter().copied().collect()),
+            ..Self::default()
+        })
+    }
+}
+
+impl Debugger for RecordingDebugger {
+    fn on_pause(&self, reason: &PauseReason, context: &mut Context) -> ResumeMode {
+        let frames = context.stack_frames();
+        let frame = &frames[0];
+        let reason = match reason {
+            PauseReason::Breakpoint => "breakpoint",
+            PauseReason::Step => "step",
+            PauseReason::Exception(_) => "exception",
+            PauseReason::Request => "request",
+        };
+        self.pauses.borrow_mut().push(format!(
+            "{reason} {}:{} depth {}",
+            frame.function_name(),
+            frame.line_number().unwrap_or_default(),
+            frames.len()
+        ));
+        self.resume_modes
+            .borrow_mut()
+            .pop_front()
+            .unwrap_or_default()
+    }
+
+    fn on_exception(&self, exception: &JsValue, context: &mut Context) -> bool {
+        self.exceptions.borrow_mut().push(
+            exception
+                .to_string(context)
+                .unwrap()
+                .to_std_string_escaped(),
+        );
+        true
+    }
+}
+
+#[test]
+fn debugger_breakpoints() {
+    let script = r#"
+        function add(a, b) {
+            const sum = a + b;
+            return sum;
+        }
+        var total = 0;
+        for (let i = 0; i < 3; i++) {
+            total = add(total, i);
+        }
+        total
+    "#;
+
+    let mut context = Context::default();
+    let debugger = RecordingDebugger::new(&[]);
+    context.set_debugger(Some(debugger.clone()));
+    assert!(context.set_breakpoint("test.js", 4));
+    assert!(!context.set_breakpoint("test.js", 4));
+    assert!(context.set_breakpoint("other.js", 3));
+
+    let result = context.eval_with_source_name(script, "test.js").unwrap();
+    assert_eq!(result.as_number(), Some(3.0));
+    assert_eq!(
+        *debugger.pauses.borrow(),
+        [
+            "breakpoint add:4 depth 2",
+            "breakpoint add:4 depth 2",
+            "breakpoint add:4 depth 2"
+        ]
+    );
+
+    debugger.pauses.borrow_mut().clear();
+    assert!(context.remove_breakpoint("test.js", 4));
+    assert!(!context.remove_breakpoint("test.js", 4));
+    context.eval_with_source_name(script, "test.js").unwrap();
+    assert!(debugger.pauses.borrow().is_empty());
+}
+
+#[test]
+fn debugger_stepping() {
+    let script = r#"
+        function inner() {
+            return 1;
+        }
+        function outer() {
+            const x = inner();
+            return x + 1;
+        }
+        outer();
+        outer();
+    "#;
+
+    let mut context = Context::default();
+    let debugger = RecordingDebugger::new(&[
+        ResumeMode::StepIn,
+        ResumeMode::StepIn,
+        ResumeMode::StepOut,
+        ResumeMode::StepOver,
+        ResumeMode::StepIn,
+    ]);
+    context.set_debugger(Some(debugger.clone()));
+    context.set_breakpoint("test.js", 9);
+
+    context.eval_with_source_name(script, "test.js").unwrap();
+    assert_eq!(
+        *debugger.pauses.borrow(),
+        [
+            "breakpoint <main>:9 depth 1",
+            "step outer:6 depth 2",
+            "step inner:3 depth 3",
+            "step outer:7 depth 2",
+            "step <main>:10 depth 1",
+            "step outer:6 depth 2",
+        ]
+    );
+}
+
+#[test]
+fn debugger_frame_inspection() {
+    #[derive(Debug, Default)]
+    struct Inspector {
+        seen: RefCell<Vec<String>>,
+    }
+
+    impl Debugger for Inspector {
+        fn on_pause(&self, _reason: &PauseReason, context: &mut Context) -> ResumeMode {
+            let mut seen = self.seen.borrow_mut();
+            for depth in 0..3 {
+                let locals = context.frame_locals(depth).unwrap_or_default();
+                let locals = locals
+                    .iter()
+                    .filter(|(name, _)| name != "arguments")
+                    .map(|(name, value)| format!("{name}={}", value.display()))
+                    .collect::<Vec<_>>();
+                seen.push(locals.join(" "));
+            }
+            for (depth, src) in [(0, "y * 10"), (1, "x + y"), (1, "x = 5")] {
+                let value = context.eval_in_frame(depth, src).unwrap();
+                seen.push(value.display().to_string());
+            }
+            assert!(context.eval_in_frame(1, "block").is_err());
+            ResumeMode::Continue
+        }
+    }
+
+    let script = r#"
+        let g = 'global';
+        function callee(y) {
+            {
+                let block = true;
+                return y;
+            }
+        }
+        function caller(x) {
+            let y = x + 1;
+            return callee(y) + x;
+        }
+        caller(1)
+    "#;
+
+    let mut context = Context::default();
+    let inspector = Rc::new(Inspector::default());
+    context.set_debugger(Some(inspector.clone()));
+    context.set_breakpoint("test.js", 6);
+
+    let result = context.eval_with_source_name(script, "test.js").unwrap();
+
+    // The evaluation in the caller frame assigned `x`.
+    assert_eq!(result.as_number(), Some(7.0));
+    assert_eq!(
+        *inspector.seen.borrow(),
+        [
+            "block=true y=2 g=\"global\"",
+            "x=1 y=2 g=\"global\"",
+            "g=\"global\"",
+            "20",
+            "3",
+            "5",
+        ]
+    );
+}
+
+#[test]
+fn debugger_exceptions() {
+    let script = r#"
+        function thrower() {
+            throw new Error('boom');
+        }
+        function f() {
+            thrower();
+        }
+        try {
+            f();
+        } catch (e) {}
+        f();
+    "#;
+
+    let mut context = Context::default();
+    let debugger = RecordingDebugger::new(&[]);
+    context.set_debugger(Some(debugger.clone()));
+
+    assert!(context.eval_with_source_name(script, "test.js").is_err());
+    assert_eq!(
+        *debugger.exceptions.borrow(),
+        ["Error: boom", "Error: boom"]
+    );
+    assert_eq!(
+        *debugger.pauses.borrow(),
+        ["exception thrower:3 depth 3", "exception thrower:3 depth 3",]
+    );
+}
+
+#[test]
+fn debugger_pause_request() {
+    let script = r#"
+        let x = 1;
+        x += 1;
+        x
+    "#;
+
+    let mut context = Context::default();
+    let debugger = RecordingDebugger::new(&[ResumeMode::StepOver]);
+    context.set_debugger(Some(debugger.clone()));
+
+    let pause_handle = context.pause_handle();
+    pause_handle.request_pause();
+    assert!(pause_handle.is_pause_requested());
+
+    context.eval_with_source_name(script, "test.js").unwrap();
+    assert!(!pause_handle.is_pause_requested());
+    assert_eq!(
+        *debugger.pauses.borrow(),
+        ["request <main>:2 depth 1", "step <main>:3 depth 1"]
+    );
+}
@@ -0,0 +1,8 @@
+// load module
+import * as calc from "./calc.js";
+
+console.log("Using calc module");
+console.log("Add: " + calc.add(3, 3));
+console.log("Subtract: " + calc.subtract(3, 3));
+console.log("Multiply: " + calc.multiply(3, 3));
+console.log("Divide: " + calc.divide(3, 3));
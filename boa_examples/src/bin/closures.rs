@@ -4,11 +4,12 @@
 use boa_engine::{
     object::{FunctionBuilder, JsObject},
     property::{Attribute, PropertyDescriptor},
-    Context, JsString, JsValue,
+    Context, JsError, JsString, JsValue,
 };
 use boa_gc::{Finalize, Trace};
+use std::{cell::RefCell, rc::Rc};
 
-fn main() -> Result<(), JsValue> {
+fn main() -> Result<(), JsError> {
     // We create a new `Context` to create a new Javascript executor.
     let mut context = Context::default();
 
@@ -32,6 +33,27 @@ fn main() -> Result<(), JsValue> {
     // We have created a closure with moved variables and executed that closure
     // inside Javascript!
 
+    // Closures can also own non-`Copy` Rust state, like a shared counter that
+    // is still accessible from Rust after the closure has been registered.
+    // The state is passed to the closure by reference, and it is traced by the
+    // garbage collector like the captures below.
+    let counter = Rc::new(RefCell::new(0));
+    context.register_global_closure_with_state(
+        "count",
+        0,
+        |_, _, counter, _| {
+            *counter.borrow_mut() += 1;
+            Ok(JsValue::new(*counter.borrow()))
+        },
+        counter.clone(),
+    )?;
+
+    assert_eq!(context.eval("count(); count(); count()")?, 3.into());
+    assert_eq!(*counter.borrow(), 3);
+
+    // `JsValue`s needed by a closure are passed as captures instead, which are
+    // traced by the garbage collector.
+
     // This struct is passed to a closure as a capture.
     #[derive(Debug, Clone, Trace, Finalize)]
     struct BigStruct {
@@ -68,10 +90,10 @@ fn main() -> Result<(), JsValue> {
 
             // We create a new message from our captured variable.
             let message = JsString::concat_array(&[
-                "message from `",
-                name.to_string(context)?.as_str(),
-                "`: ",
-                captures.greeting.as_str(),
+                &JsString::from("message from `"),
+                &name.to_string(context)?,
+                &JsString::from("`: "),
+                &captures.greeting,
             ]);
 
             // We can also mutate the moved data inside the closure.
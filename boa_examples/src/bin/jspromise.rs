@@ -0,0 +1,47 @@
+// This example shows how to create, chain and await Javascript Promises from Rust code.
+use boa_engine::{
+    builtins::promise::PromiseState,
+    object::{FunctionBuilder, JsPromise},
+    property::Attribute,
+    Context, JsError, JsNativeError, JsValue,
+};
+
+fn main() -> Result<(), JsError> {
+    // New `Context` for a new Javascript executor.
+    let context = &mut Context::default();
+
+    // Create a pending promise and keep its resolving functions.
+    let (promise, resolvers) = JsPromise::new_pending(context);
+    assert_eq!(promise.state(), PromiseState::Pending);
+
+    // Expose the promise to scripts, which can react to it like any other promise.
+    context.register_global_property("promise", promise.clone(), Attribute::default());
+    context.eval("var message; promise.then((value) => { message = `got ${value}`; });")?;
+
+    // Resolve the promise from Rust and run the reactions enqueued by the resolution.
+    resolvers
+        .resolve
+        .call(&JsValue::undefined(), &["hello".into()], context)?;
+    context.run_jobs()?;
+
+    assert_eq!(promise.state(), PromiseState::Fulfilled("hello".into()));
+    assert_eq!(context.eval("message")?, "got hello".into());
+
+    // Chain Rust callbacks to a rejected promise.
+    let recover = FunctionBuilder::closure(context, |_, _, _| Ok("recovered".into())).build();
+    let recovered = JsPromise::reject(JsNativeError::typ().with_message("boom"), context)
+        .catch(recover, context)?;
+
+    assert_eq!(recovered.await_blocking(context)?, "recovered".into());
+
+    // Await a promise returned by a script.
+    let result = context.eval("Promise.resolve(40).then((x) => x + 2)")?;
+    let promise = JsPromise::from_object(
+        result.as_object().cloned().expect("expected an object"),
+        context,
+    )?;
+
+    assert_eq!(promise.await_blocking(context)?, 42.into());
+
+    Ok(())
+}
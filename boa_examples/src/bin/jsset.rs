@@ -1,8 +1,8 @@
 // This example shows how to manipulate a Javascript Set using Rust code.
 #![allow(clippy::bool_assert_comparison)]
-use boa_engine::{object::JsSet, Context, JsValue};
+use boa_engine::{object::JsSet, Context, JsError, JsValue};
 
-fn main() -> Result<(), JsValue> {
+fn main() -> Result<(), JsError> {
     // New `Context` for a new Javascript executor.
     let context = &mut Context::default();
 
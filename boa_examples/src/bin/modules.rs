@@ -0,0 +1,39 @@
+// This example shows how to load, link and evaluate ECMAScript modules,
+// resolving the imports from the file system with a `SimpleModuleLoader`
+
+use std::{fs::read_to_string, path::Path, rc::Rc};
+
+use boa_engine::{
+    module::{Module, SimpleModuleLoader},
+    Context,
+};
+
+fn main() {
+    let js_file_path = Path::new("./scripts/modules/main.js");
+
+    let src = match read_to_string(js_file_path) {
+        Ok(src) => src,
+        Err(msg) => {
+            eprintln!("Error: {}", msg);
+            return;
+        }
+    };
+
+    // Creating the execution context with a loader for the imported modules
+    let loader = SimpleModuleLoader::new("./scripts/modules");
+    let mut context = Context::builder().module_loader(Rc::new(loader)).build();
+
+    // The path of the module is used to resolve its relative imports
+    let path = js_file_path
+        .canonicalize()
+        .unwrap_or_else(|_| js_file_path.to_path_buf());
+
+    // Parsing the module, then loading its imports, linking and evaluating it
+    let result = Module::parse(src, Some(path), &mut context)
+        .and_then(|module| module.load_link_evaluate(&mut context));
+
+    if let Err(e) = result {
+        // Pretty print the error
+        eprintln!("Uncaught {e}");
+    }
+}
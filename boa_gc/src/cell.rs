@@ -0,0 +1,556 @@
+//! A garbage collected cell with dynamically checked borrow rules.
+
+use crate::{Finalize, Trace};
+use std::{
+    cell::{Cell, UnsafeCell},
+    cmp::Ordering,
+    fmt::{self, Debug, Display},
+    hash::{Hash, Hasher},
+    ops::{Deref, DerefMut},
+};
+
+/// The borrow state of a [`GcCell`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum BorrowState {
+    Reading,
+    Writing,
+    Unused,
+}
+
+const ROOT: usize = 1;
+const WRITING: usize = !1;
+const UNUSED: usize = 0;
+
+/// The packed borrow state and root flag of a [`GcCell`].
+///
+/// The lowest bit records if the cell is rooted, and the remaining bits count the number of
+/// shared borrows, with all of them set while mutably borrowed.
+#[derive(Copy, Clone)]
+struct BorrowFlag(usize);
+
+impl BorrowFlag {
+    const fn borrowed(self) -> BorrowState {
+        match self.0 & !ROOT {
+            UNUSED => BorrowState::Unused,
+            WRITING => BorrowState::Writing,
+            _ => BorrowState::Reading,
+        }
+    }
+
+    const fn rooted(self) -> bool {
+        self.0 & ROOT != 0
+    }
+
+    const fn set_writing(self) -> Self {
+        Self(self.0 | WRITING)
+    }
+
+    const fn set_unused(self) -> Self {
+        Self(self.0 & ROOT)
+    }
+
+    fn add_reading(self) -> Self {
+        assert!(self.borrowed() != BorrowState::Writing);
+        // Each shared borrow adds 2, since the lowest bit is used by the root flag. Overflow
+        // would make the state look like `Writing`, so it panics instead.
+        let flags = Self(self.0 + 0b10);
+        assert!(
+            flags.borrowed() != BorrowState::Writing,
+            "too many immutable GcCell borrows"
+        );
+        flags
+    }
+
+    fn sub_reading(self) -> Self {
+        assert!(self.borrowed() == BorrowState::Reading);
+        Self(self.0 - 0b10)
+    }
+
+    const fn set_rooted(self, rooted: bool) -> Self {
+        Self((self.0 & !ROOT) | (rooted as usize))
+    }
+}
+
+impl Debug for BorrowFlag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BorrowFlag")
+            .field("Rooted", &self.rooted())
+            .field("State", &self.borrowed())
+            .finish()
+    }
+}
+
+/// A mutable memory location with dynamically checked borrow rules that can be used inside of
+/// a garbage collected pointer.
+///
+/// This is the garbage collected counterpart of [`RefCell`][std::cell::RefCell].
+pub struct GcCell<T: ?Sized + 'static> {
+    flags: Cell<BorrowFlag>,
+    cell: UnsafeCell<T>,
+}
+
+impl<T: Trace> GcCell<T> {
+    /// Creates a new `GcCell` containing `value`.
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self {
+            flags: Cell::new(BorrowFlag(ROOT)),
+            cell: UnsafeCell::new(value),
+        }
+    }
+
+    /// Consumes the `GcCell`, returning the wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.cell.into_inner()
+    }
+}
+
+impl<T: Trace + ?Sized> GcCell<T> {
+    /// Immutably borrows the wrapped value.
+    ///
+    /// The borrow lasts until the returned `GcCellRef` exits scope. Multiple immutable borrows
+    /// can be taken out at the same time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is currently mutably borrowed.
+    #[inline]
+    pub fn borrow(&self) -> GcCellRef<'_, T> {
+        match self.try_borrow() {
+            Ok(value) => value,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Mutably borrows the wrapped value.
+    ///
+    /// The borrow lasts until the returned `GcCellRefMut` exits scope. The value cannot be
+    /// borrowed while this borrow is active.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is currently borrowed.
+    #[inline]
+    pub fn borrow_mut(&self) -> GcCellRefMut<'_, T> {
+        match self.try_borrow_mut() {
+            Ok(value) => value,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Immutably borrows the wrapped value, returning an error if the value is currently
+    /// mutably borrowed.
+    ///
+    /// This is the non-panicking variant of [`borrow`](#method.borrow).
+    pub fn try_borrow(&self) -> Result<GcCellRef<'_, T>, BorrowError> {
+        if self.flags.get().borrowed() == BorrowState::Writing {
+            return Err(BorrowError);
+        }
+        self.flags.set(self.flags.get().add_reading());
+
+        // SAFETY: The flags were just updated to forbid mutable borrows while this reference
+        // is alive.
+        unsafe {
+            Ok(GcCellRef {
+                flags: &self.flags,
+                value: &*self.cell.get(),
+            })
+        }
+    }
+
+    /// Mutably borrows the wrapped value, returning an error if the value is currently
+    /// borrowed.
+    ///
+    /// This is the non-panicking variant of [`borrow_mut`](#method.borrow_mut).
+    pub fn try_borrow_mut(&self) -> Result<GcCellRefMut<'_, T>, BorrowMutError> {
+        if self.flags.get().borrowed() != BorrowState::Unused {
+            return Err(BorrowMutError);
+        }
+        self.flags.set(self.flags.get().set_writing());
+
+        // SAFETY: The flags were just updated to forbid any other borrow while this reference
+        // is alive. The value is rooted while it is mutably borrowed, since the collector
+        // won't trace it until the borrow ends.
+        unsafe {
+            if !self.flags.get().rooted() {
+                (*self.cell.get()).root();
+            }
+
+            Ok(GcCellRefMut {
+                gc_cell: self,
+                value: &mut *self.cell.get(),
+            })
+        }
+    }
+
+    /// Replaces a component of the wrapped value with `value`, returning the old component.
+    ///
+    /// Mutably borrowing the cell roots all its contents, which takes linear time on their
+    /// size. This instead only roots the replaced component, so it is suited to frequent
+    /// updates of a small part of a big value, like a property of an object with many of them.
+    ///
+    /// `locate` returns the component to replace. If it returns `None`, nothing is replaced
+    /// and `value` is given back as the error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is currently borrowed.
+    ///
+    /// # Safety
+    ///
+    /// `locate` must not trigger a garbage collection, e.g. by allocating a `Gc`, since the
+    /// contents of the cell are neither traced nor rooted while it runs.
+    pub unsafe fn replace_component<U, F>(&self, locate: F, value: U) -> Result<U, U>
+    where
+        U: Trace,
+        F: FnOnce(&mut T) -> Option<&mut U>,
+    {
+        /// Ends the borrow, even if `locate` panics.
+        struct ResetFlags<'a>(&'a Cell<BorrowFlag>);
+
+        impl Drop for ResetFlags<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get().set_unused());
+            }
+        }
+
+        assert!(
+            self.flags.get().borrowed() == BorrowState::Unused,
+            "{}",
+            BorrowMutError
+        );
+        self.flags.set(self.flags.get().set_writing());
+        let _reset = ResetFlags(&self.flags);
+
+        // SAFETY: The flags were just updated to forbid any other borrow until `_reset` is
+        // dropped, and the caller guarantees that no collection happens in the meantime.
+        let component = match locate(&mut *self.cell.get()) {
+            Some(component) => component,
+            None => return Err(value),
+        };
+
+        // The component moves into the contents of the cell, so it must be unrooted unless
+        // the cell is rooted, and the old component moves out of them, so it must be rooted.
+        if !self.flags.get().rooted() {
+            value.unroot();
+        }
+        let old = std::mem::replace(component, value);
+        if !self.flags.get().rooted() {
+            old.root();
+        }
+
+        Ok(old)
+    }
+}
+
+/// An error returned by [`GcCell::try_borrow`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct BorrowError;
+
+impl Display for BorrowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt("GcCell<T> already mutably borrowed", f)
+    }
+}
+
+/// An error returned by [`GcCell::try_borrow_mut`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct BorrowMutError;
+
+impl Display for BorrowMutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt("GcCell<T> already borrowed", f)
+    }
+}
+
+impl<T: Trace + ?Sized> Finalize for GcCell<T> {}
+
+// SAFETY: The contents are traced unless they are mutably borrowed, in which case they are
+// already rooted.
+unsafe impl<T: Trace + ?Sized> Trace for GcCell<T> {
+    #[inline]
+    unsafe fn trace(&self) {
+        match self.flags.get().borrowed() {
+            BorrowState::Writing => (),
+            _ => (*self.cell.get()).trace(),
+        }
+    }
+
+    #[inline]
+    unsafe fn root(&self) {
+        assert!(!self.flags.get().rooted(), "Can't root a GcCell twice!");
+        self.flags.set(self.flags.get().set_rooted(true));
+
+        match self.flags.get().borrowed() {
+            BorrowState::Writing => (),
+            _ => (*self.cell.get()).root(),
+        }
+    }
+
+    #[inline]
+    unsafe fn unroot(&self) {
+        assert!(self.flags.get().rooted(), "Can't unroot a GcCell twice!");
+        self.flags.set(self.flags.get().set_rooted(false));
+
+        match self.flags.get().borrowed() {
+            BorrowState::Writing => (),
+            _ => (*self.cell.get()).unroot(),
+        }
+    }
+
+    #[inline]
+    fn finalize_glue(&self) {
+        Finalize::finalize(self);
+        match self.flags.get().borrowed() {
+            BorrowState::Writing => (),
+            // SAFETY: The value is not mutably borrowed.
+            _ => unsafe { (*self.cell.get()).finalize_glue() },
+        }
+    }
+}
+
+/// A wrapper type for an immutably borrowed value from a [`GcCell`].
+pub struct GcCellRef<'a, T: ?Sized + 'static> {
+    flags: &'a Cell<BorrowFlag>,
+    value: &'a T,
+}
+
+impl<'a, T: ?Sized> GcCellRef<'a, T> {
+    /// Copies a `GcCellRef`.
+    ///
+    /// This is an associated function that needs to be used as `GcCellRef::clone(...)`, since a
+    /// `Clone` implementation or a method would interfere with the `clone` method of the
+    /// borrowed value.
+    #[allow(clippy::should_implement_trait)]
+    #[inline]
+    pub fn clone(orig: &GcCellRef<'a, T>) -> GcCellRef<'a, T> {
+        orig.flags.set(orig.flags.get().add_reading());
+        GcCellRef {
+            flags: orig.flags,
+            value: orig.value,
+        }
+    }
+
+    /// Makes a new `GcCellRef` for a component of the borrowed data.
+    ///
+    /// This is an associated function that needs to be used as `GcCellRef::map(...)`.
+    #[inline]
+    pub fn map<U, F>(orig: Self, f: F) -> GcCellRef<'a, U>
+    where
+        U: ?Sized,
+        F: FnOnce(&T) -> &U,
+    {
+        let ret = GcCellRef {
+            flags: orig.flags,
+            value: f(orig.value),
+        };
+
+        // The borrow is transferred to the new reference.
+        std::mem::forget(orig);
+
+        ret
+    }
+
+    /// Splits a `GcCellRef` into multiple `GcCellRef`s for different components of the
+    /// borrowed data.
+    ///
+    /// This is an associated function that needs to be used as `GcCellRef::map_split(...)`.
+    #[inline]
+    pub fn map_split<U, V, F>(orig: Self, f: F) -> (GcCellRef<'a, U>, GcCellRef<'a, V>)
+    where
+        U: ?Sized,
+        V: ?Sized,
+        F: FnOnce(&T) -> (&U, &V),
+    {
+        let (a, b) = f(orig.value);
+
+        orig.flags.set(orig.flags.get().add_reading());
+
+        let ret = (
+            GcCellRef {
+                flags: orig.flags,
+                value: a,
+            },
+            GcCellRef {
+                flags: orig.flags,
+                value: b,
+            },
+        );
+
+        // The original borrow is transferred to the first reference.
+        std::mem::forget(orig);
+
+        ret
+    }
+}
+
+impl<T: ?Sized> Deref for GcCellRef<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<T: ?Sized> Drop for GcCellRef<'_, T> {
+    fn drop(&mut self) {
+        debug_assert!(self.flags.get().borrowed() == BorrowState::Reading);
+        self.flags.set(self.flags.get().sub_reading());
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for GcCellRef<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized + Display> Display for GcCellRef<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&**self, f)
+    }
+}
+
+/// A wrapper type for a mutably borrowed value from a [`GcCell`].
+///
+/// `U` is the type of the borrowed data, which can be a component of the contents of the cell
+/// after calling [`GcCellRefMut::map`].
+pub struct GcCellRefMut<'a, T: Trace + ?Sized + 'static, U: ?Sized = T> {
+    gc_cell: &'a GcCell<T>,
+    value: &'a mut U,
+}
+
+impl<'a, T: Trace + ?Sized, U: ?Sized> GcCellRefMut<'a, T, U> {
+    /// Makes a new `GcCellRefMut` for a component of the borrowed data.
+    ///
+    /// This is an associated function that needs to be used as `GcCellRefMut::map(...)`.
+    #[inline]
+    pub fn map<V, F>(orig: Self, f: F) -> GcCellRefMut<'a, T, V>
+    where
+        V: ?Sized,
+        F: FnOnce(&mut U) -> &mut V,
+    {
+        // SAFETY: `orig` is forgotten right after, so the mutable reference is never aliased.
+        let value = unsafe { &mut *(orig.value as *mut U) };
+
+        let ret = GcCellRefMut {
+            gc_cell: orig.gc_cell,
+            value: f(value),
+        };
+
+        // The borrow is transferred to the new reference.
+        std::mem::forget(orig);
+
+        ret
+    }
+}
+
+impl<T: Trace + ?Sized, U: ?Sized> Deref for GcCellRefMut<'_, T, U> {
+    type Target = U;
+
+    #[inline]
+    fn deref(&self) -> &U {
+        self.value
+    }
+}
+
+impl<T: Trace + ?Sized, U: ?Sized> DerefMut for GcCellRefMut<'_, T, U> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut U {
+        self.value
+    }
+}
+
+impl<T: Trace + ?Sized, U: ?Sized> Drop for GcCellRefMut<'_, T, U> {
+    #[inline]
+    fn drop(&mut self) {
+        debug_assert!(self.gc_cell.flags.get().borrowed() == BorrowState::Writing);
+        // Restore the rooted state of the contents, which were rooted while borrowed.
+        if !self.gc_cell.flags.get().rooted() {
+            // SAFETY: The mutable borrow is ending, so no other reference to the contents
+            // exists.
+            unsafe {
+                (*self.gc_cell.cell.get()).unroot();
+            }
+        }
+        self.gc_cell
+            .flags
+            .set(self.gc_cell.flags.get().set_unused());
+    }
+}
+
+impl<T: Trace + ?Sized, U: Debug + ?Sized> Debug for GcCellRefMut<'_, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: Trace + ?Sized, U: Display + ?Sized> Display for GcCellRefMut<'_, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&**self, f)
+    }
+}
+
+// SAFETY: `GcCell` tracks its borrows the same way as `RefCell`, so it can be sent to another
+// thread if its contents can.
+unsafe impl<T: ?Sized + Send> Send for GcCell<T> {}
+
+impl<T: Trace + Clone> Clone for GcCell<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.borrow().clone())
+    }
+}
+
+impl<T: Trace + Default> Default for GcCell<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl<T: Trace + ?Sized + PartialEq> PartialEq for GcCell<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        *self.borrow() == *other.borrow()
+    }
+}
+
+impl<T: Trace + ?Sized + Eq> Eq for GcCell<T> {}
+
+impl<T: Trace + ?Sized + PartialOrd> PartialOrd for GcCell<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (*self.borrow()).partial_cmp(&*other.borrow())
+    }
+}
+
+impl<T: Trace + ?Sized + Ord> Ord for GcCell<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        (*self.borrow()).cmp(&*other.borrow())
+    }
+}
+
+impl<T: Trace + ?Sized + Hash> Hash for GcCell<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (*self.borrow()).hash(state);
+    }
+}
+
+impl<T: Trace + ?Sized + Debug> Debug for GcCell<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.flags.get().borrowed() {
+            BorrowState::Unused | BorrowState::Reading => f
+                .debug_struct("GcCell")
+                .field("value", &self.borrow())
+                .finish(),
+            BorrowState::Writing => f
+                .debug_struct("GcCell")
+                .field("value", &"<borrowed>")
+                .finish(),
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! Accounting of the memory owned by garbage collected values outside of the heap.
+//!
+//! Values like strings, array buffers or vectors own buffers that are not allocated by the
+//! collector, and which can be much bigger than the boxes holding them. Those buffers are
+//! reported here, so they count towards the collection threshold and the heap limit.
+
+use crate::{Finalize, Trace};
+use std::cell::Cell;
+
+// The number of bytes allocated outside of the heap that are accounted as part of it.
+// This is kept outside of `GC_STATE`, since values release their buffers while the heap is swept.
+thread_local!(static EXTERNAL_BYTES: Cell<usize> = const { Cell::new(0) });
+
+/// Returns the number of bytes allocated outside of the heap of the current thread.
+pub(crate) fn external_bytes() -> usize {
+    EXTERNAL_BYTES.with(Cell::get)
+}
+
+/// Accounts `bytes` of memory allocated outside of the heap of the current thread as part of
+/// it, until they are released with [`untrack_external_memory`].
+///
+/// The memory is accounted even if it makes the heap outgrow its limit, in which case
+/// [`heap_limit_exceeded`](crate::heap_limit_exceeded) starts returning `true`. Use
+/// [`fits_heap_limit`](crate::fits_heap_limit) to check the limit before allocating instead.
+pub fn track_external_memory(bytes: usize) {
+    if bytes == 0 {
+        return;
+    }
+    EXTERNAL_BYTES.with(|external| external.set(external.get() + bytes));
+    crate::check_heap_limit();
+}
+
+/// Releases `bytes` of memory previously accounted with [`track_external_memory`].
+pub fn untrack_external_memory(bytes: usize) {
+    EXTERNAL_BYTES.with(|external| external.set(external.get() - bytes));
+}
+
+/// A buffer owned by a garbage collected value outside of the heap.
+///
+/// The size of the buffer is accounted as part of the heap until this is dropped, and must be
+/// kept up to date with [`ExternalMemory::set`] when the buffer is resized.
+#[derive(Debug, Default)]
+pub struct ExternalMemory {
+    bytes: usize,
+}
+
+impl ExternalMemory {
+    /// Creates a new `ExternalMemory`, accounting `bytes` of memory.
+    #[inline]
+    pub fn new(bytes: usize) -> Self {
+        track_external_memory(bytes);
+        Self { bytes }
+    }
+
+    /// Gets the number of accounted bytes.
+    #[inline]
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Sets the number of accounted bytes.
+    #[inline]
+    pub fn set(&mut self, bytes: usize) {
+        if bytes > self.bytes {
+            track_external_memory(bytes - self.bytes);
+        } else {
+            untrack_external_memory(self.bytes - bytes);
+        }
+        self.bytes = bytes;
+    }
+}
+
+impl Clone for ExternalMemory {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.bytes)
+    }
+}
+
+impl Drop for ExternalMemory {
+    #[inline]
+    fn drop(&mut self) {
+        untrack_external_memory(self.bytes);
+    }
+}
+
+impl Finalize for ExternalMemory {}
+
+// SAFETY: `ExternalMemory` only holds a number of bytes.
+unsafe impl Trace for ExternalMemory {
+    crate::unsafe_empty_trace!();
+}
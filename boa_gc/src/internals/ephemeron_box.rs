@@ -0,0 +1,124 @@
+use crate::{enqueue_ephemeron, internals::GcBox, Finalize, Trace};
+use std::{
+    cell::{Cell, UnsafeCell},
+    ptr::NonNull,
+};
+
+/// The data of an ephemeron, which holds a weak reference to its key and a value that is only
+/// traced while the key is reachable.
+pub(crate) struct EphemeronBox<K: Trace + ?Sized + 'static, V: Trace + 'static> {
+    /// The key, or `None` if it was collected.
+    key: Cell<Option<NonNull<GcBox<K>>>>,
+    /// The value, which is dropped when the key is collected.
+    value: UnsafeCell<Option<V>>,
+}
+
+impl<K: Trace + ?Sized, V: Trace> EphemeronBox<K, V> {
+    /// Creates a new `EphemeronBox`.
+    pub(crate) fn new(key: NonNull<GcBox<K>>, value: V) -> Self {
+        Self {
+            key: Cell::new(Some(key)),
+            value: UnsafeCell::new(Some(value)),
+        }
+    }
+
+    /// Returns a pointer to the box of the key, if it wasn't collected.
+    pub(crate) fn key(&self) -> Option<NonNull<GcBox<K>>> {
+        self.key.get()
+    }
+
+    /// Returns a clone of the value, if the key wasn't collected.
+    pub(crate) fn value(&self) -> Option<V>
+    where
+        V: Clone,
+    {
+        // SAFETY: The value is only mutated by the collector, while no reference to it can
+        // exist.
+        unsafe { (*self.value.get()).clone() }
+    }
+
+    fn value_ref(&self) -> Option<&V> {
+        // SAFETY: See `EphemeronBox::value`.
+        unsafe { (*self.value.get()).as_ref() }
+    }
+}
+
+/// The type-erased operations of an [`EphemeronBox`] used by the collector.
+pub(crate) trait ErasedEphemeronBox {
+    /// Returns `true` if the key was marked in the current marking phase.
+    ///
+    /// # Safety
+    ///
+    /// Must only be called by the collector during the marking phase.
+    unsafe fn is_key_marked(&self) -> bool;
+
+    /// Traces the value of the ephemeron.
+    ///
+    /// # Safety
+    ///
+    /// Must only be called by the collector during the marking phase.
+    unsafe fn trace_value(&self);
+
+    /// Clears the key and drops the value of the ephemeron, since the key is about to be freed.
+    ///
+    /// # Safety
+    ///
+    /// Must only be called by the collector during the sweep phase.
+    unsafe fn clear(&self);
+}
+
+impl<K: Trace + ?Sized, V: Trace> ErasedEphemeronBox for EphemeronBox<K, V> {
+    unsafe fn is_key_marked(&self) -> bool {
+        self.key
+            .get()
+            .map_or(false, |key| key.as_ref().header.is_marked())
+    }
+
+    unsafe fn trace_value(&self) {
+        if let Some(value) = self.value_ref() {
+            value.trace();
+        }
+    }
+
+    unsafe fn clear(&self) {
+        self.key.set(None);
+        (*self.value.get()).take();
+    }
+}
+
+impl<K: Trace + ?Sized, V: Trace> Finalize for EphemeronBox<K, V> {}
+
+// SAFETY: The key is a weak reference, so it must not be traced. The value is traced once the
+// key is known to be reachable.
+unsafe impl<K: Trace + ?Sized, V: Trace> Trace for EphemeronBox<K, V> {
+    unsafe fn trace(&self) {
+        if self.is_key_marked() {
+            self.trace_value();
+        } else if self.key.get().is_some() {
+            let this: &dyn ErasedEphemeronBox = self;
+            // Outside of a marking phase, the value is conservatively kept alive.
+            if !enqueue_ephemeron(NonNull::from(this)) {
+                self.trace_value();
+            }
+        }
+    }
+
+    unsafe fn root(&self) {
+        if let Some(value) = self.value_ref() {
+            value.root();
+        }
+    }
+
+    unsafe fn unroot(&self) {
+        if let Some(value) = self.value_ref() {
+            value.unroot();
+        }
+    }
+
+    fn finalize_glue(&self) {
+        Finalize::finalize(self);
+        if let Some(value) = self.value_ref() {
+            value.finalize_glue();
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::Trace;
+use std::{
+    cell::Cell,
+    ptr::{self, NonNull},
+};
+
+/// The header of a [`GcBox`], containing the metadata used by the collector.
+pub(crate) struct GcBoxHeader {
+    /// The number of `Gc` pointers outside the heap pointing to this box.
+    roots: Cell<usize>,
+    /// The next box in the list of boxes of the heap.
+    pub(crate) next: Cell<Option<NonNull<GcBox<dyn Trace>>>>,
+    /// Whether the box was reached in the current marking phase.
+    marked: Cell<bool>,
+}
+
+impl GcBoxHeader {
+    /// Creates a new rooted header.
+    pub(crate) fn new(next: Option<NonNull<GcBox<dyn Trace>>>) -> Self {
+        Self {
+            roots: Cell::new(1),
+            next: Cell::new(next),
+            marked: Cell::new(false),
+        }
+    }
+
+    pub(crate) fn roots(&self) -> usize {
+        self.roots.get()
+    }
+
+    pub(crate) fn is_marked(&self) -> bool {
+        self.marked.get()
+    }
+
+    pub(crate) fn mark(&self) {
+        self.marked.set(true);
+    }
+
+    pub(crate) fn unmark(&self) {
+        self.marked.set(false);
+    }
+}
+
+/// A garbage collected allocation, containing the header used by the collector and the value.
+#[repr(C)]
+pub(crate) struct GcBox<T: Trace + ?Sized + 'static> {
+    pub(crate) header: GcBoxHeader,
+    value: T,
+}
+
+impl<T: Trace> GcBox<T> {
+    /// Creates a new rooted `GcBox` containing `value`.
+    pub(crate) fn new(value: T, next: Option<NonNull<GcBox<dyn Trace>>>) -> Self {
+        Self {
+            header: GcBoxHeader::new(next),
+            value,
+        }
+    }
+}
+
+impl<T: Trace + ?Sized> GcBox<T> {
+    /// Returns `true` if the two references refer to the same `GcBox`.
+    pub(crate) fn ptr_eq(this: &Self, other: &Self) -> bool {
+        // Compare the headers to ignore the metadata of fat pointers.
+        ptr::eq(&this.header, &other.header)
+    }
+
+    /// Marks this `GcBox` and traces its value, if it wasn't already marked.
+    ///
+    /// # Safety
+    ///
+    /// Must only be called by the collector.
+    pub(crate) unsafe fn trace_inner(&self) {
+        if !self.header.is_marked() {
+            self.header.mark();
+            self.value.trace();
+        }
+    }
+
+    /// Increases the root count of this `GcBox`.
+    ///
+    /// Rooted boxes are never collected.
+    pub(crate) fn root_inner(&self) {
+        // Abort on overflow to prevent `mem::forget` loops from causing erroneous drops.
+        let roots = self
+            .header
+            .roots
+            .get()
+            .checked_add(1)
+            .expect("root count overflow");
+        self.header.roots.set(roots);
+    }
+
+    /// Decreases the root count of this `GcBox`.
+    pub(crate) fn unroot_inner(&self) {
+        self.header.roots.set(self.header.roots.get() - 1);
+    }
+
+    /// Returns a reference to the value of the `GcBox`.
+    pub(crate) fn value(&self) -> &T {
+        &self.value
+    }
+}
@@ -0,0 +1,7 @@
+mod ephemeron_box;
+mod gc_box;
+
+pub(crate) use self::{
+    ephemeron_box::{EphemeronBox, ErasedEphemeronBox},
+    gc_box::GcBox,
+};
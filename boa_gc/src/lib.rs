@@ -1,6 +1,426 @@
 //! Garbage collector for the Boa JavaScript engine.
+//!
+//! This is a thread-local, mark and sweep garbage collector. Its design is derived from the
+//! [`gc`](https://crates.io/crates/gc) crate, extended with support for ephemerons and weak
+//! references, which are needed to implement `WeakRef`, `WeakMap`, `WeakSet` and
+//! `FinalizationRegistry`.
+//!
+//! Every value allocated with [`Gc::new`] is stored in a `GcBox`, which keeps track of the number
+//! of [`Gc`] pointers outside the heap (the roots) pointing to it. A collection marks all the
+//! boxes reachable from a root, and frees the remaining ones.
+//!
+//! [`Ephemeron`]s are the building block of weak references: they hold their key weakly, and
+//! their value is only kept alive while the key is reachable.
 
-pub use gc::{
-    custom_trace, finalizer_safe, force_collect, unsafe_empty_trace, Finalize, Gc, GcCell as Cell,
-    GcCellRef as Ref, GcCellRefMut as RefMut, Trace,
+// Allows using the derive macros inside this crate.
+extern crate self as boa_gc;
+
+mod cell;
+mod external;
+mod internals;
+mod pointers;
+mod stats;
+mod trace;
+
+#[cfg(test)]
+mod test;
+
+pub use boa_macros::{Finalize, Trace};
+pub use cell::{
+    BorrowError, BorrowMutError, GcCell as Cell, GcCellRef as Ref, GcCellRefMut as RefMut,
 };
+pub use external::{track_external_memory, untrack_external_memory, ExternalMemory};
+pub use pointers::{Ephemeron, Gc, WeakGc, WeakMap};
+pub use stats::GcStats;
+pub use trace::{Finalize, Trace};
+
+use internals::{ErasedEphemeronBox, GcBox};
+use std::{
+    cell::{Cell as StdCell, RefCell},
+    mem,
+    ptr::NonNull,
+    time::Duration,
+};
+
+type GcPointer = NonNull<GcBox<dyn Trace>>;
+
+/// The initial number of allocated bytes that triggers a collection.
+const INITIAL_THRESHOLD: usize = 100;
+
+/// After a collection, the ratio of used to total space should be at most this value.
+///
+/// The threshold grows exponentially, to avoid quadratic behaviour when the heap grows
+/// linearly with the number of allocations.
+const USED_SPACE_RATIO: f64 = 0.7;
+
+struct GcState {
+    bytes_allocated: usize,
+    live_boxes: usize,
+    threshold: usize,
+    heap_limit: Option<usize>,
+    collections: u64,
+    collection_time: Duration,
+    last_collection_time: Duration,
+    boxes_start: StdCell<Option<GcPointer>>,
+}
+
+impl GcState {
+    /// Returns the size of the heap, including the memory allocated outside of it.
+    fn heap_size(&self) -> usize {
+        self.bytes_allocated + external::external_bytes()
+    }
+
+    /// Returns `true` if the heap is bigger than the heap limit.
+    fn exceeds_heap_limit(&self) -> bool {
+        self.heap_limit
+            .map_or(false, |limit| self.heap_size() > limit)
+    }
+}
+
+impl Drop for GcState {
+    fn drop(&mut self) {
+        collect_garbage(self);
+        // Any remaining box could be referenced from other thread-local variables, so they
+        // must be leaked.
+    }
+}
+
+thread_local!(static GC_STATE: RefCell<GcState> = RefCell::new(GcState {
+    bytes_allocated: 0,
+    live_boxes: 0,
+    threshold: INITIAL_THRESHOLD,
+    heap_limit: None,
+    collections: 0,
+    collection_time: Duration::ZERO,
+    last_collection_time: Duration::ZERO,
+    boxes_start: StdCell::new(None),
+}));
+
+// Whether the heap outgrew the heap limit, and a collection didn't bring it back under it.
+// This is kept outside of `GC_STATE` so it can be cheaply polled by the engine.
+thread_local!(static HEAP_LIMIT_EXCEEDED: StdCell<bool> = const { StdCell::new(false) });
+
+// Whether the current thread is in the sweep phase of a collection.
+// Dereferencing a `Gc` during this phase panics, since it could point to a freed box.
+thread_local!(static GC_DROPPING: StdCell<bool> = const { StdCell::new(false) });
+
+// The ephemerons reached during the marking phase whose key wasn't marked yet.
+// This is only `Some` while marking.
+thread_local!(static EPHEMERON_QUEUE: RefCell<Option<Vec<NonNull<dyn ErasedEphemeronBox>>>> =
+    RefCell::new(None));
+
+struct DropGuard;
+
+impl DropGuard {
+    fn new() -> Self {
+        GC_DROPPING.with(|dropping| dropping.set(true));
+        Self
+    }
+}
+
+impl Drop for DropGuard {
+    fn drop(&mut self) {
+        GC_DROPPING.with(|dropping| dropping.set(false));
+    }
+}
+
+/// Returns `true` if it is safe for a type to run [`Finalize::finalize`].
+///
+/// This is `false` during the sweep phase of a collection, where garbage collected pointers
+/// could be dangling.
+pub fn finalizer_safe() -> bool {
+    GC_DROPPING.with(|dropping| !dropping.get())
+}
+
+/// Allocates a new rooted `GcBox` containing `value`, and adds it to the heap.
+///
+/// This could trigger a garbage collection.
+fn allocate<T: Trace>(value: T) -> NonNull<GcBox<T>> {
+    GC_STATE.with(|st| {
+        let mut st = st.borrow_mut();
+        let size = mem::size_of::<GcBox<T>>();
+
+        // Hitting the heap limit forces a collection, unless it was already exceeded, in which
+        // case the engine is expected to stop allocating soon.
+        let hits_heap_limit = st
+            .heap_limit
+            .map_or(false, |limit| st.heap_size() + size > limit)
+            && !heap_limit_exceeded();
+
+        if st.heap_size() > st.threshold || hits_heap_limit {
+            collect_garbage(&mut st);
+
+            let heap_size = st.heap_size();
+            if heap_size as f64 > st.threshold as f64 * USED_SPACE_RATIO {
+                // Not enough space was freed, so the threshold is increased to avoid
+                // collecting too often.
+                st.threshold = (heap_size as f64 / USED_SPACE_RATIO) as usize;
+            }
+        }
+
+        let gc_box = Box::new(GcBox::new(value, st.boxes_start.take()));
+        let ptr = NonNull::from(Box::leak(gc_box));
+        st.boxes_start.set(Some(ptr));
+        st.bytes_allocated += size;
+        st.live_boxes += 1;
+
+        // The allocation is never refused, since `Gc::new` cannot fail. Instead, the limit is
+        // reported to the engine, which must terminate the running script.
+        if st.exceeds_heap_limit() {
+            HEAP_LIMIT_EXCEEDED.with(|exceeded| exceeded.set(true));
+        }
+
+        ptr
+    })
+}
+
+/// Collects the heap if the memory allocated outside of it made it outgrow its limit.
+///
+/// Nothing is done if a collection is in progress, since it updates the heap limit flag when it
+/// finishes.
+fn check_heap_limit() {
+    let _ = GC_STATE.try_with(|st| {
+        if let Ok(mut st) = st.try_borrow_mut() {
+            if st.exceeds_heap_limit() && !heap_limit_exceeded() {
+                collect_garbage(&mut st);
+            }
+        }
+    });
+}
+
+/// Pushes an ephemeron whose key wasn't marked yet to the ephemeron queue.
+///
+/// Returns `false` if no marking phase is in progress.
+fn enqueue_ephemeron(ephemeron: NonNull<dyn ErasedEphemeronBox>) -> bool {
+    EPHEMERON_QUEUE
+        .try_with(|queue| match queue.borrow_mut().as_mut() {
+            Some(queue) => {
+                queue.push(ephemeron);
+                true
+            }
+            None => false,
+        })
+        .unwrap_or(false)
+}
+
+/// A box that wasn't reached during the marking phase.
+struct Unmarked {
+    /// The link pointing to the box in the list of boxes of the heap.
+    incoming: *const StdCell<Option<GcPointer>>,
+    this: GcPointer,
+}
+
+/// Marks all the boxes reachable from the roots.
+///
+/// Returns the boxes that weren't reached, and the reached ephemerons whose key wasn't.
+/// All marks are cleared before returning.
+unsafe fn mark(
+    head: &StdCell<Option<GcPointer>>,
+) -> (Vec<Unmarked>, Vec<NonNull<dyn ErasedEphemeronBox>>) {
+    // The queue could be already destroyed if this is the last collection of the thread, in
+    // which case the values of all ephemerons are conservatively traced.
+    let _ = EPHEMERON_QUEUE.try_with(|queue| *queue.borrow_mut() = Some(Vec::new()));
+
+    // Trace every rooted box.
+    let mut mark_head = head.get();
+    while let Some(node) = mark_head {
+        let node = node.as_ref();
+        if node.header.roots() > 0 {
+            node.trace_inner();
+        }
+        mark_head = node.header.next.get();
+    }
+
+    // Trace the values of the ephemerons whose key was reached, until no new ephemeron is
+    // found, since tracing a value can mark the key of another ephemeron.
+    let mut pending = Vec::new();
+    loop {
+        let reached = EPHEMERON_QUEUE
+            .try_with(|queue| queue.borrow_mut().as_mut().map(mem::take))
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+        let found_new = !reached.is_empty();
+        pending.extend(reached);
+
+        let mut traced = false;
+        pending.retain(|ephemeron: &NonNull<dyn ErasedEphemeronBox>| {
+            let ephemeron = ephemeron.as_ref();
+            if ephemeron.is_key_marked() {
+                ephemeron.trace_value();
+                traced = true;
+                false
+            } else {
+                true
+            }
+        });
+
+        if !found_new && !traced {
+            break;
+        }
+    }
+
+    let _ = EPHEMERON_QUEUE.try_with(|queue| *queue.borrow_mut() = None);
+
+    // Collect the boxes that weren't marked, and unmark the rest.
+    let mut unmarked = Vec::new();
+    let mut incoming = head;
+    while let Some(node) = incoming.get() {
+        let node_ref = node.as_ref();
+        if node_ref.header.is_marked() {
+            node_ref.header.unmark();
+        } else {
+            unmarked.push(Unmarked {
+                incoming,
+                this: node,
+            });
+        }
+        incoming = &node_ref.header.next;
+    }
+
+    (unmarked, pending)
+}
+
+/// Frees the boxes that weren't reached, and clears the dead ephemerons.
+unsafe fn sweep(
+    unmarked: Vec<Unmarked>,
+    dead_ephemerons: Vec<NonNull<dyn ErasedEphemeronBox>>,
+    st: &mut GcState,
+) {
+    let _guard = DropGuard::new();
+
+    for ephemeron in dead_ephemerons {
+        ephemeron.as_ref().clear();
+    }
+
+    // Iterating in reverse keeps the `incoming` links of the remaining boxes valid.
+    for node in unmarked.into_iter().rev() {
+        let incoming = &*node.incoming;
+        let gc_box = Box::from_raw(node.this.as_ptr());
+        st.bytes_allocated -= mem::size_of_val::<GcBox<_>>(&*gc_box);
+        st.live_boxes -= 1;
+        incoming.set(gc_box.header.next.take());
+    }
+}
+
+/// Collects all the unreachable boxes of the heap.
+fn collect_garbage(st: &mut GcState) {
+    let timer = stats::Timer::start();
+
+    // SAFETY: The heap is only accessed from the current thread, and the state is mutably
+    // borrowed for the whole collection.
+    unsafe {
+        let (unmarked, _) = mark(&st.boxes_start);
+        if !unmarked.is_empty() {
+            for node in &unmarked {
+                Trace::finalize_glue(node.this.as_ref().value());
+            }
+
+            // Finalizers can make boxes reachable again, so the heap is marked again to only
+            // free the boxes that are still unreachable.
+            let (unmarked, dead_ephemerons) = mark(&st.boxes_start);
+            sweep(unmarked, dead_ephemerons, st);
+        }
+    }
+
+    let elapsed = timer.elapsed();
+    st.collections += 1;
+    st.collection_time += elapsed;
+    st.last_collection_time = elapsed;
+
+    let exceeded = st.exceeds_heap_limit();
+    let _ = HEAP_LIMIT_EXCEEDED.try_with(|flag| flag.set(exceeded));
+}
+
+/// Immediately triggers a garbage collection on the current thread.
+///
+/// # Panics
+///
+/// Panics if a collection is already in progress.
+pub fn force_collect() {
+    GC_STATE.with(|st| {
+        let mut st = st.borrow_mut();
+        collect_garbage(&mut st);
+    });
+}
+
+/// Returns the statistics of the heap of the current thread.
+///
+/// # Panics
+///
+/// Panics if called from a finalizer during a collection.
+pub fn stats() -> GcStats {
+    GC_STATE.with(|st| {
+        let st = st.borrow();
+        GcStats {
+            heap_size: st.heap_size(),
+            external_memory: external::external_bytes(),
+            live_objects: st.live_boxes,
+            collection_threshold: st.threshold,
+            heap_limit: st.heap_limit,
+            collections: st.collections,
+            collection_time: st.collection_time,
+            last_collection_time: st.last_collection_time,
+        }
+    })
+}
+
+/// Returns the maximum size in bytes of the heap of the current thread, if any.
+///
+/// # Panics
+///
+/// Panics if called from a finalizer during a collection.
+pub fn heap_limit() -> Option<usize> {
+    GC_STATE.with(|st| st.borrow().heap_limit)
+}
+
+/// Sets the maximum size in bytes of the heap of the current thread, or removes it if `None`.
+///
+/// The memory accounted with [`track_external_memory`] counts towards the limit. Allocations
+/// never fail when the heap grows beyond the limit, unless they are checked beforehand with
+/// [`fits_heap_limit`]. Instead, [`heap_limit_exceeded`] starts returning `true` until a
+/// collection frees enough memory, so the owner of the heap can stop the code that is allocating.
+///
+/// # Panics
+///
+/// Panics if called from a finalizer during a collection.
+pub fn set_heap_limit(limit: Option<usize>) {
+    GC_STATE.with(|st| {
+        let mut st = st.borrow_mut();
+        st.heap_limit = limit;
+        let exceeded = st.exceeds_heap_limit();
+        HEAP_LIMIT_EXCEEDED.with(|flag| flag.set(exceeded));
+    });
+}
+
+/// Returns `true` if `bytes` more bytes fit under the heap limit of the current thread, collecting
+/// the heap if they don't.
+///
+/// This should be checked before allocating big buffers accounted with
+/// [`track_external_memory`], so the allocation can be refused instead of terminating the
+/// running script afterwards.
+///
+/// # Panics
+///
+/// Panics if called from a finalizer during a collection.
+pub fn fits_heap_limit(bytes: usize) -> bool {
+    GC_STATE.with(|st| {
+        let mut st = st.borrow_mut();
+        let fits = |st: &GcState| {
+            st.heap_limit
+                .map_or(true, |limit| st.heap_size().saturating_add(bytes) <= limit)
+        };
+        if !fits(&st) {
+            collect_garbage(&mut st);
+        }
+        fits(&st)
+    })
+}
+
+/// Returns `true` if the heap of the current thread outgrew its limit.
+///
+/// This is cheap enough to be polled frequently.
+#[inline]
+pub fn heap_limit_exceeded() -> bool {
+    HEAP_LIMIT_EXCEEDED.with(StdCell::get)
+}
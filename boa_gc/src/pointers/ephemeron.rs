@@ -0,0 +1,77 @@
+use crate::{internals::EphemeronBox, Finalize, Gc, Trace};
+use std::fmt::{self, Debug};
+
+/// A key-value pair where the key is held weakly.
+///
+/// The value of an `Ephemeron` is kept alive only while its key is reachable from somewhere
+/// other than the value itself. Once the key is collected, both the key and the value of the
+/// ephemeron are cleared.
+///
+/// Cloning an `Ephemeron` returns a handle to the same pair.
+pub struct Ephemeron<K: Trace + ?Sized + 'static, V: Trace + 'static> {
+    inner: Gc<EphemeronBox<K, V>>,
+}
+
+impl<K: Trace + ?Sized, V: Trace> Ephemeron<K, V> {
+    /// Creates a new `Ephemeron` with the given key and value.
+    ///
+    /// This could trigger a garbage collection.
+    pub fn new(key: &Gc<K>, value: V) -> Self {
+        Self {
+            inner: Gc::new(EphemeronBox::new(key.inner_ptr(), value)),
+        }
+    }
+
+    /// Returns the key of the ephemeron, or `None` if it was collected.
+    pub fn key(&self) -> Option<Gc<K>> {
+        self.inner.key().map(|key| {
+            // SAFETY: The key is cleared by the collector before freeing its box, so it points
+            // to a live box.
+            unsafe { Gc::from_box_ptr(key) }
+        })
+    }
+
+    /// Returns a clone of the value of the ephemeron, or `None` if the key was collected.
+    pub fn value(&self) -> Option<V>
+    where
+        V: Clone,
+    {
+        self.inner.value()
+    }
+
+    /// Returns `true` if the key of the ephemeron wasn't collected.
+    pub fn has_key(&self) -> bool {
+        self.inner.key().is_some()
+    }
+
+    /// Returns `true` if the key of the ephemeron is the given `Gc`.
+    pub fn key_ptr_eq(&self, key: &Gc<K>) -> bool {
+        self.inner.key() == Some(key.inner_ptr())
+    }
+}
+
+impl<K: Trace + ?Sized, V: Trace> Clone for Ephemeron<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<K: Trace + ?Sized, V: Trace> Finalize for Ephemeron<K, V> {}
+
+// SAFETY: The box of the ephemeron is traced, which traces the value only if the key is
+// reachable.
+unsafe impl<K: Trace + ?Sized, V: Trace> Trace for Ephemeron<K, V> {
+    crate::custom_trace!(this, {
+        mark(&this.inner);
+    });
+}
+
+impl<K: Trace + ?Sized, V: Trace> Debug for Ephemeron<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ephemeron")
+            .field("has_key", &self.has_key())
+            .finish_non_exhaustive()
+    }
+}
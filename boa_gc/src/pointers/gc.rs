@@ -0,0 +1,255 @@
+use crate::{allocate, finalizer_safe, internals::GcBox, Finalize, Trace};
+use std::{
+    cell::Cell,
+    cmp::Ordering,
+    fmt::{self, Debug, Display},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::Deref,
+    ptr::{self, NonNull},
+    rc::Rc,
+};
+
+/// A garbage collected pointer type over an immutable value.
+///
+/// The lowest bit of the inner pointer records if this pointer is a root, which is the case
+/// when it is not stored inside the garbage collected heap.
+///
+/// A `Gc<T>` has the same layout as its inner pointer.
+#[repr(transparent)]
+pub struct Gc<T: Trace + ?Sized + 'static> {
+    inner_ptr: Cell<NonNull<GcBox<T>>>,
+    marker: PhantomData<Rc<T>>,
+}
+
+impl<T: Trace> Gc<T> {
+    /// Constructs a new `Gc<T>` with the given value.
+    ///
+    /// This could trigger a garbage collection.
+    pub fn new(value: T) -> Self {
+        assert!(std::mem::align_of::<GcBox<T>>() > 1);
+
+        let ptr = allocate(value);
+
+        // SAFETY: The value was just moved into the heap, so its pointers are not roots
+        // anymore.
+        unsafe {
+            ptr.as_ref().value().unroot();
+        }
+
+        let gc = Self {
+            inner_ptr: Cell::new(ptr),
+            marker: PhantomData,
+        };
+        gc.set_root();
+        gc
+    }
+}
+
+impl<T: Trace + ?Sized> Gc<T> {
+    /// Returns `true` if the two `Gc`s point to the same allocation.
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        GcBox::ptr_eq(this.inner(), other.inner())
+    }
+
+    /// Creates a new rooted `Gc` from a pointer to a live `GcBox`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to a `GcBox` that was not collected.
+    pub(crate) unsafe fn from_box_ptr(ptr: NonNull<GcBox<T>>) -> Self {
+        ptr.as_ref().root_inner();
+        let gc = Self {
+            inner_ptr: Cell::new(ptr),
+            marker: PhantomData,
+        };
+        gc.set_root();
+        gc
+    }
+
+    fn rooted(&self) -> bool {
+        self.inner_ptr.get().as_ptr().cast::<u8>() as usize & 1 != 0
+    }
+
+    fn set_root(&self) {
+        let ptr = self.inner_ptr.get().as_ptr();
+        let tagged = (ptr.cast::<u8>() as usize | 1) as *mut u8;
+
+        // SAFETY: Setting the lowest bit of a non-null pointer keeps it non-null.
+        unsafe {
+            self.inner_ptr
+                .set(NonNull::new_unchecked(set_data_ptr(ptr, tagged)));
+        }
+    }
+
+    fn clear_root(&self) {
+        self.inner_ptr.set(clear_root_bit(self.inner_ptr.get()));
+    }
+
+    /// Returns the pointer to the `GcBox` of this `Gc`.
+    pub(crate) fn inner_ptr(&self) -> NonNull<GcBox<T>> {
+        // Dereferencing a `Gc` during the sweep phase is undefined behaviour, since it could
+        // point to a freed box. Implementing `Trace` requires never doing this from `Drop`,
+        // but this assertion exists just in case.
+        assert!(finalizer_safe());
+
+        clear_root_bit(self.inner_ptr.get())
+    }
+
+    fn inner(&self) -> &GcBox<T> {
+        // SAFETY: The box is alive as long as this `Gc` exists.
+        unsafe { self.inner_ptr().as_ref() }
+    }
+}
+
+/// Returns the given pointer with its root bit cleared.
+fn clear_root_bit<T: ?Sized + Trace>(ptr: NonNull<GcBox<T>>) -> NonNull<GcBox<T>> {
+    let ptr = ptr.as_ptr();
+    let untagged = (ptr.cast::<u8>() as usize & !1) as *mut u8;
+
+    // SAFETY: Boxes are aligned to at least 2 bytes, so clearing the lowest bit of a pointer
+    // to a box cannot make it null.
+    unsafe { NonNull::new_unchecked(set_data_ptr(ptr, untagged)) }
+}
+
+/// Sets the data pointer of a possibly fat raw pointer, keeping its metadata.
+unsafe fn set_data_ptr<T: ?Sized, U>(mut ptr: *mut T, data: *mut U) -> *mut T {
+    ptr::write(
+        (&mut ptr as *mut *mut T).cast::<*mut u8>(),
+        data.cast::<u8>(),
+    );
+    ptr
+}
+
+impl<T: Trace + ?Sized> Finalize for Gc<T> {}
+
+// SAFETY: Tracing a `Gc` marks its box, and rooting it updates the root count of its box.
+unsafe impl<T: Trace + ?Sized> Trace for Gc<T> {
+    #[inline]
+    unsafe fn trace(&self) {
+        self.inner().trace_inner();
+    }
+
+    #[inline]
+    unsafe fn root(&self) {
+        assert!(!self.rooted(), "Can't double-root a Gc<T>");
+
+        // Access the box before modifying the state, since this panics during the sweep phase.
+        self.inner().root_inner();
+        self.set_root();
+    }
+
+    #[inline]
+    unsafe fn unroot(&self) {
+        assert!(self.rooted(), "Can't double-unroot a Gc<T>");
+
+        // Access the box before modifying the state, since this panics during the sweep phase.
+        self.inner().unroot_inner();
+        self.clear_root();
+    }
+
+    #[inline]
+    fn finalize_glue(&self) {
+        Finalize::finalize(self);
+    }
+}
+
+impl<T: Trace + ?Sized> Clone for Gc<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        // SAFETY: The box is alive as long as this `Gc` exists.
+        unsafe { Self::from_box_ptr(self.inner_ptr()) }
+    }
+}
+
+impl<T: Trace + ?Sized> Deref for Gc<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.inner().value()
+    }
+}
+
+impl<T: Trace + ?Sized> Drop for Gc<T> {
+    #[inline]
+    fn drop(&mut self) {
+        // Only roots are accounted in the box, so pointers inside the heap don't need to
+        // access it, which would be invalid during the sweep phase.
+        if self.rooted() {
+            self.inner().unroot_inner();
+        }
+    }
+}
+
+impl<T: Trace + Default> Default for Gc<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl<T: Trace + ?Sized + PartialEq> PartialEq for Gc<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Trace + ?Sized + Eq> Eq for Gc<T> {}
+
+impl<T: Trace + ?Sized + PartialOrd> PartialOrd for Gc<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<T: Trace + ?Sized + Ord> Ord for Gc<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<T: Trace + ?Sized + Hash> Hash for Gc<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
+impl<T: Trace + ?Sized + Display> Display for Gc<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&**self, f)
+    }
+}
+
+impl<T: Trace + ?Sized + Debug> Debug for Gc<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: Trace + ?Sized> fmt::Pointer for Gc<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.inner(), f)
+    }
+}
+
+impl<T: Trace> From<T> for Gc<T> {
+    fn from(t: T) -> Self {
+        Self::new(t)
+    }
+}
+
+impl<T: Trace + ?Sized> std::borrow::Borrow<T> for Gc<T> {
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+
+impl<T: Trace + ?Sized> AsRef<T> for Gc<T> {
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
@@ -0,0 +1,11 @@
+//! The garbage collected pointer types of the collector.
+
+mod ephemeron;
+mod gc;
+mod weak;
+mod weak_map;
+
+pub use ephemeron::Ephemeron;
+pub use gc::Gc;
+pub use weak::WeakGc;
+pub use weak_map::WeakMap;
@@ -0,0 +1,61 @@
+use crate::{Ephemeron, Finalize, Gc, Trace};
+use std::fmt::{self, Debug};
+
+/// A weak reference to a [`Gc`].
+///
+/// A `WeakGc` doesn't keep its referent alive. Once the referent is collected,
+/// [`WeakGc::upgrade`] returns `None`.
+pub struct WeakGc<T: Trace + ?Sized + 'static> {
+    inner: Ephemeron<T, ()>,
+}
+
+impl<T: Trace + ?Sized> WeakGc<T> {
+    /// Creates a new weak reference to the value pointed to by `value`.
+    ///
+    /// This could trigger a garbage collection.
+    pub fn new(value: &Gc<T>) -> Self {
+        Self {
+            inner: Ephemeron::new(value, ()),
+        }
+    }
+
+    /// Returns a strong pointer to the referent, or `None` if it was collected.
+    pub fn upgrade(&self) -> Option<Gc<T>> {
+        self.inner.key()
+    }
+
+    /// Returns `true` if the referent wasn't collected.
+    pub fn is_alive(&self) -> bool {
+        self.inner.has_key()
+    }
+
+    /// Returns `true` if this weak reference points to the value of `value`.
+    pub fn ptr_eq(&self, value: &Gc<T>) -> bool {
+        self.inner.key_ptr_eq(value)
+    }
+}
+
+impl<T: Trace + ?Sized> Clone for WeakGc<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Trace + ?Sized> Finalize for WeakGc<T> {}
+
+// SAFETY: Tracing the ephemeron never traces the referent.
+unsafe impl<T: Trace + ?Sized> Trace for WeakGc<T> {
+    crate::custom_trace!(this, {
+        mark(&this.inner);
+    });
+}
+
+impl<T: Trace + ?Sized> Debug for WeakGc<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakGc")
+            .field("is_alive", &self.is_alive())
+            .finish()
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{Ephemeron, Finalize, Gc, Trace};
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug},
+};
+
+/// The minimum number of entries of a [`WeakMap`] before its dead entries are pruned.
+const INITIAL_PRUNE_THRESHOLD: usize = 32;
+
+/// A map whose keys are held weakly.
+///
+/// Every entry is stored in an [`Ephemeron`], so a value is only kept alive while its key is
+/// reachable from outside of the map. Entries whose key was collected are ignored by the map,
+/// and are periodically removed when inserting new ones.
+pub struct WeakMap<K: Trace + ?Sized + 'static, V: Trace + Clone + 'static> {
+    /// The entries of the map, indexed by the address of the box of their key.
+    entries: HashMap<usize, Ephemeron<K, V>>,
+    prune_threshold: usize,
+}
+
+impl<K: Trace + ?Sized, V: Trace + Clone> WeakMap<K, V> {
+    /// Creates a new empty `WeakMap`.
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+            prune_threshold: INITIAL_PRUNE_THRESHOLD,
+        }
+    }
+
+    fn address(key: &Gc<K>) -> usize {
+        key.inner_ptr().as_ptr().cast::<u8>() as usize
+    }
+
+    /// Returns the entry for `key`, if it exists and its key is still alive.
+    ///
+    /// A collected key could have had its address reused by `key`, so the key of the entry
+    /// must be checked.
+    fn entry(&self, key: &Gc<K>) -> Option<&Ephemeron<K, V>> {
+        self.entries
+            .get(&Self::address(key))
+            .filter(|ephemeron| ephemeron.key_ptr_eq(key))
+    }
+
+    /// Inserts a key-value pair into the map, replacing the previous value of `key`.
+    ///
+    /// This could trigger a garbage collection.
+    pub fn insert(&mut self, key: &Gc<K>, value: V) {
+        if self.entries.len() >= self.prune_threshold {
+            self.prune();
+            self.prune_threshold = (self.entries.len() * 2).max(INITIAL_PRUNE_THRESHOLD);
+        }
+
+        self.entries
+            .insert(Self::address(key), Ephemeron::new(key, value));
+    }
+
+    /// Returns a clone of the value of `key`, if it is in the map.
+    pub fn get(&self, key: &Gc<K>) -> Option<V> {
+        self.entry(key).and_then(Ephemeron::value)
+    }
+
+    /// Returns `true` if the map contains a value for `key`.
+    pub fn contains_key(&self, key: &Gc<K>) -> bool {
+        self.entry(key).is_some()
+    }
+
+    /// Removes `key` from the map, returning its value if it was in the map.
+    pub fn remove(&mut self, key: &Gc<K>) -> Option<V> {
+        let value = self.get(key)?;
+        self.entries.remove(&Self::address(key));
+        Some(value)
+    }
+
+    /// Removes all the entries whose key was collected.
+    fn prune(&mut self) {
+        self.entries.retain(|_, ephemeron| ephemeron.has_key());
+    }
+}
+
+impl<K: Trace + ?Sized, V: Trace + Clone> Default for WeakMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Trace + ?Sized, V: Trace + Clone> Finalize for WeakMap<K, V> {}
+
+// SAFETY: Only the ephemerons of the entries are traced, which never trace their keys.
+unsafe impl<K: Trace + ?Sized, V: Trace + Clone> Trace for WeakMap<K, V> {
+    crate::custom_trace!(this, {
+        for ephemeron in this.entries.values() {
+            mark(ephemeron);
+        }
+    });
+}
+
+impl<K: Trace + ?Sized, V: Trace + Clone> Debug for WeakMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakMap")
+            .field("entries", &self.entries.len())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,95 @@
+//! Statistics about the heap of the collector.
+
+use std::time::Duration;
+
+/// A snapshot of the statistics of the heap of a thread, returned by [`stats`](crate::stats).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GcStats {
+    pub(crate) heap_size: usize,
+    pub(crate) external_memory: usize,
+    pub(crate) live_objects: usize,
+    pub(crate) collection_threshold: usize,
+    pub(crate) heap_limit: Option<usize>,
+    pub(crate) collections: u64,
+    pub(crate) collection_time: Duration,
+    pub(crate) last_collection_time: Duration,
+}
+
+impl GcStats {
+    /// Gets the number of bytes used by the heap, including the memory owned by its values
+    /// outside of it.
+    #[inline]
+    pub fn heap_size(&self) -> usize {
+        self.heap_size
+    }
+
+    /// Gets the number of bytes owned by the values of the heap outside of it.
+    #[inline]
+    pub fn external_memory(&self) -> usize {
+        self.external_memory
+    }
+
+    /// Gets the number of garbage collected boxes in the heap.
+    #[inline]
+    pub fn live_objects(&self) -> usize {
+        self.live_objects
+    }
+
+    /// Gets the heap size that triggers the next collection.
+    #[inline]
+    pub fn collection_threshold(&self) -> usize {
+        self.collection_threshold
+    }
+
+    /// Gets the maximum size of the heap, if any.
+    #[inline]
+    pub fn heap_limit(&self) -> Option<usize> {
+        self.heap_limit
+    }
+
+    /// Gets the number of collections run on the heap.
+    #[inline]
+    pub fn collections(&self) -> u64 {
+        self.collections
+    }
+
+    /// Gets the total time spent collecting the heap.
+    #[inline]
+    pub fn collection_time(&self) -> Duration {
+        self.collection_time
+    }
+
+    /// Gets the time spent in the last collection of the heap.
+    #[inline]
+    pub fn last_collection_time(&self) -> Duration {
+        self.last_collection_time
+    }
+}
+
+/// Measures the duration of a collection.
+///
+/// `Instant` panics on `wasm32-unknown-unknown`, so collections are not timed there.
+pub(crate) struct Timer {
+    #[cfg(not(target_arch = "wasm32"))]
+    start: std::time::Instant,
+}
+
+impl Timer {
+    pub(crate) fn start() -> Self {
+        Self {
+            #[cfg(not(target_arch = "wasm32"))]
+            start: std::time::Instant::now(),
+        }
+    }
+
+    pub(crate) fn elapsed(&self) -> Duration {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.start.elapsed()
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            Duration::ZERO
+        }
+    }
+}
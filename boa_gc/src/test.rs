@@ -0,0 +1,203 @@
+use crate::{
+    fits_heap_limit, force_collect, heap_limit_exceeded, set_heap_limit, stats, Cell, Ephemeron,
+    ExternalMemory, Finalize, Gc, Trace, WeakGc, WeakMap,
+};
+use std::cell::Cell as StdCell;
+
+thread_local!(static FINALIZED: StdCell<usize> = const { StdCell::new(0) });
+
+fn finalized() -> usize {
+    FINALIZED.with(StdCell::get)
+}
+
+#[derive(Trace)]
+struct Node {
+    next: Cell<Option<Gc<Node>>>,
+}
+
+impl Finalize for Node {
+    fn finalize(&self) {
+        FINALIZED.with(|finalized| finalized.set(finalized.get() + 1));
+    }
+}
+
+#[test]
+fn gc_basic_cell_allocation() {
+    let gc_cell = Gc::new(Cell::new(16_u16));
+
+    force_collect();
+    assert_eq!(*gc_cell.borrow(), 16);
+
+    *gc_cell.borrow_mut() = 32;
+    force_collect();
+    assert_eq!(*gc_cell.borrow(), 32);
+}
+
+#[test]
+fn gc_collects_unreachable_cycles() {
+    let before = finalized();
+    {
+        let a = Gc::new(Node {
+            next: Cell::new(None),
+        });
+        let b = Gc::new(Node {
+            next: Cell::new(Some(a.clone())),
+        });
+        *a.next.borrow_mut() = Some(b);
+
+        force_collect();
+        assert_eq!(finalized(), before);
+    }
+
+    force_collect();
+    assert_eq!(finalized(), before + 2);
+}
+
+#[test]
+fn gc_cell_replace_component() {
+    let cell = Gc::new(Cell::new((Gc::new(1_u8), Gc::new(2_u8))));
+    let second = WeakGc::new(&cell.borrow().1);
+
+    // SAFETY: Locating the component doesn't allocate.
+    let old = unsafe { cell.replace_component(|pair| Some(&mut pair.1), Gc::new(3)) };
+    let old = old.expect("the component was located");
+    assert_eq!(*old, 2);
+
+    // SAFETY: Locating the component doesn't allocate.
+    let missing = unsafe { cell.replace_component(|_| None::<&mut Gc<u8>>, Gc::new(4)) };
+    assert_eq!(missing.map_err(|value| *value), Err(4));
+
+    // The new component is owned by the cell, and the old one is owned by the caller.
+    force_collect();
+    assert_eq!(*cell.borrow().1, 3);
+    assert!(second.is_alive());
+    drop(old);
+    force_collect();
+    assert!(!second.is_alive());
+}
+
+#[test]
+fn weak_gc_upgrade() {
+    let value = Gc::new(Cell::new(42));
+    let weak = WeakGc::new(&value);
+
+    force_collect();
+    assert!(weak.is_alive());
+    assert_eq!(*weak.upgrade().expect("value is alive").borrow(), 42);
+
+    drop(value);
+    force_collect();
+    assert!(!weak.is_alive());
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn ephemeron_keeps_value_alive_with_key() {
+    let key = Gc::new(1_u8);
+    let value = Gc::new(2_u8);
+    let weak_value = WeakGc::new(&value);
+    let ephemeron = Ephemeron::new(&key, value);
+
+    force_collect();
+    assert_eq!(ephemeron.value().as_deref(), Some(&2));
+    assert!(weak_value.is_alive());
+
+    drop(key);
+    force_collect();
+    assert!(ephemeron.key().is_none());
+    assert!(ephemeron.value().is_none());
+    assert!(!weak_value.is_alive());
+}
+
+#[test]
+fn ephemeron_value_referencing_key() {
+    // The value of the ephemeron keeps the key alive, but it must not prevent its collection.
+    let key = Gc::new(Cell::new(None::<Gc<u8>>));
+    let ephemeron = Ephemeron::new(&key, key.clone());
+    let weak_key = WeakGc::new(&key);
+
+    drop(key);
+    force_collect();
+    assert!(!weak_key.is_alive());
+    assert!(ephemeron.value().is_none());
+}
+
+#[test]
+fn weak_map_entries() {
+    let mut map = WeakMap::new();
+    let alive = Gc::new(Cell::new(1));
+    let dead = Gc::new(Cell::new(2));
+
+    map.insert(&alive, "alive");
+    map.insert(&dead, "dead");
+    assert_eq!(map.get(&dead), Some("dead"));
+
+    drop(dead);
+    force_collect();
+    assert_eq!(map.get(&alive), Some("alive"));
+    assert!(map.contains_key(&alive));
+
+    assert_eq!(map.remove(&alive), Some("alive"));
+    assert!(!map.contains_key(&alive));
+}
+
+#[test]
+fn stats_track_allocations() {
+    force_collect();
+    let before = stats();
+
+    let values: Vec<_> = (0..10).map(Gc::new).collect();
+    let allocated = stats();
+    assert_eq!(allocated.live_objects(), before.live_objects() + 10);
+    assert!(allocated.heap_size() > before.heap_size());
+
+    drop(values);
+    force_collect();
+    let collected = stats();
+    assert_eq!(collected.live_objects(), before.live_objects());
+    assert_eq!(collected.heap_size(), before.heap_size());
+    assert!(collected.collections() > allocated.collections());
+}
+
+#[test]
+fn heap_limit_is_reported() {
+    force_collect();
+    set_heap_limit(Some(stats().heap_size() + 256));
+    assert!(!heap_limit_exceeded());
+
+    let values: Vec<_> = (0..64_u64).map(Gc::new).collect();
+    assert!(heap_limit_exceeded());
+
+    drop(values);
+    force_collect();
+    assert!(!heap_limit_exceeded());
+
+    set_heap_limit(None);
+}
+
+#[test]
+fn external_memory_counts_towards_heap_limit() {
+    force_collect();
+    let base = stats().heap_size();
+    set_heap_limit(Some(base + 1024));
+
+    let buffer = Gc::new(ExternalMemory::new(512));
+    assert_eq!(stats().external_memory(), 512);
+    assert!(!heap_limit_exceeded());
+    assert!(!fits_heap_limit(1024));
+
+    let bigger = Gc::new(ExternalMemory::new(1024));
+    assert!(heap_limit_exceeded());
+
+    // Collecting the box releases the memory accounted by its value.
+    drop(bigger);
+    force_collect();
+    assert!(!heap_limit_exceeded());
+    assert_eq!(stats().external_memory(), 512);
+
+    drop(buffer);
+    force_collect();
+    assert_eq!(stats().external_memory(), 0);
+
+    set_heap_limit(None);
+}
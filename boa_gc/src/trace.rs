@@ -0,0 +1,453 @@
+use std::{
+    borrow::Cow,
+    cell::{Cell, RefCell},
+    collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque},
+    hash::{BuildHasher, Hash},
+    marker::PhantomData,
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::{
+        atomic::{
+            AtomicBool, AtomicI16, AtomicI32, AtomicI64, AtomicI8, AtomicIsize, AtomicU16,
+            AtomicU32, AtomicU64, AtomicU8, AtomicUsize,
+        },
+        mpsc::{Sender, SyncSender},
+        Arc, Mutex,
+    },
+};
+
+/// Substitute for the [`Drop`] trait for garbage collected types.
+///
+/// Types deriving [`Trace`] cannot implement [`Drop`], since their destructor could access
+/// garbage collected pointers that were already freed. The `finalize` method is called by the
+/// collector before freeing the value instead.
+pub trait Finalize {
+    /// Cleans up the value before it is collected.
+    fn finalize(&self) {}
+}
+
+/// The `Trace` trait, which needs to be implemented on garbage collected objects.
+///
+/// # Safety
+///
+/// Implementors must call the corresponding method on every garbage collected pointer
+/// contained in the value. Missing a pointer can result in it being freed while still in use.
+/// The [`custom_trace`] and [`unsafe_empty_trace`] macros help implementing this trait.
+pub unsafe trait Trace: Finalize {
+    /// Marks all contained `Gc`s.
+    ///
+    /// # Safety
+    ///
+    /// Must only be called by the collector.
+    unsafe fn trace(&self);
+
+    /// Increments the root count of all contained `Gc`s.
+    ///
+    /// # Safety
+    ///
+    /// Must only be called when the value is moved out of the garbage collected heap.
+    unsafe fn root(&self);
+
+    /// Decrements the root count of all contained `Gc`s.
+    ///
+    /// # Safety
+    ///
+    /// Must only be called when the value is moved into the garbage collected heap.
+    unsafe fn unroot(&self);
+
+    /// Runs [`Finalize::finalize`] on the value and all its contained values.
+    fn finalize_glue(&self);
+}
+
+/// Implements the methods of [`Trace`] with empty bodies.
+///
+/// Use this for types that don't contain any garbage collected pointer.
+#[macro_export]
+macro_rules! unsafe_empty_trace {
+    () => {
+        #[inline]
+        unsafe fn trace(&self) {}
+        #[inline]
+        unsafe fn root(&self) {}
+        #[inline]
+        unsafe fn unroot(&self) {}
+        #[inline]
+        fn finalize_glue(&self) {
+            $crate::Finalize::finalize(self)
+        }
+    };
+}
+
+/// Implements the methods of [`Trace`] from a single body.
+///
+/// The body receives the traced value as `$this`, and must call `mark` on every traceable
+/// value contained in it. `mark` dispatches to the right [`Trace`] method for each operation.
+#[macro_export]
+macro_rules! custom_trace {
+    ($this:ident, $body:expr) => {
+        #[inline]
+        unsafe fn trace(&self) {
+            #[inline]
+            unsafe fn mark<T: $crate::Trace + ?Sized>(it: &T) {
+                $crate::Trace::trace(it);
+            }
+            let $this = self;
+            $body
+        }
+        #[inline]
+        unsafe fn root(&self) {
+            #[inline]
+            unsafe fn mark<T: $crate::Trace + ?Sized>(it: &T) {
+                $crate::Trace::root(it);
+            }
+            let $this = self;
+            $body
+        }
+        #[inline]
+        unsafe fn unroot(&self) {
+            #[inline]
+            unsafe fn mark<T: $crate::Trace + ?Sized>(it: &T) {
+                $crate::Trace::unroot(it);
+            }
+            let $this = self;
+            $body
+        }
+        #[inline]
+        fn finalize_glue(&self) {
+            $crate::Finalize::finalize(self);
+            #[inline]
+            fn mark<T: $crate::Trace + ?Sized>(it: &T) {
+                $crate::Trace::finalize_glue(it);
+            }
+            let $this = self;
+            $body
+        }
+    };
+}
+
+impl<T: ?Sized> Finalize for &'static T {}
+// SAFETY: `'static` references cannot point to garbage collected values.
+unsafe impl<T: ?Sized> Trace for &'static T {
+    unsafe_empty_trace!();
+}
+
+macro_rules! simple_empty_finalize_trace {
+    ($($T:ty),*) => {
+        $(
+            impl Finalize for $T {}
+            // SAFETY: The type doesn't contain any garbage collected pointer.
+            unsafe impl Trace for $T { unsafe_empty_trace!(); }
+        )*
+    }
+}
+
+simple_empty_finalize_trace![
+    (),
+    bool,
+    isize,
+    usize,
+    i8,
+    u8,
+    i16,
+    u16,
+    i32,
+    u32,
+    i64,
+    u64,
+    i128,
+    u128,
+    f32,
+    f64,
+    char,
+    String,
+    Box<str>,
+    Rc<str>,
+    Path,
+    PathBuf,
+    NonZeroIsize,
+    NonZeroUsize,
+    NonZeroI8,
+    NonZeroU8,
+    NonZeroI16,
+    NonZeroU16,
+    NonZeroI32,
+    NonZeroU32,
+    NonZeroI64,
+    NonZeroU64,
+    NonZeroI128,
+    NonZeroU128,
+    AtomicBool,
+    AtomicIsize,
+    AtomicUsize,
+    AtomicI8,
+    AtomicU8,
+    AtomicI16,
+    AtomicU16,
+    AtomicI32,
+    AtomicU32,
+    AtomicI64,
+    AtomicU64
+];
+
+impl<T: Trace, const N: usize> Finalize for [T; N] {}
+// SAFETY: Every element of the array is traced.
+unsafe impl<T: Trace, const N: usize> Trace for [T; N] {
+    custom_trace!(this, {
+        for v in this {
+            mark(v);
+        }
+    });
+}
+
+macro_rules! fn_finalize_trace_one {
+    ($ty:ty $(,$args:ident)*) => {
+        impl<Ret $(,$args)*> Finalize for $ty {}
+        // SAFETY: Function pointers don't contain any garbage collected pointer.
+        unsafe impl<Ret $(,$args)*> Trace for $ty { unsafe_empty_trace!(); }
+    }
+}
+
+macro_rules! fn_finalize_trace_group {
+    () => {
+        fn_finalize_trace_one!(extern "Rust" fn () -> Ret);
+        fn_finalize_trace_one!(extern "C" fn () -> Ret);
+        fn_finalize_trace_one!(unsafe extern "Rust" fn () -> Ret);
+        fn_finalize_trace_one!(unsafe extern "C" fn () -> Ret);
+    };
+    ($($args:ident),*) => {
+        fn_finalize_trace_one!(extern "Rust" fn ($($args),*) -> Ret, $($args),*);
+        fn_finalize_trace_one!(extern "C" fn ($($args),*) -> Ret, $($args),*);
+        fn_finalize_trace_one!(extern "C" fn ($($args),*, ...) -> Ret, $($args),*);
+        fn_finalize_trace_one!(unsafe extern "Rust" fn ($($args),*) -> Ret, $($args),*);
+        fn_finalize_trace_one!(unsafe extern "C" fn ($($args),*) -> Ret, $($args),*);
+        fn_finalize_trace_one!(unsafe extern "C" fn ($($args),*, ...) -> Ret, $($args),*);
+    }
+}
+
+macro_rules! tuple_finalize_trace {
+    () => {}; // The unit type is handled by `simple_empty_finalize_trace`.
+    ($($args:ident),*) => {
+        impl<$($args),*> Finalize for ($($args,)*) {}
+        // SAFETY: Every element of the tuple is traced.
+        unsafe impl<$($args: $crate::Trace),*> Trace for ($($args,)*) {
+            custom_trace!(this, {
+                #[allow(non_snake_case, unused_unsafe)]
+                fn avoid_lints<$($args: $crate::Trace),*>(&($(ref $args,)*): &($($args,)*)) {
+                    unsafe { $(mark($args);)* }
+                }
+                avoid_lints(this)
+            });
+        }
+    }
+}
+
+macro_rules! type_arg_tuple_based_finalize_trace_impls {
+    ($(($($args:ident),*);)*) => {
+        $(
+            fn_finalize_trace_group!($($args),*);
+            tuple_finalize_trace!($($args),*);
+        )*
+    }
+}
+
+type_arg_tuple_based_finalize_trace_impls![
+    ();
+    (A);
+    (A, B);
+    (A, B, C);
+    (A, B, C, D);
+    (A, B, C, D, E);
+    (A, B, C, D, E, F);
+    (A, B, C, D, E, F, G);
+    (A, B, C, D, E, F, G, H);
+    (A, B, C, D, E, F, G, H, I);
+    (A, B, C, D, E, F, G, H, I, J);
+    (A, B, C, D, E, F, G, H, I, J, K);
+    (A, B, C, D, E, F, G, H, I, J, K, L);
+];
+
+impl<T: Trace + ?Sized> Finalize for Rc<T> {}
+// SAFETY: The contained value is traced.
+unsafe impl<T: Trace + ?Sized> Trace for Rc<T> {
+    custom_trace!(this, {
+        mark(&**this);
+    });
+}
+
+impl<T: Trace + ?Sized> Finalize for Box<T> {}
+// SAFETY: The contained value is traced.
+unsafe impl<T: Trace + ?Sized> Trace for Box<T> {
+    custom_trace!(this, {
+        mark(&**this);
+    });
+}
+
+impl<T: Trace> Finalize for Box<[T]> {}
+// SAFETY: Every element of the slice is traced.
+unsafe impl<T: Trace> Trace for Box<[T]> {
+    custom_trace!(this, {
+        for e in this.iter() {
+            mark(e);
+        }
+    });
+}
+
+impl<T: Trace> Finalize for Vec<T> {}
+// SAFETY: Every element of the vector is traced.
+unsafe impl<T: Trace> Trace for Vec<T> {
+    custom_trace!(this, {
+        for e in this {
+            mark(e);
+        }
+    });
+}
+
+impl<T: Trace> Finalize for Option<T> {}
+// SAFETY: The contained value is traced.
+unsafe impl<T: Trace> Trace for Option<T> {
+    custom_trace!(this, {
+        if let Some(ref v) = *this {
+            mark(v);
+        }
+    });
+}
+
+impl<T: Trace, E: Trace> Finalize for Result<T, E> {}
+// SAFETY: Both variants are traced.
+unsafe impl<T: Trace, E: Trace> Trace for Result<T, E> {
+    custom_trace!(this, {
+        match *this {
+            Ok(ref v) => mark(v),
+            Err(ref v) => mark(v),
+        }
+    });
+}
+
+impl<T: Ord + Trace> Finalize for BinaryHeap<T> {}
+// SAFETY: Every element of the heap is traced.
+unsafe impl<T: Ord + Trace> Trace for BinaryHeap<T> {
+    custom_trace!(this, {
+        for v in this.iter() {
+            mark(v);
+        }
+    });
+}
+
+impl<K: Trace, V: Trace> Finalize for BTreeMap<K, V> {}
+// SAFETY: Every key and value of the map is traced.
+unsafe impl<K: Trace, V: Trace> Trace for BTreeMap<K, V> {
+    custom_trace!(this, {
+        for (k, v) in this {
+            mark(k);
+            mark(v);
+        }
+    });
+}
+
+impl<T: Trace> Finalize for BTreeSet<T> {}
+// SAFETY: Every element of the set is traced.
+unsafe impl<T: Trace> Trace for BTreeSet<T> {
+    custom_trace!(this, {
+        for v in this {
+            mark(v);
+        }
+    });
+}
+
+impl<K: Eq + Hash + Trace, V: Trace, S: BuildHasher> Finalize for HashMap<K, V, S> {}
+// SAFETY: Every key and value of the map is traced.
+unsafe impl<K: Eq + Hash + Trace, V: Trace, S: BuildHasher> Trace for HashMap<K, V, S> {
+    custom_trace!(this, {
+        for (k, v) in this.iter() {
+            mark(k);
+            mark(v);
+        }
+    });
+}
+
+impl<T: Eq + Hash + Trace, S: BuildHasher> Finalize for HashSet<T, S> {}
+// SAFETY: Every element of the set is traced.
+unsafe impl<T: Eq + Hash + Trace, S: BuildHasher> Trace for HashSet<T, S> {
+    custom_trace!(this, {
+        for v in this.iter() {
+            mark(v);
+        }
+    });
+}
+
+impl<T: Eq + Hash + Trace> Finalize for LinkedList<T> {}
+// SAFETY: Every element of the list is traced.
+unsafe impl<T: Eq + Hash + Trace> Trace for LinkedList<T> {
+    custom_trace!(this, {
+        for v in this.iter() {
+            mark(v);
+        }
+    });
+}
+
+impl<T: Trace> Finalize for VecDeque<T> {}
+// SAFETY: Every element of the deque is traced.
+unsafe impl<T: Trace> Trace for VecDeque<T> {
+    custom_trace!(this, {
+        for v in this.iter() {
+            mark(v);
+        }
+    });
+}
+
+impl<T: ?Sized> Finalize for PhantomData<T> {}
+// SAFETY: `PhantomData` doesn't contain any value.
+unsafe impl<T: ?Sized> Trace for PhantomData<T> {
+    unsafe_empty_trace!();
+}
+
+macro_rules! send_empty_finalize_trace {
+    ($($T:ident $(: $bound:path)?),*) => {
+        $(
+            impl<T: Send $(+ $bound)? + ?Sized> Finalize for $T<T> {}
+            // SAFETY: `Gc` is not `Send`, so a value that can be sent to another thread cannot
+            // contain garbage collected pointers.
+            unsafe impl<T: Send $(+ $bound)? + ?Sized> Trace for $T<T> { unsafe_empty_trace!(); }
+        )*
+    }
+}
+
+send_empty_finalize_trace![Arc: Sync, Mutex, RefCell];
+
+impl<T: Send> Finalize for Sender<T> {}
+// SAFETY: The values sent through the channel are `Send`, so they cannot contain garbage
+// collected pointers.
+unsafe impl<T: Send> Trace for Sender<T> {
+    unsafe_empty_trace!();
+}
+
+impl<T: Send> Finalize for SyncSender<T> {}
+// SAFETY: The values sent through the channel are `Send`, so they cannot contain garbage
+// collected pointers.
+unsafe impl<T: Send> Trace for SyncSender<T> {
+    unsafe_empty_trace!();
+}
+
+impl<T: Copy + Trace> Finalize for Cell<T> {}
+// SAFETY: `Cell` only contains `Copy` values, which cannot contain garbage collected pointers
+// since `Gc` is not `Copy`.
+unsafe impl<T: Copy + Trace> Trace for Cell<T> {
+    unsafe_empty_trace!();
+}
+
+impl<T: ToOwned + Trace + ?Sized> Finalize for Cow<'static, T> {}
+// SAFETY: Both the borrowed and the owned variants are traced.
+unsafe impl<T: ToOwned + Trace + ?Sized> Trace for Cow<'static, T>
+where
+    T::Owned: Trace,
+{
+    custom_trace!(this, {
+        if let Cow::Owned(ref v) = this {
+            mark(v);
+        }
+    });
+}
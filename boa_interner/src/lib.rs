@@ -81,7 +81,10 @@ mod tests;
 use fixed_string::FixedString;
 pub use sym::*;
 
-use std::fmt::{Debug, Display};
+use std::{
+    borrow::Cow,
+    fmt::{Debug, Display},
+};
 
 use interned_str::InternedStr;
 use rustc_hash::FxHashMap;
@@ -105,6 +108,11 @@ pub struct Interner {
     spans: Vec<InternedStr>,
     head: FixedString,
     full: Vec<FixedString>,
+
+    // Strings with unpaired surrogates cannot be stored as a `str`, so their UTF-16 code units
+    // are kept here, and `spans` stores a lossy version of them used for display purposes.
+    utf16_symbols: FxHashMap<Box<[u16]>, Sym>,
+    utf16_spans: FxHashMap<Sym, Box<[u16]>>,
 }
 
 impl Interner {
@@ -122,6 +130,8 @@ impl Interner {
             spans: Vec::with_capacity(capacity),
             head: FixedString::new(capacity),
             full: Vec::new(),
+            utf16_symbols: FxHashMap::default(),
+            utf16_spans: FxHashMap::default(),
         }
     }
 
@@ -164,6 +174,51 @@ impl Interner {
             return sym;
         }
 
+        // SAFETY: The string is stored inside `head` or `full`, and its symbol is added to
+        // `symbols`.
+        unsafe {
+            let interned_str = self.push_str(string);
+            self.generate_symbol(interned_str)
+        }
+    }
+
+    /// Interns the given UTF-16 string, which may contain unpaired surrogates.
+    ///
+    /// Returns a symbol for resolution into the original string. Well-formed strings share
+    /// their symbols with the equivalent strings interned with [`Interner::get_or_intern`].
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible by the chosen symbol type.
+    pub fn get_or_intern_utf16(&mut self, string: &[u16]) -> Sym {
+        if let Ok(string) = String::from_utf16(string) {
+            return self.get_or_intern(string);
+        }
+
+        if let Some(sym) = self.utf16_symbols.get(string) {
+            return *sym;
+        }
+
+        // The lossy version of the string is only stored for `resolve`, so it isn't added to
+        // `symbols`: it must not be returned when interning the lossy string itself.
+        let lossy = String::from_utf16_lossy(string);
+        let sym = Sym::new(self.len() + 1).expect("cannot get interner symbol: integer overflow");
+
+        // SAFETY: The string is stored inside `head` or `full`.
+        let interned_str = unsafe { self.push_str(&lossy) };
+        self.spans.push(interned_str);
+        self.utf16_symbols.insert(string.into(), sym);
+        self.utf16_spans.insert(sym, string.into());
+        sym
+    }
+
+    /// Stores the given string inside `head`, allocating a new `head` if it doesn't have
+    /// enough capacity.
+    ///
+    /// # Safety
+    ///
+    /// The returned [`InternedStr`] must not outlive the [`Interner`].
+    unsafe fn push_str(&mut self, string: &str) -> InternedStr {
         // SAFETY:
         //
         // Firstly, this interner works on the assumption that the allocated
@@ -190,7 +245,7 @@ impl Interner {
         // of `head` is not deallocated until the whole `Interner` deallocates,
         // which we can do by moving it inside the `Interner` itself, specifically
         // on the `full` vector, where every other old `head` also lives.
-        let interned_str = unsafe {
+        unsafe {
             self.head.push(string).unwrap_or_else(|| {
                 let new_cap =
                     (usize::max(self.head.capacity(), string.len()) + 1).next_power_of_two();
@@ -208,12 +263,7 @@ impl Interner {
                 }
                 self.head.push_unchecked(string)
             })
-        };
-
-        // SAFETY: We are obtaining a pointer to the internal memory of
-        // `head`, which is alive through the whole life of `Interner`, so
-        // this is safe.
-        unsafe { self.generate_symbol(interned_str) }
+        }
     }
 
     /// Interns the given `'static` string.
@@ -238,6 +288,10 @@ impl Interner {
     }
 
     /// Returns the string for the given symbol if any.
+    ///
+    /// Unpaired surrogates of strings interned with [`Interner::get_or_intern_utf16`] are
+    /// replaced by the replacement character (U+FFFD). Use [`Interner::resolve_utf16`] to get
+    /// the original string.
     #[inline]
     pub fn resolve(&self, symbol: Sym) -> Option<&str> {
         let index = symbol.get() - 1;
@@ -260,6 +314,39 @@ impl Interner {
         self.resolve(symbol).expect("string disappeared")
     }
 
+    /// Returns the UTF-16 code units of the string for the given symbol if any.
+    #[inline]
+    pub fn resolve_utf16(&self, symbol: Sym) -> Option<Cow<'_, [u16]>> {
+        if let Some(string) = self.utf16_spans.get(&symbol) {
+            return Some(Cow::Borrowed(string));
+        }
+        self.resolve(symbol)
+            .map(|string| Cow::Owned(string.encode_utf16().collect()))
+    }
+
+    /// Returns the UTF-16 code units of the string for the given symbol.
+    ///
+    /// # Panics
+    ///
+    /// If the interner cannot resolve the given symbol.
+    #[inline]
+    pub fn resolve_expect_utf16(&self, symbol: Sym) -> Cow<'_, [u16]> {
+        self.resolve_utf16(symbol).expect("string disappeared")
+    }
+
+    /// Returns an iterator over the symbols and strings of the interner, in the order they were
+    /// interned, starting with the common strings.
+    ///
+    /// Interning the strings in the same order in a new [`Interner`] gives them the same symbols.
+    /// Strings with unpaired surrogates are returned in their lossy form, so they have to be
+    /// interned again from [`Interner::resolve_expect_utf16`].
+    pub fn iter(&self) -> impl Iterator<Item = (Sym, &str)> {
+        (1..=self.len()).map(|index| {
+            let symbol = Sym::new(index).expect("symbol indices start at one");
+            (symbol, self.resolve_expect(symbol))
+        })
+    }
+
     /// Gets the symbol of the common string if one of them
     fn get_common(string: &str) -> Option<Sym> {
         COMMON_STRINGS.get_index(string).map(|idx|
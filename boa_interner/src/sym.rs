@@ -100,6 +100,12 @@ impl Sym {
     /// Symbol for the `"target"` string.
     pub const TARGET: Self = unsafe { Self::new_unchecked(28) };
 
+    /// Symbol for the `"as"` string.
+    pub const AS: Self = unsafe { Self::new_unchecked(29) };
+
+    /// Symbol for the `"from"` string.
+    pub const FROM: Self = unsafe { Self::new_unchecked(30) };
+
     /// Creates a new [`Sym`] from the provided `value`, or returns `None` if `index` is zero.
     #[inline]
     pub(super) fn new(value: usize) -> Option<Self> {
@@ -165,6 +171,8 @@ pub(super) static COMMON_STRINGS: phf::OrderedSet<&'static str> = {
         "async",
         "of",
         "target",
+        "as",
+        "from",
     };
     // A `COMMON_STRINGS` of size `usize::MAX` would cause an overflow on our `Interner`
     sa::const_assert!(COMMON_STRINGS.len() < usize::MAX);
@@ -56,3 +56,36 @@ fn check_static_resolve() {
         assert_eq!(sym, new_sym);
     }
 }
+
+#[test]
+fn check_iter_order() {
+    let mut interner = Interner::default();
+    let hello = interner.get_or_intern("hello");
+    let world = interner.get_or_intern("world");
+
+    let strings: Vec<_> = interner.iter().skip(COMMON_STRINGS.len()).collect();
+    assert_eq!(strings, [(hello, "hello"), (world, "world")]);
+
+    let mut other = Interner::default();
+    for (sym, string) in interner.iter() {
+        assert_eq!(other.get_or_intern(string), sym);
+    }
+}
+
+#[test]
+fn check_utf16() {
+    let mut interner = Interner::default();
+
+    let hello: Vec<u16> = "hello".encode_utf16().collect();
+    let sym = interner.get_or_intern_utf16(&hello);
+    assert_eq!(sym, interner.get_or_intern("hello"));
+    assert_eq!(interner.resolve_expect_utf16(sym), hello.as_slice());
+
+    let unpaired = [0x61, 0xD800, 0x62];
+    let sym = interner.get_or_intern_utf16(&unpaired);
+    assert_eq!(interner.resolve_expect(sym), "a\u{FFFD}b");
+    assert_eq!(interner.resolve_expect_utf16(sym), unpaired.as_slice());
+    assert_eq!(interner.get_or_intern_utf16(&unpaired), sym);
+    assert_ne!(interner.get_or_intern("a\u{FFFD}b"), sym);
+    assert_ne!(interner.get_or_intern_utf16(&[0x61, 0xDC00, 0x62]), sym);
+}
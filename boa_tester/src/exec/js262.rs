@@ -1,12 +1,27 @@
 use boa_engine::{
-    builtins::JsArgs,
-    object::{JsObject, ObjectInitializer},
+    builtins::{shared_array_buffer::SharedDataBlock, JsArgs},
+    object::{FunctionBuilder, JsObject, JsSharedArrayBuffer, ObjectInitializer},
     property::Attribute,
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue,
+};
+use once_cell::sync::Lazy;
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    rc::Rc,
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
 };
 
 /// Initializes the object in the context.
 pub(super) fn init(context: &mut Context) -> JsObject {
+    let agent = main_agent(context);
+    init_with_agent(context, agent)
+}
+
+/// Initializes the object in the context, using `agent` as the `$262.agent` object.
+fn init_with_agent(context: &mut Context, agent: JsObject) -> JsObject {
     let global_obj = context.global_object().clone();
 
     let obj = ObjectInitializer::new(context)
@@ -15,7 +30,7 @@ pub(super) fn init(context: &mut Context) -> JsObject {
         .function(eval_script, "evalScript", 1)
         .function(gc, "gc", 0)
         .property("global", global_obj, Attribute::default())
-        // .property("agent", agent, Attribute::default())
+        .property("agent", agent, Attribute::default())
         .build();
 
     context.register_global_property("$262", obj.clone(), Attribute::empty());
@@ -28,11 +43,14 @@ pub(super) fn init(context: &mut Context) -> JsObject {
 /// Creates a new ECMAScript Realm, defines this API on the new realm's global object, and
 /// returns the `$262` property of the new realm's global object.
 #[allow(clippy::unnecessary_wraps)]
-fn create_realm(_this: &JsValue, _: &[JsValue], _context: &mut Context) -> JsResult<JsValue> {
-    let mut context = Context::default();
+fn create_realm(_this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let realm = context.create_realm();
+    let old_realm = context.enter_realm(realm);
 
     // add the $262 object.
-    let js_262 = init(&mut context);
+    let js_262 = init(context);
+
+    context.enter_realm(old_realm);
 
     Ok(JsValue::new(js_262))
 }
@@ -40,32 +58,31 @@ fn create_realm(_this: &JsValue, _: &[JsValue], _context: &mut Context) -> JsRes
 /// The `$262.detachArrayBuffer()` function.
 ///
 /// Implements the `DetachArrayBuffer` abstract operation.
-fn detach_array_buffer(
-    _this: &JsValue,
-    args: &[JsValue],
-    context: &mut Context,
-) -> JsResult<JsValue> {
+fn detach_array_buffer(_this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
     #[inline]
-    fn type_err(context: &mut Context) -> JsValue {
-        context.construct_type_error("The provided object was not an ArrayBuffer")
+    fn type_err() -> JsNativeError {
+        JsNativeError::typ().with_message("The provided object was not an ArrayBuffer")
     }
 
     let array_buffer = args
         .get(0)
         .and_then(JsValue::as_object)
-        .ok_or_else(|| type_err(context))?;
+        .ok_or_else(type_err)?;
     let mut array_buffer = array_buffer.borrow_mut();
-    let array_buffer = array_buffer
-        .as_array_buffer_mut()
-        .ok_or_else(|| type_err(context))?;
+    let array_buffer = array_buffer.as_array_buffer_mut().ok_or_else(type_err)?;
 
-    // 1. Assert: IsSharedArrayBuffer(arrayBuffer) is false. TODO
+    // 1. Assert: IsSharedArrayBuffer(arrayBuffer) is false.
+    if array_buffer.is_shared() {
+        return Err(type_err().into());
+    }
     // 2. If key is not present, set key to undefined.
     let key = args.get_or_undefined(1);
 
     // 3. If SameValue(arrayBuffer.[[ArrayBufferDetachKey]], key) is false, throw a TypeError exception.
     if !JsValue::same_value(&array_buffer.array_buffer_detach_key, key) {
-        return context.throw_type_error("Cannot detach array buffer with different key");
+        return Err(JsNativeError::typ()
+            .with_message("Cannot detach array buffer with different key")
+            .into());
     }
 
     // 4. Set arrayBuffer.[[ArrayBufferData]] to null.
@@ -83,12 +100,17 @@ fn detach_array_buffer(
 /// Accepts a string value as its first argument and executes it as an ECMAScript script.
 fn eval_script(_this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
     if let Some(source_text) = args.get(0).and_then(JsValue::as_string) {
-        match context.parse(source_text.as_str()) {
+        // The code units are passed through to the parser, so unpaired surrogates aren't
+        // replaced by escape sequences.
+        let source_text = source_text.to_wtf8();
+        match context.parse(&source_text) {
             // TODO: check strict
-            Err(e) => context.throw_type_error(format!("Uncaught Syntax Error: {e}")),
+            Err(e) => Err(JsNativeError::typ()
+                .with_message(format!("Uncaught Syntax Error: {e}"))
+                .into()),
             // Calling eval here parses the code a second time.
             // TODO: We can fix this after we have have defined the public api for the vm executer.
-            Ok(_) => context.eval(source_text.as_str()),
+            Ok(_) => context.eval(&source_text),
         }
     } else {
         Ok(JsValue::undefined())
@@ -105,3 +127,238 @@ fn gc(_this: &JsValue, _: &[JsValue], _context: &mut Context) -> JsResult<JsValu
     boa_gc::force_collect();
     Ok(JsValue::undefined())
 }
+
+/// A message sent by `$262.agent.broadcast()` to the agents started by the main agent.
+#[derive(Debug)]
+struct Broadcast {
+    block: SharedDataBlock,
+    id: f64,
+    received: mpsc::Sender<()>,
+}
+
+/// The state of the agents started by the main agent of a test.
+#[derive(Debug, Default)]
+struct Agents {
+    /// The channels used to broadcast messages to the started agents.
+    broadcasts: Vec<mpsc::Sender<Broadcast>>,
+
+    /// The reports of the started agents that were not yet retrieved by the main agent.
+    reports: Arc<Mutex<VecDeque<String>>>,
+}
+
+/// The time origin of `$262.agent.monotonicNow()`.
+static START: Lazy<Instant> = Lazy::new(Instant::now);
+
+thread_local! {
+    /// The callback registered by `$262.agent.receiveBroadcast()` in the agent of this thread.
+    static BROADCAST_CALLBACK: RefCell<Option<JsObject>> = const { RefCell::new(None) };
+}
+
+/// Creates the `$262.agent` object of the main agent of a test.
+fn main_agent(context: &mut Context) -> JsObject {
+    let agents = Rc::new(RefCell::new(Agents::default()));
+
+    let start = FunctionBuilder::closure_with_state(
+        context,
+        |_, args, agents, context| {
+            let source = args
+                .get_or_undefined(0)
+                .to_string(context)?
+                .to_std_string_escaped();
+            start_agent(&mut agents.borrow_mut(), source);
+            Ok(JsValue::undefined())
+        },
+        agents.clone(),
+    )
+    .name("start")
+    .length(1)
+    .build();
+
+    let broadcast = FunctionBuilder::closure_with_state(
+        context,
+        |_, args, agents, context| {
+            let buffer = args
+                .get_or_undefined(0)
+                .as_object()
+                .cloned()
+                .ok_or_else(|| JsNativeError::typ().with_message("expected a SharedArrayBuffer"))?;
+            let block = JsSharedArrayBuffer::from_object(buffer, context)?.data_block();
+            let id = args.get_or_undefined(1).to_number(context)?;
+            broadcast(&agents.borrow(), &block, id);
+            Ok(JsValue::undefined())
+        },
+        agents.clone(),
+    )
+    .name("broadcast")
+    .length(2)
+    .build();
+
+    let get_report = FunctionBuilder::closure_with_state(
+        context,
+        |_, _, reports, _| {
+            let report = reports
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .pop_front();
+            Ok(report.map_or_else(JsValue::null, JsValue::from))
+        },
+        agents.borrow().reports.clone(),
+    )
+    .name("getReport")
+    .length(0)
+    .build();
+
+    ObjectInitializer::new(context)
+        .property("start", start, Attribute::default())
+        .property("broadcast", broadcast, Attribute::default())
+        .property("getReport", get_report, Attribute::default())
+        .function(sleep, "sleep", 1)
+        .function(monotonic_now, "monotonicNow", 0)
+        .build()
+}
+
+/// Creates the `$262.agent` object of an agent started with `$262.agent.start()`.
+fn child_agent(context: &mut Context, reports: Arc<Mutex<VecDeque<String>>>) -> JsObject {
+    let report = FunctionBuilder::closure_with_state(
+        context,
+        |_, args, reports, context| {
+            let report = args
+                .get_or_undefined(0)
+                .to_string(context)?
+                .to_std_string_escaped();
+            reports
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .push_back(report);
+            Ok(JsValue::undefined())
+        },
+        reports,
+    )
+    .name("report")
+    .length(1)
+    .build();
+
+    ObjectInitializer::new(context)
+        .function(receive_broadcast, "receiveBroadcast", 1)
+        .property("report", report, Attribute::default())
+        .function(leaving, "leaving", 0)
+        .function(sleep, "sleep", 1)
+        .function(monotonic_now, "monotonicNow", 0)
+        .build()
+}
+
+/// The `$262.agent.start()` function.
+///
+/// Starts a new agent on its own thread, which evaluates `source` and then waits for a
+/// broadcast if it registered a callback with `$262.agent.receiveBroadcast()`.
+fn start_agent(agents: &mut Agents, source: String) {
+    let (sender, receiver) = mpsc::channel::<Broadcast>();
+    let reports = agents.reports.clone();
+    agents.broadcasts.push(sender);
+
+    thread::spawn(move || {
+        let mut context = Context::default();
+        let agent = child_agent(&mut context, reports);
+        init_with_agent(&mut context, agent);
+
+        if let Err(e) = context.eval(source.as_str()) {
+            eprintln!("agent error: {}", e.to_opaque(&mut context).display());
+            return;
+        }
+
+        let callback = match BROADCAST_CALLBACK.with(|callback| callback.borrow_mut().take()) {
+            Some(callback) => callback,
+            None => return,
+        };
+
+        let message = match receiver.recv() {
+            Ok(message) => message,
+            Err(_) => return,
+        };
+        let _ = message.received.send(());
+        drop(message.received);
+
+        let buffer = JsSharedArrayBuffer::from_data_block(message.block, &mut context);
+        let result = callback
+            .call(
+                &JsValue::undefined(),
+                &[buffer.into(), message.id.into()],
+                &mut context,
+            )
+            .and_then(|_| context.run_jobs());
+        if let Err(e) = result {
+            eprintln!("agent error: {}", e.to_opaque(&mut context).display());
+        }
+    });
+}
+
+/// The `$262.agent.broadcast()` function.
+///
+/// Sends the shared `block` and `id` to all the started agents, and blocks until all agents
+/// waiting for a broadcast have received it.
+fn broadcast(agents: &Agents, block: &SharedDataBlock, id: f64) {
+    let (received, receipts) = mpsc::channel();
+    let mut pending = 0;
+    for agent in &agents.broadcasts {
+        // Agents that already finished dropped their receiver, so they are skipped.
+        let message = Broadcast {
+            block: block.clone(),
+            id,
+            received: received.clone(),
+        };
+        if agent.send(message).is_ok() {
+            pending += 1;
+        }
+    }
+    drop(received);
+
+    // Agents that finish without reading the message drop it, which closes the channel once
+    // no other agent can acknowledge it.
+    while pending > 0 && receipts.recv().is_ok() {
+        pending -= 1;
+    }
+}
+
+/// The `$262.agent.receiveBroadcast()` function.
+///
+/// Registers the callback called with the shared buffer and the id of the next broadcast.
+fn receive_broadcast(_this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+    let callback = args
+        .get_or_undefined(0)
+        .as_callable()
+        .cloned()
+        .ok_or_else(|| JsNativeError::typ().with_message("expected a callback"))?;
+    BROADCAST_CALLBACK.with(|cb| *cb.borrow_mut() = Some(callback));
+    Ok(JsValue::undefined())
+}
+
+/// The `$262.agent.leaving()` function.
+///
+/// Signals that the agent is done. Agents finish when their script and broadcast callback
+/// return, so this does nothing.
+#[allow(clippy::unnecessary_wraps)]
+fn leaving(_this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+    Ok(JsValue::undefined())
+}
+
+/// The `$262.agent.sleep()` function.
+///
+/// Suspends the agent for the given number of milliseconds.
+fn sleep(_this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let ms = args.get_or_undefined(0).to_number(context)?;
+    if ms.is_finite() && ms > 0.0 {
+        thread::sleep(Duration::from_secs_f64(
+            (ms / 1000.0).min(f64::from(u32::MAX)),
+        ));
+    }
+    Ok(JsValue::undefined())
+}
+
+/// The `$262.agent.monotonicNow()` function.
+///
+/// Returns the number of milliseconds elapsed since an arbitrary point in time, which is the
+/// same for all agents.
+#[allow(clippy::unnecessary_wraps)]
+fn monotonic_now(_this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+    Ok((START.elapsed().as_secs_f64() * 1000.0).into())
+}
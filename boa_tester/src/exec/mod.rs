@@ -7,13 +7,17 @@ use super::{
     TestSuite, IGNORED,
 };
 use boa_engine::{
-    builtins::JsArgs, object::FunctionBuilder, property::Attribute, syntax::Parser, Context,
-    JsResult, JsValue,
+    builtins::JsArgs,
+    module::{Module, SimpleModuleLoader},
+    object::FunctionBuilder,
+    property::Attribute,
+    syntax::Parser,
+    Context, JsResult, JsValue,
 };
 use boa_gc::{Cell, Finalize, Gc, Trace};
 use colored::Colorize;
 use rayon::prelude::*;
-use std::panic;
+use std::{panic, rc::Rc};
 
 impl TestSuite {
     /// Runs the test suite.
@@ -115,6 +119,12 @@ impl Test {
     /// Runs the test.
     pub(crate) fn run(&self, harness: &Harness, verbose: u8) -> Vec<TestResult> {
         let mut results = Vec::new();
+        if self.flags.contains(TestFlags::MODULE) {
+            // Module code is always strict mode code.
+            results.push(self.run_once(harness, true, verbose));
+            return results;
+        }
+
         if self.flags.contains(TestFlags::STRICT) && !self.flags.contains(TestFlags::RAW) {
             results.push(self.run_once(harness, true, verbose));
         }
@@ -136,7 +146,7 @@ impl Test {
             );
         }
 
-        let test_content = if strict {
+        let test_content = if strict && !self.flags.contains(TestFlags::MODULE) {
             format!("\"use strict\";\n{}", self.content)
         } else {
             self.content.to_string()
@@ -169,19 +179,19 @@ impl Test {
                 )) {
             let res = panic::catch_unwind(|| match self.expected_outcome {
                 Outcome::Positive => {
-                    let mut context = Context::default();
+                    let mut context = self.context();
 
                     let callback_obj = CallbackObject::default();
                     // TODO: timeout
                     match self.set_up_env(harness, &mut context, callback_obj.clone()) {
                         Ok(_) => {
-                            let res = context.eval(&test_content);
+                            let res = self.eval(&test_content, &mut context);
 
                             let passed = res.is_ok()
                                 && matches!(*callback_obj.result.borrow(), Some(true) | None);
                             let text = match res {
                                 Ok(val) => val.display().to_string(),
-                                Err(e) => format!("Uncaught {}", e.display()),
+                                Err(e) => format!("Uncaught {e}"),
                             };
 
                             (passed, text)
@@ -201,31 +211,74 @@ impl Test {
                     );
 
                     let mut context = Context::default();
-                    match context.parse(&test_content) {
-                        Ok(statement_list) => match context.compile(&statement_list) {
-                            Ok(_) => (false, "StatementList compilation should fail".to_owned()),
-                            Err(e) => (true, format!("Uncaught {e:?}")),
-                        },
-                        Err(e) => (true, format!("Uncaught {e}")),
+                    if self.flags.contains(TestFlags::MODULE) {
+                        match Module::parse(test_content.as_bytes(), None, &mut context) {
+                            Ok(_) => (false, "module parsing should fail".to_owned()),
+                            Err(e) => (true, format!("Uncaught {e}")),
+                        }
+                    } else {
+                        match context.parse(&test_content) {
+                            Ok(statement_list) => match context.compile(&statement_list) {
+                                Ok(_) => {
+                                    (false, "StatementList compilation should fail".to_owned())
+                                }
+                                Err(e) => (true, format!("Uncaught {e:?}")),
+                            },
+                            Err(e) => (true, format!("Uncaught {e}")),
+                        }
                     }
                 }
                 Outcome::Negative {
                     phase: Phase::Resolution,
-                    error_type: _,
-                } => todo!("check module resolution errors"),
+                    ref error_type,
+                } => {
+                    let mut context = self.context();
+                    let result = Module::parse(
+                        test_content.as_bytes(),
+                        Some(self.path.clone()),
+                        &mut context,
+                    )
+                    .and_then(|module| {
+                        module.load(&mut context)?;
+                        module.link(&mut context)
+                    });
+                    match result {
+                        Ok(_) => (false, "module resolution should fail".to_owned()),
+                        Err(e) => {
+                            let e = e.to_opaque(&mut context);
+                            let passed = e
+                                .display()
+                                .internals(true)
+                                .to_string()
+                                .contains(error_type.as_ref());
+
+                            (passed, format!("Uncaught {}", e.display()))
+                        }
+                    }
+                }
                 Outcome::Negative {
                     phase: Phase::Runtime,
                     ref error_type,
                 } => {
-                    let mut context = Context::default();
-                    if let Err(e) = Parser::new(test_content.as_bytes()).parse_all(&mut context) {
+                    let mut context = self.context();
+                    let parsed = if self.flags.contains(TestFlags::MODULE) {
+                        Parser::new(test_content.as_bytes())
+                            .parse_module(&mut context)
+                            .map(|_| ())
+                    } else {
+                        Parser::new(test_content.as_bytes())
+                            .parse_all(&mut context)
+                            .map(|_| ())
+                    };
+                    if let Err(e) = parsed {
                         (false, format!("Uncaught {e}"))
                     } else {
                         // TODO: timeout
                         match self.set_up_env(harness, &mut context, CallbackObject::default()) {
-                            Ok(_) => match context.eval(&test_content) {
+                            Ok(_) => match self.eval(&test_content, &mut context) {
                                 Ok(res) => (false, res.display().to_string()),
                                 Err(e) => {
+                                    let e = e.to_opaque(&mut context);
                                     let passed = e
                                         .display()
                                         .internals(true)
@@ -312,6 +365,41 @@ impl Test {
         }
     }
 
+    /// Creates the context to run the test in.
+    ///
+    /// Module tests get a module loader that resolves imports relative to the test file, and
+    /// tests flagged with `CanBlockIsFalse` run in an agent that cannot be suspended.
+    fn context(&self) -> Context {
+        let builder =
+            Context::builder().can_block(!self.flags.contains(TestFlags::CAN_BLOCK_IS_FALSE));
+        if self.flags.contains(TestFlags::MODULE) {
+            let root = self
+                .path
+                .parent()
+                .map_or_else(Default::default, ToOwned::to_owned);
+            builder
+                .module_loader(Rc::new(SimpleModuleLoader::new(root)))
+                .build()
+        } else {
+            builder.build()
+        }
+    }
+
+    /// Evaluates the test code, either as a script or as a module.
+    fn eval(&self, test_content: &str, context: &mut Context) -> JsResult<JsValue> {
+        if !self.flags.contains(TestFlags::MODULE) {
+            return context.eval(test_content);
+        }
+
+        let path = self
+            .path
+            .canonicalize()
+            .unwrap_or_else(|_| self.path.clone());
+        let module = Module::parse(test_content.as_bytes(), Some(path), context)?;
+        module.load_link_evaluate(context)?;
+        Ok(JsValue::undefined())
+    }
+
     /// Sets the environment up to run the test.
     fn set_up_env(
         &self,
@@ -331,15 +419,15 @@ impl Test {
 
         context
             .eval(harness.assert.as_ref())
-            .map_err(|e| format!("could not run assert.js:\n{}", e.display()))?;
+            .map_err(|e| format!("could not run assert.js:\n{e}"))?;
         context
             .eval(harness.sta.as_ref())
-            .map_err(|e| format!("could not run sta.js:\n{}", e.display()))?;
+            .map_err(|e| format!("could not run sta.js:\n{e}"))?;
 
         if self.flags.contains(TestFlags::ASYNC) {
             context
                 .eval(harness.doneprint_handle.as_ref())
-                .map_err(|e| format!("could not run doneprintHandle.js:\n{}", e.display()))?;
+                .map_err(|e| format!("could not run doneprintHandle.js:\n{e}"))?;
         }
 
         for include in self.includes.iter() {
@@ -351,12 +439,7 @@ impl Test {
                         .ok_or_else(|| format!("could not find the {include} include file."))?
                         .as_ref(),
                 )
-                .map_err(|e| {
-                    format!(
-                        "could not run the {include} include file:\nUncaught {}",
-                        e.display()
-                    )
-                })?;
+                .map_err(|e| format!("could not run the {include} include file:\nUncaught {e}"))?;
         }
 
         Ok(())
@@ -394,7 +477,7 @@ fn test262_print(
     _context: &mut Context,
 ) -> JsResult<JsValue> {
     if let Some(message) = args.get_or_undefined(0).as_string() {
-        *captures.result.borrow_mut() = Some(message.as_str() == "Test262:AsyncTestComplete");
+        *captures.result.borrow_mut() = Some(*message == "Test262:AsyncTestComplete");
     } else {
         *captures.result.borrow_mut() = Some(false);
     }